@@ -0,0 +1,44 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use geo::{AffineTransform, MapCoords};
+use geoarrow::algorithm::geo::AffineOps;
+use geoarrow::array::{PointArray, PointBuilder};
+use geoarrow::trait_::GeometryArrayAccessor;
+use geoarrow::GeometryArrayTrait;
+
+fn create_data() -> PointArray {
+    let points = (0..10_000_000)
+        .map(|i| geo::point!(x: i as f64, y: (i % 1000) as f64))
+        .collect::<Vec<_>>();
+    points.as_slice().into()
+}
+
+/// The previous implementation, which reconstructs each point as a geo object
+fn affine_transform_geo(array: &PointArray, transform: &AffineTransform) -> PointArray {
+    let mut builder = PointBuilder::with_capacity(array.len());
+    array.iter_geo().for_each(|maybe_g| {
+        builder.push_point(
+            maybe_g
+                .map(|geom| geom.map_coords(|coord| transform.apply(coord)))
+                .as_ref(),
+        )
+    });
+    builder.finish()
+}
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let data = create_data();
+    let transform = AffineTransform::rotate(45., (0., 0.).into()).translated(10., 20.);
+
+    let mut group = c.benchmark_group("affine_transform 10M PointArray");
+    group.sample_size(10);
+    group.bench_function("coordinate buffer", |b| {
+        b.iter(|| data.affine_transform(&transform))
+    });
+    group.bench_function("geo objects", |b| {
+        b.iter(|| affine_transform_geo(&data, &transform))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -1,6 +1,7 @@
 use criterion::{criterion_group, criterion_main, Criterion};
+use geoarrow::algorithm::broadcasting::BroadcastablePrimitive;
 use geoarrow::algorithm::geos::Buffer;
-use geoarrow::array::{CoordBuffer, InterleavedCoordBuffer, PointArray, PolygonArray};
+use geoarrow::array::{CoordBuffer, InterleavedCoordBuffer, MultiPolygonArray, PointArray};
 
 fn generate_data() -> PointArray {
     let coords = vec![0.0; 100_000];
@@ -11,9 +12,11 @@ fn generate_data() -> PointArray {
 pub fn criterion_benchmark(c: &mut Criterion) {
     let point_array = generate_data();
 
+    let distance = BroadcastablePrimitive::Scalar(1.0);
+
     c.bench_function("buffer", |b| {
         b.iter(|| {
-            let _buffered: PolygonArray<i32> = point_array.buffer(1.0, 8).unwrap();
+            let _buffered: MultiPolygonArray<i32> = point_array.buffer(&distance, 8).unwrap();
         })
     });
 }
@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use arrow_schema::Schema;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use geoarrow::array::PointArray;
+use geoarrow::io::parquet::{write_geoparquet, GeoParquetWriterOptions};
+use geoarrow::table::GeoTable;
+use geoarrow::trait_::GeometryArraySelfMethods;
+use geoarrow::GeometryArrayTrait;
+
+const NUM_POINTS: usize = 10_000_000;
+const BATCH_SIZE: usize = 65_536;
+
+fn create_table() -> GeoTable {
+    let points = (0..NUM_POINTS)
+        .map(|i| {
+            let i = i as f64;
+            geo::point!(x: (i * 0.001) % 360.0 - 180.0, y: (i * 0.0007) % 180.0 - 90.0)
+        })
+        .collect::<Vec<_>>();
+    let point_array = PointArray::from(points.as_slice());
+
+    let schema = Arc::new(Schema::new(vec![point_array.extension_field()]));
+    let batches = (0..NUM_POINTS)
+        .step_by(BATCH_SIZE)
+        .map(|offset| {
+            let length = BATCH_SIZE.min(NUM_POINTS - offset);
+            let chunk = point_array.slice(offset, length);
+            RecordBatch::try_new(schema.clone(), vec![chunk.into_array_ref()]).unwrap()
+        })
+        .collect();
+    GeoTable::try_new(schema, batches, 0).unwrap()
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let table = create_table();
+
+    let mut group = c.benchmark_group("write_geoparquet");
+    group.sample_size(10);
+    for writer_threads in [1, 2, 4] {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(writer_threads),
+            &writer_threads,
+            |bencher, &writer_threads| {
+                let options =
+                    GeoParquetWriterOptions::default().with_writer_threads(writer_threads);
+                bencher.iter(|| {
+                    let mut buf = Vec::new();
+                    write_geoparquet(&table, &mut buf, &options).unwrap();
+                    criterion::black_box(buf);
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
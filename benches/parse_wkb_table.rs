@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use arrow_schema::Schema;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use geoarrow::array::PolygonArray;
+use geoarrow::io::wkb::{to_wkb, CurvePolicy, DimensionPolicy};
+use geoarrow::table::GeoTable;
+use geoarrow::GeometryArrayTrait;
+
+const NUM_POLYGONS: usize = 1_000_000;
+
+/// A table with a single batch of WKB squares.
+fn create_table() -> GeoTable {
+    let polygons = (0..NUM_POLYGONS)
+        .map(|i| {
+            let x = (i % 1000) as f64;
+            let y = (i / 1000) as f64;
+            geo::polygon![
+                (x: x, y: y),
+                (x: x + 1., y: y),
+                (x: x + 1., y: y + 1.),
+                (x: x, y: y + 1.),
+                (x: x, y: y),
+            ]
+        })
+        .collect::<Vec<_>>();
+    let polygon_array: PolygonArray<i32> = polygons.as_slice().into();
+    let wkb_array = to_wkb::<i32>(&polygon_array);
+
+    let schema = Arc::new(Schema::new(vec![wkb_array.extension_field()]));
+    let batch = RecordBatch::try_new(schema.clone(), vec![wkb_array.into_array_ref()]).unwrap();
+    GeoTable::try_new(schema, vec![batch], 0).unwrap()
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let table = create_table();
+
+    let mut group = c.benchmark_group("parse_geometry_to_native");
+    group.sample_size(10);
+    // A single batch is parsed serially, while split batches are parsed in parallel
+    for max_rows in [NUM_POLYGONS, 65_536] {
+        let table = table.split_batches(max_rows).unwrap();
+        group.bench_with_input(
+            BenchmarkId::from_parameter(table.batches().len()),
+            &table,
+            |bencher, table| {
+                bencher.iter(|| {
+                    let parsed = table
+                        .parse_geometry_to_native(None, DimensionPolicy::Error, CurvePolicy::Error)
+                        .unwrap();
+                    criterion::black_box(parsed);
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,64 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use geo::{Contains, Polygon};
+use geoarrow::array::{PointArray, PolygonArray};
+use geoarrow::indexed::point_in_polygon::PointInPolygonIndex;
+
+/// A 20x20 grid of square zones, each with 400 vertices.
+fn create_zones() -> Vec<Polygon> {
+    let mut zones = vec![];
+    for i in 0..20 {
+        for j in 0..20 {
+            let (x0, y0) = (i as f64 * 10., j as f64 * 10.);
+            let mut exterior = vec![];
+            for k in 0..100 {
+                exterior.push((x0 + k as f64 * 0.1, y0));
+            }
+            for k in 0..100 {
+                exterior.push((x0 + 10., y0 + k as f64 * 0.1));
+            }
+            for k in 0..100 {
+                exterior.push((x0 + 10. - k as f64 * 0.1, y0 + 10.));
+            }
+            for k in 0..100 {
+                exterior.push((x0, y0 + 10. - k as f64 * 0.1));
+            }
+            zones.push(Polygon::new(exterior.into(), vec![]));
+        }
+    }
+    zones
+}
+
+fn create_points() -> Vec<geo::Point> {
+    (0..10_000)
+        .map(|i| geo::point!(x: (i * 37 % 2000) as f64 * 0.1 + 0.05, y: (i * 53 % 2000) as f64 * 0.1 + 0.05))
+        .collect()
+}
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let zones = create_zones();
+    let points = create_points();
+    let zone_array: PolygonArray<i32> = zones.as_slice().into();
+    let point_array: PointArray = points.as_slice().into();
+
+    c.bench_function("build PointInPolygonIndex", |b| {
+        b.iter(|| PointInPolygonIndex::try_new_polygons(&zone_array).unwrap())
+    });
+
+    let index = PointInPolygonIndex::try_new_polygons(&zone_array).unwrap();
+    let mut group = c.benchmark_group("classify 10k points in 400 polygons");
+    group.bench_function("PointInPolygonIndex", |b| {
+        b.iter(|| index.query_points(&point_array))
+    });
+    group.bench_function("naive contains", |b| {
+        b.iter(|| {
+            points
+                .iter()
+                .map(|point| zones.iter().position(|zone| zone.contains(point)))
+                .collect::<Vec<_>>()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
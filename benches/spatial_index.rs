@@ -0,0 +1,34 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use geoarrow::algorithm::geo_index::SpatialIndex;
+use geoarrow::array::PointArray;
+use geoarrow::GeometryArrayTrait;
+
+fn points() -> PointArray {
+    let points: Vec<geo::Point> = (0..1_000_000)
+        .map(|i| geo::point!(x: (i % 1000) as f64, y: (i / 1000) as f64))
+        .collect();
+    points.as_slice().into()
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let data = points();
+    let index = SpatialIndex::try_new(&data).unwrap();
+    let bytes = index.to_bytes();
+
+    c.bench_function("build spatial index", |bencher| {
+        bencher.iter(|| {
+            criterion::black_box(SpatialIndex::try_new(criterion::black_box(&data)).unwrap());
+        });
+    });
+
+    c.bench_function("load spatial index", |bencher| {
+        bencher.iter(|| {
+            criterion::black_box(
+                SpatialIndex::from_bytes(criterion::black_box(&bytes), data.len()).unwrap(),
+            );
+        });
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::Float64Type;
+use arrow_schema::{DataType, Field, Schema};
+use criterion::{criterion_group, criterion_main, Criterion};
+use geo::point;
+use geoarrow::array::AsChunkedGeometryArray;
+use geoarrow::table::{GeoTable, TableBuilder};
+use geoarrow::trait_::GeometryArrayAccessor;
+
+fn build_table() -> GeoTable {
+    let schema = Arc::new(Schema::new(vec![Field::new(
+        "value",
+        DataType::Float64,
+        false,
+    )]));
+    let mut builder = TableBuilder::try_new(schema)
+        .unwrap()
+        .with_batch_size(10_000);
+    for i in 0..100_000 {
+        let x = i as f64;
+        builder
+            .push_feature(point!(x: x, y: -x), vec![(x / 2.).into()])
+            .unwrap();
+    }
+    builder.finish().unwrap()
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let table = build_table();
+
+    c.bench_function("table rows", |bencher| {
+        bencher.iter(|| {
+            let mut sum = 0.;
+            for row in table.rows().unwrap() {
+                let geo::Geometry::Point(point) = row.geometry().unwrap() else {
+                    unreachable!()
+                };
+                sum += point.x() + row.get_f64("value").unwrap().unwrap();
+            }
+            criterion::black_box(sum);
+        });
+    });
+
+    c.bench_function("manual batch iteration", |bencher| {
+        bencher.iter(|| {
+            let mut sum = 0.;
+            let geometry = table.geometry().unwrap();
+            let points = geometry.as_ref().as_point().chunks();
+            for (batch, points) in table.batches().iter().zip(points) {
+                let values = batch
+                    .column_by_name("value")
+                    .unwrap()
+                    .as_primitive::<Float64Type>();
+                for (i, point) in points.iter_geo().enumerate() {
+                    sum += point.unwrap().x() + values.value(i);
+                }
+            }
+            criterion::black_box(sum);
+        });
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
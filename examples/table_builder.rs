@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use arrow_schema::{DataType, Field, Schema};
+use geo::point;
+use geoarrow::error::GeoArrowError;
+use geoarrow::table::TableBuilder;
+
+fn run() -> Result<(), GeoArrowError> {
+    // Features as (location, population, name) tuples
+    let cities = vec![
+        (
+            point!(x: 2.3522, y: 48.8566),
+            2_102_650,
+            "Paris".to_string(),
+        ),
+        (
+            point!(x: -0.1276, y: 51.5072),
+            8_866_180,
+            "London".to_string(),
+        ),
+        (point!(x: 13.405, y: 52.52), 3_677_472, "Berlin".to_string()),
+    ];
+
+    // Declare the property fields up front, then push one feature per row
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("population", DataType::Int32, false),
+        Field::new("name", DataType::Utf8, false),
+    ]));
+    let mut builder = TableBuilder::try_new(schema)?;
+    for (location, population, name) in cities {
+        builder.push_feature(location, vec![population.into(), name.into()])?;
+    }
+
+    let table = builder.finish()?;
+    println!("{:?}", table.geometry_data_type()?);
+    println!("{}", table.len());
+
+    Ok(())
+}
+
+fn main() {
+    run().unwrap()
+}
@@ -28,10 +28,8 @@ use crate::table::GeoTable;
 pub fn read_flatgeobuf(file: &[u8], batch_size: Option<usize>) -> WasmResult<GeoTable> {
     // assert_parquet_file_not_empty(parquet_file)?;
     let mut cursor = Cursor::new(file);
-    let options = FlatGeobufReaderOptions {
-        batch_size,
-        ..Default::default()
-    };
+    let mut options = FlatGeobufReaderOptions::default();
+    options.batch_size = batch_size;
     let geo_table = _read_flatgeobuf(&mut cursor, options)?;
     Ok(GeoTable(geo_table))
 }
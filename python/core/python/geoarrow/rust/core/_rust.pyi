@@ -5,6 +5,7 @@ from typing import (
     BinaryIO,
     Dict,
     List,
+    Literal,
     Optional,
     Self,
     Sequence,
@@ -16,7 +17,7 @@ from typing import (
 
 try:
     import numpy as np
-    from numpy.typing import NDArray
+    from numpy.typing import ArrayLike, NDArray
 except ImportError:
     pass
 
@@ -175,7 +176,23 @@ class PointArray:
     def signed_area(
         self, *, method: AreaMethod | AreaMethodT = AreaMethod.Euclidean
     ) -> Float64Array: ...
-    def total_bounds(self) -> Tuple[float, float, float, float]: ...
+    def x(self) -> Float64Array: ...
+    def y(self) -> Float64Array: ...
+    @overload
+def symmetric_difference(
+    input: ArrowArrayExportable,
+    other: BroadcastGeometry,
+) -> MultiPolygonArray: ...
+@overload
+def symmetric_difference(
+    input: ArrowStreamExportable,
+    other: BroadcastGeometry,
+) -> ChunkedMultiPolygonArray: ...
+def symmetric_difference(
+    input: ArrowArrayExportable | ArrowStreamExportable,
+    other: BroadcastGeometry,
+) -> MultiPolygonArray | ChunkedMultiPolygonArray: ...
+def total_bounds(self) -> Tuple[float, float, float, float]: ...
     @classmethod
     def from_arrow(cls, input: ArrowArrayExportable) -> Self: ...
     @classmethod
@@ -184,6 +201,7 @@ class PointArray:
     def from_wkb(cls, input: ArrowArrayExportable) -> Self: ...
     def to_shapely(self) -> NDArray[np.object_]: ...
     def to_wkb(self) -> WKBArray: ...
+    def coords_buffer(self) -> NDArray[np.float64]: ...
 
 class LineStringArray:
     def __eq__(self, other: Self) -> bool: ...
@@ -234,6 +252,11 @@ class LineStringArray:
     def from_wkb(cls, input: ArrowArrayExportable) -> Self: ...
     def to_shapely(self) -> NDArray[np.object_]: ...
     def to_wkb(self) -> WKBArray: ...
+    def coords_buffer(self) -> NDArray[np.float64]: ...
+    def geom_offsets(self) -> NDArray[np.int32]: ...
+    def to_deckgl_binary(
+        self, attributes: Optional[Dict[str, ArrayLike]] = None
+    ) -> dict: ...
 
 class PolygonArray:
     def __eq__(self, other: Self) -> bool: ...
@@ -275,6 +298,12 @@ class PolygonArray:
     def from_wkb(cls, input: ArrowArrayExportable) -> Self: ...
     def to_shapely(self) -> NDArray[np.object_]: ...
     def to_wkb(self) -> WKBArray: ...
+    def coords_buffer(self) -> NDArray[np.float64]: ...
+    def geom_offsets(self) -> NDArray[np.int32]: ...
+    def ring_offsets(self) -> NDArray[np.int32]: ...
+    def to_deckgl_binary(
+        self, attributes: Optional[Dict[str, ArrayLike]] = None
+    ) -> dict: ...
 
 class MultiPointArray:
     def __eq__(self, other: Self) -> bool: ...
@@ -316,6 +345,11 @@ class MultiPointArray:
     def from_wkb(cls, input: ArrowArrayExportable) -> Self: ...
     def to_shapely(self) -> NDArray[np.object_]: ...
     def to_wkb(self) -> WKBArray: ...
+    def coords_buffer(self) -> NDArray[np.float64]: ...
+    def geom_offsets(self) -> NDArray[np.int32]: ...
+    def to_deckgl_binary(
+        self, attributes: Optional[Dict[str, ArrayLike]] = None
+    ) -> dict: ...
 
 class MultiLineStringArray:
     def __eq__(self, other: Self) -> bool: ...
@@ -359,6 +393,12 @@ class MultiLineStringArray:
     def from_wkb(cls, input: ArrowArrayExportable) -> Self: ...
     def to_shapely(self) -> NDArray[np.object_]: ...
     def to_wkb(self) -> WKBArray: ...
+    def coords_buffer(self) -> NDArray[np.float64]: ...
+    def geom_offsets(self) -> NDArray[np.int32]: ...
+    def ring_offsets(self) -> NDArray[np.int32]: ...
+    def to_deckgl_binary(
+        self, attributes: Optional[Dict[str, ArrayLike]] = None
+    ) -> dict: ...
 
 class MultiPolygonArray:
     def __eq__(self, other: Self) -> bool: ...
@@ -399,6 +439,13 @@ class MultiPolygonArray:
     def from_wkb(cls, input: ArrowArrayExportable) -> Self: ...
     def to_shapely(self) -> NDArray[np.object_]: ...
     def to_wkb(self) -> WKBArray: ...
+    def coords_buffer(self) -> NDArray[np.float64]: ...
+    def geom_offsets(self) -> NDArray[np.int32]: ...
+    def polygon_offsets(self) -> NDArray[np.int32]: ...
+    def ring_offsets(self) -> NDArray[np.int32]: ...
+    def to_deckgl_binary(
+        self, attributes: Optional[Dict[str, ArrayLike]] = None
+    ) -> dict: ...
 
 class MixedGeometryArray:
     def __eq__(self, other: Self) -> bool: ...
@@ -1152,6 +1199,7 @@ class GeoTable:
     ): ...
     @property
     def num_columns(self) -> int: ...
+    def to_file(self, path: str | Path, *, driver: str | None = None) -> None: ...
     def to_geopandas(self) -> gpd.GeoDataFrame: ...
 
 # Top-level array/chunked array functions
@@ -1256,10 +1304,49 @@ def convex_hull(input: ArrowStreamExportable) -> ChunkedPolygonArray: ...
 def convex_hull(
     input: ArrowArrayExportable | ArrowStreamExportable,
 ) -> PolygonArray | ChunkedPolygonArray: ...
+@overload
+def difference(
+    input: ArrowArrayExportable,
+    other: BroadcastGeometry,
+) -> MultiPolygonArray: ...
+@overload
+def difference(
+    input: ArrowStreamExportable,
+    other: BroadcastGeometry,
+) -> ChunkedMultiPolygonArray: ...
+def difference(
+    input: ArrowArrayExportable | ArrowStreamExportable,
+    other: BroadcastGeometry,
+) -> MultiPolygonArray | ChunkedMultiPolygonArray: ...
 def densify(
     input: ArrowArrayExportable, max_distance: float
 ) -> LineStringArray | PolygonArray | MultiLineStringArray | MultiPolygonArray: ...
 @overload
+def densify_haversine(
+    input: ArrowArrayExportable, max_distance: float
+) -> LineStringArray | PolygonArray | MultiLineStringArray | MultiPolygonArray: ...
+@overload
+def densify_haversine(
+    input: ArrowStreamExportable, max_distance: float
+) -> (
+    ChunkedLineStringArray
+    | ChunkedPolygonArray
+    | ChunkedMultiLineStringArray
+    | ChunkedMultiPolygonArray
+): ...
+def densify_haversine(
+    input: ArrowArrayExportable | ArrowStreamExportable, max_distance: float
+) -> (
+    LineStringArray
+    | PolygonArray
+    | MultiLineStringArray
+    | MultiPolygonArray
+    | ChunkedLineStringArray
+    | ChunkedPolygonArray
+    | ChunkedMultiLineStringArray
+    | ChunkedMultiPolygonArray
+): ...
+@overload
 def envelope(input: ArrowArrayExportable) -> RectArray: ...
 @overload
 def envelope(input: ArrowStreamExportable) -> ChunkedRectArray: ...
@@ -1280,8 +1367,50 @@ def frechet_distance(
     input: ArrowArrayExportable | ArrowStreamExportable,
     other: BroadcastGeometry,
 ) -> Float64Array | ChunkedFloat64Array: ...
+@overload
+def geodesic_distance(
+    input: ArrowArrayExportable,
+    other: BroadcastGeometry,
+) -> Float64Array: ...
+@overload
+def geodesic_distance(
+    input: ArrowStreamExportable,
+    other: BroadcastGeometry,
+) -> ChunkedFloat64Array: ...
+def geodesic_distance(
+    input: ArrowArrayExportable | ArrowStreamExportable,
+    other: BroadcastGeometry,
+) -> Float64Array | ChunkedFloat64Array: ...
 def geodesic_perimeter(input: ArrowArrayExportable) -> Float64Array: ...
 @overload
+def haversine_distance(
+    input: ArrowArrayExportable,
+    other: BroadcastGeometry,
+) -> Float64Array: ...
+@overload
+def haversine_distance(
+    input: ArrowStreamExportable,
+    other: BroadcastGeometry,
+) -> ChunkedFloat64Array: ...
+def haversine_distance(
+    input: ArrowArrayExportable | ArrowStreamExportable,
+    other: BroadcastGeometry,
+) -> Float64Array | ChunkedFloat64Array: ...
+@overload
+def intersection(
+    input: ArrowArrayExportable,
+    other: BroadcastGeometry,
+) -> MultiPolygonArray: ...
+@overload
+def intersection(
+    input: ArrowStreamExportable,
+    other: BroadcastGeometry,
+) -> ChunkedMultiPolygonArray: ...
+def intersection(
+    input: ArrowArrayExportable | ArrowStreamExportable,
+    other: BroadcastGeometry,
+) -> MultiPolygonArray | ChunkedMultiPolygonArray: ...
+@overload
 def is_empty(input: ArrowArrayExportable) -> BooleanArray: ...
 @overload
 def is_empty(input: ArrowStreamExportable) -> ChunkedBooleanArray: ...
@@ -1421,9 +1550,27 @@ def simplify(
     | ChunkedMultiLineStringArray
     | ChunkedMultiPolygonArray
 ): ...
+def supports(
+    kernel: str,
+    input: ArrowArrayExportable | ArrowStreamExportable,
+) -> Literal["full", "returns_zero", "unsupported"]: ...
 def total_bounds(
     input: ArrowArrayExportable | ArrowStreamExportable,
 ) -> Tuple[float, float, float, float]: ...
+@overload
+def union(
+    input: ArrowArrayExportable,
+    other: BroadcastGeometry,
+) -> MultiPolygonArray: ...
+@overload
+def union(
+    input: ArrowStreamExportable,
+    other: BroadcastGeometry,
+) -> ChunkedMultiPolygonArray: ...
+def union(
+    input: ArrowArrayExportable | ArrowStreamExportable,
+    other: BroadcastGeometry,
+) -> MultiPolygonArray | ChunkedMultiPolygonArray: ...
 
 # Top-level table functions
 
@@ -1485,6 +1632,13 @@ def read_csv(
     *,
     batch_size: int = 65536,
 ) -> GeoTable: ...
+def read_file(
+    path_or_url: str | Path,
+    *,
+    bbox: Tuple[float, float, float, float] | None = None,
+    columns: Sequence[str] | None = None,
+    layer: int | str | None = None,
+) -> GeoTable: ...
 def read_flatgeobuf(
     file: Union[str, Path, BinaryIO],
     *,
@@ -1500,10 +1654,21 @@ async def read_flatgeobuf_async(
     bbox: Tuple[float, float, float, float] | None = None,
 ) -> GeoTable: ...
 def read_geojson(
-    file: Union[str, Path, BinaryIO], *, batch_size: int = 65536
+    file: Union[str, Path, BinaryIO],
+    *,
+    batch_size: int = 65536,
+    max_coords_per_geometry: Optional[int] = None,
+    max_total_coords: Optional[int] = None,
+    max_nesting_depth: Optional[int] = None,
 ) -> GeoTable: ...
 def read_geojson_lines(
-    file: Union[str, Path, BinaryIO], *, batch_size: int = 65536
+    file: Union[str, Path, BinaryIO],
+    *,
+    batch_size: int = 65536,
+    schema_infer_max_records: int = 1000,
+    max_coords_per_geometry: Optional[int] = None,
+    max_total_coords: Optional[int] = None,
+    max_nesting_depth: Optional[int] = None,
 ) -> GeoTable: ...
 def read_ipc(file: Union[str, Path, BinaryIO]) -> GeoTable: ...
 def read_ipc_stream(file: Union[str, Path, BinaryIO]) -> GeoTable: ...
@@ -1523,7 +1688,7 @@ def read_pyogrio(
     columns: Sequence[str] | None = None,
     read_geometry: bool = True,
     skip_features: int = 0,
-    max_features: int | None = None,
+    max_features: Optional[int] = None,
     where: str | None = None,
     bbox: Tuple[float, float, float, float] | Sequence[float] | None = None,
     mask=None,
@@ -1534,7 +1699,13 @@ def read_pyogrio(
     batch_size=65536,
     **kwargs,
 ) -> GeoTable: ...
-def write_csv(table: ArrowStreamExportable, file: str | Path | BinaryIO) -> None: ...
+def write_csv(
+    table: ArrowStreamExportable,
+    file: str | Path | BinaryIO,
+    *,
+    geometry_column_name: str | None = None,
+    coordinate_precision: Optional[int] = None,
+) -> None: ...
 def write_flatgeobuf(
     table: ArrowStreamExportable,
     file: str | Path | BinaryIO,
@@ -1542,7 +1713,11 @@ def write_flatgeobuf(
     write_index: bool = True,
 ) -> None: ...
 def write_geojson(
-    table: ArrowStreamExportable, file: Union[str, Path, BinaryIO]
+    table: ArrowStreamExportable,
+    file: Union[str, Path, BinaryIO],
+    *,
+    coord_precision: Optional[int] = None,
+    bbox: bool = False,
 ) -> None: ...
 def write_geojson_lines(
     table: ArrowStreamExportable, file: Union[str, Path, BinaryIO]
@@ -1558,6 +1733,7 @@ def write_parquet(
     file: str,
     *,
     encoding: GeoParquetEncoding | GeoParquetEncodingT = GeoParquetEncoding.WKB,
+    writer_threads: Optional[int] = None,
 ) -> None: ...
 
 # Interop
@@ -1587,6 +1763,10 @@ def from_shapely(
 ): ...
 def from_wkb(
     input: ArrowArrayExportable,
+    *,
+    max_coords_per_geometry: Optional[int] = None,
+    max_total_coords: Optional[int] = None,
+    max_nesting_depth: Optional[int] = None,
 ) -> (
     PointArray
     | LineStringArray
@@ -1599,6 +1779,10 @@ def from_wkb(
 ): ...
 def from_wkt(
     input: ArrowArrayExportable,
+    *,
+    max_coords_per_geometry: Optional[int] = None,
+    max_total_coords: Optional[int] = None,
+    max_nesting_depth: Optional[int] = None,
 ) -> (
     PointArray
     | LineStringArray
@@ -1612,3 +1796,9 @@ def from_wkt(
 def to_geopandas(input: ArrowStreamExportable) -> gpd.GeoDataFrame: ...
 def to_shapely(input: ArrowArrayExportable) -> NDArray[np.object_]: ...
 def to_wkb(input: ArrowArrayExportable) -> WKBArray: ...
+
+# Validation
+
+def validate(
+    input: ArrowArrayExportable | ArrowStreamExportable,
+) -> List[str]: ...
@@ -82,7 +82,7 @@ AffineTransform = Union[
     Tuple[IntFloat, ...],
 ]
 
-AreaMethodT = Literal["ellipsoidal", "euclidean", "spherical"]
+AreaMethodT = Literal["ellipsoidal", "euclidean", "geodesic", "spherical"]
 """Acceptable strings to be passed into the `method` parameter for
 [`area`][geoarrow.rust.core.area] and
 [`signed_area`][geoarrow.rust.core.signed_area].
@@ -115,7 +115,7 @@ NativeChunkedGeometryArrayT = Union[
     ChunkedGeometryCollectionArray,
 ]
 
-LengthMethodT = Literal["ellipsoidal", "euclidean", "haversine", "vincenty"]
+LengthMethodT = Literal["ellipsoidal", "euclidean", "geodesic", "haversine", "vincenty"]
 """Acceptable strings to be passed into the `method` parameter for
 [`length`][geoarrow.rust.core.length].
 """
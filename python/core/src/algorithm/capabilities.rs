@@ -0,0 +1,24 @@
+use crate::error::PyGeoArrowResult;
+use crate::ffi::from_python::AnyGeometryInput;
+use geoarrow::algorithm::capabilities::KernelId;
+use pyo3::prelude::*;
+
+/// Check whether a kernel supports the geometry type of an array, without calling the kernel.
+///
+/// Args:
+///     kernel: the name of the kernel, such as "area", "centroid" or "euclidean_length"
+///     input: input geometry array or chunked geometry array
+///
+/// Returns:
+///     "full" if the kernel computes a meaningful result, "returns_zero" if it always returns
+///     zero for this geometry type, such as the area of line strings, or "unsupported" if it
+///     raises an error.
+#[pyfunction]
+pub fn supports(kernel: &str, input: AnyGeometryInput) -> PyGeoArrowResult<&'static str> {
+    let kernel: KernelId = kernel.parse()?;
+    let data_type = match &input {
+        AnyGeometryInput::Array(arr) => arr.data_type(),
+        AnyGeometryInput::Chunked(arr) => arr.data_type(),
+    };
+    Ok(geoarrow::algorithm::capabilities::supports(kernel, data_type).name())
+}
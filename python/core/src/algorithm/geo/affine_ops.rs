@@ -45,17 +45,18 @@ impl<'a> FromPyObject<'a> for AffineTransform {
 ///     coordinates.
 #[pyfunction]
 pub fn affine_transform(
+    py: Python,
     input: AnyGeometryInput,
     transform: AffineTransform,
 ) -> PyGeoArrowResult<PyObject> {
     match input {
         AnyGeometryInput::Array(arr) => {
-            let out = arr.as_ref().affine_transform(&transform.0)?;
-            Python::with_gil(|py| geometry_array_to_pyobject(py, out))
+            let out = py.allow_threads(|| arr.as_ref().affine_transform(&transform.0))?;
+            geometry_array_to_pyobject(py, out)
         }
         AnyGeometryInput::Chunked(arr) => {
-            let out = arr.as_ref().affine_transform(&transform.0)?;
-            Python::with_gil(|py| chunked_geometry_array_to_pyobject(py, out))
+            let out = py.allow_threads(|| arr.as_ref().affine_transform(&transform.0))?;
+            chunked_geometry_array_to_pyobject(py, out)
         }
     }
 }
@@ -79,8 +80,9 @@ macro_rules! impl_array {
             /// Returns:
             ///     New GeoArrow array or chunked array with the same type as input and with
             ///     transformed coordinates.
-            pub fn affine_transform(&self, transform: AffineTransform) -> Self {
-                self.0.affine_transform(&transform.0).into()
+            pub fn affine_transform(&self, py: Python, transform: AffineTransform) -> Self {
+                py.allow_threads(|| self.0.affine_transform(&transform.0))
+                    .into()
             }
         }
     };
@@ -114,8 +116,9 @@ macro_rules! impl_chunked {
             /// Returns:
             ///     New GeoArrow array or chunked array with the same type as input and with
             ///     transformed coordinates.
-            pub fn affine_transform(&self, transform: AffineTransform) -> Self {
-                self.0.affine_transform(&transform.0).into()
+            pub fn affine_transform(&self, py: Python, transform: AffineTransform) -> Self {
+                py.allow_threads(|| self.0.affine_transform(&transform.0))
+                    .into()
             }
         }
     };
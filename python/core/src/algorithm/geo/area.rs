@@ -16,7 +16,7 @@ impl<'a> FromPyObject<'a> for AreaMethod {
     fn extract(ob: &'a PyAny) -> PyResult<Self> {
         let s: String = ob.extract()?;
         match s.to_lowercase().as_str() {
-            "ellipsoidal" => Ok(Self::Geodesic),
+            "ellipsoidal" | "geodesic" => Ok(Self::Geodesic),
             "euclidean" => Ok(Self::Euclidean),
             "spherical" => Ok(Self::ChamberlainDuquette),
             _ => Err(PyValueError::new_err("Unexpected area method")),
@@ -30,8 +30,8 @@ impl<'a> FromPyObject<'a> for AreaMethod {
 ///     input: input geometry array or chunked geometry array
 ///
 /// Other args:
-///      method: The method to use for area calculation. One of "Ellipsoidal", "Euclidean", or
-///         "Spherical". Refer to the documentation on
+///      method: The method to use for area calculation. One of "Euclidean", "Ellipsoidal"
+///         (also accepted as "Geodesic"), or "Spherical". Refer to the documentation on
 ///         [AreaMethod][geoarrow.rust.core.enums.AreaMethod] for more information.
 ///
 /// Returns:
@@ -41,27 +41,27 @@ impl<'a> FromPyObject<'a> for AreaMethod {
     signature = (input, *, method = AreaMethod::Euclidean),
     text_signature = "(input, *, method = 'euclidean')")
 ]
-pub fn area(input: AnyGeometryInput, method: AreaMethod) -> PyGeoArrowResult<PyObject> {
+pub fn area(py: Python, input: AnyGeometryInput, method: AreaMethod) -> PyGeoArrowResult<PyObject> {
     match input {
         AnyGeometryInput::Array(arr) => {
-            let out = match method {
+            let out = py.allow_threads(|| match method {
                 AreaMethod::ChamberlainDuquette => {
-                    arr.as_ref().chamberlain_duquette_unsigned_area()?
+                    arr.as_ref().chamberlain_duquette_unsigned_area()
                 }
-                AreaMethod::Euclidean => arr.as_ref().unsigned_area()?,
-                AreaMethod::Geodesic => arr.as_ref().geodesic_area_unsigned()?,
-            };
-            Python::with_gil(|py| Ok(Float64Array::from(out).into_py(py)))
+                AreaMethod::Euclidean => arr.as_ref().unsigned_area(),
+                AreaMethod::Geodesic => arr.as_ref().geodesic_area_unsigned(),
+            })?;
+            Ok(Float64Array::from(out).into_py(py))
         }
         AnyGeometryInput::Chunked(arr) => {
-            let out = match method {
+            let out = py.allow_threads(|| match method {
                 AreaMethod::ChamberlainDuquette => {
-                    arr.as_ref().chamberlain_duquette_unsigned_area()?
+                    arr.as_ref().chamberlain_duquette_unsigned_area()
                 }
-                AreaMethod::Euclidean => arr.as_ref().unsigned_area()?,
-                AreaMethod::Geodesic => arr.as_ref().geodesic_area_unsigned()?,
-            };
-            Python::with_gil(|py| Ok(ChunkedFloat64Array::from(out).into_py(py)))
+                AreaMethod::Euclidean => arr.as_ref().unsigned_area(),
+                AreaMethod::Geodesic => arr.as_ref().geodesic_area_unsigned(),
+            })?;
+            Ok(ChunkedFloat64Array::from(out).into_py(py))
         }
     }
 }
@@ -72,8 +72,8 @@ pub fn area(input: AnyGeometryInput, method: AreaMethod) -> PyGeoArrowResult<PyO
 ///     input: input geometry array or chunked geometry array
 ///
 /// Other args:
-///      method: The method to use for area calculation. One of "Ellipsoidal", "Euclidean", or
-///         "Spherical". Refer to the documentation on
+///      method: The method to use for area calculation. One of "Euclidean", "Ellipsoidal"
+///         (also accepted as "Geodesic"), or "Spherical". Refer to the documentation on
 ///         [AreaMethod][geoarrow.rust.core.enums.AreaMethod] for more information.
 ///
 /// Returns:
@@ -83,27 +83,27 @@ pub fn area(input: AnyGeometryInput, method: AreaMethod) -> PyGeoArrowResult<PyO
     signature = (input, *, method = AreaMethod::Euclidean),
     text_signature = "(input, *, method = 'euclidean')")
 ]
-pub fn signed_area(input: AnyGeometryInput, method: AreaMethod) -> PyGeoArrowResult<PyObject> {
+pub fn signed_area(
+    py: Python,
+    input: AnyGeometryInput,
+    method: AreaMethod,
+) -> PyGeoArrowResult<PyObject> {
     match input {
         AnyGeometryInput::Array(arr) => {
-            let out = match method {
-                AreaMethod::ChamberlainDuquette => {
-                    arr.as_ref().chamberlain_duquette_signed_area()?
-                }
-                AreaMethod::Euclidean => arr.as_ref().signed_area()?,
-                AreaMethod::Geodesic => arr.as_ref().geodesic_area_signed()?,
-            };
-            Python::with_gil(|py| Ok(Float64Array::from(out).into_py(py)))
+            let out = py.allow_threads(|| match method {
+                AreaMethod::ChamberlainDuquette => arr.as_ref().chamberlain_duquette_signed_area(),
+                AreaMethod::Euclidean => arr.as_ref().signed_area(),
+                AreaMethod::Geodesic => arr.as_ref().geodesic_area_signed(),
+            })?;
+            Ok(Float64Array::from(out).into_py(py))
         }
         AnyGeometryInput::Chunked(arr) => {
-            let out = match method {
-                AreaMethod::ChamberlainDuquette => {
-                    arr.as_ref().chamberlain_duquette_signed_area()?
-                }
-                AreaMethod::Euclidean => arr.as_ref().signed_area()?,
-                AreaMethod::Geodesic => arr.as_ref().geodesic_area_signed()?,
-            };
-            Python::with_gil(|py| Ok(ChunkedFloat64Array::from(out).into_py(py)))
+            let out = py.allow_threads(|| match method {
+                AreaMethod::ChamberlainDuquette => arr.as_ref().chamberlain_duquette_signed_area(),
+                AreaMethod::Euclidean => arr.as_ref().signed_area(),
+                AreaMethod::Geodesic => arr.as_ref().geodesic_area_signed(),
+            })?;
+            Ok(ChunkedFloat64Array::from(out).into_py(py))
         }
     }
 }
@@ -115,41 +115,41 @@ macro_rules! impl_area {
             /// Unsigned area of a geometry array
             ///
             /// Args:
-            ///     method: The method to use for area calculation. One of "Ellipsoidal",
-            ///         "Euclidean", or "Spherical". Refer to the documentation on
-            ///         [AreaMethod][geoarrow.rust.core.enums.AreaMethod] for more information.
+            ///     method: The method to use for area calculation. One of "Euclidean",
+            ///         "Ellipsoidal" (also accepted as "Geodesic"), or "Spherical". Refer to the
+            ///         documentation on [AreaMethod][geoarrow.rust.core.enums.AreaMethod] for more
+            ///         information.
             ///
             /// Returns:
             ///     Array with area values.
             #[pyo3(signature = (*, method = AreaMethod::Euclidean), text_signature = "(*, method = 'euclidean')")]
-            pub fn area(&self, method: AreaMethod) -> Float64Array {
-                match method {
-                    AreaMethod::ChamberlainDuquette => {
-                        self.0.chamberlain_duquette_unsigned_area().into()
-                    }
-                    AreaMethod::Euclidean => self.0.unsigned_area().into(),
-                    AreaMethod::Geodesic => self.0.geodesic_area_unsigned().into(),
-                }
+            pub fn area(&self, py: Python, method: AreaMethod) -> Float64Array {
+                py.allow_threads(|| match method {
+                    AreaMethod::ChamberlainDuquette => self.0.chamberlain_duquette_unsigned_area(),
+                    AreaMethod::Euclidean => self.0.unsigned_area(),
+                    AreaMethod::Geodesic => self.0.geodesic_area_unsigned(),
+                })
+                .into()
             }
 
             /// Signed area of a geometry array
             ///
             /// Args:
-            ///     method: The method to use for area calculation. One of "Ellipsoidal",
-            ///         "Euclidean", or "Spherical". Refer to the documentation on
-            ///         [AreaMethod][geoarrow.rust.core.enums.AreaMethod] for more information.
+            ///     method: The method to use for area calculation. One of "Euclidean",
+            ///         "Ellipsoidal" (also accepted as "Geodesic"), or "Spherical". Refer to the
+            ///         documentation on [AreaMethod][geoarrow.rust.core.enums.AreaMethod] for more
+            ///         information.
             ///
             /// Returns:
             ///     Array with area values.
             #[pyo3(signature = (*, method = AreaMethod::Euclidean), text_signature = "(*, method = 'euclidean')")]
-            pub fn signed_area(&self, method: AreaMethod) -> Float64Array {
-                match method {
-                    AreaMethod::ChamberlainDuquette => {
-                        self.0.chamberlain_duquette_signed_area().into()
-                    }
-                    AreaMethod::Euclidean => self.0.signed_area().into(),
-                    AreaMethod::Geodesic => self.0.geodesic_area_signed().into(),
-                }
+            pub fn signed_area(&self, py: Python, method: AreaMethod) -> Float64Array {
+                py.allow_threads(|| match method {
+                    AreaMethod::ChamberlainDuquette => self.0.chamberlain_duquette_signed_area(),
+                    AreaMethod::Euclidean => self.0.signed_area(),
+                    AreaMethod::Geodesic => self.0.geodesic_area_signed(),
+                })
+                .into()
             }
         }
     };
@@ -171,41 +171,49 @@ macro_rules! impl_chunked {
             /// Unsigned area of a geometry array
             ///
             /// Args:
-            ///     method: The method to use for area calculation. One of "Ellipsoidal",
-            ///         "Euclidean", or "Spherical". Refer to the documentation on
-            ///         [AreaMethod][geoarrow.rust.core.enums.AreaMethod] for more information.
+            ///     method: The method to use for area calculation. One of "Euclidean",
+            ///         "Ellipsoidal" (also accepted as "Geodesic"), or "Spherical". Refer to the
+            ///         documentation on [AreaMethod][geoarrow.rust.core.enums.AreaMethod] for more
+            ///         information.
             ///
             /// Returns:
             ///     Chunked array with area values.
             #[pyo3(signature = (*, method = AreaMethod::Euclidean), text_signature = "(*, method = 'euclidean')")]
-            pub fn area(&self, method: AreaMethod) -> PyGeoArrowResult<ChunkedFloat64Array> {
-                match method {
-                    AreaMethod::ChamberlainDuquette => {
-                        Ok(self.0.chamberlain_duquette_unsigned_area()?.into())
-                    }
-                    AreaMethod::Euclidean => Ok(self.0.unsigned_area()?.into()),
-                    AreaMethod::Geodesic => Ok(self.0.geodesic_area_unsigned()?.into()),
-                }
+            pub fn area(
+                &self,
+                py: Python,
+                method: AreaMethod,
+            ) -> PyGeoArrowResult<ChunkedFloat64Array> {
+                let out = py.allow_threads(|| match method {
+                    AreaMethod::ChamberlainDuquette => self.0.chamberlain_duquette_unsigned_area(),
+                    AreaMethod::Euclidean => self.0.unsigned_area(),
+                    AreaMethod::Geodesic => self.0.geodesic_area_unsigned(),
+                })?;
+                Ok(out.into())
             }
 
             /// Signed area of a geometry array
             ///
             /// Args:
-            ///     method: The method to use for area calculation. One of "Ellipsoidal",
-            ///         "Euclidean", or "Spherical". Refer to the documentation on
-            ///         [AreaMethod][geoarrow.rust.core.enums.AreaMethod] for more information.
+            ///     method: The method to use for area calculation. One of "Euclidean",
+            ///         "Ellipsoidal" (also accepted as "Geodesic"), or "Spherical". Refer to the
+            ///         documentation on [AreaMethod][geoarrow.rust.core.enums.AreaMethod] for more
+            ///         information.
             ///
             /// Returns:
             ///     Chunked array with area values.
             #[pyo3(signature = (*, method = AreaMethod::Euclidean), text_signature = "(*, method = 'euclidean')")]
-            pub fn signed_area(&self, method: AreaMethod) -> PyGeoArrowResult<ChunkedFloat64Array> {
-                match method {
-                    AreaMethod::ChamberlainDuquette => {
-                        Ok(self.0.chamberlain_duquette_signed_area()?.into())
-                    }
-                    AreaMethod::Euclidean => Ok(self.0.signed_area()?.into()),
-                    AreaMethod::Geodesic => Ok(self.0.geodesic_area_signed()?.into()),
-                }
+            pub fn signed_area(
+                &self,
+                py: Python,
+                method: AreaMethod,
+            ) -> PyGeoArrowResult<ChunkedFloat64Array> {
+                let out = py.allow_threads(|| match method {
+                    AreaMethod::ChamberlainDuquette => self.0.chamberlain_duquette_signed_area(),
+                    AreaMethod::Euclidean => self.0.signed_area(),
+                    AreaMethod::Geodesic => self.0.geodesic_area_signed(),
+                })?;
+                Ok(out.into())
             }
         }
     };
@@ -0,0 +1,164 @@
+use crate::error::PyGeoArrowResult;
+use crate::ffi::from_python::input::AnyGeometryBroadcastInput;
+use crate::ffi::from_python::AnyGeometryInput;
+use crate::ffi::to_python::{chunked_geometry_array_to_pyobject, geometry_array_to_pyobject};
+use geoarrow::algorithm::geo::{BooleanOps, BooleanOpsScalar};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+#[derive(Clone, Copy)]
+enum Operation {
+    Intersection,
+    Union,
+    Difference,
+    SymmetricDifference,
+}
+
+fn boolean_op(
+    py: Python,
+    input: AnyGeometryInput,
+    other: AnyGeometryBroadcastInput,
+    op: Operation,
+) -> PyGeoArrowResult<PyObject> {
+    match (input, other) {
+        (AnyGeometryInput::Array(left), AnyGeometryBroadcastInput::Array(right)) => {
+            let (left, right) = (left.as_ref(), right.as_ref());
+            let out = py.allow_threads(|| match op {
+                Operation::Intersection => BooleanOps::intersection(&left, &right),
+                Operation::Union => BooleanOps::union(&left, &right),
+                Operation::Difference => BooleanOps::difference(&left, &right),
+                Operation::SymmetricDifference => BooleanOps::sym_difference(&left, &right),
+            })?;
+            geometry_array_to_pyobject(py, out)
+        }
+        (AnyGeometryInput::Chunked(left), AnyGeometryBroadcastInput::Chunked(right)) => {
+            let (left, right) = (left.as_ref(), right.as_ref());
+            let out = py.allow_threads(|| match op {
+                Operation::Intersection => BooleanOps::intersection(&left, &right),
+                Operation::Union => BooleanOps::union(&left, &right),
+                Operation::Difference => BooleanOps::difference(&left, &right),
+                Operation::SymmetricDifference => BooleanOps::sym_difference(&left, &right),
+            })?;
+            chunked_geometry_array_to_pyobject(py, out)
+        }
+        (AnyGeometryInput::Array(left), AnyGeometryBroadcastInput::Scalar(right)) => {
+            let (left, right) = (left.as_ref(), &right.0);
+            let out = py.allow_threads(|| match op {
+                Operation::Intersection => BooleanOpsScalar::intersection(&left, right),
+                Operation::Union => BooleanOpsScalar::union(&left, right),
+                Operation::Difference => BooleanOpsScalar::difference(&left, right),
+                Operation::SymmetricDifference => BooleanOpsScalar::sym_difference(&left, right),
+            })?;
+            geometry_array_to_pyobject(py, out)
+        }
+        (AnyGeometryInput::Chunked(left), AnyGeometryBroadcastInput::Scalar(right)) => {
+            let (left, right) = (left.as_ref(), &right.0);
+            let out = py.allow_threads(|| match op {
+                Operation::Intersection => BooleanOpsScalar::intersection(&left, right),
+                Operation::Union => BooleanOpsScalar::union(&left, right),
+                Operation::Difference => BooleanOpsScalar::difference(&left, right),
+                Operation::SymmetricDifference => BooleanOpsScalar::sym_difference(&left, right),
+            })?;
+            chunked_geometry_array_to_pyobject(py, out)
+        }
+        _ => Err(PyValueError::new_err("Unsupported input types.").into()),
+    }
+}
+
+/// Compute the intersection of the polygons of each row of two arrays, or of an array and a
+/// single polygon.
+///
+/// Both inputs must contain `Polygon` or `MultiPolygon` geometries. The result is null where
+/// either geometry is null, and an empty `MultiPolygon` where the polygons don't intersect.
+///
+/// When `other` is a scalar, rows whose bounding boxes don't intersect the bounding box of
+/// `other` are skipped, so clipping a large array to a small area is fast.
+///
+/// Args:
+///     input: input geometry array or chunked geometry array
+///     other: the geometry or geometry array to intersect with. A variety of inputs are accepted:
+///
+///         - A scalar [`Polygon`][geoarrow.rust.core.Polygon] or
+///           [`MultiPolygon`][geoarrow.rust.core.MultiPolygon]
+///         - A [`PolygonArray`][geoarrow.rust.core.PolygonArray] or
+///           [`MultiPolygonArray`][geoarrow.rust.core.MultiPolygonArray] of the same length as
+///           `input`
+///         - A chunked array with the same chunk lengths as `input`, when `input` is chunked
+///         - Any Python class that implements the Geo Interface, such as a [`shapely`
+///           Polygon][shapely.Polygon]
+///
+/// Returns:
+///     MultiPolygon array or chunked MultiPolygon array.
+#[pyfunction]
+pub fn intersection(
+    py: Python,
+    input: AnyGeometryInput,
+    other: AnyGeometryBroadcastInput,
+) -> PyGeoArrowResult<PyObject> {
+    boolean_op(py, input, other, Operation::Intersection)
+}
+
+/// Compute the union of the polygons of each row of two arrays, or of an array and a single
+/// polygon.
+///
+/// Both inputs must contain `Polygon` or `MultiPolygon` geometries. The result is null where
+/// either geometry is null.
+///
+/// Args:
+///     input: input geometry array or chunked geometry array
+///     other: the geometry or geometry array to union with, as in
+///         [`intersection`][geoarrow.rust.core.intersection].
+///
+/// Returns:
+///     MultiPolygon array or chunked MultiPolygon array.
+#[pyfunction]
+pub fn union(
+    py: Python,
+    input: AnyGeometryInput,
+    other: AnyGeometryBroadcastInput,
+) -> PyGeoArrowResult<PyObject> {
+    boolean_op(py, input, other, Operation::Union)
+}
+
+/// Compute the part of the polygons of each row of `input` that is not covered by `other`.
+///
+/// Both inputs must contain `Polygon` or `MultiPolygon` geometries. The result is null where
+/// either geometry is null, and an empty `MultiPolygon` where `other` covers `input`.
+///
+/// Args:
+///     input: input geometry array or chunked geometry array
+///     other: the geometry or geometry array to subtract, as in
+///         [`intersection`][geoarrow.rust.core.intersection].
+///
+/// Returns:
+///     MultiPolygon array or chunked MultiPolygon array.
+#[pyfunction]
+pub fn difference(
+    py: Python,
+    input: AnyGeometryInput,
+    other: AnyGeometryBroadcastInput,
+) -> PyGeoArrowResult<PyObject> {
+    boolean_op(py, input, other, Operation::Difference)
+}
+
+/// Compute the parts of the polygons of each row that are covered by exactly one of `input` and
+/// `other`.
+///
+/// Both inputs must contain `Polygon` or `MultiPolygon` geometries. The result is null where
+/// either geometry is null, and an empty `MultiPolygon` where the polygons are equal.
+///
+/// Args:
+///     input: input geometry array or chunked geometry array
+///     other: the geometry or geometry array to compare against, as in
+///         [`intersection`][geoarrow.rust.core.intersection].
+///
+/// Returns:
+///     MultiPolygon array or chunked MultiPolygon array.
+#[pyfunction]
+pub fn symmetric_difference(
+    py: Python,
+    input: AnyGeometryInput,
+    other: AnyGeometryBroadcastInput,
+) -> PyGeoArrowResult<PyObject> {
+    boolean_op(py, input, other, Operation::SymmetricDifference)
+}
@@ -16,15 +16,15 @@ use pyo3::prelude::*;
 /// Returns:
 ///     Array or chunked array with center values.
 #[pyfunction]
-pub fn center(input: AnyGeometryInput) -> PyGeoArrowResult<PyObject> {
+pub fn center(py: Python, input: AnyGeometryInput) -> PyGeoArrowResult<PyObject> {
     match input {
         AnyGeometryInput::Array(arr) => {
-            let out = PointArray::from(arr.as_ref().center()?);
-            Python::with_gil(|py| Ok(out.into_py(py)))
+            let out = PointArray::from(py.allow_threads(|| arr.as_ref().center())?);
+            Ok(out.into_py(py))
         }
         AnyGeometryInput::Chunked(arr) => {
-            let out = ChunkedPointArray::from(arr.as_ref().center()?);
-            Python::with_gil(|py| Ok(out.into_py(py)))
+            let out = ChunkedPointArray::from(py.allow_threads(|| arr.as_ref().center())?);
+            Ok(out.into_py(py))
         }
     }
 }
@@ -40,9 +40,9 @@ macro_rules! impl_center {
             ///
             /// Returns:
             ///     Array with center values.
-            pub fn center(&self) -> PointArray {
+            pub fn center(&self, py: Python) -> PointArray {
                 use geoarrow::algorithm::geo::Center;
-                PointArray(Center::center(&self.0))
+                PointArray(py.allow_threads(|| Center::center(&self.0)))
             }
         }
     };
@@ -68,9 +68,11 @@ macro_rules! impl_chunked {
             ///
             /// Returns:
             ///     Array with center values.
-            pub fn center(&self) -> PyGeoArrowResult<ChunkedPointArray> {
+            pub fn center(&self, py: Python) -> PyGeoArrowResult<ChunkedPointArray> {
                 use geoarrow::algorithm::geo::Center;
-                Ok(ChunkedPointArray(Center::center(&self.0)?))
+                Ok(ChunkedPointArray(
+                    py.allow_threads(|| Center::center(&self.0))?,
+                ))
             }
         }
     };
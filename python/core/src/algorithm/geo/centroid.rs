@@ -20,15 +20,15 @@ use pyo3::prelude::*;
 /// Returns:
 ///     Array or chunked array with centroid values.
 #[pyfunction]
-pub fn centroid(input: AnyGeometryInput) -> PyGeoArrowResult<PyObject> {
+pub fn centroid(py: Python, input: AnyGeometryInput) -> PyGeoArrowResult<PyObject> {
     match input {
         AnyGeometryInput::Array(arr) => {
-            let out = PointArray::from(arr.as_ref().centroid()?);
-            Python::with_gil(|py| Ok(out.into_py(py)))
+            let out = PointArray::from(py.allow_threads(|| arr.as_ref().centroid())?);
+            Ok(out.into_py(py))
         }
         AnyGeometryInput::Chunked(arr) => {
-            let out = ChunkedPointArray::from(arr.as_ref().centroid()?);
-            Python::with_gil(|py| Ok(out.into_py(py)))
+            let out = ChunkedPointArray::from(py.allow_threads(|| arr.as_ref().centroid())?);
+            Ok(out.into_py(py))
         }
     }
 }
@@ -48,9 +48,9 @@ macro_rules! impl_centroid {
             ///
             /// Returns:
             ///     Array with centroid values.
-            pub fn centroid(&self) -> PointArray {
+            pub fn centroid(&self, py: Python) -> PointArray {
                 use geoarrow::algorithm::geo::Centroid;
-                PointArray(Centroid::centroid(&self.0))
+                PointArray(py.allow_threads(|| Centroid::centroid(&self.0)))
             }
         }
     };
@@ -80,9 +80,11 @@ macro_rules! impl_chunked {
             ///
             /// Returns:
             ///     Array with centroid values.
-            pub fn centroid(&self) -> PyGeoArrowResult<ChunkedPointArray> {
+            pub fn centroid(&self, py: Python) -> PyGeoArrowResult<ChunkedPointArray> {
                 use geoarrow::algorithm::geo::Centroid;
-                Ok(ChunkedPointArray(Centroid::centroid(&self.0)?))
+                Ok(ChunkedPointArray(
+                    py.allow_threads(|| Centroid::centroid(&self.0))?,
+                ))
             }
         }
     };
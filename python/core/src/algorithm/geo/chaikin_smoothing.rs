@@ -24,15 +24,19 @@ use pyo3::prelude::*;
 /// Returns:
 ///     Smoothed geometry array or chunked geometry array.
 #[pyfunction]
-pub fn chaikin_smoothing(input: AnyGeometryInput, n_iterations: u32) -> PyGeoArrowResult<PyObject> {
+pub fn chaikin_smoothing(
+    py: Python,
+    input: AnyGeometryInput,
+    n_iterations: u32,
+) -> PyGeoArrowResult<PyObject> {
     match input {
         AnyGeometryInput::Array(arr) => {
-            let out = arr.as_ref().chaikin_smoothing(n_iterations)?;
-            Python::with_gil(|py| geometry_array_to_pyobject(py, out))
+            let out = py.allow_threads(|| arr.as_ref().chaikin_smoothing(n_iterations))?;
+            geometry_array_to_pyobject(py, out)
         }
         AnyGeometryInput::Chunked(arr) => {
-            let out = arr.as_ref().chaikin_smoothing(n_iterations)?;
-            Python::with_gil(|py| chunked_geometry_array_to_pyobject(py, out))
+            let out = py.allow_threads(|| arr.as_ref().chaikin_smoothing(n_iterations))?;
+            chunked_geometry_array_to_pyobject(py, out)
         }
     }
 }
@@ -57,8 +61,9 @@ macro_rules! impl_chaikin_smoothing {
             ///
             /// Returns:
             ///     Smoothed geometry array.
-            pub fn chaikin_smoothing(&self, n_iterations: u32) -> Self {
-                ChaikinSmoothing::chaikin_smoothing(&self.0, n_iterations).into()
+            pub fn chaikin_smoothing(&self, py: Python, n_iterations: u32) -> Self {
+                py.allow_threads(|| ChaikinSmoothing::chaikin_smoothing(&self.0, n_iterations))
+                    .into()
             }
         }
     };
@@ -89,8 +94,9 @@ macro_rules! impl_chunked {
             ///
             /// Returns:
             ///     Smoothed geometry array.
-            pub fn chaikin_smoothing(&self, n_iterations: u32) -> Self {
-                ChaikinSmoothing::chaikin_smoothing(&self.0, n_iterations).into()
+            pub fn chaikin_smoothing(&self, py: Python, n_iterations: u32) -> Self {
+                py.allow_threads(|| ChaikinSmoothing::chaikin_smoothing(&self.0, n_iterations))
+                    .into()
             }
         }
     };
@@ -19,15 +19,15 @@ use pyo3::prelude::*;
 /// Returns:
 ///     Array with convex hull polygons.
 #[pyfunction]
-pub fn convex_hull(input: AnyGeometryInput) -> PyGeoArrowResult<PyObject> {
+pub fn convex_hull(py: Python, input: AnyGeometryInput) -> PyGeoArrowResult<PyObject> {
     match input {
         AnyGeometryInput::Array(arr) => {
-            let out = PolygonArray::from(arr.as_ref().convex_hull()?);
-            Python::with_gil(|py| Ok(out.into_py(py)))
+            let out = PolygonArray::from(py.allow_threads(|| arr.as_ref().convex_hull())?);
+            Ok(out.into_py(py))
         }
         AnyGeometryInput::Chunked(arr) => {
-            let out = ChunkedPolygonArray::from(arr.as_ref().convex_hull()?);
-            Python::with_gil(|py| Ok(out.into_py(py)))
+            let out = ChunkedPolygonArray::from(py.allow_threads(|| arr.as_ref().convex_hull())?);
+            Ok(out.into_py(py))
         }
     }
 }
@@ -46,9 +46,9 @@ macro_rules! impl_alg {
             ///
             /// Returns:
             ///     Array with convex hull polygons.
-            pub fn convex_hull(&self) -> PolygonArray {
+            pub fn convex_hull(&self, py: Python) -> PolygonArray {
                 use geoarrow::algorithm::geo::ConvexHull;
-                PolygonArray(ConvexHull::convex_hull(&self.0))
+                PolygonArray(py.allow_threads(|| ConvexHull::convex_hull(&self.0)))
             }
         }
     };
@@ -77,9 +77,11 @@ macro_rules! impl_chunked {
             ///
             /// Returns:
             ///     Array with convex hull polygons.
-            pub fn convex_hull(&self) -> PyGeoArrowResult<ChunkedPolygonArray> {
+            pub fn convex_hull(&self, py: Python) -> PyGeoArrowResult<ChunkedPolygonArray> {
                 use geoarrow::algorithm::geo::ConvexHull;
-                Ok(ChunkedPolygonArray(ConvexHull::convex_hull(&self.0)?))
+                Ok(ChunkedPolygonArray(
+                    py.allow_threads(|| ConvexHull::convex_hull(&self.0))?,
+                ))
             }
         }
     };
@@ -3,7 +3,7 @@ use crate::chunked_array::*;
 use crate::error::PyGeoArrowResult;
 use crate::ffi::from_python::AnyGeometryInput;
 use crate::ffi::to_python::{chunked_geometry_array_to_pyobject, geometry_array_to_pyobject};
-use geoarrow::algorithm::geo::Densify;
+use geoarrow::algorithm::geo::{Densify, DensifyHaversine};
 use pyo3::prelude::*;
 
 /// Return a new linear geometry containing both existing and new interpolated
@@ -18,15 +18,51 @@ use pyo3::prelude::*;
 /// Returns:
 ///     Densified geometry array
 #[pyfunction]
-pub fn densify(input: AnyGeometryInput, max_distance: f64) -> PyGeoArrowResult<PyObject> {
+pub fn densify(
+    py: Python,
+    input: AnyGeometryInput,
+    max_distance: f64,
+) -> PyGeoArrowResult<PyObject> {
     match input {
         AnyGeometryInput::Array(arr) => {
-            let out = arr.as_ref().densify(max_distance)?;
-            Python::with_gil(|py| geometry_array_to_pyobject(py, out))
+            let out = py.allow_threads(|| arr.as_ref().densify(max_distance))?;
+            geometry_array_to_pyobject(py, out)
         }
         AnyGeometryInput::Chunked(arr) => {
-            let out = arr.as_ref().densify(max_distance)?;
-            Python::with_gil(|py| chunked_geometry_array_to_pyobject(py, out))
+            let out = py.allow_threads(|| arr.as_ref().densify(max_distance))?;
+            chunked_geometry_array_to_pyobject(py, out)
+        }
+    }
+}
+
+/// Return a new linear geometry containing both existing and new interpolated
+/// coordinates with a maximum haversine distance of `max_distance` meters between them.
+///
+/// New coordinates are placed along great circles, so this is meant for longitude/latitude
+/// coordinates. Densifying before reprojecting keeps long segments accurate.
+///
+/// Note: `max_distance` must be greater than 0.
+///
+/// Args:
+///     input: input geometry array, with longitude/latitude coordinates
+///     max_distance: maximum distance between coordinates, in meters
+///
+/// Returns:
+///     Densified geometry array
+#[pyfunction]
+pub fn densify_haversine(
+    py: Python,
+    input: AnyGeometryInput,
+    max_distance: f64,
+) -> PyGeoArrowResult<PyObject> {
+    match input {
+        AnyGeometryInput::Array(arr) => {
+            let out = py.allow_threads(|| arr.as_ref().densify_haversine(max_distance))?;
+            geometry_array_to_pyobject(py, out)
+        }
+        AnyGeometryInput::Chunked(arr) => {
+            let out = py.allow_threads(|| arr.as_ref().densify_haversine(max_distance))?;
+            chunked_geometry_array_to_pyobject(py, out)
         }
     }
 }
@@ -45,8 +81,9 @@ macro_rules! impl_densify {
             ///
             /// Returns:
             ///     Densified geometry array
-            pub fn densify(&self, max_distance: f64) -> Self {
-                Densify::densify(&self.0, max_distance).into()
+            pub fn densify(&self, py: Python, max_distance: f64) -> Self {
+                py.allow_threads(|| Densify::densify(&self.0, max_distance))
+                    .into()
             }
         }
     };
@@ -71,8 +108,9 @@ macro_rules! impl_vector {
             ///
             /// Returns:
             ///     Densified geometry array
-            pub fn densify(&self, max_distance: f64) -> Self {
-                Densify::densify(&self.0, max_distance).into()
+            pub fn densify(&self, py: Python, max_distance: f64) -> Self {
+                py.allow_threads(|| Densify::densify(&self.0, max_distance))
+                    .into()
             }
         }
     };
@@ -13,15 +13,18 @@ use pyo3::prelude::*;
 /// Returns:
 ///     Result array.
 #[pyfunction]
-pub fn is_empty(input: AnyGeometryInput) -> PyGeoArrowResult<PyObject> {
+pub fn is_empty(py: Python, input: AnyGeometryInput) -> PyGeoArrowResult<PyObject> {
     match input {
         AnyGeometryInput::Array(arr) => {
-            let out = BooleanArray::from(HasDimensions::is_empty(&arr.as_ref())?);
-            Python::with_gil(|py| Ok(out.into_py(py)))
+            let out =
+                BooleanArray::from(py.allow_threads(|| HasDimensions::is_empty(&arr.as_ref()))?);
+            Ok(out.into_py(py))
         }
         AnyGeometryInput::Chunked(arr) => {
-            let out = ChunkedBooleanArray::from(HasDimensions::is_empty(&arr.as_ref())?);
-            Python::with_gil(|py| Ok(out.into_py(py)))
+            let out = ChunkedBooleanArray::from(
+                py.allow_threads(|| HasDimensions::is_empty(&arr.as_ref()))?,
+            );
+            Ok(out.into_py(py))
         }
     }
 }
@@ -34,8 +37,8 @@ macro_rules! impl_alg {
             ///
             /// Returns:
             ///     Result array.
-            pub fn is_empty(&self) -> BooleanArray {
-                HasDimensions::is_empty(&self.0).into()
+            pub fn is_empty(&self, py: Python) -> BooleanArray {
+                py.allow_threads(|| HasDimensions::is_empty(&self.0)).into()
             }
         }
     };
@@ -58,8 +61,10 @@ macro_rules! impl_chunked {
             ///
             /// Returns:
             ///     Result array.
-            pub fn is_empty(&self) -> PyGeoArrowResult<ChunkedBooleanArray> {
-                Ok(HasDimensions::is_empty(&self.0)?.into())
+            pub fn is_empty(&self, py: Python) -> PyGeoArrowResult<ChunkedBooleanArray> {
+                Ok(py
+                    .allow_threads(|| HasDimensions::is_empty(&self.0))?
+                    .into())
             }
         }
     };
@@ -0,0 +1,130 @@
+use crate::array::*;
+use crate::chunked_array::*;
+use crate::error::PyGeoArrowResult;
+use crate::ffi::from_python::input::AnyGeometryBroadcastInput;
+use crate::ffi::from_python::AnyGeometryInput;
+use geoarrow::algorithm::geo::{
+    GeodesicDistance, GeodesicDistancePoint, HaversineDistance, HaversineDistancePoint,
+};
+use geoarrow::io::geo::geometry_to_geo;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// Determine the distance between two arrays of points using the [haversine formula].
+///
+/// Coordinates are interpreted as longitude/latitude in degrees.
+///
+/// *Note*: this implementation uses a mean earth radius of 6371.088 km, based on the
+/// [recommendation of the IUGG](ftp://athena.fsv.cvut.cz/ZFG/grs80-Moritz.pdf)
+///
+/// [haversine formula]: https://en.wikipedia.org/wiki/Haversine_formula
+///
+/// Args:
+///     input: input point array or chunked point array
+///     other: the point or point array to measure against. A variety of inputs are accepted:
+///
+///         - A scalar [`Point`][geoarrow.rust.core.Point]
+///         - A [`PointArray`][geoarrow.rust.core.PointArray]
+///         - A [`ChunkedPointArray`][geoarrow.rust.core.ChunkedPointArray]
+///         - Any Python class that implements the Geo Interface, such as a [`shapely` Point][shapely.Point]
+///         - Any GeoArrow array or chunked array of `Point` type
+///
+/// Returns:
+///     Array or chunked array with distance values in meters.
+#[pyfunction]
+pub fn haversine_distance(
+    py: Python,
+    input: AnyGeometryInput,
+    other: AnyGeometryBroadcastInput,
+) -> PyGeoArrowResult<PyObject> {
+    match (input, other) {
+        (AnyGeometryInput::Array(left), AnyGeometryBroadcastInput::Array(right)) => {
+            let result = py.allow_threads(|| {
+                HaversineDistance::haversine_distance(&left.as_ref(), &right.as_ref())
+            })?;
+            Ok(Float64Array::from(result).into_py(py))
+        }
+        (AnyGeometryInput::Chunked(left), AnyGeometryBroadcastInput::Chunked(right)) => {
+            let result = py.allow_threads(|| {
+                HaversineDistance::haversine_distance(&left.as_ref(), &right.as_ref())
+            })?;
+            Ok(ChunkedFloat64Array::from(result).into_py(py))
+        }
+        (AnyGeometryInput::Array(left), AnyGeometryBroadcastInput::Scalar(right)) => {
+            let scalar = geo::Point::try_from(geometry_to_geo(&right.0))
+                .map_err(|_| PyValueError::new_err("Expected type Point"))?;
+            let result = py.allow_threads(|| {
+                HaversineDistancePoint::haversine_distance(&left.as_ref(), &scalar)
+            })?;
+            Ok(Float64Array::from(result).into_py(py))
+        }
+        (AnyGeometryInput::Chunked(left), AnyGeometryBroadcastInput::Scalar(right)) => {
+            let scalar = geo::Point::try_from(geometry_to_geo(&right.0))
+                .map_err(|_| PyValueError::new_err("Expected type Point"))?;
+            let result = py.allow_threads(|| {
+                HaversineDistancePoint::haversine_distance(&left.as_ref(), &scalar)
+            })?;
+            Ok(ChunkedFloat64Array::from(result).into_py(py))
+        }
+        _ => Err(PyValueError::new_err("Unsupported input types.").into()),
+    }
+}
+
+/// Determine the distance between two arrays of points on the surface of the WGS84 ellipsoid,
+/// using the geodesic algorithm from [Karney (2013)].
+///
+/// Coordinates are interpreted as longitude/latitude in degrees. This is more accurate than
+/// [`haversine_distance`][geoarrow.rust.core.haversine_distance], which assumes a spherical earth.
+///
+/// [Karney (2013)]: https://arxiv.org/pdf/1109.4448.pdf
+///
+/// Args:
+///     input: input point array or chunked point array
+///     other: the point or point array to measure against. A variety of inputs are accepted:
+///
+///         - A scalar [`Point`][geoarrow.rust.core.Point]
+///         - A [`PointArray`][geoarrow.rust.core.PointArray]
+///         - A [`ChunkedPointArray`][geoarrow.rust.core.ChunkedPointArray]
+///         - Any Python class that implements the Geo Interface, such as a [`shapely` Point][shapely.Point]
+///         - Any GeoArrow array or chunked array of `Point` type
+///
+/// Returns:
+///     Array or chunked array with distance values in meters.
+#[pyfunction]
+pub fn geodesic_distance(
+    py: Python,
+    input: AnyGeometryInput,
+    other: AnyGeometryBroadcastInput,
+) -> PyGeoArrowResult<PyObject> {
+    match (input, other) {
+        (AnyGeometryInput::Array(left), AnyGeometryBroadcastInput::Array(right)) => {
+            let result = py.allow_threads(|| {
+                GeodesicDistance::geodesic_distance(&left.as_ref(), &right.as_ref())
+            })?;
+            Ok(Float64Array::from(result).into_py(py))
+        }
+        (AnyGeometryInput::Chunked(left), AnyGeometryBroadcastInput::Chunked(right)) => {
+            let result = py.allow_threads(|| {
+                GeodesicDistance::geodesic_distance(&left.as_ref(), &right.as_ref())
+            })?;
+            Ok(ChunkedFloat64Array::from(result).into_py(py))
+        }
+        (AnyGeometryInput::Array(left), AnyGeometryBroadcastInput::Scalar(right)) => {
+            let scalar = geo::Point::try_from(geometry_to_geo(&right.0))
+                .map_err(|_| PyValueError::new_err("Expected type Point"))?;
+            let result = py.allow_threads(|| {
+                GeodesicDistancePoint::geodesic_distance(&left.as_ref(), &scalar)
+            })?;
+            Ok(Float64Array::from(result).into_py(py))
+        }
+        (AnyGeometryInput::Chunked(left), AnyGeometryBroadcastInput::Scalar(right)) => {
+            let scalar = geo::Point::try_from(geometry_to_geo(&right.0))
+                .map_err(|_| PyValueError::new_err("Expected type Point"))?;
+            let result = py.allow_threads(|| {
+                GeodesicDistancePoint::geodesic_distance(&left.as_ref(), &scalar)
+            })?;
+            Ok(ChunkedFloat64Array::from(result).into_py(py))
+        }
+        _ => Err(PyValueError::new_err("Unsupported input types.").into()),
+    }
+}
@@ -13,15 +13,15 @@ use pyo3::prelude::*;
 /// Returns:
 ///     Array with axis-aligned bounding boxes.
 #[pyfunction]
-pub fn envelope(input: AnyGeometryInput) -> PyGeoArrowResult<PyObject> {
+pub fn envelope(py: Python, input: AnyGeometryInput) -> PyGeoArrowResult<PyObject> {
     match input {
         AnyGeometryInput::Array(arr) => {
-            let out = RectArray::from(arr.as_ref().bounding_rect()?);
-            Python::with_gil(|py| Ok(out.into_py(py)))
+            let out = RectArray::from(py.allow_threads(|| arr.as_ref().bounding_rect())?);
+            Ok(out.into_py(py))
         }
         AnyGeometryInput::Chunked(arr) => {
-            let out = ChunkedRectArray::from(arr.as_ref().bounding_rect()?);
-            Python::with_gil(|py| Ok(out.into_py(py)))
+            let out = ChunkedRectArray::from(py.allow_threads(|| arr.as_ref().bounding_rect())?);
+            Ok(out.into_py(py))
         }
     }
 }
@@ -34,8 +34,8 @@ macro_rules! impl_envelope {
             ///
             /// Returns:
             ///     Array with axis-aligned bounding boxes.
-            pub fn envelope(&self) -> RectArray {
-                RectArray(BoundingRect::bounding_rect(&self.0))
+            pub fn envelope(&self, py: Python) -> RectArray {
+                RectArray(py.allow_threads(|| BoundingRect::bounding_rect(&self.0)))
             }
         }
     };
@@ -58,8 +58,10 @@ macro_rules! impl_vector {
             ///
             /// Returns:
             ///     Array with axis-aligned bounding boxes.
-            pub fn envelope(&self) -> PyGeoArrowResult<ChunkedRectArray> {
-                Ok(ChunkedRectArray(BoundingRect::bounding_rect(&self.0)?))
+            pub fn envelope(&self, py: Python) -> PyGeoArrowResult<ChunkedRectArray> {
+                Ok(ChunkedRectArray(
+                    py.allow_threads(|| BoundingRect::bounding_rect(&self.0))?,
+                ))
             }
         }
     };
@@ -43,33 +43,42 @@ use pyo3::prelude::*;
 ///     Array or chunked array with float distance values.
 #[pyfunction]
 pub fn frechet_distance(
+    py: Python,
     input: AnyGeometryInput,
     other: AnyGeometryBroadcastInput,
 ) -> PyGeoArrowResult<PyObject> {
     match (input, other) {
         (AnyGeometryInput::Array(left), AnyGeometryBroadcastInput::Array(right)) => {
-            let result = FrechetDistance::frechet_distance(&left.as_ref(), &right.as_ref())?;
+            let result = py.allow_threads(|| {
+                FrechetDistance::frechet_distance(&left.as_ref(), &right.as_ref())
+            })?;
             let result = Float64Array::from(result);
-            Python::with_gil(|py| Ok(result.into_py(py)))
+            Ok(result.into_py(py))
         }
         (AnyGeometryInput::Chunked(left), AnyGeometryBroadcastInput::Chunked(right)) => {
-            let result = FrechetDistance::frechet_distance(&left.as_ref(), &right.as_ref())?;
+            let result = py.allow_threads(|| {
+                FrechetDistance::frechet_distance(&left.as_ref(), &right.as_ref())
+            })?;
             let result = ChunkedFloat64Array::from(result);
-            Python::with_gil(|py| Ok(result.into_py(py)))
+            Ok(result.into_py(py))
         }
         (AnyGeometryInput::Array(left), AnyGeometryBroadcastInput::Scalar(right)) => {
             let scalar = geo::LineString::try_from(geometry_to_geo(&right.0))
                 .map_err(|_| PyValueError::new_err("Expected type LineString"))?;
-            let result = FrechetDistanceLineString::frechet_distance(&left.as_ref(), &scalar)?;
+            let result = py.allow_threads(|| {
+                FrechetDistanceLineString::frechet_distance(&left.as_ref(), &scalar)
+            })?;
             let result = Float64Array::from(result);
-            Python::with_gil(|py| Ok(result.into_py(py)))
+            Ok(result.into_py(py))
         }
         (AnyGeometryInput::Chunked(left), AnyGeometryBroadcastInput::Scalar(right)) => {
             let scalar = geo::LineString::try_from(geometry_to_geo(&right.0))
                 .map_err(|_| PyValueError::new_err("Expected type LineString"))?;
-            let result = FrechetDistanceLineString::frechet_distance(&left.as_ref(), &scalar)?;
+            let result = py.allow_threads(|| {
+                FrechetDistanceLineString::frechet_distance(&left.as_ref(), &scalar)
+            })?;
             let result = ChunkedFloat64Array::from(result);
-            Python::with_gil(|py| Ok(result.into_py(py)))
+            Ok(result.into_py(py))
         }
         _ => Err(PyValueError::new_err("Unsupported input types.").into()),
     }
@@ -110,18 +119,21 @@ impl LineStringArray {
     ///     Array with float distance values.
     pub fn frechet_distance(
         &self,
+        py: Python,
         other: AnyGeometryBroadcastInput,
     ) -> PyGeoArrowResult<Float64Array> {
         match other {
             AnyGeometryBroadcastInput::Array(right) => {
                 let result = match right.data_type() {
-                    GeoDataType::LineString(_) => {
+                    GeoDataType::LineString(_) => py.allow_threads(|| {
                         FrechetDistance::frechet_distance(&self.0, right.as_ref().as_line_string())
-                    }
-                    GeoDataType::LargeLineString(_) => FrechetDistance::frechet_distance(
-                        &self.0,
-                        right.as_ref().as_large_line_string(),
-                    ),
+                    }),
+                    GeoDataType::LargeLineString(_) => py.allow_threads(|| {
+                        FrechetDistance::frechet_distance(
+                            &self.0,
+                            right.as_ref().as_large_line_string(),
+                        )
+                    }),
                     dt => {
                         return Err(PyValueError::new_err(format!(
                             "Unsupported broadcast type {:?}",
@@ -130,12 +142,14 @@ impl LineStringArray {
                         .into());
                     }
                 };
-                Ok(result.into())
+                Ok(result?.into())
             }
             AnyGeometryBroadcastInput::Scalar(right) => {
                 let scalar = geo::LineString::try_from(geometry_to_geo(&right.0))
                     .map_err(|_| PyValueError::new_err("Expected type LineString"))?;
-                let result = FrechetDistanceLineString::frechet_distance(&self.0, &scalar);
+                let result = py.allow_threads(|| {
+                    FrechetDistanceLineString::frechet_distance(&self.0, &scalar)
+                });
                 Ok(result.into())
             }
             _ => Err(PyValueError::new_err("Unsupported broadcast type.").into()),
@@ -178,18 +192,21 @@ impl ChunkedLineStringArray {
     ///     Chunked Array with float distance values.
     pub fn frechet_distance(
         &self,
+        py: Python,
         other: AnyGeometryBroadcastInput,
     ) -> PyGeoArrowResult<ChunkedFloat64Array> {
         match other {
             AnyGeometryBroadcastInput::Chunked(right) => {
                 let result = match right.data_type() {
-                    GeoDataType::LineString(_) => {
+                    GeoDataType::LineString(_) => py.allow_threads(|| {
                         FrechetDistance::frechet_distance(&self.0, right.as_ref().as_line_string())
-                    }
-                    GeoDataType::LargeLineString(_) => FrechetDistance::frechet_distance(
-                        &self.0,
-                        right.as_ref().as_large_line_string(),
-                    ),
+                    }),
+                    GeoDataType::LargeLineString(_) => py.allow_threads(|| {
+                        FrechetDistance::frechet_distance(
+                            &self.0,
+                            right.as_ref().as_large_line_string(),
+                        )
+                    }),
                     dt => {
                         return Err(PyValueError::new_err(format!(
                             "Unsupported broadcast type {:?}",
@@ -198,12 +215,14 @@ impl ChunkedLineStringArray {
                         .into());
                     }
                 };
-                Ok(result.into())
+                Ok(result?.into())
             }
             AnyGeometryBroadcastInput::Scalar(right) => {
                 let scalar = geo::LineString::try_from(geometry_to_geo(&right.0))
                     .map_err(|_| PyValueError::new_err("Expected type LineString"))?;
-                let result = FrechetDistanceLineString::frechet_distance(&self.0, &scalar);
+                let result = py.allow_threads(|| {
+                    FrechetDistanceLineString::frechet_distance(&self.0, &scalar)
+                });
                 Ok(result.into())
             }
             _ => Err(PyValueError::new_err("Unsupported broadcast type.").into()),
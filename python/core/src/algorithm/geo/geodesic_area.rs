@@ -21,15 +21,16 @@ use pyo3::prelude::*;
 /// Returns:
 ///     Array with output values.
 #[pyfunction]
-pub fn geodesic_perimeter(input: AnyGeometryInput) -> PyGeoArrowResult<PyObject> {
+pub fn geodesic_perimeter(py: Python, input: AnyGeometryInput) -> PyGeoArrowResult<PyObject> {
     match input {
         AnyGeometryInput::Array(arr) => {
-            let out = Float64Array::from(arr.as_ref().geodesic_perimeter()?);
-            Python::with_gil(|py| Ok(out.into_py(py)))
+            let out = Float64Array::from(py.allow_threads(|| arr.as_ref().geodesic_perimeter())?);
+            Ok(out.into_py(py))
         }
         AnyGeometryInput::Chunked(arr) => {
-            let out = ChunkedFloat64Array::from(arr.as_ref().geodesic_perimeter()?);
-            Python::with_gil(|py| Ok(out.into_py(py)))
+            let out =
+                ChunkedFloat64Array::from(py.allow_threads(|| arr.as_ref().geodesic_perimeter())?);
+            Ok(out.into_py(py))
         }
     }
 }
@@ -53,8 +54,9 @@ macro_rules! impl_geodesic_area {
             ///
             /// Returns:
             ///     Array with output values.
-            pub fn geodesic_perimeter(&self) -> Float64Array {
-                GeodesicArea::geodesic_perimeter(&self.0).into()
+            pub fn geodesic_perimeter(&self, py: Python) -> Float64Array {
+                py.allow_threads(|| GeodesicArea::geodesic_perimeter(&self.0))
+                    .into()
             }
         }
     };
@@ -88,8 +90,10 @@ macro_rules! impl_chunked {
             ///
             /// Returns:
             ///     Array with output values.
-            pub fn geodesic_perimeter(&self) -> PyGeoArrowResult<ChunkedFloat64Array> {
-                Ok(GeodesicArea::geodesic_perimeter(&self.0)?.into())
+            pub fn geodesic_perimeter(&self, py: Python) -> PyGeoArrowResult<ChunkedFloat64Array> {
+                Ok(py
+                    .allow_threads(|| GeodesicArea::geodesic_perimeter(&self.0))?
+                    .into())
             }
         }
     };
@@ -17,7 +17,7 @@ impl<'a> FromPyObject<'a> for LengthMethod {
     fn extract(ob: &'a PyAny) -> PyResult<Self> {
         let s: String = ob.extract()?;
         match s.to_lowercase().as_str() {
-            "ellipsoidal" => Ok(Self::Geodesic),
+            "ellipsoidal" | "geodesic" => Ok(Self::Geodesic),
             "euclidean" => Ok(Self::Euclidean),
             "haversine" => Ok(Self::Haversine),
             "vincenty" => Ok(Self::Vincenty),
@@ -28,13 +28,17 @@ impl<'a> FromPyObject<'a> for LengthMethod {
 
 /// Calculation of the length of a Line
 ///
+/// Points and MultiPoints have a length of zero. Polygon, mixed and geometry collection arrays are
+/// not supported and raise an error.
+///
 /// Args:
 ///     input: input geometry array or chunked geometry array
 ///
 /// Other args:
-///      method: The method to use for length calculation. One of "Ellipsoidal", "Euclidean",
-///         "Haversine", or "Vincenty". Refer to the documentation on
-///         [LengthMethod][geoarrow.rust.core.enums.LengthMethod] for more information.
+///      method: The method to use for length calculation. One of "Euclidean", "Haversine",
+///         "Ellipsoidal" (also accepted as "Geodesic"), or "Vincenty". Refer to the
+///         documentation on [LengthMethod][geoarrow.rust.core.enums.LengthMethod] for more
+///         information.
 ///
 /// Returns:
 ///     Array or chunked array with length values.
@@ -43,25 +47,29 @@ impl<'a> FromPyObject<'a> for LengthMethod {
     signature = (input, *, method = LengthMethod::Euclidean),
     text_signature = "(input, *, method = 'euclidean')")
 ]
-pub fn length(input: AnyGeometryInput, method: LengthMethod) -> PyGeoArrowResult<PyObject> {
+pub fn length(
+    py: Python,
+    input: AnyGeometryInput,
+    method: LengthMethod,
+) -> PyGeoArrowResult<PyObject> {
     match input {
         AnyGeometryInput::Array(arr) => {
-            let out = match method {
-                LengthMethod::Euclidean => arr.as_ref().euclidean_length()?,
-                LengthMethod::Geodesic => arr.as_ref().geodesic_length()?,
-                LengthMethod::Haversine => arr.as_ref().haversine_length()?,
-                LengthMethod::Vincenty => arr.as_ref().vincenty_length()?,
-            };
-            Python::with_gil(|py| Ok(Float64Array::from(out).into_py(py)))
+            let out = py.allow_threads(|| match method {
+                LengthMethod::Euclidean => arr.as_ref().euclidean_length(),
+                LengthMethod::Geodesic => arr.as_ref().geodesic_length(),
+                LengthMethod::Haversine => arr.as_ref().haversine_length(),
+                LengthMethod::Vincenty => arr.as_ref().vincenty_length(),
+            })?;
+            Ok(Float64Array::from(out).into_py(py))
         }
         AnyGeometryInput::Chunked(arr) => {
-            let out = match method {
-                LengthMethod::Euclidean => arr.as_ref().euclidean_length()?,
-                LengthMethod::Geodesic => arr.as_ref().geodesic_length()?,
-                LengthMethod::Haversine => arr.as_ref().haversine_length()?,
-                LengthMethod::Vincenty => arr.as_ref().vincenty_length()?,
-            };
-            Python::with_gil(|py| Ok(ChunkedFloat64Array::from(out).into_py(py)))
+            let out = py.allow_threads(|| match method {
+                LengthMethod::Euclidean => arr.as_ref().euclidean_length(),
+                LengthMethod::Geodesic => arr.as_ref().geodesic_length(),
+                LengthMethod::Haversine => arr.as_ref().haversine_length(),
+                LengthMethod::Vincenty => arr.as_ref().vincenty_length(),
+            })?;
+            Ok(ChunkedFloat64Array::from(out).into_py(py))
         }
     }
 }
@@ -79,13 +87,18 @@ macro_rules! impl_euclidean_length {
             /// Returns:
             ///     Array with length values.
             #[pyo3(signature = (*, method = LengthMethod::Euclidean), text_signature = "(*, method = 'euclidean')")]
-            pub fn length(&self, method: LengthMethod) -> PyGeoArrowResult<Float64Array> {
-                match method {
-                    LengthMethod::Euclidean => Ok(self.0.euclidean_length().into()),
-                    LengthMethod::Geodesic => Ok(self.0.geodesic_length().into()),
-                    LengthMethod::Haversine => Ok(self.0.haversine_length().into()),
-                    LengthMethod::Vincenty => Ok(self.0.vincenty_length()?.into()),
-                }
+            pub fn length(
+                &self,
+                py: Python,
+                method: LengthMethod,
+            ) -> PyGeoArrowResult<Float64Array> {
+                let out = py.allow_threads(|| match method {
+                    LengthMethod::Euclidean => Ok(self.0.euclidean_length()),
+                    LengthMethod::Geodesic => Ok(self.0.geodesic_length()),
+                    LengthMethod::Haversine => Ok(self.0.haversine_length()),
+                    LengthMethod::Vincenty => self.0.vincenty_length(),
+                })?;
+                Ok(out.into())
             }
         }
     };
@@ -110,13 +123,18 @@ macro_rules! impl_chunked {
             /// Returns:
             ///     Chunked array with length values.
             #[pyo3(signature = (*, method = LengthMethod::Euclidean), text_signature = "(*, method = 'euclidean')")]
-            pub fn length(&self, method: LengthMethod) -> PyGeoArrowResult<ChunkedFloat64Array> {
-                match method {
-                    LengthMethod::Euclidean => Ok(self.0.euclidean_length()?.into()),
-                    LengthMethod::Geodesic => Ok(self.0.geodesic_length()?.into()),
-                    LengthMethod::Haversine => Ok(self.0.haversine_length()?.into()),
-                    LengthMethod::Vincenty => Ok(self.0.vincenty_length()?.into()),
-                }
+            pub fn length(
+                &self,
+                py: Python,
+                method: LengthMethod,
+            ) -> PyGeoArrowResult<ChunkedFloat64Array> {
+                let out = py.allow_threads(|| match method {
+                    LengthMethod::Euclidean => self.0.euclidean_length(),
+                    LengthMethod::Geodesic => self.0.geodesic_length(),
+                    LengthMethod::Haversine => self.0.haversine_length(),
+                    LengthMethod::Vincenty => self.0.vincenty_length(),
+                })?;
+                Ok(out.into())
             }
         }
     };
@@ -33,25 +33,27 @@ use pyo3::prelude::*;
 ///     PointArray or ChunkedPointArray with result values
 #[pyfunction]
 pub fn line_interpolate_point(
+    py: Python,
     input: AnyGeometryInput,
     fraction: AnyPrimitiveBroadcastInput<Float64Type>,
 ) -> PyGeoArrowResult<PyObject> {
     match (input, fraction) {
         (AnyGeometryInput::Array(arr), AnyPrimitiveBroadcastInput::Array(fraction)) => {
-            let result = arr.as_ref().line_interpolate_point(&fraction)?;
-            Python::with_gil(|py| Ok(PointArray(result).into_py(py)))
+            let result = py.allow_threads(|| arr.as_ref().line_interpolate_point(&fraction))?;
+            Ok(PointArray(result).into_py(py))
         }
         (AnyGeometryInput::Chunked(arr), AnyPrimitiveBroadcastInput::Chunked(fraction)) => {
-            let result = arr.as_ref().line_interpolate_point(fraction.chunks())?;
-            Python::with_gil(|py| Ok(ChunkedPointArray(result).into_py(py)))
+            let result =
+                py.allow_threads(|| arr.as_ref().line_interpolate_point(fraction.chunks()))?;
+            Ok(ChunkedPointArray(result).into_py(py))
         }
         (AnyGeometryInput::Array(arr), AnyPrimitiveBroadcastInput::Scalar(fraction)) => {
-            let result = arr.as_ref().line_interpolate_point(fraction)?;
-            Python::with_gil(|py| Ok(PointArray(result).into_py(py)))
+            let result = py.allow_threads(|| arr.as_ref().line_interpolate_point(fraction))?;
+            Ok(PointArray(result).into_py(py))
         }
         (AnyGeometryInput::Chunked(arr), AnyPrimitiveBroadcastInput::Scalar(fraction)) => {
-            let result = arr.as_ref().line_interpolate_point(fraction)?;
-            Python::with_gil(|py| Ok(ChunkedPointArray(result).into_py(py)))
+            let result = py.allow_threads(|| arr.as_ref().line_interpolate_point(fraction))?;
+            Ok(ChunkedPointArray(result).into_py(py))
         }
         _ => Err(PyValueError::new_err("Unsupported input types.").into()),
     }
@@ -82,9 +84,14 @@ impl LineStringArray {
     ///     PointArray with result values
     pub fn line_interpolate_point(
         &self,
+        py: Python,
         fraction: AnyPrimitiveBroadcastInput<Float64Type>,
     ) -> PyGeoArrowResult<PyObject> {
-        line_interpolate_point(AnyGeometryInput::Array(Arc::new(self.0.clone())), fraction)
+        line_interpolate_point(
+            py,
+            AnyGeometryInput::Array(Arc::new(self.0.clone())),
+            fraction,
+        )
     }
 }
 
@@ -113,9 +120,11 @@ impl ChunkedLineStringArray {
     ///     ChunkedPointArray with result values
     pub fn line_interpolate_point(
         &self,
+        py: Python,
         fraction: AnyPrimitiveBroadcastInput<Float64Type>,
     ) -> PyGeoArrowResult<PyObject> {
         line_interpolate_point(
+            py,
             AnyGeometryInput::Chunked(Arc::new(self.0.clone())),
             fraction,
         )
@@ -36,29 +36,38 @@ use pyo3::prelude::*;
 ///     Array or chunked array with float fraction values.
 #[pyfunction]
 pub fn line_locate_point(
+    py: Python,
     input: AnyGeometryInput,
     point: AnyGeometryBroadcastInput,
 ) -> PyGeoArrowResult<PyObject> {
     match (input, point) {
         (AnyGeometryInput::Array(arr), AnyGeometryBroadcastInput::Array(point)) => {
-            let result = LineLocatePoint::line_locate_point(&arr.as_ref(), point.as_ref())?;
-            Python::with_gil(|py| Ok(Float64Array(result).into_py(py)))
+            let result = py.allow_threads(|| {
+                LineLocatePoint::line_locate_point(&arr.as_ref(), point.as_ref())
+            })?;
+            Ok(Float64Array(result).into_py(py))
         }
         (AnyGeometryInput::Chunked(arr), AnyGeometryBroadcastInput::Chunked(point)) => {
-            let result = LineLocatePoint::line_locate_point(&arr.as_ref(), point.as_ref())?;
-            Python::with_gil(|py| Ok(ChunkedFloat64Array(result).into_py(py)))
+            let result = py.allow_threads(|| {
+                LineLocatePoint::line_locate_point(&arr.as_ref(), point.as_ref())
+            })?;
+            Ok(ChunkedFloat64Array(result).into_py(py))
         }
         (AnyGeometryInput::Array(arr), AnyGeometryBroadcastInput::Scalar(point)) => {
             let scalar = geo::Point::try_from(geometry_to_geo(&point.0))
                 .map_err(|_| PyValueError::new_err("Expected type Point"))?;
-            let result = LineLocatePointScalar::line_locate_point(&arr.as_ref(), &scalar)?;
-            Python::with_gil(|py| Ok(Float64Array(result).into_py(py)))
+            let result = py.allow_threads(|| {
+                LineLocatePointScalar::line_locate_point(&arr.as_ref(), &scalar)
+            })?;
+            Ok(Float64Array(result).into_py(py))
         }
         (AnyGeometryInput::Chunked(arr), AnyGeometryBroadcastInput::Scalar(point)) => {
             let scalar = geo::Point::try_from(geometry_to_geo(&point.0))
                 .map_err(|_| PyValueError::new_err("Expected type Point"))?;
-            let result = LineLocatePointScalar::line_locate_point(&arr.as_ref(), &scalar)?;
-            Python::with_gil(|py| Ok(ChunkedFloat64Array(result).into_py(py)))
+            let result = py.allow_threads(|| {
+                LineLocatePointScalar::line_locate_point(&arr.as_ref(), &scalar)
+            })?;
+            Ok(ChunkedFloat64Array(result).into_py(py))
         }
         _ => Err(PyValueError::new_err("Unsupported input types.").into()),
     }
@@ -92,10 +101,11 @@ impl LineStringArray {
     ///     Array with float fraction values.
     pub fn line_locate_point(
         &self,
+        py: Python,
         point: AnyGeometryBroadcastInput,
     ) -> PyGeoArrowResult<PyObject> {
         let input = AnyGeometryInput::Array(Arc::new(self.0.clone()));
-        line_locate_point(input, point)
+        line_locate_point(py, input, point)
     }
 }
 
@@ -127,9 +137,10 @@ impl ChunkedLineStringArray {
     ///     Chunked array with float fraction values.
     pub fn line_locate_point(
         &self,
+        py: Python,
         point: AnyGeometryBroadcastInput,
     ) -> PyGeoArrowResult<PyObject> {
         let input = AnyGeometryInput::Chunked(Arc::new(self.0.clone()));
-        line_locate_point(input, point)
+        line_locate_point(py, input, point)
     }
 }
@@ -1,11 +1,13 @@
 pub mod affine_ops;
 pub mod area;
+pub mod boolean_ops;
 pub mod center;
 pub mod centroid;
 pub mod chaikin_smoothing;
 pub mod convex_hull;
 pub mod densify;
 pub mod dimensions;
+pub mod distance;
 pub mod envelope;
 pub mod frechet_distance;
 pub mod geodesic_area;
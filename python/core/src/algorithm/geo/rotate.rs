@@ -11,31 +11,33 @@ macro_rules! impl_rotate {
             /// Rotate a geometry around its centroid by an angle, in degrees
             ///
             /// Positive angles are counter-clockwise, and negative angles are clockwise rotations.
-            pub fn rotate_around_centroid(&self, degrees: BroadcastableFloat) -> Self {
+            pub fn rotate_around_centroid(&self, py: Python, degrees: BroadcastableFloat) -> Self {
                 use geoarrow::algorithm::geo::Rotate;
-                match degrees.0 {
+                py.allow_threads(|| match degrees.0 {
                     BroadcastablePrimitive::Array(arr) => {
-                        Rotate::rotate_around_centroid(&self.0, &arr).into()
+                        Rotate::rotate_around_centroid(&self.0, &arr)
                     }
                     BroadcastablePrimitive::Scalar(scalar) => {
-                        Rotate::rotate_around_centroid(&self.0, &scalar).into()
+                        Rotate::rotate_around_centroid(&self.0, &scalar)
                     }
-                }
+                })
+                .into()
             }
 
             /// Rotate a geometry around the center of its bounding box by an angle, in degrees.
             ///
             /// Positive angles are counter-clockwise, and negative angles are clockwise rotations.
-            pub fn rotate_around_center(&self, degrees: BroadcastableFloat) -> Self {
+            pub fn rotate_around_center(&self, py: Python, degrees: BroadcastableFloat) -> Self {
                 use geoarrow::algorithm::geo::Rotate;
-                match degrees.0 {
+                py.allow_threads(|| match degrees.0 {
                     BroadcastablePrimitive::Array(arr) => {
-                        Rotate::rotate_around_center(&self.0, &arr).into()
+                        Rotate::rotate_around_center(&self.0, &arr)
                     }
                     BroadcastablePrimitive::Scalar(scalar) => {
-                        Rotate::rotate_around_center(&self.0, &scalar).into()
+                        Rotate::rotate_around_center(&self.0, &scalar)
                     }
-                }
+                })
+                .into()
             }
 
             // /// Rotate a Geometry around an arbitrary point by an angle, given in degrees
@@ -7,9 +7,10 @@ macro_rules! impl_scale {
         #[pymethods]
         impl $struct_name {
             /// Scale a geometry from it's bounding box center.
-            pub fn scale(&self, scale_factor: BroadcastableFloat) -> Self {
+            pub fn scale(&self, py: Python, scale_factor: BroadcastableFloat) -> Self {
                 use geoarrow::algorithm::geo::Scale;
-                Scale::scale(&self.0, scale_factor.0).into()
+                py.allow_threads(|| Scale::scale(&self.0, scale_factor.0))
+                    .into()
             }
 
             /// Scale a geometry from it's bounding box center, using different values for
@@ -17,11 +18,13 @@ macro_rules! impl_scale {
             /// ratio](https://en.wikipedia.org/wiki/Aspect_ratio).
             pub fn scale_xy(
                 &self,
+                py: Python,
                 x_factor: BroadcastableFloat,
                 y_factor: BroadcastableFloat,
             ) -> Self {
                 use geoarrow::algorithm::geo::Scale;
-                Scale::scale_xy(&self.0, x_factor.0, y_factor.0).into()
+                py.allow_threads(|| Scale::scale_xy(&self.0, x_factor.0, y_factor.0))
+                    .into()
             }
 
             // TODO: scale around point
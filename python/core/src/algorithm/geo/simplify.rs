@@ -45,26 +45,27 @@ impl<'a> FromPyObject<'a> for SimplifyMethod {
     text_signature = "(input, epsilon, *, method = 'rdp')")
 ]
 pub fn simplify(
+    py: Python,
     input: AnyGeometryInput,
     epsilon: f64,
     method: SimplifyMethod,
 ) -> PyGeoArrowResult<PyObject> {
     match input {
         AnyGeometryInput::Array(arr) => {
-            let out = match method {
-                SimplifyMethod::Rdp => arr.as_ref().simplify(&epsilon)?,
-                SimplifyMethod::Vw => arr.as_ref().simplify_vw(&epsilon)?,
-                SimplifyMethod::VwPreserve => arr.as_ref().simplify_vw_preserve(&epsilon)?,
-            };
-            Python::with_gil(|py| geometry_array_to_pyobject(py, out))
+            let out = py.allow_threads(|| match method {
+                SimplifyMethod::Rdp => arr.as_ref().simplify(&epsilon.into()),
+                SimplifyMethod::Vw => arr.as_ref().simplify_vw(&epsilon.into()),
+                SimplifyMethod::VwPreserve => arr.as_ref().simplify_vw_preserve(&epsilon.into()),
+            })?;
+            geometry_array_to_pyobject(py, out)
         }
         AnyGeometryInput::Chunked(arr) => {
-            let out = match method {
-                SimplifyMethod::Rdp => arr.as_ref().simplify(&epsilon)?,
-                SimplifyMethod::Vw => arr.as_ref().simplify_vw(&epsilon)?,
-                SimplifyMethod::VwPreserve => arr.as_ref().simplify_vw_preserve(&epsilon)?,
-            };
-            Python::with_gil(|py| chunked_geometry_array_to_pyobject(py, out))
+            let out = py.allow_threads(|| match method {
+                SimplifyMethod::Rdp => arr.as_ref().simplify(&epsilon.into()),
+                SimplifyMethod::Vw => arr.as_ref().simplify_vw(&epsilon.into()),
+                SimplifyMethod::VwPreserve => arr.as_ref().simplify_vw_preserve(&epsilon.into()),
+            })?;
+            chunked_geometry_array_to_pyobject(py, out)
         }
     }
 }
@@ -88,12 +89,13 @@ macro_rules! impl_simplify {
             /// Returns:
             ///     Simplified geometry array.
             #[pyo3(signature = (epsilon, *, method = SimplifyMethod::Rdp), text_signature = "(epsilon, *, method = 'rdp')")]
-            pub fn simplify(&self, epsilon: f64, method: SimplifyMethod) -> Self {
-                match method {
-                    SimplifyMethod::Rdp => self.0.simplify(&epsilon).into(),
-                    SimplifyMethod::Vw => self.0.simplify_vw(&epsilon).into(),
-                    SimplifyMethod::VwPreserve => self.0.simplify_vw_preserve(&epsilon).into(),
-                }
+            pub fn simplify(&self, py: Python, epsilon: f64, method: SimplifyMethod) -> Self {
+                py.allow_threads(|| match method {
+                    SimplifyMethod::Rdp => self.0.simplify(&epsilon.into()),
+                    SimplifyMethod::Vw => self.0.simplify_vw(&epsilon.into()),
+                    SimplifyMethod::VwPreserve => self.0.simplify_vw_preserve(&epsilon.into()),
+                })
+                .into()
             }
         }
     };
@@ -125,12 +127,18 @@ macro_rules! impl_chunked {
             /// Returns:
             ///     Simplified geometry array.
             #[pyo3(signature = (epsilon, *, method = SimplifyMethod::Rdp), text_signature = "(epsilon, *, method = 'rdp')")]
-            pub fn simplify(&self, epsilon: f64, method: SimplifyMethod) -> Self {
-                match method {
-                    SimplifyMethod::Rdp => self.0.simplify(&epsilon).into(),
-                    SimplifyMethod::Vw => self.0.simplify_vw(&epsilon).into(),
-                    SimplifyMethod::VwPreserve => self.0.simplify_vw_preserve(&epsilon).into(),
-                }
+            pub fn simplify(
+                &self,
+                py: Python,
+                epsilon: f64,
+                method: SimplifyMethod,
+            ) -> PyGeoArrowResult<Self> {
+                let out = py.allow_threads(|| match method {
+                    SimplifyMethod::Rdp => self.0.simplify(&epsilon.into()),
+                    SimplifyMethod::Vw => self.0.simplify_vw(&epsilon.into()),
+                    SimplifyMethod::VwPreserve => self.0.simplify_vw_preserve(&epsilon.into()),
+                })?;
+                Ok(out.into())
             }
         }
     };
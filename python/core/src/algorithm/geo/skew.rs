@@ -9,9 +9,9 @@ macro_rules! impl_skew {
         impl $struct_name {
             /// An affine transformation which skews a geometry, sheared by a uniform angle along
             /// the x and y dimensions.
-            pub fn skew(&self, degrees: BroadcastableFloat) -> Self {
+            pub fn skew(&self, py: Python, degrees: BroadcastableFloat) -> Self {
                 use geoarrow::algorithm::geo::Skew;
-                Skew::skew(&self.0, degrees.0).into()
+                py.allow_threads(|| Skew::skew(&self.0, degrees.0)).into()
             }
 
             /// Skew a geometry from it's bounding box center, using different values for
@@ -19,11 +19,13 @@ macro_rules! impl_skew {
             /// ratio](https://en.wikipedia.org/wiki/Aspect_ratio).
             pub fn skew_xy(
                 &self,
+                py: Python,
                 degrees_x: BroadcastableFloat,
                 degrees_y: BroadcastableFloat,
             ) -> Self {
                 use geoarrow::algorithm::geo::Skew;
-                Skew::skew_xy(&self.0, degrees_x.0, degrees_y.0).into()
+                py.allow_threads(|| Skew::skew_xy(&self.0, degrees_x.0, degrees_y.0))
+                    .into()
             }
 
             // /// An affine transformation which skews a geometry around a point of `origin`, sheared
@@ -9,11 +9,13 @@ macro_rules! impl_translate {
             /// Translate a Geometry along its axes by the given offsets
             pub fn translate(
                 &self,
+                py: Python,
                 x_offset: BroadcastableFloat,
                 y_offset: BroadcastableFloat,
             ) -> Self {
                 use geoarrow::algorithm::geo::Translate;
-                Translate::translate(&self.0, x_offset.0, y_offset.0).into()
+                py.allow_threads(|| Translate::translate(&self.0, x_offset.0, y_offset.0))
+                    .into()
             }
         }
     };
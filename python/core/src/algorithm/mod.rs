@@ -1,3 +1,4 @@
+pub mod capabilities;
 pub mod geo;
 pub mod native;
 pub mod polylabel;
@@ -9,8 +9,8 @@ macro_rules! impl_len {
         #[pymethods]
         impl $struct_name {
             /// Concatenate a chunked array into a contiguous array.
-            pub fn concatenate(&self) -> PyGeoArrowResult<$return_type> {
-                Ok(self.0.concatenate()?.into())
+            pub fn concatenate(&self, py: Python) -> PyGeoArrowResult<$return_type> {
+                Ok(py.allow_threads(|| self.0.concatenate())?.into())
             }
         }
     };
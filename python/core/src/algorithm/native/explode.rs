@@ -14,8 +14,8 @@ use pyo3::prelude::*;
 /// Returns:
 ///     A new table with multi-part geometries exploded to separate rows.
 #[pyfunction]
-pub fn explode(input: GeoTable) -> PyGeoArrowResult<GeoTable> {
-    input.explode()
+pub fn explode(py: Python, input: GeoTable) -> PyGeoArrowResult<GeoTable> {
+    input.explode(py)
 }
 
 #[pymethods]
@@ -27,7 +27,7 @@ impl GeoTable {
     ///
     /// Returns:
     ///     A new table with multi-part geometries exploded to separate rows.
-    pub fn explode(&self) -> PyGeoArrowResult<GeoTable> {
-        Ok(self.0.explode()?.into())
+    pub fn explode(&self, py: Python) -> PyGeoArrowResult<GeoTable> {
+        Ok(py.allow_threads(|| self.0.explode())?.into())
     }
 }
@@ -13,10 +13,12 @@ use pyo3::prelude::*;
 /// Returns:
 ///     tuple of (xmin, ymin, xmax, ymax).
 #[pyfunction]
-pub fn total_bounds(input: AnyGeometryInput) -> PyGeoArrowResult<(f64, f64, f64, f64)> {
+pub fn total_bounds(py: Python, input: AnyGeometryInput) -> PyGeoArrowResult<(f64, f64, f64, f64)> {
     match input {
-        AnyGeometryInput::Array(arr) => Ok(arr.as_ref().total_bounds().into()),
-        AnyGeometryInput::Chunked(arr) => Ok(arr.as_ref().total_bounds().into()),
+        AnyGeometryInput::Array(arr) => Ok(py.allow_threads(|| arr.as_ref().total_bounds()).into()),
+        AnyGeometryInput::Chunked(arr) => {
+            Ok(py.allow_threads(|| arr.as_ref().total_bounds()).into())
+        }
     }
 }
 
@@ -28,8 +30,8 @@ macro_rules! impl_array {
             ///
             /// Returns:
             ///     tuple of (xmin, ymin, xmax, ymax).
-            pub fn total_bounds(&self) -> (f64, f64, f64, f64) {
-                self.0.total_bounds().into()
+            pub fn total_bounds(&self, py: Python) -> (f64, f64, f64, f64) {
+                py.allow_threads(|| self.0.total_bounds()).into()
             }
         }
     };
@@ -54,8 +56,8 @@ macro_rules! impl_chunked {
             ///
             /// Returns:
             ///     tuple of (xmin, ymin, xmax, ymax).
-            pub fn total_bounds(&self) -> (f64, f64, f64, f64) {
-                self.0.total_bounds().into()
+            pub fn total_bounds(&self, py: Python) -> (f64, f64, f64, f64) {
+                py.allow_threads(|| self.0.total_bounds()).into()
             }
         }
     };
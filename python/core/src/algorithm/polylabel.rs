@@ -24,15 +24,19 @@ use pyo3::prelude::*;
 /// Returns:
 ///     PointArray or ChunkedPointArray with result values
 #[pyfunction]
-pub fn polylabel(input: AnyGeometryInput, tolerance: f64) -> PyGeoArrowResult<PyObject> {
+pub fn polylabel(
+    py: Python,
+    input: AnyGeometryInput,
+    tolerance: f64,
+) -> PyGeoArrowResult<PyObject> {
     match input {
         AnyGeometryInput::Array(arr) => {
-            let result = arr.as_ref().polylabel(tolerance)?;
-            Python::with_gil(|py| Ok(PointArray(result).into_py(py)))
+            let result = py.allow_threads(|| arr.as_ref().polylabel(tolerance))?;
+            Ok(PointArray(result).into_py(py))
         }
         AnyGeometryInput::Chunked(chunked) => {
-            let result = chunked.as_ref().polylabel(tolerance)?;
-            Python::with_gil(|py| Ok(ChunkedPointArray(result).into_py(py)))
+            let result = py.allow_threads(|| chunked.as_ref().polylabel(tolerance))?;
+            Ok(ChunkedPointArray(result).into_py(py))
         }
     }
 }
@@ -55,8 +59,12 @@ impl PolygonArray {
     ///
     /// Returns:
     ///     PointArray with result values
-    pub fn polylabel(&self, tolerance: f64) -> PyGeoArrowResult<PyObject> {
-        polylabel(AnyGeometryInput::Array(Arc::new(self.0.clone())), tolerance)
+    pub fn polylabel(&self, py: Python, tolerance: f64) -> PyGeoArrowResult<PyObject> {
+        polylabel(
+            py,
+            AnyGeometryInput::Array(Arc::new(self.0.clone())),
+            tolerance,
+        )
     }
 }
 
@@ -78,8 +86,9 @@ impl ChunkedPolygonArray {
     ///
     /// Returns:
     ///     ChunkedPointArray with result values
-    pub fn polylabel(&self, tolerance: f64) -> PyGeoArrowResult<PyObject> {
+    pub fn polylabel(&self, py: Python, tolerance: f64) -> PyGeoArrowResult<PyObject> {
         polylabel(
+            py,
             AnyGeometryInput::Chunked(Arc::new(self.0.clone())),
             tolerance,
         )
@@ -130,4 +130,20 @@ impl PointArray {
         let coords = SeparatedCoordBuffer::try_new(x.0, y.0)?;
         Ok(geoarrow::array::PointArray::new(coords.into(), None, Default::default()).into())
     }
+
+    /// The x values of the points, with nulls where the points are null.
+    ///
+    /// Returns:
+    ///     Array of x values
+    fn x(&self) -> Float64Array {
+        Float64Array(self.0.x())
+    }
+
+    /// The y values of the points, with nulls where the points are null.
+    ///
+    /// Returns:
+    ///     Array of y values
+    fn y(&self) -> Float64Array {
+        Float64Array(self.0.y())
+    }
 }
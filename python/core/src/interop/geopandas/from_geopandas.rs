@@ -105,57 +105,43 @@ impl GeoTable {
             .is_instance_of::<PointArray>()
         {
             let ga_arr = python_geometry_array.extract::<PointArray>(py)?;
-            Arc::new(geoarrow::chunked_array::ChunkedGeometryArray::new(vec![
-                ga_arr.0,
-            ]))
+            Arc::new(geoarrow::chunked_array::ChunkedGeometryArray::new_unchecked(vec![ga_arr.0]))
         } else if python_geometry_array
             .as_ref(py)
             .is_instance_of::<LineStringArray>()
         {
             let ga_arr = python_geometry_array.extract::<LineStringArray>(py)?;
-            Arc::new(geoarrow::chunked_array::ChunkedGeometryArray::new(vec![
-                ga_arr.0,
-            ]))
+            Arc::new(geoarrow::chunked_array::ChunkedGeometryArray::new_unchecked(vec![ga_arr.0]))
         } else if python_geometry_array
             .as_ref(py)
             .is_instance_of::<PolygonArray>()
         {
             let ga_arr = python_geometry_array.extract::<PolygonArray>(py)?;
-            Arc::new(geoarrow::chunked_array::ChunkedGeometryArray::new(vec![
-                ga_arr.0,
-            ]))
+            Arc::new(geoarrow::chunked_array::ChunkedGeometryArray::new_unchecked(vec![ga_arr.0]))
         } else if python_geometry_array
             .as_ref(py)
             .is_instance_of::<MultiPointArray>()
         {
             let ga_arr = python_geometry_array.extract::<MultiPointArray>(py)?;
-            Arc::new(geoarrow::chunked_array::ChunkedGeometryArray::new(vec![
-                ga_arr.0,
-            ]))
+            Arc::new(geoarrow::chunked_array::ChunkedGeometryArray::new_unchecked(vec![ga_arr.0]))
         } else if python_geometry_array
             .as_ref(py)
             .is_instance_of::<MultiLineStringArray>()
         {
             let ga_arr = python_geometry_array.extract::<MultiLineStringArray>(py)?;
-            Arc::new(geoarrow::chunked_array::ChunkedGeometryArray::new(vec![
-                ga_arr.0,
-            ]))
+            Arc::new(geoarrow::chunked_array::ChunkedGeometryArray::new_unchecked(vec![ga_arr.0]))
         } else if python_geometry_array
             .as_ref(py)
             .is_instance_of::<MultiPolygonArray>()
         {
             let ga_arr = python_geometry_array.extract::<MultiPolygonArray>(py)?;
-            Arc::new(geoarrow::chunked_array::ChunkedGeometryArray::new(vec![
-                ga_arr.0,
-            ]))
+            Arc::new(geoarrow::chunked_array::ChunkedGeometryArray::new_unchecked(vec![ga_arr.0]))
         } else if python_geometry_array
             .as_ref(py)
             .is_instance_of::<MixedGeometryArray>()
         {
             let ga_arr = python_geometry_array.extract::<MixedGeometryArray>(py)?;
-            Arc::new(geoarrow::chunked_array::ChunkedGeometryArray::new(vec![
-                ga_arr.0,
-            ]))
+            Arc::new(geoarrow::chunked_array::ChunkedGeometryArray::new_unchecked(vec![ga_arr.0]))
         } else {
             unreachable!()
         };
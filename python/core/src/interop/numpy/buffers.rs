@@ -0,0 +1,382 @@
+use crate::array::primitive::{Float64Array, Int32Array};
+use crate::array::*;
+use crate::error::PyGeoArrowResult;
+use arrow_buffer::OffsetBuffer;
+use geoarrow::array::{CoordBuffer, CoordType};
+use geoarrow::trait_::GeometryArraySelfMethods;
+use geoarrow::GeometryArrayTrait;
+use numpy::ndarray::ArrayView1;
+use numpy::{PyArray1, ToPyArray};
+use pyo3::exceptions::PyValueError;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+/// Create a read-only numpy view onto `values`, without copying.
+///
+/// `container` must own the Arrow buffer backing `values`. numpy keeps a reference to it for as
+/// long as the view is alive.
+fn borrowed_view<'py, T: numpy::Element>(
+    py: Python<'py>,
+    values: &[T],
+    container: &'py PyAny,
+) -> PyResult<&'py PyArray1<T>> {
+    // Safety: the memory of `values` is owned by `container`, and Arrow buffers are immutable.
+    let array = unsafe { PyArray1::borrow_from_array(&ArrayView1::from(values), container) };
+    array
+        .getattr(intern!(py, "flags"))?
+        .setattr(intern!(py, "writeable"), false)?;
+    Ok(array)
+}
+
+fn coords_view<'py>(py: Python<'py>, coords: &CoordBuffer) -> PyResult<&'py PyArray1<f64>> {
+    let interleaved = match coords {
+        CoordBuffer::Interleaved(coords) => coords.clone(),
+        // Separated coordinates have no interleaved buffer to borrow from, so they are copied
+        CoordBuffer::Separated(_) => match coords.clone().into_coord_type(CoordType::Interleaved) {
+            CoordBuffer::Interleaved(coords) => coords,
+            _ => unreachable!(),
+        },
+    };
+    let container = PyCell::new(py, Float64Array(interleaved.values_array()))?;
+    let values = container.borrow().0.values().clone();
+    let values: &[f64] = &values;
+    borrowed_view(py, values, container.as_ref())
+}
+
+fn offsets_view<'py>(py: Python<'py>, offsets: &OffsetBuffer<i32>) -> PyResult<&'py PyArray1<i32>> {
+    let container = PyCell::new(
+        py,
+        Int32Array(arrow::array::Int32Array::new(offsets.inner().clone(), None)),
+    )?;
+    let values = container.borrow().0.values().clone();
+    let values: &[i32] = &values;
+    borrowed_view(py, values, container.as_ref())
+}
+
+/// The number of parts of each geometry, used to repeat per-geometry attributes once per part.
+fn part_counts(offsets: &OffsetBuffer<i32>) -> Vec<i64> {
+    offsets.windows(2).map(|w| (w[1] - w[0]) as i64).collect()
+}
+
+/// Check that no polygon has interior rings, which deck.gl's binary polygon format can't
+/// describe with start indices alone.
+fn check_no_holes(polygon_ring_offsets: &OffsetBuffer<i32>) -> PyResult<()> {
+    if polygon_ring_offsets.windows(2).any(|w| w[1] - w[0] > 1) {
+        return Err(PyValueError::new_err(
+            "Polygons with interior rings are not supported by deck.gl binary attributes",
+        ));
+    }
+    Ok(())
+}
+
+/// The start index of the exterior ring of each polygon, plus the end of the last one.
+fn exterior_start_indices(
+    polygon_ring_offsets: &OffsetBuffer<i32>,
+    ring_offsets: &OffsetBuffer<i32>,
+) -> Vec<i32> {
+    polygon_ring_offsets
+        .iter()
+        .map(|ring_idx| ring_offsets[*ring_idx as usize])
+        .collect()
+}
+
+/// Assemble the binary data object that deck.gl layers accept as `data`.
+///
+/// `attributes` maps accessor names, e.g. `getColor`, to one value per geometry. If `repeats` is
+/// given, each geometry is exploded into that many features, and its values are repeated for
+/// each of them.
+fn deckgl_binary<'py>(
+    py: Python<'py>,
+    start_indices: Option<PyObject>,
+    position_accessor: &str,
+    positions: &'py PyArray1<f64>,
+    attributes: Option<&'py PyDict>,
+    num_geometries: usize,
+    repeats: Option<Vec<i64>>,
+) -> PyGeoArrowResult<&'py PyDict> {
+    let numpy_mod = py.import(intern!(py, "numpy"))?;
+    let length = repeats.as_ref().map_or(num_geometries, |repeats| {
+        repeats.iter().sum::<i64>() as usize
+    });
+
+    let out_attributes = PyDict::new(py);
+    let position_attribute = PyDict::new(py);
+    position_attribute.set_item("value", positions)?;
+    position_attribute.set_item("size", 2)?;
+    out_attributes.set_item(position_accessor, position_attribute)?;
+
+    let repeats = repeats.map(|repeats| repeats.to_pyarray(py));
+    for (accessor, values) in attributes.into_iter().flatten() {
+        let mut values = numpy_mod.call_method1(intern!(py, "asarray"), (values,))?;
+        if values.len()? != num_geometries {
+            return Err(PyValueError::new_err(format!(
+                "Attribute {} has {} values, expected one per geometry ({})",
+                accessor,
+                values.len()?,
+                num_geometries
+            ))
+            .into());
+        }
+        if let Some(repeats) = repeats {
+            values = numpy_mod.call_method1(intern!(py, "repeat"), (values, repeats, 0))?;
+        }
+
+        let size = match values.getattr(intern!(py, "ndim"))?.extract::<usize>()? {
+            1 => 1,
+            _ => values
+                .getattr(intern!(py, "shape"))?
+                .get_item(1)?
+                .extract::<usize>()?,
+        };
+        let value = numpy_mod
+            .call_method1(intern!(py, "ascontiguousarray"), (values,))?
+            .call_method1(intern!(py, "reshape"), (-1,))?;
+
+        let attribute = PyDict::new(py);
+        attribute.set_item("value", value)?;
+        attribute.set_item("size", size)?;
+        out_attributes.set_item(accessor, attribute)?;
+    }
+
+    let data = PyDict::new(py);
+    data.set_item("length", length)?;
+    if let Some(start_indices) = start_indices {
+        data.set_item("startIndices", start_indices)?;
+    }
+    data.set_item("attributes", out_attributes)?;
+    Ok(data)
+}
+
+macro_rules! impl_coords_buffer {
+    ($struct_name:ty) => {
+        #[pymethods]
+        impl $struct_name {
+            /// The flat, interleaved `x, y` coordinates of this array as a numpy array.
+            ///
+            /// If this array stores interleaved coordinates, the returned array is a read-only
+            /// view onto its Arrow buffer, without a copy. Separated coordinates are first copied
+            /// into an interleaved buffer.
+            ///
+            /// Returns:
+            ///     A one-dimensional float64 numpy array of length `2 * num_coords`.
+            fn coords_buffer<'py>(&self, py: Python<'py>) -> PyResult<&'py PyArray1<f64>> {
+                coords_view(py, self.0.coords())
+            }
+        }
+    };
+}
+
+impl_coords_buffer!(PointArray);
+impl_coords_buffer!(LineStringArray);
+impl_coords_buffer!(PolygonArray);
+impl_coords_buffer!(MultiPointArray);
+impl_coords_buffer!(MultiLineStringArray);
+impl_coords_buffer!(MultiPolygonArray);
+
+macro_rules! impl_geom_offsets {
+    ($struct_name:ty) => {
+        #[pymethods]
+        impl $struct_name {
+            /// The offsets of each geometry into its parts, as a read-only int32 numpy view onto
+            /// the Arrow buffer. Its length is one more than the length of the array.
+            fn geom_offsets<'py>(&self, py: Python<'py>) -> PyResult<&'py PyArray1<i32>> {
+                offsets_view(py, self.0.geom_offsets())
+            }
+        }
+    };
+}
+
+impl_geom_offsets!(LineStringArray);
+impl_geom_offsets!(PolygonArray);
+impl_geom_offsets!(MultiPointArray);
+impl_geom_offsets!(MultiLineStringArray);
+impl_geom_offsets!(MultiPolygonArray);
+
+macro_rules! impl_ring_offsets {
+    ($struct_name:ty) => {
+        #[pymethods]
+        impl $struct_name {
+            /// The offsets of each ring or line string into the coordinates, as a read-only int32
+            /// numpy view onto the Arrow buffer.
+            fn ring_offsets<'py>(&self, py: Python<'py>) -> PyResult<&'py PyArray1<i32>> {
+                offsets_view(py, self.0.ring_offsets())
+            }
+        }
+    };
+}
+
+impl_ring_offsets!(PolygonArray);
+impl_ring_offsets!(MultiLineStringArray);
+impl_ring_offsets!(MultiPolygonArray);
+
+#[pymethods]
+impl MultiPolygonArray {
+    /// The offsets of each polygon into its rings, as a read-only int32 numpy view onto the
+    /// Arrow buffer.
+    fn polygon_offsets<'py>(&self, py: Python<'py>) -> PyResult<&'py PyArray1<i32>> {
+        offsets_view(py, self.0.polygon_offsets())
+    }
+}
+
+#[pymethods]
+impl LineStringArray {
+    /// Export this array in the binary format accepted by deck.gl's `PathLayer`.
+    ///
+    /// The coordinates and start indices are views onto this array's buffers where possible.
+    /// Separated coordinates are copied, as with `coords_buffer`.
+    ///
+    /// Args:
+    ///     attributes: a mapping from accessor name, e.g. `getColor`, to a numpy array with one
+    ///         row per geometry.
+    ///
+    /// Returns:
+    ///     A dict with `length`, `startIndices` and `attributes`, including `getPath`.
+    #[pyo3(signature = (attributes = None))]
+    fn to_deckgl_binary<'py>(
+        &self,
+        py: Python<'py>,
+        attributes: Option<&'py PyDict>,
+    ) -> PyGeoArrowResult<&'py PyDict> {
+        deckgl_binary(
+            py,
+            Some(offsets_view(py, self.0.geom_offsets())?.to_object(py)),
+            "getPath",
+            coords_view(py, self.0.coords())?,
+            attributes,
+            self.0.len(),
+            None,
+        )
+    }
+}
+
+#[pymethods]
+impl MultiLineStringArray {
+    /// Export this array in the binary format accepted by deck.gl's `PathLayer`.
+    ///
+    /// Each line string becomes its own path, and attribute values are repeated for each path
+    /// of a geometry.
+    ///
+    /// Args:
+    ///     attributes: a mapping from accessor name, e.g. `getColor`, to a numpy array with one
+    ///         row per geometry.
+    ///
+    /// Returns:
+    ///     A dict with `length`, `startIndices` and `attributes`, including `getPath`.
+    #[pyo3(signature = (attributes = None))]
+    fn to_deckgl_binary<'py>(
+        &self,
+        py: Python<'py>,
+        attributes: Option<&'py PyDict>,
+    ) -> PyGeoArrowResult<&'py PyDict> {
+        deckgl_binary(
+            py,
+            Some(offsets_view(py, self.0.ring_offsets())?.to_object(py)),
+            "getPath",
+            coords_view(py, self.0.coords())?,
+            attributes,
+            self.0.len(),
+            Some(part_counts(self.0.geom_offsets())),
+        )
+    }
+}
+
+#[pymethods]
+impl PolygonArray {
+    /// Export this array in the binary format accepted by deck.gl's `SolidPolygonLayer`.
+    ///
+    /// Polygons with interior rings are not supported.
+    ///
+    /// Args:
+    ///     attributes: a mapping from accessor name, e.g. `getFillColor`, to a numpy array with
+    ///         one row per geometry.
+    ///
+    /// Returns:
+    ///     A dict with `length`, `startIndices` and `attributes`, including `getPolygon`.
+    #[pyo3(signature = (attributes = None))]
+    fn to_deckgl_binary<'py>(
+        &self,
+        py: Python<'py>,
+        attributes: Option<&'py PyDict>,
+    ) -> PyGeoArrowResult<&'py PyDict> {
+        check_no_holes(self.0.geom_offsets())?;
+        let start_indices = exterior_start_indices(self.0.geom_offsets(), self.0.ring_offsets())
+            .to_pyarray(py)
+            .to_object(py);
+        deckgl_binary(
+            py,
+            Some(start_indices),
+            "getPolygon",
+            coords_view(py, self.0.coords())?,
+            attributes,
+            self.0.len(),
+            None,
+        )
+    }
+}
+
+#[pymethods]
+impl MultiPolygonArray {
+    /// Export this array in the binary format accepted by deck.gl's `SolidPolygonLayer`.
+    ///
+    /// Each polygon becomes its own feature, and attribute values are repeated for each polygon
+    /// of a geometry. Polygons with interior rings are not supported.
+    ///
+    /// Args:
+    ///     attributes: a mapping from accessor name, e.g. `getFillColor`, to a numpy array with
+    ///         one row per geometry.
+    ///
+    /// Returns:
+    ///     A dict with `length`, `startIndices` and `attributes`, including `getPolygon`.
+    #[pyo3(signature = (attributes = None))]
+    fn to_deckgl_binary<'py>(
+        &self,
+        py: Python<'py>,
+        attributes: Option<&'py PyDict>,
+    ) -> PyGeoArrowResult<&'py PyDict> {
+        check_no_holes(self.0.polygon_offsets())?;
+        let start_indices = exterior_start_indices(self.0.polygon_offsets(), self.0.ring_offsets())
+            .to_pyarray(py)
+            .to_object(py);
+        deckgl_binary(
+            py,
+            Some(start_indices),
+            "getPolygon",
+            coords_view(py, self.0.coords())?,
+            attributes,
+            self.0.len(),
+            Some(part_counts(self.0.geom_offsets())),
+        )
+    }
+}
+
+#[pymethods]
+impl MultiPointArray {
+    /// Export this array in the binary format accepted by deck.gl's `ScatterplotLayer`.
+    ///
+    /// Each point becomes its own feature, and attribute values are repeated for each point of
+    /// a geometry.
+    ///
+    /// Args:
+    ///     attributes: a mapping from accessor name, e.g. `getFillColor`, to a numpy array with
+    ///         one row per geometry.
+    ///
+    /// Returns:
+    ///     A dict with `length` and `attributes`, including `getPosition`.
+    #[pyo3(signature = (attributes = None))]
+    fn to_deckgl_binary<'py>(
+        &self,
+        py: Python<'py>,
+        attributes: Option<&'py PyDict>,
+    ) -> PyGeoArrowResult<&'py PyDict> {
+        deckgl_binary(
+            py,
+            None,
+            "getPosition",
+            coords_view(py, self.0.coords())?,
+            attributes,
+            self.0.len(),
+            Some(part_counts(self.0.geom_offsets())),
+        )
+    }
+}
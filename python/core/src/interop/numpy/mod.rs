@@ -1,2 +1,3 @@
+pub mod buffers;
 pub mod from_numpy;
 pub mod to_numpy;
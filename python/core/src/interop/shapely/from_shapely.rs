@@ -443,7 +443,7 @@ macro_rules! impl_chunked_from_shapely {
                     chunks.push(<$py_array_struct>::from_shapely(_cls, py, input_slice)?.0);
                 }
 
-                Ok(geoarrow::chunked_array::ChunkedGeometryArray::new(chunks).into())
+                Ok(geoarrow::chunked_array::ChunkedGeometryArray::new_unchecked(chunks).into())
             }
         }
     };
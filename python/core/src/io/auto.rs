@@ -0,0 +1,130 @@
+use std::path::PathBuf;
+
+use crate::error::PyGeoArrowResult;
+use crate::io::input::{construct_reader, FileReader};
+use crate::table::GeoTable;
+use geoarrow::io::detect::{read_auto_with_options, write_auto, AutoReaderOptions, Format};
+use pyo3::exceptions::{PyIOError, PyUserWarning};
+use pyo3::prelude::*;
+
+/// Read a file from a path on disk or an HTTP(S) url into a GeoTable, detecting its format.
+///
+/// This is intended to be similar to the [`read_file`][geopandas.read_file] function in
+/// GeoPandas. The format is chosen from the file extension, or from the content of the file if
+/// the path has no extension. Files with a magic number, such as FlatGeobuf, GeoParquet and Arrow
+/// IPC files, are always read as that format.
+///
+/// Supported extensions are `.geojson` and `.json`, `.geojsonl`, `.geojsonseq`, `.jsonl` and
+/// `.ndjson` for newline-delimited GeoJSON, `.fgb`, `.parquet`, `.arrow` and `.feather`,
+/// `.arrows` for Arrow IPC streams, `.wkb` and `.csv`, where the WKT geometry column must be
+/// named `geometry`. GeoPackage files and zipped Shapefiles are not supported; use
+/// [`read_pyogrio`][geoarrow.rust.core.read_pyogrio] to read them.
+///
+/// Example:
+///
+/// ```py
+/// from geoarrow.rust.core import read_file
+/// table = read_file("path/to/file.fgb", bbox=(0, 40, 10, 50))
+/// ```
+///
+/// Args:
+///     path_or_url: the path to the file or an HTTP(S) url.
+///
+/// Other args:
+///     bbox: A spatial filter for reading rows, of the format (minx, miny, maxx, maxy). This is
+///         only supported by FlatGeobuf and GeoParquet files, and ignored with a warning for other
+///         formats. For GeoParquet files, row groups outside of the bbox are skipped, but rows
+///         within the remaining row groups are not filtered.
+///     columns: the names of the columns to read. The geometry column is always read. By default
+///         all columns are read.
+///     layer: the layer to read. None of the supported formats have multiple layers, so this is
+///         ignored with a warning.
+///
+/// Returns:
+///     Table from the file.
+#[pyfunction]
+#[pyo3(signature = (path_or_url, *, bbox=None, columns=None, layer=None))]
+pub fn read_file(
+    py: Python,
+    path_or_url: PathBuf,
+    bbox: Option<(f64, f64, f64, f64)>,
+    columns: Option<Vec<String>>,
+    layer: Option<PyObject>,
+) -> PyGeoArrowResult<GeoTable> {
+    let path = path_or_url.to_string_lossy().to_string();
+    let hint = Format::from_extension(&path)?;
+    let bytes = read_bytes(py, &path)?;
+
+    let options = AutoReaderOptions::default()
+        .with_bbox(bbox.map(|(xmin, ymin, xmax, ymax)| [xmin, ymin, xmax, ymax]))
+        .with_columns(columns);
+    let (format, table) = py.allow_threads(|| read_auto_with_options(&bytes, hint, options))?;
+
+    if bbox.is_some() && !format.supports_bbox() {
+        let message = format!("bbox is not supported for {} files and was ignored", format);
+        PyErr::warn(py, py.get_type::<PyUserWarning>(), &message, 1)?;
+    }
+    if layer.is_some() {
+        let message = format!("{} files have a single layer, so layer was ignored", format);
+        PyErr::warn(py, py.get_type::<PyUserWarning>(), &message, 1)?;
+    }
+
+    Ok(GeoTable(table))
+}
+
+/// Read the whole file at a path or HTTP(S) url.
+fn read_bytes(py: Python, path: &str) -> PyGeoArrowResult<Vec<u8>> {
+    if path.starts_with("http") {
+        if let FileReader::Async(reader) = construct_reader(py, path.into_py(py), None)? {
+            let bytes = reader
+                .runtime
+                .block_on(async { reader.store.get(&reader.path).await?.bytes().await })?;
+            return Ok(bytes.to_vec());
+        }
+    }
+
+    Ok(std::fs::read(path).map_err(|err| PyIOError::new_err(format!("{}: {}", path, err)))?)
+}
+
+#[pymethods]
+impl GeoTable {
+    /// Write this table to a file on disk.
+    ///
+    /// This is intended to be similar to the [`to_file`][geopandas.GeoDataFrame.to_file] method
+    /// in GeoPandas.
+    ///
+    /// Args:
+    ///     path: the path to the file.
+    ///
+    /// Other args:
+    ///     driver: the name of the format to write, such as `"GeoJSON"`, `"GeoJSONSeq"`,
+    ///         `"FlatGeobuf"`, `"Parquet"`, `"Arrow"` or `"CSV"`, ignoring case. By default the
+    ///         format is chosen from the extension of `path`, with the same extensions as
+    ///         [`read_file`][geoarrow.rust.core.read_file].
+    ///
+    /// Returns:
+    ///     None
+    #[pyo3(signature = (path, *, driver=None))]
+    pub fn to_file(
+        &self,
+        py: Python,
+        path: PathBuf,
+        driver: Option<String>,
+    ) -> PyGeoArrowResult<()> {
+        let path_str = path.to_string_lossy();
+        let format = match driver {
+            Some(driver) => driver.parse::<Format>()?,
+            None => Format::from_extension(&path_str)?.ok_or_else(|| {
+                PyIOError::new_err(format!(
+                    "Cannot infer the format of {} without a file extension; pass driver",
+                    path_str
+                ))
+            })?,
+        };
+
+        let mut output = vec![];
+        py.allow_threads(|| write_auto(&self.0, format, &mut output))?;
+        Ok(std::fs::write(&path, output)
+            .map_err(|err| PyIOError::new_err(format!("{}: {}", path_str, err)))?)
+    }
+}
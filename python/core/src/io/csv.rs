@@ -3,7 +3,7 @@ use crate::io::input::sync::{BinaryFileReader, BinaryFileWriter};
 use crate::table::GeoTable;
 use geoarrow::io::csv::read_csv as _read_csv;
 use geoarrow::io::csv::write_csv as _write_csv;
-use geoarrow::io::csv::CSVReaderOptions;
+use geoarrow::io::csv::{CSVReaderOptions, CSVWriterOptions};
 use pyo3::prelude::*;
 
 /// Read a CSV file from a path on disk into a GeoTable.
@@ -24,7 +24,7 @@ pub fn read_csv(
     batch_size: usize,
 ) -> PyGeoArrowResult<GeoTable> {
     let mut reader = file.extract::<BinaryFileReader>(py)?;
-    let options = CSVReaderOptions::new(Default::default(), batch_size);
+    let options = CSVReaderOptions::default().with_batch_size(batch_size);
     let table = _read_csv(&mut reader, geometry_column_name, options)?;
     Ok(GeoTable(table))
 }
@@ -34,13 +34,26 @@ pub fn read_csv(
 /// Args:
 ///     table: the table to write.
 ///     file: the path to the file or a Python file object in binary write mode.
+///     geometry_column_name: the name of the WKT column of the geometry column. Defaults to the
+///         name of the geometry column.
+///     coordinate_precision: the number of decimal places of WKT coordinates. By default,
+///         coordinates are written with the fewest digits that round-trip.
 ///
 /// Returns:
 ///     None
 #[pyfunction]
-#[pyo3(signature = (table, file))]
-pub fn write_csv(py: Python, mut table: GeoTable, file: PyObject) -> PyGeoArrowResult<()> {
+#[pyo3(signature = (table, file, *, geometry_column_name=None, coordinate_precision=None))]
+pub fn write_csv(
+    py: Python,
+    table: GeoTable,
+    file: PyObject,
+    geometry_column_name: Option<String>,
+    coordinate_precision: Option<usize>,
+) -> PyGeoArrowResult<()> {
     let writer = file.extract::<BinaryFileWriter>(py)?;
-    _write_csv(&mut table.0, writer)?;
+    let mut options = CSVWriterOptions::default();
+    options.geometry_column_name = geometry_column_name;
+    options.coordinate_precision = coordinate_precision;
+    _write_csv(&table.0, writer, options)?;
     Ok(())
 }
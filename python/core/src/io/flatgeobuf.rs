@@ -3,10 +3,11 @@ use crate::io::input::sync::BinaryFileWriter;
 use crate::io::input::{construct_reader, FileReader};
 use crate::io::object_store::PyObjectStore;
 use crate::table::GeoTable;
-use flatgeobuf::FgbWriterOptions;
 use geoarrow::io::flatgeobuf::read_flatgeobuf_async as _read_flatgeobuf_async;
-use geoarrow::io::flatgeobuf::write_flatgeobuf_with_options as _write_flatgeobuf;
-use geoarrow::io::flatgeobuf::{read_flatgeobuf as _read_flatgeobuf, FlatGeobufReaderOptions};
+use geoarrow::io::flatgeobuf::write_flatgeobuf as _write_flatgeobuf;
+use geoarrow::io::flatgeobuf::{
+    read_flatgeobuf as _read_flatgeobuf, FlatGeobufReaderOptions, FlatGeobufWriterOptions,
+};
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 
@@ -77,11 +78,8 @@ pub fn read_flatgeobuf(
     let reader = construct_reader(py, file, fs)?;
     match reader {
         FileReader::Async(async_reader) => async_reader.runtime.block_on(async move {
-            let options = FlatGeobufReaderOptions {
-                batch_size: Some(batch_size),
-                bbox,
-                ..Default::default()
-            };
+            let mut options = FlatGeobufReaderOptions::default().with_batch_size(batch_size);
+            options.bbox = bbox;
             let table = _read_flatgeobuf_async(async_reader.store, async_reader.path, options)
                 .await
                 .map_err(PyGeoArrowError::GeoArrowError)?;
@@ -89,11 +87,8 @@ pub fn read_flatgeobuf(
             Ok(GeoTable(table))
         }),
         FileReader::Sync(mut sync_reader) => {
-            let options = FlatGeobufReaderOptions {
-                batch_size: Some(batch_size),
-                bbox,
-                ..Default::default()
-            };
+            let mut options = FlatGeobufReaderOptions::default().with_batch_size(batch_size);
+            options.bbox = bbox;
             let table = _read_flatgeobuf(&mut sync_reader, options)?;
             Ok(GeoTable(table))
         }
@@ -153,11 +148,8 @@ pub fn read_flatgeobuf_async(
     match reader {
         FileReader::Async(async_reader) => {
             let fut = pyo3_asyncio::tokio::future_into_py(py, async move {
-                let options = FlatGeobufReaderOptions {
-                    batch_size: Some(batch_size),
-                    bbox,
-                    ..Default::default()
-                };
+                let mut options = FlatGeobufReaderOptions::default().with_batch_size(batch_size);
+                options.bbox = bbox;
                 let table = _read_flatgeobuf_async(async_reader.store, async_reader.path, options)
                     .await
                     .map_err(PyGeoArrowError::GeoArrowError)?;
@@ -184,17 +176,16 @@ pub fn read_flatgeobuf_async(
 #[pyo3(signature = (table, file, *, write_index=true))]
 pub fn write_flatgeobuf(
     py: Python,
-    mut table: GeoTable,
+    table: GeoTable,
     file: PyObject,
     write_index: bool,
 ) -> PyGeoArrowResult<()> {
     let writer = file.extract::<BinaryFileWriter>(py)?;
     let name = writer.file_stem(py);
 
-    let options = FgbWriterOptions {
-        write_index,
-        ..Default::default()
-    };
-    _write_flatgeobuf(&mut table.0, writer, name.as_deref().unwrap_or(""), options)?;
+    let options = FlatGeobufWriterOptions::default()
+        .with_name(name.unwrap_or_default())
+        .with_write_index(write_index);
+    _write_flatgeobuf(&table.0, writer, options)?;
     Ok(())
 }
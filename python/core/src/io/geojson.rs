@@ -3,6 +3,8 @@ use crate::io::input::sync::{BinaryFileReader, BinaryFileWriter};
 use crate::table::GeoTable;
 use geoarrow::io::geojson::read_geojson as _read_geojson;
 use geoarrow::io::geojson::write_geojson as _write_geojson;
+use geoarrow::io::geojson::{GeoJsonReaderOptions, GeoJsonWriterOptions};
+use geoarrow::io::guards::ParseGuards;
 use pyo3::prelude::*;
 
 /// Read a GeoJSON file from a path on disk into a GeoTable.
@@ -10,14 +12,42 @@ use pyo3::prelude::*;
 /// Args:
 ///     file: the path to the file or a Python file object in binary read mode.
 ///     batch_size: the number of rows to include in each internal batch of the table.
+///     max_coords_per_geometry: the maximum number of coordinates in a single geometry. By
+///         default there is no limit.
+///     max_total_coords: the maximum number of coordinates in all geometries. By default there
+///         is no limit.
+///     max_nesting_depth: the maximum nesting depth of a geometry, which is 1 for a Point,
+///         LineString or Polygon, 2 for a multi geometry and one more for each level of
+///         GeometryCollection. By default there is no limit.
 ///
 /// Returns:
 ///     Table from GeoJSON file.
 #[pyfunction]
-#[pyo3(signature = (file, *, batch_size=65536))]
-pub fn read_geojson(py: Python, file: PyObject, batch_size: usize) -> PyGeoArrowResult<GeoTable> {
+#[pyo3(signature = (
+    file,
+    *,
+    batch_size=65536,
+    max_coords_per_geometry=None,
+    max_total_coords=None,
+    max_nesting_depth=None
+))]
+pub fn read_geojson(
+    py: Python,
+    file: PyObject,
+    batch_size: usize,
+    max_coords_per_geometry: Option<usize>,
+    max_total_coords: Option<usize>,
+    max_nesting_depth: Option<usize>,
+) -> PyGeoArrowResult<GeoTable> {
     let mut reader = file.extract::<BinaryFileReader>(py)?;
-    let table = _read_geojson(&mut reader, Some(batch_size))?;
+    let options = GeoJsonReaderOptions::default()
+        .with_batch_size(batch_size)
+        .with_parse_guards(ParseGuards {
+            max_coords_per_geometry,
+            max_total_coords,
+            max_nesting_depth,
+        });
+    let table = _read_geojson(&mut reader, options)?;
     Ok(GeoTable(table))
 }
 
@@ -29,12 +59,24 @@ pub fn read_geojson(py: Python, file: PyObject, batch_size: usize) -> PyGeoArrow
 /// Args:
 ///     table: the table to write.
 ///     file: the path to the file or a Python file object in binary write mode.
+///     coord_precision: the number of decimal places to write for each coordinate. If `None`,
+///         coordinates are written with full precision.
+///     bbox: whether to write the total bounds of the table as a top-level `bbox` member.
 ///
 /// Returns:
 ///     None
 #[pyfunction]
-pub fn write_geojson(py: Python, mut table: GeoTable, file: PyObject) -> PyGeoArrowResult<()> {
+#[pyo3(signature = (table, file, *, coord_precision=None, bbox=false))]
+pub fn write_geojson(
+    py: Python,
+    table: GeoTable,
+    file: PyObject,
+    coord_precision: Option<usize>,
+    bbox: bool,
+) -> PyGeoArrowResult<()> {
     let writer = file.extract::<BinaryFileWriter>(py)?;
-    _write_geojson(&mut table.0, writer)?;
+    let mut options = GeoJsonWriterOptions::default().with_bbox(bbox);
+    options.coord_precision = coord_precision;
+    _write_geojson(&table.0, writer, options)?;
     Ok(())
 }
@@ -3,6 +3,8 @@ use crate::io::input::sync::{BinaryFileReader, BinaryFileWriter};
 use crate::table::GeoTable;
 use geoarrow::io::geojson_lines::read_geojson_lines as _read_geojson_lines;
 use geoarrow::io::geojson_lines::write_geojson_lines as _write_geojson_lines;
+use geoarrow::io::geojson_lines::GeoJsonLinesReaderOptions;
+use geoarrow::io::guards::ParseGuards;
 use pyo3::prelude::*;
 
 /// Read a newline-delimited GeoJSON file from a path on disk into a GeoTable.
@@ -12,18 +14,48 @@ use pyo3::prelude::*;
 ///
 /// Args:
 ///     file: the path to the file or a Python file object in binary read mode.
+///     batch_size: the number of rows to include in each internal batch of the table.
+///     schema_infer_max_records: the number of features to scan when inferring the schema of
+///         the feature properties.
+///     max_coords_per_geometry: the maximum number of coordinates in a single geometry. By
+///         default there is no limit.
+///     max_total_coords: the maximum number of coordinates in all geometries. By default there
+///         is no limit.
+///     max_nesting_depth: the maximum nesting depth of a geometry, which is 1 for a Point,
+///         LineString or Polygon, 2 for a multi geometry and one more for each level of
+///         GeometryCollection. By default there is no limit.
 ///
 /// Returns:
 ///     Table from GeoJSON file.
 #[pyfunction]
-#[pyo3(signature = (file, *, batch_size=65536))]
+#[pyo3(signature = (
+    file,
+    *,
+    batch_size=65536,
+    schema_infer_max_records=1000,
+    max_coords_per_geometry=None,
+    max_total_coords=None,
+    max_nesting_depth=None
+))]
 pub fn read_geojson_lines(
     py: Python,
     file: PyObject,
     batch_size: usize,
+    schema_infer_max_records: usize,
+    max_coords_per_geometry: Option<usize>,
+    max_total_coords: Option<usize>,
+    max_nesting_depth: Option<usize>,
 ) -> PyGeoArrowResult<GeoTable> {
     let mut reader = file.extract::<BinaryFileReader>(py)?;
-    let table = _read_geojson_lines(&mut reader, Some(batch_size))?;
+    let options = GeoJsonLinesReaderOptions::default()
+        .with_batch_size(batch_size)
+        .with_schema_infer_max_records(schema_infer_max_records)
+        .with_parse_guards(ParseGuards {
+            max_coords_per_geometry,
+            max_total_coords,
+            max_nesting_depth,
+        });
+    let table = _read_geojson_lines(&mut reader, options)?;
     Ok(GeoTable(table))
 }
 
@@ -39,12 +71,8 @@ pub fn read_geojson_lines(
 /// Returns:
 ///     None
 #[pyfunction]
-pub fn write_geojson_lines(
-    py: Python,
-    mut table: GeoTable,
-    file: PyObject,
-) -> PyGeoArrowResult<()> {
+pub fn write_geojson_lines(py: Python, table: GeoTable, file: PyObject) -> PyGeoArrowResult<()> {
     let writer = file.extract::<BinaryFileWriter>(py)?;
-    _write_geojson_lines(&mut table.0, writer)?;
+    _write_geojson_lines(&table.0, writer)?;
     Ok(())
 }
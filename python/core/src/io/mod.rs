@@ -1,5 +1,6 @@
 //! Read and write to geospatial file formats.
 
+pub mod auto;
 pub mod csv;
 pub mod ewkb;
 pub mod flatgeobuf;
@@ -80,10 +80,7 @@ pub fn read_parquet(
                     .map_err(PyGeoArrowError::ObjectStoreError)?;
                 let reader = ParquetObjectReader::new(async_reader.store, object_meta);
 
-                let options = GeoParquetReaderOptions {
-                    batch_size,
-                    ..Default::default()
-                };
+                let options = GeoParquetReaderOptions::default().with_batch_size(batch_size);
                 let table = _read_geoparquet_async(reader, options)
                     .await
                     .map_err(PyGeoArrowError::GeoArrowError)?;
@@ -97,10 +94,7 @@ pub fn read_parquet(
                 let file = File::open(path)
                     .map_err(|err| PyFileNotFoundError::new_err(err.to_string()))?;
 
-                let options = GeoParquetReaderOptions {
-                    batch_size,
-                    ..Default::default()
-                };
+                let options = GeoParquetReaderOptions::default().with_batch_size(batch_size);
                 let table = _read_geoparquet(file, options)?;
                 Ok(GeoTable(table))
             }
@@ -161,10 +155,7 @@ pub fn read_parquet_async(
                     .map_err(PyGeoArrowError::ObjectStoreError)?;
                 let reader = ParquetObjectReader::new(async_reader.store, object_meta);
 
-                let options = GeoParquetReaderOptions {
-                    batch_size,
-                    ..Default::default()
-                };
+                let options = GeoParquetReaderOptions::default().with_batch_size(batch_size);
                 let table = _read_geoparquet_async(reader, options)
                     .await
                     .map_err(PyGeoArrowError::GeoArrowError)?;
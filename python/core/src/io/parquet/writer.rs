@@ -47,26 +47,30 @@ impl From<GeoParquetEncoding> for geoarrow::io::parquet::GeoParquetWriterEncodin
 ///     table: the table to write.
 ///     file: the path to the file or a Python file object in binary write mode.
 ///
+/// Other args:
+///     encoding: the geometry encoding to use. One of "WKB" or "native".
+///     writer_threads: the number of threads used to encode row groups. By default, row groups
+///         are encoded on the calling thread.
+///
 /// Returns:
 ///     None
 #[pyfunction]
 #[pyo3(
-    signature = (table, file, *, encoding = GeoParquetEncoding::WKB),
-    text_signature = "(table, file, *, encoding = 'WKB')")
+    signature = (table, file, *, encoding = GeoParquetEncoding::WKB, writer_threads = None),
+    text_signature = "(table, file, *, encoding = 'WKB', writer_threads = None)")
 ]
 pub fn write_parquet(
-    mut table: GeoTable,
+    table: GeoTable,
     file: String,
     encoding: GeoParquetEncoding,
+    writer_threads: Option<usize>,
 ) -> PyGeoArrowResult<()> {
     let writer = BufWriter::new(
         File::create(file).map_err(|err| PyFileNotFoundError::new_err(err.to_string()))?,
     );
-    let options = GeoParquetWriterOptions {
-        encoding: encoding.into(),
-        ..Default::default()
-    };
-    _write_geoparquet(&mut table.0, writer, &options)?;
+    let mut options = GeoParquetWriterOptions::default().with_encoding(encoding.into());
+    options.writer_threads = writer_threads;
+    _write_geoparquet(&table.0, writer, &options)?;
     Ok(())
 }
 
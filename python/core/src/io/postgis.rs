@@ -22,7 +22,7 @@ pub fn read_postgis(connection_url: String, sql: String) -> PyGeoArrowResult<Opt
                 .await
                 .map_err(|err| PyGeoArrowError::GeoArrowError(GeoArrowError::SqlxError(err)))?;
 
-            let table = _read_postgis(&pool, &sql)
+            let table = _read_postgis(&pool, &sql, Default::default())
                 .await
                 .map_err(PyGeoArrowError::GeoArrowError)?;
 
@@ -46,7 +46,7 @@ pub fn read_postgis_async(
             .await
             .map_err(|err| PyGeoArrowError::GeoArrowError(GeoArrowError::SqlxError(err)))?;
 
-        let table = _read_postgis(&pool, &sql)
+        let table = _read_postgis(&pool, &sql, Default::default())
             .await
             .map_err(PyGeoArrowError::GeoArrowError)?;
 
@@ -3,6 +3,7 @@ use std::sync::Arc;
 use geoarrow::array::{AsGeometryArray, CoordType};
 use geoarrow::datatypes::GeoDataType;
 use geoarrow::error::GeoArrowError;
+use geoarrow::io::guards::ParseGuards;
 use geoarrow::io::wkb::{to_wkb as _to_wkb, FromWKB};
 use geoarrow::GeometryArrayTrait;
 use pyo3::exceptions::PyTypeError;
@@ -18,20 +19,50 @@ use crate::ffi::to_python::geometry_array_to_pyobject;
 ///
 /// This expects ISO-formatted WKB geometries.
 ///
+/// For untrusted input, the size of the geometries can be limited. The limits are checked
+/// against the sizes declared in the WKB before any geometry is parsed, and exceeding one raises
+/// an error naming the limit and the row.
+///
 /// Args:
 ///     input: An Arrow array of Binary type holding WKB-formatted geometries.
+///     max_coords_per_geometry: the maximum number of coordinates in a single geometry. By
+///         default there is no limit.
+///     max_total_coords: the maximum number of coordinates in all geometries. By default there
+///         is no limit.
+///     max_nesting_depth: the maximum nesting depth of a geometry, which is 1 for a Point,
+///         LineString or Polygon, 2 for a multi geometry and one more for each level of
+///         GeometryCollection. By default there is no limit.
 ///
 /// Returns:
 ///     A GeoArrow-native geometry array
 #[pyfunction]
-pub fn from_wkb(input: AnyGeometryInput) -> PyGeoArrowResult<PyObject> {
+#[pyo3(signature = (
+    input,
+    *,
+    max_coords_per_geometry=None,
+    max_total_coords=None,
+    max_nesting_depth=None
+))]
+pub fn from_wkb(
+    input: AnyGeometryInput,
+    max_coords_per_geometry: Option<usize>,
+    max_total_coords: Option<usize>,
+    max_nesting_depth: Option<usize>,
+) -> PyGeoArrowResult<PyObject> {
+    let guards = ParseGuards {
+        max_coords_per_geometry,
+        max_total_coords,
+        max_nesting_depth,
+    };
     match input {
         AnyGeometryInput::Array(arr) => {
             let geo_array: Arc<dyn GeometryArrayTrait> = match arr.data_type() {
                 GeoDataType::WKB => {
+                    guards.check_wkb(arr.as_ref().as_wkb())?;
                     FromWKB::from_wkb(arr.as_ref().as_wkb(), CoordType::Interleaved)?
                 }
                 GeoDataType::LargeWKB => {
+                    guards.check_wkb(arr.as_ref().as_large_wkb())?;
                     FromWKB::from_wkb(arr.as_ref().as_large_wkb(), CoordType::Interleaved)?
                 }
                 other => {
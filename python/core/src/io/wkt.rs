@@ -2,8 +2,12 @@ use std::sync::Arc;
 
 use arrow::datatypes::DataType;
 use arrow_array::cast::AsArray;
+use geoarrow::algorithm::native::Downcast;
 use geoarrow::array::CoordType;
+use geoarrow::datatypes::GeoDataType;
 use geoarrow::io::geozero::FromWKT;
+use geoarrow::io::guards::ParseGuards;
+use geoarrow::io::wkt::from_wkt as _from_wkt;
 use geoarrow::GeometryArrayTrait;
 use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
@@ -16,14 +20,57 @@ use crate::ffi::to_python::geometry_array_to_pyobject;
 
 /// Parse an Arrow StringArray from WKT to its GeoArrow-native counterpart.
 ///
+/// For untrusted input, the size of the geometries can be limited. Exceeding a limit raises an
+/// error naming the limit and the row.
+///
 /// Args:
 ///     input: An Arrow array of string type holding WKT-formatted geometries.
+///     max_coords_per_geometry: the maximum number of coordinates in a single geometry. By
+///         default there is no limit.
+///     max_total_coords: the maximum number of coordinates in all geometries. By default there
+///         is no limit.
+///     max_nesting_depth: the maximum nesting depth of a geometry, which is 1 for a Point,
+///         LineString or Polygon, 2 for a multi geometry and one more for each level of
+///         GeometryCollection. By default there is no limit.
 ///
 /// Returns:
 ///     A GeoArrow-native geometry array
 #[pyfunction]
-pub fn from_wkt(input: &PyAny) -> PyGeoArrowResult<PyObject> {
+#[pyo3(signature = (
+    input,
+    *,
+    max_coords_per_geometry=None,
+    max_total_coords=None,
+    max_nesting_depth=None
+))]
+pub fn from_wkt(
+    input: &PyAny,
+    max_coords_per_geometry: Option<usize>,
+    max_total_coords: Option<usize>,
+    max_nesting_depth: Option<usize>,
+) -> PyGeoArrowResult<PyObject> {
     let (array, _field) = import_arrow_c_array(input)?;
+    let guards = ParseGuards {
+        max_coords_per_geometry,
+        max_total_coords,
+        max_nesting_depth,
+    };
+    if !guards.is_unlimited() {
+        // The same geometry type as FromWKT, which can't check the limits
+        let data_type = GeoDataType::LargeGeometryCollection(CoordType::Interleaved);
+        let geo_array = match array.data_type() {
+            DataType::Utf8 => _from_wkt(array.as_string::<i32>(), data_type, guards)?,
+            DataType::LargeUtf8 => _from_wkt(array.as_string::<i64>(), data_type, guards)?,
+            other => {
+                return Err(
+                    PyTypeError::new_err(format!("Unexpected array type {:?}", other)).into(),
+                )
+            }
+        };
+        let geo_array = geo_array.as_ref().downcast(true, false);
+        return Python::with_gil(|py| geometry_array_to_pyobject(py, geo_array));
+    }
+
     let geo_array: Arc<dyn GeometryArrayTrait> = match array.data_type() {
         DataType::Utf8 => FromWKT::from_wkt(
             array.as_string::<i32>(),
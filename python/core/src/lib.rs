@@ -11,6 +11,7 @@ pub mod record_batch;
 pub mod scalar;
 pub mod schema;
 pub mod table;
+pub mod validate;
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
@@ -118,10 +119,30 @@ fn _rust(_py: Python, m: &PyModule) -> PyResult<()> {
         crate::algorithm::geo::convex_hull::convex_hull,
         m
     )?)?;
+    m.add_function(wrap_pyfunction!(
+        crate::algorithm::geo::boolean_ops::difference,
+        m
+    )?)?;
+    m.add_function(wrap_pyfunction!(
+        crate::algorithm::geo::boolean_ops::intersection,
+        m
+    )?)?;
+    m.add_function(wrap_pyfunction!(
+        crate::algorithm::geo::boolean_ops::symmetric_difference,
+        m
+    )?)?;
+    m.add_function(wrap_pyfunction!(
+        crate::algorithm::geo::boolean_ops::union,
+        m
+    )?)?;
     m.add_function(wrap_pyfunction!(
         crate::algorithm::geo::densify::densify,
         m
     )?)?;
+    m.add_function(wrap_pyfunction!(
+        crate::algorithm::geo::densify::densify_haversine,
+        m
+    )?)?;
     m.add_function(wrap_pyfunction!(
         crate::algorithm::geo::envelope::envelope,
         m
@@ -134,10 +155,18 @@ fn _rust(_py: Python, m: &PyModule) -> PyResult<()> {
         crate::algorithm::geo::dimensions::is_empty,
         m
     )?)?;
+    m.add_function(wrap_pyfunction!(
+        crate::algorithm::geo::distance::geodesic_distance,
+        m
+    )?)?;
     m.add_function(wrap_pyfunction!(
         crate::algorithm::geo::geodesic_area::geodesic_perimeter,
         m
     )?)?;
+    m.add_function(wrap_pyfunction!(
+        crate::algorithm::geo::distance::haversine_distance,
+        m
+    )?)?;
     m.add_function(wrap_pyfunction!(crate::algorithm::geo::length::length, m)?)?;
     m.add_function(wrap_pyfunction!(
         crate::algorithm::geo::line_interpolate_point::line_interpolate_point,
@@ -151,6 +180,10 @@ fn _rust(_py: Python, m: &PyModule) -> PyResult<()> {
         crate::algorithm::geo::simplify::simplify,
         m
     )?)?;
+    m.add_function(wrap_pyfunction!(
+        crate::algorithm::capabilities::supports,
+        m
+    )?)?;
     m.add_function(wrap_pyfunction!(
         crate::algorithm::native::total_bounds::total_bounds,
         m
@@ -170,6 +203,7 @@ fn _rust(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<crate::io::parquet::reader::ParquetFile>()?;
     m.add_class::<crate::io::parquet::reader::ParquetDataset>()?;
 
+    m.add_function(wrap_pyfunction!(crate::io::auto::read_file, m)?)?;
     m.add_function(wrap_pyfunction!(crate::io::csv::read_csv, m)?)?;
     m.add_function(wrap_pyfunction!(crate::io::flatgeobuf::read_flatgeobuf, m)?)?;
     m.add_function(wrap_pyfunction!(
@@ -237,6 +271,9 @@ fn _rust(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(crate::io::wkb::to_wkb, m)?)?;
     m.add_function(wrap_pyfunction!(crate::io::wkt::from_wkt, m)?)?;
 
+    // Validation
+    m.add_function(wrap_pyfunction!(crate::validate::validate, m)?)?;
+
     // Exceptions
     // create_exception!(m, GeoArrowException, pyo3::exceptions::PyException);
     // m.add("GeoArrowException", py.get_type::<GeoArrowException>())?;
@@ -0,0 +1,79 @@
+use arrow::datatypes::{DataType, Field};
+use arrow_array::cast::AsArray;
+use arrow_array::Array;
+use geoarrow::validate::{validate_geoarrow_field, Violation};
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+
+use crate::error::PyGeoArrowResult;
+use crate::ffi::from_python::ffi_stream::ArrowArrayStreamReader;
+use crate::ffi::from_python::utils::{import_arrow_c_array, import_arrow_c_stream};
+
+/// Check that Arrow data follows the GeoArrow specification.
+///
+/// This is stricter than the readers of this library, which accept some deviations from the
+/// specification, such as misnamed child fields. It is meant for checking GeoArrow data before
+/// passing it to other implementations.
+///
+/// Args:
+///     input: an Arrow array, chunked array or table. For tables, every column with a GeoArrow
+///         extension type is checked.
+///
+/// Returns:
+///     A list of the violations found, each starting with "error" or "warning". An empty list
+///     means the data follows the specification.
+#[pyfunction]
+pub fn validate(input: &PyAny) -> PyGeoArrowResult<Vec<String>> {
+    let mut violations = vec![];
+    if input.hasattr("__arrow_c_array__")? {
+        let (array, field) = import_arrow_c_array(input)?;
+        add_violations(&mut violations, &field, array.as_ref());
+    } else if input.hasattr("__arrow_c_stream__")? {
+        let stream = import_arrow_c_stream(input)?;
+        let reader = ArrowArrayStreamReader::try_new(stream)
+            .map_err(|err| PyValueError::new_err(err.to_string()))?;
+        let field = reader.field();
+        for array in reader {
+            let array = array.map_err(|err| PyValueError::new_err(err.to_string()))?;
+            add_violations(&mut violations, &field, array.as_ref());
+        }
+    } else {
+        return Err(PyTypeError::new_err(
+            "Expected object with __arrow_c_array__ or __arrow_c_stream__ method",
+        )
+        .into());
+    }
+    Ok(violations
+        .iter()
+        .map(|violation| violation.to_string())
+        .collect())
+}
+
+/// Add the violations of a field, or of each GeoArrow column if the field is the struct of a
+/// record batch, skipping violations already found in a previous chunk.
+fn add_violations(violations: &mut Vec<Violation>, field: &Field, array: &dyn Array) {
+    let new_violations = match field.data_type() {
+        DataType::Struct(fields) if !field.metadata().contains_key("ARROW:extension:name") => {
+            fields
+                .iter()
+                .zip(array.as_struct().columns())
+                .filter(|(field, _)| is_geoarrow(field))
+                .flat_map(|(field, column)| validate_geoarrow_field(field, column.as_ref()))
+                .collect()
+        }
+        _ => validate_geoarrow_field(field, array),
+    };
+
+    for violation in new_violations {
+        if !violations.contains(&violation) {
+            violations.push(violation);
+        }
+    }
+}
+
+fn is_geoarrow(field: &Field) -> bool {
+    field
+        .metadata()
+        .get("ARROW:extension:name")
+        .is_some_and(|name| name.starts_with("geoarrow.") || name == "ogc.wkb")
+}
@@ -1,8 +1,11 @@
+import threading
+
 import geoarrow.rust.core as gars
 import geodatasets
 import geopandas as gpd
 import numpy as np
 import pyarrow as pa
+import shapely
 
 nybb_path = geodatasets.get_path("nybb")
 
@@ -18,3 +21,54 @@ def test_area():
 
     pa_area = pa.array(ga_area.chunk(0))
     assert np.allclose(shapely_area, pa_area)
+
+
+def test_area_releases_gil():
+    rng = np.random.default_rng(0)
+    centers = shapely.points(rng.uniform(-50, 50, size=(50_000, 2)))
+    polygons = shapely.buffer(centers, 1, quad_segs=16)
+    arr = gars.PolygonArray.from_shapely(polygons)
+
+    started = threading.Event()
+    done = threading.Event()
+    result = {}
+
+    def compute():
+        started.set()
+        result["area"] = gars.area(arr, method="ellipsoidal")
+        done.set()
+
+    thread = threading.Thread(target=compute)
+    thread.start()
+    started.wait()
+
+    # If the computation held the GIL, this loop could not run until it finished
+    ticks = 0
+    while not done.is_set():
+        ticks += 1
+    thread.join()
+
+    assert ticks > 1000
+
+    # Releasing the GIL must not change the results
+    expected = arr.area(method="ellipsoidal")
+    assert np.allclose(pa.array(result["area"]), pa.array(expected))
+    assert np.allclose(pa.array(gars.area(arr)), shapely.area(polygons))
+
+
+def test_area_methods():
+    # A one degree square at the equator is about 12,309 km² on the WGS84 ellipsoid
+    polygons = shapely.box([0, 0], [0, 60], [1, 1], [1, 61])
+    arr = gars.PolygonArray.from_shapely(polygons)
+
+    geodesic = np.asarray(pa.array(gars.area(arr, method="geodesic")))
+    ellipsoidal = np.asarray(pa.array(gars.area(arr, method="ellipsoidal")))
+    spherical = np.asarray(pa.array(gars.area(arr, method="spherical")))
+    euclidean = np.asarray(pa.array(gars.area(arr, method="euclidean")))
+
+    assert np.array_equal(geodesic, ellipsoidal)
+    assert np.allclose(geodesic[0], 12_309e6, rtol=1e-3)
+    # Squares of equal degrees shrink towards the poles
+    assert geodesic[1] < geodesic[0] / 1.9
+    assert np.allclose(spherical, geodesic, rtol=1e-2)
+    assert np.array_equal(euclidean, [1, 1])
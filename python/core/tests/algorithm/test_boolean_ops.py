@@ -0,0 +1,47 @@
+import geoarrow.rust.core as gars
+import numpy as np
+import pytest
+import shapely
+
+
+def areas(arr) -> np.ndarray:
+    return shapely.area(arr.to_shapely())
+
+
+def test_intersection_with_scalar():
+    parcels = gars.PolygonArray.from_shapely(
+        np.array([shapely.box(0, 0, 2, 2), shapely.box(10, 10, 11, 11)])
+    )
+    clipped = gars.intersection(parcels, shapely.box(1, 1, 3, 3))
+
+    assert isinstance(clipped, gars.MultiPolygonArray)
+    assert np.allclose(areas(clipped), [1, 0])
+    assert shapely.is_empty(clipped.to_shapely()[1])
+
+
+def test_pairwise():
+    left = gars.PolygonArray.from_shapely(
+        np.array([shapely.box(0, 0, 2, 2), shapely.box(0, 0, 1, 1)])
+    )
+    right = gars.PolygonArray.from_shapely(
+        np.array([shapely.box(1, 0, 3, 2), shapely.box(0, 0, 1, 1)])
+    )
+
+    assert np.allclose(areas(gars.union(left, right)), [6, 1])
+    assert np.allclose(areas(gars.difference(left, right)), [2, 0])
+    assert np.allclose(areas(gars.symmetric_difference(left, right)), [4, 0])
+
+
+def test_chunked():
+    polygons = np.array([shapely.box(0, 0, 2, 2), shapely.box(10, 10, 11, 11)])
+    chunked = gars.ChunkedPolygonArray.from_shapely(polygons, chunk_size=1)
+    clipped = gars.intersection(chunked, shapely.box(1, 1, 3, 3))
+
+    assert isinstance(clipped, gars.ChunkedMultiPolygonArray)
+    assert clipped.num_chunks() == 2
+
+
+def test_unsupported_type():
+    points = gars.PointArray.from_shapely(shapely.points([(0, 0)]))
+    with pytest.raises(Exception):
+        gars.intersection(points, shapely.box(0, 0, 1, 1))
@@ -0,0 +1,43 @@
+import geoarrow.rust.core as gars
+import numpy as np
+import pyarrow as pa
+import pytest
+import shapely
+
+# New York City and London
+NEW_YORK = (-74.006, 40.7128)
+LONDON = (-0.1278, 51.5074)
+
+
+def test_haversine_distance():
+    left = gars.PointArray.from_shapely(shapely.points([NEW_YORK, (0, 0)]))
+    right = gars.PointArray.from_shapely(shapely.points([LONDON, (0, 1)]))
+
+    distance = np.asarray(pa.array(gars.haversine_distance(left, right)))
+    assert np.allclose(distance, [5_570_230, 111_195], atol=1)
+
+
+def test_geodesic_distance():
+    left = gars.PointArray.from_shapely(shapely.points([NEW_YORK, (0, 0)]))
+    right = gars.PointArray.from_shapely(shapely.points([LONDON, (0, 1)]))
+
+    distance = np.asarray(pa.array(gars.geodesic_distance(left, right)))
+    assert np.allclose(distance, [5_585_234, 110_574], atol=1)
+
+
+def test_distance_to_scalar():
+    points = gars.PointArray.from_shapely(shapely.points([NEW_YORK, LONDON]))
+
+    distance = np.asarray(
+        pa.array(gars.haversine_distance(points, shapely.Point(LONDON)))
+    )
+    assert np.allclose(distance, [5_570_230, 0], atol=1)
+
+
+def test_distance_unsupported_type():
+    line_strings = gars.LineStringArray.from_shapely(
+        np.array([shapely.LineString([NEW_YORK, LONDON])])
+    )
+    points = gars.PointArray.from_shapely(shapely.points([LONDON]))
+    with pytest.raises(Exception):
+        gars.haversine_distance(line_strings, points)
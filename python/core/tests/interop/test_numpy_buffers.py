@@ -0,0 +1,141 @@
+import geoarrow.rust.core as gars
+import numpy as np
+import pyarrow as pa
+import pytest
+import shapely
+
+
+def arrow_coords(arr) -> np.ndarray:
+    """The interleaved coordinates of the Arrow export of a geometry array."""
+    pa_arr = pa.array(arr)
+    if isinstance(pa_arr, pa.ExtensionArray):
+        pa_arr = pa_arr.storage
+    while pa.types.is_list(pa_arr.type):
+        pa_arr = pa_arr.flatten()
+    return pa_arr.flatten().to_numpy(zero_copy_only=True)
+
+
+def test_line_string_buffers():
+    geoms = np.array(
+        [
+            shapely.LineString([(0, 0), (1, 1), (2, 0)]),
+            shapely.LineString([(5, 5), (6, 6)]),
+            shapely.LineString([(0, 1), (1, 2), (2, 3), (3, 4)]),
+        ]
+    )
+    arr = gars.from_shapely(geoms)
+    assert isinstance(arr, gars.LineStringArray)
+
+    coords = arr.coords_buffer()
+    assert coords.dtype == np.float64
+    assert np.array_equal(coords, shapely.get_coordinates(geoms).ravel())
+    assert np.shares_memory(coords, arrow_coords(arr))
+    assert not coords.flags.writeable
+
+    geom_offsets = arr.geom_offsets()
+    assert geom_offsets.dtype == np.int32
+    assert np.array_equal(np.diff(geom_offsets), shapely.get_num_coordinates(geoms))
+    assert np.shares_memory(geom_offsets, arr.geom_offsets())
+
+    data = arr.to_deckgl_binary({"getColor": np.array([[255, 0, 0]] * 3, np.uint8)})
+    assert data["length"] == 3
+    assert np.array_equal(data["startIndices"], geom_offsets)
+    assert np.shares_memory(data["attributes"]["getPath"]["value"], coords)
+    assert data["attributes"]["getPath"]["size"] == 2
+    assert data["attributes"]["getColor"]["size"] == 3
+    assert len(data["attributes"]["getColor"]["value"]) == 9
+
+
+def test_polygon_buffers():
+    geoms = shapely.buffer(shapely.points([0, 10, 20], [0, 10, 20]), [1, 2, 3])
+    arr = gars.from_shapely(geoms)
+    assert isinstance(arr, gars.PolygonArray)
+
+    assert np.array_equal(np.diff(arr.geom_offsets()), np.ones(3))
+    assert np.array_equal(
+        np.diff(arr.ring_offsets()), shapely.get_num_coordinates(geoms)
+    )
+    assert np.shares_memory(arr.coords_buffer(), arrow_coords(arr))
+
+    data = arr.to_deckgl_binary({"getElevation": np.array([1.0, 2.0, 3.0])})
+    assert data["length"] == 3
+    assert np.array_equal(data["startIndices"], arr.ring_offsets())
+    assert data["attributes"]["getElevation"]["size"] == 1
+
+
+def test_polygon_with_hole_not_supported():
+    polygon = shapely.Polygon(
+        [(0, 0), (10, 0), (10, 10), (0, 10)], [[(1, 1), (2, 1), (2, 2), (1, 1)]]
+    )
+    arr = gars.from_shapely(np.array([polygon]))
+    with pytest.raises(ValueError, match="interior rings"):
+        arr.to_deckgl_binary()
+
+
+def test_multi_polygon_buffers():
+    squares = shapely.box([0, 2, 4], [0, 0, 0], [1, 3, 5], [1, 1, 1])
+    geoms = np.array(
+        [
+            shapely.MultiPolygon([squares[0], squares[1]]),
+            shapely.MultiPolygon([squares[2]]),
+        ]
+    )
+    arr = gars.from_shapely(geoms)
+    assert isinstance(arr, gars.MultiPolygonArray)
+
+    assert np.array_equal(np.diff(arr.geom_offsets()), shapely.get_num_geometries(geoms))
+    num_coords = shapely.get_num_coordinates(geoms)
+    coords_per_geom = np.add.reduceat(
+        np.diff(arr.ring_offsets()), arr.polygon_offsets()[:-1]
+    )
+    coords_per_geom = np.add.reduceat(coords_per_geom, arr.geom_offsets()[:-1])
+    assert np.array_equal(coords_per_geom, num_coords)
+    assert np.shares_memory(arr.coords_buffer(), arrow_coords(arr))
+
+    data = arr.to_deckgl_binary({"getFillColor": np.array([1, 2])})
+    assert data["length"] == 3
+    assert np.array_equal(data["startIndices"], [0, 5, 10, 15])
+    assert np.array_equal(data["attributes"]["getFillColor"]["value"], [1, 1, 2])
+
+
+def test_multi_line_string_buffers():
+    geoms = np.array(
+        [
+            shapely.MultiLineString([[(0, 0), (1, 1)], [(2, 2), (3, 3), (4, 4)]]),
+            shapely.MultiLineString([[(5, 5), (6, 6)]]),
+        ]
+    )
+    arr = gars.from_shapely(geoms)
+    assert isinstance(arr, gars.MultiLineStringArray)
+
+    assert np.array_equal(np.diff(arr.geom_offsets()), shapely.get_num_geometries(geoms))
+    assert np.array_equal(arr.ring_offsets(), [0, 2, 5, 7])
+
+    data = arr.to_deckgl_binary({"getWidth": np.array([1.0, 2.0])})
+    assert data["length"] == 3
+    assert np.shares_memory(data["startIndices"], arr.ring_offsets())
+    assert np.array_equal(data["attributes"]["getWidth"]["value"], [1.0, 1.0, 2.0])
+
+
+def test_multi_point_buffers():
+    geoms = np.array(
+        [
+            shapely.MultiPoint([(0, 0), (1, 1), (2, 2)]),
+            shapely.MultiPoint([(3, 3)]),
+        ]
+    )
+    arr = gars.from_shapely(geoms)
+    assert isinstance(arr, gars.MultiPointArray)
+
+    assert np.array_equal(np.diff(arr.geom_offsets()), shapely.get_num_coordinates(geoms))
+
+    data = arr.to_deckgl_binary({"getRadius": np.array([1.0, 2.0])})
+    assert data["length"] == 4
+    assert "startIndices" not in data
+    assert np.array_equal(data["attributes"]["getRadius"]["value"], [1, 1, 1, 2])
+
+
+def test_attribute_length_mismatch():
+    arr = gars.from_shapely(np.array([shapely.LineString([(0, 0), (1, 1)])]))
+    with pytest.raises(ValueError, match="one per geometry"):
+        arr.to_deckgl_binary({"getColor": np.array([1, 2])})
@@ -45,3 +45,19 @@ def test_write_native_multi_points():
         schema.field("geometry").metadata[b"ARROW:extension:name"]
         == b"geoarrow.multipoint"
     )
+
+
+def test_write_parallel_row_groups():
+    points = shapely.points(range(10_000), range(10_000))
+    gdf = gpd.GeoDataFrame({"col1": range(10_000)}, geometry=points, crs="EPSG:4326")
+    table = from_geopandas(gdf)
+    write_parquet(table, "test.parquet", encoding="native", writer_threads=4)
+
+    pq_table = pq.read_table("test.parquet")
+    assert pq_table.num_rows == 10_000
+    assert pq_table["col1"].to_pylist() == list(range(10_000))
+    assert (
+        pq_table.schema.field("geometry").metadata[b"ARROW:extension:name"]
+        == b"geoarrow.point"
+    )
+    assert b"geo" in pq.read_metadata("test.parquet").metadata
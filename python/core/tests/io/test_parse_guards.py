@@ -0,0 +1,42 @@
+from io import BytesIO
+
+import geoarrow.rust.core as gars
+import pyarrow as pa
+import pytest
+import shapely
+
+GEOJSON = b"""{"type": "FeatureCollection", "features": [
+    {"type": "Feature", "properties": {}, "geometry": {"type": "Point", "coordinates": [0, 1]}},
+    {"type": "Feature", "properties": {}, "geometry": {"type": "LineString", "coordinates": [[0, 1], [2, 3]]}}
+]}"""
+
+
+def test_from_wkb_limits():
+    lines = shapely.linestrings([[[0, 0], [1, 1], [2, 2]], [[0, 0], [1, 1]]])
+    wkb = gars.LineStringArray.from_shapely(lines).to_wkb()
+
+    with pytest.raises(Exception, match="max_total_coords of 4 exceeded at row 1"):
+        gars.from_wkb(wkb, max_total_coords=4)
+
+    with pytest.raises(Exception, match="max_coords_per_geometry of 2 exceeded at row 0"):
+        gars.from_wkb(wkb, max_coords_per_geometry=2)
+
+    parsed = gars.from_wkb(wkb, max_coords_per_geometry=3, max_nesting_depth=1)
+    assert len(parsed) == 2
+
+
+def test_from_wkt_limits():
+    wkt = pa.array(["POINT (0 1)", "GEOMETRYCOLLECTION (POINT (0 1))"])
+
+    with pytest.raises(Exception, match="max_nesting_depth of 1 exceeded at row 1"):
+        gars.from_wkt(wkt, max_nesting_depth=1)
+
+    assert len(gars.from_wkt(wkt, max_nesting_depth=2)) == 2
+
+
+def test_read_geojson_limits():
+    with pytest.raises(Exception, match="max_coords_per_geometry of 1 exceeded at row 1"):
+        gars.read_geojson(BytesIO(GEOJSON), max_coords_per_geometry=1)
+
+    table = gars.read_geojson(BytesIO(GEOJSON), max_total_coords=3)
+    assert len(table) == 2
@@ -0,0 +1,55 @@
+import geoarrow.rust.core as gars
+import pytest
+
+from tests.utils import FIXTURES_DIR
+
+
+def test_read_file_flatgeobuf():
+    path = FIXTURES_DIR / "flatgeobuf" / "countries.fgb"
+    table = gars.read_file(path)
+    assert len(table) == 179
+    assert isinstance(table.geometry, gars.ChunkedMultiPolygonArray)
+
+    filtered = gars.read_file(path, bbox=(0, 40, 10, 50))
+    assert 0 < len(filtered) < len(table)
+
+
+def test_read_file_geojson():
+    path = FIXTURES_DIR / "roads.geojson"
+    table = gars.read_file(path)
+    assert len(table) == len(gars.read_geojson(path))
+
+    with pytest.warns(UserWarning, match="bbox is not supported for GeoJSON files"):
+        gars.read_file(path, bbox=(0, 0, 1, 1))
+
+    with pytest.warns(UserWarning, match="layer was ignored"):
+        gars.read_file(path, layer="roads")
+
+
+def test_read_file_parquet_columns():
+    path = FIXTURES_DIR / "geoparquet" / "nybb.parquet"
+    table = gars.read_file(path, columns=["BoroName"])
+    assert len(table) == 5
+    assert table.num_columns == 2
+
+
+def test_to_file_round_trip(tmp_path):
+    table = gars.read_file(FIXTURES_DIR / "flatgeobuf" / "countries.fgb")
+    for name in ["countries.fgb", "countries.geojson", "countries.parquet", "countries.arrow"]:
+        table.to_file(tmp_path / name)
+        assert len(gars.read_file(tmp_path / name)) == len(table)
+
+    # The driver takes precedence over the extension
+    table.to_file(tmp_path / "countries.data", driver="GeoJSONSeq")
+    with pytest.raises(Exception, match="Unsupported file extension '.data'"):
+        gars.read_file(tmp_path / "countries.data")
+
+
+def test_unsupported_extension(tmp_path):
+    path = tmp_path / "data.xyz"
+    path.write_bytes(b"")
+    with pytest.raises(Exception, match="Unsupported file extension '.xyz'"):
+        gars.read_file(path)
+
+    with pytest.raises(Exception, match="GeoPackage"):
+        gars.read_file(tmp_path / "data.gpkg")
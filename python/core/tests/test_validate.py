@@ -0,0 +1,42 @@
+import geoarrow.rust.core as gars
+import pyarrow as pa
+import shapely
+
+POINT_TYPE = pa.list_(pa.field("xy", pa.float64(), nullable=False), 2)
+
+
+def test_valid_array():
+    arr = gars.PointArray.from_shapely(shapely.points([[0, 0], [1, 2]]))
+    assert gars.validate(arr) == []
+
+
+def test_missing_extension_name():
+    arr = pa.array([[0.0, 0.0], [1.0, 2.0]], type=POINT_TYPE)
+    violations = gars.validate(arr)
+    assert len(violations) == 1
+    assert violations[0].startswith("error")
+
+
+def test_table():
+    points = pa.array([[0.0, 0.0], [1.0, 2.0]], type=POINT_TYPE)
+    schema = pa.schema(
+        [
+            pa.field("a", pa.int64()),
+            pa.field(
+                "geometry",
+                POINT_TYPE,
+                metadata={"ARROW:extension:name": "geoarrow.point"},
+            ),
+        ]
+    )
+    table = pa.Table.from_arrays([pa.array([1, 2]), points], schema=schema)
+    assert gars.validate(table) == []
+
+    # The misnamed geometry column is reported as an error
+    schema = schema.set(
+        1,
+        schema.field(1).with_metadata({"ARROW:extension:name": "geoarrow.polygon"}),
+    )
+    violations = gars.validate(table.cast(schema))
+    assert len(violations) > 0
+    assert all(v.startswith("error") for v in violations)
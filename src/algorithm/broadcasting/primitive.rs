@@ -1,8 +1,10 @@
 use arrow_array::iterator::ArrayIter;
 use arrow_array::types::ArrowPrimitiveType;
-use arrow_array::PrimitiveArray;
+use arrow_array::{Array, PrimitiveArray};
 use arrow_buffer::ArrowNativeType;
 
+use crate::error::{GeoArrowError, Result};
+
 /// An enum over primitive types defined by [`arrow2::types::NativeType`]. These include u8, i32,
 /// f64, etc.
 ///
@@ -17,6 +19,41 @@ where
     Array(PrimitiveArray<T>),
 }
 
+impl<T: ArrowPrimitiveType> BroadcastablePrimitive<T> {
+    /// Split into one value per chunk of a chunked array with chunks of the given lengths.
+    ///
+    /// A scalar is repeated for each chunk, and an array is sliced at the chunk boundaries, so it
+    /// must have one value per row of the chunked array.
+    pub(crate) fn split_chunks(&self, chunk_lengths: &[usize]) -> Result<Vec<Self>> {
+        match self {
+            BroadcastablePrimitive::Scalar(val) => Ok(vec![
+                BroadcastablePrimitive::Scalar(*val);
+                chunk_lengths.len()
+            ]),
+            BroadcastablePrimitive::Array(arr) => {
+                let num_rows = chunk_lengths.iter().sum::<usize>();
+                if arr.len() != num_rows {
+                    return Err(GeoArrowError::General(format!(
+                        "Expected 1 or {} values, got {}",
+                        num_rows,
+                        arr.len()
+                    )));
+                }
+
+                let mut offset = 0;
+                Ok(chunk_lengths
+                    .iter()
+                    .map(|chunk_length| {
+                        let chunk = arr.slice(offset, *chunk_length);
+                        offset += chunk_length;
+                        BroadcastablePrimitive::Array(chunk)
+                    })
+                    .collect())
+            }
+        }
+    }
+}
+
 pub enum BroadcastIter<'a, T: ArrowPrimitiveType> {
     Scalar(T::Native),
     Array(ArrayIter<&'a PrimitiveArray<T>>),
@@ -0,0 +1,355 @@
+//! Introspection of which kernels support which geometry types.
+//!
+//! Dynamic callers, like query engines or the Python bindings, can use [`supports`] to find out
+//! whether a kernel is meaningful for a column before calling it, instead of calling it and
+//! catching the error.
+//!
+//! ```
+//! use geoarrow::algorithm::capabilities::{supports, KernelId, Support};
+//! use geoarrow::datatypes::GeoDataType;
+//!
+//! let data_type = GeoDataType::LineString(Default::default());
+//! assert_eq!(supports(KernelId::Area, &data_type), Support::ReturnsZero);
+//! assert_eq!(supports(KernelId::EuclideanLength, &data_type), Support::Full);
+//! assert_eq!(supports(KernelId::Densify, &GeoDataType::WKB), Support::Unsupported);
+//! ```
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::datatypes::GeoDataType;
+use crate::error::GeoArrowError;
+
+/// A kernel that can be called on a `&dyn GeometryArrayTrait`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KernelId {
+    /// [`Area`](crate::algorithm::geo::Area)
+    Area,
+    /// [`ChamberlainDuquetteArea`](crate::algorithm::geo::ChamberlainDuquetteArea)
+    ChamberlainDuquetteArea,
+    /// [`GeodesicArea`](crate::algorithm::geo::GeodesicArea)
+    GeodesicArea,
+    /// [`EuclideanLength`](crate::algorithm::geo::EuclideanLength)
+    EuclideanLength,
+    /// [`GeodesicLength`](crate::algorithm::geo::GeodesicLength)
+    GeodesicLength,
+    /// [`HaversineLength`](crate::algorithm::geo::HaversineLength)
+    HaversineLength,
+    /// [`VincentyLength`](crate::algorithm::geo::VincentyLength)
+    VincentyLength,
+    /// [`BoundingRect`](crate::algorithm::geo::BoundingRect)
+    BoundingRect,
+    /// [`Center`](crate::algorithm::geo::Center)
+    Center,
+    /// [`Centroid`](crate::algorithm::geo::Centroid)
+    Centroid,
+    /// [`ConvexHull`](crate::algorithm::geo::ConvexHull)
+    ConvexHull,
+    /// [`HasDimensions::is_empty`](crate::algorithm::geo::HasDimensions::is_empty)
+    IsEmpty,
+    /// [`MinimumRotatedRect`](crate::algorithm::geo::MinimumRotatedRect)
+    MinimumRotatedRect,
+    /// [`Simplify`](crate::algorithm::geo::Simplify)
+    Simplify,
+    /// [`SimplifyVw`](crate::algorithm::geo::SimplifyVw)
+    SimplifyVw,
+    /// [`SimplifyVwPreserve`](crate::algorithm::geo::SimplifyVwPreserve)
+    SimplifyVwPreserve,
+    /// [`RemoveRepeatedPoints`](crate::algorithm::geo::RemoveRepeatedPoints)
+    RemoveRepeatedPoints,
+    /// [`ChaikinSmoothing`](crate::algorithm::geo::ChaikinSmoothing)
+    ChaikinSmoothing,
+    /// [`Densify`](crate::algorithm::geo::Densify)
+    Densify,
+    /// [`TotalBounds`](crate::algorithm::native::TotalBounds)
+    TotalBounds,
+    /// [`MinimumSegmentLength`](crate::algorithm::native::MinimumSegmentLength)
+    MinimumSegmentLength,
+}
+
+impl KernelId {
+    /// Every kernel, in declaration order.
+    pub const ALL: [KernelId; 21] = [
+        KernelId::Area,
+        KernelId::ChamberlainDuquetteArea,
+        KernelId::GeodesicArea,
+        KernelId::EuclideanLength,
+        KernelId::GeodesicLength,
+        KernelId::HaversineLength,
+        KernelId::VincentyLength,
+        KernelId::BoundingRect,
+        KernelId::Center,
+        KernelId::Centroid,
+        KernelId::ConvexHull,
+        KernelId::IsEmpty,
+        KernelId::MinimumRotatedRect,
+        KernelId::Simplify,
+        KernelId::SimplifyVw,
+        KernelId::SimplifyVwPreserve,
+        KernelId::RemoveRepeatedPoints,
+        KernelId::ChaikinSmoothing,
+        KernelId::Densify,
+        KernelId::TotalBounds,
+        KernelId::MinimumSegmentLength,
+    ];
+
+    /// The snake case name of the kernel, as accepted by [`FromStr`].
+    pub fn name(&self) -> &'static str {
+        match self {
+            KernelId::Area => "area",
+            KernelId::ChamberlainDuquetteArea => "chamberlain_duquette_area",
+            KernelId::GeodesicArea => "geodesic_area",
+            KernelId::EuclideanLength => "euclidean_length",
+            KernelId::GeodesicLength => "geodesic_length",
+            KernelId::HaversineLength => "haversine_length",
+            KernelId::VincentyLength => "vincenty_length",
+            KernelId::BoundingRect => "bounding_rect",
+            KernelId::Center => "center",
+            KernelId::Centroid => "centroid",
+            KernelId::ConvexHull => "convex_hull",
+            KernelId::IsEmpty => "is_empty",
+            KernelId::MinimumRotatedRect => "minimum_rotated_rect",
+            KernelId::Simplify => "simplify",
+            KernelId::SimplifyVw => "simplify_vw",
+            KernelId::SimplifyVwPreserve => "simplify_vw_preserve",
+            KernelId::RemoveRepeatedPoints => "remove_repeated_points",
+            KernelId::ChaikinSmoothing => "chaikin_smoothing",
+            KernelId::Densify => "densify",
+            KernelId::TotalBounds => "total_bounds",
+            KernelId::MinimumSegmentLength => "minimum_segment_length",
+        }
+    }
+}
+
+impl Display for KernelId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for KernelId {
+    type Err = GeoArrowError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        KernelId::ALL
+            .into_iter()
+            .find(|kernel| kernel.name() == s)
+            .ok_or_else(|| GeoArrowError::General(format!("Unknown kernel {}", s)))
+    }
+}
+
+/// How a kernel handles a geometry type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Support {
+    /// The kernel computes a meaningful result.
+    Full,
+    /// The kernel succeeds, but the result is always zero because the measure isn't defined for
+    /// the type, such as the area of a line string.
+    ReturnsZero,
+    /// The kernel returns an error.
+    Unsupported,
+}
+
+impl Support {
+    /// The snake case name of the support level.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Support::Full => "full",
+            Support::ReturnsZero => "returns_zero",
+            Support::Unsupported => "unsupported",
+        }
+    }
+}
+
+/// The column of [`SUPPORT_TABLE`] for a data type. The offset size and coordinate type don't
+/// affect support.
+fn geometry_type_index(data_type: &GeoDataType) -> usize {
+    use GeoDataType::*;
+    match data_type {
+        Point(_) => 0,
+        LineString(_) | LargeLineString(_) => 1,
+        Polygon(_) | LargePolygon(_) => 2,
+        MultiPoint(_) | LargeMultiPoint(_) => 3,
+        MultiLineString(_) | LargeMultiLineString(_) => 4,
+        MultiPolygon(_) | LargeMultiPolygon(_) => 5,
+        Mixed(_) | LargeMixed(_) => 6,
+        GeometryCollection(_) | LargeGeometryCollection(_) => 7,
+        Rect => 8,
+        WKB | LargeWKB => 9,
+    }
+}
+
+const F: Support = Support::Full;
+const Z: Support = Support::ReturnsZero;
+const U: Support = Support::Unsupported;
+
+/// Support of each kernel, with columns for point, line string, polygon, multi point, multi line
+/// string, multi polygon, mixed, geometry collection, rect and WKB arrays.
+///
+/// This is checked against the kernel implementations in the tests below.
+#[rustfmt::skip]
+static SUPPORT_TABLE: [(KernelId, [Support; 10]); 21] = [
+    (KernelId::Area,                    [Z, Z, F, Z, Z, F, F, F, U, U]),
+    (KernelId::ChamberlainDuquetteArea, [Z, Z, F, Z, Z, F, F, F, U, U]),
+    (KernelId::GeodesicArea,            [Z, Z, F, Z, Z, F, F, F, U, U]),
+    (KernelId::EuclideanLength,         [Z, F, U, Z, F, U, U, U, U, U]),
+    (KernelId::GeodesicLength,          [Z, F, U, Z, F, U, U, U, U, U]),
+    (KernelId::HaversineLength,         [Z, F, U, Z, F, U, U, U, U, U]),
+    (KernelId::VincentyLength,          [Z, F, U, Z, F, U, U, U, U, U]),
+    (KernelId::BoundingRect,            [F, F, F, F, F, F, F, F, F, F]),
+    (KernelId::Center,                  [F, F, F, F, F, F, F, F, U, U]),
+    (KernelId::Centroid,                [F, F, F, F, F, F, F, F, F, F]),
+    (KernelId::ConvexHull,              [F, F, F, F, F, F, F, F, F, F]),
+    (KernelId::IsEmpty,                 [F, F, F, F, F, F, F, F, U, U]),
+    (KernelId::MinimumRotatedRect,      [F, F, F, F, F, F, F, F, U, U]),
+    (KernelId::Simplify,                [F, F, F, F, F, F, U, U, U, U]),
+    (KernelId::SimplifyVw,              [F, F, F, F, F, F, U, U, U, U]),
+    (KernelId::SimplifyVwPreserve,      [F, F, F, F, F, F, U, U, U, U]),
+    (KernelId::RemoveRepeatedPoints,    [F, F, F, F, F, F, U, U, U, U]),
+    (KernelId::ChaikinSmoothing,        [U, F, F, U, F, F, U, U, U, U]),
+    (KernelId::Densify,                 [U, F, F, U, F, F, U, U, U, U]),
+    (KernelId::TotalBounds,             [F, F, F, F, F, F, F, F, F, F]),
+    (KernelId::MinimumSegmentLength,    [F, F, F, F, F, F, F, F, F, F]),
+];
+
+/// Whether `kernel` supports arrays of `data_type` when called on a `&dyn GeometryArrayTrait`.
+///
+/// A chunked array is supported to the same extent as its chunks.
+pub fn supports(kernel: KernelId, data_type: &GeoDataType) -> Support {
+    let (_, row) = SUPPORT_TABLE
+        .iter()
+        .find(|(table_kernel, _)| *table_kernel == kernel)
+        .expect("every kernel is in the support table");
+    row[geometry_type_index(data_type)]
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arrow_array::{Array, Float64Array};
+
+    use super::*;
+    use crate::algorithm::geo::*;
+    use crate::algorithm::native::{MinimumSegmentLength, TotalBounds};
+    use crate::array::*;
+    use crate::error::Result;
+    use crate::io::wkb::to_wkb;
+    use crate::test::{linestring, multilinestring, multipoint, multipolygon, point, polygon};
+    use crate::GeometryArrayTrait;
+
+    /// One array of every data type.
+    fn fixtures() -> Vec<Arc<dyn GeometryArrayTrait>> {
+        let geoms: Vec<geo::Geometry> = vec![
+            point::p0().into(),
+            linestring::ls0().into(),
+            polygon::p0().into(),
+        ];
+        let mixed: MixedGeometryArray<i32> = geoms.as_slice().try_into().unwrap();
+        let collections: GeometryCollectionArray<i32> =
+            vec![geo::GeometryCollection::new_from(geoms)]
+                .as_slice()
+                .into();
+        let rects: RectArray = vec![geo::Rect::new((0., 0.), (1., 2.))].as_slice().into();
+        let wkb = to_wkb::<i32>(&polygon::p_array());
+
+        vec![
+            Arc::new(point::point_array()),
+            Arc::new(linestring::ls_array()),
+            Arc::new(linestring::large_ls_array()),
+            Arc::new(polygon::p_array()),
+            Arc::new(PolygonArray::<i64>::from(polygon::p_array())),
+            Arc::new(multipoint::mp_array()),
+            Arc::new(MultiPointArray::<i64>::from(multipoint::mp_array())),
+            Arc::new(multilinestring::ml_array()),
+            Arc::new(MultiLineStringArray::<i64>::from(
+                multilinestring::ml_array(),
+            )),
+            Arc::new(multipolygon::mp_array()),
+            Arc::new(MultiPolygonArray::<i64>::from(multipolygon::mp_array())),
+            Arc::new(MixedGeometryArray::<i64>::from(mixed.clone())),
+            Arc::new(mixed),
+            Arc::new(GeometryCollectionArray::<i64>::from(collections.clone())),
+            Arc::new(collections),
+            Arc::new(rects),
+            Arc::new(WKBArray::<i64>::from(wkb.clone())),
+            Arc::new(wkb),
+        ]
+    }
+
+    /// Call a kernel, returning the values of kernels that compute a measure.
+    fn call_kernel(kernel: KernelId, arr: &dyn GeometryArrayTrait) -> Result<Option<Float64Array>> {
+        let measure = match kernel {
+            KernelId::Area => arr.unsigned_area(),
+            KernelId::ChamberlainDuquetteArea => arr.chamberlain_duquette_unsigned_area(),
+            KernelId::GeodesicArea => arr.geodesic_area_unsigned(),
+            KernelId::EuclideanLength => arr.euclidean_length(),
+            KernelId::GeodesicLength => arr.geodesic_length(),
+            KernelId::HaversineLength => arr.haversine_length(),
+            KernelId::VincentyLength => arr.vincenty_length(),
+            KernelId::MinimumSegmentLength => arr.minimum_segment_length(),
+            KernelId::BoundingRect => return arr.bounding_rect().map(|_| None),
+            KernelId::Center => return arr.center().map(|_| None),
+            KernelId::Centroid => return arr.centroid().map(|_| None),
+            KernelId::ConvexHull => return ConvexHull::<i32>::convex_hull(&arr).map(|_| None),
+            KernelId::IsEmpty => return HasDimensions::is_empty(&arr).map(|_| None),
+            KernelId::MinimumRotatedRect => {
+                return MinimumRotatedRect::<i32>::minimum_rotated_rect(&arr).map(|_| None)
+            }
+            KernelId::Simplify => return arr.simplify(&1.0.into()).map(|_| None),
+            KernelId::SimplifyVw => return arr.simplify_vw(&1.0.into()).map(|_| None),
+            KernelId::SimplifyVwPreserve => {
+                return arr.simplify_vw_preserve(&1.0.into()).map(|_| None)
+            }
+            KernelId::RemoveRepeatedPoints => return arr.remove_repeated_points().map(|_| None),
+            KernelId::ChaikinSmoothing => return arr.chaikin_smoothing(1).map(|_| None),
+            KernelId::Densify => return arr.densify(1.0).map(|_| None),
+            KernelId::TotalBounds => {
+                TotalBounds::total_bounds(&arr);
+                return Ok(None);
+            }
+        };
+        measure.map(Some)
+    }
+
+    #[test]
+    fn table_matches_kernels() {
+        for arr in fixtures() {
+            for kernel in KernelId::ALL {
+                let support = supports(kernel, arr.data_type());
+                let result = call_kernel(kernel, arr.as_ref());
+                match support {
+                    Support::Unsupported => assert!(
+                        result.is_err(),
+                        "{} is listed as unsupported for {:?}",
+                        kernel,
+                        arr.data_type()
+                    ),
+                    Support::Full => assert!(
+                        result.is_ok(),
+                        "{} is listed as supported for {:?}",
+                        kernel,
+                        arr.data_type()
+                    ),
+                    Support::ReturnsZero => {
+                        let values = result.unwrap().unwrap();
+                        assert!(
+                            values.iter().flatten().all(|value| value == 0.),
+                            "{} is listed as returning zero for {:?}",
+                            kernel,
+                            arr.data_type()
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn kernel_names_round_trip() {
+        for kernel in KernelId::ALL {
+            assert_eq!(kernel.name().parse::<KernelId>().unwrap(), kernel);
+        }
+        assert!("not_a_kernel".parse::<KernelId>().is_err());
+    }
+}
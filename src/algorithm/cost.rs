@@ -0,0 +1,232 @@
+//! Approximate cost estimates of kernels, for query engines choosing between plans.
+//!
+//! An estimate is a rough count of the CPU operations a kernel performs and the size of its
+//! output, derived from the data type, the number of rows and the mean number of coordinates per
+//! geometry, as returned by
+//! [`GeoTable::column_statistics`][crate::table::GeoTable::column_statistics].
+//!
+//! The per-row and per-coordinate weights are order-of-magnitude constants, so estimates are only
+//! meaningful relative to each other, and should be re-checked against the benchmarks in
+//! `benches/` when a kernel changes.
+//!
+//! ```
+//! use geoarrow::algorithm::cost::CostedKernel;
+//! use geoarrow::datatypes::GeoDataType;
+//!
+//! let data_type = GeoDataType::Polygon(Default::default());
+//! let small = CostedKernel::Area.estimated_cost(&data_type, 1000, 5.).unwrap();
+//! let large = CostedKernel::Area.estimated_cost(&data_type, 1000, 500.).unwrap();
+//! assert!(large.cpu_ops > small.cpu_ops);
+//! assert_eq!(large.output_bytes, small.output_bytes);
+//! ```
+
+use crate::algorithm::capabilities::{supports, KernelId, Support};
+use crate::datatypes::GeoDataType;
+
+/// Weights of a kernel, in operations per row and per coordinate.
+struct Weights {
+    per_row: f64,
+    per_coord: f64,
+}
+
+const AREA: Weights = Weights {
+    per_row: 5.,
+    per_coord: 4.,
+};
+
+const CENTROID: Weights = Weights {
+    per_row: 10.,
+    per_coord: 12.,
+};
+
+/// `per_coord` is the cost of each pair of coordinates of the two geometries.
+const INTERSECTS: Weights = Weights {
+    per_row: 40.,
+    per_coord: 6.,
+};
+
+const FROM_WKB: Weights = Weights {
+    per_row: 30.,
+    per_coord: 4.,
+};
+
+/// The estimated cost of running a kernel on an array.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CostEstimate {
+    /// The approximate number of CPU operations.
+    pub cpu_ops: f64,
+
+    /// The approximate size of the output array, in bytes.
+    pub output_bytes: usize,
+}
+
+/// A kernel with a cost model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CostedKernel {
+    /// [`Area`](crate::algorithm::geo::Area)
+    Area,
+    /// [`Centroid`](crate::algorithm::geo::Centroid)
+    Centroid,
+    /// [`Intersects`](crate::algorithm::geo::Intersects) between two arrays of the same data
+    /// type and size.
+    Intersects,
+    /// [`from_wkb`](crate::io::wkb::from_wkb)
+    FromWKB,
+}
+
+impl CostedKernel {
+    /// Estimate the cost of running this kernel on `num_rows` geometries of `data_type` with
+    /// `avg_coords_per_geom` coordinates each, or `None` if the kernel doesn't support the data
+    /// type.
+    ///
+    /// The estimate is approximate. Intersects assumes both arrays have the same statistics, and
+    /// compares every coordinate of one geometry with every coordinate of the other, as the
+    /// kernel does in the worst case.
+    pub fn estimated_cost(
+        &self,
+        data_type: &GeoDataType,
+        num_rows: usize,
+        avg_coords_per_geom: f64,
+    ) -> Option<CostEstimate> {
+        let rows = num_rows as f64;
+        let coords = rows * avg_coords_per_geom;
+        let validity_bytes = num_rows.div_ceil(8);
+        match self {
+            CostedKernel::Area => match supports(KernelId::Area, data_type) {
+                Support::Unsupported => None,
+                Support::ReturnsZero => Some(CostEstimate {
+                    cpu_ops: AREA.per_row * rows,
+                    output_bytes: 8 * num_rows + validity_bytes,
+                }),
+                Support::Full => Some(CostEstimate {
+                    cpu_ops: AREA.per_row * rows + AREA.per_coord * coords,
+                    output_bytes: 8 * num_rows + validity_bytes,
+                }),
+            },
+            CostedKernel::Centroid => match supports(KernelId::Centroid, data_type) {
+                Support::Unsupported => None,
+                Support::ReturnsZero | Support::Full => Some(CostEstimate {
+                    cpu_ops: CENTROID.per_row * rows + CENTROID.per_coord * coords,
+                    output_bytes: 16 * num_rows + validity_bytes,
+                }),
+            },
+            CostedKernel::Intersects => match data_type {
+                GeoDataType::WKB | GeoDataType::LargeWKB | GeoDataType::Rect => None,
+                _ => Some(CostEstimate {
+                    cpu_ops: INTERSECTS.per_row * rows
+                        + INTERSECTS.per_coord * coords * avg_coords_per_geom,
+                    output_bytes: 2 * validity_bytes,
+                }),
+            },
+            CostedKernel::FromWKB => match data_type {
+                // The coordinates, plus about two offsets per row for the parts and rings
+                GeoDataType::WKB | GeoDataType::LargeWKB => Some(CostEstimate {
+                    cpu_ops: FROM_WKB.per_row * rows + FROM_WKB.per_coord * coords,
+                    output_bytes: (16. * coords) as usize + 8 * num_rows + validity_bytes,
+                }),
+                _ => None,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::CoordType;
+
+    const KERNELS: [CostedKernel; 4] = [
+        CostedKernel::Area,
+        CostedKernel::Centroid,
+        CostedKernel::Intersects,
+        CostedKernel::FromWKB,
+    ];
+
+    fn data_types() -> Vec<GeoDataType> {
+        vec![
+            GeoDataType::Point(CoordType::Interleaved),
+            GeoDataType::LineString(CoordType::Interleaved),
+            GeoDataType::Polygon(CoordType::Separated),
+            GeoDataType::LargeMultiPolygon(CoordType::Interleaved),
+            GeoDataType::Mixed(CoordType::Interleaved),
+            GeoDataType::WKB,
+            GeoDataType::LargeWKB,
+        ]
+    }
+
+    #[test]
+    fn monotonic_in_rows() {
+        for kernel in KERNELS {
+            for data_type in data_types() {
+                let Some(small) = kernel.estimated_cost(&data_type, 100, 10.) else {
+                    continue;
+                };
+                let large = kernel.estimated_cost(&data_type, 10_000, 10.).unwrap();
+                assert!(large.cpu_ops > small.cpu_ops, "{kernel:?} {data_type:?}");
+                assert!(
+                    large.output_bytes > small.output_bytes,
+                    "{kernel:?} {data_type:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn monotonic_in_coords() {
+        for kernel in KERNELS {
+            for data_type in data_types() {
+                let Some(small) = kernel.estimated_cost(&data_type, 1000, 4.) else {
+                    continue;
+                };
+                let large = kernel.estimated_cost(&data_type, 1000, 400.).unwrap();
+                assert!(large.cpu_ops >= small.cpu_ops, "{kernel:?} {data_type:?}");
+                assert!(
+                    large.output_bytes >= small.output_bytes,
+                    "{kernel:?} {data_type:?}"
+                );
+            }
+        }
+
+        // Kernels that visit every coordinate get strictly more expensive
+        let polygon = GeoDataType::Polygon(CoordType::Interleaved);
+        for kernel in [
+            CostedKernel::Area,
+            CostedKernel::Centroid,
+            CostedKernel::Intersects,
+        ] {
+            let small = kernel.estimated_cost(&polygon, 1000, 4.).unwrap();
+            let large = kernel.estimated_cost(&polygon, 1000, 400.).unwrap();
+            assert!(large.cpu_ops > small.cpu_ops);
+        }
+        let small = CostedKernel::FromWKB
+            .estimated_cost(&GeoDataType::WKB, 1000, 4.)
+            .unwrap();
+        let large = CostedKernel::FromWKB
+            .estimated_cost(&GeoDataType::WKB, 1000, 400.)
+            .unwrap();
+        assert!(large.cpu_ops > small.cpu_ops);
+        assert!(large.output_bytes > small.output_bytes);
+    }
+
+    #[test]
+    fn unsupported_types() {
+        let wkb = GeoDataType::WKB;
+        let polygon = GeoDataType::Polygon(CoordType::Interleaved);
+        assert!(CostedKernel::Area.estimated_cost(&wkb, 10, 1.).is_none());
+        assert!(CostedKernel::Intersects
+            .estimated_cost(&wkb, 10, 1.)
+            .is_none());
+        assert!(CostedKernel::FromWKB
+            .estimated_cost(&polygon, 10, 1.)
+            .is_none());
+        assert!(CostedKernel::FromWKB.estimated_cost(&wkb, 10, 1.).is_some());
+    }
+
+    #[test]
+    fn empty_input() {
+        let polygon = GeoDataType::Polygon(CoordType::Interleaved);
+        let cost = CostedKernel::Area.estimated_cost(&polygon, 0, 0.).unwrap();
+        assert_eq!(cost.cpu_ops, 0.);
+        assert_eq!(cost.output_bytes, 0);
+    }
+}
@@ -1,10 +1,11 @@
 use std::sync::Arc;
 
+use crate::algorithm::broadcasting::BroadcastableVec;
 use crate::array::*;
 use crate::chunked_array::*;
 use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
-use crate::trait_::GeometryArrayAccessor;
+use crate::trait_::{GeometryArrayAccessor, GeometryArraySelfMethods};
 use crate::GeometryArrayTrait;
 use arrow_array::OffsetSizeTrait;
 use geo::{AffineTransform, MapCoords};
@@ -48,84 +49,136 @@ pub trait AffineOps<Rhs> {
     /// Apply `transform` immutably, outputting a new geometry.
     #[must_use]
     fn affine_transform(&self, transform: Rhs) -> Self::Output;
+}
+
+/// Arrays that can be transformed by a single [`AffineTransform`] or by one transform per
+/// geometry, as used by [`Scale`](crate::algorithm::geo::Scale),
+/// [`Skew`](crate::algorithm::geo::Skew), [`Translate`](crate::algorithm::geo::Translate) and
+/// [`Rotate`](crate::algorithm::geo::Rotate).
+pub(crate) trait AffineTransformable:
+    GeometryArrayTrait
+    + Sized
+    + for<'a> AffineOps<&'a AffineTransform, Output = Self>
+    + for<'a> AffineOps<&'a [Option<AffineTransform>], Output = Self>
+{
+}
+
+impl<G> AffineTransformable for G where
+    G: GeometryArrayTrait
+        + for<'a> AffineOps<&'a AffineTransform, Output = G>
+        + for<'a> AffineOps<&'a [Option<AffineTransform>], Output = G>
+{
+}
+
+/// Apply an [`AffineTransform`] to geometries in place. See [`AffineOps`] for an immutable API.
+///
+/// Unlike [`AffineOps`], this doesn't construct [`geo`] objects or rebuild the array: the
+/// transformation matrix is applied directly to the interleaved or separated coordinate buffers,
+/// and the geometry offsets and validity are left untouched. If the coordinate buffers are shared
+/// with another array, such as a clone, they are copied first, so the other array is unaffected.
+pub trait AffineOpsMut<Rhs> {
+    /// Apply `transform` to mutate `self`.
+    fn affine_transform_mut(&mut self, transform: Rhs);
+}
 
-    // TODO: add COW API for affine_transform_mut
-    //
-    // /// Apply `transform` to mutate `self`.
-    // fn affine_transform_mut(&mut self, transform: &AffineTransform<T>);
+/// The transformation as a function of the x and y coordinates, applying the matrix directly.
+fn transform_xy(transform: &AffineTransform) -> impl Fn(f64, f64) -> (f64, f64) {
+    let (a, b, xoff) = (transform.a(), transform.b(), transform.xoff());
+    let (d, e, yoff) = (transform.d(), transform.e(), transform.yoff());
+    move |x, y| (a * x + b * y + xoff, d * x + e * y + yoff)
 }
 
 // ┌─────────────────────────────────┐
 // │ Implementations for RHS scalars │
 // └─────────────────────────────────┘
 
-// Note: this can't (easily) be parameterized in the macro because PointArray is not generic over O
+// The transform only touches coordinates, so it's applied directly to the coordinate buffer and
+// the array is rebuilt around the existing offsets and validity.
+
 impl AffineOps<&AffineTransform> for PointArray {
     type Output = Self;
 
     fn affine_transform(&self, transform: &AffineTransform) -> Self::Output {
-        let mut output_array = PointBuilder::with_capacity(self.buffer_lengths());
-
-        self.iter_geo().for_each(|maybe_g| {
-            output_array.push_point(
-                maybe_g
-                    .map(|geom| geom.map_coords(|coord| transform.apply(coord)))
-                    .as_ref(),
-            )
-        });
-
-        output_array.finish()
+        let coords = self.coords.map_xy(transform_xy(transform));
+        self.clone().with_coords(coords)
     }
 }
 
-/// Implementation that iterates over geo objects
-macro_rules! iter_geo_impl {
-    ($type:ty, $builder_type:ty, $push_func:ident) => {
+macro_rules! coords_impl {
+    ($type:ty) => {
         impl<O: OffsetSizeTrait> AffineOps<&AffineTransform> for $type {
             type Output = Self;
 
             fn affine_transform(&self, transform: &AffineTransform) -> Self::Output {
-                let mut output_array = <$builder_type>::with_capacity(self.buffer_lengths());
-
-                self.iter_geo().for_each(|maybe_g| {
-                    output_array
-                        .$push_func(
-                            maybe_g
-                                .map(|geom| geom.map_coords(|coord| transform.apply(coord)))
-                                .as_ref(),
-                        )
-                        .unwrap()
-                });
-
-                output_array.finish()
+                let coords = self.coords.map_xy(transform_xy(transform));
+                self.clone().with_coords(coords)
             }
         }
     };
 }
 
-iter_geo_impl!(LineStringArray<O>, LineStringBuilder<O>, push_line_string);
-iter_geo_impl!(PolygonArray<O>, PolygonBuilder<O>, push_polygon);
-iter_geo_impl!(MultiPointArray<O>, MultiPointBuilder<O>, push_multi_point);
-iter_geo_impl!(
-    MultiLineStringArray<O>,
-    MultiLineStringBuilder<O>,
-    push_multi_line_string
-);
-iter_geo_impl!(
-    MultiPolygonArray<O>,
-    MultiPolygonBuilder<O>,
-    push_multi_polygon
-);
-iter_geo_impl!(
-    MixedGeometryArray<O>,
-    MixedGeometryBuilder<O>,
-    push_geometry
-);
-iter_geo_impl!(
-    GeometryCollectionArray<O>,
-    GeometryCollectionBuilder<O>,
-    push_geometry_collection
-);
+coords_impl!(LineStringArray<O>);
+coords_impl!(PolygonArray<O>);
+coords_impl!(MultiPointArray<O>);
+coords_impl!(MultiLineStringArray<O>);
+coords_impl!(MultiPolygonArray<O>);
+
+impl<O: OffsetSizeTrait> AffineOps<&AffineTransform> for MixedGeometryArray<O> {
+    type Output = Self;
+
+    fn affine_transform(&self, transform: &AffineTransform) -> Self::Output {
+        let mut output_array = self.clone();
+        output_array.points = self
+            .points
+            .as_ref()
+            .map(|arr| arr.affine_transform(transform));
+        output_array.line_strings = self
+            .line_strings
+            .as_ref()
+            .map(|arr| arr.affine_transform(transform));
+        output_array.polygons = self
+            .polygons
+            .as_ref()
+            .map(|arr| arr.affine_transform(transform));
+        output_array.multi_points = self
+            .multi_points
+            .as_ref()
+            .map(|arr| arr.affine_transform(transform));
+        output_array.multi_line_strings = self
+            .multi_line_strings
+            .as_ref()
+            .map(|arr| arr.affine_transform(transform));
+        output_array.multi_polygons = self
+            .multi_polygons
+            .as_ref()
+            .map(|arr| arr.affine_transform(transform));
+        output_array
+    }
+}
+
+impl<O: OffsetSizeTrait> AffineOps<&AffineTransform> for GeometryCollectionArray<O> {
+    type Output = Self;
+
+    fn affine_transform(&self, transform: &AffineTransform) -> Self::Output {
+        let mut output_array = self.clone();
+        output_array.array = self.array.affine_transform(transform);
+        output_array
+    }
+}
+
+/// WKB has no coordinate buffer, so each geometry is parsed, transformed, and written back to WKB.
+impl<O: OffsetSizeTrait> AffineOps<&AffineTransform> for WKBArray<O> {
+    type Output = Result<Self>;
+
+    fn affine_transform(&self, transform: &AffineTransform) -> Self::Output {
+        let geoms = self
+            .iter_geo()
+            .map(|maybe_g| maybe_g.map(|geom| geom.map_coords(|coord| transform.apply(coord))))
+            .collect::<Vec<_>>();
+        let output_array = WKBArray::<O>::try_from(geoms.as_slice())?;
+        Ok(WKBArray::new(output_array.array, self.metadata()))
+    }
+}
 
 impl AffineOps<&AffineTransform> for &dyn GeometryArrayTrait {
     type Output = Result<Arc<dyn GeometryArrayTrait>>;
@@ -154,8 +207,8 @@ impl AffineOps<&AffineTransform> for &dyn GeometryArrayTrait {
             LargeMixed(_) => impl_downcast!(as_large_mixed),
             GeometryCollection(_) => impl_downcast!(as_geometry_collection),
             LargeGeometryCollection(_) => impl_downcast!(as_large_geometry_collection),
-            // WKB => impl_downcast!(as_wkb),
-            // LargeWKB => impl_downcast!(as_large_wkb),
+            WKB => Arc::new(self.as_wkb().affine_transform(transform)?),
+            LargeWKB => Arc::new(self.as_large_wkb().affine_transform(transform)?),
             // Rect => impl_downcast!(as_rect),
             _ => return Err(GeoArrowError::IncorrectType("".into())),
         };
@@ -231,6 +284,72 @@ impl AffineOps<&AffineTransform> for &dyn ChunkedGeometryArrayTrait {
     }
 }
 
+// ┌──────────────────────────────────────┐
+// │ In-place implementations for scalars │
+// └──────────────────────────────────────┘
+
+impl AffineOpsMut<&AffineTransform> for PointArray {
+    fn affine_transform_mut(&mut self, transform: &AffineTransform) {
+        self.coords.map_xy_mut(transform_xy(transform));
+    }
+}
+
+macro_rules! coords_mut_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> AffineOpsMut<&AffineTransform> for $type {
+            fn affine_transform_mut(&mut self, transform: &AffineTransform) {
+                self.coords.map_xy_mut(transform_xy(transform));
+            }
+        }
+    };
+}
+
+coords_mut_impl!(LineStringArray<O>);
+coords_mut_impl!(PolygonArray<O>);
+coords_mut_impl!(MultiPointArray<O>);
+coords_mut_impl!(MultiLineStringArray<O>);
+coords_mut_impl!(MultiPolygonArray<O>);
+
+impl<O: OffsetSizeTrait> AffineOpsMut<&AffineTransform> for MixedGeometryArray<O> {
+    fn affine_transform_mut(&mut self, transform: &AffineTransform) {
+        if let Some(points) = self.points.as_mut() {
+            points.affine_transform_mut(transform);
+        }
+        if let Some(line_strings) = self.line_strings.as_mut() {
+            line_strings.affine_transform_mut(transform);
+        }
+        if let Some(polygons) = self.polygons.as_mut() {
+            polygons.affine_transform_mut(transform);
+        }
+        if let Some(multi_points) = self.multi_points.as_mut() {
+            multi_points.affine_transform_mut(transform);
+        }
+        if let Some(multi_line_strings) = self.multi_line_strings.as_mut() {
+            multi_line_strings.affine_transform_mut(transform);
+        }
+        if let Some(multi_polygons) = self.multi_polygons.as_mut() {
+            multi_polygons.affine_transform_mut(transform);
+        }
+    }
+}
+
+impl<O: OffsetSizeTrait> AffineOpsMut<&AffineTransform> for GeometryCollectionArray<O> {
+    fn affine_transform_mut(&mut self, transform: &AffineTransform) {
+        self.array.affine_transform_mut(transform);
+    }
+}
+
+impl<G> AffineOpsMut<&AffineTransform> for ChunkedGeometryArray<G>
+where
+    G: GeometryArrayTrait + for<'a> AffineOpsMut<&'a AffineTransform>,
+{
+    fn affine_transform_mut(&mut self, transform: &AffineTransform) {
+        self.chunks
+            .iter_mut()
+            .for_each(|chunk| chunk.affine_transform_mut(transform));
+    }
+}
+
 // ┌────────────────────────────────┐
 // │ Implementations for RHS arrays │
 // └────────────────────────────────┘
@@ -256,6 +375,28 @@ impl AffineOps<&[AffineTransform]> for PointArray {
     }
 }
 
+/// A `None` transform makes the output geometry null.
+impl AffineOps<&[Option<AffineTransform>]> for PointArray {
+    type Output = Self;
+
+    fn affine_transform(&self, transform: &[Option<AffineTransform>]) -> Self::Output {
+        let mut output_array = PointBuilder::with_capacity(self.buffer_lengths());
+
+        self.iter_geo()
+            .zip(transform.iter())
+            .for_each(|(maybe_g, maybe_transform)| {
+                output_array.push_point(
+                    maybe_g
+                        .zip(maybe_transform.as_ref())
+                        .map(|(geom, transform)| geom.map_coords(|coord| transform.apply(coord)))
+                        .as_ref(),
+                )
+            });
+
+        output_array.finish()
+    }
+}
+
 /// Implementation that iterates over geo objects
 macro_rules! iter_geo_impl2 {
     ($type:ty, $builder_type:ty, $push_func:ident) => {
@@ -280,6 +421,32 @@ macro_rules! iter_geo_impl2 {
                 output_array.finish()
             }
         }
+
+        /// A `None` transform makes the output geometry null.
+        impl<O: OffsetSizeTrait> AffineOps<&[Option<AffineTransform>]> for $type {
+            type Output = Self;
+
+            fn affine_transform(&self, transform: &[Option<AffineTransform>]) -> Self::Output {
+                let mut output_array = <$builder_type>::with_capacity(self.buffer_lengths());
+
+                self.iter_geo()
+                    .zip(transform.iter())
+                    .for_each(|(maybe_g, maybe_transform)| {
+                        output_array
+                            .$push_func(
+                                maybe_g
+                                    .zip(maybe_transform.as_ref())
+                                    .map(|(geom, transform)| {
+                                        geom.map_coords(|coord| transform.apply(coord))
+                                    })
+                                    .as_ref(),
+                            )
+                            .unwrap()
+                    });
+
+                output_array.finish()
+            }
+        }
     };
 }
 
@@ -358,3 +525,251 @@ impl AffineOps<&[AffineTransform]> for &dyn GeometryArrayTrait {
         Ok(result)
     }
 }
+
+// ┌────────────────────────────────────────────┐
+// │ Implementations for RHS broadcastable vecs │
+// └────────────────────────────────────────────┘
+
+/// Apply `transform` to each chunk, where `transform` is either a single transform applied to
+/// every chunk or one transform per row, split at the chunk boundaries.
+fn chunked_affine_transform<G>(
+    chunks: &[G],
+    transform: &BroadcastableVec<AffineTransform>,
+) -> Result<Vec<G>>
+where
+    G: GeometryArrayTrait
+        + for<'a> AffineOps<&'a AffineTransform, Output = G>
+        + for<'a> AffineOps<&'a [AffineTransform], Output = G>,
+{
+    let transforms = match transform {
+        BroadcastableVec::Scalar(transform) => {
+            return Ok(chunks
+                .iter()
+                .map(|chunk| AffineOps::<&AffineTransform>::affine_transform(chunk, transform))
+                .collect());
+        }
+        BroadcastableVec::Array(transforms) if transforms.len() == 1 => {
+            return Ok(chunks
+                .iter()
+                .map(|chunk| AffineOps::<&AffineTransform>::affine_transform(chunk, &transforms[0]))
+                .collect());
+        }
+        BroadcastableVec::Array(transforms) => transforms,
+    };
+
+    let num_rows = chunks.iter().map(|chunk| chunk.len()).sum::<usize>();
+    if transforms.len() != num_rows {
+        return Err(GeoArrowError::General(format!(
+            "Expected 1 or {} transforms, got {}",
+            num_rows,
+            transforms.len()
+        )));
+    }
+
+    let mut offset = 0;
+    Ok(chunks
+        .iter()
+        .map(|chunk| {
+            let chunk_transforms = &transforms[offset..offset + chunk.len()];
+            offset += chunk.len();
+            AffineOps::<&[AffineTransform]>::affine_transform(chunk, chunk_transforms)
+        })
+        .collect())
+}
+
+impl AffineOps<&BroadcastableVec<AffineTransform>> for ChunkedPointArray {
+    type Output = Result<Self>;
+
+    fn affine_transform(&self, transform: &BroadcastableVec<AffineTransform>) -> Self::Output {
+        Ok(ChunkedGeometryArray::new_unchecked(
+            chunked_affine_transform(self.chunks(), transform)?,
+        ))
+    }
+}
+
+macro_rules! impl_chunked_broadcast {
+    ($struct_name:ty) => {
+        impl<O: OffsetSizeTrait> AffineOps<&BroadcastableVec<AffineTransform>> for $struct_name {
+            type Output = Result<Self>;
+
+            fn affine_transform(
+                &self,
+                transform: &BroadcastableVec<AffineTransform>,
+            ) -> Self::Output {
+                Ok(ChunkedGeometryArray::new_unchecked(
+                    chunked_affine_transform(self.chunks(), transform)?,
+                ))
+            }
+        }
+    };
+}
+
+impl_chunked_broadcast!(ChunkedLineStringArray<O>);
+impl_chunked_broadcast!(ChunkedPolygonArray<O>);
+impl_chunked_broadcast!(ChunkedMultiPointArray<O>);
+impl_chunked_broadcast!(ChunkedMultiLineStringArray<O>);
+impl_chunked_broadcast!(ChunkedMultiPolygonArray<O>);
+impl_chunked_broadcast!(ChunkedMixedGeometryArray<O>);
+impl_chunked_broadcast!(ChunkedGeometryCollectionArray<O>);
+
+impl AffineOps<&BroadcastableVec<AffineTransform>> for &dyn ChunkedGeometryArrayTrait {
+    type Output = Result<Arc<dyn ChunkedGeometryArrayTrait>>;
+
+    fn affine_transform(&self, transform: &BroadcastableVec<AffineTransform>) -> Self::Output {
+        macro_rules! impl_downcast {
+            ($method:ident) => {
+                Arc::new(self.$method().affine_transform(transform)?)
+            };
+        }
+        use GeoDataType::*;
+
+        let result: Arc<dyn ChunkedGeometryArrayTrait> = match self.data_type() {
+            Point(_) => impl_downcast!(as_point),
+            LineString(_) => impl_downcast!(as_line_string),
+            LargeLineString(_) => impl_downcast!(as_large_line_string),
+            Polygon(_) => impl_downcast!(as_polygon),
+            LargePolygon(_) => impl_downcast!(as_large_polygon),
+            MultiPoint(_) => impl_downcast!(as_multi_point),
+            LargeMultiPoint(_) => impl_downcast!(as_large_multi_point),
+            MultiLineString(_) => impl_downcast!(as_multi_line_string),
+            LargeMultiLineString(_) => impl_downcast!(as_large_multi_line_string),
+            MultiPolygon(_) => impl_downcast!(as_multi_polygon),
+            LargeMultiPolygon(_) => impl_downcast!(as_large_multi_polygon),
+            Mixed(_) => impl_downcast!(as_mixed),
+            LargeMixed(_) => impl_downcast!(as_large_mixed),
+            GeometryCollection(_) => impl_downcast!(as_geometry_collection),
+            LargeGeometryCollection(_) => impl_downcast!(as_large_geometry_collection),
+            _ => return Err(GeoArrowError::IncorrectType("".into())),
+        };
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::{linestring, point, polygon};
+
+    #[test]
+    fn chunked_transforms_straddle_chunk_boundary() {
+        let chunked = ChunkedPointArray::new_unchecked(vec![
+            vec![point::p0(), point::p1()].as_slice().into(),
+            vec![point::p2()].as_slice().into(),
+        ]);
+        let transforms = BroadcastableVec::Array(vec![
+            AffineTransform::translate(1., 0.),
+            AffineTransform::translate(2., 0.),
+            AffineTransform::translate(3., 0.),
+        ]);
+        let result = chunked.affine_transform(&transforms).unwrap();
+        assert_eq!(result.chunks().len(), 2);
+        assert_eq!(result.chunks()[0].len(), 2);
+        assert_eq!(
+            result.chunks()[0].value_as_geo(1),
+            point::p1().map_coords(|c| AffineTransform::translate(2., 0.).apply(c))
+        );
+        assert_eq!(
+            result.chunks()[1].value_as_geo(0),
+            point::p2().map_coords(|c| AffineTransform::translate(3., 0.).apply(c))
+        );
+
+        let too_few = BroadcastableVec::Array(vec![AffineTransform::identity(); 2]);
+        assert!(chunked.affine_transform(&too_few).is_err());
+    }
+
+    #[test]
+    fn chunked_dyn_broadcasts_single_transform() {
+        let chunked = ChunkedLineStringArray::new_unchecked(vec![
+            linestring::ls_array(),
+            linestring::ls_array(),
+        ]);
+        let transform = AffineTransform::scale(2., 2., geo::Point::new(0., 0.));
+        let transforms = BroadcastableVec::Array(vec![transform]);
+        let result = (&chunked as &dyn ChunkedGeometryArrayTrait)
+            .affine_transform(&transforms)
+            .unwrap();
+        let result = result.as_ref().as_line_string();
+        assert_eq!(
+            result.chunks()[1].value_as_geo(1),
+            linestring::ls1().map_coords(|c| transform.apply(c))
+        );
+    }
+
+    #[test]
+    fn affine_transform_matches_geo() {
+        let transform = AffineTransform::rotate(30., (1., 2.).into()).translated(3., 4.);
+        let polygons: PolygonArray<i32> =
+            vec![Some(polygon::p0()), None, Some(polygon::p1())].into();
+        for coord_type in [CoordType::Interleaved, CoordType::Separated] {
+            let arr = polygons.clone().into_coord_type(coord_type);
+            let result = arr.affine_transform(&transform);
+            assert_eq!(result.coord_type(), coord_type);
+            assert_eq!(result.validity(), arr.validity());
+            assert!(result.is_null(1));
+            assert_eq!(
+                result.value_as_geo(2),
+                polygon::p1().map_coords(|c| transform.apply(c))
+            );
+        }
+
+        let geoms: Vec<geo::Geometry> = vec![point::p0().into(), polygon::p0().into()];
+        let mixed = MixedGeometryArray::<i32>::try_from(geoms.as_slice()).unwrap();
+        let result = mixed.affine_transform(&transform);
+        assert_eq!(
+            result.value_as_geo(1),
+            geo::Geometry::Polygon(polygon::p0().map_coords(|c| transform.apply(c)))
+        );
+
+        let wkb = WKBArray::<i32>::try_from(geoms.as_slice()).unwrap();
+        let result = wkb.affine_transform(&transform).unwrap();
+        assert_eq!(
+            result.value_as_geo(1),
+            mixed.affine_transform(&transform).value_as_geo(1)
+        );
+    }
+
+    fn coords_ptr(coords: &CoordBuffer) -> *const f64 {
+        match coords {
+            CoordBuffer::Interleaved(c) => c.coords.as_ptr(),
+            CoordBuffer::Separated(c) => c.x.as_ptr(),
+        }
+    }
+
+    #[test]
+    fn affine_transform_mut_reuses_buffers() {
+        let transform = AffineTransform::translate(1., 2.).scaled(2., 2., (0., 0.).into());
+        for coord_type in [CoordType::Interleaved, CoordType::Separated] {
+            let polygons = polygon::p_array().into_coord_type(coord_type);
+            let expected = polygons.affine_transform(&transform);
+
+            let mut arr = polygons.clone();
+            // Drop the other reference to the buffers so that they are uniquely owned
+            drop(polygons);
+            let ptr = coords_ptr(&arr.coords);
+            arr.affine_transform_mut(&transform);
+            assert_eq!(coords_ptr(&arr.coords), ptr);
+            assert_eq!(arr, expected);
+        }
+    }
+
+    #[test]
+    fn affine_transform_mut_copies_shared_buffers() {
+        let transform = AffineTransform::translate(1., 2.);
+        let points: PointArray = vec![Some(point::p0()), None, Some(point::p2())].into();
+        let mut arr = points.clone();
+        arr.affine_transform_mut(&transform);
+
+        // The clone is unaffected
+        assert_eq!(points.value_as_geo(0), point::p0());
+        assert_eq!(
+            arr.value_as_geo(0),
+            point::p0().map_coords(|c| transform.apply(c))
+        );
+        assert_eq!(arr.validity(), points.validity());
+        assert!(arr.is_null(1));
+
+        let mut chunked = ChunkedPointArray::new_unchecked(vec![points.clone(), points]);
+        chunked.affine_transform_mut(&transform);
+        assert_eq!(chunked.chunks()[1], arr);
+    }
+}
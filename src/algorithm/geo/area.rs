@@ -163,12 +163,12 @@ impl<G: GeometryArrayTrait> Area for ChunkedGeometryArray<G> {
     type Output = Result<ChunkedArray<Float64Array>>;
 
     fn signed_area(&self) -> Self::Output {
-        self.try_map(|chunk| chunk.as_ref().signed_area())?
+        self.try_map_non_null(|chunk| chunk.as_ref().signed_area())?
             .try_into()
     }
 
     fn unsigned_area(&self) -> Self::Output {
-        self.try_map(|chunk| chunk.as_ref().unsigned_area())?
+        self.try_map_non_null(|chunk| chunk.as_ref().unsigned_area())?
             .try_into()
     }
 }
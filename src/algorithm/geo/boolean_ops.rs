@@ -0,0 +1,631 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::Arc;
+
+use arrow_array::OffsetSizeTrait;
+use geo::{BooleanOps as _BooleanOps, BoundingRect, Intersects};
+
+use crate::algorithm::geo::unary_union::validate_polygons;
+use crate::algorithm::geo::utils::check_aligned_chunks;
+use crate::algorithm::native::MapChunks;
+use crate::array::*;
+use crate::chunked_array::*;
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::geo_traits::GeometryTrait;
+use crate::io::geo::geometry_to_geo;
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+
+// ┌────────────────────────────────┐
+// │ Implementations for RHS arrays │
+// └────────────────────────────────┘
+
+/// Boolean operations between the polygons of each row of two arrays, wrapping
+/// [`geo::BooleanOps`].
+///
+/// Both arrays must contain polygons or multi polygons, and must have the same length. The result
+/// is a [`MultiPolygonArray`] with the offset type and metadata of the left array. The result is
+/// null where either geometry is null, and an empty `MultiPolygon` where the result of the
+/// operation is empty, such as the intersection of two disjoint polygons.
+///
+/// Rows whose bounding boxes don't intersect are computed without a boolean operation. Polygons
+/// with non-finite coordinates or rings of fewer than 4 coordinates return an error with their
+/// row index, as does a failure of the boolean operation.
+pub trait BooleanOps<Rhs = Self> {
+    type Output;
+
+    /// The area covered by both geometries.
+    fn intersection(&self, rhs: &Rhs) -> Self::Output;
+
+    /// The area covered by either geometry.
+    fn union(&self, rhs: &Rhs) -> Self::Output;
+
+    /// The area covered by the left geometry but not the right geometry.
+    fn difference(&self, rhs: &Rhs) -> Self::Output;
+
+    /// The area covered by exactly one of the geometries.
+    fn sym_difference(&self, rhs: &Rhs) -> Self::Output;
+}
+
+macro_rules! impl_boolean_ops {
+    ($first:ty, $second:ty) => {
+        impl<O1: OffsetSizeTrait, O2: OffsetSizeTrait> BooleanOps<$second> for $first {
+            type Output = Result<MultiPolygonArray<O1>>;
+
+            fn intersection(&self, rhs: &$second) -> Self::Output {
+                binary_boolean_op(self, rhs, Op::Intersection)
+            }
+
+            fn union(&self, rhs: &$second) -> Self::Output {
+                binary_boolean_op(self, rhs, Op::Union)
+            }
+
+            fn difference(&self, rhs: &$second) -> Self::Output {
+                binary_boolean_op(self, rhs, Op::Difference)
+            }
+
+            fn sym_difference(&self, rhs: &$second) -> Self::Output {
+                binary_boolean_op(self, rhs, Op::SymDifference)
+            }
+        }
+    };
+}
+
+impl_boolean_ops!(PolygonArray<O1>, PolygonArray<O2>);
+impl_boolean_ops!(PolygonArray<O1>, MultiPolygonArray<O2>);
+impl_boolean_ops!(MultiPolygonArray<O1>, PolygonArray<O2>);
+impl_boolean_ops!(MultiPolygonArray<O1>, MultiPolygonArray<O2>);
+
+macro_rules! impl_chunked {
+    ($first:ty, $second:ty) => {
+        impl<O1: OffsetSizeTrait, O2: OffsetSizeTrait> BooleanOps<$second> for $first {
+            type Output = Result<ChunkedMultiPolygonArray<O1>>;
+
+            fn intersection(&self, rhs: &$second) -> Self::Output {
+                chunked_boolean_op(self, rhs, Op::Intersection)
+            }
+
+            fn union(&self, rhs: &$second) -> Self::Output {
+                chunked_boolean_op(self, rhs, Op::Union)
+            }
+
+            fn difference(&self, rhs: &$second) -> Self::Output {
+                chunked_boolean_op(self, rhs, Op::Difference)
+            }
+
+            fn sym_difference(&self, rhs: &$second) -> Self::Output {
+                chunked_boolean_op(self, rhs, Op::SymDifference)
+            }
+        }
+    };
+}
+
+impl_chunked!(ChunkedPolygonArray<O1>, ChunkedPolygonArray<O2>);
+impl_chunked!(ChunkedPolygonArray<O1>, ChunkedMultiPolygonArray<O2>);
+impl_chunked!(ChunkedMultiPolygonArray<O1>, ChunkedPolygonArray<O2>);
+impl_chunked!(ChunkedMultiPolygonArray<O1>, ChunkedMultiPolygonArray<O2>);
+
+impl BooleanOps for &dyn GeometryArrayTrait {
+    type Output = Result<Arc<dyn GeometryArrayTrait>>;
+
+    fn intersection(&self, rhs: &Self) -> Self::Output {
+        dyn_boolean_op(*self, *rhs, Op::Intersection)
+    }
+
+    fn union(&self, rhs: &Self) -> Self::Output {
+        dyn_boolean_op(*self, *rhs, Op::Union)
+    }
+
+    fn difference(&self, rhs: &Self) -> Self::Output {
+        dyn_boolean_op(*self, *rhs, Op::Difference)
+    }
+
+    fn sym_difference(&self, rhs: &Self) -> Self::Output {
+        dyn_boolean_op(*self, *rhs, Op::SymDifference)
+    }
+}
+
+impl BooleanOps for &dyn ChunkedGeometryArrayTrait {
+    type Output = Result<Arc<dyn ChunkedGeometryArrayTrait>>;
+
+    fn intersection(&self, rhs: &Self) -> Self::Output {
+        dyn_chunked_boolean_op(*self, *rhs, Op::Intersection)
+    }
+
+    fn union(&self, rhs: &Self) -> Self::Output {
+        dyn_chunked_boolean_op(*self, *rhs, Op::Union)
+    }
+
+    fn difference(&self, rhs: &Self) -> Self::Output {
+        dyn_chunked_boolean_op(*self, *rhs, Op::Difference)
+    }
+
+    fn sym_difference(&self, rhs: &Self) -> Self::Output {
+        dyn_chunked_boolean_op(*self, *rhs, Op::SymDifference)
+    }
+}
+
+// ┌─────────────────────────────────┐
+// │ Implementations for RHS scalars │
+// └─────────────────────────────────┘
+
+/// Boolean operations between the polygons of each row of an array and a single polygon, multi
+/// polygon or rect, as with [`BooleanOps`].
+///
+/// The bounding box of the scalar is computed once, and rows whose bounding boxes don't intersect
+/// it are computed without a boolean operation. So the intersection of a large array with a small
+/// clipping polygon is cheap for the rows outside of the clipping polygon, which are empty
+/// `MultiPolygon`s in the result.
+///
+/// A scalar of another geometry type returns an error.
+pub trait BooleanOpsScalar<Rhs> {
+    type Output;
+
+    /// The area covered by both geometries.
+    fn intersection(&self, rhs: &Rhs) -> Self::Output;
+
+    /// The area covered by either geometry.
+    fn union(&self, rhs: &Rhs) -> Self::Output;
+
+    /// The area covered by the left geometry but not the right geometry.
+    fn difference(&self, rhs: &Rhs) -> Self::Output;
+
+    /// The area covered by exactly one of the geometries.
+    fn sym_difference(&self, rhs: &Rhs) -> Self::Output;
+}
+
+macro_rules! impl_boolean_ops_scalar {
+    ($array:ty, $output:ty, $func:ident) => {
+        impl<O: OffsetSizeTrait, G: GeometryTrait<T = f64>> BooleanOpsScalar<G> for $array {
+            type Output = Result<$output>;
+
+            fn intersection(&self, rhs: &G) -> Self::Output {
+                $func(self, &Scalar::try_new(rhs)?, Op::Intersection)
+            }
+
+            fn union(&self, rhs: &G) -> Self::Output {
+                $func(self, &Scalar::try_new(rhs)?, Op::Union)
+            }
+
+            fn difference(&self, rhs: &G) -> Self::Output {
+                $func(self, &Scalar::try_new(rhs)?, Op::Difference)
+            }
+
+            fn sym_difference(&self, rhs: &G) -> Self::Output {
+                $func(self, &Scalar::try_new(rhs)?, Op::SymDifference)
+            }
+        }
+    };
+}
+
+impl_boolean_ops_scalar!(PolygonArray<O>, MultiPolygonArray<O>, scalar_boolean_op);
+impl_boolean_ops_scalar!(
+    MultiPolygonArray<O>,
+    MultiPolygonArray<O>,
+    scalar_boolean_op
+);
+impl_boolean_ops_scalar!(
+    ChunkedPolygonArray<O>,
+    ChunkedMultiPolygonArray<O>,
+    chunked_scalar_boolean_op
+);
+impl_boolean_ops_scalar!(
+    ChunkedMultiPolygonArray<O>,
+    ChunkedMultiPolygonArray<O>,
+    chunked_scalar_boolean_op
+);
+
+macro_rules! impl_dyn_scalar {
+    ($array:ty, $output:ty, $func:ident) => {
+        impl<G: GeometryTrait<T = f64>> BooleanOpsScalar<G> for $array {
+            type Output = Result<$output>;
+
+            fn intersection(&self, rhs: &G) -> Self::Output {
+                $func(*self, &Scalar::try_new(rhs)?, Op::Intersection)
+            }
+
+            fn union(&self, rhs: &G) -> Self::Output {
+                $func(*self, &Scalar::try_new(rhs)?, Op::Union)
+            }
+
+            fn difference(&self, rhs: &G) -> Self::Output {
+                $func(*self, &Scalar::try_new(rhs)?, Op::Difference)
+            }
+
+            fn sym_difference(&self, rhs: &G) -> Self::Output {
+                $func(*self, &Scalar::try_new(rhs)?, Op::SymDifference)
+            }
+        }
+    };
+}
+
+impl_dyn_scalar!(
+    &dyn GeometryArrayTrait,
+    Arc<dyn GeometryArrayTrait>,
+    dyn_scalar_boolean_op
+);
+impl_dyn_scalar!(
+    &dyn ChunkedGeometryArrayTrait,
+    Arc<dyn ChunkedGeometryArrayTrait>,
+    dyn_chunked_scalar_boolean_op
+);
+
+// ┌────────────────┐
+// │ Implementation │
+// └────────────────┘
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Intersection,
+    Union,
+    Difference,
+    SymDifference,
+}
+
+impl Op {
+    fn name(&self) -> &'static str {
+        match self {
+            Op::Intersection => "intersection",
+            Op::Union => "union",
+            Op::Difference => "difference",
+            Op::SymDifference => "symmetric difference",
+        }
+    }
+
+    /// Apply the operation to the polygons of the row `row`.
+    fn apply(
+        &self,
+        row: usize,
+        left: geo::MultiPolygon,
+        right: &geo::MultiPolygon,
+        right_bounds: Option<geo::Rect>,
+    ) -> Result<geo::MultiPolygon> {
+        let left = validate_polygons(row, left)?;
+        let overlaps = match (left.bounding_rect(), right_bounds) {
+            (Some(left_bounds), Some(right_bounds)) => left_bounds.intersects(&right_bounds),
+            _ => false,
+        };
+
+        if !overlaps {
+            return Ok(match self {
+                Op::Intersection => geo::MultiPolygon::new(vec![]),
+                Op::Difference => left,
+                Op::Union | Op::SymDifference => {
+                    let mut polygons = left.0;
+                    polygons.extend(right.iter().cloned());
+                    geo::MultiPolygon::new(polygons)
+                }
+            });
+        }
+
+        catch_unwind(AssertUnwindSafe(|| match self {
+            Op::Intersection => left.intersection(right),
+            Op::Union => left.union(right),
+            Op::Difference => left.difference(right),
+            Op::SymDifference => left.xor(right),
+        }))
+        .map_err(|_| {
+            GeoArrowError::General(format!(
+                "Failed to compute the {} of the polygons of row {}, which may be invalid",
+                self.name(),
+                row
+            ))
+        })
+    }
+}
+
+/// A validated scalar right-hand side, with its bounding box.
+struct Scalar {
+    geometry: geo::MultiPolygon,
+    bounds: Option<geo::Rect>,
+}
+
+impl Scalar {
+    fn try_new(geometry: &impl GeometryTrait<T = f64>) -> Result<Self> {
+        let geometry = match geometry_to_geo(geometry) {
+            geo::Geometry::Polygon(polygon) => polygon.into(),
+            geo::Geometry::MultiPolygon(multi_polygon) => multi_polygon,
+            geo::Geometry::Rect(rect) => rect.to_polygon().into(),
+            _ => {
+                return Err(GeoArrowError::IncorrectType(
+                    "Expected a Polygon, MultiPolygon or Rect scalar".into(),
+                ))
+            }
+        };
+        let geometry = validate_polygons(0, geometry).map_err(|_| {
+            GeoArrowError::General("Invalid polygon in the scalar geometry".to_string())
+        })?;
+        Ok(Self {
+            bounds: geometry.bounding_rect(),
+            geometry,
+        })
+    }
+}
+
+/// Iterate over the geometries of a polygon or multi polygon array as `MultiPolygon`s.
+fn iter_multi_polygons(
+    array: &dyn GeometryArrayTrait,
+) -> Result<Box<dyn Iterator<Item = Option<geo::MultiPolygon>> + '_>> {
+    let iter: Box<dyn Iterator<Item = Option<geo::MultiPolygon>> + '_> = match array.data_type() {
+        GeoDataType::Polygon(_) => Box::new(
+            array
+                .as_polygon()
+                .iter_geo()
+                .map(|maybe_polygon| maybe_polygon.map(geo::MultiPolygon::from)),
+        ),
+        GeoDataType::LargePolygon(_) => Box::new(
+            array
+                .as_large_polygon()
+                .iter_geo()
+                .map(|maybe_polygon| maybe_polygon.map(geo::MultiPolygon::from)),
+        ),
+        GeoDataType::MultiPolygon(_) => Box::new(array.as_multi_polygon().iter_geo()),
+        GeoDataType::LargeMultiPolygon(_) => Box::new(array.as_large_multi_polygon().iter_geo()),
+        data_type => {
+            return Err(GeoArrowError::IncorrectType(
+                format!(
+                    "Boolean operations require polygons or multi polygons, not {:?}",
+                    data_type
+                )
+                .into(),
+            ))
+        }
+    };
+    Ok(iter)
+}
+
+fn finish<O: OffsetSizeTrait>(
+    array: &dyn GeometryArrayTrait,
+    geoms: &[Option<geo::MultiPolygon>],
+) -> MultiPolygonArray<O> {
+    MultiPolygonBuilder::from_nullable_multi_polygons(
+        geoms,
+        Some(array.coord_type()),
+        array.metadata(),
+    )
+    .finish()
+}
+
+fn binary_boolean_op<O: OffsetSizeTrait>(
+    left: &dyn GeometryArrayTrait,
+    right: &dyn GeometryArrayTrait,
+    op: Op,
+) -> Result<MultiPolygonArray<O>> {
+    if left.len() != right.len() {
+        return Err(GeoArrowError::General(
+            "Cannot perform binary operation on arrays of different length".to_string(),
+        ));
+    }
+
+    let geoms = iter_multi_polygons(left)?
+        .zip(iter_multi_polygons(right)?)
+        .enumerate()
+        .map(|(row, pair)| match pair {
+            (Some(left), Some(right)) => {
+                let right = validate_polygons(row, right)?;
+                let right_bounds = right.bounding_rect();
+                op.apply(row, left, &right, right_bounds).map(Some)
+            }
+            _ => Ok(None),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(finish(left, &geoms))
+}
+
+fn scalar_boolean_op<O: OffsetSizeTrait>(
+    left: &dyn GeometryArrayTrait,
+    right: &Scalar,
+    op: Op,
+) -> Result<MultiPolygonArray<O>> {
+    let geoms = iter_multi_polygons(left)?
+        .enumerate()
+        .map(|(row, maybe_left)| {
+            maybe_left
+                .map(|left| op.apply(row, left, &right.geometry, right.bounds))
+                .transpose()
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(finish(left, &geoms))
+}
+
+fn dyn_boolean_op(
+    left: &dyn GeometryArrayTrait,
+    right: &dyn GeometryArrayTrait,
+    op: Op,
+) -> Result<Arc<dyn GeometryArrayTrait>> {
+    let result: Arc<dyn GeometryArrayTrait> = match left.data_type() {
+        GeoDataType::LargePolygon(_) | GeoDataType::LargeMultiPolygon(_) => {
+            Arc::new(binary_boolean_op::<i64>(left, right, op)?)
+        }
+        _ => Arc::new(binary_boolean_op::<i32>(left, right, op)?),
+    };
+    Ok(result)
+}
+
+fn dyn_scalar_boolean_op(
+    left: &dyn GeometryArrayTrait,
+    right: &Scalar,
+    op: Op,
+) -> Result<Arc<dyn GeometryArrayTrait>> {
+    let result: Arc<dyn GeometryArrayTrait> = match left.data_type() {
+        GeoDataType::LargePolygon(_) | GeoDataType::LargeMultiPolygon(_) => {
+            Arc::new(scalar_boolean_op::<i64>(left, right, op)?)
+        }
+        _ => Arc::new(scalar_boolean_op::<i32>(left, right, op)?),
+    };
+    Ok(result)
+}
+
+fn chunked_boolean_op<G1, G2, O>(
+    left: &ChunkedGeometryArray<G1>,
+    right: &ChunkedGeometryArray<G2>,
+    op: Op,
+) -> Result<ChunkedMultiPolygonArray<O>>
+where
+    G1: GeometryArrayTrait,
+    G2: GeometryArrayTrait,
+    O: OffsetSizeTrait,
+    ChunkedGeometryArray<G1>: ChunkedGeometryArrayTrait,
+    ChunkedGeometryArray<G2>: ChunkedGeometryArrayTrait,
+{
+    check_aligned_chunks(left, right)?;
+    let chunks = left.try_binary_map(right.chunks(), |(left, right)| {
+        binary_boolean_op(left, right, op)
+    })?;
+    Ok(ChunkedGeometryArray::new_unchecked(chunks))
+}
+
+fn chunked_scalar_boolean_op<G: GeometryArrayTrait, O: OffsetSizeTrait>(
+    left: &ChunkedGeometryArray<G>,
+    right: &Scalar,
+    op: Op,
+) -> Result<ChunkedMultiPolygonArray<O>> {
+    let chunks = left.try_map(|chunk| scalar_boolean_op(chunk, right, op))?;
+    Ok(ChunkedGeometryArray::new_unchecked(chunks))
+}
+
+fn dyn_chunked_boolean_op(
+    left: &dyn ChunkedGeometryArrayTrait,
+    right: &dyn ChunkedGeometryArrayTrait,
+    op: Op,
+) -> Result<Arc<dyn ChunkedGeometryArrayTrait>> {
+    check_aligned_chunks(left, right)?;
+    let pairs = left
+        .geometry_chunks()
+        .into_iter()
+        .zip(right.geometry_chunks());
+    let result: Arc<dyn ChunkedGeometryArrayTrait> = match left.data_type() {
+        GeoDataType::LargePolygon(_) | GeoDataType::LargeMultiPolygon(_) => {
+            Arc::new(ChunkedGeometryArray::new_unchecked(
+                pairs
+                    .map(|(left, right)| binary_boolean_op::<i64>(left, right, op))
+                    .collect::<Result<Vec<_>>>()?,
+            ))
+        }
+        _ => Arc::new(ChunkedGeometryArray::new_unchecked(
+            pairs
+                .map(|(left, right)| binary_boolean_op::<i32>(left, right, op))
+                .collect::<Result<Vec<_>>>()?,
+        )),
+    };
+    Ok(result)
+}
+
+fn dyn_chunked_scalar_boolean_op(
+    left: &dyn ChunkedGeometryArrayTrait,
+    right: &Scalar,
+    op: Op,
+) -> Result<Arc<dyn ChunkedGeometryArrayTrait>> {
+    let result: Arc<dyn ChunkedGeometryArrayTrait> = match left.data_type() {
+        GeoDataType::Polygon(_) => Arc::new(chunked_scalar_boolean_op::<_, i32>(
+            left.as_polygon(),
+            right,
+            op,
+        )?),
+        GeoDataType::LargePolygon(_) => Arc::new(chunked_scalar_boolean_op::<_, i64>(
+            left.as_large_polygon(),
+            right,
+            op,
+        )?),
+        GeoDataType::MultiPolygon(_) => Arc::new(chunked_scalar_boolean_op::<_, i32>(
+            left.as_multi_polygon(),
+            right,
+            op,
+        )?),
+        GeoDataType::LargeMultiPolygon(_) => Arc::new(chunked_scalar_boolean_op::<_, i64>(
+            left.as_large_multi_polygon(),
+            right,
+            op,
+        )?),
+        _ => return Err(GeoArrowError::IncorrectType("".into())),
+    };
+    Ok(result)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::trait_::GeometryArraySelfMethods;
+    use geo::{polygon, Area};
+
+    fn square(x: f64, y: f64, size: f64) -> geo::Polygon {
+        polygon![
+            (x: x, y: y),
+            (x: x + size, y: y),
+            (x: x + size, y: y + size),
+            (x: x, y: y + size),
+        ]
+    }
+
+    fn parcels() -> PolygonArray<i32> {
+        let polygons = vec![
+            Some(square(0., 0., 2.)),
+            None,
+            Some(square(10., 10., 1.)),
+            Some(square(1., 1., 2.)),
+        ];
+        PolygonBuilder::from_nullable_polygons(&polygons, Default::default(), Default::default())
+            .finish()
+    }
+
+    fn areas(arr: &MultiPolygonArray<i32>) -> Vec<Option<f64>> {
+        arr.iter_geo()
+            .map(|maybe_geom| maybe_geom.map(|geom| geom.unsigned_area()))
+            .collect()
+    }
+
+    #[test]
+    fn intersection_scalar() {
+        let parcels = parcels();
+        let clip = geo::Geometry::from(square(1., 1., 1.));
+
+        let result = BooleanOpsScalar::intersection(&parcels, &clip).unwrap();
+        assert_eq!(areas(&result), vec![Some(1.), None, Some(0.), Some(1.)]);
+        assert!(result.get_as_geo(2).unwrap().0.is_empty());
+
+        let result = BooleanOpsScalar::difference(&parcels, &clip).unwrap();
+        assert_eq!(areas(&result), vec![Some(3.), None, Some(1.), Some(3.)]);
+
+        let result = BooleanOpsScalar::sym_difference(&parcels, &clip).unwrap();
+        assert_eq!(areas(&result), vec![Some(3.), None, Some(2.), Some(3.)]);
+
+        let chunked =
+            ChunkedGeometryArray::new_unchecked(vec![parcels.slice(0, 2), parcels.slice(2, 2)]);
+        let result = BooleanOpsScalar::union(&chunked, &clip).unwrap();
+        assert_eq!(areas(&result.chunks()[1]), vec![Some(2.), Some(4.)]);
+
+        let point = geo::Geometry::Point(geo::point!(x: 0., y: 0.));
+        assert!(BooleanOpsScalar::intersection(&parcels, &point).is_err());
+    }
+
+    #[test]
+    fn intersection_arrays() {
+        let parcels = parcels();
+        let other: MultiPolygonArray<i32> = vec![
+            geo::MultiPolygon::from(square(1., 0., 2.)),
+            geo::MultiPolygon::from(square(0., 0., 1.)),
+            geo::MultiPolygon::from(square(0., 0., 1.)),
+            geo::MultiPolygon::from(square(1., 1., 2.)),
+        ]
+        .as_slice()
+        .into();
+
+        let result = BooleanOps::intersection(&parcels, &other).unwrap();
+        assert_eq!(areas(&result), vec![Some(2.), None, Some(0.), Some(4.)]);
+
+        let result = BooleanOps::union(&parcels, &other).unwrap();
+        assert_eq!(areas(&result), vec![Some(6.), None, Some(2.), Some(4.)]);
+
+        let dyn_result = BooleanOps::difference(
+            &(&parcels as &dyn GeometryArrayTrait),
+            &(&other as &dyn GeometryArrayTrait),
+        )
+        .unwrap();
+        assert_eq!(
+            areas(dyn_result.as_ref().as_multi_polygon()),
+            vec![Some(2.), None, Some(1.), Some(0.)]
+        );
+
+        assert!(BooleanOps::intersection(&parcels, &other.slice(0, 2)).is_err());
+    }
+}
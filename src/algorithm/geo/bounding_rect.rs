@@ -1,23 +1,29 @@
+use crate::algorithm::native::bounding_rect::BoundingRect as Bounds;
+use crate::algorithm::native::TotalBounds;
 use crate::array::*;
 use crate::chunked_array::{ChunkedGeometryArray, ChunkedGeometryArrayTrait};
 use crate::datatypes::GeoDataType;
-use crate::error::{GeoArrowError, Result};
+use crate::error::Result;
 use crate::trait_::GeometryArrayAccessor;
 use crate::GeometryArrayTrait;
 use arrow_array::OffsetSizeTrait;
-use geo::algorithm::bounding_rect::BoundingRect as GeoBoundingRect;
 use geo::Rect;
 
 /// Calculation of the bounding rectangle of a geometry.
+///
+/// Bounds are computed from the coordinates in place, without converting geometries to [`geo`]
+/// objects. Null and empty geometries have no bounding rectangle.
 pub trait BoundingRect {
     type Output;
 
-    /// Return the bounding rectangle of a geometry
+    /// Return the bounding rectangle of each geometry, or null for null and empty geometries.
     ///
     /// # Examples
     ///
     /// ```
-    /// use geo::BoundingRect;
+    /// use geoarrow::algorithm::geo::BoundingRect;
+    /// use geoarrow::array::LineStringArray;
+    /// use geoarrow::trait_::GeometryArrayAccessor;
     /// use geo::line_string;
     ///
     /// let line_string = line_string![
@@ -25,8 +31,9 @@ pub trait BoundingRect {
     ///     (x: 42.02f64, y: 116.34),
     ///     (x: 42.02f64, y: 118.34),
     /// ];
+    /// let line_string_array: LineStringArray<i32> = vec![line_string].as_slice().into();
     ///
-    /// let bounding_rect = line_string.bounding_rect().unwrap();
+    /// let bounding_rect = line_string_array.bounding_rect().value_as_geo(0);
     ///
     /// assert_eq!(40.02f64, bounding_rect.min().x);
     /// assert_eq!(42.02f64, bounding_rect.max().x);
@@ -34,47 +41,107 @@ pub trait BoundingRect {
     /// assert_eq!(118.34, bounding_rect.max().y);
     /// ```
     fn bounding_rect(&self) -> Self::Output;
+
+    /// Return the bounding rectangle of all geometries, as `[minx, miny, maxx, maxy]`, or `None`
+    /// if every geometry is null or empty.
+    ///
+    /// This is [`TotalBounds`] without the infinite bounds of an empty array.
+    fn total_bounds(&self) -> Option<[f64; 4]>;
+}
+
+fn bounds_to_rect(bounds: Bounds) -> Option<Rect> {
+    (bounds.minx() <= bounds.maxx()).then(|| bounds.into())
+}
+
+fn bounds_to_array(bounds: Bounds) -> Option<[f64; 4]> {
+    (bounds.minx() <= bounds.maxx())
+        .then(|| [bounds.minx(), bounds.miny(), bounds.maxx(), bounds.maxy()])
 }
 
 impl BoundingRect for PointArray {
     type Output = RectArray;
 
     fn bounding_rect(&self) -> Self::Output {
-        let output_geoms: Vec<Option<Rect>> = self
-            .iter_geo()
-            .map(|maybe_g| maybe_g.map(|geom| geom.bounding_rect()))
-            .collect();
+        self.iter()
+            .map(|maybe_g| {
+                maybe_g.and_then(|geom| {
+                    let mut bounds = Bounds::new();
+                    bounds.add_point(&geom);
+                    bounds_to_rect(bounds)
+                })
+            })
+            .collect()
+    }
+
+    fn total_bounds(&self) -> Option<[f64; 4]> {
+        bounds_to_array(TotalBounds::total_bounds(self))
+    }
+}
+
+impl BoundingRect for RectArray {
+    type Output = RectArray;
+
+    fn bounding_rect(&self) -> Self::Output {
+        self.clone()
+    }
 
-        output_geoms.into()
+    fn total_bounds(&self) -> Option<[f64; 4]> {
+        bounds_to_array(TotalBounds::total_bounds(self))
     }
 }
 
-/// Implementation that iterates over geo objects
-macro_rules! iter_geo_impl {
-    ($type:ty) => {
+/// Implementation that iterates over geometry scalars
+macro_rules! iter_impl {
+    ($type:ty, $func:ident) => {
         impl<O: OffsetSizeTrait> BoundingRect for $type {
             type Output = RectArray;
 
             fn bounding_rect(&self) -> Self::Output {
-                let output_geoms: Vec<Option<Rect>> = self
-                    .iter_geo()
-                    .map(|maybe_g| maybe_g.and_then(|geom| geom.bounding_rect()))
-                    .collect();
+                self.iter()
+                    .map(|maybe_g| {
+                        maybe_g.and_then(|geom| {
+                            let mut bounds = Bounds::new();
+                            bounds.$func(&geom);
+                            bounds_to_rect(bounds)
+                        })
+                    })
+                    .collect()
+            }
 
-                output_geoms.into()
+            fn total_bounds(&self) -> Option<[f64; 4]> {
+                bounds_to_array(TotalBounds::total_bounds(self))
             }
         }
     };
 }
 
-iter_geo_impl!(LineStringArray<O>);
-iter_geo_impl!(PolygonArray<O>);
-iter_geo_impl!(MultiPointArray<O>);
-iter_geo_impl!(MultiLineStringArray<O>);
-iter_geo_impl!(MultiPolygonArray<O>);
-iter_geo_impl!(MixedGeometryArray<O>);
-iter_geo_impl!(GeometryCollectionArray<O>);
-iter_geo_impl!(WKBArray<O>);
+iter_impl!(LineStringArray<O>, add_line_string);
+iter_impl!(PolygonArray<O>, add_polygon);
+iter_impl!(MultiPointArray<O>, add_multi_point);
+iter_impl!(MultiLineStringArray<O>, add_multi_line_string);
+iter_impl!(MultiPolygonArray<O>, add_multi_polygon);
+iter_impl!(MixedGeometryArray<O>, add_geometry);
+iter_impl!(GeometryCollectionArray<O>, add_geometry_collection);
+
+impl<O: OffsetSizeTrait> BoundingRect for WKBArray<O> {
+    type Output = RectArray;
+
+    fn bounding_rect(&self) -> Self::Output {
+        self.iter()
+            .map(|maybe_g| {
+                maybe_g.and_then(|geom| {
+                    let mut bounds = Bounds::new();
+                    bounds.add_geometry(&geom.to_wkb_object());
+                    bounds_to_rect(bounds)
+                })
+            })
+            .collect()
+    }
+
+    fn total_bounds(&self) -> Option<[f64; 4]> {
+        bounds_to_array(TotalBounds::total_bounds(self))
+    }
+}
 
 impl BoundingRect for &dyn GeometryArrayTrait {
     type Output = Result<RectArray>;
@@ -100,10 +167,16 @@ impl BoundingRect for &dyn GeometryArrayTrait {
             GeoDataType::LargeGeometryCollection(_) => {
                 self.as_large_geometry_collection().bounding_rect()
             }
-            _ => return Err(GeoArrowError::IncorrectType("".into())),
+            GeoDataType::Rect => self.as_rect().bounding_rect(),
+            GeoDataType::WKB => self.as_wkb().bounding_rect(),
+            GeoDataType::LargeWKB => self.as_large_wkb().bounding_rect(),
         };
         Ok(result)
     }
+
+    fn total_bounds(&self) -> Option<[f64; 4]> {
+        bounds_to_array(TotalBounds::total_bounds(self))
+    }
 }
 
 impl<G: GeometryArrayTrait> BoundingRect for ChunkedGeometryArray<G> {
@@ -113,6 +186,10 @@ impl<G: GeometryArrayTrait> BoundingRect for ChunkedGeometryArray<G> {
         self.try_map(|chunk| chunk.as_ref().bounding_rect())?
             .try_into()
     }
+
+    fn total_bounds(&self) -> Option<[f64; 4]> {
+        bounds_to_array(TotalBounds::total_bounds(self))
+    }
 }
 
 impl BoundingRect for &dyn ChunkedGeometryArrayTrait {
@@ -139,7 +216,63 @@ impl BoundingRect for &dyn ChunkedGeometryArrayTrait {
             GeoDataType::LargeGeometryCollection(_) => {
                 self.as_large_geometry_collection().bounding_rect()
             }
-            _ => Err(GeoArrowError::IncorrectType("".into())),
+            GeoDataType::Rect => self.as_rect().bounding_rect(),
+            GeoDataType::WKB => self.as_wkb().bounding_rect(),
+            GeoDataType::LargeWKB => self.as_large_wkb().bounding_rect(),
         }
     }
+
+    fn total_bounds(&self) -> Option<[f64; 4]> {
+        bounds_to_array(TotalBounds::total_bounds(self))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::io::wkb::to_wkb;
+    use crate::test::polygon;
+
+    #[test]
+    fn null_and_empty_geometries() {
+        let line_strings: LineStringArray<i32> = vec![
+            Some(geo::line_string![(x: 0., y: 1.), (x: 2., y: -1.)]),
+            Some(geo::LineString::new(vec![])),
+            None,
+        ]
+        .into();
+        let rects = line_strings.bounding_rect();
+        assert_eq!(
+            rects.value_as_geo(0),
+            Rect::new(geo::coord! { x: 0., y: -1. }, geo::coord! { x: 2., y: 1. })
+        );
+        assert!(rects.is_null(1));
+        assert!(rects.is_null(2));
+        assert_eq!(
+            BoundingRect::total_bounds(&line_strings),
+            Some([0., -1., 2., 1.])
+        );
+
+        let empty: LineStringArray<i32> = vec![None::<geo::LineString>].into();
+        assert_eq!(BoundingRect::total_bounds(&empty), None);
+    }
+
+    #[test]
+    fn dyn_rect_and_wkb() {
+        let polygons = polygon::p_array();
+        let expected = polygons.bounding_rect();
+
+        let wkb = to_wkb::<i32>(&polygons);
+        let rects = (&wkb as &dyn GeometryArrayTrait).bounding_rect().unwrap();
+        assert_eq!(rects, expected);
+
+        let rects = (&expected as &dyn GeometryArrayTrait)
+            .bounding_rect()
+            .unwrap();
+        assert_eq!(rects, expected);
+        assert_eq!(
+            BoundingRect::total_bounds(&wkb),
+            BoundingRect::total_bounds(&polygons)
+        );
+    }
 }
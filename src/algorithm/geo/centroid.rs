@@ -1,7 +1,7 @@
 use crate::array::*;
 use crate::chunked_array::{ChunkedGeometryArray, ChunkedGeometryArrayTrait, ChunkedPointArray};
 use crate::datatypes::GeoDataType;
-use crate::error::{GeoArrowError, Result};
+use crate::error::Result;
 use crate::trait_::GeometryArrayAccessor;
 use crate::GeometryArrayTrait;
 use arrow_array::OffsetSizeTrait;
@@ -15,6 +15,13 @@ use geo::algorithm::centroid::Centroid as GeoCentroid;
 /// The geometric centroid of a convex object always lies in the object.
 /// A non-convex object might have a centroid that _is outside the object itself_.
 ///
+/// Empty geometries have no centroid, and null geometries stay null, so both produce a null point
+/// in the output. The output has one point per input geometry, so it can be zipped with other
+/// per-row outputs; for chunked arrays the chunk boundaries of the input are preserved.
+///
+/// WKB arrays are parsed geometry by geometry, so calling this on `&dyn GeometryArrayTrait` never
+/// fails for lack of a native encoding.
+///
 /// # Examples
 ///
 /// ```
@@ -73,6 +80,17 @@ impl Centroid for PointArray {
     }
 }
 
+impl Centroid for RectArray {
+    type Output = PointArray;
+
+    fn centroid(&self) -> Self::Output {
+        let mut output_array = PointBuilder::with_capacity(self.len());
+        self.iter_geo()
+            .for_each(|maybe_g| output_array.push_point(maybe_g.map(|g| g.centroid()).as_ref()));
+        output_array.into()
+    }
+}
+
 /// Implementation that iterates over geo objects
 macro_rules! iter_geo_impl {
     ($type:ty) => {
@@ -121,7 +139,9 @@ impl Centroid for &dyn GeometryArrayTrait {
             GeoDataType::LargeGeometryCollection(_) => {
                 self.as_large_geometry_collection().centroid()
             }
-            _ => return Err(GeoArrowError::IncorrectType("".into())),
+            GeoDataType::Rect => self.as_rect().centroid(),
+            GeoDataType::WKB => self.as_wkb().centroid(),
+            GeoDataType::LargeWKB => self.as_large_wkb().centroid(),
         };
         Ok(result)
     }
@@ -157,7 +177,61 @@ impl Centroid for &dyn ChunkedGeometryArrayTrait {
             GeoDataType::LargeGeometryCollection(_) => {
                 self.as_large_geometry_collection().centroid()
             }
-            _ => Err(GeoArrowError::IncorrectType("".into())),
+            GeoDataType::Rect => self.as_rect().centroid(),
+            GeoDataType::WKB => self.as_wkb().centroid(),
+            GeoDataType::LargeWKB => self.as_large_wkb().centroid(),
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::chunked_array::ChunkedLineStringArray;
+    use crate::io::wkb::to_wkb;
+    use crate::test::polygon;
+
+    #[test]
+    fn empty_geometries_are_null() {
+        let arr: LineStringArray<i32> = vec![
+            Some(geo::line_string![(x: 0., y: 0.), (x: 2., y: 0.)]),
+            Some(geo::LineString::new(vec![])),
+            None,
+        ]
+        .into();
+        let result = arr.centroid();
+        assert_eq!(result.len(), 3);
+        assert_eq!(result.get_as_geo(0), Some(geo::point!(x: 1., y: 0.)));
+        assert!(result.is_null(1));
+        assert!(result.is_null(2));
+    }
+
+    #[test]
+    fn chunk_boundaries_are_preserved() {
+        let chunked = ChunkedLineStringArray::<i32>::new_unchecked(vec![
+            vec![geo::line_string![(x: 0., y: 0.), (x: 2., y: 0.)]]
+                .as_slice()
+                .into(),
+            vec![
+                None,
+                Some(geo::line_string![(x: 0., y: 0.), (x: 0., y: 4.)]),
+            ]
+            .into(),
+        ]);
+        let result = chunked.centroid().unwrap();
+        assert_eq!(result.chunk_lengths(), vec![1, 2]);
+        assert!(result.chunks()[1].is_null(0));
+        assert_eq!(
+            result.chunks()[1].get_as_geo(1),
+            Some(geo::point!(x: 0., y: 2.))
+        );
+    }
+
+    #[test]
+    fn dyn_wkb() {
+        let arr = polygon::p_array();
+        let wkb_arr = to_wkb::<i32>(&arr);
+        let result = (&wkb_arr as &dyn GeometryArrayTrait).centroid().unwrap();
+        assert_eq!(result, arr.centroid());
+    }
+}
@@ -18,7 +18,8 @@ use geo::ChaikinSmoothing as _ChaikinSmoothing;
 /// coordinates.
 ///
 /// This implementation preserves the start and end vertices of an open linestring and
-/// smoothes the corner between start and end of a closed linestring.
+/// smoothes the corner between start and end of a closed linestring. The output keeps the
+/// coordinate type and metadata of the input.
 pub trait ChaikinSmoothing {
     type Output;
 
@@ -29,28 +30,45 @@ pub trait ChaikinSmoothing {
 
 /// Implementation that iterates over geo objects
 macro_rules! iter_geo_impl {
-    ($type:ty, $geo_type:ty) => {
+    ($type:ty, $builder:ident, $push_func:ident) => {
         impl<O: OffsetSizeTrait> ChaikinSmoothing for $type {
             type Output = Self;
 
             fn chaikin_smoothing(&self, n_iterations: u32) -> Self::Output {
-                let output_geoms: Vec<Option<$geo_type>> = self
-                    .iter_geo()
-                    .map(|maybe_g| {
-                        maybe_g.map(|geom| geom.chaikin_smoothing(n_iterations.try_into().unwrap()))
-                    })
-                    .collect();
-
-                output_geoms.into()
+                let n_iterations = n_iterations as usize;
+                // The input's buffer lengths are a lower bound for the output's
+                let mut builder = $builder::with_capacity_and_options(
+                    self.buffer_lengths(),
+                    self.coord_type(),
+                    self.metadata(),
+                );
+                self.iter_geo().for_each(|maybe_g| {
+                    builder
+                        .$push_func(
+                            maybe_g
+                                .map(|geom| geom.chaikin_smoothing(n_iterations))
+                                .as_ref(),
+                        )
+                        .unwrap()
+                });
+                builder.finish()
             }
         }
     };
 }
 
-iter_geo_impl!(LineStringArray<O>, geo::LineString);
-iter_geo_impl!(PolygonArray<O>, geo::Polygon);
-iter_geo_impl!(MultiLineStringArray<O>, geo::MultiLineString);
-iter_geo_impl!(MultiPolygonArray<O>, geo::MultiPolygon);
+iter_geo_impl!(LineStringArray<O>, LineStringBuilder, push_line_string);
+iter_geo_impl!(PolygonArray<O>, PolygonBuilder, push_polygon);
+iter_geo_impl!(
+    MultiLineStringArray<O>,
+    MultiLineStringBuilder,
+    push_multi_line_string
+);
+iter_geo_impl!(
+    MultiPolygonArray<O>,
+    MultiPolygonBuilder,
+    push_multi_polygon
+);
 
 impl ChaikinSmoothing for &dyn GeometryArrayTrait {
     type Output = Result<Arc<dyn GeometryArrayTrait>>;
@@ -93,7 +111,7 @@ macro_rules! impl_chunked {
             type Output = Self;
 
             fn chaikin_smoothing(&self, n_iterations: u32) -> Self::Output {
-                self.map(|chunk| chunk.chaikin_smoothing(n_iterations.into()))
+                self.map(|chunk| chunk.chaikin_smoothing(n_iterations))
                     .try_into()
                     .unwrap()
             }
@@ -140,3 +158,49 @@ impl ChaikinSmoothing for &dyn ChunkedGeometryArrayTrait {
         Ok(result)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use geo::{line_string, polygon};
+
+    #[test]
+    fn smooth_line_strings() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)];
+        let arr: LineStringArray<i32> = vec![Some(line_string), None].into();
+
+        // Each iteration doubles the segments, keeping the start and end of open line strings:
+        // 3 coordinates become 6, then 12
+        let smoothed = arr.chaikin_smoothing(2);
+        assert_eq!(smoothed.geom_offsets.as_ref(), &[0, 12, 12]);
+        assert!(smoothed.is_null(1));
+
+        let geom = smoothed.value_as_geo(0);
+        assert_eq!(geom.0.first(), Some(&geo::coord! { x: 0., y: 0. }));
+        assert_eq!(geom.0.last(), Some(&geo::coord! { x: 4., y: 4. }));
+    }
+
+    #[test]
+    fn smooth_polygons() {
+        let polygon = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+        let arr: MultiPolygonArray<i32> = vec![geo::MultiPolygon(vec![polygon.clone(), polygon])]
+            .as_slice()
+            .into();
+
+        // A closed ring of 5 coordinates becomes 9, 17, then 33, and stays closed
+        let smoothed = arr.chaikin_smoothing(3);
+        assert_eq!(smoothed.geom_offsets.as_ref(), &[0, 2]);
+        assert_eq!(smoothed.ring_offsets.as_ref(), &[0, 33, 66]);
+        assert!(smoothed.value_as_geo(0).0[0].exterior().is_closed());
+
+        let chunked = ChunkedGeometryArray::new_unchecked(vec![arr.clone(), arr]);
+        let chunked: &dyn ChunkedGeometryArrayTrait = &chunked;
+        let smoothed = chunked.chaikin_smoothing(3).unwrap();
+        assert_eq!(
+            smoothed.as_ref().as_multi_polygon().chunks()[1]
+                .ring_offsets
+                .as_ref(),
+            &[0, 33, 66]
+        );
+    }
+}
@@ -170,7 +170,15 @@ impl ChamberlainDuquetteArea for &dyn GeometryArrayTrait {
             GeoDataType::LargeGeometryCollection(_) => self
                 .as_large_geometry_collection()
                 .chamberlain_duquette_signed_area(),
-            _ => return Err(GeoArrowError::IncorrectType("".into())),
+            _ => {
+                return Err(GeoArrowError::IncorrectType(
+                    format!(
+                        "Chamberlain-Duquette area of {:?} is not supported",
+                        self.data_type()
+                    )
+                    .into(),
+                ))
+            }
         };
         Ok(result)
     }
@@ -216,7 +224,15 @@ impl ChamberlainDuquetteArea for &dyn GeometryArrayTrait {
             GeoDataType::LargeGeometryCollection(_) => self
                 .as_large_geometry_collection()
                 .chamberlain_duquette_unsigned_area(),
-            _ => return Err(GeoArrowError::IncorrectType("".into())),
+            _ => {
+                return Err(GeoArrowError::IncorrectType(
+                    format!(
+                        "Chamberlain-Duquette area of {:?} is not supported",
+                        self.data_type()
+                    )
+                    .into(),
+                ))
+            }
         };
         Ok(result)
     }
@@ -226,21 +242,13 @@ impl<G: GeometryArrayTrait> ChamberlainDuquetteArea for ChunkedGeometryArray<G>
     type Output = Result<ChunkedArray<Float64Array>>;
 
     fn chamberlain_duquette_signed_area(&self) -> Self::Output {
-        let mut output_chunks = Vec::with_capacity(self.chunks.len());
-        for chunk in self.chunks.iter() {
-            output_chunks.push(chunk.as_ref().chamberlain_duquette_signed_area()?);
-        }
-
-        Ok(ChunkedArray::new(output_chunks))
+        self.try_map(|chunk| chunk.as_ref().chamberlain_duquette_signed_area())?
+            .try_into()
     }
 
     fn chamberlain_duquette_unsigned_area(&self) -> Self::Output {
-        let mut output_chunks = Vec::with_capacity(self.chunks.len());
-        for chunk in self.chunks.iter() {
-            output_chunks.push(chunk.as_ref().chamberlain_duquette_unsigned_area()?);
-        }
-
-        Ok(ChunkedArray::new(output_chunks))
+        self.try_map(|chunk| chunk.as_ref().chamberlain_duquette_unsigned_area())?
+            .try_into()
     }
 }
 
@@ -282,7 +290,13 @@ impl ChamberlainDuquetteArea for &dyn ChunkedGeometryArrayTrait {
             GeoDataType::LargeGeometryCollection(_) => self
                 .as_large_geometry_collection()
                 .chamberlain_duquette_signed_area(),
-            _ => Err(GeoArrowError::IncorrectType("".into())),
+            _ => Err(GeoArrowError::IncorrectType(
+                format!(
+                    "Chamberlain-Duquette area of {:?} is not supported",
+                    self.data_type()
+                )
+                .into(),
+            )),
         }
     }
 
@@ -327,7 +341,13 @@ impl ChamberlainDuquetteArea for &dyn ChunkedGeometryArrayTrait {
             GeoDataType::LargeGeometryCollection(_) => self
                 .as_large_geometry_collection()
                 .chamberlain_duquette_unsigned_area(),
-            _ => Err(GeoArrowError::IncorrectType("".into())),
+            _ => Err(GeoArrowError::IncorrectType(
+                format!(
+                    "Chamberlain-Duquette area of {:?} is not supported",
+                    self.data_type()
+                )
+                .into(),
+            )),
         }
     }
 }
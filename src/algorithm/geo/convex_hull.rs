@@ -1,12 +1,11 @@
 use crate::array::*;
 use crate::chunked_array::{ChunkedGeometryArray, ChunkedGeometryArrayTrait, ChunkedPolygonArray};
 use crate::datatypes::GeoDataType;
-use crate::error::{GeoArrowError, Result};
+use crate::error::Result;
 use crate::trait_::GeometryArrayAccessor;
 use crate::GeometryArrayTrait;
 use arrow_array::OffsetSizeTrait;
 use geo::algorithm::convex_hull::ConvexHull as GeoConvexHull;
-use geo::Polygon;
 
 /// Returns the convex hull of a Polygon. The hull is always oriented counter-clockwise.
 ///
@@ -44,6 +43,14 @@ use geo::Polygon;
 /// let res = poly.convex_hull();
 /// assert_eq!(res.exterior(), &correct_hull);
 /// ```
+///
+/// ## Degenerate hulls
+///
+/// The hull of a single point, or of points that are all collinear, has no area. These are
+/// emitted as-is, just like [`geo::ConvexHull`] returns them: a single point becomes a polygon
+/// whose exterior ring repeats the point, and collinear points become a polygon whose exterior
+/// ring goes out to the farthest point and back. Only null geometries produce a null polygon, so
+/// the output always has one valid polygon per valid input geometry.
 pub trait ConvexHull<O: OffsetSizeTrait> {
     type Output;
 
@@ -54,12 +61,19 @@ impl<O: OffsetSizeTrait> ConvexHull<O> for PointArray {
     type Output = PolygonArray<O>;
 
     fn convex_hull(&self) -> Self::Output {
-        let output_geoms: Vec<Option<Polygon>> = self
-            .iter_geo()
+        self.iter_geo()
             .map(|maybe_g| maybe_g.map(|geom| geom.convex_hull()))
-            .collect();
+            .collect()
+    }
+}
+
+impl<O: OffsetSizeTrait> ConvexHull<O> for RectArray {
+    type Output = PolygonArray<O>;
 
-        output_geoms.into()
+    fn convex_hull(&self) -> Self::Output {
+        self.iter_geo()
+            .map(|maybe_g| maybe_g.map(|geom| geom.convex_hull()))
+            .collect()
     }
 }
 
@@ -70,12 +84,9 @@ macro_rules! iter_geo_impl {
             type Output = PolygonArray<O>;
 
             fn convex_hull(&self) -> Self::Output {
-                let output_geoms: Vec<Option<Polygon>> = self
-                    .iter_geo()
+                self.iter_geo()
                     .map(|maybe_g| maybe_g.map(|geom| geom.convex_hull()))
-                    .collect();
-
-                output_geoms.into()
+                    .collect()
             }
         }
     };
@@ -112,7 +123,9 @@ impl<O: OffsetSizeTrait> ConvexHull<O> for &dyn GeometryArrayTrait {
             GeoDataType::LargeGeometryCollection(_) => {
                 self.as_large_geometry_collection().convex_hull()
             }
-            _ => return Err(GeoArrowError::IncorrectType("".into())),
+            GeoDataType::Rect => self.as_rect().convex_hull(),
+            GeoDataType::WKB => self.as_wkb().convex_hull(),
+            GeoDataType::LargeWKB => self.as_large_wkb().convex_hull(),
         };
         Ok(result)
     }
@@ -149,7 +162,9 @@ impl<O: OffsetSizeTrait> ConvexHull<O> for &dyn ChunkedGeometryArrayTrait {
             GeoDataType::LargeGeometryCollection(_) => {
                 self.as_large_geometry_collection().convex_hull()
             }
-            _ => Err(GeoArrowError::IncorrectType("".into())),
+            GeoDataType::Rect => self.as_rect().convex_hull(),
+            GeoDataType::WKB => self.as_wkb().convex_hull(),
+            GeoDataType::LargeWKB => self.as_large_wkb().convex_hull(),
         }
     }
 }
@@ -158,9 +173,12 @@ impl<O: OffsetSizeTrait> ConvexHull<O> for &dyn ChunkedGeometryArrayTrait {
 mod tests {
     use super::ConvexHull;
     use crate::array::polygon::PolygonArray;
-    use crate::array::{LineStringArray, MultiPointArray};
-    use crate::trait_::GeometryArrayAccessor;
-    use geo::{line_string, polygon, MultiPoint, Point};
+    use crate::array::{LineStringArray, MultiPointArray, PointArray};
+    use crate::chunked_array::ChunkedGeometryArray;
+    use crate::trait_::{GeometryArrayAccessor, GeometryArraySelfMethods};
+    use crate::GeometryArrayTrait;
+    use geo::ConvexHull as _;
+    use geo::{line_string, point, polygon, Area, MultiPoint, Point};
 
     #[test]
     fn convex_hull_for_multipoint() {
@@ -218,4 +236,54 @@ mod tests {
 
         assert_eq!(expected, result_array.get_as_geo(0).unwrap());
     }
+
+    #[test]
+    fn degenerate_hulls_are_kept() {
+        let points = vec![Some(point!(x: 1., y: 2.)), None];
+        let input_array: PointArray = points.clone().into();
+        let result_array: PolygonArray<i32> = input_array.convex_hull();
+        assert_eq!(result_array.len(), 2);
+        let hull = result_array.get_as_geo(0).unwrap();
+        assert_eq!(hull, points[0].unwrap().convex_hull());
+        assert_eq!(hull.unsigned_area(), 0.);
+        assert!(hull.exterior().points().all(|p| p == point!(x: 1., y: 2.)));
+        assert!(result_array.is_null(1));
+
+        let collinear: MultiPoint =
+            vec![Point::new(0., 0.), Point::new(1., 1.), Point::new(2., 2.)].into();
+        let input_array: MultiPointArray<i32> = vec![collinear.clone()].as_slice().into();
+        let result_array: PolygonArray<i32> = input_array.convex_hull();
+        let hull = result_array.get_as_geo(0).unwrap();
+        assert_eq!(hull, collinear.convex_hull());
+        assert_eq!(hull.unsigned_area(), 0.);
+    }
+
+    #[test]
+    fn chunked_and_dyn() {
+        let input_array: LineStringArray<i32> = vec![
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 0., y: 1.)],
+            line_string![(x: 0., y: 0.), (x: 2., y: 2.)],
+        ]
+        .as_slice()
+        .into();
+        let expected: PolygonArray<i32> = input_array.convex_hull();
+
+        let result: PolygonArray<i32> = (&input_array as &dyn GeometryArrayTrait)
+            .convex_hull()
+            .unwrap();
+        assert_eq!(result, expected);
+
+        let wkb_array = crate::io::wkb::to_wkb::<i32>(&input_array);
+        let result: PolygonArray<i32> = (&wkb_array as &dyn GeometryArrayTrait)
+            .convex_hull()
+            .unwrap();
+        assert_eq!(result, expected);
+
+        let chunked = ChunkedGeometryArray::new_unchecked(vec![
+            input_array.slice(0, 1),
+            input_array.slice(1, 1),
+        ]);
+        let result: ChunkedGeometryArray<PolygonArray<i32>> = chunked.convex_hull().unwrap();
+        assert_eq!(result.chunks()[1].get_as_geo(0), expected.get_as_geo(1));
+    }
 }
@@ -0,0 +1,284 @@
+use std::fmt::Display;
+
+use arrow_array::OffsetSizeTrait;
+use geo::{Area, BooleanOps, BoundingRect, CoordsIter, EuclideanDistance};
+use geo_index::rtree::sort::HilbertSort;
+use geo_index::rtree::{RTreeBuilder, RTreeIndex};
+
+use crate::array::{MultiPolygonArray, PolygonArray};
+use crate::trait_::GeometryArrayAccessor;
+
+/// Two polygons of a coverage whose interiors overlap.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoverageOverlap {
+    /// The row index of the first polygon. This is always less than `second`.
+    pub first: usize,
+    /// The row index of the second polygon.
+    pub second: usize,
+    /// The region covered by both polygons.
+    pub geometry: geo::MultiPolygon,
+    /// The area of `geometry`.
+    pub area: f64,
+}
+
+/// Two polygons of a coverage that come within the gap tolerance of each other without meeting,
+/// leaving a sliver gap between them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoverageGap {
+    /// The row index of the first polygon. This is always less than `second`.
+    pub first: usize,
+    /// The row index of the second polygon.
+    pub second: usize,
+    /// The smallest non-zero distance from a vertex of one polygon to the other polygon.
+    pub width: f64,
+}
+
+/// The result of [`ValidateCoverage::validate_coverage`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CoverageReport {
+    /// The number of non-null, non-empty polygons that were checked.
+    pub num_polygons: usize,
+    /// Every pair of overlapping polygons, sorted by row index.
+    pub overlaps: Vec<CoverageOverlap>,
+    /// Every pair of polygons separated by a sliver gap, sorted by row index.
+    pub gaps: Vec<CoverageGap>,
+}
+
+impl CoverageReport {
+    /// Whether the polygons form a valid coverage, without overlaps or gaps.
+    pub fn is_valid(&self) -> bool {
+        self.overlaps.is_empty() && self.gaps.is_empty()
+    }
+
+    /// The total area of all overlaps.
+    pub fn overlap_area(&self) -> f64 {
+        self.overlaps.iter().map(|overlap| overlap.area).sum()
+    }
+
+    /// Summary statistics of the report.
+    pub fn summary(&self) -> CoverageSummary {
+        CoverageSummary {
+            num_polygons: self.num_polygons,
+            num_overlaps: self.overlaps.len(),
+            overlap_area: self.overlap_area(),
+            num_gaps: self.gaps.len(),
+            max_gap_width: self.gaps.iter().map(|gap| gap.width).reduce(f64::max),
+        }
+    }
+}
+
+/// Summary statistics of a [`CoverageReport`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CoverageSummary {
+    pub num_polygons: usize,
+    pub num_overlaps: usize,
+    pub overlap_area: f64,
+    pub num_gaps: usize,
+    /// The largest gap width, or `None` if there are no gaps.
+    pub max_gap_width: Option<f64>,
+}
+
+impl Display for CoverageSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} polygons, {} overlaps with a total area of {}, {} gaps",
+            self.num_polygons, self.num_overlaps, self.overlap_area, self.num_gaps
+        )?;
+        if let Some(max_gap_width) = self.max_gap_width {
+            write!(f, " up to {} wide", max_gap_width)?;
+        }
+        Ok(())
+    }
+}
+
+/// Check whether polygons form a coverage: a planar partition without overlaps or gaps, as
+/// expected of cadastral parcels or administrative boundaries.
+///
+/// Unlike most algorithms, this analyzes the array as a whole rather than each geometry on its
+/// own. The bounding box of each polygon is put in an R-tree, and only pairs of polygons whose
+/// bounding boxes are within `gap_tolerance` of each other are compared:
+///
+/// - An **overlap** is a pair of polygons whose intersection has a non-zero area. Polygons that
+///   only share edges or vertices don't overlap.
+/// - A **gap** is a pair of polygons where a vertex of one is outside the other, but within
+///   `gap_tolerance` of it without touching it. This finds slivers narrower than the tolerance
+///   between neighboring polygons, but not wider holes, which are assumed to be intentional.
+///   A `gap_tolerance` of zero disables gap detection.
+///
+/// Null and empty polygons are skipped.
+pub trait ValidateCoverage {
+    fn validate_coverage(&self, gap_tolerance: f64) -> CoverageReport;
+}
+
+impl<O: OffsetSizeTrait> ValidateCoverage for PolygonArray<O> {
+    fn validate_coverage(&self, gap_tolerance: f64) -> CoverageReport {
+        validate_coverage(
+            self.iter_geo()
+                .map(|maybe_polygon| maybe_polygon.map(|polygon| polygon.into())),
+            gap_tolerance,
+        )
+    }
+}
+
+impl<O: OffsetSizeTrait> ValidateCoverage for MultiPolygonArray<O> {
+    fn validate_coverage(&self, gap_tolerance: f64) -> CoverageReport {
+        validate_coverage(self.iter_geo(), gap_tolerance)
+    }
+}
+
+fn validate_coverage(
+    geoms: impl Iterator<Item = Option<geo::MultiPolygon>>,
+    gap_tolerance: f64,
+) -> CoverageReport {
+    let mut rows = vec![];
+    let mut polygons = vec![];
+    let mut bounds = vec![];
+    for (row, maybe_multi_polygon) in geoms.enumerate() {
+        let Some(multi_polygon) = maybe_multi_polygon else {
+            continue;
+        };
+        let Some(rect) = multi_polygon.bounding_rect() else {
+            continue;
+        };
+        rows.push(row);
+        polygons.push(multi_polygon);
+        bounds.push(rect);
+    }
+
+    let mut report = CoverageReport {
+        num_polygons: polygons.len(),
+        ..Default::default()
+    };
+    if polygons.is_empty() {
+        return report;
+    }
+
+    let mut builder = RTreeBuilder::new(bounds.len());
+    for rect in bounds.iter() {
+        builder.add(rect.min().x, rect.min().y, rect.max().x, rect.max().y);
+    }
+    let rtree = builder.finish::<HilbertSort>();
+
+    let gap_tolerance = gap_tolerance.max(0.);
+    for (first, rect) in bounds.iter().enumerate() {
+        let mut candidates = rtree.search(
+            rect.min().x - gap_tolerance,
+            rect.min().y - gap_tolerance,
+            rect.max().x + gap_tolerance,
+            rect.max().y + gap_tolerance,
+        );
+        // Insertion order is row order, and each pair is visited once
+        candidates.retain(|&second| second > first);
+        candidates.sort_unstable();
+
+        for second in candidates {
+            let (first_polygon, second_polygon) = (&polygons[first], &polygons[second]);
+
+            let intersection = first_polygon.intersection(second_polygon);
+            let area = intersection.unsigned_area();
+            if area > 0. {
+                report.overlaps.push(CoverageOverlap {
+                    first: rows[first],
+                    second: rows[second],
+                    geometry: intersection,
+                    area,
+                });
+            }
+
+            if gap_tolerance > 0. {
+                let width = gap_width(first_polygon, second_polygon, gap_tolerance)
+                    .into_iter()
+                    .chain(gap_width(second_polygon, first_polygon, gap_tolerance))
+                    .reduce(f64::min);
+                if let Some(width) = width {
+                    report.gaps.push(CoverageGap {
+                        first: rows[first],
+                        second: rows[second],
+                        width,
+                    });
+                }
+            }
+        }
+    }
+
+    report
+}
+
+/// The smallest non-zero distance, up to `gap_tolerance`, from a vertex of `polygon` to `other`.
+///
+/// Vertices inside or on the boundary of `other` are at distance zero and are ignored.
+fn gap_width(
+    polygon: &geo::MultiPolygon,
+    other: &geo::MultiPolygon,
+    gap_tolerance: f64,
+) -> Option<f64> {
+    polygon
+        .coords_iter()
+        .map(|coord| geo::Point::from(coord).euclidean_distance(other))
+        .filter(|&distance| distance > 0. && distance <= gap_tolerance)
+        .reduce(f64::min)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use geo::polygon;
+
+    fn rect(min_x: f64, min_y: f64, max_x: f64, max_y: f64) -> geo::Polygon {
+        polygon![
+            (x: min_x, y: min_y),
+            (x: max_x, y: min_y),
+            (x: max_x, y: max_y),
+            (x: min_x, y: max_y),
+        ]
+    }
+
+    #[test]
+    fn overlap_and_gap() {
+        let polygons: PolygonArray<i32> = vec![
+            Some(rect(0., 0., 1., 1.)),
+            None,
+            // Overlaps the first polygon by 0.1 x 1
+            Some(rect(0.9, 0., 2., 1.)),
+            // Leaves a 0.01 sliver above the first polygon
+            Some(rect(0., 1.01, 0.8, 2.)),
+        ]
+        .into();
+        let report = polygons.validate_coverage(0.05);
+        assert!(!report.is_valid());
+        assert_eq!(report.num_polygons, 3);
+
+        assert_eq!(report.overlaps.len(), 1);
+        let overlap = &report.overlaps[0];
+        assert_eq!((overlap.first, overlap.second), (0, 2));
+        approx::assert_relative_eq!(overlap.area, 0.1, epsilon = 1e-9);
+        approx::assert_relative_eq!(overlap.geometry.unsigned_area(), 0.1, epsilon = 1e-9);
+
+        assert_eq!(report.gaps.len(), 1);
+        let gap = &report.gaps[0];
+        assert_eq!((gap.first, gap.second), (0, 3));
+        approx::assert_relative_eq!(gap.width, 0.01, epsilon = 1e-9);
+
+        let summary = report.summary();
+        assert_eq!(summary.num_overlaps, 1);
+        assert_eq!(summary.num_gaps, 1);
+
+        // Without a tolerance, only the overlap is reported
+        let report = polygons.validate_coverage(0.);
+        assert_eq!(report.overlaps.len(), 1);
+        assert!(report.gaps.is_empty());
+    }
+
+    #[test]
+    fn valid_coverage() {
+        let multi_polygons: MultiPolygonArray<i32> = vec![
+            geo::MultiPolygon::new(vec![rect(0., 0., 1., 1.)]),
+            geo::MultiPolygon::new(vec![rect(1., 0., 2., 1.), rect(0., 1., 2., 2.)]),
+        ]
+        .as_slice()
+        .into();
+        let report = multi_polygons.validate_coverage(0.05);
+        assert!(report.is_valid(), "{}", report.summary());
+    }
+}
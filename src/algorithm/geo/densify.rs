@@ -7,11 +7,14 @@ use crate::error::{GeoArrowError, Result};
 use crate::trait_::GeometryArrayAccessor;
 use crate::GeometryArrayTrait;
 use arrow_array::OffsetSizeTrait;
-use geo::Densify as _Densify;
+use geo::{Densify as _Densify, DensifyHaversine as _DensifyHaversine};
 
 /// Return a new linear geometry containing both existing and new interpolated coordinates with
 /// a maximum distance of `max_distance` between them.
 ///
+/// The original coordinates are kept, so the output has at least as many coordinates as the
+/// input, and the coordinate type and metadata of the input are preserved. Rings stay closed.
+///
 /// Note: `max_distance` must be greater than 0.
 ///
 /// # Examples
@@ -31,63 +34,168 @@ pub trait Densify {
     fn densify(&self, max_distance: f64) -> Self::Output;
 }
 
+/// Return a new linear geometry containing both existing and new interpolated coordinates, with
+/// a maximum [haversine distance] of `max_distance` meters between them.
+///
+/// This is the counterpart of [`Densify`] for longitude/latitude coordinates, where new
+/// coordinates are placed along the great circle between existing ones. Densifying along great
+/// circles before reprojecting keeps long segments from cutting corners in the projected output.
+///
+/// Note: `max_distance` must be greater than 0.
+///
+/// [haversine distance]: https://en.wikipedia.org/wiki/Haversine_formula
+pub trait DensifyHaversine {
+    type Output;
+
+    fn densify_haversine(&self, max_distance: f64) -> Self::Output;
+}
+
 /// Implementation that iterates over geo objects
 macro_rules! iter_geo_impl {
-    ($type:ty, $geo_type:ty) => {
-        impl<O: OffsetSizeTrait> Densify for $type {
+    ($trait:ident, $method:ident, $type:ty, $builder:ident, $push_func:ident) => {
+        impl<O: OffsetSizeTrait> $trait for $type {
             type Output = $type;
 
-            fn densify(&self, max_distance: f64) -> Self::Output {
-                let output_geoms: Vec<Option<$geo_type>> = self
-                    .iter_geo()
-                    .map(|maybe_g| maybe_g.map(|geom| geom.densify(max_distance)))
-                    .collect();
-
-                output_geoms.into()
+            fn $method(&self, max_distance: f64) -> Self::Output {
+                // The input's buffer lengths are a lower bound for the output's
+                let mut builder = $builder::with_capacity_and_options(
+                    self.buffer_lengths(),
+                    self.coord_type(),
+                    self.metadata(),
+                );
+                self.iter_geo().for_each(|maybe_g| {
+                    builder
+                        .$push_func(maybe_g.map(|geom| geom.$method(max_distance)).as_ref())
+                        .unwrap()
+                });
+                builder.finish()
             }
         }
     };
 }
 
-iter_geo_impl!(LineStringArray<O>, geo::LineString);
-iter_geo_impl!(PolygonArray<O>, geo::Polygon);
-iter_geo_impl!(MultiLineStringArray<O>, geo::MultiLineString);
-iter_geo_impl!(MultiPolygonArray<O>, geo::MultiPolygon);
+macro_rules! impl_trait {
+    ($trait:ident, $method:ident) => {
+        iter_geo_impl!(
+            $trait,
+            $method,
+            LineStringArray<O>,
+            LineStringBuilder,
+            push_line_string
+        );
+        iter_geo_impl!(
+            $trait,
+            $method,
+            PolygonArray<O>,
+            PolygonBuilder,
+            push_polygon
+        );
+        iter_geo_impl!(
+            $trait,
+            $method,
+            MultiLineStringArray<O>,
+            MultiLineStringBuilder,
+            push_multi_line_string
+        );
+        iter_geo_impl!(
+            $trait,
+            $method,
+            MultiPolygonArray<O>,
+            MultiPolygonBuilder,
+            push_multi_polygon
+        );
 
-impl Densify for &dyn GeometryArrayTrait {
-    type Output = Result<Arc<dyn GeometryArrayTrait>>;
+        impl $trait for &dyn GeometryArrayTrait {
+            type Output = Result<Arc<dyn GeometryArrayTrait>>;
 
-    fn densify(&self, max_distance: f64) -> Self::Output {
-        let result: Arc<dyn GeometryArrayTrait> = match self.data_type() {
-            GeoDataType::LineString(_) => Arc::new(self.as_line_string().densify(max_distance)),
-            GeoDataType::LargeLineString(_) => {
-                Arc::new(self.as_large_line_string().densify(max_distance))
+            #[cfg_attr(
+                                        feature = "tracing",
+                                        tracing::instrument(
+                                            skip_all,
+                                            fields(
+                                                num_rows = self.len(),
+                                                data_type = ?self.data_type(),
+                                                max_distance = max_distance
+                                            )
+                                        )
+                                    )]
+            fn $method(&self, max_distance: f64) -> Self::Output {
+                let result: Arc<dyn GeometryArrayTrait> = match self.data_type() {
+                    GeoDataType::LineString(_) => {
+                        Arc::new(self.as_line_string().$method(max_distance))
+                    }
+                    GeoDataType::LargeLineString(_) => {
+                        Arc::new(self.as_large_line_string().$method(max_distance))
+                    }
+                    GeoDataType::Polygon(_) => Arc::new(self.as_polygon().$method(max_distance)),
+                    GeoDataType::LargePolygon(_) => {
+                        Arc::new(self.as_large_polygon().$method(max_distance))
+                    }
+                    GeoDataType::MultiLineString(_) => {
+                        Arc::new(self.as_multi_line_string().$method(max_distance))
+                    }
+                    GeoDataType::LargeMultiLineString(_) => {
+                        Arc::new(self.as_large_multi_line_string().$method(max_distance))
+                    }
+                    GeoDataType::MultiPolygon(_) => {
+                        Arc::new(self.as_multi_polygon().$method(max_distance))
+                    }
+                    GeoDataType::LargeMultiPolygon(_) => {
+                        Arc::new(self.as_large_multi_polygon().$method(max_distance))
+                    }
+                    _ => return Err(GeoArrowError::IncorrectType("".into())),
+                };
+                Ok(result)
             }
-            GeoDataType::Polygon(_) => Arc::new(self.as_polygon().densify(max_distance)),
-            GeoDataType::LargePolygon(_) => Arc::new(self.as_large_polygon().densify(max_distance)),
-            GeoDataType::MultiLineString(_) => {
-                Arc::new(self.as_multi_line_string().densify(max_distance))
-            }
-            GeoDataType::LargeMultiLineString(_) => {
-                Arc::new(self.as_large_multi_line_string().densify(max_distance))
-            }
-            GeoDataType::MultiPolygon(_) => Arc::new(self.as_multi_polygon().densify(max_distance)),
-            GeoDataType::LargeMultiPolygon(_) => {
-                Arc::new(self.as_large_multi_polygon().densify(max_distance))
+        }
+
+        impl_chunked!($trait, $method, ChunkedLineStringArray<O>);
+        impl_chunked!($trait, $method, ChunkedPolygonArray<O>);
+        impl_chunked!($trait, $method, ChunkedMultiLineStringArray<O>);
+        impl_chunked!($trait, $method, ChunkedMultiPolygonArray<O>);
+
+        impl $trait for &dyn ChunkedGeometryArrayTrait {
+            type Output = Result<Arc<dyn ChunkedGeometryArrayTrait>>;
+
+            fn $method(&self, max_distance: f64) -> Self::Output {
+                let result: Arc<dyn ChunkedGeometryArrayTrait> = match self.data_type() {
+                    GeoDataType::LineString(_) => {
+                        Arc::new(self.as_line_string().$method(max_distance))
+                    }
+                    GeoDataType::LargeLineString(_) => {
+                        Arc::new(self.as_large_line_string().$method(max_distance))
+                    }
+                    GeoDataType::Polygon(_) => Arc::new(self.as_polygon().$method(max_distance)),
+                    GeoDataType::LargePolygon(_) => {
+                        Arc::new(self.as_large_polygon().$method(max_distance))
+                    }
+                    GeoDataType::MultiLineString(_) => {
+                        Arc::new(self.as_multi_line_string().$method(max_distance))
+                    }
+                    GeoDataType::LargeMultiLineString(_) => {
+                        Arc::new(self.as_large_multi_line_string().$method(max_distance))
+                    }
+                    GeoDataType::MultiPolygon(_) => {
+                        Arc::new(self.as_multi_polygon().$method(max_distance))
+                    }
+                    GeoDataType::LargeMultiPolygon(_) => {
+                        Arc::new(self.as_large_multi_polygon().$method(max_distance))
+                    }
+                    _ => return Err(GeoArrowError::IncorrectType("".into())),
+                };
+                Ok(result)
             }
-            _ => return Err(GeoArrowError::IncorrectType("".into())),
-        };
-        Ok(result)
-    }
+        }
+    };
 }
 
 macro_rules! impl_chunked {
-    ($struct_name:ty) => {
-        impl<O: OffsetSizeTrait> Densify for $struct_name {
+    ($trait:ident, $method:ident, $struct_name:ty) => {
+        impl<O: OffsetSizeTrait> $trait for $struct_name {
             type Output = $struct_name;
 
-            fn densify(&self, max_distance: f64) -> Self::Output {
-                self.map(|chunk| chunk.densify(max_distance))
+            fn $method(&self, max_distance: f64) -> Self::Output {
+                self.map(|chunk| chunk.$method(max_distance))
                     .try_into()
                     .unwrap()
             }
@@ -95,34 +203,94 @@ macro_rules! impl_chunked {
     };
 }
 
-impl_chunked!(ChunkedLineStringArray<O>);
-impl_chunked!(ChunkedPolygonArray<O>);
-impl_chunked!(ChunkedMultiLineStringArray<O>);
-impl_chunked!(ChunkedMultiPolygonArray<O>);
+impl_trait!(Densify, densify);
+impl_trait!(DensifyHaversine, densify_haversine);
 
-impl Densify for &dyn ChunkedGeometryArrayTrait {
-    type Output = Result<Arc<dyn ChunkedGeometryArrayTrait>>;
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::metadata::ArrayMetadata;
+    use crate::geo_traits::LineStringTrait;
+    use geo::{line_string, polygon, EuclideanLength, HaversineDistance, Line};
+    use serde_json::json;
 
-    fn densify(&self, max_distance: f64) -> Self::Output {
-        let result: Arc<dyn ChunkedGeometryArrayTrait> = match self.data_type() {
-            GeoDataType::LineString(_) => Arc::new(self.as_line_string().densify(max_distance)),
-            GeoDataType::LargeLineString(_) => {
-                Arc::new(self.as_large_line_string().densify(max_distance))
-            }
-            GeoDataType::Polygon(_) => Arc::new(self.as_polygon().densify(max_distance)),
-            GeoDataType::LargePolygon(_) => Arc::new(self.as_large_polygon().densify(max_distance)),
-            GeoDataType::MultiLineString(_) => {
-                Arc::new(self.as_multi_line_string().densify(max_distance))
-            }
-            GeoDataType::LargeMultiLineString(_) => {
-                Arc::new(self.as_large_multi_line_string().densify(max_distance))
-            }
-            GeoDataType::MultiPolygon(_) => Arc::new(self.as_multi_polygon().densify(max_distance)),
-            GeoDataType::LargeMultiPolygon(_) => {
-                Arc::new(self.as_large_multi_polygon().densify(max_distance))
-            }
-            _ => return Err(GeoArrowError::IncorrectType("".into())),
-        };
-        Ok(result)
+    /// The length of the longest segment of each line string of an array.
+    fn max_segment_lengths<O: OffsetSizeTrait>(
+        arr: &LineStringArray<O>,
+        length: impl Fn(Line) -> f64,
+    ) -> Vec<f64> {
+        arr.iter_geo_values()
+            .map(|line_string| line_string.lines().map(&length).fold(0., f64::max))
+            .collect()
+    }
+
+    #[test]
+    fn densify_line_strings() {
+        let metadata = Arc::new(ArrayMetadata {
+            crs: Some(json!("EPSG:3857")),
+            edges: None,
+        });
+        let line_string = line_string![(x: 0., y: 0.), (x: 100., y: 0.), (x: 100., y: 2.5)];
+        let arr = LineStringBuilder::<i32>::push_line_string(
+            &[Some(line_string.clone()), None, Some(line_string![])],
+            Some(CoordType::Separated),
+            metadata.clone(),
+        )
+        .finish();
+
+        let densified = arr.densify(1.);
+        assert_eq!(densified.coord_type(), CoordType::Separated);
+        assert_eq!(densified.metadata(), metadata);
+        assert!(densified.is_null(1));
+
+        // 100 segments along the first line and 3 along the second make 104 coordinates
+        assert_eq!(densified.geom_offsets.as_ref(), &[0, 104, 104, 104]);
+        let values = densified.value(0);
+        assert_eq!(values.num_coords(), 104);
+        assert!(max_segment_lengths(&densified, |line| line.euclidean_length())[0] <= 1. + 1e-9);
+
+        // The original coordinates are kept
+        let coords = densified.value_as_geo(0).0;
+        for coord in line_string.coords() {
+            assert!(coords.contains(coord));
+        }
+    }
+
+    #[test]
+    fn densify_polygons() {
+        let polygon = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+        let arr: PolygonArray<i64> = vec![polygon].as_slice().into();
+        let densified = arr.densify(0.5);
+
+        // Each of the four sides is split in 20 segments, and the ring stays closed
+        assert_eq!(densified.ring_offsets.as_ref(), &[0, 81]);
+        let exterior = densified.value_as_geo(0).exterior().clone();
+        assert!(exterior.is_closed());
+        assert!(exterior
+            .lines()
+            .all(|line| line.euclidean_length() <= 0.5 + 1e-9));
+
+        let chunked = ChunkedGeometryArray::new_unchecked(vec![arr.clone(), arr]);
+        let chunked = chunked.densify(0.5);
+        assert_eq!(chunked.chunks()[1].ring_offsets.as_ref(), &[0, 81]);
+    }
+
+    #[test]
+    fn densify_haversine() {
+        // New York City to London
+        let line_string = line_string![(x: -74.006, y: 40.7128), (x: -0.1278, y: 51.5074)];
+        let arr: LineStringArray<i32> = vec![line_string].as_slice().into();
+        let max_distance = 100_000.;
+        let densified = arr.densify_haversine(max_distance);
+
+        let max_length = max_segment_lengths(&densified, |line| {
+            geo::Point::from(line.start).haversine_distance(&geo::Point::from(line.end))
+        })[0];
+        assert!(max_length <= max_distance * (1. + 1e-9));
+        assert!(densified.value(0).num_coords() > 50);
+
+        let dyn_arr: &dyn GeometryArrayTrait = &arr;
+        let result = dyn_arr.densify_haversine(max_distance).unwrap();
+        assert_eq!(result.as_ref().as_line_string(), &densified);
     }
 }
@@ -110,7 +110,7 @@ impl<G: GeometryArrayTrait> HasDimensions for ChunkedGeometryArray<G> {
     type Output = Result<ChunkedArray<BooleanArray>>;
 
     fn is_empty(&self) -> Self::Output {
-        self.try_map(|chunk| HasDimensions::is_empty(&chunk.as_ref()))?
+        self.try_map_non_null(|chunk| HasDimensions::is_empty(&chunk.as_ref()))?
             .try_into()
     }
 }
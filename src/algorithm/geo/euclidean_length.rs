@@ -9,6 +9,14 @@ use crate::GeometryArrayTrait;
 use arrow_array::{Float64Array, OffsetSizeTrait};
 use geo::EuclideanLength as _EuclideanLength;
 
+/// Calculation of the planar length of a geometry.
+///
+/// # Supported types
+///
+/// Line strings and multi line strings have a length. Points and multi points are accepted and
+/// have a length of zero. Polygonal arrays don't implement this trait, and the implementations on
+/// `&dyn GeometryArrayTrait` and `&dyn ChunkedGeometryArrayTrait` return an error for polygon,
+/// mixed and geometry collection arrays.
 pub trait EuclideanLength {
     type Output;
 
@@ -101,7 +109,15 @@ impl EuclideanLength for &dyn GeometryArrayTrait {
             // GeoDataType::LargeGeometryCollection(_) => {
             //     self.as_large_geometry_collection().euclidean_length()
             // }
-            _ => return Err(GeoArrowError::IncorrectType("".into())),
+            _ => {
+                return Err(GeoArrowError::IncorrectType(
+                    format!(
+                        "Euclidean length of {:?} is not supported",
+                        self.data_type()
+                    )
+                    .into(),
+                ))
+            }
         };
         Ok(result)
     }
@@ -156,7 +172,13 @@ impl EuclideanLength for &dyn ChunkedGeometryArrayTrait {
             // GeoDataType::LargeGeometryCollection(_) => {
             //     self.as_large_geometry_collection().euclidean_length()
             // }
-            _ => Err(GeoArrowError::IncorrectType("".into())),
+            _ => Err(GeoArrowError::IncorrectType(
+                format!(
+                    "Euclidean length of {:?} is not supported",
+                    self.data_type()
+                )
+                .into(),
+            )),
         }
     }
 }
@@ -165,6 +187,7 @@ impl EuclideanLength for &dyn ChunkedGeometryArrayTrait {
 mod tests {
     use super::*;
     use crate::array::LineStringArray;
+    use crate::test::{multilinestring, point, polygon};
     use arrow_array::Array;
     use geo::line_string;
 
@@ -185,4 +208,26 @@ mod tests {
         assert_eq!(expected, result_array.value(0).round());
         assert!(result_array.is_valid(0));
     }
+
+    #[test]
+    fn euclidean_length_dyn_dispatch() {
+        let points = point::point_array();
+        let result = (&points as &dyn GeometryArrayTrait)
+            .euclidean_length()
+            .unwrap();
+        assert_eq!(result.values().as_ref(), &[0., 0., 0.]);
+
+        let multi_line_strings: MultiLineStringArray<i32> =
+            vec![multilinestring::ml0()].as_slice().into();
+        let chunked = ChunkedGeometryArray::new_unchecked(vec![multi_line_strings]);
+        let result = (&chunked as &dyn ChunkedGeometryArrayTrait)
+            .euclidean_length()
+            .unwrap();
+        assert_eq!(result.chunks()[0].value(0), 15.);
+
+        let polygons = polygon::p_array();
+        assert!((&polygons as &dyn GeometryArrayTrait)
+            .euclidean_length()
+            .is_err());
+    }
 }
@@ -1,4 +1,5 @@
-use crate::algorithm::native::{Binary, MapChunks, Unary};
+use crate::algorithm::geo::utils::{check_aligned_chunks, line_string_distances};
+use crate::algorithm::native::{MapChunks, Unary};
 use crate::array::*;
 use crate::chunked_array::{ChunkedArray, ChunkedGeometryArrayTrait, ChunkedLineStringArray};
 use crate::datatypes::GeoDataType;
@@ -18,6 +19,10 @@ use geo::FrechetDistance as _FrechetDistance;
 ///
 /// Based on [Computing Discrete Frechet Distance] by T. Eiter and H. Mannila.
 ///
+/// The arrays must have the same length, and the distance is null where either line string is
+/// null or empty. The distance is quadratic in the number of coordinates, so with the `rayon`
+/// feature, chunked arrays are computed in parallel over rows as well as chunks.
+///
 /// [Frechet distance]: https://en.wikipedia.org/wiki/Fr%C3%A9chet_distance
 /// [Computing Discrete Frechet Distance]: http://www.kr.tuwien.ac.at/staff/eiter/et-archive/cdtr9464.pdf
 pub trait FrechetDistance<Rhs = Self> {
@@ -29,25 +34,26 @@ pub trait FrechetDistance<Rhs = Self> {
 impl<O1: OffsetSizeTrait, O2: OffsetSizeTrait> FrechetDistance<LineStringArray<O2>>
     for LineStringArray<O1>
 {
-    type Output = Float64Array;
+    type Output = Result<Float64Array>;
 
     fn frechet_distance(&self, rhs: &LineStringArray<O2>) -> Self::Output {
-        self.try_binary_primitive(rhs, |left, right| {
-            Ok(left.to_geo().frechet_distance(&right.to_geo()))
-        })
-        .unwrap()
+        line_string_distances(self, rhs, false, |left, right| left.frechet_distance(right))
     }
 }
 
 impl<O1: OffsetSizeTrait, O2: OffsetSizeTrait> FrechetDistance<ChunkedLineStringArray<O2>>
     for ChunkedLineStringArray<O1>
 {
-    type Output = ChunkedArray<Float64Array>;
+    type Output = Result<ChunkedArray<Float64Array>>;
 
     fn frechet_distance(&self, rhs: &ChunkedLineStringArray<O2>) -> Self::Output {
-        ChunkedArray::new(self.binary_map(rhs.chunks(), |(left, right)| {
-            FrechetDistance::frechet_distance(left, right)
-        }))
+        check_aligned_chunks(self, rhs)?;
+        self.try_binary_map(rhs.chunks(), |(left, right)| {
+            line_string_distances(left, right, true, |left, right| {
+                left.frechet_distance(right)
+            })
+        })?
+        .try_into()
     }
 }
 
@@ -57,19 +63,25 @@ impl FrechetDistance for &dyn GeometryArrayTrait {
     fn frechet_distance(&self, rhs: &Self) -> Self::Output {
         let result = match (self.data_type(), rhs.data_type()) {
             (GeoDataType::LineString(_), GeoDataType::LineString(_)) => {
-                FrechetDistance::frechet_distance(self.as_line_string(), rhs.as_line_string())
+                FrechetDistance::frechet_distance(self.as_line_string(), rhs.as_line_string())?
             }
             (GeoDataType::LineString(_), GeoDataType::LargeLineString(_)) => {
-                FrechetDistance::frechet_distance(self.as_line_string(), rhs.as_large_line_string())
+                FrechetDistance::frechet_distance(
+                    self.as_line_string(),
+                    rhs.as_large_line_string(),
+                )?
             }
             (GeoDataType::LargeLineString(_), GeoDataType::LineString(_)) => {
-                FrechetDistance::frechet_distance(self.as_large_line_string(), rhs.as_line_string())
+                FrechetDistance::frechet_distance(
+                    self.as_large_line_string(),
+                    rhs.as_line_string(),
+                )?
             }
             (GeoDataType::LargeLineString(_), GeoDataType::LargeLineString(_)) => {
                 FrechetDistance::frechet_distance(
                     self.as_large_line_string(),
                     rhs.as_large_line_string(),
-                )
+                )?
             }
             _ => return Err(GeoArrowError::IncorrectType("".into())),
         };
@@ -83,19 +95,25 @@ impl FrechetDistance for &dyn ChunkedGeometryArrayTrait {
     fn frechet_distance(&self, rhs: &Self) -> Self::Output {
         let result = match (self.data_type(), rhs.data_type()) {
             (GeoDataType::LineString(_), GeoDataType::LineString(_)) => {
-                FrechetDistance::frechet_distance(self.as_line_string(), rhs.as_line_string())
+                FrechetDistance::frechet_distance(self.as_line_string(), rhs.as_line_string())?
             }
             (GeoDataType::LineString(_), GeoDataType::LargeLineString(_)) => {
-                FrechetDistance::frechet_distance(self.as_line_string(), rhs.as_large_line_string())
+                FrechetDistance::frechet_distance(
+                    self.as_line_string(),
+                    rhs.as_large_line_string(),
+                )?
             }
             (GeoDataType::LargeLineString(_), GeoDataType::LineString(_)) => {
-                FrechetDistance::frechet_distance(self.as_large_line_string(), rhs.as_line_string())
+                FrechetDistance::frechet_distance(
+                    self.as_large_line_string(),
+                    rhs.as_line_string(),
+                )?
             }
             (GeoDataType::LargeLineString(_), GeoDataType::LargeLineString(_)) => {
                 FrechetDistance::frechet_distance(
                     self.as_large_line_string(),
                     rhs.as_large_line_string(),
-                )
+                )?
             }
             _ => return Err(GeoArrowError::IncorrectType("".into())),
         };
@@ -358,7 +358,11 @@ impl GeodesicArea for &dyn GeometryArrayTrait {
             GeoDataType::LargeGeometryCollection(_) => {
                 self.as_large_geometry_collection().geodesic_area_signed()
             }
-            _ => return Err(GeoArrowError::IncorrectType("".into())),
+            _ => {
+                return Err(GeoArrowError::IncorrectType(
+                    format!("Geodesic area of {:?} is not supported", self.data_type()).into(),
+                ))
+            }
         };
         Ok(result)
     }
@@ -388,7 +392,11 @@ impl GeodesicArea for &dyn GeometryArrayTrait {
             GeoDataType::LargeGeometryCollection(_) => {
                 self.as_large_geometry_collection().geodesic_area_unsigned()
             }
-            _ => return Err(GeoArrowError::IncorrectType("".into())),
+            _ => {
+                return Err(GeoArrowError::IncorrectType(
+                    format!("Geodesic area of {:?} is not supported", self.data_type()).into(),
+                ))
+            }
         };
         Ok(result)
     }
@@ -416,7 +424,15 @@ impl GeodesicArea for &dyn GeometryArrayTrait {
             GeoDataType::LargeGeometryCollection(_) => {
                 self.as_large_geometry_collection().geodesic_perimeter()
             }
-            _ => return Err(GeoArrowError::IncorrectType("".into())),
+            _ => {
+                return Err(GeoArrowError::IncorrectType(
+                    format!(
+                        "Geodesic perimeter of {:?} is not supported",
+                        self.data_type()
+                    )
+                    .into(),
+                ))
+            }
         };
         Ok(result)
     }
@@ -456,7 +472,15 @@ impl GeodesicArea for &dyn GeometryArrayTrait {
             GeoDataType::LargeGeometryCollection(_) => self
                 .as_large_geometry_collection()
                 .geodesic_perimeter_area_signed(),
-            _ => return Err(GeoArrowError::IncorrectType("".into())),
+            _ => {
+                return Err(GeoArrowError::IncorrectType(
+                    format!(
+                        "Geodesic perimeter and area of {:?} is not supported",
+                        self.data_type()
+                    )
+                    .into(),
+                ))
+            }
         };
         Ok(result)
     }
@@ -496,7 +520,15 @@ impl GeodesicArea for &dyn GeometryArrayTrait {
             GeoDataType::LargeGeometryCollection(_) => self
                 .as_large_geometry_collection()
                 .geodesic_perimeter_area_unsigned(),
-            _ => return Err(GeoArrowError::IncorrectType("".into())),
+            _ => {
+                return Err(GeoArrowError::IncorrectType(
+                    format!(
+                        "Geodesic perimeter and area of {:?} is not supported",
+                        self.data_type()
+                    )
+                    .into(),
+                ))
+            }
         };
         Ok(result)
     }
@@ -507,17 +539,17 @@ impl<G: GeometryArrayTrait> GeodesicArea for ChunkedGeometryArray<G> {
     type OutputDouble = Result<(ChunkedArray<Float64Array>, ChunkedArray<Float64Array>)>;
 
     fn geodesic_area_signed(&self) -> Self::OutputSingle {
-        self.try_map(|chunk| chunk.as_ref().geodesic_area_signed())?
+        self.try_map_non_null(|chunk| chunk.as_ref().geodesic_area_signed())?
             .try_into()
     }
 
     fn geodesic_area_unsigned(&self) -> Self::OutputSingle {
-        self.try_map(|chunk| chunk.as_ref().geodesic_area_unsigned())?
+        self.try_map_non_null(|chunk| chunk.as_ref().geodesic_area_unsigned())?
             .try_into()
     }
 
     fn geodesic_perimeter(&self) -> Self::OutputSingle {
-        self.try_map(|chunk| chunk.as_ref().geodesic_perimeter())?
+        self.try_map_non_null(|chunk| chunk.as_ref().geodesic_perimeter())?
             .try_into()
     }
 
@@ -567,7 +599,9 @@ impl GeodesicArea for &dyn ChunkedGeometryArrayTrait {
             GeoDataType::LargeGeometryCollection(_) => {
                 self.as_large_geometry_collection().geodesic_area_signed()
             }
-            _ => Err(GeoArrowError::IncorrectType("".into())),
+            _ => Err(GeoArrowError::IncorrectType(
+                format!("Geodesic area of {:?} is not supported", self.data_type()).into(),
+            )),
         }
     }
 
@@ -596,7 +630,9 @@ impl GeodesicArea for &dyn ChunkedGeometryArrayTrait {
             GeoDataType::LargeGeometryCollection(_) => {
                 self.as_large_geometry_collection().geodesic_area_unsigned()
             }
-            _ => Err(GeoArrowError::IncorrectType("".into())),
+            _ => Err(GeoArrowError::IncorrectType(
+                format!("Geodesic area of {:?} is not supported", self.data_type()).into(),
+            )),
         }
     }
 
@@ -623,7 +659,13 @@ impl GeodesicArea for &dyn ChunkedGeometryArrayTrait {
             GeoDataType::LargeGeometryCollection(_) => {
                 self.as_large_geometry_collection().geodesic_perimeter()
             }
-            _ => Err(GeoArrowError::IncorrectType("".into())),
+            _ => Err(GeoArrowError::IncorrectType(
+                format!(
+                    "Geodesic perimeter of {:?} is not supported",
+                    self.data_type()
+                )
+                .into(),
+            )),
         }
     }
 
@@ -662,7 +704,13 @@ impl GeodesicArea for &dyn ChunkedGeometryArrayTrait {
             GeoDataType::LargeGeometryCollection(_) => self
                 .as_large_geometry_collection()
                 .geodesic_perimeter_area_signed(),
-            _ => Err(GeoArrowError::IncorrectType("".into())),
+            _ => Err(GeoArrowError::IncorrectType(
+                format!(
+                    "Geodesic perimeter and area of {:?} is not supported",
+                    self.data_type()
+                )
+                .into(),
+            )),
         }
     }
 
@@ -701,7 +749,48 @@ impl GeodesicArea for &dyn ChunkedGeometryArrayTrait {
             GeoDataType::LargeGeometryCollection(_) => self
                 .as_large_geometry_collection()
                 .geodesic_perimeter_area_unsigned(),
-            _ => Err(GeoArrowError::IncorrectType("".into())),
+            _ => Err(GeoArrowError::IncorrectType(
+                format!(
+                    "Geodesic perimeter and area of {:?} is not supported",
+                    self.data_type()
+                )
+                .into(),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::geo::ChamberlainDuquetteArea;
+    use crate::test::polygon::{p0, p1, p_array};
+
+    #[test]
+    fn geodesic_area_dyn_dispatch() {
+        let polygons = p_array();
+        let expected = polygons.geodesic_area_unsigned();
+        // The interior ring of p1 is subtracted
+        assert!(expected.value(1) < expected.value(0));
+
+        let geoms = vec![geo::Geometry::Polygon(p0()), geo::Geometry::Polygon(p1())];
+        let mixed: MixedGeometryArray<i32> = geoms.as_slice().try_into().unwrap();
+        let mixed_ref = &mixed as &dyn GeometryArrayTrait;
+        assert_eq!(mixed_ref.geodesic_area_unsigned().unwrap(), expected);
+
+        let chunked = ChunkedGeometryArray::new_unchecked(vec![polygons.clone()]);
+        let chunked_ref = &chunked as &dyn ChunkedGeometryArrayTrait;
+        let result = chunked_ref.geodesic_area_unsigned().unwrap();
+        assert_eq!(result.chunks()[0], expected);
+
+        // The spherical approximation is within a fraction of a percent of the ellipsoidal area
+        let spherical = polygons.chamberlain_duquette_unsigned_area();
+        for i in 0..polygons.len() {
+            approx::assert_relative_eq!(spherical.value(i), expected.value(i), max_relative = 0.01);
         }
+
+        let rects: RectArray = vec![Some(geo::Rect::new((0., 0.), (1., 1.)))].into();
+        let rects_ref = &rects as &dyn GeometryArrayTrait;
+        assert!(rects_ref.geodesic_area_unsigned().is_err());
     }
 }
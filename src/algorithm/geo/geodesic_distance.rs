@@ -0,0 +1,246 @@
+use crate::algorithm::native::{Binary, MapChunks, Unary};
+use crate::array::*;
+use crate::chunked_array::{ChunkedArray, ChunkedGeometryArrayTrait, ChunkedPointArray};
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::geo_traits::PointTrait;
+use crate::io::geo::point_to_geo;
+use crate::trait_::GeometryScalarTrait;
+use crate::GeometryArrayTrait;
+use arrow_array::Float64Array;
+use geo::GeodesicDistance as _GeodesicDistance;
+
+// ┌────────────────────────────────┐
+// │ Implementations for RHS arrays │
+// └────────────────────────────────┘
+
+/// Determine the distance between two arrays of points on the surface of the WGS84 ellipsoid,
+/// using the geodesic algorithm from [Karney (2013)].
+///
+/// Coordinates are interpreted as longitude/latitude in degrees. This is more accurate than
+/// [`HaversineDistance`][crate::algorithm::geo::HaversineDistance], which assumes a spherical
+/// earth.
+///
+/// [Karney (2013)]: https://arxiv.org/pdf/1109.4448.pdf
+pub trait GeodesicDistance<Rhs = Self> {
+    type Output;
+
+    /// Determine the distance between two arrays of points on the surface of the WGS84 ellipsoid.
+    ///
+    /// # Units
+    ///
+    /// - return value: meters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::point;
+    /// use geoarrow::array::PointArray;
+    /// use geoarrow::algorithm::geo::GeodesicDistance;
+    ///
+    /// // New York City
+    /// let new_york: PointArray = vec![point!(x: -74.006, y: 40.7128)].as_slice().into();
+    /// // London
+    /// let london: PointArray = vec![point!(x: -0.1278, y: 51.5074)].as_slice().into();
+    ///
+    /// let distance = new_york.geodesic_distance(&london).unwrap();
+    ///
+    /// assert_eq!(
+    ///     5_585_234., // meters
+    ///     distance.value(0).round()
+    /// );
+    /// ```
+    fn geodesic_distance(&self, rhs: &Rhs) -> Self::Output;
+}
+
+impl GeodesicDistance for PointArray {
+    type Output = Result<Float64Array>;
+
+    fn geodesic_distance(&self, rhs: &PointArray) -> Self::Output {
+        self.try_binary_primitive(rhs, |left, right| {
+            Ok(left.to_geo().geodesic_distance(&right.to_geo()))
+        })
+    }
+}
+
+impl GeodesicDistance for ChunkedPointArray {
+    type Output = Result<ChunkedArray<Float64Array>>;
+
+    fn geodesic_distance(&self, rhs: &ChunkedPointArray) -> Self::Output {
+        if self.chunks().len() != rhs.chunks().len() {
+            return Err(GeoArrowError::General(
+                "Cannot perform binary operation on chunked arrays with different numbers of chunks"
+                    .to_string(),
+            ));
+        }
+        Ok(ChunkedArray::new(
+            self.try_binary_map(rhs.chunks(), |(left, right)| {
+                GeodesicDistance::geodesic_distance(left, right)
+            })?,
+        ))
+    }
+}
+
+impl GeodesicDistance for &dyn GeometryArrayTrait {
+    type Output = Result<Float64Array>;
+
+    fn geodesic_distance(&self, rhs: &Self) -> Self::Output {
+        match (self.data_type(), rhs.data_type()) {
+            (GeoDataType::Point(_), GeoDataType::Point(_)) => {
+                GeodesicDistance::geodesic_distance(self.as_point(), rhs.as_point())
+            }
+            (left, right) => Err(GeoArrowError::IncorrectType(
+                format!(
+                    "Geodesic distance between {:?} and {:?} is not supported",
+                    left, right
+                )
+                .into(),
+            )),
+        }
+    }
+}
+
+impl GeodesicDistance for &dyn ChunkedGeometryArrayTrait {
+    type Output = Result<ChunkedArray<Float64Array>>;
+
+    fn geodesic_distance(&self, rhs: &Self) -> Self::Output {
+        match (self.data_type(), rhs.data_type()) {
+            (GeoDataType::Point(_), GeoDataType::Point(_)) => {
+                GeodesicDistance::geodesic_distance(self.as_point(), rhs.as_point())
+            }
+            (left, right) => Err(GeoArrowError::IncorrectType(
+                format!(
+                    "Geodesic distance between {:?} and {:?} is not supported",
+                    left, right
+                )
+                .into(),
+            )),
+        }
+    }
+}
+
+// ┌─────────────────────────────────┐
+// │ Implementations for RHS scalars │
+// └─────────────────────────────────┘
+
+/// Determine the distance between each point of an array and a single point on the surface of the
+/// WGS84 ellipsoid.
+pub trait GeodesicDistancePoint<Rhs> {
+    type Output;
+
+    fn geodesic_distance(&self, rhs: &Rhs) -> Self::Output;
+}
+
+impl<G: PointTrait<T = f64>> GeodesicDistancePoint<G> for PointArray {
+    type Output = Float64Array;
+
+    fn geodesic_distance(&self, rhs: &G) -> Self::Output {
+        let rhs = point_to_geo(rhs);
+        self.try_unary_primitive(|geom| {
+            Ok::<_, GeoArrowError>(geom.to_geo().geodesic_distance(&rhs))
+        })
+        .unwrap()
+    }
+}
+
+impl<G: PointTrait<T = f64> + Sync> GeodesicDistancePoint<G> for ChunkedPointArray {
+    type Output = ChunkedArray<Float64Array>;
+
+    fn geodesic_distance(&self, rhs: &G) -> Self::Output {
+        ChunkedArray::new(self.map(|chunk| GeodesicDistancePoint::geodesic_distance(chunk, rhs)))
+    }
+}
+
+impl<G: PointTrait<T = f64>> GeodesicDistancePoint<G> for &dyn GeometryArrayTrait {
+    type Output = Result<Float64Array>;
+
+    fn geodesic_distance(&self, rhs: &G) -> Self::Output {
+        match self.data_type() {
+            GeoDataType::Point(_) => Ok(GeodesicDistancePoint::geodesic_distance(
+                self.as_point(),
+                rhs,
+            )),
+            dt => Err(GeoArrowError::IncorrectType(
+                format!("Geodesic distance of {:?} is not supported", dt).into(),
+            )),
+        }
+    }
+}
+
+impl<G: PointTrait<T = f64>> GeodesicDistancePoint<G> for &dyn ChunkedGeometryArrayTrait {
+    type Output = Result<ChunkedArray<Float64Array>>;
+
+    fn geodesic_distance(&self, rhs: &G) -> Self::Output {
+        let rhs = point_to_geo(rhs);
+        match self.data_type() {
+            GeoDataType::Point(_) => Ok(GeodesicDistancePoint::geodesic_distance(
+                self.as_point(),
+                &rhs,
+            )),
+            dt => Err(GeoArrowError::IncorrectType(
+                format!("Geodesic distance of {:?} is not supported", dt).into(),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::chunked_array::ChunkedGeometryArray;
+    use crate::test::point;
+    use crate::trait_::GeometryArrayAccessor;
+    use approx::assert_relative_eq;
+    use geo::point;
+
+    #[test]
+    fn geodesic_distance_arrays() {
+        let left: PointArray = vec![point!(x: -74.006, y: 40.7128), point!(x: 0., y: 0.)]
+            .as_slice()
+            .into();
+        let right: PointArray = vec![point!(x: -0.1278, y: 51.5074), point!(x: 0., y: 1.)]
+            .as_slice()
+            .into();
+
+        let distance = GeodesicDistance::geodesic_distance(&left, &right).unwrap();
+        assert_relative_eq!(distance.value(0), 5_585_234., epsilon = 1.);
+        assert_relative_eq!(distance.value(1), 110_574., epsilon = 1.);
+    }
+
+    #[test]
+    fn geodesic_distance_scalar() {
+        let arr = point::point_array();
+        let origin = point!(x: 0., y: 0.);
+
+        let distance = GeodesicDistancePoint::geodesic_distance(&arr, &origin);
+        assert_eq!(distance.len(), arr.len());
+        for (i, p) in arr.iter_geo_values().enumerate() {
+            assert_relative_eq!(distance.value(i), p.geodesic_distance(&origin));
+        }
+    }
+
+    #[test]
+    fn geodesic_distance_chunked() {
+        let chunked =
+            ChunkedGeometryArray::new_unchecked(vec![point::point_array(), point::point_array()]);
+
+        let distance = GeodesicDistance::geodesic_distance(&chunked, &chunked).unwrap();
+        assert_eq!(distance.len(), 2 * point::point_array().len());
+        assert!(distance
+            .chunks()
+            .iter()
+            .all(|chunk| chunk.values().iter().all(|value| *value == 0.)));
+
+        let single = ChunkedGeometryArray::new_unchecked(vec![point::point_array()]);
+        assert!(GeodesicDistance::geodesic_distance(&chunked, &single).is_err());
+    }
+
+    #[test]
+    fn geodesic_distance_unsupported_type() {
+        let points = point::point_array();
+        let line_strings = crate::test::linestring::large_ls_array();
+        let left = &points as &dyn GeometryArrayTrait;
+        let right = &line_strings as &dyn GeometryArrayTrait;
+        assert!(GeodesicDistance::geodesic_distance(&left, &right).is_err());
+    }
+}
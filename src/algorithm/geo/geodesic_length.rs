@@ -15,6 +15,10 @@ use geo::GeodesicLength as _GeodesicLength;
 /// like Vincenty, this method is accurate to a few nanometers and always converges.
 ///
 /// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
+///
+/// Supports the same array types as [`EuclideanLength`][crate::algorithm::geo::EuclideanLength]:
+/// points and multi points have a length of zero, while polygon, mixed and geometry collection
+/// arrays are unsupported.
 pub trait GeodesicLength {
     type Output;
 
@@ -121,7 +125,11 @@ impl GeodesicLength for &dyn GeometryArrayTrait {
             // GeoDataType::LargeGeometryCollection(_) => {
             //     self.as_large_geometry_collection().geodesic_length()
             // }
-            _ => return Err(GeoArrowError::IncorrectType("".into())),
+            _ => {
+                return Err(GeoArrowError::IncorrectType(
+                    format!("Geodesic length of {:?} is not supported", self.data_type()).into(),
+                ))
+            }
         };
         Ok(result)
     }
@@ -176,7 +184,9 @@ impl GeodesicLength for &dyn ChunkedGeometryArrayTrait {
             // GeoDataType::LargeGeometryCollection(_) => {
             //     self.as_large_geometry_collection().geodesic_length()
             // }
-            _ => Err(GeoArrowError::IncorrectType("".into())),
+            _ => Err(GeoArrowError::IncorrectType(
+                format!("Geodesic length of {:?} is not supported", self.data_type()).into(),
+            )),
         }
     }
 }
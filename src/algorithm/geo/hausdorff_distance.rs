@@ -0,0 +1,194 @@
+use crate::algorithm::geo::utils::{check_aligned_chunks, line_string_distances};
+use crate::algorithm::native::MapChunks;
+use crate::array::*;
+use crate::chunked_array::{ChunkedArray, ChunkedGeometryArrayTrait, ChunkedLineStringArray};
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::GeometryArrayTrait;
+use arrow_array::{Float64Array, OffsetSizeTrait};
+use geo::HausdorffDistance as _HausdorffDistance;
+
+/// Determine the similarity between two arrays of `LineStrings` using the [Hausdorff distance].
+///
+/// The Hausdorff distance is the greatest distance from any vertex of one line string to the
+/// closest vertex of the other. Unlike the [Fréchet
+/// distance][crate::algorithm::geo::FrechetDistance], it ignores the direction and order of the
+/// vertices.
+///
+/// The arrays must have the same length, and the distance is null where either line string is
+/// null or empty. With the `rayon` feature, chunked arrays are computed in parallel over rows as
+/// well as chunks.
+///
+/// [Hausdorff distance]: https://en.wikipedia.org/wiki/Hausdorff_distance
+pub trait HausdorffDistance<Rhs = Self> {
+    type Output;
+
+    fn hausdorff_distance(&self, rhs: &Rhs) -> Self::Output;
+}
+
+impl<O1: OffsetSizeTrait, O2: OffsetSizeTrait> HausdorffDistance<LineStringArray<O2>>
+    for LineStringArray<O1>
+{
+    type Output = Result<Float64Array>;
+
+    fn hausdorff_distance(&self, rhs: &LineStringArray<O2>) -> Self::Output {
+        line_string_distances(self, rhs, false, |left, right| {
+            left.hausdorff_distance(right)
+        })
+    }
+}
+
+impl<O1: OffsetSizeTrait, O2: OffsetSizeTrait> HausdorffDistance<ChunkedLineStringArray<O2>>
+    for ChunkedLineStringArray<O1>
+{
+    type Output = Result<ChunkedArray<Float64Array>>;
+
+    fn hausdorff_distance(&self, rhs: &ChunkedLineStringArray<O2>) -> Self::Output {
+        check_aligned_chunks(self, rhs)?;
+        self.try_binary_map(rhs.chunks(), |(left, right)| {
+            line_string_distances(left, right, true, |left, right| {
+                left.hausdorff_distance(right)
+            })
+        })?
+        .try_into()
+    }
+}
+
+impl HausdorffDistance for &dyn GeometryArrayTrait {
+    type Output = Result<Float64Array>;
+
+    fn hausdorff_distance(&self, rhs: &Self) -> Self::Output {
+        match (self.data_type(), rhs.data_type()) {
+            (GeoDataType::LineString(_), GeoDataType::LineString(_)) => {
+                HausdorffDistance::hausdorff_distance(self.as_line_string(), rhs.as_line_string())
+            }
+            (GeoDataType::LineString(_), GeoDataType::LargeLineString(_)) => {
+                HausdorffDistance::hausdorff_distance(
+                    self.as_line_string(),
+                    rhs.as_large_line_string(),
+                )
+            }
+            (GeoDataType::LargeLineString(_), GeoDataType::LineString(_)) => {
+                HausdorffDistance::hausdorff_distance(
+                    self.as_large_line_string(),
+                    rhs.as_line_string(),
+                )
+            }
+            (GeoDataType::LargeLineString(_), GeoDataType::LargeLineString(_)) => {
+                HausdorffDistance::hausdorff_distance(
+                    self.as_large_line_string(),
+                    rhs.as_large_line_string(),
+                )
+            }
+            _ => Err(GeoArrowError::IncorrectType(
+                "Hausdorff distance is only implemented between line string arrays".into(),
+            )),
+        }
+    }
+}
+
+impl HausdorffDistance for &dyn ChunkedGeometryArrayTrait {
+    type Output = Result<ChunkedArray<Float64Array>>;
+
+    fn hausdorff_distance(&self, rhs: &Self) -> Self::Output {
+        match (self.data_type(), rhs.data_type()) {
+            (GeoDataType::LineString(_), GeoDataType::LineString(_)) => {
+                HausdorffDistance::hausdorff_distance(self.as_line_string(), rhs.as_line_string())
+            }
+            (GeoDataType::LineString(_), GeoDataType::LargeLineString(_)) => {
+                HausdorffDistance::hausdorff_distance(
+                    self.as_line_string(),
+                    rhs.as_large_line_string(),
+                )
+            }
+            (GeoDataType::LargeLineString(_), GeoDataType::LineString(_)) => {
+                HausdorffDistance::hausdorff_distance(
+                    self.as_large_line_string(),
+                    rhs.as_line_string(),
+                )
+            }
+            (GeoDataType::LargeLineString(_), GeoDataType::LargeLineString(_)) => {
+                HausdorffDistance::hausdorff_distance(
+                    self.as_large_line_string(),
+                    rhs.as_large_line_string(),
+                )
+            }
+            _ => Err(GeoArrowError::IncorrectType(
+                "Hausdorff distance is only implemented between line string arrays".into(),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::geo::FrechetDistance;
+    use crate::array::LineStringBuilder;
+    use arrow_array::Array;
+    use geo::line_string;
+
+    fn array(geoms: &[Option<geo::LineString>]) -> LineStringArray<i32> {
+        LineStringBuilder::from_nullable_line_strings(geoms, None, Default::default()).finish()
+    }
+
+    #[test]
+    fn distances() {
+        let a = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.)];
+        let b = line_string![(x: 0., y: 1.), (x: 1., y: 2.), (x: 2., y: 1.)];
+        let reversed = line_string![(x: 2., y: 0.), (x: 1., y: 0.), (x: 0., y: 0.)];
+        let left = array(&[Some(a.clone()), Some(a.clone()), None]);
+        let right = array(&[Some(b.clone()), Some(reversed.clone()), Some(b.clone())]);
+
+        let hausdorff = left.hausdorff_distance(&right).unwrap();
+        assert_eq!(hausdorff.value(0), a.hausdorff_distance(&b));
+        assert_eq!(hausdorff.value(1), 0.);
+        assert!(hausdorff.is_null(2));
+
+        // The Fréchet distance follows the direction of the line strings
+        let frechet = left.frechet_distance(&right).unwrap();
+        assert_eq!(frechet.value(1), 2.);
+        assert!(frechet.is_null(2));
+    }
+
+    #[test]
+    fn empty_line_strings_are_null() {
+        let a = line_string![(x: 0., y: 0.), (x: 1., y: 0.)];
+        let left = array(&[Some(a.clone()), Some(geo::LineString::new(vec![]))]);
+        let right = array(&[Some(geo::LineString::new(vec![])), Some(a)]);
+        let result = left.hausdorff_distance(&right).unwrap();
+        assert_eq!(result.null_count(), 2);
+        let result = left.frechet_distance(&right).unwrap();
+        assert_eq!(result.null_count(), 2);
+    }
+
+    #[test]
+    fn mismatched_lengths() {
+        let a = line_string![(x: 0., y: 0.), (x: 1., y: 0.)];
+        let left = array(&[Some(a.clone()), Some(a.clone())]);
+        let right = array(&[Some(a)]);
+        assert!(left.hausdorff_distance(&right).is_err());
+        assert!(left.frechet_distance(&right).is_err());
+
+        let left = ChunkedLineStringArray::new_unchecked(vec![left.clone(), left]);
+        let right = ChunkedLineStringArray::new_unchecked(vec![right.clone(), right]);
+        assert!(left.hausdorff_distance(&right).is_err());
+        assert!(left.frechet_distance(&right).is_err());
+    }
+
+    #[test]
+    fn chunked() {
+        let a = line_string![(x: 0., y: 0.), (x: 1., y: 0.)];
+        let b = line_string![(x: 0., y: 3.), (x: 1., y: 3.)];
+        let left = array(&[Some(a.clone()), None]);
+        let right = array(&[Some(b), Some(a)]);
+        let left = ChunkedLineStringArray::new_unchecked(vec![left.clone(), left]);
+        let right = ChunkedLineStringArray::new_unchecked(vec![right.clone(), right]);
+        let result = left.hausdorff_distance(&right).unwrap();
+        assert_eq!(result.chunks().len(), 2);
+        for chunk in result.chunks() {
+            assert_eq!(chunk.value(0), 3.);
+            assert!(chunk.is_null(1));
+        }
+    }
+}
@@ -0,0 +1,250 @@
+use crate::algorithm::native::{Binary, MapChunks, Unary};
+use crate::array::*;
+use crate::chunked_array::{ChunkedArray, ChunkedGeometryArrayTrait, ChunkedPointArray};
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::geo_traits::PointTrait;
+use crate::io::geo::point_to_geo;
+use crate::trait_::GeometryScalarTrait;
+use crate::GeometryArrayTrait;
+use arrow_array::Float64Array;
+use geo::HaversineDistance as _HaversineDistance;
+
+// ┌────────────────────────────────┐
+// │ Implementations for RHS arrays │
+// └────────────────────────────────┘
+
+/// Determine the distance between two arrays of points using the [haversine formula].
+///
+/// Coordinates are interpreted as longitude/latitude in degrees.
+///
+/// *Note*: this implementation uses a mean earth radius of 6371.088 km, based on the [recommendation of
+/// the IUGG](ftp://athena.fsv.cvut.cz/ZFG/grs80-Moritz.pdf)
+///
+/// [haversine formula]: https://en.wikipedia.org/wiki/Haversine_formula
+pub trait HaversineDistance<Rhs = Self> {
+    type Output;
+
+    /// Determine the distance between two arrays of points using the [haversine formula].
+    ///
+    /// # Units
+    ///
+    /// - return value: meters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::point;
+    /// use geoarrow::array::PointArray;
+    /// use geoarrow::algorithm::geo::HaversineDistance;
+    ///
+    /// // New York City
+    /// let new_york: PointArray = vec![point!(x: -74.006, y: 40.7128)].as_slice().into();
+    /// // London
+    /// let london: PointArray = vec![point!(x: -0.1278, y: 51.5074)].as_slice().into();
+    ///
+    /// let distance = new_york.haversine_distance(&london).unwrap();
+    ///
+    /// assert_eq!(
+    ///     5_570_230., // meters
+    ///     distance.value(0).round()
+    /// );
+    /// ```
+    ///
+    /// [haversine formula]: https://en.wikipedia.org/wiki/Haversine_formula
+    fn haversine_distance(&self, rhs: &Rhs) -> Self::Output;
+}
+
+impl HaversineDistance for PointArray {
+    type Output = Result<Float64Array>;
+
+    fn haversine_distance(&self, rhs: &PointArray) -> Self::Output {
+        self.try_binary_primitive(rhs, |left, right| {
+            Ok(left.to_geo().haversine_distance(&right.to_geo()))
+        })
+    }
+}
+
+impl HaversineDistance for ChunkedPointArray {
+    type Output = Result<ChunkedArray<Float64Array>>;
+
+    fn haversine_distance(&self, rhs: &ChunkedPointArray) -> Self::Output {
+        if self.chunks().len() != rhs.chunks().len() {
+            return Err(GeoArrowError::General(
+                "Cannot perform binary operation on chunked arrays with different numbers of chunks"
+                    .to_string(),
+            ));
+        }
+        Ok(ChunkedArray::new(
+            self.try_binary_map(rhs.chunks(), |(left, right)| {
+                HaversineDistance::haversine_distance(left, right)
+            })?,
+        ))
+    }
+}
+
+impl HaversineDistance for &dyn GeometryArrayTrait {
+    type Output = Result<Float64Array>;
+
+    fn haversine_distance(&self, rhs: &Self) -> Self::Output {
+        match (self.data_type(), rhs.data_type()) {
+            (GeoDataType::Point(_), GeoDataType::Point(_)) => {
+                HaversineDistance::haversine_distance(self.as_point(), rhs.as_point())
+            }
+            (left, right) => Err(GeoArrowError::IncorrectType(
+                format!(
+                    "Haversine distance between {:?} and {:?} is not supported",
+                    left, right
+                )
+                .into(),
+            )),
+        }
+    }
+}
+
+impl HaversineDistance for &dyn ChunkedGeometryArrayTrait {
+    type Output = Result<ChunkedArray<Float64Array>>;
+
+    fn haversine_distance(&self, rhs: &Self) -> Self::Output {
+        match (self.data_type(), rhs.data_type()) {
+            (GeoDataType::Point(_), GeoDataType::Point(_)) => {
+                HaversineDistance::haversine_distance(self.as_point(), rhs.as_point())
+            }
+            (left, right) => Err(GeoArrowError::IncorrectType(
+                format!(
+                    "Haversine distance between {:?} and {:?} is not supported",
+                    left, right
+                )
+                .into(),
+            )),
+        }
+    }
+}
+
+// ┌─────────────────────────────────┐
+// │ Implementations for RHS scalars │
+// └─────────────────────────────────┘
+
+/// Determine the distance between each point of an array and a single point using the [haversine
+/// formula].
+///
+/// [haversine formula]: https://en.wikipedia.org/wiki/Haversine_formula
+pub trait HaversineDistancePoint<Rhs> {
+    type Output;
+
+    fn haversine_distance(&self, rhs: &Rhs) -> Self::Output;
+}
+
+impl<G: PointTrait<T = f64>> HaversineDistancePoint<G> for PointArray {
+    type Output = Float64Array;
+
+    fn haversine_distance(&self, rhs: &G) -> Self::Output {
+        let rhs = point_to_geo(rhs);
+        self.try_unary_primitive(|geom| {
+            Ok::<_, GeoArrowError>(geom.to_geo().haversine_distance(&rhs))
+        })
+        .unwrap()
+    }
+}
+
+impl<G: PointTrait<T = f64> + Sync> HaversineDistancePoint<G> for ChunkedPointArray {
+    type Output = ChunkedArray<Float64Array>;
+
+    fn haversine_distance(&self, rhs: &G) -> Self::Output {
+        ChunkedArray::new(self.map(|chunk| HaversineDistancePoint::haversine_distance(chunk, rhs)))
+    }
+}
+
+impl<G: PointTrait<T = f64>> HaversineDistancePoint<G> for &dyn GeometryArrayTrait {
+    type Output = Result<Float64Array>;
+
+    fn haversine_distance(&self, rhs: &G) -> Self::Output {
+        match self.data_type() {
+            GeoDataType::Point(_) => Ok(HaversineDistancePoint::haversine_distance(
+                self.as_point(),
+                rhs,
+            )),
+            dt => Err(GeoArrowError::IncorrectType(
+                format!("Haversine distance of {:?} is not supported", dt).into(),
+            )),
+        }
+    }
+}
+
+impl<G: PointTrait<T = f64>> HaversineDistancePoint<G> for &dyn ChunkedGeometryArrayTrait {
+    type Output = Result<ChunkedArray<Float64Array>>;
+
+    fn haversine_distance(&self, rhs: &G) -> Self::Output {
+        let rhs = point_to_geo(rhs);
+        match self.data_type() {
+            GeoDataType::Point(_) => Ok(HaversineDistancePoint::haversine_distance(
+                self.as_point(),
+                &rhs,
+            )),
+            dt => Err(GeoArrowError::IncorrectType(
+                format!("Haversine distance of {:?} is not supported", dt).into(),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::chunked_array::ChunkedGeometryArray;
+    use crate::test::point;
+    use crate::trait_::GeometryArrayAccessor;
+    use approx::assert_relative_eq;
+    use geo::point;
+
+    #[test]
+    fn haversine_distance_arrays() {
+        let left: PointArray = vec![point!(x: -74.006, y: 40.7128), point!(x: 0., y: 0.)]
+            .as_slice()
+            .into();
+        let right: PointArray = vec![point!(x: -0.1278, y: 51.5074), point!(x: 0., y: 1.)]
+            .as_slice()
+            .into();
+
+        let distance = HaversineDistance::haversine_distance(&left, &right).unwrap();
+        assert_relative_eq!(distance.value(0), 5_570_230., epsilon = 1.);
+        assert_relative_eq!(distance.value(1), 111_195., epsilon = 1.);
+    }
+
+    #[test]
+    fn haversine_distance_scalar() {
+        let arr = point::point_array();
+        let origin = point!(x: 0., y: 0.);
+
+        let distance = HaversineDistancePoint::haversine_distance(&arr, &origin);
+        assert_eq!(distance.len(), arr.len());
+        for (i, p) in arr.iter_geo_values().enumerate() {
+            assert_relative_eq!(distance.value(i), p.haversine_distance(&origin));
+        }
+    }
+
+    #[test]
+    fn haversine_distance_chunked() {
+        let chunked =
+            ChunkedGeometryArray::new_unchecked(vec![point::point_array(), point::point_array()]);
+
+        let distance = HaversineDistance::haversine_distance(&chunked, &chunked).unwrap();
+        assert_eq!(distance.len(), 2 * point::point_array().len());
+        assert!(distance
+            .chunks()
+            .iter()
+            .all(|chunk| chunk.values().iter().all(|value| *value == 0.)));
+
+        let single = ChunkedGeometryArray::new_unchecked(vec![point::point_array()]);
+        assert!(HaversineDistance::haversine_distance(&chunked, &single).is_err());
+    }
+
+    #[test]
+    fn haversine_distance_unsupported_type() {
+        let points = point::point_array();
+        let line_strings = crate::test::linestring::large_ls_array();
+        let left = &points as &dyn GeometryArrayTrait;
+        let right = &line_strings as &dyn GeometryArrayTrait;
+        assert!(HaversineDistance::haversine_distance(&left, &right).is_err());
+    }
+}
@@ -15,6 +15,10 @@ use geo::HaversineLength as _HaversineLength;
 ///
 /// *Note*: this implementation uses a mean earth radius of 6371.088 km, based on the [recommendation of
 /// the IUGG](ftp://athena.fsv.cvut.cz/ZFG/grs80-Moritz.pdf)
+///
+/// Supports the same array types as [`EuclideanLength`][crate::algorithm::geo::EuclideanLength]:
+/// points and multi points have a length of zero, while polygon, mixed and geometry collection
+/// arrays are unsupported.
 pub trait HaversineLength {
     type Output;
 
@@ -115,7 +119,15 @@ impl HaversineLength for &dyn GeometryArrayTrait {
             // GeoDataType::LargeGeometryCollection(_) => {
             //     self.as_large_geometry_collection().haversine_length()
             // }
-            _ => return Err(GeoArrowError::IncorrectType("".into())),
+            _ => {
+                return Err(GeoArrowError::IncorrectType(
+                    format!(
+                        "Haversine length of {:?} is not supported",
+                        self.data_type()
+                    )
+                    .into(),
+                ))
+            }
         };
         Ok(result)
     }
@@ -170,7 +182,13 @@ impl HaversineLength for &dyn ChunkedGeometryArrayTrait {
             // GeoDataType::LargeGeometryCollection(_) => {
             //     self.as_large_geometry_collection().haversine_length()
             // }
-            _ => Err(GeoArrowError::IncorrectType("".into())),
+            _ => Err(GeoArrowError::IncorrectType(
+                format!(
+                    "Haversine length of {:?} is not supported",
+                    self.data_type()
+                )
+                .into(),
+            )),
         }
     }
 }
@@ -1,4 +1,9 @@
-use crate::chunked_array::ChunkedArray;
+use crate::algorithm::native::bounding_rect::BoundingRect;
+use crate::algorithm::native::Unary;
+use crate::array::*;
+use crate::chunked_array::{ChunkedArray, ChunkedGeometryArray, ChunkedGeometryArrayTrait};
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
 use crate::geo_traits::{
     GeometryCollectionTrait, GeometryTrait, LineStringTrait, MultiLineStringTrait, MultiPointTrait,
     MultiPolygonTrait, PointTrait, PolygonTrait,
@@ -10,8 +15,9 @@ use crate::io::geo::{
     multi_point_to_geo, multi_polygon_to_geo, point_to_geo, polygon_to_geo,
 };
 use crate::trait_::GeometryScalarTrait;
+use crate::GeometryArrayTrait;
 use arrow_array::{BooleanArray, OffsetSizeTrait};
-use geo::{BoundingRect, Intersects as _Intersects};
+use geo::{BoundingRect as _, Intersects as _Intersects};
 
 /// Checks if the geometry Self intersects the geometry Rhs.
 /// More formally, either boundary or interior of Self has
@@ -763,3 +769,229 @@ impl_intersects!(IndexedChunkedMultiLineStringArray<O>);
 impl_intersects!(IndexedChunkedMultiPolygonArray<O>);
 impl_intersects!(IndexedChunkedMixedGeometryArray<O>);
 impl_intersects!(IndexedChunkedGeometryCollectionArray<O>);
+
+// ┌──────────────────────────────────────────────────────┐
+// │ Implementations for non-indexed arrays and a scalar  │
+// └──────────────────────────────────────────────────────┘
+
+/// Checks if each geometry of an array intersects a single scalar geometry, such as an area of
+/// interest.
+///
+/// Unlike [`IntersectsGeometry`], this needs no spatial index. The bounding rect of `rhs` is
+/// computed once, and rows whose bounding rect doesn't intersect it are `false` without running
+/// the full predicate. Null geometries are null in the output.
+///
+/// Combined with an Arrow filter, this selects the features of a table inside an area:
+///
+/// ```
+/// use arrow::compute::filter_record_batch;
+/// use geo::polygon;
+/// use geoarrow::algorithm::geo::IntersectsScalar;
+/// use geoarrow::table::GeoTable;
+/// # use arrow_array::RecordBatch;
+/// # use arrow_schema::Schema;
+/// # use geoarrow::array::PointArray;
+/// # use geoarrow::GeometryArrayTrait;
+/// # use std::sync::Arc;
+/// #
+/// # let points: PointArray = vec![geo::point!(x: 0.5, y: 0.5), geo::point!(x: 5., y: 5.)]
+/// #     .as_slice()
+/// #     .into();
+/// # let schema = Arc::new(Schema::new(vec![points.extension_field()]));
+/// # let batch = RecordBatch::try_new(schema.clone(), vec![points.to_array_ref()]).unwrap();
+/// # let table = GeoTable::try_new(schema, vec![batch], 0).unwrap();
+///
+/// let area_of_interest = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+/// let geometry = table.geometry().unwrap();
+/// let mask = geometry.as_ref().intersects_scalar(&area_of_interest).unwrap();
+///
+/// let filtered = table
+///     .batches()
+///     .iter()
+///     .zip(mask.chunks())
+///     .map(|(batch, mask)| filter_record_batch(batch, mask).unwrap())
+///     .collect::<Vec<_>>();
+/// assert_eq!(filtered[0].num_rows(), 1);
+/// ```
+pub trait IntersectsScalar<Rhs> {
+    type Output;
+
+    fn intersects_scalar(&self, rhs: &Rhs) -> Self::Output;
+}
+
+impl<G: GeometryTrait<T = f64>> IntersectsScalar<G> for PointArray {
+    type Output = BooleanArray;
+
+    fn intersects_scalar(&self, rhs: &G) -> Self::Output {
+        let mut rhs_rect = BoundingRect::new();
+        rhs_rect.add_geometry(rhs);
+        let rhs = geometry_to_geo(rhs);
+        self.try_unary_boolean::<_, GeoArrowError>(|geom| {
+            let mut rect = BoundingRect::new();
+            rect.add_point(&geom);
+            Ok(rect.intersects(&rhs_rect) && geom.to_geo().intersects(&rhs))
+        })
+        .unwrap()
+    }
+}
+
+macro_rules! impl_intersects_scalar {
+    ($array:ty, $add_func:ident) => {
+        impl<O: OffsetSizeTrait, G: GeometryTrait<T = f64>> IntersectsScalar<G> for $array {
+            type Output = BooleanArray;
+
+            fn intersects_scalar(&self, rhs: &G) -> Self::Output {
+                let mut rhs_rect = BoundingRect::new();
+                rhs_rect.add_geometry(rhs);
+                let rhs = geometry_to_geo(rhs);
+                self.try_unary_boolean::<_, GeoArrowError>(|geom| {
+                    let mut rect = BoundingRect::new();
+                    rect.$add_func(&geom);
+                    Ok(rect.intersects(&rhs_rect) && geom.to_geo().intersects(&rhs))
+                })
+                .unwrap()
+            }
+        }
+    };
+}
+
+impl_intersects_scalar!(LineStringArray<O>, add_line_string);
+impl_intersects_scalar!(PolygonArray<O>, add_polygon);
+impl_intersects_scalar!(MultiPointArray<O>, add_multi_point);
+impl_intersects_scalar!(MultiLineStringArray<O>, add_multi_line_string);
+impl_intersects_scalar!(MultiPolygonArray<O>, add_multi_polygon);
+impl_intersects_scalar!(MixedGeometryArray<O>, add_geometry);
+impl_intersects_scalar!(GeometryCollectionArray<O>, add_geometry_collection);
+
+impl<G: GeometryTrait<T = f64>> IntersectsScalar<G> for &dyn GeometryArrayTrait {
+    type Output = Result<BooleanArray>;
+
+    fn intersects_scalar(&self, rhs: &G) -> Self::Output {
+        let result = match self.data_type() {
+            GeoDataType::Point(_) => self.as_point().intersects_scalar(rhs),
+            GeoDataType::LineString(_) => self.as_line_string().intersects_scalar(rhs),
+            GeoDataType::LargeLineString(_) => self.as_large_line_string().intersects_scalar(rhs),
+            GeoDataType::Polygon(_) => self.as_polygon().intersects_scalar(rhs),
+            GeoDataType::LargePolygon(_) => self.as_large_polygon().intersects_scalar(rhs),
+            GeoDataType::MultiPoint(_) => self.as_multi_point().intersects_scalar(rhs),
+            GeoDataType::LargeMultiPoint(_) => self.as_large_multi_point().intersects_scalar(rhs),
+            GeoDataType::MultiLineString(_) => self.as_multi_line_string().intersects_scalar(rhs),
+            GeoDataType::LargeMultiLineString(_) => {
+                self.as_large_multi_line_string().intersects_scalar(rhs)
+            }
+            GeoDataType::MultiPolygon(_) => self.as_multi_polygon().intersects_scalar(rhs),
+            GeoDataType::LargeMultiPolygon(_) => {
+                self.as_large_multi_polygon().intersects_scalar(rhs)
+            }
+            GeoDataType::Mixed(_) => self.as_mixed().intersects_scalar(rhs),
+            GeoDataType::LargeMixed(_) => self.as_large_mixed().intersects_scalar(rhs),
+            GeoDataType::GeometryCollection(_) => {
+                self.as_geometry_collection().intersects_scalar(rhs)
+            }
+            GeoDataType::LargeGeometryCollection(_) => {
+                self.as_large_geometry_collection().intersects_scalar(rhs)
+            }
+            dt => {
+                return Err(GeoArrowError::IncorrectType(
+                    format!("Intersects is not supported for {:?}", dt).into(),
+                ))
+            }
+        };
+        Ok(result)
+    }
+}
+
+impl<A: GeometryArrayTrait, G: GeometryTrait<T = f64>> IntersectsScalar<G>
+    for ChunkedGeometryArray<A>
+{
+    type Output = Result<ChunkedArray<BooleanArray>>;
+
+    fn intersects_scalar(&self, rhs: &G) -> Self::Output {
+        let rhs = geometry_to_geo(rhs);
+        self.try_map(|chunk| chunk.as_ref().intersects_scalar(&rhs))?
+            .try_into()
+    }
+}
+
+impl<G: GeometryTrait<T = f64>> IntersectsScalar<G> for &dyn ChunkedGeometryArrayTrait {
+    type Output = Result<ChunkedArray<BooleanArray>>;
+
+    fn intersects_scalar(&self, rhs: &G) -> Self::Output {
+        let rhs = geometry_to_geo(rhs);
+        self.geometry_chunks()
+            .into_iter()
+            .map(|chunk| chunk.intersects_scalar(&rhs))
+            .collect::<Result<Vec<_>>>()?
+            .try_into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use geo::{line_string, point, polygon};
+
+    fn area_of_interest() -> geo::Polygon {
+        polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)]
+    }
+
+    #[test]
+    fn intersects_scalar_points() {
+        let arr: PointArray = vec![
+            Some(point!(x: 5., y: 5.)),
+            None,
+            Some(point!(x: 20., y: 20.)),
+            Some(point!(x: 10., y: 5.)),
+        ]
+        .into();
+        let result = arr.intersects_scalar(&area_of_interest());
+        assert_eq!(
+            result,
+            BooleanArray::from(vec![Some(true), None, Some(false), Some(true)])
+        );
+    }
+
+    #[test]
+    fn intersects_scalar_bounding_rect_is_not_enough() {
+        // The bounding rects of the line and the triangle overlap, but the geometries don't
+        let triangle = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 0., y: 10.)];
+        let arr: LineStringArray<i32> = vec![
+            line_string![(x: 8., y: 8.), (x: 9., y: 9.)],
+            line_string![(x: 1., y: 1.), (x: 9., y: 9.)],
+        ]
+        .as_slice()
+        .into();
+        let result = arr.intersects_scalar(&triangle);
+        assert_eq!(result, BooleanArray::from(vec![false, true]));
+    }
+
+    #[test]
+    fn intersects_scalar_dyn_and_chunked() {
+        let arr: PolygonArray<i32> = vec![
+            polygon![(x: 9., y: 9.), (x: 12., y: 9.), (x: 12., y: 12.)],
+            polygon![(x: 11., y: 11.), (x: 12., y: 11.), (x: 12., y: 12.)],
+        ]
+        .as_slice()
+        .into();
+        let expected = BooleanArray::from(vec![true, false]);
+
+        let result = (&arr as &dyn GeometryArrayTrait)
+            .intersects_scalar(&area_of_interest())
+            .unwrap();
+        assert_eq!(result, expected);
+
+        let chunked = ChunkedGeometryArray::new_unchecked(vec![arr.clone(), arr]);
+        let result = chunked.intersects_scalar(&area_of_interest()).unwrap();
+        assert_eq!(result.chunks(), &[expected.clone(), expected.clone()]);
+
+        let result = (&chunked as &dyn ChunkedGeometryArrayTrait)
+            .intersects_scalar(&area_of_interest())
+            .unwrap();
+        assert_eq!(result.chunks(), &[expected.clone(), expected]);
+
+        let wkb = crate::io::wkb::to_wkb::<i32>(&chunked.chunks()[0]);
+        assert!((&wkb as &dyn GeometryArrayTrait)
+            .intersects_scalar(&area_of_interest())
+            .is_err());
+    }
+}
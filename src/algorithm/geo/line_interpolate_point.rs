@@ -83,7 +83,7 @@ impl<O: OffsetSizeTrait> LineInterpolatePoint<&[Float64Array]> for ChunkedLineSt
     type Output = ChunkedPointArray;
 
     fn line_interpolate_point(&self, p: &[Float64Array]) -> Self::Output {
-        ChunkedPointArray::new(
+        ChunkedPointArray::new_unchecked(
             self.binary_map(p, |(left, right)| left.line_interpolate_point(right)),
         )
     }
@@ -141,7 +141,7 @@ impl<O: OffsetSizeTrait> LineInterpolatePoint<f64> for ChunkedLineStringArray<O>
     type Output = ChunkedPointArray;
 
     fn line_interpolate_point(&self, fraction: f64) -> Self::Output {
-        ChunkedPointArray::new(self.map(|chunk| chunk.line_interpolate_point(fraction)))
+        ChunkedPointArray::new_unchecked(self.map(|chunk| chunk.line_interpolate_point(fraction)))
     }
 }
 
@@ -7,13 +7,17 @@ pub(crate) mod utils;
 
 /// Composable affine operations such as rotate, scale, skew, and translate
 mod affine_ops;
-pub use affine_ops::AffineOps;
+pub use affine_ops::{AffineOps, AffineOpsMut};
 pub use geo::AffineTransform;
 
 /// Calculate the area of the surface of geometries.
 mod area;
 pub use area::Area;
 
+/// Boolean operations, such as intersection and union, between pairs of polygons.
+mod boolean_ops;
+pub use boolean_ops::{BooleanOps, BooleanOpsScalar};
+
 /// Calculate the bounding rectangle of geometries.
 mod bounding_rect;
 pub use bounding_rect::BoundingRect;
@@ -42,9 +46,13 @@ pub use contains::Contains;
 mod convex_hull;
 pub use convex_hull::ConvexHull;
 
+/// Detect gaps and overlaps in polygons that are supposed to form a coverage
+mod coverage;
+pub use coverage::{CoverageGap, CoverageOverlap, CoverageReport, CoverageSummary, ValidateCoverage};
+
 /// Densify linear geometry components
 mod densify;
-pub use densify::Densify;
+pub use densify::{Densify, DensifyHaversine};
 
 /// Dimensionality of a geometry and its boundary, based on OGC-SFA.
 mod dimensions;
@@ -59,6 +67,7 @@ pub use euclidean_length::EuclideanLength;
 mod euclidean_distance;
 pub use euclidean_distance::EuclideanDistance;
 
+/// Determine the similarity between two arrays of `LineStrings` using the Fréchet distance.
 mod frechet_distance;
 pub use frechet_distance::{FrechetDistance, FrechetDistanceLineString};
 
@@ -66,17 +75,29 @@ pub use frechet_distance::{FrechetDistance, FrechetDistanceLineString};
 mod geodesic_area;
 pub use geodesic_area::GeodesicArea;
 
+/// Calculate the Geodesic distance between points.
+mod geodesic_distance;
+pub use geodesic_distance::{GeodesicDistance, GeodesicDistancePoint};
+
 /// Calculate the Geodesic length of a line.
 mod geodesic_length;
 pub use geodesic_length::GeodesicLength;
 
+/// Determine the similarity between two arrays of `LineStrings` using the Hausdorff distance.
+mod hausdorff_distance;
+pub use hausdorff_distance::HausdorffDistance;
+
+/// Calculate the Haversine distance between points.
+mod haversine_distance;
+pub use haversine_distance::{HaversineDistance, HaversineDistancePoint};
+
 /// Calculate the Haversine length of a Line.
 mod haversine_length;
 pub use haversine_length::HaversineLength;
 
 /// Determine whether `Geometry` `A` intersects `Geometry` `B`.
 mod intersects;
-pub use intersects::Intersects;
+pub use intersects::{Intersects, IntersectsScalar};
 
 /// Interpolate a point along a `LineStringArray`.
 mod line_interpolate_point;
@@ -90,9 +111,16 @@ pub use line_locate_point::{LineLocatePoint, LineLocatePointScalar};
 mod minimum_rotated_rect;
 pub use minimum_rotated_rect::MinimumRotatedRect;
 
+/// Rewind polygon rings to follow a winding order convention.
+mod orient;
+pub use geo::winding_order::WindingOrder;
+pub use orient::{Orient, RingWindingOrder};
+
 /// Remove (consecutive) repeated points
 mod remove_repeated_points;
-pub use remove_repeated_points::RemoveRepeatedPoints;
+pub use remove_repeated_points::{
+    CollapsedRings, RemoveRepeatedPoints, RemoveRepeatedPointsOptions,
+};
 
 /// Rotate geometries by an angle given in degrees.
 mod rotate;
@@ -114,6 +142,10 @@ pub use simplify_vw::SimplifyVw;
 mod simplify_vw_preserve;
 pub use simplify_vw_preserve::SimplifyVwPreserve;
 
+/// Split segments longer than a length in meters, measured on the Earth.
+mod split_long_segments;
+pub use split_long_segments::{LengthMethod, SplitLongSegments};
+
 /// Skew geometries by shearing it at angles along the x and y dimensions
 mod skew;
 pub use skew::Skew;
@@ -122,6 +154,11 @@ pub use skew::Skew;
 mod translate;
 pub use translate::Translate;
 
+/// Union all polygons of an array into a single MultiPolygon.
+mod unary_union;
+pub(crate) use unary_union::unary_union;
+pub use unary_union::UnaryUnion;
+
 /// Calculate the Vincenty length of a [`LineStringArray`][crate::array::LineStringArray].
 mod vincenty_length;
 pub use vincenty_length::VincentyLength;
@@ -0,0 +1,309 @@
+use std::sync::Arc;
+
+use crate::array::*;
+use crate::chunked_array::*;
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::geo_traits::{MultiPolygonTrait, PolygonTrait};
+use crate::io::geo::line_string_to_geo;
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+use arrow_array::OffsetSizeTrait;
+use geo::orient::{Direction, Orient as _Orient};
+use geo::winding_order::{Winding, WindingOrder};
+
+/// Rewind the rings of polygons to follow a winding order convention.
+///
+/// With [`WindingOrder::CounterClockwise`], exterior rings are wound counter-clockwise and
+/// interior rings clockwise, as required by GeoJSON ([RFC 7946]). With
+/// [`WindingOrder::Clockwise`], exterior rings are wound clockwise and interior rings
+/// counter-clockwise, as in Shapefiles.
+///
+/// Only the order of the coordinates within each ring changes, so the output has the same
+/// offsets, coordinate type and metadata as the input, and null geometries stay null. Degenerate
+/// rings, which enclose no area and so have no winding order, are left unchanged.
+///
+/// [RFC 7946]: https://datatracker.ietf.org/doc/html/rfc7946#section-3.1.6
+pub trait Orient {
+    type Output;
+
+    /// Rewind the rings of each polygon to follow `convention`.
+    fn orient(&self, convention: WindingOrder) -> Self::Output;
+}
+
+/// Inspect the winding order of each polygon ring.
+pub trait RingWindingOrder {
+    type Output;
+
+    /// The winding order of each ring, in the order of the rings in the array: the exterior ring
+    /// of each polygon followed by its interior rings. Null geometries have no rings.
+    ///
+    /// Degenerate rings, which enclose no area, have a winding order of `None`.
+    fn winding_order(&self) -> Self::Output;
+}
+
+fn direction(convention: WindingOrder) -> Direction {
+    match convention {
+        WindingOrder::CounterClockwise => Direction::Default,
+        WindingOrder::Clockwise => Direction::Reversed,
+    }
+}
+
+fn polygon_winding_order(
+    polygon: &impl PolygonTrait<T = f64>,
+    out: &mut Vec<Option<WindingOrder>>,
+) {
+    if let Some(exterior) = polygon.exterior() {
+        out.push(line_string_to_geo(&exterior).winding_order());
+    }
+    out.extend(
+        polygon
+            .interiors()
+            .map(|ring| line_string_to_geo(&ring).winding_order()),
+    );
+}
+
+impl<O: OffsetSizeTrait> Orient for PolygonArray<O> {
+    type Output = Self;
+
+    fn orient(&self, convention: WindingOrder) -> Self::Output {
+        let direction = direction(convention);
+        // Orienting only reorders coordinates, so the input's buffer lengths are exact
+        let mut builder = PolygonBuilder::with_capacity_and_options(
+            self.buffer_lengths(),
+            self.coord_type(),
+            self.metadata(),
+        );
+        self.iter_geo().for_each(|maybe_g| {
+            builder
+                .push_polygon(maybe_g.map(|geom| geom.orient(direction)).as_ref())
+                .unwrap()
+        });
+        builder.finish()
+    }
+}
+
+impl<O: OffsetSizeTrait> Orient for MultiPolygonArray<O> {
+    type Output = Self;
+
+    fn orient(&self, convention: WindingOrder) -> Self::Output {
+        let direction = direction(convention);
+        // Orienting only reorders coordinates, so the input's buffer lengths are exact
+        let mut builder = MultiPolygonBuilder::with_capacity_and_options(
+            self.buffer_lengths(),
+            self.coord_type(),
+            self.metadata(),
+        );
+        self.iter_geo().for_each(|maybe_g| {
+            builder
+                .push_multi_polygon(maybe_g.map(|geom| geom.orient(direction)).as_ref())
+                .unwrap()
+        });
+        builder.finish()
+    }
+}
+
+impl<O: OffsetSizeTrait> RingWindingOrder for PolygonArray<O> {
+    type Output = Vec<Option<WindingOrder>>;
+
+    fn winding_order(&self) -> Self::Output {
+        let mut output = Vec::with_capacity(self.len());
+        self.iter()
+            .flatten()
+            .for_each(|polygon| polygon_winding_order(&polygon, &mut output));
+        output
+    }
+}
+
+impl<O: OffsetSizeTrait> RingWindingOrder for MultiPolygonArray<O> {
+    type Output = Vec<Option<WindingOrder>>;
+
+    fn winding_order(&self) -> Self::Output {
+        let mut output = Vec::with_capacity(self.len());
+        self.iter().flatten().for_each(|multi_polygon| {
+            multi_polygon
+                .polygons()
+                .for_each(|polygon| polygon_winding_order(&polygon, &mut output))
+        });
+        output
+    }
+}
+
+impl Orient for &dyn GeometryArrayTrait {
+    type Output = Result<Arc<dyn GeometryArrayTrait>>;
+
+    fn orient(&self, convention: WindingOrder) -> Self::Output {
+        let result: Arc<dyn GeometryArrayTrait> = match self.data_type() {
+            GeoDataType::Polygon(_) => Arc::new(self.as_polygon().orient(convention)),
+            GeoDataType::LargePolygon(_) => Arc::new(self.as_large_polygon().orient(convention)),
+            GeoDataType::MultiPolygon(_) => Arc::new(self.as_multi_polygon().orient(convention)),
+            GeoDataType::LargeMultiPolygon(_) => {
+                Arc::new(self.as_large_multi_polygon().orient(convention))
+            }
+            _ => return Err(GeoArrowError::IncorrectType("".into())),
+        };
+        Ok(result)
+    }
+}
+
+impl RingWindingOrder for &dyn GeometryArrayTrait {
+    type Output = Result<Vec<Option<WindingOrder>>>;
+
+    fn winding_order(&self) -> Self::Output {
+        match self.data_type() {
+            GeoDataType::Polygon(_) => Ok(self.as_polygon().winding_order()),
+            GeoDataType::LargePolygon(_) => Ok(self.as_large_polygon().winding_order()),
+            GeoDataType::MultiPolygon(_) => Ok(self.as_multi_polygon().winding_order()),
+            GeoDataType::LargeMultiPolygon(_) => Ok(self.as_large_multi_polygon().winding_order()),
+            _ => Err(GeoArrowError::IncorrectType("".into())),
+        }
+    }
+}
+
+macro_rules! impl_chunked {
+    ($chunked_array:ty) => {
+        impl<O: OffsetSizeTrait> Orient for $chunked_array {
+            type Output = Self;
+
+            fn orient(&self, convention: WindingOrder) -> Self::Output {
+                self.map(|chunk| chunk.orient(convention))
+                    .try_into()
+                    .unwrap()
+            }
+        }
+
+        impl<O: OffsetSizeTrait> RingWindingOrder for $chunked_array {
+            type Output = Vec<Option<WindingOrder>>;
+
+            fn winding_order(&self) -> Self::Output {
+                self.map(|chunk| chunk.winding_order()).concat()
+            }
+        }
+    };
+}
+
+impl_chunked!(ChunkedPolygonArray<O>);
+impl_chunked!(ChunkedMultiPolygonArray<O>);
+
+impl Orient for &dyn ChunkedGeometryArrayTrait {
+    type Output = Result<Arc<dyn ChunkedGeometryArrayTrait>>;
+
+    fn orient(&self, convention: WindingOrder) -> Self::Output {
+        let result: Arc<dyn ChunkedGeometryArrayTrait> = match self.data_type() {
+            GeoDataType::Polygon(_) => Arc::new(self.as_polygon().orient(convention)),
+            GeoDataType::LargePolygon(_) => Arc::new(self.as_large_polygon().orient(convention)),
+            GeoDataType::MultiPolygon(_) => Arc::new(self.as_multi_polygon().orient(convention)),
+            GeoDataType::LargeMultiPolygon(_) => {
+                Arc::new(self.as_large_multi_polygon().orient(convention))
+            }
+            _ => return Err(GeoArrowError::IncorrectType("".into())),
+        };
+        Ok(result)
+    }
+}
+
+impl RingWindingOrder for &dyn ChunkedGeometryArrayTrait {
+    type Output = Result<Vec<Option<WindingOrder>>>;
+
+    fn winding_order(&self) -> Self::Output {
+        match self.data_type() {
+            GeoDataType::Polygon(_) => Ok(self.as_polygon().winding_order()),
+            GeoDataType::LargePolygon(_) => Ok(self.as_large_polygon().winding_order()),
+            GeoDataType::MultiPolygon(_) => Ok(self.as_multi_polygon().winding_order()),
+            GeoDataType::LargeMultiPolygon(_) => Ok(self.as_large_multi_polygon().winding_order()),
+            _ => Err(GeoArrowError::IncorrectType("".into())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::metadata::ArrayMetadata;
+    use geo::{line_string, polygon, LineString};
+    use serde_json::json;
+
+    /// A clockwise square with a counter-clockwise hole, the opposite of RFC 7946.
+    fn clockwise_polygon() -> geo::Polygon {
+        geo::Polygon::new(
+            line_string![(x: 0., y: 0.), (x: 0., y: 4.), (x: 4., y: 4.), (x: 4., y: 0.)],
+            vec![line_string![(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)]],
+        )
+    }
+
+    /// A polygon whose exterior ring is a line, with no area.
+    fn degenerate_polygon() -> geo::Polygon {
+        polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.)]
+    }
+
+    #[test]
+    fn orient_polygons() {
+        let metadata = Arc::new(ArrayMetadata {
+            crs: Some(json!("EPSG:4326")),
+            edges: None,
+        });
+        let arr = PolygonBuilder::<i32>::from_nullable_polygons(
+            &[Some(clockwise_polygon()), None, Some(degenerate_polygon())],
+            Some(CoordType::Separated),
+            metadata.clone(),
+        )
+        .finish();
+        assert_eq!(
+            arr.winding_order(),
+            vec![
+                Some(WindingOrder::Clockwise),
+                Some(WindingOrder::CounterClockwise),
+                None
+            ]
+        );
+
+        let oriented = arr.orient(WindingOrder::CounterClockwise);
+        assert_eq!(
+            oriented.winding_order(),
+            vec![
+                Some(WindingOrder::CounterClockwise),
+                Some(WindingOrder::Clockwise),
+                None
+            ]
+        );
+        assert_eq!(oriented.geom_offsets, arr.geom_offsets);
+        assert_eq!(oriented.ring_offsets, arr.ring_offsets);
+        assert_eq!(oriented.coord_type(), CoordType::Separated);
+        assert_eq!(oriented.metadata(), metadata);
+        assert!(oriented.is_null(1));
+        assert_eq!(oriented.value_as_geo(2), degenerate_polygon());
+
+        let exterior: LineString = oriented.value_as_geo(0).exterior().clone();
+        let mut expected = clockwise_polygon().exterior().clone();
+        expected.0.reverse();
+        assert_eq!(exterior, expected);
+
+        // Already oriented geometries are unchanged
+        let clockwise = arr.orient(WindingOrder::Clockwise);
+        assert_eq!(clockwise.value_as_geo(0), clockwise_polygon());
+    }
+
+    #[test]
+    fn orient_multi_polygons() {
+        let multi_polygon = geo::MultiPolygon(vec![clockwise_polygon(), degenerate_polygon()]);
+        let arr: MultiPolygonArray<i32> = vec![multi_polygon].as_slice().into();
+        assert_eq!(arr.winding_order().len(), 3);
+
+        let oriented = arr.orient(WindingOrder::CounterClockwise);
+        assert_eq!(
+            oriented.winding_order(),
+            vec![
+                Some(WindingOrder::CounterClockwise),
+                Some(WindingOrder::Clockwise),
+                None
+            ]
+        );
+
+        let dyn_arr: &dyn GeometryArrayTrait = &arr;
+        let oriented = dyn_arr.orient(WindingOrder::CounterClockwise).unwrap();
+        assert_eq!(
+            oriented.as_ref().as_multi_polygon().winding_order()[0],
+            Some(WindingOrder::CounterClockwise)
+        );
+    }
+}
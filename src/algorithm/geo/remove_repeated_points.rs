@@ -9,6 +9,34 @@ use crate::GeometryArrayTrait;
 use arrow_array::OffsetSizeTrait;
 use geo::RemoveRepeatedPoints as _RemoveRepeatedPoints;
 
+/// What to do with a polygon ring that has fewer than four coordinates after removing repeated
+/// points, and so no longer encloses an area.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CollapsedRings {
+    /// Remove the ring. A polygon whose exterior ring collapsed is removed from its multi
+    /// polygon, or becomes an empty polygon.
+    #[default]
+    Drop,
+
+    /// Make the whole geometry null.
+    Null,
+}
+
+/// Options for [`RemoveRepeatedPoints`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RemoveRepeatedPointsOptions {
+    /// What to do with polygon rings that collapse to fewer than four coordinates.
+    pub collapsed_rings: CollapsedRings,
+}
+
+impl RemoveRepeatedPointsOptions {
+    /// Set what to do with polygon rings that collapse to fewer than four coordinates.
+    pub fn with_collapsed_rings(mut self, collapsed_rings: CollapsedRings) -> Self {
+        self.collapsed_rings = collapsed_rings;
+        self
+    }
+}
+
 /// Remove repeated points from a `MultiPoint` and repeated consecutive coordinates
 /// from `LineString`, `Polygon`, `MultiLineString` and `MultiPolygon`.
 ///
@@ -16,11 +44,24 @@ use geo::RemoveRepeatedPoints as _RemoveRepeatedPoints;
 /// of each geometry in the collection.
 ///
 /// For `Point`, `Line`, `Rect` and `Triangle` the geometry remains the same.
+///
+/// The output keeps the coordinate type and metadata of the input. Polygon rings left with fewer
+/// than four coordinates are handled as set by [`RemoveRepeatedPointsOptions`], and are dropped
+/// by default.
 pub trait RemoveRepeatedPoints {
     type Output;
 
     /// Create a new geometry with (consecutive) repeated points removed.
-    fn remove_repeated_points(&self) -> Self::Output;
+    fn remove_repeated_points(&self) -> Self::Output {
+        self.remove_repeated_points_with_options(Default::default())
+    }
+
+    /// Create a new geometry with (consecutive) repeated points removed, handling collapsed
+    /// rings as set by `options`.
+    fn remove_repeated_points_with_options(
+        &self,
+        options: RemoveRepeatedPointsOptions,
+    ) -> Self::Output;
 
     // /// Remove (consecutive) repeated points inplace.
     // fn remove_repeated_points_mut(&mut self);
@@ -30,78 +71,166 @@ pub trait RemoveRepeatedPoints {
 impl RemoveRepeatedPoints for PointArray {
     type Output = Self;
 
-    fn remove_repeated_points(&self) -> Self::Output {
+    fn remove_repeated_points_with_options(
+        &self,
+        _options: RemoveRepeatedPointsOptions,
+    ) -> Self::Output {
         self.clone()
     }
 }
 
 /// Implementation that iterates over geo objects
 macro_rules! iter_geo_impl {
-    ($type:ty, $builder_type:ty, $push_func:ident) => {
+    ($type:ty, $remove_func:expr, $builder:ident) => {
         impl<O: OffsetSizeTrait> RemoveRepeatedPoints for $type {
             type Output = Self;
 
-            fn remove_repeated_points(&self) -> Self::Output {
-                let mut output_array = <$builder_type>::with_capacity(self.buffer_lengths());
-
-                self.iter_geo().for_each(|maybe_g| {
-                    output_array
-                        .$push_func(maybe_g.map(|geom| geom.remove_repeated_points()).as_ref())
-                        .unwrap();
-                });
-
-                output_array.finish()
+            fn remove_repeated_points_with_options(
+                &self,
+                options: RemoveRepeatedPointsOptions,
+            ) -> Self::Output {
+                let output_geoms = self
+                    .iter_geo()
+                    .map(|maybe_g| maybe_g.and_then(|geom| $remove_func(geom, options)));
+                // Removing points only shrinks geometries, so the input's buffer lengths are an
+                // upper bound for the output's
+                $builder::from_iter_with_capacity(
+                    output_geoms,
+                    self.buffer_lengths(),
+                    self.coord_type(),
+                    self.metadata(),
+                )
+                .finish()
             }
         }
     };
 }
 
-iter_geo_impl!(LineStringArray<O>, LineStringBuilder<O>, push_line_string);
-iter_geo_impl!(PolygonArray<O>, PolygonBuilder<O>, push_polygon);
-iter_geo_impl!(MultiPointArray<O>, MultiPointBuilder<O>, push_multi_point);
+iter_geo_impl!(
+    LineStringArray<O>,
+    |geom: geo::LineString, _| Some(geom.remove_repeated_points_with_options(options)),
+    LineStringBuilder
+);
+iter_geo_impl!(
+    PolygonArray<O>,
+    |geom: geo::Polygon, options: RemoveRepeatedPointsOptions| {
+        match remove_from_polygon(&geom, options.collapsed_rings) {
+            Cleaned::Geometry(polygon) => Some(polygon),
+            Cleaned::Dropped => Some(geo::Polygon::new(geo::LineString::new(vec![]), vec![])),
+            Cleaned::Null => None,
+        }
+    },
+    PolygonBuilder
+);
+iter_geo_impl!(
+    MultiPointArray<O>,
+    |geom: geo::MultiPoint, _| Some(geom.remove_repeated_points_with_options(options)),
+    MultiPointBuilder
+);
 iter_geo_impl!(
     MultiLineStringArray<O>,
-    MultiLineStringBuilder<O>,
-    push_multi_line_string
+    |geom: geo::MultiLineString, _| Some(geom.remove_repeated_points_with_options(options)),
+    MultiLineStringBuilder
 );
 iter_geo_impl!(
     MultiPolygonArray<O>,
-    MultiPolygonBuilder<O>,
-    push_multi_polygon
+    |geom: geo::MultiPolygon, options: RemoveRepeatedPointsOptions| {
+        let mut polygons = Vec::with_capacity(geom.0.len());
+        for polygon in geom.iter() {
+            match remove_from_polygon(polygon, options.collapsed_rings) {
+                Cleaned::Geometry(polygon) => polygons.push(polygon),
+                Cleaned::Dropped => {}
+                Cleaned::Null => return None,
+            }
+        }
+        Some(geo::MultiPolygon::new(polygons))
+    },
+    MultiPolygonBuilder
 );
 // iter_geo_impl!(MixedGeometryArray<O>, MixedGeometryBuilder<O>, push_geometry);
 // iter_geo_impl!(GeometryCollectionArray<O>, geo::GeometryCollection);
 
+/// A polygon with repeated points removed.
+enum Cleaned {
+    Geometry(geo::Polygon),
+    /// The exterior ring collapsed, and the polygon should be removed.
+    Dropped,
+    /// A ring collapsed, and the geometry should be null.
+    Null,
+}
+
+fn remove_from_polygon(polygon: &geo::Polygon, collapsed_rings: CollapsedRings) -> Cleaned {
+    // Empty rings have nothing to collapse
+    let is_collapsed = |ring: &geo::LineString| !ring.0.is_empty() && ring.0.len() < 4;
+
+    let (exterior, interiors) = polygon.remove_repeated_points().into_inner();
+    if collapsed_rings == CollapsedRings::Null
+        && (is_collapsed(&exterior) || interiors.iter().any(is_collapsed))
+    {
+        return Cleaned::Null;
+    }
+    if is_collapsed(&exterior) {
+        return Cleaned::Dropped;
+    }
+
+    let interiors = interiors
+        .into_iter()
+        .filter(|ring| !is_collapsed(ring))
+        .collect();
+    Cleaned::Geometry(geo::Polygon::new(exterior, interiors))
+}
+
 impl RemoveRepeatedPoints for &dyn GeometryArrayTrait {
     type Output = Result<Arc<dyn GeometryArrayTrait>>;
 
-    fn remove_repeated_points(&self) -> Self::Output {
+    fn remove_repeated_points_with_options(
+        &self,
+        options: RemoveRepeatedPointsOptions,
+    ) -> Self::Output {
         let result: Arc<dyn GeometryArrayTrait> = match self.data_type() {
-            GeoDataType::Point(_) => Arc::new(self.as_point().remove_repeated_points()),
-            GeoDataType::LineString(_) => Arc::new(self.as_line_string().remove_repeated_points()),
-            GeoDataType::LargeLineString(_) => {
-                Arc::new(self.as_large_line_string().remove_repeated_points())
-            }
-            GeoDataType::Polygon(_) => Arc::new(self.as_polygon().remove_repeated_points()),
-            GeoDataType::LargePolygon(_) => {
-                Arc::new(self.as_large_polygon().remove_repeated_points())
-            }
-            GeoDataType::MultiPoint(_) => Arc::new(self.as_multi_point().remove_repeated_points()),
-            GeoDataType::LargeMultiPoint(_) => {
-                Arc::new(self.as_large_multi_point().remove_repeated_points())
-            }
-            GeoDataType::MultiLineString(_) => {
-                Arc::new(self.as_multi_line_string().remove_repeated_points())
-            }
-            GeoDataType::LargeMultiLineString(_) => {
-                Arc::new(self.as_large_multi_line_string().remove_repeated_points())
-            }
-            GeoDataType::MultiPolygon(_) => {
-                Arc::new(self.as_multi_polygon().remove_repeated_points())
-            }
-            GeoDataType::LargeMultiPolygon(_) => {
-                Arc::new(self.as_large_multi_polygon().remove_repeated_points())
+            GeoDataType::Point(_) => {
+                Arc::new(self.as_point().remove_repeated_points_with_options(options))
             }
+            GeoDataType::LineString(_) => Arc::new(
+                self.as_line_string()
+                    .remove_repeated_points_with_options(options),
+            ),
+            GeoDataType::LargeLineString(_) => Arc::new(
+                self.as_large_line_string()
+                    .remove_repeated_points_with_options(options),
+            ),
+            GeoDataType::Polygon(_) => Arc::new(
+                self.as_polygon()
+                    .remove_repeated_points_with_options(options),
+            ),
+            GeoDataType::LargePolygon(_) => Arc::new(
+                self.as_large_polygon()
+                    .remove_repeated_points_with_options(options),
+            ),
+            GeoDataType::MultiPoint(_) => Arc::new(
+                self.as_multi_point()
+                    .remove_repeated_points_with_options(options),
+            ),
+            GeoDataType::LargeMultiPoint(_) => Arc::new(
+                self.as_large_multi_point()
+                    .remove_repeated_points_with_options(options),
+            ),
+            GeoDataType::MultiLineString(_) => Arc::new(
+                self.as_multi_line_string()
+                    .remove_repeated_points_with_options(options),
+            ),
+            GeoDataType::LargeMultiLineString(_) => Arc::new(
+                self.as_large_multi_line_string()
+                    .remove_repeated_points_with_options(options),
+            ),
+            GeoDataType::MultiPolygon(_) => Arc::new(
+                self.as_multi_polygon()
+                    .remove_repeated_points_with_options(options),
+            ),
+            GeoDataType::LargeMultiPolygon(_) => Arc::new(
+                self.as_large_multi_polygon()
+                    .remove_repeated_points_with_options(options),
+            ),
             // GeoDataType::Mixed(_) => self.as_mixed().remove_repeated_points(),
             // GeoDataType::LargeMixed(_) => self.as_large_mixed().remove_repeated_points(),
             // GeoDataType::GeometryCollection(_) => self.as_geometry_collection().remove_repeated_points(),
@@ -117,7 +246,10 @@ impl RemoveRepeatedPoints for &dyn GeometryArrayTrait {
 impl RemoveRepeatedPoints for ChunkedPointArray {
     type Output = Self;
 
-    fn remove_repeated_points(&self) -> Self::Output {
+    fn remove_repeated_points_with_options(
+        &self,
+        _options: RemoveRepeatedPointsOptions,
+    ) -> Self::Output {
         self.clone()
     }
 }
@@ -127,8 +259,11 @@ macro_rules! impl_chunked {
         impl<O: OffsetSizeTrait> RemoveRepeatedPoints for $struct_name {
             type Output = $struct_name;
 
-            fn remove_repeated_points(&self) -> Self::Output {
-                self.map(|chunk| chunk.remove_repeated_points())
+            fn remove_repeated_points_with_options(
+                &self,
+                options: RemoveRepeatedPointsOptions,
+            ) -> Self::Output {
+                self.map(|chunk| chunk.remove_repeated_points_with_options(options))
                     .try_into()
                     .unwrap()
             }
@@ -145,33 +280,54 @@ impl_chunked!(ChunkedMultiPolygonArray<O>);
 impl RemoveRepeatedPoints for &dyn ChunkedGeometryArrayTrait {
     type Output = Result<Arc<dyn ChunkedGeometryArrayTrait>>;
 
-    fn remove_repeated_points(&self) -> Self::Output {
+    fn remove_repeated_points_with_options(
+        &self,
+        options: RemoveRepeatedPointsOptions,
+    ) -> Self::Output {
         let result: Arc<dyn ChunkedGeometryArrayTrait> = match self.data_type() {
-            GeoDataType::Point(_) => Arc::new(self.as_point().remove_repeated_points()),
-            GeoDataType::LineString(_) => Arc::new(self.as_line_string().remove_repeated_points()),
-            GeoDataType::LargeLineString(_) => {
-                Arc::new(self.as_large_line_string().remove_repeated_points())
-            }
-            GeoDataType::Polygon(_) => Arc::new(self.as_polygon().remove_repeated_points()),
-            GeoDataType::LargePolygon(_) => {
-                Arc::new(self.as_large_polygon().remove_repeated_points())
-            }
-            GeoDataType::MultiPoint(_) => Arc::new(self.as_multi_point().remove_repeated_points()),
-            GeoDataType::LargeMultiPoint(_) => {
-                Arc::new(self.as_large_multi_point().remove_repeated_points())
-            }
-            GeoDataType::MultiLineString(_) => {
-                Arc::new(self.as_multi_line_string().remove_repeated_points())
-            }
-            GeoDataType::LargeMultiLineString(_) => {
-                Arc::new(self.as_large_multi_line_string().remove_repeated_points())
-            }
-            GeoDataType::MultiPolygon(_) => {
-                Arc::new(self.as_multi_polygon().remove_repeated_points())
-            }
-            GeoDataType::LargeMultiPolygon(_) => {
-                Arc::new(self.as_large_multi_polygon().remove_repeated_points())
+            GeoDataType::Point(_) => {
+                Arc::new(self.as_point().remove_repeated_points_with_options(options))
             }
+            GeoDataType::LineString(_) => Arc::new(
+                self.as_line_string()
+                    .remove_repeated_points_with_options(options),
+            ),
+            GeoDataType::LargeLineString(_) => Arc::new(
+                self.as_large_line_string()
+                    .remove_repeated_points_with_options(options),
+            ),
+            GeoDataType::Polygon(_) => Arc::new(
+                self.as_polygon()
+                    .remove_repeated_points_with_options(options),
+            ),
+            GeoDataType::LargePolygon(_) => Arc::new(
+                self.as_large_polygon()
+                    .remove_repeated_points_with_options(options),
+            ),
+            GeoDataType::MultiPoint(_) => Arc::new(
+                self.as_multi_point()
+                    .remove_repeated_points_with_options(options),
+            ),
+            GeoDataType::LargeMultiPoint(_) => Arc::new(
+                self.as_large_multi_point()
+                    .remove_repeated_points_with_options(options),
+            ),
+            GeoDataType::MultiLineString(_) => Arc::new(
+                self.as_multi_line_string()
+                    .remove_repeated_points_with_options(options),
+            ),
+            GeoDataType::LargeMultiLineString(_) => Arc::new(
+                self.as_large_multi_line_string()
+                    .remove_repeated_points_with_options(options),
+            ),
+            GeoDataType::MultiPolygon(_) => Arc::new(
+                self.as_multi_polygon()
+                    .remove_repeated_points_with_options(options),
+            ),
+            GeoDataType::LargeMultiPolygon(_) => Arc::new(
+                self.as_large_multi_polygon()
+                    .remove_repeated_points_with_options(options),
+            ),
             // GeoDataType::Mixed(_) => self.as_mixed().remove_repeated_points(),
             // GeoDataType::LargeMixed(_) => self.as_large_mixed().remove_repeated_points(),
             // GeoDataType::GeometryCollection(_) => self.as_geometry_collection().remove_repeated_points(),
@@ -183,3 +339,119 @@ impl RemoveRepeatedPoints for &dyn ChunkedGeometryArrayTrait {
         Ok(result)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::metadata::ArrayMetadata;
+    use geo::{line_string, polygon};
+    use serde_json::json;
+
+    fn run_polygon() -> geo::Polygon {
+        polygon![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+            (x: 0., y: 1.),
+            (x: 0., y: 0.),
+        ]
+    }
+
+    /// A polygon with a hole that collapses to a line after removing repeated points.
+    fn collapsing_hole_polygon() -> geo::Polygon {
+        geo::Polygon::new(
+            line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+            vec![line_string![
+                (x: 1., y: 1.),
+                (x: 1., y: 1.),
+                (x: 2., y: 2.),
+                (x: 2., y: 2.),
+                (x: 1., y: 1.),
+            ]],
+        )
+    }
+
+    #[test]
+    fn run_of_identical_coordinates() {
+        let metadata = Arc::new(ArrayMetadata {
+            crs: Some(json!("EPSG:4326")),
+            edges: None,
+        });
+        let arr = PolygonBuilder::<i32>::from_nullable_polygons(
+            &[Some(run_polygon()), None],
+            Some(CoordType::Separated),
+            metadata.clone(),
+        )
+        .finish();
+        let result = arr.remove_repeated_points();
+
+        let expected = polygon![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+            (x: 0., y: 1.),
+            (x: 0., y: 0.),
+        ];
+        assert_eq!(result.value_as_geo(0), expected);
+        assert!(result.is_null(1));
+        assert_eq!(result.geom_offsets().to_vec(), vec![0, 1, 1]);
+        assert_eq!(result.ring_offsets().to_vec(), vec![0, 5]);
+        assert_eq!(result.coord_type(), CoordType::Separated);
+        assert_eq!(result.metadata(), metadata);
+    }
+
+    #[test]
+    fn collapsed_rings() {
+        let arr = PolygonBuilder::<i32>::from_nullable_polygons(
+            &[Some(collapsing_hole_polygon())],
+            None,
+            Default::default(),
+        )
+        .finish();
+
+        let dropped = arr.remove_repeated_points();
+        assert!(dropped.value_as_geo(0).interiors().is_empty());
+
+        let options =
+            RemoveRepeatedPointsOptions::default().with_collapsed_rings(CollapsedRings::Null);
+        let nulled = arr.remove_repeated_points_with_options(options);
+        assert!(nulled.is_null(0));
+    }
+
+    #[test]
+    fn collapsed_multi_polygon_parts() {
+        let collapsed = polygon![(x: 5., y: 5.), (x: 5., y: 5.), (x: 6., y: 6.), (x: 5., y: 5.)];
+        let multi = geo::MultiPolygon::new(vec![run_polygon(), collapsed]);
+        let arr = MultiPolygonBuilder::<i32>::from_nullable_multi_polygons(
+            &[Some(multi)],
+            None,
+            Default::default(),
+        )
+        .finish();
+
+        let dropped = arr.remove_repeated_points();
+        assert_eq!(dropped.value_as_geo(0).0.len(), 1);
+        assert_eq!(dropped.polygon_offsets().to_vec(), vec![0, 1]);
+        assert_eq!(dropped.ring_offsets().to_vec(), vec![0, 5]);
+
+        let options =
+            RemoveRepeatedPointsOptions::default().with_collapsed_rings(CollapsedRings::Null);
+        assert!(arr.remove_repeated_points_with_options(options).is_null(0));
+    }
+
+    #[test]
+    fn line_strings() {
+        let ls = line_string![(x: 0., y: 0.), (x: 0., y: 0.), (x: 1., y: 1.), (x: 1., y: 1.)];
+        let arr: LineStringArray<i32> = vec![ls].as_slice().into();
+        let result = arr.remove_repeated_points();
+        assert_eq!(result.geom_offsets().to_vec(), vec![0, 2]);
+        assert_eq!(
+            result.value_as_geo(0),
+            line_string![(x: 0., y: 0.), (x: 1., y: 1.)]
+        );
+    }
+}
@@ -1,7 +1,12 @@
-use crate::algorithm::geo::{AffineOps, Center, Centroid};
-use crate::array::MultiPointArray;
+use crate::algorithm::broadcasting::BroadcastablePrimitive;
+use crate::algorithm::geo::affine_ops::AffineTransformable;
+use crate::algorithm::geo::utils::{broadcast_affine_transform, TransformOrigin};
+use crate::algorithm::geo::{Center, Centroid};
 use crate::array::*;
-use crate::trait_::GeometryArrayAccessor;
+use crate::chunked_array::ChunkedGeometryArray;
+use crate::error::Result;
+use crate::GeometryArrayTrait;
+use arrow_array::types::Float64Type;
 use arrow_array::{Float64Array, OffsetSizeTrait};
 use geo::AffineTransform;
 
@@ -16,7 +21,17 @@ use geo::AffineTransform;
 /// [`Translate`](crate::algorithm::geo::Translate), or [`Rotate`](crate::algorithm::geo::Rotate),
 /// it is more efficient to compose the transformations and apply them as a single operation using
 /// the [`AffineOps`](crate::algorithm::geo::AffineOps) trait.
+///
+/// ## Broadcasting
+///
+/// The angle is either a scalar applied to every geometry or an array with one value per
+/// geometry. Geometries with a null angle become null. Geometries without a centroid or center,
+/// such as empty geometries, are left unchanged by
+/// [`rotate_around_centroid`](Self::rotate_around_centroid) and
+/// [`rotate_around_center`](Self::rotate_around_center).
 pub trait Rotate<DegreesT> {
+    type Output;
+
     /// Rotate a geometry around its [centroid](Centroid) by an angle, in degrees
     ///
     /// Positive angles are counter-clockwise, and negative angles are clockwise rotations.
@@ -45,7 +60,7 @@ pub trait Rotate<DegreesT> {
     /// assert_relative_eq!(expected, rotated);
     /// ```
     #[must_use]
-    fn rotate_around_centroid(&self, degrees: &DegreesT) -> Self;
+    fn rotate_around_centroid(&self, degrees: &DegreesT) -> Self::Output;
 
     // /// Mutable version of [`Self::rotate_around_centroid`]
     // fn rotate_around_centroid_mut(&mut self, degrees: f64);
@@ -56,7 +71,7 @@ pub trait Rotate<DegreesT> {
     /// Positive angles are counter-clockwise, and negative angles are clockwise rotations.
     ///
     #[must_use]
-    fn rotate_around_center(&self, degrees: &DegreesT) -> Self;
+    fn rotate_around_center(&self, degrees: &DegreesT) -> Self::Output;
 
     // /// Mutable version of [`Self::rotate_around_center`]
     // fn rotate_around_center_mut(&mut self, degrees: f64);
@@ -89,152 +104,220 @@ pub trait Rotate<DegreesT> {
     /// ]);
     /// ```
     #[must_use]
-    fn rotate_around_point(&self, degrees: &DegreesT, point: geo::Point) -> Self;
+    fn rotate_around_point(&self, degrees: &DegreesT, point: geo::Point) -> Self::Output;
 
     // /// Mutable version of [`Self::rotate_around_point`]
     // fn rotate_around_point_mut(&mut self, degrees: f64, point: Point<f64>);
 }
 
-// ┌────────────────────────────────┐
-// │ Implementations for RHS arrays │
-// └────────────────────────────────┘
+fn rotate_array<G>(
+    array: &G,
+    degrees: &BroadcastablePrimitive<Float64Type>,
+    origin: TransformOrigin,
+) -> G
+where
+    G: AffineTransformable,
+{
+    broadcast_affine_transform(array, [degrees], origin, |[degrees], origin| {
+        AffineTransform::rotate(degrees, origin)
+    })
+}
 
 // Note: this can't (easily) be parameterized in the macro because PointArray is not generic over O
-impl Rotate<Float64Array> for PointArray {
-    fn rotate_around_centroid(&self, degrees: &Float64Array) -> Self {
-        let centroids = self.centroid();
-        let transforms: Vec<AffineTransform> = centroids
-            .iter_geo_values()
-            .zip(degrees.values().iter())
-            .map(|(point, angle)| AffineTransform::rotate(*angle, point))
-            .collect();
-        self.affine_transform(transforms.as_slice())
+impl Rotate<BroadcastablePrimitive<Float64Type>> for PointArray {
+    type Output = Self;
+
+    fn rotate_around_centroid(&self, degrees: &BroadcastablePrimitive<Float64Type>) -> Self {
+        rotate_array(self, degrees, TransformOrigin::PerGeometry(self.centroid()))
     }
 
-    fn rotate_around_center(&self, degrees: &Float64Array) -> Self {
-        let centers = self.center();
-        let transforms: Vec<AffineTransform> = centers
-            .iter_geo_values()
-            .zip(degrees.values().iter())
-            .map(|(point, angle)| AffineTransform::rotate(*angle, point))
-            .collect();
-        self.affine_transform(transforms.as_slice())
+    fn rotate_around_center(&self, degrees: &BroadcastablePrimitive<Float64Type>) -> Self {
+        rotate_array(self, degrees, TransformOrigin::PerGeometry(self.center()))
     }
 
-    fn rotate_around_point(&self, degrees: &Float64Array, point: geo::Point) -> Self {
-        let transforms: Vec<AffineTransform> = degrees
-            .values()
-            .iter()
-            .map(|degrees| AffineTransform::rotate(*degrees, point))
-            .collect();
-        self.affine_transform(transforms.as_slice())
+    fn rotate_around_point(
+        &self,
+        degrees: &BroadcastablePrimitive<Float64Type>,
+        point: geo::Point,
+    ) -> Self {
+        rotate_array(self, degrees, TransformOrigin::Point(point))
     }
 }
 
-/// Implementation that iterates over geo objects
-macro_rules! iter_geo_impl {
+macro_rules! impl_rotate {
     ($type:ty) => {
-        impl<O: OffsetSizeTrait> Rotate<Float64Array> for $type {
-            fn rotate_around_centroid(&self, degrees: &Float64Array) -> $type {
-                let centroids = self.centroid();
-                let transforms: Vec<AffineTransform> = centroids
-                    .iter_geo_values()
-                    .zip(degrees.values().iter())
-                    .map(|(point, angle)| AffineTransform::rotate(*angle, point))
-                    .collect();
-                self.affine_transform(transforms.as_slice())
+        impl<O: OffsetSizeTrait> Rotate<BroadcastablePrimitive<Float64Type>> for $type {
+            type Output = Self;
+
+            fn rotate_around_centroid(
+                &self,
+                degrees: &BroadcastablePrimitive<Float64Type>,
+            ) -> Self {
+                rotate_array(self, degrees, TransformOrigin::PerGeometry(self.centroid()))
             }
 
-            fn rotate_around_center(&self, degrees: &Float64Array) -> Self {
-                let centers = self.center();
-                let transforms: Vec<AffineTransform> = centers
-                    .iter_geo_values()
-                    .zip(degrees.values().iter())
-                    .map(|(point, angle)| AffineTransform::rotate(*angle, point))
-                    .collect();
-                self.affine_transform(transforms.as_slice())
+            fn rotate_around_center(&self, degrees: &BroadcastablePrimitive<Float64Type>) -> Self {
+                rotate_array(self, degrees, TransformOrigin::PerGeometry(self.center()))
             }
 
-            fn rotate_around_point(&self, degrees: &Float64Array, point: geo::Point) -> Self {
-                let transforms: Vec<AffineTransform> = degrees
-                    .values()
-                    .iter()
-                    .map(|degrees| AffineTransform::rotate(*degrees, point))
-                    .collect();
-                self.affine_transform(transforms.as_slice())
+            fn rotate_around_point(
+                &self,
+                degrees: &BroadcastablePrimitive<Float64Type>,
+                point: geo::Point,
+            ) -> Self {
+                rotate_array(self, degrees, TransformOrigin::Point(point))
             }
         }
     };
 }
 
-iter_geo_impl!(LineStringArray<O>);
-iter_geo_impl!(PolygonArray<O>);
-iter_geo_impl!(MultiPointArray<O>);
-iter_geo_impl!(MultiLineStringArray<O>);
-iter_geo_impl!(MultiPolygonArray<O>);
+impl_rotate!(LineStringArray<O>);
+impl_rotate!(PolygonArray<O>);
+impl_rotate!(MultiPointArray<O>);
+impl_rotate!(MultiLineStringArray<O>);
+impl_rotate!(MultiPolygonArray<O>);
+impl_rotate!(MixedGeometryArray<O>);
+impl_rotate!(GeometryCollectionArray<O>);
 
-// ┌─────────────────────────────────┐
-// │ Implementations for RHS scalars │
-// └─────────────────────────────────┘
+/// Implementations for an array or scalar of angles, which delegate to the
+/// [`BroadcastablePrimitive`] implementation.
+macro_rules! impl_rotate_delegate {
+    ($degrees_type:ty, $to_broadcast:expr) => {
+        impl<G> Rotate<$degrees_type> for G
+        where
+            G: Rotate<BroadcastablePrimitive<Float64Type>, Output = G> + GeometryArrayTrait,
+        {
+            type Output = G;
 
-// Note: this can't (easily) be parameterized in the macro because PointArray is not generic over O
-impl Rotate<f64> for PointArray {
-    fn rotate_around_centroid(&self, degrees: &f64) -> Self {
-        let centroids = self.centroid();
-        let transforms: Vec<AffineTransform> = centroids
-            .iter_geo_values()
-            .map(|point| AffineTransform::rotate(*degrees, point))
+            fn rotate_around_centroid(&self, degrees: &$degrees_type) -> G {
+                self.rotate_around_centroid(&$to_broadcast(degrees))
+            }
+
+            fn rotate_around_center(&self, degrees: &$degrees_type) -> G {
+                self.rotate_around_center(&$to_broadcast(degrees))
+            }
+
+            fn rotate_around_point(&self, degrees: &$degrees_type, point: geo::Point) -> G {
+                self.rotate_around_point(&$to_broadcast(degrees), point)
+            }
+        }
+    };
+}
+
+impl_rotate_delegate!(Float64Array, |degrees: &Float64Array| {
+    BroadcastablePrimitive::<Float64Type>::Array(degrees.clone())
+});
+impl_rotate_delegate!(f64, |degrees: &f64| {
+    BroadcastablePrimitive::<Float64Type>::Scalar(*degrees)
+});
+
+/// Angle arrays have one value per geometry of the chunked array, and are split at the chunk
+/// boundaries.
+impl<G> Rotate<BroadcastablePrimitive<Float64Type>> for ChunkedGeometryArray<G>
+where
+    G: GeometryArrayTrait + Rotate<BroadcastablePrimitive<Float64Type>, Output = G>,
+{
+    type Output = Result<Self>;
+
+    fn rotate_around_centroid(
+        &self,
+        degrees: &BroadcastablePrimitive<Float64Type>,
+    ) -> Self::Output {
+        let chunks = self
+            .chunks
+            .iter()
+            .zip(degrees.split_chunks(&self.chunk_lengths())?)
+            .map(|(chunk, degrees)| chunk.rotate_around_centroid(&degrees))
             .collect();
-        self.affine_transform(transforms.as_slice())
+        Ok(ChunkedGeometryArray::new_unchecked(chunks))
     }
 
-    fn rotate_around_center(&self, degrees: &f64) -> Self {
-        let centers = self.center();
-        let transforms: Vec<AffineTransform> = centers
-            .iter_geo_values()
-            .map(|point| AffineTransform::rotate(*degrees, point))
+    fn rotate_around_center(&self, degrees: &BroadcastablePrimitive<Float64Type>) -> Self::Output {
+        let chunks = self
+            .chunks
+            .iter()
+            .zip(degrees.split_chunks(&self.chunk_lengths())?)
+            .map(|(chunk, degrees)| chunk.rotate_around_center(&degrees))
             .collect();
-        self.affine_transform(transforms.as_slice())
+        Ok(ChunkedGeometryArray::new_unchecked(chunks))
     }
 
-    fn rotate_around_point(&self, degrees: &f64, point: geo::Point) -> Self {
-        let transform = AffineTransform::rotate(*degrees, point);
-        self.affine_transform(&transform)
+    fn rotate_around_point(
+        &self,
+        degrees: &BroadcastablePrimitive<Float64Type>,
+        point: geo::Point,
+    ) -> Self::Output {
+        let chunks = self
+            .chunks
+            .iter()
+            .zip(degrees.split_chunks(&self.chunk_lengths())?)
+            .map(|(chunk, degrees)| chunk.rotate_around_point(&degrees, point))
+            .collect();
+        Ok(ChunkedGeometryArray::new_unchecked(chunks))
     }
 }
 
-/// Implementation that iterates over geo objects
-macro_rules! iter_geo_impl_scalar {
-    ($type:ty) => {
-        impl<O: OffsetSizeTrait> Rotate<f64> for $type {
-            fn rotate_around_centroid(&self, degrees: &f64) -> $type {
-                let centroids = self.centroid();
-                let transforms: Vec<AffineTransform> = centroids
-                    .iter_geo_values()
-                    .map(|point| AffineTransform::rotate(*degrees, point))
-                    .collect();
-                self.affine_transform(transforms.as_slice())
-            }
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::chunked_array::ChunkedPolygonArray;
+    use crate::test::polygon;
+    use crate::trait_::GeometryArrayAccessor;
+    use approx::assert_relative_eq;
+    use geo::Rotate as _Rotate;
 
-            fn rotate_around_center(&self, degrees: &f64) -> Self {
-                let centers = self.center();
-                let transforms: Vec<AffineTransform> = centers
-                    .iter_geo_values()
-                    .map(|point| AffineTransform::rotate(*degrees, point))
-                    .collect();
-                self.affine_transform(transforms.as_slice())
-            }
+    #[test]
+    fn rotate_each_geometry_around_its_centroid() {
+        let polygons = [polygon::p0(), polygon::p1()];
+        let arr: PolygonArray<i32> = vec![
+            Some(polygons[0].clone()),
+            Some(polygons[1].clone()),
+            None,
+            Some(polygons[0].clone()),
+        ]
+        .into();
+        let degrees = BroadcastablePrimitive::Array(Float64Array::from(vec![
+            Some(90.),
+            Some(-30.),
+            Some(10.),
+            None,
+        ]));
 
-            fn rotate_around_point(&self, degrees: &f64, point: geo::Point) -> Self {
-                let transform = AffineTransform::rotate(*degrees, point);
-                self.affine_transform(&transform)
-            }
-        }
-    };
-}
+        let result = arr.rotate_around_centroid(&degrees);
+        assert_relative_eq!(
+            result.value_as_geo(0),
+            polygons[0].rotate_around_centroid(90.)
+        );
+        assert_relative_eq!(
+            result.value_as_geo(1),
+            polygons[1].rotate_around_centroid(-30.)
+        );
+        assert!(result.is_null(2));
+        assert!(result.is_null(3));
 
-iter_geo_impl_scalar!(LineStringArray<O>);
-iter_geo_impl_scalar!(PolygonArray<O>);
-iter_geo_impl_scalar!(MultiPointArray<O>);
-iter_geo_impl_scalar!(MultiLineStringArray<O>);
-iter_geo_impl_scalar!(MultiPolygonArray<O>);
+        // A scalar angle still rotates each geometry around its own centroid
+        let result = arr.rotate_around_centroid(&45.);
+        assert_relative_eq!(
+            result.value_as_geo(1),
+            polygons[1].rotate_around_centroid(45.)
+        );
+    }
+
+    #[test]
+    fn rotate_chunked() {
+        let chunked = ChunkedPolygonArray::<i32>::new_unchecked(vec![
+            vec![polygon::p0()].as_slice().into(),
+            vec![polygon::p1(), polygon::p0()].as_slice().into(),
+        ]);
+        let degrees = BroadcastablePrimitive::Array(Float64Array::from(vec![10., 20., 30.]));
+        let result = chunked.rotate_around_center(&degrees).unwrap();
+        assert_eq!(result.chunks()[1].len(), 2);
+        assert_relative_eq!(
+            result.chunks()[1].value_as_geo(1),
+            polygon::p0().rotate_around_center(30.)
+        );
+
+        let degrees = BroadcastablePrimitive::Array(Float64Array::from(vec![10., 20.]));
+        assert!(chunked.rotate_around_center(&degrees).is_err());
+    }
+}
@@ -1,10 +1,14 @@
 use crate::algorithm::broadcasting::BroadcastablePrimitive;
-use crate::array::LineStringArray;
+use crate::algorithm::geo::affine_ops::AffineTransformable;
+use crate::algorithm::geo::utils::{broadcast_affine_transform, TransformOrigin};
+use crate::algorithm::geo::Center;
 use crate::array::*;
-use crate::trait_::GeometryArrayAccessor;
+use crate::chunked_array::ChunkedGeometryArray;
+use crate::error::Result;
+use crate::GeometryArrayTrait;
 use arrow_array::types::Float64Type;
 use arrow_array::OffsetSizeTrait;
-use geo::Scale as _Scale;
+use geo::AffineTransform;
 
 /// An affine transformation which scales geometries up or down by a factor.
 ///
@@ -15,7 +19,14 @@ use geo::Scale as _Scale;
 /// [`Translate`](crate::algorithm::geo::Translate), or [`Rotate`](crate::algorithm::geo::Rotate),
 /// it is more efficient to compose the transformations and apply them as a single operation using
 /// the [`AffineOps`](crate::algorithm::geo::AffineOps) trait.
+///
+/// ## Broadcasting
+///
+/// Each factor is either a scalar applied to every geometry or an array with one value per
+/// geometry. Geometries with a null factor become null.
 pub trait Scale {
+    type Output;
+
     /// Scale geometries from its bounding box center.
     ///
     /// # Examples
@@ -34,7 +45,7 @@ pub trait Scale {
     /// ]);
     /// ```
     #[must_use]
-    fn scale(&self, scale_factor: BroadcastablePrimitive<Float64Type>) -> Self;
+    fn scale(&self, scale_factor: BroadcastablePrimitive<Float64Type>) -> Self::Output;
 
     // /// Mutable version of [`scale`](Self::scale)
     // fn scale_mut(&mut self, scale_factor: BroadcastablePrimitive<Float64Type>);
@@ -62,7 +73,7 @@ pub trait Scale {
         &self,
         x_factor: BroadcastablePrimitive<Float64Type>,
         y_factor: BroadcastablePrimitive<Float64Type>,
-    ) -> Self;
+    ) -> Self::Output;
 
     // /// Mutable version of [`scale_xy`](Self::scale_xy).
     // fn scale_xy_mut(&mut self, x_factor: BroadcastablePrimitive<Float64Type>, y_factor: BroadcastablePrimitive<Float64Type>);
@@ -94,48 +105,41 @@ pub trait Scale {
         x_factor: BroadcastablePrimitive<Float64Type>,
         y_factor: BroadcastablePrimitive<Float64Type>,
         origin: geo::Point,
-    ) -> Self;
+    ) -> Self::Output;
 
     // /// Mutable version of [`scale_around_point`](Self::scale_around_point).
     // fn scale_around_point_mut(&mut self, x_factor: BroadcastablePrimitive<Float64Type>, y_factor: BroadcastablePrimitive<Float64Type>, origin: geo::Point);
 }
 
+fn scale_array<G: AffineTransformable + Center<Output = PointArray>>(
+    array: &G,
+    x_factor: &BroadcastablePrimitive<Float64Type>,
+    y_factor: &BroadcastablePrimitive<Float64Type>,
+    origin: Option<geo::Point>,
+) -> G {
+    let origin = match origin {
+        Some(origin) => TransformOrigin::Point(origin),
+        None => TransformOrigin::PerGeometry(array.center()),
+    };
+    broadcast_affine_transform(array, [x_factor, y_factor], origin, |[x, y], origin| {
+        AffineTransform::scale(x, y, origin)
+    })
+}
+
 // Note: this can't (easily) be parameterized in the macro because PointArray is not generic over O
 impl Scale for PointArray {
-    fn scale(&self, scale_factor: BroadcastablePrimitive<Float64Type>) -> Self {
-        let mut output_array = PointBuilder::with_capacity(self.buffer_lengths());
-
-        self.iter_geo()
-            .zip(&scale_factor)
-            .for_each(|(maybe_g, scale_factor)| {
-                output_array.push_point(
-                    maybe_g
-                        .map(|geom| geom.scale(scale_factor.unwrap()))
-                        .as_ref(),
-                )
-            });
-
-        output_array.finish()
+    type Output = Self;
+
+    fn scale(&self, scale_factor: BroadcastablePrimitive<Float64Type>) -> Self::Output {
+        scale_array(self, &scale_factor, &scale_factor, None)
     }
 
     fn scale_xy(
         &self,
         x_factor: BroadcastablePrimitive<Float64Type>,
         y_factor: BroadcastablePrimitive<Float64Type>,
-    ) -> Self {
-        let mut output_array = PointBuilder::with_capacity(self.buffer_lengths());
-
-        self.iter_geo().zip(&x_factor).zip(&y_factor).for_each(
-            |((maybe_g, x_factor), y_factor)| {
-                output_array.push_point(
-                    maybe_g
-                        .map(|geom| geom.scale_xy(x_factor.unwrap(), y_factor.unwrap()))
-                        .as_ref(),
-                )
-            },
-        );
-
-        output_array.finish()
+    ) -> Self::Output {
+        scale_array(self, &x_factor, &y_factor, None)
     }
 
     fn scale_around_point(
@@ -143,67 +147,26 @@ impl Scale for PointArray {
         x_factor: BroadcastablePrimitive<Float64Type>,
         y_factor: BroadcastablePrimitive<Float64Type>,
         origin: geo::Point,
-    ) -> Self {
-        let mut output_array = PointBuilder::with_capacity(self.buffer_lengths());
-
-        self.iter_geo().zip(&x_factor).zip(&y_factor).for_each(
-            |((maybe_g, x_factor), y_factor)| {
-                output_array.push_point(
-                    maybe_g
-                        .map(|geom| {
-                            geom.scale_around_point(x_factor.unwrap(), y_factor.unwrap(), origin)
-                        })
-                        .as_ref(),
-                )
-            },
-        );
-
-        output_array.finish()
+    ) -> Self::Output {
+        scale_array(self, &x_factor, &y_factor, Some(origin))
     }
 }
 
-/// Implementation that iterates over geo objects
-macro_rules! iter_geo_impl {
-    ($type:ty, $builder_type:ty, $push_func:ident) => {
+macro_rules! impl_scale {
+    ($type:ty) => {
         impl<O: OffsetSizeTrait> Scale for $type {
-            fn scale(&self, scale_factor: BroadcastablePrimitive<Float64Type>) -> Self {
-                let mut output_array = <$builder_type>::with_capacity(self.buffer_lengths());
-
-                self.iter_geo()
-                    .zip(&scale_factor)
-                    .for_each(|(maybe_g, scale_factor)| {
-                        output_array
-                            .$push_func(
-                                maybe_g
-                                    .map(|geom| geom.scale(scale_factor.unwrap()))
-                                    .as_ref(),
-                            )
-                            .unwrap();
-                    });
-
-                output_array.finish()
+            type Output = Self;
+
+            fn scale(&self, scale_factor: BroadcastablePrimitive<Float64Type>) -> Self::Output {
+                scale_array(self, &scale_factor, &scale_factor, None)
             }
 
             fn scale_xy(
                 &self,
                 x_factor: BroadcastablePrimitive<Float64Type>,
                 y_factor: BroadcastablePrimitive<Float64Type>,
-            ) -> Self {
-                let mut output_array = <$builder_type>::with_capacity(self.buffer_lengths());
-
-                self.iter_geo().zip(&x_factor).zip(&y_factor).for_each(
-                    |((maybe_g, x_factor), y_factor)| {
-                        output_array
-                            .$push_func(
-                                maybe_g
-                                    .map(|geom| geom.scale_xy(x_factor.unwrap(), y_factor.unwrap()))
-                                    .as_ref(),
-                            )
-                            .unwrap()
-                    },
-                );
-
-                output_array.finish()
+            ) -> Self::Output {
+                scale_array(self, &x_factor, &y_factor, None)
             }
 
             fn scale_around_point(
@@ -211,43 +174,101 @@ macro_rules! iter_geo_impl {
                 x_factor: BroadcastablePrimitive<Float64Type>,
                 y_factor: BroadcastablePrimitive<Float64Type>,
                 origin: geo::Point,
-            ) -> Self {
-                let mut output_array = <$builder_type>::with_capacity(self.buffer_lengths());
-
-                self.iter_geo().zip(&x_factor).zip(&y_factor).for_each(
-                    |((maybe_g, x_factor), y_factor)| {
-                        output_array
-                            .$push_func(
-                                maybe_g
-                                    .map(|geom| {
-                                        geom.scale_around_point(
-                                            x_factor.unwrap(),
-                                            y_factor.unwrap(),
-                                            origin,
-                                        )
-                                    })
-                                    .as_ref(),
-                            )
-                            .unwrap()
-                    },
-                );
-
-                output_array.finish()
+            ) -> Self::Output {
+                scale_array(self, &x_factor, &y_factor, Some(origin))
             }
         }
     };
 }
 
-iter_geo_impl!(LineStringArray<O>, LineStringBuilder<O>, push_line_string);
-iter_geo_impl!(PolygonArray<O>, PolygonBuilder<O>, push_polygon);
-iter_geo_impl!(MultiPointArray<O>, MultiPointBuilder<O>, push_multi_point);
-iter_geo_impl!(
-    MultiLineStringArray<O>,
-    MultiLineStringBuilder<O>,
-    push_multi_line_string
-);
-iter_geo_impl!(
-    MultiPolygonArray<O>,
-    MultiPolygonBuilder<O>,
-    push_multi_polygon
-);
+impl_scale!(LineStringArray<O>);
+impl_scale!(PolygonArray<O>);
+impl_scale!(MultiPointArray<O>);
+impl_scale!(MultiLineStringArray<O>);
+impl_scale!(MultiPolygonArray<O>);
+impl_scale!(MixedGeometryArray<O>);
+impl_scale!(GeometryCollectionArray<O>);
+
+/// Factor arrays have one value per geometry of the chunked array, and are split at the chunk
+/// boundaries.
+impl<G: GeometryArrayTrait + Scale<Output = G>> Scale for ChunkedGeometryArray<G> {
+    type Output = Result<Self>;
+
+    fn scale(&self, scale_factor: BroadcastablePrimitive<Float64Type>) -> Self::Output {
+        let chunks = self
+            .chunks
+            .iter()
+            .zip(scale_factor.split_chunks(&self.chunk_lengths())?)
+            .map(|(chunk, scale_factor)| chunk.scale(scale_factor))
+            .collect();
+        Ok(ChunkedGeometryArray::new_unchecked(chunks))
+    }
+
+    fn scale_xy(
+        &self,
+        x_factor: BroadcastablePrimitive<Float64Type>,
+        y_factor: BroadcastablePrimitive<Float64Type>,
+    ) -> Self::Output {
+        let chunk_lengths = self.chunk_lengths();
+        let chunks = self
+            .chunks
+            .iter()
+            .zip(x_factor.split_chunks(&chunk_lengths)?)
+            .zip(y_factor.split_chunks(&chunk_lengths)?)
+            .map(|((chunk, x_factor), y_factor)| chunk.scale_xy(x_factor, y_factor))
+            .collect();
+        Ok(ChunkedGeometryArray::new_unchecked(chunks))
+    }
+
+    fn scale_around_point(
+        &self,
+        x_factor: BroadcastablePrimitive<Float64Type>,
+        y_factor: BroadcastablePrimitive<Float64Type>,
+        origin: geo::Point,
+    ) -> Self::Output {
+        let chunk_lengths = self.chunk_lengths();
+        let chunks = self
+            .chunks
+            .iter()
+            .zip(x_factor.split_chunks(&chunk_lengths)?)
+            .zip(y_factor.split_chunks(&chunk_lengths)?)
+            .map(|((chunk, x_factor), y_factor)| {
+                chunk.scale_around_point(x_factor, y_factor, origin)
+            })
+            .collect();
+        Ok(ChunkedGeometryArray::new_unchecked(chunks))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::trait_::GeometryArrayAccessor;
+    use arrow_array::Float64Array;
+    use geo::Scale as _Scale;
+
+    #[test]
+    fn scale_per_geometry_center() {
+        let line_strings = vec![
+            geo::line_string![(x: 0., y: 0.), (x: 10., y: 10.)],
+            geo::line_string![(x: 5., y: 0.), (x: 5., y: 4.)],
+        ];
+        let arr: LineStringArray<i32> = vec![
+            Some(line_strings[0].clone()),
+            Some(line_strings[1].clone()),
+            None,
+        ]
+        .into();
+        let factors =
+            BroadcastablePrimitive::Array(Float64Array::from(vec![Some(2.), Some(0.5), Some(3.)]));
+        let result = arr.scale(factors);
+        assert_eq!(result.value_as_geo(0), line_strings[0].scale(2.));
+        assert_eq!(result.value_as_geo(1), line_strings[1].scale(0.5));
+        assert!(result.is_null(2));
+
+        let factors = BroadcastablePrimitive::Array(Float64Array::from(vec![None, Some(1.), None]));
+        let result = arr.scale_xy(factors, 1.0.into());
+        assert!(result.is_null(0));
+        assert_eq!(result.value_as_geo(1), line_strings[1]);
+    }
+}
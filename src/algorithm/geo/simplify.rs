@@ -1,11 +1,14 @@
 use std::sync::Arc;
 
+use crate::algorithm::broadcasting::BroadcastablePrimitive;
+use crate::algorithm::geo::utils::broadcast_values;
 use crate::array::*;
 use crate::chunked_array::{ChunkedGeometryArray, ChunkedGeometryArrayTrait};
 use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
 use crate::trait_::GeometryArrayAccessor;
 use crate::GeometryArrayTrait;
+use arrow_array::types::Float64Type;
 use arrow_array::OffsetSizeTrait;
 use geo::Simplify as _Simplify;
 
@@ -19,6 +22,11 @@ use geo::Simplify as _Simplify;
 /// Multi* objects are simplified by simplifying all their constituent geometries individually.
 ///
 /// An epsilon less than or equal to zero will return an unaltered version of the geometry.
+///
+/// ## Broadcasting
+///
+/// `epsilon` is either a scalar applied to every geometry or an array with one value per geometry.
+/// Geometries with a null epsilon become null.
 pub trait Simplify {
     type Output;
 
@@ -41,7 +49,7 @@ pub trait Simplify {
     /// ];
     /// let line_string_array: LineStringArray<i32> = vec![line_string].as_slice().into();
     ///
-    /// let simplified_array = line_string_array.simplify(&1.0);
+    /// let simplified_array = line_string_array.simplify(&1.0.into());
     ///
     /// let expected = line_string![
     ///     (x: 0.0, y: 0.0),
@@ -52,14 +60,14 @@ pub trait Simplify {
     ///
     /// assert_eq!(expected, simplified_array.value_as_geo(0))
     /// ```
-    fn simplify(&self, epsilon: &f64) -> Self::Output;
+    fn simplify(&self, epsilon: &BroadcastablePrimitive<Float64Type>) -> Self::Output;
 }
 
 // Note: this can't (easily) be parameterized in the macro because PointArray is not generic over O
 impl Simplify for PointArray {
     type Output = Self;
 
-    fn simplify(&self, _epsilon: &f64) -> Self {
+    fn simplify(&self, _epsilon: &BroadcastablePrimitive<Float64Type>) -> Self {
         self.clone()
     }
 }
@@ -70,7 +78,7 @@ macro_rules! identity_impl {
         impl<O: OffsetSizeTrait> Simplify for $type {
             type Output = Self;
 
-            fn simplify(&self, _epsilon: &f64) -> Self {
+            fn simplify(&self, _epsilon: &BroadcastablePrimitive<Float64Type>) -> Self {
                 self.clone()
             }
         }
@@ -81,33 +89,38 @@ identity_impl!(MultiPointArray<O>);
 
 /// Implementation that iterates over geo objects
 macro_rules! iter_geo_impl {
-    ($type:ty, $geo_type:ty) => {
+    ($type:ty) => {
         impl<O: OffsetSizeTrait> Simplify for $type {
             type Output = Self;
 
-            fn simplify(&self, epsilon: &f64) -> Self {
-                let output_geoms: Vec<Option<$geo_type>> = self
-                    .iter_geo()
-                    .map(|maybe_g| maybe_g.map(|geom| geom.simplify(epsilon)))
-                    .collect();
-
-                output_geoms.into()
+            fn simplify(&self, epsilon: &BroadcastablePrimitive<Float64Type>) -> Self {
+                self.iter_geo()
+                    .zip(broadcast_values(self.len(), epsilon))
+                    .map(|(maybe_g, epsilon)| Some(maybe_g?.simplify(&epsilon?)))
+                    .collect()
             }
         }
     };
 }
 
-iter_geo_impl!(LineStringArray<O>, geo::LineString);
-iter_geo_impl!(PolygonArray<O>, geo::Polygon);
-iter_geo_impl!(MultiLineStringArray<O>, geo::MultiLineString);
-iter_geo_impl!(MultiPolygonArray<O>, geo::MultiPolygon);
-// iter_geo_impl!(MixedGeometryArray<O>, geo::Geometry);
-// iter_geo_impl!(GeometryCollectionArray<O>, geo::GeometryCollection);
+iter_geo_impl!(LineStringArray<O>);
+iter_geo_impl!(PolygonArray<O>);
+iter_geo_impl!(MultiLineStringArray<O>);
+iter_geo_impl!(MultiPolygonArray<O>);
+// iter_geo_impl!(MixedGeometryArray<O>);
+// iter_geo_impl!(GeometryCollectionArray<O>);
 
 impl Simplify for &dyn GeometryArrayTrait {
     type Output = Result<Arc<dyn GeometryArrayTrait>>;
 
-    fn simplify(&self, epsilon: &f64) -> Self::Output {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            skip_all,
+            fields(num_rows = self.len(), data_type = ?self.data_type(), epsilon = ?epsilon)
+        )
+    )]
+    fn simplify(&self, epsilon: &BroadcastablePrimitive<Float64Type>) -> Self::Output {
         let result: Arc<dyn GeometryArrayTrait> = match self.data_type() {
             GeoDataType::Point(_) => Arc::new(self.as_point().simplify(epsilon)),
             GeoDataType::LineString(_) => Arc::new(self.as_line_string().simplify(epsilon)),
@@ -142,62 +155,47 @@ impl Simplify for &dyn GeometryArrayTrait {
     }
 }
 
-impl Simplify for ChunkedGeometryArray<PointArray> {
-    type Output = Self;
+/// Epsilon arrays have one value per geometry of the chunked array, and are split at the chunk
+/// boundaries.
+impl<G: GeometryArrayTrait + Simplify<Output = G>> Simplify for ChunkedGeometryArray<G> {
+    type Output = Result<Self>;
 
-    fn simplify(&self, epsilon: &f64) -> Self::Output {
-        self.map(|chunk| chunk.simplify(epsilon))
-            .try_into()
-            .unwrap()
+    fn simplify(&self, epsilon: &BroadcastablePrimitive<Float64Type>) -> Self::Output {
+        let chunks = self
+            .chunks
+            .iter()
+            .zip(epsilon.split_chunks(&self.chunk_lengths())?)
+            .map(|(chunk, epsilon)| chunk.simplify(&epsilon))
+            .collect();
+        Ok(ChunkedGeometryArray::new_unchecked(chunks))
     }
 }
 
-/// Implementation that iterates over chunks
-macro_rules! chunked_impl {
-    ($type:ty) => {
-        impl<O: OffsetSizeTrait> Simplify for $type {
-            type Output = Self;
-
-            fn simplify(&self, epsilon: &f64) -> Self {
-                self.map(|chunk| chunk.simplify(epsilon))
-                    .try_into()
-                    .unwrap()
-            }
-        }
-    };
-}
-
-chunked_impl!(ChunkedGeometryArray<LineStringArray<O>>);
-chunked_impl!(ChunkedGeometryArray<PolygonArray<O>>);
-chunked_impl!(ChunkedGeometryArray<MultiPointArray<O>>);
-chunked_impl!(ChunkedGeometryArray<MultiLineStringArray<O>>);
-chunked_impl!(ChunkedGeometryArray<MultiPolygonArray<O>>);
-
 impl Simplify for &dyn ChunkedGeometryArrayTrait {
     type Output = Result<Arc<dyn ChunkedGeometryArrayTrait>>;
 
-    fn simplify(&self, epsilon: &f64) -> Self::Output {
+    fn simplify(&self, epsilon: &BroadcastablePrimitive<Float64Type>) -> Self::Output {
         let result: Arc<dyn ChunkedGeometryArrayTrait> = match self.data_type() {
-            GeoDataType::Point(_) => Arc::new(self.as_point().simplify(epsilon)),
-            GeoDataType::LineString(_) => Arc::new(self.as_line_string().simplify(epsilon)),
+            GeoDataType::Point(_) => Arc::new(self.as_point().simplify(epsilon)?),
+            GeoDataType::LineString(_) => Arc::new(self.as_line_string().simplify(epsilon)?),
             GeoDataType::LargeLineString(_) => {
-                Arc::new(self.as_large_line_string().simplify(epsilon))
+                Arc::new(self.as_large_line_string().simplify(epsilon)?)
             }
-            GeoDataType::Polygon(_) => Arc::new(self.as_polygon().simplify(epsilon)),
-            GeoDataType::LargePolygon(_) => Arc::new(self.as_large_polygon().simplify(epsilon)),
-            GeoDataType::MultiPoint(_) => Arc::new(self.as_multi_point().simplify(epsilon)),
+            GeoDataType::Polygon(_) => Arc::new(self.as_polygon().simplify(epsilon)?),
+            GeoDataType::LargePolygon(_) => Arc::new(self.as_large_polygon().simplify(epsilon)?),
+            GeoDataType::MultiPoint(_) => Arc::new(self.as_multi_point().simplify(epsilon)?),
             GeoDataType::LargeMultiPoint(_) => {
-                Arc::new(self.as_large_multi_point().simplify(epsilon))
+                Arc::new(self.as_large_multi_point().simplify(epsilon)?)
             }
             GeoDataType::MultiLineString(_) => {
-                Arc::new(self.as_multi_line_string().simplify(epsilon))
+                Arc::new(self.as_multi_line_string().simplify(epsilon)?)
             }
             GeoDataType::LargeMultiLineString(_) => {
-                Arc::new(self.as_large_multi_line_string().simplify(epsilon))
+                Arc::new(self.as_large_multi_line_string().simplify(epsilon)?)
             }
-            GeoDataType::MultiPolygon(_) => Arc::new(self.as_multi_polygon().simplify(epsilon)),
+            GeoDataType::MultiPolygon(_) => Arc::new(self.as_multi_polygon().simplify(epsilon)?),
             GeoDataType::LargeMultiPolygon(_) => {
-                Arc::new(self.as_large_multi_polygon().simplify(epsilon))
+                Arc::new(self.as_large_multi_polygon().simplify(epsilon)?)
             }
             // GeoDataType::Mixed(_) => self.as_mixed().simplify(epsilon),
             // GeoDataType::LargeMixed(_) => self.as_large_mixed().simplify(),
@@ -228,7 +226,7 @@ mod tests {
             (x: 27.8, y: 0.1 ),
         ];
         let input_array: LineStringArray<i64> = vec![input_geom].as_slice().into();
-        let result_array = input_array.simplify(&1.0);
+        let result_array = input_array.simplify(&1.0.into());
 
         let expected = line_string![
             ( x: 0.0, y: 0.0 ),
@@ -251,7 +249,7 @@ mod tests {
             (x: 0., y: 0.),
         ];
         let input_array: PolygonArray<i64> = vec![input_geom].as_slice().into();
-        let result_array = input_array.simplify(&2.0);
+        let result_array = input_array.simplify(&2.0.into());
 
         let expected = polygon![
             (x: 0., y: 0.),
@@ -263,4 +261,48 @@ mod tests {
 
         assert_eq!(expected, result_array.get_as_geo(0).unwrap());
     }
+
+    #[test]
+    fn broadcast_epsilon() {
+        use crate::trait_::GeometryArraySelfMethods;
+        use arrow_array::Float64Array;
+        use geo::CoordsIter;
+
+        let input_geom = line_string![
+            (x: 0.0, y: 0.0 ),
+            (x: 5.0, y: 4.0 ),
+            (x: 11.0, y: 5.5 ),
+            (x: 17.3, y: 3.2 ),
+            (x: 27.8, y: 0.1 ),
+        ];
+        let input_array: LineStringArray<i32> = vec![
+            Some(input_geom.clone()),
+            Some(input_geom.clone()),
+            None,
+            Some(input_geom),
+        ]
+        .into();
+        let epsilon = BroadcastablePrimitive::Array(Float64Array::from(vec![
+            Some(1.0),
+            Some(0.0),
+            Some(1.0),
+            None,
+        ]));
+        let result_array = input_array.simplify(&epsilon);
+        assert_eq!(result_array.value_as_geo(0).coords_count(), 4);
+        assert_eq!(result_array.value_as_geo(1).coords_count(), 5);
+        assert!(result_array.is_null(2));
+        assert!(result_array.is_null(3));
+
+        let chunked = ChunkedGeometryArray::new_unchecked(vec![
+            input_array.slice(0, 1),
+            input_array.slice(1, 3),
+        ]);
+        let result = chunked.simplify(&epsilon).unwrap();
+        assert_eq!(result.chunks()[0].value_as_geo(0).coords_count(), 4);
+        assert!(result.chunks()[1].is_null(1));
+
+        let wrong_length = BroadcastablePrimitive::Array(Float64Array::from(vec![1.0]));
+        assert!(chunked.simplify(&wrong_length).is_err());
+    }
 }
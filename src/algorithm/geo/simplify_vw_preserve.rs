@@ -1,17 +1,25 @@
 use std::sync::Arc;
 
+use crate::algorithm::broadcasting::BroadcastablePrimitive;
+use crate::algorithm::geo::utils::broadcast_values;
 use crate::array::*;
 use crate::chunked_array::{ChunkedGeometryArray, ChunkedGeometryArrayTrait};
 use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
 use crate::trait_::GeometryArrayAccessor;
 use crate::GeometryArrayTrait;
+use arrow_array::types::Float64Type;
 use arrow_array::OffsetSizeTrait;
 use geo::SimplifyVwPreserve as _SimplifyVwPreserve;
 
 /// Simplifies a geometry, attempting to preserve its topology by removing self-intersections
 ///
-/// An epsilon less than or equal to zero will return an unaltered version of the geometry
+/// An epsilon less than or equal to zero will return an unaltered version of the geometry.
+///
+/// ## Broadcasting
+///
+/// `epsilon` is either a scalar applied to every geometry or an array with one value per geometry.
+/// Geometries with a null epsilon become null.
 pub trait SimplifyVwPreserve {
     type Output;
 
@@ -44,14 +52,14 @@ pub trait SimplifyVwPreserve {
     /// points to form a valid geometry.
     /// - The tolerance used to remove a point is `epsilon`, in keeping with GEOS. JTS uses
     ///   `epsilon ^ 2`
-    fn simplify_vw_preserve(&self, epsilon: &f64) -> Self::Output;
+    fn simplify_vw_preserve(&self, epsilon: &BroadcastablePrimitive<Float64Type>) -> Self::Output;
 }
 
 // Note: this can't (easily) be parameterized in the macro because PointArray is not generic over O
 impl SimplifyVwPreserve for PointArray {
     type Output = Self;
 
-    fn simplify_vw_preserve(&self, _epsilon: &f64) -> Self {
+    fn simplify_vw_preserve(&self, _epsilon: &BroadcastablePrimitive<Float64Type>) -> Self {
         self.clone()
     }
 }
@@ -62,7 +70,7 @@ macro_rules! identity_impl {
         impl<O: OffsetSizeTrait> SimplifyVwPreserve for $type {
             type Output = Self;
 
-            fn simplify_vw_preserve(&self, _epsilon: &f64) -> Self {
+            fn simplify_vw_preserve(&self, _epsilon: &BroadcastablePrimitive<Float64Type>) -> Self {
                 self.clone()
             }
         }
@@ -73,33 +81,31 @@ identity_impl!(MultiPointArray<O>);
 
 /// Implementation that iterates over geo objects
 macro_rules! iter_geo_impl {
-    ($type:ty, $geo_type:ty) => {
+    ($type:ty) => {
         impl<O: OffsetSizeTrait> SimplifyVwPreserve for $type {
             type Output = Self;
 
-            fn simplify_vw_preserve(&self, epsilon: &f64) -> Self {
-                let output_geoms: Vec<Option<$geo_type>> = self
-                    .iter_geo()
-                    .map(|maybe_g| maybe_g.map(|geom| geom.simplify_vw_preserve(epsilon)))
-                    .collect();
-
-                output_geoms.into()
+            fn simplify_vw_preserve(&self, epsilon: &BroadcastablePrimitive<Float64Type>) -> Self {
+                self.iter_geo()
+                    .zip(broadcast_values(self.len(), epsilon))
+                    .map(|(maybe_g, epsilon)| Some(maybe_g?.simplify_vw_preserve(&epsilon?)))
+                    .collect()
             }
         }
     };
 }
 
-iter_geo_impl!(LineStringArray<O>, geo::LineString);
-iter_geo_impl!(PolygonArray<O>, geo::Polygon);
-iter_geo_impl!(MultiLineStringArray<O>, geo::MultiLineString);
-iter_geo_impl!(MultiPolygonArray<O>, geo::MultiPolygon);
-// iter_geo_impl!(MixedGeometryArray<O>, geo::Geometry);
-// iter_geo_impl!(GeometryCollectionArray<O>, geo::GeometryCollection);
+iter_geo_impl!(LineStringArray<O>);
+iter_geo_impl!(PolygonArray<O>);
+iter_geo_impl!(MultiLineStringArray<O>);
+iter_geo_impl!(MultiPolygonArray<O>);
+// iter_geo_impl!(MixedGeometryArray<O>);
+// iter_geo_impl!(GeometryCollectionArray<O>);
 
 impl SimplifyVwPreserve for &dyn GeometryArrayTrait {
     type Output = Result<Arc<dyn GeometryArrayTrait>>;
 
-    fn simplify_vw_preserve(&self, epsilon: &f64) -> Self::Output {
+    fn simplify_vw_preserve(&self, epsilon: &BroadcastablePrimitive<Float64Type>) -> Self::Output {
         let result: Arc<dyn GeometryArrayTrait> = match self.data_type() {
             GeoDataType::Point(_) => Arc::new(self.as_point().simplify_vw_preserve(epsilon)),
             GeoDataType::LineString(_) => {
@@ -143,72 +149,60 @@ impl SimplifyVwPreserve for &dyn GeometryArrayTrait {
     }
 }
 
-impl SimplifyVwPreserve for ChunkedGeometryArray<PointArray> {
-    type Output = Self;
+/// Epsilon arrays have one value per geometry of the chunked array, and are split at the chunk
+/// boundaries.
+impl<G: GeometryArrayTrait + SimplifyVwPreserve<Output = G>> SimplifyVwPreserve
+    for ChunkedGeometryArray<G>
+{
+    type Output = Result<Self>;
 
-    fn simplify_vw_preserve(&self, epsilon: &f64) -> Self::Output {
-        self.map(|chunk| chunk.simplify_vw_preserve(epsilon))
-            .try_into()
-            .unwrap()
+    fn simplify_vw_preserve(&self, epsilon: &BroadcastablePrimitive<Float64Type>) -> Self::Output {
+        let chunks = self
+            .chunks
+            .iter()
+            .zip(epsilon.split_chunks(&self.chunk_lengths())?)
+            .map(|(chunk, epsilon)| chunk.simplify_vw_preserve(&epsilon))
+            .collect();
+        Ok(ChunkedGeometryArray::new_unchecked(chunks))
     }
 }
 
-/// Implementation that iterates over chunks
-macro_rules! chunked_impl {
-    ($type:ty) => {
-        impl<O: OffsetSizeTrait> SimplifyVwPreserve for $type {
-            type Output = Self;
-
-            fn simplify_vw_preserve(&self, epsilon: &f64) -> Self {
-                self.map(|chunk| chunk.simplify_vw_preserve(epsilon))
-                    .try_into()
-                    .unwrap()
-            }
-        }
-    };
-}
-
-chunked_impl!(ChunkedGeometryArray<LineStringArray<O>>);
-chunked_impl!(ChunkedGeometryArray<PolygonArray<O>>);
-chunked_impl!(ChunkedGeometryArray<MultiPointArray<O>>);
-chunked_impl!(ChunkedGeometryArray<MultiLineStringArray<O>>);
-chunked_impl!(ChunkedGeometryArray<MultiPolygonArray<O>>);
-
 impl SimplifyVwPreserve for &dyn ChunkedGeometryArrayTrait {
     type Output = Result<Arc<dyn ChunkedGeometryArrayTrait>>;
 
-    fn simplify_vw_preserve(&self, epsilon: &f64) -> Self::Output {
+    fn simplify_vw_preserve(&self, epsilon: &BroadcastablePrimitive<Float64Type>) -> Self::Output {
         let result: Arc<dyn ChunkedGeometryArrayTrait> = match self.data_type() {
-            GeoDataType::Point(_) => Arc::new(self.as_point().simplify_vw_preserve(epsilon)),
+            GeoDataType::Point(_) => Arc::new(self.as_point().simplify_vw_preserve(epsilon)?),
             GeoDataType::LineString(_) => {
-                Arc::new(self.as_line_string().simplify_vw_preserve(epsilon))
+                Arc::new(self.as_line_string().simplify_vw_preserve(epsilon)?)
             }
             GeoDataType::LargeLineString(_) => {
-                Arc::new(self.as_large_line_string().simplify_vw_preserve(epsilon))
+                Arc::new(self.as_large_line_string().simplify_vw_preserve(epsilon)?)
             }
-            GeoDataType::Polygon(_) => Arc::new(self.as_polygon().simplify_vw_preserve(epsilon)),
+            GeoDataType::Polygon(_) => Arc::new(self.as_polygon().simplify_vw_preserve(epsilon)?),
             GeoDataType::LargePolygon(_) => {
-                Arc::new(self.as_large_polygon().simplify_vw_preserve(epsilon))
+                Arc::new(self.as_large_polygon().simplify_vw_preserve(epsilon)?)
             }
             GeoDataType::MultiPoint(_) => {
-                Arc::new(self.as_multi_point().simplify_vw_preserve(epsilon))
+                Arc::new(self.as_multi_point().simplify_vw_preserve(epsilon)?)
             }
             GeoDataType::LargeMultiPoint(_) => {
-                Arc::new(self.as_large_multi_point().simplify_vw_preserve(epsilon))
+                Arc::new(self.as_large_multi_point().simplify_vw_preserve(epsilon)?)
             }
             GeoDataType::MultiLineString(_) => {
-                Arc::new(self.as_multi_line_string().simplify_vw_preserve(epsilon))
+                Arc::new(self.as_multi_line_string().simplify_vw_preserve(epsilon)?)
             }
             GeoDataType::LargeMultiLineString(_) => Arc::new(
                 self.as_large_multi_line_string()
-                    .simplify_vw_preserve(epsilon),
+                    .simplify_vw_preserve(epsilon)?,
             ),
             GeoDataType::MultiPolygon(_) => {
-                Arc::new(self.as_multi_polygon().simplify_vw_preserve(epsilon))
-            }
-            GeoDataType::LargeMultiPolygon(_) => {
-                Arc::new(self.as_large_multi_polygon().simplify_vw_preserve(epsilon))
+                Arc::new(self.as_multi_polygon().simplify_vw_preserve(epsilon)?)
             }
+            GeoDataType::LargeMultiPolygon(_) => Arc::new(
+                self.as_large_multi_polygon()
+                    .simplify_vw_preserve(epsilon)?,
+            ),
             // GeoDataType::Mixed(_) => self.as_mixed().simplify_vw_preserve(epsilon),
             // GeoDataType::LargeMixed(_) => self.as_large_mixed().simplify_vw_preserve(),
             // GeoDataType::GeometryCollection(_) => self.as_geometry_collection().simplify_vw_preserve(),
@@ -220,3 +214,50 @@ impl SimplifyVwPreserve for &dyn ChunkedGeometryArrayTrait {
         Ok(result)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::{LineStringArray, PolygonArray};
+    use arrow_array::Float64Array;
+    use geo::{line_string, polygon, CoordsIter};
+
+    #[test]
+    fn line_string() {
+        let input_geom = line_string![
+            (x: 5.0, y: 2.0),
+            (x: 3.0, y: 8.0),
+            (x: 6.0, y: 20.0),
+            (x: 7.0, y: 25.0),
+            (x: 10.0, y: 10.0),
+        ];
+        let input_array: LineStringArray<i32> =
+            vec![Some(input_geom.clone()), None, Some(input_geom)].into();
+        let epsilon =
+            BroadcastablePrimitive::Array(Float64Array::from(vec![Some(30.0), Some(30.0), None]));
+        let result_array = input_array.simplify_vw_preserve(&epsilon);
+        assert_eq!(result_array.value_as_geo(0).coords_count(), 3);
+        assert!(result_array.is_null(1));
+        assert!(result_array.is_null(2));
+    }
+
+    #[test]
+    fn polygon() {
+        let input_geom = polygon![
+            (x: 0., y: 0.),
+            (x: 0., y: 10.),
+            (x: 5., y: 11.),
+            (x: 10., y: 10.),
+            (x: 10., y: 0.),
+            (x: 0., y: 0.),
+        ];
+        let input_array: PolygonArray<i64> = vec![Some(input_geom), None].into();
+        let result_array = input_array.simplify_vw_preserve(&10.0.into());
+        assert!(result_array.value_as_geo(0).coords_count() < 6);
+        assert!(result_array.is_null(1));
+
+        let chunked = ChunkedGeometryArray::new_unchecked(vec![input_array.clone()]);
+        let result = chunked.simplify_vw_preserve(&10.0.into()).unwrap();
+        assert_eq!(result.chunks()[0], result_array);
+    }
+}
@@ -1,10 +1,14 @@
 use crate::algorithm::broadcasting::BroadcastablePrimitive;
-use crate::array::LineStringArray;
+use crate::algorithm::geo::affine_ops::AffineTransformable;
+use crate::algorithm::geo::utils::{broadcast_affine_transform, TransformOrigin};
+use crate::algorithm::geo::Center;
 use crate::array::*;
-use crate::trait_::GeometryArrayAccessor;
+use crate::chunked_array::ChunkedGeometryArray;
+use crate::error::Result;
+use crate::GeometryArrayTrait;
 use arrow_array::types::Float64Type;
 use arrow_array::OffsetSizeTrait;
-use geo::Skew as _Skew;
+use geo::AffineTransform;
 
 /// An affine transformation which skews a geometry, sheared by angles along x and y dimensions.
 ///
@@ -15,7 +19,14 @@ use geo::Skew as _Skew;
 /// [`Translate`](crate::algorithm::geo::Translate), or [`Rotate`](crate::algorithm::geo::Rotate),
 /// it is more efficient to compose the transformations and apply them as a single operation using
 /// the [`AffineOps`](crate::algorithm::geo::AffineOps) trait.
+///
+/// ## Broadcasting
+///
+/// Each angle is either a scalar applied to every geometry or an array with one value per
+/// geometry. Geometries with a null angle become null.
 pub trait Skew {
+    type Output;
+
     /// An affine transformation which skews a geometry, sheared by a uniform angle along the x and
     /// y dimensions.
     ///
@@ -43,7 +54,7 @@ pub trait Skew {
     /// approx::assert_relative_eq!(skewed, expected_output, epsilon = 1e-2);
     /// ```
     #[must_use]
-    fn skew(&self, degrees: BroadcastablePrimitive<Float64Type>) -> Self;
+    fn skew(&self, degrees: BroadcastablePrimitive<Float64Type>) -> Self::Output;
 
     // /// Mutable version of [`skew`](Self::skew).
     // fn skew_mut(&mut self, degrees: BroadcastablePrimitive<Float64Type>);
@@ -78,7 +89,7 @@ pub trait Skew {
         &self,
         degrees_x: BroadcastablePrimitive<Float64Type>,
         degrees_y: BroadcastablePrimitive<Float64Type>,
-    ) -> Self;
+    ) -> Self::Output;
 
     // /// Mutable version of [`skew_xy`](Self::skew_xy).
     // fn skew_xy_mut(
@@ -124,7 +135,7 @@ pub trait Skew {
         degrees_x: BroadcastablePrimitive<Float64Type>,
         degrees_y: BroadcastablePrimitive<Float64Type>,
         origin: geo::Point,
-    ) -> Self;
+    ) -> Self::Output;
 
     // /// Mutable version of [`skew_around_point`](Self::skew_around_point).
     // fn skew_around_point_mut(
@@ -135,154 +146,131 @@ pub trait Skew {
     // );
 }
 
+fn skew_array<G: AffineTransformable + Center<Output = PointArray>>(
+    array: &G,
+    degrees_x: &BroadcastablePrimitive<Float64Type>,
+    degrees_y: &BroadcastablePrimitive<Float64Type>,
+    origin: Option<geo::Point>,
+) -> G {
+    let origin = match origin {
+        Some(origin) => TransformOrigin::Point(origin),
+        None => TransformOrigin::PerGeometry(array.center()),
+    };
+    broadcast_affine_transform(array, [degrees_x, degrees_y], origin, |[x, y], origin| {
+        AffineTransform::skew(x, y, origin)
+    })
+}
+
 // Note: this can't (easily) be parameterized in the macro because PointArray is not generic over O
 impl Skew for PointArray {
-    fn skew(&self, scale_factor: BroadcastablePrimitive<Float64Type>) -> Self {
-        let mut output_array = PointBuilder::with_capacity(self.buffer_lengths());
-
-        self.iter_geo()
-            .zip(&scale_factor)
-            .for_each(|(maybe_g, scale_factor)| {
-                output_array.push_point(
-                    maybe_g
-                        .map(|geom| geom.skew(scale_factor.unwrap()))
-                        .as_ref(),
-                )
-            });
+    type Output = Self;
 
-        output_array.finish()
+    fn skew(&self, degrees: BroadcastablePrimitive<Float64Type>) -> Self::Output {
+        skew_array(self, &degrees, &degrees, None)
     }
 
     fn skew_xy(
         &self,
-        x_factor: BroadcastablePrimitive<Float64Type>,
-        y_factor: BroadcastablePrimitive<Float64Type>,
-    ) -> Self {
-        let mut output_array = PointBuilder::with_capacity(self.buffer_lengths());
-
-        self.iter_geo().zip(&x_factor).zip(&y_factor).for_each(
-            |((maybe_g, x_factor), y_factor)| {
-                output_array.push_point(
-                    maybe_g
-                        .map(|geom| geom.skew_xy(x_factor.unwrap(), y_factor.unwrap()))
-                        .as_ref(),
-                )
-            },
-        );
-
-        output_array.finish()
+        degrees_x: BroadcastablePrimitive<Float64Type>,
+        degrees_y: BroadcastablePrimitive<Float64Type>,
+    ) -> Self::Output {
+        skew_array(self, &degrees_x, &degrees_y, None)
     }
 
     fn skew_around_point(
         &self,
-        x_factor: BroadcastablePrimitive<Float64Type>,
-        y_factor: BroadcastablePrimitive<Float64Type>,
+        degrees_x: BroadcastablePrimitive<Float64Type>,
+        degrees_y: BroadcastablePrimitive<Float64Type>,
         origin: geo::Point,
-    ) -> Self {
-        let mut output_array = PointBuilder::with_capacity(self.buffer_lengths());
-
-        self.iter_geo().zip(&x_factor).zip(&y_factor).for_each(
-            |((maybe_g, x_factor), y_factor)| {
-                output_array.push_point(
-                    maybe_g
-                        .map(|geom| {
-                            geom.skew_around_point(x_factor.unwrap(), y_factor.unwrap(), origin)
-                        })
-                        .as_ref(),
-                )
-            },
-        );
-
-        output_array.finish()
+    ) -> Self::Output {
+        skew_array(self, &degrees_x, &degrees_y, Some(origin))
     }
 }
 
-/// Implementation that iterates over geo objects
-macro_rules! iter_geo_impl {
-    ($type:ty, $builder_type:ty, $push_func:ident) => {
+macro_rules! impl_skew {
+    ($type:ty) => {
         impl<O: OffsetSizeTrait> Skew for $type {
-            fn skew(&self, scale_factor: BroadcastablePrimitive<Float64Type>) -> Self {
-                let mut output_array = <$builder_type>::with_capacity(self.buffer_lengths());
+            type Output = Self;
 
-                self.iter_geo()
-                    .zip(&scale_factor)
-                    .for_each(|(maybe_g, scale_factor)| {
-                        output_array
-                            .$push_func(
-                                maybe_g
-                                    .map(|geom| geom.skew(scale_factor.unwrap()))
-                                    .as_ref(),
-                            )
-                            .unwrap();
-                    });
-
-                output_array.finish()
+            fn skew(&self, degrees: BroadcastablePrimitive<Float64Type>) -> Self::Output {
+                skew_array(self, &degrees, &degrees, None)
             }
 
             fn skew_xy(
                 &self,
-                x_factor: BroadcastablePrimitive<Float64Type>,
-                y_factor: BroadcastablePrimitive<Float64Type>,
-            ) -> Self {
-                let mut output_array = <$builder_type>::with_capacity(self.buffer_lengths());
-
-                self.iter_geo().zip(&x_factor).zip(&y_factor).for_each(
-                    |((maybe_g, x_factor), y_factor)| {
-                        output_array
-                            .$push_func(
-                                maybe_g
-                                    .map(|geom| geom.skew_xy(x_factor.unwrap(), y_factor.unwrap()))
-                                    .as_ref(),
-                            )
-                            .unwrap()
-                    },
-                );
-
-                output_array.finish()
+                degrees_x: BroadcastablePrimitive<Float64Type>,
+                degrees_y: BroadcastablePrimitive<Float64Type>,
+            ) -> Self::Output {
+                skew_array(self, &degrees_x, &degrees_y, None)
             }
 
             fn skew_around_point(
                 &self,
-                x_factor: BroadcastablePrimitive<Float64Type>,
-                y_factor: BroadcastablePrimitive<Float64Type>,
+                degrees_x: BroadcastablePrimitive<Float64Type>,
+                degrees_y: BroadcastablePrimitive<Float64Type>,
                 origin: geo::Point,
-            ) -> Self {
-                let mut output_array = <$builder_type>::with_capacity(self.buffer_lengths());
-
-                self.iter_geo().zip(&x_factor).zip(&y_factor).for_each(
-                    |((maybe_g, x_factor), y_factor)| {
-                        output_array
-                            .$push_func(
-                                maybe_g
-                                    .map(|geom| {
-                                        geom.skew_around_point(
-                                            x_factor.unwrap(),
-                                            y_factor.unwrap(),
-                                            origin,
-                                        )
-                                    })
-                                    .as_ref(),
-                            )
-                            .unwrap()
-                    },
-                );
-
-                output_array.finish()
+            ) -> Self::Output {
+                skew_array(self, &degrees_x, &degrees_y, Some(origin))
             }
         }
     };
 }
 
-iter_geo_impl!(LineStringArray<O>, LineStringBuilder<O>, push_line_string);
-iter_geo_impl!(PolygonArray<O>, PolygonBuilder<O>, push_polygon);
-iter_geo_impl!(MultiPointArray<O>, MultiPointBuilder<O>, push_multi_point);
-iter_geo_impl!(
-    MultiLineStringArray<O>,
-    MultiLineStringBuilder<O>,
-    push_multi_line_string
-);
-iter_geo_impl!(
-    MultiPolygonArray<O>,
-    MultiPolygonBuilder<O>,
-    push_multi_polygon
-);
+impl_skew!(LineStringArray<O>);
+impl_skew!(PolygonArray<O>);
+impl_skew!(MultiPointArray<O>);
+impl_skew!(MultiLineStringArray<O>);
+impl_skew!(MultiPolygonArray<O>);
+impl_skew!(MixedGeometryArray<O>);
+impl_skew!(GeometryCollectionArray<O>);
+
+/// Angle arrays have one value per geometry of the chunked array, and are split at the chunk
+/// boundaries.
+impl<G: GeometryArrayTrait + Skew<Output = G>> Skew for ChunkedGeometryArray<G> {
+    type Output = Result<Self>;
+
+    fn skew(&self, degrees: BroadcastablePrimitive<Float64Type>) -> Self::Output {
+        let chunks = self
+            .chunks
+            .iter()
+            .zip(degrees.split_chunks(&self.chunk_lengths())?)
+            .map(|(chunk, degrees)| chunk.skew(degrees))
+            .collect();
+        Ok(ChunkedGeometryArray::new_unchecked(chunks))
+    }
+
+    fn skew_xy(
+        &self,
+        degrees_x: BroadcastablePrimitive<Float64Type>,
+        degrees_y: BroadcastablePrimitive<Float64Type>,
+    ) -> Self::Output {
+        let chunk_lengths = self.chunk_lengths();
+        let chunks = self
+            .chunks
+            .iter()
+            .zip(degrees_x.split_chunks(&chunk_lengths)?)
+            .zip(degrees_y.split_chunks(&chunk_lengths)?)
+            .map(|((chunk, degrees_x), degrees_y)| chunk.skew_xy(degrees_x, degrees_y))
+            .collect();
+        Ok(ChunkedGeometryArray::new_unchecked(chunks))
+    }
+
+    fn skew_around_point(
+        &self,
+        degrees_x: BroadcastablePrimitive<Float64Type>,
+        degrees_y: BroadcastablePrimitive<Float64Type>,
+        origin: geo::Point,
+    ) -> Self::Output {
+        let chunk_lengths = self.chunk_lengths();
+        let chunks = self
+            .chunks
+            .iter()
+            .zip(degrees_x.split_chunks(&chunk_lengths)?)
+            .zip(degrees_y.split_chunks(&chunk_lengths)?)
+            .map(|((chunk, degrees_x), degrees_y)| {
+                chunk.skew_around_point(degrees_x, degrees_y, origin)
+            })
+            .collect();
+        Ok(ChunkedGeometryArray::new_unchecked(chunks))
+    }
+}
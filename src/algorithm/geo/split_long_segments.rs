@@ -0,0 +1,298 @@
+use std::sync::Arc;
+
+use crate::algorithm::native::MapChunks;
+use crate::array::*;
+use crate::chunked_array::*;
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+use arrow_array::OffsetSizeTrait;
+use geo::{Coord, GeodesicDistance, HaversineDistance, HaversineIntermediate, LineString, Point};
+
+/// How to measure the length of a segment between two longitude/latitude coordinates, in meters.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LengthMethod {
+    /// The great circle distance on a sphere with the mean radius of the Earth.
+    #[default]
+    Haversine,
+
+    /// The shortest distance on the WGS84 ellipsoid, with the algorithm of Karney (2013). This is
+    /// more accurate but slower than [`Haversine`][LengthMethod::Haversine].
+    Geodesic,
+}
+
+impl LengthMethod {
+    fn length(&self, start: Coord, end: Coord) -> f64 {
+        let (start, end) = (Point::from(start), Point::from(end));
+        match self {
+            LengthMethod::Haversine => start.haversine_distance(&end),
+            LengthMethod::Geodesic => start.geodesic_distance(&end),
+        }
+    }
+}
+
+/// Split the segments of linear geometries that are longer than `max_length_m` meters, as measured
+/// with `method` on longitude/latitude coordinates.
+///
+/// Each long segment is split into the smallest number of equal parts that are all at most
+/// `max_length_m` long, with new vertices along the great circle between its endpoints. Segments
+/// that are short enough and the original vertices are kept, so rings stay closed.
+///
+/// Unlike [`Densify`][crate::algorithm::geo::Densify], which measures in the units of the
+/// coordinates, lengths are always measured in meters on the Earth, so coordinates must be
+/// longitude/latitude in degrees. This is useful before reprojecting, where a long straight
+/// segment in one CRS is not straight in another; see
+/// [`ReprojectOptions`][crate::algorithm::proj::ReprojectOptions].
+///
+/// Note: `max_length_m` must be greater than 0.
+pub trait SplitLongSegments {
+    type Output;
+
+    fn split_long_segments(&self, max_length_m: f64, method: LengthMethod) -> Self::Output;
+}
+
+/// Split the long segments between consecutive coordinates.
+fn split_coords(coords: &[Coord], max_length_m: f64, method: LengthMethod) -> Vec<Coord> {
+    let mut output = Vec::with_capacity(coords.len());
+    for segment in coords.windows(2) {
+        let (start, end) = (segment[0], segment[1]);
+        output.push(start);
+
+        let length = method.length(start, end);
+        if length > max_length_m {
+            let num_parts = (length / max_length_m).ceil() as usize;
+            let (start, end) = (Point::from(start), Point::from(end));
+            for i in 1..num_parts {
+                let fraction = i as f64 / num_parts as f64;
+                output.push(start.haversine_intermediate(&end, fraction).0);
+            }
+        }
+    }
+    if let Some(last) = coords.last() {
+        output.push(*last);
+    }
+    output
+}
+
+fn split_line_string(
+    line_string: &LineString,
+    max_length_m: f64,
+    method: LengthMethod,
+) -> LineString {
+    LineString::new(split_coords(&line_string.0, max_length_m, method))
+}
+
+fn split_polygon(polygon: &geo::Polygon, max_length_m: f64, method: LengthMethod) -> geo::Polygon {
+    geo::Polygon::new(
+        split_line_string(polygon.exterior(), max_length_m, method),
+        polygon
+            .interiors()
+            .iter()
+            .map(|interior| split_line_string(interior, max_length_m, method))
+            .collect(),
+    )
+}
+
+/// Implementation that iterates over geo objects
+macro_rules! iter_geo_impl {
+    ($type:ty, $builder:ident, $push_func:ident, $split_func:expr) => {
+        impl<O: OffsetSizeTrait> SplitLongSegments for $type {
+            type Output = $type;
+
+            fn split_long_segments(&self, max_length_m: f64, method: LengthMethod) -> Self::Output {
+                // The input's buffer lengths are a lower bound for the output's
+                let mut builder = $builder::with_capacity_and_options(
+                    self.buffer_lengths(),
+                    self.coord_type(),
+                    self.metadata(),
+                );
+                self.iter_geo().for_each(|maybe_g| {
+                    builder
+                        .$push_func(
+                            maybe_g
+                                .map(|geom| $split_func(&geom, max_length_m, method))
+                                .as_ref(),
+                        )
+                        .unwrap()
+                });
+                builder.finish()
+            }
+        }
+    };
+}
+
+iter_geo_impl!(
+    LineStringArray<O>,
+    LineStringBuilder,
+    push_line_string,
+    split_line_string
+);
+iter_geo_impl!(PolygonArray<O>, PolygonBuilder, push_polygon, split_polygon);
+iter_geo_impl!(
+    MultiLineStringArray<O>,
+    MultiLineStringBuilder,
+    push_multi_line_string,
+    |geom: &geo::MultiLineString, max_length_m, method| {
+        geo::MultiLineString::new(
+            geom.iter()
+                .map(|line_string| split_line_string(line_string, max_length_m, method))
+                .collect(),
+        )
+    }
+);
+iter_geo_impl!(
+    MultiPolygonArray<O>,
+    MultiPolygonBuilder,
+    push_multi_polygon,
+    |geom: &geo::MultiPolygon, max_length_m, method| {
+        geo::MultiPolygon::new(
+            geom.iter()
+                .map(|polygon| split_polygon(polygon, max_length_m, method))
+                .collect(),
+        )
+    }
+);
+
+impl SplitLongSegments for &dyn GeometryArrayTrait {
+    type Output = Result<Arc<dyn GeometryArrayTrait>>;
+
+    fn split_long_segments(&self, max_length_m: f64, method: LengthMethod) -> Self::Output {
+        let (m, l) = (max_length_m, method);
+        let result: Arc<dyn GeometryArrayTrait> = match self.data_type() {
+            GeoDataType::LineString(_) => Arc::new(self.as_line_string().split_long_segments(m, l)),
+            GeoDataType::LargeLineString(_) => {
+                Arc::new(self.as_large_line_string().split_long_segments(m, l))
+            }
+            GeoDataType::Polygon(_) => Arc::new(self.as_polygon().split_long_segments(m, l)),
+            GeoDataType::LargePolygon(_) => {
+                Arc::new(self.as_large_polygon().split_long_segments(m, l))
+            }
+            GeoDataType::MultiLineString(_) => {
+                Arc::new(self.as_multi_line_string().split_long_segments(m, l))
+            }
+            GeoDataType::LargeMultiLineString(_) => {
+                Arc::new(self.as_large_multi_line_string().split_long_segments(m, l))
+            }
+            GeoDataType::MultiPolygon(_) => {
+                Arc::new(self.as_multi_polygon().split_long_segments(m, l))
+            }
+            GeoDataType::LargeMultiPolygon(_) => {
+                Arc::new(self.as_large_multi_polygon().split_long_segments(m, l))
+            }
+            _ => return Err(GeoArrowError::IncorrectType("".into())),
+        };
+        Ok(result)
+    }
+}
+
+macro_rules! impl_chunked {
+    ($struct_name:ty) => {
+        impl<O: OffsetSizeTrait> SplitLongSegments for $struct_name {
+            type Output = $struct_name;
+
+            fn split_long_segments(&self, max_length_m: f64, method: LengthMethod) -> Self::Output {
+                ChunkedGeometryArray::new_unchecked(
+                    self.map(|chunk| chunk.split_long_segments(max_length_m, method)),
+                )
+            }
+        }
+    };
+}
+
+impl_chunked!(ChunkedLineStringArray<O>);
+impl_chunked!(ChunkedPolygonArray<O>);
+impl_chunked!(ChunkedMultiLineStringArray<O>);
+impl_chunked!(ChunkedMultiPolygonArray<O>);
+
+impl SplitLongSegments for &dyn ChunkedGeometryArrayTrait {
+    type Output = Result<Arc<dyn ChunkedGeometryArrayTrait>>;
+
+    fn split_long_segments(&self, max_length_m: f64, method: LengthMethod) -> Self::Output {
+        let (m, l) = (max_length_m, method);
+        let result: Arc<dyn ChunkedGeometryArrayTrait> = match self.data_type() {
+            GeoDataType::LineString(_) => Arc::new(self.as_line_string().split_long_segments(m, l)),
+            GeoDataType::LargeLineString(_) => {
+                Arc::new(self.as_large_line_string().split_long_segments(m, l))
+            }
+            GeoDataType::Polygon(_) => Arc::new(self.as_polygon().split_long_segments(m, l)),
+            GeoDataType::LargePolygon(_) => {
+                Arc::new(self.as_large_polygon().split_long_segments(m, l))
+            }
+            GeoDataType::MultiLineString(_) => {
+                Arc::new(self.as_multi_line_string().split_long_segments(m, l))
+            }
+            GeoDataType::LargeMultiLineString(_) => {
+                Arc::new(self.as_large_multi_line_string().split_long_segments(m, l))
+            }
+            GeoDataType::MultiPolygon(_) => {
+                Arc::new(self.as_multi_polygon().split_long_segments(m, l))
+            }
+            GeoDataType::LargeMultiPolygon(_) => {
+                Arc::new(self.as_large_multi_polygon().split_long_segments(m, l))
+            }
+            _ => return Err(GeoArrowError::IncorrectType("".into())),
+        };
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use geo::{line_string, polygon};
+
+    fn length(line_string: &LineString, method: LengthMethod) -> f64 {
+        line_string
+            .lines()
+            .map(|line| method.length(line.start, line.end))
+            .sum()
+    }
+
+    #[test]
+    fn split_transatlantic_segment() {
+        // New York City to London, about 5,570 km
+        let line_string = line_string![(x: -74.006, y: 40.7128), (x: -0.1278, y: 51.5074)];
+        let arr: LineStringArray<i32> = vec![line_string.clone()].as_slice().into();
+
+        for method in [LengthMethod::Haversine, LengthMethod::Geodesic] {
+            let split = arr.split_long_segments(500_000., method);
+            let split_line_string = split.value_as_geo(0);
+            assert_eq!(split_line_string.0.len(), 13);
+            assert_eq!(split_line_string.0.first(), line_string.0.first());
+            assert_eq!(split_line_string.0.last(), line_string.0.last());
+            assert!(split_line_string
+                .lines()
+                .all(|line| method.length(line.start, line.end) <= 500_000. * 1.001));
+
+            let original_length = length(&line_string, method);
+            let split_length = length(&split_line_string, method);
+            assert!((split_length - original_length).abs() / original_length < 1e-4);
+        }
+
+        // Short segments are unchanged
+        let split = arr.split_long_segments(10_000_000., LengthMethod::Haversine);
+        assert_eq!(split, arr);
+    }
+
+    #[test]
+    fn split_polygon_rings() {
+        let polygon = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+        let arr: PolygonArray<i32> = vec![polygon].as_slice().into();
+        let dyn_arr: &dyn GeometryArrayTrait = &arr;
+        let split = dyn_arr
+            .split_long_segments(200_000., LengthMethod::Haversine)
+            .unwrap();
+        let exterior = split
+            .as_ref()
+            .as_polygon()
+            .value_as_geo(0)
+            .exterior()
+            .clone();
+        assert!(exterior.is_closed());
+        assert!(exterior.0.len() > 5);
+        assert!(exterior
+            .lines()
+            .all(|line| LengthMethod::Haversine.length(line.start, line.end) <= 200_000. * 1.001));
+    }
+}
@@ -1,13 +1,22 @@
 use crate::algorithm::broadcasting::BroadcastablePrimitive;
+use crate::algorithm::geo::affine_ops::AffineTransformable;
+use crate::algorithm::geo::utils::broadcast_transforms;
 use crate::array::*;
-use crate::trait_::GeometryArrayAccessor;
+use crate::chunked_array::ChunkedGeometryArray;
+use crate::error::Result;
+use crate::GeometryArrayTrait;
 use arrow_array::types::Float64Type;
 use arrow_array::OffsetSizeTrait;
-use geo::Translate as _Translate;
+use geo::AffineTransform;
 
 pub trait Translate {
+    type Output;
+
     /// Translate a Geometry along its axes by the given offsets
     ///
+    /// Each offset is either a scalar applied to every geometry or an array with one value per
+    /// geometry. Geometries with a null offset become null.
+    ///
     /// ## Performance
     ///
     /// If you will be performing multiple transformations, like
@@ -42,76 +51,124 @@ pub trait Translate {
         &self,
         x_offset: BroadcastablePrimitive<Float64Type>,
         y_offset: BroadcastablePrimitive<Float64Type>,
-    ) -> Self;
+    ) -> Self::Output;
 
     // /// Translate a Geometry along its axes, but in place.
     // fn translate_mut(&mut self, x_offset: T, y_offset: T);
 }
 
+fn translate_array<G: AffineTransformable>(
+    array: &G,
+    x_offset: &BroadcastablePrimitive<Float64Type>,
+    y_offset: &BroadcastablePrimitive<Float64Type>,
+) -> G {
+    if let (BroadcastablePrimitive::Scalar(x_offset), BroadcastablePrimitive::Scalar(y_offset)) =
+        (x_offset, y_offset)
+    {
+        return array.affine_transform(&AffineTransform::translate(*x_offset, *y_offset));
+    }
+
+    let transforms = broadcast_transforms(array.len(), [x_offset, y_offset], |_, [x, y]| {
+        Some(AffineTransform::translate(x, y))
+    });
+    array.affine_transform(transforms.as_slice())
+}
+
 // Note: this can't (easily) be parameterized in the macro because PointArray is not generic over O
 impl Translate for PointArray {
+    type Output = Self;
+
     fn translate(
         &self,
         x_offset: BroadcastablePrimitive<Float64Type>,
         y_offset: BroadcastablePrimitive<Float64Type>,
-    ) -> Self {
-        let mut output_array = PointBuilder::with_capacity(self.buffer_lengths());
-
-        self.iter_geo().zip(&x_offset).zip(&y_offset).for_each(
-            |((maybe_g, x_offset), y_offset)| {
-                output_array.push_point(
-                    maybe_g
-                        .map(|geom| geom.translate(x_offset.unwrap(), y_offset.unwrap()))
-                        .as_ref(),
-                )
-            },
-        );
-
-        output_array.finish()
+    ) -> Self::Output {
+        translate_array(self, &x_offset, &y_offset)
     }
 }
 
-/// Implementation that iterates over geo objects
-macro_rules! iter_geo_impl {
-    ($type:ty, $builder_type:ty, $push_func:ident) => {
+macro_rules! impl_translate {
+    ($type:ty) => {
         impl<O: OffsetSizeTrait> Translate for $type {
+            type Output = Self;
+
             fn translate(
                 &self,
                 x_offset: BroadcastablePrimitive<Float64Type>,
                 y_offset: BroadcastablePrimitive<Float64Type>,
-            ) -> Self {
-                let mut output_array = <$builder_type>::with_capacity(self.buffer_lengths());
-
-                self.iter_geo().zip(&x_offset).zip(&y_offset).for_each(
-                    |((maybe_g, x_offset), y_offset)| {
-                        output_array
-                            .$push_func(
-                                maybe_g
-                                    .map(|geom| {
-                                        geom.translate(x_offset.unwrap(), y_offset.unwrap())
-                                    })
-                                    .as_ref(),
-                            )
-                            .unwrap()
-                    },
-                );
-
-                output_array.finish()
+            ) -> Self::Output {
+                translate_array(self, &x_offset, &y_offset)
             }
         }
     };
 }
 
-iter_geo_impl!(LineStringArray<O>, LineStringBuilder<O>, push_line_string);
-iter_geo_impl!(PolygonArray<O>, PolygonBuilder<O>, push_polygon);
-iter_geo_impl!(MultiPointArray<O>, MultiPointBuilder<O>, push_multi_point);
-iter_geo_impl!(
-    MultiLineStringArray<O>,
-    MultiLineStringBuilder<O>,
-    push_multi_line_string
-);
-iter_geo_impl!(
-    MultiPolygonArray<O>,
-    MultiPolygonBuilder<O>,
-    push_multi_polygon
-);
+impl_translate!(LineStringArray<O>);
+impl_translate!(PolygonArray<O>);
+impl_translate!(MultiPointArray<O>);
+impl_translate!(MultiLineStringArray<O>);
+impl_translate!(MultiPolygonArray<O>);
+impl_translate!(MixedGeometryArray<O>);
+impl_translate!(GeometryCollectionArray<O>);
+
+/// Offset arrays have one value per geometry of the chunked array, and are split at the chunk
+/// boundaries.
+impl<G: GeometryArrayTrait + Translate<Output = G>> Translate for ChunkedGeometryArray<G> {
+    type Output = Result<Self>;
+
+    fn translate(
+        &self,
+        x_offset: BroadcastablePrimitive<Float64Type>,
+        y_offset: BroadcastablePrimitive<Float64Type>,
+    ) -> Self::Output {
+        let chunk_lengths = self.chunk_lengths();
+        let chunks = self
+            .chunks
+            .iter()
+            .zip(x_offset.split_chunks(&chunk_lengths)?)
+            .zip(y_offset.split_chunks(&chunk_lengths)?)
+            .map(|((chunk, x_offset), y_offset)| chunk.translate(x_offset, y_offset))
+            .collect();
+        Ok(ChunkedGeometryArray::new_unchecked(chunks))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::chunked_array::ChunkedPointArray;
+    use crate::test::point;
+    use crate::trait_::GeometryArrayAccessor;
+    use arrow_array::Float64Array;
+
+    #[test]
+    fn translate_broadcast_with_nulls() {
+        let arr: PointArray = vec![Some(point::p0()), Some(point::p1()), None].into();
+        let x_offset =
+            BroadcastablePrimitive::Array(Float64Array::from(vec![Some(1.), None, Some(3.)]));
+        let result = arr.translate(x_offset, 10.0.into());
+        assert_eq!(
+            result.value_as_geo(0),
+            geo::point!(x: point::p0().x() + 1., y: point::p0().y() + 10.)
+        );
+        assert!(result.is_null(1));
+        assert!(result.is_null(2));
+    }
+
+    #[test]
+    fn translate_chunked() {
+        let chunked = ChunkedPointArray::new_unchecked(vec![
+            vec![point::p0(), point::p1()].as_slice().into(),
+            vec![point::p2()].as_slice().into(),
+        ]);
+        let x_offset = BroadcastablePrimitive::Array(Float64Array::from(vec![1., 2., 3.]));
+        let result = chunked.translate(x_offset, 0.0.into()).unwrap();
+        assert_eq!(
+            result.chunks()[1].value_as_geo(0),
+            geo::point!(x: point::p2().x() + 3., y: point::p2().y())
+        );
+
+        let x_offset = BroadcastablePrimitive::Array(Float64Array::from(vec![1., 2.]));
+        assert!(chunked.translate(x_offset, 0.0.into()).is_err());
+    }
+}
@@ -0,0 +1,283 @@
+use std::ops::Range;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use arrow_array::OffsetSizeTrait;
+use geo::{BooleanOps, BoundingRect, Intersects};
+
+use crate::array::*;
+use crate::chunked_array::*;
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+
+/// Union all polygons of an array into a single [`MultiPolygon`][geo::MultiPolygon].
+///
+/// Rather than adding one polygon at a time to a growing result, this is a cascaded union:
+/// neighboring polygons are unioned in pairs, then the pairs in pairs, and so on, which keeps the
+/// inputs of each boolean operation small. Pairs whose bounding boxes don't intersect are
+/// combined without a boolean operation.
+///
+/// Null and empty geometries are skipped, and the union of an array without polygons is an empty
+/// `MultiPolygon`. Polygons with non-finite coordinates or rings of fewer than 4 coordinates
+/// return an error with their row index, as does a failure of the boolean operation, with the
+/// range of rows being unioned.
+pub trait UnaryUnion {
+    type Output;
+
+    fn unary_union(&self) -> Self::Output;
+}
+
+impl<O: OffsetSizeTrait> UnaryUnion for PolygonArray<O> {
+    type Output = Result<geo::MultiPolygon>;
+
+    fn unary_union(&self) -> Self::Output {
+        unary_union(
+            self.iter_geo()
+                .map(|maybe_polygon| maybe_polygon.map(geo::MultiPolygon::from))
+                .enumerate(),
+        )
+    }
+}
+
+impl<O: OffsetSizeTrait> UnaryUnion for MultiPolygonArray<O> {
+    type Output = Result<geo::MultiPolygon>;
+
+    fn unary_union(&self) -> Self::Output {
+        unary_union(self.iter_geo().enumerate())
+    }
+}
+
+impl UnaryUnion for &dyn GeometryArrayTrait {
+    type Output = Result<geo::MultiPolygon>;
+
+    fn unary_union(&self) -> Self::Output {
+        match self.data_type() {
+            GeoDataType::Polygon(_) => self.as_polygon().unary_union(),
+            GeoDataType::LargePolygon(_) => self.as_large_polygon().unary_union(),
+            GeoDataType::MultiPolygon(_) => self.as_multi_polygon().unary_union(),
+            GeoDataType::LargeMultiPolygon(_) => self.as_large_multi_polygon().unary_union(),
+            _ => Err(GeoArrowError::IncorrectType("".into())),
+        }
+    }
+}
+
+impl<O: OffsetSizeTrait> UnaryUnion for ChunkedPolygonArray<O> {
+    type Output = Result<geo::MultiPolygon>;
+
+    fn unary_union(&self) -> Self::Output {
+        unary_union(
+            self.map_flat(|chunk| {
+                chunk
+                    .iter_geo()
+                    .map(|maybe_polygon| maybe_polygon.map(geo::MultiPolygon::from))
+                    .collect::<Vec<_>>()
+            })
+            .into_iter()
+            .enumerate(),
+        )
+    }
+}
+
+impl<O: OffsetSizeTrait> UnaryUnion for ChunkedMultiPolygonArray<O> {
+    type Output = Result<geo::MultiPolygon>;
+
+    fn unary_union(&self) -> Self::Output {
+        unary_union(
+            self.map_flat(|chunk| chunk.iter_geo().collect::<Vec<_>>())
+                .into_iter()
+                .enumerate(),
+        )
+    }
+}
+
+impl UnaryUnion for &dyn ChunkedGeometryArrayTrait {
+    type Output = Result<geo::MultiPolygon>;
+
+    fn unary_union(&self) -> Self::Output {
+        match self.data_type() {
+            GeoDataType::Polygon(_) => self.as_polygon().unary_union(),
+            GeoDataType::LargePolygon(_) => self.as_large_polygon().unary_union(),
+            GeoDataType::MultiPolygon(_) => self.as_multi_polygon().unary_union(),
+            GeoDataType::LargeMultiPolygon(_) => self.as_large_multi_polygon().unary_union(),
+            _ => Err(GeoArrowError::IncorrectType("".into())),
+        }
+    }
+}
+
+/// Union the polygons of each row, given with its row index. Null rows are skipped.
+pub(crate) fn unary_union(
+    geoms: impl IntoIterator<Item = (usize, Option<geo::MultiPolygon>)>,
+) -> Result<geo::MultiPolygon> {
+    let mut parts = geoms
+        .into_iter()
+        .filter_map(|(row, maybe_multi_polygon)| {
+            maybe_multi_polygon.map(|multi_polygon| Part::try_new(row, multi_polygon))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    while parts.len() > 1 {
+        let mut unioned = Vec::with_capacity(parts.len().div_ceil(2));
+        let mut parts_iter = parts.into_iter();
+        while let Some(left) = parts_iter.next() {
+            match parts_iter.next() {
+                Some(right) => unioned.push(left.union(right)?),
+                None => unioned.push(left),
+            }
+        }
+        parts = unioned;
+    }
+
+    Ok(parts
+        .pop()
+        .map(|part| part.geometry)
+        .unwrap_or_else(|| geo::MultiPolygon::new(vec![])))
+}
+
+/// The union of the polygons of a range of rows.
+struct Part {
+    rows: Range<usize>,
+    geometry: geo::MultiPolygon,
+    bounds: Option<geo::Rect>,
+}
+
+impl Part {
+    fn try_new(row: usize, geometry: geo::MultiPolygon) -> Result<Self> {
+        let geometry = validate_polygons(row, geometry)?;
+        Ok(Self {
+            rows: row..row + 1,
+            bounds: geometry.bounding_rect(),
+            geometry,
+        })
+    }
+
+    fn union(self, other: Self) -> Result<Self> {
+        let rows = self.rows.start..other.rows.end;
+        let (bounds, geometry) = match (self.bounds, other.bounds) {
+            (Some(left), Some(right)) if left.intersects(&right) => {
+                let geometry =
+                    catch_unwind(AssertUnwindSafe(|| self.geometry.union(&other.geometry)))
+                        .map_err(|_| union_error(&rows))?;
+                (Some(merge_bounds(left, right)), geometry)
+            }
+            (left, right) => {
+                let bounds = match (left, right) {
+                    (Some(left), Some(right)) => Some(merge_bounds(left, right)),
+                    (left, right) => left.or(right),
+                };
+                let mut polygons = self.geometry.0;
+                polygons.extend(other.geometry.0);
+                (bounds, geo::MultiPolygon::new(polygons))
+            }
+        };
+
+        Ok(Self {
+            rows,
+            geometry,
+            bounds,
+        })
+    }
+}
+
+/// Remove the empty polygons of the row `row`, and check that the other polygons can be passed to
+/// the boolean operations.
+pub(super) fn validate_polygons(
+    row: usize,
+    mut geometry: geo::MultiPolygon,
+) -> Result<geo::MultiPolygon> {
+    geometry
+        .0
+        .retain(|polygon| !polygon.exterior().0.is_empty());
+    for polygon in geometry.iter() {
+        let rings = std::iter::once(polygon.exterior()).chain(polygon.interiors());
+        if let Some(reason) = rings.filter_map(invalid_ring_reason).next() {
+            return Err(GeoArrowError::General(format!(
+                "Invalid polygon at row {}: {}",
+                row, reason
+            )));
+        }
+    }
+    Ok(geometry)
+}
+
+/// Why a ring can't be passed to the boolean operations, if it can't.
+fn invalid_ring_reason(ring: &geo::LineString) -> Option<&'static str> {
+    if ring.0.len() < 4 {
+        Some("a ring has fewer than 4 coordinates")
+    } else if !ring.0.iter().all(|c| c.x.is_finite() && c.y.is_finite()) {
+        Some("a coordinate is not finite")
+    } else {
+        None
+    }
+}
+
+fn union_error(rows: &Range<usize>) -> GeoArrowError {
+    GeoArrowError::General(format!(
+        "Failed to union the polygons of rows {} to {}, which may be invalid",
+        rows.start,
+        rows.end - 1
+    ))
+}
+
+fn merge_bounds(left: geo::Rect, right: geo::Rect) -> geo::Rect {
+    geo::Rect::new(
+        geo::coord! { x: left.min().x.min(right.min().x), y: left.min().y.min(right.min().y) },
+        geo::coord! { x: left.max().x.max(right.max().x), y: left.max().y.max(right.max().y) },
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::trait_::GeometryArraySelfMethods;
+    use geo::{polygon, Area};
+
+    fn square(x: f64, y: f64) -> geo::Polygon {
+        polygon![(x: x, y: y), (x: x + 1., y: y), (x: x + 1., y: y + 1.), (x: x, y: y + 1.)]
+    }
+
+    #[test]
+    fn union_grid() {
+        let polygons = vec![
+            Some(square(0., 0.)),
+            Some(square(1., 0.)),
+            None,
+            Some(square(10., 10.)),
+            Some(square(0., 1.)),
+            Some(square(1., 1.)),
+            Some(square(0.5, 0.5)),
+        ];
+        let arr = PolygonBuilder::<i32>::from_nullable_polygons(
+            &polygons,
+            Default::default(),
+            Default::default(),
+        )
+        .finish();
+
+        let union = arr.unary_union().unwrap();
+        assert_eq!(union.0.len(), 2);
+        assert!((union.unsigned_area() - 5.).abs() < 1e-9);
+
+        let chunked = ChunkedGeometryArray::new_unchecked(vec![arr.slice(0, 3), arr.slice(3, 4)]);
+        let chunked_union = chunked.unary_union().unwrap();
+        assert!((chunked_union.unsigned_area() - 5.).abs() < 1e-9);
+
+        let empty = PolygonBuilder::<i32>::new().finish();
+        assert!(empty.unary_union().unwrap().0.is_empty());
+    }
+
+    #[test]
+    fn invalid_polygon() {
+        let polygons = vec![
+            square(0., 0.),
+            square(1., 0.),
+            polygon![(x: 0., y: 0.), (x: f64::NAN, y: 0.), (x: 1., y: 1.)],
+        ];
+        let arr: PolygonArray<i32> = polygons.as_slice().into();
+        let err = arr.unary_union().unwrap_err();
+        assert!(err.to_string().contains("row 2"), "{}", err);
+
+        let dyn_arr: &dyn GeometryArrayTrait = &arr;
+        assert!(dyn_arr.unary_union().is_err());
+    }
+}
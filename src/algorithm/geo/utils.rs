@@ -1,7 +1,170 @@
-use arrow_array::Float64Array;
+use arrow_array::types::Float64Type;
+use arrow_array::{Array, Float64Array, OffsetSizeTrait};
 use arrow_buffer::NullBuffer;
+use geo::AffineTransform;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::algorithm::broadcasting::BroadcastablePrimitive;
+use crate::algorithm::geo::affine_ops::AffineTransformable;
+use crate::array::{LineStringArray, PointArray};
+use crate::chunked_array::ChunkedGeometryArrayTrait;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::GeometryArrayAccessor;
 
 pub(crate) fn zeroes(len: usize, nulls: Option<&NullBuffer>) -> Float64Array {
     let values = vec![0.0f64; len];
     Float64Array::new(values.into(), nulls.cloned())
 }
+
+/// Iterate over the value of a broadcast parameter for each of `len` geometries.
+///
+/// # Panics
+///
+/// Panics if the parameter is an array without `len` values.
+pub(crate) fn broadcast_values(
+    len: usize,
+    param: &BroadcastablePrimitive<Float64Type>,
+) -> impl Iterator<Item = Option<f64>> + '_ {
+    if let BroadcastablePrimitive::Array(arr) = param {
+        assert_eq!(arr.len(), len, "Expected one parameter per geometry");
+    }
+    param.into_iter()
+}
+
+/// Build one affine transform per geometry from broadcast parameters.
+///
+/// `build` is called with the row index and the parameters of each row, and the transform is
+/// `None` where any parameter is null or `build` returns `None`, so that the geometry becomes
+/// null.
+///
+/// # Panics
+///
+/// Panics if a parameter array doesn't have `len` values.
+pub(crate) fn broadcast_transforms<const N: usize>(
+    len: usize,
+    params: [&BroadcastablePrimitive<Float64Type>; N],
+    build: impl Fn(usize, [f64; N]) -> Option<AffineTransform>,
+) -> Vec<Option<AffineTransform>> {
+    let mut iters = params.map(|param| broadcast_values(len, param));
+    (0..len)
+        .map(|row| {
+            let mut values = [0.; N];
+            for (value, iter) in values.iter_mut().zip(iters.iter_mut()) {
+                *value = iter.next().flatten()?;
+            }
+            build(row, values)
+        })
+        .collect()
+}
+
+/// The point that a scale, skew or rotation is applied around.
+pub(crate) enum TransformOrigin {
+    /// The same point for every geometry
+    Point(geo::Point),
+    /// One point per geometry, such as its centroid. Geometries with a null origin, such as empty
+    /// geometries, are left unchanged.
+    PerGeometry(PointArray),
+}
+
+/// Apply an affine transform built from broadcast parameters and an origin to each geometry.
+///
+/// When every parameter is a scalar and the origin is a single point, a single transform is
+/// applied to the whole array.
+pub(crate) fn broadcast_affine_transform<G: AffineTransformable, const N: usize>(
+    array: &G,
+    params: [&BroadcastablePrimitive<Float64Type>; N],
+    origin: TransformOrigin,
+    build: impl Fn([f64; N], geo::Point) -> AffineTransform,
+) -> G {
+    match origin {
+        TransformOrigin::Point(origin) => {
+            let mut scalars = [0.; N];
+            let all_scalar = scalars.iter_mut().zip(params).all(|(value, param)| {
+                if let BroadcastablePrimitive::Scalar(scalar) = param {
+                    *value = *scalar;
+                    true
+                } else {
+                    false
+                }
+            });
+            if all_scalar {
+                return array.affine_transform(&build(scalars, origin));
+            }
+
+            let transforms =
+                broadcast_transforms(array.len(), params, |_, values| Some(build(values, origin)));
+            array.affine_transform(transforms.as_slice())
+        }
+        TransformOrigin::PerGeometry(origins) => {
+            let transforms = broadcast_transforms(array.len(), params, |row, values| {
+                Some(
+                    origins
+                        .get_as_geo(row)
+                        .map(|origin| build(values, origin))
+                        .unwrap_or_else(AffineTransform::identity),
+                )
+            });
+            array.affine_transform(transforms.as_slice())
+        }
+    }
+}
+
+/// Compute a distance between each pair of line strings of two arrays of the same length.
+///
+/// The distance is null where either line string is null or empty. With `parallel` and the
+/// `rayon` feature, rows are computed in parallel, which is worth it for kernels that are
+/// quadratic in the number of coordinates, like the Fréchet distance.
+pub(crate) fn line_string_distances<O1: OffsetSizeTrait, O2: OffsetSizeTrait>(
+    left: &LineStringArray<O1>,
+    right: &LineStringArray<O2>,
+    parallel: bool,
+    op: impl Fn(&geo::LineString, &geo::LineString) -> f64 + Sync,
+) -> Result<Float64Array> {
+    if left.len() != right.len() {
+        return Err(GeoArrowError::General(
+            "Cannot perform binary operation on arrays of different length".to_string(),
+        ));
+    }
+
+    let pairs = left
+        .iter_geo()
+        .zip(right.iter_geo())
+        .map(|pair| match pair {
+            (Some(left), Some(right)) if !left.0.is_empty() && !right.0.is_empty() => {
+                Some((left, right))
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let distance = |pair: &Option<(geo::LineString, geo::LineString)>| {
+        pair.as_ref().map(|(left, right)| op(left, right))
+    };
+
+    #[cfg(feature = "rayon")]
+    if parallel {
+        let distances: Vec<Option<f64>> = pairs.par_iter().map(distance).collect();
+        return Ok(distances.into());
+    }
+    #[cfg(not(feature = "rayon"))]
+    let _ = parallel;
+
+    let distances: Vec<Option<f64>> = pairs.iter().map(distance).collect();
+    Ok(distances.into())
+}
+
+/// Check that two chunked arrays have chunks of the same lengths, so that their chunks can be
+/// zipped.
+pub(crate) fn check_aligned_chunks(
+    left: &dyn ChunkedGeometryArrayTrait,
+    right: &dyn ChunkedGeometryArrayTrait,
+) -> Result<()> {
+    let left_lengths = left.geometry_chunks().into_iter().map(|chunk| chunk.len());
+    if !left_lengths.eq(right.geometry_chunks().into_iter().map(|chunk| chunk.len())) {
+        return Err(GeoArrowError::General(
+            "Cannot perform binary operation on chunked arrays with different chunk lengths"
+                .to_string(),
+        ));
+    }
+    Ok(())
+}
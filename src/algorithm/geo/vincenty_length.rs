@@ -12,6 +12,10 @@ use geo::VincentyLength as _VincentyLength;
 /// Determine the length of a geometry using [Vincenty’s formulae].
 ///
 /// [Vincenty’s formulae]: https://en.wikipedia.org/wiki/Vincenty%27s_formulae
+///
+/// Supports the same array types as [`EuclideanLength`][crate::algorithm::geo::EuclideanLength]:
+/// points and multi points have a length of zero, while polygon, mixed and geometry collection
+/// arrays are unsupported.
 pub trait VincentyLength {
     type Output;
 
@@ -114,7 +118,9 @@ impl VincentyLength for &dyn GeometryArrayTrait {
             // GeoDataType::LargeGeometryCollection(_) => {
             //     self.as_large_geometry_collection().vincenty_length()
             // }
-            _ => Err(GeoArrowError::IncorrectType("".into())),
+            _ => Err(GeoArrowError::IncorrectType(
+                format!("Vincenty length of {:?} is not supported", self.data_type()).into(),
+            )),
         }
     }
 }
@@ -123,7 +129,8 @@ impl VincentyLength for ChunkedGeometryArray<PointArray> {
     type Output = Result<ChunkedArray<Float64Array>>;
 
     fn vincenty_length(&self) -> Self::Output {
-        self.try_map(|chunk| chunk.vincenty_length())?.try_into()
+        self.try_map_non_null(|chunk| chunk.vincenty_length())?
+            .try_into()
     }
 }
 
@@ -134,7 +141,8 @@ macro_rules! chunked_impl {
             type Output = Result<ChunkedArray<Float64Array>>;
 
             fn vincenty_length(&self) -> Self::Output {
-                self.try_map(|chunk| chunk.vincenty_length())?.try_into()
+                self.try_map_non_null(|chunk| chunk.vincenty_length())?
+                    .try_into()
             }
         }
     };
@@ -168,7 +176,9 @@ impl VincentyLength for &dyn ChunkedGeometryArrayTrait {
             // GeoDataType::LargeGeometryCollection(_) => {
             //     self.as_large_geometry_collection().vincenty_length()
             // }
-            _ => Err(GeoArrowError::IncorrectType("".into())),
+            _ => Err(GeoArrowError::IncorrectType(
+                format!("Vincenty length of {:?} is not supported", self.data_type()).into(),
+            )),
         }
     }
 }
@@ -1,3 +1,8 @@
+mod nearest;
 pub mod rtree;
+pub mod spatial_index;
 
+pub(crate) use nearest::NearestSearch;
+pub use nearest::nearest;
 pub use rtree::RTree;
+pub use spatial_index::SpatialIndex;
@@ -0,0 +1,239 @@
+//! Nearest neighbor queries over a geometry column, using a [`SpatialIndex`].
+
+use std::cmp::Ordering;
+
+use arrow_array::builder::{Float64Builder, ListBuilder, UInt32Builder};
+use arrow_array::ListArray;
+use geo::{coord, EuclideanDistance, Rect};
+
+use crate::algorithm::geo::BoundingRect;
+use crate::algorithm::geo_index::SpatialIndex;
+use crate::array::{geometry_at, PointArray};
+use crate::error::Result;
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+
+/// Find the `k` geometries of `right` nearest to each point of `left`.
+///
+/// Returns two list arrays with a row per point: the indices of the nearest geometries of
+/// `right`, nearest first, and their distances. Geometries at the same distance are ordered by
+/// index, so ties always resolve to the lower index. If `max_distance` is provided, geometries
+/// further away are left out, so a row can have fewer than `k` entries. Null points have null
+/// rows, and null or empty geometries of `right` are never returned.
+///
+/// Distances are planar (Euclidean) distances in the units of the coordinates, which is only
+/// meaningful for projected coordinates. The geometries of `right` are indexed with a
+/// [`SpatialIndex`], which is searched in windows of growing size around each point.
+pub fn nearest(
+    left: &PointArray,
+    right: &dyn GeometryArrayTrait,
+    k: usize,
+    max_distance: Option<f64>,
+) -> Result<(ListArray, ListArray)> {
+    let search = NearestSearch::new(
+        SpatialIndex::try_new(right)?,
+        (0..right.len()).map(|i| geometry_at(right, i)).collect(),
+        BoundingRect::total_bounds(&right),
+    );
+
+    let mut indices = ListBuilder::with_capacity(UInt32Builder::new(), left.len());
+    let mut distances = ListBuilder::with_capacity(Float64Builder::new(), left.len());
+    for maybe_point in left.iter_geo() {
+        match maybe_point {
+            Some(point) => {
+                for (index, distance) in search.query(&point, k, max_distance) {
+                    indices.values().append_value(index as u32);
+                    distances.values().append_value(distance);
+                }
+                indices.append(true);
+                distances.append(true);
+            }
+            None => {
+                indices.append_null();
+                distances.append_null();
+            }
+        }
+    }
+    Ok((indices.finish(), distances.finish()))
+}
+
+/// The nearest neighbors of points among the geometries of an indexed column.
+pub(crate) struct NearestSearch {
+    index: SpatialIndex,
+    geometries: Vec<Option<geo::Geometry>>,
+    /// The extent of the geometries as `[minx, miny, maxx, maxy]`, or `None` if they are all null
+    /// or empty.
+    bounds: Option<[f64; 4]>,
+}
+
+impl NearestSearch {
+    /// Create a search over `geometries`, which must be the rows of the column `index` was built
+    /// from, with `bounds` as their extent.
+    pub(crate) fn new(
+        index: SpatialIndex,
+        geometries: Vec<Option<geo::Geometry>>,
+        bounds: Option<[f64; 4]>,
+    ) -> Self {
+        Self {
+            index,
+            geometries,
+            bounds,
+        }
+    }
+
+    /// The indices and distances of the `k` geometries nearest to `point`, nearest first.
+    ///
+    /// Any geometry within distance `r` of the point has a bounding box that intersects the
+    /// square window of half-width `r` around it. So once a window holds `k` geometries within
+    /// its half-width, they are the nearest ones. Otherwise the window is doubled, until it
+    /// reaches `max_distance` or covers the extent of all geometries.
+    pub(crate) fn query(
+        &self,
+        point: &geo::Point,
+        k: usize,
+        max_distance: Option<f64>,
+    ) -> Vec<(usize, f64)> {
+        let Some([minx, miny, maxx, maxy]) = self.bounds else {
+            return vec![];
+        };
+        if k == 0 {
+            return vec![];
+        }
+
+        // The half-width of a window around the point that covers all geometries
+        let reach = [
+            point.x() - minx,
+            maxx - point.x(),
+            point.y() - miny,
+            maxy - point.y(),
+        ]
+        .into_iter()
+        .fold(0., f64::max);
+        let limit = max_distance.map_or(reach, |max_distance| max_distance.min(reach));
+
+        // A window that would hold about k geometries if they were evenly spread
+        let area = (maxx - minx) * (maxy - miny);
+        let mut radius = (area * k as f64 / self.index.len() as f64).sqrt() / 2.;
+        if radius.is_nan() || radius <= 0. {
+            radius = reach / 1024.;
+        }
+
+        loop {
+            let is_last = radius >= limit;
+            let half_width = if is_last { limit } else { radius };
+            let window = Rect::new(
+                coord! { x: point.x() - half_width, y: point.y() - half_width },
+                coord! { x: point.x() + half_width, y: point.y() + half_width },
+            );
+            let max_candidate_distance = if is_last {
+                max_distance.unwrap_or(f64::INFINITY)
+            } else {
+                half_width
+            };
+
+            let mut candidates = self
+                .index
+                .query_bbox(&window)
+                .into_iter()
+                .filter_map(|row| {
+                    let geometry = self.geometries[row].as_ref()?;
+                    let distance = point_distance(point, geometry);
+                    (distance <= max_candidate_distance).then_some((row, distance))
+                })
+                .collect::<Vec<_>>();
+
+            if is_last || candidates.len() >= k {
+                candidates.sort_by(|a, b| {
+                    a.1.partial_cmp(&b.1)
+                        .unwrap_or(Ordering::Equal)
+                        .then(a.0.cmp(&b.0))
+                });
+                candidates.truncate(k);
+                return candidates;
+            }
+            radius *= 2.;
+        }
+    }
+}
+
+/// The planar distance between a point and a geometry.
+fn point_distance(point: &geo::Point, geometry: &geo::Geometry) -> f64 {
+    match geometry {
+        geo::Geometry::Point(g) => point.euclidean_distance(g),
+        geo::Geometry::Line(g) => point.euclidean_distance(g),
+        geo::Geometry::LineString(g) => point.euclidean_distance(g),
+        geo::Geometry::Polygon(g) => point.euclidean_distance(g),
+        geo::Geometry::MultiPoint(g) => point.euclidean_distance(g),
+        geo::Geometry::MultiLineString(g) => point.euclidean_distance(g),
+        geo::Geometry::MultiPolygon(g) => point.euclidean_distance(g),
+        geo::Geometry::GeometryCollection(g) => g
+            .iter()
+            .map(|geometry| point_distance(point, geometry))
+            .fold(f64::INFINITY, f64::min),
+        geo::Geometry::Rect(g) => point.euclidean_distance(&g.to_polygon()),
+        geo::Geometry::Triangle(g) => point.euclidean_distance(&g.to_polygon()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Float64Type, UInt32Type};
+    use arrow_array::Array;
+
+    #[test]
+    fn nearest_points() {
+        let grid: Vec<geo::Point> = (0..100)
+            .map(|i| geo::point!(x: (i % 10) as f64, y: (i / 10) as f64))
+            .collect();
+        let right: PointArray = grid.as_slice().into();
+        let left: PointArray = vec![
+            Some(geo::point!(x: 2.1, y: 3.)),
+            None,
+            Some(geo::point!(x: 4.5, y: 20.)),
+            Some(geo::point!(x: -100., y: -100.)),
+        ]
+        .into();
+
+        let (indices, distances) = nearest(&left, &right, 2, None).unwrap();
+        assert_eq!(indices.len(), 4);
+        let row = |i: usize| {
+            let indices = indices.value(i);
+            indices.as_primitive::<UInt32Type>().values().to_vec()
+        };
+        assert_eq!(row(0), vec![32, 33]);
+        assert!(indices.is_null(1));
+        // (4, 9) and (5, 9) are at the same distance, so the lower index comes first
+        assert_eq!(row(2), vec![94, 95]);
+        assert_eq!(row(3), vec![0, 1]);
+        let first_distances = distances.value(0);
+        let first_distances = first_distances.as_primitive::<Float64Type>();
+        assert!((first_distances.value(0) - 0.1).abs() < 1e-12);
+        assert!((first_distances.value(1) - 0.9).abs() < 1e-12);
+
+        let (indices, _) = nearest(&left, &right, 1, Some(1.)).unwrap();
+        assert_eq!(indices.value(0).len(), 1);
+        assert_eq!(indices.value(2).len(), 0);
+        assert_eq!(indices.value(3).len(), 0);
+    }
+
+    #[test]
+    fn nearest_line() {
+        let right: crate::array::LineStringArray<i32> = vec![
+            geo::line_string![(x: 0., y: 0.), (x: 10., y: 0.)],
+            geo::line_string![(x: 0., y: 5.), (x: 10., y: 5.)],
+        ]
+        .as_slice()
+        .into();
+        let left: PointArray = vec![geo::point!(x: 5., y: 3.)].as_slice().into();
+        let (indices, distances) = nearest(&left, &right, 1, None).unwrap();
+        let indices = indices.value(0);
+        assert_eq!(
+            indices.as_primitive::<UInt32Type>().values().to_vec(),
+            vec![1]
+        );
+        let distances = distances.value(0);
+        assert_eq!(distances.as_primitive::<Float64Type>().value(0), 2.);
+    }
+}
@@ -90,6 +90,13 @@ impl_rtree!(
 impl RTree for &dyn GeometryArrayTrait {
     type Output = OwnedRTree<f64>;
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            skip_all,
+            fields(num_rows = self.len(), data_type = ?self.data_type(), node_size = node_size)
+        )
+    )]
     fn create_rtree_with_node_size(&self, node_size: usize) -> Self::Output {
         use GeoDataType::*;
 
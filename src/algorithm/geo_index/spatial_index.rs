@@ -0,0 +1,523 @@
+//! A static R-tree over a geometry column that can be cached alongside the data.
+
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Seek, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::{BinaryArray, RecordBatch};
+use arrow_ipc::reader::FileReader;
+use arrow_ipc::writer::FileWriter;
+use arrow_schema::{DataType, Field, Schema};
+use geo::{BoundingRect as _, Intersects};
+use geo_index::rtree::sort::HilbertSort;
+use geo_index::rtree::{RTreeBuilder, RTreeIndex, RTreeRef};
+
+use crate::algorithm::geo::BoundingRect;
+use crate::array::{geometry_at, RectArray};
+use crate::chunked_array::{ChunkedGeometryArray, ChunkedGeometryArrayTrait};
+use crate::error::{GeoArrowError, Result};
+use crate::geo_traits::{CoordTrait, RectTrait};
+use crate::GeometryArrayTrait;
+
+/// The first bytes of a serialized [`SpatialIndex`], ending with the version of the format.
+const MAGIC: &[u8; 8] = b"GARTREE\x01";
+
+/// The length of the header of a serialized [`SpatialIndex`]: the magic bytes, the number of
+/// rows and the hash of the bounds. The tree follows, aligned to 8 bytes.
+const HEADER_LEN: usize = 24;
+
+/// The number of children of each node of the tree.
+const NODE_SIZE: usize = 16;
+
+/// The name of the column holding a serialized [`SpatialIndex`] in an Arrow IPC file.
+const IPC_COLUMN_NAME: &str = "spatial_index";
+
+/// A static, packed Hilbert R-tree over the bounding boxes of a geometry column.
+///
+/// The tree uses the [flatbush] layout, which is a flat buffer of boxes and indices, so an index
+/// can be serialized with [`to_bytes`][Self::to_bytes] and loaded again with
+/// [`from_bytes`][Self::from_bytes] without rebuilding or parsing the nodes of the tree. This
+/// allows caching the index of a large table, for example in a
+/// [sidecar file][Self::write_sidecar] next to a GeoParquet file.
+///
+/// Serialized indexes record the number of rows and a hash of the bounding boxes of the column
+/// they were built from, so an index that is stale because the data changed can be detected.
+///
+/// Search results are row indices into the geometry column. Rows of chunked columns are numbered
+/// across chunks. Null and empty geometries are never returned.
+///
+/// [flatbush]: https://github.com/mourner/flatbush
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpatialIndex {
+    /// The flatbush buffer of the tree, empty if there are no rows.
+    tree: Vec<u8>,
+    num_rows: usize,
+    bounds_hash: u64,
+}
+
+impl SpatialIndex {
+    /// Build an index over the geometries of an array.
+    pub fn try_new(array: &dyn GeometryArrayTrait) -> Result<Self> {
+        Ok(Self::from_rects(&[array.bounding_rect()?]))
+    }
+
+    /// Build an index over the geometries of a chunked array.
+    pub fn try_new_chunked(array: &dyn ChunkedGeometryArrayTrait) -> Result<Self> {
+        Ok(Self::from_rects(array.bounding_rect()?.chunks()))
+    }
+
+    fn from_rects(chunks: &[RectArray]) -> Self {
+        let num_rows = chunks.iter().map(|chunk| chunk.len()).sum();
+        let bounds_hash = bounds_hash(chunks);
+        if num_rows == 0 {
+            return Self {
+                tree: vec![],
+                num_rows,
+                bounds_hash,
+            };
+        }
+
+        let mut builder = RTreeBuilder::<f64>::new_with_node_size(num_rows, NODE_SIZE);
+        for rect in chunks.iter().flat_map(|chunk| chunk.iter()) {
+            match rect {
+                Some(rect) => builder.add(
+                    rect.lower().x(),
+                    rect.lower().y(),
+                    rect.upper().x(),
+                    rect.upper().y(),
+                ),
+                // An inverted box is never within a search box, nor does it widen its parent node
+                None => builder.add(f64::INFINITY, f64::INFINITY, -f64::INFINITY, -f64::INFINITY),
+            };
+        }
+
+        Self {
+            tree: builder.finish::<HilbertSort>().into_inner(),
+            num_rows,
+            bounds_hash,
+        }
+    }
+
+    /// The number of rows of the geometry column this index was built from.
+    pub fn len(&self) -> usize {
+        self.num_rows
+    }
+
+    /// Whether the geometry column this index was built from has no rows.
+    pub fn is_empty(&self) -> bool {
+        self.num_rows == 0
+    }
+
+    /// The rows whose bounding box intersects the given box.
+    pub fn search(&self, min_x: f64, min_y: f64, max_x: f64, max_y: f64) -> Vec<usize> {
+        if self.tree.is_empty() {
+            return vec![];
+        }
+
+        // The buffer was checked when the index was built or loaded
+        let tree = RTreeRef::<f64>::try_new(&self.tree).unwrap();
+        tree.search(min_x, min_y, max_x, max_y)
+    }
+
+    /// The rows whose bounding box intersects `rect`, in ascending order.
+    pub fn query_bbox(&self, rect: &geo::Rect) -> Vec<usize> {
+        let mut rows = self.search(rect.min().x, rect.min().y, rect.max().x, rect.max().y);
+        rows.sort_unstable();
+        rows
+    }
+
+    /// The rows of `array` whose geometry intersects `geometry`, in ascending order.
+    ///
+    /// The rows whose bounding box intersects that of `geometry` are found with the index, and
+    /// then tested with [`Intersects`][geo::Intersects]. `array` must be the geometry column this
+    /// index was built from; only its number of rows is checked, as with
+    /// [`from_bytes`][Self::from_bytes].
+    pub fn query_geometry(
+        &self,
+        array: &dyn ChunkedGeometryArrayTrait,
+        geometry: &geo::Geometry,
+    ) -> Result<Vec<usize>> {
+        check_len(self.num_rows, array.len())?;
+        let Some(rect) = geometry.bounding_rect() else {
+            return Ok(vec![]);
+        };
+
+        let chunks = array.geometry_chunks();
+        let mut chunk_starts = Vec::with_capacity(chunks.len());
+        let mut len = 0;
+        for chunk in chunks.iter() {
+            chunk_starts.push(len);
+            len += chunk.len();
+        }
+
+        let rows = self
+            .query_bbox(&rect)
+            .into_iter()
+            .filter(|row| {
+                let chunk_index = chunk_starts.partition_point(|start| start <= row) - 1;
+                let offset = row - chunk_starts[chunk_index];
+                geometry_at(chunks[chunk_index], offset)
+                    .is_some_and(|candidate| candidate.intersects(geometry))
+            })
+            .collect();
+        Ok(rows)
+    }
+
+    /// Check that this index was built from the given geometry column, by comparing the number of
+    /// rows and the hash of the bounding boxes of its geometries.
+    ///
+    /// This computes the bounding boxes of the column, which is much faster than building the
+    /// index, but not free. [`from_bytes`][Self::from_bytes] only checks the number of rows.
+    pub fn check_source(&self, array: &dyn ChunkedGeometryArrayTrait) -> Result<()> {
+        check_len(self.num_rows, array.len())?;
+        let rects: ChunkedGeometryArray<RectArray> = array.bounding_rect()?;
+        if bounds_hash(rects.chunks()) != self.bounds_hash {
+            return Err(GeoArrowError::General(
+                "Stale spatial index: the bounds of the geometries have changed since it was built"
+                    .to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Serialize this index to bytes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_LEN + self.tree.len());
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&(self.num_rows as u64).to_le_bytes());
+        bytes.extend_from_slice(&self.bounds_hash.to_le_bytes());
+        bytes.extend_from_slice(&self.tree);
+        bytes
+    }
+
+    /// Load an index serialized with [`to_bytes`][Self::to_bytes], checking that it was built
+    /// from a geometry column of `expected_len` rows.
+    ///
+    /// The tree is copied as is, without visiting its nodes, so loading takes about as long as
+    /// copying the bytes.
+    pub fn from_bytes(bytes: &[u8], expected_len: usize) -> Result<Self> {
+        if bytes.len() < HEADER_LEN || &bytes[..MAGIC.len()] != MAGIC {
+            return Err(GeoArrowError::General(
+                "Invalid spatial index: missing header".to_string(),
+            ));
+        }
+        let num_rows = u64::from_le_bytes(bytes[8..16].try_into().unwrap()) as usize;
+        let bounds_hash = u64::from_le_bytes(bytes[16..24].try_into().unwrap());
+        check_len(num_rows, expected_len)?;
+
+        let tree = bytes[HEADER_LEN..].to_vec();
+        if num_rows == 0 && !tree.is_empty() {
+            return Err(GeoArrowError::General(
+                "Invalid spatial index: unexpected tree for an empty column".to_string(),
+            ));
+        }
+        if num_rows > 0 {
+            check_tree(&tree, num_rows)?;
+        }
+
+        Ok(Self {
+            tree,
+            num_rows,
+            bounds_hash,
+        })
+    }
+
+    /// Write this index to an Arrow IPC file, as a single binary value.
+    pub fn write_ipc<W: Write>(&self, writer: W) -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            IPC_COLUMN_NAME,
+            DataType::Binary,
+            false,
+        )]));
+        let column = BinaryArray::from_vec(vec![self.to_bytes().as_slice()]);
+        let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(column)])?;
+
+        let mut writer = FileWriter::try_new(writer, &schema)?;
+        writer.write(&batch)?;
+        writer.finish()?;
+        Ok(())
+    }
+
+    /// Read an index written with [`write_ipc`][Self::write_ipc], checking that it was built from
+    /// a geometry column of `expected_len` rows.
+    pub fn read_ipc<R: Read + Seek>(reader: R, expected_len: usize) -> Result<Self> {
+        let mut reader = FileReader::try_new(reader, None)?;
+        let batch = reader.next().transpose()?.ok_or_else(|| {
+            GeoArrowError::General("Invalid spatial index: empty IPC file".to_string())
+        })?;
+        let column = batch
+            .column_by_name(IPC_COLUMN_NAME)
+            .filter(|column| column.data_type() == &DataType::Binary && column.len() == 1)
+            .ok_or_else(|| {
+                GeoArrowError::General(format!(
+                    "Invalid spatial index: expected a single binary value in a {} column",
+                    IPC_COLUMN_NAME
+                ))
+            })?;
+        Self::from_bytes(column.as_binary::<i32>().value(0), expected_len)
+    }
+
+    /// The path of the sidecar file holding the index of the data at `data_path`, which is
+    /// `data_path` with `.rtree` appended, as in `nybb.parquet.rtree`.
+    pub fn sidecar_path(data_path: impl AsRef<Path>) -> PathBuf {
+        let mut path = data_path.as_ref().as_os_str().to_owned();
+        path.push(".rtree");
+        path.into()
+    }
+
+    /// Write this index to the [sidecar file][Self::sidecar_path] of the data at `data_path`,
+    /// such as a GeoParquet file.
+    pub fn write_sidecar(&self, data_path: impl AsRef<Path>) -> Result<()> {
+        let mut writer = BufWriter::new(File::create(Self::sidecar_path(data_path))?);
+        writer.write_all(&self.to_bytes())?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Read the index in the [sidecar file][Self::sidecar_path] of the data at `data_path`,
+    /// checking that it was built from a geometry column of `expected_len` rows.
+    pub fn read_sidecar(data_path: impl AsRef<Path>, expected_len: usize) -> Result<Self> {
+        let mut bytes = vec![];
+        BufReader::new(File::open(Self::sidecar_path(data_path))?).read_to_end(&mut bytes)?;
+        Self::from_bytes(&bytes, expected_len)
+    }
+}
+
+fn check_len(num_rows: usize, expected_len: usize) -> Result<()> {
+    if num_rows != expected_len {
+        return Err(GeoArrowError::General(format!(
+            "Stale spatial index: built for {} rows, but the geometry column has {} rows",
+            num_rows, expected_len
+        )));
+    }
+    Ok(())
+}
+
+/// Check that a flatbush buffer holds a tree of `num_rows` items, and has the length implied by
+/// its header, so that searching it can't read out of bounds.
+fn check_tree(tree: &[u8], num_rows: usize) -> Result<()> {
+    let invalid =
+        |message: String| GeoArrowError::General(format!("Invalid spatial index: {}", message));
+    if tree.len() < 8 {
+        return Err(invalid("truncated tree header".to_string()));
+    }
+    let node_size = u16::from_le_bytes([tree[2], tree[3]]) as usize;
+    let num_items = u32::from_le_bytes([tree[4], tree[5], tree[6], tree[7]]) as usize;
+    if num_items != num_rows {
+        return Err(invalid(format!(
+            "the header has {} rows, but the tree has {}",
+            num_rows, num_items
+        )));
+    }
+    if node_size < 2 {
+        return Err(invalid(format!("node size of {}", node_size)));
+    }
+
+    // As in flatbush, every level of the tree has a node for each `node_size` nodes below it
+    let mut num_nodes = num_items;
+    let mut level_len = num_items;
+    loop {
+        level_len = level_len.div_ceil(node_size);
+        num_nodes += level_len;
+        if level_len == 1 {
+            break;
+        }
+    }
+    let index_size = if num_nodes < 16384 { 2 } else { 4 };
+    let expected_len = 8 + num_nodes * (4 * std::mem::size_of::<f64>() + index_size);
+    if tree.len() != expected_len {
+        return Err(invalid(format!(
+            "expected a tree of {} bytes, got {}",
+            expected_len,
+            tree.len()
+        )));
+    }
+
+    RTreeRef::<f64>::try_new(&tree).map_err(|err| invalid(err.to_string()))?;
+    Ok(())
+}
+
+/// A 64-bit FNV-1a hash of the number of rows and the bounding box of each row.
+fn bounds_hash(chunks: &[RectArray]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+
+    let mut hash = OFFSET_BASIS;
+    let mut write = |value: u64| {
+        for byte in value.to_le_bytes() {
+            hash = (hash ^ byte as u64).wrapping_mul(PRIME);
+        }
+    };
+
+    let num_rows: usize = chunks.iter().map(|chunk| chunk.len()).sum();
+    write(num_rows as u64);
+    for rect in chunks.iter().flat_map(|chunk| chunk.iter()) {
+        match rect {
+            Some(rect) => {
+                write(rect.lower().x().to_bits());
+                write(rect.lower().y().to_bits());
+                write(rect.upper().x().to_bits());
+                write(rect.upper().y().to_bits());
+            }
+            None => write(u64::MAX),
+        }
+    }
+    hash
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::array::{MultiPolygonArray, PointArray};
+    use crate::io::ipc::read_ipc;
+    use crate::test::multipolygon;
+    use crate::trait_::GeometryArraySelfMethods;
+
+    fn points() -> PointArray {
+        let points: Vec<Option<geo::Point>> = (0..1000)
+            .map(|i| (i % 7 != 0).then(|| geo::point!(x: (i % 40) as f64, y: (i / 40) as f64)))
+            .collect();
+        points.into()
+    }
+
+    #[test]
+    fn round_trip() {
+        let arr = points();
+        let index = SpatialIndex::try_new(&arr).unwrap();
+        assert_eq!(index.len(), 1000);
+
+        let loaded = SpatialIndex::from_bytes(&index.to_bytes(), 1000).unwrap();
+        assert_eq!(loaded, index);
+        for query in [
+            [0., 0., 5., 5.],
+            [10.5, 3., 20., 12.],
+            [-1., -1., 100., 100.],
+        ] {
+            let [min_x, min_y, max_x, max_y] = query;
+            let mut expected = index.search(min_x, min_y, max_x, max_y);
+            let mut result = loaded.search(min_x, min_y, max_x, max_y);
+            expected.sort();
+            result.sort();
+            assert_eq!(result, expected);
+        }
+
+        // Null rows are never returned
+        let mut all = loaded.search(-1., -1., 100., 100.);
+        all.sort();
+        let valid: Vec<usize> = (0..1000).filter(|i| i % 7 != 0).collect();
+        assert_eq!(all, valid);
+    }
+
+    #[test]
+    fn stale_index() {
+        let arr = points();
+        let index = SpatialIndex::try_new(&arr).unwrap();
+        let bytes = index.to_bytes();
+
+        let err = SpatialIndex::from_bytes(&bytes, 999).unwrap_err();
+        assert!(err.to_string().contains("built for 1000 rows"), "{}", err);
+
+        let chunked = ChunkedGeometryArray::new_unchecked(vec![arr]);
+        index.check_source(&chunked).unwrap();
+
+        let moved: Vec<geo::Point> = (0..1000).map(|i| geo::point!(x: i as f64, y: 0.)).collect();
+        let moved: PointArray = moved.as_slice().into();
+        let err = index
+            .check_source(&ChunkedGeometryArray::new_unchecked(vec![moved]))
+            .unwrap_err();
+        assert!(err.to_string().contains("bounds"), "{}", err);
+
+        assert!(SpatialIndex::from_bytes(&bytes[..HEADER_LEN - 1], 1000).is_err());
+        assert!(SpatialIndex::from_bytes(&bytes[..bytes.len() - 8], 1000).is_err());
+    }
+
+    #[test]
+    fn chunked_row_indices() {
+        let arr: MultiPolygonArray<i32> = multipolygon::mp_array();
+        let chunked = ChunkedGeometryArray::new_unchecked(vec![arr.clone(), arr]);
+        let index = SpatialIndex::try_new_chunked(&chunked).unwrap();
+        assert_eq!(index.len(), 4);
+
+        let mut rows = index.search(f64::MIN, f64::MIN, f64::MAX, f64::MAX);
+        rows.sort();
+        assert_eq!(rows, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn query_geometry() {
+        let arr = points();
+        let index = SpatialIndex::try_new(&arr).unwrap();
+        let (first, second) = (arr.slice(0, 400), arr.slice(400, 600));
+        let chunked = ChunkedGeometryArray::new_unchecked(vec![first, second]);
+
+        let rect = geo::Rect::new(
+            geo::coord! { x: 0., y: 10. },
+            geo::coord! { x: 39., y: 12. },
+        );
+        let bbox_rows = index.query_bbox(&rect);
+        let expected: Vec<usize> = (400..520).filter(|i| i % 7 != 0).collect();
+        assert_eq!(bbox_rows, expected);
+
+        // Three of the points in the bounding box of the triangle lie above its diagonal edge
+        let triangle: geo::Geometry = geo::polygon![
+            (x: 0., y: 10.),
+            (x: 2., y: 12.),
+            (x: 2., y: 10.),
+            (x: 0., y: 10.),
+        ]
+        .into();
+        let rows = index.query_geometry(&chunked, &triangle).unwrap();
+        let expected: Vec<usize> = [400, 401, 402, 441, 442, 482]
+            .into_iter()
+            .filter(|i| i % 7 != 0)
+            .collect();
+        assert_eq!(rows, expected);
+
+        let err = index
+            .query_geometry(
+                &ChunkedGeometryArray::new_unchecked(vec![arr.slice(0, 10)]),
+                &triangle,
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("built for 1000 rows"), "{}", err);
+    }
+
+    #[test]
+    fn ipc_and_sidecar() {
+        let table = read_ipc(std::fs::File::open("fixtures/nybb.arrow").unwrap()).unwrap();
+        let geometry = table.geometry().unwrap();
+        let index = SpatialIndex::try_new_chunked(geometry.as_ref()).unwrap();
+
+        let mut buf = vec![];
+        index.write_ipc(&mut buf).unwrap();
+        let loaded = SpatialIndex::read_ipc(Cursor::new(buf), table.len()).unwrap();
+        assert_eq!(loaded, index);
+        loaded.check_source(geometry.as_ref()).unwrap();
+
+        let dir = std::env::temp_dir().join(format!("geoarrow-sidecar-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let data_path = dir.join("nybb.parquet");
+        assert_eq!(
+            SpatialIndex::sidecar_path(&data_path),
+            dir.join("nybb.parquet.rtree")
+        );
+        index.write_sidecar(&data_path).unwrap();
+        let loaded = SpatialIndex::read_sidecar(&data_path, table.len()).unwrap();
+        assert_eq!(loaded, index);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn empty() {
+        let arr: PointArray = Vec::<geo::Point>::new().as_slice().into();
+        let index = SpatialIndex::try_new(&arr).unwrap();
+        assert!(index.is_empty());
+        assert!(index.search(0., 0., 1., 1.).is_empty());
+        let loaded = SpatialIndex::from_bytes(&index.to_bytes(), 0).unwrap();
+        assert_eq!(loaded, index);
+    }
+}
@@ -89,7 +89,8 @@ impl<G: GeometryArrayTrait> Area for ChunkedGeometryArray<G> {
     type Output = Result<ChunkedArray<Float64Array>>;
 
     fn area(&self) -> Self::Output {
-        self.try_map(|chunk| chunk.as_ref().area())?.try_into()
+        self.try_map_non_null(|chunk| chunk.as_ref().area())?
+            .try_into()
     }
 }
 
@@ -1,104 +1,510 @@
-use crate::array::{PointArray, PolygonArray, PolygonBuilder};
-use crate::error::Result;
-use crate::io::geos::scalar::GEOSPolygon;
+use std::sync::Arc;
+
+use crate::algorithm::broadcasting::BroadcastablePrimitive;
+use crate::algorithm::geo::utils::broadcast_values;
+use crate::algorithm::native::MapChunks;
+use crate::array::metadata::ArrayMetadata;
+use crate::array::*;
+use crate::chunked_array::{ChunkedGeometryArray, ChunkedGeometryArrayTrait};
+use crate::chunked_array::{ChunkedMultiPolygonArray, ChunkedPointArray};
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::io::geos::scalar::{GEOSMultiPolygon, GEOSPolygon};
 use crate::trait_::{GeometryArrayAccessor, GeometryScalarTrait};
+use crate::GeometryArrayTrait;
+use arrow_array::types::Float64Type;
 use arrow_array::OffsetSizeTrait;
-use geos::{BufferParams, Geom};
+use geos::{BufferParams, Geom, GeometryTypes};
 
-pub trait Buffer<O: OffsetSizeTrait> {
+/// Buffer each geometry by a distance with GEOS.
+///
+/// The result is always a `MultiPolygon` array with the coord type and metadata of the input.
+/// Negative distances erode polygons, and a geometry that is eroded away, or a point or line
+/// buffered by a negative distance, becomes an empty `MultiPolygon`. The result is null where the
+/// geometry or the distance is null.
+///
+/// `distance` is either a single value for all geometries or an array with one value per
+/// geometry. For chunked arrays, a distance array is split at the chunk boundaries, and chunks are
+/// buffered in parallel when the `rayon` feature is enabled.
+pub trait Buffer {
     type Output;
 
-    fn buffer(&self, width: f64, quadsegs: i32) -> Self::Output;
+    /// Buffer with `quad_segs` segments per quarter circle for round caps and joins.
+    fn buffer(
+        &self,
+        distance: &BroadcastablePrimitive<Float64Type>,
+        quad_segs: usize,
+    ) -> Self::Output;
 
-    fn buffer_with_params(&self, width: f64, buffer_params: &BufferParams<'_>) -> Self::Output;
+    /// Buffer with the cap, join and quadrant segment options of `buffer_params`.
+    fn buffer_with_params(
+        &self,
+        distance: &BroadcastablePrimitive<Float64Type>,
+        buffer_params: &BufferParams<'_>,
+    ) -> Self::Output;
 }
 
-impl<O: OffsetSizeTrait> Buffer<O> for PointArray {
-    type Output = Result<PolygonArray<O>>;
+/// The options of a buffer operation.
+#[derive(Clone, Copy)]
+enum Options<'a, 'b> {
+    QuadSegs(i32),
+    Params(&'a BufferParams<'b>),
+}
 
-    fn buffer(&self, width: f64, quadsegs: i32) -> Self::Output {
-        let mut builder = PolygonBuilder::new();
+impl<'a, 'b> Options<'a, 'b> {
+    fn quad_segs(quad_segs: usize) -> Result<Self> {
+        let quad_segs = quad_segs.try_into().map_err(|_| {
+            GeoArrowError::General(format!(
+                "quad_segs must be at most {}, got {}",
+                i32::MAX,
+                quad_segs
+            ))
+        })?;
+        Ok(Self::QuadSegs(quad_segs))
+    }
+}
 
-        for maybe_g in self.iter() {
-            if let Some(g) = maybe_g {
-                let x = g.to_geos()?.buffer(width, quadsegs)?;
-                let polygon = GEOSPolygon::new_unchecked(x);
-                builder.push_polygon(Some(&polygon))?;
-            } else {
-                builder.push_null();
+/// Buffer each geometry of an iterator of scalars, pushing the results to a `MultiPolygon` array.
+fn buffer_geometries<O: OffsetSizeTrait, G: GeometryScalarTrait>(
+    geoms: impl Iterator<Item = Option<G>>,
+    len: usize,
+    coord_type: CoordType,
+    metadata: Arc<ArrayMetadata>,
+    distance: &BroadcastablePrimitive<Float64Type>,
+    options: Options,
+) -> Result<MultiPolygonArray<O>> {
+    let mut builder = MultiPolygonBuilder::<O>::new_with_options(coord_type, metadata);
+    for (maybe_g, distance) in geoms.zip(broadcast_values(len, distance)) {
+        match (maybe_g, distance) {
+            (Some(g), Some(distance)) => {
+                let geom = g.to_geos()?;
+                let buffered = match options {
+                    Options::QuadSegs(quad_segs) => geom.buffer(distance, quad_segs)?,
+                    Options::Params(params) => geom.buffer_with_params(distance, params)?,
+                };
+                match buffered.geometry_type() {
+                    GeometryTypes::Polygon => {
+                        builder.push_polygon(Some(&GEOSPolygon::new_unchecked(buffered)))?
+                    }
+                    GeometryTypes::MultiPolygon => builder
+                        .push_multi_polygon(Some(&GEOSMultiPolygon::new_unchecked(buffered)))?,
+                    geometry_type => {
+                        return Err(GeoArrowError::General(format!(
+                            "Expected a polygonal buffer, got {:?}",
+                            geometry_type
+                        )))
+                    }
+                }
             }
+            _ => builder.push_null(),
         }
+    }
+    Ok(builder.finish())
+}
 
-        Ok(builder.finish())
+// Note: this can't (easily) be parameterized in the macro because PointArray is not generic over O
+impl Buffer for PointArray {
+    type Output = Result<MultiPolygonArray<i32>>;
+
+    fn buffer(
+        &self,
+        distance: &BroadcastablePrimitive<Float64Type>,
+        quad_segs: usize,
+    ) -> Self::Output {
+        buffer_geometries(
+            self.iter(),
+            self.len(),
+            self.coord_type(),
+            self.metadata(),
+            distance,
+            Options::quad_segs(quad_segs)?,
+        )
     }
 
-    fn buffer_with_params(&self, width: f64, buffer_params: &BufferParams<'_>) -> Self::Output {
-        let mut builder = PolygonBuilder::new();
+    fn buffer_with_params(
+        &self,
+        distance: &BroadcastablePrimitive<Float64Type>,
+        buffer_params: &BufferParams<'_>,
+    ) -> Self::Output {
+        buffer_geometries(
+            self.iter(),
+            self.len(),
+            self.coord_type(),
+            self.metadata(),
+            distance,
+            Options::Params(buffer_params),
+        )
+    }
+}
 
-        for maybe_g in self.iter() {
-            if let Some(g) = maybe_g {
-                let x = g.to_geos()?.buffer_with_params(width, buffer_params)?;
-                let polygon = GEOSPolygon::new_unchecked(x);
-                builder.push_polygon(Some(&polygon))?;
-            } else {
-                builder.push_null();
+macro_rules! iter_geos_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> Buffer for $type {
+            type Output = Result<MultiPolygonArray<O>>;
+
+            fn buffer(
+                &self,
+                distance: &BroadcastablePrimitive<Float64Type>,
+                quad_segs: usize,
+            ) -> Self::Output {
+                buffer_geometries(
+                    self.iter(),
+                    self.len(),
+                    self.coord_type(),
+                    self.metadata(),
+                    distance,
+                    Options::quad_segs(quad_segs)?,
+                )
+            }
+
+            fn buffer_with_params(
+                &self,
+                distance: &BroadcastablePrimitive<Float64Type>,
+                buffer_params: &BufferParams<'_>,
+            ) -> Self::Output {
+                buffer_geometries(
+                    self.iter(),
+                    self.len(),
+                    self.coord_type(),
+                    self.metadata(),
+                    distance,
+                    Options::Params(buffer_params),
+                )
             }
         }
+    };
+}
+
+iter_geos_impl!(LineStringArray<O>);
+iter_geos_impl!(PolygonArray<O>);
+iter_geos_impl!(MultiPointArray<O>);
+iter_geos_impl!(MultiLineStringArray<O>);
+iter_geos_impl!(MultiPolygonArray<O>);
+iter_geos_impl!(MixedGeometryArray<O>);
+iter_geos_impl!(GeometryCollectionArray<O>);
+
+/// Arrays with `i64` offsets are buffered to a `LargeMultiPolygon` array, and other arrays to a
+/// `MultiPolygon` array.
+impl Buffer for &dyn GeometryArrayTrait {
+    type Output = Result<Arc<dyn GeometryArrayTrait>>;
+
+    fn buffer(
+        &self,
+        distance: &BroadcastablePrimitive<Float64Type>,
+        quad_segs: usize,
+    ) -> Self::Output {
+        let result: Arc<dyn GeometryArrayTrait> = match self.data_type() {
+            GeoDataType::Point(_) => Arc::new(self.as_point().buffer(distance, quad_segs)?),
+            GeoDataType::LineString(_) => {
+                Arc::new(self.as_line_string().buffer(distance, quad_segs)?)
+            }
+            GeoDataType::LargeLineString(_) => {
+                Arc::new(self.as_large_line_string().buffer(distance, quad_segs)?)
+            }
+            GeoDataType::Polygon(_) => Arc::new(self.as_polygon().buffer(distance, quad_segs)?),
+            GeoDataType::LargePolygon(_) => {
+                Arc::new(self.as_large_polygon().buffer(distance, quad_segs)?)
+            }
+            GeoDataType::MultiPoint(_) => {
+                Arc::new(self.as_multi_point().buffer(distance, quad_segs)?)
+            }
+            GeoDataType::LargeMultiPoint(_) => {
+                Arc::new(self.as_large_multi_point().buffer(distance, quad_segs)?)
+            }
+            GeoDataType::MultiLineString(_) => {
+                Arc::new(self.as_multi_line_string().buffer(distance, quad_segs)?)
+            }
+            GeoDataType::LargeMultiLineString(_) => Arc::new(
+                self.as_large_multi_line_string()
+                    .buffer(distance, quad_segs)?,
+            ),
+            GeoDataType::MultiPolygon(_) => {
+                Arc::new(self.as_multi_polygon().buffer(distance, quad_segs)?)
+            }
+            GeoDataType::LargeMultiPolygon(_) => {
+                Arc::new(self.as_large_multi_polygon().buffer(distance, quad_segs)?)
+            }
+            GeoDataType::Mixed(_) => Arc::new(self.as_mixed().buffer(distance, quad_segs)?),
+            GeoDataType::LargeMixed(_) => {
+                Arc::new(self.as_large_mixed().buffer(distance, quad_segs)?)
+            }
+            GeoDataType::GeometryCollection(_) => {
+                Arc::new(self.as_geometry_collection().buffer(distance, quad_segs)?)
+            }
+            GeoDataType::LargeGeometryCollection(_) => Arc::new(
+                self.as_large_geometry_collection()
+                    .buffer(distance, quad_segs)?,
+            ),
+            _ => return Err(GeoArrowError::IncorrectType("".into())),
+        };
+        Ok(result)
+    }
+
+    fn buffer_with_params(
+        &self,
+        distance: &BroadcastablePrimitive<Float64Type>,
+        buffer_params: &BufferParams<'_>,
+    ) -> Self::Output {
+        let (d, p) = (distance, buffer_params);
+        let result: Arc<dyn GeometryArrayTrait> = match self.data_type() {
+            GeoDataType::Point(_) => Arc::new(self.as_point().buffer_with_params(d, p)?),
+            GeoDataType::LineString(_) => Arc::new(self.as_line_string().buffer_with_params(d, p)?),
+            GeoDataType::LargeLineString(_) => {
+                Arc::new(self.as_large_line_string().buffer_with_params(d, p)?)
+            }
+            GeoDataType::Polygon(_) => Arc::new(self.as_polygon().buffer_with_params(d, p)?),
+            GeoDataType::LargePolygon(_) => {
+                Arc::new(self.as_large_polygon().buffer_with_params(d, p)?)
+            }
+            GeoDataType::MultiPoint(_) => Arc::new(self.as_multi_point().buffer_with_params(d, p)?),
+            GeoDataType::LargeMultiPoint(_) => {
+                Arc::new(self.as_large_multi_point().buffer_with_params(d, p)?)
+            }
+            GeoDataType::MultiLineString(_) => {
+                Arc::new(self.as_multi_line_string().buffer_with_params(d, p)?)
+            }
+            GeoDataType::LargeMultiLineString(_) => {
+                Arc::new(self.as_large_multi_line_string().buffer_with_params(d, p)?)
+            }
+            GeoDataType::MultiPolygon(_) => {
+                Arc::new(self.as_multi_polygon().buffer_with_params(d, p)?)
+            }
+            GeoDataType::LargeMultiPolygon(_) => {
+                Arc::new(self.as_large_multi_polygon().buffer_with_params(d, p)?)
+            }
+            GeoDataType::Mixed(_) => Arc::new(self.as_mixed().buffer_with_params(d, p)?),
+            GeoDataType::LargeMixed(_) => Arc::new(self.as_large_mixed().buffer_with_params(d, p)?),
+            GeoDataType::GeometryCollection(_) => {
+                Arc::new(self.as_geometry_collection().buffer_with_params(d, p)?)
+            }
+            GeoDataType::LargeGeometryCollection(_) => Arc::new(
+                self.as_large_geometry_collection()
+                    .buffer_with_params(d, p)?,
+            ),
+            _ => return Err(GeoArrowError::IncorrectType("".into())),
+        };
+        Ok(result)
+    }
+}
+
+impl Buffer for ChunkedPointArray {
+    type Output = Result<ChunkedMultiPolygonArray<i32>>;
+
+    fn buffer(
+        &self,
+        distance: &BroadcastablePrimitive<Float64Type>,
+        quad_segs: usize,
+    ) -> Self::Output {
+        let distances = distance.split_chunks(&self.chunk_lengths())?;
+        let chunks = self.try_binary_map(distances.as_slice(), |(chunk, distance)| {
+            chunk.buffer(distance, quad_segs)
+        })?;
+        Ok(ChunkedGeometryArray::new_unchecked(chunks))
+    }
 
-        Ok(builder.finish())
+    fn buffer_with_params(
+        &self,
+        distance: &BroadcastablePrimitive<Float64Type>,
+        buffer_params: &BufferParams<'_>,
+    ) -> Self::Output {
+        let distances = distance.split_chunks(&self.chunk_lengths())?;
+        let chunks = self.try_binary_map(distances.as_slice(), |(chunk, distance)| {
+            chunk.buffer_with_params(distance, buffer_params)
+        })?;
+        Ok(ChunkedGeometryArray::new_unchecked(chunks))
     }
 }
 
-// // Note: this can't (easily) be parameterized in the macro because PointArray is not generic over O
-// impl Area for PointArray {
-//     fn area(&self) -> Result<PrimitiveArray<f64>> {
-//         Ok(zeroes(self.len(), self.nulls()))
-//     }
-// }
-
-// /// Implementation where the result is zero.
-// macro_rules! zero_impl {
-//     ($type:ty) => {
-//         impl<O: OffsetSizeTrait> Area for $type {
-//             fn area(&self) -> Result<PrimitiveArray<f64>> {
-//                 Ok(zeroes(self.len(), self.nulls()))
-//             }
-//         }
-//     };
-// }
-
-// zero_impl!(LineStringArray<O>);
-// zero_impl!(MultiPointArray<O>);
-// zero_impl!(MultiLineStringArray<O>);
-
-// macro_rules! iter_geos_impl {
-//     ($type:ty) => {
-//         impl<O: OffsetSizeTrait> Area for $type {
-//             fn area(&self) -> Result<PrimitiveArray<f64>> {
-//             }
-//         }
-//     };
-// }
-
-// iter_geos_impl!(PolygonArray<O>);
-// iter_geos_impl!(MultiPolygonArray<O>);
-// iter_geos_impl!(WKBArray<O>);
-
-// impl<O: OffsetSizeTrait> Area for GeometryArray<O> {
-//     crate::geometry_array_delegate_impl! {
-//         fn area(&self) -> Result<PrimitiveArray<f64>>;
-//     }
-// }
+macro_rules! chunked_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> Buffer for ChunkedGeometryArray<$type> {
+            type Output = Result<ChunkedMultiPolygonArray<O>>;
+
+            fn buffer(
+                &self,
+                distance: &BroadcastablePrimitive<Float64Type>,
+                quad_segs: usize,
+            ) -> Self::Output {
+                let distances = distance.split_chunks(&self.chunk_lengths())?;
+                let chunks = self.try_binary_map(distances.as_slice(), |(chunk, distance)| {
+                    chunk.buffer(distance, quad_segs)
+                })?;
+                Ok(ChunkedGeometryArray::new_unchecked(chunks))
+            }
+
+            fn buffer_with_params(
+                &self,
+                distance: &BroadcastablePrimitive<Float64Type>,
+                buffer_params: &BufferParams<'_>,
+            ) -> Self::Output {
+                let distances = distance.split_chunks(&self.chunk_lengths())?;
+                let chunks = self.try_binary_map(distances.as_slice(), |(chunk, distance)| {
+                    chunk.buffer_with_params(distance, buffer_params)
+                })?;
+                Ok(ChunkedGeometryArray::new_unchecked(chunks))
+            }
+        }
+    };
+}
+
+chunked_impl!(LineStringArray<O>);
+chunked_impl!(PolygonArray<O>);
+chunked_impl!(MultiPointArray<O>);
+chunked_impl!(MultiLineStringArray<O>);
+chunked_impl!(MultiPolygonArray<O>);
+chunked_impl!(MixedGeometryArray<O>);
+chunked_impl!(GeometryCollectionArray<O>);
+
+/// Arrays with `i64` offsets are buffered to a `LargeMultiPolygon` array, and other arrays to a
+/// `MultiPolygon` array.
+impl Buffer for &dyn ChunkedGeometryArrayTrait {
+    type Output = Result<Arc<dyn ChunkedGeometryArrayTrait>>;
+
+    fn buffer(
+        &self,
+        distance: &BroadcastablePrimitive<Float64Type>,
+        quad_segs: usize,
+    ) -> Self::Output {
+        let result: Arc<dyn ChunkedGeometryArrayTrait> = match self.data_type() {
+            GeoDataType::Point(_) => Arc::new(self.as_point().buffer(distance, quad_segs)?),
+            GeoDataType::LineString(_) => {
+                Arc::new(self.as_line_string().buffer(distance, quad_segs)?)
+            }
+            GeoDataType::LargeLineString(_) => {
+                Arc::new(self.as_large_line_string().buffer(distance, quad_segs)?)
+            }
+            GeoDataType::Polygon(_) => Arc::new(self.as_polygon().buffer(distance, quad_segs)?),
+            GeoDataType::LargePolygon(_) => {
+                Arc::new(self.as_large_polygon().buffer(distance, quad_segs)?)
+            }
+            GeoDataType::MultiPoint(_) => {
+                Arc::new(self.as_multi_point().buffer(distance, quad_segs)?)
+            }
+            GeoDataType::LargeMultiPoint(_) => {
+                Arc::new(self.as_large_multi_point().buffer(distance, quad_segs)?)
+            }
+            GeoDataType::MultiLineString(_) => {
+                Arc::new(self.as_multi_line_string().buffer(distance, quad_segs)?)
+            }
+            GeoDataType::LargeMultiLineString(_) => Arc::new(
+                self.as_large_multi_line_string()
+                    .buffer(distance, quad_segs)?,
+            ),
+            GeoDataType::MultiPolygon(_) => {
+                Arc::new(self.as_multi_polygon().buffer(distance, quad_segs)?)
+            }
+            GeoDataType::LargeMultiPolygon(_) => {
+                Arc::new(self.as_large_multi_polygon().buffer(distance, quad_segs)?)
+            }
+            GeoDataType::Mixed(_) => Arc::new(self.as_mixed().buffer(distance, quad_segs)?),
+            GeoDataType::LargeMixed(_) => {
+                Arc::new(self.as_large_mixed().buffer(distance, quad_segs)?)
+            }
+            GeoDataType::GeometryCollection(_) => {
+                Arc::new(self.as_geometry_collection().buffer(distance, quad_segs)?)
+            }
+            GeoDataType::LargeGeometryCollection(_) => Arc::new(
+                self.as_large_geometry_collection()
+                    .buffer(distance, quad_segs)?,
+            ),
+            _ => return Err(GeoArrowError::IncorrectType("".into())),
+        };
+        Ok(result)
+    }
+
+    fn buffer_with_params(
+        &self,
+        distance: &BroadcastablePrimitive<Float64Type>,
+        buffer_params: &BufferParams<'_>,
+    ) -> Self::Output {
+        let (d, p) = (distance, buffer_params);
+        let result: Arc<dyn ChunkedGeometryArrayTrait> = match self.data_type() {
+            GeoDataType::Point(_) => Arc::new(self.as_point().buffer_with_params(d, p)?),
+            GeoDataType::LineString(_) => Arc::new(self.as_line_string().buffer_with_params(d, p)?),
+            GeoDataType::LargeLineString(_) => {
+                Arc::new(self.as_large_line_string().buffer_with_params(d, p)?)
+            }
+            GeoDataType::Polygon(_) => Arc::new(self.as_polygon().buffer_with_params(d, p)?),
+            GeoDataType::LargePolygon(_) => {
+                Arc::new(self.as_large_polygon().buffer_with_params(d, p)?)
+            }
+            GeoDataType::MultiPoint(_) => Arc::new(self.as_multi_point().buffer_with_params(d, p)?),
+            GeoDataType::LargeMultiPoint(_) => {
+                Arc::new(self.as_large_multi_point().buffer_with_params(d, p)?)
+            }
+            GeoDataType::MultiLineString(_) => {
+                Arc::new(self.as_multi_line_string().buffer_with_params(d, p)?)
+            }
+            GeoDataType::LargeMultiLineString(_) => {
+                Arc::new(self.as_large_multi_line_string().buffer_with_params(d, p)?)
+            }
+            GeoDataType::MultiPolygon(_) => {
+                Arc::new(self.as_multi_polygon().buffer_with_params(d, p)?)
+            }
+            GeoDataType::LargeMultiPolygon(_) => {
+                Arc::new(self.as_large_multi_polygon().buffer_with_params(d, p)?)
+            }
+            GeoDataType::Mixed(_) => Arc::new(self.as_mixed().buffer_with_params(d, p)?),
+            GeoDataType::LargeMixed(_) => Arc::new(self.as_large_mixed().buffer_with_params(d, p)?),
+            GeoDataType::GeometryCollection(_) => {
+                Arc::new(self.as_geometry_collection().buffer_with_params(d, p)?)
+            }
+            GeoDataType::LargeGeometryCollection(_) => Arc::new(
+                self.as_large_geometry_collection()
+                    .buffer_with_params(d, p)?,
+            ),
+            _ => return Err(GeoArrowError::IncorrectType("".into())),
+        };
+        Ok(result)
+    }
+}
 
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::test::point::point_array;
+    use crate::trait_::GeometryArraySelfMethods;
+    use arrow_array::Float64Array;
+    use geo::{polygon, Area};
 
     #[test]
     fn point_buffer() {
         let arr = point_array();
-        let buffered: PolygonArray<i32> = arr.buffer(1., 8).unwrap();
-        dbg!(buffered);
+        let buffered = arr.buffer(&BroadcastablePrimitive::Scalar(1.), 8).unwrap();
+        assert_eq!(buffered.len(), arr.len());
+        let area = buffered.value_as_geo(0).unsigned_area();
+        assert!((area - std::f64::consts::PI).abs() < 0.1);
+    }
+
+    #[test]
+    fn negative_buffer() {
+        let polygons = vec![
+            polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)],
+        ];
+        let arr: PolygonArray<i32> = polygons.as_slice().into();
+
+        let buffered = arr.buffer(&BroadcastablePrimitive::Scalar(-1.), 8).unwrap();
+        assert!((buffered.value_as_geo(0).unsigned_area() - 64.).abs() < 1e-9);
+        // Eroded away
+        assert!(buffered.is_valid(1));
+        assert!(buffered.value_as_geo(1).0.is_empty());
+    }
+
+    #[test]
+    fn broadcast_distance() {
+        let arr = point_array();
+        let distance =
+            BroadcastablePrimitive::Array(Float64Array::from(vec![Some(1.), None, Some(2.)]));
+        let buffered = arr.buffer(&distance, 8).unwrap();
+        assert!(buffered.is_null(1));
+        assert!(
+            buffered.value_as_geo(2).unsigned_area() > buffered.value_as_geo(0).unsigned_area()
+        );
+
+        let chunked = ChunkedGeometryArray::new_unchecked(vec![arr.slice(0, 2), arr.slice(2, 1)]);
+        let buffered = chunked.buffer(&distance, 8).unwrap();
+        assert_eq!(buffered.chunks().len(), 2);
+        assert!(buffered.chunks()[0].is_null(1));
     }
 }
@@ -97,11 +97,6 @@ impl<G: GeometryArrayTrait> IsRing for ChunkedGeometryArray<G> {
     type Output = Result<ChunkedArray<BooleanArray>>;
 
     fn is_ring(&self) -> Self::Output {
-        let mut output_chunks = Vec::with_capacity(self.chunks.len());
-        for chunk in self.chunks.iter() {
-            output_chunks.push(chunk.as_ref().is_ring()?);
-        }
-
-        Ok(ChunkedArray::new(output_chunks))
+        self.try_map(|chunk| chunk.as_ref().is_ring())?.try_into()
     }
 }
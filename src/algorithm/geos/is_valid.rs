@@ -99,11 +99,7 @@ impl<G: GeometryArrayTrait> IsValid for ChunkedGeometryArray<G> {
     type Output = Result<ChunkedArray<BooleanArray>>;
 
     fn is_valid(&self) -> Self::Output {
-        let mut output_chunks = Vec::with_capacity(self.chunks.len());
-        for chunk in self.chunks.iter() {
-            output_chunks.push(IsValid::is_valid(&chunk.as_ref())?);
-        }
-
-        Ok(ChunkedArray::new(output_chunks))
+        self.try_map(|chunk| IsValid::is_valid(&chunk.as_ref()))?
+            .try_into()
     }
 }
@@ -0,0 +1,119 @@
+use crate::array::*;
+use crate::chunked_array::{ChunkedArray, ChunkedGeometryArray};
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::GeometryArrayAccessor;
+use crate::trait_::GeometryScalarTrait;
+use crate::GeometryArrayTrait;
+use arrow_array::builder::StringBuilder;
+use arrow_array::{OffsetSizeTrait, StringArray};
+use geos::Geom;
+
+/// Describes why each geometry is invalid, according to GEOS.
+///
+/// Valid geometries have the reason `"Valid Geometry"`. Invalid geometries have a reason such as
+/// `"Self-intersection[1 1]"` or `"Too few points[0 0]"`, with the location of the problem in
+/// brackets. Null geometries have a null reason.
+pub trait IsValidReason {
+    type Output;
+
+    fn is_valid_reason(&self) -> Self::Output;
+}
+
+// Note: this can't (easily) be parameterized in the macro because PointArray is not generic over O
+impl IsValidReason for PointArray {
+    type Output = Result<StringArray>;
+
+    fn is_valid_reason(&self) -> Self::Output {
+        let mut output_array = StringBuilder::with_capacity(self.len(), 0);
+
+        for maybe_g in self.iter() {
+            if let Some(g) = maybe_g {
+                output_array.append_value(g.to_geos()?.is_valid_reason()?);
+            } else {
+                output_array.append_null();
+            }
+        }
+
+        Ok(output_array.finish())
+    }
+}
+
+macro_rules! iter_geos_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> IsValidReason for $type {
+            type Output = Result<StringArray>;
+
+            fn is_valid_reason(&self) -> Self::Output {
+                let mut output_array = StringBuilder::with_capacity(self.len(), 0);
+
+                for maybe_g in self.iter() {
+                    if let Some(g) = maybe_g {
+                        output_array.append_value(g.to_geos()?.is_valid_reason()?);
+                    } else {
+                        output_array.append_null();
+                    }
+                }
+
+                Ok(output_array.finish())
+            }
+        }
+    };
+}
+
+iter_geos_impl!(LineStringArray<O>);
+iter_geos_impl!(MultiPointArray<O>);
+iter_geos_impl!(MultiLineStringArray<O>);
+iter_geos_impl!(PolygonArray<O>);
+iter_geos_impl!(MultiPolygonArray<O>);
+iter_geos_impl!(MixedGeometryArray<O>);
+iter_geos_impl!(GeometryCollectionArray<O>);
+iter_geos_impl!(WKBArray<O>);
+
+impl IsValidReason for &dyn GeometryArrayTrait {
+    type Output = Result<StringArray>;
+
+    fn is_valid_reason(&self) -> Self::Output {
+        match self.data_type() {
+            GeoDataType::Point(_) => IsValidReason::is_valid_reason(self.as_point()),
+            GeoDataType::LineString(_) => IsValidReason::is_valid_reason(self.as_line_string()),
+            GeoDataType::LargeLineString(_) => {
+                IsValidReason::is_valid_reason(self.as_large_line_string())
+            }
+            GeoDataType::Polygon(_) => IsValidReason::is_valid_reason(self.as_polygon()),
+            GeoDataType::LargePolygon(_) => IsValidReason::is_valid_reason(self.as_large_polygon()),
+            GeoDataType::MultiPoint(_) => IsValidReason::is_valid_reason(self.as_multi_point()),
+            GeoDataType::LargeMultiPoint(_) => {
+                IsValidReason::is_valid_reason(self.as_large_multi_point())
+            }
+            GeoDataType::MultiLineString(_) => {
+                IsValidReason::is_valid_reason(self.as_multi_line_string())
+            }
+            GeoDataType::LargeMultiLineString(_) => {
+                IsValidReason::is_valid_reason(self.as_large_multi_line_string())
+            }
+            GeoDataType::MultiPolygon(_) => IsValidReason::is_valid_reason(self.as_multi_polygon()),
+            GeoDataType::LargeMultiPolygon(_) => {
+                IsValidReason::is_valid_reason(self.as_large_multi_polygon())
+            }
+            GeoDataType::Mixed(_) => IsValidReason::is_valid_reason(self.as_mixed()),
+            GeoDataType::LargeMixed(_) => IsValidReason::is_valid_reason(self.as_large_mixed()),
+            GeoDataType::GeometryCollection(_) => {
+                IsValidReason::is_valid_reason(self.as_geometry_collection())
+            }
+            GeoDataType::LargeGeometryCollection(_) => {
+                IsValidReason::is_valid_reason(self.as_large_geometry_collection())
+            }
+            _ => Err(GeoArrowError::IncorrectType("".into())),
+        }
+    }
+}
+
+impl<G: GeometryArrayTrait> IsValidReason for ChunkedGeometryArray<G> {
+    type Output = Result<ChunkedArray<StringArray>>;
+
+    fn is_valid_reason(&self) -> Self::Output {
+        self.try_map(|chunk| IsValidReason::is_valid_reason(&chunk.as_ref()))?
+            .try_into()
+    }
+}
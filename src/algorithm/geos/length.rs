@@ -75,6 +75,7 @@ impl<G: GeometryArrayTrait> Length for ChunkedGeometryArray<G> {
     type Output = Result<ChunkedArray<Float64Array>>;
 
     fn length(&self) -> Self::Output {
-        self.try_map(|chunk| chunk.as_ref().length())?.try_into()
+        self.try_map_non_null(|chunk| chunk.as_ref().length())?
+            .try_into()
     }
 }
@@ -0,0 +1,208 @@
+use std::sync::Arc;
+
+use crate::algorithm::native::Downcast;
+use crate::array::metadata::ArrayMetadata;
+use crate::array::*;
+use crate::chunked_array::{from_geoarrow_chunks, ChunkedGeometryArray, ChunkedGeometryArrayTrait};
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::{GeometryArrayAccessor, GeometryScalarTrait};
+use crate::GeometryArrayTrait;
+use arrow_array::OffsetSizeTrait;
+use geos::Geom;
+
+/// Repair invalid geometries with GEOS, without losing any of their vertices.
+///
+/// Repairing can change the type of a geometry: for example a self-intersecting "bowtie" polygon
+/// becomes a MultiPolygon of its two triangles, and a polygon that has collapsed to a line
+/// becomes a LineString. So the geometries are first collected into a
+/// [`GeometryCollectionArray`] and then [downcast][Downcast] to the narrowest array type that can
+/// hold all of them, the same way [`GeoTable::parse_geometry_to_native`] picks the type of
+/// parsed WKT.
+///
+/// Valid geometries are returned unchanged, though their type may still be downcast.
+///
+/// [`GeoTable::parse_geometry_to_native`]: crate::table::GeoTable::parse_geometry_to_native
+pub trait MakeValid {
+    type Output;
+
+    fn make_valid(&self) -> Self::Output;
+}
+
+/// Repair each geometry of an iterator of scalars, collecting the results to a
+/// `GeometryCollection` array.
+fn make_valid_geometries<G: GeometryScalarTrait>(
+    geoms: impl Iterator<Item = Option<G>>,
+    coord_type: CoordType,
+    metadata: Arc<ArrayMetadata>,
+) -> Result<GeometryCollectionArray<i64>> {
+    let valid_geoms = geoms
+        .map(|maybe_g| {
+            maybe_g
+                .map(|g| Ok(geo::Geometry::try_from(g.to_geos()?.make_valid()?)?))
+                .transpose()
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(GeometryCollectionBuilder::from_nullable_geometries(
+        &valid_geoms,
+        Some(coord_type),
+        metadata,
+        false,
+    )?
+    .finish())
+}
+
+/// Repair each geometry of an array, collecting the results to a `GeometryCollection` array
+/// before any downcasting.
+fn make_valid_collection(array: &dyn GeometryArrayTrait) -> Result<GeometryCollectionArray<i64>> {
+    macro_rules! impl_collection {
+        ($cast_func:ident) => {{
+            let array = array.$cast_func();
+            make_valid_geometries(array.iter(), array.coord_type(), array.metadata())
+        }};
+    }
+
+    match array.data_type() {
+        GeoDataType::Point(_) => impl_collection!(as_point),
+        GeoDataType::LineString(_) => impl_collection!(as_line_string),
+        GeoDataType::LargeLineString(_) => impl_collection!(as_large_line_string),
+        GeoDataType::Polygon(_) => impl_collection!(as_polygon),
+        GeoDataType::LargePolygon(_) => impl_collection!(as_large_polygon),
+        GeoDataType::MultiPoint(_) => impl_collection!(as_multi_point),
+        GeoDataType::LargeMultiPoint(_) => impl_collection!(as_large_multi_point),
+        GeoDataType::MultiLineString(_) => impl_collection!(as_multi_line_string),
+        GeoDataType::LargeMultiLineString(_) => impl_collection!(as_large_multi_line_string),
+        GeoDataType::MultiPolygon(_) => impl_collection!(as_multi_polygon),
+        GeoDataType::LargeMultiPolygon(_) => impl_collection!(as_large_multi_polygon),
+        GeoDataType::Mixed(_) => impl_collection!(as_mixed),
+        GeoDataType::LargeMixed(_) => impl_collection!(as_large_mixed),
+        GeoDataType::GeometryCollection(_) => impl_collection!(as_geometry_collection),
+        GeoDataType::LargeGeometryCollection(_) => {
+            impl_collection!(as_large_geometry_collection)
+        }
+        GeoDataType::WKB => impl_collection!(as_wkb),
+        GeoDataType::LargeWKB => impl_collection!(as_large_wkb),
+        _ => Err(GeoArrowError::IncorrectType("".into())),
+    }
+}
+
+macro_rules! impl_array {
+    ($type:ty) => {
+        impl MakeValid for $type {
+            type Output = Result<Arc<dyn GeometryArrayTrait>>;
+
+            fn make_valid(&self) -> Self::Output {
+                Ok(make_valid_collection(self)?.downcast(true, false))
+            }
+        }
+    };
+    ($type:ty, O) => {
+        impl<O: OffsetSizeTrait> MakeValid for $type {
+            type Output = Result<Arc<dyn GeometryArrayTrait>>;
+
+            fn make_valid(&self) -> Self::Output {
+                Ok(make_valid_collection(self)?.downcast(true, false))
+            }
+        }
+    };
+}
+
+impl_array!(PointArray);
+impl_array!(LineStringArray<O>, O);
+impl_array!(MultiPointArray<O>, O);
+impl_array!(MultiLineStringArray<O>, O);
+impl_array!(PolygonArray<O>, O);
+impl_array!(MultiPolygonArray<O>, O);
+impl_array!(MixedGeometryArray<O>, O);
+impl_array!(GeometryCollectionArray<O>, O);
+impl_array!(WKBArray<O>, O);
+impl_array!(&dyn GeometryArrayTrait);
+
+impl<G: GeometryArrayTrait> MakeValid for ChunkedGeometryArray<G> {
+    type Output = Result<Arc<dyn ChunkedGeometryArrayTrait>>;
+
+    /// Each chunk is downcast on its own. If the chunks end up with different types, they are
+    /// all kept as geometry collections instead.
+    fn make_valid(&self) -> Self::Output {
+        let collection_chunks = self.try_map(|chunk| make_valid_collection(chunk.as_ref()))?;
+
+        let downcast_chunks = collection_chunks
+            .iter()
+            .map(|chunk| chunk.downcast(true, false))
+            .collect::<Vec<_>>();
+        let downcast_refs = downcast_chunks
+            .iter()
+            .map(|chunk| chunk.as_ref())
+            .collect::<Vec<_>>();
+        if downcast_refs
+            .windows(2)
+            .all(|pair| pair[0].data_type() == pair[1].data_type())
+        {
+            return from_geoarrow_chunks(&downcast_refs);
+        }
+
+        Ok(Arc::new(ChunkedGeometryArray::new_unchecked(
+            collection_chunks,
+        )))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::geos::{IsValid, IsValidReason};
+    use crate::io::geojson::read_geojson;
+    use std::fs::File;
+    use std::io::BufReader;
+
+    fn invalid_polygons() -> Arc<dyn ChunkedGeometryArrayTrait> {
+        let file = File::open("fixtures/invalid_polygons.geojson").unwrap();
+        let table = read_geojson(BufReader::new(file), Default::default()).unwrap();
+        table.geometry().unwrap()
+    }
+
+    #[test]
+    fn validity_reasons() {
+        let geometry = invalid_polygons();
+        let chunk = geometry.geometry_chunks()[0];
+
+        let valid = IsValid::is_valid(&chunk).unwrap();
+        let valid = valid.iter().map(Option::unwrap).collect::<Vec<_>>();
+        assert_eq!(valid, vec![true, false, true, false]);
+
+        let reasons = IsValidReason::is_valid_reason(&chunk).unwrap();
+        assert_eq!(reasons.value(0), "Valid Geometry");
+        assert!(reasons.value(1).starts_with("Self-intersection"));
+        assert_eq!(reasons.value(2), "Valid Geometry");
+        assert!(reasons.value(3).starts_with("Too few points"));
+    }
+
+    #[test]
+    fn make_valid_fixture() {
+        let geometry = invalid_polygons();
+        let chunk = geometry.geometry_chunks()[0];
+
+        let repaired = MakeValid::make_valid(&chunk).unwrap();
+        assert_eq!(repaired.len(), 4);
+        let valid = IsValid::is_valid(&repaired.as_ref()).unwrap();
+        assert_eq!(valid.true_count(), 4);
+        // The degenerate ring collapses to a line, so the output needs a mixed array
+        assert!(matches!(repaired.data_type(), GeoDataType::Mixed(_)));
+    }
+
+    #[test]
+    fn make_valid_bowtie() {
+        let bowtie = geo::polygon![
+            (x: 0., y: 0.),
+            (x: 2., y: 2.),
+            (x: 2., y: 0.),
+            (x: 0., y: 2.),
+            (x: 0., y: 0.),
+        ];
+        let arr: PolygonArray<i32> = vec![bowtie].as_slice().into();
+        let repaired = arr.make_valid().unwrap();
+        assert!(matches!(repaired.data_type(), GeoDataType::MultiPolygon(_)));
+        let multi_polygon = repaired.as_ref().as_multi_polygon().value_as_geo(0);
+        assert_eq!(multi_polygon.0.len(), 2);
+    }
+}
@@ -0,0 +1,104 @@
+use crate::algorithm::native::Unary;
+use crate::array::*;
+use crate::chunked_array::{ChunkedArray, ChunkedGeometryArray};
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::GeometryScalarTrait;
+use crate::GeometryArrayTrait;
+use arrow_array::{Float64Array, OffsetSizeTrait};
+use geos::Geom;
+
+/// Returns the minimum clearance of each geometry: the smallest distance that a vertex can be
+/// moved before the geometry becomes invalid or collapses.
+///
+/// Geometries with a small minimum clearance relative to their coordinates are likely to be
+/// made invalid by rounding during overlay operations. A geometry with no segments, such as a
+/// point, has a minimum clearance of infinity.
+pub trait MinimumClearance {
+    type Output;
+
+    fn minimum_clearance(&self) -> Self::Output;
+}
+
+impl MinimumClearance for PointArray {
+    type Output = Result<Float64Array>;
+
+    fn minimum_clearance(&self) -> Self::Output {
+        Ok(self.try_unary_primitive(|geom| geom.to_geos()?.minimum_clearance())?)
+    }
+}
+
+macro_rules! iter_geos_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> MinimumClearance for $type {
+            type Output = Result<Float64Array>;
+
+            fn minimum_clearance(&self) -> Self::Output {
+                Ok(self.try_unary_primitive(|geom| geom.to_geos()?.minimum_clearance())?)
+            }
+        }
+    };
+}
+
+iter_geos_impl!(LineStringArray<O>);
+iter_geos_impl!(MultiPointArray<O>);
+iter_geos_impl!(MultiLineStringArray<O>);
+iter_geos_impl!(PolygonArray<O>);
+iter_geos_impl!(MultiPolygonArray<O>);
+iter_geos_impl!(MixedGeometryArray<O>);
+iter_geos_impl!(GeometryCollectionArray<O>);
+iter_geos_impl!(WKBArray<O>);
+
+impl MinimumClearance for &dyn GeometryArrayTrait {
+    type Output = Result<Float64Array>;
+
+    fn minimum_clearance(&self) -> Self::Output {
+        match self.data_type() {
+            GeoDataType::Point(_) => self.as_point().minimum_clearance(),
+            GeoDataType::LineString(_) => self.as_line_string().minimum_clearance(),
+            GeoDataType::LargeLineString(_) => self.as_large_line_string().minimum_clearance(),
+            GeoDataType::Polygon(_) => self.as_polygon().minimum_clearance(),
+            GeoDataType::LargePolygon(_) => self.as_large_polygon().minimum_clearance(),
+            GeoDataType::MultiPoint(_) => self.as_multi_point().minimum_clearance(),
+            GeoDataType::LargeMultiPoint(_) => self.as_large_multi_point().minimum_clearance(),
+            GeoDataType::MultiLineString(_) => self.as_multi_line_string().minimum_clearance(),
+            GeoDataType::LargeMultiLineString(_) => {
+                self.as_large_multi_line_string().minimum_clearance()
+            }
+            GeoDataType::MultiPolygon(_) => self.as_multi_polygon().minimum_clearance(),
+            GeoDataType::LargeMultiPolygon(_) => self.as_large_multi_polygon().minimum_clearance(),
+            GeoDataType::Mixed(_) => self.as_mixed().minimum_clearance(),
+            GeoDataType::LargeMixed(_) => self.as_large_mixed().minimum_clearance(),
+            GeoDataType::GeometryCollection(_) => self.as_geometry_collection().minimum_clearance(),
+            GeoDataType::LargeGeometryCollection(_) => {
+                self.as_large_geometry_collection().minimum_clearance()
+            }
+            GeoDataType::WKB => self.as_wkb().minimum_clearance(),
+            GeoDataType::LargeWKB => self.as_large_wkb().minimum_clearance(),
+            _ => Err(GeoArrowError::IncorrectType("".into())),
+        }
+    }
+}
+
+impl<G: GeometryArrayTrait> MinimumClearance for ChunkedGeometryArray<G> {
+    type Output = Result<ChunkedArray<Float64Array>>;
+
+    fn minimum_clearance(&self) -> Self::Output {
+        self.try_map_non_null(|chunk| chunk.as_ref().minimum_clearance())?
+            .try_into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sliver_polygon() {
+        // A sliver whose apex is 0.01 above its base
+        let polygon = geo::polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 5., y: 0.01)];
+        let polygon_array: PolygonArray<i32> = vec![polygon].as_slice().into();
+        let result = polygon_array.minimum_clearance().unwrap();
+        assert!((result.value(0) - 0.01).abs() < 1e-9);
+    }
+}
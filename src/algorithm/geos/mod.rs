@@ -4,10 +4,16 @@ mod area;
 mod buffer;
 mod is_ring;
 mod is_valid;
+mod is_valid_reason;
 mod length;
+mod make_valid;
+mod minimum_clearance;
 
 pub use area::Area;
 pub use buffer::Buffer;
 pub use is_ring::IsRing;
 pub use is_valid::IsValid;
+pub use is_valid_reason::IsValidReason;
 pub use length::Length;
+pub use make_valid::MakeValid;
+pub use minimum_clearance::MinimumClearance;
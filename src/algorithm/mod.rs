@@ -1,6 +1,8 @@
 //! Vectorized algorithms implemented on and returning GeoArrow arrays.
 
 pub mod broadcasting;
+pub mod capabilities;
+pub mod cost;
 pub mod geo;
 pub mod geo_index;
 #[cfg(feature = "geodesy")]
@@ -0,0 +1,470 @@
+use arrow_array::OffsetSizeTrait;
+use geo::{Contains, Coord, LineString, MultiLineString, MultiPolygon, Polygon};
+
+use crate::array::{
+    LineStringArray, MultiLineStringArray, MultiLineStringBuilder, MultiPolygonArray,
+    MultiPolygonBuilder, PolygonArray,
+};
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+
+/// Split geometries that cross the antimeridian (±180° longitude).
+///
+/// Coordinates are assumed to be longitude/latitude. Longitudes outside of `[-180, 180]` are
+/// first normalized into that range. A segment is considered to cross the antimeridian when the
+/// difference in longitude between its two vertices is larger than 180°, i.e. when the shorter
+/// way around the globe passes over the antimeridian. A split point is inserted on each side of
+/// the antimeridian at the latitude where the great circle between the two vertices crosses it.
+///
+/// This follows the approach used by GeoJSON tooling such as the `antimeridian` Python package
+/// and [RFC 7946 section 3.1.9](https://datatracker.ietf.org/doc/html/rfc7946#section-3.1.9),
+/// so that the output renders correctly on web maps instead of as horizontal streaks.
+pub trait SplitAntimeridian {
+    type Output;
+
+    /// Split each geometry into pieces that lie entirely on one side of the antimeridian.
+    ///
+    /// Geometries that don't cross the antimeridian are returned as a single piece. Null
+    /// geometries stay null.
+    fn split_antimeridian(&self) -> Self::Output;
+}
+
+impl<O: OffsetSizeTrait> SplitAntimeridian for LineStringArray<O> {
+    type Output = MultiLineStringArray<O>;
+
+    fn split_antimeridian(&self) -> Self::Output {
+        let output_geoms: Vec<Option<MultiLineString>> = self
+            .iter_geo()
+            .map(|maybe_g| maybe_g.map(|line_string| split_line_string(&line_string)))
+            .collect();
+
+        MultiLineStringBuilder::from_nullable_multi_line_strings(
+            &output_geoms,
+            Some(self.coord_type()),
+            self.metadata(),
+        )
+        .finish()
+    }
+}
+
+/// Polygon rings are split at the antimeridian and the resulting pieces are reconnected by
+/// walking along the antimeridian, so each output polygon is closed on its own side.
+///
+/// A polygon whose exterior crosses the antimeridian an odd number of times encloses one of the
+/// poles. Splitting such a polygon would require inserting vertices at the pole, which is not
+/// supported; these polygons return an error.
+impl<O: OffsetSizeTrait> SplitAntimeridian for PolygonArray<O> {
+    type Output = Result<MultiPolygonArray<O>>;
+
+    fn split_antimeridian(&self) -> Self::Output {
+        let output_geoms = self
+            .iter_geo()
+            .map(|maybe_g| maybe_g.map(|polygon| split_polygon(&polygon)).transpose())
+            .collect::<Result<Vec<Option<MultiPolygon>>>>()?;
+
+        Ok(MultiPolygonBuilder::from_nullable_multi_polygons(
+            &output_geoms,
+            Some(self.coord_type()),
+            self.metadata(),
+        )
+        .finish())
+    }
+}
+
+fn split_line_string(line_string: &LineString) -> MultiLineString {
+    let pieces = split_coords(&line_string.0)
+        .into_iter()
+        .filter(|piece| piece.len() >= 2)
+        .map(LineString::new)
+        .collect();
+    MultiLineString::new(pieces)
+}
+
+fn split_polygon(polygon: &Polygon) -> Result<MultiPolygon> {
+    let exterior = oriented_ring(polygon.exterior(), true);
+    let mut exterior_pieces = split_coords(&exterior);
+    if exterior_pieces.len() == 1 {
+        let exterior = LineString::new(exterior_pieces.pop().unwrap());
+        let interiors = polygon
+            .interiors()
+            .iter()
+            .map(|interior| {
+                LineString::new(interior.coords().copied().map(normalize_coord).collect())
+            })
+            .collect();
+        return Ok(MultiPolygon::new(vec![Polygon::new(exterior, interiors)]));
+    }
+
+    // Every piece of a split ring starts and ends on the antimeridian. These are reconnected
+    // along the antimeridian into closed rings.
+    let mut segments = close_ring_pieces(exterior_pieces)?;
+    let mut whole_interiors = vec![];
+    for interior in polygon.interiors() {
+        let interior = oriented_ring(interior, false);
+        let mut interior_pieces = split_coords(&interior);
+        if interior_pieces.len() == 1 {
+            whole_interiors.push(LineString::new(interior_pieces.pop().unwrap()));
+        } else {
+            segments.extend(close_ring_pieces(interior_pieces)?);
+        }
+    }
+
+    let mut polygons = join_segments(segments)?
+        .into_iter()
+        .map(|exterior| Polygon::new(LineString::new(exterior), vec![]))
+        .collect::<Vec<_>>();
+
+    // Interiors that don't cross the antimeridian belong to whichever piece contains them
+    for interior in whole_interiors {
+        let first_coord = interior.0[0];
+        let polygon = polygons
+            .iter_mut()
+            .find(|polygon| polygon.contains(&first_coord))
+            .ok_or_else(|| {
+                GeoArrowError::General(
+                    "Polygon interior ring is not contained in its exterior ring".to_string(),
+                )
+            })?;
+        polygon.interiors_push(interior);
+    }
+
+    Ok(MultiPolygon::new(polygons))
+}
+
+/// Merge the first and last pieces of a split ring, which are connected at the ring's start
+/// point.
+fn close_ring_pieces(mut pieces: Vec<Vec<Coord>>) -> Result<Vec<Vec<Coord>>> {
+    let num_crossings = pieces.len() - 1;
+    if num_crossings % 2 == 1 {
+        return Err(GeoArrowError::General(
+            "Polygon ring crosses the antimeridian an odd number of times. Polygons enclosing a \
+             pole are not supported."
+                .to_string(),
+        ));
+    }
+
+    let first = pieces.remove(0);
+    let last = pieces.last_mut().unwrap();
+    for coord in first.into_iter().skip(1) {
+        push_coord(last, coord);
+    }
+    Ok(pieces)
+}
+
+/// Join segments that start and end on the antimeridian into closed rings.
+///
+/// Exterior rings are oriented counter-clockwise and interior rings clockwise, so the inside of
+/// the polygon is always to the left of the direction of travel. Along +180° the inside lies to
+/// the west, so the boundary continues northwards; along -180° it continues southwards.
+fn join_segments(segments: Vec<Vec<Coord>>) -> Result<Vec<Vec<Coord>>> {
+    let mut segments = segments.into_iter().map(Some).collect::<Vec<_>>();
+    let mut rings = vec![];
+
+    for i in 0..segments.len() {
+        let Some(mut ring) = segments[i].take() else {
+            continue;
+        };
+
+        loop {
+            let end = *ring.last().unwrap();
+
+            // The distance along the antimeridian to the start of this ring, if it's reachable
+            let mut closest =
+                is_next_on_antimeridian(end, ring[0]).then(|| ((ring[0].y - end.y).abs(), None));
+            for (j, segment) in segments.iter().enumerate() {
+                let Some(segment) = segment else {
+                    continue;
+                };
+                if !is_next_on_antimeridian(end, segment[0]) {
+                    continue;
+                }
+                let distance = (segment[0].y - end.y).abs();
+                if closest.map_or(true, |(closest_distance, _)| distance < closest_distance) {
+                    closest = Some((distance, Some(j)));
+                }
+            }
+
+            match closest {
+                Some((_, Some(j))) => {
+                    for coord in segments[j].take().unwrap() {
+                        push_coord(&mut ring, coord);
+                    }
+                }
+                Some((_, None)) => {
+                    let start = ring[0];
+                    ring.push(start);
+                    rings.push(ring);
+                    break;
+                }
+                None => {
+                    return Err(GeoArrowError::General(
+                        "Unable to close polygon ring along the antimeridian. Polygons enclosing \
+                         a pole are not supported."
+                            .to_string(),
+                    ))
+                }
+            }
+        }
+    }
+
+    Ok(rings)
+}
+
+/// Whether `candidate` can be reached from `end` by walking along the antimeridian with the
+/// inside of the polygon on the left.
+fn is_next_on_antimeridian(end: Coord, candidate: Coord) -> bool {
+    if candidate.x != end.x {
+        return false;
+    }
+    if end.x > 0.0 {
+        candidate.y >= end.y
+    } else {
+        candidate.y <= end.y
+    }
+}
+
+/// Split a sequence of coordinates into pieces at each antimeridian crossing.
+///
+/// Always returns at least one piece. Pieces after a crossing start on the antimeridian and
+/// pieces before a crossing end on it.
+fn split_coords(coords: &[Coord]) -> Vec<Vec<Coord>> {
+    let mut pieces = vec![];
+    let mut current = vec![];
+
+    let mut coords = coords.iter().copied().map(normalize_coord);
+    if let Some(mut prev) = coords.next() {
+        current.push(prev);
+        for coord in coords {
+            if let Some(meridian) = crossed_meridian(prev, coord) {
+                let lat = crossing_latitude(prev, coord, meridian);
+                push_coord(
+                    &mut current,
+                    Coord {
+                        x: meridian,
+                        y: lat,
+                    },
+                );
+                pieces.push(std::mem::take(&mut current));
+                current.push(Coord {
+                    x: -meridian,
+                    y: lat,
+                });
+            }
+            push_coord(&mut current, coord);
+            prev = coord;
+        }
+    }
+
+    pieces.push(current);
+    pieces
+}
+
+/// If the segment from `start` to `end` crosses the antimeridian, the longitude (180 or -180) of
+/// the antimeridian on the side of `start`.
+fn crossed_meridian(start: Coord, end: Coord) -> Option<f64> {
+    let dx = end.x - start.x;
+    if dx.abs() <= 180.0 {
+        None
+    } else if dx < 0.0 {
+        // Travelling east over +180°
+        Some(180.0)
+    } else {
+        // Travelling west over -180°
+        Some(-180.0)
+    }
+}
+
+/// The latitude at which the great circle from `start` to `end` crosses the antimeridian.
+fn crossing_latitude(start: Coord, end: Coord, meridian: f64) -> f64 {
+    let (lon1, lat1) = (start.x.to_radians(), start.y.to_radians());
+    let (lon2, lat2) = (end.x.to_radians(), end.y.to_radians());
+    let lon = meridian.to_radians();
+
+    let denominator = lat1.cos() * lat2.cos() * (lon1 - lon2).sin();
+    if denominator.abs() > 1e-12 {
+        let numerator = lat1.sin() * lat2.cos() * (lon - lon2).sin()
+            - lat2.sin() * lat1.cos() * (lon - lon1).sin();
+        return (numerator / denominator).atan().to_degrees();
+    }
+
+    // The great circle is undefined (e.g. a vertex on a pole), so interpolate linearly in
+    // longitude instead.
+    let end_x = end.x + 2.0 * meridian;
+    if end_x == start.x {
+        start.y
+    } else {
+        let t = (meridian - start.x) / (end_x - start.x);
+        start.y + t * (end.y - start.y)
+    }
+}
+
+/// Normalize the ring's coordinates and orient it counter-clockwise if `ccw` is true, otherwise
+/// clockwise.
+fn oriented_ring(ring: &LineString, ccw: bool) -> Vec<Coord> {
+    let mut coords = ring
+        .coords()
+        .copied()
+        .map(normalize_coord)
+        .collect::<Vec<_>>();
+    if (unwrapped_signed_area(&coords) > 0.0) != ccw {
+        coords.reverse();
+    }
+    coords
+}
+
+/// The signed area of a ring after removing jumps in longitude at the antimeridian.
+fn unwrapped_signed_area(coords: &[Coord]) -> f64 {
+    let mut area = 0.0;
+    let mut offset = 0.0;
+    let mut unwrapped_prev: Option<Coord> = None;
+    let mut prev_x = 0.0;
+    for coord in coords {
+        if let Some(prev) = unwrapped_prev {
+            let dx = coord.x - prev_x;
+            if dx > 180.0 {
+                offset -= 360.0;
+            } else if dx < -180.0 {
+                offset += 360.0;
+            }
+            let x = coord.x + offset;
+            area += prev.x * coord.y - x * prev.y;
+            unwrapped_prev = Some(Coord { x, y: coord.y });
+        } else {
+            unwrapped_prev = Some(*coord);
+        }
+        prev_x = coord.x;
+    }
+    area / 2.0
+}
+
+fn normalize_coord(coord: Coord) -> Coord {
+    let x = if (-180.0..=180.0).contains(&coord.x) {
+        coord.x
+    } else {
+        (coord.x + 180.0).rem_euclid(360.0) - 180.0
+    };
+    Coord { x, y: coord.y }
+}
+
+/// Push a coordinate, skipping it if it repeats the previous coordinate.
+fn push_coord(coords: &mut Vec<Coord>, coord: Coord) {
+    if coords.last() != Some(&coord) {
+        coords.push(coord);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::antimeridian::{fiji, north_pole_cap, tonga_loop, transpacific_flight};
+    use geo::{polygon, Area, BoundingRect};
+
+    #[test]
+    fn transpacific_flight_path() {
+        let arr: LineStringArray<i32> = vec![transpacific_flight()].as_slice().into();
+        let split = arr.split_antimeridian();
+        let multi_line_string = split.value_as_geo(0);
+        assert_eq!(multi_line_string.0.len(), 2);
+
+        let west = &multi_line_string.0[0];
+        let east = &multi_line_string.0[1];
+        assert_eq!(west.0.last().unwrap().x, 180.0);
+        assert_eq!(east.0[0].x, -180.0);
+        assert_eq!(west.0.last().unwrap().y, east.0[0].y);
+        assert!(west.0.iter().all(|c| c.x > 0.0));
+        assert!(east.0.iter().all(|c| c.x < 0.0));
+    }
+
+    #[test]
+    fn line_crossing_twice() {
+        let arr: LineStringArray<i32> = vec![tonga_loop()].as_slice().into();
+        let split = arr.split_antimeridian();
+        assert_eq!(split.value_as_geo(0).0.len(), 3);
+    }
+
+    #[test]
+    fn line_not_crossing() {
+        let arr = crate::test::linestring::ls_array();
+        let split = arr.split_antimeridian();
+        assert_eq!(split.len(), 2);
+        assert_eq!(split.value_as_geo(0).0.len(), 1);
+        assert_eq!(split.value_as_geo(0).0[0], crate::test::linestring::ls0());
+    }
+
+    #[test]
+    fn crossing_latitude_on_equator() {
+        let lat = crossing_latitude(Coord { x: 170., y: 0. }, Coord { x: -170., y: 0. }, 180.);
+        assert!(lat.abs() < 1e-9);
+    }
+
+    #[test]
+    fn fiji_polygon() {
+        let arr: PolygonArray<i32> = vec![fiji()].as_slice().into();
+        let split = arr.split_antimeridian().unwrap();
+        let multi_polygon = split.value_as_geo(0);
+        assert_eq!(multi_polygon.0.len(), 2);
+
+        for polygon in multi_polygon.iter() {
+            let rect = polygon.bounding_rect().unwrap();
+            assert!(rect.min().x >= 0.0 || rect.max().x <= 0.0);
+            assert!(polygon.exterior().is_closed());
+        }
+
+        // The pieces together cover about the same area as the unwrapped polygon. The split
+        // points follow the great circle, so they differ slightly from a planar interpolation.
+        let unwrapped: Polygon = polygon![
+            (x: 177.0, y: -16.0),
+            (x: 177.0, y: -19.0),
+            (x: 182.0, y: -19.0),
+            (x: 182.0, y: -16.0),
+            (x: 179.0, y: -17.0),
+        ];
+        let relative_diff = (multi_polygon.unsigned_area() - unwrapped.unsigned_area()).abs()
+            / unwrapped.unsigned_area();
+        assert!(relative_diff < 1e-2);
+    }
+
+    #[test]
+    fn polygon_with_interior() {
+        let polygon: Polygon = polygon!(
+            exterior: [
+                (x: 170.0, y: -10.0),
+                (x: -170.0, y: -10.0),
+                (x: -170.0, y: 10.0),
+                (x: 170.0, y: 10.0),
+            ],
+            interiors: [
+                [
+                    (x: 172.0, y: -2.0),
+                    (x: 174.0, y: -2.0),
+                    (x: 174.0, y: 2.0),
+                    (x: 172.0, y: 2.0),
+                ],
+                [
+                    (x: 178.0, y: -2.0),
+                    (x: -178.0, y: -2.0),
+                    (x: -178.0, y: 2.0),
+                    (x: 178.0, y: 2.0),
+                ],
+            ],
+        );
+        let arr: PolygonArray<i32> = vec![polygon].as_slice().into();
+        let multi_polygon = arr.split_antimeridian().unwrap().value_as_geo(0);
+        assert_eq!(multi_polygon.0.len(), 2);
+
+        let east = multi_polygon
+            .iter()
+            .find(|polygon| polygon.exterior().0[0].x > 0.0)
+            .unwrap();
+        assert_eq!(east.interiors().len(), 1);
+        let expected_area = 20.0 * 20.0 - 2.0 * 4.0 - 4.0 * 4.0;
+        assert!((multi_polygon.unsigned_area() - expected_area).abs() / expected_area < 1e-2);
+    }
+
+    #[test]
+    fn polygon_enclosing_pole() {
+        let arr: PolygonArray<i32> = vec![north_pole_cap()].as_slice().into();
+        assert!(arr.split_antimeridian().is_err());
+    }
+}
@@ -135,6 +135,14 @@ impl BoundingRect {
         self.add_coord(&rect.upper());
     }
 
+    /// Whether this bounding rect and `other` share any point, including their boundaries.
+    pub fn intersects(&self, other: &BoundingRect) -> bool {
+        self.minx <= other.maxx
+            && other.minx <= self.maxx
+            && self.miny <= other.maxy
+            && other.miny <= self.maxy
+    }
+
     pub fn update(&mut self, other: &BoundingRect) {
         self.add_rect(other)
     }
@@ -1,9 +1,7 @@
-//! Note: In the future, it may be possible to optimize some of these casts, e.g. from Point to
-//! MultiPoint by only constructing a new offsets array, but you have to check that the coordinate
-//! type is not casted!
-//!
-//! todo: have a set of "fast cast" functions, where you first try to fast cast and fall back to
-//! slower copies if necessary. Can check that the coord type of the input and output is the same.
+//! Casts that keep the coordinate type of the input and only widen the geometry type (e.g. Point
+//! to MultiPoint) or change the offset size reuse the input coordinate buffers, allocating only
+//! new offsets. All other casts, including any change of coordinate type, copy each geometry
+//! into a new builder.
 
 use std::sync::Arc;
 
@@ -56,10 +54,137 @@ pub trait Cast {
     fn cast(&self, to_type: &GeoDataType) -> Self::Output;
 }
 
+/// Cast an array without copying its coordinates, if possible.
+///
+/// This is possible when the coordinate type is unchanged and the output geometry type has the
+/// same coordinate layout as the input, i.e. the cast is to the same geometry type, possibly with
+/// a different offset size, or from a single geometry type to its multi geometry type. Only the
+/// offset buffers are (re)allocated. Returns `None` when the coordinates would have to be copied.
+fn zero_copy_cast(
+    array: &dyn GeometryArrayTrait,
+    to_type: &GeoDataType,
+) -> Result<Option<Arc<dyn GeometryArrayTrait>>> {
+    // Compare the coordinate types through the data types, as `coord_type` is not defined for all
+    // arrays (e.g. an empty mixed array)
+    let from_type = *array.data_type();
+    let same_coord_type = [CoordType::Interleaved, CoordType::Separated]
+        .into_iter()
+        .any(|ct| {
+            from_type.with_coord_type(ct) == from_type && to_type.with_coord_type(ct) == *to_type
+        });
+    if !same_coord_type {
+        return Ok(None);
+    }
+
+    use GeoDataType::*;
+    let result: Arc<dyn GeometryArrayTrait> = match (array.data_type(), to_type) {
+        (Point(_), Point(_)) => Arc::new(array.as_point().clone()),
+        (Point(_), MultiPoint(_)) => {
+            Arc::new(MultiPointArray::<i32>::try_from(array.as_point().clone())?)
+        }
+        (Point(_), LargeMultiPoint(_)) => {
+            Arc::new(MultiPointArray::<i64>::try_from(array.as_point().clone())?)
+        }
+
+        (LineString(_), LineString(_)) => Arc::new(array.as_line_string().clone()),
+        (LineString(_), LargeLineString(_)) => {
+            Arc::new(LineStringArray::<i64>::from(array.as_line_string().clone()))
+        }
+        (LargeLineString(_), LineString(_)) => Arc::new(LineStringArray::<i32>::try_from(
+            array.as_large_line_string().clone(),
+        )?),
+        (LargeLineString(_), LargeLineString(_)) => Arc::new(array.as_large_line_string().clone()),
+        (LineString(_), MultiLineString(_)) => Arc::new(MultiLineStringArray::<i32>::try_from(
+            array.as_line_string().clone(),
+        )?),
+        (LineString(_), LargeMultiLineString(_)) => {
+            let line_strings = LineStringArray::<i64>::from(array.as_line_string().clone());
+            Arc::new(MultiLineStringArray::<i64>::try_from(line_strings)?)
+        }
+        (LargeLineString(_), MultiLineString(_)) => {
+            let line_strings =
+                LineStringArray::<i32>::try_from(array.as_large_line_string().clone())?;
+            Arc::new(MultiLineStringArray::<i32>::try_from(line_strings)?)
+        }
+        (LargeLineString(_), LargeMultiLineString(_)) => Arc::new(
+            MultiLineStringArray::<i64>::try_from(array.as_large_line_string().clone())?,
+        ),
+
+        (Polygon(_), Polygon(_)) => Arc::new(array.as_polygon().clone()),
+        (Polygon(_), LargePolygon(_)) => {
+            Arc::new(PolygonArray::<i64>::from(array.as_polygon().clone()))
+        }
+        (LargePolygon(_), Polygon(_)) => Arc::new(PolygonArray::<i32>::try_from(
+            array.as_large_polygon().clone(),
+        )?),
+        (LargePolygon(_), LargePolygon(_)) => Arc::new(array.as_large_polygon().clone()),
+        (Polygon(_), MultiPolygon(_)) => Arc::new(MultiPolygonArray::<i32>::try_from(
+            array.as_polygon().clone(),
+        )?),
+        (Polygon(_), LargeMultiPolygon(_)) => {
+            let polygons = PolygonArray::<i64>::from(array.as_polygon().clone());
+            Arc::new(MultiPolygonArray::<i64>::try_from(polygons)?)
+        }
+        (LargePolygon(_), MultiPolygon(_)) => {
+            let polygons = PolygonArray::<i32>::try_from(array.as_large_polygon().clone())?;
+            Arc::new(MultiPolygonArray::<i32>::try_from(polygons)?)
+        }
+        (LargePolygon(_), LargeMultiPolygon(_)) => Arc::new(MultiPolygonArray::<i64>::try_from(
+            array.as_large_polygon().clone(),
+        )?),
+
+        (MultiPoint(_), MultiPoint(_)) => Arc::new(array.as_multi_point().clone()),
+        (MultiPoint(_), LargeMultiPoint(_)) => {
+            Arc::new(MultiPointArray::<i64>::from(array.as_multi_point().clone()))
+        }
+        (LargeMultiPoint(_), MultiPoint(_)) => Arc::new(MultiPointArray::<i32>::try_from(
+            array.as_large_multi_point().clone(),
+        )?),
+        (LargeMultiPoint(_), LargeMultiPoint(_)) => Arc::new(array.as_large_multi_point().clone()),
+
+        (MultiLineString(_), MultiLineString(_)) => Arc::new(array.as_multi_line_string().clone()),
+        (MultiLineString(_), LargeMultiLineString(_)) => Arc::new(
+            MultiLineStringArray::<i64>::from(array.as_multi_line_string().clone()),
+        ),
+        (LargeMultiLineString(_), MultiLineString(_)) => Arc::new(
+            MultiLineStringArray::<i32>::try_from(array.as_large_multi_line_string().clone())?,
+        ),
+        (LargeMultiLineString(_), LargeMultiLineString(_)) => {
+            Arc::new(array.as_large_multi_line_string().clone())
+        }
+
+        (MultiPolygon(_), MultiPolygon(_)) => Arc::new(array.as_multi_polygon().clone()),
+        (MultiPolygon(_), LargeMultiPolygon(_)) => Arc::new(MultiPolygonArray::<i64>::from(
+            array.as_multi_polygon().clone(),
+        )),
+        (LargeMultiPolygon(_), MultiPolygon(_)) => Arc::new(MultiPolygonArray::<i32>::try_from(
+            array.as_large_multi_polygon().clone(),
+        )?),
+        (LargeMultiPolygon(_), LargeMultiPolygon(_)) => {
+            Arc::new(array.as_large_multi_polygon().clone())
+        }
+
+        (Mixed(_), Mixed(_)) => Arc::new(array.as_mixed().clone()),
+        (LargeMixed(_), LargeMixed(_)) => Arc::new(array.as_large_mixed().clone()),
+        (GeometryCollection(_), GeometryCollection(_)) => {
+            Arc::new(array.as_geometry_collection().clone())
+        }
+        (LargeGeometryCollection(_), LargeGeometryCollection(_)) => {
+            Arc::new(array.as_large_geometry_collection().clone())
+        }
+        _ => return Ok(None),
+    };
+    Ok(Some(result))
+}
+
 impl Cast for PointArray {
     type Output = Result<Arc<dyn GeometryArrayTrait>>;
 
     fn cast(&self, to_type: &GeoDataType) -> Self::Output {
+        if let Some(array) = zero_copy_cast(self, to_type)? {
+            return Ok(array);
+        }
+
         use GeoDataType::*;
         match to_type {
             Point(ct) => {
@@ -163,6 +288,10 @@ impl<O: OffsetSizeTrait> Cast for LineStringArray<O> {
     type Output = Result<Arc<dyn GeometryArrayTrait>>;
 
     fn cast(&self, to_type: &GeoDataType) -> Self::Output {
+        if let Some(array) = zero_copy_cast(self, to_type)? {
+            return Ok(array);
+        }
+
         use GeoDataType::*;
         match to_type {
             LineString(ct) => {
@@ -277,6 +406,10 @@ impl<O: OffsetSizeTrait> Cast for PolygonArray<O> {
     type Output = Result<Arc<dyn GeometryArrayTrait>>;
 
     fn cast(&self, to_type: &GeoDataType) -> Self::Output {
+        if let Some(array) = zero_copy_cast(self, to_type)? {
+            return Ok(array);
+        }
+
         use GeoDataType::*;
         match to_type {
             Polygon(ct) => {
@@ -391,6 +524,10 @@ impl<O: OffsetSizeTrait> Cast for MultiPointArray<O> {
     type Output = Result<Arc<dyn GeometryArrayTrait>>;
 
     fn cast(&self, to_type: &GeoDataType) -> Self::Output {
+        if let Some(array) = zero_copy_cast(self, to_type)? {
+            return Ok(array);
+        }
+
         use GeoDataType::*;
         match to_type {
             Point(ct) => {
@@ -494,6 +631,10 @@ impl<O: OffsetSizeTrait> Cast for MultiLineStringArray<O> {
     type Output = Result<Arc<dyn GeometryArrayTrait>>;
 
     fn cast(&self, to_type: &GeoDataType) -> Self::Output {
+        if let Some(array) = zero_copy_cast(self, to_type)? {
+            return Ok(array);
+        }
+
         use GeoDataType::*;
         match to_type {
             LineString(ct) => {
@@ -604,6 +745,10 @@ impl<O: OffsetSizeTrait> Cast for MultiPolygonArray<O> {
     type Output = Result<Arc<dyn GeometryArrayTrait>>;
 
     fn cast(&self, to_type: &GeoDataType) -> Self::Output {
+        if let Some(array) = zero_copy_cast(self, to_type)? {
+            return Ok(array);
+        }
+
         use GeoDataType::*;
         match to_type {
             Polygon(ct) => {
@@ -717,6 +862,10 @@ impl<O: OffsetSizeTrait> Cast for MixedGeometryArray<O> {
 
     /// TODO: in the future, do more validation before trying to fill all geometries
     fn cast(&self, to_type: &GeoDataType) -> Self::Output {
+        if let Some(array) = zero_copy_cast(self, to_type)? {
+            return Ok(array);
+        }
+
         use GeoDataType::*;
         match to_type {
             Point(ct) => {
@@ -1091,11 +1240,17 @@ impl<O: OffsetSizeTrait> Cast for MixedGeometryArray<O> {
 impl Cast for &dyn GeometryArrayTrait {
     type Output = Result<Arc<dyn GeometryArrayTrait>>;
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            skip_all,
+            fields(num_rows = self.len(), from_type = ?self.data_type(), to_type = ?to_type)
+        )
+    )]
     fn cast(&self, to_type: &GeoDataType) -> Self::Output {
-        // TODO: not working :/
-        // if self.data_type() == to_type {
-        //     return Ok(Arc::new(self.to_owned()));
-        // }
+        if let Some(array) = zero_copy_cast(*self, to_type)? {
+            return Ok(array);
+        }
 
         use GeoDataType::*;
         match self.data_type() {
@@ -1125,7 +1280,7 @@ macro_rules! impl_chunked_cast_non_generic {
             fn cast(&self, to_type: &GeoDataType) -> Self::Output {
                 macro_rules! impl_cast {
                     ($method:ident) => {
-                        Arc::new(ChunkedGeometryArray::new(
+                        Arc::new(ChunkedGeometryArray::new_unchecked(
                             self.geometry_chunks()
                                 .iter()
                                 .map(|chunk| {
@@ -1171,7 +1326,7 @@ macro_rules! impl_chunked_cast_generic {
             fn cast(&self, to_type: &GeoDataType) -> Self::Output {
                 macro_rules! impl_cast {
                     ($method:ident) => {
-                        Arc::new(ChunkedGeometryArray::new(
+                        Arc::new(ChunkedGeometryArray::new_unchecked(
                             self.geometry_chunks()
                                 .iter()
                                 .map(|chunk| {
@@ -1219,3 +1374,94 @@ impl_chunked_cast_generic!(ChunkedMultiLineStringArray<O>);
 impl_chunked_cast_generic!(ChunkedMultiPolygonArray<O>);
 impl_chunked_cast_generic!(ChunkedMixedGeometryArray<O>);
 impl_chunked_cast_generic!(ChunkedGeometryCollectionArray<O>);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::{linestring, point, polygon};
+
+    fn coord_ptrs(coords: &CoordBuffer) -> Vec<*const f64> {
+        match coords {
+            CoordBuffer::Interleaved(c) => vec![c.coords.inner().as_ptr() as *const f64],
+            CoordBuffer::Separated(c) => vec![
+                c.x.inner().as_ptr() as *const f64,
+                c.y.inner().as_ptr() as *const f64,
+            ],
+        }
+    }
+
+    #[test]
+    fn point_to_multi_point_reuses_coords() {
+        let arr = point::point_array();
+        let ct = arr.coord_type();
+        let casted = arr.cast(&GeoDataType::MultiPoint(ct)).unwrap();
+        let casted = casted.as_ref().as_multi_point();
+        assert_eq!(coord_ptrs(arr.coords()), coord_ptrs(casted.coords()));
+        assert_eq!(casted.len(), 3);
+        assert_eq!(casted.value_as_geo(1), geo::MultiPoint(vec![point::p1()]));
+
+        let casted = arr.cast(&GeoDataType::LargeMultiPoint(ct)).unwrap();
+        let casted = casted.as_ref().as_large_multi_point();
+        assert_eq!(coord_ptrs(arr.coords()), coord_ptrs(casted.coords()));
+    }
+
+    #[test]
+    fn line_string_to_multi_line_string_reuses_coords() {
+        let arr = linestring::ls_array();
+        let ct = arr.coord_type();
+
+        let casted = arr.cast(&GeoDataType::LargeMultiLineString(ct)).unwrap();
+        let casted = casted.as_ref().as_large_multi_line_string();
+        assert_eq!(coord_ptrs(arr.coords()), coord_ptrs(casted.coords()));
+        assert_eq!(casted.len(), 2);
+        assert_eq!(
+            casted.value_as_geo(1),
+            geo::MultiLineString(vec![linestring::ls1()])
+        );
+
+        let casted = linestring::large_ls_array()
+            .cast(&GeoDataType::LineString(ct))
+            .unwrap();
+        let casted = casted.as_ref().as_line_string();
+        assert_eq!(casted.value_as_geo(0), linestring::ls0());
+    }
+
+    #[test]
+    fn polygon_to_multi_polygon_reuses_coords() {
+        let arr = polygon::p_array();
+        let ct = arr.coord_type();
+        let casted = arr.cast(&GeoDataType::MultiPolygon(ct)).unwrap();
+        let casted = casted.as_ref().as_multi_polygon();
+        assert_eq!(coord_ptrs(arr.coords()), coord_ptrs(casted.coords()));
+        assert_eq!(casted.len(), 2);
+        assert_eq!(
+            casted.value_as_geo(1),
+            geo::MultiPolygon(vec![polygon::p1()])
+        );
+    }
+
+    #[test]
+    fn coord_type_change_copies() {
+        let arr = point::point_array();
+        let to_type = GeoDataType::MultiPoint(CoordType::Separated);
+        assert_eq!(arr.coord_type(), CoordType::Interleaved);
+        let casted = arr.cast(&to_type).unwrap();
+        assert_eq!(casted.data_type(), &to_type);
+        let casted = casted.as_ref().as_multi_point();
+        assert_ne!(coord_ptrs(arr.coords()), coord_ptrs(casted.coords()));
+        assert_eq!(casted.value_as_geo(2), geo::MultiPoint(vec![point::p2()]));
+    }
+
+    #[test]
+    fn chunked_cast_reuses_coords() {
+        let chunks = vec![point::point_array(), point::point_array()];
+        let chunked = ChunkedGeometryArray::new(chunks);
+        let casted = chunked
+            .cast(&GeoDataType::MultiPoint(CoordType::Interleaved))
+            .unwrap();
+        let casted = casted.as_ref().as_multi_point();
+        for (input, output) in chunked.chunks().iter().zip(casted.chunks()) {
+            assert_eq!(coord_ptrs(input.coords()), coord_ptrs(output.coords()));
+        }
+    }
+}
@@ -0,0 +1,639 @@
+use std::sync::Arc;
+
+use arrow_array::OffsetSizeTrait;
+use geo::{BoundingRect, Coord, LineString, MultiLineString, MultiPolygon, Polygon, Rect};
+
+use crate::algorithm::native::MapChunks;
+use crate::array::*;
+use crate::chunked_array::*;
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+
+/// Clip each geometry of an array to an axis-aligned rectangle.
+///
+/// Line strings are clipped segment by segment with the Liang–Barsky algorithm, so a line string
+/// that leaves and re-enters the rectangle becomes several line strings, and a `LineStringArray`
+/// is clipped into a `MultiLineStringArray`. Each ring of a polygon is clipped with the
+/// Sutherland–Hodgman algorithm, so a concave polygon split in two by the rectangle stays a
+/// single polygon, joined by zero-area edges along the rectangle. This is the usual trade-off for
+/// tiling, where the result is rendered rather than used for further overlay operations.
+///
+/// Geometries whose bounding box is within the rectangle are returned unchanged, and those whose
+/// bounding box doesn't intersect it become empty, without looking at their coordinates. Parts
+/// and rings that are clipped away are removed, so a polygon entirely outside of the rectangle
+/// becomes an empty polygon. In mixed arrays, points outside of the rectangle become null.
+///
+/// Null geometries stay null.
+pub trait Clip {
+    type Output;
+
+    fn clip(&self, rect: &Rect) -> Self::Output;
+}
+
+impl<O: OffsetSizeTrait> Clip for LineStringArray<O> {
+    type Output = MultiLineStringArray<O>;
+
+    fn clip(&self, rect: &Rect) -> Self::Output {
+        let output_geoms: Vec<Option<MultiLineString>> = self
+            .iter_geo()
+            .map(|maybe_g| maybe_g.map(|geom| MultiLineString::new(clip_line_string(&geom, rect))))
+            .collect();
+
+        MultiLineStringBuilder::from_nullable_multi_line_strings(
+            &output_geoms,
+            Some(self.coord_type()),
+            self.metadata(),
+        )
+        .finish()
+    }
+}
+
+impl<O: OffsetSizeTrait> Clip for MultiLineStringArray<O> {
+    type Output = Self;
+
+    fn clip(&self, rect: &Rect) -> Self::Output {
+        let output_geoms: Vec<Option<MultiLineString>> = self
+            .iter_geo()
+            .map(|maybe_g| maybe_g.map(|geom| clip_multi_line_string(&geom, rect)))
+            .collect();
+
+        MultiLineStringBuilder::from_nullable_multi_line_strings(
+            &output_geoms,
+            Some(self.coord_type()),
+            self.metadata(),
+        )
+        .finish()
+    }
+}
+
+impl<O: OffsetSizeTrait> Clip for PolygonArray<O> {
+    type Output = Self;
+
+    fn clip(&self, rect: &Rect) -> Self::Output {
+        let output_geoms: Vec<Option<Polygon>> = self
+            .iter_geo()
+            .map(|maybe_g| {
+                maybe_g.map(|geom| clip_polygon(&geom, rect).unwrap_or_else(empty_polygon))
+            })
+            .collect();
+
+        PolygonBuilder::from_nullable_polygons(
+            &output_geoms,
+            Some(self.coord_type()),
+            self.metadata(),
+        )
+        .finish()
+    }
+}
+
+impl<O: OffsetSizeTrait> Clip for MultiPolygonArray<O> {
+    type Output = Self;
+
+    fn clip(&self, rect: &Rect) -> Self::Output {
+        let output_geoms: Vec<Option<MultiPolygon>> = self
+            .iter_geo()
+            .map(|maybe_g| maybe_g.map(|geom| clip_multi_polygon(&geom, rect)))
+            .collect();
+
+        MultiPolygonBuilder::from_nullable_multi_polygons(
+            &output_geoms,
+            Some(self.coord_type()),
+            self.metadata(),
+        )
+        .finish()
+    }
+}
+
+impl<O: OffsetSizeTrait> Clip for MixedGeometryArray<O> {
+    type Output = Result<Self>;
+
+    fn clip(&self, rect: &Rect) -> Self::Output {
+        let output_geoms: Vec<Option<geo::Geometry>> = self
+            .iter_geo()
+            .map(|maybe_g| maybe_g.and_then(|geom| clip_geometry(geom, rect)))
+            .collect();
+
+        Ok(MixedGeometryBuilder::from_nullable_geometries(
+            &output_geoms,
+            Some(self.coord_type()),
+            self.metadata(),
+            false,
+        )?
+        .finish())
+    }
+}
+
+impl Clip for &dyn GeometryArrayTrait {
+    type Output = Result<Arc<dyn GeometryArrayTrait>>;
+
+    fn clip(&self, rect: &Rect) -> Self::Output {
+        let result: Arc<dyn GeometryArrayTrait> = match self.data_type() {
+            GeoDataType::LineString(_) => Arc::new(self.as_line_string().clip(rect)),
+            GeoDataType::LargeLineString(_) => Arc::new(self.as_large_line_string().clip(rect)),
+            GeoDataType::Polygon(_) => Arc::new(self.as_polygon().clip(rect)),
+            GeoDataType::LargePolygon(_) => Arc::new(self.as_large_polygon().clip(rect)),
+            GeoDataType::MultiLineString(_) => Arc::new(self.as_multi_line_string().clip(rect)),
+            GeoDataType::LargeMultiLineString(_) => {
+                Arc::new(self.as_large_multi_line_string().clip(rect))
+            }
+            GeoDataType::MultiPolygon(_) => Arc::new(self.as_multi_polygon().clip(rect)),
+            GeoDataType::LargeMultiPolygon(_) => Arc::new(self.as_large_multi_polygon().clip(rect)),
+            GeoDataType::Mixed(_) => Arc::new(self.as_mixed().clip(rect)?),
+            GeoDataType::LargeMixed(_) => Arc::new(self.as_large_mixed().clip(rect)?),
+            _ => return Err(GeoArrowError::IncorrectType("".into())),
+        };
+        Ok(result)
+    }
+}
+
+macro_rules! impl_chunked {
+    ($chunked_array:ty, $output:ty) => {
+        impl<O: OffsetSizeTrait> Clip for $chunked_array {
+            type Output = $output;
+
+            fn clip(&self, rect: &Rect) -> Self::Output {
+                ChunkedGeometryArray::new_unchecked(self.map(|chunk| chunk.clip(rect)))
+            }
+        }
+    };
+}
+
+impl_chunked!(ChunkedLineStringArray<O>, ChunkedMultiLineStringArray<O>);
+impl_chunked!(ChunkedPolygonArray<O>, ChunkedPolygonArray<O>);
+impl_chunked!(
+    ChunkedMultiLineStringArray<O>,
+    ChunkedMultiLineStringArray<O>
+);
+impl_chunked!(ChunkedMultiPolygonArray<O>, ChunkedMultiPolygonArray<O>);
+
+impl<O: OffsetSizeTrait> Clip for ChunkedMixedGeometryArray<O> {
+    type Output = Result<Self>;
+
+    fn clip(&self, rect: &Rect) -> Self::Output {
+        Ok(ChunkedGeometryArray::new_unchecked(
+            self.try_map(|chunk| chunk.clip(rect))?,
+        ))
+    }
+}
+
+impl Clip for &dyn ChunkedGeometryArrayTrait {
+    type Output = Result<Arc<dyn ChunkedGeometryArrayTrait>>;
+
+    fn clip(&self, rect: &Rect) -> Self::Output {
+        let result: Arc<dyn ChunkedGeometryArrayTrait> = match self.data_type() {
+            GeoDataType::LineString(_) => Arc::new(self.as_line_string().clip(rect)),
+            GeoDataType::LargeLineString(_) => Arc::new(self.as_large_line_string().clip(rect)),
+            GeoDataType::Polygon(_) => Arc::new(self.as_polygon().clip(rect)),
+            GeoDataType::LargePolygon(_) => Arc::new(self.as_large_polygon().clip(rect)),
+            GeoDataType::MultiLineString(_) => Arc::new(self.as_multi_line_string().clip(rect)),
+            GeoDataType::LargeMultiLineString(_) => {
+                Arc::new(self.as_large_multi_line_string().clip(rect))
+            }
+            GeoDataType::MultiPolygon(_) => Arc::new(self.as_multi_polygon().clip(rect)),
+            GeoDataType::LargeMultiPolygon(_) => Arc::new(self.as_large_multi_polygon().clip(rect)),
+            GeoDataType::Mixed(_) => Arc::new(self.as_mixed().clip(rect)?),
+            GeoDataType::LargeMixed(_) => Arc::new(self.as_large_mixed().clip(rect)?),
+            _ => return Err(GeoArrowError::IncorrectType("".into())),
+        };
+        Ok(result)
+    }
+}
+
+/// How the bounding box of a geometry relates to the clipping rectangle.
+enum Relation {
+    Within,
+    Disjoint,
+    Crosses,
+}
+
+fn relate(geometry: &impl BoundingRect<f64, Output = Option<Rect>>, rect: &Rect) -> Relation {
+    let Some(bounds) = geometry.bounding_rect() else {
+        // Empty geometries have nothing to clip
+        return Relation::Disjoint;
+    };
+    let (min, max) = (bounds.min(), bounds.max());
+    if min.x >= rect.min().x
+        && max.x <= rect.max().x
+        && min.y >= rect.min().y
+        && max.y <= rect.max().y
+    {
+        Relation::Within
+    } else if min.x > rect.max().x
+        || max.x < rect.min().x
+        || min.y > rect.max().y
+        || max.y < rect.min().y
+    {
+        Relation::Disjoint
+    } else {
+        Relation::Crosses
+    }
+}
+
+fn empty_polygon() -> Polygon {
+    Polygon::new(LineString::new(vec![]), vec![])
+}
+
+fn clip_line_string(line_string: &LineString, rect: &Rect) -> Vec<LineString> {
+    match relate(line_string, rect) {
+        Relation::Within => vec![line_string.clone()],
+        Relation::Disjoint => vec![],
+        Relation::Crosses => clip_coords(&line_string.0, rect),
+    }
+}
+
+fn clip_multi_line_string(multi_line_string: &MultiLineString, rect: &Rect) -> MultiLineString {
+    match relate(multi_line_string, rect) {
+        Relation::Within => multi_line_string.clone(),
+        Relation::Disjoint => MultiLineString::new(vec![]),
+        Relation::Crosses => MultiLineString::new(
+            multi_line_string
+                .iter()
+                .flat_map(|line_string| clip_line_string(line_string, rect))
+                .collect(),
+        ),
+    }
+}
+
+/// Clip a polygon, returning `None` if its exterior is clipped away.
+fn clip_polygon(polygon: &Polygon, rect: &Rect) -> Option<Polygon> {
+    match relate(polygon, rect) {
+        Relation::Within => Some(polygon.clone()),
+        Relation::Disjoint => None,
+        Relation::Crosses => {
+            let exterior = clip_ring(&polygon.exterior().0, rect)?;
+            let interiors = polygon
+                .interiors()
+                .iter()
+                .filter_map(|interior| clip_ring(&interior.0, rect))
+                .collect();
+            Some(Polygon::new(exterior, interiors))
+        }
+    }
+}
+
+fn clip_multi_polygon(multi_polygon: &MultiPolygon, rect: &Rect) -> MultiPolygon {
+    match relate(multi_polygon, rect) {
+        Relation::Within => multi_polygon.clone(),
+        Relation::Disjoint => MultiPolygon::new(vec![]),
+        Relation::Crosses => MultiPolygon::new(
+            multi_polygon
+                .iter()
+                .filter_map(|polygon| clip_polygon(polygon, rect))
+                .collect(),
+        ),
+    }
+}
+
+/// Clip a geometry of a mixed array, returning `None` for a point outside of the rectangle.
+fn clip_geometry(geometry: geo::Geometry, rect: &Rect) -> Option<geo::Geometry> {
+    let clipped = match geometry {
+        geo::Geometry::Point(point) => {
+            if contains(rect, point.0) {
+                geo::Geometry::Point(point)
+            } else {
+                return None;
+            }
+        }
+        geo::Geometry::MultiPoint(multi_point) => geo::Geometry::MultiPoint(
+            multi_point
+                .into_iter()
+                .filter(|point| contains(rect, point.0))
+                .collect(),
+        ),
+        geo::Geometry::LineString(line_string) => {
+            let mut parts = clip_line_string(&line_string, rect);
+            if parts.len() == 1 {
+                geo::Geometry::LineString(parts.remove(0))
+            } else {
+                geo::Geometry::MultiLineString(MultiLineString::new(parts))
+            }
+        }
+        geo::Geometry::MultiLineString(multi_line_string) => {
+            geo::Geometry::MultiLineString(clip_multi_line_string(&multi_line_string, rect))
+        }
+        geo::Geometry::Polygon(polygon) => {
+            geo::Geometry::Polygon(clip_polygon(&polygon, rect).unwrap_or_else(empty_polygon))
+        }
+        geo::Geometry::MultiPolygon(multi_polygon) => {
+            geo::Geometry::MultiPolygon(clip_multi_polygon(&multi_polygon, rect))
+        }
+        other => other,
+    };
+    Some(clipped)
+}
+
+#[inline]
+fn contains(rect: &Rect, coord: Coord) -> bool {
+    coord.x >= rect.min().x
+        && coord.x <= rect.max().x
+        && coord.y >= rect.min().y
+        && coord.y <= rect.max().y
+}
+
+/// Clip the segments of a line string, joining consecutive clipped segments into line strings.
+fn clip_coords(coords: &[Coord], rect: &Rect) -> Vec<LineString> {
+    let mut parts = vec![];
+    let mut current: Vec<Coord> = vec![];
+    for segment in coords.windows(2) {
+        match clip_segment(segment[0], segment[1], rect) {
+            Some((start, end)) => {
+                if current.last() != Some(&start) {
+                    finish_part(&mut parts, &mut current);
+                    current.push(start);
+                }
+                if current.last() != Some(&end) {
+                    current.push(end);
+                }
+            }
+            None => finish_part(&mut parts, &mut current),
+        }
+    }
+    finish_part(&mut parts, &mut current);
+    parts
+}
+
+fn finish_part(parts: &mut Vec<LineString>, current: &mut Vec<Coord>) {
+    if current.len() > 1 {
+        parts.push(LineString::new(std::mem::take(current)));
+    } else {
+        current.clear();
+    }
+}
+
+/// Clip the segment from `start` to `end` with the Liang–Barsky algorithm, returning `None` if
+/// it doesn't intersect the rectangle.
+pub(super) fn clip_segment(start: Coord, end: Coord, rect: &Rect) -> Option<(Coord, Coord)> {
+    let delta = end - start;
+    let (mut t_start, mut t_end) = (0.0, 1.0);
+    for (p, q) in [
+        (-delta.x, start.x - rect.min().x),
+        (delta.x, rect.max().x - start.x),
+        (-delta.y, start.y - rect.min().y),
+        (delta.y, rect.max().y - start.y),
+    ] {
+        if p == 0.0 {
+            // Parallel to this edge, so entirely inside or outside of it
+            if q < 0.0 {
+                return None;
+            }
+        } else {
+            let t = q / p;
+            if p < 0.0 {
+                if t > t_end {
+                    return None;
+                }
+                t_start = f64::max(t_start, t);
+            } else {
+                if t < t_start {
+                    return None;
+                }
+                t_end = f64::min(t_end, t);
+            }
+        }
+    }
+
+    let clipped_start = if t_start > 0.0 {
+        start + delta * t_start
+    } else {
+        start
+    };
+    let clipped_end = if t_end < 1.0 {
+        start + delta * t_end
+    } else {
+        end
+    };
+    Some((clipped_start, clipped_end))
+}
+
+/// An edge of the clipping rectangle, as the axis it is perpendicular to, its position along
+/// that axis, and whether the inside of the rectangle is above that position.
+#[derive(Clone, Copy)]
+struct Edge {
+    is_x: bool,
+    value: f64,
+    inside_above: bool,
+}
+
+impl Edge {
+    #[inline]
+    fn position(&self, coord: Coord) -> f64 {
+        if self.is_x {
+            coord.x
+        } else {
+            coord.y
+        }
+    }
+
+    #[inline]
+    fn is_inside(&self, coord: Coord) -> bool {
+        if self.inside_above {
+            self.position(coord) >= self.value
+        } else {
+            self.position(coord) <= self.value
+        }
+    }
+
+    /// The intersection of this edge with the segment from `start` to `end`, which crosses it.
+    #[inline]
+    fn intersection(&self, start: Coord, end: Coord) -> Coord {
+        let t = (self.value - self.position(start)) / (self.position(end) - self.position(start));
+        let mut coord = start + (end - start) * t;
+        // Avoid rounding the intersection off of the edge
+        if self.is_x {
+            coord.x = self.value;
+        } else {
+            coord.y = self.value;
+        }
+        coord
+    }
+}
+
+/// Clip a ring with the Sutherland–Hodgman algorithm, returning `None` if fewer than 3 distinct
+/// coordinates remain.
+fn clip_ring(ring: &[Coord], rect: &Rect) -> Option<LineString> {
+    let mut output = ring.to_vec();
+    if output.len() > 1 && output.first() == output.last() {
+        output.pop();
+    }
+
+    let edges = [
+        Edge {
+            is_x: true,
+            value: rect.min().x,
+            inside_above: true,
+        },
+        Edge {
+            is_x: true,
+            value: rect.max().x,
+            inside_above: false,
+        },
+        Edge {
+            is_x: false,
+            value: rect.min().y,
+            inside_above: true,
+        },
+        Edge {
+            is_x: false,
+            value: rect.max().y,
+            inside_above: false,
+        },
+    ];
+    for edge in edges {
+        let input = std::mem::take(&mut output);
+        let Some(&last) = input.last() else {
+            break;
+        };
+        let mut previous = last;
+        for &coord in &input {
+            match (edge.is_inside(previous), edge.is_inside(coord)) {
+                (true, true) => output.push(coord),
+                (true, false) => output.push(edge.intersection(previous, coord)),
+                (false, true) => {
+                    output.push(edge.intersection(previous, coord));
+                    output.push(coord);
+                }
+                (false, false) => {}
+            }
+            previous = coord;
+        }
+    }
+
+    output.dedup();
+    if output.len() > 1 && output.first() == output.last() {
+        output.pop();
+    }
+    if output.len() < 3 {
+        return None;
+    }
+    output.push(output[0]);
+    Some(LineString::new(output))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::trait_::GeometryArraySelfMethods;
+    use geo::{line_string, point, polygon, Area, EuclideanLength};
+
+    fn rect() -> Rect {
+        Rect::new((0., 0.), (10., 10.))
+    }
+
+    #[test]
+    fn clip_line_strings() {
+        let line_strings = vec![
+            Some(line_string![(x: 1., y: 1.), (x: 2., y: 2.)]),
+            // Leaves and re-enters the rect
+            Some(line_string![(x: 5., y: 5.), (x: 15., y: 5.), (x: 15., y: 8.), (x: 5., y: 8.)]),
+            Some(line_string![(x: 20., y: 20.), (x: 30., y: 30.)]),
+            None,
+        ];
+        let arr = LineStringBuilder::<i32>::from_nullable_line_strings(
+            &line_strings,
+            Default::default(),
+            Default::default(),
+        )
+        .finish();
+
+        let clipped = arr.clip(&rect());
+        assert_eq!(
+            clipped.value_as_geo(0),
+            MultiLineString::new(vec![line_strings[0].clone().unwrap()])
+        );
+        assert_eq!(
+            clipped.value_as_geo(1),
+            MultiLineString::new(vec![
+                line_string![(x: 5., y: 5.), (x: 10., y: 5.)],
+                line_string![(x: 10., y: 8.), (x: 5., y: 8.)],
+            ])
+        );
+        assert!(clipped.value_as_geo(2).0.is_empty());
+        assert!(clipped.get(3).is_none());
+
+        let chunked = ChunkedGeometryArray::new_unchecked(vec![arr.slice(0, 2), arr.slice(2, 2)]);
+        let chunked_clipped = chunked.clip(&rect());
+        assert_eq!(chunked_clipped.chunks()[0].len(), 2);
+    }
+
+    #[test]
+    fn clip_polygons() {
+        let polygons: Vec<Polygon> = vec![
+            polygon![(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)],
+            polygon![(x: -5., y: -5.), (x: 5., y: -5.), (x: 5., y: 5.), (x: -5., y: 5.)],
+            polygon![(x: 20., y: 20.), (x: 30., y: 20.), (x: 30., y: 30.)],
+            polygon!(
+                exterior: [(x: -5., y: -5.), (x: 15., y: -5.), (x: 15., y: 15.), (x: -5., y: 15.)],
+                interiors: [[(x: 8., y: 4.), (x: 12., y: 4.), (x: 12., y: 6.), (x: 8., y: 6.)]],
+            ),
+        ];
+        let arr: PolygonArray<i32> = polygons.as_slice().into();
+
+        let clipped = arr.clip(&rect());
+        assert_eq!(clipped.value_as_geo(0), polygons[0]);
+        assert_eq!(clipped.value_as_geo(1).unsigned_area(), 25.);
+        assert!(clipped.value_as_geo(2).exterior().0.is_empty());
+        assert_eq!(clipped.value_as_geo(3).unsigned_area(), 100. - 4.);
+
+        let multi: MultiPolygonArray<i32> =
+            vec![MultiPolygon::new(polygons.clone())].as_slice().into();
+        let clipped = multi.clip(&rect());
+        assert_eq!(clipped.value_as_geo(0).0.len(), 3);
+    }
+
+    #[test]
+    fn clip_mixed() {
+        let geoms = vec![
+            geo::Geometry::Point(point!(x: 1., y: 1.)),
+            geo::Geometry::Point(point!(x: 11., y: 1.)),
+            geo::Geometry::LineString(line_string![(x: 5., y: 5.), (x: 15., y: 5.)]),
+        ];
+        let arr: MixedGeometryArray<i32> = geoms.as_slice().try_into().unwrap();
+        let dyn_arr: &dyn GeometryArrayTrait = &arr;
+        let clipped = dyn_arr.clip(&rect()).unwrap();
+        let clipped = clipped.as_ref().as_mixed();
+
+        assert_eq!(clipped.value_as_geo(0), geoms[0]);
+        assert!(clipped.get(1).is_none());
+        match clipped.value_as_geo(2) {
+            geo::Geometry::LineString(line_string) => {
+                assert_eq!(line_string.euclidean_length(), 5.)
+            }
+            other => panic!("Expected a LineString, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn clip_table() {
+        let polygons = vec![
+            Some(polygon![(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)]),
+            Some(polygon![(x: 20., y: 20.), (x: 30., y: 20.), (x: 30., y: 30.)]),
+            None,
+            Some(polygon![(x: 5., y: 5.), (x: 15., y: 5.), (x: 15., y: 15.), (x: 5., y: 15.)]),
+        ];
+        let arr = PolygonBuilder::<i32>::from_nullable_polygons(
+            &polygons,
+            Default::default(),
+            Default::default(),
+        )
+        .finish();
+        let ids = arrow_array::Int32Array::from(vec![0, 1, 2, 3]);
+        let schema = Arc::new(arrow_schema::Schema::new(vec![
+            arrow_schema::Field::new("id", arrow_schema::DataType::Int32, false),
+            arr.extension_field().as_ref().clone(),
+        ]));
+        let batch = arrow_array::RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(ids), arr.into_array_ref()],
+        )
+        .unwrap();
+        let table = crate::table::GeoTable::try_new(schema, vec![batch], 1).unwrap();
+
+        let clipped = table.clip(&rect()).unwrap();
+        assert_eq!(clipped.len(), 2);
+        let geometry = clipped.geometry().unwrap();
+        let chunk = geometry.as_ref().as_polygon().chunks()[0].clone();
+        assert_eq!(chunk.value_as_geo(1).unsigned_area(), 25.);
+    }
+}
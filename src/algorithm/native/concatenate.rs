@@ -1,25 +1,247 @@
+use std::ops::Range;
+use std::sync::Arc;
+
 use arrow_array::OffsetSizeTrait;
+use arrow_buffer::{BooleanBufferBuilder, NullBuffer, OffsetBuffer};
 
+use crate::array::metadata::ArrayMetadata;
 use crate::array::*;
 use crate::chunked_array::*;
-use crate::error::Result;
+use crate::error::{GeoArrowError, Result};
 use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
 
+/// Concatenate geometry arrays of the same type into a single array.
+///
+/// Point and (multi) line string and polygon arrays are concatenated at the buffer level: the
+/// coordinates and offsets of each input are copied once, only covering the range of the buffers
+/// used by a sliced input. Mixed and geometry collection arrays are concatenated geometry by
+/// geometry.
+///
+/// The output has the coordinate type and metadata of the first input.
 pub trait Concatenate: Sized {
     type Output;
 
     fn concatenate(&self) -> Self::Output;
 }
 
+/// Concatenate the coordinates at `range` of each buffer, converting them to the coordinate type
+/// of the first buffer if necessary.
+fn concat_coords(pieces: &[(&CoordBuffer, Range<usize>)]) -> CoordBuffer {
+    let capacity = pieces.iter().map(|(_, range)| range.len()).sum::<usize>();
+    match pieces.first().map(|(coords, _)| coords.coord_type()) {
+        Some(CoordType::Separated) => {
+            let mut x = Vec::with_capacity(capacity);
+            let mut y = Vec::with_capacity(capacity);
+            for (coords, range) in pieces {
+                match coords {
+                    CoordBuffer::Separated(coords) => {
+                        x.extend_from_slice(&coords.x[range.clone()]);
+                        y.extend_from_slice(&coords.y[range.clone()]);
+                    }
+                    _ => range.clone().for_each(|i| {
+                        x.push(coords.get_x(i));
+                        y.push(coords.get_y(i));
+                    }),
+                }
+            }
+            CoordBuffer::Separated(SeparatedCoordBuffer::new(x.into(), y.into()))
+        }
+        _ => {
+            let mut values = Vec::with_capacity(capacity * 2);
+            for (coords, range) in pieces {
+                match coords {
+                    CoordBuffer::Interleaved(coords) => {
+                        values.extend_from_slice(&coords.coords[range.start * 2..range.end * 2])
+                    }
+                    _ => range.clone().for_each(|i| {
+                        values.push(coords.get_x(i));
+                        values.push(coords.get_y(i));
+                    }),
+                }
+            }
+            CoordBuffer::Interleaved(InterleavedCoordBuffer::new(values.into()))
+        }
+    }
+}
+
+/// Concatenate the offsets of the items at `range` of each buffer, rebasing them so that each
+/// piece starts where the previous one ended.
+///
+/// Also returns the range of the next level (e.g. of the coordinates) covered by each piece.
+fn concat_offsets<O: OffsetSizeTrait>(
+    pieces: &[(&OffsetBuffer<O>, Range<usize>)],
+) -> Result<(OffsetBuffer<O>, Vec<Range<usize>>)> {
+    let capacity = pieces.iter().map(|(_, range)| range.len()).sum::<usize>() + 1;
+    let mut output = Vec::with_capacity(capacity);
+    output.push(O::zero());
+    let mut child_ranges = Vec::with_capacity(pieces.len());
+    let mut child_length = 0;
+    for (offsets, range) in pieces {
+        let start = offsets[range.start].as_usize();
+        let end = offsets[range.end].as_usize();
+        for offset in &offsets[range.start + 1..=range.end] {
+            let offset = child_length + offset.as_usize() - start;
+            output.push(O::from_usize(offset).ok_or(GeoArrowError::Overflow)?);
+        }
+        child_length += end - start;
+        child_ranges.push(start..end);
+    }
+    Ok((OffsetBuffer::new(output.into()), child_ranges))
+}
+
+/// Concatenate the validity of each array, given with its length. Returns `None` if no array
+/// has a validity buffer.
+fn concat_validity(pieces: &[(Option<&NullBuffer>, usize)]) -> Option<NullBuffer> {
+    if pieces.iter().all(|(validity, _)| validity.is_none()) {
+        return None;
+    }
+
+    let capacity = pieces.iter().map(|(_, len)| len).sum();
+    let mut builder = BooleanBufferBuilder::new(capacity);
+    for (validity, len) in pieces {
+        match validity {
+            Some(validity) => builder.append_buffer(validity.inner()),
+            None => builder.append_n(*len, true),
+        }
+    }
+    Some(NullBuffer::new(builder.finish()))
+}
+
+/// The metadata of the first array, or the default metadata if there are no arrays.
+fn first_metadata<G: GeometryArrayTrait>(arrays: &[G]) -> Arc<ArrayMetadata> {
+    arrays
+        .first()
+        .map(|array| array.metadata())
+        .unwrap_or_default()
+}
+
+fn validity_pieces<G: GeometryArrayTrait>(arrays: &[G]) -> Vec<(Option<&NullBuffer>, usize)> {
+    arrays
+        .iter()
+        .map(|array| (array.validity(), array.len()))
+        .collect()
+}
+
 impl Concatenate for &[PointArray] {
     type Output = Result<PointArray>;
 
     fn concatenate(&self) -> Self::Output {
-        let output_capacity = self.iter().fold(0, |sum, val| sum + val.buffer_lengths());
-        let mut builder = PointBuilder::with_capacity(output_capacity);
-        self.iter()
-            .for_each(|chunk| chunk.iter().for_each(|p| builder.push_point(p.as_ref())));
-        Ok(builder.finish())
+        let coords = self
+            .iter()
+            .map(|array| (&array.coords, 0..array.len()))
+            .collect::<Vec<_>>();
+        PointArray::try_new(
+            concat_coords(&coords),
+            concat_validity(&validity_pieces(self)),
+            first_metadata(self),
+        )
+    }
+}
+
+/// Concatenate arrays with one level of offsets into the coordinates.
+macro_rules! impl_concatenate_one_level {
+    ($array:ty) => {
+        impl<O: OffsetSizeTrait> Concatenate for &[$array] {
+            type Output = Result<$array>;
+
+            fn concatenate(&self) -> Self::Output {
+                let geom_offsets = self
+                    .iter()
+                    .map(|array| (&array.geom_offsets, 0..array.len()))
+                    .collect::<Vec<_>>();
+                let (geom_offsets, coord_ranges) = concat_offsets(&geom_offsets)?;
+                let coords = self
+                    .iter()
+                    .zip(coord_ranges)
+                    .map(|(array, range)| (&array.coords, range))
+                    .collect::<Vec<_>>();
+                <$array>::try_new(
+                    concat_coords(&coords),
+                    geom_offsets,
+                    concat_validity(&validity_pieces(self)),
+                    first_metadata(self),
+                )
+            }
+        }
+    };
+}
+
+impl_concatenate_one_level!(LineStringArray<O>);
+impl_concatenate_one_level!(MultiPointArray<O>);
+
+/// Concatenate arrays with two levels of offsets into the coordinates.
+macro_rules! impl_concatenate_two_levels {
+    ($array:ty) => {
+        impl<O: OffsetSizeTrait> Concatenate for &[$array] {
+            type Output = Result<$array>;
+
+            fn concatenate(&self) -> Self::Output {
+                let geom_offsets = self
+                    .iter()
+                    .map(|array| (&array.geom_offsets, 0..array.len()))
+                    .collect::<Vec<_>>();
+                let (geom_offsets, ring_ranges) = concat_offsets(&geom_offsets)?;
+                let ring_offsets = self
+                    .iter()
+                    .zip(ring_ranges)
+                    .map(|(array, range)| (&array.ring_offsets, range))
+                    .collect::<Vec<_>>();
+                let (ring_offsets, coord_ranges) = concat_offsets(&ring_offsets)?;
+                let coords = self
+                    .iter()
+                    .zip(coord_ranges)
+                    .map(|(array, range)| (&array.coords, range))
+                    .collect::<Vec<_>>();
+                <$array>::try_new(
+                    concat_coords(&coords),
+                    geom_offsets,
+                    ring_offsets,
+                    concat_validity(&validity_pieces(self)),
+                    first_metadata(self),
+                )
+            }
+        }
+    };
+}
+
+impl_concatenate_two_levels!(PolygonArray<O>);
+impl_concatenate_two_levels!(MultiLineStringArray<O>);
+
+impl<O: OffsetSizeTrait> Concatenate for &[MultiPolygonArray<O>] {
+    type Output = Result<MultiPolygonArray<O>>;
+
+    fn concatenate(&self) -> Self::Output {
+        let geom_offsets = self
+            .iter()
+            .map(|array| (&array.geom_offsets, 0..array.len()))
+            .collect::<Vec<_>>();
+        let (geom_offsets, polygon_ranges) = concat_offsets(&geom_offsets)?;
+        let polygon_offsets = self
+            .iter()
+            .zip(polygon_ranges)
+            .map(|(array, range)| (&array.polygon_offsets, range))
+            .collect::<Vec<_>>();
+        let (polygon_offsets, ring_ranges) = concat_offsets(&polygon_offsets)?;
+        let ring_offsets = self
+            .iter()
+            .zip(ring_ranges)
+            .map(|(array, range)| (&array.ring_offsets, range))
+            .collect::<Vec<_>>();
+        let (ring_offsets, coord_ranges) = concat_offsets(&ring_offsets)?;
+        let coords = self
+            .iter()
+            .zip(coord_ranges)
+            .map(|(array, range)| (&array.coords, range))
+            .collect::<Vec<_>>();
+        MultiPolygonArray::try_new(
+            concat_coords(&coords),
+            geom_offsets,
+            polygon_offsets,
+            ring_offsets,
+            concat_validity(&validity_pieces(self)),
+            first_metadata(self),
+        )
     }
 }
 
@@ -44,36 +266,6 @@ macro_rules! impl_concatenate {
     };
 }
 
-impl_concatenate!(
-    LineStringArray<O>,
-    LineStringCapacity,
-    LineStringBuilder<O>,
-    push_line_string
-);
-impl_concatenate!(
-    PolygonArray<O>,
-    PolygonCapacity,
-    PolygonBuilder<O>,
-    push_polygon
-);
-impl_concatenate!(
-    MultiPointArray<O>,
-    MultiPointCapacity,
-    MultiPointBuilder<O>,
-    push_multi_point
-);
-impl_concatenate!(
-    MultiLineStringArray<O>,
-    MultiLineStringCapacity,
-    MultiLineStringBuilder<O>,
-    push_multi_line_string
-);
-impl_concatenate!(
-    MultiPolygonArray<O>,
-    MultiPolygonCapacity,
-    MultiPolygonBuilder<O>,
-    push_multi_polygon
-);
 impl_concatenate!(
     MixedGeometryArray<O>,
     MixedCapacity,
@@ -117,3 +309,62 @@ impl_chunked_concatenate!(
     ChunkedGeometryCollectionArray<O>,
     GeometryCollectionArray<O>
 );
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::{multipolygon, point, polygon};
+    use crate::trait_::GeometryArraySelfMethods;
+
+    #[test]
+    fn concatenate_sliced_polygons() {
+        let with_null: PolygonArray<i32> =
+            vec![Some(polygon::p0()), None, Some(polygon::p1())].into();
+        let chunks = vec![polygon::p_array().slice(1, 1), with_null.slice(1, 2)];
+        let concatenated = chunks.as_slice().concatenate().unwrap();
+        assert_eq!(concatenated.len(), 3);
+        assert_eq!(
+            concatenated.iter_geo().collect::<Vec<_>>(),
+            vec![Some(polygon::p1()), None, Some(polygon::p1())]
+        );
+        // Only the coordinates of the sliced geometries are copied, and p1 has 10 coordinates
+        assert_eq!(concatenated.coords.len(), 20);
+    }
+
+    #[test]
+    fn concatenate_multi_polygons() {
+        let array = multipolygon::mp_array();
+        let chunks = vec![array.clone(), array.slice(1, 1)];
+        let concatenated = chunks.as_slice().concatenate().unwrap();
+        assert!(concatenated.validity().is_none());
+        assert_eq!(
+            concatenated.iter_geo_values().collect::<Vec<_>>(),
+            vec![
+                multipolygon::mp0(),
+                multipolygon::mp1(),
+                multipolygon::mp1()
+            ]
+        );
+    }
+
+    #[test]
+    fn concatenate_mixed_coord_types() {
+        let chunks = vec![
+            point::point_array().into_coord_type(CoordType::Separated),
+            point::point_array(),
+        ];
+        let concatenated = chunks.as_slice().concatenate().unwrap();
+        assert_eq!(concatenated.coord_type(), CoordType::Separated);
+        assert_eq!(
+            concatenated.iter_geo_values().collect::<Vec<_>>(),
+            vec![
+                point::p0(),
+                point::p1(),
+                point::p2(),
+                point::p0(),
+                point::p1(),
+                point::p2()
+            ]
+        );
+    }
+}
@@ -21,7 +21,7 @@ pub trait Downcast {
     type Output;
 
     /// The data type that downcasting would result in.
-    fn downcasted_data_type(&self, small_offsets: bool) -> GeoDataType;
+    fn downcasted_data_type(&self, small_offsets: bool, coerce_multi: bool) -> GeoDataType;
 
     /// If possible, convert this array to a simpler and/or smaller data type
     ///
@@ -35,17 +35,22 @@ pub trait Downcast {
     ///
     /// If small_offsets is `true`, it will additionally try to convert `i64` offset buffers to
     /// `i32` if the offsets would not overflow.
-    fn downcast(&self, small_offsets: bool) -> Self::Output;
+    ///
+    /// If coerce_multi is `true`, a MixedGeometry array that holds only one single geometry type
+    /// and its multi counterpart, e.g. only Polygons and MultiPolygons, is converted to the multi
+    /// type, promoting each single geometry to a multi geometry with one part. This applies to
+    /// Point/MultiPoint, LineString/MultiLineString and Polygon/MultiPolygon.
+    fn downcast(&self, small_offsets: bool, coerce_multi: bool) -> Self::Output;
 }
 
 impl Downcast for PointArray {
     type Output = Arc<dyn GeometryArrayTrait>;
 
-    fn downcasted_data_type(&self, small_offsets: bool) -> GeoDataType {
+    fn downcasted_data_type(&self, small_offsets: bool, coerce_multi: bool) -> GeoDataType {
         *self.data_type()
     }
 
-    fn downcast(&self, small_offsets: bool) -> Self::Output {
+    fn downcast(&self, small_offsets: bool, coerce_multi: bool) -> Self::Output {
         Arc::new(self.clone())
     }
 }
@@ -91,7 +96,7 @@ fn can_downcast_multi<O: OffsetSizeTrait>(buffer: &OffsetBuffer<O>) -> bool {
 impl<O: OffsetSizeTrait> Downcast for LineStringArray<O> {
     type Output = Arc<dyn GeometryArrayTrait>;
 
-    fn downcasted_data_type(&self, small_offsets: bool) -> GeoDataType {
+    fn downcasted_data_type(&self, small_offsets: bool, coerce_multi: bool) -> GeoDataType {
         match self.data_type() {
             GeoDataType::LineString(ct) => GeoDataType::LineString(*ct),
             GeoDataType::LargeLineString(ct) => {
@@ -105,8 +110,11 @@ impl<O: OffsetSizeTrait> Downcast for LineStringArray<O> {
         }
     }
 
-    fn downcast(&self, small_offsets: bool) -> Self::Output {
-        match (*self.data_type(), self.downcasted_data_type(small_offsets)) {
+    fn downcast(&self, small_offsets: bool, coerce_multi: bool) -> Self::Output {
+        match (
+            *self.data_type(),
+            self.downcasted_data_type(small_offsets, coerce_multi),
+        ) {
             (GeoDataType::LineString(_), GeoDataType::LineString(_))
             | (GeoDataType::LargeLineString(_), GeoDataType::LargeLineString(_)) => {
                 Arc::new(self.clone())
@@ -120,7 +128,7 @@ impl<O: OffsetSizeTrait> Downcast for LineStringArray<O> {
 impl<O: OffsetSizeTrait> Downcast for PolygonArray<O> {
     type Output = Arc<dyn GeometryArrayTrait>;
 
-    fn downcasted_data_type(&self, small_offsets: bool) -> GeoDataType {
+    fn downcasted_data_type(&self, small_offsets: bool, coerce_multi: bool) -> GeoDataType {
         match self.data_type() {
             GeoDataType::Polygon(ct) => GeoDataType::Polygon(*ct),
             GeoDataType::LargePolygon(ct) => {
@@ -134,7 +142,7 @@ impl<O: OffsetSizeTrait> Downcast for PolygonArray<O> {
         }
     }
 
-    fn downcast(&self, small_offsets: bool) -> Self::Output {
+    fn downcast(&self, small_offsets: bool, coerce_multi: bool) -> Self::Output {
         Arc::new(self.clone())
     }
 }
@@ -142,7 +150,7 @@ impl<O: OffsetSizeTrait> Downcast for PolygonArray<O> {
 impl<O: OffsetSizeTrait> Downcast for MultiPointArray<O> {
     type Output = Arc<dyn GeometryArrayTrait>;
 
-    fn downcasted_data_type(&self, small_offsets: bool) -> GeoDataType {
+    fn downcasted_data_type(&self, small_offsets: bool, coerce_multi: bool) -> GeoDataType {
         match self.data_type() {
             GeoDataType::MultiPoint(ct) => {
                 if can_downcast_multi(&self.geom_offsets) {
@@ -164,7 +172,7 @@ impl<O: OffsetSizeTrait> Downcast for MultiPointArray<O> {
             _ => unreachable!(),
         }
     }
-    fn downcast(&self, small_offsets: bool) -> Self::Output {
+    fn downcast(&self, small_offsets: bool, coerce_multi: bool) -> Self::Output {
         // Note: this won't allow a downcast for empty MultiPoints
         if self.geom_offsets.last().to_usize().unwrap() == self.len() {
             return Arc::new(PointArray::new(
@@ -181,7 +189,7 @@ impl<O: OffsetSizeTrait> Downcast for MultiPointArray<O> {
 impl<O: OffsetSizeTrait> Downcast for MultiLineStringArray<O> {
     type Output = Arc<dyn GeometryArrayTrait>;
 
-    fn downcasted_data_type(&self, small_offsets: bool) -> GeoDataType {
+    fn downcasted_data_type(&self, small_offsets: bool, coerce_multi: bool) -> GeoDataType {
         match self.data_type() {
             GeoDataType::MultiLineString(ct) => {
                 if can_downcast_multi(&self.geom_offsets) {
@@ -205,7 +213,7 @@ impl<O: OffsetSizeTrait> Downcast for MultiLineStringArray<O> {
         }
     }
 
-    fn downcast(&self, small_offsets: bool) -> Self::Output {
+    fn downcast(&self, small_offsets: bool, coerce_multi: bool) -> Self::Output {
         if self.geom_offsets.last().to_usize().unwrap() == self.len() {
             return Arc::new(LineStringArray::new(
                 self.coords.clone(),
@@ -222,7 +230,7 @@ impl<O: OffsetSizeTrait> Downcast for MultiLineStringArray<O> {
 impl<O: OffsetSizeTrait> Downcast for MultiPolygonArray<O> {
     type Output = Arc<dyn GeometryArrayTrait>;
 
-    fn downcasted_data_type(&self, small_offsets: bool) -> GeoDataType {
+    fn downcasted_data_type(&self, small_offsets: bool, coerce_multi: bool) -> GeoDataType {
         match self.data_type() {
             GeoDataType::MultiPolygon(ct) => {
                 if can_downcast_multi(&self.geom_offsets) {
@@ -246,7 +254,7 @@ impl<O: OffsetSizeTrait> Downcast for MultiPolygonArray<O> {
         }
     }
 
-    fn downcast(&self, small_offsets: bool) -> Self::Output {
+    fn downcast(&self, small_offsets: bool, coerce_multi: bool) -> Self::Output {
         if self.geom_offsets.last().to_usize().unwrap() == self.len() {
             return Arc::new(PolygonArray::new(
                 self.coords.clone(),
@@ -261,10 +269,41 @@ impl<O: OffsetSizeTrait> Downcast for MultiPolygonArray<O> {
     }
 }
 
+/// The multi geometry type that a mixed array can be coerced to, if its only children are a single
+/// geometry type and its multi counterpart.
+fn coerced_multi_data_type<O: OffsetSizeTrait>(
+    array: &MixedGeometryArray<O>,
+) -> Option<GeoDataType> {
+    let ct = array.coord_type();
+    let data_type = match (
+        array.has_points(),
+        array.has_line_strings(),
+        array.has_polygons(),
+        array.has_multi_points(),
+        array.has_multi_line_strings(),
+        array.has_multi_polygons(),
+    ) {
+        (true, false, false, true, false, false) => match O::IS_LARGE {
+            true => GeoDataType::LargeMultiPoint(ct),
+            false => GeoDataType::MultiPoint(ct),
+        },
+        (false, true, false, false, true, false) => match O::IS_LARGE {
+            true => GeoDataType::LargeMultiLineString(ct),
+            false => GeoDataType::MultiLineString(ct),
+        },
+        (false, false, true, false, false, true) => match O::IS_LARGE {
+            true => GeoDataType::LargeMultiPolygon(ct),
+            false => GeoDataType::MultiPolygon(ct),
+        },
+        _ => return None,
+    };
+    Some(data_type)
+}
+
 impl<O: OffsetSizeTrait> Downcast for MixedGeometryArray<O> {
     type Output = Arc<dyn GeometryArrayTrait>;
 
-    fn downcasted_data_type(&self, small_offsets: bool) -> GeoDataType {
+    fn downcasted_data_type(&self, small_offsets: bool, coerce_multi: bool) -> GeoDataType {
         let coord_type = self.coord_type();
 
         if self.points.is_some()
@@ -288,7 +327,7 @@ impl<O: OffsetSizeTrait> Downcast for MixedGeometryArray<O> {
                 .line_strings
                 .as_ref()
                 .unwrap()
-                .downcasted_data_type(small_offsets);
+                .downcasted_data_type(small_offsets, coerce_multi);
         }
 
         if self.points.is_none()
@@ -302,7 +341,7 @@ impl<O: OffsetSizeTrait> Downcast for MixedGeometryArray<O> {
                 .polygons
                 .as_ref()
                 .unwrap()
-                .downcasted_data_type(small_offsets);
+                .downcasted_data_type(small_offsets, coerce_multi);
         }
 
         if self.points.is_none()
@@ -316,7 +355,7 @@ impl<O: OffsetSizeTrait> Downcast for MixedGeometryArray<O> {
                 .multi_points
                 .as_ref()
                 .unwrap()
-                .downcasted_data_type(small_offsets);
+                .downcasted_data_type(small_offsets, coerce_multi);
         }
 
         if self.points.is_none()
@@ -330,7 +369,7 @@ impl<O: OffsetSizeTrait> Downcast for MixedGeometryArray<O> {
                 .multi_line_strings
                 .as_ref()
                 .unwrap()
-                .downcasted_data_type(small_offsets);
+                .downcasted_data_type(small_offsets, coerce_multi);
         }
 
         if self.points.is_none()
@@ -344,13 +383,19 @@ impl<O: OffsetSizeTrait> Downcast for MixedGeometryArray<O> {
                 .multi_polygons
                 .as_ref()
                 .unwrap()
-                .downcasted_data_type(small_offsets);
+                .downcasted_data_type(small_offsets, coerce_multi);
+        }
+
+        if coerce_multi {
+            if let Some(data_type) = coerced_multi_data_type(self) {
+                return data_type;
+            }
         }
 
         *self.data_type()
     }
 
-    fn downcast(&self, small_offsets: bool) -> Self::Output {
+    fn downcast(&self, small_offsets: bool, coerce_multi: bool) -> Self::Output {
         // TODO: do I need to handle the slice offset?
         if self.points.is_some()
             && self.line_strings.is_none()
@@ -369,7 +414,11 @@ impl<O: OffsetSizeTrait> Downcast for MixedGeometryArray<O> {
             && self.multi_line_strings.is_none()
             && self.multi_polygons.is_none()
         {
-            return self.line_strings.as_ref().unwrap().downcast(small_offsets);
+            return self
+                .line_strings
+                .as_ref()
+                .unwrap()
+                .downcast(small_offsets, coerce_multi);
         }
 
         if self.points.is_none()
@@ -379,7 +428,11 @@ impl<O: OffsetSizeTrait> Downcast for MixedGeometryArray<O> {
             && self.multi_line_strings.is_none()
             && self.multi_polygons.is_none()
         {
-            return self.polygons.as_ref().unwrap().downcast(small_offsets);
+            return self
+                .polygons
+                .as_ref()
+                .unwrap()
+                .downcast(small_offsets, coerce_multi);
         }
 
         if self.points.is_none()
@@ -389,7 +442,11 @@ impl<O: OffsetSizeTrait> Downcast for MixedGeometryArray<O> {
             && self.multi_line_strings.is_none()
             && self.multi_polygons.is_none()
         {
-            return self.multi_points.as_ref().unwrap().downcast(small_offsets);
+            return self
+                .multi_points
+                .as_ref()
+                .unwrap()
+                .downcast(small_offsets, coerce_multi);
         }
 
         if self.points.is_none()
@@ -403,7 +460,7 @@ impl<O: OffsetSizeTrait> Downcast for MixedGeometryArray<O> {
                 .multi_line_strings
                 .as_ref()
                 .unwrap()
-                .downcast(small_offsets);
+                .downcast(small_offsets, coerce_multi);
         }
 
         if self.points.is_none()
@@ -417,7 +474,13 @@ impl<O: OffsetSizeTrait> Downcast for MixedGeometryArray<O> {
                 .multi_polygons
                 .as_ref()
                 .unwrap()
-                .downcast(small_offsets);
+                .downcast(small_offsets, coerce_multi);
+        }
+
+        if coerce_multi {
+            if let Some(data_type) = coerced_multi_data_type(self) {
+                return self.cast(&data_type).unwrap();
+            }
         }
 
         Arc::new(self.clone())
@@ -427,14 +490,14 @@ impl<O: OffsetSizeTrait> Downcast for MixedGeometryArray<O> {
 impl<O: OffsetSizeTrait> Downcast for GeometryCollectionArray<O> {
     type Output = Arc<dyn GeometryArrayTrait>;
 
-    fn downcasted_data_type(&self, small_offsets: bool) -> GeoDataType {
+    fn downcasted_data_type(&self, small_offsets: bool, coerce_multi: bool) -> GeoDataType {
         todo!()
     }
-    fn downcast(&self, small_offsets: bool) -> Self::Output {
+    fn downcast(&self, small_offsets: bool, coerce_multi: bool) -> Self::Output {
         // TODO: support downcasting with null elements
         if self.geom_offsets.last().to_usize().unwrap() == self.len() && self.null_count() == 0 {
             // Call downcast on the mixed array
-            return self.array.downcast(small_offsets);
+            return self.array.downcast(small_offsets, coerce_multi);
         }
 
         Arc::new(self.clone())
@@ -444,10 +507,10 @@ impl<O: OffsetSizeTrait> Downcast for GeometryCollectionArray<O> {
 impl Downcast for RectArray {
     type Output = Arc<dyn GeometryArrayTrait>;
 
-    fn downcasted_data_type(&self, small_offsets: bool) -> GeoDataType {
+    fn downcasted_data_type(&self, small_offsets: bool, coerce_multi: bool) -> GeoDataType {
         *self.data_type()
     }
-    fn downcast(&self, small_offsets: bool) -> Self::Output {
+    fn downcast(&self, small_offsets: bool, coerce_multi: bool) -> Self::Output {
         Arc::new(self.clone())
     }
 }
@@ -455,74 +518,104 @@ impl Downcast for RectArray {
 impl Downcast for &dyn GeometryArrayTrait {
     type Output = Arc<dyn GeometryArrayTrait>;
 
-    fn downcasted_data_type(&self, small_offsets: bool) -> GeoDataType {
+    fn downcasted_data_type(&self, small_offsets: bool, coerce_multi: bool) -> GeoDataType {
         match self.data_type() {
-            GeoDataType::Point(_) => self.as_point().downcasted_data_type(small_offsets),
-            GeoDataType::LineString(_) => self.as_line_string().downcasted_data_type(small_offsets),
+            GeoDataType::Point(_) => self
+                .as_point()
+                .downcasted_data_type(small_offsets, coerce_multi),
+            GeoDataType::LineString(_) => self
+                .as_line_string()
+                .downcasted_data_type(small_offsets, coerce_multi),
             GeoDataType::LargeLineString(_) => self
                 .as_large_line_string()
-                .downcasted_data_type(small_offsets),
-            GeoDataType::Polygon(_) => self.as_polygon().downcasted_data_type(small_offsets),
-            GeoDataType::LargePolygon(_) => {
-                self.as_large_polygon().downcasted_data_type(small_offsets)
-            }
-            GeoDataType::MultiPoint(_) => self.as_multi_point().downcasted_data_type(small_offsets),
+                .downcasted_data_type(small_offsets, coerce_multi),
+            GeoDataType::Polygon(_) => self
+                .as_polygon()
+                .downcasted_data_type(small_offsets, coerce_multi),
+            GeoDataType::LargePolygon(_) => self
+                .as_large_polygon()
+                .downcasted_data_type(small_offsets, coerce_multi),
+            GeoDataType::MultiPoint(_) => self
+                .as_multi_point()
+                .downcasted_data_type(small_offsets, coerce_multi),
             GeoDataType::LargeMultiPoint(_) => self
                 .as_large_multi_point()
-                .downcasted_data_type(small_offsets),
+                .downcasted_data_type(small_offsets, coerce_multi),
             GeoDataType::MultiLineString(_) => self
                 .as_multi_line_string()
-                .downcasted_data_type(small_offsets),
+                .downcasted_data_type(small_offsets, coerce_multi),
             GeoDataType::LargeMultiLineString(_) => self
                 .as_large_multi_line_string()
-                .downcasted_data_type(small_offsets),
-            GeoDataType::MultiPolygon(_) => {
-                self.as_multi_polygon().downcasted_data_type(small_offsets)
-            }
+                .downcasted_data_type(small_offsets, coerce_multi),
+            GeoDataType::MultiPolygon(_) => self
+                .as_multi_polygon()
+                .downcasted_data_type(small_offsets, coerce_multi),
             GeoDataType::LargeMultiPolygon(_) => self
                 .as_large_multi_polygon()
-                .downcasted_data_type(small_offsets),
-            GeoDataType::Mixed(_) => self.as_mixed().downcasted_data_type(small_offsets),
-            GeoDataType::LargeMixed(_) => self.as_large_mixed().downcasted_data_type(small_offsets),
+                .downcasted_data_type(small_offsets, coerce_multi),
+            GeoDataType::Mixed(_) => self
+                .as_mixed()
+                .downcasted_data_type(small_offsets, coerce_multi),
+            GeoDataType::LargeMixed(_) => self
+                .as_large_mixed()
+                .downcasted_data_type(small_offsets, coerce_multi),
             GeoDataType::GeometryCollection(_) => self
                 .as_geometry_collection()
-                .downcasted_data_type(small_offsets),
+                .downcasted_data_type(small_offsets, coerce_multi),
             GeoDataType::LargeGeometryCollection(_) => self
                 .as_large_geometry_collection()
-                .downcasted_data_type(small_offsets),
-            GeoDataType::Rect => self.as_rect().downcasted_data_type(small_offsets),
+                .downcasted_data_type(small_offsets, coerce_multi),
+            GeoDataType::Rect => self
+                .as_rect()
+                .downcasted_data_type(small_offsets, coerce_multi),
             // TODO: downcast largewkb to wkb
             GeoDataType::WKB => *self.data_type(),
             GeoDataType::LargeWKB => *self.data_type(),
         }
     }
 
-    fn downcast(&self, small_offsets: bool) -> Self::Output {
+    fn downcast(&self, small_offsets: bool, coerce_multi: bool) -> Self::Output {
         match self.data_type() {
-            GeoDataType::Point(_) => self.as_point().downcast(small_offsets),
-            GeoDataType::LineString(_) => self.as_line_string().downcast(small_offsets),
-            GeoDataType::LargeLineString(_) => self.as_large_line_string().downcast(small_offsets),
-            GeoDataType::Polygon(_) => self.as_polygon().downcast(small_offsets),
-            GeoDataType::LargePolygon(_) => self.as_large_polygon().downcast(small_offsets),
-            GeoDataType::MultiPoint(_) => self.as_multi_point().downcast(small_offsets),
-            GeoDataType::LargeMultiPoint(_) => self.as_large_multi_point().downcast(small_offsets),
-            GeoDataType::MultiLineString(_) => self.as_multi_line_string().downcast(small_offsets),
-            GeoDataType::LargeMultiLineString(_) => {
-                self.as_large_multi_line_string().downcast(small_offsets)
+            GeoDataType::Point(_) => self.as_point().downcast(small_offsets, coerce_multi),
+            GeoDataType::LineString(_) => {
+                self.as_line_string().downcast(small_offsets, coerce_multi)
             }
-            GeoDataType::MultiPolygon(_) => self.as_multi_polygon().downcast(small_offsets),
-            GeoDataType::LargeMultiPolygon(_) => {
-                self.as_large_multi_polygon().downcast(small_offsets)
-            }
-            GeoDataType::Mixed(_) => self.as_mixed().downcast(small_offsets),
-            GeoDataType::LargeMixed(_) => self.as_large_mixed().downcast(small_offsets),
-            GeoDataType::GeometryCollection(_) => {
-                self.as_geometry_collection().downcast(small_offsets)
+            GeoDataType::LargeLineString(_) => self
+                .as_large_line_string()
+                .downcast(small_offsets, coerce_multi),
+            GeoDataType::Polygon(_) => self.as_polygon().downcast(small_offsets, coerce_multi),
+            GeoDataType::LargePolygon(_) => self
+                .as_large_polygon()
+                .downcast(small_offsets, coerce_multi),
+            GeoDataType::MultiPoint(_) => {
+                self.as_multi_point().downcast(small_offsets, coerce_multi)
             }
-            GeoDataType::LargeGeometryCollection(_) => {
-                self.as_large_geometry_collection().downcast(small_offsets)
+            GeoDataType::LargeMultiPoint(_) => self
+                .as_large_multi_point()
+                .downcast(small_offsets, coerce_multi),
+            GeoDataType::MultiLineString(_) => self
+                .as_multi_line_string()
+                .downcast(small_offsets, coerce_multi),
+            GeoDataType::LargeMultiLineString(_) => self
+                .as_large_multi_line_string()
+                .downcast(small_offsets, coerce_multi),
+            GeoDataType::MultiPolygon(_) => self
+                .as_multi_polygon()
+                .downcast(small_offsets, coerce_multi),
+            GeoDataType::LargeMultiPolygon(_) => self
+                .as_large_multi_polygon()
+                .downcast(small_offsets, coerce_multi),
+            GeoDataType::Mixed(_) => self.as_mixed().downcast(small_offsets, coerce_multi),
+            GeoDataType::LargeMixed(_) => {
+                self.as_large_mixed().downcast(small_offsets, coerce_multi)
             }
-            GeoDataType::Rect => self.as_rect().downcast(small_offsets),
+            GeoDataType::GeometryCollection(_) => self
+                .as_geometry_collection()
+                .downcast(small_offsets, coerce_multi),
+            GeoDataType::LargeGeometryCollection(_) => self
+                .as_large_geometry_collection()
+                .downcast(small_offsets, coerce_multi),
+            GeoDataType::Rect => self.as_rect().downcast(small_offsets, coerce_multi),
             GeoDataType::WKB => Arc::new(self.as_wkb().clone()),
             GeoDataType::LargeWKB => Arc::new(self.as_large_wkb().clone()),
         }
@@ -566,10 +659,10 @@ fn resolve_types(types: &HashSet<GeoDataType>) -> GeoDataType {
 impl Downcast for ChunkedPointArray {
     type Output = Arc<dyn ChunkedGeometryArrayTrait>;
 
-    fn downcasted_data_type(&self, small_offsets: bool) -> GeoDataType {
+    fn downcasted_data_type(&self, small_offsets: bool, coerce_multi: bool) -> GeoDataType {
         *self.data_type()
     }
-    fn downcast(&self, small_offsets: bool) -> Self::Output {
+    fn downcast(&self, small_offsets: bool, coerce_multi: bool) -> Self::Output {
         Arc::new(self.clone())
     }
 }
@@ -579,15 +672,15 @@ macro_rules! impl_chunked_downcast {
         impl<O: OffsetSizeTrait> Downcast for $chunked_array {
             type Output = Arc<dyn ChunkedGeometryArrayTrait>;
 
-            fn downcasted_data_type(&self, small_offsets: bool) -> GeoDataType {
+            fn downcasted_data_type(&self, small_offsets: bool, coerce_multi: bool) -> GeoDataType {
                 let mut types = HashSet::new();
                 self.chunks.iter().for_each(|chunk| {
-                    types.insert(chunk.downcasted_data_type(small_offsets));
+                    types.insert(chunk.downcasted_data_type(small_offsets, coerce_multi));
                 });
                 resolve_types(&types)
             }
-            fn downcast(&self, small_offsets: bool) -> Self::Output {
-                let to_data_type = self.downcasted_data_type(small_offsets);
+            fn downcast(&self, small_offsets: bool, coerce_multi: bool) -> Self::Output {
+                let to_data_type = self.downcasted_data_type(small_offsets, coerce_multi);
 
                 if to_data_type == *self.data_type() {
                     return Arc::new(self.clone());
@@ -610,10 +703,10 @@ impl_chunked_downcast!(ChunkedGeometryCollectionArray<O>);
 impl Downcast for ChunkedRectArray {
     type Output = Arc<dyn ChunkedGeometryArrayTrait>;
 
-    fn downcasted_data_type(&self, small_offsets: bool) -> GeoDataType {
+    fn downcasted_data_type(&self, small_offsets: bool, coerce_multi: bool) -> GeoDataType {
         *self.data_type()
     }
-    fn downcast(&self, small_offsets: bool) -> Self::Output {
+    fn downcast(&self, small_offsets: bool, coerce_multi: bool) -> Self::Output {
         Arc::new(self.clone())
     }
 }
@@ -621,72 +714,102 @@ impl Downcast for ChunkedRectArray {
 impl Downcast for &dyn ChunkedGeometryArrayTrait {
     type Output = Arc<dyn ChunkedGeometryArrayTrait>;
 
-    fn downcasted_data_type(&self, small_offsets: bool) -> GeoDataType {
+    fn downcasted_data_type(&self, small_offsets: bool, coerce_multi: bool) -> GeoDataType {
         match self.data_type() {
-            GeoDataType::Point(_) => self.as_point().downcasted_data_type(small_offsets),
-            GeoDataType::LineString(_) => self.as_line_string().downcasted_data_type(small_offsets),
+            GeoDataType::Point(_) => self
+                .as_point()
+                .downcasted_data_type(small_offsets, coerce_multi),
+            GeoDataType::LineString(_) => self
+                .as_line_string()
+                .downcasted_data_type(small_offsets, coerce_multi),
             GeoDataType::LargeLineString(_) => self
                 .as_large_line_string()
-                .downcasted_data_type(small_offsets),
-            GeoDataType::Polygon(_) => self.as_polygon().downcasted_data_type(small_offsets),
-            GeoDataType::LargePolygon(_) => {
-                self.as_large_polygon().downcasted_data_type(small_offsets)
-            }
-            GeoDataType::MultiPoint(_) => self.as_multi_point().downcasted_data_type(small_offsets),
+                .downcasted_data_type(small_offsets, coerce_multi),
+            GeoDataType::Polygon(_) => self
+                .as_polygon()
+                .downcasted_data_type(small_offsets, coerce_multi),
+            GeoDataType::LargePolygon(_) => self
+                .as_large_polygon()
+                .downcasted_data_type(small_offsets, coerce_multi),
+            GeoDataType::MultiPoint(_) => self
+                .as_multi_point()
+                .downcasted_data_type(small_offsets, coerce_multi),
             GeoDataType::LargeMultiPoint(_) => self
                 .as_large_multi_point()
-                .downcasted_data_type(small_offsets),
+                .downcasted_data_type(small_offsets, coerce_multi),
             GeoDataType::MultiLineString(_) => self
                 .as_multi_line_string()
-                .downcasted_data_type(small_offsets),
+                .downcasted_data_type(small_offsets, coerce_multi),
             GeoDataType::LargeMultiLineString(_) => self
                 .as_large_multi_line_string()
-                .downcasted_data_type(small_offsets),
-            GeoDataType::MultiPolygon(_) => {
-                self.as_multi_polygon().downcasted_data_type(small_offsets)
-            }
+                .downcasted_data_type(small_offsets, coerce_multi),
+            GeoDataType::MultiPolygon(_) => self
+                .as_multi_polygon()
+                .downcasted_data_type(small_offsets, coerce_multi),
             GeoDataType::LargeMultiPolygon(_) => self
                 .as_large_multi_polygon()
-                .downcasted_data_type(small_offsets),
-            GeoDataType::Mixed(_) => self.as_mixed().downcasted_data_type(small_offsets),
-            GeoDataType::LargeMixed(_) => self.as_large_mixed().downcasted_data_type(small_offsets),
+                .downcasted_data_type(small_offsets, coerce_multi),
+            GeoDataType::Mixed(_) => self
+                .as_mixed()
+                .downcasted_data_type(small_offsets, coerce_multi),
+            GeoDataType::LargeMixed(_) => self
+                .as_large_mixed()
+                .downcasted_data_type(small_offsets, coerce_multi),
             GeoDataType::GeometryCollection(_) => self
                 .as_geometry_collection()
-                .downcasted_data_type(small_offsets),
+                .downcasted_data_type(small_offsets, coerce_multi),
             GeoDataType::LargeGeometryCollection(_) => self
                 .as_large_geometry_collection()
-                .downcasted_data_type(small_offsets),
-            GeoDataType::Rect => self.as_rect().downcasted_data_type(small_offsets),
+                .downcasted_data_type(small_offsets, coerce_multi),
+            GeoDataType::Rect => self
+                .as_rect()
+                .downcasted_data_type(small_offsets, coerce_multi),
             _ => todo!(),
         }
     }
 
-    fn downcast(&self, small_offsets: bool) -> Self::Output {
+    fn downcast(&self, small_offsets: bool, coerce_multi: bool) -> Self::Output {
         match self.data_type() {
-            GeoDataType::Point(_) => self.as_point().downcast(small_offsets),
-            GeoDataType::LineString(_) => self.as_line_string().downcast(small_offsets),
-            GeoDataType::LargeLineString(_) => self.as_large_line_string().downcast(small_offsets),
-            GeoDataType::Polygon(_) => self.as_polygon().downcast(small_offsets),
-            GeoDataType::LargePolygon(_) => self.as_large_polygon().downcast(small_offsets),
-            GeoDataType::MultiPoint(_) => self.as_multi_point().downcast(small_offsets),
-            GeoDataType::LargeMultiPoint(_) => self.as_large_multi_point().downcast(small_offsets),
-            GeoDataType::MultiLineString(_) => self.as_multi_line_string().downcast(small_offsets),
-            GeoDataType::LargeMultiLineString(_) => {
-                self.as_large_multi_line_string().downcast(small_offsets)
+            GeoDataType::Point(_) => self.as_point().downcast(small_offsets, coerce_multi),
+            GeoDataType::LineString(_) => {
+                self.as_line_string().downcast(small_offsets, coerce_multi)
             }
-            GeoDataType::MultiPolygon(_) => self.as_multi_polygon().downcast(small_offsets),
-            GeoDataType::LargeMultiPolygon(_) => {
-                self.as_large_multi_polygon().downcast(small_offsets)
-            }
-            GeoDataType::Mixed(_) => self.as_mixed().downcast(small_offsets),
-            GeoDataType::LargeMixed(_) => self.as_large_mixed().downcast(small_offsets),
-            GeoDataType::GeometryCollection(_) => {
-                self.as_geometry_collection().downcast(small_offsets)
+            GeoDataType::LargeLineString(_) => self
+                .as_large_line_string()
+                .downcast(small_offsets, coerce_multi),
+            GeoDataType::Polygon(_) => self.as_polygon().downcast(small_offsets, coerce_multi),
+            GeoDataType::LargePolygon(_) => self
+                .as_large_polygon()
+                .downcast(small_offsets, coerce_multi),
+            GeoDataType::MultiPoint(_) => {
+                self.as_multi_point().downcast(small_offsets, coerce_multi)
             }
-            GeoDataType::LargeGeometryCollection(_) => {
-                self.as_large_geometry_collection().downcast(small_offsets)
+            GeoDataType::LargeMultiPoint(_) => self
+                .as_large_multi_point()
+                .downcast(small_offsets, coerce_multi),
+            GeoDataType::MultiLineString(_) => self
+                .as_multi_line_string()
+                .downcast(small_offsets, coerce_multi),
+            GeoDataType::LargeMultiLineString(_) => self
+                .as_large_multi_line_string()
+                .downcast(small_offsets, coerce_multi),
+            GeoDataType::MultiPolygon(_) => self
+                .as_multi_polygon()
+                .downcast(small_offsets, coerce_multi),
+            GeoDataType::LargeMultiPolygon(_) => self
+                .as_large_multi_polygon()
+                .downcast(small_offsets, coerce_multi),
+            GeoDataType::Mixed(_) => self.as_mixed().downcast(small_offsets, coerce_multi),
+            GeoDataType::LargeMixed(_) => {
+                self.as_large_mixed().downcast(small_offsets, coerce_multi)
             }
-            GeoDataType::Rect => self.as_rect().downcast(small_offsets),
+            GeoDataType::GeometryCollection(_) => self
+                .as_geometry_collection()
+                .downcast(small_offsets, coerce_multi),
+            GeoDataType::LargeGeometryCollection(_) => self
+                .as_large_geometry_collection()
+                .downcast(small_offsets, coerce_multi),
+            GeoDataType::Rect => self.as_rect().downcast(small_offsets, coerce_multi),
             GeoDataType::WKB => Arc::new(self.as_wkb().clone()),
             GeoDataType::LargeWKB => Arc::new(self.as_large_wkb().clone()),
         }
@@ -696,11 +819,28 @@ impl Downcast for &dyn ChunkedGeometryArrayTrait {
 impl Downcast for GeoTable {
     type Output = Result<GeoTable>;
 
-    fn downcasted_data_type(&self, small_offsets: bool) -> GeoDataType {
+    fn downcasted_data_type(&self, small_offsets: bool, coerce_multi: bool) -> GeoDataType {
         self.geometry_data_type().unwrap()
     }
-    fn downcast(&self, small_offsets: bool) -> Self::Output {
-        let downcasted_chunked_geometry = self.geometry()?.as_ref().downcast(small_offsets);
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "downcast_table",
+            skip_all,
+            fields(
+                num_rows = self.len(),
+                num_batches = self.batches().len(),
+                small_offsets = small_offsets,
+                coerce_multi = coerce_multi
+            )
+        )
+    )]
+    fn downcast(&self, small_offsets: bool, coerce_multi: bool) -> Self::Output {
+        let downcasted_chunked_geometry = self
+            .geometry()?
+            .as_ref()
+            .downcast(small_offsets, coerce_multi);
 
         let (schema, batches, geometry_column_index) = self.clone().into_inner();
 
@@ -718,8 +858,27 @@ impl Downcast for GeoTable {
             })
             .collect::<Vec<_>>();
 
-        // Add the new geometry column at the end of the new fields
-        new_fields.push(downcasted_chunked_geometry.extension_field());
+        // Add the new geometry column at the end of the new fields. Downcasting doesn't change
+        // the CRS or edges of the geometries, so carry over the existing extension metadata.
+        let mut new_geometry_field = downcasted_chunked_geometry.extension_field();
+        if let Some(extension_metadata) = schema
+            .field(geometry_column_index)
+            .metadata()
+            .get("ARROW:extension:metadata")
+        {
+            let mut field_metadata = new_geometry_field.metadata().clone();
+            field_metadata.insert(
+                "ARROW:extension:metadata".to_string(),
+                extension_metadata.clone(),
+            );
+            new_geometry_field = Arc::new(
+                new_geometry_field
+                    .as_ref()
+                    .clone()
+                    .with_metadata(field_metadata),
+            );
+        }
+        new_fields.push(new_geometry_field);
         let new_geometry_column_index = new_fields.len() - 1;
 
         // Construct a new schema with the new fields
@@ -752,9 +911,52 @@ impl Downcast for GeoTable {
 //         }
 
 //         //  else {
-//         //     let x = ChunkedGeometryArray::new(self.chunks.iter().map(|chunk| chunk.downcast()).collect());
+//         //     let x = ChunkedGeometryArray::new_unchecked(self.chunks.iter().map(|chunk| chunk.downcast()).collect());
 
 //         // }
 
 //     }
 // }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::{multipolygon, polygon};
+    use crate::trait_::GeometryArrayAccessor;
+
+    #[test]
+    fn coerce_multi_polygons() {
+        let geoms = vec![
+            geo::Geometry::Polygon(polygon::p0()),
+            geo::Geometry::MultiPolygon(multipolygon::mp0()),
+        ];
+        let arr = MixedGeometryBuilder::<i32>::from_geometries(
+            geoms.as_slice(),
+            None,
+            Default::default(),
+            false,
+        )
+        .unwrap()
+        .finish();
+
+        assert_eq!(
+            arr.downcasted_data_type(true, false),
+            GeoDataType::Mixed(CoordType::Interleaved)
+        );
+        assert_eq!(
+            arr.downcast(true, false).data_type(),
+            &GeoDataType::Mixed(CoordType::Interleaved)
+        );
+
+        let coerced_type = GeoDataType::MultiPolygon(CoordType::Interleaved);
+        assert_eq!(arr.downcasted_data_type(true, true), coerced_type);
+        let coerced = arr.downcast(true, true);
+        assert_eq!(coerced.data_type(), &coerced_type);
+        let coerced = coerced.as_ref().as_multi_polygon();
+        assert_eq!(
+            coerced.value_as_geo(0),
+            geo::MultiPolygon(vec![polygon::p0()])
+        );
+        assert_eq!(coerced.value_as_geo(1), multipolygon::mp0());
+    }
+}
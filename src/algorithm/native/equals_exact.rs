@@ -0,0 +1,145 @@
+use crate::geo_traits::{
+    GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait, MultiLineStringTrait,
+    MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait, RectTrait,
+};
+use geo::CoordFloat;
+
+/// Whether two geometries are structurally equal, with each coordinate within `tolerance` of the
+/// matching coordinate of the other geometry.
+///
+/// As with `equals_exact` in GEOS, the geometries must have the same type and the same number of
+/// parts, rings and coordinates, in the same order. Two geometries covering the same area with
+/// their coordinates in a different order, such as a ring with a different start point, are not
+/// equal. A `tolerance` of zero compares the coordinates exactly.
+///
+/// Points with NaN coordinates, which are empty, are equal to each other.
+pub fn equals_exact<T: CoordFloat>(
+    left: &impl GeometryTrait<T = T>,
+    right: &impl GeometryTrait<T = T>,
+    tolerance: T,
+) -> bool {
+    match (left.as_type(), right.as_type()) {
+        (GeometryType::Point(l), GeometryType::Point(r)) => point_equals_exact(l, r, tolerance),
+        (GeometryType::LineString(l), GeometryType::LineString(r)) => {
+            line_string_equals_exact(l, r, tolerance)
+        }
+        (GeometryType::Polygon(l), GeometryType::Polygon(r)) => {
+            polygon_equals_exact(l, r, tolerance)
+        }
+        (GeometryType::MultiPoint(l), GeometryType::MultiPoint(r)) => {
+            l.num_points() == r.num_points()
+                && l.points()
+                    .zip(r.points())
+                    .all(|(l, r)| point_equals_exact(&l, &r, tolerance))
+        }
+        (GeometryType::MultiLineString(l), GeometryType::MultiLineString(r)) => {
+            l.num_lines() == r.num_lines()
+                && l.lines()
+                    .zip(r.lines())
+                    .all(|(l, r)| line_string_equals_exact(&l, &r, tolerance))
+        }
+        (GeometryType::MultiPolygon(l), GeometryType::MultiPolygon(r)) => {
+            l.num_polygons() == r.num_polygons()
+                && l.polygons()
+                    .zip(r.polygons())
+                    .all(|(l, r)| polygon_equals_exact(&l, &r, tolerance))
+        }
+        (GeometryType::Rect(l), GeometryType::Rect(r)) => {
+            within_tolerance(l.lower().x_y(), r.lower().x_y(), tolerance)
+                && within_tolerance(l.upper().x_y(), r.upper().x_y(), tolerance)
+        }
+        (GeometryType::GeometryCollection(l), GeometryType::GeometryCollection(r)) => {
+            l.num_geometries() == r.num_geometries()
+                && l.geometries()
+                    .zip(r.geometries())
+                    .all(|(l, r)| equals_exact(&l, &r, tolerance))
+        }
+        _ => false,
+    }
+}
+
+#[inline]
+fn within_tolerance<T: CoordFloat>(left: (T, T), right: (T, T), tolerance: T) -> bool {
+    (left.0 - right.0).hypot(left.1 - right.1) <= tolerance
+}
+
+#[inline]
+fn point_equals_exact<T: CoordFloat>(
+    left: &impl PointTrait<T = T>,
+    right: &impl PointTrait<T = T>,
+    tolerance: T,
+) -> bool {
+    if left.x().is_nan() && right.x().is_nan() && left.y().is_nan() && right.y().is_nan() {
+        return true;
+    }
+
+    within_tolerance(left.x_y(), right.x_y(), tolerance)
+}
+
+#[inline]
+fn line_string_equals_exact<T: CoordFloat>(
+    left: &impl LineStringTrait<T = T>,
+    right: &impl LineStringTrait<T = T>,
+    tolerance: T,
+) -> bool {
+    left.num_coords() == right.num_coords()
+        && left
+            .coords()
+            .zip(right.coords())
+            .all(|(l, r)| within_tolerance(l.x_y(), r.x_y(), tolerance))
+}
+
+#[inline]
+fn polygon_equals_exact<T: CoordFloat>(
+    left: &impl PolygonTrait<T = T>,
+    right: &impl PolygonTrait<T = T>,
+    tolerance: T,
+) -> bool {
+    let exteriors_equal = match (left.exterior(), right.exterior()) {
+        (None, None) => true,
+        (Some(l), Some(r)) => line_string_equals_exact(&l, &r, tolerance),
+        _ => false,
+    };
+
+    exteriors_equal
+        && left.num_interiors() == right.num_interiors()
+        && left
+            .interiors()
+            .zip(right.interiors())
+            .all(|(l, r)| line_string_equals_exact(&l, &r, tolerance))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use geo::{line_string, point, polygon, Geometry};
+
+    #[test]
+    fn tolerance() {
+        let left = Geometry::from(line_string![(x: 0., y: 0.), (x: 1., y: 1.)]);
+        let right = Geometry::from(line_string![(x: 0., y: 0.), (x: 1., y: 1.1)]);
+        assert!(equals_exact(&left, &left, 0.));
+        assert!(!equals_exact(&left, &right, 0.));
+        assert!(!equals_exact(&left, &right, 0.05));
+        assert!(equals_exact(&left, &right, 0.2));
+    }
+
+    #[test]
+    fn structure() {
+        let polygon = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)];
+        let rotated = polygon![(x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 0.)];
+        assert!(!equals_exact(
+            &Geometry::from(polygon.clone()),
+            &Geometry::from(rotated),
+            0.
+        ));
+        assert!(!equals_exact(
+            &Geometry::from(polygon.exterior().clone()),
+            &Geometry::from(polygon),
+            10.
+        ));
+
+        let empty = Geometry::from(point!(x: f64::NAN, y: f64::NAN));
+        assert!(equals_exact(&empty, &empty, 0.));
+    }
+}
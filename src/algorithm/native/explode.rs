@@ -1,10 +1,11 @@
 use std::sync::Arc;
 
 use arrow::compute::take;
-use arrow_array::{Int32Array, OffsetSizeTrait, RecordBatch};
-use arrow_buffer::OffsetBuffer;
-use arrow_schema::SchemaBuilder;
+use arrow_array::{Int32Array, OffsetSizeTrait, RecordBatch, UInt32Array};
+use arrow_buffer::{NullBuffer, OffsetBuffer};
+use arrow_schema::Schema;
 
+use crate::algorithm::native::Take;
 use crate::array::*;
 use crate::chunked_array::{
     from_geoarrow_chunks, ChunkedArray, ChunkedGeometryArray, ChunkedGeometryArrayTrait,
@@ -12,20 +13,60 @@ use crate::chunked_array::{
 use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
 use crate::table::GeoTable;
+use crate::trait_::GeometryArraySelfMethods;
 use crate::GeometryArrayTrait;
 
+/// What to do with null and empty multi-part geometries when exploding.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ExplodeNulls {
+    /// Produce one row with a null geometry.
+    #[default]
+    Keep,
+
+    /// Produce no rows.
+    Drop,
+}
+
+/// Options for [`Explode`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ExplodeOptions {
+    /// What to do with null and empty multi-part geometries.
+    pub nulls: ExplodeNulls,
+}
+
+impl ExplodeOptions {
+    /// Set what to do with null and empty multi-part geometries.
+    pub fn with_nulls(mut self, nulls: ExplodeNulls) -> Self {
+        self.nulls = nulls;
+        self
+    }
+}
+
+/// Explode multi-part geometries into their single-part components.
+///
+/// MultiPoint, MultiLineString and MultiPolygon arrays become Point, LineString and Polygon
+/// arrays, and GeometryCollection arrays become mixed arrays of their members. Single-part arrays
+/// are returned unchanged. Null and empty multi-part geometries produce one null geometry or no
+/// geometry at all, as set by [`ExplodeOptions`].
 pub trait Explode {
     type Output;
 
     /// Returns the exploded geometries and, if an explode needs to happen, the indices that should
     /// be passed into a [`take`][arrow::compute::take] operation.
-    fn explode(&self) -> Self::Output;
+    fn explode(&self) -> Self::Output {
+        self.explode_with_options(Default::default())
+    }
+
+    /// Returns the exploded geometries and, if an explode needs to happen, the indices that should
+    /// be passed into a [`take`][arrow::compute::take] operation, handling null and empty
+    /// geometries as set by `options`.
+    fn explode_with_options(&self, options: ExplodeOptions) -> Self::Output;
 }
 
 impl Explode for PointArray {
     type Output = (Self, Option<Int32Array>);
 
-    fn explode(&self) -> Self::Output {
+    fn explode_with_options(&self, _options: ExplodeOptions) -> Self::Output {
         (self.clone(), None)
     }
 }
@@ -33,7 +74,7 @@ impl Explode for PointArray {
 impl<O: OffsetSizeTrait> Explode for LineStringArray<O> {
     type Output = (Self, Option<Int32Array>);
 
-    fn explode(&self) -> Self::Output {
+    fn explode_with_options(&self, _options: ExplodeOptions) -> Self::Output {
         (self.clone(), None)
     }
 }
@@ -41,7 +82,7 @@ impl<O: OffsetSizeTrait> Explode for LineStringArray<O> {
 impl<O: OffsetSizeTrait> Explode for PolygonArray<O> {
     type Output = (Self, Option<Int32Array>);
 
-    fn explode(&self) -> Self::Output {
+    fn explode_with_options(&self, _options: ExplodeOptions) -> Self::Output {
         (self.clone(), None)
     }
 }
@@ -53,11 +94,12 @@ impl<O: OffsetSizeTrait> Explode for PolygonArray<O> {
 /// ```notest
 /// [0, 0, 1, 1, 1, 2, 2, 2, 2, 2]
 /// ```
-/// Also note that the length of the `indices` created is the same as the last value of the
-/// offsets.
+/// Also note that the length of the `indices` created is the number of elements between the
+/// first and the last offset.
 fn explode_offsets<O: OffsetSizeTrait>(offsets: &OffsetBuffer<O>) -> Int32Array {
-    let mut take_indices: Vec<i32> =
-        Vec::with_capacity(offsets.last().unwrap().to_usize().unwrap());
+    let num_parts =
+        offsets.last().unwrap().to_usize().unwrap() - offsets.first().unwrap().to_usize().unwrap();
+    let mut take_indices: Vec<i32> = Vec::with_capacity(num_parts);
     for (offset_idx, offset_start_end) in offsets.as_ref().windows(2).enumerate() {
         let offset_start = offset_start_end[0].to_usize().unwrap();
         let offset_end = offset_start_end[1].to_usize().unwrap();
@@ -68,74 +110,141 @@ fn explode_offsets<O: OffsetSizeTrait>(offsets: &OffsetBuffer<O>) -> Int32Array
     Int32Array::new(take_indices.into(), None)
 }
 
+/// The indices of the parts to take, and of the rows they come from, to explode geometries whose
+/// parts are `parts[offsets[i]..offsets[i + 1]]`.
+///
+/// The part indices are `None` when the parts are in the right order already, which is when there
+/// are no null or empty geometries to keep. The parts between the first and the last offset can
+/// then be used as they are.
+fn explode_parts<O: OffsetSizeTrait>(
+    offsets: &OffsetBuffer<O>,
+    nulls: Option<&NullBuffer>,
+    options: ExplodeOptions,
+) -> (Option<UInt32Array>, Int32Array) {
+    let is_null = |i: usize| nulls.is_some_and(|nulls| nulls.is_null(i));
+    let has_nulls = nulls.is_some_and(|nulls| nulls.null_count() > 0);
+    let has_empty = offsets.windows(2).any(|w| w[0] == w[1]);
+    if !has_nulls && (!has_empty || options.nulls == ExplodeNulls::Drop) {
+        return (None, explode_offsets(offsets));
+    }
+
+    let mut part_indices: Vec<Option<u32>> = Vec::with_capacity(offsets.len());
+    let mut take_indices: Vec<i32> = Vec::with_capacity(offsets.len());
+    for (i, start_end) in offsets.windows(2).enumerate() {
+        let start = start_end[0].to_usize().unwrap();
+        let end = start_end[1].to_usize().unwrap();
+        let row: i32 = i.try_into().unwrap();
+        if is_null(i) || start == end {
+            if options.nulls == ExplodeNulls::Keep {
+                part_indices.push(None);
+                take_indices.push(row);
+            }
+            continue;
+        }
+        for part in start..end {
+            part_indices.push(Some(part.try_into().unwrap()));
+            take_indices.push(row);
+        }
+    }
+    (Some(part_indices.into()), take_indices.into())
+}
+
+/// The parts between the first and the last offset.
+fn slice_parts<O: OffsetSizeTrait, G: GeometryArraySelfMethods>(
+    parts: G,
+    offsets: &OffsetBuffer<O>,
+) -> G {
+    let start = offsets.first().unwrap().to_usize().unwrap();
+    let end = offsets.last().unwrap().to_usize().unwrap();
+    parts.slice(start, end - start)
+}
+
 impl<O: OffsetSizeTrait> Explode for MultiPointArray<O> {
     type Output = (PointArray, Option<Int32Array>);
 
-    fn explode(&self) -> Self::Output {
-        assert_eq!(
-            self.null_count(),
-            0,
-            "Null values not yet supported in explode"
-        );
-
-        let exploded_geoms = PointArray::new(self.coords.clone(), None, self.metadata());
-        let take_indices = explode_offsets(self.geom_offsets());
+    fn explode_with_options(&self, options: ExplodeOptions) -> Self::Output {
+        let parts = PointArray::new(self.coords.clone(), None, self.metadata());
+        let (part_indices, take_indices) =
+            explode_parts(self.geom_offsets(), self.nulls(), options);
+        let exploded_geoms = match part_indices {
+            Some(part_indices) => parts.take(&part_indices),
+            None => slice_parts(parts, self.geom_offsets()),
+        };
         (exploded_geoms, Some(take_indices))
     }
 }
 
 impl<O: OffsetSizeTrait> Explode for MultiLineStringArray<O> {
-    type Output = (LineStringArray<O>, Option<Int32Array>);
+    type Output = Result<(LineStringArray<O>, Option<Int32Array>)>;
 
-    fn explode(&self) -> Self::Output {
-        assert_eq!(
-            self.null_count(),
-            0,
-            "Null values not yet supported in explode"
-        );
-
-        let exploded_geoms = LineStringArray::new(
+    fn explode_with_options(&self, options: ExplodeOptions) -> Self::Output {
+        let parts = LineStringArray::new(
             self.coords.clone(),
             self.ring_offsets.clone(),
             None,
             self.metadata(),
         );
-        let take_indices = explode_offsets(self.geom_offsets());
-        (exploded_geoms, Some(take_indices))
+        let (part_indices, take_indices) =
+            explode_parts(self.geom_offsets(), self.nulls(), options);
+        let exploded_geoms = match part_indices {
+            Some(part_indices) => parts.take(&part_indices)?,
+            None => slice_parts(parts, self.geom_offsets()),
+        };
+        Ok((exploded_geoms, Some(take_indices)))
     }
 }
 
 impl<O: OffsetSizeTrait> Explode for MultiPolygonArray<O> {
-    type Output = (PolygonArray<O>, Option<Int32Array>);
-
-    fn explode(&self) -> Self::Output {
-        assert_eq!(
-            self.null_count(),
-            0,
-            "Null values not yet supported in explode"
-        );
+    type Output = Result<(PolygonArray<O>, Option<Int32Array>)>;
 
-        let exploded_geoms = PolygonArray::new(
+    fn explode_with_options(&self, options: ExplodeOptions) -> Self::Output {
+        let parts = PolygonArray::new(
             self.coords.clone(),
             self.polygon_offsets.clone(),
             self.ring_offsets.clone(),
             None,
             self.metadata(),
         );
-        let take_indices = explode_offsets(self.geom_offsets());
-        (exploded_geoms, Some(take_indices))
+        let (part_indices, take_indices) =
+            explode_parts(self.geom_offsets(), self.nulls(), options);
+        let exploded_geoms = match part_indices {
+            Some(part_indices) => parts.take(&part_indices)?,
+            None => slice_parts(parts, self.geom_offsets()),
+        };
+        Ok((exploded_geoms, Some(take_indices)))
+    }
+}
+
+impl<O: OffsetSizeTrait> Explode for GeometryCollectionArray<O> {
+    type Output = Result<(MixedGeometryArray<O>, Option<Int32Array>)>;
+
+    fn explode_with_options(&self, options: ExplodeOptions) -> Self::Output {
+        let (part_indices, take_indices) = explode_parts(&self.geom_offsets, self.nulls(), options);
+        let exploded_geoms = match part_indices {
+            Some(part_indices) => self.array.take(&part_indices)?,
+            None => slice_parts(self.array.clone(), &self.geom_offsets),
+        };
+        Ok((exploded_geoms, Some(take_indices)))
     }
 }
 
 impl Explode for &dyn GeometryArrayTrait {
     type Output = Result<(Arc<dyn GeometryArrayTrait>, Option<Int32Array>)>;
 
-    fn explode(&self) -> Self::Output {
+    fn explode_with_options(&self, options: ExplodeOptions) -> Self::Output {
         use GeoDataType::*;
 
         macro_rules! call_explode {
             ($as_func:ident) => {{
-                let (exploded_geoms, take_indices) = self.$as_func().explode();
+                let (exploded_geoms, take_indices) = self.$as_func().explode_with_options(options);
+                (Arc::new(exploded_geoms), take_indices)
+            }};
+        }
+
+        macro_rules! try_call_explode {
+            ($as_func:ident) => {{
+                let (exploded_geoms, take_indices) =
+                    self.$as_func().explode_with_options(options)?;
                 (Arc::new(exploded_geoms), take_indices)
             }};
         }
@@ -148,14 +257,14 @@ impl Explode for &dyn GeometryArrayTrait {
             LargePolygon(_) => call_explode!(as_large_polygon),
             MultiPoint(_) => call_explode!(as_multi_point),
             LargeMultiPoint(_) => call_explode!(as_large_multi_point),
-            MultiLineString(_) => call_explode!(as_multi_line_string),
-            LargeMultiLineString(_) => call_explode!(as_large_multi_line_string),
-            MultiPolygon(_) => call_explode!(as_multi_polygon),
-            LargeMultiPolygon(_) => call_explode!(as_large_multi_polygon),
+            MultiLineString(_) => try_call_explode!(as_multi_line_string),
+            LargeMultiLineString(_) => try_call_explode!(as_large_multi_line_string),
+            MultiPolygon(_) => try_call_explode!(as_multi_polygon),
+            LargeMultiPolygon(_) => try_call_explode!(as_large_multi_polygon),
             // Mixed(_) => self.as_mixed().explode(),
             // LargeMixed(_) => self.as_large_mixed().explode(),
-            // GeometryCollection(_) => self.as_geometry_collection().explode(),
-            // LargeGeometryCollection(_) => self.as_large_geometry_collection().explode(),
+            GeometryCollection(_) => try_call_explode!(as_geometry_collection),
+            LargeGeometryCollection(_) => try_call_explode!(as_large_geometry_collection),
             _ => return Err(GeoArrowError::IncorrectType("".into())),
         };
         Ok(result)
@@ -168,8 +277,8 @@ impl<G: GeometryArrayTrait> Explode for ChunkedGeometryArray<G> {
         Option<ChunkedArray<Int32Array>>,
     )>;
 
-    fn explode(&self) -> Self::Output {
-        let result = self.try_map(|chunk| chunk.as_ref().explode())?;
+    fn explode_with_options(&self, options: ExplodeOptions) -> Self::Output {
+        let result = self.try_map(|chunk| chunk.as_ref().explode_with_options(options))?;
 
         // Convert Vec of tuples to tuple of vecs
         let (geometry_arrays, take_indices): (Vec<_>, Vec<_>) = result.into_iter().unzip();
@@ -193,26 +302,38 @@ impl Explode for &dyn ChunkedGeometryArrayTrait {
         Option<ChunkedArray<Int32Array>>,
     )>;
 
-    fn explode(&self) -> Self::Output {
+    fn explode_with_options(&self, options: ExplodeOptions) -> Self::Output {
         match self.data_type() {
-            GeoDataType::Point(_) => self.as_point().explode(),
-            GeoDataType::LineString(_) => self.as_line_string().explode(),
-            GeoDataType::LargeLineString(_) => self.as_large_line_string().explode(),
-            GeoDataType::Polygon(_) => self.as_polygon().explode(),
-            GeoDataType::LargePolygon(_) => self.as_large_polygon().explode(),
-            GeoDataType::MultiPoint(_) => self.as_multi_point().explode(),
-            GeoDataType::LargeMultiPoint(_) => self.as_large_multi_point().explode(),
-            GeoDataType::MultiLineString(_) => self.as_multi_line_string().explode(),
-            GeoDataType::LargeMultiLineString(_) => self.as_large_multi_line_string().explode(),
-            GeoDataType::MultiPolygon(_) => self.as_multi_polygon().explode(),
-            GeoDataType::LargeMultiPolygon(_) => self.as_large_multi_polygon().explode(),
-            GeoDataType::Mixed(_) => self.as_mixed().explode(),
-            GeoDataType::LargeMixed(_) => self.as_large_mixed().explode(),
-            GeoDataType::GeometryCollection(_) => self.as_geometry_collection().explode(),
-            GeoDataType::LargeGeometryCollection(_) => {
-                self.as_large_geometry_collection().explode()
+            GeoDataType::Point(_) => self.as_point().explode_with_options(options),
+            GeoDataType::LineString(_) => self.as_line_string().explode_with_options(options),
+            GeoDataType::LargeLineString(_) => {
+                self.as_large_line_string().explode_with_options(options)
+            }
+            GeoDataType::Polygon(_) => self.as_polygon().explode_with_options(options),
+            GeoDataType::LargePolygon(_) => self.as_large_polygon().explode_with_options(options),
+            GeoDataType::MultiPoint(_) => self.as_multi_point().explode_with_options(options),
+            GeoDataType::LargeMultiPoint(_) => {
+                self.as_large_multi_point().explode_with_options(options)
+            }
+            GeoDataType::MultiLineString(_) => {
+                self.as_multi_line_string().explode_with_options(options)
+            }
+            GeoDataType::LargeMultiLineString(_) => self
+                .as_large_multi_line_string()
+                .explode_with_options(options),
+            GeoDataType::MultiPolygon(_) => self.as_multi_polygon().explode_with_options(options),
+            GeoDataType::LargeMultiPolygon(_) => {
+                self.as_large_multi_polygon().explode_with_options(options)
+            }
+            GeoDataType::Mixed(_) => self.as_mixed().explode_with_options(options),
+            GeoDataType::LargeMixed(_) => self.as_large_mixed().explode_with_options(options),
+            GeoDataType::GeometryCollection(_) => {
+                self.as_geometry_collection().explode_with_options(options)
             }
-            GeoDataType::Rect => self.as_rect().explode(),
+            GeoDataType::LargeGeometryCollection(_) => self
+                .as_large_geometry_collection()
+                .explode_with_options(options),
+            GeoDataType::Rect => self.as_rect().explode_with_options(options),
             _ => todo!(),
         }
     }
@@ -221,63 +342,80 @@ impl Explode for &dyn ChunkedGeometryArrayTrait {
 impl Explode for GeoTable {
     type Output = Result<GeoTable>;
 
-    fn explode(&self) -> Self::Output {
-        let geometry_column = self.geometry()?;
-        let (exploded_geometry, take_indices) = geometry_column.as_ref().explode()?;
-
-        // TODO: optionally use rayon?
-        if let Some(take_indices) = take_indices {
-            // Remove existing geometry column
-            let mut new_table = self.clone();
-            new_table.remove_column(new_table.geometry_column_index());
+    fn explode_with_options(&self, options: ExplodeOptions) -> Self::Output {
+        explode_table(self, self.geometry_column_index(), options)
+    }
+}
 
-            let field = exploded_geometry.extension_field();
+/// Explode the geometry column at `index` of a table, repeating the other columns for each part.
+///
+/// The exploded column keeps its position in the table.
+pub(crate) fn explode_table(
+    table: &GeoTable,
+    index: usize,
+    options: ExplodeOptions,
+) -> Result<GeoTable> {
+    if index >= table.num_columns() {
+        return Err(GeoArrowError::General(format!(
+            "Column index {} out of range for a table with {} columns",
+            index,
+            table.num_columns()
+        )));
+    }
 
-            // Call take on each chunk and append geometry chunk
-            let new_batches = new_table
-                .batches()
+    // Read the column to explode as the geometry column of a table sharing the same data
+    let column_table = GeoTable::try_new(table.schema().clone(), table.batches().clone(), index)?;
+    let geometry_column = column_table.geometry()?;
+    let (exploded_geometry, take_indices) =
+        geometry_column.as_ref().explode_with_options(options)?;
+
+    // No take is necessary; nothing happens
+    let Some(take_indices) = take_indices else {
+        return Ok(table.clone());
+    };
+
+    let mut fields = table.schema().fields().to_vec();
+    fields[index] = exploded_geometry.extension_field();
+    let schema = Arc::new(Schema::new_with_metadata(
+        fields,
+        table.schema().metadata().clone(),
+    ));
+
+    // TODO: optionally use rayon?
+    // Call take on each chunk and replace the geometry chunk
+    let new_batches = table
+        .batches()
+        .iter()
+        .zip(take_indices.chunks())
+        .zip(exploded_geometry.geometry_chunks())
+        .map(|((batch, indices), geom_chunk)| {
+            let new_columns = batch
+                .columns()
                 .iter()
-                .zip(take_indices.chunks())
-                .zip(exploded_geometry.geometry_chunks())
-                .map(|((batch, indices), geom_chunk)| {
-                    let mut schema_builder = SchemaBuilder::from(batch.schema().as_ref().clone());
-
-                    let mut new_columns = batch
-                        .columns()
-                        .iter()
-                        .map(|values| Ok(take(values, indices, None)?))
-                        .collect::<Result<Vec<_>>>()?;
-
-                    // Add geometry column
-                    new_columns.push(geom_chunk.to_array_ref());
-                    schema_builder.push(field.clone());
-
-                    Ok(RecordBatch::try_new(
-                        schema_builder.finish().into(),
-                        new_columns,
-                    )?)
+                .enumerate()
+                .map(|(i, values)| {
+                    if i == index {
+                        Ok(geom_chunk.to_array_ref())
+                    } else {
+                        Ok(take(values, indices, None)?)
+                    }
                 })
                 .collect::<Result<Vec<_>>>()?;
+            Ok(RecordBatch::try_new(schema.clone(), new_columns)?)
+        })
+        .collect::<Result<Vec<_>>>()?;
 
-            // Update top-level schema
-            let mut schema_builder = SchemaBuilder::from(new_table.schema().as_ref().clone());
-            schema_builder.push(field.clone());
-            let schema = schema_builder.finish();
-            let geometry_column_index = schema.fields().len() - 1;
-
-            GeoTable::try_new(schema.into(), new_batches, geometry_column_index)
-        } else {
-            // No take is necessary; nothing happens
-            Ok(self.clone())
-        }
-    }
+    GeoTable::try_new(schema, new_batches, table.geometry_column_index())
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::test::multipoint;
+    use crate::test::{multipoint, multipolygon};
     use crate::trait_::GeometryArrayAccessor;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int32Type;
+    use arrow_schema::{DataType, Field};
 
     #[test]
     fn explode_multi_point() {
@@ -295,4 +433,115 @@ mod test {
         assert_eq!(take_indices.value(2), 1);
         assert_eq!(take_indices.value(3), 1);
     }
+
+    #[test]
+    fn explode_nulls_and_empties() {
+        let geoms = vec![
+            Some(multipolygon::mp0()),
+            None,
+            Some(geo::MultiPolygon::new(vec![])),
+            Some(multipolygon::mp1()),
+        ];
+        let arr = MultiPolygonBuilder::<i32>::from_nullable_multi_polygons(
+            &geoms,
+            Some(CoordType::Separated),
+            Default::default(),
+        )
+        .finish();
+        let num_parts = multipolygon::mp0().0.len() + multipolygon::mp1().0.len();
+
+        let (exploded_geoms, take_indices) = arr.explode().unwrap();
+        let take_indices = take_indices.unwrap();
+        assert_eq!(exploded_geoms.len(), num_parts + 2);
+        assert_eq!(exploded_geoms.coord_type(), CoordType::Separated);
+        assert_eq!(exploded_geoms.value_as_geo(0), multipolygon::mp0().0[0]);
+        let null_rows: Vec<usize> = (0..exploded_geoms.len())
+            .filter(|i| exploded_geoms.is_null(*i))
+            .collect();
+        assert_eq!(null_rows.len(), 2);
+        assert_eq!(take_indices.value(null_rows[0]), 1);
+        assert_eq!(take_indices.value(null_rows[1]), 2);
+
+        let options = ExplodeOptions::default().with_nulls(ExplodeNulls::Drop);
+        let (exploded_geoms, take_indices) = arr.explode_with_options(options).unwrap();
+        let take_indices = take_indices.unwrap();
+        assert_eq!(exploded_geoms.len(), num_parts);
+        assert_eq!(exploded_geoms.null_count(), 0);
+        assert_eq!(take_indices.values().first(), Some(&0));
+        assert_eq!(take_indices.values().last(), Some(&3));
+    }
+
+    #[test]
+    fn explode_sliced() {
+        let arr = multipoint::mp_array().slice(1, 1);
+        let (exploded_geoms, take_indices) = arr.explode();
+        assert_eq!(exploded_geoms.len(), 2);
+        assert_eq!(exploded_geoms.value_as_geo(0), multipoint::mp1().0[0]);
+        assert_eq!(take_indices.unwrap().values().as_ref(), &[0, 0]);
+    }
+
+    #[test]
+    fn explode_geometry_collection() {
+        let point = geo::Geometry::Point(geo::point!(x: 0., y: 1.));
+        let line_string =
+            geo::Geometry::LineString(geo::line_string![(x: 0., y: 0.), (x: 1., y: 1.)]);
+        let geoms = vec![
+            Some(geo::GeometryCollection(vec![
+                point.clone(),
+                line_string.clone(),
+            ])),
+            Some(geo::GeometryCollection(vec![point.clone()])),
+        ];
+        let arr = GeometryCollectionBuilder::<i32>::from_nullable_geometry_collections(
+            &geoms,
+            None,
+            Default::default(),
+            false,
+        )
+        .unwrap()
+        .finish();
+
+        let dyn_arr: &dyn GeometryArrayTrait = &arr;
+        let (exploded_geoms, take_indices) = dyn_arr.explode().unwrap();
+        assert!(matches!(exploded_geoms.data_type(), GeoDataType::Mixed(_)));
+        let exploded_geoms = exploded_geoms.as_ref().as_mixed();
+        assert_eq!(exploded_geoms.value_as_geo(0), point);
+        assert_eq!(exploded_geoms.value_as_geo(1), line_string);
+        assert_eq!(exploded_geoms.value_as_geo(2), point);
+        assert_eq!(take_indices.unwrap().values().as_ref(), &[0, 0, 1]);
+    }
+
+    #[test]
+    fn explode_table_column() {
+        let geometry = multipolygon::mp_array();
+        let ids = Int32Array::from(vec![10, 20]);
+        let schema = Arc::new(Schema::new(vec![
+            geometry.extension_field(),
+            Arc::new(Field::new("id", DataType::Int32, false)),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![geometry.into_array_ref(), Arc::new(ids)],
+        )
+        .unwrap();
+        let table = GeoTable::try_new(schema, vec![batch], 0).unwrap();
+
+        let exploded = table.explode_column(None, Default::default()).unwrap();
+        let num_parts = multipolygon::mp0().0.len() + multipolygon::mp1().0.len();
+        assert_eq!(exploded.len(), num_parts);
+        assert_eq!(exploded.geometry_column_index(), 0);
+        assert!(matches!(
+            exploded.geometry_data_type().unwrap(),
+            GeoDataType::Polygon(_)
+        ));
+
+        let ids = exploded.batches()[0].column(1).as_primitive::<Int32Type>();
+        let expected: Vec<i32> = std::iter::repeat(10)
+            .take(multipolygon::mp0().0.len())
+            .chain(std::iter::repeat(20).take(multipolygon::mp1().0.len()))
+            .collect();
+        assert_eq!(ids.values().as_ref(), expected.as_slice());
+
+        assert!(table.explode_column(Some(2), Default::default()).is_err());
+    }
 }
@@ -0,0 +1,167 @@
+use arrow_array::UInt32Array;
+
+use crate::algorithm::geo::BoundingRect;
+use crate::chunked_array::ChunkedGeometryArrayTrait;
+use crate::error::Result;
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+
+/// The largest coordinate of the 2^16 x 2^16 grid that geometry centers are snapped to.
+const HILBERT_MAX: f64 = u16::MAX as f64;
+
+/// Compute the indices that sort a geometry column along a Hilbert curve.
+///
+/// Each geometry is keyed by the center of its bounding box, snapped to a 2^16 x 2^16 grid over
+/// the total bounds of the column. Null and empty geometries are sorted last, and geometries with
+/// the same key keep their original order. The indices refer to the rows of all chunks as if they
+/// were concatenated, as expected by [`Take`][crate::algorithm::native::Take] and
+/// [`GeoTable::take`][crate::table::GeoTable::take].
+pub fn hilbert_sort_indices(array: &dyn ChunkedGeometryArrayTrait) -> Result<UInt32Array> {
+    // A column of only null and empty geometries is left in its original order
+    let bounds = BoundingRect::total_bounds(&array).unwrap_or_default();
+
+    let mut keys = vec![];
+    for chunk in array.geometry_chunks() {
+        keys.extend(geometry_hilbert_keys(chunk, bounds)?);
+    }
+
+    let mut indices = (0..keys.len() as u32).collect::<Vec<_>>();
+    indices.sort_by_key(|index| {
+        let key = keys[*index as usize];
+        (key.is_none(), key)
+    });
+    Ok(UInt32Array::from(indices))
+}
+
+/// Compute the Hilbert key of the bounding box center of each geometry, within `bounds`.
+///
+/// Null and empty geometries have no key.
+pub(crate) fn geometry_hilbert_keys(
+    array: &dyn GeometryArrayTrait,
+    bounds: [f64; 4],
+) -> Result<Vec<Option<u32>>> {
+    let [minx, miny, maxx, maxy] = bounds;
+    let rects = array.bounding_rect()?;
+    Ok(rects
+        .iter_geo()
+        .map(|maybe_rect| {
+            maybe_rect.map(|rect| {
+                let center = rect.center();
+                hilbert(
+                    grid_coordinate(center.x, minx, maxx),
+                    grid_coordinate(center.y, miny, maxy),
+                )
+            })
+        })
+        .collect())
+}
+
+/// Snap a coordinate to the Hilbert grid, clamping values outside of `[min, max]`.
+fn grid_coordinate(value: f64, min: f64, max: f64) -> u32 {
+    let width = max - min;
+    if width <= 0. || !value.is_finite() {
+        return 0;
+    }
+    (HILBERT_MAX * (value - min) / width).clamp(0., HILBERT_MAX) as u32
+}
+
+/// The index of a cell of a 2^16 x 2^16 grid along the Hilbert curve.
+///
+/// From <https://github.com/rawrunprotected/hilbert_curves> (public domain), as used by flatbush.
+fn hilbert(x: u32, y: u32) -> u32 {
+    let mut a0 = x ^ y;
+    let mut b0 = 0xFFFF ^ a0;
+    let mut c0 = 0xFFFF ^ (x | y);
+    let mut d0 = x & (y ^ 0xFFFF);
+
+    let mut a1 = a0 | (b0 >> 1);
+    let mut b1 = (a0 >> 1) ^ a0;
+    let mut c1 = ((c0 >> 1) ^ (b0 & (d0 >> 1))) ^ c0;
+    let mut d1 = ((a0 & (c0 >> 1)) ^ (d0 >> 1)) ^ d0;
+
+    a0 = a1;
+    b0 = b1;
+    c0 = c1;
+    d0 = d1;
+    a1 = (a0 & (a0 >> 2)) ^ (b0 & (b0 >> 2));
+    b1 = (a0 & (b0 >> 2)) ^ (b0 & ((a0 ^ b0) >> 2));
+    c1 ^= (a0 & (c0 >> 2)) ^ (b0 & (d0 >> 2));
+    d1 ^= (b0 & (c0 >> 2)) ^ ((a0 ^ b0) & (d0 >> 2));
+
+    a0 = a1;
+    b0 = b1;
+    c0 = c1;
+    d0 = d1;
+    a1 = (a0 & (a0 >> 4)) ^ (b0 & (b0 >> 4));
+    b1 = (a0 & (b0 >> 4)) ^ (b0 & ((a0 ^ b0) >> 4));
+    c1 ^= (a0 & (c0 >> 4)) ^ (b0 & (d0 >> 4));
+    d1 ^= (b0 & (c0 >> 4)) ^ ((a0 ^ b0) & (d0 >> 4));
+
+    a0 = a1;
+    b0 = b1;
+    c0 = c1;
+    d0 = d1;
+    c1 ^= (a0 & (c0 >> 8)) ^ (b0 & (d0 >> 8));
+    d1 ^= (b0 & (c0 >> 8)) ^ ((a0 ^ b0) & (d0 >> 8));
+
+    a0 = c1 ^ (c1 >> 1);
+    b0 = d1 ^ (d1 >> 1);
+
+    let mut i0 = x ^ y;
+    let mut i1 = b0 | (0xFFFF ^ (i0 | a0));
+
+    i0 = (i0 | (i0 << 8)) & 0x00FF00FF;
+    i0 = (i0 | (i0 << 4)) & 0x0F0F0F0F;
+    i0 = (i0 | (i0 << 2)) & 0x33333333;
+    i0 = (i0 | (i0 << 1)) & 0x55555555;
+
+    i1 = (i1 | (i1 << 8)) & 0x00FF00FF;
+    i1 = (i1 | (i1 << 4)) & 0x0F0F0F0F;
+    i1 = (i1 | (i1 << 2)) & 0x33333333;
+    i1 = (i1 | (i1 << 1)) & 0x55555555;
+
+    (i1 << 1) | i0
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::PointArray;
+    use crate::chunked_array::ChunkedGeometryArray;
+
+    #[test]
+    fn hilbert_curve_order() {
+        // The curve fills the 16 x 16 block at the origin first, one neighboring cell at a time
+        let mut cells = vec![None; 256];
+        for x in 0..16 {
+            for y in 0..16 {
+                cells[hilbert(x, y) as usize] = Some((x, y));
+            }
+        }
+        let cells = cells.into_iter().map(Option::unwrap).collect::<Vec<_>>();
+        assert!(cells
+            .windows(2)
+            .all(|pair| pair[0].0.abs_diff(pair[1].0) + pair[0].1.abs_diff(pair[1].1) == 1));
+    }
+
+    #[test]
+    fn sort_indices() {
+        let chunks: Vec<PointArray> = vec![
+            vec![
+                Some(geo::point!(x: 10., y: 0.)),
+                None,
+                Some(geo::point!(x: 0., y: 0.)),
+            ]
+            .into(),
+            vec![
+                Some(geo::point!(x: 0., y: 10.)),
+                Some(geo::point!(x: 10., y: 10.)),
+            ]
+            .into(),
+        ];
+        let chunked = ChunkedGeometryArray::new(chunks);
+        let indices = hilbert_sort_indices(&chunked).unwrap();
+        // The curve starts at the minimum corner and ends at (max, min)
+        assert_eq!(indices.values().to_vec(), vec![2, 3, 4, 0, 1]);
+    }
+}
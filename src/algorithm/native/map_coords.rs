@@ -491,7 +491,7 @@ impl MapCoords for ChunkedPointArray {
         F: Fn(&crate::scalar::Coord) -> std::result::Result<geo::Coord, E> + Sync,
         GeoArrowError: From<E>,
     {
-        Ok(ChunkedGeometryArray::new(
+        Ok(ChunkedGeometryArray::new_unchecked(
             self.try_map(|chunk| chunk.try_map_coords(&map_op))?,
         ))
     }
@@ -505,7 +505,7 @@ impl<O: OffsetSizeTrait> MapCoords for ChunkedLineStringArray<O> {
         F: Fn(&crate::scalar::Coord) -> std::result::Result<geo::Coord, E> + Sync,
         GeoArrowError: From<E>,
     {
-        Ok(ChunkedGeometryArray::new(
+        Ok(ChunkedGeometryArray::new_unchecked(
             self.try_map(|chunk| chunk.try_map_coords(&map_op))?,
         ))
     }
@@ -519,7 +519,7 @@ impl<O: OffsetSizeTrait> MapCoords for ChunkedPolygonArray<O> {
         F: Fn(&crate::scalar::Coord) -> std::result::Result<geo::Coord, E> + Sync,
         GeoArrowError: From<E>,
     {
-        Ok(ChunkedGeometryArray::new(
+        Ok(ChunkedGeometryArray::new_unchecked(
             self.try_map(|chunk| chunk.try_map_coords(&map_op))?,
         ))
     }
@@ -533,7 +533,7 @@ impl<O: OffsetSizeTrait> MapCoords for ChunkedMultiPointArray<O> {
         F: Fn(&crate::scalar::Coord) -> std::result::Result<geo::Coord, E> + Sync,
         GeoArrowError: From<E>,
     {
-        Ok(ChunkedGeometryArray::new(
+        Ok(ChunkedGeometryArray::new_unchecked(
             self.try_map(|chunk| chunk.try_map_coords(&map_op))?,
         ))
     }
@@ -547,7 +547,7 @@ impl<O: OffsetSizeTrait> MapCoords for ChunkedMultiLineStringArray<O> {
         F: Fn(&crate::scalar::Coord) -> std::result::Result<geo::Coord, E> + Sync,
         GeoArrowError: From<E>,
     {
-        Ok(ChunkedGeometryArray::new(
+        Ok(ChunkedGeometryArray::new_unchecked(
             self.try_map(|chunk| chunk.try_map_coords(&map_op))?,
         ))
     }
@@ -561,7 +561,7 @@ impl<O: OffsetSizeTrait> MapCoords for ChunkedMultiPolygonArray<O> {
         F: Fn(&crate::scalar::Coord) -> std::result::Result<geo::Coord, E> + Sync,
         GeoArrowError: From<E>,
     {
-        Ok(ChunkedGeometryArray::new(
+        Ok(ChunkedGeometryArray::new_unchecked(
             self.try_map(|chunk| chunk.try_map_coords(&map_op))?,
         ))
     }
@@ -575,7 +575,7 @@ impl<O: OffsetSizeTrait> MapCoords for ChunkedMixedGeometryArray<O> {
         F: Fn(&crate::scalar::Coord) -> std::result::Result<geo::Coord, E> + Sync,
         GeoArrowError: From<E>,
     {
-        Ok(ChunkedGeometryArray::new(
+        Ok(ChunkedGeometryArray::new_unchecked(
             self.try_map(|chunk| chunk.try_map_coords(&map_op))?,
         ))
     }
@@ -589,7 +589,7 @@ impl<O: OffsetSizeTrait> MapCoords for ChunkedGeometryCollectionArray<O> {
         F: Fn(&crate::scalar::Coord) -> std::result::Result<geo::Coord, E> + Sync,
         GeoArrowError: From<E>,
     {
-        Ok(ChunkedGeometryArray::new(
+        Ok(ChunkedGeometryArray::new_unchecked(
             self.try_map(|chunk| chunk.try_map_coords(&map_op))?,
         ))
     }
@@ -603,7 +603,7 @@ impl MapCoords for ChunkedRectArray {
         F: Fn(&crate::scalar::Coord) -> std::result::Result<geo::Coord, E> + Sync,
         GeoArrowError: From<E>,
     {
-        Ok(ChunkedGeometryArray::new(
+        Ok(ChunkedGeometryArray::new_unchecked(
             self.try_map(|chunk| chunk.try_map_coords(&map_op))?,
         ))
     }
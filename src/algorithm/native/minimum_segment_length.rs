@@ -0,0 +1,242 @@
+use arrow_array::{Float64Array, OffsetSizeTrait};
+
+use crate::algorithm::native::Unary;
+use crate::array::*;
+use crate::chunked_array::{ChunkedArray, ChunkedGeometryArray, ChunkedGeometryArrayTrait};
+use crate::datatypes::GeoDataType;
+use crate::error::Result;
+use crate::geo_traits::{
+    CoordTrait, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait,
+    MultiLineStringTrait, MultiPolygonTrait, PolygonTrait, RectTrait,
+};
+use crate::GeometryArrayTrait;
+
+/// The length of the shortest segment of each geometry.
+///
+/// This is a cheap indicator of how numerically fragile a geometry is: a very short segment (or a
+/// zero-length segment from a repeated vertex) is easily collapsed by rounding during overlay
+/// operations. It only reads the coordinates, unlike the minimum clearance, which also accounts
+/// for vertices that are close to non-adjacent segments.
+///
+/// Geometries without any segments, such as points, have a minimum segment length of infinity.
+pub trait MinimumSegmentLength {
+    type Output;
+
+    fn minimum_segment_length(&self) -> Self::Output;
+}
+
+impl MinimumSegmentLength for PointArray {
+    type Output = Float64Array;
+
+    fn minimum_segment_length(&self) -> Self::Output {
+        self.unary_primitive(|_| f64::INFINITY)
+    }
+}
+
+impl MinimumSegmentLength for RectArray {
+    type Output = Float64Array;
+
+    fn minimum_segment_length(&self) -> Self::Output {
+        self.unary_primitive(|geom| rect_minimum_segment_length(&geom))
+    }
+}
+
+macro_rules! impl_array {
+    ($type:ty, $func:ident) => {
+        impl<O: OffsetSizeTrait> MinimumSegmentLength for $type {
+            type Output = Float64Array;
+
+            fn minimum_segment_length(&self) -> Self::Output {
+                self.unary_primitive(|geom| $func(&geom))
+            }
+        }
+    };
+}
+
+impl_array!(LineStringArray<O>, line_string_minimum_segment_length);
+impl_array!(PolygonArray<O>, polygon_minimum_segment_length);
+impl_array!(
+    MultiLineStringArray<O>,
+    multi_line_string_minimum_segment_length
+);
+impl_array!(MultiPolygonArray<O>, multi_polygon_minimum_segment_length);
+impl_array!(MixedGeometryArray<O>, geometry_minimum_segment_length);
+impl_array!(
+    GeometryCollectionArray<O>,
+    geometry_collection_minimum_segment_length
+);
+
+impl<O: OffsetSizeTrait> MinimumSegmentLength for MultiPointArray<O> {
+    type Output = Float64Array;
+
+    fn minimum_segment_length(&self) -> Self::Output {
+        self.unary_primitive(|_| f64::INFINITY)
+    }
+}
+
+impl<O: OffsetSizeTrait> MinimumSegmentLength for WKBArray<O> {
+    type Output = Float64Array;
+
+    fn minimum_segment_length(&self) -> Self::Output {
+        self.unary_primitive(|geom| geometry_minimum_segment_length(&geom.to_wkb_object()))
+    }
+}
+
+impl MinimumSegmentLength for &dyn GeometryArrayTrait {
+    type Output = Result<Float64Array>;
+
+    fn minimum_segment_length(&self) -> Self::Output {
+        let result = match self.data_type() {
+            GeoDataType::Point(_) => self.as_point().minimum_segment_length(),
+            GeoDataType::LineString(_) => self.as_line_string().minimum_segment_length(),
+            GeoDataType::LargeLineString(_) => self.as_large_line_string().minimum_segment_length(),
+            GeoDataType::Polygon(_) => self.as_polygon().minimum_segment_length(),
+            GeoDataType::LargePolygon(_) => self.as_large_polygon().minimum_segment_length(),
+            GeoDataType::MultiPoint(_) => self.as_multi_point().minimum_segment_length(),
+            GeoDataType::LargeMultiPoint(_) => self.as_large_multi_point().minimum_segment_length(),
+            GeoDataType::MultiLineString(_) => self.as_multi_line_string().minimum_segment_length(),
+            GeoDataType::LargeMultiLineString(_) => {
+                self.as_large_multi_line_string().minimum_segment_length()
+            }
+            GeoDataType::MultiPolygon(_) => self.as_multi_polygon().minimum_segment_length(),
+            GeoDataType::LargeMultiPolygon(_) => {
+                self.as_large_multi_polygon().minimum_segment_length()
+            }
+            GeoDataType::Mixed(_) => self.as_mixed().minimum_segment_length(),
+            GeoDataType::LargeMixed(_) => self.as_large_mixed().minimum_segment_length(),
+            GeoDataType::GeometryCollection(_) => {
+                self.as_geometry_collection().minimum_segment_length()
+            }
+            GeoDataType::LargeGeometryCollection(_) => {
+                self.as_large_geometry_collection().minimum_segment_length()
+            }
+            GeoDataType::Rect => self.as_rect().minimum_segment_length(),
+            GeoDataType::WKB => self.as_wkb().minimum_segment_length(),
+            GeoDataType::LargeWKB => self.as_large_wkb().minimum_segment_length(),
+        };
+        Ok(result)
+    }
+}
+
+impl<G: GeometryArrayTrait> MinimumSegmentLength for ChunkedGeometryArray<G> {
+    type Output = Result<ChunkedArray<Float64Array>>;
+
+    fn minimum_segment_length(&self) -> Self::Output {
+        self.try_map_non_null(|chunk| chunk.as_ref().minimum_segment_length())?
+            .try_into()
+    }
+}
+
+impl MinimumSegmentLength for &dyn ChunkedGeometryArrayTrait {
+    type Output = Result<ChunkedArray<Float64Array>>;
+
+    fn minimum_segment_length(&self) -> Self::Output {
+        let chunks = self
+            .geometry_chunks()
+            .into_iter()
+            .map(|chunk| chunk.minimum_segment_length())
+            .collect::<Result<Vec<_>>>()?;
+        Ok(ChunkedArray::new(chunks))
+    }
+}
+
+fn line_string_minimum_segment_length(line_string: &impl LineStringTrait<T = f64>) -> f64 {
+    let mut min_length = f64::INFINITY;
+    let mut coords = line_string.coords();
+    if let Some(first) = coords.next() {
+        let (mut prev_x, mut prev_y) = (first.x(), first.y());
+        for coord in coords {
+            let (x, y) = (coord.x(), coord.y());
+            min_length = min_length.min((x - prev_x).hypot(y - prev_y));
+            (prev_x, prev_y) = (x, y);
+        }
+    }
+    min_length
+}
+
+fn polygon_minimum_segment_length(polygon: &impl PolygonTrait<T = f64>) -> f64 {
+    let exterior_length = polygon
+        .exterior()
+        .map(|exterior| line_string_minimum_segment_length(&exterior))
+        .unwrap_or(f64::INFINITY);
+    polygon
+        .interiors()
+        .map(|interior| line_string_minimum_segment_length(&interior))
+        .fold(exterior_length, f64::min)
+}
+
+fn multi_line_string_minimum_segment_length(
+    multi_line_string: &impl MultiLineStringTrait<T = f64>,
+) -> f64 {
+    multi_line_string
+        .lines()
+        .map(|line_string| line_string_minimum_segment_length(&line_string))
+        .fold(f64::INFINITY, f64::min)
+}
+
+fn multi_polygon_minimum_segment_length(multi_polygon: &impl MultiPolygonTrait<T = f64>) -> f64 {
+    multi_polygon
+        .polygons()
+        .map(|polygon| polygon_minimum_segment_length(&polygon))
+        .fold(f64::INFINITY, f64::min)
+}
+
+fn rect_minimum_segment_length(rect: &impl RectTrait<T = f64>) -> f64 {
+    let width = rect.upper().x() - rect.lower().x();
+    let height = rect.upper().y() - rect.lower().y();
+    width.min(height)
+}
+
+fn geometry_collection_minimum_segment_length(
+    geometry_collection: &impl GeometryCollectionTrait<T = f64>,
+) -> f64 {
+    geometry_collection
+        .geometries()
+        .map(|geometry| geometry_minimum_segment_length(&geometry))
+        .fold(f64::INFINITY, f64::min)
+}
+
+fn geometry_minimum_segment_length(geometry: &impl GeometryTrait<T = f64>) -> f64 {
+    match geometry.as_type() {
+        GeometryType::Point(_) | GeometryType::MultiPoint(_) => f64::INFINITY,
+        GeometryType::LineString(g) => line_string_minimum_segment_length(g),
+        GeometryType::Polygon(g) => polygon_minimum_segment_length(g),
+        GeometryType::MultiLineString(g) => multi_line_string_minimum_segment_length(g),
+        GeometryType::MultiPolygon(g) => multi_polygon_minimum_segment_length(g),
+        GeometryType::GeometryCollection(g) => geometry_collection_minimum_segment_length(g),
+        GeometryType::Rect(g) => rect_minimum_segment_length(g),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::{linestring, point};
+    use arrow_array::Array;
+
+    #[test]
+    fn sliver_polygon() {
+        // A sliver whose apex is 0.01 above its base
+        let polygon = geo::polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 5., y: 0.01)];
+        let polygon_array: PolygonArray<i32> = vec![Some(polygon), None].into();
+        let result = polygon_array.minimum_segment_length();
+        assert!((result.value(0) - 0.01_f64.hypot(5.)).abs() < 1e-12);
+        assert!(result.is_null(1));
+    }
+
+    #[test]
+    fn repeated_vertex() {
+        let line_string = geo::line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 0.)];
+        let arr: LineStringArray<i32> = vec![line_string].as_slice().into();
+        assert_eq!(arr.minimum_segment_length().value(0), 0.);
+
+        let arr = linestring::ls_array();
+        let result = (&arr as &dyn GeometryArrayTrait)
+            .minimum_segment_length()
+            .unwrap();
+        assert_eq!(result.len(), 2);
+
+        let result = point::point_array().minimum_segment_length();
+        assert_eq!(result.value(0), f64::INFINITY);
+    }
+}
@@ -3,29 +3,48 @@
 //! Where possible, operations on scalars are implemented in terms of [geometry
 //! traits](../../geo_traits).
 
+mod antimeridian;
 mod binary;
 pub mod bounding_rect;
 mod cast;
+mod clip;
 mod concatenate;
 mod downcast;
 pub(crate) mod eq;
+mod equals_exact;
 mod explode;
+mod hilbert;
 mod map_chunks;
 mod map_coords;
+mod minimum_segment_length;
+mod quadkey;
+mod rasterize;
 mod rechunk;
+mod round_coordinates;
 mod take;
 mod total_bounds;
 pub(crate) mod type_id;
 mod unary;
 
+pub use antimeridian::SplitAntimeridian;
 pub use binary::Binary;
 pub use cast::Cast;
+pub use clip::Clip;
 pub use concatenate::Concatenate;
 pub use downcast::Downcast;
-pub use explode::Explode;
+pub use equals_exact::equals_exact;
+pub(crate) use explode::explode_table;
+pub use explode::{Explode, ExplodeNulls, ExplodeOptions};
+pub(crate) use hilbert::geometry_hilbert_keys;
+pub use hilbert::hilbert_sort_indices;
 pub use map_chunks::MapChunks;
 pub use map_coords::MapCoords;
+pub use minimum_segment_length::MinimumSegmentLength;
+pub use quadkey::{Quadkey, MAX_QUADKEY_ZOOM};
+pub(crate) use rasterize::Grid;
+pub use rasterize::{rasterize, Raster, RasterizeMode};
 pub use rechunk::Rechunk;
+pub use round_coordinates::{RoundCoordinates, RoundingReport};
 pub use take::Take;
 pub use total_bounds::TotalBounds;
 pub use unary::Unary;
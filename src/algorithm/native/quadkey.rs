@@ -0,0 +1,125 @@
+use std::f64::consts::PI;
+
+use arrow_array::builder::StringBuilder;
+use arrow_array::StringArray;
+
+use crate::algorithm::geo::BoundingRect;
+use crate::array::RectArray;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+
+/// The maximum zoom level supported by [`Quadkey`].
+pub const MAX_QUADKEY_ZOOM: u8 = 31;
+
+/// The latitude bounds of the Web Mercator projection.
+const MAX_LATITUDE: f64 = 85.05112878;
+
+/// Bin geometries into [Bing Maps quadkeys](https://learn.microsoft.com/en-us/bingmaps/articles/bing-maps-tile-system).
+///
+/// Each geometry is assigned the quadkey of the Web Mercator tile at the given zoom level that
+/// contains the center of its bounding box. Coordinates are assumed to be longitude/latitude.
+///
+/// Quadkeys are string prefixes of their parent tiles, which makes them well suited as a
+/// partitioning or filtering column: a Parquet bloom filter on the quadkey column allows readers
+/// to skip row groups that don't contain a given tile.
+pub trait Quadkey {
+    type Output;
+
+    /// Compute the quadkey of each geometry at the provided zoom level.
+    ///
+    /// Null geometries produce null quadkeys.
+    fn quadkey(&self, zoom: u8) -> Self::Output;
+}
+
+impl Quadkey for RectArray {
+    type Output = Result<StringArray>;
+
+    fn quadkey(&self, zoom: u8) -> Self::Output {
+        if zoom > MAX_QUADKEY_ZOOM {
+            return Err(GeoArrowError::General(format!(
+                "quadkey zoom must be at most {}, got {}",
+                MAX_QUADKEY_ZOOM, zoom
+            )));
+        }
+
+        let mut builder = StringBuilder::with_capacity(self.len(), self.len() * usize::from(zoom));
+        for maybe_rect in self.iter_geo() {
+            match maybe_rect {
+                Some(rect) => {
+                    let center = rect.center();
+                    builder.append_value(lon_lat_to_quadkey(center.x, center.y, zoom));
+                }
+                None => builder.append_null(),
+            }
+        }
+        Ok(builder.finish())
+    }
+}
+
+impl Quadkey for &dyn GeometryArrayTrait {
+    type Output = Result<StringArray>;
+
+    fn quadkey(&self, zoom: u8) -> Self::Output {
+        self.bounding_rect()?.quadkey(zoom)
+    }
+}
+
+/// Compute the quadkey of the tile containing the given longitude/latitude.
+pub(crate) fn lon_lat_to_quadkey(lon: f64, lat: f64, zoom: u8) -> String {
+    let lat = lat.clamp(-MAX_LATITUDE, MAX_LATITUDE);
+    let lon = lon.clamp(-180.0, 180.0);
+
+    let x = (lon + 180.0) / 360.0;
+    let sin_lat = (lat * PI / 180.0).sin();
+    let y = 0.5 - ((1.0 + sin_lat) / (1.0 - sin_lat)).ln() / (4.0 * PI);
+
+    let num_tiles = (1_u64 << zoom) as f64;
+    let max_tile = (1_u64 << zoom) - 1;
+    let tile_x = ((x * num_tiles).floor().max(0.0) as u64).min(max_tile);
+    let tile_y = ((y * num_tiles).floor().max(0.0) as u64).min(max_tile);
+
+    let mut quadkey = String::with_capacity(usize::from(zoom));
+    for level in (1..=zoom).rev() {
+        let mask = 1 << (level - 1);
+        let mut digit = b'0';
+        if tile_x & mask != 0 {
+            digit += 1;
+        }
+        if tile_y & mask != 0 {
+            digit += 2;
+        }
+        quadkey.push(digit as char);
+    }
+    quadkey
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::point;
+
+    #[test]
+    fn quadkey_known_tiles() {
+        assert_eq!(lon_lat_to_quadkey(-179.0, 84.0, 1), "0");
+        assert_eq!(lon_lat_to_quadkey(179.0, -84.0, 1), "3");
+        assert_eq!(lon_lat_to_quadkey(-122.3321, 47.6062, 3), "021");
+        assert_eq!(lon_lat_to_quadkey(0.0, 0.0, 0), "");
+    }
+
+    #[test]
+    fn quadkey_array() {
+        let arr = point::point_array();
+        let dyn_arr: &dyn GeometryArrayTrait = &arr;
+        let quadkeys = dyn_arr.quadkey(4).unwrap();
+        assert_eq!(quadkeys.len(), 3);
+        assert_eq!(quadkeys.value(0), "1222");
+    }
+
+    #[test]
+    fn quadkey_invalid_zoom() {
+        let arr = point::point_array();
+        let dyn_arr: &dyn GeometryArrayTrait = &arr;
+        assert!(dyn_arr.quadkey(MAX_QUADKEY_ZOOM + 1).is_err());
+    }
+}
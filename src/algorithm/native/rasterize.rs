@@ -0,0 +1,452 @@
+use arrow_array::{Array, Float64Array};
+use geo::{BoundingRect, Coord, LineString, Polygon, Rect};
+
+use crate::algorithm::native::clip::clip_segment;
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+
+/// What to store in each cell of a [`Raster`].
+///
+/// `A` is the attribute to sum: a [`Float64Array`] with one value per geometry for
+/// [`rasterize`], or the name of a column for
+/// [`GeoTable::rasterize`][crate::table::GeoTable::rasterize].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RasterizeMode<A> {
+    /// `1.` in the cells covered by any geometry and `0.` elsewhere, for masks.
+    Binary,
+
+    /// The number of geometries that cover each cell, for density maps.
+    Count,
+
+    /// The sum of the attribute values of the geometries that cover each cell. Geometries with a
+    /// null value are skipped.
+    AttributeSum(A),
+}
+
+/// A grid of `f64` values, as returned by [`rasterize`].
+///
+/// The values are stored in row-major order, starting from the top left cell, so the cell at
+/// `row` and `col` is `values().value(row * width() + col)`. This is the layout expected by image
+/// crates and by numpy for an array of shape [`shape`][Raster::shape].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Raster {
+    values: Float64Array,
+    width: usize,
+    height: usize,
+}
+
+impl Raster {
+    /// The value of each cell, in row-major order.
+    pub fn values(&self) -> &Float64Array {
+        &self.values
+    }
+
+    /// The number of columns of the grid.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The number of rows of the grid.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The `(height, width)` shape of the grid.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.height, self.width)
+    }
+
+    /// Consume the raster, returning its values.
+    pub fn into_values(self) -> Float64Array {
+        self.values
+    }
+}
+
+/// Rasterize the geometries of an array onto a grid of `width` by `height` cells covering
+/// `bounds`.
+///
+/// Which cells a geometry covers depends on its type:
+///
+/// - A point covers the cell that contains it. Points on the boundary between two cells are in
+///   the cell to their right or below them, except on the right and bottom edges of `bounds`.
+/// - A line string covers the cells along each of its segments, chosen with Bresenham's
+///   algorithm between the cells of their endpoints. Segments are first clipped to `bounds`.
+/// - A polygon covers the cells whose center is inside of it, with the even-odd rule, so holes
+///   are not covered. A center on the left or bottom edge of a polygon is inside, and one on the
+///   right or top edge is outside, so polygons that share an edge never both cover a cell.
+///   Polygons that are smaller than a cell may not cover any cell.
+///
+/// Multi geometries and geometry collections cover the cells covered by any of their parts, and
+/// add to each of them only once. Geometries outside of `bounds` and null geometries are
+/// skipped.
+///
+/// Returns an error if the grid has no cells, if `bounds` has no area, or if the attribute of
+/// [`RasterizeMode::AttributeSum`] doesn't have the length of the array.
+pub fn rasterize(
+    geometries: &dyn GeometryArrayTrait,
+    bounds: &Rect,
+    width: usize,
+    height: usize,
+    mode: RasterizeMode<&Float64Array>,
+) -> Result<Raster> {
+    let mut grid = Grid::try_new(bounds, width, height)?;
+    grid.add_array(geometries, mode)?;
+    Ok(grid.finish())
+}
+
+/// The cells of a raster, to which the geometries of several arrays can be added.
+pub(crate) struct Grid {
+    bounds: Rect,
+    width: usize,
+    height: usize,
+    cell_width: f64,
+    cell_height: f64,
+    values: Vec<f64>,
+}
+
+impl Grid {
+    pub(crate) fn try_new(bounds: &Rect, width: usize, height: usize) -> Result<Self> {
+        if width == 0 || height == 0 {
+            return Err(GeoArrowError::General(format!(
+                "Cannot rasterize onto a grid of {} by {} cells",
+                width, height
+            )));
+        }
+        if bounds.width() <= 0.0 || bounds.height() <= 0.0 {
+            return Err(GeoArrowError::General(format!(
+                "Cannot rasterize onto bounds with no area: {:?}",
+                bounds
+            )));
+        }
+
+        Ok(Self {
+            bounds: *bounds,
+            width,
+            height,
+            cell_width: bounds.width() / width as f64,
+            cell_height: bounds.height() / height as f64,
+            values: vec![0.0; width * height],
+        })
+    }
+
+    /// Add the geometries of `array` to the grid.
+    pub(crate) fn add_array(
+        &mut self,
+        array: &dyn GeometryArrayTrait,
+        mode: RasterizeMode<&Float64Array>,
+    ) -> Result<()> {
+        if let RasterizeMode::AttributeSum(attribute) = mode {
+            if attribute.len() != array.len() {
+                return Err(GeoArrowError::General(format!(
+                    "The attribute to sum has length {}, but the geometry array has length {}",
+                    attribute.len(),
+                    array.len()
+                )));
+            }
+        }
+
+        macro_rules! impl_add {
+            ($array:expr) => {
+                for (i, maybe_geometry) in $array.iter_geo().enumerate() {
+                    if let Some(geometry) = maybe_geometry {
+                        self.add_geometry(&geo::Geometry::from(geometry), i, mode);
+                    }
+                }
+            };
+        }
+
+        match array.data_type() {
+            GeoDataType::Point(_) => impl_add!(array.as_point()),
+            GeoDataType::LineString(_) => impl_add!(array.as_line_string()),
+            GeoDataType::LargeLineString(_) => impl_add!(array.as_large_line_string()),
+            GeoDataType::Polygon(_) => impl_add!(array.as_polygon()),
+            GeoDataType::LargePolygon(_) => impl_add!(array.as_large_polygon()),
+            GeoDataType::MultiPoint(_) => impl_add!(array.as_multi_point()),
+            GeoDataType::LargeMultiPoint(_) => impl_add!(array.as_large_multi_point()),
+            GeoDataType::MultiLineString(_) => impl_add!(array.as_multi_line_string()),
+            GeoDataType::LargeMultiLineString(_) => impl_add!(array.as_large_multi_line_string()),
+            GeoDataType::MultiPolygon(_) => impl_add!(array.as_multi_polygon()),
+            GeoDataType::LargeMultiPolygon(_) => impl_add!(array.as_large_multi_polygon()),
+            GeoDataType::Mixed(_) => impl_add!(array.as_mixed()),
+            GeoDataType::LargeMixed(_) => impl_add!(array.as_large_mixed()),
+            GeoDataType::GeometryCollection(_) => impl_add!(array.as_geometry_collection()),
+            GeoDataType::LargeGeometryCollection(_) => {
+                impl_add!(array.as_large_geometry_collection())
+            }
+            GeoDataType::Rect => impl_add!(array.as_rect()),
+            GeoDataType::WKB => impl_add!(array.as_wkb()),
+            GeoDataType::LargeWKB => impl_add!(array.as_large_wkb()),
+        }
+        Ok(())
+    }
+
+    /// Add the geometry at index `i` of an array to each cell that it covers.
+    fn add_geometry(
+        &mut self,
+        geometry: &geo::Geometry,
+        i: usize,
+        mode: RasterizeMode<&Float64Array>,
+    ) {
+        let value = match mode {
+            RasterizeMode::Binary | RasterizeMode::Count => 1.0,
+            RasterizeMode::AttributeSum(attribute) if attribute.is_valid(i) => attribute.value(i),
+            RasterizeMode::AttributeSum(_) => return,
+        };
+
+        let mut cells = vec![];
+        self.covered_cells(geometry, &mut cells);
+        cells.sort_unstable();
+        cells.dedup();
+        for cell in cells {
+            match mode {
+                RasterizeMode::Binary => self.values[cell] = 1.0,
+                _ => self.values[cell] += value,
+            }
+        }
+    }
+
+    pub(crate) fn finish(self) -> Raster {
+        Raster {
+            values: Float64Array::from(self.values),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Push the index of each cell covered by `geometry` to `cells`, possibly more than once.
+    fn covered_cells(&self, geometry: &geo::Geometry, cells: &mut Vec<usize>) {
+        match geometry {
+            geo::Geometry::Point(point) => {
+                if let Some(cell) = self.cell_of(point.0) {
+                    cells.push(self.index(cell));
+                }
+            }
+            geo::Geometry::Line(line) => self.line_string_cells(&LineString::from(*line), cells),
+            geo::Geometry::LineString(line_string) => self.line_string_cells(line_string, cells),
+            geo::Geometry::Polygon(polygon) => self.polygon_cells(polygon, cells),
+            geo::Geometry::MultiPoint(multi_point) => {
+                for point in multi_point {
+                    if let Some(cell) = self.cell_of(point.0) {
+                        cells.push(self.index(cell));
+                    }
+                }
+            }
+            geo::Geometry::MultiLineString(multi_line_string) => {
+                for line_string in multi_line_string {
+                    self.line_string_cells(line_string, cells);
+                }
+            }
+            geo::Geometry::MultiPolygon(multi_polygon) => {
+                for polygon in multi_polygon {
+                    self.polygon_cells(polygon, cells);
+                }
+            }
+            geo::Geometry::GeometryCollection(collection) => {
+                for geometry in collection {
+                    self.covered_cells(geometry, cells);
+                }
+            }
+            geo::Geometry::Rect(rect) => self.polygon_cells(&rect.to_polygon(), cells),
+            geo::Geometry::Triangle(triangle) => self.polygon_cells(&triangle.to_polygon(), cells),
+        }
+    }
+
+    fn line_string_cells(&self, line_string: &LineString, cells: &mut Vec<usize>) {
+        for line in line_string.lines() {
+            let Some((start, end)) = clip_segment(line.start, line.end, &self.bounds) else {
+                continue;
+            };
+            // The clipped endpoints are within the bounds, up to rounding
+            let (Some(start), Some(end)) = (self.cell_of(start), self.cell_of(end)) else {
+                continue;
+            };
+            bresenham(start, end, |cell| cells.push(self.index(cell)));
+        }
+    }
+
+    fn polygon_cells(&self, polygon: &Polygon, cells: &mut Vec<usize>) {
+        let Some(polygon_bounds) = polygon.bounding_rect() else {
+            return;
+        };
+        let rings = std::iter::once(polygon.exterior()).chain(polygon.interiors());
+
+        let edges = rings
+            .flat_map(|ring| ring.lines())
+            .filter(|line| line.start.y != line.end.y)
+            .collect::<Vec<_>>();
+        let mut crossings = vec![];
+        for row in 0..self.height {
+            let y = self.bounds.max().y - (row as f64 + 0.5) * self.cell_height;
+            if !(polygon_bounds.min().y..=polygon_bounds.max().y).contains(&y) {
+                continue;
+            }
+
+            // Each edge includes its lower endpoint but not its upper one, so that a vertex
+            // shared by two edges is crossed once, or twice at a local extremum
+            crossings.clear();
+            for edge in &edges {
+                let (low, high) = if edge.start.y < edge.end.y {
+                    (edge.start, edge.end)
+                } else {
+                    (edge.end, edge.start)
+                };
+                if (low.y..high.y).contains(&y) {
+                    crossings.push(low.x + (y - low.y) * (high.x - low.x) / (high.y - low.y));
+                }
+            }
+            crossings.sort_unstable_by(f64::total_cmp);
+
+            // The cell centers from the start of each span, included, to its end, excluded
+            for span in crossings.chunks_exact(2) {
+                let start_col = self.first_col_from(span[0]);
+                let end_col = self.first_col_from(span[1]);
+                cells.extend((start_col..end_col).map(|col| self.index((row, col))));
+            }
+        }
+    }
+
+    /// The first column whose center is at or to the right of `x`, or `width` if there is none.
+    fn first_col_from(&self, x: f64) -> usize {
+        let col = ((x - self.bounds.min().x) / self.cell_width - 0.5).ceil();
+        col.clamp(0.0, self.width as f64) as usize
+    }
+
+    /// The row and column of the cell that contains `coord`, or `None` if it's outside of the
+    /// bounds.
+    fn cell_of(&self, coord: Coord) -> Option<(usize, usize)> {
+        let (min, max) = (self.bounds.min(), self.bounds.max());
+        if !(min.x..=max.x).contains(&coord.x) || !(min.y..=max.y).contains(&coord.y) {
+            return None;
+        }
+        let col = ((coord.x - min.x) / self.cell_width) as usize;
+        let row = ((max.y - coord.y) / self.cell_height) as usize;
+        Some((row.min(self.height - 1), col.min(self.width - 1)))
+    }
+
+    fn index(&self, (row, col): (usize, usize)) -> usize {
+        row * self.width + col
+    }
+}
+
+/// Call `f` with each cell on the line from `start` to `end`, both included, with Bresenham's
+/// algorithm.
+fn bresenham(start: (usize, usize), end: (usize, usize), mut f: impl FnMut((usize, usize))) {
+    let (mut row, mut col) = (start.0 as i64, start.1 as i64);
+    let (end_row, end_col) = (end.0 as i64, end.1 as i64);
+    let (d_col, d_row) = ((end_col - col).abs(), -(end_row - row).abs());
+    let (step_col, step_row) = ((end_col - col).signum(), (end_row - row).signum());
+    let mut error = d_col + d_row;
+    loop {
+        f((row as usize, col as usize));
+        if row == end_row && col == end_col {
+            break;
+        }
+        let double_error = 2 * error;
+        if double_error >= d_row {
+            error += d_row;
+            col += step_col;
+        }
+        if double_error <= d_col {
+            error += d_col;
+            row += step_row;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{LineStringArray, PointArray, PolygonArray};
+    use geo::{line_string, point, polygon};
+
+    fn bounds() -> Rect {
+        Rect::new((0., 0.), (10., 10.))
+    }
+
+    fn covered(raster: &Raster) -> Vec<(usize, usize)> {
+        (0..raster.height())
+            .flat_map(|row| (0..raster.width()).map(move |col| (row, col)))
+            .filter(|(row, col)| raster.values().value(row * raster.width() + col) != 0.)
+            .collect()
+    }
+
+    #[test]
+    fn rasterize_square() {
+        let square = polygon![(x: 2., y: 2.), (x: 6., y: 2.), (x: 6., y: 6.), (x: 2., y: 6.)];
+        let arr: PolygonArray<i32> = vec![square].as_slice().into();
+        let raster = rasterize(&arr, &bounds(), 10, 10, RasterizeMode::Binary).unwrap();
+        assert_eq!(raster.shape(), (10, 10));
+        assert_eq!(raster.values().len(), 100);
+
+        let expected = (4..8)
+            .flat_map(|row| (2..6).map(move |col| (row, col)))
+            .collect::<Vec<_>>();
+        assert_eq!(covered(&raster), expected);
+    }
+
+    #[test]
+    fn rasterize_edge_touching() {
+        // Cell centers lie on every edge of this square. The left and bottom edges are inside
+        // and the right and top edges are outside.
+        let square =
+            polygon![(x: 0.5, y: 0.5), (x: 2.5, y: 0.5), (x: 2.5, y: 2.5), (x: 0.5, y: 2.5)];
+        let arr: PolygonArray<i32> = vec![square].as_slice().into();
+        let raster = rasterize(&arr, &bounds(), 10, 10, RasterizeMode::Count).unwrap();
+        assert_eq!(covered(&raster), vec![(8, 0), (8, 1), (9, 0), (9, 1)]);
+
+        // Squares that share an edge never cover the same cell
+        let left = polygon![(x: 0., y: 0.), (x: 5.5, y: 0.), (x: 5.5, y: 10.), (x: 0., y: 10.)];
+        let right = polygon![(x: 5.5, y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 5.5, y: 10.)];
+        let arr: PolygonArray<i32> = vec![left, right].as_slice().into();
+        let raster = rasterize(&arr, &bounds(), 10, 10, RasterizeMode::Count).unwrap();
+        assert!(raster.values().values().iter().all(|value| *value == 1.));
+    }
+
+    #[test]
+    fn rasterize_hole() {
+        let square_with_hole = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 2., y: 2.), (x: 8., y: 2.), (x: 8., y: 8.), (x: 2., y: 8.)]],
+        );
+        let arr: PolygonArray<i32> = vec![square_with_hole].as_slice().into();
+        let raster = rasterize(&arr, &bounds(), 10, 10, RasterizeMode::Binary).unwrap();
+        assert_eq!(covered(&raster).len(), 100 - 36);
+    }
+
+    #[test]
+    fn rasterize_lines_and_points() {
+        let diagonal = line_string![(x: 0., y: 10.), (x: 10., y: 0.)];
+        let arr: LineStringArray<i32> = vec![diagonal].as_slice().into();
+        let raster = rasterize(&arr, &bounds(), 10, 10, RasterizeMode::Count).unwrap();
+        let expected = (0..10).map(|i| (i, i)).collect::<Vec<_>>();
+        assert_eq!(covered(&raster), expected);
+
+        let points = vec![
+            point!(x: 0.5, y: 9.5),
+            point!(x: 0.7, y: 9.2),
+            point!(x: 20., y: 0.),
+        ];
+        let arr: PointArray = points.as_slice().into();
+        let weights = Float64Array::from(vec![Some(2.), Some(3.), Some(4.)]);
+        let raster = rasterize(
+            &arr,
+            &bounds(),
+            10,
+            10,
+            RasterizeMode::AttributeSum(&weights),
+        );
+        let raster = raster.unwrap();
+        assert_eq!(raster.values().value(0), 5.);
+        assert_eq!(raster.values().values().iter().sum::<f64>(), 5.);
+    }
+
+    #[test]
+    fn rasterize_empty_grid() {
+        let arr: PointArray = vec![point!(x: 0., y: 0.)].as_slice().into();
+        assert!(rasterize(&arr, &bounds(), 0, 10, RasterizeMode::Binary).is_err());
+        let flat = Rect::new((0., 0.), (10., 0.));
+        assert!(rasterize(&arr, &flat, 10, 10, RasterizeMode::Binary).is_err());
+    }
+}
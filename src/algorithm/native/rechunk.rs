@@ -26,7 +26,7 @@ impl Rechunk for PointArray {
         for range in ranges {
             output_arrays.push(self.take_range(range));
         }
-        ChunkedGeometryArray::new(output_arrays)
+        ChunkedGeometryArray::new_unchecked(output_arrays)
     }
 
     // fn rechunk_num_geoms(&self, n_geoms_per_chunk: usize) -> Self::Output {
@@ -52,7 +52,7 @@ macro_rules! rechunk_impl {
                 for range in ranges {
                     output_arrays.push(self.take_range(range)?);
                 }
-                Ok(ChunkedGeometryArray::new(output_arrays))
+                Ok(ChunkedGeometryArray::new_unchecked(output_arrays))
             }
         }
     };
@@ -74,6 +74,6 @@ rechunk_impl!(GeometryCollectionArray<O>);
 //         for range in ranges {
 //             output_arrays.push(self.take_range(range)?);
 //         }
-//         Ok(ChunkedGeometryArray::new(output_arrays))
+//         Ok(ChunkedGeometryArray::new_unchecked(output_arrays))
 //     }
 // }
@@ -0,0 +1,640 @@
+use std::sync::Arc;
+
+use arrow_array::OffsetSizeTrait;
+use geo::{
+    Area, Coord, Geometry, GeometryCollection, LineString, MapCoordsInPlace, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon,
+};
+
+use crate::array::*;
+use crate::chunked_array::{from_geoarrow_chunks, ChunkedGeometryArray, ChunkedGeometryArrayTrait};
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+
+/// The number of geometries that [`RoundCoordinates`] had to repair.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RoundingReport {
+    /// The number of geometries that were repaired and kept.
+    pub num_repaired: usize,
+
+    /// The number of geometries that collapsed entirely and were replaced by null.
+    pub num_collapsed: usize,
+}
+
+impl std::ops::Add for RoundingReport {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            num_repaired: self.num_repaired + rhs.num_repaired,
+            num_collapsed: self.num_collapsed + rhs.num_collapsed,
+        }
+    }
+}
+
+/// Round coordinates to a number of decimal places, optionally repairing geometries that
+/// rounding made invalid.
+///
+/// Rounding can snap distinct vertices onto each other, so that a thin polygon collapses to a
+/// line, or a ring doubles back on itself. With `fix_invalid`, after rounding:
+///
+/// - Repeated consecutive coordinates are removed from lines and rings.
+/// - Spikes, where a ring goes out to a vertex and straight back, are removed from rings.
+/// - Rings with fewer than three distinct vertices or no area are dropped, and rings are closed
+///   again.
+/// - A line with fewer than two distinct coordinates, or a polygon whose exterior was dropped, has
+///   collapsed. Collapsed parts are removed from multi geometries and geometry collections, and a
+///   geometry with no parts left is replaced by null.
+///
+/// Rounding may in rare cases also make two edges cross, which is not repaired.
+pub trait RoundCoordinates {
+    type Output;
+
+    /// Round every coordinate to `decimals` decimal places, repairing the geometries that became
+    /// invalid if `fix_invalid` is set. The output is returned together with the number of
+    /// geometries that needed repair.
+    ///
+    /// ```
+    /// use geo::polygon;
+    /// use geoarrow::algorithm::native::RoundCoordinates;
+    /// use geoarrow::array::PolygonArray;
+    /// use geoarrow::GeometryArrayTrait;
+    ///
+    /// // A sliver that is thinner than 0.001
+    /// let sliver = polygon![
+    ///     (x: 0., y: 0.),
+    ///     (x: 1., y: 0.0001),
+    ///     (x: 2., y: 0.),
+    ///     (x: 1., y: -0.0001),
+    /// ];
+    /// let array: PolygonArray<i32> = vec![sliver].as_slice().into();
+    ///
+    /// let (rounded, report) = array.round_coordinates(3, true);
+    /// assert!(rounded.is_null(0));
+    /// assert_eq!(report.num_collapsed, 1);
+    /// ```
+    fn round_coordinates(&self, decimals: u8, fix_invalid: bool) -> Self::Output;
+}
+
+impl RoundCoordinates for PointArray {
+    type Output = (Self, RoundingReport);
+
+    fn round_coordinates(&self, decimals: u8, _fix_invalid: bool) -> Self::Output {
+        let factor = 10_f64.powi(decimals as i32);
+        let output_geoms: Vec<Option<Point>> = self
+            .iter_geo()
+            .map(|maybe_g| {
+                maybe_g.map(|mut point| {
+                    point.map_coords_in_place(|coord| round_coord(coord, factor));
+                    point
+                })
+            })
+            .collect();
+        let array = PointBuilder::from_nullable_points(
+            output_geoms.iter().map(|x| x.as_ref()),
+            Some(self.coord_type()),
+            self.metadata(),
+        )
+        .finish();
+        (array, RoundingReport::default())
+    }
+}
+
+/// Implementation that rounds geo objects and rebuilds the array
+macro_rules! iter_geo_impl {
+    ($type:ty, $geo_type:ty, $from_func:expr) => {
+        impl<O: OffsetSizeTrait> RoundCoordinates for $type {
+            type Output = (Self, RoundingReport);
+
+            fn round_coordinates(&self, decimals: u8, fix_invalid: bool) -> Self::Output {
+                let factor = 10_f64.powi(decimals as i32);
+                let mut report = RoundingReport::default();
+                let output_geoms: Vec<Option<$geo_type>> = self
+                    .iter_geo()
+                    .map(|maybe_g| {
+                        maybe_g.and_then(|mut geom| {
+                            match geom.round_and_repair(factor, fix_invalid) {
+                                Outcome::Unchanged => Some(geom),
+                                Outcome::Repaired => {
+                                    report.num_repaired += 1;
+                                    Some(geom)
+                                }
+                                Outcome::Collapsed => {
+                                    report.num_collapsed += 1;
+                                    None
+                                }
+                            }
+                        })
+                    })
+                    .collect();
+                let array = $from_func(&output_geoms, Some(self.coord_type()), self.metadata());
+                (array, report)
+            }
+        }
+    };
+}
+
+iter_geo_impl!(
+    LineStringArray<O>,
+    LineString,
+    |geoms, coord_type, metadata| {
+        LineStringBuilder::from_nullable_line_strings(geoms, coord_type, metadata).finish()
+    }
+);
+iter_geo_impl!(PolygonArray<O>, Polygon, |geoms, coord_type, metadata| {
+    PolygonBuilder::from_nullable_polygons(geoms, coord_type, metadata).finish()
+});
+iter_geo_impl!(
+    MultiPointArray<O>,
+    MultiPoint,
+    |geoms, coord_type, metadata| {
+        MultiPointBuilder::from_nullable_multi_points(geoms, coord_type, metadata).finish()
+    }
+);
+iter_geo_impl!(
+    MultiLineStringArray<O>,
+    MultiLineString,
+    |geoms, coord_type, metadata| {
+        MultiLineStringBuilder::from_nullable_multi_line_strings(geoms, coord_type, metadata)
+            .finish()
+    }
+);
+iter_geo_impl!(
+    MultiPolygonArray<O>,
+    MultiPolygon,
+    |geoms, coord_type, metadata| {
+        MultiPolygonBuilder::from_nullable_multi_polygons(geoms, coord_type, metadata).finish()
+    }
+);
+iter_geo_impl!(
+    MixedGeometryArray<O>,
+    Geometry,
+    |geoms, coord_type, metadata| {
+        MixedGeometryBuilder::from_nullable_geometries(geoms, coord_type, metadata, false)
+            .unwrap()
+            .finish()
+    }
+);
+iter_geo_impl!(
+    GeometryCollectionArray<O>,
+    GeometryCollection,
+    |geoms, coord_type, metadata| {
+        GeometryCollectionBuilder::from_nullable_geometry_collections(
+            geoms, coord_type, metadata, false,
+        )
+        .unwrap()
+        .finish()
+    }
+);
+
+impl RoundCoordinates for &dyn GeometryArrayTrait {
+    type Output = Result<(Arc<dyn GeometryArrayTrait>, RoundingReport)>;
+
+    fn round_coordinates(&self, decimals: u8, fix_invalid: bool) -> Self::Output {
+        macro_rules! impl_round {
+            ($arr:expr) => {{
+                let (array, report) = $arr.round_coordinates(decimals, fix_invalid);
+                (Arc::new(array) as Arc<dyn GeometryArrayTrait>, report)
+            }};
+        }
+
+        let result = match self.data_type() {
+            GeoDataType::Point(_) => impl_round!(self.as_point()),
+            GeoDataType::LineString(_) => impl_round!(self.as_line_string()),
+            GeoDataType::LargeLineString(_) => impl_round!(self.as_large_line_string()),
+            GeoDataType::Polygon(_) => impl_round!(self.as_polygon()),
+            GeoDataType::LargePolygon(_) => impl_round!(self.as_large_polygon()),
+            GeoDataType::MultiPoint(_) => impl_round!(self.as_multi_point()),
+            GeoDataType::LargeMultiPoint(_) => impl_round!(self.as_large_multi_point()),
+            GeoDataType::MultiLineString(_) => impl_round!(self.as_multi_line_string()),
+            GeoDataType::LargeMultiLineString(_) => {
+                impl_round!(self.as_large_multi_line_string())
+            }
+            GeoDataType::MultiPolygon(_) => impl_round!(self.as_multi_polygon()),
+            GeoDataType::LargeMultiPolygon(_) => impl_round!(self.as_large_multi_polygon()),
+            GeoDataType::Mixed(_) => impl_round!(self.as_mixed()),
+            GeoDataType::LargeMixed(_) => impl_round!(self.as_large_mixed()),
+            GeoDataType::GeometryCollection(_) => impl_round!(self.as_geometry_collection()),
+            GeoDataType::LargeGeometryCollection(_) => {
+                impl_round!(self.as_large_geometry_collection())
+            }
+            dt => {
+                return Err(GeoArrowError::IncorrectType(
+                    format!("Rounding coordinates of {:?} is not supported", dt).into(),
+                ))
+            }
+        };
+        Ok(result)
+    }
+}
+
+impl<G> RoundCoordinates for ChunkedGeometryArray<G>
+where
+    G: GeometryArrayTrait + RoundCoordinates<Output = (G, RoundingReport)>,
+{
+    type Output = (Self, RoundingReport);
+
+    fn round_coordinates(&self, decimals: u8, fix_invalid: bool) -> Self::Output {
+        let (chunks, reports): (Vec<_>, Vec<_>) = self
+            .map(|chunk| chunk.round_coordinates(decimals, fix_invalid))
+            .into_iter()
+            .unzip();
+        let report = reports
+            .into_iter()
+            .fold(RoundingReport::default(), |a, b| a + b);
+        (ChunkedGeometryArray::new_unchecked(chunks), report)
+    }
+}
+
+impl RoundCoordinates for &dyn ChunkedGeometryArrayTrait {
+    type Output = Result<(Arc<dyn ChunkedGeometryArrayTrait>, RoundingReport)>;
+
+    fn round_coordinates(&self, decimals: u8, fix_invalid: bool) -> Self::Output {
+        let mut report = RoundingReport::default();
+        let chunks = self
+            .geometry_chunks()
+            .into_iter()
+            .map(|chunk| {
+                let (chunk, chunk_report) = chunk.round_coordinates(decimals, fix_invalid)?;
+                report = report + chunk_report;
+                Ok(chunk)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let chunk_refs = chunks
+            .iter()
+            .map(|chunk| chunk.as_ref())
+            .collect::<Vec<_>>();
+        Ok((from_geoarrow_chunks(&chunk_refs)?, report))
+    }
+}
+
+/// The result of rounding a single geometry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Outcome {
+    /// The geometry was rounded and needed no repair.
+    Unchanged,
+    /// The geometry was repaired.
+    Repaired,
+    /// Nothing valid is left of the geometry.
+    Collapsed,
+}
+
+impl Outcome {
+    fn from_changed(changed: bool) -> Self {
+        if changed {
+            Outcome::Repaired
+        } else {
+            Outcome::Unchanged
+        }
+    }
+}
+
+/// Round a coordinate, leaving values unchanged that have no digits beyond `factor` or that would
+/// overflow when scaled.
+fn round_coord(coord: Coord, factor: f64) -> Coord {
+    let round = |value: f64| {
+        let scaled = value * factor;
+        if scaled.is_finite() && scaled.abs() < 2_f64.powi(52) {
+            scaled.round() / factor
+        } else {
+            value
+        }
+    };
+    Coord {
+        x: round(coord.x),
+        y: round(coord.y),
+    }
+}
+
+trait RoundAndRepair {
+    /// Round in place, then repair the geometry if `fix_invalid` is set.
+    fn round_and_repair(&mut self, factor: f64, fix_invalid: bool) -> Outcome;
+}
+
+impl RoundAndRepair for Point {
+    fn round_and_repair(&mut self, factor: f64, _fix_invalid: bool) -> Outcome {
+        self.map_coords_in_place(|coord| round_coord(coord, factor));
+        Outcome::Unchanged
+    }
+}
+
+impl RoundAndRepair for MultiPoint {
+    fn round_and_repair(&mut self, factor: f64, _fix_invalid: bool) -> Outcome {
+        self.map_coords_in_place(|coord| round_coord(coord, factor));
+        Outcome::Unchanged
+    }
+}
+
+impl RoundAndRepair for LineString {
+    fn round_and_repair(&mut self, factor: f64, fix_invalid: bool) -> Outcome {
+        self.map_coords_in_place(|coord| round_coord(coord, factor));
+        if !fix_invalid || self.0.is_empty() {
+            return Outcome::Unchanged;
+        }
+
+        let num_coords = self.0.len();
+        self.0.dedup();
+        if self.0.len() < 2 {
+            Outcome::Collapsed
+        } else {
+            Outcome::from_changed(self.0.len() != num_coords)
+        }
+    }
+}
+
+impl RoundAndRepair for Polygon {
+    fn round_and_repair(&mut self, factor: f64, fix_invalid: bool) -> Outcome {
+        self.map_coords_in_place(|coord| round_coord(coord, factor));
+        if !fix_invalid || self.exterior().0.is_empty() {
+            return Outcome::Unchanged;
+        }
+
+        // Polygon only gives access to its interiors as a slice, so take it apart to drop rings
+        let empty = Polygon::new(LineString::new(vec![]), vec![]);
+        let (mut exterior, mut interiors) = std::mem::replace(self, empty).into_inner();
+        let mut changed = match repair_ring(&mut exterior) {
+            Outcome::Unchanged => false,
+            Outcome::Repaired => true,
+            Outcome::Collapsed => return Outcome::Collapsed,
+        };
+        interiors.retain_mut(|interior| match repair_ring(interior) {
+            Outcome::Unchanged => true,
+            Outcome::Repaired => {
+                changed = true;
+                true
+            }
+            Outcome::Collapsed => {
+                changed = true;
+                false
+            }
+        });
+        *self = Polygon::new(exterior, interiors);
+        Outcome::from_changed(changed)
+    }
+}
+
+/// Implementation for multi geometries, which drop their collapsed parts
+macro_rules! multi_impl {
+    ($type:ty) => {
+        impl RoundAndRepair for $type {
+            fn round_and_repair(&mut self, factor: f64, fix_invalid: bool) -> Outcome {
+                retain_parts(&mut self.0, factor, fix_invalid)
+            }
+        }
+    };
+}
+
+multi_impl!(MultiLineString);
+multi_impl!(MultiPolygon);
+multi_impl!(GeometryCollection);
+
+impl RoundAndRepair for Geometry {
+    fn round_and_repair(&mut self, factor: f64, fix_invalid: bool) -> Outcome {
+        match self {
+            Geometry::Point(g) => g.round_and_repair(factor, fix_invalid),
+            Geometry::LineString(g) => g.round_and_repair(factor, fix_invalid),
+            Geometry::Polygon(g) => g.round_and_repair(factor, fix_invalid),
+            Geometry::MultiPoint(g) => g.round_and_repair(factor, fix_invalid),
+            Geometry::MultiLineString(g) => g.round_and_repair(factor, fix_invalid),
+            Geometry::MultiPolygon(g) => g.round_and_repair(factor, fix_invalid),
+            Geometry::GeometryCollection(g) => g.round_and_repair(factor, fix_invalid),
+            // Not produced by GeoArrow arrays
+            Geometry::Line(_) | Geometry::Rect(_) | Geometry::Triangle(_) => {
+                self.map_coords_in_place(|coord| round_coord(coord, factor));
+                Outcome::Unchanged
+            }
+        }
+    }
+}
+
+/// Round and repair every part, removing the parts that collapsed.
+fn retain_parts<G: RoundAndRepair>(parts: &mut Vec<G>, factor: f64, fix_invalid: bool) -> Outcome {
+    if parts.is_empty() {
+        return Outcome::Unchanged;
+    }
+
+    let mut changed = false;
+    parts.retain_mut(|part| match part.round_and_repair(factor, fix_invalid) {
+        Outcome::Unchanged => true,
+        Outcome::Repaired => {
+            changed = true;
+            true
+        }
+        Outcome::Collapsed => {
+            changed = true;
+            false
+        }
+    });
+    if parts.is_empty() {
+        Outcome::Collapsed
+    } else {
+        Outcome::from_changed(changed)
+    }
+}
+
+/// Remove repeated coordinates and spikes from a closed ring, and close it again.
+fn repair_ring(ring: &mut LineString) -> Outcome {
+    let original = ring.0.clone();
+
+    // Work on the open ring, where the last coordinate is not a repeat of the first
+    let coords = &mut ring.0;
+    coords.dedup();
+    while coords.len() > 1 && coords.first() == coords.last() {
+        coords.pop();
+    }
+
+    // Removing a spike can create a new one, as a ring that collapsed to a line is a series of
+    // nested spikes
+    let mut i = 0;
+    while coords.len() >= 3 && i < coords.len() {
+        let len = coords.len();
+        let prev = coords[(i + len - 1) % len];
+        let next = coords[(i + 1) % len];
+        if prev == next {
+            // Remove the tip of the spike, and the repeat of its base
+            let next_idx = (i + 1) % len;
+            coords.remove(i.max(next_idx));
+            coords.remove(i.min(next_idx));
+            i = i.saturating_sub(2);
+        } else {
+            i += 1;
+        }
+    }
+
+    if coords.len() < 3 {
+        return Outcome::Collapsed;
+    }
+    ring.close();
+    if ring.unsigned_area() == 0. {
+        return Outcome::Collapsed;
+    }
+    Outcome::from_changed(ring.0 != original)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use geo::{line_string, point, polygon};
+
+    fn sliver() -> Polygon {
+        polygon![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.0001),
+            (x: 2., y: 0.),
+            (x: 1., y: -0.0001),
+        ]
+    }
+
+    #[test]
+    fn rounds_coordinates() {
+        let arr: LineStringArray<i32> = vec![line_string![
+            (x: 0.12345, y: 1.98765),
+            (x: 2.5, y: -3.4444),
+        ]]
+        .as_slice()
+        .into();
+        let (rounded, report) = arr.round_coordinates(2, true);
+        assert_eq!(
+            rounded.value_as_geo(0),
+            line_string![(x: 0.12, y: 1.99), (x: 2.5, y: -3.44)]
+        );
+        assert_eq!(report, RoundingReport::default());
+
+        let arr: PointArray = vec![point!(x: 1e300, y: -0.5)].as_slice().into();
+        let (rounded, _) = arr.round_coordinates(3, true);
+        assert_eq!(rounded.value_as_geo(0), point!(x: 1e300, y: -0.5));
+    }
+
+    #[test]
+    fn sliver_collapses() {
+        let arr: PolygonArray<i32> = vec![sliver(), sliver()].as_slice().into();
+
+        // At 3 decimals the sliver is a line
+        let (rounded, report) = arr.round_coordinates(3, true);
+        assert_eq!(rounded.len(), 2);
+        assert!(rounded.is_null(0) && rounded.is_null(1));
+        assert_eq!(
+            report,
+            RoundingReport {
+                num_repaired: 0,
+                num_collapsed: 2
+            }
+        );
+
+        // Without repair, the degenerate polygon is kept as is
+        let (rounded, report) = arr.round_coordinates(3, false);
+        assert!(rounded.is_valid(0));
+        assert_eq!(rounded.value_as_geo(0).unsigned_area(), 0.);
+        assert_eq!(report, RoundingReport::default());
+
+        // At 4 decimals it survives
+        let (rounded, report) = arr.round_coordinates(4, true);
+        assert_eq!(rounded.value_as_geo(0), sliver());
+        assert_eq!(report, RoundingReport::default());
+    }
+
+    #[test]
+    fn collapsed_hole_is_dropped() {
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 1., y: 1.), (x: 1.0001, y: 1.), (x: 1.0001, y: 1.0001)]],
+        );
+        let arr: PolygonArray<i32> = vec![polygon].as_slice().into();
+        let (rounded, report) = arr.round_coordinates(2, true);
+        let rounded = rounded.value_as_geo(0);
+        assert!(rounded.interiors().is_empty());
+        assert_eq!(rounded.unsigned_area(), 100.);
+        assert_eq!(report.num_repaired, 1);
+    }
+
+    #[test]
+    fn spike_is_removed() {
+        // The last vertex rounds onto the second, leaving a spike out to (2, 0)
+        let polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 2., y: 0.),
+            (x: 1.0001, y: 0.),
+            (x: 1., y: 1.),
+        ];
+        let arr: PolygonArray<i32> = vec![polygon].as_slice().into();
+        let (rounded, report) = arr.round_coordinates(2, true);
+        assert_eq!(
+            rounded.value_as_geo(0),
+            polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)]
+        );
+        assert_eq!(report.num_repaired, 1);
+    }
+
+    #[test]
+    fn multi_polygon_keeps_valid_parts() {
+        let square = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        let arr: MultiPolygonArray<i32> = vec![
+            MultiPolygon::new(vec![square.clone(), sliver()]),
+            MultiPolygon::new(vec![sliver()]),
+        ]
+        .as_slice()
+        .into();
+        let (rounded, report) = arr.round_coordinates(3, true);
+        assert_eq!(rounded.value_as_geo(0), MultiPolygon::new(vec![square]));
+        assert!(rounded.is_null(1));
+        assert_eq!(
+            report,
+            RoundingReport {
+                num_repaired: 1,
+                num_collapsed: 1
+            }
+        );
+    }
+
+    #[test]
+    fn line_string_repair() {
+        let arr: LineStringArray<i32> = vec![
+            line_string![(x: 0., y: 0.), (x: 0.0001, y: 0.), (x: 1., y: 1.)],
+            line_string![(x: 0., y: 0.), (x: 0.0001, y: 0.)],
+        ]
+        .as_slice()
+        .into();
+        let (rounded, report) = arr.round_coordinates(2, true);
+        assert_eq!(
+            rounded.value_as_geo(0),
+            line_string![(x: 0., y: 0.), (x: 1., y: 1.)]
+        );
+        assert!(rounded.is_null(1));
+        assert_eq!(
+            report,
+            RoundingReport {
+                num_repaired: 1,
+                num_collapsed: 1
+            }
+        );
+    }
+
+    #[test]
+    fn dyn_and_chunked() {
+        let arr: PolygonArray<i32> = vec![sliver()].as_slice().into();
+        let (rounded, report) = (&arr as &dyn GeometryArrayTrait)
+            .round_coordinates(3, true)
+            .unwrap();
+        assert!(rounded.is_null(0));
+        assert_eq!(report.num_collapsed, 1);
+
+        let chunked = ChunkedGeometryArray::new_unchecked(vec![arr.clone(), arr]);
+        let (rounded, report) = chunked.round_coordinates(3, true);
+        assert_eq!(rounded.len(), 2);
+        assert_eq!(report.num_collapsed, 2);
+
+        let (rounded, report) = (&chunked as &dyn ChunkedGeometryArrayTrait)
+            .round_coordinates(3, true)
+            .unwrap();
+        assert_eq!(rounded.null_count(), 2);
+        assert_eq!(report.num_collapsed, 2);
+
+        let rects: RectArray = vec![geo::Rect::new((0., 0.), (1., 1.))].as_slice().into();
+        assert!((&rects as &dyn GeometryArrayTrait)
+            .round_coordinates(3, true)
+            .is_err());
+    }
+}
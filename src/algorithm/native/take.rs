@@ -1,8 +1,9 @@
 use std::ops::Range;
 use std::sync::Arc;
 
+use crate::array::metadata::ArrayMetadata;
 use crate::array::*;
-use crate::chunked_array::ChunkedGeometryArray;
+use crate::chunked_array::{ChunkedGeometryArray, ChunkedGeometryArrayTrait};
 use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
 use crate::trait_::GeometryArrayAccessor;
@@ -11,6 +12,10 @@ use arrow_array::{OffsetSizeTrait, UInt32Array};
 use arrow_buffer::ArrowNativeType;
 
 /// Take elements by index from Array, creating a new Array from those indexes.
+///
+/// A null index creates a null element. Indices into a chunked array refer to the elements of all
+/// chunks as if they were concatenated, and the output has a single chunk. Taking from a chunked
+/// array returns an error if an index is out of bounds.
 pub trait Take {
     type Output;
 
@@ -279,59 +284,273 @@ impl Take for &dyn GeometryArrayTrait {
     }
 }
 
+/// Resolve indices into the logical array formed by concatenating `chunks` to the
+/// `(chunk, offset)` pair of each element. Null indices resolve to `None`.
+fn resolve_indices<G: GeometryArrayTrait>(
+    chunks: &[G],
+    indices: &UInt32Array,
+) -> Result<Vec<Option<(usize, usize)>>> {
+    let mut chunk_starts = Vec::with_capacity(chunks.len());
+    let mut len = 0;
+    for chunk in chunks {
+        chunk_starts.push(len);
+        len += chunk.len();
+    }
+
+    indices
+        .iter()
+        .map(|index| {
+            index
+                .map(|index| {
+                    let index = index.as_usize();
+                    if index >= len {
+                        return Err(GeoArrowError::General(format!(
+                            "Index {} out of bounds for a chunked array of length {}",
+                            index, len
+                        )));
+                    }
+                    // The last chunk starting at or before `index` can't be empty, as it would
+                    // then start where the next chunk does.
+                    let chunk = chunk_starts.partition_point(|start| *start <= index) - 1;
+                    Ok((chunk, index - chunk_starts[chunk]))
+                })
+                .transpose()
+        })
+        .collect()
+}
+
+/// The coordinate type and metadata of the output of taking from `chunks`, which are those of
+/// the first chunk.
+fn output_options<G: GeometryArrayTrait>(chunks: &[G]) -> (CoordType, Arc<ArrayMetadata>) {
+    chunks
+        .first()
+        .map(|chunk| (chunk.coord_type(), chunk.metadata()))
+        .unwrap_or_default()
+}
+
+fn range_indices(range: &Range<usize>) -> UInt32Array {
+    UInt32Array::from_iter_values(range.clone().map(|i| i as u32))
+}
+
 impl Take for ChunkedGeometryArray<PointArray> {
     type Output = Result<ChunkedGeometryArray<PointArray>>;
 
     fn take(&self, indices: &UInt32Array) -> Self::Output {
-        let mut output_chunks = Vec::with_capacity(self.chunks.len());
-        for chunk in self.chunks.iter() {
-            output_chunks.push(chunk.take(indices));
+        let locations = resolve_indices(&self.chunks, indices)?;
+        let (coord_type, metadata) = output_options(&self.chunks);
+        let mut builder =
+            PointBuilder::with_capacity_and_options(indices.len(), coord_type, metadata);
+        for location in locations {
+            if let Some((chunk, offset)) = location {
+                builder.push_point(self.chunks[chunk].get(offset).as_ref());
+            } else {
+                builder.push_null();
+            }
         }
 
-        Ok(ChunkedGeometryArray::new(output_chunks))
+        Ok(ChunkedGeometryArray::new_unchecked(vec![builder.finish()]))
     }
 
     fn take_range(&self, range: &Range<usize>) -> Self::Output {
-        let mut output_chunks = Vec::with_capacity(self.chunks.len());
-        for chunk in self.chunks.iter() {
-            output_chunks.push(chunk.take_range(range));
-        }
-
-        Ok(ChunkedGeometryArray::new(output_chunks))
+        self.take(&range_indices(range))
     }
 }
 
-/// Implementation that iterates over chunks
+/// Implementation that resolves global indices to chunks
 macro_rules! chunked_impl {
-    ($type:ty) => {
-        impl<O: OffsetSizeTrait> Take for $type {
-            type Output = Result<$type>;
+    ($array_type:ty, $capacity_type:ty, $builder_type:ty, $capacity_add_func:ident, $push_func:ident) => {
+        impl<O: OffsetSizeTrait> Take for ChunkedGeometryArray<$array_type> {
+            type Output = Result<Self>;
 
             fn take(&self, indices: &UInt32Array) -> Self::Output {
-                let mut output_chunks = Vec::with_capacity(self.chunks.len());
-                for chunk in self.chunks.iter() {
-                    output_chunks.push(chunk.take(indices)?);
+                let locations = resolve_indices(&self.chunks, indices)?;
+
+                let mut capacity = <$capacity_type>::new_empty();
+                for (chunk, offset) in locations.iter().flatten() {
+                    capacity.$capacity_add_func(self.chunks[*chunk].get(*offset).as_ref());
+                }
+
+                let (coord_type, metadata) = output_options(&self.chunks);
+                let mut builder =
+                    <$builder_type>::with_capacity_and_options(capacity, coord_type, metadata);
+
+                for location in locations {
+                    if let Some((chunk, offset)) = location {
+                        builder.$push_func(self.chunks[chunk].get(offset).as_ref())?;
+                    } else {
+                        builder.push_null();
+                    }
                 }
 
-                Ok(ChunkedGeometryArray::new(output_chunks))
+                Ok(ChunkedGeometryArray::new_unchecked(vec![builder.finish()]))
             }
 
             fn take_range(&self, range: &Range<usize>) -> Self::Output {
-                let mut output_chunks = Vec::with_capacity(self.chunks.len());
-                for chunk in self.chunks.iter() {
-                    output_chunks.push(chunk.take_range(range)?);
+                self.take(&range_indices(range))
+            }
+        }
+    };
+}
+
+chunked_impl!(
+    LineStringArray<O>,
+    LineStringCapacity,
+    LineStringBuilder<O>,
+    add_line_string,
+    push_line_string
+);
+chunked_impl!(
+    PolygonArray<O>,
+    PolygonCapacity,
+    PolygonBuilder<O>,
+    add_polygon,
+    push_polygon
+);
+chunked_impl!(
+    MultiPointArray<O>,
+    MultiPointCapacity,
+    MultiPointBuilder<O>,
+    add_multi_point,
+    push_multi_point
+);
+chunked_impl!(
+    MultiLineStringArray<O>,
+    MultiLineStringCapacity,
+    MultiLineStringBuilder<O>,
+    add_multi_line_string,
+    push_multi_line_string
+);
+chunked_impl!(
+    MultiPolygonArray<O>,
+    MultiPolygonCapacity,
+    MultiPolygonBuilder<O>,
+    add_multi_polygon,
+    push_multi_polygon
+);
+
+macro_rules! chunked_impl_fallible {
+    ($array_type:ty, $capacity_type:ty, $builder_type:ty, $capacity_add_func:ident, $push_func:ident) => {
+        impl<O: OffsetSizeTrait> Take for ChunkedGeometryArray<$array_type> {
+            type Output = Result<Self>;
+
+            fn take(&self, indices: &UInt32Array) -> Self::Output {
+                let locations = resolve_indices(&self.chunks, indices)?;
+
+                let mut capacity = <$capacity_type>::new_empty();
+                for (chunk, offset) in locations.iter().flatten() {
+                    capacity.$capacity_add_func(self.chunks[*chunk].get(*offset).as_ref())?;
                 }
 
-                Ok(ChunkedGeometryArray::new(output_chunks))
+                let (coord_type, metadata) = output_options(&self.chunks);
+                let mut builder =
+                    <$builder_type>::with_capacity_and_options(capacity, coord_type, metadata);
+
+                for location in locations {
+                    if let Some((chunk, offset)) = location {
+                        builder.$push_func(self.chunks[chunk].get(offset).as_ref())?;
+                    } else {
+                        builder.push_null();
+                    }
+                }
+
+                Ok(ChunkedGeometryArray::new_unchecked(vec![builder.finish()]))
+            }
+
+            fn take_range(&self, range: &Range<usize>) -> Self::Output {
+                self.take(&range_indices(range))
             }
         }
     };
 }
 
-chunked_impl!(ChunkedGeometryArray<LineStringArray<O>>);
-chunked_impl!(ChunkedGeometryArray<PolygonArray<O>>);
-chunked_impl!(ChunkedGeometryArray<MultiPointArray<O>>);
-chunked_impl!(ChunkedGeometryArray<MultiLineStringArray<O>>);
-chunked_impl!(ChunkedGeometryArray<MultiPolygonArray<O>>);
-chunked_impl!(ChunkedGeometryArray<MixedGeometryArray<O>>);
-chunked_impl!(ChunkedGeometryArray<GeometryCollectionArray<O>>);
+chunked_impl_fallible!(
+    MixedGeometryArray<O>,
+    MixedCapacity,
+    MixedGeometryBuilder<O>,
+    add_geometry,
+    push_geometry
+);
+chunked_impl_fallible!(
+    GeometryCollectionArray<O>,
+    GeometryCollectionCapacity,
+    GeometryCollectionBuilder<O>,
+    add_geometry_collection,
+    push_geometry_collection
+);
+
+impl Take for &dyn ChunkedGeometryArrayTrait {
+    type Output = Result<Arc<dyn ChunkedGeometryArrayTrait>>;
+
+    fn take(&self, indices: &UInt32Array) -> Self::Output {
+        macro_rules! impl_take {
+            ($cast_func:ident) => {
+                Arc::new(self.$cast_func().take(indices)?)
+            };
+        }
+
+        let result: Arc<dyn ChunkedGeometryArrayTrait> = match self.data_type() {
+            GeoDataType::Point(_) => impl_take!(as_point),
+            GeoDataType::LineString(_) => impl_take!(as_line_string),
+            GeoDataType::LargeLineString(_) => impl_take!(as_large_line_string),
+            GeoDataType::Polygon(_) => impl_take!(as_polygon),
+            GeoDataType::LargePolygon(_) => impl_take!(as_large_polygon),
+            GeoDataType::MultiPoint(_) => impl_take!(as_multi_point),
+            GeoDataType::LargeMultiPoint(_) => impl_take!(as_large_multi_point),
+            GeoDataType::MultiLineString(_) => impl_take!(as_multi_line_string),
+            GeoDataType::LargeMultiLineString(_) => impl_take!(as_large_multi_line_string),
+            GeoDataType::MultiPolygon(_) => impl_take!(as_multi_polygon),
+            GeoDataType::LargeMultiPolygon(_) => impl_take!(as_large_multi_polygon),
+            GeoDataType::Mixed(_) => impl_take!(as_mixed),
+            GeoDataType::LargeMixed(_) => impl_take!(as_large_mixed),
+            GeoDataType::GeometryCollection(_) => impl_take!(as_geometry_collection),
+            GeoDataType::LargeGeometryCollection(_) => {
+                impl_take!(as_large_geometry_collection)
+            }
+            _ => return Err(GeoArrowError::IncorrectType("".into())),
+        };
+        Ok(result)
+    }
+
+    fn take_range(&self, range: &Range<usize>) -> Self::Output {
+        self.take(&range_indices(range))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::point;
+    use crate::trait_::GeometryArraySelfMethods;
+
+    #[test]
+    fn take_chunked_global_indices() {
+        let chunked = ChunkedGeometryArray::new(vec![point::point_array(), point::point_array()]);
+        let indices = UInt32Array::from(vec![Some(4), None, Some(0), Some(2)]);
+        let taken = chunked.take(&indices).unwrap();
+        assert_eq!(taken.chunks().len(), 1);
+
+        let chunk = &taken.chunks()[0];
+        assert_eq!(chunk.len(), 4);
+        assert_eq!(chunk.value_as_geo(0), point::p1());
+        assert!(chunk.is_null(1));
+        assert_eq!(chunk.value_as_geo(2), point::p0());
+        assert_eq!(chunk.value_as_geo(3), point::p2());
+    }
+
+    #[test]
+    fn take_chunked_out_of_bounds() {
+        let chunked = ChunkedGeometryArray::new(vec![point::point_array(), point::point_array()]);
+        let indices = UInt32Array::from(vec![6]);
+        assert!(chunked.take(&indices).is_err());
+    }
+
+    #[test]
+    fn take_chunked_skips_empty_chunks() {
+        let empty = point::point_array().slice(0, 0);
+        let chunked = ChunkedGeometryArray::new(vec![empty, point::point_array()]);
+        let taken = chunked.take_range(&(1..3)).unwrap();
+        let chunk = &taken.chunks()[0];
+        assert_eq!(chunk.value_as_geo(0), point::p1());
+        assert_eq!(chunk.value_as_geo(1), point::p2());
+    }
+}
@@ -141,7 +141,7 @@ mod test {
     #[test]
     fn test_dyn_chunked_array() {
         let chunked_array: Arc<dyn ChunkedGeometryArrayTrait> =
-            Arc::new(ChunkedGeometryArray::new(vec![
+            Arc::new(ChunkedGeometryArray::new_unchecked(vec![
                 polygon::p_array(),
                 polygon::p_array(),
             ]));
@@ -154,7 +154,7 @@ mod test {
     //     let dyn_arrs: Vec<Arc<dyn GeometryArrayTrait>> =
     //         vec![Arc::new(polygon::p_array()), Arc::new(polygon::p_array())];
     //     let chunked_array: Arc<dyn ChunkedGeometryArrayTrait> =
-    //         Arc::new(ChunkedGeometryArray::new(dyn_arrs));
+    //         Arc::new(ChunkedGeometryArray::new_unchecked(dyn_arrs));
     //     let total_bounds = chunked_array.as_ref().total_bounds();
     //     dbg!(total_bounds);
     // }
@@ -51,7 +51,7 @@ impl<O: OffsetSizeTrait> Polylabel for ChunkedPolygonArray<O> {
 
     fn polylabel(&self, tolerance: f64) -> Self::Output {
         let chunks = self.try_map(|chunk| chunk.polylabel(tolerance))?;
-        Ok(ChunkedGeometryArray::new(chunks))
+        Ok(ChunkedGeometryArray::new_unchecked(chunks))
     }
 }
 
@@ -1,5 +1,6 @@
 //! Bindings to the [`proj`] crate for coordinate reprojection.
 
+use crate::algorithm::geo::{LengthMethod, SplitLongSegments};
 use crate::array::*;
 use crate::error::Result;
 use crate::trait_::GeometryArrayAccessor;
@@ -7,15 +8,53 @@ use crate::GeometryArrayTrait;
 use arrow_array::OffsetSizeTrait;
 use proj::{Proj, Transform};
 
+/// Options for [`Reproject::reproject_with_options`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ReprojectOptions {
+    /// If set, split segments longer than this many meters with
+    /// [`SplitLongSegments`] before reprojecting, so that long edges follow the curve they
+    /// trace in the output CRS instead of being drawn as a single straight segment. This requires
+    /// the input coordinates to be longitude/latitude. Points are never densified.
+    pub pre_densify: Option<f64>,
+
+    /// How segment lengths are measured when `pre_densify` is set.
+    pub length_method: LengthMethod,
+}
+
+impl ReprojectOptions {
+    /// Split segments longer than `max_length_m` meters before reprojecting.
+    pub fn with_pre_densify(self, max_length_m: f64) -> Self {
+        Self {
+            pre_densify: Some(max_length_m),
+            ..self
+        }
+    }
+
+    /// Set how segment lengths are measured when pre-densifying.
+    pub fn with_length_method(self, length_method: LengthMethod) -> Self {
+        Self {
+            length_method,
+            ..self
+        }
+    }
+}
+
 /// Reproject an array using PROJ
 pub trait Reproject {
     fn reproject(&self, proj: &Proj) -> Result<Self>
+    where
+        Self: Sized,
+    {
+        self.reproject_with_options(proj, &Default::default())
+    }
+
+    fn reproject_with_options(&self, proj: &Proj, options: &ReprojectOptions) -> Result<Self>
     where
         Self: Sized;
 }
 
 impl Reproject for PointArray {
-    fn reproject(&self, proj: &Proj) -> Result<Self> {
+    fn reproject_with_options(&self, proj: &Proj, _options: &ReprojectOptions) -> Result<Self> {
         let mut output_array = PointBuilder::with_capacity(self.len());
 
         for maybe_geom in self.iter_geo() {
@@ -34,7 +73,11 @@ impl Reproject for PointArray {
 macro_rules! iter_geo_impl {
     ($type:ty, $builder_type:ty, $push_func:ident) => {
         impl<O: OffsetSizeTrait> Reproject for $type {
-            fn reproject(&self, proj: &Proj) -> Result<Self> {
+            fn reproject_with_options(
+                &self,
+                proj: &Proj,
+                _options: &ReprojectOptions,
+            ) -> Result<Self> {
                 let mut output_array = <$builder_type>::with_capacity(self.buffer_lengths());
 
                 for maybe_geom in self.iter_geo() {
@@ -52,15 +95,51 @@ macro_rules! iter_geo_impl {
     };
 }
 
-iter_geo_impl!(LineStringArray<O>, LineStringBuilder<O>, push_line_string);
-iter_geo_impl!(PolygonArray<O>, PolygonBuilder<O>, push_polygon);
 iter_geo_impl!(MultiPointArray<O>, MultiPointBuilder<O>, push_multi_point);
-iter_geo_impl!(
+
+/// Implementation for linear types, which are split into shorter segments first if
+/// `pre_densify` is set
+macro_rules! densify_impl {
+    ($type:ty, $builder_type:ty, $push_func:ident) => {
+        impl<O: OffsetSizeTrait> Reproject for $type {
+            fn reproject_with_options(
+                &self,
+                proj: &Proj,
+                options: &ReprojectOptions,
+            ) -> Result<Self> {
+                let densified;
+                let input = if let Some(max_length_m) = options.pre_densify {
+                    densified = self.split_long_segments(max_length_m, options.length_method);
+                    &densified
+                } else {
+                    self
+                };
+
+                let mut output_array = <$builder_type>::with_capacity(input.buffer_lengths());
+
+                for maybe_geom in input.iter_geo() {
+                    if let Some(mut geom) = maybe_geom {
+                        geom.transform(proj)?;
+                        output_array.$push_func(Some(&geom))?;
+                    } else {
+                        output_array.push_null()
+                    }
+                }
+
+                Ok(output_array.into())
+            }
+        }
+    };
+}
+
+densify_impl!(LineStringArray<O>, LineStringBuilder<O>, push_line_string);
+densify_impl!(PolygonArray<O>, PolygonBuilder<O>, push_polygon);
+densify_impl!(
     MultiLineStringArray<O>,
     MultiLineStringBuilder<O>,
     push_multi_line_string
 );
-iter_geo_impl!(
+densify_impl!(
     MultiPolygonArray<O>,
     MultiPolygonBuilder<O>,
     push_multi_polygon
@@ -88,4 +167,25 @@ mod test {
         assert_relative_eq!(out.value_as_geo(0).y(), 111325.1428663851);
         dbg!(out);
     }
+
+    #[test]
+    fn pre_densify_line_string() {
+        // New York City to London
+        let line_string = geo::line_string![(x: -74.006, y: 40.7128), (x: -0.1278, y: 51.5074)];
+        let arr: LineStringArray<i32> = vec![line_string].as_slice().into();
+        let proj = Proj::new_known_crs("EPSG:4326", "EPSG:3857", None).unwrap();
+
+        let out = arr.reproject(&proj).unwrap();
+        assert_eq!(out.value_as_geo(0).0.len(), 2);
+
+        let options = ReprojectOptions::default().with_pre_densify(500_000.);
+        let out = arr.reproject_with_options(&proj, &options).unwrap();
+        let out_line_string = out.value_as_geo(0);
+        assert_eq!(out_line_string.0.len(), 13);
+
+        // The great circle bends north of the straight line in Web Mercator
+        let midpoint = out_line_string.0[6];
+        let straight_midpoint_y = (out_line_string.0[0].y + out_line_string.0[12].y) / 2.;
+        assert!(midpoint.y > straight_midpoint_y);
+    }
 }
@@ -3,14 +3,14 @@ use std::sync::Arc;
 
 use crate::array::binary::WKBCapacity;
 use crate::array::metadata::ArrayMetadata;
-use crate::array::util::{offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32};
+use crate::array::util::{offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32, validity_size};
 use crate::array::{CoordType, WKBBuilder};
 use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
 use crate::geo_traits::GeometryTrait;
 use crate::scalar::WKB;
 // use crate::util::{owned_slice_offsets, owned_slice_validity};
-use crate::trait_::{GeometryArrayAccessor, GeometryArraySelfMethods, IntoArrow};
+use crate::trait_::{BufferSizes, GeometryArrayAccessor, GeometryArraySelfMethods, IntoArrow};
 use crate::GeometryArrayTrait;
 use arrow_array::OffsetSizeTrait;
 use arrow_array::{Array, BinaryArray, GenericBinaryArray, LargeBinaryArray};
@@ -139,6 +139,18 @@ impl<O: OffsetSizeTrait> GeometryArrayTrait for WKBArray<O> {
         self.metadata.clone()
     }
 
+    fn buffer_sizes(&self) -> BufferSizes {
+        let value_offsets = self.array.value_offsets();
+        let first = value_offsets.first().map_or(0, |offset| offset.as_usize());
+        let last = value_offsets.last().map_or(0, |offset| offset.as_usize());
+        BufferSizes {
+            offsets: std::mem::size_of_val(value_offsets),
+            validity: validity_size(self.validity()),
+            data: last - first,
+            ..Default::default()
+        }
+    }
+
     /// Returns the number of geometries in this array
     #[inline]
     fn len(&self) -> usize {
@@ -164,6 +176,11 @@ impl<O: OffsetSizeTrait> GeometryArraySelfMethods for WKBArray<O> {
         self
     }
 
+    fn with_metadata(mut self, metadata: Arc<ArrayMetadata>) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
     /// Slices this [`WKBArray`] in place.
     /// # Panic
     /// This function panics iff `offset + length > self.len()`.
@@ -7,6 +7,7 @@ use crate::geo_traits::{
     GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait, MultiLineStringTrait,
     MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait,
 };
+use crate::io::wkb::reader::Endianness;
 use crate::io::wkb::writer::{
     geometry_collection_wkb_size, line_string_wkb_size, multi_line_string_wkb_size,
     multi_point_wkb_size, multi_polygon_wkb_size, polygon_wkb_size,
@@ -83,7 +84,7 @@ impl<O: OffsetSizeTrait> WKBBuilder<O> {
         if let Some(geom) = geom {
             // TODO: figure out how to write directly to the underlying vec without a copy
             let mut buf = Vec::with_capacity(POINT_WKB_SIZE);
-            write_point_as_wkb(&mut buf, geom).unwrap();
+            write_point_as_wkb(&mut buf, geom, Endianness::LittleEndian).unwrap();
             self.0.append_value(&buf)
         } else {
             self.0.append_null();
@@ -96,7 +97,7 @@ impl<O: OffsetSizeTrait> WKBBuilder<O> {
         if let Some(geom) = geom {
             // TODO: figure out how to write directly to the underlying vec without a copy
             let mut buf = Vec::with_capacity(line_string_wkb_size(geom));
-            write_line_string_as_wkb(&mut buf, geom).unwrap();
+            write_line_string_as_wkb(&mut buf, geom, Endianness::LittleEndian).unwrap();
             self.0.append_value(&buf)
         } else {
             self.0.append_null()
@@ -109,7 +110,7 @@ impl<O: OffsetSizeTrait> WKBBuilder<O> {
         if let Some(geom) = geom {
             // TODO: figure out how to write directly to the underlying vec without a copy
             let mut buf = Vec::with_capacity(polygon_wkb_size(geom));
-            write_polygon_as_wkb(&mut buf, geom).unwrap();
+            write_polygon_as_wkb(&mut buf, geom, Endianness::LittleEndian).unwrap();
             self.0.append_value(&buf)
         } else {
             self.0.append_null()
@@ -122,7 +123,7 @@ impl<O: OffsetSizeTrait> WKBBuilder<O> {
         if let Some(geom) = geom {
             // TODO: figure out how to write directly to the underlying vec without a copy
             let mut buf = Vec::with_capacity(multi_point_wkb_size(geom));
-            write_multi_point_as_wkb(&mut buf, geom).unwrap();
+            write_multi_point_as_wkb(&mut buf, geom, Endianness::LittleEndian).unwrap();
             self.0.append_value(&buf)
         } else {
             self.0.append_null()
@@ -135,7 +136,7 @@ impl<O: OffsetSizeTrait> WKBBuilder<O> {
         if let Some(geom) = geom {
             // TODO: figure out how to write directly to the underlying vec without a copy
             let mut buf = Vec::with_capacity(multi_line_string_wkb_size(geom));
-            write_multi_line_string_as_wkb(&mut buf, geom).unwrap();
+            write_multi_line_string_as_wkb(&mut buf, geom, Endianness::LittleEndian).unwrap();
             self.0.append_value(&buf)
         } else {
             self.0.append_null()
@@ -148,7 +149,7 @@ impl<O: OffsetSizeTrait> WKBBuilder<O> {
         if let Some(geom) = geom {
             // TODO: figure out how to write directly to the underlying vec without a copy
             let mut buf = Vec::with_capacity(multi_polygon_wkb_size(geom));
-            write_multi_polygon_as_wkb(&mut buf, geom).unwrap();
+            write_multi_polygon_as_wkb(&mut buf, geom, Endianness::LittleEndian).unwrap();
             self.0.append_value(&buf)
         } else {
             self.0.append_null()
@@ -189,7 +190,7 @@ impl<O: OffsetSizeTrait> WKBBuilder<O> {
         if let Some(geom) = geom {
             // TODO: figure out how to write directly to the underlying vec without a copy
             let mut buf = Vec::with_capacity(geometry_collection_wkb_size(geom));
-            write_geometry_collection_as_wkb(&mut buf, geom).unwrap();
+            write_geometry_collection_as_wkb(&mut buf, geom, Endianness::LittleEndian).unwrap();
             self.0.append_value(&buf)
         } else {
             self.0.append_null()
@@ -0,0 +1,303 @@
+use std::sync::Arc;
+
+use arrow_array::{GenericBinaryArray, OffsetSizeTrait};
+use arrow_buffer::{Buffer, NullBuffer, OffsetBuffer, ScalarBuffer};
+
+use crate::array::metadata::ArrayMetadata;
+use crate::array::util::OffsetBufferUtils;
+use crate::array::{WKBArray, WKBCapacity};
+use crate::error::{GeoArrowError, Result};
+use crate::GeometryArrayTrait;
+
+/// The default number of geometries in each compressed block.
+pub const DEFAULT_BLOCK_SIZE: usize = 1024;
+
+/// A block of up to `block_size` geometries, with the WKB values compressed together.
+#[derive(Debug, Clone, PartialEq)]
+struct CompressedBlock<O: OffsetSizeTrait> {
+    /// Offsets into the decompressed values of this block, starting at zero.
+    offsets: OffsetBuffer<O>,
+
+    /// The zstd-compressed WKB values of this block.
+    values: Vec<u8>,
+}
+
+impl<O: OffsetSizeTrait> CompressedBlock<O> {
+    fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    fn uncompressed_len(&self) -> usize {
+        self.offsets.last().unwrap().as_usize()
+    }
+
+    fn decompress(&self) -> Result<Buffer> {
+        let values = zstd::bulk::decompress(&self.values, self.uncompressed_len())?;
+        Ok(Buffer::from_vec(values))
+    }
+}
+
+/// An immutable array of WKB geometries where the values are held zstd-compressed in memory.
+///
+/// Geometries are grouped into blocks of `block_size` geometries, and the WKB values of each block
+/// are compressed together. Offsets and validity are kept uncompressed, so the length and null
+/// count are known without decompressing. Accessing a geometry decompresses only the block that
+/// holds it, and parsing the whole array with
+/// [`from_compressed_wkb`][crate::io::wkb::from_compressed_wkb] decompresses one block at a time.
+///
+/// This is intended for memory-constrained environments such as WASM, where holding a full
+/// [`WKBArray`] is the limiting factor. Convert to a [`WKBArray`] or to a GeoArrow-native array for
+/// computations.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompressedWKBArray<O: OffsetSizeTrait> {
+    metadata: Arc<ArrayMetadata>,
+    block_size: usize,
+    len: usize,
+    blocks: Vec<CompressedBlock<O>>,
+    validity: Option<NullBuffer>,
+}
+
+impl<O: OffsetSizeTrait> CompressedWKBArray<O> {
+    /// Compress a [`WKBArray`] in blocks of `block_size` geometries, with the given zstd
+    /// compression level.
+    pub fn try_new(array: &WKBArray<O>, block_size: usize, level: i32) -> Result<Self> {
+        if block_size == 0 {
+            return Err(GeoArrowError::General(
+                "block_size must be greater than 0".to_string(),
+            ));
+        }
+
+        let offsets = array.array.offsets();
+        let values = array.array.values();
+        let mut blocks = Vec::with_capacity(array.len().div_ceil(block_size));
+        for block_start in (0..array.len()).step_by(block_size) {
+            let block_end = (block_start + block_size).min(array.len());
+            let values_start = offsets[block_start];
+            let values_end = offsets[block_end];
+
+            let block_offsets = offsets[block_start..=block_end]
+                .iter()
+                .map(|offset| *offset - values_start)
+                .collect::<Vec<_>>();
+            let block_values = zstd::bulk::compress(
+                &values[values_start.as_usize()..values_end.as_usize()],
+                level,
+            )?;
+            blocks.push(CompressedBlock {
+                offsets: OffsetBuffer::new(ScalarBuffer::from(block_offsets)),
+                values: block_values,
+            });
+        }
+
+        Ok(Self {
+            metadata: array.metadata(),
+            block_size,
+            len: array.len(),
+            blocks,
+            validity: array.validity().cloned(),
+        })
+    }
+
+    /// The number of geometries in this array.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the array is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The number of geometries in each block. The last block may hold fewer geometries.
+    pub fn block_size(&self) -> usize {
+        self.block_size
+    }
+
+    /// The number of compressed blocks.
+    pub fn num_blocks(&self) -> usize {
+        self.blocks.len()
+    }
+
+    /// The validity of the geometries in this array.
+    pub fn validity(&self) -> Option<&NullBuffer> {
+        self.validity.as_ref()
+    }
+
+    pub fn metadata(&self) -> Arc<ArrayMetadata> {
+        self.metadata.clone()
+    }
+
+    /// Returns true if the geometry at position `i` is valid.
+    pub fn is_valid(&self, i: usize) -> bool {
+        match &self.validity {
+            Some(validity) => validity.is_valid(i),
+            None => true,
+        }
+    }
+
+    /// Decompress block `block_idx` to a [`WKBArray`].
+    pub fn block(&self, block_idx: usize) -> Result<WKBArray<O>> {
+        let block = &self.blocks[block_idx];
+        let block_start = block_idx * self.block_size;
+        let validity = self
+            .validity
+            .as_ref()
+            .map(|validity| validity.slice(block_start, block.len()));
+        let array =
+            GenericBinaryArray::try_new(block.offsets.clone(), block.decompress()?, validity)?;
+        Ok(WKBArray::new(array, self.metadata()))
+    }
+
+    /// Iterate over the decompressed blocks of this array, in order.
+    ///
+    /// Only one block is decompressed at a time.
+    pub fn blocks(&self) -> impl Iterator<Item = Result<WKBArray<O>>> + '_ {
+        (0..self.blocks.len()).map(|block_idx| self.block(block_idx))
+    }
+
+    /// The WKB bytes of the geometry at position `i`, or `None` if the geometry is null.
+    ///
+    /// This decompresses the block that holds the geometry, so prefer [`blocks`][Self::blocks]
+    /// when accessing many geometries.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is out of bounds.
+    pub fn value(&self, i: usize) -> Result<Option<Vec<u8>>> {
+        assert!(i < self.len, "index out of bounds");
+        if !self.is_valid(i) {
+            return Ok(None);
+        }
+
+        let block = &self.blocks[i / self.block_size];
+        let values = block.decompress()?;
+        let (start, end) = block.offsets.start_end(i % self.block_size);
+        Ok(Some(values[start..end].to_vec()))
+    }
+
+    /// The number of bytes occupied by this array, with the WKB values compressed.
+    pub fn num_bytes(&self) -> usize {
+        let validity_len = self
+            .validity
+            .as_ref()
+            .map(|v| v.buffer().len())
+            .unwrap_or(0);
+        let blocks_len = self
+            .blocks
+            .iter()
+            .map(|block| block.offsets.inner().inner().len() + block.values.len())
+            .sum::<usize>();
+        validity_len + blocks_len
+    }
+
+    /// The number of bytes this array occupies as an uncompressed [`WKBArray`], as reported by
+    /// [`WKBArray::num_bytes`].
+    pub fn uncompressed_num_bytes(&self) -> usize {
+        let validity_len = self
+            .validity
+            .as_ref()
+            .map(|v| v.buffer().len())
+            .unwrap_or(0);
+        let values_len = self
+            .blocks
+            .iter()
+            .map(|block| block.uncompressed_len())
+            .sum::<usize>();
+        validity_len + WKBCapacity::new(values_len, self.len).num_bytes::<O>()
+    }
+
+    /// The ratio of the uncompressed size to the compressed size of this array.
+    pub fn compression_ratio(&self) -> f64 {
+        self.uncompressed_num_bytes() as f64 / self.num_bytes() as f64
+    }
+}
+
+impl<O: OffsetSizeTrait> TryFrom<&WKBArray<O>> for CompressedWKBArray<O> {
+    type Error = GeoArrowError;
+
+    /// Compress with [`DEFAULT_BLOCK_SIZE`] geometries per block and the default zstd level.
+    fn try_from(value: &WKBArray<O>) -> Result<Self> {
+        Self::try_new(value, DEFAULT_BLOCK_SIZE, zstd::DEFAULT_COMPRESSION_LEVEL)
+    }
+}
+
+impl<O: OffsetSizeTrait> TryFrom<&CompressedWKBArray<O>> for WKBArray<O> {
+    type Error = GeoArrowError;
+
+    fn try_from(value: &CompressedWKBArray<O>) -> Result<Self> {
+        let values_len = value
+            .blocks
+            .iter()
+            .map(|block| block.uncompressed_len())
+            .sum::<usize>();
+        let mut offsets = Vec::with_capacity(value.len + 1);
+        let mut values = Vec::with_capacity(values_len);
+        offsets.push(O::usize_as(0));
+        for block in value.blocks.iter() {
+            let values_start = O::usize_as(values.len());
+            offsets.extend(
+                block.offsets[1..]
+                    .iter()
+                    .map(|offset| *offset + values_start),
+            );
+            values.extend_from_slice(&block.decompress()?);
+        }
+
+        let array = GenericBinaryArray::try_new(
+            OffsetBuffer::new(ScalarBuffer::from(offsets)),
+            Buffer::from_vec(values),
+            value.validity.clone(),
+        )?;
+        Ok(WKBArray::new(array, value.metadata()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::PointArray;
+    use crate::test::point;
+    use crate::trait_::GeometryArrayAccessor;
+
+    fn wkb_array() -> WKBArray<i32> {
+        let point_array: PointArray = vec![
+            Some(point::p0()),
+            None,
+            Some(point::p1()),
+            Some(point::p2()),
+            None,
+        ]
+        .into();
+        (&point_array).into()
+    }
+
+    #[test]
+    fn round_trip() {
+        let wkb_array = wkb_array();
+        let compressed = CompressedWKBArray::try_new(&wkb_array, 2, 3).unwrap();
+        assert_eq!(compressed.len(), 5);
+        assert_eq!(compressed.num_blocks(), 3);
+
+        let block = compressed.block(1).unwrap();
+        assert_eq!(block.len(), 2);
+        assert_eq!(block.validity().unwrap().null_count(), 0);
+
+        let decompressed = WKBArray::try_from(&compressed).unwrap();
+        assert_eq!(decompressed, wkb_array);
+    }
+
+    #[test]
+    fn value() {
+        let wkb_array = wkb_array();
+        let compressed = CompressedWKBArray::try_new(&wkb_array, 2, 3).unwrap();
+        for i in 0..wkb_array.len() {
+            let expected = wkb_array.get(i).map(|wkb| wkb.as_ref().to_vec());
+            assert_eq!(compressed.value(i).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn zero_block_size() {
+        assert!(CompressedWKBArray::try_new(&wkb_array(), 0, 3).is_err());
+    }
+}
@@ -4,7 +4,11 @@
 pub use array::WKBArray;
 pub use builder::WKBBuilder;
 pub use capacity::WKBCapacity;
+#[cfg(feature = "wkb_compression")]
+pub use compressed::{CompressedWKBArray, DEFAULT_BLOCK_SIZE};
 
 mod array;
 mod builder;
 mod capacity;
+#[cfg(feature = "wkb_compression")]
+mod compressed;
@@ -1,5 +1,7 @@
 use crate::array::*;
 use crate::chunked_array::*;
+use crate::datatypes::GeoDataType;
+use crate::trait_::GeometryArrayAccessor;
 
 /// Helpers for downcasting a [`GeometryArrayTrait`] to a concrete implementation.
 pub trait AsGeometryArray {
@@ -538,3 +540,33 @@ impl AsChunkedGeometryArray for &dyn ChunkedGeometryArrayTrait {
         self.as_any().downcast_ref::<ChunkedRectArray>()
     }
 }
+
+/// The geometry at index `i` of an array, or `None` if it's null.
+pub(crate) fn geometry_at(array: &dyn GeometryArrayTrait, i: usize) -> Option<geo::Geometry> {
+    macro_rules! impl_get {
+        ($cast_func:ident) => {
+            array.$cast_func().get_as_geo(i).map(geo::Geometry::from)
+        };
+    }
+
+    match array.data_type() {
+        GeoDataType::Point(_) => impl_get!(as_point),
+        GeoDataType::LineString(_) => impl_get!(as_line_string),
+        GeoDataType::LargeLineString(_) => impl_get!(as_large_line_string),
+        GeoDataType::Polygon(_) => impl_get!(as_polygon),
+        GeoDataType::LargePolygon(_) => impl_get!(as_large_polygon),
+        GeoDataType::MultiPoint(_) => impl_get!(as_multi_point),
+        GeoDataType::LargeMultiPoint(_) => impl_get!(as_large_multi_point),
+        GeoDataType::MultiLineString(_) => impl_get!(as_multi_line_string),
+        GeoDataType::LargeMultiLineString(_) => impl_get!(as_large_multi_line_string),
+        GeoDataType::MultiPolygon(_) => impl_get!(as_multi_polygon),
+        GeoDataType::LargeMultiPolygon(_) => impl_get!(as_large_multi_polygon),
+        GeoDataType::Mixed(_) => impl_get!(as_mixed),
+        GeoDataType::LargeMixed(_) => impl_get!(as_large_mixed),
+        GeoDataType::GeometryCollection(_) => impl_get!(as_geometry_collection),
+        GeoDataType::LargeGeometryCollection(_) => impl_get!(as_large_geometry_collection),
+        GeoDataType::Rect => impl_get!(as_rect),
+        GeoDataType::WKB => impl_get!(as_wkb),
+        GeoDataType::LargeWKB => impl_get!(as_large_wkb),
+    }
+}
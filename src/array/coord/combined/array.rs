@@ -1,12 +1,13 @@
 use std::sync::Arc;
 
+use crate::array::metadata::ArrayMetadata;
 use crate::array::{
     CoordType, InterleavedCoordBuffer, InterleavedCoordBufferBuilder, SeparatedCoordBuffer,
     SeparatedCoordBufferBuilder,
 };
 use crate::error::GeoArrowError;
 use crate::scalar::Coord;
-use crate::trait_::{GeometryArrayAccessor, GeometryArraySelfMethods, IntoArrow};
+use crate::trait_::{BufferSizes, GeometryArrayAccessor, GeometryArraySelfMethods, IntoArrow};
 use crate::GeometryArrayTrait;
 use arrow_array::{Array, FixedSizeListArray, StructArray};
 use arrow_buffer::NullBuffer;
@@ -41,6 +42,26 @@ impl CoordBuffer {
         let geo_coord: geo::Coord = self.value(i).into();
         geo_coord.y
     }
+
+    /// Map each coordinate to a new buffer of the same coordinate type.
+    pub(crate) fn map_xy(&self, map_op: impl Fn(f64, f64) -> (f64, f64)) -> CoordBuffer {
+        match self {
+            CoordBuffer::Interleaved(c) => CoordBuffer::Interleaved(c.map_xy(map_op)),
+            CoordBuffer::Separated(c) => CoordBuffer::Separated(c.map_xy(map_op)),
+        }
+    }
+
+    /// Map each coordinate in place.
+    ///
+    /// The existing buffer allocations are reused when they aren't shared with another array.
+    /// Otherwise, the coordinates are copied before they're mapped, and other arrays sharing the
+    /// buffers are unaffected.
+    pub(crate) fn map_xy_mut(&mut self, map_op: impl Fn(f64, f64) -> (f64, f64)) {
+        match self {
+            CoordBuffer::Interleaved(c) => c.map_xy_mut(map_op),
+            CoordBuffer::Separated(c) => c.map_xy_mut(map_op),
+        }
+    }
 }
 
 impl GeometryArrayTrait for CoordBuffer {
@@ -71,6 +92,13 @@ impl GeometryArrayTrait for CoordBuffer {
         panic!()
     }
 
+    fn buffer_sizes(&self) -> BufferSizes {
+        match self {
+            CoordBuffer::Interleaved(c) => c.buffer_sizes(),
+            CoordBuffer::Separated(c) => c.buffer_sizes(),
+        }
+    }
+
     fn into_array_ref(self) -> Arc<dyn Array> {
         self.into_arrow()
     }
@@ -134,6 +162,10 @@ impl GeometryArraySelfMethods for CoordBuffer {
         }
     }
 
+    fn with_metadata(self, _metadata: Arc<ArrayMetadata>) -> Self {
+        self
+    }
+
     fn slice(&self, offset: usize, length: usize) -> Self {
         match self {
             CoordBuffer::Interleaved(c) => CoordBuffer::Interleaved(c.slice(offset, length)),
@@ -174,6 +206,22 @@ impl IntoArrow for CoordBuffer {
     }
 }
 
+impl TryFrom<&FixedSizeListArray> for CoordBuffer {
+    type Error = GeoArrowError;
+
+    fn try_from(value: &FixedSizeListArray) -> Result<Self, Self::Error> {
+        Ok(CoordBuffer::Interleaved(value.try_into()?))
+    }
+}
+
+impl TryFrom<&StructArray> for CoordBuffer {
+    type Error = GeoArrowError;
+
+    fn try_from(value: &StructArray) -> Result<Self, Self::Error> {
+        Ok(CoordBuffer::Separated(value.try_into()?))
+    }
+}
+
 impl TryFrom<&dyn Array> for CoordBuffer {
     type Error = GeoArrowError;
 
@@ -181,11 +229,11 @@ impl TryFrom<&dyn Array> for CoordBuffer {
         match value.data_type() {
             DataType::Struct(_) => {
                 let downcasted = value.as_any().downcast_ref::<StructArray>().unwrap();
-                Ok(CoordBuffer::Separated(downcasted.try_into()?))
+                downcasted.try_into()
             }
             DataType::FixedSizeList(_, _) => {
                 let downcasted = value.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
-                Ok(CoordBuffer::Interleaved(downcasted.try_into()?))
+                downcasted.try_into()
             }
             _ => Err(GeoArrowError::General(format!(
                 "Unexpected type: {:?}",
@@ -1,11 +1,13 @@
 use core::panic;
 use std::sync::Arc;
 
+use crate::array::metadata::ArrayMetadata;
+use crate::array::util::take_scalar_buffer;
 use crate::array::{CoordType, InterleavedCoordBufferBuilder};
 use crate::error::{GeoArrowError, Result};
 use crate::geo_traits::CoordTrait;
 use crate::scalar::InterleavedCoord;
-use crate::trait_::{GeometryArrayAccessor, GeometryArraySelfMethods, IntoArrow};
+use crate::trait_::{BufferSizes, GeometryArrayAccessor, GeometryArraySelfMethods, IntoArrow};
 use crate::GeometryArrayTrait;
 use arrow_array::{Array, FixedSizeListArray, Float64Array};
 use arrow_buffer::{Buffer, NullBuffer, ScalarBuffer};
@@ -52,6 +54,28 @@ impl InterleavedCoordBuffer {
         Float64Array::new(self.coords.clone(), None)
     }
 
+    /// Map each coordinate to a new buffer.
+    pub(crate) fn map_xy(&self, map_op: impl Fn(f64, f64) -> (f64, f64)) -> Self {
+        let mut coords = Vec::with_capacity(self.coords.len());
+        for xy in self.coords.chunks_exact(2) {
+            let (x, y) = map_op(xy[0], xy[1]);
+            coords.push(x);
+            coords.push(y);
+        }
+        Self {
+            coords: coords.into(),
+        }
+    }
+
+    /// Map each coordinate in place, reusing the buffer allocation when it isn't shared.
+    pub(crate) fn map_xy_mut(&mut self, map_op: impl Fn(f64, f64) -> (f64, f64)) {
+        let mut coords = take_scalar_buffer(&mut self.coords);
+        for xy in coords.chunks_exact_mut(2) {
+            (xy[0], xy[1]) = map_op(xy[0], xy[1]);
+        }
+        self.coords = coords.into();
+    }
+
     pub fn values_field(&self) -> Field {
         Field::new("xy", DataType::Float64, false)
     }
@@ -82,6 +106,13 @@ impl GeometryArrayTrait for InterleavedCoordBuffer {
         panic!()
     }
 
+    fn buffer_sizes(&self) -> BufferSizes {
+        BufferSizes {
+            coords: self.coords.inner().len(),
+            ..Default::default()
+        }
+    }
+
     fn into_array_ref(self) -> Arc<dyn Array> {
         Arc::new(self.into_arrow())
     }
@@ -120,6 +151,10 @@ impl GeometryArraySelfMethods for InterleavedCoordBuffer {
         panic!("into_coord_type only implemented on CoordBuffer");
     }
 
+    fn with_metadata(self, _metadata: Arc<ArrayMetadata>) -> Self {
+        self
+    }
+
     fn slice(&self, offset: usize, length: usize) -> Self {
         assert!(
             offset + length <= self.len(),
@@ -167,21 +202,45 @@ impl From<InterleavedCoordBuffer> for FixedSizeListArray {
     }
 }
 
+/// Zero-copy conversion from a `FixedSizeList<Float64>[2]` array.
+///
+/// The coordinate values buffer is shared with the input array, not copied. The validity of the
+/// list array itself is not part of the coordinate buffer.
 impl TryFrom<&FixedSizeListArray> for InterleavedCoordBuffer {
     type Error = GeoArrowError;
 
     fn try_from(value: &FixedSizeListArray) -> std::result::Result<Self, Self::Error> {
         if value.value_length() != 2 {
-            return Err(GeoArrowError::General(
-                "Expected this FixedSizeListArray to have size 2".to_string(),
-            ));
+            return Err(GeoArrowError::General(format!(
+                "Expected interleaved coordinates to have a list size of 2 (only XY coordinates \
+                 are supported), got {}",
+                value.value_length()
+            )));
         }
 
         let coord_array_values = value
             .values()
             .as_any()
             .downcast_ref::<Float64Array>()
-            .unwrap();
+            .ok_or_else(|| {
+                GeoArrowError::General(format!(
+                    "Expected interleaved coordinate values to have type Float64, got {}",
+                    value.values().data_type()
+                ))
+            })?;
+
+        // Child values under a null list slot are ignored
+        if let Some(child_nulls) = coord_array_values.nulls() {
+            let has_null_coord = (0..value.len()).any(|i| {
+                value.is_valid(i) && (child_nulls.is_null(2 * i) || child_nulls.is_null(2 * i + 1))
+            });
+            if has_null_coord {
+                return Err(GeoArrowError::General(
+                    "Interleaved coordinate values must not be null for non-null coordinates"
+                        .to_string(),
+                ));
+            }
+        }
 
         Ok(InterleavedCoordBuffer::new(
             coord_array_values.values().clone(),
@@ -5,11 +5,13 @@ use arrow_array::{Array, Float64Array, StructArray};
 use arrow_buffer::{NullBuffer, ScalarBuffer};
 use arrow_schema::{DataType, Field};
 
+use crate::array::metadata::ArrayMetadata;
+use crate::array::util::take_scalar_buffer;
 use crate::array::{CoordType, SeparatedCoordBufferBuilder};
 use crate::error::{GeoArrowError, Result};
 use crate::geo_traits::CoordTrait;
 use crate::scalar::SeparatedCoord;
-use crate::trait_::{GeometryArrayAccessor, GeometryArraySelfMethods, IntoArrow};
+use crate::trait_::{BufferSizes, GeometryArrayAccessor, GeometryArraySelfMethods, IntoArrow};
 use crate::GeometryArrayTrait;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -49,6 +51,31 @@ impl SeparatedCoordBuffer {
         Ok(Self { x, y })
     }
 
+    /// Map each coordinate to new buffers.
+    pub(crate) fn map_xy(&self, map_op: impl Fn(f64, f64) -> (f64, f64)) -> Self {
+        let (x, y) = self
+            .x
+            .iter()
+            .zip(self.y.iter())
+            .map(|(x, y)| map_op(*x, *y))
+            .unzip::<_, _, Vec<_>, Vec<_>>();
+        Self {
+            x: x.into(),
+            y: y.into(),
+        }
+    }
+
+    /// Map each coordinate in place, reusing the buffer allocations when they aren't shared.
+    pub(crate) fn map_xy_mut(&mut self, map_op: impl Fn(f64, f64) -> (f64, f64)) {
+        let mut x = take_scalar_buffer(&mut self.x);
+        let mut y = take_scalar_buffer(&mut self.y);
+        for (x, y) in x.iter_mut().zip(y.iter_mut()) {
+            (*x, *y) = map_op(*x, *y);
+        }
+        self.x = x.into();
+        self.y = y.into();
+    }
+
     pub fn values_array(&self) -> Vec<Arc<dyn Array>> {
         vec![
             Arc::new(Float64Array::new(self.x.clone(), None)),
@@ -105,6 +132,13 @@ impl GeometryArrayTrait for SeparatedCoordBuffer {
         panic!()
     }
 
+    fn buffer_sizes(&self) -> BufferSizes {
+        BufferSizes {
+            coords: self.x.inner().len() + self.y.inner().len(),
+            ..Default::default()
+        }
+    }
+
     fn len(&self) -> usize {
         self.x.len()
     }
@@ -127,6 +161,10 @@ impl GeometryArraySelfMethods for SeparatedCoordBuffer {
         panic!("into_coord_type only implemented on CoordBuffer");
     }
 
+    fn with_metadata(self, _metadata: Arc<ArrayMetadata>) -> Self {
+        self
+    }
+
     fn slice(&self, offset: usize, length: usize) -> Self {
         assert!(
             offset + length <= self.len(),
@@ -171,25 +209,63 @@ impl From<SeparatedCoordBuffer> for StructArray {
     }
 }
 
+/// Zero-copy conversion from a `Struct<x: Float64, y: Float64>` array.
+///
+/// The x and y buffers are shared with the input array, not copied. The validity of the struct
+/// array itself is not part of the coordinate buffer.
 impl TryFrom<&StructArray> for SeparatedCoordBuffer {
     type Error = GeoArrowError;
 
     fn try_from(value: &StructArray) -> Result<Self> {
-        let arrays = value.columns();
-
-        if !arrays.len() == 2 {
-            return Err(GeoArrowError::General(
-                "Expected two child arrays of this StructArray.".to_string(),
-            ));
+        let fields = value.fields();
+        if fields.len() != 2 {
+            return Err(GeoArrowError::General(format!(
+                "Expected separated coordinates to have 2 fields (only XY coordinates are \
+                 supported), got {}",
+                fields.len()
+            )));
         }
 
-        let x_array_values = arrays[0].as_any().downcast_ref::<Float64Array>().unwrap();
-        let y_array_values = arrays[1].as_any().downcast_ref::<Float64Array>().unwrap();
+        let mut buffers = Vec::with_capacity(2);
+        for (i, (field, expected_name)) in fields.iter().zip(["x", "y"]).enumerate() {
+            if field.name() != expected_name {
+                return Err(GeoArrowError::General(format!(
+                    "Expected field {} of separated coordinates to be named \"{}\", got \"{}\"",
+                    i,
+                    expected_name,
+                    field.name()
+                )));
+            }
+
+            let array = value
+                .column(i)
+                .as_any()
+                .downcast_ref::<Float64Array>()
+                .ok_or_else(|| {
+                    GeoArrowError::General(format!(
+                        "Expected field \"{}\" of separated coordinates to have type Float64, got {}",
+                        expected_name,
+                        field.data_type()
+                    ))
+                })?;
+
+            // Child values under a null struct slot are ignored
+            if let Some(child_nulls) = array.nulls() {
+                if (0..value.len()).any(|j| value.is_valid(j) && child_nulls.is_null(j)) {
+                    return Err(GeoArrowError::General(format!(
+                        "Field \"{}\" of separated coordinates must not be null for non-null \
+                         coordinates",
+                        expected_name
+                    )));
+                }
+            }
+
+            buffers.push(array.values().clone());
+        }
 
-        Ok(SeparatedCoordBuffer::new(
-            x_array_values.values().clone(),
-            y_array_values.values().clone(),
-        ))
+        let y = buffers.pop().unwrap();
+        let x = buffers.pop().unwrap();
+        Ok(SeparatedCoordBuffer::new(x, y))
     }
 }
 
@@ -8,6 +8,7 @@ use arrow_schema::{DataType, Field};
 
 use crate::algorithm::native::type_id::TypeIds;
 // use crate::algorithm::native::type_id::TypeIds;
+use crate::array::metadata::ArrayMetadata;
 use crate::array::{
     CoordType, LineStringArray, MultiLineStringArray, MultiPointArray, MultiPolygonArray,
     PointArray, PolygonArray, RectArray, WKBArray,
@@ -15,7 +16,7 @@ use crate::array::{
 use crate::datatypes::GeoDataType;
 use crate::error::GeoArrowError;
 use crate::scalar::Geometry;
-use crate::trait_::{GeometryArrayAccessor, GeometryArraySelfMethods, IntoArrow};
+use crate::trait_::{BufferSizes, GeometryArrayAccessor, GeometryArraySelfMethods, IntoArrow};
 use crate::GeometryArrayTrait;
 
 /// A GeometryArray is an enum over the various underlying _zero copy_ GeoArrow array types.
@@ -89,6 +90,18 @@ impl<O: OffsetSizeTrait> GeometryArrayTrait for GeometryArray<O> {
         todo!()
     }
 
+    fn buffer_sizes(&self) -> BufferSizes {
+        match self {
+            GeometryArray::Point(arr) => arr.buffer_sizes(),
+            GeometryArray::LineString(arr) => arr.buffer_sizes(),
+            GeometryArray::Polygon(arr) => arr.buffer_sizes(),
+            GeometryArray::MultiPoint(arr) => arr.buffer_sizes(),
+            GeometryArray::MultiLineString(arr) => arr.buffer_sizes(),
+            GeometryArray::MultiPolygon(arr) => arr.buffer_sizes(),
+            GeometryArray::Rect(arr) => arr.buffer_sizes(),
+        }
+    }
+
     fn extension_name(&self) -> &str {
         match self {
             GeometryArray::Point(arr) => arr.extension_name(),
@@ -209,6 +222,26 @@ impl<O: OffsetSizeTrait> GeometryArraySelfMethods for GeometryArray<O> {
         }
     }
 
+    fn with_metadata(self, metadata: Arc<ArrayMetadata>) -> Self {
+        match self {
+            GeometryArray::Point(arr) => GeometryArray::Point(arr.with_metadata(metadata)),
+            GeometryArray::LineString(arr) => {
+                GeometryArray::LineString(arr.with_metadata(metadata))
+            }
+            GeometryArray::Polygon(arr) => GeometryArray::Polygon(arr.with_metadata(metadata)),
+            GeometryArray::MultiPoint(arr) => {
+                GeometryArray::MultiPoint(arr.with_metadata(metadata))
+            }
+            GeometryArray::MultiLineString(arr) => {
+                GeometryArray::MultiLineString(arr.with_metadata(metadata))
+            }
+            GeometryArray::MultiPolygon(arr) => {
+                GeometryArray::MultiPolygon(arr.with_metadata(metadata))
+            }
+            GeometryArray::Rect(arr) => GeometryArray::Rect(arr.with_metadata(metadata)),
+        }
+    }
+
     /// Slices the [`GeometryArray`] in place
     /// # Implementation
     /// This operation is `O(1)` over `len`, as it amounts to increase two ref counts
@@ -299,6 +332,7 @@ impl TryFrom<(&Field, &dyn Array)> for GeometryArray<i32> {
                 "geoarrow.multipoint" => Ok(GeometryArray::MultiPoint(array.try_into()?)),
                 "geoarrow.multilinestring" => Ok(GeometryArray::MultiLineString(array.try_into()?)),
                 "geoarrow.multipolygon" => Ok(GeometryArray::MultiPolygon(array.try_into()?)),
+                "geoarrow.box" => Ok(GeometryArray::Rect(array.try_into()?)),
                 // TODO: create a top-level API that parses any named geoarrow array?
                 // "geoarrow.wkb" => Ok(GeometryArray::WKB(array.try_into()?)),
                 _ => Err(GeoArrowError::General(format!(
@@ -330,6 +364,7 @@ impl TryFrom<(&Field, &dyn Array)> for GeometryArray<i64> {
                 "geoarrow.multipoint" => Ok(GeometryArray::MultiPoint(array.try_into()?)),
                 "geoarrow.multilinestring" => Ok(GeometryArray::MultiLineString(array.try_into()?)),
                 "geoarrow.multipolygon" => Ok(GeometryArray::MultiPolygon(array.try_into()?)),
+                "geoarrow.box" => Ok(GeometryArray::Rect(array.try_into()?)),
                 // TODO: create a top-level API that parses any named geoarrow array?
                 // "geoarrow.wkb" => Ok(GeometryArray::WKB(array.try_into()?)),
                 _ => Err(GeoArrowError::General(format!(
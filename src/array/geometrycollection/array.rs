@@ -8,13 +8,15 @@ use arrow_schema::{DataType, Field};
 use crate::algorithm::native::eq::offset_buffer_eq;
 use crate::array::geometrycollection::{GeometryCollectionBuilder, GeometryCollectionCapacity};
 use crate::array::metadata::ArrayMetadata;
-use crate::array::util::{offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32};
+use crate::array::util::{
+    offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32, offsets_size, validity_size,
+};
 use crate::array::{CoordBuffer, CoordType, MixedGeometryArray, WKBArray};
 use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
 use crate::geo_traits::GeometryCollectionTrait;
 use crate::scalar::GeometryCollection;
-use crate::trait_::{GeometryArrayAccessor, GeometryArraySelfMethods, IntoArrow};
+use crate::trait_::{BufferSizes, GeometryArrayAccessor, GeometryArraySelfMethods, IntoArrow};
 use crate::GeometryArrayTrait;
 
 /// An immutable array of GeometryCollection geometries using GeoArrow's in-memory representation.
@@ -26,7 +28,7 @@ pub struct GeometryCollectionArray<O: OffsetSizeTrait> {
     // Always GeoDataType::GeometryCollection or GeoDataType::LargeGeometryCollection
     data_type: GeoDataType,
 
-    metadata: Arc<ArrayMetadata>,
+    pub(crate) metadata: Arc<ArrayMetadata>,
 
     pub(crate) array: MixedGeometryArray<O>,
 
@@ -141,6 +143,16 @@ impl<O: OffsetSizeTrait> GeometryArrayTrait for GeometryCollectionArray<O> {
         self.metadata.clone()
     }
 
+    fn buffer_sizes(&self) -> BufferSizes {
+        let (offsets, _) = offsets_size(&self.geom_offsets, 0..self.len());
+        let own = BufferSizes {
+            offsets,
+            validity: validity_size(self.validity()),
+            ..Default::default()
+        };
+        own + self.array.buffer_sizes()
+    }
+
     /// Returns the number of geometries in this array
     #[inline]
     fn len(&self) -> usize {
@@ -168,6 +180,11 @@ impl<O: OffsetSizeTrait> GeometryArraySelfMethods for GeometryCollectionArray<O>
         todo!()
     }
 
+    fn with_metadata(mut self, metadata: Arc<ArrayMetadata>) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
     /// Slices this [`GeometryCollectionArray`] in place.
     ///
     /// # Implementation
@@ -324,8 +324,9 @@ impl<'a, O: OffsetSizeTrait> GeometryCollectionBuilder<O> {
         Ok(())
     }
 
+    /// Add a new null value to the end of this array.
     #[inline]
-    pub(crate) fn push_null(&mut self) {
+    pub fn push_null(&mut self) {
         self.geom_offsets.extend_constant(1);
         self.validity.append(false);
     }
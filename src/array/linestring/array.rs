@@ -4,13 +4,16 @@ use std::sync::Arc;
 use crate::algorithm::native::eq::offset_buffer_eq;
 use crate::array::linestring::LineStringCapacity;
 use crate::array::metadata::ArrayMetadata;
-use crate::array::util::{offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32, OffsetBufferUtils};
+use crate::array::util::{
+    offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32, offsets_size, validity_size,
+    OffsetBufferUtils,
+};
 use crate::array::{CoordBuffer, CoordType, MultiPointArray, WKBArray};
 use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
 use crate::geo_traits::LineStringTrait;
 use crate::scalar::LineString;
-use crate::trait_::{GeometryArrayAccessor, GeometryArraySelfMethods, IntoArrow};
+use crate::trait_::{BufferSizes, GeometryArrayAccessor, GeometryArraySelfMethods, IntoArrow};
 use crate::util::{owned_slice_offsets, owned_slice_validity};
 use crate::GeometryArrayTrait;
 use arrow_array::{Array, ArrayRef, GenericListArray, LargeListArray, ListArray, OffsetSizeTrait};
@@ -193,6 +196,16 @@ impl<O: OffsetSizeTrait> GeometryArrayTrait for LineStringArray<O> {
         self.metadata.clone()
     }
 
+    fn buffer_sizes(&self) -> BufferSizes {
+        let (offsets, coord_range) = offsets_size(&self.geom_offsets, 0..self.len());
+        BufferSizes {
+            coords: coord_range.len() * 2 * 8,
+            offsets,
+            validity: validity_size(self.validity()),
+            data: 0,
+        }
+    }
+
     /// Returns the number of geometries in this array
     #[inline]
     fn len(&self) -> usize {
@@ -225,6 +238,11 @@ impl<O: OffsetSizeTrait> GeometryArraySelfMethods for LineStringArray<O> {
         )
     }
 
+    fn with_metadata(mut self, metadata: Arc<ArrayMetadata>) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
     /// Slices this [`LineStringArray`] in place.
     ///
     /// # Implementation
@@ -370,6 +388,14 @@ impl<O: OffsetSizeTrait, G: LineStringTrait<T = f64>> From<Vec<Option<G>>> for L
     }
 }
 
+impl<O: OffsetSizeTrait, G: LineStringTrait<T = f64>> FromIterator<Option<G>>
+    for LineStringArray<O>
+{
+    fn from_iter<I: IntoIterator<Item = Option<G>>>(iter: I) -> Self {
+        LineStringBuilder::from_iter(iter).into()
+    }
+}
+
 impl<O: OffsetSizeTrait, G: LineStringTrait<T = f64>> From<&[G]> for LineStringArray<O> {
     fn from(other: &[G]) -> Self {
         let mut_arr: LineStringBuilder<O> = other.into();
@@ -2,8 +2,11 @@
 //!
 //! This metadata is [defined by the GeoArrow specification](https://geoarrow.org/extension-types).
 
+use arrow_schema::Field;
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{json, Map, Value};
+
+use crate::error::{GeoArrowError, Result};
 
 /// If present, instructs consumers that edges follow a spherical path rather than a planar one. If
 /// this value is omitted, edges will be interpreted as planar.
@@ -26,9 +29,185 @@ pub struct ArrayMetadata {
     /// to the wording in the GeoPackage WKB binary encoding: axis order is always (longitude,
     /// latitude) and (easting, northing) regardless of the the axis order encoded in the CRS
     /// specification.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub crs: Option<Value>,
 
     /// If present, instructs consumers that edges follow a spherical path rather than a planar
     /// one. If this value is omitted, edges will be interpreted as planar.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub edges: Option<Edges>,
 }
+
+impl ArrayMetadata {
+    /// Parse the JSON of an `ARROW:extension:metadata` field metadata value, tolerating the
+    /// variants written by other producers such as geoarrow-c, GeoPandas and GDAL.
+    ///
+    /// - An empty string or object has no CRS and planar edges.
+    /// - A CRS of `null` is treated as missing.
+    /// - A CRS that is a string holding a JSON object, as written by geoarrow-c before PROJJSON
+    ///   objects were allowed, is parsed to that object.
+    /// - A CRS that is an `authority:code` string such as `"EPSG:4326"` or `"OGC:CRS84"` is
+    ///   converted to a PROJJSON `id` object. Other strings, such as WKT2, are kept as is.
+    /// - `"edges": "planar"` is the same as omitting `edges`.
+    /// - Other keys, such as `crs_type`, are ignored.
+    ///
+    /// Unknown edge types are an error, as they can't be interpreted as planar.
+    pub fn from_extension_metadata(extension_metadata: &str) -> Result<Self> {
+        if extension_metadata.trim().is_empty() {
+            return Ok(Default::default());
+        }
+
+        let value: Value = serde_json::from_str(extension_metadata)?;
+        let mut object = match value {
+            Value::Object(object) => object,
+            Value::Null => Map::new(),
+            value => {
+                return Err(GeoArrowError::General(format!(
+                    "Expected a JSON object as extension metadata, got {}",
+                    value
+                )))
+            }
+        };
+
+        let crs = object.remove("crs").and_then(normalize_crs);
+        let edges = match object.remove("edges") {
+            None | Some(Value::Null) => None,
+            Some(Value::String(edges)) if edges == "planar" => None,
+            Some(Value::String(edges)) if edges == "spherical" => Some(Edges::Spherical),
+            Some(edges) => {
+                return Err(GeoArrowError::NotYetImplemented(format!(
+                    "Unsupported edges {}",
+                    edges
+                )))
+            }
+        };
+        Ok(Self { crs, edges })
+    }
+}
+
+impl TryFrom<&Field> for ArrayMetadata {
+    type Error = GeoArrowError;
+
+    /// Parse the extension metadata of a field with
+    /// [`from_extension_metadata`][Self::from_extension_metadata]. Fields without extension
+    /// metadata have the default metadata.
+    fn try_from(field: &Field) -> Result<Self> {
+        match field.metadata().get("ARROW:extension:metadata") {
+            Some(extension_metadata) => Self::from_extension_metadata(extension_metadata),
+            None => Ok(Default::default()),
+        }
+    }
+}
+
+fn normalize_crs(crs: Value) -> Option<Value> {
+    let crs_string = match crs {
+        Value::Null => return None,
+        Value::String(crs_string) => crs_string,
+        crs => return Some(crs),
+    };
+
+    if let Ok(Value::Object(crs)) = serde_json::from_str(&crs_string) {
+        return Some(Value::Object(crs));
+    }
+
+    let is_authority_code = |authority: &str, code: &str| {
+        !authority.is_empty()
+            && !code.is_empty()
+            && authority.chars().all(|c| c.is_ascii_alphanumeric())
+            && code.chars().all(|c| c.is_ascii_alphanumeric())
+    };
+    match crs_string.split_once(':') {
+        Some((authority, code)) if is_authority_code(authority, code) => {
+            let code = match code.parse::<u64>() {
+                Ok(code) => json!(code),
+                Err(_) => json!(code),
+            };
+            Some(json!({"id": {"authority": authority, "code": code}}))
+        }
+        _ => Some(Value::String(crs_string)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// A shortened PROJJSON description of EPSG:4326.
+    const PROJJSON: &str = r#"{"$schema":"https://proj.org/schemas/v0.7/projjson.schema.json","type":"GeographicCRS","name":"WGS 84","datum_ensemble":{"name":"World Geodetic System 1984 ensemble"},"id":{"authority":"EPSG","code":4326}}"#;
+
+    fn normalize_all(blobs: &[String]) -> Vec<ArrayMetadata> {
+        blobs
+            .iter()
+            .map(|blob| ArrayMetadata::from_extension_metadata(blob).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn projjson_variants() {
+        let escaped = serde_json::to_string(PROJJSON).unwrap();
+        let blobs = [
+            // geoarrow-c 0.1, with the PROJJSON as a string
+            format!(r#"{{"crs":{}}}"#, escaped),
+            // geoarrow-c 0.2, with a crs_type
+            format!(r#"{{"crs":{},"crs_type":"projjson"}}"#, PROJJSON),
+            // GeoPandas, as written by pyogrio
+            format!(r#"{{"crs": {}}}"#, PROJJSON),
+            // GDAL, with planar edges spelled out
+            format!(r#"{{"crs":{},"edges":"planar"}}"#, PROJJSON),
+        ];
+        let expected = ArrayMetadata {
+            crs: Some(serde_json::from_str(PROJJSON).unwrap()),
+            edges: None,
+        };
+        for metadata in normalize_all(&blobs) {
+            assert_eq!(metadata, expected);
+        }
+    }
+
+    #[test]
+    fn missing_crs_variants() {
+        let blobs = [
+            "".to_string(),
+            "{}".to_string(),
+            r#"{"crs":null}"#.to_string(),
+            r#"{"edges":"planar"}"#.to_string(),
+            r#"{"crs":null,"edges":null}"#.to_string(),
+        ];
+        for metadata in normalize_all(&blobs) {
+            assert_eq!(metadata, ArrayMetadata::default());
+        }
+        assert_eq!(
+            serde_json::to_string(&ArrayMetadata::default()).unwrap(),
+            "{}"
+        );
+    }
+
+    #[test]
+    fn authority_code_variants() {
+        let blobs = [
+            r#"{"crs":"EPSG:4326","crs_type":"authority_code"}"#.to_string(),
+            r#"{"crs":{"id":{"authority":"EPSG","code":4326}}}"#.to_string(),
+        ];
+        let expected = ArrayMetadata {
+            crs: Some(json!({"id": {"authority": "EPSG", "code": 4326}})),
+            edges: None,
+        };
+        for metadata in normalize_all(&blobs) {
+            assert_eq!(metadata, expected);
+        }
+
+        let metadata = ArrayMetadata::from_extension_metadata(r#"{"crs":"OGC:CRS84"}"#).unwrap();
+        assert_eq!(
+            metadata.crs,
+            Some(json!({"id": {"authority": "OGC", "code": "CRS84"}}))
+        );
+    }
+
+    #[test]
+    fn edges() {
+        let metadata = ArrayMetadata::from_extension_metadata(r#"{"edges":"spherical"}"#).unwrap();
+        assert_eq!(metadata.edges, Some(Edges::Spherical));
+        assert!(ArrayMetadata::from_extension_metadata(r#"{"edges":"vincenty"}"#).is_err());
+        assert!(ArrayMetadata::from_extension_metadata("[]").is_err());
+    }
+}
@@ -16,7 +16,7 @@ use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
 use crate::geo_traits::GeometryTrait;
 use crate::scalar::Geometry;
-use crate::trait_::{GeometryArrayAccessor, GeometryArraySelfMethods, IntoArrow};
+use crate::trait_::{BufferSizes, GeometryArrayAccessor, GeometryArraySelfMethods, IntoArrow};
 use crate::GeometryArrayTrait;
 
 /// # Invariants
@@ -371,6 +371,24 @@ impl<O: OffsetSizeTrait> GeometryArrayTrait for MixedGeometryArray<O> {
         self.metadata.clone()
     }
 
+    fn buffer_sizes(&self) -> BufferSizes {
+        let type_ids_and_offsets = BufferSizes {
+            offsets: self.type_ids.inner().len() + self.offsets.inner().len(),
+            ..Default::default()
+        };
+        let children = [
+            self.points.as_ref().map(|arr| arr.buffer_sizes()),
+            self.line_strings.as_ref().map(|arr| arr.buffer_sizes()),
+            self.polygons.as_ref().map(|arr| arr.buffer_sizes()),
+            self.multi_points.as_ref().map(|arr| arr.buffer_sizes()),
+            self.multi_line_strings
+                .as_ref()
+                .map(|arr| arr.buffer_sizes()),
+            self.multi_polygons.as_ref().map(|arr| arr.buffer_sizes()),
+        ];
+        type_ids_and_offsets + children.into_iter().flatten().sum()
+    }
+
     /// Returns the number of geometries in this array
     #[inline]
     fn len(&self) -> usize {
@@ -384,6 +402,36 @@ impl<O: OffsetSizeTrait> GeometryArrayTrait for MixedGeometryArray<O> {
         None
     }
 
+    /// Union arrays have no validity buffer, so nulls are read from the child arrays.
+    fn logical_nulls(&self) -> Option<NullBuffer> {
+        if self.null_count() == 0 {
+            return None;
+        }
+        let validity = (0..self.len())
+            .map(|i| !self.is_null(i))
+            .collect::<Vec<_>>();
+        Some(NullBuffer::from(validity))
+    }
+
+    fn null_count(&self) -> usize {
+        (0..self.len()).filter(|i| self.is_null(*i)).count()
+    }
+
+    fn is_null(&self, i: usize) -> bool {
+        let offset = self.offsets[i] as usize;
+        match self.map[self.type_ids[i] as usize].unwrap() {
+            GeometryType::Point => self.points.as_ref().unwrap().is_null(offset),
+            GeometryType::LineString => self.line_strings.as_ref().unwrap().is_null(offset),
+            GeometryType::Polygon => self.polygons.as_ref().unwrap().is_null(offset),
+            GeometryType::MultiPoint => self.multi_points.as_ref().unwrap().is_null(offset),
+            GeometryType::MultiLineString => {
+                self.multi_line_strings.as_ref().unwrap().is_null(offset)
+            }
+            GeometryType::MultiPolygon => self.multi_polygons.as_ref().unwrap().is_null(offset),
+            GeometryType::GeometryCollection => false,
+        }
+    }
+
     fn as_ref(&self) -> &dyn GeometryArrayTrait {
         self
     }
@@ -398,6 +446,11 @@ impl<O: OffsetSizeTrait> GeometryArraySelfMethods for MixedGeometryArray<O> {
         todo!();
     }
 
+    fn with_metadata(mut self, metadata: Arc<ArrayMetadata>) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
     /// Slices this [`MixedGeometryArray`] in place.
     ///
     /// # Implementation
@@ -904,4 +957,23 @@ mod test {
         assert_eq!(round_trip_arr.value_as_geo(0), geoms[0]);
         assert_eq!(round_trip_arr.value_as_geo(1), geoms[1]);
     }
+
+    #[test]
+    fn push_null() {
+        let geoms = vec![
+            Some(geo::Geometry::LineString(linestring::ls0())),
+            None,
+            Some(geo::Geometry::Point(point::p0())),
+        ];
+        let arr: MixedGeometryArray<i32> =
+            MixedGeometryBuilder::from_nullable_geometries(&geoms, None, Default::default(), false)
+                .unwrap()
+                .finish();
+
+        assert_eq!(arr.len(), 3);
+        assert_eq!(arr.null_count(), 1);
+        assert!(arr.is_null(1));
+        assert_eq!(arr.iter_geo().collect::<Vec<_>>(), geoms);
+        assert_eq!(arr.logical_nulls().unwrap().null_count(), 1);
+    }
 }
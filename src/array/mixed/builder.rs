@@ -416,9 +416,14 @@ impl<'a, O: OffsetSizeTrait> MixedGeometryBuilder<O> {
         Ok(())
     }
 
+    /// Add a new null value to the end of this array.
+    ///
+    /// A union array has no validity buffer of its own, so as in other Arrow implementations the
+    /// null is stored as a null slot of a child array, here the PointBuilder.
     #[inline]
     pub fn push_null(&mut self) {
-        todo!("push null geometry")
+        self.add_point_type();
+        self.points.push_null();
     }
 
     pub fn extend_from_iter(
@@ -1,6 +1,9 @@
 //! Implementations of immutable GeoArrow arrays plus builders to more easily create arrays.
 
+#[cfg(feature = "wkb_compression")]
+pub use binary::CompressedWKBArray;
 pub use binary::{WKBArray, WKBBuilder, WKBCapacity};
+pub(crate) use cast::geometry_at;
 pub use cast::{AsChunkedGeometryArray, AsGeometryArray};
 pub use coord::{
     CoordBuffer, CoordBufferBuilder, CoordType, InterleavedCoordBuffer,
@@ -38,118 +41,51 @@ pub(crate) mod util;
 use std::sync::Arc;
 
 use arrow_array::Array;
-use arrow_schema::{DataType, Field};
+use arrow_schema::Field;
 
-use crate::error::{GeoArrowError, Result};
+use crate::array::metadata::ArrayMetadata;
+use crate::datatypes::GeoDataType;
+use crate::error::Result;
+use crate::trait_::GeometryArraySelfMethods;
 use crate::GeometryArrayTrait;
 
 /// Convert an Arrow [Array] to a geoarrow GeometryArray
+///
+/// The geometry type is read from the extension name of `field`, and its extension metadata is
+/// normalized with [`ArrayMetadata::from_extension_metadata`] and attached to the array. Fields
+/// without an extension name must be unambiguously typed, as with [`GeoDataType`]'s
+/// `TryFrom<&Field>`.
+///
+/// [`ArrayMetadata::from_extension_metadata`]: metadata::ArrayMetadata::from_extension_metadata
 pub fn from_arrow_array(array: &dyn Array, field: &Field) -> Result<Arc<dyn GeometryArrayTrait>> {
-    if let Some(extension_name) = field.metadata().get("ARROW:extension:name") {
-        let geom_arr: Arc<dyn GeometryArrayTrait> = match extension_name.as_str() {
-            "geoarrow.point" => Arc::new(PointArray::try_from(array).unwrap()),
-            "geoarrow.linestring" => match field.data_type() {
-                DataType::List(_) => Arc::new(LineStringArray::<i32>::try_from(array).unwrap()),
-                DataType::LargeList(_) => {
-                    Arc::new(LineStringArray::<i64>::try_from(array).unwrap())
-                }
-                _ => panic!("Unexpected data type"),
-            },
-            "geoarrow.polygon" => match field.data_type() {
-                DataType::List(_) => Arc::new(PolygonArray::<i32>::try_from(array).unwrap()),
-                DataType::LargeList(_) => Arc::new(PolygonArray::<i64>::try_from(array).unwrap()),
-                _ => panic!("Unexpected data type"),
-            },
-            "geoarrow.multipoint" => match field.data_type() {
-                DataType::List(_) => Arc::new(MultiPointArray::<i32>::try_from(array).unwrap()),
-                DataType::LargeList(_) => {
-                    Arc::new(MultiPointArray::<i64>::try_from(array).unwrap())
-                }
-                _ => panic!("Unexpected data type"),
-            },
-            "geoarrow.multilinestring" => match field.data_type() {
-                DataType::List(_) => {
-                    Arc::new(MultiLineStringArray::<i32>::try_from(array).unwrap())
-                }
-                DataType::LargeList(_) => {
-                    Arc::new(MultiLineStringArray::<i64>::try_from(array).unwrap())
-                }
-                _ => panic!("Unexpected data type"),
-            },
-            "geoarrow.multipolygon" => match field.data_type() {
-                DataType::List(_) => Arc::new(MultiPolygonArray::<i32>::try_from(array).unwrap()),
-                DataType::LargeList(_) => {
-                    Arc::new(MultiPolygonArray::<i64>::try_from(array).unwrap())
-                }
-                _ => panic!("Unexpected data type"),
-            },
-            "geoarrow.geometry" => match field.data_type() {
-                DataType::Union(fields, _) => {
-                    let mut large_offsets: Vec<bool> = vec![];
-
-                    fields.iter().for_each(|(_type_ids, field)| {
-                        match field.data_type() {
-                            DataType::List(_) => large_offsets.push(false),
-                            DataType::LargeList(_) => large_offsets.push(true),
-                            _ => (),
-                        };
-                    });
-
-                    if large_offsets.is_empty() {
-                        // Only contains a point array, we can cast to i32
-                        Arc::new(MixedGeometryArray::<i32>::try_from(array).unwrap())
-                    } else if large_offsets.iter().all(|x| *x) {
-                        // All large offsets, cast to i64
-                        Arc::new(MixedGeometryArray::<i64>::try_from(array).unwrap())
-                    } else if large_offsets.iter().all(|x| !x) {
-                        // All small offsets, cast to i32
-                        Arc::new(MixedGeometryArray::<i32>::try_from(array).unwrap())
-                    } else {
-                        panic!("Mix of offset types");
-                    }
-                }
-                _ => panic!("Unexpected data type"),
-            },
-            "geoarrow.geometrycollection" => match field.data_type() {
-                DataType::List(_) => {
-                    Arc::new(GeometryCollectionArray::<i32>::try_from(array).unwrap())
-                }
-                DataType::LargeList(_) => {
-                    Arc::new(GeometryCollectionArray::<i64>::try_from(array).unwrap())
-                }
-                _ => panic!("Unexpected data type"),
-            },
-            "geoarrow.wkb" | "ogc.wkb" => match field.data_type() {
-                DataType::Binary => Arc::new(WKBArray::<i32>::try_from(array).unwrap()),
-                DataType::LargeBinary => Arc::new(WKBArray::<i64>::try_from(array).unwrap()),
-                _ => panic!("Unexpected data type"),
-            },
-            _ => {
-                return Err(GeoArrowError::General(format!(
-                    "Unknown geoarrow type {}",
-                    extension_name
-                )))
-            }
+    macro_rules! impl_downcast {
+        ($array:ty) => {
+            Arc::new(<$array>::try_from(array)?.with_metadata(metadata))
         };
-        Ok(geom_arr)
-    } else {
-        // TODO: better error here, and document that arrays without geoarrow extension
-        // metadata should use TryFrom for a specific geometry type directly, instead of using
-        // GeometryArray
-        match field.data_type() {
-            DataType::Binary => {
-                Ok(Arc::new(WKBArray::<i32>::try_from(array).unwrap()))
-            }
-            DataType::LargeBinary => {
-                Ok(Arc::new(WKBArray::<i64>::try_from(array).unwrap()))
-            }
-            DataType::Struct(_) => {
-                Ok(Arc::new(PointArray::try_from(array).unwrap()))
-            }
-            DataType::FixedSizeList(_, _) => {
-                Ok(Arc::new(PointArray::try_from(array).unwrap()))
-            }
-            _ => Err(GeoArrowError::General("Only Binary, LargeBinary, FixedSizeList, and Struct arrays are unambigously typed and can be used without extension metadata.".to_string()))
-        }
     }
+    use GeoDataType::*;
+
+    let geo_data_type = GeoDataType::try_from(field)?;
+    let metadata = Arc::new(ArrayMetadata::try_from(field)?);
+    let geom_arr: Arc<dyn GeometryArrayTrait> = match geo_data_type {
+        Point(_) => impl_downcast!(PointArray),
+        LineString(_) => impl_downcast!(LineStringArray<i32>),
+        LargeLineString(_) => impl_downcast!(LineStringArray<i64>),
+        Polygon(_) => impl_downcast!(PolygonArray<i32>),
+        LargePolygon(_) => impl_downcast!(PolygonArray<i64>),
+        MultiPoint(_) => impl_downcast!(MultiPointArray<i32>),
+        LargeMultiPoint(_) => impl_downcast!(MultiPointArray<i64>),
+        MultiLineString(_) => impl_downcast!(MultiLineStringArray<i32>),
+        LargeMultiLineString(_) => impl_downcast!(MultiLineStringArray<i64>),
+        MultiPolygon(_) => impl_downcast!(MultiPolygonArray<i32>),
+        LargeMultiPolygon(_) => impl_downcast!(MultiPolygonArray<i64>),
+        Mixed(_) => impl_downcast!(MixedGeometryArray<i32>),
+        LargeMixed(_) => impl_downcast!(MixedGeometryArray<i64>),
+        GeometryCollection(_) => impl_downcast!(GeometryCollectionArray<i32>),
+        LargeGeometryCollection(_) => impl_downcast!(GeometryCollectionArray<i64>),
+        WKB => impl_downcast!(WKBArray<i32>),
+        LargeWKB => impl_downcast!(WKBArray<i64>),
+        Rect => impl_downcast!(RectArray),
+    };
+    Ok(geom_arr)
 }
@@ -5,13 +5,16 @@ use crate::algorithm::native::eq::offset_buffer_eq;
 use crate::array::metadata::ArrayMetadata;
 use crate::array::multilinestring::MultiLineStringCapacity;
 use crate::array::offset_builder::OffsetsBuilder;
-use crate::array::util::{offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32, OffsetBufferUtils};
+use crate::array::util::{
+    offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32, offsets_size, validity_size,
+    OffsetBufferUtils,
+};
 use crate::array::{CoordBuffer, CoordType, LineStringArray, PolygonArray, WKBArray};
 use crate::datatypes::GeoDataType;
 use crate::error::GeoArrowError;
 use crate::geo_traits::MultiLineStringTrait;
 use crate::scalar::MultiLineString;
-use crate::trait_::{GeometryArrayAccessor, GeometryArraySelfMethods, IntoArrow};
+use crate::trait_::{BufferSizes, GeometryArrayAccessor, GeometryArraySelfMethods, IntoArrow};
 use crate::util::{owned_slice_offsets, owned_slice_validity};
 use crate::GeometryArrayTrait;
 use arrow_array::{Array, GenericListArray, LargeListArray, ListArray, OffsetSizeTrait};
@@ -230,6 +233,17 @@ impl<O: OffsetSizeTrait> GeometryArrayTrait for MultiLineStringArray<O> {
         self.metadata.clone()
     }
 
+    fn buffer_sizes(&self) -> BufferSizes {
+        let (geom_offsets, ring_range) = offsets_size(&self.geom_offsets, 0..self.len());
+        let (ring_offsets, coord_range) = offsets_size(&self.ring_offsets, ring_range);
+        BufferSizes {
+            coords: coord_range.len() * 2 * 8,
+            offsets: geom_offsets + ring_offsets,
+            validity: validity_size(self.validity()),
+            data: 0,
+        }
+    }
+
     /// Returns the number of geometries in this array
     #[inline]
     fn len(&self) -> usize {
@@ -269,6 +283,11 @@ impl<O: OffsetSizeTrait> GeometryArraySelfMethods for MultiLineStringArray<O> {
         )
     }
 
+    fn with_metadata(mut self, metadata: Arc<ArrayMetadata>) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
     /// Slices this [`MultiLineStringArray`] in place.
     /// # Panic
     /// This function panics iff `offset + length > self.len()`.
@@ -435,6 +454,14 @@ impl<O: OffsetSizeTrait, G: MultiLineStringTrait<T = f64>> From<Vec<Option<G>>>
     }
 }
 
+impl<O: OffsetSizeTrait, G: MultiLineStringTrait<T = f64>> FromIterator<Option<G>>
+    for MultiLineStringArray<O>
+{
+    fn from_iter<I: IntoIterator<Item = Option<G>>>(iter: I) -> Self {
+        MultiLineStringBuilder::from_iter(iter).into()
+    }
+}
+
 impl<O: OffsetSizeTrait, G: MultiLineStringTrait<T = f64>> From<&[G]> for MultiLineStringArray<O> {
     fn from(other: &[G]) -> Self {
         let mut_arr: MultiLineStringBuilder<O> = other.into();
@@ -494,7 +521,7 @@ impl<O: OffsetSizeTrait> TryFrom<LineStringArray<O>> for MultiLineStringArray<O>
 
         // Create offsets that are all of length 1
         let mut geom_offsets = OffsetsBuilder::with_capacity(geom_length);
-        for _ in 0..coords.len() {
+        for _ in 0..geom_length {
             geom_offsets.try_push_usize(1)?;
         }
 
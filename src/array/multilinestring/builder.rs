@@ -322,8 +322,9 @@ impl<O: OffsetSizeTrait> MultiLineStringBuilder<O> {
         Ok(())
     }
 
+    /// Add a new null value to the end of this array.
     #[inline]
-    pub(crate) fn push_null(&mut self) {
+    pub fn push_null(&mut self) {
         // NOTE! Only the geom_offsets array needs to get extended, because the next geometry will
         // point to the same ring array location
         self.geom_offsets.extend_constant(1);
@@ -358,6 +359,26 @@ impl<O: OffsetSizeTrait> MultiLineStringBuilder<O> {
         array
     }
 
+    /// Create this builder from an iterator of nullable MultiLineStrings, reserving `capacity` up
+    /// front.
+    ///
+    /// Unlike [`from_nullable_multi_line_strings`][Self::from_nullable_multi_line_strings], the
+    /// geometries don't need to be collected first, so `capacity` is only an estimate when they are
+    /// computed on the fly.
+    pub fn from_iter_with_capacity(
+        geoms: impl IntoIterator<Item = Option<impl MultiLineStringTrait<T = f64>>>,
+        capacity: MultiLineStringCapacity,
+        coord_type: CoordType,
+        metadata: Arc<ArrayMetadata>,
+    ) -> Self {
+        let mut builder = Self::with_capacity_and_options(capacity, coord_type, metadata);
+        geoms
+            .into_iter()
+            .try_for_each(|maybe_g| builder.push_multi_line_string(maybe_g.as_ref()))
+            .unwrap();
+        builder
+    }
+
     pub(crate) fn from_wkb<W: OffsetSizeTrait>(
         wkb_objects: &[Option<WKB<'_, W>>],
         coord_type: Option<CoordType>,
@@ -474,6 +495,18 @@ impl<O: OffsetSizeTrait, G: MultiLineStringTrait<T = f64>> From<Vec<Option<G>>>
     }
 }
 
+impl<O: OffsetSizeTrait, G: MultiLineStringTrait<T = f64>> FromIterator<Option<G>>
+    for MultiLineStringBuilder<O>
+{
+    fn from_iter<I: IntoIterator<Item = Option<G>>>(iter: I) -> Self {
+        let mut builder = Self::new();
+        iter.into_iter()
+            .try_for_each(|maybe_g| builder.push_multi_line_string(maybe_g.as_ref()))
+            .unwrap();
+        builder
+    }
+}
+
 impl<O: OffsetSizeTrait, G: MultiLineStringTrait<T = f64>> From<bumpalo::collections::Vec<'_, G>>
     for MultiLineStringBuilder<O>
 {
@@ -6,13 +6,16 @@ use crate::algorithm::native::eq::offset_buffer_eq;
 use crate::array::metadata::ArrayMetadata;
 use crate::array::multipoint::MultiPointCapacity;
 use crate::array::offset_builder::OffsetsBuilder;
-use crate::array::util::{offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32, OffsetBufferUtils};
+use crate::array::util::{
+    offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32, offsets_size, validity_size,
+    OffsetBufferUtils,
+};
 use crate::array::{CoordBuffer, CoordType, LineStringArray, PointArray, WKBArray};
 use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
 use crate::geo_traits::MultiPointTrait;
 use crate::scalar::MultiPoint;
-use crate::trait_::{GeometryArrayAccessor, GeometryArraySelfMethods, IntoArrow};
+use crate::trait_::{BufferSizes, GeometryArrayAccessor, GeometryArraySelfMethods, IntoArrow};
 use crate::util::{owned_slice_offsets, owned_slice_validity};
 use crate::GeometryArrayTrait;
 use arrow_array::{Array, GenericListArray, LargeListArray, ListArray, OffsetSizeTrait};
@@ -193,6 +196,16 @@ impl<O: OffsetSizeTrait> GeometryArrayTrait for MultiPointArray<O> {
         self.metadata.clone()
     }
 
+    fn buffer_sizes(&self) -> BufferSizes {
+        let (offsets, coord_range) = offsets_size(&self.geom_offsets, 0..self.len());
+        BufferSizes {
+            coords: coord_range.len() * 2 * 8,
+            offsets,
+            validity: validity_size(self.validity()),
+            data: 0,
+        }
+    }
+
     /// Returns the number of geometries in this array
     #[inline]
     fn len(&self) -> usize {
@@ -225,6 +238,11 @@ impl<O: OffsetSizeTrait> GeometryArraySelfMethods for MultiPointArray<O> {
         )
     }
 
+    fn with_metadata(mut self, metadata: Arc<ArrayMetadata>) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
     /// Slices this [`MultiPointArray`] in place.
     /// # Implementation
     /// This operation is `O(1)` as it amounts to increase two ref counts.
@@ -370,6 +388,14 @@ impl<O: OffsetSizeTrait, G: MultiPointTrait<T = f64>> From<Vec<Option<G>>> for M
     }
 }
 
+impl<O: OffsetSizeTrait, G: MultiPointTrait<T = f64>> FromIterator<Option<G>>
+    for MultiPointArray<O>
+{
+    fn from_iter<I: IntoIterator<Item = Option<G>>>(iter: I) -> Self {
+        MultiPointBuilder::from_iter(iter).into()
+    }
+}
+
 impl<O: OffsetSizeTrait, G: MultiPointTrait<T = f64>> From<&[G]> for MultiPointArray<O> {
     fn from(other: &[G]) -> Self {
         let mut_arr: MultiPointBuilder<O> = other.into();
@@ -428,7 +454,7 @@ impl<O: OffsetSizeTrait> TryFrom<PointArray> for MultiPointArray<O> {
 
         // Create offsets that are all of length 1
         let mut geom_offsets = OffsetsBuilder::with_capacity(geom_length);
-        for _ in 0..coords.len() {
+        for _ in 0..geom_length {
             geom_offsets.try_push_usize(1)?;
         }
 
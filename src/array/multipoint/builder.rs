@@ -266,8 +266,9 @@ impl<O: OffsetSizeTrait> MultiPointBuilder<O> {
         Ok(())
     }
 
+    /// Add a new null value to the end of this array.
     #[inline]
-    pub(crate) fn push_null(&mut self) {
+    pub fn push_null(&mut self) {
         self.geom_offsets.extend_constant(1);
         self.validity.append(false);
     }
@@ -300,6 +301,25 @@ impl<O: OffsetSizeTrait> MultiPointBuilder<O> {
         array
     }
 
+    /// Create this builder from an iterator of nullable MultiPoints, reserving `capacity` up front.
+    ///
+    /// Unlike [`from_nullable_multi_points`][Self::from_nullable_multi_points], the geometries
+    /// don't need to be collected first, so `capacity` is only an estimate when they are computed
+    /// on the fly.
+    pub fn from_iter_with_capacity(
+        geoms: impl IntoIterator<Item = Option<impl MultiPointTrait<T = f64>>>,
+        capacity: MultiPointCapacity,
+        coord_type: CoordType,
+        metadata: Arc<ArrayMetadata>,
+    ) -> Self {
+        let mut builder = Self::with_capacity_and_options(capacity, coord_type, metadata);
+        geoms
+            .into_iter()
+            .try_for_each(|maybe_g| builder.push_multi_point(maybe_g.as_ref()))
+            .unwrap();
+        builder
+    }
+
     pub(crate) fn from_wkb<W: OffsetSizeTrait>(
         wkb_objects: &[Option<WKB<'_, W>>],
         coord_type: Option<CoordType>,
@@ -420,6 +440,18 @@ impl<O: OffsetSizeTrait, G: MultiPointTrait<T = f64>> From<Vec<Option<G>>>
     }
 }
 
+impl<O: OffsetSizeTrait, G: MultiPointTrait<T = f64>> FromIterator<Option<G>>
+    for MultiPointBuilder<O>
+{
+    fn from_iter<I: IntoIterator<Item = Option<G>>>(iter: I) -> Self {
+        let mut builder = Self::new();
+        iter.into_iter()
+            .try_for_each(|maybe_g| builder.push_multi_point(maybe_g.as_ref()))
+            .unwrap();
+        builder
+    }
+}
+
 impl<O: OffsetSizeTrait, G: MultiPointTrait<T = f64>> From<bumpalo::collections::Vec<'_, G>>
     for MultiPointBuilder<O>
 {
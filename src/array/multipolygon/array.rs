@@ -5,13 +5,16 @@ use crate::algorithm::native::eq::offset_buffer_eq;
 use crate::array::metadata::ArrayMetadata;
 use crate::array::multipolygon::MultiPolygonCapacity;
 use crate::array::offset_builder::OffsetsBuilder;
-use crate::array::util::{offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32, OffsetBufferUtils};
+use crate::array::util::{
+    offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32, offsets_size, validity_size,
+    OffsetBufferUtils,
+};
 use crate::array::{CoordBuffer, CoordType, PolygonArray, WKBArray};
 use crate::datatypes::GeoDataType;
 use crate::error::GeoArrowError;
 use crate::geo_traits::MultiPolygonTrait;
 use crate::scalar::MultiPolygon;
-use crate::trait_::{GeometryArrayAccessor, GeometryArraySelfMethods, IntoArrow};
+use crate::trait_::{BufferSizes, GeometryArrayAccessor, GeometryArraySelfMethods, IntoArrow};
 use crate::util::{owned_slice_offsets, owned_slice_validity};
 use crate::GeometryArrayTrait;
 use arrow_array::{Array, GenericListArray, LargeListArray, ListArray, OffsetSizeTrait};
@@ -268,6 +271,18 @@ impl<O: OffsetSizeTrait> GeometryArrayTrait for MultiPolygonArray<O> {
         self.metadata.clone()
     }
 
+    fn buffer_sizes(&self) -> BufferSizes {
+        let (geom_offsets, polygon_range) = offsets_size(&self.geom_offsets, 0..self.len());
+        let (polygon_offsets, ring_range) = offsets_size(&self.polygon_offsets, polygon_range);
+        let (ring_offsets, coord_range) = offsets_size(&self.ring_offsets, ring_range);
+        BufferSizes {
+            coords: coord_range.len() * 2 * 8,
+            offsets: geom_offsets + polygon_offsets + ring_offsets,
+            validity: validity_size(self.validity()),
+            data: 0,
+        }
+    }
+
     /// Returns the number of geometries in this array
     #[inline]
     fn len(&self) -> usize {
@@ -309,6 +324,11 @@ impl<O: OffsetSizeTrait> GeometryArraySelfMethods for MultiPolygonArray<O> {
         )
     }
 
+    fn with_metadata(mut self, metadata: Arc<ArrayMetadata>) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
     /// Slices this [`MultiPolygonArray`] in place.
     /// # Panic
     /// This function panics iff `offset + length > self.len()`.
@@ -507,6 +527,14 @@ impl<O: OffsetSizeTrait, G: MultiPolygonTrait<T = f64>> From<Vec<Option<G>>>
     }
 }
 
+impl<O: OffsetSizeTrait, G: MultiPolygonTrait<T = f64>> FromIterator<Option<G>>
+    for MultiPolygonArray<O>
+{
+    fn from_iter<I: IntoIterator<Item = Option<G>>>(iter: I) -> Self {
+        MultiPolygonBuilder::from_iter(iter).into()
+    }
+}
+
 impl<O: OffsetSizeTrait, G: MultiPolygonTrait<T = f64>> From<&[G]> for MultiPolygonArray<O> {
     fn from(other: &[G]) -> Self {
         let mut_arr: MultiPolygonBuilder<O> = other.into();
@@ -554,7 +582,7 @@ impl<O: OffsetSizeTrait> TryFrom<PolygonArray<O>> for MultiPolygonArray<O> {
 
         // Create offsets that are all of length 1
         let mut geom_offsets = OffsetsBuilder::with_capacity(geom_length);
-        for _ in 0..coords.len() {
+        for _ in 0..geom_length {
             geom_offsets.try_push_usize(1)?;
         }
 
@@ -653,6 +681,23 @@ mod test {
         assert_eq!(arr.get_as_geo(2), None);
     }
 
+    #[test]
+    fn from_iter_matches_from_vec() {
+        let geoms = vec![Some(mp0()), None, Some(mp1())];
+        let from_vec: MultiPolygonArray<i32> = geoms.clone().into();
+        let from_iter: MultiPolygonArray<i32> = geoms.clone().into_iter().collect();
+        assert_eq!(from_iter, from_vec);
+
+        let with_capacity = MultiPolygonBuilder::<i32>::from_iter_with_capacity(
+            geoms,
+            from_vec.buffer_lengths(),
+            CoordType::Interleaved,
+            Default::default(),
+        )
+        .finish();
+        assert_eq!(with_capacity, from_vec);
+    }
+
     #[test]
     fn slice() {
         let arr: MultiPolygonArray<i64> = vec![mp0(), mp1()].as_slice().into();
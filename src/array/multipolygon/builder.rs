@@ -382,8 +382,9 @@ impl<O: OffsetSizeTrait> MultiPolygonBuilder<O> {
         self.validity.append(true);
     }
 
+    /// Add a new null value to the end of this array.
     #[inline]
-    pub(crate) fn push_null(&mut self) {
+    pub fn push_null(&mut self) {
         // NOTE! Only the geom_offsets array needs to get extended, because the next geometry will
         // point to the same polygon array location
         // Note that we don't use self.try_push_geom_offset because that sets validity to true
@@ -419,6 +420,26 @@ impl<O: OffsetSizeTrait> MultiPolygonBuilder<O> {
         array
     }
 
+    /// Create this builder from an iterator of nullable MultiPolygons, reserving `capacity` up
+    /// front.
+    ///
+    /// Unlike [`from_nullable_multi_polygons`][Self::from_nullable_multi_polygons], the geometries
+    /// don't need to be collected first, so `capacity` is only an estimate when they are computed
+    /// on the fly.
+    pub fn from_iter_with_capacity(
+        geoms: impl IntoIterator<Item = Option<impl MultiPolygonTrait<T = f64>>>,
+        capacity: MultiPolygonCapacity,
+        coord_type: CoordType,
+        metadata: Arc<ArrayMetadata>,
+    ) -> Self {
+        let mut builder = Self::with_capacity_and_options(capacity, coord_type, metadata);
+        geoms
+            .into_iter()
+            .try_for_each(|maybe_g| builder.push_multi_polygon(maybe_g.as_ref()))
+            .unwrap();
+        builder
+    }
+
     pub(crate) fn from_wkb<W: OffsetSizeTrait>(
         wkb_objects: &[Option<WKB<'_, W>>],
         coord_type: Option<CoordType>,
@@ -540,6 +561,18 @@ impl<O: OffsetSizeTrait, G: MultiPolygonTrait<T = f64>> From<Vec<Option<G>>>
     }
 }
 
+impl<O: OffsetSizeTrait, G: MultiPolygonTrait<T = f64>> FromIterator<Option<G>>
+    for MultiPolygonBuilder<O>
+{
+    fn from_iter<I: IntoIterator<Item = Option<G>>>(iter: I) -> Self {
+        let mut builder = Self::new();
+        iter.into_iter()
+            .try_for_each(|maybe_g| builder.push_multi_polygon(maybe_g.as_ref()))
+            .unwrap();
+        builder
+    }
+}
+
 impl<O: OffsetSizeTrait, G: MultiPolygonTrait<T = f64>> From<bumpalo::collections::Vec<'_, G>>
     for MultiPolygonBuilder<O>
 {
@@ -3,6 +3,7 @@ use std::sync::Arc;
 
 use crate::algorithm::native::eq::coord_eq_allow_nan;
 use crate::array::metadata::ArrayMetadata;
+use crate::array::util::validity_size;
 use crate::array::{
     CoordBuffer, CoordType, InterleavedCoordBuffer, PointBuilder, SeparatedCoordBuffer, WKBArray,
 };
@@ -10,10 +11,12 @@ use crate::datatypes::GeoDataType;
 use crate::error::GeoArrowError;
 use crate::geo_traits::PointTrait;
 use crate::scalar::Point;
-use crate::trait_::{GeometryArrayAccessor, GeometryArraySelfMethods, IntoArrow};
+use crate::trait_::{BufferSizes, GeometryArrayAccessor, GeometryArraySelfMethods, IntoArrow};
 use crate::util::owned_slice_validity;
 use crate::GeometryArrayTrait;
-use arrow_array::{Array, ArrayRef, FixedSizeListArray, OffsetSizeTrait, StructArray};
+use arrow_array::{
+    Array, ArrayRef, FixedSizeListArray, Float64Array, OffsetSizeTrait, StructArray,
+};
 
 use arrow_buffer::NullBuffer;
 use arrow_schema::{DataType, Field};
@@ -93,6 +96,63 @@ impl PointArray {
         (self.coords, self.validity)
     }
 
+    /// Create a new PointArray from arrays of x and y values.
+    ///
+    /// The coordinates are stored separated and reuse the value buffers of `x` and `y` without
+    /// copying. Use [`into_coord_type`][GeometryArraySelfMethods::into_coord_type] to interleave
+    /// them. A point is null where either its x or its y value is null.
+    ///
+    /// # Errors
+    ///
+    /// - if `x` and `y` have different lengths
+    pub fn try_from_xy(x: &Float64Array, y: &Float64Array) -> Result<Self, GeoArrowError> {
+        if x.len() != y.len() {
+            return Err(GeoArrowError::General(format!(
+                "x and y must have the same length, got {} and {}",
+                x.len(),
+                y.len()
+            )));
+        }
+        let coords = SeparatedCoordBuffer::try_new(x.values().clone(), y.values().clone())?;
+        let validity = NullBuffer::union(x.nulls(), y.nulls());
+        Self::try_new(coords.into(), validity, Default::default())
+    }
+
+    /// The x values of the points, with the validity of this array.
+    ///
+    /// This is zero-copy for separated coordinates.
+    pub fn x(&self) -> Float64Array {
+        let values = match &self.coords {
+            CoordBuffer::Separated(c) => c.x.clone(),
+            CoordBuffer::Interleaved(c) => c
+                .coords
+                .iter()
+                .step_by(2)
+                .copied()
+                .collect::<Vec<_>>()
+                .into(),
+        };
+        Float64Array::new(values, self.validity.clone())
+    }
+
+    /// The y values of the points, with the validity of this array.
+    ///
+    /// This is zero-copy for separated coordinates.
+    pub fn y(&self) -> Float64Array {
+        let values = match &self.coords {
+            CoordBuffer::Separated(c) => c.y.clone(),
+            CoordBuffer::Interleaved(c) => c
+                .coords
+                .iter()
+                .skip(1)
+                .step_by(2)
+                .copied()
+                .collect::<Vec<_>>()
+                .into(),
+        };
+        Float64Array::new(values, self.validity.clone())
+    }
+
     /// The lengths of each buffer contained in this array.
     pub fn buffer_lengths(&self) -> usize {
         self.len()
@@ -155,6 +215,13 @@ impl GeometryArrayTrait for PointArray {
         self.metadata.clone()
     }
 
+    fn buffer_sizes(&self) -> BufferSizes {
+        BufferSizes {
+            validity: validity_size(self.validity()),
+            ..self.coords.buffer_sizes()
+        }
+    }
+
     /// Returns the number of geometries in this array
     #[inline]
     fn len(&self) -> usize {
@@ -186,6 +253,11 @@ impl GeometryArraySelfMethods for PointArray {
         )
     }
 
+    fn with_metadata(mut self, metadata: Arc<ArrayMetadata>) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
     /// Slices this [`PointArray`] in place.
     /// # Panic
     /// This function panics iff `offset + length > self.len()`.
@@ -248,6 +320,9 @@ impl IntoArrow for PointArray {
     }
 }
 
+/// Zero-copy conversion from an array of interleaved coordinates.
+///
+/// The coordinate buffer and validity are shared with the input array.
 impl TryFrom<&FixedSizeListArray> for PointArray {
     type Error = GeoArrowError;
 
@@ -262,6 +337,9 @@ impl TryFrom<&FixedSizeListArray> for PointArray {
     }
 }
 
+/// Zero-copy conversion from an array of separated coordinates.
+///
+/// The coordinate buffers and validity are shared with the input array.
 impl TryFrom<&StructArray> for PointArray {
     type Error = GeoArrowError;
 
@@ -289,8 +367,54 @@ impl TryFrom<&dyn Array> for PointArray {
                 let arr = value.as_any().downcast_ref::<StructArray>().unwrap();
                 arr.try_into()
             }
-            _ => Err(GeoArrowError::General(
-                "Invalid data type for PointArray".to_string(),
+            dt => Err(GeoArrowError::General(format!(
+                "Expected a FixedSizeList or Struct array for PointArray, got {}",
+                dt
+            ))),
+        }
+    }
+}
+
+/// Zero-copy conversion to a `FixedSizeList` array.
+///
+/// Returns an error if the coordinates are separated; use
+/// [`into_coord_type`][GeometryArraySelfMethods::into_coord_type] to convert them first.
+impl TryFrom<PointArray> for FixedSizeListArray {
+    type Error = GeoArrowError;
+
+    fn try_from(value: PointArray) -> Result<Self, Self::Error> {
+        match value.coords {
+            CoordBuffer::Interleaved(c) => Ok(FixedSizeListArray::new(
+                c.values_field().into(),
+                2,
+                Arc::new(c.values_array()),
+                value.validity,
+            )),
+            CoordBuffer::Separated(_) => Err(GeoArrowError::General(
+                "Cannot convert a PointArray with separated coordinates to a FixedSizeListArray"
+                    .to_string(),
+            )),
+        }
+    }
+}
+
+/// Zero-copy conversion to a `Struct` array.
+///
+/// Returns an error if the coordinates are interleaved; use
+/// [`into_coord_type`][GeometryArraySelfMethods::into_coord_type] to convert them first.
+impl TryFrom<PointArray> for StructArray {
+    type Error = GeoArrowError;
+
+    fn try_from(value: PointArray) -> Result<Self, Self::Error> {
+        match value.coords {
+            CoordBuffer::Separated(c) => Ok(StructArray::new(
+                c.values_field().into(),
+                c.values_array(),
+                value.validity,
+            )),
+            CoordBuffer::Interleaved(_) => Err(GeoArrowError::General(
+                "Cannot convert a PointArray with interleaved coordinates to a StructArray"
+                    .to_string(),
             )),
         }
     }
@@ -303,6 +427,12 @@ impl<G: PointTrait<T = f64>> From<Vec<Option<G>>> for PointArray {
     }
 }
 
+impl<G: PointTrait<T = f64>> FromIterator<Option<G>> for PointArray {
+    fn from_iter<I: IntoIterator<Item = Option<G>>>(iter: I) -> Self {
+        PointBuilder::from_iter(iter).into()
+    }
+}
+
 impl<G: PointTrait<T = f64>> From<&[G]> for PointArray {
     fn from(other: &[G]) -> Self {
         let mut_arr: PointBuilder = other.into();
@@ -406,6 +536,21 @@ mod test {
         assert_eq!(sliced.get_as_geo(0), Some(p1()));
     }
 
+    #[test]
+    fn sliced_buffer_sizes() {
+        let point_array: PointArray = vec![Some(p0()), None, Some(p1()), Some(p2())].into();
+        assert_eq!(point_array.buffer_sizes().coords, 4 * 2 * 8);
+        assert_eq!(point_array.buffer_sizes().validity, 1);
+
+        let sliced = point_array.slice(1, 2);
+        let sizes = sliced.buffer_sizes();
+        assert_eq!(sizes.coords, 2 * 2 * 8);
+        assert_eq!(sizes.offsets, 0);
+        assert_eq!(sizes.validity, 1);
+        assert_eq!(sizes.total(), 33);
+        assert_eq!(sliced.owned_slice(0, 2).buffer_sizes(), sizes);
+    }
+
     #[test]
     fn owned_slice() {
         let points: Vec<Point> = vec![p0(), p1(), p2()];
@@ -431,6 +576,25 @@ mod test {
         assert_eq!(geom_arr.get_as_geo(2), parsed_geom_arr.get_as_geo(2));
     }
 
+    #[test]
+    fn from_xy() {
+        let x = Float64Array::from(vec![Some(1.), Some(2.), None]);
+        let y = Float64Array::from(vec![Some(3.), None, Some(5.)]);
+        let arr = PointArray::try_from_xy(&x, &y).unwrap();
+        assert_eq!(arr.coord_type(), CoordType::Separated);
+        assert_eq!(arr.get_as_geo(0), Some(Point::new(1., 3.)));
+        assert_eq!(arr.null_count(), 2);
+        assert_eq!(arr.x().values().as_ptr(), x.values().as_ptr());
+        assert_eq!(arr.y().null_count(), 2);
+
+        let interleaved = arr.clone().into_coord_type(CoordType::Interleaved);
+        assert_eq!(interleaved.x(), arr.x());
+        assert_eq!(interleaved.y(), arr.y());
+        assert_eq!(interleaved.slice(1, 2).x(), arr.x().slice(1, 2));
+
+        assert!(PointArray::try_from_xy(&x, &y.slice(0, 2)).is_err());
+    }
+
     #[test]
     fn parse_wkb_geoarrow_separated_example() {
         let geom_arr = example_point_separated();
@@ -440,4 +604,123 @@ mod test {
 
         assert_eq!(geom_arr, parsed_geom_arr);
     }
+
+    #[test]
+    fn zero_copy_fixed_size_list() {
+        use arrow_array::Float64Array;
+
+        let values = Float64Array::from(vec![0., 1., 2., 3., 4., 5.]);
+        let values_ptr = values.values().as_ptr();
+        let field = Arc::new(Field::new("xy", DataType::Float64, false));
+        let list = FixedSizeListArray::new(field, 2, Arc::new(values), None);
+
+        let point_array = PointArray::try_from(&list).unwrap();
+        assert_eq!(point_array.len(), 3);
+        match point_array.coords() {
+            CoordBuffer::Interleaved(c) => assert_eq!(c.coords.as_ptr(), values_ptr),
+            CoordBuffer::Separated(_) => panic!("expected interleaved coords"),
+        }
+
+        let list = FixedSizeListArray::try_from(point_array).unwrap();
+        let values = list
+            .values()
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .unwrap();
+        assert_eq!(values.values().as_ptr(), values_ptr);
+    }
+
+    #[test]
+    fn zero_copy_struct() {
+        use arrow_array::Float64Array;
+
+        let x = Float64Array::from(vec![0., 1., 2.]);
+        let y = Float64Array::from(vec![3., 4., 5.]);
+        let (x_ptr, y_ptr) = (x.values().as_ptr(), y.values().as_ptr());
+        let fields = vec![
+            Field::new("x", DataType::Float64, false),
+            Field::new("y", DataType::Float64, false),
+        ];
+        let validity = NullBuffer::from(vec![true, false, true]);
+        let array = StructArray::new(
+            fields.into(),
+            vec![Arc::new(x), Arc::new(y)],
+            Some(validity),
+        );
+
+        let point_array = PointArray::try_from(&array).unwrap();
+        assert_eq!(point_array.null_count(), 1);
+        match point_array.coords() {
+            CoordBuffer::Separated(c) => {
+                assert_eq!(c.x.as_ptr(), x_ptr);
+                assert_eq!(c.y.as_ptr(), y_ptr);
+            }
+            CoordBuffer::Interleaved(_) => panic!("expected separated coords"),
+        }
+
+        assert!(FixedSizeListArray::try_from(point_array.clone()).is_err());
+        let array = StructArray::try_from(point_array).unwrap();
+        assert_eq!(array.null_count(), 1);
+        let x = array
+            .column(0)
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .unwrap();
+        assert_eq!(x.values().as_ptr(), x_ptr);
+    }
+
+    #[test]
+    fn invalid_coord_arrays() {
+        use arrow_array::{Float32Array, Float64Array};
+
+        let values = Float64Array::from(vec![0., 1., 2., 3., 4., 5.]);
+        let field = Arc::new(Field::new("xyz", DataType::Float64, false));
+        let list = FixedSizeListArray::new(field, 3, Arc::new(values), None);
+        let err = PointArray::try_from(&list).unwrap_err();
+        assert!(err.to_string().contains("list size of 2"));
+
+        let values = Float32Array::from(vec![0., 1., 2., 3.]);
+        let field = Arc::new(Field::new("xy", DataType::Float32, false));
+        let list = FixedSizeListArray::new(field, 2, Arc::new(values), None);
+        let err = PointArray::try_from(&list).unwrap_err();
+        assert!(err.to_string().contains("type Float64"));
+
+        let values = Float64Array::from(vec![Some(0.), None, Some(2.), Some(3.)]);
+        let field = Arc::new(Field::new("xy", DataType::Float64, true));
+        let list = FixedSizeListArray::new(field, 2, Arc::new(values), None);
+        let err = PointArray::try_from(&list).unwrap_err();
+        assert!(err.to_string().contains("must not be null"));
+
+        let fields = vec![
+            Field::new("y", DataType::Float64, false),
+            Field::new("x", DataType::Float64, false),
+        ];
+        let array = StructArray::new(
+            fields.into(),
+            vec![
+                Arc::new(Float64Array::from(vec![0.])),
+                Arc::new(Float64Array::from(vec![1.])),
+            ],
+            None,
+        );
+        let err = PointArray::try_from(&array).unwrap_err();
+        assert!(err.to_string().contains("to be named \"x\""));
+
+        let fields = vec![
+            Field::new("x", DataType::Float64, false),
+            Field::new("y", DataType::Float64, false),
+            Field::new("z", DataType::Float64, false),
+        ];
+        let array = StructArray::new(
+            fields.into(),
+            vec![
+                Arc::new(Float64Array::from(vec![0.])),
+                Arc::new(Float64Array::from(vec![1.])),
+                Arc::new(Float64Array::from(vec![2.])),
+            ],
+            None,
+        );
+        let err = PointArray::try_from(&array).unwrap_err();
+        assert!(err.to_string().contains("2 fields"));
+    }
 }
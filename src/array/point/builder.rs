@@ -60,6 +60,36 @@ impl PointBuilder {
         }
     }
 
+    /// Creates a new [`PointBuilder`] with the capacity needed to hold the points of `geoms`.
+    pub fn with_capacity_from_iter<'a>(
+        geoms: impl Iterator<Item = Option<&'a (impl PointTrait + 'a)>>,
+    ) -> Self {
+        Self::with_capacity_and_options_from_iter(geoms, Default::default(), Default::default())
+    }
+
+    /// Creates a new [`PointBuilder`] with the capacity needed to hold the points of `geoms`.
+    pub fn with_capacity_and_options_from_iter<'a>(
+        geoms: impl Iterator<Item = Option<&'a (impl PointTrait + 'a)>>,
+        coord_type: CoordType,
+        metadata: Arc<ArrayMetadata>,
+    ) -> Self {
+        Self::with_capacity_and_options(geoms.count(), coord_type, metadata)
+    }
+
+    pub fn reserve_from_iter<'a>(
+        &mut self,
+        geoms: impl Iterator<Item = Option<&'a (impl PointTrait + 'a)>>,
+    ) {
+        self.reserve(geoms.count())
+    }
+
+    pub fn reserve_exact_from_iter<'a>(
+        &mut self,
+        geoms: impl Iterator<Item = Option<&'a (impl PointTrait + 'a)>>,
+    ) {
+        self.reserve_exact(geoms.count())
+    }
+
     /// Reserves capacity for at least `additional` more points to be inserted
     /// in the given `Vec<T>`. The collection may reserve more space to
     /// speculatively avoid frequent reallocations. After calling `reserve`,
@@ -194,6 +224,23 @@ impl PointBuilder {
         mutable_array
     }
 
+    /// Create this builder from an iterator of nullable Points, reserving `capacity` up front.
+    ///
+    /// Unlike [`from_nullable_points`][Self::from_nullable_points], the geometries don't need to be
+    /// collected first, so `capacity` is only an estimate when they are computed on the fly.
+    pub fn from_iter_with_capacity(
+        geoms: impl IntoIterator<Item = Option<impl PointTrait<T = f64>>>,
+        capacity: usize,
+        coord_type: CoordType,
+        metadata: Arc<ArrayMetadata>,
+    ) -> Self {
+        let mut builder = Self::with_capacity_and_options(capacity, coord_type, metadata);
+        geoms
+            .into_iter()
+            .for_each(|maybe_g| builder.push_point(maybe_g.as_ref()));
+        builder
+    }
+
     pub(crate) fn from_wkb<O: OffsetSizeTrait>(
         wkb_objects: &[Option<WKB<'_, O>>],
         coord_type: Option<CoordType>,
@@ -305,6 +352,15 @@ impl<G: PointTrait<T = f64>> From<Vec<Option<G>>> for PointBuilder {
     }
 }
 
+impl<G: PointTrait<T = f64>> FromIterator<Option<G>> for PointBuilder {
+    fn from_iter<I: IntoIterator<Item = Option<G>>>(iter: I) -> Self {
+        let mut builder = Self::new();
+        iter.into_iter()
+            .for_each(|maybe_g| builder.push_point(maybe_g.as_ref()));
+        builder
+    }
+}
+
 impl<G: PointTrait<T = f64>> From<bumpalo::collections::Vec<'_, G>> for PointBuilder {
     fn from(geoms: bumpalo::collections::Vec<'_, G>) -> Self {
         PointBuilder::from_points(geoms.iter(), Default::default(), Default::default())
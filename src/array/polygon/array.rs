@@ -4,13 +4,16 @@ use std::sync::Arc;
 use crate::algorithm::native::eq::offset_buffer_eq;
 use crate::array::metadata::ArrayMetadata;
 use crate::array::polygon::PolygonCapacity;
-use crate::array::util::{offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32, OffsetBufferUtils};
+use crate::array::util::{
+    offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32, offsets_size, validity_size,
+    OffsetBufferUtils,
+};
 use crate::array::{CoordBuffer, CoordType, MultiLineStringArray, RectArray, WKBArray};
 use crate::datatypes::GeoDataType;
 use crate::error::GeoArrowError;
 use crate::geo_traits::PolygonTrait;
 use crate::scalar::Polygon;
-use crate::trait_::{GeometryArrayAccessor, GeometryArraySelfMethods, IntoArrow};
+use crate::trait_::{BufferSizes, GeometryArrayAccessor, GeometryArraySelfMethods, IntoArrow};
 use crate::util::{owned_slice_offsets, owned_slice_validity};
 use crate::GeometryArrayTrait;
 use arrow_array::{Array, OffsetSizeTrait};
@@ -231,6 +234,17 @@ impl<O: OffsetSizeTrait> GeometryArrayTrait for PolygonArray<O> {
         self.metadata.clone()
     }
 
+    fn buffer_sizes(&self) -> BufferSizes {
+        let (geom_offsets, ring_range) = offsets_size(&self.geom_offsets, 0..self.len());
+        let (ring_offsets, coord_range) = offsets_size(&self.ring_offsets, ring_range);
+        BufferSizes {
+            coords: coord_range.len() * 2 * 8,
+            offsets: geom_offsets + ring_offsets,
+            validity: validity_size(self.validity()),
+            data: 0,
+        }
+    }
+
     /// Returns the number of geometries in this array
     #[inline]
     fn len(&self) -> usize {
@@ -270,6 +284,11 @@ impl<O: OffsetSizeTrait> GeometryArraySelfMethods for PolygonArray<O> {
         )
     }
 
+    fn with_metadata(mut self, metadata: Arc<ArrayMetadata>) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
     /// Slices this [`PolygonArray`] in place.
     /// # Panic
     /// This function panics iff `offset + length > self.len()`.
@@ -433,6 +452,12 @@ impl<O: OffsetSizeTrait, G: PolygonTrait<T = f64>> From<Vec<Option<G>>> for Poly
     }
 }
 
+impl<O: OffsetSizeTrait, G: PolygonTrait<T = f64>> FromIterator<Option<G>> for PolygonArray<O> {
+    fn from_iter<I: IntoIterator<Item = Option<G>>>(iter: I) -> Self {
+        PolygonBuilder::from_iter(iter).into()
+    }
+}
+
 impl<O: OffsetSizeTrait, G: PolygonTrait<T = f64>> From<&[G]> for PolygonArray<O> {
     fn from(other: &[G]) -> Self {
         let mut_arr: PolygonBuilder<O> = other.into();
@@ -1,27 +1,31 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use arrow_array::{Array, FixedSizeListArray, Float64Array};
+use arrow_array::{Array, ArrayRef, FixedSizeListArray, Float64Array, StructArray};
 use arrow_buffer::{NullBuffer, ScalarBuffer};
-use arrow_schema::{DataType, Field};
+use arrow_schema::{DataType, Field, Fields};
 
 use crate::array::metadata::ArrayMetadata;
 use crate::array::rect::RectBuilder;
+use crate::array::util::validity_size;
 use crate::array::{CoordBuffer, CoordType};
 use crate::datatypes::GeoDataType;
+use crate::error::GeoArrowError;
 use crate::geo_traits::RectTrait;
 use crate::scalar::Rect;
-use crate::trait_::{GeometryArrayAccessor, GeometryArraySelfMethods, IntoArrow};
+use crate::trait_::{BufferSizes, GeometryArrayAccessor, GeometryArraySelfMethods, IntoArrow};
 use crate::util::owned_slice_validity;
 use crate::GeometryArrayTrait;
 
 /// An immutable array of Rect geometries.
 ///
-/// This is **not** an array type defined by the GeoArrow specification (as of spec version 0.1)
-/// but is included here for parity with georust/geo, and to save memory for the output of
+/// This is included for parity with georust/geo, and to save memory for the output of
 /// `bounds()`.
 ///
-/// Internally this is implemented as a FixedSizeList[4], laid out as minx, miny, maxx, maxy.
+/// In Arrow this is stored with the GeoArrow `box` encoding: a struct of four non-nullable
+/// `Float64` fields named `xmin`, `ymin`, `xmax` and `ymax`, with the extension name
+/// `geoarrow.box`. Internally the values are kept interleaved as minx, miny, maxx, maxy, so
+/// converting to and from Arrow copies them.
 #[derive(Debug, Clone, PartialEq)]
 pub struct RectArray {
     // Always GeoDataType::Rect
@@ -49,12 +53,18 @@ impl RectArray {
         }
     }
 
-    fn inner_field(&self) -> Arc<Field> {
-        Field::new("rect", DataType::Float64, false).into()
+    fn fields(&self) -> Fields {
+        vec![
+            Field::new("xmin", DataType::Float64, false),
+            Field::new("ymin", DataType::Float64, false),
+            Field::new("xmax", DataType::Float64, false),
+            Field::new("ymax", DataType::Float64, false),
+        ]
+        .into()
     }
 
     fn outer_type(&self) -> DataType {
-        DataType::FixedSizeList(self.inner_field(), 4)
+        DataType::Struct(self.fields())
     }
 }
 
@@ -85,7 +95,7 @@ impl GeometryArrayTrait for RectArray {
     }
 
     fn extension_name(&self) -> &str {
-        "geoarrow._rect"
+        "geoarrow.box"
     }
 
     fn into_array_ref(self) -> Arc<dyn Array> {
@@ -108,6 +118,14 @@ impl GeometryArrayTrait for RectArray {
         self.metadata.clone()
     }
 
+    fn buffer_sizes(&self) -> BufferSizes {
+        BufferSizes {
+            coords: self.values.inner().len(),
+            validity: validity_size(self.validity()),
+            ..Default::default()
+        }
+    }
+
     /// Returns the number of geometries in this array
     #[inline]
     fn len(&self) -> usize {
@@ -134,6 +152,11 @@ impl GeometryArraySelfMethods for RectArray {
         unimplemented!()
     }
 
+    fn with_metadata(mut self, metadata: Arc<ArrayMetadata>) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
     /// Slices this [`RectArray`] in place.
     /// # Panic
     /// This function panics iff `offset + length > self.len()`.
@@ -170,13 +193,118 @@ impl<'a> GeometryArrayAccessor<'a> for RectArray {
 }
 
 impl IntoArrow for RectArray {
-    type ArrowArray = FixedSizeListArray;
+    type ArrowArray = StructArray;
 
     fn into_arrow(self) -> Self::ArrowArray {
-        let inner_field = self.inner_field();
-        let validity = self.validity;
-        let values = Float64Array::new(self.values, None);
-        FixedSizeListArray::new(inner_field, 2, Arc::new(values), validity)
+        let fields = self.fields();
+        let columns = (0..4)
+            .map(|dim| {
+                let values = self
+                    .values
+                    .iter()
+                    .skip(dim)
+                    .step_by(4)
+                    .copied()
+                    .collect::<Vec<_>>();
+                Arc::new(Float64Array::new(values.into(), None)) as ArrayRef
+            })
+            .collect::<Vec<_>>();
+        StructArray::new(fields, columns, self.validity)
+    }
+}
+
+/// Conversion from a GeoArrow `box` array: a struct of four `Float64` fields, in the order xmin,
+/// ymin, xmax, ymax.
+impl TryFrom<&StructArray> for RectArray {
+    type Error = GeoArrowError;
+
+    fn try_from(value: &StructArray) -> Result<Self, Self::Error> {
+        if value.num_columns() != 4 {
+            return Err(GeoArrowError::General(format!(
+                "Expected box arrays to have 4 fields, got {}",
+                value.num_columns()
+            )));
+        }
+
+        let columns = value
+            .columns()
+            .iter()
+            .map(|column| {
+                column
+                    .as_any()
+                    .downcast_ref::<Float64Array>()
+                    .ok_or_else(|| {
+                        GeoArrowError::General(format!(
+                            "Expected box fields to have type Float64, got {}",
+                            column.data_type()
+                        ))
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut values = Vec::with_capacity(value.len() * 4);
+        for geom_idx in 0..value.len() {
+            values.extend(columns.iter().map(|column| column.value(geom_idx)));
+        }
+
+        Ok(Self::new(
+            values.into(),
+            value.nulls().cloned(),
+            Default::default(),
+        ))
+    }
+}
+
+/// Zero-copy conversion from a `FixedSizeList<Float64>[4]` array laid out as minx, miny, maxx,
+/// maxy, as written with the earlier `geoarrow._rect` extension name.
+impl TryFrom<&FixedSizeListArray> for RectArray {
+    type Error = GeoArrowError;
+
+    fn try_from(value: &FixedSizeListArray) -> Result<Self, Self::Error> {
+        if value.value_length() != 4 {
+            return Err(GeoArrowError::General(format!(
+                "Expected rects to have a list size of 4, got {}",
+                value.value_length()
+            )));
+        }
+
+        let values = value
+            .values()
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .ok_or_else(|| {
+                GeoArrowError::General(format!(
+                    "Expected rect values to have type Float64, got {}",
+                    value.values().data_type()
+                ))
+            })?;
+
+        Ok(Self::new(
+            values.values().clone(),
+            value.nulls().cloned(),
+            Default::default(),
+        ))
+    }
+}
+
+impl TryFrom<&dyn Array> for RectArray {
+    type Error = GeoArrowError;
+
+    fn try_from(value: &dyn Array) -> Result<Self, Self::Error> {
+        match value.data_type() {
+            DataType::Struct(_) => {
+                let arr = value.as_any().downcast_ref::<StructArray>().unwrap();
+                arr.try_into()
+            }
+            DataType::FixedSizeList(_, _) => {
+                let arr = value.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+                arr.try_into()
+            }
+            dt => Err(GeoArrowError::General(format!(
+                "Expected a Struct or FixedSizeList array for RectArray, got {}",
+                dt
+            ))),
+        }
     }
 }
 
@@ -193,3 +321,9 @@ impl<G: RectTrait<T = f64>> From<Vec<Option<G>>> for RectArray {
         mut_arr.into()
     }
 }
+
+impl<G: RectTrait<T = f64>> FromIterator<Option<G>> for RectArray {
+    fn from_iter<I: IntoIterator<Item = Option<G>>>(iter: I) -> Self {
+        RectBuilder::from_iter(iter).into()
+    }
+}
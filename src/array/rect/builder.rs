@@ -4,7 +4,7 @@ use crate::error::GeoArrowError;
 use crate::geo_traits::{CoordTrait, RectTrait};
 use crate::scalar::Rect;
 use crate::trait_::IntoArrow;
-use arrow_array::{Array, FixedSizeListArray};
+use arrow_array::{Array, StructArray};
 use arrow_buffer::NullBufferBuilder;
 use std::sync::Arc;
 
@@ -149,6 +149,22 @@ impl RectBuilder {
         mutable_array
     }
 
+    /// Create this builder from an iterator of nullable Rects, reserving `capacity` up front.
+    ///
+    /// Unlike [`from_nullable_rects`][Self::from_nullable_rects], the geometries don't need to be
+    /// collected first, so `capacity` is only an estimate when they are computed on the fly.
+    pub fn from_iter_with_capacity(
+        geoms: impl IntoIterator<Item = Option<impl RectTrait<T = f64>>>,
+        capacity: usize,
+        metadata: Arc<ArrayMetadata>,
+    ) -> Self {
+        let mut builder = Self::with_capacity(capacity, metadata);
+        geoms
+            .into_iter()
+            .for_each(|maybe_g| builder.push_rect(maybe_g.as_ref()));
+        builder
+    }
+
     pub fn finish(self) -> RectArray {
         self.into()
     }
@@ -161,7 +177,7 @@ impl Default for RectBuilder {
 }
 
 impl IntoArrow for RectBuilder {
-    type ArrowArray = FixedSizeListArray;
+    type ArrowArray = StructArray;
 
     fn into_arrow(self) -> Self::ArrowArray {
         let rect_array: RectArray = self.into();
@@ -190,3 +206,12 @@ impl<G: RectTrait<T = f64>> From<Vec<Option<G>>> for RectBuilder {
         RectBuilder::from_nullable_rects(geoms.iter().map(|x| x.as_ref()), Default::default())
     }
 }
+
+impl<G: RectTrait<T = f64>> FromIterator<Option<G>> for RectBuilder {
+    fn from_iter<I: IntoIterator<Item = Option<G>>>(iter: I) -> Self {
+        let mut builder = Self::new();
+        iter.into_iter()
+            .for_each(|maybe_g| builder.push_rect(maybe_g.as_ref()));
+        builder
+    }
+}
@@ -1,7 +1,9 @@
 //! Note: This entire mod is a candidate to upstream into arrow-rs.
 
+use std::ops::Range;
+
 use arrow_array::OffsetSizeTrait;
-use arrow_buffer::OffsetBuffer;
+use arrow_buffer::{ArrowNativeType, NullBuffer, OffsetBuffer, ScalarBuffer};
 
 use crate::error::Result;
 
@@ -19,6 +21,18 @@ pub(crate) fn offsets_buffer_i64_to_i32(offsets: &OffsetBuffer<i64>) -> Result<O
     Ok(unsafe { OffsetBuffer::new_unchecked(i32_offsets.into()) })
 }
 
+/// Take the values out of `buffer`, leaving it empty.
+///
+/// The allocation is reused when the buffer isn't shared with another array and was allocated
+/// from a `Vec`. Otherwise, the values are copied.
+pub(crate) fn take_scalar_buffer<T: ArrowNativeType>(buffer: &mut ScalarBuffer<T>) -> Vec<T> {
+    let buffer = std::mem::replace(buffer, ScalarBuffer::from(Vec::new()));
+    match buffer.into_inner().into_vec() {
+        Ok(values) => values,
+        Err(buffer) => buffer.typed_data::<T>().to_vec(),
+    }
+}
+
 /// Returns an iterator with the lengths of the offsets
 #[inline]
 pub(crate) fn offset_lengths<O: OffsetSizeTrait>(
@@ -69,3 +83,21 @@ impl<O: OffsetSizeTrait> OffsetBufferUtils<O> for OffsetBuffer<O> {
         self.as_ref().last().unwrap()
     }
 }
+
+/// The number of bytes of the offsets of the items at `range`, and the range of the next level
+/// (e.g. of the coordinates) covered by those items.
+pub(crate) fn offsets_size<O: OffsetSizeTrait>(
+    offsets: &OffsetBuffer<O>,
+    range: Range<usize>,
+) -> (usize, Range<usize>) {
+    let size = (range.len() + 1) * std::mem::size_of::<O>();
+    let child_range = offsets[range.start].as_usize()..offsets[range.end].as_usize();
+    (size, child_range)
+}
+
+/// The number of bytes of a validity buffer, covering only its slots.
+pub(crate) fn validity_size(validity: Option<&NullBuffer>) -> usize {
+    validity
+        .map(|validity| validity.len().div_ceil(8))
+        .unwrap_or(0)
+}
@@ -1,18 +1,20 @@
 use std::any::Any;
-use std::collections::HashSet;
 use std::sync::Arc;
 
 use arrow::array::OffsetSizeTrait;
-use arrow_array::Array;
+use arrow_array::types::ArrowPrimitiveType;
+use arrow_array::{Array, BooleanArray, PrimitiveArray};
 use arrow_schema::{DataType, Field};
 
 #[cfg(feature = "rayon")]
 use rayon::prelude::*;
 
+use crate::algorithm::native::Concatenate;
+use crate::array::metadata::ArrayMetadata;
 use crate::array::*;
 use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
-use crate::trait_::GeometryArrayAccessor;
+use crate::trait_::{BufferSizes, GeometryArrayAccessor, GeometryArraySelfMethods};
 use crate::GeometryArrayTrait;
 
 /// A collection of Arrow arrays of the same type.
@@ -122,15 +124,55 @@ pub struct ChunkedGeometryArray<G: GeometryArrayTrait> {
 }
 
 impl<G: GeometryArrayTrait> ChunkedGeometryArray<G> {
-    pub fn new(chunks: Vec<G>) -> Self {
-        // TODO: assert all equal extension fields
+    /// Construct a chunked array, checking that every chunk has the same [`GeoDataType`] (which
+    /// includes the coord type) and the same [`ArrayMetadata`] (which includes the CRS) as the
+    /// first chunk.
+    ///
+    /// Returns an error naming the first chunk that doesn't match, or if `chunks` is empty.
+    pub fn try_new(chunks: Vec<G>) -> Result<Self> {
+        let first = chunks.first().ok_or_else(|| {
+            GeoArrowError::General("A chunked geometry array must have at least one chunk".into())
+        })?;
+        for (i, chunk) in chunks.iter().enumerate().skip(1) {
+            if chunk.data_type() != first.data_type() {
+                return Err(GeoArrowError::General(format!(
+                    "Chunk {} has data type {:?}, but chunk 0 has data type {:?}",
+                    i,
+                    chunk.data_type(),
+                    first.data_type()
+                )));
+            }
+            if chunk.metadata() != first.metadata() {
+                return Err(GeoArrowError::General(format!(
+                    "Chunk {} has metadata {:?}, but chunk 0 has metadata {:?}",
+                    i,
+                    chunk.metadata(),
+                    first.metadata()
+                )));
+            }
+        }
+        Ok(Self::new_unchecked(chunks))
+    }
+
+    /// Construct a chunked array without checking that the chunks have the same type and
+    /// metadata.
+    ///
+    /// This is meant for chunks that are known to match, such as the output of applying the same
+    /// kernel to every chunk of an existing chunked array. Otherwise prefer
+    /// [`try_new`][Self::try_new].
+    pub fn new_unchecked(chunks: Vec<G>) -> Self {
         let mut length = 0;
         chunks.iter().for_each(|x| length += x.len());
         Self { chunks, length }
     }
 
-    // TODO: check/assert on creation that all are the same so we can be comfortable here only
-    // taking the first.
+    #[deprecated(note = "Use try_new, or new_unchecked for chunks that are known to match")]
+    pub fn new(chunks: Vec<G>) -> Self {
+        Self::new_unchecked(chunks)
+    }
+
+    /// The extension field of the first chunk, which is the same as every other chunk's if the
+    /// array was created with [`try_new`][Self::try_new].
     pub fn extension_field(&self) -> Arc<Field> {
         self.chunks.first().unwrap().extension_field()
     }
@@ -151,10 +193,22 @@ impl<G: GeometryArrayTrait> ChunkedGeometryArray<G> {
         self.chunks.as_slice()
     }
 
+    /// The number of geometries in each chunk.
+    pub(crate) fn chunk_lengths(&self) -> Vec<usize> {
+        self.chunks.iter().map(|chunk| chunk.len()).collect()
+    }
+
     pub fn data_type(&self) -> &GeoDataType {
         self.chunks.first().unwrap().data_type()
     }
 
+    /// The metadata of the first chunk, such as its CRS, which is the same as every other chunk's
+    /// if the array was created with [`try_new`][Self::try_new].
+    pub fn metadata(&self) -> Arc<ArrayMetadata> {
+        self.chunks.first().unwrap().metadata()
+    }
+
+    /// Like [`map`][Self::map], but consuming the chunks.
     pub fn into_map<F: Fn(G) -> R + Sync + Send, R: Send>(self, map_op: F) -> Vec<R> {
         #[cfg(feature = "rayon")]
         {
@@ -172,6 +226,10 @@ impl<G: GeometryArrayTrait> ChunkedGeometryArray<G> {
         }
     }
 
+    /// Apply `map_op` to each chunk, returning one output per chunk, in the order of the chunks.
+    ///
+    /// With the `rayon` feature, the chunks are mapped in parallel. The output of a kernel that
+    /// returns arrow arrays can be wrapped with `ChunkedArray::try_from`.
     pub fn map<F: Fn(&G) -> R + Sync + Send, R: Send>(&self, map_op: F) -> Vec<R> {
         #[cfg(feature = "rayon")]
         {
@@ -189,6 +247,8 @@ impl<G: GeometryArrayTrait> ChunkedGeometryArray<G> {
         }
     }
 
+    /// Like [`map`][Self::map], for a fallible `map_op`. Returns the first error if any chunk
+    /// fails.
     pub fn try_map<F: Fn(&G) -> Result<R> + Sync + Send, R: Send>(
         &self,
         map_op: F,
@@ -203,6 +263,72 @@ impl<G: GeometryArrayTrait> ChunkedGeometryArray<G> {
             self.chunks.iter().map(map_op).collect()
         }
     }
+
+    /// Apply `map_op` to each chunk, concatenating the outputs of all chunks in the order of the
+    /// chunks.
+    ///
+    /// Unlike [`map`][Self::map], each chunk can have any number of outputs, for operations whose
+    /// output length differs from the number of chunks, such as converting every geometry to a
+    /// `geo` geometry. With the `rayon` feature, the chunks are mapped in parallel.
+    pub fn map_flat<F, I, R>(&self, map_op: F) -> Vec<R>
+    where
+        F: Fn(&G) -> I + Sync + Send,
+        I: IntoIterator<Item = R>,
+        R: Send,
+    {
+        #[cfg(feature = "rayon")]
+        {
+            self.chunks.par_iter().flat_map_iter(map_op).collect()
+        }
+
+        #[cfg(not(feature = "rayon"))]
+        {
+            self.chunks.iter().flat_map(map_op).collect()
+        }
+    }
+
+    /// The number of null geometries in each chunk.
+    pub fn chunk_null_counts(&self) -> Vec<usize> {
+        self.chunks.iter().map(|chunk| chunk.null_count()).collect()
+    }
+
+    /// The number of null geometries across all chunks.
+    pub fn null_count(&self) -> usize {
+        self.chunks.iter().map(|chunk| chunk.null_count()).sum()
+    }
+
+    /// Like [`try_map`][Self::try_map], but chunks where every geometry is null are not passed to
+    /// `map_op`. The output for such a chunk is an all-null array of the same length.
+    pub(crate) fn try_map_non_null<F: Fn(&G) -> Result<R> + Sync + Send, R: NewNullArray + Send>(
+        &self,
+        map_op: F,
+    ) -> Result<Vec<R>> {
+        self.try_map(|chunk| {
+            if chunk.null_count() == chunk.len() {
+                Ok(R::new_null_array(chunk.len()))
+            } else {
+                map_op(chunk)
+            }
+        })
+    }
+}
+
+/// An array that can be created with every value null, so that kernels can skip chunks where
+/// every geometry is null.
+pub(crate) trait NewNullArray {
+    fn new_null_array(len: usize) -> Self;
+}
+
+impl<T: ArrowPrimitiveType> NewNullArray for PrimitiveArray<T> {
+    fn new_null_array(len: usize) -> Self {
+        Self::new_null(len)
+    }
+}
+
+impl NewNullArray for BooleanArray {
+    fn new_null_array(len: usize) -> Self {
+        Self::new_null(len)
+    }
 }
 
 impl<'a, G: GeometryArrayTrait + GeometryArrayAccessor<'a>> ChunkedGeometryArray<G> {
@@ -233,11 +359,122 @@ impl<'a, G: GeometryArrayTrait + GeometryArrayAccessor<'a>> ChunkedGeometryArray
     }
 }
 
+impl<G: GeometryArrayTrait + GeometryArraySelfMethods> ChunkedGeometryArray<G> {
+    /// Replace the metadata of every chunk, such as its CRS, keeping its geometries.
+    pub fn with_metadata(self, metadata: Arc<ArrayMetadata>) -> Self {
+        let length = self.length;
+        let chunks = self
+            .chunks
+            .into_iter()
+            .map(|chunk| chunk.with_metadata(metadata.clone()))
+            .collect();
+        Self { chunks, length }
+    }
+
+    /// Construct a chunked array like [`try_new`][Self::try_new], but first convert every chunk
+    /// to the coord type of the first chunk instead of returning an error for chunks with a
+    /// different coord type.
+    ///
+    /// Mixed and geometry collection chunks can't be converted yet, so they still need to share
+    /// a coord type.
+    pub fn try_new_with_coord_type_cast(chunks: Vec<G>) -> Result<Self> {
+        let Some(coord_type) = chunks.first().map(|chunk| chunk.coord_type()) else {
+            return Self::try_new(chunks);
+        };
+        let chunks = chunks
+            .into_iter()
+            .enumerate()
+            .map(|(i, chunk)| {
+                if chunk.coord_type() == coord_type {
+                    return Ok(chunk);
+                }
+                match chunk.data_type() {
+                    GeoDataType::Mixed(_)
+                    | GeoDataType::LargeMixed(_)
+                    | GeoDataType::GeometryCollection(_)
+                    | GeoDataType::LargeGeometryCollection(_) => {
+                        Err(GeoArrowError::NotYetImplemented(format!(
+                            "Chunk {} has data type {:?}, which can't be cast to coord type {:?}",
+                            i,
+                            chunk.data_type(),
+                            coord_type
+                        )))
+                    }
+                    _ => Ok(chunk.into_coord_type(coord_type)),
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Self::try_new(chunks)
+    }
+}
+
+impl<G> ChunkedGeometryArray<G>
+where
+    G: GeometryArrayTrait + GeometryArraySelfMethods,
+    for<'a> &'a [G]: Concatenate<Output = Result<G>>,
+{
+    /// Concatenate the chunks into a single array, for consumers that only accept a single
+    /// array.
+    ///
+    /// The buffers of each chunk are copied once, see [`Concatenate`] for details.
+    pub fn concat(&self) -> Result<G> {
+        self.chunks.as_slice().concatenate()
+    }
+
+    /// Split and merge the chunks so that every chunk has `target_chunk_size` geometries, apart
+    /// from the last one, which may be smaller.
+    ///
+    /// Output chunks within a single input chunk are zero-copy slices of it, while output chunks
+    /// spanning several input chunks are concatenated. Rechunking two arrays to the same chunk
+    /// size aligns their chunks, so that they can be zipped chunk by chunk.
+    pub fn rechunk(&self, target_chunk_size: usize) -> Result<Self> {
+        if target_chunk_size == 0 {
+            return Err(GeoArrowError::General(
+                "target_chunk_size must be greater than 0".to_string(),
+            ));
+        }
+
+        let merge = |mut pieces: Vec<G>| {
+            if pieces.len() == 1 {
+                Ok(pieces.pop().unwrap())
+            } else {
+                pieces.as_slice().concatenate()
+            }
+        };
+
+        let mut output_chunks = Vec::new();
+        let mut pieces = Vec::new();
+        let mut pieces_length = 0;
+        for chunk in self.chunks.iter() {
+            let mut offset = 0;
+            while offset < chunk.len() {
+                let length = (target_chunk_size - pieces_length).min(chunk.len() - offset);
+                pieces.push(chunk.slice(offset, length));
+                pieces_length += length;
+                offset += length;
+                if pieces_length == target_chunk_size {
+                    output_chunks.push(merge(std::mem::take(&mut pieces))?);
+                    pieces_length = 0;
+                }
+            }
+        }
+        if !pieces.is_empty() {
+            output_chunks.push(merge(pieces)?);
+        }
+        // Keep an empty chunk for the data type of an empty array
+        if output_chunks.is_empty() {
+            output_chunks.extend(self.chunks.first().map(|chunk| chunk.slice(0, 0)));
+        }
+
+        Ok(Self::new_unchecked(output_chunks))
+    }
+}
+
 impl<G: GeometryArrayTrait> TryFrom<Vec<G>> for ChunkedGeometryArray<G> {
     type Error = GeoArrowError;
 
     fn try_from(value: Vec<G>) -> Result<Self> {
-        Ok(Self::new(value))
+        Self::try_new(value)
     }
 }
 
@@ -276,9 +513,35 @@ pub trait ChunkedGeometryArrayTrait: std::fmt::Debug + Send + Sync {
     /// Access the geometry chunks contained within this chunked array.
     fn geometry_chunks(&self) -> Vec<&dyn GeometryArrayTrait>;
 
+    /// The metadata of the first chunk, such as its CRS.
+    fn metadata(&self) -> Arc<ArrayMetadata> {
+        self.geometry_chunks()[0].metadata()
+    }
+
     /// The number of chunks in this chunked array.
     fn num_chunks(&self) -> usize;
 
+    /// The number of null geometries in each chunk.
+    fn chunk_null_counts(&self) -> Vec<usize> {
+        self.geometry_chunks()
+            .iter()
+            .map(|chunk| chunk.null_count())
+            .collect()
+    }
+
+    /// The number of null geometries across all chunks.
+    fn null_count(&self) -> usize {
+        self.chunk_null_counts().iter().sum()
+    }
+
+    /// The sizes of the buffers backing all chunks. See [`GeometryArrayTrait::buffer_sizes`].
+    fn buffer_sizes(&self) -> BufferSizes {
+        self.geometry_chunks()
+            .iter()
+            .map(|chunk| chunk.buffer_sizes())
+            .sum()
+    }
+
     fn as_ref(&self) -> &dyn ChunkedGeometryArrayTrait;
 }
 
@@ -381,16 +644,23 @@ impl ChunkedGeometryArrayTrait for ChunkedRectArray {
 
 /// Construct
 /// Does **not** parse WKB. Will return a ChunkedWKBArray for WKB input.
+///
+/// The extension metadata of `field` is normalized with
+/// [`ArrayMetadata::from_extension_metadata`] and attached to every chunk.
 pub fn from_arrow_chunks(
     chunks: &[&dyn Array],
     field: &Field,
 ) -> Result<Arc<dyn ChunkedGeometryArrayTrait>> {
     macro_rules! impl_downcast {
         ($array:ty) => {
-            Ok(Arc::new(ChunkedGeometryArray::new(
+            Ok(Arc::new(ChunkedGeometryArray::new_unchecked(
                 chunks
                     .iter()
-                    .map(|array| <$array>::try_from(*array))
+                    .map(|array| {
+                        let mut array = <$array>::try_from(*array)?;
+                        array.metadata = metadata.clone();
+                        Ok(array)
+                    })
                     .collect::<Result<Vec<_>>>()?,
             )))
         };
@@ -398,6 +668,7 @@ pub fn from_arrow_chunks(
     use GeoDataType::*;
 
     let geo_data_type = GeoDataType::try_from(field)?;
+    let metadata = Arc::new(ArrayMetadata::try_from(field)?);
     match geo_data_type {
         Point(_) => impl_downcast!(PointArray),
         LineString(_) => impl_downcast!(LineStringArray<i32>),
@@ -416,56 +687,274 @@ pub fn from_arrow_chunks(
         LargeGeometryCollection(_) => impl_downcast!(GeometryCollectionArray<i64>),
         WKB => impl_downcast!(WKBArray<i32>),
         LargeWKB => impl_downcast!(WKBArray<i64>),
-        // Rect => impl_downcast!(RectArray),
-        _ => todo!(),
+        Rect => Ok(Arc::new(ChunkedGeometryArray::new_unchecked(
+            chunks
+                .iter()
+                .map(|array| Ok(RectArray::try_from(*array)?.with_metadata(metadata.clone())))
+                .collect::<Result<Vec<_>>>()?,
+        ))),
     }
 }
 
+/// Construct a chunked array from geometry arrays, which must all have the same [`GeoDataType`]
+/// and [`ArrayMetadata`].
+///
+/// Returns an error naming the first chunk that doesn't match the first, or if `chunks` is
+/// empty.
 pub fn from_geoarrow_chunks(
     chunks: &[&dyn GeometryArrayTrait],
 ) -> Result<Arc<dyn ChunkedGeometryArrayTrait>> {
-    let mut data_types = HashSet::new();
-    chunks.iter().for_each(|chunk| {
-        data_types.insert(chunk.as_ref().data_type());
-    });
-
-    if data_types.len() == 1 {
-        macro_rules! impl_downcast {
-            ($cast_func:ident) => {
-                Arc::new(ChunkedGeometryArray::new(
-                    chunks
-                        .iter()
-                        .map(|chunk| chunk.as_ref().$cast_func().clone())
-                        .collect(),
-                ))
-            };
-        }
+    let first = chunks.first().ok_or_else(|| {
+        GeoArrowError::General("A chunked geometry array must have at least one chunk".into())
+    })?;
+    if let Some((i, chunk)) = chunks
+        .iter()
+        .enumerate()
+        .find(|(_, chunk)| chunk.data_type() != first.data_type())
+    {
+        return Err(GeoArrowError::General(format!(
+            "Chunk {} has data type {:?}, but chunk 0 has data type {:?}. Handling multiple \
+            geometry types in `from_geoarrow_chunks` is not yet implemented",
+            i,
+            chunk.data_type(),
+            first.data_type()
+        )));
+    }
+
+    macro_rules! impl_downcast {
+        ($cast_func:ident) => {
+            Arc::new(ChunkedGeometryArray::try_new(
+                chunks
+                    .iter()
+                    .map(|chunk| chunk.as_ref().$cast_func().clone())
+                    .collect(),
+            )?)
+        };
+    }
+
+    use GeoDataType::*;
+    let result: Arc<dyn ChunkedGeometryArrayTrait> = match *first.data_type() {
+        Point(_) => impl_downcast!(as_point),
+        LineString(_) => impl_downcast!(as_line_string),
+        LargeLineString(_) => impl_downcast!(as_large_line_string),
+        Polygon(_) => impl_downcast!(as_polygon),
+        LargePolygon(_) => impl_downcast!(as_large_polygon),
+        MultiPoint(_) => impl_downcast!(as_multi_point),
+        LargeMultiPoint(_) => impl_downcast!(as_large_multi_point),
+        MultiLineString(_) => impl_downcast!(as_multi_line_string),
+        LargeMultiLineString(_) => impl_downcast!(as_large_multi_line_string),
+        MultiPolygon(_) => impl_downcast!(as_multi_polygon),
+        LargeMultiPolygon(_) => impl_downcast!(as_large_multi_polygon),
+        Mixed(_) => impl_downcast!(as_mixed),
+        LargeMixed(_) => impl_downcast!(as_large_mixed),
+        GeometryCollection(_) => impl_downcast!(as_geometry_collection),
+        LargeGeometryCollection(_) => impl_downcast!(as_large_geometry_collection),
+        WKB => impl_downcast!(as_wkb),
+        LargeWKB => impl_downcast!(as_large_wkb),
+        Rect => impl_downcast!(as_rect),
+    };
+    Ok(result)
+}
+
+/// Concatenate chunked arrays of the same data type into a single chunked array, keeping the
+/// chunks of each array in order.
+///
+/// The chunks are not copied. Use [`ChunkedGeometryArray::concat`] on the output to flatten it
+/// to a single array.
+pub fn concat_chunked(
+    arrays: &[&dyn ChunkedGeometryArrayTrait],
+) -> Result<Arc<dyn ChunkedGeometryArrayTrait>> {
+    let chunks = arrays
+        .iter()
+        .flat_map(|array| array.geometry_chunks())
+        .collect::<Vec<_>>();
+    from_geoarrow_chunks(&chunks)
+}
 
-        use GeoDataType::*;
-        let result: Arc<dyn ChunkedGeometryArrayTrait> = match *data_types.drain().next().unwrap() {
-            Point(_) => impl_downcast!(as_point),
-            LineString(_) => impl_downcast!(as_line_string),
-            LargeLineString(_) => impl_downcast!(as_large_line_string),
-            Polygon(_) => impl_downcast!(as_polygon),
-            LargePolygon(_) => impl_downcast!(as_large_polygon),
-            MultiPoint(_) => impl_downcast!(as_multi_point),
-            LargeMultiPoint(_) => impl_downcast!(as_large_multi_point),
-            MultiLineString(_) => impl_downcast!(as_multi_line_string),
-            LargeMultiLineString(_) => impl_downcast!(as_large_multi_line_string),
-            MultiPolygon(_) => impl_downcast!(as_multi_polygon),
-            LargeMultiPolygon(_) => impl_downcast!(as_large_multi_polygon),
-            Mixed(_) => impl_downcast!(as_mixed),
-            LargeMixed(_) => impl_downcast!(as_large_mixed),
-            GeometryCollection(_) => impl_downcast!(as_geometry_collection),
-            LargeGeometryCollection(_) => impl_downcast!(as_large_geometry_collection),
-            WKB => impl_downcast!(as_wkb),
-            LargeWKB => impl_downcast!(as_large_wkb),
-            Rect => impl_downcast!(as_rect),
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::geo::Area;
+    use crate::test::{linestring, point};
+    use arrow_array::Float64Array;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn chunked_with_null_chunk() -> ChunkedPointArray {
+        ChunkedGeometryArray::new_unchecked(vec![
+            vec![Some(point::p0()), None].into(),
+            vec![None::<geo::Point>, None].into(),
+            vec![Some(point::p1()), Some(point::p2())].into(),
+        ])
+    }
+
+    #[test]
+    fn null_counts() {
+        let chunked = chunked_with_null_chunk();
+        assert_eq!(chunked.chunk_null_counts(), vec![1, 2, 0]);
+        assert_eq!(chunked.null_count(), 3);
+        let chunked_ref = chunked.as_ref();
+        assert_eq!(chunked_ref.chunk_null_counts(), vec![1, 2, 0]);
+        assert_eq!(chunked_ref.null_count(), 3);
+    }
+
+    #[test]
+    fn skip_null_chunks() {
+        let chunked = chunked_with_null_chunk();
+        let num_calls = AtomicUsize::new(0);
+        let output = chunked
+            .try_map_non_null(|chunk| {
+                num_calls.fetch_add(1, Ordering::Relaxed);
+                Ok(Float64Array::from(vec![1.; chunk.len()]))
+            })
+            .unwrap();
+        assert_eq!(num_calls.load(Ordering::Relaxed), 2);
+        let lengths = output.iter().map(|arr| arr.len()).collect::<Vec<_>>();
+        assert_eq!(lengths, vec![2, 2, 2]);
+        assert_eq!(output[1].null_count(), 2);
+
+        let area = chunked.unsigned_area().unwrap();
+        let lengths = area
+            .chunks()
+            .iter()
+            .map(|arr| arr.len())
+            .collect::<Vec<_>>();
+        assert_eq!(lengths, vec![2, 2, 2]);
+        assert_eq!(area.chunks()[1].null_count(), 2);
+        assert_eq!(area.chunks()[2].value(1), 0.);
+    }
+
+    #[test]
+    fn map_preserves_order() {
+        let chunked = chunked_with_null_chunk();
+        let lengths = chunked.map(|chunk| chunk.len());
+        assert_eq!(lengths, vec![2, 2, 2]);
+
+        let xs = chunked.map_flat(|chunk| {
+            chunk
+                .iter_geo()
+                .map(|maybe_point| maybe_point.map(|point| point.x()))
+                .collect::<Vec<_>>()
+        });
+        let expected = vec![
+            Some(point::p0().x()),
+            None,
+            None,
+            None,
+            Some(point::p1().x()),
+            Some(point::p2().x()),
+        ];
+        assert_eq!(xs, expected);
+
+        let result = chunked.try_map(|chunk| {
+            if chunk.null_count() == chunk.len() {
+                Err(GeoArrowError::General("all null".to_string()))
+            } else {
+                Ok(chunk.len())
+            }
+        });
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn rechunk() {
+        let chunked = chunked_with_null_chunk();
+        let rechunked = chunked.rechunk(4).unwrap();
+        let lengths = rechunked.map(|chunk| chunk.len());
+        assert_eq!(lengths, vec![4, 2]);
+        assert_eq!(rechunked.chunk_null_counts(), vec![3, 0]);
+        let values = |chunked: &ChunkedPointArray| {
+            chunked.map_flat(|chunk| chunk.iter_geo().collect::<Vec<_>>())
         };
-        Ok(result)
-    } else {
-        Err(GeoArrowError::General(format!(
-            "Handling multiple geometry types in `from_geoarrow_chunks` not yet implemented. Received {:?}", data_types
-        )))
+        assert_eq!(values(&rechunked), values(&chunked));
+
+        let rechunked = chunked.rechunk(1).unwrap();
+        assert_eq!(rechunked.chunks().len(), 6);
+        assert!(chunked.rechunk(0).is_err());
+
+        let concatenated = chunked.concat().unwrap();
+        assert_eq!(concatenated.len(), 6);
+        assert_eq!(concatenated.null_count(), 3);
+    }
+
+    #[test]
+    fn concat_chunked_arrays() {
+        let chunked = chunked_with_null_chunk();
+        let concatenated = concat_chunked(&[chunked.as_ref(), chunked.as_ref()]).unwrap();
+        assert_eq!(concatenated.len(), 12);
+        assert_eq!(concatenated.geometry_chunks().len(), 6);
+
+        let line_strings: ChunkedLineStringArray<i32> =
+            ChunkedGeometryArray::new(vec![linestring::ls_array()]);
+        assert!(concat_chunked(&[chunked.as_ref(), line_strings.as_ref()]).is_err());
+    }
+
+    fn points(coord_type: CoordType) -> PointArray {
+        let points: PointArray = vec![point::p0(), point::p1()].as_slice().into();
+        points.into_coord_type(coord_type)
+    }
+
+    fn error_message(result: Result<ChunkedPointArray>) -> String {
+        match result {
+            Err(GeoArrowError::General(message)) => message,
+            other => panic!("Expected a general error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn try_new_mismatched_coord_types() {
+        let chunks = vec![
+            points(CoordType::Interleaved),
+            points(CoordType::Interleaved),
+            points(CoordType::Separated),
+        ];
+        let message = error_message(ChunkedGeometryArray::try_new(chunks));
+        assert!(message.starts_with("Chunk 2 has data type Point(Separated)"));
+    }
+
+    #[test]
+    fn try_new_mismatched_metadata() {
+        let mut with_crs = points(CoordType::Interleaved);
+        with_crs.metadata = Arc::new(ArrayMetadata {
+            crs: Some(serde_json::json!({"id": {"authority": "EPSG", "code": 4326}})),
+            ..Default::default()
+        });
+        let chunks = vec![points(CoordType::Interleaved), with_crs];
+        let message = error_message(ChunkedGeometryArray::try_new(chunks));
+        assert!(message.starts_with("Chunk 1 has metadata"));
+    }
+
+    #[test]
+    fn try_new_empty() {
+        let message = error_message(ChunkedGeometryArray::try_new(vec![]));
+        assert!(message.contains("at least one chunk"));
+    }
+
+    #[test]
+    fn try_new_with_coord_type_cast() {
+        let chunks = vec![points(CoordType::Separated), points(CoordType::Interleaved)];
+        let chunked = ChunkedGeometryArray::try_new_with_coord_type_cast(chunks).unwrap();
+        assert_eq!(chunked.len(), 4);
+        assert_eq!(
+            chunked.data_type(),
+            &GeoDataType::Point(CoordType::Separated)
+        );
+        assert!(chunked
+            .chunks()
+            .iter()
+            .all(|chunk| chunk.coord_type() == CoordType::Separated));
+        assert_eq!(chunked.chunks()[1].value_as_geo(1), point::p1());
+    }
+
+    #[test]
+    fn from_geoarrow_chunks_mismatched_types() {
+        let points = points(CoordType::Interleaved);
+        let line_strings = linestring::ls_array();
+        let chunks: Vec<&dyn GeometryArrayTrait> = vec![&points, &points, &line_strings];
+        match from_geoarrow_chunks(&chunks) {
+            Err(GeoArrowError::General(message)) => {
+                assert!(message.starts_with("Chunk 2 has data type LineString"))
+            }
+            _ => panic!("Expected an error for mismatched chunk types"),
+        }
     }
 }
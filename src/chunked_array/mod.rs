@@ -11,7 +11,7 @@
 mod chunked_array;
 
 pub use chunked_array::{
-    from_arrow_chunks, from_geoarrow_chunks, ChunkedArray, ChunkedGeometryArray,
+    concat_chunked, from_arrow_chunks, from_geoarrow_chunks, ChunkedArray, ChunkedGeometryArray,
     ChunkedGeometryArrayTrait, ChunkedGeometryCollectionArray, ChunkedLineStringArray,
     ChunkedMixedGeometryArray, ChunkedMultiLineStringArray, ChunkedMultiPointArray,
     ChunkedMultiPolygonArray, ChunkedPointArray, ChunkedPolygonArray, ChunkedRectArray,
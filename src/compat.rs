@@ -0,0 +1,276 @@
+//! Conversions to and from other versions of GeoArrow crates, such as upstream `geoarrow` 0.x
+//! releases, through the [Arrow C data interface].
+//!
+//! Other versions of this crate depend on other versions of `arrow`, so their tables and arrays
+//! can't be passed to this crate directly, and their `GeoDataType` may be laid out differently.
+//! The C data interface structs [`FFI_ArrowArrayStream`], [`FFI_ArrowArray`] and
+//! [`FFI_ArrowSchema`] are the same in every version of `arrow`, so the structs exported by one
+//! version can be cast to those of another, as [`read_gdal`] does for the streams exported by
+//! GDAL. The geometry type is recovered from the GeoArrow extension name of each field, and its
+//! extension metadata, including the CRS, is passed along unchanged.
+//!
+//! For example, to convert a table from a crate that uses another version of `arrow`, here
+//! imported as `other_arrow`:
+//!
+//! ```ignore
+//! let (schema, batches, _) = other_table.into_inner();
+//! let reader = other_arrow::record_batch::RecordBatchIterator::new(
+//!     batches.into_iter().map(Ok),
+//!     schema,
+//! );
+//! let stream = other_arrow::ffi_stream::FFI_ArrowArrayStream::new(Box::new(reader));
+//!
+//! // Safety: the C stream interface has the same layout in every version of arrow
+//! let stream: FFI_ArrowArrayStream = unsafe { std::mem::transmute(stream) };
+//! let table = GeoTable::from_ffi_stream(stream)?;
+//! ```
+//!
+//! [Arrow C data interface]: https://arrow.apache.org/docs/format/CDataInterface.html
+//! [`read_gdal`]: crate::io::gdal::read_gdal
+
+use std::sync::Arc;
+
+use arrow::ffi::{from_ffi, FFI_ArrowArray, FFI_ArrowSchema};
+use arrow::ffi_stream::{ArrowArrayStreamReader, FFI_ArrowArrayStream};
+use arrow::record_batch::RecordBatchReader;
+use arrow_array::{make_array, Array, RecordBatch};
+use arrow_schema::{ArrowError, Field};
+
+use crate::array::from_arrow_array;
+use crate::error::{GeoArrowError, Result};
+use crate::table::{GeoTable, GEOARROW_EXTENSION_NAMES};
+use crate::GeometryArrayTrait;
+
+impl GeoTable {
+    /// Import a table from an Arrow C stream.
+    ///
+    /// The geometry column is the first column with a GeoArrow extension type. Unlike
+    /// [`from_arrow`][Self::from_arrow], columns are kept in their original order and WKB geometry
+    /// columns are kept as WKB, so exporting the table again with
+    /// [`into_ffi_stream`][Self::into_ffi_stream] gives back the same schema.
+    pub fn from_ffi_stream(stream: FFI_ArrowArrayStream) -> Result<Self> {
+        let reader = ArrowArrayStreamReader::try_new(stream)?;
+        let schema = reader.schema();
+        let batches = reader.collect::<std::result::Result<Vec<RecordBatch>, ArrowError>>()?;
+
+        let geometry_column_index = schema
+            .fields()
+            .iter()
+            .position(|field| {
+                field
+                    .metadata()
+                    .get("ARROW:extension:name")
+                    .is_some_and(|name| GEOARROW_EXTENSION_NAMES.contains(name.as_str()))
+            })
+            .ok_or_else(|| {
+                GeoArrowError::General("No GeoArrow geometry column in stream".to_string())
+            })?;
+        Self::try_new(schema, batches, geometry_column_index)
+    }
+
+    /// Export this table as an Arrow C stream, with the geometry column's extension type and
+    /// metadata in its field metadata.
+    ///
+    /// Every batch is exported with the table schema, as with [`into_reader`][Self::into_reader].
+    pub fn into_ffi_stream(self) -> FFI_ArrowArrayStream {
+        FFI_ArrowArrayStream::new(Box::new(self.into_reader()))
+    }
+}
+
+/// Export a geometry array through the Arrow C data interface.
+///
+/// The schema is exported from [`extension_field`][GeometryArrayTrait::extension_field], so it
+/// holds the extension type and metadata of the array. Sliced arrays are exported with their
+/// offset, so only the values in the slice are visible to the consumer.
+pub fn geometry_array_to_ffi(
+    array: &dyn GeometryArrayTrait,
+) -> Result<(FFI_ArrowArray, FFI_ArrowSchema)> {
+    let schema = FFI_ArrowSchema::try_from(array.extension_field().as_ref())?;
+    let array = FFI_ArrowArray::new(&array.to_array_ref().to_data());
+    Ok((array, schema))
+}
+
+/// Import a geometry array from the Arrow C data interface.
+///
+/// `schema` must describe a field with a GeoArrow extension type, as exported by
+/// [`geometry_array_to_ffi`], or an error is returned. The imported array holds on to the buffers
+/// of `array`, which are only released by the producer once the imported array is dropped.
+///
+/// # Safety
+///
+/// `array` and `schema` must be valid and describe the same data, as required by
+/// [`arrow::ffi::from_ffi`].
+pub unsafe fn geometry_array_from_ffi(
+    array: FFI_ArrowArray,
+    schema: &FFI_ArrowSchema,
+) -> Result<Arc<dyn GeometryArrayTrait>> {
+    let field = Field::try_from(schema)?;
+    match field.metadata().get("ARROW:extension:name") {
+        Some(name) if GEOARROW_EXTENSION_NAMES.contains(name.as_str()) => (),
+        Some(name) => {
+            return Err(GeoArrowError::General(format!(
+                "Unknown GeoArrow extension name {}",
+                name
+            )))
+        }
+        None => {
+            return Err(GeoArrowError::General(
+                "Field has no GeoArrow extension name".to_string(),
+            ))
+        }
+    }
+    let array = make_array(from_ffi(array, schema)?);
+    from_arrow_array(array.as_ref(), &field)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::metadata::ArrayMetadata;
+    use crate::array::{AsGeometryArray, MixedGeometryBuilder, PolygonBuilder};
+    use crate::chunked_array::ChunkedGeometryArrayTrait;
+    use crate::test::{linestring, multilinestring, multipoint, multipolygon, point, polygon};
+    use crate::trait_::{GeometryArrayAccessor, GeometryArraySelfMethods};
+    use arrow_array::RecordBatchIterator;
+    use arrow_schema::{DataType, Schema};
+    use serde_json::json;
+
+    fn metadata() -> Arc<ArrayMetadata> {
+        Arc::new(ArrayMetadata {
+            crs: Some(json!({"id": {"authority": "EPSG", "code": 4326}})),
+            edges: None,
+        })
+    }
+
+    fn round_trip_array(array: &dyn GeometryArrayTrait) -> Arc<dyn GeometryArrayTrait> {
+        let (ffi_array, ffi_schema) = geometry_array_to_ffi(array).unwrap();
+        unsafe { geometry_array_from_ffi(ffi_array, &ffi_schema) }.unwrap()
+    }
+
+    #[test]
+    fn array_round_trip() {
+        let arrays: Vec<Arc<dyn GeometryArrayTrait>> = vec![
+            Arc::new(point::point_array()),
+            Arc::new(linestring::ls_array()),
+            Arc::new(linestring::large_ls_array()),
+            Arc::new(polygon::p_array()),
+            Arc::new(multipoint::mp_array()),
+            Arc::new(multilinestring::ml_array()),
+            Arc::new(multipolygon::mp_array()),
+            Arc::new(
+                MixedGeometryBuilder::<i32>::from_nullable_geometries(
+                    &[
+                        Some(geo::Geometry::Point(point::p0())),
+                        None,
+                        Some(geo::Geometry::Polygon(polygon::p0())),
+                    ],
+                    None,
+                    Default::default(),
+                    false,
+                )
+                .unwrap()
+                .finish(),
+            ),
+        ];
+        for array in arrays {
+            let round_tripped = round_trip_array(array.as_ref());
+            assert_eq!(round_tripped.data_type(), array.data_type());
+            assert_eq!(round_tripped.metadata(), array.metadata());
+            assert_eq!(
+                round_tripped.to_array_ref().as_ref(),
+                array.to_array_ref().as_ref()
+            );
+        }
+    }
+
+    #[test]
+    fn array_round_trip_metadata() {
+        let array = PolygonBuilder::<i32>::from_nullable_polygons(
+            &[Some(polygon::p0()), None, Some(polygon::p1())],
+            None,
+            metadata(),
+        )
+        .finish();
+        let round_tripped = round_trip_array(&array);
+        assert_eq!(round_tripped.metadata(), metadata());
+        let round_tripped = round_tripped.as_ref().as_polygon();
+        assert!(round_tripped.is_null(1));
+        assert_eq!(round_tripped.value_as_geo(2), polygon::p1());
+    }
+
+    #[test]
+    fn sliced_array_round_trip() {
+        let points = point::point_array().slice(1, 2);
+        let round_tripped = round_trip_array(&points);
+        let round_tripped = round_tripped.as_ref().as_point();
+        assert_eq!(round_tripped.len(), 2);
+        assert_eq!(round_tripped.value_as_geo(0), point::p1());
+        assert_eq!(round_tripped.value_as_geo(1), point::p2());
+
+        let polygons = polygon::p_array().slice(1, 1);
+        let round_tripped = round_trip_array(&polygons);
+        let round_tripped = round_tripped.as_ref().as_polygon();
+        assert_eq!(round_tripped.len(), 1);
+        assert_eq!(round_tripped.value_as_geo(0), polygon::p1());
+    }
+
+    #[test]
+    fn imported_array_outlives_exported_array() {
+        let (ffi_array, ffi_schema) = {
+            let array = polygon::p_array();
+            geometry_array_to_ffi(&array).unwrap()
+        };
+        let imported = unsafe { geometry_array_from_ffi(ffi_array, &ffi_schema) }.unwrap();
+        drop(ffi_schema);
+        let imported = imported.as_ref().as_polygon();
+        assert_eq!(imported.value_as_geo(0), polygon::p0());
+        assert_eq!(imported.value_as_geo(1), polygon::p1());
+    }
+
+    #[test]
+    fn import_without_extension_name() {
+        let field = Field::new("a", DataType::Binary, true);
+        let array = arrow_array::BinaryArray::from_vec(vec![b"a"]);
+        let ffi_schema = FFI_ArrowSchema::try_from(&field).unwrap();
+        let ffi_array = FFI_ArrowArray::new(&array.to_data());
+        assert!(unsafe { geometry_array_from_ffi(ffi_array, &ffi_schema) }.is_err());
+    }
+
+    #[test]
+    fn table_round_trip() {
+        let table = point::table();
+        let round_tripped = GeoTable::from_ffi_stream(table.clone().into_ffi_stream()).unwrap();
+        assert_eq!(round_tripped.schema(), table.schema());
+        assert_eq!(round_tripped.batches(), table.batches());
+        assert_eq!(
+            round_tripped.geometry_column_index(),
+            table.geometry_column_index()
+        );
+        assert_eq!(round_tripped.validate_geoarrow(), vec![]);
+    }
+
+    #[test]
+    fn table_round_trip_metadata() {
+        let array = PolygonBuilder::<i32>::from_nullable_polygons(
+            &[Some(polygon::p0()), Some(polygon::p1())],
+            None,
+            metadata(),
+        )
+        .finish();
+        let schema = Arc::new(Schema::new(vec![array.extension_field()]));
+        let batch = RecordBatch::try_new(schema.clone(), vec![array.to_array_ref()]).unwrap();
+        let table = GeoTable::try_new(schema, vec![batch], 0).unwrap();
+
+        let round_tripped = GeoTable::from_ffi_stream(table.into_ffi_stream()).unwrap();
+        let geometry = round_tripped.geometry().unwrap();
+        assert_eq!(geometry.geometry_chunks()[0].metadata(), metadata());
+    }
+
+    #[test]
+    fn stream_without_geometry() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let reader = RecordBatchIterator::new(std::iter::empty(), schema);
+        let stream = FFI_ArrowArrayStream::new(Box::new(reader));
+        assert!(GeoTable::from_ffi_stream(stream).is_err());
+    }
+}
@@ -0,0 +1,413 @@
+//! Run SQL over geospatial tables with [DataFusion](https://datafusion.apache.org).
+//!
+//! [`GeoTable::into_mem_table`] converts a table to a DataFusion [`MemTable`], keeping the
+//! GeoArrow extension metadata of its fields, and [`register_udfs`] registers scalar functions
+//! that take GeoArrow geometry columns:
+//!
+//! - `st_area(geometry)`: the unsigned planar area, as with [`Area`].
+//! - `st_length(geometry)`: the planar length, as with [`EuclideanLength`].
+//! - `st_centroid(geometry)`: the centroid, as with [`Centroid`].
+//! - `st_intersects(geometry, geometry)`: whether the geometries of each row intersect.
+//! - `st_buffer(geometry, distance)`: the buffer with GEOS, as with [`Buffer`]. Only registered
+//!   with the `geos` feature.
+//!
+//! ```
+//! use std::sync::Arc;
+//!
+//! use arrow_array::{Float64Array, RecordBatch};
+//! use arrow_schema::Schema;
+//! use datafusion::prelude::SessionContext;
+//! use geoarrow::array::PolygonArray;
+//! use geoarrow::datafusion::register_udfs;
+//! use geoarrow::table::GeoTable;
+//! use geoarrow::GeometryArrayTrait;
+//!
+//! # tokio::runtime::Runtime::new().unwrap().block_on(async {
+//! let polygon = geo::polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+//! let array: PolygonArray<i32> = vec![polygon].as_slice().into();
+//! let schema = Arc::new(Schema::new(vec![array.extension_field()]));
+//! let batch = RecordBatch::try_new(schema.clone(), vec![array.to_array_ref()]).unwrap();
+//! let table = GeoTable::try_new(schema, vec![batch], 0).unwrap();
+//!
+//! let ctx = SessionContext::new();
+//! register_udfs(&ctx);
+//! ctx.register_table("t", Arc::new(table.into_mem_table().unwrap()))
+//!     .unwrap();
+//!
+//! let batches = ctx
+//!     .sql("SELECT st_area(geometry) FROM t")
+//!     .await
+//!     .unwrap()
+//!     .collect()
+//!     .await
+//!     .unwrap();
+//! let area = batches[0]
+//!     .column(0)
+//!     .as_any()
+//!     .downcast_ref::<Float64Array>()
+//!     .unwrap();
+//! assert_eq!(area.value(0), 4.);
+//! # });
+//! ```
+//!
+//! DataFusion passes the arrays of a column to functions without the column's field, so the
+//! geometry type of an argument is recovered from its storage type with [`geometry_field`], and
+//! its CRS is not available. Geometry results, such as those of `st_centroid`, are returned in
+//! their GeoArrow storage layout without extension metadata; [`geometry_field`] gives the
+//! extension field to read them with [`from_arrow_array`].
+//!
+//! [`Buffer`]: crate::algorithm::geos::Buffer
+
+use std::any::Any;
+use std::sync::Arc;
+
+use arrow::record_batch::RecordBatchReader;
+use arrow_array::{new_empty_array, Array, ArrayRef, BooleanArray};
+use arrow_schema::{ArrowError, DataType, Field};
+use datafusion::datasource::MemTable;
+use datafusion::error::{DataFusionError, Result as DataFusionResult};
+use datafusion::logical_expr::{ColumnarValue, ScalarUDF, ScalarUDFImpl, Signature, Volatility};
+use datafusion::prelude::SessionContext;
+use datafusion::scalar::ScalarValue;
+use geo::Intersects as _Intersects;
+
+use crate::algorithm::geo::{Area, Centroid, EuclideanLength};
+use crate::array::{from_arrow_array, geometry_at, CoordType};
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::table::GeoTable;
+use crate::GeometryArrayTrait;
+
+impl GeoTable {
+    /// Convert this table to a DataFusion [`MemTable`] with a single partition.
+    ///
+    /// The table schema, including the extension metadata of the geometry column, is the schema
+    /// of the `MemTable` and of every batch in it.
+    pub fn into_mem_table(self) -> Result<MemTable> {
+        let reader = self.into_reader();
+        let schema = reader.schema();
+        let batches = reader.collect::<std::result::Result<Vec<_>, ArrowError>>()?;
+        Ok(MemTable::try_new(schema, vec![batches])?)
+    }
+}
+
+/// Register the geospatial scalar functions of this module with a DataFusion session.
+pub fn register_udfs(ctx: &SessionContext) {
+    let mut functions = vec![
+        GeoFunction::Area,
+        GeoFunction::Length,
+        GeoFunction::Centroid,
+        GeoFunction::Intersects,
+    ];
+    #[cfg(feature = "geos")]
+    functions.push(GeoFunction::Buffer);
+
+    for function in functions {
+        ctx.register_udf(ScalarUDF::new_from_impl(GeoUdf::new(function)));
+    }
+}
+
+/// The GeoArrow extension field of a geometry array with the given storage type.
+///
+/// Every GeoArrow layout has its own child field names, so types with the same nesting, such as
+/// LineString and MultiPoint, are told apart. Arrays made by this crate always match; arrays
+/// from other producers may only match if they use the child field names and nullability of the
+/// GeoArrow specification.
+pub fn geometry_field(data_type: &DataType) -> Result<Field> {
+    use GeoDataType::*;
+
+    [CoordType::Interleaved, CoordType::Separated]
+        .into_iter()
+        .flat_map(|coord_type| {
+            [
+                Point(coord_type),
+                LineString(coord_type),
+                LargeLineString(coord_type),
+                Polygon(coord_type),
+                LargePolygon(coord_type),
+                MultiPoint(coord_type),
+                LargeMultiPoint(coord_type),
+                MultiLineString(coord_type),
+                LargeMultiLineString(coord_type),
+                MultiPolygon(coord_type),
+                LargeMultiPolygon(coord_type),
+                Mixed(coord_type),
+                LargeMixed(coord_type),
+                GeometryCollection(coord_type),
+                LargeGeometryCollection(coord_type),
+            ]
+        })
+        .chain([WKB, LargeWKB, Rect])
+        .find(|geo_data_type| geo_data_type.to_data_type() == *data_type)
+        .map(|geo_data_type| geo_data_type.to_field("geometry", true))
+        .ok_or_else(|| {
+            GeoArrowError::IncorrectType(
+                format!("{} is not a GeoArrow geometry type", data_type).into(),
+            )
+        })
+}
+
+/// Parse an argument of a geospatial function to a geometry array.
+fn geometry_array(array: &dyn Array) -> Result<Arc<dyn GeometryArrayTrait>> {
+    from_arrow_array(array, &geometry_field(array.data_type())?)
+}
+
+#[derive(Debug, Clone, Copy)]
+enum GeoFunction {
+    Area,
+    Length,
+    Centroid,
+    Intersects,
+    #[cfg(feature = "geos")]
+    Buffer,
+}
+
+impl GeoFunction {
+    fn name(&self) -> &'static str {
+        match self {
+            GeoFunction::Area => "st_area",
+            GeoFunction::Length => "st_length",
+            GeoFunction::Centroid => "st_centroid",
+            GeoFunction::Intersects => "st_intersects",
+            #[cfg(feature = "geos")]
+            GeoFunction::Buffer => "st_buffer",
+        }
+    }
+
+    fn num_args(&self) -> usize {
+        match self {
+            GeoFunction::Area | GeoFunction::Length | GeoFunction::Centroid => 1,
+            GeoFunction::Intersects => 2,
+            #[cfg(feature = "geos")]
+            GeoFunction::Buffer => 2,
+        }
+    }
+
+    /// Evaluate this function on arguments of the same length.
+    fn evaluate(&self, args: &[ArrayRef]) -> Result<ArrayRef> {
+        let geometry = geometry_array(args[0].as_ref())?;
+        let result: ArrayRef = match self {
+            GeoFunction::Area => Arc::new(geometry.as_ref().unsigned_area()?),
+            GeoFunction::Length => Arc::new(geometry.as_ref().euclidean_length()?),
+            GeoFunction::Centroid => geometry.as_ref().centroid()?.to_array_ref(),
+            GeoFunction::Intersects => {
+                let other = geometry_array(args[1].as_ref())?;
+                let result = (0..geometry.len())
+                    .map(|i| {
+                        match (
+                            geometry_at(geometry.as_ref(), i),
+                            geometry_at(other.as_ref(), i),
+                        ) {
+                            (Some(left), Some(right)) => Some(left.intersects(&right)),
+                            _ => None,
+                        }
+                    })
+                    .collect::<BooleanArray>();
+                Arc::new(result)
+            }
+            #[cfg(feature = "geos")]
+            GeoFunction::Buffer => {
+                use crate::algorithm::broadcasting::BroadcastablePrimitive;
+                use crate::algorithm::geos::Buffer;
+                use arrow_array::Float64Array;
+
+                let distance = arrow_cast::cast(args[1].as_ref(), &DataType::Float64)?;
+                let distance = distance
+                    .as_any()
+                    .downcast_ref::<Float64Array>()
+                    .unwrap()
+                    .clone();
+                geometry
+                    .as_ref()
+                    .buffer(&BroadcastablePrimitive::Array(distance), 8)?
+                    .to_array_ref()
+            }
+        };
+        Ok(result)
+    }
+}
+
+/// A geospatial function as a DataFusion scalar UDF.
+#[derive(Debug)]
+struct GeoUdf {
+    function: GeoFunction,
+    signature: Signature,
+}
+
+impl GeoUdf {
+    fn new(function: GeoFunction) -> Self {
+        Self {
+            function,
+            signature: Signature::any(function.num_args(), Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for GeoUdf {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        self.function.name()
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    /// The return type of geometry functions depends on the geometry type of the input, so it's
+    /// found by evaluating the function on empty arrays.
+    fn return_type(&self, arg_types: &[DataType]) -> DataFusionResult<DataType> {
+        let args = arg_types.iter().map(new_empty_array).collect::<Vec<_>>();
+        let result = self.function.evaluate(&args).map_err(to_datafusion_error)?;
+        Ok(result.data_type().clone())
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> DataFusionResult<ColumnarValue> {
+        let num_rows = args.iter().find_map(|arg| match arg {
+            ColumnarValue::Array(array) => Some(array.len()),
+            ColumnarValue::Scalar(_) => None,
+        });
+        let arrays = args
+            .iter()
+            .map(|arg| arg.clone().into_array(num_rows.unwrap_or(1)))
+            .collect::<DataFusionResult<Vec<_>>>()?;
+        let result = self
+            .function
+            .evaluate(&arrays)
+            .map_err(to_datafusion_error)?;
+
+        match num_rows {
+            Some(_) => Ok(ColumnarValue::Array(result)),
+            None => Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(
+                &result, 0,
+            )?)),
+        }
+    }
+}
+
+fn to_datafusion_error(err: GeoArrowError) -> DataFusionError {
+    DataFusionError::External(Box::new(err))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{LineStringArray, MultiPointArray, PointArray};
+    use crate::test::{linestring, multipoint, point};
+    use arrow_array::{Float64Array, RecordBatch};
+    use arrow_schema::Schema;
+
+    async fn query(table: GeoTable, sql: &str) -> Vec<RecordBatch> {
+        let ctx = SessionContext::new();
+        register_udfs(&ctx);
+        ctx.register_table("t", Arc::new(table.into_mem_table().unwrap()))
+            .unwrap();
+        ctx.sql(sql).await.unwrap().collect().await.unwrap()
+    }
+
+    fn table(array: &dyn GeometryArrayTrait) -> GeoTable {
+        let schema = Arc::new(Schema::new(vec![array.extension_field()]));
+        let batch = RecordBatch::try_new(schema.clone(), vec![array.to_array_ref()]).unwrap();
+        GeoTable::try_new(schema, vec![batch], 0).unwrap()
+    }
+
+    #[test]
+    fn geometry_field_from_storage_type() {
+        let line_strings = linestring::ls_array();
+        let field = geometry_field(&line_strings.storage_type()).unwrap();
+        assert_eq!(
+            GeoDataType::try_from(&field).unwrap(),
+            *line_strings.data_type()
+        );
+
+        let multi_points = multipoint::mp_array();
+        let field = geometry_field(&multi_points.storage_type()).unwrap();
+        assert_eq!(
+            GeoDataType::try_from(&field).unwrap(),
+            *multi_points.data_type()
+        );
+
+        assert!(geometry_field(&DataType::Int32).is_err());
+    }
+
+    #[tokio::test]
+    async fn mem_table_keeps_metadata() {
+        let table = point::table();
+        let schema = table.schema().clone();
+        let batches = query(table, "SELECT * FROM t").await;
+        assert_eq!(batches[0].schema().fields(), schema.fields());
+    }
+
+    #[tokio::test]
+    async fn length_and_centroid() {
+        let array: LineStringArray<i32> = linestring::ls_array();
+        let batches = query(
+            table(&array),
+            "SELECT st_length(geometry), st_centroid(geometry) FROM t",
+        )
+        .await;
+
+        let length = batches[0]
+            .column(0)
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .unwrap();
+        let expected = array.euclidean_length();
+        assert_eq!(length, &expected);
+
+        let centroid_field = geometry_field(batches[0].column(1).data_type()).unwrap();
+        let centroid = from_arrow_array(batches[0].column(1).as_ref(), &centroid_field).unwrap();
+        let centroid = centroid.as_any().downcast_ref::<PointArray>().unwrap();
+        assert_eq!(centroid, &array.centroid());
+    }
+
+    #[tokio::test]
+    async fn intersects() {
+        let points: MultiPointArray<i32> = multipoint::mp_array();
+        let schema = Arc::new(Schema::new(vec![
+            points.extension_field().as_ref().clone().with_name("a"),
+            points.extension_field().as_ref().clone().with_name("b"),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![points.to_array_ref(), points.to_array_ref()],
+        )
+        .unwrap();
+        let table = GeoTable::try_new(schema, vec![batch], 0).unwrap();
+
+        let batches = query(table, "SELECT st_intersects(a, b) FROM t").await;
+        let intersects = batches[0]
+            .column(0)
+            .as_any()
+            .downcast_ref::<BooleanArray>()
+            .unwrap();
+        assert_eq!(intersects.true_count(), points.len());
+    }
+
+    #[cfg(feature = "geos")]
+    #[tokio::test]
+    async fn buffer() {
+        let array = crate::test::polygon::p_array();
+        let batches = query(
+            table(&array),
+            "SELECT st_area(st_buffer(geometry, 1.0)), st_area(geometry) FROM t",
+        )
+        .await;
+        let buffered = batches[0]
+            .column(0)
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .unwrap();
+        let original = batches[0]
+            .column(1)
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .unwrap();
+        assert!(buffered
+            .iter()
+            .zip(original.iter())
+            .all(|(buffered, original)| buffered.unwrap() > original.unwrap()));
+    }
+}
@@ -245,8 +245,13 @@ fn wkb_data_type<O: OffsetSizeTrait>() -> DataType {
 }
 
 fn rect_data_type() -> DataType {
-    let inner_field = Field::new("rect", DataType::Float64, false).into();
-    DataType::FixedSizeList(inner_field, 4)
+    let fields = vec![
+        Field::new("xmin", DataType::Float64, false),
+        Field::new("ymin", DataType::Float64, false),
+        Field::new("xmax", DataType::Float64, false),
+        Field::new("ymax", DataType::Float64, false),
+    ];
+    DataType::Struct(fields.into())
 }
 
 impl GeoDataType {
@@ -293,7 +298,7 @@ impl GeoDataType {
             Mixed(_) | LargeMixed(_) => "geoarrow.geometry",
             GeometryCollection(_) | LargeGeometryCollection(_) => "geoarrow.geometrycollection",
             WKB | LargeWKB => "geoarrow.wkb",
-            Rect => unimplemented!(),
+            Rect => "geoarrow.box",
         }
     }
 
@@ -525,6 +530,7 @@ impl TryFrom<&Field> for GeoDataType {
                 "geoarrow.geometry" => parse_geometry(field),
                 "geoarrow.geometrycollection" => parse_geometry_collection(field),
                 "geoarrow.wkb" | "ogc.wkb" => parse_wkb(field),
+                "geoarrow.box" | "geoarrow._rect" => GeoDataType::Rect,
                 name => {
                     return Err(GeoArrowError::General(format!(
                         "Unexpected extension name {}",
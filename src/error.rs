@@ -19,6 +19,15 @@ pub enum GeoArrowError {
     #[error("General error: {0}")]
     General(String),
 
+    /// Returned when parsing input exceeds a limit set in
+    /// [`ParseGuards`](crate::io::guards::ParseGuards).
+    #[error("{limit} of {max} exceeded at row {row}")]
+    ResourceLimitExceeded {
+        limit: crate::io::guards::ResourceLimit,
+        max: usize,
+        row: usize,
+    },
+
     /// Wrapper for an error triggered by a dependency
     #[error(transparent)]
     External(#[from] anyhow::Error),
@@ -34,6 +43,10 @@ pub enum GeoArrowError {
     #[error(transparent)]
     FailedToConvergeError(#[from] geo::vincenty_distance::FailedToConvergeError),
 
+    #[cfg(feature = "datafusion")]
+    #[error(transparent)]
+    DataFusionError(#[from] datafusion::error::DataFusionError),
+
     #[cfg(feature = "gdal")]
     #[error(transparent)]
     GdalError(#[from] gdal::errors::GdalError),
@@ -46,7 +59,7 @@ pub enum GeoArrowError {
     #[error(transparent)]
     GeosError(#[from] geos::Error),
 
-    #[cfg(feature = "flatgeobuf_async")]
+    #[cfg(any(feature = "flatgeobuf_async", feature = "parquet_async"))]
     #[error(transparent)]
     ObjectStoreError(#[from] object_store::Error),
 
@@ -69,6 +82,10 @@ pub enum GeoArrowError {
     #[error(transparent)]
     IOError(#[from] std::io::Error),
 
+    #[cfg(feature = "http")]
+    #[error(transparent)]
+    ReqwestError(#[from] reqwest::Error),
+
     #[cfg(feature = "parquet")]
     #[error(transparent)]
     SerdeJsonError(#[from] serde_json::Error),
@@ -15,8 +15,8 @@ pub struct IndexedChunkedGeometryArray<G: GeometryArrayTrait> {
 impl<G: GeometryArrayTrait> IndexedChunkedGeometryArray<G> {
     pub fn new(chunks: Vec<G>) -> Self {
         assert!(chunks.iter().all(|chunk| chunk.null_count() == 0));
-        let chunks =
-            ChunkedGeometryArray::new(chunks).into_map(|chunk| IndexedGeometryArray::new(chunk));
+        let chunks = ChunkedGeometryArray::new_unchecked(chunks)
+            .into_map(|chunk| IndexedGeometryArray::new(chunk));
         Self { chunks }
     }
 
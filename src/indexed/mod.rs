@@ -3,3 +3,4 @@
 
 pub mod array;
 pub mod chunked;
+pub mod point_in_polygon;
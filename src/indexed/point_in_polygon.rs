@@ -0,0 +1,370 @@
+use arrow_array::builder::UInt32Builder;
+use arrow_array::{OffsetSizeTrait, UInt32Array};
+use geo_index::rtree::sort::HilbertSort;
+use geo_index::rtree::{OwnedRTree, RTreeBuilder, RTreeIndex};
+
+use crate::array::{MultiPolygonArray, PointArray, PolygonArray};
+use crate::error::{GeoArrowError, Result};
+use crate::geo_traits::{CoordTrait, LineStringTrait, MultiPolygonTrait, PointTrait, PolygonTrait};
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+
+/// The maximum number of horizontal bands of a prepared polygon.
+const MAX_BANDS: usize = 1024;
+
+#[derive(Debug, Clone, Copy)]
+struct Edge {
+    x0: f64,
+    y0: f64,
+    x1: f64,
+    y1: f64,
+}
+
+/// A polygon or multi polygon prepared for repeated point-in-polygon tests.
+///
+/// The y range of the polygon is split into horizontal bands, and each band holds the edges that
+/// overlap it, so that a test only visits the edges near the point instead of every edge.
+#[derive(Debug, Clone)]
+struct PreparedPolygon {
+    min_y: f64,
+    band_height: f64,
+    /// The edges of band `i` are `band_edges[band_offsets[i]..band_offsets[i + 1]]`.
+    band_offsets: Vec<usize>,
+    band_edges: Vec<Edge>,
+}
+
+impl PreparedPolygon {
+    fn new(edges: &[Edge], min_y: f64, max_y: f64) -> Self {
+        let num_bands = ((edges.len() as f64).sqrt() as usize).clamp(1, MAX_BANDS);
+        let band_height = (max_y - min_y) / num_bands as f64;
+        let mut prepared = Self {
+            min_y,
+            band_height,
+            band_offsets: Vec::with_capacity(num_bands + 1),
+            band_edges: vec![],
+        };
+
+        let mut bands = vec![vec![]; num_bands];
+        for edge in edges {
+            let first_band = prepared.band(edge.y0.min(edge.y1), num_bands);
+            let last_band = prepared.band(edge.y0.max(edge.y1), num_bands);
+            for band in bands[first_band..=last_band].iter_mut() {
+                band.push(*edge);
+            }
+        }
+        prepared.band_offsets.push(0);
+        for band in bands {
+            prepared.band_edges.extend(band);
+            prepared.band_offsets.push(prepared.band_edges.len());
+        }
+        prepared
+    }
+
+    fn num_bands(&self) -> usize {
+        self.band_offsets.len() - 1
+    }
+
+    /// The band holding `y`. This is monotonic in `y`, so an edge is in the band of every y
+    /// within its range.
+    fn band(&self, y: f64, num_bands: usize) -> usize {
+        if self.band_height > 0. {
+            (((y - self.min_y) / self.band_height) as usize).min(num_bands - 1)
+        } else {
+            0
+        }
+    }
+
+    /// Even-odd crossing test of a point against the edges of its band.
+    fn contains(&self, x: f64, y: f64) -> bool {
+        let band = self.band(y, self.num_bands());
+        let edges = &self.band_edges[self.band_offsets[band]..self.band_offsets[band + 1]];
+        let mut inside = false;
+        for edge in edges {
+            if (edge.y0 > y) != (edge.y1 > y) {
+                let x_intersection =
+                    edge.x0 + (y - edge.y0) * (edge.x1 - edge.x0) / (edge.y1 - edge.y0);
+                if x < x_intersection {
+                    inside = !inside;
+                }
+            }
+        }
+        inside
+    }
+}
+
+/// A spatial index for classifying points by the polygon that contains them, such as looking up
+/// the country or timezone of a location.
+///
+/// The index is built once from a [`PolygonArray`] or [`MultiPolygonArray`], and can then be
+/// queried with single points or with a [`PointArray`] without further preparation. Building it
+/// puts the bounding box of each polygon in a packed R-tree and splits the edges of each polygon
+/// into horizontal bands, which takes `O(n log n)` time in the number of polygons plus linear time
+/// in the number of edges. A query only tests the polygons whose bounding boxes contain the point,
+/// and only the edges in the point's band of each of them.
+///
+/// Containment uses the even-odd rule over all rings, so holes don't need to be oriented. Points
+/// exactly on a boundary may be classified as inside either adjacent polygon, or as outside. Null
+/// and empty polygons never contain a point.
+///
+/// The index owns all of its data and is `Send + Sync`, so it can be shared between threads, for
+/// example behind an [`Arc`][std::sync::Arc] in a server.
+#[derive(Debug, Clone)]
+pub struct PointInPolygonIndex {
+    /// An R-tree of the bounding boxes of the non-empty polygons, or `None` if there are none.
+    rtree: Option<OwnedRTree<f64>>,
+    /// The row index of each polygon in the R-tree, by insertion order.
+    rows: Vec<u32>,
+    /// The prepared polygon of each polygon in the R-tree, by insertion order.
+    polygons: Vec<PreparedPolygon>,
+}
+
+impl PointInPolygonIndex {
+    fn try_new(
+        num_rows: usize,
+        rings: impl Iterator<Item = Option<Vec<Vec<(f64, f64)>>>>,
+    ) -> Result<Self> {
+        if num_rows > u32::MAX as usize {
+            return Err(GeoArrowError::General(format!(
+                "A point-in-polygon index can hold at most {} polygons",
+                u32::MAX
+            )));
+        }
+
+        let mut rows = vec![];
+        let mut bounds = vec![];
+        let mut polygons = vec![];
+        for (row, maybe_rings) in rings.enumerate() {
+            let Some(rings) = maybe_rings else {
+                continue;
+            };
+            let (mut min_x, mut min_y) = (f64::INFINITY, f64::INFINITY);
+            let (mut max_x, mut max_y) = (f64::NEG_INFINITY, f64::NEG_INFINITY);
+            let mut edges = vec![];
+            for ring in rings.iter() {
+                for (&(x0, y0), &(x1, y1)) in ring.iter().zip(ring.iter().skip(1)) {
+                    edges.push(Edge { x0, y0, x1, y1 });
+                }
+                for &(x, y) in ring.iter() {
+                    min_x = min_x.min(x);
+                    min_y = min_y.min(y);
+                    max_x = max_x.max(x);
+                    max_y = max_y.max(y);
+                }
+            }
+            // Empty polygons, and polygons whose coordinates are all NaN, can't contain a point
+            if edges.is_empty() || min_x > max_x {
+                continue;
+            }
+
+            rows.push(row as u32);
+            bounds.push([min_x, min_y, max_x, max_y]);
+            polygons.push(PreparedPolygon::new(&edges, min_y, max_y));
+        }
+
+        let rtree = (!bounds.is_empty()).then(|| {
+            let mut builder = RTreeBuilder::new(bounds.len());
+            for [min_x, min_y, max_x, max_y] in bounds {
+                builder.add(min_x, min_y, max_x, max_y);
+            }
+            builder.finish::<HilbertSort>()
+        });
+
+        Ok(Self {
+            rtree,
+            rows,
+            polygons,
+        })
+    }
+
+    /// Build an index over the polygons of a [`PolygonArray`].
+    pub fn try_new_polygons<O: OffsetSizeTrait>(array: &PolygonArray<O>) -> Result<Self> {
+        Self::try_new(
+            array.len(),
+            array
+                .iter()
+                .map(|maybe_polygon| maybe_polygon.map(|polygon| polygon_rings(&polygon))),
+        )
+    }
+
+    /// Build an index over the multi polygons of a [`MultiPolygonArray`].
+    pub fn try_new_multi_polygons<O: OffsetSizeTrait>(
+        array: &MultiPolygonArray<O>,
+    ) -> Result<Self> {
+        Self::try_new(
+            array.len(),
+            array.iter().map(|maybe_multi_polygon| {
+                maybe_multi_polygon.map(|multi_polygon| {
+                    multi_polygon
+                        .polygons()
+                        .flat_map(|polygon| polygon_rings(&polygon))
+                        .collect()
+                })
+            }),
+        )
+    }
+
+    /// The number of non-empty polygons in the index.
+    pub fn num_polygons(&self) -> usize {
+        self.polygons.len()
+    }
+
+    /// The row index of the first polygon that contains the point, or `None` if no polygon
+    /// contains it.
+    pub fn query_point(&self, x: f64, y: f64) -> Option<usize> {
+        let rtree = self.rtree.as_ref()?;
+        let mut candidates = rtree.search(x, y, x, y);
+        // Insertion order is row order
+        candidates.sort_unstable();
+        candidates
+            .into_iter()
+            .find(|&candidate| self.polygons[candidate].contains(x, y))
+            .map(|candidate| self.rows[candidate] as usize)
+    }
+
+    /// The row index of the first polygon that contains each point, or null if no polygon
+    /// contains it or the point is null.
+    pub fn query_points(&self, points: &PointArray) -> UInt32Array {
+        let mut output_array = UInt32Builder::with_capacity(points.len());
+        for maybe_point in points.iter() {
+            output_array.append_option(
+                maybe_point
+                    .and_then(|point| self.query_point(point.x(), point.y()))
+                    .map(|row| row as u32),
+            );
+        }
+        output_array.finish()
+    }
+}
+
+impl<O: OffsetSizeTrait> TryFrom<&PolygonArray<O>> for PointInPolygonIndex {
+    type Error = GeoArrowError;
+
+    fn try_from(value: &PolygonArray<O>) -> Result<Self> {
+        Self::try_new_polygons(value)
+    }
+}
+
+impl<O: OffsetSizeTrait> TryFrom<&MultiPolygonArray<O>> for PointInPolygonIndex {
+    type Error = GeoArrowError;
+
+    fn try_from(value: &MultiPolygonArray<O>) -> Result<Self> {
+        Self::try_new_multi_polygons(value)
+    }
+}
+
+fn polygon_rings(polygon: &impl PolygonTrait<T = f64>) -> Vec<Vec<(f64, f64)>> {
+    polygon
+        .exterior()
+        .into_iter()
+        .chain(polygon.interiors())
+        .map(|ring| ring.coords().map(|coord| (coord.x(), coord.y())).collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use geo::{polygon, Contains, MultiPolygon};
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    /// Timezone-like zones: two zones with a jagged shared border, a zone with an island, and an
+    /// enclave in a hole of another zone.
+    fn zones() -> Vec<MultiPolygon> {
+        let west = polygon![
+            (x: 0., y: 0.), (x: 4., y: 0.), (x: 5., y: 3.), (x: 3., y: 5.), (x: 4., y: 10.),
+            (x: 0., y: 10.), (x: 0., y: 0.)
+        ];
+        let east = polygon![
+            (x: 4., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 4., y: 10.), (x: 3., y: 5.),
+            (x: 5., y: 3.), (x: 4., y: 0.)
+        ];
+        let island = polygon![
+            (x: 20., y: 20.), (x: 22., y: 20.), (x: 22., y: 22.), (x: 20., y: 22.), (x: 20., y: 20.)
+        ];
+        let with_hole = polygon!(
+            exterior: [
+                (x: 0., y: 10.), (x: 10., y: 10.), (x: 10., y: 20.), (x: 0., y: 20.),
+                (x: 0., y: 10.)
+            ],
+            interiors: [
+                [(x: 4., y: 14.), (x: 6., y: 14.), (x: 6., y: 16.), (x: 4., y: 16.), (x: 4., y: 14.)],
+            ],
+        );
+        let enclave = polygon![
+            (x: 4., y: 14.), (x: 6., y: 14.), (x: 6., y: 16.), (x: 4., y: 16.), (x: 4., y: 14.)
+        ];
+        vec![
+            MultiPolygon(vec![west]),
+            MultiPolygon(vec![east, island]),
+            MultiPolygon(vec![with_hole]),
+            MultiPolygon(vec![enclave]),
+        ]
+    }
+
+    #[test]
+    fn classifies_zones() {
+        assert_send_sync::<PointInPolygonIndex>();
+
+        let zones = zones();
+        let array: MultiPolygonArray<i32> = zones.as_slice().into();
+        let index = PointInPolygonIndex::try_from(&array).unwrap();
+        assert_eq!(index.num_polygons(), 4);
+
+        assert_eq!(index.query_point(1., 5.), Some(0));
+        assert_eq!(index.query_point(4.5, 1.), Some(0));
+        assert_eq!(index.query_point(4.5, 5.), Some(1));
+        assert_eq!(index.query_point(21., 21.), Some(1));
+        assert_eq!(index.query_point(1., 15.), Some(2));
+        assert_eq!(index.query_point(5., 15.), Some(3));
+        assert_eq!(index.query_point(15., 15.), None);
+        assert_eq!(index.query_point(-1., 5.), None);
+
+        let points: PointArray = vec![
+            Some(geo::point!(x: 1., y: 5.)),
+            None,
+            Some(geo::point!(x: 15., y: 15.)),
+            Some(geo::point!(x: 5., y: 15.)),
+        ]
+        .into();
+        let result = index.query_points(&points);
+        assert_eq!(
+            result.iter().collect::<Vec<_>>(),
+            vec![Some(0), None, None, Some(3)]
+        );
+    }
+
+    #[test]
+    fn matches_contains() {
+        let zones = zones();
+        let array: MultiPolygonArray<i32> = zones.as_slice().into();
+        let index = PointInPolygonIndex::try_from(&array).unwrap();
+
+        // Offset the grid so that no point is on a boundary
+        for i in 0..100 {
+            for j in 0..100 {
+                let point = geo::point!(x: i as f64 * 0.25 + 0.01, y: j as f64 * 0.25 + 0.01);
+                let expected = zones.iter().position(|zone| zone.contains(&point));
+                assert_eq!(
+                    index.query_point(point.x(), point.y()),
+                    expected,
+                    "{:?}",
+                    point
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn null_and_empty_polygons() {
+        let square = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 0.)];
+        let array: PolygonArray<i32> = vec![None, Some(square)].into();
+        let index = PointInPolygonIndex::try_from(&array).unwrap();
+        assert_eq!(index.num_polygons(), 1);
+        assert_eq!(index.query_point(0.9, 0.1), Some(1));
+
+        let array: PolygonArray<i32> = vec![None::<geo::Polygon>].into();
+        let index = PointInPolygonIndex::try_from(&array).unwrap();
+        assert_eq!(index.query_point(0.5, 0.5), None);
+    }
+}
@@ -1,7 +1,7 @@
 //! Read from and write to CSV files.
 
 pub use reader::{read_csv, CSVReaderOptions};
-pub use writer::write_csv;
+pub use writer::{write_csv, CSVWriterOptions};
 
 mod reader;
 mod writer;
@@ -9,6 +9,8 @@ use crate::io::geozero::table::{GeoTableBuilder, GeoTableBuilderOptions};
 use crate::table::GeoTable;
 
 /// Options for the CSV reader.
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
 pub struct CSVReaderOptions {
     /// The GeoArrow coordinate type to use in the geometry arrays.
     pub coord_type: CoordType,
@@ -18,17 +20,33 @@ pub struct CSVReaderOptions {
 }
 
 impl CSVReaderOptions {
+    #[deprecated(note = "Use `CSVReaderOptions::default()` and the `with_*` methods instead")]
     pub fn new(coord_type: CoordType, batch_size: usize) -> Self {
         Self {
             coord_type,
             batch_size,
         }
     }
+
+    /// Set the GeoArrow coordinate type to use in the geometry arrays.
+    pub fn with_coord_type(mut self, coord_type: CoordType) -> Self {
+        self.coord_type = coord_type;
+        self
+    }
+
+    /// Set the number of rows in each batch.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
 }
 
 impl Default for CSVReaderOptions {
     fn default() -> Self {
-        Self::new(Default::default(), 65_536)
+        Self {
+            coord_type: Default::default(),
+            batch_size: 65_536,
+        }
     }
 }
 
@@ -52,3 +70,23 @@ pub fn read_csv<R: Read>(
     csv.process(&mut geo_table)?;
     geo_table.finish()
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::io::csv::write_csv;
+    use crate::test::point;
+
+    #[test]
+    fn test_round_trip() {
+        let mut output_buffer = Vec::new();
+        write_csv(&point::table(), &mut output_buffer, Default::default()).unwrap();
+
+        let options = CSVReaderOptions::default()
+            .with_coord_type(CoordType::Separated)
+            .with_batch_size(2);
+        let table = read_csv(output_buffer.as_slice(), "geometry", options).unwrap();
+        assert_eq!(table.len(), 3);
+        assert_eq!(table.batches().len(), 2);
+    }
+}
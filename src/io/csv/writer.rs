@@ -1,30 +1,220 @@
-use crate::error::Result;
-use crate::table::GeoTable;
-use geozero::csv::CsvWriter;
-use geozero::GeozeroDatasource;
 use std::io::Write;
+use std::sync::Arc;
+
+use arrow_array::{Array, ArrayRef, RecordBatch};
+use arrow_csv::WriterBuilder;
+use arrow_schema::{DataType, Field, Schema};
+
+use crate::array::from_arrow_array;
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::io::wkt::writer::geometry_array_to_wkt;
+use crate::table::GeoTable;
+
+/// Options for the CSV writer.
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct CSVWriterOptions {
+    /// The name of the WKT column written for the primary geometry column of the table. If
+    /// `None`, the name of the geometry field is used.
+    pub geometry_column_name: Option<String>,
+
+    /// The number of digits after the decimal point of WKT coordinates. If `None`, each
+    /// coordinate is written with the fewest digits that round-trip.
+    pub coordinate_precision: Option<usize>,
+
+    /// The names of the geometry columns to write as WKT. If `None`, every geometry column is
+    /// written as its own WKT column.
+    ///
+    /// Geometry columns that are not selected are left out of the output.
+    pub geometry_columns: Option<Vec<String>>,
+}
+
+impl CSVWriterOptions {
+    /// Set the name of the WKT column written for the primary geometry column.
+    pub fn with_geometry_column_name(mut self, geometry_column_name: impl Into<String>) -> Self {
+        self.geometry_column_name = Some(geometry_column_name.into());
+        self
+    }
+
+    /// Set the number of digits after the decimal point of WKT coordinates.
+    pub fn with_coordinate_precision(mut self, coordinate_precision: usize) -> Self {
+        self.coordinate_precision = Some(coordinate_precision);
+        self
+    }
+
+    /// Only write the geometry columns with these names.
+    pub fn with_geometry_columns(
+        mut self,
+        geometry_columns: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.geometry_columns = Some(geometry_columns.into_iter().map(Into::into).collect());
+        self
+    }
+}
+
+/// Write a GeoTable to CSV.
+///
+/// Attribute columns are written with the Arrow CSV writer, and geometry columns are written as
+/// WKT text. Null geometries are written as empty cells. Record batches are written one at a time
+/// after a single header row.
+pub fn write_csv<W: Write>(table: &GeoTable, writer: W, options: CSVWriterOptions) -> Result<()> {
+    let schema = table.schema();
+    let geometry_columns = select_geometry_columns(table, &options)?;
+
+    let mut output_fields = Vec::with_capacity(schema.fields().len());
+    for (field_idx, field) in schema.fields().iter().enumerate() {
+        match geometry_columns[field_idx] {
+            Some(true) => {
+                let name = match &options.geometry_column_name {
+                    Some(name) if field_idx == table.geometry_column_index() => name.as_str(),
+                    _ => field.name().as_str(),
+                };
+                output_fields.push(Arc::new(Field::new(name, DataType::Utf8, true)));
+            }
+            Some(false) => continue,
+            None => output_fields.push(field.clone()),
+        }
+    }
+    let output_schema = Arc::new(Schema::new(output_fields));
 
-/// Write a GeoTable to CSV
-pub fn write_csv<W: Write>(table: &mut GeoTable, writer: W) -> Result<()> {
-    let mut csv_writer = CsvWriter::new(writer);
-    table.process(&mut csv_writer)?;
+    let mut csv_writer = WriterBuilder::new().with_header(true).build(writer);
+    for batch in table.batches() {
+        let mut columns: Vec<ArrayRef> = Vec::with_capacity(output_schema.fields().len());
+        for (field_idx, field) in schema.fields().iter().enumerate() {
+            let array = batch.column(field_idx);
+            match geometry_columns[field_idx] {
+                Some(true) => columns.push(wkt_column(
+                    array.as_ref(),
+                    field,
+                    options.coordinate_precision,
+                )?),
+                Some(false) => continue,
+                None => columns.push(array.clone()),
+            }
+        }
+        csv_writer.write(&RecordBatch::try_new(output_schema.clone(), columns)?)?;
+    }
     Ok(())
 }
 
+/// For each column index, whether the column is a geometry column that is written (`Some(true)`),
+/// a geometry column that is left out (`Some(false)`), or an attribute column (`None`).
+fn select_geometry_columns(
+    table: &GeoTable,
+    options: &CSVWriterOptions,
+) -> Result<Vec<Option<bool>>> {
+    let schema = table.schema();
+    let mut geometry_columns = schema
+        .fields()
+        .iter()
+        .enumerate()
+        .map(|(field_idx, field)| {
+            let is_geometry = field_idx == table.geometry_column_index()
+                || (field.metadata().contains_key("ARROW:extension:name")
+                    && GeoDataType::try_from(field.as_ref()).is_ok());
+            is_geometry.then_some(true)
+        })
+        .collect::<Vec<_>>();
+
+    if let Some(selected_names) = &options.geometry_columns {
+        for selected in geometry_columns.iter_mut().flatten() {
+            *selected = false;
+        }
+        for name in selected_names {
+            let field_idx = schema.index_of(name)?;
+            match &mut geometry_columns[field_idx] {
+                Some(selected) => *selected = true,
+                None => {
+                    return Err(GeoArrowError::General(format!(
+                        "Column {} is not a geometry column",
+                        name
+                    )))
+                }
+            }
+        }
+    }
+
+    Ok(geometry_columns)
+}
+
+/// Serialize a geometry column chunk as WKT strings, with a null for each null geometry.
+fn wkt_column(array: &dyn Array, field: &Field, precision: Option<usize>) -> Result<ArrayRef> {
+    let geometry_array = from_arrow_array(array, field)?;
+    let wkt_array = geometry_array_to_wkt::<i32>(geometry_array.as_ref(), precision)?;
+    Ok(Arc::new(wkt_array))
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::test::point;
-    use std::io::BufWriter;
+    use crate::GeometryArrayTrait;
+
+    fn write_to_string(table: &GeoTable, options: CSVWriterOptions) -> String {
+        let mut output_buffer = Vec::new();
+        write_csv(table, &mut output_buffer, options).unwrap();
+        String::from_utf8(output_buffer).unwrap()
+    }
 
     #[test]
     fn test_write() {
-        let mut table = point::table();
+        let table = point::table();
+        let output = write_to_string(&table, Default::default());
+        let lines = output.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], "u8,string,geometry");
+        assert_eq!(lines[1], "1,foo,POINT (0 1)");
+        assert_eq!(lines.len(), 4);
+    }
+
+    #[test]
+    fn test_write_options() {
+        let point_array: crate::array::PointArray = vec![
+            Some(geo::point!(x: 0.123456, y: 1.)),
+            None,
+            Some(geo::point!(x: 2., y: 3.987654)),
+        ]
+        .into();
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            point_array.extension_field().as_ref().clone(),
+            point_array
+                .extension_field()
+                .as_ref()
+                .clone()
+                .with_name("other_geometry"),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(arrow_array::Int32Array::from(vec![0, 1, 2])),
+                point_array.clone().into_array_ref(),
+                point_array.into_array_ref(),
+            ],
+        )
+        .unwrap();
+        // Two batches share a single header row
+        let table = GeoTable::try_new(schema, vec![batch.clone(), batch], 1).unwrap();
+
+        let options = CSVWriterOptions::default()
+            .with_geometry_column_name("wkt")
+            .with_coordinate_precision(2);
+        let output = write_to_string(&table, options);
+        let lines = output.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 7);
+        assert_eq!(lines[0], "id,wkt,other_geometry");
+        assert_eq!(lines[1], "0,POINT (0.12 1.00),POINT (0.12 1.00)");
+        assert_eq!(lines[2], "1,,");
+        assert_eq!(lines[4], "0,POINT (0.12 1.00),POINT (0.12 1.00)");
+
+        let options = CSVWriterOptions::default().with_geometry_columns(["other_geometry"]);
+        let output = write_to_string(&table, options);
+        let lines = output.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], "id,other_geometry");
+        assert_eq!(lines[3], "2,POINT (2 3.987654)");
 
+        let options = CSVWriterOptions::default().with_geometry_columns(["id"]);
         let mut output_buffer = Vec::new();
-        let writer = BufWriter::new(&mut output_buffer);
-        write_csv(&mut table, writer).unwrap();
-        let output_string = String::from_utf8(output_buffer).unwrap();
-        println!("{}", output_string);
+        assert!(write_csv(&table, &mut output_buffer, options).is_err());
     }
 }
@@ -0,0 +1,576 @@
+//! Read and write in-memory bytes, detecting the file format of the input.
+//!
+//! [`read_auto`] sniffs the first bytes of its input to choose a reader, which is convenient when
+//! the bytes come from a network response whose format isn't known ahead of time. Formats whose
+//! reader is behind a disabled feature are detected but return an error naming the feature.
+//!
+//! [`Format::from_extension`] and the [`FromStr`] implementation of [`Format`] choose a format
+//! from a file name or a driver name, for reading and writing files.
+
+use std::fmt::Display;
+use std::io::Cursor;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use arrow_array::{BinaryArray, RecordBatch};
+use arrow_schema::Schema;
+
+use crate::array::WKBArray;
+use crate::error::{GeoArrowError, Result};
+use crate::io::ipc::{read_ipc, read_ipc_stream, write_ipc, write_ipc_stream};
+use crate::io::wkb::to_wkb;
+use crate::table::GeoTable;
+use crate::GeometryArrayTrait;
+
+/// The number of leading bytes included in the error for inputs of an unknown format.
+const HEX_DUMP_LEN: usize = 16;
+
+/// A file format that can be read by [`read_auto`] and written by [`write_auto`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Format {
+    /// A GeoJSON FeatureCollection.
+    GeoJson,
+
+    /// Newline-delimited GeoJSON features.
+    GeoJsonLines,
+
+    /// A FlatGeobuf file.
+    FlatGeobuf,
+
+    /// A GeoParquet file.
+    GeoParquet,
+
+    /// An Arrow IPC file.
+    Ipc,
+
+    /// An Arrow IPC stream.
+    IpcStream,
+
+    /// A single WKB geometry, read as a table with one row.
+    Wkb,
+
+    /// A CSV file with a WKT geometry column named `geometry`.
+    Csv,
+}
+
+impl Format {
+    /// Choose a format from the extension of a path or URL, ignoring case and any query string.
+    ///
+    /// Returns `None` if the path has no extension, so that the format can be detected from the
+    /// content instead, and an error for extensions of unsupported formats, such as GeoPackage
+    /// and zipped Shapefiles.
+    pub fn from_extension(path: &str) -> Result<Option<Self>> {
+        let path = path.split(['?', '#']).next().unwrap_or(path);
+        let file_name = path.rsplit(['/', '\\']).next().unwrap_or(path);
+        let Some((_, extension)) = file_name.rsplit_once('.') else {
+            return Ok(None);
+        };
+
+        let format = match extension.to_ascii_lowercase().as_str() {
+            "geojson" | "json" => Format::GeoJson,
+            "geojsonl" | "geojsons" | "geojsonseq" | "jsonl" | "ndjson" => Format::GeoJsonLines,
+            "fgb" => Format::FlatGeobuf,
+            "parquet" | "geoparquet" => Format::GeoParquet,
+            "arrow" | "feather" | "ipc" => Format::Ipc,
+            "arrows" => Format::IpcStream,
+            "wkb" => Format::Wkb,
+            "csv" => Format::Csv,
+            "gpkg" => {
+                return Err(GeoArrowError::NotYetImplemented(
+                    "Reading GeoPackage files is not supported".to_string(),
+                ))
+            }
+            "shp" | "zip" => {
+                return Err(GeoArrowError::NotYetImplemented(
+                    "Reading Shapefiles and zip archives is not supported".to_string(),
+                ))
+            }
+            _ => {
+                return Err(GeoArrowError::General(format!(
+                    "Unsupported file extension '.{}'. Supported extensions are .geojson, .json, \
+                     .geojsonl, .geojsonseq, .jsonl, .ndjson, .fgb, .parquet, .arrow, .feather, \
+                     .arrows, .wkb and .csv",
+                    extension
+                )))
+            }
+        };
+        Ok(Some(format))
+    }
+
+    /// Whether the reader of this format can skip data outside of the bbox of
+    /// [`AutoReaderOptions`]. Other formats ignore the bbox.
+    pub fn supports_bbox(&self) -> bool {
+        matches!(self, Format::FlatGeobuf | Format::GeoParquet)
+    }
+}
+
+/// Parse a format from its name, ignoring case, as used for the driver of a file to write.
+///
+/// Besides the names of the variants, the GDAL driver names `GeoJSONSeq`, `Parquet` and `Arrow`
+/// are accepted, as are the file extensions accepted by [`Format::from_extension`].
+impl FromStr for Format {
+    type Err = GeoArrowError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let format = match s.to_ascii_lowercase().as_str() {
+            "geojson" | "json" => Format::GeoJson,
+            "geojsonlines" | "geojsonseq" | "geojsonl" | "geojsons" | "jsonl" | "ndjson" => {
+                Format::GeoJsonLines
+            }
+            "flatgeobuf" | "fgb" => Format::FlatGeobuf,
+            "geoparquet" | "parquet" => Format::GeoParquet,
+            "ipc" | "arrow" | "feather" => Format::Ipc,
+            "ipcstream" | "arrows" => Format::IpcStream,
+            "wkb" => Format::Wkb,
+            "csv" => Format::Csv,
+            _ => {
+                return Err(GeoArrowError::General(format!(
+                    "Unknown format '{}'. Supported formats are GeoJSON, GeoJSONSeq, FlatGeobuf, \
+                     Parquet, Arrow, IpcStream, WKB and CSV",
+                    s
+                )))
+            }
+        };
+        Ok(format)
+    }
+}
+
+/// Options for [`read_auto_with_options`].
+#[derive(Debug, Clone, Default)]
+pub struct AutoReaderOptions {
+    /// A spatial filter for reading rows, as `[xmin, ymin, xmax, ymax]`.
+    ///
+    /// This is passed to the FlatGeobuf and GeoParquet readers, and ignored by other formats. See
+    /// [`Format::supports_bbox`].
+    pub bbox: Option<[f64; 4]>,
+
+    /// The names of the columns to keep, in order. The geometry column is always kept, at the end
+    /// if it isn't listed. If `None`, all columns are kept.
+    pub columns: Option<Vec<String>>,
+}
+
+impl AutoReaderOptions {
+    pub fn with_bbox(self, bbox: Option<[f64; 4]>) -> Self {
+        Self { bbox, ..self }
+    }
+
+    pub fn with_columns(self, columns: Option<Vec<String>>) -> Self {
+        Self { columns, ..self }
+    }
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Format::GeoJson => "GeoJSON",
+            Format::GeoJsonLines => "GeoJSON Lines",
+            Format::FlatGeobuf => "FlatGeobuf",
+            Format::GeoParquet => "GeoParquet",
+            Format::Ipc => "Arrow IPC file",
+            Format::IpcStream => "Arrow IPC stream",
+            Format::Wkb => "WKB",
+            Format::Csv => "CSV",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Detect the format of `bytes` and read them to a GeoTable, returning the format that was read.
+///
+/// Formats with a magic number (GeoParquet, FlatGeobuf and Arrow IPC) are always detected from
+/// it. Otherwise `hint` is used if given, and if not, input starting with `{` is read as GeoJSON,
+/// or as GeoJSON Lines if its first line is a whole object followed by more lines, and input
+/// starting with a WKB header is read as a single WKB geometry.
+///
+/// Input of an unknown format is an error, which includes the first bytes of the input. So are
+/// zip archives, such as zipped Shapefiles, which aren't supported.
+pub fn read_auto(bytes: &[u8], hint: Option<Format>) -> Result<(Format, GeoTable)> {
+    read_auto_with_options(bytes, hint, Default::default())
+}
+
+/// Detect the format of `bytes` as in [`read_auto`] and read them to a GeoTable, with a spatial
+/// filter and column projection.
+pub fn read_auto_with_options(
+    bytes: &[u8],
+    hint: Option<Format>,
+    options: AutoReaderOptions,
+) -> Result<(Format, GeoTable)> {
+    let format = match (sniff_magic(bytes)?, hint) {
+        (Some(format), _) | (None, Some(format)) => format,
+        (None, None) => sniff_content(bytes).ok_or_else(|| unknown_format(bytes))?,
+    };
+    let table = read_format(bytes, format, options.bbox)?;
+    let table = match options.columns {
+        Some(columns) => select_columns(&table, &columns)?,
+        None => table,
+    };
+    Ok((format, table))
+}
+
+/// Write a GeoTable to `output` in the given format.
+///
+/// [`Format::Wkb`] can only be written for a table with exactly one row.
+pub fn write_auto(table: &GeoTable, format: Format, output: &mut Vec<u8>) -> Result<()> {
+    match format {
+        Format::Ipc => write_ipc(&mut table.clone(), output),
+        Format::IpcStream => write_ipc_stream(&mut table.clone(), output),
+        Format::Wkb => write_wkb(table, output),
+        #[cfg(feature = "geozero")]
+        Format::GeoJson => crate::io::geojson::write_geojson(table, output, Default::default()),
+        #[cfg(feature = "geozero")]
+        Format::GeoJsonLines => crate::io::geojson_lines::write_geojson_lines(table, output),
+        #[cfg(feature = "flatgeobuf")]
+        Format::FlatGeobuf => {
+            crate::io::flatgeobuf::write_flatgeobuf(table, output, Default::default())
+        }
+        #[cfg(feature = "parquet")]
+        Format::GeoParquet => {
+            crate::io::parquet::write_geoparquet(table, output, &Default::default())
+        }
+        #[cfg(feature = "csv")]
+        Format::Csv => crate::io::csv::write_csv(table, output, Default::default()),
+        #[cfg(not(feature = "geozero"))]
+        Format::GeoJson | Format::GeoJsonLines => Err(missing_feature(format, "geozero")),
+        #[cfg(not(feature = "flatgeobuf"))]
+        Format::FlatGeobuf => Err(missing_feature(format, "flatgeobuf")),
+        #[cfg(not(feature = "parquet"))]
+        Format::GeoParquet => Err(missing_feature(format, "parquet")),
+        #[cfg(not(feature = "csv"))]
+        Format::Csv => Err(missing_feature(format, "csv")),
+    }
+}
+
+#[cfg_attr(
+    not(any(feature = "flatgeobuf", feature = "parquet")),
+    allow(unused_variables)
+)]
+fn read_format(bytes: &[u8], format: Format, bbox: Option<[f64; 4]>) -> Result<GeoTable> {
+    match format {
+        Format::Ipc => read_ipc(Cursor::new(bytes)),
+        Format::IpcStream => read_ipc_stream(bytes),
+        Format::Wkb => read_wkb(bytes),
+        #[cfg(feature = "geozero")]
+        Format::GeoJson => crate::io::geojson::read_geojson(bytes, None),
+        #[cfg(feature = "geozero")]
+        Format::GeoJsonLines => {
+            crate::io::geojson_lines::read_geojson_lines(bytes, Default::default())
+        }
+        #[cfg(feature = "flatgeobuf")]
+        Format::FlatGeobuf => {
+            let options = crate::io::flatgeobuf::FlatGeobufReaderOptions {
+                bbox: bbox.map(|[xmin, ymin, xmax, ymax]| (xmin, ymin, xmax, ymax)),
+                ..Default::default()
+            };
+            crate::io::flatgeobuf::read_flatgeobuf(&mut Cursor::new(bytes), options)
+        }
+        #[cfg(feature = "parquet")]
+        Format::GeoParquet => {
+            let options = crate::io::parquet::GeoParquetReaderOptions {
+                bbox,
+                ..Default::default()
+            };
+            crate::io::parquet::read_geoparquet(bytes::Bytes::copy_from_slice(bytes), options)
+        }
+        #[cfg(feature = "csv")]
+        Format::Csv => crate::io::csv::read_csv(bytes, "geometry", Default::default()),
+        #[cfg(not(feature = "geozero"))]
+        Format::GeoJson | Format::GeoJsonLines => Err(missing_feature(format, "geozero")),
+        #[cfg(not(feature = "flatgeobuf"))]
+        Format::FlatGeobuf => Err(missing_feature(format, "flatgeobuf")),
+        #[cfg(not(feature = "parquet"))]
+        Format::GeoParquet => Err(missing_feature(format, "parquet")),
+        #[cfg(not(feature = "csv"))]
+        Format::Csv => Err(missing_feature(format, "csv")),
+    }
+}
+
+/// Keep only the named columns of a table, and its geometry column.
+fn select_columns(table: &GeoTable, columns: &[String]) -> Result<GeoTable> {
+    let schema = table.schema();
+    let mut indices = columns
+        .iter()
+        .map(|name| schema.index_of(name))
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    let geometry_column_index = match indices
+        .iter()
+        .position(|index| *index == table.geometry_column_index())
+    {
+        Some(position) => position,
+        None => {
+            indices.push(table.geometry_column_index());
+            indices.len() - 1
+        }
+    };
+
+    let batches = table
+        .batches()
+        .iter()
+        .map(|batch| batch.project(&indices))
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    GeoTable::try_new(
+        Arc::new(schema.project(&indices)?),
+        batches,
+        geometry_column_index,
+    )
+}
+
+/// Detect a format from its magic number.
+fn sniff_magic(bytes: &[u8]) -> Result<Option<Format>> {
+    let format = if bytes.starts_with(b"PAR1") {
+        Format::GeoParquet
+    } else if bytes.starts_with(b"fgb\x03") {
+        Format::FlatGeobuf
+    } else if bytes.starts_with(b"ARROW1") {
+        Format::Ipc
+    } else if bytes.starts_with(&[0xff, 0xff, 0xff, 0xff]) {
+        // The continuation marker that starts each message of an IPC stream
+        Format::IpcStream
+    } else if bytes.starts_with(b"PK\x03\x04") {
+        return Err(GeoArrowError::NotYetImplemented(
+            "Reading zip archives, such as zipped Shapefiles, is not supported".to_string(),
+        ));
+    } else {
+        return Ok(None);
+    };
+    Ok(Some(format))
+}
+
+/// Detect a format without a magic number from its content.
+fn sniff_content(bytes: &[u8]) -> Option<Format> {
+    let text = bytes.strip_prefix(b"\xef\xbb\xbf").unwrap_or(bytes);
+    let start = text.iter().position(|byte| !byte.is_ascii_whitespace());
+    if start.is_some_and(|start| text[start] == b'{') {
+        return Some(if is_geojson_lines(text) {
+            Format::GeoJsonLines
+        } else {
+            Format::GeoJson
+        });
+    }
+
+    if is_wkb(bytes) {
+        return Some(Format::Wkb);
+    }
+
+    None
+}
+
+/// Whether the first non-empty line is a complete JSON value, followed by another non-empty line.
+fn is_geojson_lines(text: &[u8]) -> bool {
+    let mut lines = text
+        .split(|byte| *byte == b'\n')
+        .filter(|line| !line.iter().all(u8::is_ascii_whitespace));
+    match (lines.next(), lines.next()) {
+        (Some(first), Some(_)) => serde_json::from_slice::<serde_json::Value>(first).is_ok(),
+        _ => false,
+    }
+}
+
+/// Whether the bytes start with a WKB byte order and a known geometry type.
+fn is_wkb(bytes: &[u8]) -> bool {
+    if bytes.len() < 9 {
+        return false;
+    }
+    let type_bytes = [bytes[1], bytes[2], bytes[3], bytes[4]];
+    let geometry_type = match bytes[0] {
+        0 => u32::from_be_bytes(type_bytes),
+        1 => u32::from_le_bytes(type_bytes),
+        _ => return false,
+    };
+
+    // EWKB flags the dimensions and SRID in the high bits, and ISO WKB adds 1000, 2000 or 3000
+    // for Z, M and ZM.
+    let geometry_type = geometry_type & 0x0fff_ffff;
+    (1..=7).contains(&(geometry_type % 1000)) && geometry_type / 1000 <= 3
+}
+
+fn read_wkb(bytes: &[u8]) -> Result<GeoTable> {
+    let wkb: WKBArray<i32> = BinaryArray::from_vec(vec![bytes]).into();
+    let schema = Arc::new(Schema::new(vec![wkb.extension_field()]));
+    let batch = RecordBatch::try_new(schema.clone(), vec![wkb.into_array_ref()])?;
+    GeoTable::from_arrow(vec![batch], schema, Some(0), None)
+}
+
+fn write_wkb(table: &GeoTable, output: &mut Vec<u8>) -> Result<()> {
+    if table.len() != 1 {
+        return Err(GeoArrowError::General(format!(
+            "Writing WKB requires a table with exactly one row, got {} rows",
+            table.len()
+        )));
+    }
+
+    let geometry = table.geometry()?;
+    let chunk = geometry
+        .geometry_chunks()
+        .into_iter()
+        .find(|chunk| chunk.len() == 1)
+        .unwrap();
+    let wkb = to_wkb::<i32>(chunk.as_ref());
+    let value = wkb.into_inner();
+    if value.is_null(0) {
+        return Err(GeoArrowError::General(
+            "Cannot write a null geometry as WKB".to_string(),
+        ));
+    }
+    output.extend_from_slice(value.value(0));
+    Ok(())
+}
+
+#[cfg(not(all(
+    feature = "csv",
+    feature = "geozero",
+    feature = "flatgeobuf",
+    feature = "parquet"
+)))]
+fn missing_feature(format: Format, feature: &str) -> GeoArrowError {
+    GeoArrowError::General(format!(
+        "{} support requires the {} feature",
+        format, feature
+    ))
+}
+
+fn unknown_format(bytes: &[u8]) -> GeoArrowError {
+    let hex = bytes
+        .iter()
+        .take(HEX_DUMP_LEN)
+        .map(|byte| format!("{:02x}", byte))
+        .collect::<Vec<_>>()
+        .join(" ");
+    GeoArrowError::General(format!(
+        "Could not detect the format of the input, which starts with: {}",
+        hex
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::point;
+
+    #[test]
+    fn ipc_round_trip() {
+        let table = point::table();
+        for format in [Format::Ipc, Format::IpcStream] {
+            let mut bytes = vec![];
+            write_auto(&table, format, &mut bytes).unwrap();
+            let (detected, read) = read_auto(&bytes, None).unwrap();
+            assert_eq!(detected, format);
+            assert_eq!(read.len(), table.len());
+        }
+
+        let bytes = std::fs::read("fixtures/nybb.arrow").unwrap();
+        let (format, table) = read_auto(&bytes, Some(Format::GeoJson)).unwrap();
+        assert_eq!(format, Format::Ipc);
+        assert_eq!(table.len(), 5);
+    }
+
+    #[test]
+    fn wkb() {
+        let wkb = to_wkb::<i32>(&point::point_array().slice(0, 1));
+        let bytes = wkb.into_inner().value(0).to_vec();
+
+        let (format, table) = read_auto(&bytes, None).unwrap();
+        assert_eq!(format, Format::Wkb);
+        assert_eq!(table.len(), 1);
+
+        let mut written = vec![];
+        write_auto(&table, Format::Wkb, &mut written).unwrap();
+        assert_eq!(written, bytes);
+
+        let err = write_auto(&point::table(), Format::Wkb, &mut vec![]).unwrap_err();
+        assert!(err.to_string().contains("exactly one row"));
+    }
+
+    #[test]
+    fn unknown() {
+        let err = read_auto(b"hello, world", None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "General error: Could not detect the format of the input, which starts with: \
+             68 65 6c 6c 6f 2c 20 77 6f 72 6c 64"
+        );
+
+        let err = read_auto(b"PK\x03\x04rest", None).unwrap_err();
+        assert!(err.to_string().contains("zip"));
+    }
+
+    #[test]
+    fn format_names() {
+        let from_extension = Format::from_extension;
+        assert_eq!(
+            from_extension("roads.GeoJSON").unwrap(),
+            Some(Format::GeoJson)
+        );
+        assert_eq!(
+            from_extension("https://example.com/v1.2/countries.fgb?v=1.2").unwrap(),
+            Some(Format::FlatGeobuf)
+        );
+        assert_eq!(from_extension("data/roads").unwrap(), None);
+        let err = from_extension("data.gpkg").unwrap_err();
+        assert!(err.to_string().contains("GeoPackage"));
+        let err = from_extension("data.xyz").unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Unsupported file extension '.xyz'"));
+
+        assert_eq!("Parquet".parse::<Format>().unwrap(), Format::GeoParquet);
+        assert_eq!(
+            "GeoJSONSeq".parse::<Format>().unwrap(),
+            Format::GeoJsonLines
+        );
+        assert!("GPKG".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn column_projection() {
+        let mut bytes = vec![];
+        write_auto(&point::table(), Format::Ipc, &mut bytes).unwrap();
+
+        let options = AutoReaderOptions::default().with_columns(Some(vec!["string".to_string()]));
+        let (_, table) = read_auto_with_options(&bytes, None, options).unwrap();
+        assert_eq!(table.schema().fields().len(), 2);
+        assert_eq!(table.schema().field(0).name(), "string");
+        assert_eq!(table.geometry_column_index(), 1);
+        assert_eq!(table.len(), 3);
+
+        let options = AutoReaderOptions::default().with_columns(Some(vec!["missing".to_string()]));
+        assert!(read_auto_with_options(&bytes, None, options).is_err());
+    }
+
+    #[cfg(feature = "geozero")]
+    #[test]
+    fn geojson() {
+        let bytes = std::fs::read("fixtures/roads.geojson").unwrap();
+        let (format, table) = read_auto(&bytes, None).unwrap();
+        assert_eq!(format, Format::GeoJson);
+
+        let mut lines = vec![];
+        write_auto(&table, Format::GeoJsonLines, &mut lines).unwrap();
+        let (format, lines_table) = read_auto(&lines, None).unwrap();
+        assert_eq!(format, Format::GeoJsonLines);
+        assert_eq!(lines_table.len(), table.len());
+    }
+
+    #[cfg(feature = "flatgeobuf")]
+    #[test]
+    fn flatgeobuf() {
+        let bytes = std::fs::read("fixtures/flatgeobuf/countries.fgb").unwrap();
+        let (format, table) = read_auto(&bytes, None).unwrap();
+        assert_eq!(format, Format::FlatGeobuf);
+        assert!(!table.is_empty());
+
+        let options = AutoReaderOptions::default().with_bbox(Some([0., 40., 10., 50.]));
+        let (_, filtered) = read_auto_with_options(&bytes, None, options).unwrap();
+        assert!(!filtered.is_empty());
+        assert!(filtered.len() < table.len());
+    }
+
+    #[cfg(feature = "parquet")]
+    #[test]
+    fn geoparquet() {
+        let bytes = std::fs::read("fixtures/geoparquet/nybb.parquet").unwrap();
+        let (format, table) = read_auto(&bytes, None).unwrap();
+        assert_eq!(format, Format::GeoParquet);
+        assert_eq!(table.len(), 5);
+    }
+}
@@ -111,14 +111,14 @@ mod test {
     #[test]
     fn test_display_chunked_point_array() {
         let point_array = point::point_array();
-        let _chunked = ChunkedPointArray::new(vec![point_array; 3]);
+        let _chunked = ChunkedPointArray::new_unchecked(vec![point_array; 3]);
         // println!("{}", chunked);
     }
 
     #[test]
     fn test_display_chunked_point_array_large() {
         let point_array = point::point_array();
-        let _chunked = ChunkedPointArray::new(vec![point_array; 7]);
+        let _chunked = ChunkedPointArray::new_unchecked(vec![point_array; 7]);
         // println!("{}", chunked);
     }
 }
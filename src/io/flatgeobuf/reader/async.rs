@@ -8,12 +8,23 @@ use object_store::ObjectStore;
 use crate::algorithm::native::Downcast;
 use crate::array::*;
 use crate::error::{GeoArrowError, Result};
-use crate::io::flatgeobuf::reader::common::{infer_schema, FlatGeobufReaderOptions};
+use crate::io::flatgeobuf::reader::common::{infer_schema, parse_crs, FlatGeobufReaderOptions};
 use crate::io::flatgeobuf::reader::object_store_reader::ObjectStoreWrapper;
 use crate::io::geozero::array::MixedGeometryStreamBuilder;
 use crate::io::geozero::table::{GeoTableBuilder, GeoTableBuilderOptions};
 use crate::table::GeoTable;
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        skip_all,
+        fields(
+            num_bytes = tracing::field::Empty,
+            geometry_type = tracing::field::Empty,
+            features_count = tracing::field::Empty,
+        )
+    )
+)]
 pub async fn read_flatgeobuf_async<T: ObjectStore>(
     reader: T,
     location: Path,
@@ -21,6 +32,9 @@ pub async fn read_flatgeobuf_async<T: ObjectStore>(
 ) -> Result<GeoTable> {
     let head = reader.head(&location).await?;
 
+    #[cfg(feature = "tracing")]
+    tracing::Span::current().record("num_bytes", head.size);
+
     let object_store_wrapper = ObjectStoreWrapper {
         reader,
         location,
@@ -38,6 +52,7 @@ pub async fn read_flatgeobuf_async<T: ObjectStore>(
     }
 
     let schema = infer_schema(header);
+    let metadata = parse_crs(header);
     let geometry_type = header.geometry_type();
 
     let mut selection = if let Some((min_x, min_y, max_x, max_y)) = options.bbox {
@@ -48,14 +63,20 @@ pub async fn read_flatgeobuf_async<T: ObjectStore>(
 
     let features_count = selection.features_count();
 
-    // TODO: propagate CRS
+    #[cfg(feature = "tracing")]
+    {
+        let span = tracing::Span::current();
+        span.record("geometry_type", tracing::field::debug(geometry_type));
+        span.record("features_count", features_count);
+    }
+
     let options = GeoTableBuilderOptions::new(
         options.coord_type,
         true,
         options.batch_size,
         Some(Arc::new(schema.finish())),
         features_count,
-        Default::default(),
+        metadata,
     );
 
     match geometry_type {
@@ -96,7 +117,7 @@ pub async fn read_flatgeobuf_async<T: ObjectStore>(
                 GeoTableBuilder::<MixedGeometryStreamBuilder<i32>>::new_with_options(options);
             selection.process_features(&mut builder).await?;
             let table = builder.finish()?;
-            table.downcast(true)
+            table.downcast(true, false)
         }
         // TODO: Parse into a GeometryCollection array and then downcast to a single-typed array if possible.
         geom_type => Err(GeoArrowError::NotYetImplemented(format!(
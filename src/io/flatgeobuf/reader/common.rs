@@ -1,10 +1,15 @@
+use std::sync::Arc;
+
 use arrow_schema::{DataType, Field, SchemaBuilder, TimeUnit};
 use flatgeobuf::{ColumnType, Header};
+use serde_json::{json, Value};
 
+use crate::array::metadata::ArrayMetadata;
 use crate::array::CoordType;
 
 /// Options for the FlatGeobuf reader
 #[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
 pub struct FlatGeobufReaderOptions {
     /// The GeoArrow coordinate type to use in the geometry arrays.
     pub coord_type: CoordType,
@@ -28,6 +33,26 @@ impl Default for FlatGeobufReaderOptions {
     }
 }
 
+impl FlatGeobufReaderOptions {
+    /// Set the GeoArrow coordinate type to use in the geometry arrays.
+    pub fn with_coord_type(mut self, coord_type: CoordType) -> Self {
+        self.coord_type = coord_type;
+        self
+    }
+
+    /// Set the number of rows in each batch.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = Some(batch_size);
+        self
+    }
+
+    /// Only read features that intersect this bbox, as `(min_x, min_y, max_x, max_y)`.
+    pub fn with_bbox(mut self, bbox: (f64, f64, f64, f64)) -> Self {
+        self.bbox = Some(bbox);
+        self
+    }
+}
+
 pub(super) fn infer_schema(header: Header<'_>) -> SchemaBuilder {
     let columns = header.columns().unwrap();
     let mut schema = SchemaBuilder::with_capacity(columns.len());
@@ -62,3 +87,24 @@ pub(super) fn infer_schema(header: Header<'_>) -> SchemaBuilder {
 
     schema
 }
+
+/// Construct the GeoArrow array metadata from the CRS defined in the FlatGeobuf header.
+///
+/// When the header identifies the CRS by an authority code, the CRS is stored as a PROJJSON
+/// object with only the `id` member. Otherwise the WKT definition of the CRS, if any, is stored
+/// as a string.
+pub(super) fn parse_crs(header: Header<'_>) -> Arc<ArrayMetadata> {
+    let crs = header.crs().and_then(|crs| match (crs.org(), crs.code()) {
+        (Some(org), code) if code != 0 => Some(json!({
+            "id": {
+                "authority": org,
+                "code": code,
+            }
+        })),
+        _ => crs.wkt().map(|wkt| Value::String(wkt.to_string())),
+    });
+    Arc::new(ArrayMetadata {
+        crs,
+        ..Default::default()
+    })
+}
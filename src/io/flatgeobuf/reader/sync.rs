@@ -22,7 +22,7 @@
 use crate::algorithm::native::Downcast;
 use crate::array::*;
 use crate::error::{GeoArrowError, Result};
-use crate::io::flatgeobuf::reader::common::{infer_schema, FlatGeobufReaderOptions};
+use crate::io::flatgeobuf::reader::common::{infer_schema, parse_crs, FlatGeobufReaderOptions};
 use crate::io::geozero::array::MixedGeometryStreamBuilder;
 use crate::io::geozero::table::{GeoTableBuilder, GeoTableBuilderOptions};
 use crate::table::GeoTable;
@@ -31,6 +31,16 @@ use std::io::{Read, Seek};
 use std::sync::Arc;
 
 /// Read a FlatGeobuf file to a GeoTable
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        skip_all,
+        fields(
+            geometry_type = tracing::field::Empty,
+            features_count = tracing::field::Empty,
+        )
+    )
+)]
 pub fn read_flatgeobuf<R: Read + Seek>(
     file: &mut R,
     options: FlatGeobufReaderOptions,
@@ -45,6 +55,7 @@ pub fn read_flatgeobuf<R: Read + Seek>(
     }
 
     let schema = infer_schema(header);
+    let metadata = parse_crs(header);
     let geometry_type = header.geometry_type();
 
     let mut selection = if let Some((min_x, min_y, max_x, max_y)) = options.bbox {
@@ -55,14 +66,20 @@ pub fn read_flatgeobuf<R: Read + Seek>(
 
     let features_count = selection.features_count();
 
-    // TODO: propagate CRS
+    #[cfg(feature = "tracing")]
+    {
+        let span = tracing::Span::current();
+        span.record("geometry_type", tracing::field::debug(geometry_type));
+        span.record("features_count", features_count);
+    }
+
     let options = GeoTableBuilderOptions::new(
         options.coord_type,
         true,
         options.batch_size,
         Some(Arc::new(schema.finish())),
         features_count,
-        Default::default(),
+        metadata,
     );
 
     match geometry_type {
@@ -103,7 +120,7 @@ pub fn read_flatgeobuf<R: Read + Seek>(
                 GeoTableBuilder::<MixedGeometryStreamBuilder<i32>>::new_with_options(options);
             selection.process_features(&mut builder)?;
             let table = builder.finish()?;
-            table.downcast(true)
+            table.downcast(true, false)
         }
         // TODO: Parse into a GeometryCollection array and then downcast to a single-typed array if possible.
         geom_type => Err(GeoArrowError::NotYetImplemented(format!(
@@ -126,6 +143,38 @@ mod test {
         let _table = read_flatgeobuf(&mut filein, Default::default()).unwrap();
     }
 
+    #[test]
+    fn test_countries_bbox() {
+        let mut filein = BufReader::new(File::open("fixtures/flatgeobuf/countries.fgb").unwrap());
+        let full_table = read_flatgeobuf(&mut filein, Default::default()).unwrap();
+
+        let mut filein = BufReader::new(File::open("fixtures/flatgeobuf/countries.fgb").unwrap());
+        let options = FlatGeobufReaderOptions::default()
+            .with_bbox((0., -10., 10., 10.))
+            .with_batch_size(16);
+        let table = read_flatgeobuf(&mut filein, options).unwrap();
+        assert!(!table.is_empty());
+        assert!(table.len() < full_table.len());
+    }
+
+    #[test]
+    fn test_countries_crs() {
+        let mut filein = BufReader::new(File::open("fixtures/flatgeobuf/countries.fgb").unwrap());
+        let table = read_flatgeobuf(&mut filein, Default::default()).unwrap();
+        let geometry_field = table.schema().field(table.geometry_column_index());
+        let extension_metadata: serde_json::Value = serde_json::from_str(
+            geometry_field
+                .metadata()
+                .get("ARROW:extension:metadata")
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            extension_metadata["crs"]["id"],
+            serde_json::json!({"authority": "EPSG", "code": 4326})
+        );
+    }
+
     #[test]
     fn test_nz_buildings() {
         let mut filein = BufReader::new(
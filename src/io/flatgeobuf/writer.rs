@@ -1,56 +1,211 @@
 use std::io::Write;
 
-use flatgeobuf::{FgbWriter, FgbWriterOptions};
-use geozero::GeozeroDatasource;
+use arrow_schema::{DataType, Schema};
+use flatgeobuf::{ColumnType, FgbWriter, FgbWriterOptions, GeometryType};
 
-use crate::error::GeoArrowError;
+use crate::algorithm::native::Downcast;
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::io::geozero::table::process_geotable;
 use crate::table::GeoTable;
 
-// TODO: always write CRS saved in GeoTable metadata (you can do this by adding an option)
-/// Write a GeoTable to a FlatGeobuf file.
-pub fn write_flatgeobuf<W: Write>(
-    table: &mut GeoTable,
-    writer: W,
-    name: &str,
-) -> Result<(), GeoArrowError> {
-    write_flatgeobuf_with_options(table, writer, name, Default::default())
+/// Options for the FlatGeobuf writer.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct FlatGeobufWriterOptions {
+    /// The name of the dataset, which is what OGR observes as the layer name of the file.
+    pub name: String,
+
+    /// Whether to write a packed Hilbert R-tree spatial index. Files with a spatial index support
+    /// bounding box queries, such as with the `bbox` option of the reader.
+    pub write_index: bool,
+
+    /// A title for the dataset.
+    pub title: Option<String>,
+
+    /// A description of the dataset.
+    pub description: Option<String>,
+
+    /// Dataset metadata, intended to be a JSON string.
+    pub metadata: Option<String>,
+}
+
+impl Default for FlatGeobufWriterOptions {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            write_index: true,
+            title: None,
+            description: None,
+            metadata: None,
+        }
+    }
+}
+
+impl FlatGeobufWriterOptions {
+    /// Set the name of the dataset.
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Set whether to write a packed Hilbert R-tree spatial index.
+    pub fn with_write_index(mut self, write_index: bool) -> Self {
+        self.write_index = write_index;
+        self
+    }
+
+    /// Set a title for the dataset.
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set a description of the dataset.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set the dataset metadata, intended to be a JSON string.
+    pub fn with_metadata(mut self, metadata: impl Into<String>) -> Self {
+        self.metadata = Some(metadata.into());
+        self
+    }
 }
 
-/// Write a GeoTable to a FlatGeobuf file with specific writer options.
+// TODO: always write CRS saved in GeoTable metadata
+/// Write a GeoTable to a FlatGeobuf file.
+///
+/// Mixed geometry columns are downcasted to a single geometry type when possible, so that the
+/// header can declare a concrete geometry type. Features are streamed to the FlatGeobuf writer
+/// one record batch at a time.
 ///
-/// Note: this `name` argument is what OGR observes as the layer name of the file.
-pub fn write_flatgeobuf_with_options<W: Write>(
-    table: &mut GeoTable,
+/// All attribute columns are declared as nullable, and null attribute values are omitted from the
+/// feature. Null geometries are written as features without a geometry; because such features
+/// have no extent, they can't be written when `write_index` is set.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        skip_all,
+        fields(
+            layer_name = options.name.as_str(),
+            num_rows = table.len(),
+            num_batches = table.batches().len(),
+        )
+    )
+)]
+pub fn write_flatgeobuf<W: Write>(
+    table: &GeoTable,
     writer: W,
-    name: &str,
-    options: FgbWriterOptions,
-) -> Result<(), GeoArrowError> {
-    let mut fgb =
-        FgbWriter::create_with_options(name, infer_flatgeobuf_geometry_type(table), options)?;
-    table.process(&mut fgb)?;
+    options: FlatGeobufWriterOptions,
+) -> Result<()> {
+    let downcasted_table;
+    let table = match table.geometry_data_type()? {
+        GeoDataType::Mixed(_)
+        | GeoDataType::LargeMixed(_)
+        | GeoDataType::GeometryCollection(_)
+        | GeoDataType::LargeGeometryCollection(_) => {
+            downcasted_table = table.downcast(true, false)?;
+            &downcasted_table
+        }
+        _ => table,
+    };
+
+    if options.write_index && has_null_geometries(table) {
+        return Err(GeoArrowError::General(
+            "Cannot write a spatial index for a table with null geometries. Set `write_index` \
+             to false or remove the null geometries."
+                .to_string(),
+        ));
+    }
+
+    let fgb_options = FgbWriterOptions {
+        write_index: options.write_index,
+        title: options.title.as_deref(),
+        description: options.description.as_deref(),
+        metadata: options.metadata.as_deref(),
+        ..Default::default()
+    };
+    let geometry_type = infer_flatgeobuf_geometry_type(&table.geometry_data_type()?)?;
+    let mut fgb = FgbWriter::create_with_options(&options.name, geometry_type, fgb_options)?;
+    add_columns(&mut fgb, table.schema(), table.geometry_column_index())?;
+
+    process_geotable(table, &mut fgb)?;
     fgb.write(writer)?;
     Ok(())
 }
 
-fn infer_flatgeobuf_geometry_type(table: &GeoTable) -> flatgeobuf::GeometryType {
-    let fields = &table.schema().fields;
-    let geometry_field = &fields[table.geometry_column_index()];
-    if let Some(extension_name) = geometry_field.metadata().get("ARROW:extension:name") {
-        let geometry_type = match extension_name.as_str() {
-            "geoarrow.point" => flatgeobuf::GeometryType::Point,
-            "geoarrow.linestring" => flatgeobuf::GeometryType::LineString,
-            "geoarrow.polygon" => flatgeobuf::GeometryType::Polygon,
-            "geoarrow.multipoint" => flatgeobuf::GeometryType::MultiPoint,
-            "geoarrow.multilinestring" => flatgeobuf::GeometryType::MultiLineString,
-            "geoarrow.multipolygon" => flatgeobuf::GeometryType::MultiPolygon,
-            "geoarrow.geometry" => flatgeobuf::GeometryType::Unknown,
-            "geoarrow.geometrycollection" => flatgeobuf::GeometryType::GeometryCollection,
-            _ => todo!(),
+/// Declare a column for each attribute field up front, so that the header is complete even when
+/// the first feature has null values.
+fn add_columns(fgb: &mut FgbWriter, schema: &Schema, geometry_column_index: usize) -> Result<()> {
+    for (field_idx, field) in schema.fields().iter().enumerate() {
+        if field_idx == geometry_column_index {
+            continue;
+        }
+        let column_type = match field.data_type() {
+            DataType::Boolean => ColumnType::Bool,
+            DataType::Int8 => ColumnType::Byte,
+            DataType::UInt8 => ColumnType::UByte,
+            DataType::Int16 => ColumnType::Short,
+            DataType::UInt16 => ColumnType::UShort,
+            DataType::Int32 => ColumnType::Int,
+            DataType::UInt32 => ColumnType::UInt,
+            DataType::Int64 => ColumnType::Long,
+            DataType::UInt64 => ColumnType::ULong,
+            DataType::Float16 | DataType::Float32 => ColumnType::Float,
+            DataType::Float64 | DataType::Decimal128(_, _) | DataType::Decimal256(_, _) => {
+                ColumnType::Double
+            }
+            DataType::Utf8 | DataType::LargeUtf8 => ColumnType::String,
+            DataType::Binary | DataType::LargeBinary => ColumnType::Binary,
+            DataType::Date32
+            | DataType::Date64
+            | DataType::Timestamp(_, _)
+            | DataType::Time32(_)
+            | DataType::Time64(_) => ColumnType::DateTime,
+            dt => {
+                return Err(GeoArrowError::NotYetImplemented(format!(
+                    "Writing column {} with data type {} to FlatGeobuf is not yet supported",
+                    field.name(),
+                    dt
+                )))
+            }
         };
-        geometry_type
-    } else {
-        todo!()
+        fgb.add_column(field.name(), column_type, |_fbb, col| {
+            col.nullable = true;
+        });
     }
+    Ok(())
+}
+
+fn has_null_geometries(table: &GeoTable) -> bool {
+    let geometry_column_index = table.geometry_column_index();
+    table
+        .batches()
+        .iter()
+        .any(|batch| batch.column(geometry_column_index).null_count() > 0)
+}
+
+fn infer_flatgeobuf_geometry_type(data_type: &GeoDataType) -> Result<GeometryType> {
+    use GeoDataType::*;
+
+    let geometry_type = match data_type {
+        Point(_) => GeometryType::Point,
+        LineString(_) | LargeLineString(_) => GeometryType::LineString,
+        Polygon(_) | LargePolygon(_) => GeometryType::Polygon,
+        MultiPoint(_) | LargeMultiPoint(_) => GeometryType::MultiPoint,
+        MultiLineString(_) | LargeMultiLineString(_) => GeometryType::MultiLineString,
+        MultiPolygon(_) | LargeMultiPolygon(_) => GeometryType::MultiPolygon,
+        GeometryCollection(_) | LargeGeometryCollection(_) => GeometryType::GeometryCollection,
+        Mixed(_) | LargeMixed(_) | WKB | LargeWKB => GeometryType::Unknown,
+        Rect => {
+            return Err(GeoArrowError::NotYetImplemented(
+                "Writing Rect arrays to FlatGeobuf is not yet supported".to_string(),
+            ))
+        }
+    };
+    Ok(geometry_type)
 }
 
 #[cfg(test)]
@@ -59,23 +214,88 @@ mod test {
     use crate::io::flatgeobuf::read_flatgeobuf;
     use crate::test::point;
     use std::io::{BufWriter, Cursor};
+    use std::sync::Arc;
+
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::UInt8Type;
+    use arrow_array::{RecordBatch, StringArray, UInt8Array};
+    use arrow_schema::Field;
+
+    use crate::array::MixedGeometryArray;
+    use crate::GeometryArrayTrait;
 
     #[test]
     fn test_write() {
-        let mut table = point::table();
+        let table = point::table();
 
         let mut output_buffer = Vec::new();
         let writer = BufWriter::new(&mut output_buffer);
-        write_flatgeobuf(&mut table, writer, "name").unwrap();
+        write_flatgeobuf(&table, writer, Default::default()).unwrap();
 
         let mut reader = Cursor::new(output_buffer);
         let new_table = read_flatgeobuf(&mut reader, Default::default()).unwrap();
+        assert_eq!(new_table.len(), 3);
+        assert_eq!(
+            new_table.schema().field(0).data_type(),
+            &arrow_schema::DataType::UInt8
+        );
+        assert_eq!(
+            new_table.geometry_data_type().unwrap(),
+            GeoDataType::Point(Default::default())
+        );
+    }
+
+    #[test]
+    fn test_write_null_attributes() {
+        let point_array = point::point_array();
+        let fields = vec![
+            Arc::new(Field::new("u8", DataType::UInt8, true)),
+            Arc::new(Field::new("string", DataType::Utf8, true)),
+            point_array.extension_field(),
+        ];
+        let schema = Arc::new(Schema::new(fields));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(UInt8Array::from(vec![None, Some(2), Some(3)])),
+                Arc::new(StringArray::from(vec![None, Some("b"), None])),
+                point_array.into_array_ref(),
+            ],
+        )
+        .unwrap();
+        let table = GeoTable::try_new(schema, vec![batch], 2).unwrap();
 
-        // TODO: it looks like it's getting read back in backwards row order!
-        let batch = &new_table.batches()[0];
-        let arr = batch.column(0);
-        dbg!(arr);
-        dbg!(new_table);
-        // dbg!(output_buffer);
+        let mut output_buffer = Vec::new();
+        let options = FlatGeobufWriterOptions::default().with_write_index(false);
+        write_flatgeobuf(&table, &mut output_buffer, options).unwrap();
+
+        let mut reader = Cursor::new(output_buffer);
+        let new_table = read_flatgeobuf(&mut reader, Default::default()).unwrap();
+        assert_eq!(new_table.schema().fields().len(), 3);
+        let u8_col = new_table.batches()[0].column(0).as_primitive::<UInt8Type>();
+        assert_eq!(u8_col.null_count(), 1);
+        assert_eq!(u8_col.value(1), 2);
+    }
+
+    #[test]
+    fn test_write_downcasts_mixed() {
+        let geoms: Vec<geo::Geometry> =
+            vec![point::p0().into(), point::p1().into(), point::p2().into()];
+        let mixed_array = MixedGeometryArray::<i32>::try_from(geoms.as_slice()).unwrap();
+        let fields = vec![mixed_array.extension_field()];
+        let schema = Arc::new(Schema::new(fields));
+        let batch =
+            RecordBatch::try_new(schema.clone(), vec![mixed_array.into_array_ref()]).unwrap();
+        let table = GeoTable::try_new(schema, vec![batch], 0).unwrap();
+
+        let mut output_buffer = Vec::new();
+        write_flatgeobuf(&table, &mut output_buffer, Default::default()).unwrap();
+
+        let mut reader = Cursor::new(output_buffer);
+        let new_table = read_flatgeobuf(&mut reader, Default::default()).unwrap();
+        assert_eq!(
+            new_table.geometry_data_type().unwrap(),
+            GeoDataType::Point(Default::default())
+        );
     }
 }
@@ -1,7 +1,22 @@
 //! Read from and write to [GeoJSON](https://geojson.org/) files.
+//!
+//! [Newline-delimited GeoJSON](https://stevage.github.io/ndgeojson/) is also supported through
+//! [`read_geojson_lines`] and [`write_geojson_lines`].
+//!
+//! [`read_geojson_stream`] parses features while the input is still being read, such as from a
+//! slow HTTP response, and with the `http` feature, [`read_geojson_url`] fetches and reads a
+//! FeatureCollection from a URL.
 
-pub use reader::read_geojson;
-pub use writer::write_geojson;
+pub use crate::io::geojson_lines::{
+    read_geojson_lines, write_geojson_lines, GeoJsonLinesReaderOptions,
+};
+pub use reader::{read_geojson, read_geojson_geometries, GeoJsonReaderOptions};
+#[cfg(feature = "http")]
+pub use stream::read_geojson_url;
+pub use stream::read_geojson_stream;
+pub(crate) use writer::GeoJsonWriter;
+pub use writer::{write_geojson, write_geojson_geometries, GeoJsonWriterOptions};
 
 mod reader;
+mod stream;
 mod writer;
@@ -1,29 +1,260 @@
-use geozero::geojson::GeoJsonReader;
-use geozero::GeozeroDatasource;
+use arrow_schema::Schema;
+use geozero::geojson::{GeoJson, GeoJsonReader};
+use geozero::{GeozeroDatasource, ToGeo};
+use serde_json::Value;
+use std::collections::HashSet;
 use std::io::Read;
+use std::sync::Arc;
 
 use crate::array::CoordType;
-use crate::error::Result;
+use crate::chunked_array::{from_geoarrow_chunks, ChunkedGeometryArrayTrait};
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
 use crate::io::geozero::array::MixedGeometryStreamBuilder;
-use crate::io::geozero::table::{GeoTableBuilder, GeoTableBuilderOptions};
+use crate::io::geozero::table::{properties_map_field, GeoTableBuilder, GeoTableBuilderOptions};
+use crate::io::guards::{GuardCounter, ParseGuards};
+use crate::io::wkt::geometries_to_array;
 use crate::table::GeoTable;
 
+/// Options for the GeoJSON reader.
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct GeoJsonReaderOptions {
+    /// The GeoArrow coordinate type to use in the geometry arrays.
+    pub coord_type: CoordType,
+
+    /// The number of rows in each batch. If `None`, batches hold 65,536 rows.
+    pub batch_size: Option<usize>,
+
+    /// Limits on the size of the geometries read, for untrusted input. Unlimited by default.
+    pub parse_guards: ParseGuards,
+
+    /// Whether to store all properties of each feature in a single `Map<Utf8, Utf8>` column
+    /// named `properties`, instead of one column per property name. `false` by default.
+    ///
+    /// This keeps the schema compact for data with many sparse property names, such as
+    /// OpenStreetMap extracts. String values are stored as they are, and other values as their
+    /// JSON representation. Use [`GeoTable::map_column_to_columns`] to extract properties to
+    /// their own columns.
+    pub properties_as_map: bool,
+}
+
+impl Default for GeoJsonReaderOptions {
+    fn default() -> Self {
+        Self {
+            coord_type: CoordType::Interleaved,
+            batch_size: None,
+            parse_guards: Default::default(),
+            properties_as_map: false,
+        }
+    }
+}
+
+impl GeoJsonReaderOptions {
+    /// Set the GeoArrow coordinate type to use in the geometry arrays.
+    pub fn with_coord_type(mut self, coord_type: CoordType) -> Self {
+        self.coord_type = coord_type;
+        self
+    }
+
+    /// Set the number of rows in each batch.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = Some(batch_size);
+        self
+    }
+
+    /// Set the limits on the size of the geometries read.
+    pub fn with_parse_guards(mut self, parse_guards: ParseGuards) -> Self {
+        self.parse_guards = parse_guards;
+        self
+    }
+
+    /// Set whether to store all properties of each feature in a single map column.
+    pub fn with_properties_as_map(mut self, properties_as_map: bool) -> Self {
+        self.properties_as_map = properties_as_map;
+        self
+    }
+
+    /// The options of the table builder for these options.
+    pub(crate) fn table_builder_options(&self) -> GeoTableBuilderOptions {
+        let options = GeoTableBuilderOptions::new(
+            self.coord_type,
+            true,
+            self.batch_size,
+            None,
+            None,
+            Default::default(),
+        )
+        .with_parse_guards(self.parse_guards);
+        if self.properties_as_map {
+            options.with_properties_schema(Arc::new(Schema::new(vec![properties_map_field()])))
+        } else {
+            options
+        }
+    }
+}
+
+/// Reader options with the given batch size.
+///
+/// This keeps calls that pass a batch size to [`read_geojson`] directly compiling.
+impl From<Option<usize>> for GeoJsonReaderOptions {
+    fn from(batch_size: Option<usize>) -> Self {
+        Self {
+            batch_size,
+            ..Default::default()
+        }
+    }
+}
+
 /// Read a GeoJSON file to a GeoTable.
-pub fn read_geojson<R: Read>(reader: R, batch_size: Option<usize>) -> Result<GeoTable> {
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        skip_all,
+        fields(num_rows = tracing::field::Empty, num_batches = tracing::field::Empty)
+    )
+)]
+pub fn read_geojson<R: Read>(
+    reader: R,
+    options: impl Into<GeoJsonReaderOptions>,
+) -> Result<GeoTable> {
+    let options = options.into().table_builder_options();
     let mut geojson = GeoJsonReader(reader);
     // TODO: set CRS to epsg:4326?
-    let options = GeoTableBuilderOptions::new(
-        CoordType::Interleaved,
-        true,
-        batch_size,
-        None,
-        None,
-        Default::default(),
-    );
     let mut geo_table =
         GeoTableBuilder::<MixedGeometryStreamBuilder<i32>>::new_with_options(options);
-    geojson.process(&mut geo_table)?;
-    geo_table.finish()
+    geojson
+        .process(&mut geo_table)
+        .map_err(|err| geo_table.processing_error(err))?;
+    let table = geo_table.finish()?;
+
+    #[cfg(feature = "tracing")]
+    {
+        let span = tracing::Span::current();
+        span.record("num_rows", table.len());
+        span.record("num_batches", table.batches().len());
+    }
+
+    Ok(table)
+}
+
+/// Read a JSON array of GeoJSON geometry objects to a chunked geometry array.
+///
+/// A single bare geometry object is also accepted and read as an array of length one. Each `null`
+/// entry becomes a null geometry.
+///
+/// The geometries are stored in the simplest array type that can hold all of them, as if a mixed
+/// array were [downcast](crate::algorithm::native::Downcast): points and multi points are read
+/// to a MultiPoint array, while points and polygons are read to a Mixed array. Mixed arrays can't
+/// yet hold nulls, so geometries of different kinds are read to a GeometryCollection array if any
+/// entry is null.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(skip_all, fields(num_rows = tracing::field::Empty))
+)]
+pub fn read_geojson_geometries<R: Read>(
+    reader: R,
+    options: impl Into<GeoJsonReaderOptions>,
+) -> Result<Arc<dyn ChunkedGeometryArrayTrait>> {
+    let options = options.into();
+    let value: Value = serde_json::from_reader(reader)
+        .map_err(|err| GeoArrowError::General(format!("Failed to parse JSON: {}", err)))?;
+    let values = match value {
+        Value::Array(values) => values,
+        Value::Object(_) => vec![value],
+        _ => {
+            return Err(GeoArrowError::General(
+                "Expected a GeoJSON geometry or an array of GeoJSON geometries".to_string(),
+            ))
+        }
+    };
+
+    let mut guards = GuardCounter::new(options.parse_guards);
+    let geoms = values
+        .iter()
+        .enumerate()
+        .map(|(row, value)| match value {
+            Value::Null => Ok(None),
+            Value::Object(_) => {
+                let geom = GeoJson(&value.to_string()).to_geo().map_err(|err| {
+                    GeoArrowError::General(format!(
+                        "Failed to parse the GeoJSON geometry at row {}: {}",
+                        row, err
+                    ))
+                })?;
+                guards.add_geometry(row, &geom)?;
+                Ok(Some(geom))
+            }
+            _ => Err(GeoArrowError::General(format!(
+                "Expected a GeoJSON geometry or null at row {}",
+                row
+            ))),
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    #[cfg(feature = "tracing")]
+    tracing::Span::current().record("num_rows", geoms.len());
+
+    let data_type = geometries_data_type(&geoms, options.coord_type);
+    let batch_size = options.batch_size.unwrap_or(65_536);
+    let chunks = if geoms.is_empty() {
+        vec![geometries_to_array(&[], data_type)?]
+    } else {
+        geoms
+            .chunks(batch_size)
+            .map(|chunk| geometries_to_array(chunk, data_type))
+            .collect::<Result<Vec<_>>>()?
+    };
+    let chunk_refs = chunks
+        .iter()
+        .map(|chunk| chunk.as_ref())
+        .collect::<Vec<_>>();
+    from_geoarrow_chunks(&chunk_refs)
+}
+
+/// The simplest geometry type that can hold all of `geoms`.
+fn geometries_data_type(geoms: &[Option<geo::Geometry>], coord_type: CoordType) -> GeoDataType {
+    use GeoDataType::*;
+
+    let types = geoms
+        .iter()
+        .flatten()
+        .map(|geom| match geom {
+            geo::Geometry::Point(_) => Point(coord_type),
+            geo::Geometry::Line(_) | geo::Geometry::LineString(_) => LineString(coord_type),
+            geo::Geometry::Polygon(_) | geo::Geometry::Rect(_) | geo::Geometry::Triangle(_) => {
+                Polygon(coord_type)
+            }
+            geo::Geometry::MultiPoint(_) => MultiPoint(coord_type),
+            geo::Geometry::MultiLineString(_) => MultiLineString(coord_type),
+            geo::Geometry::MultiPolygon(_) => MultiPolygon(coord_type),
+            geo::Geometry::GeometryCollection(_) => GeometryCollection(coord_type),
+        })
+        .collect::<HashSet<_>>();
+
+    // An array of only nulls has no geometry type of its own
+    if types.is_empty() {
+        return Point(coord_type);
+    }
+    if types.len() == 1 {
+        return types.into_iter().next().unwrap();
+    }
+    for (single, multi) in [
+        (Point(coord_type), MultiPoint(coord_type)),
+        (LineString(coord_type), MultiLineString(coord_type)),
+        (Polygon(coord_type), MultiPolygon(coord_type)),
+    ] {
+        if types.iter().all(|t| *t == single || *t == multi) {
+            return multi;
+        }
+    }
+
+    let has_nulls = geoms.iter().any(Option::is_none);
+    if has_nulls || types.contains(&GeometryCollection(coord_type)) {
+        GeometryCollection(coord_type)
+    } else {
+        Mixed(coord_type)
+    }
 }
 
 #[cfg(test)]
@@ -32,6 +263,7 @@ mod test {
     use std::io::BufReader;
 
     use super::*;
+    use crate::GeometryArrayTrait;
 
     #[ignore = "non-vendored file"]
     #[test]
@@ -40,4 +272,190 @@ mod test {
         let mut filein = BufReader::new(File::open(path).unwrap());
         let _table = read_geojson(&mut filein, None).unwrap();
     }
+
+    #[test]
+    fn test_read_options() {
+        let geojson = r#"{"type": "FeatureCollection", "features": [
+            {"type": "Feature", "properties": {"a": 1}, "geometry": {"type": "Point", "coordinates": [0, 1]}},
+            {"type": "Feature", "properties": {"a": 2}, "geometry": {"type": "Point", "coordinates": [2, 3]}},
+            {"type": "Feature", "properties": {"a": 3}, "geometry": {"type": "Point", "coordinates": [4, 5]}}
+        ]}"#;
+        let options = GeoJsonReaderOptions::default()
+            .with_coord_type(CoordType::Separated)
+            .with_batch_size(2);
+        let table = read_geojson(geojson.as_bytes(), options).unwrap();
+        assert_eq!(table.len(), 3);
+        assert_eq!(table.batches().len(), 2);
+    }
+
+    #[test]
+    fn test_parse_guards() {
+        use crate::io::guards::ResourceLimit;
+
+        let geojson = r#"{"type": "FeatureCollection", "features": [
+            {"type": "Feature", "properties": {}, "geometry": {"type": "Point", "coordinates": [0, 1]}},
+            {"type": "Feature", "properties": {}, "geometry": {"type": "Point", "coordinates": [2, 3]}},
+            {"type": "Feature", "properties": {}, "geometry": {"type": "LineString", "coordinates": [[0, 1], [2, 3]]}}
+        ]}"#;
+        let guards = ParseGuards::default().with_max_coords_per_geometry(1);
+        let options = GeoJsonReaderOptions::default()
+            .with_batch_size(1)
+            .with_parse_guards(guards);
+        let err = read_geojson(geojson.as_bytes(), options).unwrap_err();
+        assert!(matches!(
+            err,
+            GeoArrowError::ResourceLimitExceeded {
+                limit: ResourceLimit::CoordsPerGeometry,
+                max: 1,
+                row: 2
+            }
+        ));
+
+        let options = options.with_parse_guards(ParseGuards::default().with_max_total_coords(4));
+        assert_eq!(read_geojson(geojson.as_bytes(), options).unwrap().len(), 3);
+
+        let geometries = r#"[
+            {"type": "Point", "coordinates": [0, 1]},
+            {"type": "GeometryCollection", "geometries": [{"type": "Point", "coordinates": [0, 1]}]}
+        ]"#;
+        let guards = ParseGuards::default().with_max_nesting_depth(1);
+        let options = GeoJsonReaderOptions::default().with_parse_guards(guards);
+        let err = read_geojson_geometries(geometries.as_bytes(), options).unwrap_err();
+        assert_eq!(err.to_string(), "max_nesting_depth of 1 exceeded at row 1");
+    }
+
+    #[test]
+    fn test_read_geometries() {
+        let geojson = r#"[
+            {"type": "Point", "coordinates": [0, 1]},
+            {"type": "MultiPoint", "coordinates": [[2, 3], [4, 5]]}
+        ]"#;
+        let arr = read_geojson_geometries(geojson.as_bytes(), None).unwrap();
+        assert_eq!(
+            arr.data_type(),
+            &GeoDataType::MultiPoint(CoordType::Interleaved)
+        );
+        assert_eq!(arr.geometry_chunks()[0].len(), 2);
+
+        let geojson = r#"[
+            {"type": "Point", "coordinates": [0, 1]},
+            {"type": "LineString", "coordinates": [[2, 3], [4, 5]]},
+            {"type": "Polygon", "coordinates": [[[0, 0], [1, 0], [1, 1], [0, 0]]]}
+        ]"#;
+        let options = GeoJsonReaderOptions::default().with_batch_size(2);
+        let arr = read_geojson_geometries(geojson.as_bytes(), options).unwrap();
+        assert_eq!(arr.data_type(), &GeoDataType::Mixed(CoordType::Interleaved));
+        let chunk_lengths = arr
+            .geometry_chunks()
+            .iter()
+            .map(|chunk| chunk.len())
+            .collect::<Vec<_>>();
+        assert_eq!(chunk_lengths, vec![2, 1]);
+    }
+
+    #[test]
+    fn test_properties_as_map() {
+        use crate::io::geojson::{write_geojson, GeoJsonWriterOptions};
+        use arrow_array::cast::AsArray;
+        use arrow_array::Array;
+        use serde_json::json;
+
+        let read = |options: GeoJsonReaderOptions| {
+            let file = File::open("fixtures/ragged_keys.geojson").unwrap();
+            read_geojson(BufReader::new(file), options).unwrap()
+        };
+        // One column per key and the geometry column
+        let wide = read(Default::default());
+        assert_eq!(wide.schema().fields().len(), 13);
+
+        let options = GeoJsonReaderOptions::default()
+            .with_properties_as_map(true)
+            .with_batch_size(3);
+        let table = read(options);
+        assert_eq!(table.schema().fields().len(), 2);
+        assert_eq!(table.schema().field(0).name(), "properties");
+        assert_eq!(table.batches().len(), 2);
+        let map = table.batches()[0].column(0).as_map();
+        let entries = map.value(2);
+        let keys = entries.column(0).as_string::<i32>();
+        let values = entries.column(1).as_string::<i32>();
+        let pairs = keys.iter().zip(values.iter()).collect::<Vec<_>>();
+        assert_eq!(
+            pairs,
+            vec![
+                (Some("building"), Some("yes")),
+                (Some("height"), Some("12.5")),
+                (Some("levels"), Some("4")),
+                (Some("wheelchair"), Some("true")),
+            ]
+        );
+        assert_eq!(table.batches()[1].column(0).as_map().value(1).len(), 0);
+
+        let mut output = vec![];
+        write_geojson(&table, &mut output, GeoJsonWriterOptions::default()).unwrap();
+        let written: Value = serde_json::from_slice(&output).unwrap();
+        let properties = |i: usize| written["features"][i]["properties"].clone();
+        assert_eq!(
+            properties(0),
+            json!({"highway": "residential", "name": "Main Street", "lanes": "2"})
+        );
+        assert_eq!(
+            properties(1),
+            json!({"amenity": "cafe", "name:fr": "Le Café", "opening_hours": "Mo-Fr 08:00-18:00"})
+        );
+        // Non-string values are written back as their JSON text
+        assert_eq!(properties(2)["levels"], json!("4"));
+        let species: Value =
+            serde_json::from_str(properties(3)["species"].as_str().unwrap()).unwrap();
+        assert_eq!(species, json!({"genus": "Quercus", "leaf": "broadleaved"}));
+        assert_eq!(properties(4), json!({}));
+
+        let extracted = table
+            .map_column_to_columns("properties", &["name", "height"])
+            .unwrap();
+        assert_eq!(extracted.schema().field(1).name(), "name");
+        assert_eq!(extracted.geometry_column_index(), 3);
+        let names = extracted.batches()[0].column(1).as_string::<i32>();
+        assert_eq!(
+            names.iter().collect::<Vec<_>>(),
+            vec![Some("Main Street"), None, None]
+        );
+        let heights = extracted.batches()[0].column(2).as_string::<i32>();
+        assert_eq!(heights.value(2), "12.5");
+        assert!(table
+            .map_column_to_columns("properties", &["properties"])
+            .is_err());
+    }
+
+    #[test]
+    fn test_read_single_geometry() {
+        let geojson = r#"{"type": "LineString", "coordinates": [[0, 1], [2, 3]]}"#;
+        let arr = read_geojson_geometries(geojson.as_bytes(), None).unwrap();
+        assert_eq!(
+            arr.data_type(),
+            &GeoDataType::LineString(CoordType::Interleaved)
+        );
+        assert_eq!(arr.geometry_chunks()[0].len(), 1);
+    }
+
+    #[test]
+    fn test_read_null_geometries() {
+        let geojson = r#"[{"type": "Point", "coordinates": [0, 1]}, null]"#;
+        let arr = read_geojson_geometries(geojson.as_bytes(), None).unwrap();
+        assert_eq!(arr.data_type(), &GeoDataType::Point(CoordType::Interleaved));
+        assert_eq!(arr.null_count(), 1);
+
+        let geojson = r#"[
+            null,
+            {"type": "Point", "coordinates": [0, 1]},
+            {"type": "LineString", "coordinates": [[2, 3], [4, 5]]}
+        ]"#;
+        let arr = read_geojson_geometries(geojson.as_bytes(), None).unwrap();
+        assert_eq!(
+            arr.data_type(),
+            &GeoDataType::GeometryCollection(CoordType::Interleaved)
+        );
+        assert_eq!(arr.null_count(), 1);
+        assert!(read_geojson_geometries(r#"[1]"#.as_bytes(), None).is_err());
+    }
 }
@@ -0,0 +1,326 @@
+use std::io::{ErrorKind, Read};
+
+use geozero::geojson::GeoJson;
+use geozero::GeozeroDatasource;
+
+use crate::error::{GeoArrowError, Result};
+use crate::io::geojson::GeoJsonReaderOptions;
+use crate::io::geozero::array::MixedGeometryStreamBuilder;
+use crate::io::geozero::table::GeoTableBuilder;
+use crate::table::GeoTable;
+
+/// The number of bytes requested from the reader at a time.
+const READ_BUFFER_SIZE: usize = 8192;
+
+/// Read a GeoJSON FeatureCollection to a GeoTable, parsing each feature as soon as it has been
+/// read.
+///
+/// Unlike [`read_geojson`][super::read_geojson], which needs the whole document before parsing
+/// it, this reads the input in chunks and parses each feature as soon as its closing brace
+/// arrives, so parsing overlaps with reading from a slow source such as an HTTP response. The
+/// reader may return any number of bytes from each call to `read`, and the content length
+/// doesn't need to be known. The resulting table is the same as with
+/// [`read_geojson`][super::read_geojson].
+///
+/// The input must be a single FeatureCollection. Anything other than whitespace after the
+/// FeatureCollection is an error, reported with its byte offset.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        skip_all,
+        fields(num_rows = tracing::field::Empty, num_batches = tracing::field::Empty)
+    )
+)]
+pub fn read_geojson_stream<R: Read>(
+    reader: R,
+    options: impl Into<GeoJsonReaderOptions>,
+) -> Result<GeoTable> {
+    let options = options.into().table_builder_options();
+    let mut geo_table =
+        GeoTableBuilder::<MixedGeometryStreamBuilder<i32>>::new_with_options(options);
+
+    let mut features = FeatureSplitter::new(reader);
+    while let Some(feature) = features.next_feature()? {
+        let feature = std::str::from_utf8(&feature).map_err(|err| {
+            GeoArrowError::General(format!("Invalid UTF-8 in GeoJSON feature: {}", err))
+        })?;
+        GeoJson(feature)
+            .process(&mut geo_table)
+            .map_err(|err| geo_table.processing_error(err))?;
+    }
+    let table = geo_table.finish()?;
+
+    #[cfg(feature = "tracing")]
+    {
+        let span = tracing::Span::current();
+        span.record("num_rows", table.len());
+        span.record("num_batches", table.batches().len());
+    }
+
+    Ok(table)
+}
+
+/// Fetch a GeoJSON FeatureCollection over HTTP and read it to a GeoTable, parsing features while
+/// the response body is still downloading, as in [`read_geojson_stream`].
+#[cfg(feature = "http")]
+pub fn read_geojson_url(url: &str, options: impl Into<GeoJsonReaderOptions>) -> Result<GeoTable> {
+    let response = reqwest::blocking::get(url)?.error_for_status()?;
+    read_geojson_stream(response, options)
+}
+
+/// Where the splitter is in the document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Position {
+    /// Before the opening brace of the FeatureCollection.
+    BeforeRoot,
+    /// Inside the FeatureCollection.
+    InRoot,
+    /// After the closing brace of the FeatureCollection.
+    AfterRoot,
+}
+
+/// An incremental tokenizer that splits the features out of a GeoJSON FeatureCollection.
+///
+/// It only tracks strings, nesting and the keys of the FeatureCollection object, which is enough
+/// to find the start and end of each feature. The features themselves are validated when they are
+/// parsed.
+struct FeatureSplitter<R> {
+    reader: R,
+    buffer: Box<[u8]>,
+    buffer_start: usize,
+    buffer_end: usize,
+    /// The offset in the input of the next byte to tokenize.
+    offset: u64,
+    position: Position,
+    /// The closing bracket of each open object or array.
+    stack: Vec<u8>,
+    in_string: bool,
+    escaped: bool,
+    /// The last string read at the top level of the FeatureCollection, which is the key of the
+    /// next value when followed by a colon.
+    root_string: Vec<u8>,
+    root_key: Vec<u8>,
+    /// The depth of the `features` array, once it has been opened.
+    features_depth: Option<usize>,
+    found_features: bool,
+    /// The bytes of the feature being read, if any.
+    feature: Option<Vec<u8>>,
+}
+
+impl<R: Read> FeatureSplitter<R> {
+    fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buffer: vec![0; READ_BUFFER_SIZE].into_boxed_slice(),
+            buffer_start: 0,
+            buffer_end: 0,
+            offset: 0,
+            position: Position::BeforeRoot,
+            stack: vec![],
+            in_string: false,
+            escaped: false,
+            root_string: vec![],
+            root_key: vec![],
+            features_depth: None,
+            found_features: false,
+            feature: None,
+        }
+    }
+
+    /// Read until the next feature is complete, returning its bytes, or `None` at the end of the
+    /// input.
+    fn next_feature(&mut self) -> Result<Option<Vec<u8>>> {
+        loop {
+            while self.buffer_start < self.buffer_end {
+                let byte = self.buffer[self.buffer_start];
+                self.buffer_start += 1;
+                let feature = self.push(byte)?;
+                self.offset += 1;
+                if feature.is_some() {
+                    return Ok(feature);
+                }
+            }
+
+            match self.reader.read(&mut self.buffer) {
+                Ok(0) => return self.finish().map(|_| None),
+                Ok(len) => {
+                    self.buffer_start = 0;
+                    self.buffer_end = len;
+                }
+                Err(err) if err.kind() == ErrorKind::Interrupted => {}
+                Err(err) => return Err(err.into()),
+            }
+        }
+    }
+
+    /// Tokenize one byte, returning a feature if this byte closes it.
+    fn push(&mut self, byte: u8) -> Result<Option<Vec<u8>>> {
+        if let Some(feature) = self.feature.as_mut() {
+            feature.push(byte);
+        }
+
+        if self.in_string {
+            if self.escaped {
+                self.escaped = false;
+            } else if byte == b'\\' {
+                self.escaped = true;
+            } else if byte == b'"' {
+                self.in_string = false;
+            } else if self.stack.len() == 1 {
+                self.root_string.push(byte);
+            }
+            return Ok(None);
+        }
+
+        match self.position {
+            Position::BeforeRoot => {
+                if byte == b'{' {
+                    self.position = Position::InRoot;
+                    self.stack.push(b'}');
+                } else if !byte.is_ascii_whitespace() {
+                    return Err(self.error("Expected a GeoJSON FeatureCollection object"));
+                }
+                return Ok(None);
+            }
+            Position::AfterRoot => {
+                if !byte.is_ascii_whitespace() {
+                    return Err(self.error("Unexpected data after the FeatureCollection"));
+                }
+                return Ok(None);
+            }
+            Position::InRoot => {}
+        }
+
+        match byte {
+            b'"' => {
+                self.in_string = true;
+                if self.stack.len() == 1 {
+                    self.root_string.clear();
+                }
+            }
+            b':' if self.stack.len() == 1 => {
+                self.root_key = std::mem::take(&mut self.root_string);
+            }
+            b'{' | b'[' => {
+                if byte == b'{' && self.features_depth == Some(self.stack.len()) {
+                    self.feature = Some(vec![byte]);
+                }
+                if byte == b'[' && self.stack.len() == 1 && self.root_key == b"features" {
+                    if self.found_features {
+                        return Err(self.error("Duplicate features member"));
+                    }
+                    self.found_features = true;
+                    self.features_depth = Some(2);
+                }
+                self.stack.push(if byte == b'{' { b'}' } else { b']' });
+            }
+            b'}' | b']' => {
+                if self.stack.pop() != Some(byte) {
+                    return Err(self.error(&format!("Unexpected '{}'", byte as char)));
+                }
+                if self.features_depth == Some(self.stack.len() + 1) {
+                    self.features_depth = None;
+                } else if self.features_depth == Some(self.stack.len()) && byte == b'}' {
+                    return Ok(self.feature.take());
+                }
+                if self.stack.is_empty() {
+                    self.position = Position::AfterRoot;
+                }
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    /// Check that the input ended after a complete FeatureCollection.
+    fn finish(&self) -> Result<()> {
+        if self.position != Position::AfterRoot {
+            return Err(self.error("Unexpected end of input"));
+        }
+        if !self.found_features {
+            return Err(GeoArrowError::General(
+                "Expected a GeoJSON FeatureCollection with a features array".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn error(&self, message: &str) -> GeoArrowError {
+        GeoArrowError::General(format!(
+            "{} at byte offset {} of the GeoJSON input",
+            message, self.offset
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::File;
+
+    use super::*;
+    use crate::io::geojson::read_geojson;
+
+    /// A reader that returns at most `chunk_size` bytes from each call to `read`.
+    struct ThrottledReader<R> {
+        inner: R,
+        chunk_size: usize,
+    }
+
+    impl<R: Read> Read for ThrottledReader<R> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let len = buf.len().min(self.chunk_size);
+            self.inner.read(&mut buf[..len])
+        }
+    }
+
+    #[test]
+    fn roads_one_byte_chunks() {
+        let expected = read_geojson(File::open("fixtures/roads.geojson").unwrap(), None).unwrap();
+        let reader = ThrottledReader {
+            inner: File::open("fixtures/roads.geojson").unwrap(),
+            chunk_size: 1,
+        };
+        let table = read_geojson_stream(reader, None).unwrap();
+        assert_eq!(table.schema(), expected.schema());
+        assert_eq!(table.batches(), expected.batches());
+    }
+
+    #[test]
+    fn split_features() {
+        let geojson = r#" {"features": [{"type": "Feature", "properties": {"a": "}{\"]"},
+            "geometry": {"type": "Point", "coordinates": [0, 1]}}, {"type": "Feature",
+            "properties": {"features": [{}]}, "geometry": null}], "type": "FeatureCollection"}
+        "#;
+        let mut splitter = FeatureSplitter::new(geojson.as_bytes());
+        let first = splitter.next_feature().unwrap().unwrap();
+        let first: serde_json::Value = serde_json::from_slice(&first).unwrap();
+        assert_eq!(first["properties"]["a"], "}{\"]");
+        let second = splitter.next_feature().unwrap().unwrap();
+        let second: serde_json::Value = serde_json::from_slice(&second).unwrap();
+        assert!(second["geometry"].is_null());
+        assert!(splitter.next_feature().unwrap().is_none());
+    }
+
+    #[test]
+    fn trailing_bytes() {
+        let geojson = r#"{"type": "FeatureCollection", "features": [{"type": "Feature",
+            "properties": {}, "geometry": {"type": "Point", "coordinates": [0, 1]}}]} x"#;
+        let err = read_geojson_stream(geojson.as_bytes(), None).unwrap_err();
+        let offset = geojson.len() - 1;
+        assert!(
+            err.to_string().contains(&format!("byte offset {}", offset)),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn truncated_input() {
+        let geojson = r#"{"type": "FeatureCollection", "features": [{"type": "Feature""#;
+        let err = read_geojson_stream(geojson.as_bytes(), None).unwrap_err();
+        assert!(err.to_string().contains("Unexpected end of input"));
+
+        let err = read_geojson_stream(r#"{"type": "Point"}"#.as_bytes(), None).unwrap_err();
+        assert!(err.to_string().contains("features array"));
+    }
+}
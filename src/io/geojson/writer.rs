@@ -1,32 +1,661 @@
-use crate::error::Result;
-use crate::table::GeoTable;
-use geozero::geojson::GeoJsonWriter;
-use geozero::GeozeroDatasource;
+use std::borrow::Cow;
 use std::io::Write;
+use std::sync::Arc;
+
+use arrow_array::{OffsetSizeTrait, RecordBatch};
+use geozero::error::{GeozeroError, Result as GeozeroResult};
+use geozero::{ColumnValue, FeatureProcessor, GeomProcessor, PropertyProcessor};
+
+use crate::algorithm::geo::{Orient, WindingOrder};
+use crate::algorithm::native::{RoundCoordinates, TotalBounds};
+use crate::array::{AsGeometryArray, WKBArray};
+use crate::chunked_array::ChunkedGeometryArrayTrait;
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::io::geozero::scalar::process_geometry;
+use crate::io::geozero::table::process_geotable;
+use crate::io::wkb::to_wkb;
+use crate::table::GeoTable;
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+
+/// Options for the GeoJSON writer.
+#[derive(Debug, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct GeoJsonWriterOptions {
+    /// The number of decimal places to write for each coordinate. If `None`, coordinates are
+    /// written with full precision.
+    ///
+    /// GeoArrow-native geometries are rounded with
+    /// [`RoundCoordinates`][crate::algorithm::native::RoundCoordinates] before writing, so that
+    /// rings and lines that rounding made degenerate are repaired, and geometries that collapse
+    /// entirely are written as `null`. WKB geometries and Z values are only rounded as they are
+    /// formatted.
+    pub coord_precision: Option<usize>,
+
+    /// Whether to compute the total bounds of the geometry column and write it as the top-level
+    /// `bbox` member of the FeatureCollection.
+    pub bbox: bool,
+
+    /// Whether to rewind polygon rings to the winding order required by [RFC 7946], with
+    /// counter-clockwise exterior rings and clockwise interior rings.
+    ///
+    /// Only Polygon and MultiPolygon geometry columns are rewound, with
+    /// [`Orient`][crate::algorithm::geo::Orient]. Other geometries are written as they are.
+    ///
+    /// [RFC 7946]: https://datatracker.ietf.org/doc/html/rfc7946#section-3.1.6
+    pub orient: bool,
+}
+
+impl GeoJsonWriterOptions {
+    #[deprecated(note = "Use `GeoJsonWriterOptions::default()` and the `with_*` methods instead")]
+    pub fn new(coord_precision: Option<usize>, bbox: bool) -> Self {
+        Self {
+            coord_precision,
+            bbox,
+            orient: false,
+        }
+    }
+
+    /// Set the number of decimal places to write for each coordinate.
+    pub fn with_coord_precision(mut self, coord_precision: usize) -> Self {
+        self.coord_precision = Some(coord_precision);
+        self
+    }
+
+    /// Set whether to write the total bounds of the geometry column as the `bbox` member.
+    pub fn with_bbox(mut self, bbox: bool) -> Self {
+        self.bbox = bbox;
+        self
+    }
+
+    /// Set whether to rewind polygon rings to the winding order required by RFC 7946.
+    pub fn with_orient(mut self, orient: bool) -> Self {
+        self.orient = orient;
+        self
+    }
+}
 
 /// Write a GeoTable to GeoJSON
 ///
+/// The geometry column of the table is written as the geometry of each Feature and all other
+/// columns are written as Feature properties. Record batches are serialized one at a time, so the
+/// output is streamed to `writer` without an intermediate copy of the table.
+///
 /// Note: Does not reproject to WGS84 for you
-pub fn write_geojson<W: Write>(table: &mut GeoTable, writer: W) -> Result<()> {
-    let mut geojson = GeoJsonWriter::new(writer);
-    table.process(&mut geojson)?;
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        skip_all,
+        fields(num_rows = table.len(), num_batches = table.batches().len())
+    )
+)]
+pub fn write_geojson<W: Write>(
+    table: &GeoTable,
+    writer: W,
+    options: GeoJsonWriterOptions,
+) -> Result<()> {
+    let bbox = if options.bbox {
+        let bounds = table.geometry()?.as_ref().total_bounds();
+        Some([bounds.minx(), bounds.miny(), bounds.maxx(), bounds.maxy()])
+    } else {
+        None
+    };
+    let mut geojson = GeoJsonWriter::new(writer, options.coord_precision, bbox);
+    let mut table = Cow::Borrowed(table);
+    if let Some(precision) = options.coord_precision {
+        if is_native(&table.geometry_data_type()?) && !table.is_empty() {
+            table = Cow::Owned(round_geometry_column(&table, precision)?);
+        }
+    }
+    // Rounding can flip tiny rings, so they are rewound afterwards
+    if options.orient && is_polygonal(&table.geometry_data_type()?) && !table.is_empty() {
+        let oriented = table
+            .geometry()?
+            .as_ref()
+            .orient(WindingOrder::CounterClockwise)?;
+        table = Cow::Owned(replace_geometry_column(&table, oriented.as_ref())?);
+    }
+    process_geotable(&table, &mut geojson)?;
+    Ok(())
+}
+
+/// Whether a data type can be rounded with [`RoundCoordinates`].
+fn is_native(data_type: &GeoDataType) -> bool {
+    !matches!(
+        data_type,
+        GeoDataType::WKB | GeoDataType::LargeWKB | GeoDataType::Rect
+    )
+}
+
+/// Whether a data type can be rewound with [`Orient`].
+fn is_polygonal(data_type: &GeoDataType) -> bool {
+    matches!(
+        data_type,
+        GeoDataType::Polygon(_)
+            | GeoDataType::LargePolygon(_)
+            | GeoDataType::MultiPolygon(_)
+            | GeoDataType::LargeMultiPolygon(_)
+    )
+}
+
+/// Clamp a coordinate precision to the `u8` taken by [`RoundCoordinates`]. Rounding to that many
+/// decimal places already leaves every `f64` unchanged.
+fn precision_decimals(precision: usize) -> u8 {
+    precision.min(u8::MAX as usize) as u8
+}
+
+/// Copy the table with its geometry column rounded to `precision` decimal places.
+fn round_geometry_column(table: &GeoTable, precision: usize) -> Result<GeoTable> {
+    let geometry = table.geometry()?;
+    let (rounded, _) = geometry
+        .as_ref()
+        .round_coordinates(precision_decimals(precision), true)?;
+    replace_geometry_column(table, rounded.as_ref())
+}
+
+/// Copy the table with its geometry column replaced by `geometry`, which must have the same
+/// chunks.
+fn replace_geometry_column(
+    table: &GeoTable,
+    geometry: &dyn ChunkedGeometryArrayTrait,
+) -> Result<GeoTable> {
+    let geometry_column_index = table.geometry_column_index();
+    let batches = table
+        .batches()
+        .iter()
+        .zip(geometry.geometry_chunks())
+        .map(|(batch, chunk)| {
+            let mut columns = batch.columns().to_vec();
+            columns[geometry_column_index] = chunk.to_array_ref();
+            Ok(RecordBatch::try_new(batch.schema(), columns)?)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    GeoTable::try_new(table.schema().clone(), batches, geometry_column_index)
+}
+
+/// Write a geometry array to a JSON array of GeoJSON geometry objects, with a `null` for each null
+/// geometry.
+///
+/// This is the counterpart of [`read_geojson_geometries`](super::read_geojson_geometries). Only
+/// the coordinate precision and orientation of `options` apply, as there is no FeatureCollection
+/// to hold a bbox.
+pub fn write_geojson_geometries<W: Write>(
+    array: &dyn GeometryArrayTrait,
+    writer: W,
+    options: GeoJsonWriterOptions,
+) -> Result<()> {
+    let mut geojson = GeoJsonWriter::new(writer, options.coord_precision, None);
+    match array.data_type() {
+        GeoDataType::WKB => write_wkb_geometries(array.as_wkb(), &mut geojson),
+        GeoDataType::LargeWKB => write_wkb_geometries(array.as_large_wkb(), &mut geojson),
+        GeoDataType::Rect => Err(GeoArrowError::NotYetImplemented(
+            "Writing Rect arrays to GeoJSON is not yet supported".to_string(),
+        )),
+        _ => {
+            let mut prepared: Option<Arc<dyn GeometryArrayTrait>> = None;
+            if let Some(precision) = options.coord_precision {
+                let (rounded, _) = array.round_coordinates(precision_decimals(precision), true)?;
+                prepared = Some(rounded);
+            }
+            if options.orient && is_polygonal(array.data_type()) {
+                let current = prepared.as_deref().unwrap_or(array);
+                prepared = Some(current.orient(WindingOrder::CounterClockwise)?);
+            }
+            let array = prepared.as_deref().unwrap_or(array);
+            write_wkb_geometries(&to_wkb::<i64>(array), &mut geojson)
+        }
+    }
+}
+
+fn write_wkb_geometries<O: OffsetSizeTrait, W: Write>(
+    array: &WKBArray<O>,
+    geojson: &mut GeoJsonWriter<W>,
+) -> Result<()> {
+    geojson.out.write_all(b"[")?;
+    for (geom_idx, maybe_wkb) in array.iter().enumerate() {
+        if geom_idx > 0 {
+            geojson.out.write_all(b",\n")?;
+        }
+        match maybe_wkb {
+            Some(wkb) => process_geometry(&wkb.to_wkb_object(), 0, geojson)?,
+            None => geojson.out.write_all(b"null")?,
+        }
+    }
+    geojson.out.write_all(b"]")?;
     Ok(())
 }
 
+/// A [`FeatureProcessor`] that writes a FeatureCollection to an underlying writer.
+///
+/// In newline-delimited mode, each Feature is instead written on its own line without an
+/// enclosing FeatureCollection.
+pub(crate) struct GeoJsonWriter<W: Write> {
+    out: W,
+    newline_delimited: bool,
+    /// Scale factor used to round coordinates, if a coordinate precision was provided.
+    precision_factor: Option<f64>,
+    bbox: Option<[f64; 4]>,
+    /// Number of properties written so far for the current feature.
+    num_properties: usize,
+    /// Whether any geometry has been written since the last `geometry_begin`.
+    geometry_written: bool,
+}
+
+impl<W: Write> GeoJsonWriter<W> {
+    pub(crate) fn new(out: W, coord_precision: Option<usize>, bbox: Option<[f64; 4]>) -> Self {
+        Self {
+            out,
+            newline_delimited: false,
+            precision_factor: coord_precision.map(|p| 10_f64.powi(p as i32)),
+            bbox,
+            num_properties: 0,
+            geometry_written: false,
+        }
+    }
+
+    /// Create a writer for [newline-delimited GeoJSON](https://stevage.github.io/ndgeojson/).
+    pub(crate) fn new_newline_delimited(out: W, coord_precision: Option<usize>) -> Self {
+        Self {
+            newline_delimited: true,
+            ..Self::new(out, coord_precision, None)
+        }
+    }
+
+    fn comma(&mut self, idx: usize) -> GeozeroResult<()> {
+        if idx > 0 {
+            self.out.write_all(b",")?;
+        }
+        Ok(())
+    }
+
+    fn round(&self, value: f64) -> f64 {
+        match self.precision_factor {
+            Some(factor) => (value * factor).round() / factor,
+            None => value,
+        }
+    }
+
+    fn geometry_begin_tagged(&mut self, geometry_type: &str, idx: usize) -> GeozeroResult<()> {
+        self.geometry_written = true;
+        self.comma(idx)?;
+        write!(
+            self.out,
+            r#"{{"type": "{}", "coordinates": ["#,
+            geometry_type
+        )?;
+        Ok(())
+    }
+}
+
+impl<W: Write> FeatureProcessor for GeoJsonWriter<W> {
+    fn dataset_begin(&mut self, _name: Option<&str>) -> GeozeroResult<()> {
+        if self.newline_delimited {
+            return Ok(());
+        }
+        self.out.write_all(br#"{"type": "FeatureCollection""#)?;
+        if let Some([minx, miny, maxx, maxy]) = self.bbox {
+            write!(
+                self.out,
+                r#", "bbox": [{},{},{},{}]"#,
+                minx, miny, maxx, maxy
+            )?;
+        }
+        self.out.write_all(br#", "features": ["#)?;
+        Ok(())
+    }
+
+    fn dataset_end(&mut self) -> GeozeroResult<()> {
+        if self.newline_delimited {
+            return Ok(());
+        }
+        self.out.write_all(b"]}")?;
+        Ok(())
+    }
+
+    fn feature_begin(&mut self, idx: u64) -> GeozeroResult<()> {
+        if idx > 0 && !self.newline_delimited {
+            self.out.write_all(b",\n")?;
+        }
+        self.out.write_all(br#"{"type": "Feature""#)?;
+        Ok(())
+    }
+
+    fn feature_end(&mut self, _idx: u64) -> GeozeroResult<()> {
+        if self.newline_delimited {
+            self.out.write_all(b"}\n")?;
+        } else {
+            self.out.write_all(b"}")?;
+        }
+        Ok(())
+    }
+
+    fn properties_begin(&mut self) -> GeozeroResult<()> {
+        self.num_properties = 0;
+        self.out.write_all(br#", "properties": {"#)?;
+        Ok(())
+    }
+
+    fn properties_end(&mut self) -> GeozeroResult<()> {
+        self.out.write_all(b"}")?;
+        Ok(())
+    }
+
+    fn geometry_begin(&mut self) -> GeozeroResult<()> {
+        self.geometry_written = false;
+        self.out.write_all(br#", "geometry": "#)?;
+        Ok(())
+    }
+
+    fn geometry_end(&mut self) -> GeozeroResult<()> {
+        // Null geometries don't emit any geometry events
+        if !self.geometry_written {
+            self.out.write_all(b"null")?;
+        }
+        Ok(())
+    }
+}
+
+impl<W: Write> GeomProcessor for GeoJsonWriter<W> {
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> GeozeroResult<()> {
+        self.comma(idx)?;
+        write!(self.out, "[{},{}]", self.round(x), self.round(y))?;
+        Ok(())
+    }
+
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        _m: Option<f64>,
+        _t: Option<f64>,
+        _tm: Option<u64>,
+        idx: usize,
+    ) -> GeozeroResult<()> {
+        self.comma(idx)?;
+        write!(self.out, "[{},{}", self.round(x), self.round(y))?;
+        if let Some(z) = z {
+            write!(self.out, ",{}", self.round(z))?;
+        }
+        self.out.write_all(b"]")?;
+        Ok(())
+    }
+
+    fn empty_point(&mut self, idx: usize) -> GeozeroResult<()> {
+        self.geometry_written = true;
+        self.comma(idx)?;
+        self.out
+            .write_all(br#"{"type": "Point", "coordinates": []}"#)?;
+        Ok(())
+    }
+
+    fn point_begin(&mut self, idx: usize) -> GeozeroResult<()> {
+        self.geometry_written = true;
+        self.comma(idx)?;
+        self.out
+            .write_all(br#"{"type": "Point", "coordinates": "#)?;
+        Ok(())
+    }
+
+    fn point_end(&mut self, _idx: usize) -> GeozeroResult<()> {
+        self.out.write_all(b"}")?;
+        Ok(())
+    }
+
+    fn multipoint_begin(&mut self, _size: usize, idx: usize) -> GeozeroResult<()> {
+        self.geometry_begin_tagged("MultiPoint", idx)
+    }
+
+    fn multipoint_end(&mut self, _idx: usize) -> GeozeroResult<()> {
+        self.out.write_all(b"]}")?;
+        Ok(())
+    }
+
+    fn linestring_begin(&mut self, tagged: bool, _size: usize, idx: usize) -> GeozeroResult<()> {
+        if tagged {
+            self.geometry_begin_tagged("LineString", idx)
+        } else {
+            self.comma(idx)?;
+            self.out.write_all(b"[")?;
+            Ok(())
+        }
+    }
+
+    fn linestring_end(&mut self, tagged: bool, _idx: usize) -> GeozeroResult<()> {
+        if tagged {
+            self.out.write_all(b"]}")?;
+        } else {
+            self.out.write_all(b"]")?;
+        }
+        Ok(())
+    }
+
+    fn multilinestring_begin(&mut self, _size: usize, idx: usize) -> GeozeroResult<()> {
+        self.geometry_begin_tagged("MultiLineString", idx)
+    }
+
+    fn multilinestring_end(&mut self, _idx: usize) -> GeozeroResult<()> {
+        self.out.write_all(b"]}")?;
+        Ok(())
+    }
+
+    fn polygon_begin(&mut self, tagged: bool, _size: usize, idx: usize) -> GeozeroResult<()> {
+        if tagged {
+            self.geometry_begin_tagged("Polygon", idx)
+        } else {
+            self.comma(idx)?;
+            self.out.write_all(b"[")?;
+            Ok(())
+        }
+    }
+
+    fn polygon_end(&mut self, tagged: bool, _idx: usize) -> GeozeroResult<()> {
+        if tagged {
+            self.out.write_all(b"]}")?;
+        } else {
+            self.out.write_all(b"]")?;
+        }
+        Ok(())
+    }
+
+    fn multipolygon_begin(&mut self, _size: usize, idx: usize) -> GeozeroResult<()> {
+        self.geometry_begin_tagged("MultiPolygon", idx)
+    }
+
+    fn multipolygon_end(&mut self, _idx: usize) -> GeozeroResult<()> {
+        self.out.write_all(b"]}")?;
+        Ok(())
+    }
+
+    fn geometrycollection_begin(&mut self, _size: usize, idx: usize) -> GeozeroResult<()> {
+        self.geometry_written = true;
+        self.comma(idx)?;
+        self.out
+            .write_all(br#"{"type": "GeometryCollection", "geometries": ["#)?;
+        Ok(())
+    }
+
+    fn geometrycollection_end(&mut self, _idx: usize) -> GeozeroResult<()> {
+        self.out.write_all(b"]}")?;
+        Ok(())
+    }
+}
+
+impl<W: Write> PropertyProcessor for GeoJsonWriter<W> {
+    fn property(&mut self, _i: usize, colname: &str, colval: &ColumnValue) -> GeozeroResult<bool> {
+        // Binary values have no JSON representation
+        if matches!(colval, ColumnValue::Binary(_)) {
+            return Ok(false);
+        }
+
+        if self.num_properties > 0 {
+            self.out.write_all(b", ")?;
+        }
+        self.num_properties += 1;
+
+        let colname = serde_json::to_string(colname)
+            .map_err(|err| GeozeroError::Property(err.to_string()))?;
+        write!(self.out, "{}: ", colname)?;
+
+        match colval {
+            ColumnValue::Byte(v) => write!(self.out, "{}", v)?,
+            ColumnValue::UByte(v) => write!(self.out, "{}", v)?,
+            ColumnValue::Bool(v) => write!(self.out, "{}", v)?,
+            ColumnValue::Short(v) => write!(self.out, "{}", v)?,
+            ColumnValue::UShort(v) => write!(self.out, "{}", v)?,
+            ColumnValue::Int(v) => write!(self.out, "{}", v)?,
+            ColumnValue::UInt(v) => write!(self.out, "{}", v)?,
+            ColumnValue::Long(v) => write!(self.out, "{}", v)?,
+            ColumnValue::ULong(v) => write!(self.out, "{}", v)?,
+            // JSON has no representation of NaN or infinity
+            ColumnValue::Float(v) if !v.is_finite() => self.out.write_all(b"null")?,
+            ColumnValue::Double(v) if !v.is_finite() => self.out.write_all(b"null")?,
+            ColumnValue::Float(v) => write!(self.out, "{}", v)?,
+            ColumnValue::Double(v) => write!(self.out, "{}", v)?,
+            ColumnValue::String(v) | ColumnValue::DateTime(v) => {
+                let value = serde_json::to_string(v)
+                    .map_err(|err| GeozeroError::Property(err.to_string()))?;
+                self.out.write_all(value.as_bytes())?;
+            }
+            ColumnValue::Json(v) => self.out.write_all(v.as_bytes())?,
+            ColumnValue::Binary(_) => unreachable!(),
+        };
+        Ok(false)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::array::PolygonArray;
     use crate::test::point;
+    use arrow_schema::Schema;
     use std::io::BufWriter;
 
+    fn write_to_json(table: &GeoTable, options: GeoJsonWriterOptions) -> serde_json::Value {
+        let mut output_buffer = Vec::new();
+        let writer = BufWriter::new(&mut output_buffer);
+        write_geojson(table, writer, options).unwrap();
+        serde_json::from_slice(&output_buffer).unwrap()
+    }
+
     #[test]
     fn test_write() {
-        let mut table = point::table();
+        let table = point::table();
+        let output = write_to_json(&table, Default::default());
+        assert_eq!(output["type"], "FeatureCollection");
+        assert_eq!(output["features"].as_array().unwrap().len(), 3);
+        assert_eq!(
+            output["features"][1]["geometry"],
+            serde_json::json!({"type": "Point", "coordinates": [1.0, 2.0]})
+        );
+        assert!(output.get("bbox").is_none());
+    }
+
+    #[test]
+    fn test_write_bbox() {
+        let table = point::table();
+        let options = GeoJsonWriterOptions::default().with_bbox(true);
+        let output = write_to_json(&table, options);
+        assert_eq!(output["bbox"], serde_json::json!([0.0, 1.0, 2.0, 3.0]));
+    }
+
+    #[test]
+    fn test_coord_precision() {
+        let mut writer = GeoJsonWriter::new(Vec::new(), Some(2), None);
+        writer.xy(1.23456, -7.891011, 0).unwrap();
+        assert_eq!(String::from_utf8(writer.out).unwrap(), "[1.23,-7.89]");
+    }
+
+    #[test]
+    fn test_coord_precision_repairs_geometries() {
+        let sliver = geo::polygon![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.0001),
+            (x: 2., y: 0.),
+            (x: 1., y: -0.0001),
+        ];
+        let square = geo::polygon![
+            (x: 0., y: 0.),
+            (x: 1.00001, y: 0.),
+            (x: 1., y: 1.),
+            (x: 0., y: 1.),
+        ];
+        let arr: PolygonArray<i32> = vec![sliver, square].as_slice().into();
+        let schema = Arc::new(Schema::new(vec![arr.extension_field()]));
+        let batch = RecordBatch::try_new(schema.clone(), vec![arr.to_array_ref()]).unwrap();
+        let table = GeoTable::try_new(schema, vec![batch], 0).unwrap();
+
+        let options = GeoJsonWriterOptions::default().with_coord_precision(3);
+        let output = write_to_json(&table, options);
+        assert!(output["features"][0]["geometry"].is_null());
+        assert_eq!(
+            output["features"][1]["geometry"]["coordinates"],
+            serde_json::json!([[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0], [0.0, 0.0]]])
+        );
 
         let mut output_buffer = Vec::new();
-        let writer = BufWriter::new(&mut output_buffer);
-        write_geojson(&mut table, writer).unwrap();
-        let output_string = String::from_utf8(output_buffer).unwrap();
-        println!("{}", output_string);
+        write_geojson_geometries(&arr, &mut output_buffer, options).unwrap();
+        let output: serde_json::Value = serde_json::from_slice(&output_buffer).unwrap();
+        assert!(output[0].is_null());
+        assert!(output[1].is_object());
+    }
+
+    #[test]
+    fn test_orient() {
+        // A clockwise exterior ring and a counter-clockwise hole, the opposite of RFC 7946
+        let polygon = geo::Polygon::new(
+            geo::line_string![(x: 0., y: 0.), (x: 0., y: 4.), (x: 4., y: 4.), (x: 4., y: 0.)],
+            vec![geo::line_string![(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)]],
+        );
+        let arr: PolygonArray<i32> = vec![polygon].as_slice().into();
+        let schema = Arc::new(Schema::new(vec![arr.extension_field()]));
+        let batch = RecordBatch::try_new(schema.clone(), vec![arr.to_array_ref()]).unwrap();
+        let table = GeoTable::try_new(schema, vec![batch], 0).unwrap();
+        let expected = serde_json::json!([
+            [[0.0, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 4.0], [0.0, 0.0]],
+            [[1.0, 1.0], [1.0, 2.0], [2.0, 2.0], [2.0, 1.0], [1.0, 1.0]]
+        ]);
+
+        let output = write_to_json(&table, Default::default());
+        assert_ne!(output["features"][0]["geometry"]["coordinates"], expected);
+
+        let options = GeoJsonWriterOptions::default().with_orient(true);
+        let output = write_to_json(&table, options);
+        assert_eq!(output["features"][0]["geometry"]["coordinates"], expected);
+
+        let mut output_buffer = Vec::new();
+        write_geojson_geometries(&arr, &mut output_buffer, options).unwrap();
+        let output: serde_json::Value = serde_json::from_slice(&output_buffer).unwrap();
+        assert_eq!(output[0]["coordinates"], expected);
+    }
+
+    #[test]
+    fn test_null_geometry() {
+        let mut writer = GeoJsonWriter::new(Vec::new(), None, None);
+        writer.feature_begin(0).unwrap();
+        writer.geometry_begin().unwrap();
+        writer.geometry_end().unwrap();
+        writer.feature_end(0).unwrap();
+        let output: serde_json::Value = serde_json::from_slice(&writer.out).unwrap();
+        assert!(output["geometry"].is_null());
+    }
+
+    #[test]
+    fn test_write_geometries() {
+        let geojson = r#"[
+            {"type": "LineString", "coordinates": [[0.5, 1.5], [2.5, 3.5]]},
+            null,
+            {"type": "LineString", "coordinates": [[2.5, 3.5], [4.5, 5.5]]}
+        ]"#;
+        let arr = crate::io::geojson::read_geojson_geometries(geojson.as_bytes(), None).unwrap();
+
+        let mut output_buffer = Vec::new();
+        let chunk = arr.geometry_chunks()[0];
+        write_geojson_geometries(chunk, &mut output_buffer, Default::default()).unwrap();
+        let output: serde_json::Value = serde_json::from_slice(&output_buffer).unwrap();
+        let expected: serde_json::Value = serde_json::from_str(geojson).unwrap();
+        assert_eq!(output, expected);
     }
 }
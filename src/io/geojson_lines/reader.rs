@@ -1,31 +1,261 @@
+use std::io::{BufRead, Cursor, Read};
+use std::sync::Arc;
+
+use arrow_schema::{DataType, Field, Schema};
 use geozero::geojson::GeoJsonLineReader;
 use geozero::GeozeroDatasource;
-use std::io::BufRead;
+use indexmap::IndexMap;
+use serde_json::Value;
 
 use crate::array::CoordType;
-use crate::error::Result;
+use crate::error::{GeoArrowError, Result};
 use crate::io::geozero::array::MixedGeometryStreamBuilder;
 use crate::io::geozero::table::{GeoTableBuilder, GeoTableBuilderOptions};
+use crate::io::guards::ParseGuards;
 use crate::table::GeoTable;
 
+/// Options for the newline-delimited GeoJSON reader.
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct GeoJsonLinesReaderOptions {
+    /// The GeoArrow coordinate type to use in the geometry arrays.
+    pub coord_type: CoordType,
+
+    /// The number of rows in each batch.
+    pub batch_size: usize,
+
+    /// The number of features to scan when inferring the schema of the feature properties. If 0,
+    /// the schema is not inferred up front and columns are created as they are encountered.
+    ///
+    /// Inferring the schema allows a property whose type differs between features (e.g. an
+    /// integer in one feature and a float in the next) to be read into a common type.
+    pub schema_infer_max_records: usize,
+
+    /// Limits on the size of the geometries read, for untrusted input. Unlimited by default.
+    pub parse_guards: ParseGuards,
+}
+
+impl GeoJsonLinesReaderOptions {
+    #[deprecated(
+        note = "Use `GeoJsonLinesReaderOptions::default()` and the `with_*` methods instead"
+    )]
+    pub fn new(coord_type: CoordType, batch_size: usize, schema_infer_max_records: usize) -> Self {
+        Self {
+            coord_type,
+            batch_size,
+            schema_infer_max_records,
+            parse_guards: Default::default(),
+        }
+    }
+
+    /// Set the GeoArrow coordinate type to use in the geometry arrays.
+    pub fn with_coord_type(mut self, coord_type: CoordType) -> Self {
+        self.coord_type = coord_type;
+        self
+    }
+
+    /// Set the number of rows in each batch.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Set the number of features to scan when inferring the schema of the feature properties.
+    pub fn with_schema_infer_max_records(mut self, schema_infer_max_records: usize) -> Self {
+        self.schema_infer_max_records = schema_infer_max_records;
+        self
+    }
+
+    /// Set the limits on the size of the geometries read.
+    pub fn with_parse_guards(mut self, parse_guards: ParseGuards) -> Self {
+        self.parse_guards = parse_guards;
+        self
+    }
+}
+
+impl Default for GeoJsonLinesReaderOptions {
+    fn default() -> Self {
+        Self {
+            coord_type: CoordType::Interleaved,
+            batch_size: 65_536,
+            schema_infer_max_records: 1000,
+            parse_guards: Default::default(),
+        }
+    }
+}
+
 /// Read a GeoJSON Lines file
 ///
 /// This expects a GeoJSON Feature on each line of a text file, with a newline character separating
-/// each Feature.
-pub fn read_geojson_lines<R: BufRead>(reader: R, batch_size: Option<usize>) -> Result<GeoTable> {
-    let mut geojson_line_reader = GeoJsonLineReader::new(reader);
+/// each Feature. Geometries are parsed directly into GeoArrow-native arrays.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        skip_all,
+        fields(
+            batch_size = options.batch_size,
+            num_rows = tracing::field::Empty,
+            num_batches = tracing::field::Empty,
+        )
+    )
+)]
+pub fn read_geojson_lines<R: BufRead>(
+    mut reader: R,
+    options: GeoJsonLinesReaderOptions,
+) -> Result<GeoTable> {
+    // The lines used for schema inference are buffered and then read again before the rest of
+    // the input.
+    let mut inferred_lines = Vec::new();
+    let properties_schema = if options.schema_infer_max_records > 0 {
+        Some(infer_properties_schema(
+            &mut reader,
+            options.schema_infer_max_records,
+            &mut inferred_lines,
+        )?)
+    } else {
+        None
+    };
+    let mut geojson_line_reader = GeoJsonLineReader::new(Cursor::new(inferred_lines).chain(reader));
 
     // TODO: set crs to epsg:4326?
     let options = GeoTableBuilderOptions::new(
-        CoordType::Interleaved,
+        options.coord_type,
         true,
-        batch_size,
-        None,
+        Some(options.batch_size),
+        properties_schema,
         None,
         Default::default(),
-    );
+    )
+    .with_parse_guards(options.parse_guards);
     let mut geo_table =
         GeoTableBuilder::<MixedGeometryStreamBuilder<i32>>::new_with_options(options);
-    geojson_line_reader.process(&mut geo_table)?;
-    geo_table.finish()
+    geojson_line_reader
+        .process(&mut geo_table)
+        .map_err(|err| geo_table.processing_error(err))?;
+    let table = geo_table.finish()?;
+
+    #[cfg(feature = "tracing")]
+    {
+        let span = tracing::Span::current();
+        span.record("num_rows", table.len());
+        span.record("num_batches", table.batches().len());
+    }
+
+    Ok(table)
+}
+
+/// Infer the schema of the feature properties from the first `max_records` features of `reader`.
+///
+/// All consumed bytes are appended to `buffer`.
+fn infer_properties_schema<R: BufRead>(
+    reader: &mut R,
+    max_records: usize,
+    buffer: &mut Vec<u8>,
+) -> Result<Arc<Schema>> {
+    let mut data_types: IndexMap<String, DataType> = IndexMap::new();
+    let mut num_records = 0;
+    let mut line = String::new();
+
+    while num_records < max_records {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            break;
+        }
+        buffer.extend_from_slice(line.as_bytes());
+
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        num_records += 1;
+
+        let feature: Value = serde_json::from_str(trimmed)
+            .map_err(|err| GeoArrowError::General(format!("Invalid GeoJSON line: {}", err)))?;
+        let Some(properties) = feature.get("properties").and_then(Value::as_object) else {
+            continue;
+        };
+
+        for (key, value) in properties.iter() {
+            // Null values don't contribute any type information
+            let Some(data_type) = json_value_data_type(value) else {
+                continue;
+            };
+            match data_types.get_mut(key) {
+                Some(existing) => *existing = merge_data_types(existing, &data_type),
+                None => {
+                    data_types.insert(key.clone(), data_type);
+                }
+            }
+        }
+    }
+
+    let fields = data_types
+        .into_iter()
+        .map(|(name, data_type)| Field::new(name, data_type, true))
+        .collect::<Vec<_>>();
+    Ok(Arc::new(Schema::new(fields)))
+}
+
+/// The Arrow data type that geozero will produce for this JSON value.
+fn json_value_data_type(value: &Value) -> Option<DataType> {
+    match value {
+        Value::Null => None,
+        Value::Bool(_) => Some(DataType::Boolean),
+        Value::Number(n) if n.is_f64() => Some(DataType::Float64),
+        Value::Number(n) if n.is_i64() => Some(DataType::Int64),
+        Value::Number(_) => Some(DataType::UInt64),
+        // Nested values are stored as serialized JSON strings
+        Value::String(_) | Value::Array(_) | Value::Object(_) => Some(DataType::Utf8),
+    }
+}
+
+/// Find a common type for values seen with two different types.
+fn merge_data_types(left: &DataType, right: &DataType) -> DataType {
+    match (left, right) {
+        (left, right) if left == right => left.clone(),
+        (l, r) if l.is_numeric() && r.is_numeric() => DataType::Float64,
+        _ => DataType::Utf8,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Float64Type;
+
+    #[test]
+    fn test_read_with_inferred_schema() {
+        let input = r#"{"type": "Feature", "properties": {"a": 1, "b": "x"}, "geometry": {"type": "Point", "coordinates": [0, 1]}}
+{"type": "Feature", "properties": {"a": 2.5, "b": null}, "geometry": {"type": "Point", "coordinates": [1, 2]}}
+{"type": "Feature", "properties": {"a": 3}, "geometry": {"type": "Point", "coordinates": [2, 3]}}
+"#;
+        let options = GeoJsonLinesReaderOptions::default()
+            .with_batch_size(2)
+            .with_schema_infer_max_records(10);
+        let table = read_geojson_lines(input.as_bytes(), options).unwrap();
+        assert_eq!(table.len(), 3);
+        assert_eq!(table.batches().len(), 2);
+
+        let schema = table.schema();
+        assert_eq!(schema.field(0).name(), "a");
+        assert_eq!(schema.field(0).data_type(), &DataType::Float64);
+        assert_eq!(schema.field(1).data_type(), &DataType::Utf8);
+
+        let a = table.batches()[0].column(0).as_primitive::<Float64Type>();
+        assert_eq!(a.value(0), 1.0);
+        assert_eq!(a.value(1), 2.5);
+    }
+
+    #[test]
+    fn test_merge_data_types() {
+        assert_eq!(
+            merge_data_types(&DataType::Int64, &DataType::Float64),
+            DataType::Float64
+        );
+        assert_eq!(
+            merge_data_types(&DataType::Boolean, &DataType::Int64),
+            DataType::Utf8
+        );
+    }
 }
@@ -1,13 +1,53 @@
-use geozero::geojson::GeoJsonLineWriter;
-use geozero::GeozeroDatasource;
 use std::io::Write;
 
 use crate::error::Result;
+use crate::io::geojson::GeoJsonWriter;
+use crate::io::geozero::table::process_geotable;
 use crate::table::GeoTable;
 
 /// Write a table to newline-delimited GeoJSON
-pub fn write_geojson_lines<W: Write>(table: &mut GeoTable, writer: W) -> Result<()> {
-    let mut geojson_writer = GeoJsonLineWriter::new(writer);
-    table.process(&mut geojson_writer)?;
+///
+/// Each row of the table is written as a GeoJSON Feature on its own line. Record batches are
+/// serialized one at a time, so the output is streamed to `writer` without being buffered in
+/// memory.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        skip_all,
+        fields(num_rows = table.len(), num_batches = table.batches().len())
+    )
+)]
+pub fn write_geojson_lines<W: Write>(table: &GeoTable, writer: W) -> Result<()> {
+    let mut geojson_writer = GeoJsonWriter::new_newline_delimited(writer, None);
+    process_geotable(table, &mut geojson_writer)?;
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::io::geojson_lines::read_geojson_lines;
+    use crate::test::point;
+
+    #[test]
+    fn test_write_multiple_batches() {
+        let table = point::table();
+        let (schema, batches, geometry_column_index) = table.into_inner();
+        let batches = vec![batches[0].slice(0, 2), batches[0].slice(2, 1)];
+        let table = GeoTable::try_new(schema, batches, geometry_column_index).unwrap();
+
+        let mut output_buffer = Vec::new();
+        write_geojson_lines(&table, &mut output_buffer).unwrap();
+        let output_string = String::from_utf8(output_buffer).unwrap();
+
+        let lines = output_string.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 3);
+        for line in lines {
+            let feature: serde_json::Value = serde_json::from_str(line).unwrap();
+            assert_eq!(feature["type"], "Feature");
+        }
+
+        let round_trip = read_geojson_lines(output_string.as_bytes(), Default::default()).unwrap();
+        assert_eq!(round_trip.len(), 3);
+    }
+}
@@ -90,7 +90,7 @@ impl FromEWKB for Arc<dyn GeometryArrayTrait> {
     ) -> Result<Self> {
         let geom_arr =
             GeometryCollectionArray::<i64>::from_ewkb(arr, coord_type, metadata, prefer_multi)?;
-        Ok(geom_arr.downcast(true))
+        Ok(geom_arr.downcast(true, false))
     }
 }
 
@@ -137,6 +137,6 @@ impl FromEWKB for Arc<dyn ChunkedGeometryArrayTrait> {
             metadata,
             prefer_multi,
         )?;
-        Ok(geom_arr.downcast(true))
+        Ok(geom_arr.downcast(true, false))
     }
 }
@@ -85,7 +85,7 @@ impl FromWKT for Arc<dyn GeometryArrayTrait> {
     ) -> Result<Self> {
         let geom_arr =
             GeometryCollectionArray::<i64>::from_wkt(arr, coord_type, metadata, prefer_multi)?;
-        Ok(geom_arr.downcast(true))
+        Ok(geom_arr.downcast(true, false))
     }
 }
 
@@ -132,7 +132,7 @@ impl FromWKT for Arc<dyn ChunkedGeometryArrayTrait> {
             metadata,
             prefer_multi,
         )?;
-        Ok(geom_arr.downcast(true))
+        Ok(geom_arr.downcast(true, false))
     }
 }
 
@@ -177,7 +177,7 @@ mod test {
         let geom_arr =
             MixedGeometryArray::<i32>::from_wkt(&arr, Default::default(), Default::default(), true)
                 .unwrap();
-        let geom_arr = geom_arr.downcast(true);
+        let geom_arr = geom_arr.downcast(true, false);
         assert!(matches!(geom_arr.data_type(), GeoDataType::Point(_)));
     }
 }
@@ -0,0 +1,24 @@
+use arrow_array::OffsetSizeTrait;
+use geozero::{GeomProcessor, GeozeroGeometry};
+
+use crate::array::WKBArray;
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+
+/// Each geometry is parsed from WKB as it is processed. Null geometries are skipped.
+impl<O: OffsetSizeTrait> GeozeroGeometry for WKBArray<O> {
+    fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> geozero::error::Result<()>
+    where
+        Self: Sized,
+    {
+        let num_geometries = self.len() - self.null_count();
+        processor.geometrycollection_begin(num_geometries, 0)?;
+
+        for wkb in self.iter().flatten() {
+            wkb.process_geom(processor)?;
+        }
+
+        processor.geometrycollection_end(num_geometries.saturating_sub(1))?;
+        Ok(())
+    }
+}
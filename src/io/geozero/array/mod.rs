@@ -1,3 +1,4 @@
+mod binary;
 mod geometrycollection;
 mod linestring;
 mod mixed;
@@ -6,6 +7,7 @@ mod multipoint;
 mod multipolygon;
 mod point;
 mod polygon;
+mod rect;
 
 pub use linestring::ToLineStringArray;
 pub use mixed::{MixedGeometryStreamBuilder, ToMixedArray};
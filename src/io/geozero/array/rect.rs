@@ -0,0 +1,48 @@
+use crate::array::RectArray;
+use crate::io::geozero::scalar::process_rect;
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+use geozero::{GeomProcessor, GeozeroGeometry};
+
+/// Each rect is processed as a polygon.
+impl GeozeroGeometry for RectArray {
+    fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> geozero::error::Result<()>
+    where
+        Self: Sized,
+    {
+        let num_geometries = self.len();
+        processor.geometrycollection_begin(num_geometries, 0)?;
+
+        for geom_idx in 0..num_geometries {
+            process_rect(&self.value(geom_idx), true, geom_idx, processor)?;
+        }
+
+        processor.geometrycollection_end(num_geometries - 1)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::array::{PolygonArray, RectArray};
+    use geo::Rect;
+    use geozero::ToWkt;
+
+    #[test]
+    fn rect_matches_polygon() {
+        let rects = vec![
+            Rect::new((0., 1.), (2., 3.)),
+            Rect::new((-1., -2.), (4., 5.)),
+        ];
+        let rect_array: RectArray = rects.as_slice().into();
+        let polygons = rects
+            .iter()
+            .map(|rect| rect.to_polygon())
+            .collect::<Vec<_>>();
+        let polygon_array: PolygonArray<i32> = polygons.as_slice().into();
+        assert_eq!(
+            rect_array.to_wkt().unwrap(),
+            polygon_array.to_wkt().unwrap()
+        );
+    }
+}
@@ -6,6 +6,7 @@ use crate::io::geozero::scalar::multipoint::process_multi_point;
 use crate::io::geozero::scalar::multipolygon::process_multi_polygon;
 use crate::io::geozero::scalar::point::process_point;
 use crate::io::geozero::scalar::polygon::process_polygon;
+use crate::io::geozero::scalar::rect::process_rect;
 use crate::io::geozero::ToMixedArray;
 use crate::scalar::{Geometry, OwnedGeometry};
 use crate::trait_::GeometryArrayAccessor;
@@ -26,7 +27,7 @@ pub(crate) fn process_geometry<P: GeomProcessor>(
         GeometryType::MultiLineString(g) => process_multi_line_string(g, geom_idx, processor)?,
         GeometryType::MultiPolygon(g) => process_multi_polygon(g, geom_idx, processor)?,
         GeometryType::GeometryCollection(g) => process_geometry_collection(g, geom_idx, processor)?,
-        GeometryType::Rect(_g) => todo!(),
+        GeometryType::Rect(g) => process_rect(g, true, geom_idx, processor)?,
     };
 
     Ok(())
@@ -7,6 +7,7 @@ mod multipoint;
 mod multipolygon;
 mod point;
 mod polygon;
+mod rect;
 
 pub(crate) use geometry::process_geometry;
 pub(crate) use geometry_collection::process_geometry_collection;
@@ -16,5 +17,6 @@ pub(crate) use multipoint::process_multi_point;
 pub(crate) use multipolygon::process_multi_polygon;
 pub(crate) use point::process_point;
 pub(crate) use polygon::process_polygon;
+pub(crate) use rect::process_rect;
 
 pub use geometry::ToGeometry;
@@ -0,0 +1,41 @@
+use crate::geo_traits::{CoordTrait, RectTrait};
+use crate::scalar::Rect;
+use geozero::{GeomProcessor, GeozeroGeometry};
+
+/// Process a rect as a polygon with one ring, with the same vertex order as
+/// [`geo::Rect::to_polygon`].
+pub(crate) fn process_rect<P: GeomProcessor>(
+    geom: &impl RectTrait<T = f64>,
+    tagged: bool,
+    geom_idx: usize,
+    processor: &mut P,
+) -> geozero::error::Result<()> {
+    let (minx, miny) = (geom.lower().x(), geom.lower().y());
+    let (maxx, maxy) = (geom.upper().x(), geom.upper().y());
+    let coords = [
+        (maxx, miny),
+        (maxx, maxy),
+        (minx, maxy),
+        (minx, miny),
+        (maxx, miny),
+    ];
+
+    processor.polygon_begin(tagged, 1, geom_idx)?;
+    processor.linestring_begin(false, coords.len(), 0)?;
+    for (coord_idx, (x, y)) in coords.into_iter().enumerate() {
+        processor.xy(x, y, coord_idx)?;
+    }
+    processor.linestring_end(false, 0)?;
+    processor.polygon_end(tagged, geom_idx)?;
+
+    Ok(())
+}
+
+impl GeozeroGeometry for Rect<'_> {
+    fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> geozero::error::Result<()>
+    where
+        Self: Sized,
+    {
+        process_rect(self, true, 0, processor)
+    }
+}
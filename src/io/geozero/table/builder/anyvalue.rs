@@ -276,6 +276,20 @@ impl AnyBuilder {
             (AnyBuilder::Binary(arr), ColumnValue::Binary(val)) => {
                 arr.append_value(*val);
             }
+            // Coerce values when the column type was inferred from a set of values with
+            // different types, such as integers and floats.
+            (AnyBuilder::Float64(arr), ColumnValue::Long(val)) => {
+                arr.append_value(*val as f64);
+            }
+            (AnyBuilder::Float64(arr), ColumnValue::ULong(val)) => {
+                arr.append_value(*val as f64);
+            }
+            (AnyBuilder::Float64(arr), ColumnValue::Int(val)) => {
+                arr.append_value(*val as f64);
+            }
+            (AnyBuilder::String(arr), val) => {
+                arr.append_value(val.to_string());
+            }
             // Should be unreachable
             (s, v) => panic!(
                 "Trying to insert a column value {} in the wrong type column {:?}",
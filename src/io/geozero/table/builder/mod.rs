@@ -2,4 +2,5 @@ mod anyvalue;
 mod properties;
 mod table;
 
+pub(crate) use properties::properties_map_field;
 pub use table::{GeoTableBuilder, GeoTableBuilderOptions};
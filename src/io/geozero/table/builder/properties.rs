@@ -1,7 +1,8 @@
 use std::sync::Arc;
 
-use arrow_array::RecordBatch;
-use arrow_schema::{Field, Schema, SchemaBuilder};
+use arrow_array::builder::{MapBuilder, StringBuilder};
+use arrow_array::{ArrayRef, RecordBatch};
+use arrow_schema::{DataType, Field, Fields, Schema, SchemaBuilder};
 use chrono::{DateTime, Utc};
 use geozero::{FeatureProcessor, GeomProcessor, PropertyProcessor};
 
@@ -35,6 +36,29 @@ pub struct PropertiesBatchBuilder {
     /// The counter does not include the current row. So a row counter of 0 is expected if
     /// ingesting the first row.
     row_counter: usize,
+
+    /// The builder of the single properties column, when all properties are stored in a map.
+    map: Option<MapBuilder<StringBuilder, StringBuilder>>,
+}
+
+/// The name of the column holding all properties of a feature in a map.
+pub(crate) const PROPERTIES_MAP_COLUMN: &str = "properties";
+
+/// The field of the column holding all properties of a feature in a `Map<Utf8, Utf8>`, as
+/// created by [`MapBuilder`].
+pub(crate) fn properties_map_field() -> Field {
+    let entries = Fields::from(vec![
+        Field::new("keys", DataType::Utf8, false),
+        Field::new("values", DataType::Utf8, true),
+    ]);
+    Field::new(
+        PROPERTIES_MAP_COLUMN,
+        DataType::Map(
+            Arc::new(Field::new("entries", DataType::Struct(entries), false)),
+            false,
+        ),
+        true,
+    )
 }
 
 impl PropertiesBatchBuilder {
@@ -42,6 +66,22 @@ impl PropertiesBatchBuilder {
         Self {
             columns: IndexMap::new(),
             row_counter: 0,
+            map: None,
+        }
+    }
+
+    /// Create a builder that stores all properties in a single `Map<Utf8, Utf8>` column named
+    /// `properties` instead of one column per property name.
+    ///
+    /// String values are stored as they are, and other values as their JSON representation.
+    pub fn new_map() -> Self {
+        Self {
+            map: Some(MapBuilder::new(
+                None,
+                StringBuilder::new(),
+                StringBuilder::new(),
+            )),
+            ..Self::new()
         }
     }
 
@@ -60,7 +100,10 @@ impl PropertiesBatchBuilder {
         name: &str,
         value: DateTime<Utc>,
     ) -> Result<()> {
-        if let Some(any_builder) = self.columns.get_mut(name) {
+        if let Some(map) = self.map.as_mut() {
+            map.keys().append_value(name);
+            map.values().append_value(value.to_rfc3339());
+        } else if let Some(any_builder) = self.columns.get_mut(name) {
             any_builder.add_timestamp_value(value)?;
         } else {
             // If this column name doesn't yet exist
@@ -75,7 +118,10 @@ impl PropertiesBatchBuilder {
         name: &str,
         value: &geozero::ColumnValue,
     ) -> geozero::error::Result<()> {
-        if let Some(any_builder) = self.columns.get_mut(name) {
+        if let Some(map) = self.map.as_mut() {
+            map.keys().append_value(name);
+            map.values().append_value(value.to_string());
+        } else if let Some(any_builder) = self.columns.get_mut(name) {
             any_builder.add_value(value);
         } else {
             // If this column name doesn't yet exist
@@ -89,7 +135,19 @@ impl PropertiesBatchBuilder {
         Self::from_schema_with_capacity(schema, 0)
     }
 
+    /// Create a builder for properties with the given schema.
+    ///
+    /// A schema with a single `Map<Utf8, Utf8>` field named `properties` creates a builder that
+    /// stores all properties in a map, as with [`new_map`][Self::new_map].
     pub fn from_schema_with_capacity(schema: &Schema, capacity: usize) -> Self {
+        if let [field] = schema.fields().as_ref() {
+            if field.name() == PROPERTIES_MAP_COLUMN
+                && matches!(field.data_type(), DataType::Map(..))
+            {
+                return Self::new_map();
+            }
+        }
+
         let mut columns = IndexMap::with_capacity(schema.fields().len());
         for field in schema.fields().iter() {
             columns.insert(
@@ -101,10 +159,15 @@ impl PropertiesBatchBuilder {
         Self {
             columns,
             row_counter: 0,
+            map: None,
         }
     }
 
     pub fn schema(&self) -> Schema {
+        if self.map.is_some() {
+            return Schema::new(vec![properties_map_field()]);
+        }
+
         let mut schema_builder = SchemaBuilder::with_capacity(self.columns.len());
         for (name, builder) in self.columns.iter() {
             schema_builder.push(Field::new(name, builder.data_type(), true));
@@ -113,6 +176,13 @@ impl PropertiesBatchBuilder {
     }
 
     pub fn finish(self) -> Result<RecordBatch> {
+        if let Some(mut map) = self.map {
+            let array: ArrayRef = Arc::new(map.finish());
+            let field = Field::new(PROPERTIES_MAP_COLUMN, array.data_type().clone(), true);
+            let schema = Schema::new(vec![field]);
+            return Ok(RecordBatch::try_new(Arc::new(schema), vec![array])?);
+        }
+
         let mut schema_builder = SchemaBuilder::with_capacity(self.columns.len());
         let mut columns = Vec::with_capacity(self.columns.len());
 
@@ -176,6 +246,10 @@ impl FeatureProcessor for PropertiesBatchBuilder {
     }
 
     fn feature_end(&mut self, _idx: u64) -> geozero::error::Result<()> {
+        if let Some(map) = self.map.as_mut() {
+            map.append(true)
+                .map_err(|err| geozero::error::GeozeroError::Property(err.to_string()))?;
+        }
         self.row_counter += 1;
         Ok(())
     }
@@ -2,20 +2,22 @@ use std::mem::replace;
 use std::sync::Arc;
 
 use arrow_array::RecordBatch;
-use arrow_schema::{Schema, SchemaBuilder};
+use arrow_schema::Schema;
 use geozero::{FeatureProcessor, GeomProcessor, PropertyProcessor};
 
-use crate::algorithm::native::Downcast;
 use crate::array::metadata::ArrayMetadata;
 use crate::array::CoordType;
 use crate::error::{GeoArrowError, Result};
 use crate::io::geozero::table::builder::properties::PropertiesBatchBuilder;
-use crate::table::GeoTable;
+use crate::io::guards::{GuardCounter, ParseGuards};
+use crate::table::{table_from_batches, GeoTable};
 use crate::trait_::{GeometryArrayBuilder, GeometryArrayTrait};
 
 /// Options for creating a GeoTableBuilder.
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct GeoTableBuilderOptions {
+    /// The metadata, such as the CRS, attached to the generated geometry arrays
     pub metadata: Arc<ArrayMetadata>,
 
     /// The [CoordType] of the generated geometry arrays
@@ -32,6 +34,9 @@ pub struct GeoTableBuilderOptions {
 
     /// The number of rows to be read
     pub num_rows: Option<usize>,
+
+    /// Limits on the size of the geometries read. Unlimited by default.
+    pub parse_guards: ParseGuards,
 }
 
 impl GeoTableBuilderOptions {
@@ -50,8 +55,51 @@ impl GeoTableBuilderOptions {
             properties_schema,
             num_rows,
             metadata,
+            parse_guards: Default::default(),
         }
     }
+
+    /// Set the [CoordType] of the generated geometry arrays.
+    pub fn with_coord_type(mut self, coord_type: CoordType) -> Self {
+        self.coord_type = coord_type;
+        self
+    }
+
+    /// Set whether to prefer multi-geometries for geometries.
+    pub fn with_prefer_multi(mut self, prefer_multi: bool) -> Self {
+        self.prefer_multi = prefer_multi;
+        self
+    }
+
+    /// Set the max number of rows in a batch.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Set the schema of properties, which must not include the schema of the geometry.
+    pub fn with_properties_schema(mut self, properties_schema: Arc<Schema>) -> Self {
+        self.properties_schema = Some(properties_schema);
+        self
+    }
+
+    /// Set the number of rows to be read.
+    pub fn with_num_rows(mut self, num_rows: usize) -> Self {
+        self.num_rows = Some(num_rows);
+        self
+    }
+
+    /// Set the metadata attached to the generated geometry arrays.
+    pub fn with_metadata(mut self, metadata: Arc<ArrayMetadata>) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    /// Set the limits on the size of the geometries read.
+    pub fn with_parse_guards(mut self, parse_guards: ParseGuards) -> Self {
+        self.parse_guards = parse_guards;
+        self
+    }
 }
 
 impl Default for GeoTableBuilderOptions {
@@ -63,6 +111,7 @@ impl Default for GeoTableBuilderOptions {
             properties_schema: None,
             num_rows: None,
             metadata: Default::default(),
+            parse_guards: Default::default(),
         }
     }
 }
@@ -96,6 +145,15 @@ pub struct GeoTableBuilder<G: GeometryArrayBuilder + GeomProcessor> {
 
     /// Builder for the geometries of the current batch
     geom_builder: G,
+
+    /// Counts the coordinates read against the parse guards
+    guards: GuardCounter,
+
+    /// The number of multi geometries and geometry collections open in the current geometry
+    depth: usize,
+
+    /// The error of a parse guard that stopped processing, which geozero can't pass through
+    guard_error: Option<GeoArrowError>,
 }
 
 impl<G: GeometryArrayBuilder + GeomProcessor> GeoTableBuilder<G> {
@@ -148,6 +206,9 @@ impl<G: GeometryArrayBuilder + GeomProcessor> GeoTableBuilder<G> {
             prop_builder,
             geom_arrays,
             geom_builder,
+            guards: GuardCounter::new(options.parse_guards),
+            depth: 0,
+            guard_error: None,
         }
     }
 
@@ -155,6 +216,38 @@ impl<G: GeometryArrayBuilder + GeomProcessor> GeoTableBuilder<G> {
         &mut self.prop_builder
     }
 
+    pub(crate) fn geometry_builder_mut(&mut self) -> &mut G {
+        &mut self.geom_builder
+    }
+
+    /// Convert an error returned by processing a data source into this builder, recovering the
+    /// [`GeoArrowError::ResourceLimitExceeded`] of a parse guard that stopped processing.
+    pub(crate) fn processing_error(&mut self, err: geozero::error::GeozeroError) -> GeoArrowError {
+        self.guard_error.take().unwrap_or(err.into())
+    }
+
+    /// Run a parse guard check, stashing its error so that it can be recovered after processing.
+    fn guard(
+        &mut self,
+        check: impl FnOnce(&mut GuardCounter) -> Result<()>,
+    ) -> geozero::error::Result<()> {
+        if self.guards.is_unlimited() {
+            return Ok(());
+        }
+        check(&mut self.guards).map_err(|err| {
+            let message = err.to_string();
+            self.guard_error = Some(err);
+            geozero::error::GeozeroError::Geometry(message)
+        })
+    }
+
+    /// Open a multi geometry or geometry collection, checking the depth of its members.
+    fn open_collection(&mut self) -> geozero::error::Result<()> {
+        self.depth += 1;
+        let member_depth = self.depth + 1;
+        self.guard(|guards| guards.check_depth(member_depth))
+    }
+
     fn flush_batch(&mut self) -> geozero::error::Result<()> {
         let next_schema = self.prop_builder.schema();
         let coord_type = self.geom_builder.coord_type();
@@ -195,42 +288,7 @@ impl<G: GeometryArrayBuilder + GeomProcessor> GeoTableBuilder<G> {
             self.flush_batch()?;
         }
 
-        if self.batches.is_empty() {
-            return Err(GeoArrowError::General("No rows loaded".to_string()));
-        }
-
-        // TODO: validate schema compatibility of batches and geometry arrays
-
-        let batch = self.batches.first().unwrap();
-        let schema = batch.schema();
-
-        // Set geometry column after property columns
-        let geometry_column_index = schema.fields().len();
-
-        let first_geom_arr = self.geom_arrays.first().unwrap();
-
-        let mut new_schema = SchemaBuilder::with_capacity(schema.fields().len() + 1);
-        schema
-            .fields()
-            .iter()
-            .for_each(|field| new_schema.push(field.clone()));
-        new_schema.push(first_geom_arr.extension_field());
-        let new_schema = Arc::new(new_schema.finish());
-
-        // Need to add the geometry column onto the table
-        let batches = self
-            .batches
-            .into_iter()
-            .zip(self.geom_arrays)
-            .map(|(batch, geom_arr)| {
-                let mut columns = batch.columns().to_vec();
-                columns.push(geom_arr.to_array_ref());
-                Ok(RecordBatch::try_new(new_schema.clone(), columns)?)
-            })
-            .collect::<Result<Vec<_>>>()?;
-
-        let table = GeoTable::try_new(new_schema, batches, geometry_column_index)?;
-        table.downcast(false)
+        table_from_batches(self.batches, self.geom_arrays)
     }
 }
 
@@ -241,6 +299,13 @@ impl<G: GeometryArrayBuilder + GeomProcessor> Default for GeoTableBuilder<G> {
 }
 
 impl<G: GeometryArrayBuilder + GeomProcessor> FeatureProcessor for GeoTableBuilder<G> {
+    fn feature_begin(&mut self, _idx: u64) -> geozero::error::Result<()> {
+        self.guards
+            .start_row(self.batches_len + self.prop_builder.len());
+        self.depth = 0;
+        Ok(())
+    }
+
     fn properties_end(&mut self) -> geozero::error::Result<()> {
         self.prop_builder.properties_end()
     }
@@ -271,6 +336,7 @@ impl<G: GeometryArrayBuilder + GeomProcessor> PropertyProcessor for GeoTableBuil
 
 impl<G: GeometryArrayBuilder + GeomProcessor> GeomProcessor for GeoTableBuilder<G> {
     fn xy(&mut self, x: f64, y: f64, idx: usize) -> geozero::error::Result<()> {
+        self.guard(|guards| guards.add_coords(1))?;
         self.geom_builder.xy(x, y, idx)
     }
 
@@ -287,10 +353,12 @@ impl<G: GeometryArrayBuilder + GeomProcessor> GeomProcessor for GeoTableBuilder<
     }
 
     fn multipoint_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        self.open_collection()?;
         self.geom_builder.multipoint_begin(size, idx)
     }
 
     fn multipoint_end(&mut self, idx: usize) -> geozero::error::Result<()> {
+        self.depth = self.depth.saturating_sub(1);
         self.geom_builder.multipoint_end(idx)
     }
 
@@ -308,10 +376,12 @@ impl<G: GeometryArrayBuilder + GeomProcessor> GeomProcessor for GeoTableBuilder<
     }
 
     fn multilinestring_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        self.open_collection()?;
         self.geom_builder.multilinestring_begin(size, idx)
     }
 
     fn multilinestring_end(&mut self, idx: usize) -> geozero::error::Result<()> {
+        self.depth = self.depth.saturating_sub(1);
         self.geom_builder.multilinestring_end(idx)
     }
 
@@ -329,18 +399,22 @@ impl<G: GeometryArrayBuilder + GeomProcessor> GeomProcessor for GeoTableBuilder<
     }
 
     fn multipolygon_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        self.open_collection()?;
         self.geom_builder.multipolygon_begin(size, idx)
     }
 
     fn multipolygon_end(&mut self, idx: usize) -> geozero::error::Result<()> {
+        self.depth = self.depth.saturating_sub(1);
         self.geom_builder.multipolygon_end(idx)
     }
 
     fn geometrycollection_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        self.open_collection()?;
         self.geom_builder.geometrycollection_begin(size, idx)
     }
 
     fn geometrycollection_end(&mut self, idx: usize) -> geozero::error::Result<()> {
+        self.depth = self.depth.saturating_sub(1);
         self.geom_builder.geometrycollection_end(idx)
     }
 }
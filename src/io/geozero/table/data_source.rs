@@ -4,11 +4,13 @@ use crate::array::geometry::GeometryArray;
 use crate::io::geozero::scalar::process_geometry;
 use crate::table::GeoTable;
 use crate::trait_::GeometryArrayAccessor;
+use arrow_array::cast::AsArray;
 use arrow_array::{
-    BinaryArray, Float16Array, Float32Array, Float64Array, Int16Array, Int32Array, Int64Array,
-    Int8Array, LargeBinaryArray, LargeStringArray, RecordBatch, StringArray, UInt16Array,
-    UInt32Array, UInt64Array, UInt8Array,
+    Array, BinaryArray, BooleanArray, Float16Array, Float32Array, Float64Array, Int16Array,
+    Int32Array, Int64Array, Int8Array, LargeBinaryArray, LargeStringArray, RecordBatch,
+    StringArray, UInt16Array, UInt32Array, UInt64Array, UInt8Array,
 };
+use arrow_cast::display::{ArrayFormatter, FormatOptions};
 use arrow_schema::{DataType, Schema};
 use geozero::error::GeozeroError;
 use geozero::{ColumnValue, FeatureProcessor, GeomProcessor, GeozeroDatasource, PropertyProcessor};
@@ -19,8 +21,8 @@ impl GeozeroDatasource for GeoTable {
     }
 }
 
-fn process_geotable<P: FeatureProcessor>(
-    table: &mut GeoTable,
+pub(crate) fn process_geotable<P: FeatureProcessor>(
+    table: &GeoTable,
     processor: &mut P,
 ) -> Result<(), GeozeroError> {
     let schema = table.schema();
@@ -39,6 +41,13 @@ fn process_geotable<P: FeatureProcessor>(
             processor,
         )?;
         overall_row_idx += batch.num_rows();
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            num_rows = batch.num_rows(),
+            rows_processed = overall_row_idx,
+            "processed batch"
+        );
     }
 
     processor.dataset_end()?;
@@ -72,8 +81,12 @@ fn process_batch<P: FeatureProcessor>(
         )?;
         processor.properties_end()?;
 
+        // Null geometries emit an empty geometry block, which processors may interpret as they
+        // see fit (e.g. the GeoJSON writer emits `null`)
         processor.geometry_begin()?;
-        process_geometry_n(&geometry_column, within_batch_row_idx, processor)?;
+        if geometry_column.is_valid(within_batch_row_idx) {
+            process_geometry_n(&geometry_column, within_batch_row_idx, processor)?;
+        }
         processor.geometry_end()?;
 
         processor.feature_end((within_batch_row_idx + batch_start_idx) as u64)?;
@@ -98,9 +111,23 @@ fn process_properties<P: PropertyProcessor>(
         if column_idx == geometry_column_index {
             continue;
         }
+        // Skip null values entirely rather than writing a placeholder. The property index still
+        // advances, because processors such as the FlatGeobuf writer identify columns by index.
+        if array.is_null(within_batch_row_idx) {
+            property_idx += 1;
+            continue;
+        }
         let name = field.name();
 
         match field.data_type() {
+            DataType::Boolean => {
+                let arr = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+                processor.property(
+                    property_idx,
+                    name,
+                    &ColumnValue::Bool(arr.value(within_batch_row_idx)),
+                )?;
+            }
             DataType::UInt8 => {
                 let arr = array.as_any().downcast_ref::<UInt8Array>().unwrap();
                 processor.property(
@@ -221,8 +248,49 @@ fn process_properties<P: PropertyProcessor>(
                     &ColumnValue::Binary(arr.value(within_batch_row_idx)),
                 )?;
             }
-            // geozero type system also supports json and datetime
-            _ => todo!("json and datetime types"),
+            DataType::Date32
+            | DataType::Date64
+            | DataType::Timestamp(_, _)
+            | DataType::Time32(_)
+            | DataType::Time64(_) => {
+                // Temporal types are formatted as ISO 8601 strings
+                let formatted = format_value(array, within_batch_row_idx)?;
+                processor.property(property_idx, name, &ColumnValue::DateTime(&formatted))?;
+            }
+            DataType::Decimal128(_, _) | DataType::Decimal256(_, _) => {
+                let formatted = format_value(array, within_batch_row_idx)?;
+                let value = formatted
+                    .parse::<f64>()
+                    .map_err(|err| GeozeroError::Property(err.to_string()))?;
+                processor.property(property_idx, name, &ColumnValue::Double(value))?;
+            }
+            DataType::Map(_, _) => {
+                // Each entry of a map column, such as the one read by the GeoJSON reader with
+                // `properties_as_map`, is written as a string property. All entries share the
+                // property index of the column.
+                let entries = array.as_map().value(within_batch_row_idx);
+                let (Some(keys), Some(values)) = (
+                    entries.column(0).as_string_opt::<i32>(),
+                    entries.column(1).as_string_opt::<i32>(),
+                ) else {
+                    return Err(GeozeroError::Property(format!(
+                        "Unsupported property type: {}; map keys and values must be Utf8",
+                        field.data_type()
+                    )));
+                };
+                for (key, value) in keys.iter().zip(values.iter()) {
+                    if let (Some(key), Some(value)) = (key, value) {
+                        processor.property(property_idx, key, &ColumnValue::String(value))?;
+                    }
+                }
+            }
+            // geozero type system also supports json
+            dt => {
+                return Err(GeozeroError::Property(format!(
+                    "Unsupported property type: {}",
+                    dt
+                )))
+            }
         }
         property_idx += 1;
     }
@@ -230,6 +298,12 @@ fn process_properties<P: PropertyProcessor>(
     Ok(())
 }
 
+fn format_value(array: &dyn Array, i: usize) -> Result<String, GeozeroError> {
+    let formatter = ArrayFormatter::try_new(array, &FormatOptions::default())
+        .map_err(|err| GeozeroError::Property(err.to_string()))?;
+    Ok(formatter.value(i).to_string())
+}
+
 fn process_geometry_n<P: GeomProcessor>(
     geometry_column: &GeometryArray<i32>,
     within_batch_row_idx: usize,
@@ -1,4 +1,6 @@
 mod builder;
 mod data_source;
 
+pub(crate) use builder::properties_map_field;
 pub use builder::{GeoTableBuilder, GeoTableBuilderOptions};
+pub(crate) use data_source::process_geotable;
@@ -0,0 +1,211 @@
+//! Limits on the size of parsed geometries, for reading untrusted input.
+//!
+//! A WKB geometry of a few bytes can declare billions of coordinates, and a GeoJSON or WKT
+//! geometry can nest collections arbitrarily deep. [`ParseGuards`] bounds the work and memory
+//! spent on such input: parsing stops as soon as a limit is exceeded, with a
+//! [`GeoArrowError::ResourceLimitExceeded`] naming the limit and the row.
+
+use std::fmt::Display;
+
+use arrow_array::OffsetSizeTrait;
+use geo::{CoordsIter, Geometry};
+
+use crate::array::WKBArray;
+use crate::error::{GeoArrowError, Result};
+
+/// Limits checked while parsing geometries. By default there are no limits.
+///
+/// The nesting depth of a geometry is the number of levels in it: 1 for a Point, LineString or
+/// Polygon, 2 for a multi geometry or a GeometryCollection of those, and 1 more for each further
+/// level of GeometryCollections.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ParseGuards {
+    /// The maximum number of coordinates in a single geometry.
+    pub max_coords_per_geometry: Option<usize>,
+
+    /// The maximum number of coordinates in all geometries read in one call.
+    pub max_total_coords: Option<usize>,
+
+    /// The maximum nesting depth of a geometry.
+    pub max_nesting_depth: Option<usize>,
+}
+
+impl ParseGuards {
+    pub fn with_max_coords_per_geometry(self, max_coords_per_geometry: usize) -> Self {
+        Self {
+            max_coords_per_geometry: Some(max_coords_per_geometry),
+            ..self
+        }
+    }
+
+    pub fn with_max_total_coords(self, max_total_coords: usize) -> Self {
+        Self {
+            max_total_coords: Some(max_total_coords),
+            ..self
+        }
+    }
+
+    pub fn with_max_nesting_depth(self, max_nesting_depth: usize) -> Self {
+        Self {
+            max_nesting_depth: Some(max_nesting_depth),
+            ..self
+        }
+    }
+
+    /// Whether no limit is set.
+    pub fn is_unlimited(&self) -> bool {
+        self.max_coords_per_geometry.is_none()
+            && self.max_total_coords.is_none()
+            && self.max_nesting_depth.is_none()
+    }
+
+    /// Check the geometries of a WKB array against these limits without parsing them.
+    ///
+    /// Only the declared counts of each geometry are read, so a geometry that declares more
+    /// coordinates than allowed is rejected without allocating anything for it. A geometry that
+    /// declares more coordinates than its bytes hold is an error as well.
+    pub fn check_wkb<O: OffsetSizeTrait>(&self, arr: &WKBArray<O>) -> Result<()> {
+        crate::io::wkb::check_wkb_guards(arr, self)
+    }
+}
+
+/// A limit of [`ParseGuards`], displayed as the name of its field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResourceLimit {
+    CoordsPerGeometry,
+    TotalCoords,
+    NestingDepth,
+}
+
+impl Display for ResourceLimit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            ResourceLimit::CoordsPerGeometry => "max_coords_per_geometry",
+            ResourceLimit::TotalCoords => "max_total_coords",
+            ResourceLimit::NestingDepth => "max_nesting_depth",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Counts the coordinates parsed so far against [`ParseGuards`].
+#[derive(Debug, Clone, Default)]
+pub(crate) struct GuardCounter {
+    guards: ParseGuards,
+    row: usize,
+    row_coords: usize,
+    total_coords: usize,
+}
+
+impl GuardCounter {
+    pub(crate) fn new(guards: ParseGuards) -> Self {
+        Self {
+            guards,
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn is_unlimited(&self) -> bool {
+        self.guards.is_unlimited()
+    }
+
+    /// Start counting the coordinates of the geometry at `row`.
+    pub(crate) fn start_row(&mut self, row: usize) {
+        self.row = row;
+        self.row_coords = 0;
+    }
+
+    /// Add `num_coords` coordinates to the current geometry.
+    pub(crate) fn add_coords(&mut self, num_coords: usize) -> Result<()> {
+        self.row_coords = self.row_coords.saturating_add(num_coords);
+        self.total_coords = self.total_coords.saturating_add(num_coords);
+        self.check(
+            ResourceLimit::CoordsPerGeometry,
+            self.guards.max_coords_per_geometry,
+            self.row_coords,
+        )?;
+        self.check(
+            ResourceLimit::TotalCoords,
+            self.guards.max_total_coords,
+            self.total_coords,
+        )
+    }
+
+    /// Check the nesting depth of a geometry within the current geometry.
+    pub(crate) fn check_depth(&self, depth: usize) -> Result<()> {
+        self.check(
+            ResourceLimit::NestingDepth,
+            self.guards.max_nesting_depth,
+            depth,
+        )
+    }
+
+    /// Count an already parsed geometry at `row`.
+    pub(crate) fn add_geometry(&mut self, row: usize, geom: &Geometry) -> Result<()> {
+        self.start_row(row);
+        self.check_depth(geometry_depth(geom))?;
+        self.add_coords(geom.coords_count())
+    }
+
+    fn check(&self, limit: ResourceLimit, max: Option<usize>, value: usize) -> Result<()> {
+        match max {
+            Some(max) if value > max => Err(GeoArrowError::ResourceLimitExceeded {
+                limit,
+                max,
+                row: self.row,
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn geometry_depth(geom: &Geometry) -> usize {
+    match geom {
+        Geometry::GeometryCollection(collection) => {
+            1 + collection.iter().map(geometry_depth).max().unwrap_or(1)
+        }
+        Geometry::MultiPoint(_) | Geometry::MultiLineString(_) | Geometry::MultiPolygon(_) => 2,
+        _ => 1,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use geo::{point, GeometryCollection};
+
+    #[test]
+    fn depth() {
+        let point = Geometry::Point(point!(x: 0., y: 1.));
+        assert_eq!(geometry_depth(&point), 1);
+
+        let collection = Geometry::GeometryCollection(GeometryCollection(vec![point.clone()]));
+        assert_eq!(geometry_depth(&collection), 2);
+
+        let nested = Geometry::GeometryCollection(GeometryCollection(vec![point, collection]));
+        assert_eq!(geometry_depth(&nested), 3);
+    }
+
+    #[test]
+    fn counter() {
+        let guards = ParseGuards::default()
+            .with_max_coords_per_geometry(3)
+            .with_max_total_coords(5);
+        let mut counter = GuardCounter::new(guards);
+        counter.start_row(0);
+        counter.add_coords(3).unwrap();
+        counter.start_row(1);
+        counter.add_coords(2).unwrap();
+        counter.start_row(2);
+        let err = counter.add_coords(1).unwrap_err();
+        assert!(matches!(
+            err,
+            GeoArrowError::ResourceLimitExceeded {
+                limit: ResourceLimit::TotalCoords,
+                max: 5,
+                row: 2
+            }
+        ));
+        assert_eq!(err.to_string(), "max_total_coords of 5 exceeded at row 2");
+    }
+}
@@ -21,3 +21,62 @@ pub fn read_ipc_stream<R: Read>(reader: R) -> Result<GeoTable> {
     let batches = reader.collect::<std::result::Result<Vec<_>, ArrowError>>()?;
     GeoTable::from_arrow(batches, schema, None, None)
 }
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+    use std::sync::Arc;
+
+    use arrow_array::{RecordBatch, UInt8Array};
+    use arrow_schema::{DataType, Field, Schema};
+    use geo::Rect;
+
+    use super::*;
+    use crate::array::{AsChunkedGeometryArray, RectArray};
+    use crate::datatypes::GeoDataType;
+    use crate::io::ipc::write_ipc;
+    use crate::trait_::GeometryArrayAccessor;
+    use crate::GeometryArrayTrait;
+
+    #[test]
+    fn box_round_trip() {
+        let rects = vec![
+            Some(Rect::new((0., 1.), (2., 3.))),
+            None,
+            Some(Rect::new((-1., -2.), (4., 5.))),
+        ];
+        let rect_array: RectArray = rects.clone().into();
+        let fields = vec![
+            Arc::new(Field::new("u8", DataType::UInt8, true)),
+            rect_array.extension_field(),
+        ];
+        let schema = Arc::new(Schema::new(fields));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(UInt8Array::from(vec![1, 2, 3])),
+                rect_array.into_array_ref(),
+            ],
+        )
+        .unwrap();
+        let mut table = GeoTable::try_new(schema, vec![batch], 1).unwrap();
+
+        let mut buf = Vec::new();
+        write_ipc(&mut table, &mut buf).unwrap();
+        let table = read_ipc(Cursor::new(buf)).unwrap();
+
+        let field = table.schema().field(table.geometry_column_index());
+        assert_eq!(
+            field.metadata().get("ARROW:extension:name").unwrap(),
+            "geoarrow.box"
+        );
+        assert!(matches!(field.data_type(), DataType::Struct(_)));
+
+        let geometry = table.geometry().unwrap();
+        assert_eq!(geometry.data_type(), &GeoDataType::Rect);
+        let round_tripped = geometry.as_ref().as_rect().chunks()[0]
+            .iter_geo()
+            .collect::<Vec<_>>();
+        assert_eq!(round_tripped, rects);
+    }
+}
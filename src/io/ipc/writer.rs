@@ -2,11 +2,15 @@ use std::io::Write;
 
 use arrow_ipc::writer::{FileWriter, StreamWriter};
 
-use crate::error::Result;
+use crate::error::{GeoArrowError, Result};
 use crate::table::GeoTable;
 
 /// Write a GeoTable to an Arrow IPC (Feather v2) file
+///
+/// Returns an error if the geometry column has no GeoArrow extension name, as it would otherwise
+/// be written as an untyped column and not be read back as geometries.
 pub fn write_ipc<W: Write>(table: &mut GeoTable, writer: W) -> Result<()> {
+    check_geometry_field(table)?;
     let mut writer = FileWriter::try_new(writer, table.schema())?;
     table
         .batches()
@@ -17,7 +21,11 @@ pub fn write_ipc<W: Write>(table: &mut GeoTable, writer: W) -> Result<()> {
 }
 
 /// Write a GeoTable to an Arrow IPC stream
+///
+/// Returns an error if the geometry column has no GeoArrow extension name, as it would otherwise
+/// be written as an untyped column and not be read back as geometries.
 pub fn write_ipc_stream<W: Write>(table: &mut GeoTable, writer: W) -> Result<()> {
+    check_geometry_field(table)?;
     let mut writer = StreamWriter::try_new(writer, table.schema())?;
     table
         .batches()
@@ -26,3 +34,89 @@ pub fn write_ipc_stream<W: Write>(table: &mut GeoTable, writer: W) -> Result<()>
     writer.finish()?;
     Ok(())
 }
+
+fn check_geometry_field(table: &GeoTable) -> Result<()> {
+    let field = table.schema().field(table.geometry_column_index());
+    if field.metadata().contains_key("ARROW:extension:name") {
+        return Ok(());
+    }
+    Err(GeoArrowError::General(format!(
+        "The geometry column {} has no GeoArrow extension name, so it would be written as an \
+         untyped {} column. Create the table with GeoTable::from_arrow to tag it.",
+        field.name(),
+        field.data_type()
+    )))
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+    use std::sync::Arc;
+
+    use arrow_array::RecordBatch;
+    use arrow_schema::{Field, Schema};
+    use serde_json::json;
+
+    use super::*;
+    use crate::io::ipc::read_ipc_stream;
+
+    #[test]
+    fn stream_round_trip_keeps_metadata() {
+        let mut table = crate::test::point::table();
+        let geometry_column_index = table.geometry_column_index();
+        table
+            .set_crs(geometry_column_index, Some(json!("EPSG:4326")))
+            .unwrap();
+
+        let mut buf = Vec::new();
+        write_ipc_stream(&mut table, &mut buf).unwrap();
+        let round_tripped = read_ipc_stream(Cursor::new(buf)).unwrap();
+
+        assert_eq!(
+            round_tripped
+                .schema()
+                .field(round_tripped.geometry_column_index())
+                .metadata(),
+            table.schema().field(geometry_column_index).metadata()
+        );
+        let crs = table.crs(geometry_column_index).unwrap();
+        assert!(crs.is_some());
+        assert_eq!(
+            round_tripped
+                .crs(round_tripped.geometry_column_index())
+                .unwrap(),
+            crs
+        );
+        assert_eq!(
+            round_tripped.geometry().unwrap().data_type(),
+            table.geometry().unwrap().data_type()
+        );
+    }
+
+    #[test]
+    fn untagged_geometry_column() {
+        let table = crate::test::point::table();
+        let schema = table.schema();
+        let fields = schema
+            .fields()
+            .iter()
+            .map(|field| {
+                Arc::new(Field::new(
+                    field.name(),
+                    field.data_type().clone(),
+                    field.is_nullable(),
+                ))
+            })
+            .collect::<Vec<_>>();
+        let schema = Arc::new(Schema::new(fields));
+        let batches = table
+            .batches()
+            .iter()
+            .map(|batch| RecordBatch::try_new(schema.clone(), batch.columns().to_vec()).unwrap())
+            .collect();
+        let mut table = GeoTable::try_new(schema, batches, table.geometry_column_index()).unwrap();
+
+        let err = write_ipc(&mut table, Vec::new()).unwrap_err();
+        assert!(err.to_string().contains("no GeoArrow extension name"));
+    }
+}
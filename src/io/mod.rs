@@ -3,6 +3,7 @@
 
 #[cfg(feature = "csv")]
 pub mod csv;
+pub mod detect;
 #[cfg(feature = "geozero")]
 pub mod display;
 #[cfg(feature = "flatgeobuf")]
@@ -16,11 +17,14 @@ pub mod geojson;
 pub mod geojson_lines;
 #[cfg(feature = "geos")]
 pub mod geos;
+pub mod guards;
 #[cfg(feature = "geozero")]
 pub mod geozero;
 pub mod ipc;
 #[cfg(feature = "parquet")]
 pub mod parquet;
+pub mod plain_arrow;
 #[cfg(feature = "postgis")]
 pub mod postgis;
 pub mod wkb;
+pub mod wkt;
@@ -1,11 +1,12 @@
 use std::fmt::Debug;
 
-use parquet::file::metadata::{ColumnChunkMetaData, RowGroupMetaData};
+use parquet::file::metadata::{ColumnChunkMetaData, ParquetMetaData, RowGroupMetaData};
 use parquet::file::statistics::Statistics;
 use parquet::schema::types::{ColumnPath, SchemaDescriptor};
 
 use crate::algorithm::native::bounding_rect::BoundingRect;
 use crate::error::{GeoArrowError, Result};
+use crate::io::parquet::metadata::GeoParquetMetadata;
 
 /// A helper for accessing the bounding box of a single row group from the Parquet metadata.
 ///
@@ -45,22 +46,18 @@ impl GeoStatistics {
 
             if xmin_col.is_none() && path_matches(column_path, xmin_path) {
                 xmin_col = Some(idx);
-                continue;
             }
 
             if ymin_col.is_none() && path_matches(column_path, ymin_path) {
                 ymin_col = Some(idx);
-                continue;
             }
 
             if xmax_col.is_none() && path_matches(column_path, xmax_path) {
                 xmax_col = Some(idx);
-                continue;
             }
 
             if ymax_col.is_none() && path_matches(column_path, ymax_path) {
                 ymax_col = Some(idx);
-                continue;
             }
         }
 
@@ -117,6 +114,77 @@ impl GeoStatistics {
     }
 }
 
+/// Select the row groups of a GeoParquet file that may contain geometries of the primary column
+/// intersecting `bbox`.
+///
+/// Row groups are pruned with the column statistics of the bounding box covering declared in the
+/// GeoParquet metadata or, for natively-encoded geometry columns, of the x and y coordinate
+/// columns. Row groups without statistics for these columns are always selected.
+///
+/// Returns `None` if the file has neither a file-level bbox that excludes the query nor columns
+/// whose statistics can be used, in which case every row group needs to be read.
+pub(crate) fn intersecting_row_groups(
+    metadata: &ParquetMetaData,
+    geo_meta: &GeoParquetMetadata,
+    bbox: &BoundingRect,
+) -> Option<Vec<usize>> {
+    let column_meta = geo_meta.columns.get(&geo_meta.primary_column)?;
+
+    // The file-level bbox excludes the query entirely
+    if let Some([minx, miny, maxx, maxy]) = column_meta.bbox.as_deref() {
+        let file_bounds = BoundingRect {
+            minx: *minx,
+            miny: *miny,
+            maxx: *maxx,
+            maxy: *maxy,
+        };
+        if !file_bounds.intersects(bbox) {
+            return Some(vec![]);
+        }
+    }
+
+    let parquet_schema = metadata.file_metadata().schema_descr();
+    let geo_statistics = if let Some(covering) = &column_meta.covering {
+        GeoStatistics::from_schema(
+            parquet_schema,
+            &covering.bbox.xmin,
+            &covering.bbox.ymin,
+            &covering.bbox.xmax,
+            &covering.bbox.ymax,
+        )
+        .ok()?
+    } else if column_meta.encoding != "WKB" {
+        // The bounds of native geometries are the bounds of their x and y coordinates
+        let coord_path = |dim: &str| {
+            parquet_schema.columns().iter().find_map(|column_desc| {
+                let parts = column_desc.path().parts();
+                (parts.first() == Some(&geo_meta.primary_column)
+                    && parts.last().map(String::as_str) == Some(dim))
+                .then(|| parts.to_vec())
+            })
+        };
+        let x_path = coord_path("x")?;
+        let y_path = coord_path("y")?;
+        GeoStatistics::from_schema(parquet_schema, &x_path, &y_path, &x_path, &y_path).ok()?
+    } else {
+        return None;
+    };
+
+    let row_groups = metadata
+        .row_groups()
+        .iter()
+        .enumerate()
+        .filter_map(|(row_group_idx, rg_meta)| {
+            match geo_statistics.get_bbox(rg_meta) {
+                Ok(row_group_bounds) => row_group_bounds.intersects(bbox),
+                Err(_) => true,
+            }
+            .then_some(row_group_idx)
+        })
+        .collect();
+    Some(row_groups)
+}
+
 fn path_matches<T: AsRef<str>>(path: &ColumnPath, search: &[T]) -> bool {
     let parts = path.parts();
     if parts.len() != search.len() {
@@ -139,6 +207,12 @@ fn parse_statistics_f64(column_meta: &ColumnChunkMetaData) -> Result<(f64, f64)>
             "No statistics for column {}",
             column_meta.column_path()
         )))?;
+    if !stats.has_min_max_set() {
+        return Err(GeoArrowError::General(format!(
+            "No min and max statistics for column {}",
+            column_meta.column_path()
+        )));
+    }
     match stats {
         Statistics::Double(ref typed_stats) => Ok((*typed_stats.min(), *typed_stats.max())),
         Statistics::Float(ref typed_stats) => {
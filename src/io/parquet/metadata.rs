@@ -2,12 +2,20 @@ use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 use crate::array::metadata::{ArrayMetadata, Edges};
-use crate::array::CoordType;
+use crate::array::{CoordType, RectArray};
 use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
+use crate::io::parquet::reader::GeometryHandling;
+use crate::table::GeoTable;
+use crate::GeometryArrayTrait;
 
-use arrow_schema::Schema;
+use arrow_array::cast::AsArray;
+use arrow_array::types::Float64Type;
+use arrow_array::{new_null_array, ArrayRef, Float64Array, RecordBatch};
+use arrow_buffer::NullBuffer;
+use arrow_schema::{DataType, Schema};
 use parquet::arrow::arrow_reader::ArrowReaderBuilder;
+use parquet::arrow::ProjectionMask;
 use parquet::file::metadata::FileMetaData;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -33,6 +41,23 @@ pub struct GeoParquetColumnMetadata {
     pub bbox: Option<Vec<f64>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub epoch: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub covering: Option<GeoParquetCovering>,
+}
+
+/// Columns that cover the geometries of a geometry column, as defined in GeoParquet 1.1.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GeoParquetCovering {
+    pub bbox: GeoParquetBboxCovering,
+}
+
+/// The paths to the Parquet columns storing the bounding box of each geometry.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GeoParquetBboxCovering {
+    pub xmin: Vec<String>,
+    pub ymin: Vec<String>,
+    pub xmax: Vec<String>,
+    pub ymax: Vec<String>,
 }
 
 impl GeoParquetMetadata {
@@ -177,7 +202,7 @@ fn parse_geoparquet_metadata(
     metadata: &FileMetaData,
     schema: &Schema,
     coord_type: CoordType,
-) -> Result<(usize, Option<GeoDataType>)> {
+) -> Result<(usize, GeoParquetColumnMetadata, Option<GeoDataType>)> {
     let meta = GeoParquetMetadata::from_parquet_meta(metadata)?;
     let column_meta = meta
         .columns
@@ -198,17 +223,283 @@ fn parse_geoparquet_metadata(
     });
     Ok((
         geometry_column_index,
+        column_meta.clone(),
         infer_geo_data_type(&geometry_types, coord_type)?,
     ))
 }
 
+/// Tag the geometry field with the GeoArrow extension type of its GeoParquet encoding, and carry
+/// the CRS and edges of the GeoParquet column metadata onto the field's extension metadata.
+fn annotate_geometry_field(
+    schema: &Schema,
+    geometry_column_index: usize,
+    column_meta: GeoParquetColumnMetadata,
+) -> Result<Arc<Schema>> {
+    let field = schema.field(geometry_column_index);
+    let mut field_metadata = field.metadata().clone();
+
+    let extension_name = match column_meta.encoding.as_str() {
+        "WKB" => Some("geoarrow.wkb"),
+        "point" => Some("geoarrow.point"),
+        "linestring" => Some("geoarrow.linestring"),
+        "polygon" => Some("geoarrow.polygon"),
+        "multipoint" => Some("geoarrow.multipoint"),
+        "multilinestring" => Some("geoarrow.multilinestring"),
+        "multipolygon" => Some("geoarrow.multipolygon"),
+        _ => None,
+    };
+    if let Some(extension_name) = extension_name {
+        field_metadata.insert(
+            "ARROW:extension:name".to_string(),
+            extension_name.to_string(),
+        );
+    }
+
+    let array_meta = ArrayMetadata::from(column_meta);
+    field_metadata.insert(
+        "ARROW:extension:metadata".to_string(),
+        serde_json::to_string(&array_meta)?,
+    );
+
+    let mut fields = schema.fields().to_vec();
+    fields[geometry_column_index] = Arc::new(field.clone().with_metadata(field_metadata));
+    Ok(Arc::new(Schema::new_with_metadata(
+        fields,
+        schema.metadata().clone(),
+    )))
+}
+
 pub(crate) fn build_arrow_schema<T>(
     builder: &ArrowReaderBuilder<T>,
     coord_type: &CoordType,
 ) -> Result<(Arc<Schema>, usize, Option<GeoDataType>)> {
     let parquet_meta = builder.metadata();
-    let arrow_schema = builder.schema().clone();
-    let (geometry_column_index, target_geo_data_type) =
-        parse_geoparquet_metadata(parquet_meta.file_metadata(), &arrow_schema, *coord_type)?;
+    let (geometry_column_index, column_meta, target_geo_data_type) =
+        parse_geoparquet_metadata(parquet_meta.file_metadata(), builder.schema(), *coord_type)?;
+    let arrow_schema =
+        annotate_geometry_field(builder.schema(), geometry_column_index, column_meta)?;
     Ok((arrow_schema, geometry_column_index, target_geo_data_type))
 }
+
+/// The names of the geometry columns that are not read from the file with the given geometry
+/// handling.
+fn skipped_geometry_columns(
+    geo_meta: &GeoParquetMetadata,
+    geometry_handling: GeometryHandling,
+) -> Result<HashSet<&str>> {
+    match geometry_handling {
+        GeometryHandling::Parse | GeometryHandling::KeepWkb => Ok(HashSet::new()),
+        GeometryHandling::Skip => Ok(geo_meta.columns.keys().map(|name| name.as_str()).collect()),
+        GeometryHandling::BboxOnly => {
+            bbox_covering(geo_meta)?;
+            Ok(HashSet::from([geo_meta.primary_column.as_str()]))
+        }
+    }
+}
+
+/// The bbox covering of the primary geometry column.
+fn bbox_covering(geo_meta: &GeoParquetMetadata) -> Result<&GeoParquetBboxCovering> {
+    geo_meta
+        .columns
+        .get(&geo_meta.primary_column)
+        .and_then(|column_meta| column_meta.covering.as_ref())
+        .map(|covering| &covering.bbox)
+        .ok_or_else(|| {
+            GeoArrowError::General(format!(
+                "Reading only the bbox of column {} requires a bbox covering in the GeoParquet \
+                 metadata",
+                geo_meta.primary_column
+            ))
+        })
+}
+
+/// The projection that leaves out the geometry columns that are not read with the given geometry
+/// handling, so that their column chunks are never fetched or decoded. Returns `None` if all
+/// columns are read.
+pub(crate) fn geometry_projection<T>(
+    builder: &ArrowReaderBuilder<T>,
+    geo_meta: &GeoParquetMetadata,
+    geometry_handling: GeometryHandling,
+) -> Result<Option<ProjectionMask>> {
+    let skipped_columns = skipped_geometry_columns(geo_meta, geometry_handling)?;
+    if skipped_columns.is_empty() {
+        return Ok(None);
+    }
+
+    // Each top-level Arrow field corresponds to a root column of the Parquet schema
+    let root_indices = builder
+        .schema()
+        .fields()
+        .iter()
+        .enumerate()
+        .filter(|(_, field)| !skipped_columns.contains(field.name().as_str()))
+        .map(|(idx, _)| idx);
+    Ok(Some(ProjectionMask::roots(
+        builder.parquet_schema(),
+        root_indices,
+    )))
+}
+
+/// Create a GeoTable from the record batches read from a GeoParquet file, reading the geometry
+/// column according to `geometry_handling`.
+///
+/// With [`GeometryHandling::Skip`] and [`GeometryHandling::BboxOnly`], the batches are expected to
+/// have been read with the projection of [`geometry_projection`].
+pub(crate) fn build_geo_table(
+    mut batches: Vec<RecordBatch>,
+    arrow_schema: Arc<Schema>,
+    geometry_column_index: usize,
+    target_geo_data_type: Option<GeoDataType>,
+    geo_meta: &GeoParquetMetadata,
+    geometry_handling: GeometryHandling,
+) -> Result<GeoTable> {
+    let skipped_columns = skipped_geometry_columns(geo_meta, geometry_handling)?;
+    let read_schema = Arc::new(Schema::new_with_metadata(
+        arrow_schema
+            .fields()
+            .iter()
+            .filter(|field| !skipped_columns.contains(field.name().as_str()))
+            .cloned()
+            .collect::<Vec<_>>(),
+        arrow_schema.metadata().clone(),
+    ));
+
+    // All row groups may have been pruned by a spatial filter
+    if batches.is_empty() {
+        batches.push(RecordBatch::new_empty(read_schema.clone()));
+    }
+
+    if geometry_handling == GeometryHandling::Parse {
+        return GeoTable::from_arrow(
+            batches,
+            arrow_schema,
+            Some(geometry_column_index),
+            target_geo_data_type,
+        );
+    }
+
+    // The batches don't yet have the annotated geometry field
+    let batches = batches
+        .into_iter()
+        .map(|batch| RecordBatch::try_new(read_schema.clone(), batch.columns().to_vec()))
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    if geometry_handling == GeometryHandling::KeepWkb {
+        return GeoTable::try_new(arrow_schema, batches, geometry_column_index);
+    }
+
+    let geometry_field = arrow_schema.field(geometry_column_index);
+    let (output_geometry_field, geometry_columns) = match geometry_handling {
+        GeometryHandling::Skip => {
+            let field = GeoDataType::WKB.to_field(geometry_field.name(), true);
+            let columns = batches
+                .iter()
+                .map(|batch| new_null_array(&DataType::Binary, batch.num_rows()))
+                .collect::<Vec<_>>();
+            (field, columns)
+        }
+        _ => {
+            let covering = bbox_covering(geo_meta)?;
+            let array_metadata = Arc::new(ArrayMetadata::from(
+                geo_meta.columns[&geo_meta.primary_column].clone(),
+            ));
+            let field = GeoDataType::Rect.to_field(geometry_field.name(), true);
+            let columns = batches
+                .iter()
+                .map(|batch| {
+                    Ok(
+                        bbox_covering_array(batch, covering, array_metadata.clone())?
+                            .into_array_ref(),
+                    )
+                })
+                .collect::<Result<Vec<_>>>()?;
+            (field, columns)
+        }
+    };
+
+    // Keep the CRS of the geometry field
+    let mut field_metadata = output_geometry_field.metadata().clone();
+    if let Some(extension_metadata) = geometry_field.metadata().get("ARROW:extension:metadata") {
+        field_metadata.insert(
+            "ARROW:extension:metadata".to_string(),
+            extension_metadata.clone(),
+        );
+    }
+    let output_geometry_field = output_geometry_field.with_metadata(field_metadata);
+
+    // The position of the geometry column among the columns that were read
+    let output_geometry_column_index = arrow_schema.fields()[..geometry_column_index]
+        .iter()
+        .filter(|field| !skipped_columns.contains(field.name().as_str()))
+        .count();
+
+    let mut output_fields = read_schema.fields().to_vec();
+    output_fields.insert(
+        output_geometry_column_index,
+        Arc::new(output_geometry_field),
+    );
+    let output_schema = Arc::new(Schema::new_with_metadata(
+        output_fields,
+        arrow_schema.metadata().clone(),
+    ));
+
+    let output_batches = batches
+        .into_iter()
+        .zip(geometry_columns)
+        .map(|(batch, geometry_column)| {
+            let mut columns = batch.columns().to_vec();
+            columns.insert(output_geometry_column_index, geometry_column);
+            RecordBatch::try_new(output_schema.clone(), columns)
+        })
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    GeoTable::try_new(output_schema, output_batches, output_geometry_column_index)
+}
+
+/// Build a Rect array from the bbox covering columns of a record batch. A rect is null if any of
+/// its bounds is null.
+fn bbox_covering_array(
+    batch: &RecordBatch,
+    covering: &GeoParquetBboxCovering,
+    metadata: Arc<ArrayMetadata>,
+) -> Result<RectArray> {
+    let xmin = covering_column(batch, &covering.xmin)?;
+    let ymin = covering_column(batch, &covering.ymin)?;
+    let xmax = covering_column(batch, &covering.xmax)?;
+    let ymax = covering_column(batch, &covering.ymax)?;
+
+    let mut values = Vec::with_capacity(batch.num_rows() * 4);
+    for i in 0..batch.num_rows() {
+        values.extend_from_slice(&[xmin.value(i), ymin.value(i), xmax.value(i), ymax.value(i)]);
+    }
+
+    let validity = [&ymin, &xmax, &ymax]
+        .iter()
+        .fold(xmin.nulls().cloned(), |acc, arr| {
+            NullBuffer::union(acc.as_ref(), arr.nulls())
+        });
+    Ok(RectArray::new(values.into(), validity, metadata))
+}
+
+/// Look up a bbox covering column by its path, where the first element is the name of a
+/// top-level column and any further elements are names of nested struct fields.
+fn covering_column(batch: &RecordBatch, path: &[String]) -> Result<Float64Array> {
+    let missing_column =
+        || GeoArrowError::General(format!("bbox covering column {} not found", path.join(".")));
+
+    let (name, nested_names) = path.split_first().ok_or_else(missing_column)?;
+    let mut array: ArrayRef = batch
+        .column_by_name(name)
+        .ok_or_else(missing_column)?
+        .clone();
+    for name in nested_names {
+        array = array
+            .as_struct_opt()
+            .and_then(|struct_array| struct_array.column_by_name(name))
+            .ok_or_else(missing_column)?
+            .clone();
+    }
+
+    // Covering columns may be stored as float or double
+    let array = arrow_cast::cast(&array, &DataType::Float64)?;
+    Ok(array.as_primitive::<Float64Type>().clone())
+}
@@ -10,10 +10,7 @@
 //! use std::fs::File;
 //!
 //! let file = File::open("fixtures/geoparquet/nybb.parquet").unwrap();
-//! let options = GeoParquetReaderOptions {
-//!     batch_size: 65536,
-//!     ..Default::default()
-//! };
+//! let options = GeoParquetReaderOptions::default().with_batch_size(65536);
 //! let output_geotable = read_geoparquet(file, options).unwrap();
 //! println!("GeoTable schema: {}", output_geotable.schema());
 //! ```
@@ -30,10 +27,7 @@
 //!     let file = File::open("fixtures/geoparquet/nybb.parquet")
 //!         .await
 //!         .unwrap();
-//!     let options = GeoParquetReaderOptions {
-//!         batch_size: 65536,
-//!         ..Default::default()
-//!     };
+//!     let options = GeoParquetReaderOptions::default().with_batch_size(65536);
 //!     let output_geotable = read_geoparquet_async(file, options).await.unwrap();
 //!     println!("GeoTable schema: {}", output_geotable.schema());
 //! }
@@ -46,11 +40,13 @@ mod reader;
 mod test;
 mod writer;
 
-pub use reader::{read_geoparquet, GeoParquetReaderOptions};
+pub use reader::{read_geoparquet, GeoParquetReaderOptions, GeometryHandling};
 #[cfg(feature = "parquet_async")]
 pub use reader::{read_geoparquet_async, ParquetDataset, ParquetFile, ParquetReaderOptions};
 pub use writer::{
-    write_geoparquet, GeoParquetWriter, GeoParquetWriterEncoding, GeoParquetWriterOptions,
+    write_geoparquet, write_geoparquet_spatially_sorted, GeoParquetWriter,
+    GeoParquetWriterEncoding, GeoParquetWriterOptions, ParquetColumnOptions, QuadkeyColumnOptions,
+    SpatialSort, SpatialSortOptions,
 };
 #[cfg(feature = "parquet_async")]
-pub use writer::{write_geoparquet_async, GeoParquetWriterAsync};
+pub use writer::{write_geoparquet_async, write_geoparquet_to_object_store, GeoParquetWriterAsync};
@@ -3,9 +3,11 @@ use std::fmt::Debug;
 use crate::algorithm::native::bounding_rect::BoundingRect;
 use crate::array::{CoordType, PolygonArray, RectBuilder};
 use crate::error::{GeoArrowError, Result};
-use crate::io::parquet::common::GeoStatistics;
-use crate::io::parquet::metadata::{build_arrow_schema, GeoParquetMetadata};
-use crate::io::parquet::reader::GeoParquetReaderOptions;
+use crate::io::parquet::common::{intersecting_row_groups, GeoStatistics};
+use crate::io::parquet::metadata::{
+    build_arrow_schema, build_geo_table, geometry_projection, GeoParquetMetadata,
+};
+use crate::io::parquet::reader::{GeoParquetReaderOptions, GeometryHandling};
 use crate::table::GeoTable;
 
 use arrow_schema::SchemaRef;
@@ -16,42 +18,122 @@ use parquet::arrow::ProjectionMask;
 use serde_json::Value;
 
 /// Asynchronously read a GeoParquet file to a GeoTable.
+///
+/// See [`read_geoparquet`][crate::io::parquet::read_geoparquet] for details.
 pub async fn read_geoparquet_async<R: AsyncFileReader + Unpin + Send + 'static>(
     reader: R,
     options: GeoParquetReaderOptions,
 ) -> Result<GeoTable> {
-    let builder = ParquetRecordBatchStreamBuilder::new(reader)
+    let mut builder = ParquetRecordBatchStreamBuilder::new(reader)
         .await?
         .with_batch_size(options.batch_size);
-    read_builder(builder, &options.coord_type).await
+
+    let geo_meta = GeoParquetMetadata::from_parquet_meta(builder.metadata().file_metadata())?;
+    if let Some(projection) = geometry_projection(&builder, &geo_meta, options.geometry_handling)? {
+        builder = builder.with_projection(projection);
+    }
+
+    if let Some([minx, miny, maxx, maxy]) = options.bbox {
+        let bbox = BoundingRect {
+            minx,
+            miny,
+            maxx,
+            maxy,
+        };
+        if let Some(row_groups) = intersecting_row_groups(builder.metadata(), &geo_meta, &bbox) {
+            builder = builder.with_row_groups(row_groups);
+        }
+    }
+
+    read_builder(builder, &options.coord_type, options.geometry_handling).await
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        name = "read_geoparquet_async",
+        skip_all,
+        fields(
+            num_row_groups = builder.metadata().num_row_groups(),
+            num_batches = tracing::field::Empty,
+            num_rows = tracing::field::Empty,
+        )
+    )
+)]
 async fn read_builder<R: AsyncFileReader + Unpin + Send + 'static>(
     builder: ParquetRecordBatchStreamBuilder<R>,
     coord_type: &CoordType,
+    geometry_handling: GeometryHandling,
 ) -> Result<GeoTable> {
     let (arrow_schema, geometry_column_index, target_geo_data_type) =
         build_arrow_schema(&builder, coord_type)?;
+    let geo_meta = GeoParquetMetadata::from_parquet_meta(builder.metadata().file_metadata())?;
 
     let stream = builder.build()?;
-    let batches = stream.try_collect::<_>().await?;
+    let batches: Vec<_> = stream.try_collect().await?;
+
+    #[cfg(feature = "tracing")]
+    {
+        let span = tracing::Span::current();
+        span.record("num_batches", batches.len());
+        span.record(
+            "num_rows",
+            batches.iter().map(|batch| batch.num_rows()).sum::<usize>(),
+        );
+    }
 
-    GeoTable::from_arrow(
+    build_geo_table(
         batches,
         arrow_schema,
-        Some(geometry_column_index),
+        geometry_column_index,
         target_geo_data_type,
+        &geo_meta,
+        geometry_handling,
     )
 }
 
+/// Options for reading a [`ParquetFile`] or [`ParquetDataset`].
 #[derive(Clone, Default)]
 pub struct ParquetReaderOptions {
+    /// The number of rows in each batch.
     batch_size: Option<usize>,
+
+    /// The maximum number of rows to read.
     limit: Option<usize>,
+
+    /// The number of rows to skip before reading.
     offset: Option<usize>,
+
+    /// The columns to read.
     projection: Option<ProjectionMask>,
 }
 
+impl ParquetReaderOptions {
+    /// Set the number of rows in each batch.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = Some(batch_size);
+        self
+    }
+
+    /// Set the maximum number of rows to read.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Set the number of rows to skip before reading.
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Only read the columns selected by this projection.
+    pub fn with_projection(mut self, projection: ProjectionMask) -> Self {
+        self.projection = Some(projection);
+        self
+    }
+}
+
 /// To create from an object-store item:
 ///
 /// ```notest
@@ -243,7 +325,7 @@ impl<R: AsyncFileReader + Clone + Unpin + Send + 'static> ParquetFile<R> {
     /// Read into a table.
     pub async fn read(&self, coord_type: &CoordType) -> Result<GeoTable> {
         let builder = self.builder();
-        read_builder(builder, coord_type).await
+        read_builder(builder, coord_type, GeometryHandling::Parse).await
     }
 
     /// Read the specified row groups into a table.
@@ -253,7 +335,7 @@ impl<R: AsyncFileReader + Clone + Unpin + Send + 'static> ParquetFile<R> {
         coord_type: &CoordType,
     ) -> Result<GeoTable> {
         let builder = self.builder().with_row_groups(row_groups);
-        read_builder(builder, coord_type).await
+        read_builder(builder, coord_type, GeometryHandling::Parse).await
     }
 }
 
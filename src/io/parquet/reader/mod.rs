@@ -4,7 +4,7 @@ mod options;
 // mod parse;
 mod sync;
 
-pub use options::GeoParquetReaderOptions;
+pub use options::{GeoParquetReaderOptions, GeometryHandling};
 #[cfg(feature = "parquet_async")]
 pub use r#async::{read_geoparquet_async, ParquetDataset, ParquetFile, ParquetReaderOptions};
 pub use sync::read_geoparquet;
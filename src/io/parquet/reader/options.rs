@@ -1,6 +1,31 @@
 use crate::array::CoordType;
 
+/// How the geometry columns of a GeoParquet file are read.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GeometryHandling {
+    /// Parse WKB-encoded geometries to GeoArrow-native arrays.
+    #[default]
+    Parse,
+
+    /// Keep WKB-encoded geometries as a WKB array, without parsing them.
+    KeepWkb,
+
+    /// Don't read the geometry columns from the file.
+    ///
+    /// A table must have a geometry column, so the primary geometry column is replaced by an
+    /// all-null WKB column of the same name. Other geometry columns are omitted from the table.
+    Skip,
+
+    /// Don't read the primary geometry column, and instead build a Rect geometry column from the
+    /// bbox covering columns declared in the GeoParquet metadata.
+    ///
+    /// Returns an error if the primary geometry column has no bbox covering.
+    BboxOnly,
+}
+
 /// Options for reading GeoParquet
+#[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct GeoParquetReaderOptions {
     /// The number of rows in each batch.
     pub batch_size: usize,
@@ -10,10 +35,18 @@ pub struct GeoParquetReaderOptions {
     /// Note that for now this is only used when parsing from WKB-encoded geometries.
     pub coord_type: CoordType,
 
-    /// A spatial filter for reading rows.
+    /// A spatial filter for reading rows, as `[xmin, ymin, xmax, ymax]`.
+    ///
+    /// Row groups whose bounds don't intersect this bbox are skipped. The bounds of each row group
+    /// are taken from the statistics of the bbox covering columns declared in the GeoParquet
+    /// metadata, or of the coordinate columns of natively-encoded geometries. Rows within the
+    /// selected row groups are not filtered, and files without such statistics are read in full.
     ///
     /// If set to `None`, no spatial filtering will be performed.
-    pub bbox: Option<(f64, f64, f64, f64)>,
+    pub bbox: Option<[f64; 4]>,
+
+    /// How to read the geometry columns.
+    pub geometry_handling: GeometryHandling,
 }
 
 impl Default for GeoParquetReaderOptions {
@@ -22,6 +55,33 @@ impl Default for GeoParquetReaderOptions {
             batch_size: 65535,
             coord_type: Default::default(),
             bbox: None,
+            geometry_handling: Default::default(),
         }
     }
 }
+
+impl GeoParquetReaderOptions {
+    /// Set the number of rows in each batch.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Set the GeoArrow coordinate type to use in the geometry arrays.
+    pub fn with_coord_type(mut self, coord_type: CoordType) -> Self {
+        self.coord_type = coord_type;
+        self
+    }
+
+    /// Only read row groups that intersect this bbox, as `[xmin, ymin, xmax, ymax]`.
+    pub fn with_bbox(mut self, bbox: [f64; 4]) -> Self {
+        self.bbox = Some(bbox);
+        self
+    }
+
+    /// Set how to read the geometry columns.
+    pub fn with_geometry_handling(mut self, geometry_handling: GeometryHandling) -> Self {
+        self.geometry_handling = geometry_handling;
+        self
+    }
+}
@@ -1,5 +1,9 @@
+use crate::algorithm::native::bounding_rect::BoundingRect;
 use crate::error::Result;
-use crate::io::parquet::metadata::build_arrow_schema;
+use crate::io::parquet::common::intersecting_row_groups;
+use crate::io::parquet::metadata::{
+    build_arrow_schema, build_geo_table, geometry_projection, GeoParquetMetadata,
+};
 use crate::io::parquet::GeoParquetReaderOptions;
 use crate::table::GeoTable;
 
@@ -7,28 +11,101 @@ use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
 use parquet::file::reader::ChunkReader;
 
 /// Read a GeoParquet file to a GeoTable.
+///
+/// The `geo` file metadata determines the geometry column, whose CRS is stored on the geometry
+/// field of the output table. `options.geometry_handling` controls whether the geometries are
+/// parsed, kept as WKB, skipped, or replaced by their bbox covering. If `options.bbox` is set, row
+/// groups that don't intersect it are skipped.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        skip_all,
+        fields(
+            batch_size = options.batch_size,
+            geometry_handling = ?options.geometry_handling,
+            num_row_groups = tracing::field::Empty,
+            num_bytes = tracing::field::Empty,
+            num_batches = tracing::field::Empty,
+            num_rows = tracing::field::Empty,
+        )
+    )
+)]
 pub fn read_geoparquet<R: ChunkReader + 'static>(
     reader: R,
     options: GeoParquetReaderOptions,
 ) -> Result<GeoTable> {
-    let builder =
+    let mut builder =
         ParquetRecordBatchReaderBuilder::try_new(reader)?.with_batch_size(options.batch_size);
 
     let (arrow_schema, geometry_column_index, target_geo_data_type) =
         build_arrow_schema(&builder, &options.coord_type)?;
 
+    #[cfg(feature = "tracing")]
+    {
+        let file_metadata = builder.metadata();
+        let num_bytes: i64 = file_metadata
+            .row_groups()
+            .iter()
+            .map(|row_group| row_group.total_byte_size())
+            .sum();
+        let span = tracing::Span::current();
+        span.record("num_row_groups", file_metadata.num_row_groups());
+        span.record("num_bytes", num_bytes);
+    }
+
+    let geo_meta = GeoParquetMetadata::from_parquet_meta(builder.metadata().file_metadata())?;
+    if let Some(projection) = geometry_projection(&builder, &geo_meta, options.geometry_handling)? {
+        builder = builder.with_projection(projection);
+    }
+
+    if let Some([minx, miny, maxx, maxy]) = options.bbox {
+        let bbox = BoundingRect {
+            minx,
+            miny,
+            maxx,
+            maxy,
+        };
+        if let Some(row_groups) = intersecting_row_groups(builder.metadata(), &geo_meta, &bbox) {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                num_row_groups = row_groups.len(),
+                "selected row groups intersecting bbox"
+            );
+            builder = builder.with_row_groups(row_groups);
+        }
+    }
+
     let reader = builder.build()?;
 
     let mut batches = vec![];
     for maybe_batch in reader {
-        batches.push(maybe_batch?);
+        let batch = maybe_batch?;
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            batch_index = batches.len(),
+            num_rows = batch.num_rows(),
+            "read GeoParquet batch"
+        );
+        batches.push(batch);
+    }
+
+    #[cfg(feature = "tracing")]
+    {
+        let span = tracing::Span::current();
+        span.record("num_batches", batches.len());
+        span.record(
+            "num_rows",
+            batches.iter().map(|batch| batch.num_rows()).sum::<usize>(),
+        );
     }
 
-    GeoTable::from_arrow(
+    build_geo_table(
         batches,
         arrow_schema,
-        Some(geometry_column_index),
+        geometry_column_index,
         target_geo_data_type,
+        &geo_meta,
+        options.geometry_handling,
     )
 }
 
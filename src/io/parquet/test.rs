@@ -9,11 +9,768 @@ use crate::io::parquet::{read_geoparquet, write_geoparquet};
 #[test]
 fn round_trip_nybb() {
     let file = File::open("fixtures/geoparquet/nybb.parquet").unwrap();
-    let mut table = read_geoparquet(file, Default::default()).unwrap();
+    let table = read_geoparquet(file, Default::default()).unwrap();
 
     let mut buf = vec![];
-    write_geoparquet(&mut table, Cursor::new(&mut buf), &Default::default()).unwrap();
+    write_geoparquet(&table, Cursor::new(&mut buf), &Default::default()).unwrap();
     let again = read_geoparquet(Bytes::from(buf), Default::default()).unwrap();
     assert_eq!(table.schema(), again.schema());
     // assert_eq!(table.geometry().unwrap().ch, again.geometry().unwrap());
 }
+
+#[test]
+fn quadkey_column_bloom_filter() {
+    use arrow_array::{RecordBatch, StringArray};
+    use parquet::arrow::arrow_reader::{
+        ArrowPredicateFn, ParquetRecordBatchReaderBuilder, RowFilter,
+    };
+    use parquet::arrow::ProjectionMask;
+    use parquet::file::properties::{ReaderProperties, WriterProperties};
+    use parquet::file::reader::{FileReader, RowGroupReader};
+    use parquet::file::serialized_reader::{ReadOptionsBuilder, SerializedFileReader};
+
+    use crate::algorithm::native::Quadkey;
+    use crate::io::parquet::{GeoParquetWriterOptions, QuadkeyColumnOptions};
+    use crate::test::point;
+    use crate::GeometryArrayTrait;
+
+    // Write each row to its own row group
+    let table = point::table();
+    let options = GeoParquetWriterOptions {
+        writer_properties: Some(
+            WriterProperties::builder()
+                .set_max_row_group_size(1)
+                .build(),
+        ),
+        quadkey_column: Some(QuadkeyColumnOptions::new("quadkey", 8)),
+        ..Default::default()
+    };
+    let mut buf = vec![];
+    write_geoparquet(&table, Cursor::new(&mut buf), &options).unwrap();
+    let buf = Bytes::from(buf);
+
+    let point_array = point::point_array();
+    let target = point_array
+        .as_ref()
+        .quadkey(8)
+        .unwrap()
+        .value(1)
+        .to_string();
+
+    let read_options = ReadOptionsBuilder::new()
+        .with_reader_properties(
+            ReaderProperties::builder()
+                .set_read_bloom_filter(true)
+                .build(),
+        )
+        .build();
+    let reader = SerializedFileReader::new_with_options(buf.clone(), read_options).unwrap();
+    let metadata = reader.metadata();
+    assert_eq!(metadata.num_row_groups(), 3);
+
+    let quadkey_idx = metadata
+        .file_metadata()
+        .schema_descr()
+        .columns()
+        .iter()
+        .position(|column| column.name() == "quadkey")
+        .unwrap();
+    let quadkey_column_meta = metadata.row_group(0).column(quadkey_idx);
+    assert!(quadkey_column_meta.bloom_filter_offset().is_some());
+    assert!(quadkey_column_meta.column_index_offset().is_some());
+
+    // Use the bloom filter to select candidate row groups
+    let row_groups = (0..metadata.num_row_groups())
+        .filter(|i| {
+            let row_group = reader.get_row_group(*i).unwrap();
+            let bloom_filter = row_group.get_column_bloom_filter(quadkey_idx).unwrap();
+            bloom_filter.check(&target.as_str())
+        })
+        .collect::<Vec<_>>();
+    assert!(row_groups.contains(&1));
+    assert!(row_groups.len() < 3);
+
+    // Then filter rows within the candidate row groups
+    let builder = ParquetRecordBatchReaderBuilder::try_new(buf).unwrap();
+    let mask = ProjectionMask::leaves(builder.parquet_schema(), [quadkey_idx]);
+    let predicate = ArrowPredicateFn::new(mask, move |batch: RecordBatch| {
+        let scalar = StringArray::new_scalar(target.clone());
+        arrow::compute::kernels::cmp::eq(batch.column(0), &scalar)
+    });
+    let reader = builder
+        .with_row_groups(row_groups)
+        .with_row_filter(RowFilter::new(vec![Box::new(predicate)]))
+        .build()
+        .unwrap();
+    let num_rows = reader.map(|batch| batch.unwrap().num_rows()).sum::<usize>();
+    assert_eq!(num_rows, 1);
+}
+
+#[test]
+fn write_geoparquet_metadata() {
+    use std::sync::Arc;
+
+    use arrow_array::RecordBatch;
+    use arrow_schema::Schema;
+    use parquet::file::reader::FileReader;
+    use parquet::file::serialized_reader::SerializedFileReader;
+    use serde_json::json;
+
+    use crate::io::parquet::metadata::GeoParquetMetadata;
+    use crate::table::GeoTable;
+    use crate::test::point;
+    use crate::GeometryArrayTrait;
+
+    let projjson = json!({"type": "GeographicCRS", "name": "WGS 84"});
+    let point_array = point::point_array();
+    let mut field_metadata = point_array.extension_field().metadata().clone();
+    field_metadata.insert(
+        "ARROW:extension:metadata".to_string(),
+        json!({ "crs": projjson }).to_string(),
+    );
+    let field = point_array
+        .extension_field()
+        .as_ref()
+        .clone()
+        .with_metadata(field_metadata);
+    let schema = Arc::new(Schema::new(vec![field]));
+    let batch = RecordBatch::try_new(schema.clone(), vec![point_array.into_array_ref()]).unwrap();
+    let table = GeoTable::try_new(schema, vec![batch], 0).unwrap();
+
+    let mut buf = vec![];
+    write_geoparquet(&table, Cursor::new(&mut buf), &Default::default()).unwrap();
+
+    let reader = SerializedFileReader::new(Bytes::from(buf)).unwrap();
+    let geo_meta =
+        GeoParquetMetadata::from_parquet_meta(reader.metadata().file_metadata()).unwrap();
+    assert_eq!(geo_meta.version, "1.1.0");
+    assert_eq!(geo_meta.primary_column, "geometry");
+
+    let column_meta = &geo_meta.columns["geometry"];
+    assert_eq!(column_meta.encoding, "WKB");
+    assert_eq!(column_meta.geometry_types, vec!["Point".to_string()]);
+    assert_eq!(column_meta.bbox, Some(vec![0., 1., 2., 3.]));
+    assert_eq!(column_meta.crs, Some(projjson));
+}
+
+#[test]
+fn write_geoparquet_mixed_geometry_types() {
+    use std::sync::Arc;
+
+    use arrow_array::RecordBatch;
+    use arrow_schema::Schema;
+    use parquet::file::reader::FileReader;
+    use parquet::file::serialized_reader::SerializedFileReader;
+
+    use crate::array::MixedGeometryArray;
+    use crate::io::parquet::metadata::GeoParquetMetadata;
+    use crate::table::GeoTable;
+    use crate::test::{linestring, point};
+    use crate::GeometryArrayTrait;
+
+    let geoms: Vec<geo::Geometry> = vec![
+        point::p0().into(),
+        linestring::ls0().into(),
+        point::p1().into(),
+    ];
+    let mixed_array = MixedGeometryArray::<i32>::try_from(geoms.as_slice()).unwrap();
+    let schema = Arc::new(Schema::new(vec![mixed_array.extension_field()]));
+    let batch = RecordBatch::try_new(schema.clone(), vec![mixed_array.into_array_ref()]).unwrap();
+    let table = GeoTable::try_new(schema, vec![batch], 0).unwrap();
+
+    let mut buf = vec![];
+    write_geoparquet(&table, Cursor::new(&mut buf), &Default::default()).unwrap();
+
+    let reader = SerializedFileReader::new(Bytes::from(buf)).unwrap();
+    let geo_meta =
+        GeoParquetMetadata::from_parquet_meta(reader.metadata().file_metadata()).unwrap();
+    let column_meta = &geo_meta.columns[&geo_meta.primary_column];
+    assert_eq!(
+        column_meta.geometry_types,
+        vec!["LineString".to_string(), "Point".to_string()]
+    );
+    assert_eq!(column_meta.bbox, Some(vec![0., 1., 1., 2.]));
+}
+
+#[test]
+fn write_geoparquet_row_group_size_and_compression() {
+    use parquet::basic::Compression;
+    use parquet::file::reader::FileReader;
+    use parquet::file::serialized_reader::SerializedFileReader;
+
+    use crate::io::parquet::GeoParquetWriterOptions;
+    use crate::test::point;
+
+    let table = point::table();
+    let options = GeoParquetWriterOptions::default()
+        .with_row_group_size(2)
+        .with_compression(Compression::SNAPPY);
+    let mut buf = vec![];
+    write_geoparquet(&table, Cursor::new(&mut buf), &options).unwrap();
+
+    let reader = SerializedFileReader::new(Bytes::from(buf)).unwrap();
+    let metadata = reader.metadata();
+    assert_eq!(metadata.num_row_groups(), 2);
+    assert_eq!(metadata.row_group(0).num_rows(), 2);
+    for column in metadata.row_group(0).columns() {
+        assert_eq!(column.compression(), Compression::SNAPPY);
+    }
+}
+
+#[test]
+fn write_parquet_without_geometry_column() {
+    use std::sync::Arc;
+
+    use arrow_array::RecordBatch;
+    use arrow_schema::{Field, Schema};
+    use parquet::file::reader::FileReader;
+    use parquet::file::serialized_reader::SerializedFileReader;
+
+    use crate::io::parquet::GeoParquetWriter;
+    use crate::test::properties;
+
+    let schema = Arc::new(Schema::new(vec![Field::new(
+        "u8",
+        arrow_schema::DataType::UInt8,
+        true,
+    )]));
+    let batch =
+        RecordBatch::try_new(schema.clone(), vec![Arc::new(properties::u8_array())]).unwrap();
+
+    let mut buf = vec![];
+    let mut writer =
+        GeoParquetWriter::try_new(Cursor::new(&mut buf), &schema, &Default::default()).unwrap();
+    writer.write_batch(&batch).unwrap();
+    writer.finish().unwrap();
+
+    let reader = SerializedFileReader::new(Bytes::from(buf)).unwrap();
+    let file_metadata = reader.metadata().file_metadata();
+    assert_eq!(file_metadata.num_rows(), 3);
+    let has_geo_key = file_metadata
+        .key_value_metadata()
+        .is_some_and(|kv_metadata| kv_metadata.iter().any(|kv| kv.key == "geo"));
+    assert!(!has_geo_key);
+}
+
+#[test]
+fn read_geoparquet_bbox_prunes_row_groups() {
+    use crate::io::parquet::{
+        GeoParquetReaderOptions, GeoParquetWriterEncoding, GeoParquetWriterOptions,
+    };
+    use crate::test::point;
+
+    // Write each row to its own row group, with native encoding so that the coordinate columns
+    // have statistics
+    let table = point::table();
+    let write_options = GeoParquetWriterOptions::default()
+        .with_encoding(GeoParquetWriterEncoding::Native)
+        .with_row_group_size(1);
+    let mut buf = vec![];
+    write_geoparquet(&table, Cursor::new(&mut buf), &write_options).unwrap();
+    let buf = Bytes::from(buf);
+
+    let options = GeoParquetReaderOptions::default()
+        .with_bbox([0.5, 1.5, 1.5, 2.5])
+        .with_batch_size(1);
+    let filtered = read_geoparquet(buf.clone(), options).unwrap();
+    assert_eq!(filtered.len(), 1);
+
+    // A bbox outside of the file bounds selects no row groups
+    let options = GeoParquetReaderOptions {
+        bbox: Some([10., 10., 20., 20.]),
+        ..Default::default()
+    };
+    let empty = read_geoparquet(buf, options).unwrap();
+    assert_eq!(empty.len(), 0);
+}
+
+#[test]
+fn read_geoparquet_crs_and_wkb() {
+    use std::sync::Arc;
+
+    use arrow_array::RecordBatch;
+    use arrow_schema::Schema;
+    use serde_json::{json, Value};
+
+    use crate::datatypes::GeoDataType;
+    use crate::io::parquet::{GeoParquetReaderOptions, GeometryHandling};
+    use crate::table::GeoTable;
+    use crate::test::point;
+    use crate::GeometryArrayTrait;
+
+    let projjson = json!({"type": "GeographicCRS", "name": "WGS 84"});
+    let point_array = point::point_array();
+    let mut field_metadata = point_array.extension_field().metadata().clone();
+    field_metadata.insert(
+        "ARROW:extension:metadata".to_string(),
+        json!({ "crs": projjson }).to_string(),
+    );
+    let field = point_array
+        .extension_field()
+        .as_ref()
+        .clone()
+        .with_metadata(field_metadata);
+    let schema = Arc::new(Schema::new(vec![field]));
+    let batch = RecordBatch::try_new(schema.clone(), vec![point_array.into_array_ref()]).unwrap();
+    let table = GeoTable::try_new(schema, vec![batch], 0).unwrap();
+
+    let mut buf = vec![];
+    write_geoparquet(&table, Cursor::new(&mut buf), &Default::default()).unwrap();
+    let buf = Bytes::from(buf);
+
+    let field_crs = |table: &GeoTable| {
+        let field = table.schema().field(table.geometry_column_index());
+        let extension_metadata: Value =
+            serde_json::from_str(&field.metadata()["ARROW:extension:metadata"]).unwrap();
+        extension_metadata["crs"].clone()
+    };
+
+    let native = read_geoparquet(buf.clone(), Default::default()).unwrap();
+    assert_eq!(
+        native.geometry_data_type().unwrap(),
+        GeoDataType::Point(Default::default())
+    );
+    assert_eq!(field_crs(&native), projjson);
+    assert_eq!(native.validate_geoarrow(), vec![]);
+
+    let options = GeoParquetReaderOptions {
+        geometry_handling: GeometryHandling::KeepWkb,
+        ..Default::default()
+    };
+    let wkb = read_geoparquet(buf, options).unwrap();
+    assert_eq!(wkb.len(), 3);
+    assert_eq!(wkb.geometry_data_type().unwrap(), GeoDataType::WKB);
+    assert_eq!(field_crs(&wkb), projjson);
+    assert_eq!(wkb.validate_geoarrow(), vec![]);
+}
+
+/// Write a GeoParquet file with a WKB geometry column and a bbox covering struct column, as
+/// described in GeoParquet 1.1.
+fn write_covering_fixture() -> Bytes {
+    use std::sync::Arc;
+
+    use arrow_array::{ArrayRef, Float64Array, Int32Array, RecordBatch, StructArray};
+    use arrow_schema::{DataType, Field, Fields, Schema};
+    use parquet::arrow::ArrowWriter;
+    use parquet::file::metadata::KeyValue;
+    use parquet::file::properties::WriterProperties;
+    use serde_json::json;
+
+    use crate::io::wkb::to_wkb;
+    use crate::test::linestring;
+    use crate::GeometryArrayTrait;
+
+    let ls_array = linestring::ls_array();
+    let wkb_array = to_wkb::<i32>(&ls_array);
+
+    let bbox_fields = Fields::from(
+        ["xmin", "ymin", "xmax", "ymax"]
+            .into_iter()
+            .map(|name| Field::new(name, DataType::Float64, false))
+            .collect::<Vec<_>>(),
+    );
+    let bbox_values: Vec<ArrayRef> = vec![
+        Arc::new(Float64Array::from(vec![0., 3.])),
+        Arc::new(Float64Array::from(vec![1., 4.])),
+        Arc::new(Float64Array::from(vec![1., 5.])),
+        Arc::new(Float64Array::from(vec![2., 6.])),
+    ];
+    let bbox_array = StructArray::new(bbox_fields.clone(), bbox_values, None);
+
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("id", DataType::Int32, false),
+        Field::new("geometry", DataType::Binary, true),
+        Field::new("bbox", DataType::Struct(bbox_fields), false),
+    ]));
+    let batch = RecordBatch::try_new(
+        schema.clone(),
+        vec![
+            Arc::new(Int32Array::from(vec![0, 1])),
+            wkb_array.into_array_ref(),
+            Arc::new(bbox_array),
+        ],
+    )
+    .unwrap();
+
+    let geo_meta = json!({
+        "version": "1.1.0",
+        "primary_column": "geometry",
+        "columns": {
+            "geometry": {
+                "encoding": "WKB",
+                "geometry_types": ["LineString"],
+                "crs": {"type": "GeographicCRS", "name": "WGS 84"},
+                "covering": {
+                    "bbox": {
+                        "xmin": ["bbox", "xmin"],
+                        "ymin": ["bbox", "ymin"],
+                        "xmax": ["bbox", "xmax"],
+                        "ymax": ["bbox", "ymax"],
+                    }
+                }
+            }
+        }
+    });
+    let props = WriterProperties::builder()
+        .set_key_value_metadata(Some(vec![KeyValue::new(
+            "geo".to_string(),
+            geo_meta.to_string(),
+        )]))
+        .build();
+
+    let mut buf = vec![];
+    let mut writer = ArrowWriter::try_new(&mut buf, schema, Some(props)).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+    Bytes::from(buf)
+}
+
+#[test]
+fn read_geoparquet_geometry_handling() {
+    use arrow_array::Array;
+    use serde_json::{json, Value};
+
+    use crate::algorithm::native::TotalBounds;
+    use crate::datatypes::GeoDataType;
+    use crate::io::parquet::{GeoParquetReaderOptions, GeometryHandling};
+    use crate::table::GeoTable;
+
+    let buf = write_covering_fixture();
+    let read = |geometry_handling| {
+        let options = GeoParquetReaderOptions {
+            geometry_handling,
+            ..Default::default()
+        };
+        read_geoparquet(buf.clone(), options).unwrap()
+    };
+    let field_crs = |table: &GeoTable| {
+        let field = table.schema().field(table.geometry_column_index());
+        let extension_metadata: Value =
+            serde_json::from_str(&field.metadata()["ARROW:extension:metadata"]).unwrap();
+        extension_metadata["crs"].clone()
+    };
+
+    let parsed = read(GeometryHandling::Parse);
+    let wkb = read(GeometryHandling::KeepWkb);
+    let skipped = read(GeometryHandling::Skip);
+    let bbox_only = read(GeometryHandling::BboxOnly);
+
+    for table in [&parsed, &wkb, &skipped, &bbox_only] {
+        assert_eq!(table.len(), 2);
+        assert_eq!(table.schema().fields().len(), 3);
+        assert_eq!(
+            table
+                .schema()
+                .field(table.geometry_column_index())
+                .name()
+                .as_str(),
+            "geometry"
+        );
+    }
+
+    assert_eq!(
+        parsed.geometry_data_type().unwrap(),
+        GeoDataType::LineString(Default::default())
+    );
+    assert_eq!(wkb.geometry_data_type().unwrap(), GeoDataType::WKB);
+    assert_eq!(skipped.geometry_data_type().unwrap(), GeoDataType::WKB);
+    assert_eq!(
+        skipped.batches()[0]
+            .column(skipped.geometry_column_index())
+            .null_count(),
+        2
+    );
+    assert_eq!(bbox_only.geometry_data_type().unwrap(), GeoDataType::Rect);
+    assert_eq!(
+        field_crs(&bbox_only),
+        json!({"type": "GeographicCRS", "name": "WGS 84"})
+    );
+
+    let parsed_bounds = parsed.geometry().unwrap().as_ref().total_bounds();
+    let wkb_bounds = wkb.geometry().unwrap().as_ref().total_bounds();
+    let bbox_bounds = bbox_only.geometry().unwrap().as_ref().total_bounds();
+    for bounds in [wkb_bounds, bbox_bounds] {
+        assert_eq!(
+            [bounds.minx, bounds.miny, bounds.maxx, bounds.maxy],
+            [
+                parsed_bounds.minx,
+                parsed_bounds.miny,
+                parsed_bounds.maxx,
+                parsed_bounds.maxy
+            ]
+        );
+    }
+}
+
+#[test]
+fn read_geoparquet_bbox_only_requires_covering() {
+    use crate::io::parquet::{GeoParquetReaderOptions, GeometryHandling};
+    use crate::test::point;
+
+    let mut buf = vec![];
+    write_geoparquet(&point::table(), Cursor::new(&mut buf), &Default::default()).unwrap();
+
+    let options =
+        GeoParquetReaderOptions::default().with_geometry_handling(GeometryHandling::BboxOnly);
+    assert!(read_geoparquet(Bytes::from(buf), options).is_err());
+}
+
+#[cfg(feature = "tracing")]
+#[test]
+fn round_trip_tracing_spans() {
+    use std::collections::HashMap;
+    use std::fmt::Debug;
+    use std::sync::{Arc, Mutex};
+
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Current, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    use crate::test::point;
+
+    type Fields = HashMap<String, String>;
+
+    #[derive(Default)]
+    struct Recorded {
+        spans: Vec<(&'static Metadata<'static>, Fields)>,
+        events: Vec<Fields>,
+        stack: Vec<Id>,
+    }
+
+    struct FieldVisitor<'a>(&'a mut Fields);
+
+    impl Visit for FieldVisitor<'_> {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            self.0
+                .insert(field.name().to_string(), format!("{:?}", value));
+        }
+    }
+
+    /// A minimal subscriber that records every span and event with its fields.
+    #[derive(Clone, Default)]
+    struct Collector(Arc<Mutex<Recorded>>);
+
+    impl Subscriber for Collector {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            let mut fields = Fields::new();
+            span.record(&mut FieldVisitor(&mut fields));
+            let mut recorded = self.0.lock().unwrap();
+            recorded.spans.push((span.metadata(), fields));
+            Id::from_u64(recorded.spans.len() as u64)
+        }
+
+        fn record(&self, span: &Id, values: &Record<'_>) {
+            let mut recorded = self.0.lock().unwrap();
+            let (_, fields) = &mut recorded.spans[span.into_u64() as usize - 1];
+            values.record(&mut FieldVisitor(fields));
+        }
+
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut fields = Fields::new();
+            event.record(&mut FieldVisitor(&mut fields));
+            self.0.lock().unwrap().events.push(fields);
+        }
+
+        fn enter(&self, span: &Id) {
+            self.0.lock().unwrap().stack.push(span.clone());
+        }
+
+        fn exit(&self, _span: &Id) {
+            self.0.lock().unwrap().stack.pop();
+        }
+
+        fn current_span(&self) -> Current {
+            let recorded = self.0.lock().unwrap();
+            match recorded.stack.last() {
+                Some(id) => {
+                    let (metadata, _) = recorded.spans[id.into_u64() as usize - 1];
+                    Current::new(id.clone(), metadata)
+                }
+                None => Current::none(),
+            }
+        }
+    }
+
+    let collector = Collector::default();
+    tracing::subscriber::with_default(collector.clone(), || {
+        let table = point::table();
+        let mut buf = vec![];
+        write_geoparquet(&table, Cursor::new(&mut buf), &Default::default()).unwrap();
+        let again = read_geoparquet(Bytes::from(buf), Default::default()).unwrap();
+        assert_eq!(again.len(), 3);
+    });
+
+    let recorded = collector.0.lock().unwrap();
+    fn span_fields<'a>(recorded: &'a Recorded, name: &str) -> &'a Fields {
+        &recorded
+            .spans
+            .iter()
+            .find(|(metadata, _)| metadata.name() == name)
+            .unwrap_or_else(|| panic!("no span named {}", name))
+            .1
+    }
+
+    let write_fields = span_fields(&recorded, "write_geoparquet");
+    assert_eq!(write_fields["num_rows"], "3");
+    assert_eq!(write_fields["num_batches"], "1");
+
+    let read_fields = span_fields(&recorded, "read_geoparquet");
+    assert_eq!(read_fields["num_rows"], "3");
+    assert_eq!(read_fields["num_batches"], "1");
+    assert_eq!(read_fields["num_row_groups"], "1");
+    assert!(read_fields["num_bytes"].parse::<i64>().unwrap() > 0);
+
+    let from_wkb_fields = span_fields(&recorded, "from_wkb");
+    assert_eq!(from_wkb_fields["num_rows"], "3");
+    assert!(from_wkb_fields.contains_key("num_bytes"));
+
+    let messages = recorded
+        .events
+        .iter()
+        .filter_map(|fields| fields.get("message"))
+        .collect::<Vec<_>>();
+    assert!(messages.iter().any(|m| *m == "wrote GeoParquet batch"));
+    assert!(messages.iter().any(|m| *m == "finished GeoParquet file"));
+    assert!(messages.iter().any(|m| *m == "read GeoParquet batch"));
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn write_geoparquet_parallel_matches_single_threaded() {
+    use arrow_array::RecordBatch;
+    use parquet::file::reader::FileReader;
+    use parquet::file::serialized_reader::SerializedFileReader;
+
+    use crate::io::parquet::metadata::GeoParquetMetadata;
+    use crate::io::parquet::{GeoParquetWriterEncoding, GeoParquetWriterOptions};
+    use crate::table::GeoTable;
+    use crate::test::point;
+
+    // Several batches, with row groups that span batch boundaries
+    let table = point::table();
+    let batches = vec![table.batches()[0].clone(); 5];
+    let table = GeoTable::try_new(
+        table.schema().clone(),
+        batches,
+        table.geometry_column_index(),
+    )
+    .unwrap();
+
+    let write = |writer_threads| {
+        let options = GeoParquetWriterOptions {
+            encoding: GeoParquetWriterEncoding::Native,
+            row_group_size: Some(4),
+            writer_threads,
+            ..Default::default()
+        };
+        let mut buf = vec![];
+        write_geoparquet(&table, Cursor::new(&mut buf), &options).unwrap();
+        Bytes::from(buf)
+    };
+    let single_threaded = write(None);
+    let parallel = write(Some(4));
+
+    let reader = SerializedFileReader::new(parallel.clone()).unwrap();
+    let metadata = reader.metadata();
+    assert_eq!(metadata.num_row_groups(), 4);
+    let row_group_sizes = metadata
+        .row_groups()
+        .iter()
+        .map(|row_group| row_group.num_rows())
+        .collect::<Vec<_>>();
+    assert_eq!(row_group_sizes, vec![4, 4, 4, 3]);
+
+    let geo_metadata = |buf: Bytes| {
+        let reader = SerializedFileReader::new(buf).unwrap();
+        let kv_metadata = reader
+            .metadata()
+            .file_metadata()
+            .key_value_metadata()
+            .unwrap();
+        let geo = kv_metadata.iter().find(|kv| kv.key == "geo").unwrap();
+        serde_json::from_str::<GeoParquetMetadata>(geo.value.as_ref().unwrap()).unwrap()
+    };
+    let expected_meta = geo_metadata(single_threaded.clone());
+    let parallel_meta = geo_metadata(parallel.clone());
+    let expected_column = &expected_meta.columns[&expected_meta.primary_column];
+    let parallel_column = &parallel_meta.columns[&parallel_meta.primary_column];
+    assert_eq!(parallel_column.bbox, expected_column.bbox);
+    assert_eq!(
+        parallel_column.geometry_types,
+        expected_column.geometry_types
+    );
+
+    let expected = read_geoparquet(single_threaded, Default::default()).unwrap();
+    let actual = read_geoparquet(parallel, Default::default()).unwrap();
+    assert_eq!(actual.schema(), expected.schema());
+    let concat =
+        |table: &GeoTable| arrow::compute::concat_batches(table.schema(), table.batches()).unwrap();
+    let actual_batch: RecordBatch = concat(&actual);
+    assert_eq!(actual_batch, concat(&expected));
+    assert_eq!(actual_batch.num_rows(), 15);
+}
+
+#[test]
+fn cast_geometry_keeps_crs() {
+    use serde_json::json;
+
+    use crate::array::CoordType;
+    use crate::datatypes::GeoDataType;
+    use crate::test::point;
+
+    let projjson = json!({"type": "GeographicCRS", "name": "WGS 84"});
+    let mut table = point::table();
+    table.set_crs(2, Some(projjson.clone())).unwrap();
+
+    let mut buf = vec![];
+    write_geoparquet(&table, Cursor::new(&mut buf), &Default::default()).unwrap();
+    let table = read_geoparquet(Bytes::from(buf), Default::default()).unwrap();
+    let index = table.geometry_column_index();
+    assert_eq!(table.crs(index).unwrap(), Some(projjson.clone()));
+
+    let separated = GeoDataType::Point(CoordType::Separated);
+    let casted = table.cast_geometry(&separated).unwrap();
+    assert_eq!(casted.geometry_data_type().unwrap(), separated);
+    assert_eq!(
+        casted.schema().field(index).name(),
+        table.schema().field(index).name()
+    );
+    assert_eq!(casted.crs(index).unwrap(), Some(projjson.clone()));
+    assert_eq!(casted.geometry().unwrap().metadata().crs, Some(projjson));
+}
+
+#[test]
+fn write_geoparquet_hilbert_sorted() {
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::UInt8Type;
+    use arrow_array::UInt32Array;
+
+    use crate::io::parquet::{GeoParquetWriterOptions, SpatialSort};
+    use crate::test::point;
+
+    // The points lie on a diagonal, so sorting restores their original order
+    let table = point::table()
+        .take(&UInt32Array::from(vec![2, 0, 1]))
+        .unwrap();
+
+    let options = GeoParquetWriterOptions::default().with_sort(SpatialSort::Hilbert);
+    let mut buf = vec![];
+    write_geoparquet(&table, Cursor::new(&mut buf), &options).unwrap();
+    let table = read_geoparquet(Bytes::from(buf), Default::default()).unwrap();
+
+    let values = table
+        .batches()
+        .iter()
+        .flat_map(|batch| {
+            let column = batch.column_by_name("u8").unwrap();
+            column.as_primitive::<UInt8Type>().values().to_vec()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(values, vec![1, 2, 3]);
+}
@@ -1,19 +1,42 @@
+use std::sync::Arc;
+
 use crate::error::Result;
 use crate::io::parquet::writer::encode::encode_record_batch;
 use crate::io::parquet::writer::metadata::GeoParquetMetadataBuilder;
-use crate::io::parquet::writer::options::GeoParquetWriterOptions;
+use crate::io::parquet::writer::options::{GeoParquetWriterOptions, SpatialSort};
 use crate::table::GeoTable;
 use arrow_array::RecordBatch;
 use arrow_schema::Schema;
+use futures::{Stream, StreamExt};
+use object_store::path::Path;
+use object_store::ObjectStore;
 use parquet::arrow::AsyncArrowWriter;
 use parquet::file::metadata::KeyValue;
 use tokio::io::AsyncWrite;
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        skip_all,
+        fields(num_rows = table.len(), num_batches = table.batches().len())
+    )
+)]
+/// Asynchronously write a GeoTable to a GeoParquet file.
+///
+/// See [`write_geoparquet`][crate::io::parquet::write_geoparquet] for details.
 pub async fn write_geoparquet_async<W: AsyncWrite + Unpin + Send>(
-    table: &mut GeoTable,
+    table: &GeoTable,
     writer: W,
     options: &GeoParquetWriterOptions,
 ) -> Result<()> {
+    let sorted_table;
+    let table = match options.sort {
+        Some(SpatialSort::Hilbert) => {
+            sorted_table = table.sort_hilbert(None)?;
+            &sorted_table
+        }
+        None => table,
+    };
     let mut parquet_writer = GeoParquetWriterAsync::try_new(writer, table.schema(), options)?;
 
     for batch in table.batches() {
@@ -24,6 +47,50 @@ pub async fn write_geoparquet_async<W: AsyncWrite + Unpin + Send>(
     Ok(())
 }
 
+/// Write a stream of record batches with the given `schema` to a GeoParquet file at `path` in
+/// `store`, with a multipart upload.
+///
+/// Each batch is encoded and written before the next one is pulled from the stream, and
+/// finished row groups are uploaded as parts, so memory use is bounded by the row group size of
+/// the writer properties in `options` rather than by the size of the file. The "geo" metadata,
+/// such as the bounding box and geometry types of each column, is computed incrementally from
+/// the batches. The output is the same as that of
+/// [`write_geoparquet`][crate::io::parquet::write_geoparquet] with the same batches and options.
+///
+/// If the stream returns an error or the upload fails, the multipart upload is aborted so that no
+/// partial object is left at `path`, and the error is returned.
+pub async fn write_geoparquet_to_object_store<S: Stream<Item = Result<RecordBatch>> + Unpin>(
+    stream: S,
+    schema: &Schema,
+    store: Arc<dyn ObjectStore>,
+    path: &Path,
+    options: &GeoParquetWriterOptions,
+) -> Result<()> {
+    let (multipart_id, upload) = store.put_multipart(path).await?;
+    let result = write_stream(stream, schema, upload, options).await;
+    if result.is_err() {
+        // Return the error that caused the abort rather than any error aborting
+        let _ = store.abort_multipart(path, &multipart_id).await;
+    }
+    result
+}
+
+async fn write_stream<
+    S: Stream<Item = Result<RecordBatch>> + Unpin,
+    W: AsyncWrite + Unpin + Send,
+>(
+    mut stream: S,
+    schema: &Schema,
+    writer: W,
+    options: &GeoParquetWriterOptions,
+) -> Result<()> {
+    let mut parquet_writer = GeoParquetWriterAsync::try_new(writer, schema, options)?;
+    while let Some(batch) = stream.next().await {
+        parquet_writer.write_batch(&batch?).await?;
+    }
+    parquet_writer.finish().await
+}
+
 pub struct GeoParquetWriterAsync<W: AsyncWrite + Unpin + Send> {
     writer: AsyncArrowWriter<W>,
     metadata_builder: GeoParquetMetadataBuilder,
@@ -36,7 +103,7 @@ impl<W: AsyncWrite + Unpin + Send> GeoParquetWriterAsync<W> {
         let writer = AsyncArrowWriter::try_new(
             writer,
             metadata_builder.output_schema.clone(),
-            options.writer_properties.clone(),
+            options.writer_properties(&metadata_builder.output_schema)?,
         )?;
 
         Ok(Self {
@@ -48,6 +115,10 @@ impl<W: AsyncWrite + Unpin + Send> GeoParquetWriterAsync<W> {
     pub async fn write_batch(&mut self, batch: &RecordBatch) -> Result<()> {
         let encoded_batch = encode_record_batch(batch, &mut self.metadata_builder)?;
         self.writer.write(&encoded_batch).await?;
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(num_rows = batch.num_rows(), "wrote GeoParquet batch");
+
         Ok(())
     }
 
@@ -61,7 +132,75 @@ impl<W: AsyncWrite + Unpin + Send> GeoParquetWriterAsync<W> {
             self.writer.append_key_value_metadata(kv_metadata);
         }
 
-        self.writer.close().await?;
+        #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+        let file_metadata = self.writer.close().await?;
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            num_rows = file_metadata.num_rows,
+            num_row_groups = file_metadata.row_groups.len(),
+            num_bytes = file_metadata
+                .row_groups
+                .iter()
+                .map(|row_group| row_group.total_byte_size)
+                .sum::<i64>(),
+            "finished GeoParquet file"
+        );
+
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::error::GeoArrowError;
+    use crate::io::parquet::write_geoparquet;
+    use crate::test::point;
+    use futures::stream;
+    use object_store::memory::InMemory;
+
+    #[tokio::test]
+    async fn object_store_matches_sync_writer() {
+        let table = point::table();
+        let options = GeoParquetWriterOptions::default();
+
+        let mut expected = vec![];
+        write_geoparquet(&table, &mut expected, &options).unwrap();
+
+        let store = Arc::new(InMemory::new());
+        let path = Path::from("points.parquet");
+        let batches = stream::iter(table.batches().clone().into_iter().map(Ok));
+        write_geoparquet_to_object_store(batches, table.schema(), store.clone(), &path, &options)
+            .await
+            .unwrap();
+
+        let written = store.get(&path).await.unwrap().bytes().await.unwrap();
+        assert_eq!(written.as_ref(), expected.as_slice());
+    }
+
+    #[tokio::test]
+    async fn object_store_abort_on_error() {
+        let table = point::table();
+        let store = Arc::new(InMemory::new());
+        let path = Path::from("points.parquet");
+        let batches = stream::iter(vec![
+            Ok(table.batches()[0].clone()),
+            Err(GeoArrowError::General("stream failed".to_string())),
+        ]);
+        let result = write_geoparquet_to_object_store(
+            batches,
+            table.schema(),
+            store.clone(),
+            &path,
+            &Default::default(),
+        )
+        .await;
+
+        assert!(matches!(result, Err(GeoArrowError::General(_))));
+        assert!(matches!(
+            store.head(&path).await,
+            Err(object_store::Error::NotFound { .. })
+        ));
+    }
+}
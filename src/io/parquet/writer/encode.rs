@@ -4,7 +4,7 @@ use arrow_array::{Array, RecordBatch};
 use arrow_schema::Field;
 
 use crate::algorithm::native::bounding_rect::BoundingRect;
-use crate::algorithm::native::TotalBounds;
+use crate::algorithm::native::{Quadkey, TotalBounds};
 use crate::array::{from_arrow_array, CoordType};
 use crate::error::Result;
 use crate::io::parquet::writer::metadata::{
@@ -18,6 +18,12 @@ pub(super) fn encode_record_batch(
     metadata_builder: &mut GeoParquetMetadataBuilder,
 ) -> Result<RecordBatch> {
     let mut new_columns = batch.columns().to_vec();
+    if let Some((geometry_column_idx, zoom)) = metadata_builder.quadkey {
+        let field = batch.schema_ref().field(geometry_column_idx);
+        let geo_arr = from_arrow_array(batch.column(geometry_column_idx), field)?;
+        new_columns.push(Arc::new(geo_arr.as_ref().quadkey(zoom)?));
+    }
+
     for (column_idx, column_info) in metadata_builder.columns.iter_mut() {
         let array = batch.column(*column_idx);
         let field = batch.schema_ref().field(*column_idx);
@@ -39,6 +45,7 @@ fn encode_column(
 ) -> Result<(Arc<dyn Array>, BoundingRect)> {
     let geo_arr = from_arrow_array(array, field)?;
     let array_bounds = geo_arr.as_ref().total_bounds();
+    column_info.update_geometry_types(geo_arr.as_ref());
     let encoded_array = match column_info.encoding {
         GeoColumnEncoding::WKB => encode_wkb_column(geo_arr.as_ref())?,
         _ => encode_native_column(geo_arr.as_ref())?,
@@ -1,18 +1,20 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Display;
 use std::sync::Arc;
 
-use arrow_schema::{Field, Schema};
+use arrow_schema::{DataType, Field, Schema};
 use serde_json::Value;
 
 use crate::algorithm::native::bounding_rect::BoundingRect;
+use crate::algorithm::native::type_id::TypeIds;
 use crate::array::metadata::{ArrayMetadata, Edges};
-use crate::array::CoordType;
+use crate::array::{AsGeometryArray, CoordType};
 use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
 use crate::io::parquet::metadata::{GeoParquetColumnMetadata, GeoParquetMetadata};
 use crate::io::parquet::writer::options::{GeoParquetWriterEncoding, GeoParquetWriterOptions};
 use crate::table::GeoTable;
+use crate::GeometryArrayTrait;
 
 /// The actual encoding of the geometry in the Parquet file.
 ///
@@ -77,6 +79,7 @@ impl Display for GeoColumnEncoding {
 }
 
 /// Information for one geometry column being written to Parquet
+#[derive(Clone)]
 pub struct ColumnInfo {
     /// The name of this geometry column
     pub name: String,
@@ -85,7 +88,7 @@ pub struct ColumnInfo {
     pub encoding: GeoColumnEncoding,
 
     /// The set of string geometry types for this geometry column
-    pub geometry_types: Vec<String>,
+    pub geometry_types: HashSet<String>,
 
     /// The bounding box of this column.
     pub bbox: Option<BoundingRect>,
@@ -106,17 +109,45 @@ impl ColumnInfo {
         array_meta: ArrayMetadata,
     ) -> Result<Self> {
         let encoding = GeoColumnEncoding::try_new(writer_encoding, data_type)?;
-        let geometry_types = get_geometry_types(data_type);
+        let geometry_types = get_geometry_types(data_type).into_iter().collect();
+        let crs = array_meta.crs.map(parse_projjson).transpose()?;
         Ok(Self {
             name,
             encoding,
             geometry_types,
             bbox: None,
-            crs: array_meta.crs,
+            crs,
             edges: array_meta.edges,
         })
     }
 
+    /// Add the geometry types present in this array.
+    ///
+    /// The geometry types of mixed and WKB arrays aren't known from the data type alone, so
+    /// these arrays are scanned.
+    pub fn update_geometry_types(&mut self, array: &dyn GeometryArrayTrait) {
+        let type_ids = match array.data_type() {
+            GeoDataType::Mixed(_) => array.as_mixed().get_unique_type_ids(),
+            GeoDataType::LargeMixed(_) => array.as_large_mixed().get_unique_type_ids(),
+            GeoDataType::WKB => array.as_wkb().get_unique_type_ids(),
+            GeoDataType::LargeWKB => array.as_large_wkb().get_unique_type_ids(),
+            _ => return,
+        };
+        for type_id in type_ids {
+            let geometry_type = match type_id {
+                0 => "Point",
+                1 => "LineString",
+                3 => "Polygon",
+                4 => "MultiPoint",
+                5 => "MultiLineString",
+                6 => "MultiPolygon",
+                7 => "GeometryCollection",
+                _ => continue,
+            };
+            self.geometry_types.insert(geometry_type.to_string());
+        }
+    }
+
     pub fn update_bbox(&mut self, new_bounds: &BoundingRect) {
         if let Some(existing_bounds) = self.bbox.as_mut() {
             existing_bounds.update(new_bounds)
@@ -125,14 +156,24 @@ impl ColumnInfo {
         }
     }
 
+    /// Merge the geometry types and bounding box accumulated by another writer of the same column.
+    pub fn merge(&mut self, other: ColumnInfo) {
+        self.geometry_types.extend(other.geometry_types);
+        if let Some(other_bounds) = other.bbox {
+            self.update_bbox(&other_bounds);
+        }
+    }
+
     /// Returns (column_name, column_metadata)
     pub fn finish(self) -> (String, GeoParquetColumnMetadata) {
         let edges = self.edges.map(|edges| match edges {
             Edges::Spherical => "spherical".to_string(),
         });
+        let mut geometry_types = self.geometry_types.into_iter().collect::<Vec<_>>();
+        geometry_types.sort();
         let column_meta = GeoParquetColumnMetadata {
             encoding: self.encoding.to_string(),
-            geometry_types: self.geometry_types,
+            geometry_types,
             crs: self.crs,
             bbox: self
                 .bbox
@@ -140,15 +181,21 @@ impl ColumnInfo {
             edges,
             orientation: None,
             epoch: None,
+            covering: None,
         };
         (self.name, column_meta)
     }
 }
 
+#[derive(Clone)]
 pub struct GeoParquetMetadataBuilder {
     pub output_schema: Arc<Schema>,
     pub primary_column: Option<String>,
     pub columns: HashMap<usize, ColumnInfo>,
+
+    /// If set, the index of the geometry column to derive a quadkey column from, and the zoom
+    /// level of the quadkeys. The quadkey column is appended as the last column of the output.
+    pub quadkey: Option<(usize, u8)>,
 }
 
 impl GeoParquetMetadataBuilder {
@@ -179,11 +226,35 @@ impl GeoParquetMetadataBuilder {
             }
         }
 
-        let output_schema = create_output_schema(schema, &columns);
+        let quadkey = options
+            .quadkey_column
+            .as_ref()
+            .map(|quadkey_options| {
+                let geometry_column_idx = columns.keys().min().copied().ok_or_else(|| {
+                    GeoArrowError::General(
+                        "a quadkey column requires a geometry column".to_string(),
+                    )
+                })?;
+                Ok::<_, GeoArrowError>((geometry_column_idx, quadkey_options.zoom))
+            })
+            .transpose()?;
+
+        let mut output_schema = create_output_schema(schema, &columns);
+        if let Some(quadkey_options) = &options.quadkey_column {
+            if schema.field_with_name(&quadkey_options.name).is_ok() {
+                return Err(GeoArrowError::General(format!(
+                    "column {} already exists in the input schema",
+                    quadkey_options.name
+                )));
+            }
+            output_schema = append_quadkey_field(&output_schema, &quadkey_options.name);
+        }
+
         Ok(Self {
             primary_column: None,
             columns,
             output_schema,
+            quadkey,
         })
     }
 
@@ -213,6 +284,7 @@ impl GeoParquetMetadataBuilder {
             primary_column: None,
             columns,
             output_schema,
+            quadkey: None,
         })
     }
 
@@ -226,24 +298,55 @@ impl GeoParquetMetadataBuilder {
         }
     }
 
+    /// Merge the column information accumulated by a copy of this builder, such as one used to
+    /// encode a row group on another thread.
+    pub fn merge(&mut self, other: GeoParquetMetadataBuilder) {
+        for (column_idx, column_info) in other.columns {
+            if let Some(existing) = self.columns.get_mut(&column_idx) {
+                existing.merge(column_info);
+            }
+        }
+    }
+
     pub fn finish(self) -> Option<GeoParquetMetadata> {
+        // Unless set explicitly, the first geometry column in the schema is the primary column
+        let primary_column = self.primary_column.or_else(|| {
+            self.columns
+                .iter()
+                .min_by_key(|(column_idx, _)| **column_idx)
+                .map(|(_, column_info)| column_info.name.clone())
+        })?;
+
         let mut columns = HashMap::with_capacity(self.columns.len());
         for column_info in self.columns.into_values() {
             let (column_name, column_meta) = column_info.finish();
             columns.insert(column_name, column_meta);
         }
 
-        if columns.is_empty() {
-            None
-        } else {
-            Some(GeoParquetMetadata {
-                version: "1.1.0".to_string(),
-                primary_column: self
-                    .primary_column
-                    .unwrap_or_else(|| columns.keys().next().unwrap().clone()),
-                columns,
-            })
-        }
+        Some(GeoParquetMetadata {
+            version: "1.1.0".to_string(),
+            primary_column,
+            columns,
+        })
+    }
+}
+
+/// The CRS in GeoParquet metadata must be PROJJSON. A CRS stored as a string is accepted when
+/// that string is itself a PROJJSON object.
+fn parse_projjson(crs: Value) -> Result<Value> {
+    match crs {
+        Value::Object(_) => Ok(crs),
+        Value::String(s) => match serde_json::from_str::<Value>(&s) {
+            Ok(projjson @ Value::Object(_)) => Ok(projjson),
+            _ => Err(GeoArrowError::General(format!(
+                "GeoParquet requires the CRS to be PROJJSON, got {}",
+                s
+            ))),
+        },
+        crs => Err(GeoArrowError::General(format!(
+            "GeoParquet requires the CRS to be PROJJSON, got {}",
+            crs
+        ))),
     }
 }
 
@@ -263,35 +366,14 @@ pub fn get_geometry_types(data_type: &GeoDataType) -> Vec<String> {
         GeoDataType::MultiPolygon(_) | GeoDataType::LargeMultiPolygon(_) => {
             vec!["MultiPolygon".to_string()]
         }
-        GeoDataType::Mixed(_) | GeoDataType::LargeMixed(_) => {
-            vec![]
-            // let mut geom_types = HashSet::new();
-            // arr.as_mixed().chunks().iter().for_each(|chunk| {
-            //     if chunk.has_points() {
-            //         geom_types.insert("Point".to_string());
-            //     }
-            //     if chunk.has_line_strings() {
-            //         geom_types.insert("LineString".to_string());
-            //     }
-            //     if chunk.has_polygons() {
-            //         geom_types.insert("Polygon".to_string());
-            //     }
-            //     if chunk.has_multi_points() {
-            //         geom_types.insert("MultiPoint".to_string());
-            //     }
-            //     if chunk.has_multi_line_strings() {
-            //         geom_types.insert("MultiLineString".to_string());
-            //     }
-            //     if chunk.has_multi_polygons() {
-            //         geom_types.insert("MultiPolygon".to_string());
-            //     }
-            // });
-            // geom_types.into_iter().collect()
-        }
         GeoDataType::GeometryCollection(_) | GeoDataType::LargeGeometryCollection(_) => {
             vec!["GeometryCollection".to_string()]
         }
-        GeoDataType::WKB | GeoDataType::LargeWKB => vec![],
+        // The geometry types of these arrays are scanned from the data while writing
+        GeoDataType::Mixed(_)
+        | GeoDataType::LargeMixed(_)
+        | GeoDataType::WKB
+        | GeoDataType::LargeWKB => vec![],
         GeoDataType::Rect => unimplemented!(),
     }
 }
@@ -318,6 +400,12 @@ fn create_output_schema(
     ))
 }
 
+fn append_quadkey_field(schema: &Schema, name: &str) -> Arc<Schema> {
+    let mut fields = schema.fields().to_vec();
+    fields.push(Field::new(name, DataType::Utf8, true).into());
+    Arc::new(Schema::new_with_metadata(fields, schema.metadata().clone()))
+}
+
 fn create_output_field(encoding: GeoColumnEncoding, name: String, nullable: bool) -> Field {
     match encoding {
         GeoColumnEncoding::WKB => GeoDataType::WKB.to_field(name, nullable),
@@ -2,9 +2,18 @@ mod r#async;
 mod encode;
 mod metadata;
 mod options;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod sort;
 mod sync;
 
-pub use options::{GeoParquetWriterEncoding, GeoParquetWriterOptions};
+pub use options::{
+    GeoParquetWriterEncoding, GeoParquetWriterOptions, ParquetColumnOptions, QuadkeyColumnOptions,
+    SpatialSort,
+};
 #[cfg(feature = "parquet_async")]
-pub use r#async::{write_geoparquet_async, GeoParquetWriterAsync};
+pub use r#async::{
+    write_geoparquet_async, write_geoparquet_to_object_store, GeoParquetWriterAsync,
+};
+pub use sort::{write_geoparquet_spatially_sorted, SpatialSortOptions};
 pub use sync::{write_geoparquet, GeoParquetWriter};
@@ -1,23 +1,327 @@
-use parquet::file::properties::WriterProperties;
+use std::collections::HashMap;
 
-#[derive(Copy, Clone)]
+use arrow_schema::Schema;
+use parquet::arrow::arrow_to_parquet_schema;
+use parquet::basic::Compression;
+use parquet::file::properties::{EnabledStatistics, WriterProperties};
+
+use crate::algorithm::native::MAX_QUADKEY_ZOOM;
+use crate::error::{GeoArrowError, Result};
+
+/// The encoding of geometry columns in a GeoParquet file.
+#[derive(Debug, Copy, Clone, Default)]
 #[allow(clippy::upper_case_acronyms)]
 pub enum GeoParquetWriterEncoding {
+    /// Well-known binary.
+    #[default]
     WKB,
+
+    /// The GeoArrow-native nested list encoding.
     Native,
 }
 
+/// Parquet settings for a single attribute column.
+///
+/// Page-level pruning is not possible on WKB geometry columns, but attribute columns used for
+/// spatial partitioning (such as quadkey or H3 cell strings) benefit from bloom filters and
+/// column indexes.
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct ParquetColumnOptions {
+    /// Whether to write a bloom filter for this column.
+    pub bloom_filter: bool,
+
+    /// The false positive probability of the bloom filter, if enabled.
+    pub bloom_filter_fpp: Option<f64>,
+
+    /// The expected number of distinct values of the bloom filter, if enabled.
+    pub bloom_filter_ndv: Option<u64>,
+
+    /// The level of statistics to write for this column. [`EnabledStatistics::Page`] also writes
+    /// the column index and offset index for the column.
+    pub statistics: Option<EnabledStatistics>,
+}
+
+impl ParquetColumnOptions {
+    /// Options that enable a bloom filter and page-level statistics.
+    pub fn with_bloom_filter() -> Self {
+        Self {
+            bloom_filter: true,
+            statistics: Some(EnabledStatistics::Page),
+            ..Default::default()
+        }
+    }
+
+    /// Set whether to write a bloom filter for this column.
+    pub fn with_bloom_filter_enabled(mut self, bloom_filter: bool) -> Self {
+        self.bloom_filter = bloom_filter;
+        self
+    }
+
+    /// Set the false positive probability of the bloom filter.
+    pub fn with_bloom_filter_fpp(mut self, fpp: f64) -> Self {
+        self.bloom_filter_fpp = Some(fpp);
+        self
+    }
+
+    /// Set the expected number of distinct values of the bloom filter.
+    pub fn with_bloom_filter_ndv(mut self, ndv: u64) -> Self {
+        self.bloom_filter_ndv = Some(ndv);
+        self
+    }
+
+    /// Set the level of statistics to write for this column.
+    pub fn with_statistics(mut self, statistics: EnabledStatistics) -> Self {
+        self.statistics = Some(statistics);
+        self
+    }
+}
+
+/// Options for writing a quadkey string column derived from the primary geometry column.
+///
+/// The quadkey column has a bloom filter and a column index enabled by default. On the read side,
+/// a filter on the quadkey column (for example with a [`RowFilter`][parquet::arrow::arrow_reader::RowFilter])
+/// allows readers to skip row groups and pages that don't contain a given tile.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct QuadkeyColumnOptions {
+    /// The name of the output column.
+    pub name: String,
+
+    /// The zoom level of the quadkeys, which is also the length of each quadkey string.
+    pub zoom: u8,
+}
+
+impl QuadkeyColumnOptions {
+    pub fn new(name: impl Into<String>, zoom: u8) -> Self {
+        Self {
+            name: name.into(),
+            zoom,
+        }
+    }
+
+    /// Set the name of the output column.
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Set the zoom level of the quadkeys.
+    pub fn with_zoom(mut self, zoom: u8) -> Self {
+        self.zoom = zoom;
+        self
+    }
+}
+
+/// A column named `quadkey` at zoom level 12.
+impl Default for QuadkeyColumnOptions {
+    fn default() -> Self {
+        Self::new("quadkey", 12)
+    }
+}
+
+/// A spatial ordering of the rows of a table, applied before writing it.
+///
+/// Spatially clustered rows give row groups tighter bounding boxes, which lets readers skip more
+/// of the file when filtering by extent.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SpatialSort {
+    /// Sort rows along a Hilbert curve through the bounding box centers of the primary geometry
+    /// column, as with [`GeoTable::sort_hilbert`][crate::table::GeoTable::sort_hilbert].
+    Hilbert,
+}
+
 /// Options for writing GeoParquet
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
 pub struct GeoParquetWriterOptions {
+    /// The encoding of the geometry columns.
     pub encoding: GeoParquetWriterEncoding,
+
+    /// The Parquet writer properties. If `None`, the default properties are used.
     pub writer_properties: Option<WriterProperties>,
+
+    /// The maximum number of rows in each row group. This overrides the row group size of
+    /// `writer_properties`.
+    pub row_group_size: Option<usize>,
+
+    /// The compression codec for all columns. This overrides the compression of
+    /// `writer_properties`.
+    pub compression: Option<Compression>,
+
+    /// Per-column Parquet settings, keyed by column name. These are applied on top of
+    /// `writer_properties`.
+    pub column_options: HashMap<String, ParquetColumnOptions>,
+
+    /// If provided, append a quadkey column computed from the primary geometry column.
+    pub quadkey_column: Option<QuadkeyColumnOptions>,
+
+    /// The number of threads used to encode and compress row groups when writing a whole table
+    /// with [`write_geoparquet`][crate::io::parquet::write_geoparquet]. Row groups are encoded
+    /// concurrently and written to the file in order.
+    ///
+    /// If `None` or 1, row groups are encoded on the calling thread. More than one thread
+    /// requires the `rayon` feature.
+    pub writer_threads: Option<usize>,
+
+    /// If provided, the rows of a table are sorted before writing it with
+    /// [`write_geoparquet`][crate::io::parquet::write_geoparquet]. Batches written one at a time
+    /// to a [`GeoParquetWriter`][crate::io::parquet::GeoParquetWriter] are not sorted; use
+    /// [`write_geoparquet_spatially_sorted`][crate::io::parquet::write_geoparquet_spatially_sorted]
+    /// to sort a stream that doesn't fit in memory.
+    pub sort: Option<SpatialSort>,
 }
 
-impl Default for GeoParquetWriterOptions {
-    fn default() -> Self {
-        Self {
-            encoding: GeoParquetWriterEncoding::WKB,
-            writer_properties: None,
+impl GeoParquetWriterOptions {
+    /// Set the encoding of the geometry columns.
+    pub fn with_encoding(mut self, encoding: GeoParquetWriterEncoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    /// Set the Parquet writer properties.
+    pub fn with_writer_properties(mut self, writer_properties: WriterProperties) -> Self {
+        self.writer_properties = Some(writer_properties);
+        self
+    }
+
+    /// Set the maximum number of rows in each row group.
+    pub fn with_row_group_size(mut self, row_group_size: usize) -> Self {
+        self.row_group_size = Some(row_group_size);
+        self
+    }
+
+    /// Set the compression codec for all columns.
+    pub fn with_compression(mut self, compression: Compression) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    /// Set the Parquet settings of a single column.
+    pub fn with_column_options(
+        mut self,
+        column_name: impl Into<String>,
+        column_options: ParquetColumnOptions,
+    ) -> Self {
+        self.column_options
+            .insert(column_name.into(), column_options);
+        self
+    }
+
+    /// Append a quadkey column computed from the primary geometry column.
+    pub fn with_quadkey_column(mut self, quadkey_column: QuadkeyColumnOptions) -> Self {
+        self.quadkey_column = Some(quadkey_column);
+        self
+    }
+
+    /// Set the number of threads used to encode row groups.
+    pub fn with_writer_threads(mut self, writer_threads: usize) -> Self {
+        self.writer_threads = Some(writer_threads);
+        self
+    }
+
+    /// Sort the rows of each table before writing it.
+    pub fn with_sort(mut self, sort: SpatialSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Create the [`WriterProperties`] for a file with the given output schema, applying the row
+    /// group size, compression and any per-column options on top of the user-provided writer
+    /// properties.
+    pub(crate) fn writer_properties(
+        &self,
+        output_schema: &Schema,
+    ) -> Result<Option<WriterProperties>> {
+        if let Some(quadkey_options) = &self.quadkey_column {
+            if quadkey_options.zoom > MAX_QUADKEY_ZOOM {
+                return Err(GeoArrowError::General(format!(
+                    "quadkey zoom must be at most {}, got {}",
+                    MAX_QUADKEY_ZOOM, quadkey_options.zoom
+                )));
+            }
         }
+
+        let mut column_options = self.column_options.clone();
+        if let Some(quadkey_options) = &self.quadkey_column {
+            column_options
+                .entry(quadkey_options.name.clone())
+                .or_insert_with(ParquetColumnOptions::with_bloom_filter);
+        }
+
+        if column_options.is_empty() && self.row_group_size.is_none() && self.compression.is_none()
+        {
+            return Ok(self.writer_properties.clone());
+        }
+
+        for column_name in column_options.keys() {
+            if output_schema.field_with_name(column_name).is_err() {
+                return Err(GeoArrowError::General(format!(
+                    "column options provided for unknown column {}",
+                    column_name
+                )));
+            }
+        }
+
+        let base = self.writer_properties.clone().unwrap_or_default();
+        let parquet_schema = arrow_to_parquet_schema(output_schema)?;
+
+        // WriterProperties can't be converted back into a builder, so we copy over the global
+        // settings and each leaf column's settings before applying the per-column options.
+        let mut builder = WriterProperties::builder()
+            .set_writer_version(base.writer_version())
+            .set_data_pagesize_limit(base.data_pagesize_limit())
+            .set_dictionary_pagesize_limit(base.dictionary_pagesize_limit())
+            .set_data_page_row_count_limit(base.data_page_row_count_limit())
+            .set_write_batch_size(base.write_batch_size())
+            .set_max_row_group_size(
+                self.row_group_size
+                    .unwrap_or_else(|| base.max_row_group_size()),
+            )
+            .set_created_by(base.created_by().to_string())
+            .set_key_value_metadata(base.key_value_metadata().cloned())
+            .set_sorting_columns(base.sorting_columns().cloned())
+            .set_column_index_truncate_length(base.column_index_truncate_length());
+
+        for column in parquet_schema.columns() {
+            let path = column.path().clone();
+            if let Some(encoding) = base.encoding(&path) {
+                builder = builder.set_column_encoding(path.clone(), encoding);
+            }
+            builder = builder
+                .set_column_compression(
+                    path.clone(),
+                    self.compression.unwrap_or_else(|| base.compression(&path)),
+                )
+                .set_column_dictionary_enabled(path.clone(), base.dictionary_enabled(&path))
+                .set_column_statistics_enabled(path.clone(), base.statistics_enabled(&path))
+                .set_column_max_statistics_size(path.clone(), base.max_statistics_size(&path));
+            if let Some(bloom_filter) = base.bloom_filter_properties(&path) {
+                builder = builder
+                    .set_column_bloom_filter_enabled(path.clone(), true)
+                    .set_column_bloom_filter_fpp(path.clone(), bloom_filter.fpp)
+                    .set_column_bloom_filter_ndv(path.clone(), bloom_filter.ndv);
+            }
+
+            // Per-column options are keyed by the top-level column name
+            let Some(options) = column_options.get(&path.parts()[0]) else {
+                continue;
+            };
+            if options.bloom_filter {
+                builder = builder.set_column_bloom_filter_enabled(path.clone(), true);
+                if let Some(fpp) = options.bloom_filter_fpp {
+                    builder = builder.set_column_bloom_filter_fpp(path.clone(), fpp);
+                }
+                if let Some(ndv) = options.bloom_filter_ndv {
+                    builder = builder.set_column_bloom_filter_ndv(path.clone(), ndv);
+                }
+            }
+            if let Some(statistics) = options.statistics {
+                builder = builder.set_column_statistics_enabled(path.clone(), statistics);
+            }
+        }
+
+        Ok(Some(builder.build()))
     }
 }
@@ -0,0 +1,199 @@
+use std::io::Write;
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use arrow_ipc::writer::{IpcDataGenerator, IpcWriteOptions};
+use arrow_schema::Schema;
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use parquet::arrow::arrow_writer::{compute_leaves, get_column_writers, ArrowColumnChunk};
+use parquet::arrow::{arrow_to_parquet_schema, ARROW_SCHEMA_META_KEY};
+use parquet::file::metadata::KeyValue;
+use parquet::file::properties::WriterPropertiesPtr;
+use parquet::file::writer::SerializedFileWriter;
+use parquet::schema::types::SchemaDescriptor;
+use rayon::prelude::*;
+
+use crate::error::{GeoArrowError, Result};
+use crate::io::parquet::writer::encode::encode_record_batch;
+use crate::io::parquet::writer::metadata::GeoParquetMetadataBuilder;
+use crate::io::parquet::writer::options::GeoParquetWriterOptions;
+use crate::table::GeoTable;
+
+/// Write a GeoTable to a GeoParquet file, encoding up to `num_threads` row groups concurrently.
+///
+/// Each row group is encoded and compressed into memory on a rayon worker, with its own copy of
+/// the metadata builder. The encoded row groups are then appended to the file in order, and their
+/// geometry types and bounding boxes are merged into the file metadata. At most `num_threads` row
+/// groups are held in memory at once.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        skip_all,
+        fields(num_rows = table.len(), num_batches = table.batches().len(), num_threads)
+    )
+)]
+pub(super) fn write_geoparquet_parallel<W: Write + Send>(
+    table: &GeoTable,
+    writer: W,
+    options: &GeoParquetWriterOptions,
+    num_threads: usize,
+) -> Result<()> {
+    let mut metadata_builder = GeoParquetMetadataBuilder::try_new(table.schema(), options)?;
+    let output_schema = metadata_builder.output_schema.clone();
+    let props: WriterPropertiesPtr = Arc::new(
+        options
+            .writer_properties(&output_schema)?
+            .unwrap_or_default(),
+    );
+    let parquet_schema = arrow_to_parquet_schema(&output_schema)?;
+
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .build()
+        .map_err(|err| GeoArrowError::General(err.to_string()))?;
+
+    let mut file_writer =
+        SerializedFileWriter::new(writer, parquet_schema.root_schema_ptr(), props.clone())?;
+
+    let row_groups = split_row_groups(table.batches(), props.max_row_group_size());
+    for row_group_window in row_groups.chunks(num_threads) {
+        let encoded_row_groups = pool.install(|| {
+            row_group_window
+                .par_iter()
+                .map(|batches| {
+                    encode_row_group(batches, &metadata_builder, &parquet_schema, &props)
+                })
+                .collect::<Result<Vec<_>>>()
+        })?;
+
+        for (column_chunks, row_group_metadata) in encoded_row_groups {
+            let mut row_group_writer = file_writer.next_row_group()?;
+            for column_chunk in column_chunks {
+                column_chunk.append_to_row_group(&mut row_group_writer)?;
+            }
+            row_group_writer.close()?;
+            metadata_builder.merge(row_group_metadata);
+        }
+    }
+
+    // The Arrow writer stores the Arrow schema so that readers can restore the Arrow types and
+    // field metadata. The same is done here, as the file is written without the Arrow writer.
+    file_writer.append_key_value_metadata(KeyValue::new(
+        ARROW_SCHEMA_META_KEY.to_string(),
+        encode_arrow_schema(&output_schema),
+    ));
+    if let Some(geo_meta) = metadata_builder.finish() {
+        let kv_metadata = KeyValue::new("geo".to_string(), serde_json::to_string(&geo_meta)?);
+        file_writer.append_key_value_metadata(kv_metadata);
+    }
+
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    let file_metadata = file_writer.close()?;
+
+    #[cfg(feature = "tracing")]
+    tracing::debug!(
+        num_rows = file_metadata.num_rows,
+        num_row_groups = file_metadata.row_groups.len(),
+        "finished GeoParquet file"
+    );
+
+    Ok(())
+}
+
+/// Split record batches into groups of at most `max_row_group_size` rows, slicing batches that
+/// cross a row group boundary. This matches how the Arrow writer assigns rows to row groups.
+fn split_row_groups(batches: &[RecordBatch], max_row_group_size: usize) -> Vec<Vec<RecordBatch>> {
+    let mut row_groups = vec![];
+    let mut current_row_group = vec![];
+    let mut current_num_rows = 0;
+
+    for batch in batches {
+        let mut offset = 0;
+        while offset < batch.num_rows() {
+            let length = (max_row_group_size - current_num_rows).min(batch.num_rows() - offset);
+            current_row_group.push(batch.slice(offset, length));
+            offset += length;
+            current_num_rows += length;
+
+            if current_num_rows == max_row_group_size {
+                row_groups.push(std::mem::take(&mut current_row_group));
+                current_num_rows = 0;
+            }
+        }
+    }
+
+    if !current_row_group.is_empty() {
+        row_groups.push(current_row_group);
+    }
+    row_groups
+}
+
+/// Encode the batches of one row group into in-memory column chunks.
+///
+/// Returns the column chunks and the metadata builder holding the geometry types and bounding
+/// boxes of this row group.
+fn encode_row_group(
+    batches: &[RecordBatch],
+    metadata_builder: &GeoParquetMetadataBuilder,
+    parquet_schema: &SchemaDescriptor,
+    props: &WriterPropertiesPtr,
+) -> Result<(Vec<ArrowColumnChunk>, GeoParquetMetadataBuilder)> {
+    let mut metadata_builder = metadata_builder.clone();
+    let output_schema = metadata_builder.output_schema.clone();
+    let mut column_writers = get_column_writers(parquet_schema, props, &output_schema)?;
+
+    for batch in batches {
+        let encoded_batch = encode_record_batch(batch, &mut metadata_builder)?;
+
+        let mut column_writers_iter = column_writers.iter_mut();
+        for (array, field) in encoded_batch.columns().iter().zip(output_schema.fields()) {
+            for leaf in compute_leaves(field, array)? {
+                column_writers_iter
+                    .next()
+                    .expect("one column writer per leaf column")
+                    .write(&leaf)?;
+            }
+        }
+    }
+
+    let column_chunks = column_writers
+        .into_iter()
+        .map(|column_writer| column_writer.close())
+        .collect::<parquet::errors::Result<Vec<_>>>()?;
+    Ok((column_chunks, metadata_builder))
+}
+
+/// Serialize the Arrow schema in the same way as the Arrow writer: an IPC schema message with a
+/// length prefix, encoded as base64.
+fn encode_arrow_schema(schema: &Schema) -> String {
+    let options = IpcWriteOptions::default();
+    let encoded_schema = IpcDataGenerator::default().schema_to_bytes(schema, &options);
+    let ipc_message = encoded_schema.ipc_message;
+
+    let mut len_prefixed_schema = Vec::with_capacity(ipc_message.len() + 8);
+    len_prefixed_schema.extend_from_slice(&[255u8, 255, 255, 255]);
+    len_prefixed_schema.extend_from_slice(&(ipc_message.len() as u32).to_le_bytes());
+    len_prefixed_schema.extend_from_slice(&ipc_message);
+    BASE64_STANDARD.encode(&len_prefixed_schema)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::point;
+
+    #[test]
+    fn split_row_groups_across_batches() {
+        let batch = point::table().batches()[0].clone();
+        let batches = vec![batch.clone(), batch.clone(), batch];
+
+        let row_groups = split_row_groups(&batches, 4);
+        let row_group_sizes = row_groups
+            .iter()
+            .map(|row_group| row_group.iter().map(|b| b.num_rows()).sum::<usize>())
+            .collect::<Vec<_>>();
+        assert_eq!(row_group_sizes, vec![4, 4, 1]);
+        assert_eq!(row_groups[1].len(), 2);
+    }
+}
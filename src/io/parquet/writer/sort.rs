@@ -0,0 +1,644 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use arrow::compute::{concat_batches, interleave, sort_to_indices, take_record_batch};
+use arrow_array::{Array, ArrayRef, RecordBatch, UInt32Array};
+use arrow_ipc::reader::FileReader;
+use arrow_ipc::writer::FileWriter;
+use arrow_schema::{ArrowError, DataType, Field, Schema, SchemaRef};
+
+use crate::algorithm::geo::BoundingRect;
+use crate::algorithm::native::geometry_hilbert_keys;
+use crate::array::from_arrow_array;
+use crate::error::{GeoArrowError, Result};
+use crate::io::parquet::writer::options::GeoParquetWriterOptions;
+use crate::io::parquet::writer::sync::GeoParquetWriter;
+
+/// The name of the column holding the Hilbert key of each row in the spilled runs.
+const HILBERT_KEY_COLUMN: &str = "__geoarrow_hilbert_key";
+
+/// Options for [`write_geoparquet_spatially_sorted`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct SpatialSortOptions {
+    /// The approximate number of bytes of input batches to buffer before sorting them and
+    /// spilling them to disk as a run. Sorting a run needs about three times this much memory.
+    /// Defaults to 256 MiB.
+    pub memory_budget: usize,
+
+    /// The maximum number of runs merged at once. When there are more runs, they are first merged
+    /// in groups into longer runs. Defaults to 16.
+    pub merge_fan_in: usize,
+
+    /// The number of rows in each batch of the spilled runs and of the output. Defaults to 65,536.
+    pub batch_size: usize,
+
+    /// The extent `[minx, miny, maxx, maxy]` mapped onto the Hilbert curve. If `None`, the extent
+    /// of the input is used, which requires spilling the input unsorted before sorting each run.
+    pub bounds: Option<[f64; 4]>,
+
+    /// The index of the geometry column to sort by. If `None`, the first GeoArrow column is used.
+    pub geometry_column: Option<usize>,
+
+    /// Options for writing the sorted GeoParquet file.
+    pub writer_options: GeoParquetWriterOptions,
+}
+
+impl Default for SpatialSortOptions {
+    fn default() -> Self {
+        Self {
+            memory_budget: 256 * 1024 * 1024,
+            merge_fan_in: 16,
+            batch_size: 65_536,
+            bounds: None,
+            geometry_column: None,
+            writer_options: Default::default(),
+        }
+    }
+}
+
+impl SpatialSortOptions {
+    /// Set the approximate number of bytes of input batches sorted in memory at once.
+    pub fn with_memory_budget(mut self, memory_budget: usize) -> Self {
+        self.memory_budget = memory_budget;
+        self
+    }
+
+    /// Set the maximum number of runs merged at once.
+    pub fn with_merge_fan_in(mut self, merge_fan_in: usize) -> Self {
+        self.merge_fan_in = merge_fan_in;
+        self
+    }
+
+    /// Set the number of rows in each spilled and output batch.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Set the extent mapped onto the Hilbert curve.
+    pub fn with_bounds(mut self, bounds: [f64; 4]) -> Self {
+        self.bounds = Some(bounds);
+        self
+    }
+
+    /// Set the index of the geometry column to sort by.
+    pub fn with_geometry_column(mut self, geometry_column: usize) -> Self {
+        self.geometry_column = Some(geometry_column);
+        self
+    }
+
+    /// Set the options for writing the sorted GeoParquet file.
+    pub fn with_writer_options(mut self, writer_options: GeoParquetWriterOptions) -> Self {
+        self.writer_options = writer_options;
+        self
+    }
+}
+
+/// Write a stream of record batches to a GeoParquet file, sorted by the Hilbert curve index of
+/// the center of each geometry, without holding the whole dataset in memory.
+///
+/// This is an external merge sort:
+///
+/// 1. Input batches are buffered up to the
+///    [memory budget][SpatialSortOptions::memory_budget], then sorted by Hilbert key and spilled
+///    to `tmp_dir` as an Arrow IPC file. Without explicit
+///    [bounds][SpatialSortOptions::bounds], the buffered batches are spilled unsorted while the
+///    extent of the input is computed, and each run is sorted once the input is exhausted.
+/// 2. Runs are merged [`merge_fan_in`][SpatialSortOptions::merge_fan_in] at a time with a k-way
+///    merge, until few enough remain to be merged straight into the output file.
+///
+/// While merging, one batch of each run is held in memory, so peak memory is proportional to the
+/// batch size times the merge fan-in. Null and empty geometries are sorted last. Temporary files
+/// are written to a new directory within `tmp_dir`, which is removed when writing finishes or
+/// fails.
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub fn write_geoparquet_spatially_sorted<W: Write + Send>(
+    batches: impl IntoIterator<Item = std::result::Result<RecordBatch, ArrowError>>,
+    schema: SchemaRef,
+    tmp_dir: impl AsRef<Path>,
+    writer: W,
+    options: &SpatialSortOptions,
+) -> Result<()> {
+    if options.merge_fan_in < 2 {
+        return Err(GeoArrowError::General(
+            "merge fan-in must be at least 2".to_string(),
+        ));
+    }
+    if options.batch_size == 0 {
+        return Err(GeoArrowError::General(
+            "batch size must be greater than 0".to_string(),
+        ));
+    }
+    let geometry_column = match options.geometry_column {
+        Some(geometry_column) => geometry_column,
+        None => schema
+            .fields()
+            .iter()
+            .position(|field| {
+                field
+                    .metadata()
+                    .get("ARROW:extension:name")
+                    .is_some_and(|name| name.starts_with("geoarrow"))
+            })
+            .ok_or_else(|| {
+                GeoArrowError::General("spatial sorting requires a geometry column".to_string())
+            })?,
+    };
+    if geometry_column >= schema.fields().len() {
+        return Err(GeoArrowError::General(format!(
+            "geometry column index {} is out of bounds",
+            geometry_column
+        )));
+    }
+    if schema.field_with_name(HILBERT_KEY_COLUMN).is_ok() {
+        return Err(GeoArrowError::General(format!(
+            "column {} is reserved for spatial sorting",
+            HILBERT_KEY_COLUMN
+        )));
+    }
+
+    let mut spill_dir = SpillDir::try_new(tmp_dir.as_ref())?;
+    let run_sorter = RunSorter {
+        schema: schema.clone(),
+        key_schema: append_key_field(&schema),
+        geometry_column,
+        batch_size: options.batch_size,
+    };
+
+    // Either sorted runs, or unsorted runs and the extent of the input if no bounds were given
+    let mut runs = vec![];
+    let mut input_bounds = None;
+    let mut buffered = vec![];
+    let mut buffered_size = 0;
+    for batch in batches {
+        let batch = batch?;
+        if batch.schema().fields() != schema.fields() {
+            return Err(GeoArrowError::General(
+                "record batch schema does not match the provided schema".to_string(),
+            ));
+        }
+        if options.bounds.is_none() {
+            let field = schema.field(geometry_column);
+            let geo_arr = from_arrow_array(batch.column(geometry_column), field)?;
+            input_bounds = union_bounds(input_bounds, geo_arr.as_ref().total_bounds());
+        }
+
+        buffered_size += batch.get_array_memory_size();
+        buffered.push(batch);
+        if buffered_size >= options.memory_budget {
+            let run_batches = std::mem::take(&mut buffered);
+            runs.push(run_sorter.spill(&mut spill_dir, run_batches, options.bounds)?);
+            buffered_size = 0;
+        }
+    }
+    if !buffered.is_empty() {
+        runs.push(run_sorter.spill(&mut spill_dir, buffered, options.bounds)?);
+    }
+
+    if options.bounds.is_none() {
+        // An input of only null and empty geometries is left in its original order
+        let bounds = input_bounds.unwrap_or_default();
+        runs = runs
+            .iter()
+            .map(|run| -> Result<PathBuf> {
+                let run_batches = read_run(run)?;
+                fs::remove_file(run)?;
+                run_sorter.spill(&mut spill_dir, run_batches, Some(bounds))
+            })
+            .collect::<Result<Vec<_>>>()?;
+    }
+
+    #[cfg(feature = "tracing")]
+    tracing::debug!(num_runs = runs.len(), "spilled sorted runs");
+
+    while runs.len() > options.merge_fan_in {
+        runs = runs
+            .chunks(options.merge_fan_in)
+            .map(|group| -> Result<PathBuf> {
+                if let [run] = group {
+                    return Ok(run.clone());
+                }
+                let path = spill_dir.next_path();
+                let mut run_writer = FileWriter::try_new(
+                    BufWriter::new(File::create(&path)?),
+                    &run_sorter.key_schema,
+                )?;
+                merge_runs(group, options.batch_size, |batch| {
+                    run_writer.write(&batch)?;
+                    Ok(())
+                })?;
+                run_writer.finish()?;
+                for run in group {
+                    fs::remove_file(run)?;
+                }
+                Ok(path)
+            })
+            .collect::<Result<Vec<_>>>()?;
+    }
+
+    let mut parquet_writer = GeoParquetWriter::try_new(writer, &schema, &options.writer_options)?;
+    let num_columns = schema.fields().len();
+    let projection = (0..num_columns).collect::<Vec<_>>();
+    merge_runs(&runs, options.batch_size, |batch| {
+        parquet_writer.write_batch(&batch.project(&projection)?)
+    })?;
+    parquet_writer.finish()?;
+
+    Ok(())
+}
+
+/// Sorts buffered batches by Hilbert key and spills them as runs.
+struct RunSorter {
+    schema: SchemaRef,
+    /// The input schema with the Hilbert key column appended.
+    key_schema: SchemaRef,
+    geometry_column: usize,
+    batch_size: usize,
+}
+
+impl RunSorter {
+    /// Write `batches` to a new run file. If `bounds` is provided, the run is sorted by Hilbert
+    /// key, with the keys appended as the last column.
+    fn spill(
+        &self,
+        spill_dir: &mut SpillDir,
+        batches: Vec<RecordBatch>,
+        bounds: Option<[f64; 4]>,
+    ) -> Result<PathBuf> {
+        let path = spill_dir.next_path();
+        let file = BufWriter::new(File::create(&path)?);
+        let Some(bounds) = bounds else {
+            let mut run_writer = FileWriter::try_new(file, &self.schema)?;
+            for batch in batches.iter() {
+                run_writer.write(batch)?;
+            }
+            run_writer.finish()?;
+            return Ok(path);
+        };
+
+        let batch = concat_batches(&self.schema, &batches)?;
+        drop(batches);
+        let keys = hilbert_keys(&batch, self.geometry_column, bounds)?;
+        let indices = sort_to_indices(&keys, None, None)?;
+        let mut columns = batch.columns().to_vec();
+        columns.push(Arc::new(keys));
+        let batch = RecordBatch::try_new(self.key_schema.clone(), columns)?;
+        let sorted = take_record_batch(&batch, &indices)?;
+        drop(batch);
+
+        let mut run_writer = FileWriter::try_new(file, &self.key_schema)?;
+        let mut offset = 0;
+        while offset < sorted.num_rows() {
+            let length = self.batch_size.min(sorted.num_rows() - offset);
+            run_writer.write(&sorted.slice(offset, length))?;
+            offset += length;
+        }
+        run_writer.finish()?;
+        Ok(path)
+    }
+}
+
+/// Read all batches of an unsorted run.
+fn read_run(path: &Path) -> Result<Vec<RecordBatch>> {
+    let reader = FileReader::try_new(BufReader::new(File::open(path)?), None)?;
+    Ok(reader.collect::<std::result::Result<Vec<_>, _>>()?)
+}
+
+/// A position in a sorted run being merged.
+struct RunCursor {
+    reader: FileReader<BufReader<File>>,
+    batch: RecordBatch,
+    keys: UInt32Array,
+    row: usize,
+    /// The index of `batch` in the batches referenced by the pending output rows.
+    source: usize,
+}
+
+impl RunCursor {
+    /// Open a sorted run, returning `None` if it has no rows.
+    fn try_new(path: &Path) -> Result<Option<Self>> {
+        let mut reader = FileReader::try_new(BufReader::new(File::open(path)?), None)?;
+        let Some(batch) = next_non_empty_batch(&mut reader)? else {
+            return Ok(None);
+        };
+        Ok(Some(Self {
+            reader,
+            keys: batch_keys(&batch),
+            batch,
+            row: 0,
+            source: 0,
+        }))
+    }
+
+    fn key(&self) -> u32 {
+        self.keys.value(self.row)
+    }
+
+    /// Move to the next row, loading the next batch of the run if needed. Returns `false` once the
+    /// run is exhausted.
+    fn advance(&mut self) -> Result<bool> {
+        self.row += 1;
+        if self.row < self.batch.num_rows() {
+            return Ok(true);
+        }
+        match next_non_empty_batch(&mut self.reader)? {
+            Some(batch) => {
+                self.keys = batch_keys(&batch);
+                self.batch = batch;
+                self.row = 0;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+}
+
+fn next_non_empty_batch(reader: &mut FileReader<BufReader<File>>) -> Result<Option<RecordBatch>> {
+    for batch in reader {
+        let batch = batch?;
+        if batch.num_rows() > 0 {
+            return Ok(Some(batch));
+        }
+    }
+    Ok(None)
+}
+
+fn batch_keys(batch: &RecordBatch) -> UInt32Array {
+    let key_column = batch.num_columns() - 1;
+    batch
+        .column(key_column)
+        .as_any()
+        .downcast_ref::<UInt32Array>()
+        .unwrap()
+        .clone()
+}
+
+/// Merge sorted runs with a k-way merge, passing batches of up to `batch_size` rows to `emit` in
+/// Hilbert key order.
+fn merge_runs(
+    runs: &[PathBuf],
+    batch_size: usize,
+    mut emit: impl FnMut(RecordBatch) -> Result<()>,
+) -> Result<()> {
+    let mut cursors = vec![];
+    for run in runs {
+        cursors.push(RunCursor::try_new(run)?);
+    }
+
+    // The batches referenced by the pending output rows
+    let mut sources = vec![];
+    let mut heap = BinaryHeap::new();
+    for (cursor_idx, cursor) in cursors.iter_mut().enumerate() {
+        if let Some(cursor) = cursor {
+            cursor.source = sources.len();
+            sources.push(cursor.batch.clone());
+            heap.push(Reverse((cursor.key(), cursor_idx)));
+        }
+    }
+
+    let mut indices = Vec::with_capacity(batch_size);
+    while let Some(Reverse((_, cursor_idx))) = heap.pop() {
+        let cursor = cursors[cursor_idx].as_mut().unwrap();
+        indices.push((cursor.source, cursor.row));
+        if cursor.advance()? {
+            if cursor.row == 0 {
+                cursor.source = sources.len();
+                sources.push(cursor.batch.clone());
+            }
+            heap.push(Reverse((cursor.key(), cursor_idx)));
+        } else {
+            cursors[cursor_idx] = None;
+        }
+
+        if indices.len() == batch_size {
+            emit(interleave_batches(&sources, &indices)?)?;
+            indices.clear();
+
+            // Release the batches that have been fully merged
+            sources.clear();
+            for cursor in cursors.iter_mut().flatten() {
+                cursor.source = sources.len();
+                sources.push(cursor.batch.clone());
+            }
+        }
+    }
+    if !indices.is_empty() {
+        emit(interleave_batches(&sources, &indices)?)?;
+    }
+    Ok(())
+}
+
+fn interleave_batches(sources: &[RecordBatch], indices: &[(usize, usize)]) -> Result<RecordBatch> {
+    let schema = sources[0].schema();
+    let columns = (0..schema.fields().len())
+        .map(|column_idx| {
+            let arrays = sources
+                .iter()
+                .map(|batch| batch.column(column_idx).as_ref())
+                .collect::<Vec<_>>();
+            interleave(&arrays, indices)
+        })
+        .collect::<std::result::Result<Vec<ArrayRef>, _>>()?;
+    Ok(RecordBatch::try_new(schema, columns)?)
+}
+
+fn append_key_field(schema: &Schema) -> SchemaRef {
+    let mut fields = schema.fields().to_vec();
+    fields.push(Arc::new(Field::new(
+        HILBERT_KEY_COLUMN,
+        DataType::UInt32,
+        false,
+    )));
+    Arc::new(Schema::new_with_metadata(fields, schema.metadata().clone()))
+}
+
+fn union_bounds(a: Option<[f64; 4]>, b: Option<[f64; 4]>) -> Option<[f64; 4]> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some([
+            a[0].min(b[0]),
+            a[1].min(b[1]),
+            a[2].max(b[2]),
+            a[3].max(b[3]),
+        ]),
+        (a, None) => a,
+        (None, b) => b,
+    }
+}
+
+/// Compute the Hilbert key of the bounding box center of each geometry, within `bounds`.
+///
+/// Null and empty geometries have the largest possible key.
+fn hilbert_keys(
+    batch: &RecordBatch,
+    geometry_column: usize,
+    bounds: [f64; 4],
+) -> Result<UInt32Array> {
+    let field = batch.schema_ref().field(geometry_column);
+    let geo_arr = from_arrow_array(batch.column(geometry_column), field)?;
+    let keys = geometry_hilbert_keys(geo_arr.as_ref(), bounds)?
+        .into_iter()
+        .map(|key| key.unwrap_or(u32::MAX))
+        .collect::<Vec<_>>();
+    Ok(UInt32Array::from(keys))
+}
+
+/// A uniquely named directory for the spilled runs, removed with its contents when dropped.
+struct SpillDir {
+    path: PathBuf,
+    num_files: usize,
+}
+
+impl SpillDir {
+    fn try_new(tmp_dir: &Path) -> Result<Self> {
+        static NUM_SPILL_DIRS: AtomicUsize = AtomicUsize::new(0);
+        let path = tmp_dir.join(format!(
+            "geoarrow-sort-{}-{}",
+            std::process::id(),
+            NUM_SPILL_DIRS.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::create_dir(&path)?;
+        Ok(Self { path, num_files: 0 })
+    }
+
+    fn next_path(&mut self) -> PathBuf {
+        self.num_files += 1;
+        self.path.join(format!("run-{}.arrow", self.num_files))
+    }
+}
+
+impl Drop for SpillDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::PointArray;
+    use crate::io::parquet::read_geoparquet;
+    use crate::GeometryArrayTrait;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::UInt32Type;
+    use bytes::Bytes;
+
+    fn test_dir(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).unwrap();
+        path
+    }
+
+    /// 300 batches of 10 points each, scattered over [0, 1000] x [0, 1000].
+    fn synthetic_batches() -> (SchemaRef, Vec<RecordBatch>) {
+        let mut state = 42_u64;
+        let mut next_coord = move || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 33) as f64 % 1000.
+        };
+
+        let mut schema = None;
+        let mut batches = vec![];
+        for batch_idx in 0..300_u32 {
+            let mut points = (0..10)
+                .map(|_| geo::Point::new(next_coord(), next_coord()))
+                .collect::<Vec<_>>();
+            if batch_idx == 0 {
+                // Pin the extent of the input
+                points[0] = geo::Point::new(0., 0.);
+                points[1] = geo::Point::new(1000., 1000.);
+            }
+            let point_array: PointArray = points.as_slice().into();
+            let ids = UInt32Array::from_iter_values((0..10).map(|row| batch_idx * 10 + row));
+            let schema = schema
+                .get_or_insert_with(|| {
+                    Arc::new(Schema::new(vec![
+                        point_array.extension_field(),
+                        Arc::new(Field::new("id", DataType::UInt32, false)),
+                    ]))
+                })
+                .clone();
+            let batch =
+                RecordBatch::try_new(schema, vec![point_array.into_array_ref(), Arc::new(ids)])
+                    .unwrap();
+            batches.push(batch);
+        }
+        (schema.unwrap(), batches)
+    }
+
+    #[test]
+    fn sort_synthetic_stream() {
+        let tmp_dir = test_dir("geoarrow-sort-synthetic");
+        let (schema, batches) = synthetic_batches();
+
+        // Spill each batch as its own run, forcing several rounds of merging
+        let options = SpatialSortOptions::default()
+            .with_memory_budget(1)
+            .with_merge_fan_in(4)
+            .with_batch_size(64);
+        let mut buf = vec![];
+        write_geoparquet_spatially_sorted(
+            batches.into_iter().map(Ok),
+            schema,
+            &tmp_dir,
+            &mut buf,
+            &options,
+        )
+        .unwrap();
+        assert_eq!(fs::read_dir(&tmp_dir).unwrap().count(), 0);
+
+        let table = read_geoparquet(Bytes::from(buf), Default::default()).unwrap();
+        assert_eq!(table.len(), 3000);
+        let mut keys = vec![];
+        let mut ids = vec![];
+        for batch in table.batches() {
+            let geometry_column = batch.schema().index_of("geometry").unwrap();
+            let batch_keys = hilbert_keys(batch, geometry_column, [0., 0., 1000., 1000.]).unwrap();
+            keys.extend(batch_keys.values().iter().copied());
+            let batch_ids = batch
+                .column_by_name("id")
+                .unwrap()
+                .as_primitive::<UInt32Type>();
+            ids.extend(batch_ids.values().iter().copied());
+        }
+        assert!(keys.windows(2).all(|pair| pair[0] <= pair[1]));
+        ids.sort_unstable();
+        assert_eq!(ids, (0..3000).collect::<Vec<_>>());
+
+        fs::remove_dir_all(&tmp_dir).unwrap();
+    }
+
+    #[test]
+    fn cleanup_on_error() {
+        let tmp_dir = test_dir("geoarrow-sort-error");
+        let (schema, batches) = synthetic_batches();
+        let failing_batches = batches
+            .into_iter()
+            .take(20)
+            .map(Ok)
+            .chain([Err(ArrowError::ComputeError("input failed".to_string()))]);
+
+        let options = SpatialSortOptions::default()
+            .with_memory_budget(1)
+            .with_bounds([0., 0., 1000., 1000.]);
+        let result = write_geoparquet_spatially_sorted(
+            failing_batches,
+            schema,
+            &tmp_dir,
+            Vec::<u8>::new(),
+            &options,
+        );
+        assert!(result.is_err());
+        assert_eq!(fs::read_dir(&tmp_dir).unwrap().count(), 0);
+
+        fs::remove_dir_all(&tmp_dir).unwrap();
+    }
+}
@@ -1,20 +1,62 @@
 use std::io::Write;
 
+#[cfg(not(feature = "rayon"))]
+use crate::error::GeoArrowError;
 use crate::error::Result;
 use crate::io::parquet::writer::encode::encode_record_batch;
 use crate::io::parquet::writer::metadata::GeoParquetMetadataBuilder;
-use crate::io::parquet::writer::options::GeoParquetWriterOptions;
+use crate::io::parquet::writer::options::{GeoParquetWriterOptions, SpatialSort};
+#[cfg(feature = "rayon")]
+use crate::io::parquet::writer::parallel::write_geoparquet_parallel;
 use crate::table::GeoTable;
 use arrow_array::RecordBatch;
 use arrow_schema::Schema;
 use parquet::arrow::ArrowWriter;
 use parquet::file::metadata::KeyValue;
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        skip_all,
+        fields(num_rows = table.len(), num_batches = table.batches().len())
+    )
+)]
+/// Write a GeoTable to a GeoParquet file.
+///
+/// The GeoParquet metadata is stored under the `geo` key of the Parquet file metadata. It
+/// includes the encoding, the geometry types present and the bounding box of each geometry
+/// column, all of which are computed while writing.
+///
+/// If [`writer_threads`][GeoParquetWriterOptions::writer_threads] is more than 1, row groups are
+/// encoded concurrently.
 pub fn write_geoparquet<W: Write + Send>(
-    table: &mut GeoTable,
+    table: &GeoTable,
     writer: W,
     options: &GeoParquetWriterOptions,
 ) -> Result<()> {
+    let sorted_table;
+    let table = match options.sort {
+        Some(SpatialSort::Hilbert) => {
+            sorted_table = table.sort_hilbert(None)?;
+            &sorted_table
+        }
+        None => table,
+    };
+
+    if let Some(num_threads) = options
+        .writer_threads
+        .filter(|num_threads| *num_threads > 1)
+    {
+        #[cfg(feature = "rayon")]
+        return write_geoparquet_parallel(table, writer, options, num_threads);
+
+        #[cfg(not(feature = "rayon"))]
+        return Err(GeoArrowError::General(format!(
+            "writing with {} threads requires the rayon feature",
+            num_threads
+        )));
+    }
+
     let mut parquet_writer = GeoParquetWriter::try_new(writer, table.schema(), options)?;
 
     for batch in table.batches() {
@@ -25,6 +67,10 @@ pub fn write_geoparquet<W: Write + Send>(
     Ok(())
 }
 
+/// A writer for GeoParquet files that accepts record batches incrementally.
+///
+/// Schemas without any GeoArrow geometry columns are written as plain Parquet, without the `geo`
+/// file metadata.
 pub struct GeoParquetWriter<W: Write + Send> {
     writer: ArrowWriter<W>,
     metadata_builder: GeoParquetMetadataBuilder,
@@ -37,7 +83,7 @@ impl<W: Write + Send> GeoParquetWriter<W> {
         let writer = ArrowWriter::try_new(
             writer,
             metadata_builder.output_schema.clone(),
-            options.writer_properties.clone(),
+            options.writer_properties(&metadata_builder.output_schema)?,
         )?;
 
         Ok(Self {
@@ -49,6 +95,10 @@ impl<W: Write + Send> GeoParquetWriter<W> {
     pub fn write_batch(&mut self, batch: &RecordBatch) -> Result<()> {
         let encoded_batch = encode_record_batch(batch, &mut self.metadata_builder)?;
         self.writer.write(&encoded_batch)?;
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(num_rows = batch.num_rows(), "wrote GeoParquet batch");
+
         Ok(())
     }
 
@@ -62,7 +112,21 @@ impl<W: Write + Send> GeoParquetWriter<W> {
             self.writer.append_key_value_metadata(kv_metadata);
         }
 
-        self.writer.close()?;
+        #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+        let file_metadata = self.writer.close()?;
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            num_rows = file_metadata.num_rows,
+            num_row_groups = file_metadata.row_groups.len(),
+            num_bytes = file_metadata
+                .row_groups
+                .iter()
+                .map(|row_group| row_group.total_byte_size)
+                .sum::<i64>(),
+            "finished GeoParquet file"
+        );
+
         Ok(())
     }
 }
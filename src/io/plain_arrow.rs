@@ -0,0 +1,273 @@
+//! Convert geometry arrays to and from plain Arrow arrays, without GeoArrow extension types.
+//!
+//! Some Arrow consumers, such as BI connectors, can't handle extension types or the
+//! `FixedSizeList` layout of interleaved coordinates, but can handle plain nested lists of
+//! `Struct<x: Float64, y: Float64>`. [`to_plain_arrow`] converts a geometry array to one of the
+//! layouts in [`PlainStyle`], and [`GeoTable::to_plain_arrow`] converts every geometry column of
+//! a table and strips their extension metadata. [`from_plain_arrow`] reads the struct layout
+//! back, given the geometry type that it was declared with.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_array::{new_empty_array, Array, ArrayRef, RecordBatch};
+use arrow_schema::{Field, Schema, SchemaRef};
+
+use crate::array::*;
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::io::wkb::to_wkb;
+use crate::io::wkt::to_wkt;
+use crate::table::{GeoTable, GEOARROW_EXTENSION_NAMES};
+use crate::GeometryArrayTrait;
+
+/// The layout of a plain Arrow geometry array.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PlainStyle {
+    /// Nested lists of `Struct<x: Float64, y: Float64>` coordinates, with one level of lists per
+    /// level of nesting of the geometry type: a point is a struct, a line string a list of
+    /// structs, and a multi polygon a list of lists of lists of structs.
+    ///
+    /// This is the GeoArrow layout with separated coordinates, so it can't represent mixed
+    /// geometry arrays, geometry collections or rects.
+    #[default]
+    ListOfXYStructs,
+
+    /// A `Binary` array of little-endian WKB.
+    WkbBinary,
+
+    /// A `Utf8` array of WKT.
+    WktUtf8,
+}
+
+/// Convert a geometry array to a plain Arrow array with the layout `style`.
+///
+/// The result doesn't carry any GeoArrow metadata, such as the CRS.
+pub fn to_plain_arrow(arr: &dyn GeometryArrayTrait, style: PlainStyle) -> Result<ArrayRef> {
+    match style {
+        PlainStyle::ListOfXYStructs => match arr.data_type() {
+            GeoDataType::Point(_)
+            | GeoDataType::LineString(_)
+            | GeoDataType::LargeLineString(_)
+            | GeoDataType::Polygon(_)
+            | GeoDataType::LargePolygon(_)
+            | GeoDataType::MultiPoint(_)
+            | GeoDataType::LargeMultiPoint(_)
+            | GeoDataType::MultiLineString(_)
+            | GeoDataType::LargeMultiLineString(_)
+            | GeoDataType::MultiPolygon(_)
+            | GeoDataType::LargeMultiPolygon(_) => {
+                Ok(arr.to_coord_type(CoordType::Separated).to_array_ref())
+            }
+            data_type => Err(GeoArrowError::NotYetImplemented(format!(
+                "{:?} arrays can't be converted to lists of structs; use PlainStyle::WkbBinary",
+                data_type
+            ))),
+        },
+        PlainStyle::WkbBinary => match arr.data_type() {
+            GeoDataType::WKB | GeoDataType::LargeWKB => Ok(arr.to_array_ref()),
+            GeoDataType::Rect => Err(GeoArrowError::NotYetImplemented(
+                "Writing Rect arrays to WKB is not yet supported".to_string(),
+            )),
+            _ => Ok(to_wkb::<i32>(arr).into_array_ref()),
+        },
+        PlainStyle::WktUtf8 => Ok(Arc::new(to_wkt::<i32>(arr)?)),
+    }
+}
+
+/// Read a geometry array of type `data_type` from an array with the
+/// [`ListOfXYStructs`][PlainStyle::ListOfXYStructs] layout.
+///
+/// The coordinates of the result have the coord type of `data_type`. Arrays with interleaved
+/// `FixedSizeList` coordinates are accepted as well. Returns an error if the nesting of `array`
+/// doesn't match `data_type`.
+pub fn from_plain_arrow(
+    array: &dyn Array,
+    data_type: &GeoDataType,
+) -> Result<Arc<dyn GeometryArrayTrait>> {
+    let (result, coord_type): (Arc<dyn GeometryArrayTrait>, _) = match data_type {
+        GeoDataType::Point(ct) => (Arc::new(PointArray::try_from(array)?), ct),
+        GeoDataType::LineString(ct) => (Arc::new(LineStringArray::<i32>::try_from(array)?), ct),
+        GeoDataType::LargeLineString(ct) => {
+            (Arc::new(LineStringArray::<i64>::try_from(array)?), ct)
+        }
+        GeoDataType::Polygon(ct) => (Arc::new(PolygonArray::<i32>::try_from(array)?), ct),
+        GeoDataType::LargePolygon(ct) => (Arc::new(PolygonArray::<i64>::try_from(array)?), ct),
+        GeoDataType::MultiPoint(ct) => (Arc::new(MultiPointArray::<i32>::try_from(array)?), ct),
+        GeoDataType::LargeMultiPoint(ct) => {
+            (Arc::new(MultiPointArray::<i64>::try_from(array)?), ct)
+        }
+        GeoDataType::MultiLineString(ct) => {
+            (Arc::new(MultiLineStringArray::<i32>::try_from(array)?), ct)
+        }
+        GeoDataType::LargeMultiLineString(ct) => {
+            (Arc::new(MultiLineStringArray::<i64>::try_from(array)?), ct)
+        }
+        GeoDataType::MultiPolygon(ct) => (Arc::new(MultiPolygonArray::<i32>::try_from(array)?), ct),
+        GeoDataType::LargeMultiPolygon(ct) => {
+            (Arc::new(MultiPolygonArray::<i64>::try_from(array)?), ct)
+        }
+        data_type => {
+            return Err(GeoArrowError::IncorrectType(
+                format!("{:?} arrays can't be read from lists of structs", data_type).into(),
+            ))
+        }
+    };
+
+    if result.coord_type() == *coord_type {
+        Ok(result)
+    } else {
+        Ok(result.to_coord_type(*coord_type))
+    }
+}
+
+impl GeoTable {
+    /// Convert every geometry column of this table to a plain Arrow column with the layout
+    /// `style`, as with [`to_plain_arrow`], and remove the GeoArrow extension metadata of their
+    /// fields.
+    ///
+    /// Geometry columns are the columns with a GeoArrow extension type, including the geometry
+    /// column of the table. WKT columns are already plain, and are kept as they are in any style.
+    /// Other columns and the schema metadata are unchanged.
+    pub fn to_plain_arrow(&self, style: PlainStyle) -> Result<(SchemaRef, Vec<RecordBatch>)> {
+        let schema = self.schema();
+        let geometry_columns = schema
+            .fields()
+            .iter()
+            .enumerate()
+            .filter_map(|(index, field)| {
+                field
+                    .metadata()
+                    .get("ARROW:extension:name")
+                    .filter(|name| GEOARROW_EXTENSION_NAMES.contains(name.as_str()))
+                    .map(|name| (index, name.as_str() != "geoarrow.wkt"))
+            })
+            .collect::<HashMap<_, _>>();
+
+        let convert = |index: usize, column: &ArrayRef| -> Result<ArrayRef> {
+            match geometry_columns.get(&index) {
+                Some(true) => {
+                    let arr = from_arrow_array(column.as_ref(), schema.field(index))?;
+                    to_plain_arrow(arr.as_ref(), style)
+                }
+                _ => Ok(column.clone()),
+            }
+        };
+
+        let fields = schema
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(index, field)| {
+                if !geometry_columns.contains_key(&index) {
+                    return Ok(field.clone());
+                }
+
+                let empty = convert(index, &new_empty_array(field.data_type()))?;
+                let mut metadata = field.metadata().clone();
+                metadata.remove("ARROW:extension:name");
+                metadata.remove("ARROW:extension:metadata");
+                Ok(Arc::new(
+                    Field::new(field.name(), empty.data_type().clone(), true)
+                        .with_metadata(metadata),
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let plain_schema = Arc::new(Schema::new_with_metadata(fields, schema.metadata().clone()));
+
+        let batches = self
+            .batches()
+            .iter()
+            .map(|batch| {
+                let columns = batch
+                    .columns()
+                    .iter()
+                    .enumerate()
+                    .map(|(index, column)| convert(index, column))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(RecordBatch::try_new(plain_schema.clone(), columns)?)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok((plain_schema, batches))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::io::wkb::from_wkb;
+    use crate::io::wkt::from_wkt;
+    use crate::test::{linestring, multilinestring, multipoint, multipolygon, point, polygon};
+    use arrow_array::cast::AsArray;
+    use arrow_schema::DataType;
+
+    fn wkb(arr: &dyn GeometryArrayTrait) -> arrow_array::BinaryArray {
+        to_wkb::<i32>(arr).into_inner()
+    }
+
+    fn round_trip(arr: Arc<dyn GeometryArrayTrait>) {
+        let data_type = *arr.data_type();
+
+        let plain = to_plain_arrow(arr.as_ref(), PlainStyle::ListOfXYStructs).unwrap();
+        assert!(!format!("{:?}", plain.data_type()).contains("FixedSizeList"));
+        let parsed = from_plain_arrow(plain.as_ref(), &data_type).unwrap();
+        assert_eq!(parsed.data_type(), &data_type);
+        assert_eq!(wkb(parsed.as_ref()), wkb(arr.as_ref()));
+
+        let plain = to_plain_arrow(arr.as_ref(), PlainStyle::WkbBinary).unwrap();
+        assert_eq!(plain.data_type(), &DataType::Binary);
+        let parsed = from_wkb(
+            &WKBArray::<i32>::try_from(plain.as_ref()).unwrap(),
+            data_type,
+            false,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        )
+        .unwrap();
+        assert_eq!(wkb(parsed.as_ref()), wkb(arr.as_ref()));
+
+        let plain = to_plain_arrow(arr.as_ref(), PlainStyle::WktUtf8).unwrap();
+        let parsed = from_wkt(plain.as_string::<i32>(), data_type, Default::default()).unwrap();
+        assert_eq!(wkb(parsed.as_ref()), wkb(arr.as_ref()));
+    }
+
+    #[test]
+    fn round_trip_geometry_types() {
+        round_trip(Arc::new(point::point_array()));
+        round_trip(Arc::new(linestring::ls_array()));
+        round_trip(Arc::new(linestring::large_ls_array()));
+        round_trip(Arc::new(polygon::p_array()));
+        round_trip(Arc::new(multipoint::mp_array()));
+        round_trip(Arc::new(multilinestring::ml_array()));
+        round_trip(Arc::new(multipolygon::mp_array()));
+    }
+
+    #[test]
+    fn mismatched_type() {
+        let plain = to_plain_arrow(&linestring::ls_array(), PlainStyle::ListOfXYStructs).unwrap();
+        let polygon_type = GeoDataType::Polygon(CoordType::Interleaved);
+        assert!(from_plain_arrow(plain.as_ref(), &polygon_type).is_err());
+
+        let mixed: MixedGeometryArray<i32> = vec![geo::Geometry::from(point::p0())]
+            .as_slice()
+            .try_into()
+            .unwrap();
+        assert!(to_plain_arrow(&mixed, PlainStyle::ListOfXYStructs).is_err());
+    }
+
+    #[test]
+    fn plain_table() {
+        let table = point::table();
+        let (schema, batches) = table.to_plain_arrow(PlainStyle::ListOfXYStructs).unwrap();
+        let field = schema.field(2);
+        assert!(field.metadata().get("ARROW:extension:name").is_none());
+        assert!(matches!(field.data_type(), DataType::Struct(_)));
+        assert_eq!(batches[0].schema(), schema);
+        assert_eq!(schema.field(0), table.schema().field(0));
+
+        let (schema, _) = table.to_plain_arrow(PlainStyle::WktUtf8).unwrap();
+        assert_eq!(schema.field(2).data_type(), &DataType::Utf8);
+    }
+}
@@ -3,4 +3,4 @@
 mod reader;
 mod type_info;
 
-pub use reader::read_postgis;
+pub use reader::{read_postgis, PostGISReaderOptions};
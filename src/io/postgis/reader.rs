@@ -1,11 +1,13 @@
 //! This is partially derived from <https://github.com/alttch/myval> under the Apache 2 license
 
+use arrow_array::OffsetSizeTrait;
 use arrow_schema::{DataType, Field, SchemaBuilder, TimeUnit};
-use chrono::{DateTime, Utc};
+use byteorder::{BigEndian, ReadBytesExt};
+use chrono::{DateTime, NaiveDateTime, Utc};
 use futures::stream::TryStreamExt;
 use geozero::wkb::process_ewkb_geom;
-use geozero::{ColumnValue, FeatureProcessor, GeomProcessor, GeozeroGeometry, PropertyProcessor};
-use sqlx::postgres::{PgRow, PgTypeInfo};
+use geozero::{ColumnValue, FeatureProcessor, GeozeroGeometry, PropertyProcessor};
+use sqlx::postgres::{PgRow, PgTypeInfo, PgValueFormat, PgValueRef};
 use sqlx::{Column, Decode, Executor, Postgres, Row, Type, TypeInfo};
 use std::io::Cursor;
 use std::sync::Arc;
@@ -13,8 +15,32 @@ use std::sync::Arc;
 use crate::error::{GeoArrowError, Result};
 use crate::io::geozero::array::MixedGeometryStreamBuilder;
 use crate::io::geozero::table::{GeoTableBuilder, GeoTableBuilderOptions};
+use crate::io::wkb::{ewkb_srid, linearize_curves, srid_crs, CurvePolicy};
 use crate::table::GeoTable;
-use crate::trait_::GeometryArrayBuilder;
+
+/// Options for reading the result of a SQL query against a PostGIS database.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PostGISReaderOptions {
+    /// The number of rows in each batch. If `None`, batches hold 65,536 rows.
+    pub batch_size: Option<usize>,
+
+    /// How to read curved geometries, such as CircularStrings and CurvePolygons.
+    pub curve_policy: CurvePolicy,
+}
+
+impl PostGISReaderOptions {
+    /// Set the number of rows in each batch.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = Some(batch_size);
+        self
+    }
+
+    /// Set how to read curved geometries.
+    pub fn with_curve_policy(mut self, curve_policy: CurvePolicy) -> Self {
+        self.curve_policy = curve_policy;
+        self
+    }
+}
 
 /// A wrapper for an EWKB-encoded postgis geometry
 pub struct PostgisEWKBGeometry<'a>(&'a [u8]);
@@ -42,21 +68,84 @@ impl<'a> GeozeroGeometry for PostgisEWKBGeometry<'a> {
     }
 }
 
+/// A Postgres `NUMERIC` value, converted to the nearest `f64`.
+///
+/// This loses precision for values with more than about 15 significant digits.
+struct PgNumeric(f64);
+
+impl<'r> Decode<'r, Postgres> for PgNumeric {
+    fn decode(value: PgValueRef<'r>) -> std::prelude::v1::Result<Self, sqlx::error::BoxDynError> {
+        if let PgValueFormat::Text = value.format() {
+            return Ok(Self(value.as_str()?.parse()?));
+        }
+
+        // The binary format is the number of base-10000 digits, the weight of the first digit,
+        // the sign and the display scale, followed by the digits.
+        let mut reader = Cursor::new(value.as_bytes()?);
+        let num_digits = reader.read_i16::<BigEndian>()?;
+        let weight = reader.read_i16::<BigEndian>()?;
+        let sign = reader.read_u16::<BigEndian>()?;
+        let _scale = reader.read_u16::<BigEndian>()?;
+        match sign {
+            0xC000 => return Ok(Self(f64::NAN)),
+            0xD000 => return Ok(Self(f64::INFINITY)),
+            0xF000 => return Ok(Self(f64::NEG_INFINITY)),
+            _ => (),
+        }
+
+        // Write the digits out in scientific notation, so that parsing rounds correctly
+        let mut decimal = String::from(if sign == 0x4000 { "-0." } else { "0." });
+        for _ in 0..num_digits {
+            decimal.push_str(&format!("{:04}", reader.read_i16::<BigEndian>()?));
+        }
+        decimal.push_str(&format!("e{}", 4 * (weight as i32 + 1)));
+        Ok(Self(decimal.parse()?))
+    }
+}
+
+impl Type<Postgres> for PgNumeric {
+    fn type_info() -> <Postgres as sqlx::Database>::TypeInfo {
+        PgTypeInfo::with_name("numeric")
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        ty.name() == "NUMERIC"
+    }
+}
+
 // TODO: right now this uses a hashmap with names. In the future, it should switch to using a
 // positional schema.
-// TODO: manage buffering
-impl<G: GeometryArrayBuilder + GeomProcessor> GeoTableBuilder<G> {
-    fn add_postgres_geometry(&mut self, value: PostgisEWKBGeometry) -> Result<()> {
+impl<O: OffsetSizeTrait> GeoTableBuilder<MixedGeometryStreamBuilder<O>> {
+    fn add_postgres_geometry(
+        &mut self,
+        value: Option<PostgisEWKBGeometry>,
+        curve_policy: CurvePolicy,
+    ) -> Result<()> {
+        let Some(value) = value else {
+            self.geometry_builder_mut().push_null();
+            return Ok(());
+        };
+
         self.geometry_begin()?;
-        value.process_geom(self)?;
+        // Curves are linearized to plain WKB first, as the builder has no curve support
+        match linearize_curves(value.0, curve_policy)? {
+            Some(wkb) => PostgisEWKBGeometry(&wkb).process_geom(self)?,
+            None => value.process_geom(self)?,
+        }
         self.geometry_end()?;
         Ok(())
     }
 
-    fn add_postgres_row(&mut self, row_idx: u64, row: &PgRow) -> Result<()> {
+    fn add_postgres_row(
+        &mut self,
+        row_idx: u64,
+        row: &PgRow,
+        curve_policy: CurvePolicy,
+    ) -> Result<()> {
         self.feature_begin(row_idx)?;
         self.properties_begin()?;
-        let mut geometry: Option<PostgisEWKBGeometry> = None;
+        // `None` if the query has no geometry column, and `Some(None)` for a null geometry
+        let mut geometry: Option<Option<PostgisEWKBGeometry>> = None;
         for (i, column) in row.columns().iter().enumerate() {
             let column_name = column.name();
             let upstream_type_info = column.type_info();
@@ -64,38 +153,42 @@ impl<G: GeometryArrayBuilder + GeomProcessor> GeoTableBuilder<G> {
                 super::type_info::PgTypeInfo::from_upstream(upstream_type_info)
             {
                 use super::type_info::PgType::*;
+                // Null values are skipped, and are filled in as nulls by `properties_end`
                 let column_value = match our_type_info.0 {
-                    Bool => Some(ColumnValue::Bool(row.try_get(i)?)),
-                    Bytea | Bit => Some(ColumnValue::Binary(row.try_get(i)?)),
-                    Int2 => Some(ColumnValue::Short(row.try_get(i)?)),
-                    Int4 => Some(ColumnValue::Int(row.try_get(i)?)),
-                    Int8 => Some(ColumnValue::Long(row.try_get(i)?)),
-                    Float4 => Some(ColumnValue::Float(row.try_get(i)?)),
-                    Float8 => Some(ColumnValue::Double(row.try_get(i)?)),
+                    Bool => Some(row.try_get::<Option<_>, _>(i)?.map(ColumnValue::Bool)),
+                    Bytea | Bit => Some(row.try_get::<Option<_>, _>(i)?.map(ColumnValue::Binary)),
+                    Int2 => Some(row.try_get::<Option<_>, _>(i)?.map(ColumnValue::Short)),
+                    Int4 => Some(row.try_get::<Option<_>, _>(i)?.map(ColumnValue::Int)),
+                    Int8 => Some(row.try_get::<Option<_>, _>(i)?.map(ColumnValue::Long)),
+                    Float4 => Some(row.try_get::<Option<_>, _>(i)?.map(ColumnValue::Float)),
+                    Float8 => Some(row.try_get::<Option<_>, _>(i)?.map(ColumnValue::Double)),
+                    Numeric => Some(
+                        row.try_get::<Option<PgNumeric>, _>(i)?
+                            .map(|value| ColumnValue::Double(value.0)),
+                    ),
                     Text | Varchar | Char | Json | Jsonb => {
-                        Some(ColumnValue::String(row.try_get(i)?))
+                        Some(row.try_get::<Option<_>, _>(i)?.map(ColumnValue::String))
                     }
                     _ => None,
                 };
 
                 if let Some(column_value) = column_value {
                     // The property type is contained within geozero's type system
-                    self.property(i, column_name, &column_value)?;
+                    if let Some(column_value) = column_value {
+                        self.property(i, column_name, &column_value)?;
+                    }
                 } else {
                     // The type is outside of geozero's type system so we handle it manually
-                    match our_type_info.0 {
-                        Timestamp => {
-                            let value: DateTime<Utc> = row.try_get(i)?;
-                            self.properties_builder_mut()
-                                .add_timestamp_property(column_name, value)?;
-                        }
-                        Timestamptz => {
-                            let value: DateTime<Utc> = row.try_get(i)?;
-                            self.properties_builder_mut()
-                                .add_timestamp_property(column_name, value)?;
-                        }
-
+                    let value = match our_type_info.0 {
+                        Timestamp => row
+                            .try_get::<Option<NaiveDateTime>, _>(i)?
+                            .map(|value| value.and_utc()),
+                        Timestamptz => row.try_get::<Option<DateTime<Utc>>, _>(i)?,
                         v => todo!("unimplemented type in column value: {}", v.display_name()),
+                    };
+                    if let Some(value) = value {
+                        self.properties_builder_mut()
+                            .add_timestamp_property(column_name, value)?;
                     }
                 }
             } else {
@@ -114,12 +207,19 @@ impl<G: GeometryArrayBuilder + GeomProcessor> GeoTableBuilder<G> {
         }
         self.properties_end()?;
         // Add geometry after we've finished writing properties
-        self.add_postgres_geometry(geometry.expect("missing geometry for row {}"))?;
+        let geometry = geometry.ok_or_else(|| {
+            GeoArrowError::General("The query result has no geometry column".to_string())
+        })?;
+        self.add_postgres_geometry(geometry, curve_policy)?;
         self.feature_end(row_idx)?;
         Ok(())
     }
 
-    fn initialize_from_row(row: &PgRow, mut options: GeoTableBuilderOptions) -> Result<Self> {
+    fn initialize_from_row(
+        row: &PgRow,
+        mut options: GeoTableBuilderOptions,
+        curve_policy: CurvePolicy,
+    ) -> Result<Self> {
         let mut schema = SchemaBuilder::new();
         for column in row.columns() {
             let column_name = column.name();
@@ -135,7 +235,7 @@ impl<G: GeometryArrayBuilder + GeomProcessor> GeoTableBuilder<G> {
                     Int4 => DataType::Int32,
                     Int8 => DataType::Int64,
                     Float4 => DataType::Float32,
-                    Float8 => DataType::Float64,
+                    Float8 | Numeric => DataType::Float64,
                     Timestamp => DataType::Timestamp(TimeUnit::Microsecond, None),
                     Timestamptz => DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
                     Text | Varchar | Char | Json | Jsonb => DataType::Utf8,
@@ -161,41 +261,80 @@ impl<G: GeometryArrayBuilder + GeomProcessor> GeoTableBuilder<G> {
 
         // Create builder and add this row
         let mut builder = Self::new_with_options(options);
-        builder.add_postgres_row(0, row)?;
+        builder.add_postgres_row(0, row, curve_policy)?;
         Ok(builder)
     }
 }
 
+/// Read the result of a SQL query against a PostGIS database to a [GeoTable].
+///
+/// Geometry and geography columns are parsed from EWKB, and the SRID of the first geometry with
+/// one is recorded as the CRS of the geometry column. Null values, including null geometries, are
+/// read as nulls.
+///
+/// `NUMERIC` columns are read as `Float64`, so values with more than about 15 significant digits
+/// lose precision.
+///
+/// Returns `None` if the query returns no rows.
 pub async fn read_postgis<'c, E: Executor<'c, Database = Postgres>>(
     executor: E,
     sql: &str,
+    options: PostGISReaderOptions,
 ) -> Result<Option<GeoTable>> {
     let query = sqlx::query::<Postgres>(sql);
     let mut result_stream = query.fetch(executor);
     let mut table_builder: Option<GeoTableBuilder<MixedGeometryStreamBuilder<i32>>> = None;
+    let mut srid = None;
 
-    // TODO: try out chunking with `result_stream.try_chunks`
     let mut row_idx = 0;
     while let Some(row) = result_stream.try_next().await? {
+        if srid.is_none() {
+            srid = row_srid(&row)?;
+        }
+
         if let Some(ref mut table_builder) = table_builder {
             // Add this row
-            table_builder.add_postgres_row(row_idx, &row)?;
+            table_builder.add_postgres_row(row_idx, &row, options.curve_policy)?;
         } else {
             // Initialize table builder
-            let table_builder_options = GeoTableBuilderOptions::default();
+            let table_builder_options = GeoTableBuilderOptions::new(
+                Default::default(),
+                true,
+                options.batch_size,
+                None,
+                None,
+                Default::default(),
+            );
             table_builder = Some(GeoTableBuilder::initialize_from_row(
                 &row,
                 table_builder_options,
+                options.curve_policy,
             )?)
         };
         row_idx += 1;
     }
 
-    if let Some(table_builder) = table_builder {
-        Ok(Some(table_builder.finish()?))
-    } else {
-        Ok(None)
+    let Some(table_builder) = table_builder else {
+        return Ok(None);
+    };
+    let mut table = table_builder.finish()?;
+    if let Some(crs) = srid.and_then(srid_crs) {
+        table.set_crs(table.geometry_column_index(), Some(crs))?;
+    }
+    Ok(Some(table))
+}
+
+/// The SRID of the geometry in this row, if it isn't null and has one.
+fn row_srid(row: &PgRow) -> Result<Option<u32>> {
+    for (i, column) in row.columns().iter().enumerate() {
+        if let "geometry" | "geography" = column.type_info().name() {
+            return match row.try_get::<Option<PostgisEWKBGeometry>, _>(i)? {
+                Some(geometry) => ewkb_srid(geometry.0),
+                None => Ok(None),
+            };
+        }
     }
+    Ok(None)
 }
 
 #[cfg(test)]
@@ -210,6 +349,6 @@ mod test {
         let pool = PgPoolOptions::new().connect(connection_url).await.unwrap();
         // let sql = "SELECT * FROM sample1;";
         let sql = "SELECT *, clock_timestamp() as ts FROM sample1;";
-        let _table = read_postgis(&pool, sql).await.unwrap();
+        let _table = read_postgis(&pool, sql, Default::default()).await.unwrap();
     }
 }
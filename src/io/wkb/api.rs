@@ -6,6 +6,9 @@ use crate::array::*;
 use crate::chunked_array::*;
 use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
+use crate::io::guards::ParseGuards;
+use crate::io::wkb::normalize::{normalize_wkb, CurvePolicy, DimensionPolicy};
+use crate::io::wkb::reader::Endianness;
 use crate::scalar::WKB;
 use crate::trait_::GeometryArrayAccessor;
 use crate::GeometryArrayTrait;
@@ -99,7 +102,7 @@ impl FromWKB for Arc<dyn GeometryArrayTrait> {
             arr.metadata(),
             true,
         )?;
-        Ok(builder.finish().downcast(true))
+        Ok(builder.finish().downcast(true, false))
     }
 }
 
@@ -138,14 +141,39 @@ impl_chunked!(ChunkedGeometryCollectionArray<OOutput>);
 
 /// Parse an ISO [WKBArray] to a GeometryArray with GeoArrow native encoding.
 ///
+/// Geometries may be encoded with either byte order. Only 2D geometries are supported, and
+/// `dimension_policy` decides what happens to geometries with Z or M coordinates, in either the
+/// ISO or the EWKB encoding. Likewise, `curve_policy` decides what happens to curved geometries
+/// such as CircularStrings and CurvePolygons.
+///
+/// Before parsing, the declared size of each geometry is checked against `guards`, so that
+/// untrusted input can't make parsing allocate or loop without bound.
+///
 /// Does not downcast automatically
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        skip_all,
+        fields(
+            num_rows = arr.len(),
+            num_bytes = arr.num_bytes(),
+            target_type = ?target_geo_data_type,
+        )
+    )
+)]
 pub fn from_wkb<O: OffsetSizeTrait>(
     arr: &WKBArray<O>,
     target_geo_data_type: GeoDataType,
     prefer_multi: bool,
+    dimension_policy: DimensionPolicy,
+    curve_policy: CurvePolicy,
+    guards: ParseGuards,
 ) -> Result<Arc<dyn GeometryArrayTrait>> {
     use GeoDataType::*;
 
+    guards.check_wkb(arr)?;
+    let normalized = normalize_wkb(arr, dimension_policy, curve_policy, 0)?;
+    let arr = normalized.as_ref().unwrap_or(arr);
     let wkb_objects: Vec<Option<crate::scalar::WKB<'_, O>>> = arr.iter().collect();
     match target_geo_data_type {
         Point(coord_type) => {
@@ -257,120 +285,227 @@ pub fn from_wkb<O: OffsetSizeTrait>(
     }
 }
 
-/// An optimized implementation of converting from ISO WKB-encoded geometries.
+/// Parse a [CompressedWKBArray] to a GeometryArray with GeoArrow native encoding.
 ///
-/// This implementation performs a two-pass approach, first scanning the input geometries to
-/// determine the exact buffer sizes, then making a single set of allocations and filling those new
-/// arrays with the WKB coordinate values.
+/// Blocks are decompressed one at a time and their geometries pushed into a single builder, so
+/// at most one decompressed block is held in memory alongside the output array. Unlike
+/// [from_wkb], the output buffers are grown as needed instead of being sized up front.
+///
+/// Does not downcast automatically
+#[cfg(feature = "wkb_compression")]
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        skip_all,
+        fields(
+            num_rows = arr.len(),
+            num_blocks = arr.num_blocks(),
+            num_bytes = arr.num_bytes(),
+            target_type = ?target_geo_data_type,
+        )
+    )
+)]
+pub fn from_compressed_wkb<O: OffsetSizeTrait>(
+    arr: &CompressedWKBArray<O>,
+    target_geo_data_type: GeoDataType,
+    prefer_multi: bool,
+    dimension_policy: DimensionPolicy,
+    curve_policy: CurvePolicy,
+) -> Result<Arc<dyn GeometryArrayTrait>> {
+    use GeoDataType::*;
+
+    macro_rules! push_blocks {
+        ($builder:expr, $push_func:ident $(, $arg:expr)?) => {{
+            let mut builder = $builder;
+            for (block_idx, block) in arr.blocks().enumerate() {
+                let block = block?;
+                let row_offset = block_idx * arr.block_size();
+                let normalized = normalize_wkb(&block, dimension_policy, curve_policy, row_offset)?;
+                let block = normalized.unwrap_or(block);
+                let wkb_objects: Vec<Option<WKB<'_, O>>> = block.iter().collect();
+                for maybe_wkb in wkb_objects.iter() {
+                    let geom = maybe_wkb.as_ref().map(|wkb| wkb.to_wkb_object());
+                    builder.$push_func(geom.as_ref() $(, $arg)?)?;
+                }
+            }
+            Ok(Arc::new(builder.finish()))
+        }};
+    }
+
+    let metadata = arr.metadata();
+    match target_geo_data_type {
+        Point(coord_type) => push_blocks!(
+            PointBuilder::new_with_options(coord_type, metadata),
+            push_geometry
+        ),
+        LineString(coord_type) => push_blocks!(
+            LineStringBuilder::<i32>::new_with_options(coord_type, metadata),
+            push_geometry
+        ),
+        LargeLineString(coord_type) => push_blocks!(
+            LineStringBuilder::<i64>::new_with_options(coord_type, metadata),
+            push_geometry
+        ),
+        Polygon(coord_type) => push_blocks!(
+            PolygonBuilder::<i32>::new_with_options(coord_type, metadata),
+            push_geometry
+        ),
+        LargePolygon(coord_type) => push_blocks!(
+            PolygonBuilder::<i64>::new_with_options(coord_type, metadata),
+            push_geometry
+        ),
+        MultiPoint(coord_type) => push_blocks!(
+            MultiPointBuilder::<i32>::new_with_options(coord_type, metadata),
+            push_geometry
+        ),
+        LargeMultiPoint(coord_type) => push_blocks!(
+            MultiPointBuilder::<i64>::new_with_options(coord_type, metadata),
+            push_geometry
+        ),
+        MultiLineString(coord_type) => push_blocks!(
+            MultiLineStringBuilder::<i32>::new_with_options(coord_type, metadata),
+            push_geometry
+        ),
+        LargeMultiLineString(coord_type) => push_blocks!(
+            MultiLineStringBuilder::<i64>::new_with_options(coord_type, metadata),
+            push_geometry
+        ),
+        MultiPolygon(coord_type) => push_blocks!(
+            MultiPolygonBuilder::<i32>::new_with_options(coord_type, metadata),
+            push_geometry
+        ),
+        LargeMultiPolygon(coord_type) => push_blocks!(
+            MultiPolygonBuilder::<i64>::new_with_options(coord_type, metadata),
+            push_geometry
+        ),
+        Mixed(coord_type) if prefer_multi => push_blocks!(
+            MixedGeometryBuilder::<i32>::new_with_options(coord_type, metadata),
+            push_geometry_preferring_multi
+        ),
+        Mixed(coord_type) => push_blocks!(
+            MixedGeometryBuilder::<i32>::new_with_options(coord_type, metadata),
+            push_geometry
+        ),
+        LargeMixed(coord_type) if prefer_multi => push_blocks!(
+            MixedGeometryBuilder::<i64>::new_with_options(coord_type, metadata),
+            push_geometry_preferring_multi
+        ),
+        LargeMixed(coord_type) => push_blocks!(
+            MixedGeometryBuilder::<i64>::new_with_options(coord_type, metadata),
+            push_geometry
+        ),
+        GeometryCollection(coord_type) => push_blocks!(
+            GeometryCollectionBuilder::<i32>::new_with_options(coord_type, metadata),
+            push_geometry,
+            prefer_multi
+        ),
+        LargeGeometryCollection(coord_type) => push_blocks!(
+            GeometryCollectionBuilder::<i64>::new_with_options(coord_type, metadata),
+            push_geometry,
+            prefer_multi
+        ),
+        t => Err(GeoArrowError::General(format!(
+            "Unexpected data type {:?}",
+            t,
+        ))),
+    }
+}
+
+/// Encode geometry arrays as ISO WKB.
 pub trait ToWKB: Sized {
     type Output<O: OffsetSizeTrait>;
 
-    fn to_wkb<O: OffsetSizeTrait>(&self) -> Self::Output<O>;
+    /// Encode as little-endian WKB.
+    fn to_wkb<O: OffsetSizeTrait>(&self) -> Self::Output<O> {
+        self.to_wkb_with_endianness(Endianness::LittleEndian)
+    }
+
+    /// Encode as WKB with the given byte order.
+    fn to_wkb_with_endianness<O: OffsetSizeTrait>(&self, endianness: Endianness)
+        -> Self::Output<O>;
 }
 
 impl ToWKB for &dyn GeometryArrayTrait {
     type Output<O: OffsetSizeTrait> = WKBArray<O>;
 
-    fn to_wkb<O: OffsetSizeTrait>(&self) -> Self::Output<O> {
-        match self.data_type() {
-            GeoDataType::Point(_) => self.as_point().into(),
-            GeoDataType::LineString(_) => self.as_line_string().into(),
-            GeoDataType::LargeLineString(_) => self.as_large_line_string().into(),
-            GeoDataType::Polygon(_) => self.as_polygon().into(),
-            GeoDataType::LargePolygon(_) => self.as_large_polygon().into(),
-            GeoDataType::MultiPoint(_) => self.as_multi_point().into(),
-            GeoDataType::LargeMultiPoint(_) => self.as_large_multi_point().into(),
-            GeoDataType::MultiLineString(_) => self.as_multi_line_string().into(),
-            GeoDataType::LargeMultiLineString(_) => self.as_large_multi_line_string().into(),
-            GeoDataType::MultiPolygon(_) => self.as_multi_polygon().into(),
-            GeoDataType::LargeMultiPolygon(_) => self.as_large_multi_polygon().into(),
-            GeoDataType::Mixed(_) => self.as_mixed().into(),
-            GeoDataType::LargeMixed(_) => self.as_large_mixed().into(),
-            GeoDataType::GeometryCollection(_) => self.as_geometry_collection().into(),
-            GeoDataType::LargeGeometryCollection(_) => self.as_large_geometry_collection().into(),
-            GeoDataType::WKB => todo!(),
-            GeoDataType::LargeWKB => todo!(),
-            GeoDataType::Rect => todo!(),
-        }
+    fn to_wkb_with_endianness<O: OffsetSizeTrait>(
+        &self,
+        endianness: Endianness,
+    ) -> Self::Output<O> {
+        to_wkb_with_endianness(*self, endianness)
     }
 }
 
 impl ToWKB for &dyn ChunkedGeometryArrayTrait {
     type Output<O: OffsetSizeTrait> = ChunkedWKBArray<O>;
 
-    fn to_wkb<O: OffsetSizeTrait>(&self) -> Self::Output<O> {
-        match self.data_type() {
-            GeoDataType::Point(_) => {
-                ChunkedGeometryArray::new(self.as_point().map(|chunk| chunk.into()))
-            }
-            GeoDataType::LineString(_) => {
-                ChunkedGeometryArray::new(self.as_line_string().map(|chunk| chunk.into()))
-            }
-            GeoDataType::LargeLineString(_) => {
-                ChunkedGeometryArray::new(self.as_large_line_string().map(|chunk| chunk.into()))
-            }
-            GeoDataType::Polygon(_) => {
-                ChunkedGeometryArray::new(self.as_polygon().map(|chunk| chunk.into()))
-            }
-            GeoDataType::LargePolygon(_) => {
-                ChunkedGeometryArray::new(self.as_large_polygon().map(|chunk| chunk.into()))
-            }
-            GeoDataType::MultiPoint(_) => {
-                ChunkedGeometryArray::new(self.as_multi_point().map(|chunk| chunk.into()))
-            }
-            GeoDataType::LargeMultiPoint(_) => {
-                ChunkedGeometryArray::new(self.as_large_multi_point().map(|chunk| chunk.into()))
-            }
-            GeoDataType::MultiLineString(_) => {
-                ChunkedGeometryArray::new(self.as_multi_line_string().map(|chunk| chunk.into()))
-            }
-            GeoDataType::LargeMultiLineString(_) => ChunkedGeometryArray::new(
-                self.as_large_multi_line_string().map(|chunk| chunk.into()),
-            ),
-            GeoDataType::MultiPolygon(_) => {
-                ChunkedGeometryArray::new(self.as_multi_polygon().map(|chunk| chunk.into()))
-            }
-            GeoDataType::LargeMultiPolygon(_) => {
-                ChunkedGeometryArray::new(self.as_large_multi_polygon().map(|chunk| chunk.into()))
-            }
-            GeoDataType::Mixed(_) => {
-                ChunkedGeometryArray::new(self.as_mixed().map(|chunk| chunk.into()))
-            }
-            GeoDataType::LargeMixed(_) => {
-                ChunkedGeometryArray::new(self.as_large_mixed().map(|chunk| chunk.into()))
-            }
-            GeoDataType::GeometryCollection(_) => {
-                ChunkedGeometryArray::new(self.as_geometry_collection().map(|chunk| chunk.into()))
-            }
-            GeoDataType::LargeGeometryCollection(_) => ChunkedGeometryArray::new(
-                self.as_large_geometry_collection()
-                    .map(|chunk| chunk.into()),
-            ),
-            GeoDataType::WKB => todo!(),
-            GeoDataType::LargeWKB => todo!(),
-            GeoDataType::Rect => todo!(),
-        }
+    fn to_wkb_with_endianness<O: OffsetSizeTrait>(
+        &self,
+        endianness: Endianness,
+    ) -> Self::Output<O> {
+        ChunkedGeometryArray::new_unchecked(
+            self.geometry_chunks()
+                .into_iter()
+                .map(|chunk| to_wkb_with_endianness(chunk, endianness))
+                .collect(),
+        )
     }
 }
 
 /// Convert a geometry array to a [WKBArray].
+///
+/// Geometries are encoded as little-endian WKB. Use [to_wkb_with_endianness] to choose the byte
+/// order.
 pub fn to_wkb<O: OffsetSizeTrait>(arr: &dyn GeometryArrayTrait) -> WKBArray<O> {
+    to_wkb_with_endianness(arr, Endianness::LittleEndian)
+}
+
+/// Convert a geometry array to a [WKBArray] with the given byte order.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        skip_all,
+        fields(num_rows = arr.len(), data_type = ?arr.data_type(), ?endianness)
+    )
+)]
+pub fn to_wkb_with_endianness<O: OffsetSizeTrait>(
+    arr: &dyn GeometryArrayTrait,
+    endianness: Endianness,
+) -> WKBArray<O> {
+    use crate::io::wkb::writer::*;
+
     match arr.data_type() {
-        GeoDataType::Point(_) => arr.as_point().into(),
-        GeoDataType::LineString(_) => arr.as_line_string().into(),
-        GeoDataType::LargeLineString(_) => arr.as_large_line_string().into(),
-        GeoDataType::Polygon(_) => arr.as_polygon().into(),
-        GeoDataType::LargePolygon(_) => arr.as_large_polygon().into(),
-        GeoDataType::MultiPoint(_) => arr.as_multi_point().into(),
-        GeoDataType::LargeMultiPoint(_) => arr.as_large_multi_point().into(),
-        GeoDataType::MultiLineString(_) => arr.as_multi_line_string().into(),
-        GeoDataType::LargeMultiLineString(_) => arr.as_large_multi_line_string().into(),
-        GeoDataType::MultiPolygon(_) => arr.as_multi_polygon().into(),
-        GeoDataType::LargeMultiPolygon(_) => arr.as_large_multi_polygon().into(),
-        GeoDataType::Mixed(_) => arr.as_mixed().into(),
-        GeoDataType::LargeMixed(_) => arr.as_large_mixed().into(),
-        GeoDataType::GeometryCollection(_) => arr.as_geometry_collection().into(),
-        GeoDataType::LargeGeometryCollection(_) => arr.as_large_geometry_collection().into(),
+        GeoDataType::Point(_) => point_array_to_wkb(arr.as_point(), endianness),
+        GeoDataType::LineString(_) => line_string_array_to_wkb(arr.as_line_string(), endianness),
+        GeoDataType::LargeLineString(_) => {
+            line_string_array_to_wkb(arr.as_large_line_string(), endianness)
+        }
+        GeoDataType::Polygon(_) => polygon_array_to_wkb(arr.as_polygon(), endianness),
+        GeoDataType::LargePolygon(_) => polygon_array_to_wkb(arr.as_large_polygon(), endianness),
+        GeoDataType::MultiPoint(_) => multi_point_array_to_wkb(arr.as_multi_point(), endianness),
+        GeoDataType::LargeMultiPoint(_) => {
+            multi_point_array_to_wkb(arr.as_large_multi_point(), endianness)
+        }
+        GeoDataType::MultiLineString(_) => {
+            multi_line_string_array_to_wkb(arr.as_multi_line_string(), endianness)
+        }
+        GeoDataType::LargeMultiLineString(_) => {
+            multi_line_string_array_to_wkb(arr.as_large_multi_line_string(), endianness)
+        }
+        GeoDataType::MultiPolygon(_) => {
+            multi_polygon_array_to_wkb(arr.as_multi_polygon(), endianness)
+        }
+        GeoDataType::LargeMultiPolygon(_) => {
+            multi_polygon_array_to_wkb(arr.as_large_multi_polygon(), endianness)
+        }
+        GeoDataType::Mixed(_) => mixed_array_to_wkb(arr.as_mixed(), endianness),
+        GeoDataType::LargeMixed(_) => mixed_array_to_wkb(arr.as_large_mixed(), endianness),
+        GeoDataType::GeometryCollection(_) => {
+            geometry_collection_array_to_wkb(arr.as_geometry_collection(), endianness)
+        }
+        GeoDataType::LargeGeometryCollection(_) => {
+            geometry_collection_array_to_wkb(arr.as_large_geometry_collection(), endianness)
+        }
         GeoDataType::WKB => todo!(),
         GeoDataType::LargeWKB => todo!(),
         GeoDataType::Rect => todo!(),
@@ -381,13 +516,21 @@ pub fn to_wkb<O: OffsetSizeTrait>(arr: &dyn GeometryArrayTrait) -> WKBArray<O> {
 mod test {
     use super::*;
     use crate::test::point;
+    use crate::validate::validate_geoarrow_field;
 
     #[test]
     fn point_round_trip_explicit_casting() {
         let arr = point::point_array();
         let wkb_arr: WKBArray<i32> = to_wkb(&arr);
-        let roundtrip =
-            from_wkb(&wkb_arr, GeoDataType::Point(CoordType::Interleaved), true).unwrap();
+        let roundtrip = from_wkb(
+            &wkb_arr,
+            GeoDataType::Point(CoordType::Interleaved),
+            true,
+            DimensionPolicy::Error,
+            CurvePolicy::Error,
+            ParseGuards::default(),
+        )
+        .unwrap();
         let rt_point_arr = roundtrip.as_ref();
         let rt_point_arr_ref = rt_point_arr.as_point();
         assert_eq!(&arr, rt_point_arr_ref);
@@ -398,13 +541,404 @@ mod test {
         let points = vec![point::p0(), point::p1(), point::p2()];
         let arr = PointArray::from(points.as_slice());
         let wkb_arr: WKBArray<i32> = to_wkb(&arr);
-        let roundtrip =
-            from_wkb(&wkb_arr, GeoDataType::Mixed(CoordType::Interleaved), true).unwrap();
+        let roundtrip = from_wkb(
+            &wkb_arr,
+            GeoDataType::Mixed(CoordType::Interleaved),
+            true,
+            DimensionPolicy::Error,
+            CurvePolicy::Error,
+            ParseGuards::default(),
+        )
+        .unwrap();
         let rt_ref = roundtrip.as_ref();
         let rt_mixed_arr = rt_ref.as_mixed();
-        let downcasted = rt_mixed_arr.downcast(true);
+        let downcasted = rt_mixed_arr.downcast(true, false);
         let downcasted_ref = downcasted.as_ref();
         let rt_point_arr = downcasted_ref.as_point();
         assert_eq!(&arr, rt_point_arr);
     }
+
+    fn assert_round_trip(arr: &dyn GeometryArrayTrait) {
+        for endianness in [Endianness::LittleEndian, Endianness::BigEndian] {
+            let wkb_arr: WKBArray<i32> = to_wkb_with_endianness(arr, endianness);
+            for wkb in wkb_arr.iter().flatten() {
+                assert_eq!(wkb.as_ref()[0], u8::from(endianness));
+            }
+
+            let roundtrip = from_wkb(
+                &wkb_arr,
+                *arr.data_type(),
+                false,
+                DimensionPolicy::Error,
+                CurvePolicy::Error,
+                ParseGuards::default(),
+            )
+            .unwrap();
+            assert_eq!(roundtrip.data_type(), arr.data_type());
+            assert_eq!(to_wkb::<i32>(roundtrip.as_ref()), to_wkb::<i32>(arr));
+            for arr in [&wkb_arr as &dyn GeometryArrayTrait, roundtrip.as_ref()] {
+                let violations =
+                    validate_geoarrow_field(&arr.extension_field(), &arr.to_array_ref());
+                assert_eq!(violations, vec![]);
+            }
+        }
+    }
+
+    #[test]
+    fn round_trip_endianness() {
+        use crate::test::{linestring, multilinestring, multipoint, multipolygon, polygon};
+
+        assert_round_trip(&point::point_array());
+        assert_round_trip(&linestring::ls_array());
+        assert_round_trip(&polygon::p_array());
+        assert_round_trip(&multipoint::mp_array());
+        assert_round_trip(&multilinestring::ml_array());
+        assert_round_trip(&multipolygon::mp_array());
+
+        let geoms: Vec<geo::Geometry> = vec![
+            point::p0().into(),
+            linestring::ls0().into(),
+            polygon::p0().into(),
+            multipolygon::mp1().into(),
+        ];
+        let mixed_arr = MixedGeometryArray::<i32>::try_from(geoms.as_slice()).unwrap();
+        assert_round_trip(&mixed_arr);
+    }
+
+    #[test]
+    fn big_endian_bytes() {
+        let arr = PointArray::from(vec![point::p0()].as_slice());
+        let wkb_arr: WKBArray<i32> =
+            (&arr as &dyn GeometryArrayTrait).to_wkb_with_endianness(Endianness::BigEndian);
+        let mut expected = vec![0, 0, 0, 0, 1];
+        expected.extend_from_slice(&point::p0().x().to_be_bytes());
+        expected.extend_from_slice(&point::p0().y().to_be_bytes());
+        assert_eq!(wkb_arr.value(0).as_ref(), expected.as_slice());
+    }
+
+    #[test]
+    fn reject_z_and_m() {
+        let point_wkb = |geometry_type: u32| {
+            let mut buf = vec![1];
+            buf.extend_from_slice(&geometry_type.to_le_bytes());
+            buf.extend_from_slice(&[0; 24]);
+            buf
+        };
+        // ISO PointZ, PointM and PointZM, then EWKB PointZ and PointM
+        for geometry_type in [1001, 2001, 3001, 0x8000_0001, 0x4000_0001] {
+            let binary_arr =
+                arrow_array::BinaryArray::from_vec(vec![point_wkb(geometry_type).as_slice()]);
+            let wkb_arr = WKBArray::new(binary_arr, Default::default());
+            let err = from_wkb(
+                &wkb_arr,
+                GeoDataType::Point(CoordType::Interleaved),
+                true,
+                DimensionPolicy::Error,
+                CurvePolicy::Error,
+                ParseGuards::default(),
+            )
+            .unwrap_err();
+            assert!(matches!(err, GeoArrowError::NotYetImplemented(_)));
+        }
+    }
+
+    #[test]
+    fn mixed_dimensions() {
+        let point_wkb = |geometry_type: u32, coords: &[f64]| {
+            let mut buf = vec![1];
+            buf.extend_from_slice(&geometry_type.to_le_bytes());
+            for coord in coords {
+                buf.extend_from_slice(&coord.to_le_bytes());
+            }
+            buf
+        };
+        let point_xy = point_wkb(1, &[1., 2.]);
+        let point_z = point_wkb(1001, &[3., 4., 5.]);
+        let binary_arr = arrow_array::BinaryArray::from(vec![
+            Some(point_xy.as_slice()),
+            None,
+            Some(point_z.as_slice()),
+        ]);
+        let wkb_arr = WKBArray::new(binary_arr, Default::default());
+        let data_type = GeoDataType::Point(CoordType::Interleaved);
+
+        let err = from_wkb(
+            &wkb_arr,
+            data_type,
+            true,
+            DimensionPolicy::Error,
+            CurvePolicy::Error,
+            ParseGuards::default(),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("row 2"));
+
+        let parsed = from_wkb(
+            &wkb_arr,
+            data_type,
+            true,
+            DimensionPolicy::ForceXY,
+            CurvePolicy::Error,
+            ParseGuards::default(),
+        )
+        .unwrap();
+        let expected = PointArray::from(vec![
+            Some(geo::point!(x: 1., y: 2.)),
+            None,
+            Some(geo::point!(x: 3., y: 4.)),
+        ]);
+        assert_eq!(parsed.as_ref().as_point(), &expected);
+
+        let err = from_wkb(
+            &wkb_arr,
+            data_type,
+            true,
+            DimensionPolicy::PromoteXYZ(f64::NAN),
+            CurvePolicy::Error,
+            ParseGuards::default(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, GeoArrowError::NotYetImplemented(_)));
+    }
+
+    fn hex_to_bytes(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn linearize_curves() {
+        use geo::{coord, Area};
+
+        // ST_AsBinary('CIRCULARSTRING(0 0, 1 1, 2 0)')
+        let circular_string = hex_to_bytes(
+            "01080000000300000000000000000000000000000000000000000000000000f03f000000000000f03f00\
+             000000000000400000000000000000",
+        );
+        // ST_AsBinary('COMPOUNDCURVE(CIRCULARSTRING(0 0, 1 1, 2 0), (2 0, 4 0))')
+        let compound_curve = hex_to_bytes(
+            "01090000000200000001080000000300000000000000000000000000000000000000000000000000f03f\
+             000000000000f03f00000000000000400000000000000000010200000002000000000000000000004000\
+             0000000000000000000000000010400000000000000000",
+        );
+        // ST_AsEWKB('SRID=4326;CURVEPOLYGON(CIRCULARSTRING(-1 0, 1 0, -1 0),
+        //     (-0.5 -0.5, 0.5 -0.5, 0.5 0.5, -0.5 -0.5))')
+        let curve_polygon = hex_to_bytes(
+            "010a000020e610000002000000010800000003000000000000000000f0bf00000000000000000000000000\
+             00f03f0000000000000000000000000000f0bf0000000000000000010200000004000000000000000000e0\
+             bf000000000000e0bf000000000000e03f000000000000e0bf000000000000e03f000000000000e03f0000\
+             00000000e0bf000000000000e0bf",
+        );
+
+        let binary_arr = arrow_array::BinaryArray::from_vec(vec![
+            circular_string.as_slice(),
+            compound_curve.as_slice(),
+        ]);
+        let wkb_arr = WKBArray::new(binary_arr, Default::default());
+        let data_type = GeoDataType::LineString(CoordType::Interleaved);
+
+        let err = from_wkb(
+            &wkb_arr,
+            data_type,
+            true,
+            DimensionPolicy::Error,
+            CurvePolicy::Error,
+            ParseGuards::default(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, GeoArrowError::NotYetImplemented(_)));
+        assert!(err.to_string().contains("row 0"));
+
+        // Half circles are split into 12 segments of 15 degrees
+        let curve_policy = CurvePolicy::Linearize { max_angle_deg: 16. };
+        let parsed = from_wkb(
+            &wkb_arr,
+            data_type,
+            true,
+            DimensionPolicy::Error,
+            curve_policy,
+            ParseGuards::default(),
+        )
+        .unwrap();
+        let line_strings = parsed.as_ref().as_line_string();
+        let line_string = line_strings.value_as_geo(0);
+        assert_eq!(line_string.0.len(), 13);
+        assert_eq!(line_string.0[0], coord! { x: 0., y: 0. });
+        assert_eq!(line_string.0[12], coord! { x: 2., y: 0. });
+        let line_string = line_strings.value_as_geo(1);
+        assert_eq!(line_string.0.len(), 14);
+        assert_eq!(line_string.0[12], coord! { x: 2., y: 0. });
+        assert_eq!(line_string.0[13], coord! { x: 4., y: 0. });
+
+        let binary_arr = arrow_array::BinaryArray::from_vec(vec![curve_polygon.as_slice()]);
+        let wkb_arr = WKBArray::new(binary_arr, Default::default());
+        let parsed = from_wkb(
+            &wkb_arr,
+            GeoDataType::Polygon(CoordType::Interleaved),
+            true,
+            DimensionPolicy::Error,
+            curve_policy,
+            ParseGuards::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            parsed.metadata().crs,
+            Some(serde_json::json!({"id": {"authority": "EPSG", "code": 4326}}))
+        );
+        let polygon = parsed.as_ref().as_polygon().value_as_geo(0);
+        // The full circle is split into 23 segments, and its ring closed
+        assert_eq!(polygon.exterior().0.len(), 24);
+        assert_eq!(polygon.exterior().0[0], polygon.exterior().0[23]);
+        assert_eq!(polygon.interiors()[0].0.len(), 4);
+        // The unit circle, approximated from the inside, minus a triangle
+        let area = polygon.unsigned_area();
+        assert!(area < std::f64::consts::PI - 0.5 && area > std::f64::consts::PI - 0.55);
+
+        let err = from_wkb(
+            &wkb_arr,
+            GeoDataType::Polygon(CoordType::Interleaved),
+            true,
+            DimensionPolicy::Error,
+            CurvePolicy::Linearize { max_angle_deg: 0. },
+            ParseGuards::default(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, GeoArrowError::General(_)));
+    }
+
+    #[test]
+    fn parse_guards() {
+        use crate::io::guards::ResourceLimit;
+        use std::time::{Duration, Instant};
+
+        // A LineString that declares 2^30 points but holds none of them
+        let mut malicious = vec![1];
+        malicious.extend_from_slice(&2_u32.to_le_bytes());
+        malicious.extend_from_slice(&0x4000_0000_u32.to_le_bytes());
+        let mut line_string = vec![1];
+        line_string.extend_from_slice(&2_u32.to_le_bytes());
+        line_string.extend_from_slice(&2_u32.to_le_bytes());
+        line_string.extend_from_slice(&[0; 32]);
+        let binary_arr =
+            arrow_array::BinaryArray::from_vec(vec![line_string.as_slice(), malicious.as_slice()]);
+        let wkb_arr = WKBArray::new(binary_arr, Default::default());
+        let data_type = GeoDataType::LineString(CoordType::Interleaved);
+
+        let start = Instant::now();
+        let err = from_wkb(
+            &wkb_arr,
+            data_type,
+            true,
+            DimensionPolicy::Error,
+            CurvePolicy::Error,
+            ParseGuards::default().with_max_total_coords(100),
+        )
+        .unwrap_err();
+        assert!(start.elapsed() < Duration::from_secs(1));
+        assert!(matches!(
+            err,
+            GeoArrowError::ResourceLimitExceeded {
+                limit: ResourceLimit::TotalCoords,
+                max: 100,
+                row: 1
+            }
+        ));
+
+        // With a high enough limit, the declared count is checked against the bytes
+        let err = from_wkb(
+            &wkb_arr,
+            data_type,
+            true,
+            DimensionPolicy::Error,
+            CurvePolicy::Error,
+            ParseGuards::default().with_max_coords_per_geometry(usize::MAX),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("row 1"), "{}", err);
+
+        let err = from_wkb(
+            &wkb_arr,
+            GeoDataType::Mixed(CoordType::Interleaved),
+            true,
+            DimensionPolicy::Error,
+            CurvePolicy::Error,
+            ParseGuards::default().with_max_nesting_depth(0),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            GeoArrowError::ResourceLimitExceeded {
+                limit: ResourceLimit::NestingDepth,
+                row: 0,
+                ..
+            }
+        ));
+
+        let wkb_arr: WKBArray<i32> = to_wkb(&point::point_array());
+        let parsed = from_wkb(
+            &wkb_arr,
+            GeoDataType::Point(CoordType::Interleaved),
+            true,
+            DimensionPolicy::Error,
+            CurvePolicy::Error,
+            ParseGuards::default()
+                .with_max_coords_per_geometry(1)
+                .with_max_total_coords(wkb_arr.len())
+                .with_max_nesting_depth(1),
+        )
+        .unwrap();
+        assert_eq!(parsed.len(), wkb_arr.len());
+    }
+
+    #[cfg(all(feature = "wkb_compression", feature = "geozero"))]
+    #[test]
+    fn compressed_round_trip_roads() {
+        use std::fs::File;
+
+        use crate::io::geojson::read_geojson;
+
+        let table = read_geojson(File::open("fixtures/roads.geojson").unwrap(), None).unwrap();
+        let geometry = table.geometry().unwrap();
+        let chunk = geometry.geometry_chunks()[0];
+        let data_type = *chunk.data_type();
+        let wkb_arr: WKBArray<i32> = to_wkb(chunk);
+
+        let compressed = CompressedWKBArray::try_from(&wkb_arr).unwrap();
+        println!(
+            "roads: {} bytes uncompressed, {} bytes compressed, ratio {:.2}",
+            compressed.uncompressed_num_bytes(),
+            compressed.num_bytes(),
+            compressed.compression_ratio()
+        );
+        assert_eq!(compressed.uncompressed_num_bytes(), wkb_arr.num_bytes());
+        assert!(compressed.num_bytes() < wkb_arr.num_bytes());
+
+        // Several blocks, with a partial last block
+        let compressed = CompressedWKBArray::try_new(&wkb_arr, 8, 3).unwrap();
+        assert_eq!(compressed.num_blocks(), wkb_arr.len().div_ceil(8));
+        assert_eq!(WKBArray::try_from(&compressed).unwrap(), wkb_arr);
+
+        let expected = from_wkb(
+            &wkb_arr,
+            data_type,
+            true,
+            DimensionPolicy::Error,
+            CurvePolicy::Error,
+            ParseGuards::default(),
+        )
+        .unwrap();
+        let parsed = from_compressed_wkb(
+            &compressed,
+            data_type,
+            true,
+            DimensionPolicy::Error,
+            CurvePolicy::Error,
+        )
+        .unwrap();
+        assert_eq!(parsed.data_type(), expected.data_type());
+        assert_eq!(parsed.len(), expected.len());
+        assert_eq!(to_wkb::<i32>(parsed.as_ref()), wkb_arr);
+    }
 }
@@ -0,0 +1,204 @@
+//! Approximate circular arcs with line segments.
+
+use std::f64::consts::TAU;
+
+use geo::Coord;
+
+use crate::error::{GeoArrowError, Result};
+
+/// Linearize the arcs of a CircularString, where each arc is given by a start point, a point
+/// anywhere on the arc, and an end point, with the end point of one arc being the start point of
+/// the next.
+///
+/// Each arc is replaced by segments spanning at most `max_angle` radians. The start and end points
+/// of every arc are kept exactly, while the middle points are not kept.
+pub(crate) fn linearize_circular_string(points: &[Coord], max_angle: f64) -> Result<Vec<Coord>> {
+    if points.is_empty() {
+        return Ok(vec![]);
+    }
+    if points.len() < 3 || points.len() % 2 == 0 {
+        return Err(GeoArrowError::General(format!(
+            "A CircularString must have an odd number of at least three points, got {}",
+            points.len()
+        )));
+    }
+
+    let mut out = vec![points[0]];
+    for arc in points.windows(3).step_by(2) {
+        linearize_arc(arc[0], arc[1], arc[2], max_angle, &mut out);
+    }
+    Ok(out)
+}
+
+/// Push the points after `start` of the linearized arc through `start`, `mid` and `end` to `out`.
+///
+/// An arc that starts and ends at the same point is a full circle, with `mid` diametrically
+/// opposite the start, and is traversed counter-clockwise. An arc whose points are collinear is a
+/// straight line through all three.
+fn linearize_arc(start: Coord, mid: Coord, end: Coord, max_angle: f64, out: &mut Vec<Coord>) {
+    let full_circle = start == end;
+    let center = if full_circle {
+        (start + mid) / 2.
+    } else {
+        match circumcenter(start, mid, end) {
+            Some(center) => center,
+            None => {
+                out.extend([mid, end]);
+                return;
+            }
+        }
+    };
+    let offset = start - center;
+    let radius = offset.x.hypot(offset.y);
+    if radius == 0. {
+        out.push(end);
+        return;
+    }
+
+    let start_angle = angle(center, start);
+    let sweep = if full_circle {
+        TAU
+    } else {
+        let counter_clockwise_sweep = (angle(center, end) - start_angle).rem_euclid(TAU);
+        if cross(mid - start, end - mid) > 0. {
+            counter_clockwise_sweep
+        } else {
+            counter_clockwise_sweep - TAU
+        }
+    };
+
+    // A full circle needs at least three segments to remain a valid ring
+    let min_segments = if full_circle { 3 } else { 1 };
+    let num_segments = ((sweep.abs() / max_angle).ceil() as usize).max(min_segments);
+    for i in 1..num_segments {
+        let theta = start_angle + sweep * i as f64 / num_segments as f64;
+        out.push(Coord {
+            x: center.x + radius * theta.cos(),
+            y: center.y + radius * theta.sin(),
+        });
+    }
+    out.push(end);
+}
+
+/// The center of the circle through three points, or `None` if they are collinear.
+fn circumcenter(a: Coord, b: Coord, c: Coord) -> Option<Coord> {
+    // Translate to `a` for precision with large coordinates
+    let b = b - a;
+    let c = c - a;
+    let d = 2. * cross(b, c);
+    let b_len_sq = b.x * b.x + b.y * b.y;
+    let c_len_sq = c.x * c.x + c.y * c.y;
+    if d.abs() <= 1e-12 * (b_len_sq * c_len_sq).sqrt() {
+        return None;
+    }
+
+    Some(Coord {
+        x: a.x + (c.y * b_len_sq - b.y * c_len_sq) / d,
+        y: a.y + (b.x * c_len_sq - c.x * b_len_sq) / d,
+    })
+}
+
+fn angle(center: Coord, point: Coord) -> f64 {
+    (point.y - center.y).atan2(point.x - center.x)
+}
+
+fn cross(a: Coord, b: Coord) -> f64 {
+    a.x * b.y - a.y * b.x
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use approx::assert_relative_eq;
+    use geo::coord;
+
+    fn assert_on_circle(points: &[Coord], center: Coord, radius: f64) {
+        for point in points {
+            assert_relative_eq!(
+                (point.x - center.x).hypot(point.y - center.y),
+                radius,
+                epsilon = 1e-9
+            );
+        }
+    }
+
+    #[test]
+    fn half_circle() {
+        // Clockwise from (0, 0) over (1, 1) to (2, 0)
+        let points = [
+            coord! { x: 0., y: 0. },
+            coord! { x: 1., y: 1. },
+            coord! { x: 2., y: 0. },
+        ];
+        let out = linearize_circular_string(&points, 16_f64.to_radians()).unwrap();
+        assert_eq!(out.len(), 13);
+        assert_eq!(out[0], points[0]);
+        assert_eq!(out[12], points[2]);
+        assert_on_circle(&out, coord! { x: 1., y: 0. }, 1.);
+        // The arc bulges upwards
+        assert!(out.iter().all(|point| point.y >= -1e-9));
+        assert_relative_eq!(out[6].y, 1., epsilon = 1e-9);
+
+        // Counter-clockwise, bulging downwards
+        let points = [
+            coord! { x: 0., y: 0. },
+            coord! { x: 1., y: -1. },
+            coord! { x: 2., y: 0. },
+        ];
+        let out = linearize_circular_string(&points, 16_f64.to_radians()).unwrap();
+        assert_eq!(out.len(), 13);
+        assert!(out.iter().all(|point| point.y <= 1e-9));
+    }
+
+    #[test]
+    fn full_circle() {
+        let points = [
+            coord! { x: 0., y: 0. },
+            coord! { x: 2., y: 0. },
+            coord! { x: 0., y: 0. },
+        ];
+        let out = linearize_circular_string(&points, 100_f64.to_radians()).unwrap();
+        assert_eq!(out.len(), 5);
+        assert_eq!(out[0], out[4]);
+        assert_on_circle(&out, coord! { x: 1., y: 0. }, 1.);
+
+        // Still a valid ring with a large tolerance
+        let out = linearize_circular_string(&points, 360_f64.to_radians()).unwrap();
+        assert_eq!(out.len(), 4);
+    }
+
+    #[test]
+    fn multiple_arcs() {
+        // Two quarter circles, counter-clockwise and then clockwise
+        let points = [
+            coord! { x: 1., y: 0. },
+            coord! { x: 0.5_f64.sqrt(), y: 0.5_f64.sqrt() },
+            coord! { x: 0., y: 1. },
+            coord! { x: -0.5_f64.sqrt(), y: 2. - 0.5_f64.sqrt() },
+            coord! { x: -1., y: 2. },
+        ];
+        let out = linearize_circular_string(&points, 10.5_f64.to_radians()).unwrap();
+        assert_eq!(out.len(), 19);
+        assert_eq!(out[9], points[2]);
+        assert_on_circle(&out[..10], coord! { x: 0., y: 0. }, 1.);
+        assert_on_circle(&out[9..], coord! { x: 0., y: 2. }, 1.);
+    }
+
+    #[test]
+    fn collinear() {
+        let points = [
+            coord! { x: 0., y: 0. },
+            coord! { x: 1., y: 1. },
+            coord! { x: 2., y: 2. },
+        ];
+        let out = linearize_circular_string(&points, 1_f64.to_radians()).unwrap();
+        assert_eq!(out, points);
+    }
+
+    #[test]
+    fn invalid_num_points() {
+        let points = [coord! { x: 0., y: 0. }, coord! { x: 1., y: 1. }];
+        assert!(linearize_circular_string(&points, 1.).is_err());
+        assert!(linearize_circular_string(&[], 1.).unwrap().is_empty());
+    }
+}
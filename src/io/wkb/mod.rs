@@ -1,7 +1,16 @@
 //! An optimized implementation of reading and writing ISO-flavored WKB-encoded geometries.
 
 mod api;
+mod curve;
+mod normalize;
 pub(crate) mod reader;
 pub(crate) mod writer;
 
-pub use api::{from_wkb, to_wkb, FromWKB, ToWKB};
+#[cfg(feature = "wkb_compression")]
+pub use api::from_compressed_wkb;
+pub use api::{from_wkb, to_wkb, to_wkb_with_endianness, FromWKB, ToWKB};
+pub(crate) use normalize::check_wkb_guards;
+#[cfg(feature = "postgis")]
+pub(crate) use normalize::{ewkb_srid, linearize_curves, srid_crs};
+pub use normalize::{CurvePolicy, DimensionPolicy};
+pub use reader::Endianness;
@@ -0,0 +1,740 @@
+//! Normalize WKB columns to 2D ISO WKB before parsing, for columns with Z or M coordinates, for
+//! PostGIS EWKB and for curved geometries.
+
+use std::io::{Cursor, Read};
+use std::sync::Arc;
+
+use arrow_array::builder::GenericBinaryBuilder;
+use arrow_array::OffsetSizeTrait;
+use byteorder::{BigEndian, LittleEndian, ReadBytesExt, WriteBytesExt};
+use geo::Coord;
+use serde_json::{json, Value};
+
+use crate::array::metadata::ArrayMetadata;
+use crate::array::WKBArray;
+use crate::error::{GeoArrowError, Result};
+use crate::io::guards::{GuardCounter, ParseGuards};
+use crate::io::wkb::curve::linearize_circular_string;
+use crate::io::wkb::reader::Endianness;
+use crate::io::wkb::writer::WriteWKBExt;
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+
+/// How to parse WKB geometries that are not 2D.
+///
+/// GeoArrow-native arrays in this crate are 2D, so a WKB column that contains geometries with Z or
+/// M coordinates, such as a column mixing `POINT` and `POINT Z` rows, can't be parsed as-is.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum DimensionPolicy {
+    /// Return an error naming the first row with Z or M coordinates.
+    #[default]
+    Error,
+
+    /// Drop the Z and M coordinates of any geometry that has them.
+    ForceXY,
+
+    /// Parse as 3D, filling in this Z value for 2D geometries, which is usually `f64::NAN`.
+    ///
+    /// This is not yet supported, as there are no 3D geometry arrays yet.
+    PromoteXYZ(f64),
+}
+
+/// How to parse curved WKB geometries: CircularString, CompoundCurve, CurvePolygon, MultiCurve
+/// and MultiSurface.
+///
+/// GeoArrow has no curved geometry types, so curves can only be parsed by approximating them with
+/// straight line segments.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum CurvePolicy {
+    /// Return an error naming the first row with a curve.
+    #[default]
+    Error,
+
+    /// Replace each circular arc with line segments spanning at most `max_angle_deg` degrees of
+    /// the arc, so that curves are parsed as LineString, Polygon, MultiLineString and
+    /// MultiPolygon geometries. The start and end points of each arc are kept exactly.
+    ///
+    /// For reference, PostGIS's `ST_CurveToLine` defaults to 32 segments per quarter circle, or
+    /// about 2.8 degrees.
+    Linearize { max_angle_deg: f64 },
+}
+
+impl CurvePolicy {
+    /// The maximum angle of an arc segment in radians, or an error if curves aren't linearized.
+    fn max_angle(&self) -> Result<f64> {
+        match self {
+            CurvePolicy::Error => Err(GeoArrowError::NotYetImplemented(
+                "Curved WKB geometries are not supported. Use CurvePolicy::Linearize to \
+                 approximate them with line segments."
+                    .to_string(),
+            )),
+            CurvePolicy::Linearize { max_angle_deg } => {
+                if *max_angle_deg > 0. && max_angle_deg.is_finite() {
+                    Ok(max_angle_deg.to_radians())
+                } else {
+                    Err(GeoArrowError::General(format!(
+                        "The maximum angle for linearizing curves must be positive, got {}",
+                        max_angle_deg
+                    )))
+                }
+            }
+        }
+    }
+}
+
+/// Rewrite a WKB array as 2D ISO WKB before parsing, if needed.
+///
+/// Geometries with Z or M coordinates are handled according to `policy`, and curved geometries
+/// according to `curve_policy`. EWKB geometries with an SRID have it removed, and the SRID is
+/// recorded as the CRS of the returned array when the input doesn't have a CRS already.
+/// `row_offset` is added to the row index reported in errors.
+///
+/// Returns `None` if the array can be parsed as-is.
+pub(crate) fn normalize_wkb<O: OffsetSizeTrait>(
+    arr: &WKBArray<O>,
+    policy: DimensionPolicy,
+    curve_policy: CurvePolicy,
+    row_offset: usize,
+) -> Result<Option<WKBArray<O>>> {
+    if let CurvePolicy::Linearize { .. } = curve_policy {
+        curve_policy.max_angle()?;
+    }
+    if let DimensionPolicy::PromoteXYZ(_) = policy {
+        return Err(GeoArrowError::NotYetImplemented(
+            "Promoting WKB geometries to XYZ requires 3D geometry arrays".to_string(),
+        ));
+    }
+
+    let mut needs_rewrite = false;
+    let mut srid = None;
+    for (row, maybe_wkb) in arr.iter().enumerate() {
+        let Some(wkb) = maybe_wkb else {
+            continue;
+        };
+        let mut reader = Cursor::new(wkb.as_ref());
+        let header = WKBHeader::read(&mut reader)?;
+        if header.is_curve() || (header.geometry_type == 7 && contains_curve(&mut reader, &header)?)
+        {
+            if let CurvePolicy::Error = curve_policy {
+                return Err(GeoArrowError::NotYetImplemented(format!(
+                    "Curved WKB geometries are not supported, but row {} has or contains one. \
+                     Use CurvePolicy::Linearize to approximate curves with line segments.",
+                    row + row_offset,
+                )));
+            }
+            needs_rewrite = true;
+        }
+        if header.has_z || header.has_m {
+            if let DimensionPolicy::Error = policy {
+                return Err(GeoArrowError::NotYetImplemented(format!(
+                    "Only 2D WKB geometries are supported, but row {} has geometry type {}. Use \
+                     DimensionPolicy::ForceXY to drop the Z and M coordinates.",
+                    row + row_offset,
+                    header.type_code
+                )));
+            }
+            needs_rewrite = true;
+        }
+        if let Some(row_srid) = header.srid {
+            match srid {
+                Some(srid) if srid != row_srid => {
+                    return Err(GeoArrowError::General(format!(
+                        "WKB geometries have different SRIDs {} and {}, at row {}",
+                        srid,
+                        row_srid,
+                        row + row_offset
+                    )))
+                }
+                _ => srid = Some(row_srid),
+            }
+            needs_rewrite = true;
+        }
+    }
+    if !needs_rewrite {
+        return Ok(None);
+    }
+
+    let mut metadata = arr.metadata();
+    if let Some(crs) = srid.and_then(srid_crs) {
+        if metadata.crs.is_none() {
+            metadata = Arc::new(ArrayMetadata {
+                crs: Some(crs),
+                ..metadata.as_ref().clone()
+            });
+        }
+    }
+
+    let mut builder = GenericBinaryBuilder::<O>::with_capacity(arr.len(), arr.array.values().len());
+    let mut buf = Vec::new();
+    for maybe_wkb in arr.iter() {
+        match maybe_wkb {
+            Some(wkb) => {
+                buf.clear();
+                write_xy_geometry(&mut Cursor::new(wkb.as_ref()), &mut buf, curve_policy)?;
+                builder.append_value(&buf);
+            }
+            None => builder.append_null(),
+        }
+    }
+    Ok(Some(WKBArray::new(builder.finish(), metadata)))
+}
+
+/// Check the declared coordinate counts and nesting of each geometry in a WKB array against
+/// `guards`, without reading any coordinates.
+pub(crate) fn check_wkb_guards<O: OffsetSizeTrait>(
+    arr: &WKBArray<O>,
+    guards: &ParseGuards,
+) -> Result<()> {
+    if guards.is_unlimited() {
+        return Ok(());
+    }
+
+    let mut counter = GuardCounter::new(*guards);
+    for (row, maybe_wkb) in arr.iter().enumerate() {
+        let Some(wkb) = maybe_wkb else {
+            continue;
+        };
+        counter.start_row(row);
+        let mut reader = Cursor::new(wkb.as_ref());
+        let header = WKBHeader::read(&mut reader)?;
+        count_geometry_coords(&mut reader, &header, 1, &mut counter).map_err(|err| match err {
+            GeoArrowError::ResourceLimitExceeded { .. } => err,
+            GeoArrowError::General(message) => {
+                GeoArrowError::General(format!("Invalid WKB at row {}: {}", row, message))
+            }
+            err => GeoArrowError::General(format!("Invalid WKB at row {}: {}", row, err)),
+        })?;
+    }
+    Ok(())
+}
+
+/// Count the coordinates of a geometry, whose header has already been read, at nesting `depth`.
+///
+/// Each declared count is checked against the guards and against the remaining bytes before
+/// skipping over the coordinates.
+fn count_geometry_coords(
+    reader: &mut Cursor<&[u8]>,
+    header: &WKBHeader,
+    depth: usize,
+    counter: &mut GuardCounter,
+) -> Result<()> {
+    counter.check_depth(depth)?;
+    let coord_len = header.num_dims() as u64 * 8;
+    let skip_coords =
+        |reader: &mut Cursor<&[u8]>, counter: &mut GuardCounter, num_coords: u32| -> Result<()> {
+            counter.add_coords(num_coords as usize)?;
+            let end = reader.position() + num_coords as u64 * coord_len;
+            if end > reader.get_ref().len() as u64 {
+                return Err(GeoArrowError::General(format!(
+                    "geometry declares {} coordinates, more than its bytes hold",
+                    num_coords
+                )));
+            }
+            reader.set_position(end);
+            Ok(())
+        };
+    match header.geometry_type {
+        1 => skip_coords(reader, counter, 1),
+        2 | 8 => {
+            let num_coords = read_u32(reader, header.endianness)?;
+            skip_coords(reader, counter, num_coords)
+        }
+        3 => {
+            let num_rings = read_u32(reader, header.endianness)?;
+            for _ in 0..num_rings {
+                let num_coords = read_u32(reader, header.endianness)?;
+                skip_coords(reader, counter, num_coords)?;
+            }
+            Ok(())
+        }
+        // The members of a CompoundCurve or CurvePolygon are parts of the same geometry
+        9 | 10 => {
+            let num_members = read_u32(reader, header.endianness)?;
+            for _ in 0..num_members {
+                let member = WKBHeader::read(reader)?;
+                count_geometry_coords(reader, &member, depth, counter)?;
+            }
+            Ok(())
+        }
+        4..=7 | 11 | 12 => {
+            counter.check_depth(depth + 1)?;
+            let num_members = read_u32(reader, header.endianness)?;
+            for _ in 0..num_members {
+                let member = WKBHeader::read(reader)?;
+                count_geometry_coords(reader, &member, depth + 1, counter)?;
+            }
+            Ok(())
+        }
+        _ => Err(GeoArrowError::General(format!(
+            "Unexpected WKB geometry type {}",
+            header.type_code
+        ))),
+    }
+}
+
+/// The CRS of an EWKB SRID, as an EPSG code. PostGIS uses an SRID of 0 for an unknown CRS, which
+/// returns `None`.
+pub(crate) fn srid_crs(srid: u32) -> Option<Value> {
+    if srid == 0 {
+        return None;
+    }
+    Some(json!({
+        "id": {
+            "authority": "EPSG",
+            "code": srid,
+        }
+    }))
+}
+
+/// The SRID of a single EWKB geometry, or `None` if it has none.
+#[cfg(feature = "postgis")]
+pub(crate) fn ewkb_srid(wkb: &[u8]) -> Result<Option<u32>> {
+    Ok(WKBHeader::read(&mut Cursor::new(wkb))?.srid)
+}
+
+/// Rewrite a single WKB or EWKB geometry that is or contains a curve as 2D ISO WKB, with its
+/// curves linearized according to `curve_policy`.
+///
+/// Returns `None` if the geometry has no curves.
+#[cfg(feature = "postgis")]
+pub(crate) fn linearize_curves(wkb: &[u8], curve_policy: CurvePolicy) -> Result<Option<Vec<u8>>> {
+    let mut reader = Cursor::new(wkb);
+    let header = WKBHeader::read(&mut reader)?;
+    if !(header.is_curve() || (header.geometry_type == 7 && contains_curve(&mut reader, &header)?))
+    {
+        return Ok(None);
+    }
+    curve_policy.max_angle()?;
+
+    let mut buf = Vec::with_capacity(wkb.len());
+    write_xy_geometry(&mut Cursor::new(wkb), &mut buf, curve_policy)?;
+    Ok(Some(buf))
+}
+
+/// The byte order, geometry type, dimension and SRID of a WKB geometry.
+struct WKBHeader {
+    endianness: Endianness,
+
+    /// The geometry type code as written, including any dimension.
+    type_code: u32,
+
+    /// The 2D geometry type, from 1 (Point) to 7 (GeometryCollection).
+    geometry_type: u32,
+    has_z: bool,
+    has_m: bool,
+
+    /// The SRID of an EWKB geometry.
+    srid: Option<u32>,
+}
+
+impl WKBHeader {
+    /// Read the header of both ISO WKB, where Z, M and ZM add 1000, 2000 or 3000 to the geometry
+    /// type, and EWKB, where they are set as high bits and an SRID may follow the geometry type.
+    fn read(reader: &mut Cursor<&[u8]>) -> Result<Self> {
+        let endianness = match reader.read_u8()? {
+            0 => Endianness::BigEndian,
+            1 => Endianness::LittleEndian,
+            byte_order => {
+                return Err(GeoArrowError::General(format!(
+                    "Unexpected WKB byte order {}",
+                    byte_order
+                )))
+            }
+        };
+        let type_code = read_u32(reader, endianness)?;
+
+        let has_srid = type_code & 0x2000_0000 != 0;
+        let ewkb_z = type_code & 0x8000_0000 != 0;
+        let ewkb_m = type_code & 0x4000_0000 != 0;
+        let iso_type_code = type_code & 0x0FFF_FFFF;
+        let (iso_z, iso_m) = match iso_type_code / 1000 {
+            0 => (false, false),
+            1 => (true, false),
+            2 => (false, true),
+            3 => (true, true),
+            _ => {
+                return Err(GeoArrowError::General(format!(
+                    "Unexpected WKB geometry type {}",
+                    type_code
+                )))
+            }
+        };
+        let srid = if has_srid {
+            Some(read_u32(reader, endianness)?)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            endianness,
+            type_code,
+            geometry_type: iso_type_code % 1000,
+            has_z: ewkb_z || iso_z,
+            has_m: ewkb_m || iso_m,
+            srid,
+        })
+    }
+
+    fn num_dims(&self) -> usize {
+        2 + self.has_z as usize + self.has_m as usize
+    }
+
+    /// Whether this is a CircularString, CompoundCurve, CurvePolygon, MultiCurve or MultiSurface.
+    fn is_curve(&self) -> bool {
+        (8..=12).contains(&self.geometry_type)
+    }
+}
+
+/// Whether the members of a geometry collection, whose header has already been read, include a
+/// curve. Reading stops at the first curve.
+fn contains_curve(reader: &mut Cursor<&[u8]>, header: &WKBHeader) -> Result<bool> {
+    let num_geometries = read_u32(reader, header.endianness)?;
+    for _ in 0..num_geometries {
+        let member = WKBHeader::read(reader)?;
+        let member_contains_curve = match member.geometry_type {
+            7 => contains_curve(reader, &member)?,
+            _ if member.is_curve() => true,
+            _ => {
+                skip_geometry_body(reader, &member)?;
+                false
+            }
+        };
+        if member_contains_curve {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Skip the rest of a geometry without curves, whose header has already been read.
+fn skip_geometry_body(reader: &mut Cursor<&[u8]>, header: &WKBHeader) -> Result<()> {
+    let coord_len = header.num_dims() as u64 * 8;
+    let skip_coords = |reader: &mut Cursor<&[u8]>| -> Result<()> {
+        let num_coords = read_u32(reader, header.endianness)?;
+        reader.set_position(reader.position() + num_coords as u64 * coord_len);
+        Ok(())
+    };
+    match header.geometry_type {
+        1 => {
+            reader.set_position(reader.position() + coord_len);
+            Ok(())
+        }
+        2 => skip_coords(reader),
+        3 => {
+            let num_rings = read_u32(reader, header.endianness)?;
+            for _ in 0..num_rings {
+                skip_coords(reader)?;
+            }
+            Ok(())
+        }
+        4..=7 => {
+            let num_geometries = read_u32(reader, header.endianness)?;
+            for _ in 0..num_geometries {
+                let member = WKBHeader::read(reader)?;
+                skip_geometry_body(reader, &member)?;
+            }
+            Ok(())
+        }
+        _ => Err(GeoArrowError::General(format!(
+            "Unexpected WKB geometry type {}",
+            header.type_code
+        ))),
+    }
+}
+
+/// Copy one geometry from `reader` to `out` as 2D ISO WKB without an SRID, in its original byte
+/// order.
+///
+/// Curves are linearized according to `curve_policy`: CircularStrings and CompoundCurves become
+/// LineStrings, CurvePolygons become Polygons, MultiCurves become MultiLineStrings and
+/// MultiSurfaces become MultiPolygons.
+fn write_xy_geometry(
+    reader: &mut Cursor<&[u8]>,
+    out: &mut Vec<u8>,
+    curve_policy: CurvePolicy,
+) -> Result<()> {
+    let header = WKBHeader::read(reader)?;
+    let endianness = header.endianness;
+    let output_geometry_type = match header.geometry_type {
+        8 | 9 => 2,
+        10 => 3,
+        11 => 5,
+        12 => 6,
+        geometry_type => geometry_type,
+    };
+    out.write_u8(endianness.into())?;
+    out.write_wkb_u32(endianness, output_geometry_type)?;
+
+    match header.geometry_type {
+        1 => write_xy_coords(reader, out, &header, 1),
+        2 => {
+            let num_coords = read_u32(reader, endianness)?;
+            out.write_wkb_u32(endianness, num_coords)?;
+            write_xy_coords(reader, out, &header, num_coords as usize)
+        }
+        3 => {
+            let num_rings = read_u32(reader, endianness)?;
+            out.write_wkb_u32(endianness, num_rings)?;
+            for _ in 0..num_rings {
+                let num_coords = read_u32(reader, endianness)?;
+                out.write_wkb_u32(endianness, num_coords)?;
+                write_xy_coords(reader, out, &header, num_coords as usize)?;
+            }
+            Ok(())
+        }
+        4..=7 | 11 | 12 => {
+            let num_geometries = read_u32(reader, endianness)?;
+            out.write_wkb_u32(endianness, num_geometries)?;
+            for _ in 0..num_geometries {
+                write_xy_geometry(reader, out, curve_policy)?;
+            }
+            Ok(())
+        }
+        8 | 9 => {
+            let coords = read_curve_body(reader, &header, curve_policy)?;
+            write_linearized_coords(out, endianness, &coords)
+        }
+        10 => {
+            // Each ring is a LineString, CircularString or CompoundCurve with its own header
+            let num_rings = read_u32(reader, endianness)?;
+            out.write_wkb_u32(endianness, num_rings)?;
+            for _ in 0..num_rings {
+                let ring_header = WKBHeader::read(reader)?;
+                let coords = read_curve_body(reader, &ring_header, curve_policy)?;
+                write_linearized_coords(out, endianness, &coords)?;
+            }
+            Ok(())
+        }
+        _ => Err(GeoArrowError::General(format!(
+            "Unexpected WKB geometry type {}",
+            header.type_code
+        ))),
+    }
+}
+
+/// Read the rest of a LineString, CircularString or CompoundCurve whose header has already been
+/// read, as linearized 2D coordinates.
+fn read_curve_body(
+    reader: &mut Cursor<&[u8]>,
+    header: &WKBHeader,
+    curve_policy: CurvePolicy,
+) -> Result<Vec<Coord>> {
+    match header.geometry_type {
+        2 => read_xy_coords(reader, header),
+        8 => linearize_circular_string(&read_xy_coords(reader, header)?, curve_policy.max_angle()?),
+        9 => {
+            // The segments of a CompoundCurve each start where the previous one ends
+            let num_segments = read_u32(reader, header.endianness)?;
+            let mut coords: Vec<Coord> = vec![];
+            for _ in 0..num_segments {
+                let segment_header = WKBHeader::read(reader)?;
+                let segment = read_curve_body(reader, &segment_header, curve_policy)?;
+                let skip_first = matches!(
+                    (coords.last(), segment.first()),
+                    (Some(last), Some(first)) if last == first
+                );
+                coords.extend(segment.into_iter().skip(skip_first as usize));
+            }
+            Ok(coords)
+        }
+        _ => Err(GeoArrowError::General(format!(
+            "Unexpected WKB geometry type {} in a curve",
+            header.type_code
+        ))),
+    }
+}
+
+/// Read the number of coordinates followed by the X and Y values of each, skipping any Z and M
+/// values.
+fn read_xy_coords(reader: &mut Cursor<&[u8]>, header: &WKBHeader) -> Result<Vec<Coord>> {
+    let num_coords = read_u32(reader, header.endianness)?;
+    let mut coords = Vec::with_capacity(num_coords as usize);
+    for _ in 0..num_coords {
+        let x = read_f64(reader, header.endianness)?;
+        let y = read_f64(reader, header.endianness)?;
+        for _ in 2..header.num_dims() {
+            read_f64(reader, header.endianness)?;
+        }
+        coords.push(Coord { x, y });
+    }
+    Ok(coords)
+}
+
+/// Write the number of coordinates followed by their values, as in a LineString or a ring.
+fn write_linearized_coords(
+    out: &mut Vec<u8>,
+    endianness: Endianness,
+    coords: &[Coord],
+) -> Result<()> {
+    out.write_wkb_u32(endianness, coords.len() as u32)?;
+    for coord in coords {
+        out.write_wkb_f64(endianness, coord.x)?;
+        out.write_wkb_f64(endianness, coord.y)?;
+    }
+    Ok(())
+}
+
+/// Copy the X and Y values of `num_coords` coordinates, skipping any Z and M values.
+fn write_xy_coords(
+    reader: &mut Cursor<&[u8]>,
+    out: &mut Vec<u8>,
+    header: &WKBHeader,
+    num_coords: usize,
+) -> Result<()> {
+    let mut coord = [0; 32];
+    let coord_len = header.num_dims() * 8;
+    for _ in 0..num_coords {
+        reader.read_exact(&mut coord[..coord_len])?;
+        out.extend_from_slice(&coord[..16]);
+    }
+    Ok(())
+}
+
+fn read_u32(reader: &mut Cursor<&[u8]>, endianness: Endianness) -> Result<u32> {
+    let value = match endianness {
+        Endianness::BigEndian => reader.read_u32::<BigEndian>()?,
+        Endianness::LittleEndian => reader.read_u32::<LittleEndian>()?,
+    };
+    Ok(value)
+}
+
+fn read_f64(reader: &mut Cursor<&[u8]>, endianness: Endianness) -> Result<f64> {
+    let value = match endianness {
+        Endianness::BigEndian => reader.read_f64::<BigEndian>()?,
+        Endianness::LittleEndian => reader.read_f64::<LittleEndian>()?,
+    };
+    Ok(value)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow_array::BinaryArray;
+
+    fn point_wkb(endianness: Endianness, type_code: u32, coords: &[f64]) -> Vec<u8> {
+        let mut buf = vec![endianness.into()];
+        buf.write_wkb_u32(endianness, type_code).unwrap();
+        for coord in coords {
+            buf.write_wkb_f64(endianness, *coord).unwrap();
+        }
+        buf
+    }
+
+    #[test]
+    fn header() {
+        // EWKB PointZ with an SRID
+        let mut wkb = point_wkb(Endianness::BigEndian, 0xA000_0001, &[]);
+        wkb.extend_from_slice(&4326_u32.to_be_bytes());
+        let mut reader = Cursor::new(wkb.as_slice());
+        let header = WKBHeader::read(&mut reader).unwrap();
+        assert_eq!(header.geometry_type, 1);
+        assert!(header.has_z && !header.has_m);
+        assert_eq!(header.srid, Some(4326));
+        assert_eq!(reader.position(), 9);
+
+        let wkb = point_wkb(Endianness::LittleEndian, 3005, &[]);
+        let header = WKBHeader::read(&mut Cursor::new(wkb.as_slice())).unwrap();
+        assert_eq!(header.geometry_type, 5);
+        assert_eq!(header.num_dims(), 4);
+    }
+
+    #[test]
+    fn force_xy_multi_point() {
+        let mut wkb = vec![1];
+        wkb.write_wkb_u32(Endianness::LittleEndian, 1004).unwrap();
+        wkb.write_wkb_u32(Endianness::LittleEndian, 2).unwrap();
+        wkb.extend(point_wkb(Endianness::LittleEndian, 1001, &[1., 2., 3.]));
+        wkb.extend(point_wkb(Endianness::LittleEndian, 1001, &[4., 5., 6.]));
+        let arr = WKBArray::new(
+            BinaryArray::from_vec(vec![wkb.as_slice()]),
+            Default::default(),
+        );
+
+        let xy_arr = normalize_wkb(&arr, DimensionPolicy::ForceXY, CurvePolicy::Error, 0)
+            .unwrap()
+            .unwrap();
+        let mut expected = vec![1];
+        expected.write_wkb_u32(Endianness::LittleEndian, 4).unwrap();
+        expected.write_wkb_u32(Endianness::LittleEndian, 2).unwrap();
+        expected.extend(point_wkb(Endianness::LittleEndian, 1, &[1., 2.]));
+        expected.extend(point_wkb(Endianness::LittleEndian, 1, &[4., 5.]));
+        assert_eq!(xy_arr.value(0).as_ref(), expected.as_slice());
+    }
+
+    #[test]
+    fn ewkb_srid() {
+        let mut wkb = point_wkb(Endianness::BigEndian, 0x2000_0001, &[]);
+        wkb.extend_from_slice(&3857_u32.to_be_bytes());
+        wkb.write_wkb_f64(Endianness::BigEndian, 1.).unwrap();
+        wkb.write_wkb_f64(Endianness::BigEndian, 2.).unwrap();
+        let arr = WKBArray::new(
+            BinaryArray::from(vec![Some(wkb.as_slice()), None]),
+            Default::default(),
+        );
+
+        let normalized = normalize_wkb(&arr, DimensionPolicy::Error, CurvePolicy::Error, 0)
+            .unwrap()
+            .unwrap();
+        let expected = point_wkb(Endianness::BigEndian, 1, &[1., 2.]);
+        assert_eq!(normalized.value(0).as_ref(), expected.as_slice());
+        assert!(normalized.is_null(1));
+        assert_eq!(
+            normalized.metadata().crs,
+            Some(json!({"id": {"authority": "EPSG", "code": 3857}}))
+        );
+
+        // Geometries with different SRIDs can't share a CRS
+        let mut other_wkb = point_wkb(Endianness::LittleEndian, 0x2000_0001, &[]);
+        other_wkb.extend_from_slice(&4326_u32.to_le_bytes());
+        other_wkb
+            .write_wkb_f64(Endianness::LittleEndian, 1.)
+            .unwrap();
+        other_wkb
+            .write_wkb_f64(Endianness::LittleEndian, 2.)
+            .unwrap();
+        let arr = WKBArray::new(
+            BinaryArray::from_vec(vec![wkb.as_slice(), other_wkb.as_slice()]),
+            Default::default(),
+        );
+        assert!(normalize_wkb(&arr, DimensionPolicy::Error, CurvePolicy::Error, 0).is_err());
+    }
+
+    #[test]
+    fn linearize_collection_member() {
+        // GEOMETRYCOLLECTION(POINT(1 2), CIRCULARSTRING(0 0, 1 1, 2 0))
+        let mut wkb = vec![1];
+        wkb.write_wkb_u32(Endianness::LittleEndian, 7).unwrap();
+        wkb.write_wkb_u32(Endianness::LittleEndian, 2).unwrap();
+        wkb.extend(point_wkb(Endianness::LittleEndian, 1, &[1., 2.]));
+        wkb.extend(point_wkb(Endianness::LittleEndian, 8, &[]));
+        wkb.write_wkb_u32(Endianness::LittleEndian, 3).unwrap();
+        for coord in [0., 0., 1., 1., 2., 0.] {
+            wkb.write_wkb_f64(Endianness::LittleEndian, coord).unwrap();
+        }
+        let arr = WKBArray::new(
+            BinaryArray::from_vec(vec![wkb.as_slice()]),
+            Default::default(),
+        );
+
+        let err = normalize_wkb(&arr, DimensionPolicy::Error, CurvePolicy::Error, 0).unwrap_err();
+        assert!(err.to_string().contains("row 0"));
+
+        let curve_policy = CurvePolicy::Linearize { max_angle_deg: 90. };
+        let normalized = normalize_wkb(&arr, DimensionPolicy::Error, curve_policy, 0)
+            .unwrap()
+            .unwrap();
+        let mut expected = vec![1];
+        expected.write_wkb_u32(Endianness::LittleEndian, 7).unwrap();
+        expected.write_wkb_u32(Endianness::LittleEndian, 2).unwrap();
+        expected.extend(point_wkb(Endianness::LittleEndian, 1, &[1., 2.]));
+        expected.extend(point_wkb(Endianness::LittleEndian, 2, &[]));
+        expected.write_wkb_u32(Endianness::LittleEndian, 3).unwrap();
+        // The half circle is split into two segments, meeting at its top
+        for coord in [0., 0., 1., 1., 2., 0.] {
+            expected
+                .write_wkb_f64(Endianness::LittleEndian, coord)
+                .unwrap();
+        }
+        assert_eq!(normalized.value(0).as_ref(), expected.as_slice());
+    }
+}
@@ -57,9 +57,13 @@ impl<'a, O: OffsetSizeTrait> WKB<'a, O> {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+/// The byte order of a WKB geometry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Endianness {
+    /// Most significant byte first, marked by a `0` byte order flag.
     BigEndian,
+
+    /// Least significant byte first, marked by a `1` byte order flag.
     LittleEndian,
 }
 
@@ -4,6 +4,7 @@ use crate::array::offset_builder::OffsetsBuilder;
 use crate::array::{MixedGeometryArray, WKBArray};
 use crate::error::Result;
 use crate::geo_traits::{GeometryTrait, GeometryType};
+use crate::io::wkb::reader::Endianness;
 use crate::io::wkb::writer::{
     geometry_collection_wkb_size, line_string_wkb_size, multi_line_string_wkb_size,
     multi_point_wkb_size, multi_polygon_wkb_size, polygon_wkb_size, write_line_string_as_wkb,
@@ -33,20 +34,21 @@ pub fn geometry_wkb_size(geom: &impl GeometryTrait) -> usize {
 pub fn write_geometry_as_wkb<W: Write>(
     writer: W,
     geom: &impl GeometryTrait<T = f64>,
+    endianness: Endianness,
 ) -> Result<()> {
     use GeometryType::*;
     match geom.as_type() {
-        Point(p) => write_point_as_wkb(writer, p),
-        LineString(ls) => write_line_string_as_wkb(writer, ls),
-        Polygon(p) => write_polygon_as_wkb(writer, p),
-        MultiPoint(mp) => write_multi_point_as_wkb(writer, mp),
-        MultiLineString(ml) => write_multi_line_string_as_wkb(writer, ml),
-        MultiPolygon(mp) => write_multi_polygon_as_wkb(writer, mp),
+        Point(p) => write_point_as_wkb(writer, p, endianness),
+        LineString(ls) => write_line_string_as_wkb(writer, ls, endianness),
+        Polygon(p) => write_polygon_as_wkb(writer, p, endianness),
+        MultiPoint(mp) => write_multi_point_as_wkb(writer, mp, endianness),
+        MultiLineString(ml) => write_multi_line_string_as_wkb(writer, ml, endianness),
+        MultiPolygon(mp) => write_multi_polygon_as_wkb(writer, mp, endianness),
         GeometryCollection(_gc) => {
             todo!()
             // error[E0275]: overflow evaluating the requirement `&mut std::io::Cursor<std::vec::Vec<u8>>: std::io::Write`
             // https://stackoverflow.com/a/31197781/7319250
-            // write_geometry_collection_as_wkb(writer, gc)
+            // write_geometry_collection_as_wkb(writer, gc, endianness)
         }
         Rect(_) => todo!(),
         // _ => todo!(),
@@ -55,32 +57,39 @@ pub fn write_geometry_as_wkb<W: Write>(
 
 impl<A: OffsetSizeTrait, B: OffsetSizeTrait> From<&MixedGeometryArray<A>> for WKBArray<B> {
     fn from(value: &MixedGeometryArray<A>) -> Self {
-        let mut offsets: OffsetsBuilder<B> = OffsetsBuilder::with_capacity(value.len());
+        mixed_array_to_wkb(value, Endianness::LittleEndian)
+    }
+}
+
+/// Encode a mixed geometry array as WKB with the given byte order.
+pub(crate) fn mixed_array_to_wkb<A: OffsetSizeTrait, B: OffsetSizeTrait>(
+    value: &MixedGeometryArray<A>,
+    endianness: Endianness,
+) -> WKBArray<B> {
+    let mut offsets: OffsetsBuilder<B> = OffsetsBuilder::with_capacity(value.len());
 
-        // First pass: calculate binary array offsets
-        for maybe_geom in value.iter() {
-            if let Some(geom) = maybe_geom {
-                offsets.try_push_usize(geometry_wkb_size(&geom)).unwrap();
-            } else {
-                offsets.extend_constant(1);
-            }
+    // First pass: calculate binary array offsets
+    for maybe_geom in value.iter() {
+        if let Some(geom) = maybe_geom {
+            offsets.try_push_usize(geometry_wkb_size(&geom)).unwrap();
+        } else {
+            offsets.extend_constant(1);
         }
+    }
 
-        let values = {
-            let values = Vec::with_capacity(offsets.last().to_usize().unwrap());
-            let mut writer = Cursor::new(values);
+    let values = {
+        let values = Vec::with_capacity(offsets.last().to_usize().unwrap());
+        let mut writer = Cursor::new(values);
 
-            for geom in value.iter().flatten() {
-                write_geometry_as_wkb(&mut writer, &geom).unwrap();
-            }
+        for geom in value.iter().flatten() {
+            write_geometry_as_wkb(&mut writer, &geom, endianness).unwrap();
+        }
 
-            writer.into_inner()
-        };
+        writer.into_inner()
+    };
 
-        let binary_arr =
-            GenericBinaryArray::new(offsets.into(), values.into(), value.nulls().cloned());
-        WKBArray::new(binary_arr, value.metadata())
-    }
+    let binary_arr = GenericBinaryArray::new(offsets.into(), values.into(), value.nulls().cloned());
+    WKBArray::new(binary_arr, value.metadata())
 }
 
 // #[cfg(test)]
@@ -4,10 +4,11 @@ use crate::error::Result;
 use crate::geo_traits::GeometryCollectionTrait;
 use crate::io::wkb::reader::Endianness;
 use crate::io::wkb::writer::geometry::{geometry_wkb_size, write_geometry_as_wkb};
+use crate::io::wkb::writer::WriteWKBExt;
 use crate::trait_::GeometryArrayAccessor;
 use crate::trait_::GeometryArrayTrait;
 use arrow_array::{GenericBinaryArray, OffsetSizeTrait};
-use byteorder::{LittleEndian, WriteBytesExt};
+use byteorder::WriteBytesExt;
 use std::io::{Cursor, Write};
 
 /// The byte length of a WKBGeometryCollection
@@ -25,20 +26,21 @@ pub fn geometry_collection_wkb_size(geom: &impl GeometryCollectionTrait) -> usiz
 pub fn write_geometry_collection_as_wkb<W: Write>(
     mut writer: W,
     geom: &impl GeometryCollectionTrait<T = f64>,
+    endianness: Endianness,
 ) -> Result<()> {
     // Byte order
-    writer.write_u8(Endianness::LittleEndian.into()).unwrap();
+    writer.write_u8(endianness.into()).unwrap();
 
     // wkbType = 7
-    writer.write_u32::<LittleEndian>(7).unwrap();
+    writer.write_wkb_u32(endianness, 7).unwrap();
 
     // numGeometries
     writer
-        .write_u32::<LittleEndian>(geom.num_geometries().try_into().unwrap())
+        .write_wkb_u32(endianness, geom.num_geometries().try_into().unwrap())
         .unwrap();
 
     for inner_geom in geom.geometries() {
-        write_geometry_as_wkb(&mut writer, &inner_geom).unwrap();
+        write_geometry_as_wkb(&mut writer, &inner_geom, endianness).unwrap();
     }
 
     Ok(())
@@ -46,34 +48,41 @@ pub fn write_geometry_collection_as_wkb<W: Write>(
 
 impl<A: OffsetSizeTrait, B: OffsetSizeTrait> From<&GeometryCollectionArray<A>> for WKBArray<B> {
     fn from(value: &GeometryCollectionArray<A>) -> Self {
-        let mut offsets: OffsetsBuilder<B> = OffsetsBuilder::with_capacity(value.len());
-
-        // First pass: calculate binary array offsets
-        for maybe_geom in value.iter() {
-            if let Some(geom) = maybe_geom {
-                offsets
-                    .try_push_usize(geometry_collection_wkb_size(&geom))
-                    .unwrap();
-            } else {
-                offsets.extend_constant(1);
-            }
+        geometry_collection_array_to_wkb(value, Endianness::LittleEndian)
+    }
+}
+
+/// Encode a geometry collection array as WKB with the given byte order.
+pub(crate) fn geometry_collection_array_to_wkb<A: OffsetSizeTrait, B: OffsetSizeTrait>(
+    value: &GeometryCollectionArray<A>,
+    endianness: Endianness,
+) -> WKBArray<B> {
+    let mut offsets: OffsetsBuilder<B> = OffsetsBuilder::with_capacity(value.len());
+
+    // First pass: calculate binary array offsets
+    for maybe_geom in value.iter() {
+        if let Some(geom) = maybe_geom {
+            offsets
+                .try_push_usize(geometry_collection_wkb_size(&geom))
+                .unwrap();
+        } else {
+            offsets.extend_constant(1);
         }
+    }
 
-        let values = {
-            let values = Vec::with_capacity(offsets.last().to_usize().unwrap());
-            let mut writer = Cursor::new(values);
+    let values = {
+        let values = Vec::with_capacity(offsets.last().to_usize().unwrap());
+        let mut writer = Cursor::new(values);
 
-            for geom in value.iter().flatten() {
-                write_geometry_collection_as_wkb(&mut writer, &geom).unwrap();
-            }
+        for geom in value.iter().flatten() {
+            write_geometry_collection_as_wkb(&mut writer, &geom, endianness).unwrap();
+        }
 
-            writer.into_inner()
-        };
+        writer.into_inner()
+    };
 
-        let binary_arr =
-            GenericBinaryArray::new(offsets.into(), values.into(), value.nulls().cloned());
-        WKBArray::new(binary_arr, value.metadata())
-    }
+    let binary_arr = GenericBinaryArray::new(offsets.into(), values.into(), value.nulls().cloned());
+    WKBArray::new(binary_arr, value.metadata())
 }
 
 // #[cfg(test)]
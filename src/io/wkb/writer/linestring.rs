@@ -3,10 +3,11 @@ use crate::array::{LineStringArray, WKBArray};
 use crate::error::Result;
 use crate::geo_traits::{CoordTrait, LineStringTrait};
 use crate::io::wkb::reader::Endianness;
+use crate::io::wkb::writer::WriteWKBExt;
 use crate::trait_::GeometryArrayAccessor;
 use crate::trait_::GeometryArrayTrait;
 use arrow_array::{GenericBinaryArray, OffsetSizeTrait};
-use byteorder::{LittleEndian, WriteBytesExt};
+use byteorder::WriteBytesExt;
 use std::io::{Cursor, Write};
 
 /// The byte length of a WKBLineString
@@ -18,21 +19,22 @@ pub fn line_string_wkb_size(geom: &impl LineStringTrait) -> usize {
 pub fn write_line_string_as_wkb<W: Write>(
     mut writer: W,
     geom: &impl LineStringTrait<T = f64>,
+    endianness: Endianness,
 ) -> Result<()> {
     // Byte order
-    writer.write_u8(Endianness::LittleEndian.into()).unwrap();
+    writer.write_u8(endianness.into()).unwrap();
 
     // wkbType = 2
-    writer.write_u32::<LittleEndian>(2).unwrap();
+    writer.write_wkb_u32(endianness, 2).unwrap();
 
     // numPoints
     writer
-        .write_u32::<LittleEndian>(geom.num_coords().try_into().unwrap())
+        .write_wkb_u32(endianness, geom.num_coords().try_into().unwrap())
         .unwrap();
 
     for coord in geom.coords() {
-        writer.write_f64::<LittleEndian>(coord.x()).unwrap();
-        writer.write_f64::<LittleEndian>(coord.y()).unwrap();
+        writer.write_wkb_f64(endianness, coord.x()).unwrap();
+        writer.write_wkb_f64(endianness, coord.y()).unwrap();
     }
 
     Ok(())
@@ -40,32 +42,39 @@ pub fn write_line_string_as_wkb<W: Write>(
 
 impl<A: OffsetSizeTrait, B: OffsetSizeTrait> From<&LineStringArray<A>> for WKBArray<B> {
     fn from(value: &LineStringArray<A>) -> Self {
-        let mut offsets: OffsetsBuilder<B> = OffsetsBuilder::with_capacity(value.len());
-
-        // First pass: calculate binary array offsets
-        for maybe_geom in value.iter() {
-            if let Some(geom) = maybe_geom {
-                offsets.try_push_usize(line_string_wkb_size(&geom)).unwrap();
-            } else {
-                offsets.extend_constant(1);
-            }
+        line_string_array_to_wkb(value, Endianness::LittleEndian)
+    }
+}
+
+/// Encode a line string array as WKB with the given byte order.
+pub(crate) fn line_string_array_to_wkb<A: OffsetSizeTrait, B: OffsetSizeTrait>(
+    value: &LineStringArray<A>,
+    endianness: Endianness,
+) -> WKBArray<B> {
+    let mut offsets: OffsetsBuilder<B> = OffsetsBuilder::with_capacity(value.len());
+
+    // First pass: calculate binary array offsets
+    for maybe_geom in value.iter() {
+        if let Some(geom) = maybe_geom {
+            offsets.try_push_usize(line_string_wkb_size(&geom)).unwrap();
+        } else {
+            offsets.extend_constant(1);
         }
+    }
 
-        let values = {
-            let values = Vec::with_capacity(offsets.last().to_usize().unwrap());
-            let mut writer = Cursor::new(values);
+    let values = {
+        let values = Vec::with_capacity(offsets.last().to_usize().unwrap());
+        let mut writer = Cursor::new(values);
 
-            for geom in value.iter().flatten() {
-                write_line_string_as_wkb(&mut writer, &geom).unwrap();
-            }
+        for geom in value.iter().flatten() {
+            write_line_string_as_wkb(&mut writer, &geom, endianness).unwrap();
+        }
 
-            writer.into_inner()
-        };
+        writer.into_inner()
+    };
 
-        let binary_arr =
-            GenericBinaryArray::new(offsets.into(), values.into(), value.nulls().cloned());
-        WKBArray::new(binary_arr, value.metadata())
-    }
+    let binary_arr = GenericBinaryArray::new(offsets.into(), values.into(), value.nulls().cloned());
+    WKBArray::new(binary_arr, value.metadata())
 }
 
 #[cfg(test)]
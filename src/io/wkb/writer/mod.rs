@@ -16,3 +16,37 @@ pub use multipoint::{multi_point_wkb_size, write_multi_point_as_wkb};
 pub use multipolygon::{multi_polygon_wkb_size, write_multi_polygon_as_wkb};
 pub use point::{write_point_as_wkb, POINT_WKB_SIZE};
 pub use polygon::{polygon_wkb_size, write_polygon_as_wkb};
+
+pub(crate) use geometry::mixed_array_to_wkb;
+pub(crate) use geometrycollection::geometry_collection_array_to_wkb;
+pub(crate) use linestring::line_string_array_to_wkb;
+pub(crate) use multilinestring::multi_line_string_array_to_wkb;
+pub(crate) use multipoint::multi_point_array_to_wkb;
+pub(crate) use multipolygon::multi_polygon_array_to_wkb;
+pub(crate) use point::point_array_to_wkb;
+pub(crate) use polygon::polygon_array_to_wkb;
+
+use std::io::Write;
+
+use byteorder::{BigEndian, LittleEndian, WriteBytesExt};
+
+use crate::io::wkb::reader::Endianness;
+
+/// Write the numeric values of a WKB geometry in a byte order chosen at runtime.
+pub(crate) trait WriteWKBExt: Write {
+    fn write_wkb_u32(&mut self, endianness: Endianness, n: u32) -> std::io::Result<()> {
+        match endianness {
+            Endianness::LittleEndian => self.write_u32::<LittleEndian>(n),
+            Endianness::BigEndian => self.write_u32::<BigEndian>(n),
+        }
+    }
+
+    fn write_wkb_f64(&mut self, endianness: Endianness, n: f64) -> std::io::Result<()> {
+        match endianness {
+            Endianness::LittleEndian => self.write_f64::<LittleEndian>(n),
+            Endianness::BigEndian => self.write_f64::<BigEndian>(n),
+        }
+    }
+}
+
+impl<W: Write + ?Sized> WriteWKBExt for W {}
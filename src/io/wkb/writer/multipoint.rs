@@ -4,10 +4,11 @@ use crate::error::Result;
 use crate::geo_traits::MultiPointTrait;
 use crate::io::wkb::reader::Endianness;
 use crate::io::wkb::writer::point::{write_point_as_wkb, POINT_WKB_SIZE};
+use crate::io::wkb::writer::WriteWKBExt;
 use crate::trait_::GeometryArrayAccessor;
 use crate::trait_::GeometryArrayTrait;
 use arrow_array::{GenericBinaryArray, OffsetSizeTrait};
-use byteorder::{LittleEndian, WriteBytesExt};
+use byteorder::WriteBytesExt;
 use std::io::{Cursor, Write};
 
 /// The byte length of a WKBMultiPoint
@@ -19,20 +20,21 @@ pub fn multi_point_wkb_size(geom: &impl MultiPointTrait) -> usize {
 pub fn write_multi_point_as_wkb<W: Write>(
     mut writer: W,
     geom: &impl MultiPointTrait<T = f64>,
+    endianness: Endianness,
 ) -> Result<()> {
     // Byte order
-    writer.write_u8(Endianness::LittleEndian.into()).unwrap();
+    writer.write_u8(endianness.into()).unwrap();
 
     // wkbType = 4
-    writer.write_u32::<LittleEndian>(4).unwrap();
+    writer.write_wkb_u32(endianness, 4).unwrap();
 
     // numPoints
     writer
-        .write_u32::<LittleEndian>(geom.num_points().try_into().unwrap())
+        .write_wkb_u32(endianness, geom.num_points().try_into().unwrap())
         .unwrap();
 
     for point in geom.points() {
-        write_point_as_wkb(&mut writer, &point).unwrap();
+        write_point_as_wkb(&mut writer, &point, endianness).unwrap();
     }
 
     Ok(())
@@ -40,32 +42,39 @@ pub fn write_multi_point_as_wkb<W: Write>(
 
 impl<A: OffsetSizeTrait, B: OffsetSizeTrait> From<&MultiPointArray<A>> for WKBArray<B> {
     fn from(value: &MultiPointArray<A>) -> Self {
-        let mut offsets: OffsetsBuilder<B> = OffsetsBuilder::with_capacity(value.len());
-
-        // First pass: calculate binary array offsets
-        for maybe_geom in value.iter() {
-            if let Some(geom) = maybe_geom {
-                offsets.try_push_usize(multi_point_wkb_size(&geom)).unwrap();
-            } else {
-                offsets.extend_constant(1);
-            }
+        multi_point_array_to_wkb(value, Endianness::LittleEndian)
+    }
+}
+
+/// Encode a multi point array as WKB with the given byte order.
+pub(crate) fn multi_point_array_to_wkb<A: OffsetSizeTrait, B: OffsetSizeTrait>(
+    value: &MultiPointArray<A>,
+    endianness: Endianness,
+) -> WKBArray<B> {
+    let mut offsets: OffsetsBuilder<B> = OffsetsBuilder::with_capacity(value.len());
+
+    // First pass: calculate binary array offsets
+    for maybe_geom in value.iter() {
+        if let Some(geom) = maybe_geom {
+            offsets.try_push_usize(multi_point_wkb_size(&geom)).unwrap();
+        } else {
+            offsets.extend_constant(1);
         }
+    }
 
-        let values = {
-            let values = Vec::with_capacity(offsets.last().to_usize().unwrap());
-            let mut writer = Cursor::new(values);
+    let values = {
+        let values = Vec::with_capacity(offsets.last().to_usize().unwrap());
+        let mut writer = Cursor::new(values);
 
-            for geom in value.iter().flatten() {
-                write_multi_point_as_wkb(&mut writer, &geom).unwrap();
-            }
+        for geom in value.iter().flatten() {
+            write_multi_point_as_wkb(&mut writer, &geom, endianness).unwrap();
+        }
 
-            writer.into_inner()
-        };
+        writer.into_inner()
+    };
 
-        let binary_arr =
-            GenericBinaryArray::new(offsets.into(), values.into(), value.nulls().cloned());
-        WKBArray::new(binary_arr, value.metadata())
-    }
+    let binary_arr = GenericBinaryArray::new(offsets.into(), values.into(), value.nulls().cloned());
+    WKBArray::new(binary_arr, value.metadata())
 }
 
 #[cfg(test)]
@@ -3,59 +3,72 @@ use crate::array::{PointArray, WKBArray};
 use crate::error::Result;
 use crate::geo_traits::PointTrait;
 use crate::io::wkb::reader::Endianness;
+use crate::io::wkb::writer::WriteWKBExt;
 use crate::trait_::GeometryArrayAccessor;
 use crate::trait_::GeometryArrayTrait;
 use arrow_array::{GenericBinaryArray, OffsetSizeTrait};
-use byteorder::{LittleEndian, WriteBytesExt};
+use byteorder::WriteBytesExt;
 use std::io::{Cursor, Write};
 
 /// The byte length of a WKBPoint
 pub const POINT_WKB_SIZE: usize = 1 + 4 + 8 + 8;
 
 /// Write a Point geometry to a Writer encoded as WKB
-pub fn write_point_as_wkb<W: Write>(mut writer: W, geom: &impl PointTrait<T = f64>) -> Result<()> {
+pub fn write_point_as_wkb<W: Write>(
+    mut writer: W,
+    geom: &impl PointTrait<T = f64>,
+    endianness: Endianness,
+) -> Result<()> {
     // Byte order
-    writer.write_u8(Endianness::LittleEndian.into()).unwrap();
+    writer.write_u8(endianness.into()).unwrap();
 
     // wkbType = 1
-    writer.write_u32::<LittleEndian>(1).unwrap();
+    writer.write_wkb_u32(endianness, 1).unwrap();
 
-    writer.write_f64::<LittleEndian>(geom.x()).unwrap();
-    writer.write_f64::<LittleEndian>(geom.y()).unwrap();
+    writer.write_wkb_f64(endianness, geom.x()).unwrap();
+    writer.write_wkb_f64(endianness, geom.y()).unwrap();
 
     Ok(())
 }
 
 impl<O: OffsetSizeTrait> From<&PointArray> for WKBArray<O> {
     fn from(value: &PointArray) -> Self {
-        let non_null_count = value
-            .nulls()
-            .map_or(value.len(), |validity| value.len() - validity.null_count());
-
-        let validity = value.nulls().cloned();
-        // only allocate space for a WKBPoint for non-null items
-        let values_len = non_null_count * POINT_WKB_SIZE;
-        let mut offsets: OffsetsBuilder<O> = OffsetsBuilder::with_capacity(value.len());
-
-        let values = {
-            let values = Vec::with_capacity(values_len);
-            let mut writer = Cursor::new(values);
-
-            for maybe_geom in value.iter() {
-                if let Some(geom) = maybe_geom {
-                    write_point_as_wkb(&mut writer, &geom).unwrap();
-                    offsets.try_push_usize(POINT_WKB_SIZE).unwrap();
-                } else {
-                    offsets.extend_constant(1);
-                }
+        point_array_to_wkb(value, Endianness::LittleEndian)
+    }
+}
+
+/// Encode a point array as WKB with the given byte order.
+pub(crate) fn point_array_to_wkb<O: OffsetSizeTrait>(
+    value: &PointArray,
+    endianness: Endianness,
+) -> WKBArray<O> {
+    let non_null_count = value
+        .nulls()
+        .map_or(value.len(), |validity| value.len() - validity.null_count());
+
+    let validity = value.nulls().cloned();
+    // only allocate space for a WKBPoint for non-null items
+    let values_len = non_null_count * POINT_WKB_SIZE;
+    let mut offsets: OffsetsBuilder<O> = OffsetsBuilder::with_capacity(value.len());
+
+    let values = {
+        let values = Vec::with_capacity(values_len);
+        let mut writer = Cursor::new(values);
+
+        for maybe_geom in value.iter() {
+            if let Some(geom) = maybe_geom {
+                write_point_as_wkb(&mut writer, &geom, endianness).unwrap();
+                offsets.try_push_usize(POINT_WKB_SIZE).unwrap();
+            } else {
+                offsets.extend_constant(1);
             }
+        }
 
-            writer.into_inner()
-        };
+        writer.into_inner()
+    };
 
-        let binary_arr = GenericBinaryArray::new(offsets.into(), values.into(), validity);
-        WKBArray::new(binary_arr, value.metadata())
-    }
+    let binary_arr = GenericBinaryArray::new(offsets.into(), values.into(), validity);
+    WKBArray::new(binary_arr, value.metadata())
 }
 
 #[cfg(test)]
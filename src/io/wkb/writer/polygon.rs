@@ -3,10 +3,11 @@ use crate::array::{PolygonArray, WKBArray};
 use crate::error::Result;
 use crate::geo_traits::{CoordTrait, LineStringTrait, PolygonTrait};
 use crate::io::wkb::reader::Endianness;
+use crate::io::wkb::writer::WriteWKBExt;
 use crate::trait_::GeometryArrayAccessor;
 use crate::trait_::GeometryArrayTrait;
 use arrow_array::{GenericBinaryArray, OffsetSizeTrait};
-use byteorder::{LittleEndian, WriteBytesExt};
+use byteorder::WriteBytesExt;
 use std::io::{Cursor, Write};
 
 /// The byte length of a WKBPolygon
@@ -28,38 +29,39 @@ pub fn polygon_wkb_size(geom: &impl PolygonTrait) -> usize {
 pub fn write_polygon_as_wkb<W: Write>(
     mut writer: W,
     geom: &impl PolygonTrait<T = f64>,
+    endianness: Endianness,
 ) -> Result<()> {
     // Byte order
-    writer.write_u8(Endianness::LittleEndian.into()).unwrap();
+    writer.write_u8(endianness.into()).unwrap();
 
     // wkbType = 3
-    writer.write_u32::<LittleEndian>(3).unwrap();
+    writer.write_wkb_u32(endianness, 3).unwrap();
 
     // numRings
     // TODO: support empty polygons where this will panic
     let num_rings = 1 + geom.num_interiors();
     writer
-        .write_u32::<LittleEndian>(num_rings.try_into().unwrap())
+        .write_wkb_u32(endianness, num_rings.try_into().unwrap())
         .unwrap();
 
     let ext_ring = geom.exterior().unwrap();
     writer
-        .write_u32::<LittleEndian>(ext_ring.num_coords().try_into().unwrap())
+        .write_wkb_u32(endianness, ext_ring.num_coords().try_into().unwrap())
         .unwrap();
 
     for coord in ext_ring.coords() {
-        writer.write_f64::<LittleEndian>(coord.x()).unwrap();
-        writer.write_f64::<LittleEndian>(coord.y()).unwrap();
+        writer.write_wkb_f64(endianness, coord.x()).unwrap();
+        writer.write_wkb_f64(endianness, coord.y()).unwrap();
     }
 
     for int_ring in geom.interiors() {
         writer
-            .write_u32::<LittleEndian>(int_ring.num_coords().try_into().unwrap())
+            .write_wkb_u32(endianness, int_ring.num_coords().try_into().unwrap())
             .unwrap();
 
         for coord in int_ring.coords() {
-            writer.write_f64::<LittleEndian>(coord.x()).unwrap();
-            writer.write_f64::<LittleEndian>(coord.y()).unwrap();
+            writer.write_wkb_f64(endianness, coord.x()).unwrap();
+            writer.write_wkb_f64(endianness, coord.y()).unwrap();
         }
     }
 
@@ -68,32 +70,39 @@ pub fn write_polygon_as_wkb<W: Write>(
 
 impl<A: OffsetSizeTrait, B: OffsetSizeTrait> From<&PolygonArray<A>> for WKBArray<B> {
     fn from(value: &PolygonArray<A>) -> Self {
-        let mut offsets: OffsetsBuilder<B> = OffsetsBuilder::with_capacity(value.len());
-
-        // First pass: calculate binary array offsets
-        for maybe_geom in value.iter() {
-            if let Some(geom) = maybe_geom {
-                offsets.try_push_usize(polygon_wkb_size(&geom)).unwrap();
-            } else {
-                offsets.extend_constant(1);
-            }
+        polygon_array_to_wkb(value, Endianness::LittleEndian)
+    }
+}
+
+/// Encode a polygon array as WKB with the given byte order.
+pub(crate) fn polygon_array_to_wkb<A: OffsetSizeTrait, B: OffsetSizeTrait>(
+    value: &PolygonArray<A>,
+    endianness: Endianness,
+) -> WKBArray<B> {
+    let mut offsets: OffsetsBuilder<B> = OffsetsBuilder::with_capacity(value.len());
+
+    // First pass: calculate binary array offsets
+    for maybe_geom in value.iter() {
+        if let Some(geom) = maybe_geom {
+            offsets.try_push_usize(polygon_wkb_size(&geom)).unwrap();
+        } else {
+            offsets.extend_constant(1);
         }
+    }
 
-        let values = {
-            let values = Vec::with_capacity(offsets.last().to_usize().unwrap());
-            let mut writer = Cursor::new(values);
+    let values = {
+        let values = Vec::with_capacity(offsets.last().to_usize().unwrap());
+        let mut writer = Cursor::new(values);
 
-            for geom in value.iter().flatten() {
-                write_polygon_as_wkb(&mut writer, &geom).unwrap();
-            }
+        for geom in value.iter().flatten() {
+            write_polygon_as_wkb(&mut writer, &geom, endianness).unwrap();
+        }
 
-            writer.into_inner()
-        };
+        writer.into_inner()
+    };
 
-        let binary_arr =
-            GenericBinaryArray::new(offsets.into(), values.into(), value.nulls().cloned());
-        WKBArray::new(binary_arr, value.metadata())
-    }
+    let binary_arr = GenericBinaryArray::new(offsets.into(), values.into(), value.nulls().cloned());
+    WKBArray::new(binary_arr, value.metadata())
 }
 
 #[cfg(test)]
@@ -0,0 +1,8 @@
+//! Read from and write to WKT-encoded geometries, stored in Arrow string arrays.
+
+mod reader;
+pub(crate) mod writer;
+
+pub(crate) use reader::geometries_to_array;
+pub use reader::from_wkt;
+pub use writer::{to_wkt, ToWKT};
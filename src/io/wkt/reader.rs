@@ -0,0 +1,329 @@
+use std::str::FromStr;
+use std::sync::Arc;
+
+use arrow_array::{GenericStringArray, OffsetSizeTrait};
+use wkt::Wkt;
+
+use crate::array::geometrycollection::GeometryCollectionBuilder;
+use crate::array::*;
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::io::guards::{GuardCounter, ParseGuards};
+use crate::GeometryArrayTrait;
+
+/// Parse a string array of WKT to a GeometryArray with GeoArrow native encoding.
+///
+/// `EMPTY` geometries are supported, and an empty point is stored with NaN coordinates. Z and M
+/// coordinates are parsed but dropped, as GeoArrow-native arrays are 2D. Null strings become null
+/// geometries. Errors name the row that failed to parse or that doesn't fit the target type.
+///
+/// Each geometry is checked against `guards` as soon as it has been parsed, and its nesting is
+/// checked before parsing it.
+///
+/// Does not downcast automatically
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        skip_all,
+        fields(num_rows = arr.len(), target_type = ?target_geo_data_type)
+    )
+)]
+pub fn from_wkt<O: OffsetSizeTrait>(
+    arr: &GenericStringArray<O>,
+    target_geo_data_type: GeoDataType,
+    guards: ParseGuards,
+) -> Result<Arc<dyn GeometryArrayTrait>> {
+    let mut counter = GuardCounter::new(guards);
+    let geoms = arr
+        .iter()
+        .enumerate()
+        .map(|(row, maybe_wkt)| {
+            maybe_wkt
+                .map(|wkt| {
+                    if counter.is_unlimited() {
+                        return parse_row(wkt, row);
+                    }
+                    counter.start_row(row);
+                    // A geometry nested n levels deep has at most n + 1 levels of parentheses,
+                    // so this rejects deeply nested input before the recursive parser sees it
+                    counter.check_depth(max_paren_depth(wkt).saturating_sub(1))?;
+                    let geom = parse_row(wkt, row)?;
+                    counter.add_geometry(row, &geom)?;
+                    Ok(geom)
+                })
+                .transpose()
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    geometries_to_array(&geoms, target_geo_data_type)
+}
+
+/// Build a GeometryArray of the target type from parsed geometries, with a null for each `None`.
+///
+/// Errors name the row of the geometry that doesn't fit the target type.
+pub(crate) fn geometries_to_array(
+    geoms: &[Option<geo::Geometry>],
+    target_geo_data_type: GeoDataType,
+) -> Result<Arc<dyn GeometryArrayTrait>> {
+    use GeoDataType::*;
+
+    macro_rules! push_geoms {
+        ($builder:expr, $push_func:ident $(, $arg:expr)?) => {{
+            let mut builder = $builder;
+            for (row, geom) in geoms.iter().enumerate() {
+                builder
+                    .$push_func(geom.as_ref() $(, $arg)?)
+                    .map_err(|err| {
+                        GeoArrowError::General(format!(
+                            "Failed to add the geometry at row {} to a {:?} array: {}",
+                            row, target_geo_data_type, err
+                        ))
+                    })?;
+            }
+            Ok(Arc::new(builder.finish()))
+        }};
+    }
+
+    let metadata = Default::default();
+    match target_geo_data_type {
+        Point(coord_type) => push_geoms!(
+            PointBuilder::new_with_options(coord_type, metadata),
+            push_geometry
+        ),
+        LineString(coord_type) => push_geoms!(
+            LineStringBuilder::<i32>::new_with_options(coord_type, metadata),
+            push_geometry
+        ),
+        LargeLineString(coord_type) => push_geoms!(
+            LineStringBuilder::<i64>::new_with_options(coord_type, metadata),
+            push_geometry
+        ),
+        Polygon(coord_type) => push_geoms!(
+            PolygonBuilder::<i32>::new_with_options(coord_type, metadata),
+            push_geometry
+        ),
+        LargePolygon(coord_type) => push_geoms!(
+            PolygonBuilder::<i64>::new_with_options(coord_type, metadata),
+            push_geometry
+        ),
+        MultiPoint(coord_type) => push_geoms!(
+            MultiPointBuilder::<i32>::new_with_options(coord_type, metadata),
+            push_geometry
+        ),
+        LargeMultiPoint(coord_type) => push_geoms!(
+            MultiPointBuilder::<i64>::new_with_options(coord_type, metadata),
+            push_geometry
+        ),
+        MultiLineString(coord_type) => push_geoms!(
+            MultiLineStringBuilder::<i32>::new_with_options(coord_type, metadata),
+            push_geometry
+        ),
+        LargeMultiLineString(coord_type) => push_geoms!(
+            MultiLineStringBuilder::<i64>::new_with_options(coord_type, metadata),
+            push_geometry
+        ),
+        MultiPolygon(coord_type) => push_geoms!(
+            MultiPolygonBuilder::<i32>::new_with_options(coord_type, metadata),
+            push_geometry
+        ),
+        LargeMultiPolygon(coord_type) => push_geoms!(
+            MultiPolygonBuilder::<i64>::new_with_options(coord_type, metadata),
+            push_geometry
+        ),
+        Mixed(coord_type) => push_geoms!(
+            MixedGeometryBuilder::<i32>::new_with_options(coord_type, metadata),
+            push_geometry
+        ),
+        LargeMixed(coord_type) => push_geoms!(
+            MixedGeometryBuilder::<i64>::new_with_options(coord_type, metadata),
+            push_geometry
+        ),
+        GeometryCollection(coord_type) => push_geoms!(
+            GeometryCollectionBuilder::<i32>::new_with_options(coord_type, metadata),
+            push_geometry,
+            false
+        ),
+        LargeGeometryCollection(coord_type) => push_geoms!(
+            GeometryCollectionBuilder::<i64>::new_with_options(coord_type, metadata),
+            push_geometry,
+            false
+        ),
+        t => Err(GeoArrowError::General(format!(
+            "Unexpected data type {:?}",
+            t,
+        ))),
+    }
+}
+
+fn parse_row(wkt: &str, row: usize) -> Result<geo::Geometry> {
+    parse_geometry(wkt).map_err(|err| {
+        GeoArrowError::General(format!("Failed to parse WKT at row {}: {}", row, err))
+    })
+}
+
+/// The maximum nesting of parentheses in a WKT string.
+fn max_paren_depth(wkt: &str) -> usize {
+    let mut depth = 0_usize;
+    let mut max_depth = 0;
+    for byte in wkt.bytes() {
+        match byte {
+            b'(' => {
+                depth += 1;
+                max_depth = max_depth.max(depth);
+            }
+            b')' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+    max_depth
+}
+
+fn parse_geometry(wkt: &str) -> std::result::Result<geo::Geometry, String> {
+    match Wkt::<f64>::from_str(wkt)? {
+        // geo has no empty point, so store it the same way as PointBuilder::push_empty
+        Wkt::Point(wkt::types::Point(None)) => Ok(geo::Point::new(f64::NAN, f64::NAN).into()),
+        wkt => geo::Geometry::try_from(wkt).map_err(|err| err.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::io::wkt::to_wkt;
+    use crate::test::{linestring, multipolygon, point, polygon};
+    use crate::trait_::GeometryArrayAccessor;
+    use arrow_array::{Array, LargeStringArray, StringArray};
+
+    #[test]
+    fn round_trip() {
+        let arr = point::point_array();
+        let wkt_arr = to_wkt::<i32>(&arr).unwrap();
+        assert_eq!(wkt_arr.value(0), "POINT (0 1)");
+        let round_trip = from_wkt(
+            &wkt_arr,
+            GeoDataType::Point(Default::default()),
+            ParseGuards::default(),
+        )
+        .unwrap();
+        assert_eq!(round_trip.as_ref().as_point(), &arr);
+
+        let arr = linestring::ls_array();
+        let wkt_arr = to_wkt::<i64>(&arr).unwrap();
+        let round_trip = from_wkt(
+            &wkt_arr,
+            GeoDataType::LineString(Default::default()),
+            ParseGuards::default(),
+        )
+        .unwrap();
+        assert_eq!(round_trip.as_ref().as_line_string(), &arr);
+
+        let arr = polygon::p_array();
+        let wkt_arr = to_wkt::<i32>(&arr).unwrap();
+        let round_trip = from_wkt(
+            &wkt_arr,
+            GeoDataType::Polygon(Default::default()),
+            ParseGuards::default(),
+        )
+        .unwrap();
+        assert_eq!(round_trip.as_ref().as_polygon(), &arr);
+
+        let arr = multipolygon::mp_array();
+        let wkt_arr = to_wkt::<i32>(&arr).unwrap();
+        let round_trip = from_wkt(
+            &wkt_arr,
+            GeoDataType::MultiPolygon(Default::default()),
+            ParseGuards::default(),
+        )
+        .unwrap();
+        assert_eq!(round_trip.as_ref().as_multi_polygon(), &arr);
+    }
+
+    #[test]
+    fn empty_and_z() {
+        let wkt_arr = StringArray::from(vec![
+            Some("POINT EMPTY"),
+            None,
+            Some("POINT Z (1 2 3)"),
+            Some("LINESTRING EMPTY"),
+        ]);
+        let arr = from_wkt(
+            &wkt_arr,
+            GeoDataType::Mixed(Default::default()),
+            ParseGuards::default(),
+        )
+        .unwrap();
+        assert_eq!(arr.len(), 4);
+        assert!(arr.is_null(1));
+
+        let mixed = arr.as_ref().as_mixed();
+        let empty_point = mixed.value_as_geo(0);
+        assert!(matches!(empty_point, geo::Geometry::Point(p) if p.x().is_nan()));
+        assert_eq!(
+            mixed.value_as_geo(2),
+            geo::Geometry::Point(geo::point!(x: 1., y: 2.))
+        );
+
+        let wkt_arr = to_wkt::<i32>(arr.as_ref()).unwrap();
+        assert_eq!(wkt_arr.value(0), "POINT EMPTY");
+        assert!(wkt_arr.is_null(1));
+        assert_eq!(wkt_arr.value(2), "POINT (1 2)");
+        assert_eq!(wkt_arr.value(3), "LINESTRING EMPTY");
+    }
+
+    #[test]
+    fn errors_name_the_row() {
+        let wkt_arr = LargeStringArray::from(vec!["POINT (0 1)", "POINT (0 1)", "POINT (0"]);
+        let err = from_wkt(
+            &wkt_arr,
+            GeoDataType::Point(Default::default()),
+            ParseGuards::default(),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("row 2"), "{}", err);
+
+        let wkt_arr = StringArray::from(vec!["POINT (0 1)", "LINESTRING (0 1, 2 3)"]);
+        let err = from_wkt(
+            &wkt_arr,
+            GeoDataType::Point(Default::default()),
+            ParseGuards::default(),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("row 1"), "{}", err);
+    }
+
+    #[test]
+    fn parse_guards() {
+        use crate::io::guards::ResourceLimit;
+
+        let wkt_arr = StringArray::from(vec![
+            "POINT (0 1)",
+            "GEOMETRYCOLLECTION (GEOMETRYCOLLECTION (POINT (0 1)))",
+        ]);
+        let data_type = GeoDataType::Mixed(Default::default());
+        let guards = ParseGuards::default().with_max_nesting_depth(2);
+        let err = from_wkt(&wkt_arr, data_type, guards).unwrap_err();
+        assert!(matches!(
+            err,
+            GeoArrowError::ResourceLimitExceeded {
+                limit: ResourceLimit::NestingDepth,
+                max: 2,
+                row: 1
+            }
+        ));
+
+        let wkt_arr =
+            StringArray::from(vec!["LINESTRING (0 1, 2 3)", "LINESTRING (0 1, 2 3, 4 5)"]);
+        let data_type = GeoDataType::LineString(Default::default());
+        let guards = ParseGuards::default().with_max_coords_per_geometry(2);
+        let err = from_wkt(&wkt_arr, data_type, guards).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "max_coords_per_geometry of 2 exceeded at row 1"
+        );
+
+        let guards = ParseGuards::default()
+            .with_max_total_coords(5)
+            .with_max_nesting_depth(1);
+        assert_eq!(from_wkt(&wkt_arr, data_type, guards).unwrap().len(), 2);
+    }
+}
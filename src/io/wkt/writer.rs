@@ -0,0 +1,354 @@
+//! Encode geometries as WKT text, optionally with a fixed number of decimal places.
+
+use std::fmt::{Result, Write};
+
+use arrow_array::builder::GenericStringBuilder;
+use arrow_array::{GenericStringArray, OffsetSizeTrait};
+
+use crate::array::*;
+use crate::chunked_array::{ChunkedArray, ChunkedGeometryArray, ChunkedGeometryArrayTrait};
+use crate::datatypes::GeoDataType;
+use crate::error::GeoArrowError;
+use crate::geo_traits::{
+    CoordTrait, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait,
+    MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait, RectTrait,
+};
+use crate::io::wkb::to_wkb;
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+
+/// Encode geometry arrays as WKT.
+pub trait ToWKT {
+    type Output<O: OffsetSizeTrait>;
+
+    fn to_wkt<O: OffsetSizeTrait>(&self) -> Self::Output<O>;
+}
+
+macro_rules! impl_array {
+    ($type:ty) => {
+        impl<OInput: OffsetSizeTrait> ToWKT for $type {
+            type Output<O: OffsetSizeTrait> = crate::error::Result<GenericStringArray<O>>;
+
+            fn to_wkt<O: OffsetSizeTrait>(&self) -> Self::Output<O> {
+                to_wkt(self)
+            }
+        }
+    };
+}
+
+impl_array!(LineStringArray<OInput>);
+impl_array!(PolygonArray<OInput>);
+impl_array!(MultiPointArray<OInput>);
+impl_array!(MultiLineStringArray<OInput>);
+impl_array!(MultiPolygonArray<OInput>);
+impl_array!(MixedGeometryArray<OInput>);
+impl_array!(GeometryCollectionArray<OInput>);
+impl_array!(WKBArray<OInput>);
+
+impl ToWKT for PointArray {
+    type Output<O: OffsetSizeTrait> = crate::error::Result<GenericStringArray<O>>;
+
+    fn to_wkt<O: OffsetSizeTrait>(&self) -> Self::Output<O> {
+        to_wkt(self)
+    }
+}
+
+impl ToWKT for &dyn GeometryArrayTrait {
+    type Output<O: OffsetSizeTrait> = crate::error::Result<GenericStringArray<O>>;
+
+    fn to_wkt<O: OffsetSizeTrait>(&self) -> Self::Output<O> {
+        to_wkt(*self)
+    }
+}
+
+impl<G: GeometryArrayTrait> ToWKT for ChunkedGeometryArray<G> {
+    type Output<O: OffsetSizeTrait> = crate::error::Result<ChunkedArray<GenericStringArray<O>>>;
+
+    fn to_wkt<O: OffsetSizeTrait>(&self) -> Self::Output<O> {
+        self.try_map(|chunk| to_wkt(chunk.as_ref()))?.try_into()
+    }
+}
+
+impl ToWKT for &dyn ChunkedGeometryArrayTrait {
+    type Output<O: OffsetSizeTrait> = crate::error::Result<ChunkedArray<GenericStringArray<O>>>;
+
+    fn to_wkt<O: OffsetSizeTrait>(&self) -> Self::Output<O> {
+        let chunks = self
+            .geometry_chunks()
+            .into_iter()
+            .map(to_wkt)
+            .collect::<crate::error::Result<Vec<_>>>()?;
+        Ok(ChunkedArray::new(chunks))
+    }
+}
+
+/// Convert a geometry array to a string array of WKT, with a null for each null geometry.
+///
+/// Empty points, which are stored with NaN coordinates, are written as `POINT EMPTY`.
+pub fn to_wkt<O: OffsetSizeTrait>(
+    arr: &dyn GeometryArrayTrait,
+) -> crate::error::Result<GenericStringArray<O>> {
+    geometry_array_to_wkt(arr, None)
+}
+
+/// Convert a geometry array to WKT, writing coordinates with `precision` digits after the decimal
+/// point if given.
+pub(crate) fn geometry_array_to_wkt<O: OffsetSizeTrait>(
+    arr: &dyn GeometryArrayTrait,
+    precision: Option<usize>,
+) -> crate::error::Result<GenericStringArray<O>> {
+    match arr.data_type() {
+        GeoDataType::WKB => wkb_array_to_wkt(arr.as_wkb(), precision),
+        GeoDataType::LargeWKB => wkb_array_to_wkt(arr.as_large_wkb(), precision),
+        GeoDataType::Rect => Err(GeoArrowError::NotYetImplemented(
+            "Writing Rect arrays to WKT is not yet supported".to_string(),
+        )),
+        _ => wkb_array_to_wkt(&to_wkb::<i64>(arr), precision),
+    }
+}
+
+fn wkb_array_to_wkt<OInput: OffsetSizeTrait, O: OffsetSizeTrait>(
+    wkb_array: &WKBArray<OInput>,
+    precision: Option<usize>,
+) -> crate::error::Result<GenericStringArray<O>> {
+    let mut builder = GenericStringBuilder::<O>::with_capacity(wkb_array.len(), 0);
+    let mut wkt = String::new();
+    for maybe_wkb in wkb_array.iter() {
+        match maybe_wkb {
+            Some(wkb) => {
+                wkt.clear();
+                write_geometry(&mut wkt, &wkb.to_wkb_object(), precision)
+                    .map_err(|err| GeoArrowError::General(err.to_string()))?;
+                builder.append_value(&wkt);
+            }
+            None => builder.append_null(),
+        }
+    }
+    Ok(builder.finish())
+}
+
+/// Write a geometry as WKT.
+///
+/// If `precision` is `None`, each coordinate is written with the fewest digits that round-trip.
+pub(crate) fn write_geometry(
+    out: &mut impl Write,
+    geom: &impl GeometryTrait<T = f64>,
+    precision: Option<usize>,
+) -> Result {
+    match geom.as_type() {
+        GeometryType::Point(g) => write_point(out, g, precision),
+        GeometryType::LineString(g) => {
+            out.write_str("LINESTRING")?;
+            write_line_string_body(out, g, precision)
+        }
+        GeometryType::Polygon(g) => {
+            out.write_str("POLYGON")?;
+            write_polygon_body(out, g, precision)
+        }
+        GeometryType::MultiPoint(g) => {
+            out.write_str("MULTIPOINT")?;
+            if g.num_points() == 0 {
+                return out.write_str(" EMPTY");
+            }
+            out.write_str(" (")?;
+            for (i, point) in g.points().enumerate() {
+                if i > 0 {
+                    out.write_str(", ")?;
+                }
+                out.write_char('(')?;
+                write_coord(out, point.x(), point.y(), precision)?;
+                out.write_char(')')?;
+            }
+            out.write_char(')')
+        }
+        GeometryType::MultiLineString(g) => {
+            out.write_str("MULTILINESTRING")?;
+            if g.num_lines() == 0 {
+                return out.write_str(" EMPTY");
+            }
+            out.write_str(" (")?;
+            for (i, line) in g.lines().enumerate() {
+                if i > 0 {
+                    out.write_str(", ")?;
+                }
+                write_coords(out, line.coords(), precision)?;
+            }
+            out.write_char(')')
+        }
+        GeometryType::MultiPolygon(g) => {
+            out.write_str("MULTIPOLYGON")?;
+            if g.num_polygons() == 0 {
+                return out.write_str(" EMPTY");
+            }
+            out.write_str(" (")?;
+            for (i, polygon) in g.polygons().enumerate() {
+                if i > 0 {
+                    out.write_str(", ")?;
+                }
+                write_rings(out, &polygon, precision)?;
+            }
+            out.write_char(')')
+        }
+        GeometryType::GeometryCollection(g) => {
+            out.write_str("GEOMETRYCOLLECTION")?;
+            if g.num_geometries() == 0 {
+                return out.write_str(" EMPTY");
+            }
+            out.write_str(" (")?;
+            for (i, geometry) in g.geometries().enumerate() {
+                if i > 0 {
+                    out.write_str(", ")?;
+                }
+                write_geometry(out, &geometry, precision)?;
+            }
+            out.write_char(')')
+        }
+        GeometryType::Rect(g) => {
+            let (minx, miny) = (g.lower().x(), g.lower().y());
+            let (maxx, maxy) = (g.upper().x(), g.upper().y());
+            out.write_str("POLYGON ((")?;
+            for (i, (x, y)) in [
+                (minx, miny),
+                (maxx, miny),
+                (maxx, maxy),
+                (minx, maxy),
+                (minx, miny),
+            ]
+            .into_iter()
+            .enumerate()
+            {
+                if i > 0 {
+                    out.write_str(", ")?;
+                }
+                write_coord(out, x, y, precision)?;
+            }
+            out.write_str("))")
+        }
+    }
+}
+
+fn write_point(
+    out: &mut impl Write,
+    point: &impl PointTrait<T = f64>,
+    precision: Option<usize>,
+) -> Result {
+    // Empty points are stored with NaN coordinates
+    if point.x().is_nan() && point.y().is_nan() {
+        return out.write_str("POINT EMPTY");
+    }
+    out.write_str("POINT (")?;
+    write_coord(out, point.x(), point.y(), precision)?;
+    out.write_char(')')
+}
+
+fn write_line_string_body(
+    out: &mut impl Write,
+    line_string: &impl LineStringTrait<T = f64>,
+    precision: Option<usize>,
+) -> Result {
+    if line_string.num_coords() == 0 {
+        return out.write_str(" EMPTY");
+    }
+    out.write_char(' ')?;
+    write_coords(out, line_string.coords(), precision)
+}
+
+fn write_polygon_body(
+    out: &mut impl Write,
+    polygon: &impl PolygonTrait<T = f64>,
+    precision: Option<usize>,
+) -> Result {
+    let is_empty = match polygon.exterior() {
+        Some(exterior) => exterior.num_coords() == 0,
+        None => true,
+    };
+    if is_empty {
+        return out.write_str(" EMPTY");
+    }
+    out.write_char(' ')?;
+    write_rings(out, polygon, precision)
+}
+
+/// Write the rings of a non-empty polygon, as `((x y, ...), (x y, ...))`.
+fn write_rings(
+    out: &mut impl Write,
+    polygon: &impl PolygonTrait<T = f64>,
+    precision: Option<usize>,
+) -> Result {
+    out.write_char('(')?;
+    if let Some(exterior) = polygon.exterior() {
+        write_coords(out, exterior.coords(), precision)?;
+    }
+    for interior in polygon.interiors() {
+        out.write_str(", ")?;
+        write_coords(out, interior.coords(), precision)?;
+    }
+    out.write_char(')')
+}
+
+/// Write a sequence of coordinates, as `(x y, x y, ...)`.
+fn write_coords(
+    out: &mut impl Write,
+    coords: impl Iterator<Item = impl CoordTrait<T = f64>>,
+    precision: Option<usize>,
+) -> Result {
+    out.write_char('(')?;
+    for (i, coord) in coords.enumerate() {
+        if i > 0 {
+            out.write_str(", ")?;
+        }
+        write_coord(out, coord.x(), coord.y(), precision)?;
+    }
+    out.write_char(')')
+}
+
+fn write_coord(out: &mut impl Write, x: f64, y: f64, precision: Option<usize>) -> Result {
+    match precision {
+        Some(precision) => write!(out, "{:.*} {:.*}", precision, x, precision, y),
+        None => write!(out, "{} {}", x, y),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn to_wkt(geom: &geo::Geometry, precision: Option<usize>) -> String {
+        let mut out = String::new();
+        write_geometry(&mut out, geom, precision).unwrap();
+        out
+    }
+
+    #[test]
+    fn geometries() {
+        let polygon: geo::Geometry = geo::polygon![
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 0.)],
+            interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 1.)]],
+        ]
+        .into();
+        assert_eq!(
+            to_wkt(&polygon, None),
+            "POLYGON ((0 0, 4 0, 4 4, 0 0), (1 1, 2 1, 2 2, 1 1))"
+        );
+
+        let multi_point: geo::Geometry = geo::MultiPoint::from(vec![(0.5, 1.), (2., 3.25)]).into();
+        assert_eq!(to_wkt(&multi_point, None), "MULTIPOINT ((0.5 1), (2 3.25))");
+
+        let collection: geo::Geometry = geo::GeometryCollection::new_from(vec![
+            geo::point!(x: 1., y: 2.).into(),
+            geo::LineString::<f64>::new(vec![]).into(),
+        ])
+        .into();
+        assert_eq!(
+            to_wkt(&collection, None),
+            "GEOMETRYCOLLECTION (POINT (1 2), LINESTRING EMPTY)"
+        );
+    }
+
+    #[test]
+    fn precision() {
+        let point: geo::Geometry = geo::point!(x: 1.23456, y: -2.).into();
+        assert_eq!(to_wkt(&point, Some(2)), "POINT (1.23 -2.00)");
+        assert_eq!(to_wkt(&point, None), "POINT (1.23456 -2)");
+    }
+}
@@ -6,6 +6,10 @@ pub use trait_::GeometryArrayTrait;
 pub mod algorithm;
 pub mod array;
 pub mod chunked_array;
+#[cfg(feature = "compat-upstream")]
+pub mod compat;
+#[cfg(feature = "datafusion")]
+pub mod datafusion;
 pub mod datatypes;
 pub mod error;
 pub mod geo_traits;
@@ -17,3 +21,4 @@ pub mod table;
 pub(crate) mod test;
 pub mod trait_;
 mod util;
+pub mod validate;
@@ -0,0 +1,531 @@
+//! Building a table row by row from geo-types geometries and scalar properties.
+
+use std::sync::Arc;
+
+use arrow::compute::cast;
+use arrow_array::{
+    ArrayRef, BooleanArray, Float64Array, Int64Array, RecordBatch, RecordBatchOptions, StringArray,
+};
+use arrow_schema::{DataType, Field, SchemaBuilder, SchemaRef};
+
+use crate::algorithm::native::Downcast;
+use crate::array::metadata::ArrayMetadata;
+use crate::array::{CoordType, MixedGeometryBuilder};
+use crate::error::{GeoArrowError, Result};
+use crate::table::GeoTable;
+use crate::GeometryArrayTrait;
+
+/// The default maximum number of rows in each batch of a [`TableBuilder`].
+const DEFAULT_BATCH_SIZE: usize = 65_536;
+
+/// A scalar property of a feature pushed to a [`TableBuilder`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum PropertyValue {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    String(String),
+}
+
+impl From<bool> for PropertyValue {
+    fn from(value: bool) -> Self {
+        PropertyValue::Bool(value)
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($type:ty),*) => {
+        $(
+            impl From<$type> for PropertyValue {
+                fn from(value: $type) -> Self {
+                    PropertyValue::Int(value.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i8, i16, i32, i64, u8, u16, u32);
+
+impl From<f32> for PropertyValue {
+    fn from(value: f32) -> Self {
+        PropertyValue::Float(value.into())
+    }
+}
+
+impl From<f64> for PropertyValue {
+    fn from(value: f64) -> Self {
+        PropertyValue::Float(value)
+    }
+}
+
+impl From<String> for PropertyValue {
+    fn from(value: String) -> Self {
+        PropertyValue::String(value)
+    }
+}
+
+impl From<&str> for PropertyValue {
+    fn from(value: &str) -> Self {
+        PropertyValue::String(value.to_string())
+    }
+}
+
+impl<T: Into<PropertyValue>> From<Option<T>> for PropertyValue {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(PropertyValue::Null, Into::into)
+    }
+}
+
+impl PropertyValue {
+    /// Whether this value can be stored in a column with the data type of `field`.
+    fn fits(&self, field: &Field) -> bool {
+        match (self, field.data_type()) {
+            (PropertyValue::Null, _) => field.is_nullable(),
+            (PropertyValue::Bool(_), DataType::Boolean) => true,
+            (PropertyValue::Int(value), data_type) => match data_type {
+                DataType::Int8 => i8::try_from(*value).is_ok(),
+                DataType::Int16 => i16::try_from(*value).is_ok(),
+                DataType::Int32 => i32::try_from(*value).is_ok(),
+                DataType::Int64 => true,
+                DataType::UInt8 => u8::try_from(*value).is_ok(),
+                DataType::UInt16 => u16::try_from(*value).is_ok(),
+                DataType::UInt32 => u32::try_from(*value).is_ok(),
+                DataType::UInt64 => u64::try_from(*value).is_ok(),
+                DataType::Float32 | DataType::Float64 => true,
+                _ => false,
+            },
+            (PropertyValue::Float(_), DataType::Float32 | DataType::Float64) => true,
+            (PropertyValue::String(_), DataType::Utf8 | DataType::LargeUtf8) => true,
+            _ => false,
+        }
+    }
+}
+
+/// A builder for creating a [`GeoTable`] one feature at a time, from a geo-types geometry and a
+/// property for each of a fixed set of fields.
+///
+/// Rows are flushed to a record batch every [`batch_size`][Self::with_batch_size] rows. The
+/// geometries of each batch are collected into a mixed geometry array, and the geometry column of
+/// the table is then downcast to the narrowest type that holds all of them, so a table of points
+/// has a point geometry column. The geometry column comes after the property columns and is
+/// tagged with its GeoArrow extension name.
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use arrow_schema::{DataType, Field, Schema};
+/// use geo::point;
+/// use geoarrow::table::TableBuilder;
+///
+/// let cities = vec![
+///     (point!(x: 2.35, y: 48.86), 2_102_650, "Paris".to_string()),
+///     (point!(x: -0.13, y: 51.51), 8_866_180, "London".to_string()),
+/// ];
+///
+/// let schema = Arc::new(Schema::new(vec![
+///     Field::new("population", DataType::Int32, false),
+///     Field::new("name", DataType::Utf8, false),
+/// ]));
+/// let mut builder = TableBuilder::try_new(schema).unwrap();
+/// for (point, population, name) in cities {
+///     builder
+///         .push_feature(point, vec![population.into(), name.into()])
+///         .unwrap();
+/// }
+/// let table = builder.finish().unwrap();
+/// assert_eq!(table.len(), 2);
+/// ```
+#[derive(Debug)]
+pub struct TableBuilder {
+    /// The schema of the properties, without the geometry field
+    properties_schema: SchemaRef,
+
+    /// The max number of rows in each batch
+    batch_size: usize,
+
+    /// The [`CoordType`] of the geometry arrays
+    coord_type: CoordType,
+
+    /// The metadata, such as the CRS, attached to the geometry arrays
+    metadata: Arc<ArrayMetadata>,
+
+    /// The properties of the rows of the current batch, by column
+    columns: Vec<Vec<PropertyValue>>,
+
+    /// The geometries of the rows of the current batch
+    geometries: Vec<geo::Geometry>,
+
+    /// Property batches that have already been finished
+    batches: Vec<RecordBatch>,
+
+    /// Geometry arrays of the batches that have already been finished
+    geom_arrays: Vec<Arc<dyn GeometryArrayTrait>>,
+}
+
+impl TableBuilder {
+    /// Create a builder for features with the properties of `properties_schema`, which must not
+    /// include a geometry field.
+    ///
+    /// Properties may be `Boolean`, integer, `Float32`, `Float64`, `Utf8` or `LargeUtf8` fields.
+    /// Returns an error for fields of other data types.
+    pub fn try_new(properties_schema: SchemaRef) -> Result<Self> {
+        for field in properties_schema.fields() {
+            let supported = matches!(
+                field.data_type(),
+                DataType::Boolean
+                    | DataType::Int8
+                    | DataType::Int16
+                    | DataType::Int32
+                    | DataType::Int64
+                    | DataType::UInt8
+                    | DataType::UInt16
+                    | DataType::UInt32
+                    | DataType::UInt64
+                    | DataType::Float32
+                    | DataType::Float64
+                    | DataType::Utf8
+                    | DataType::LargeUtf8
+            );
+            if !supported {
+                return Err(GeoArrowError::IncorrectType(
+                    format!(
+                        "Property {} has data type {}, which a TableBuilder doesn't support",
+                        field.name(),
+                        field.data_type()
+                    )
+                    .into(),
+                ));
+            }
+        }
+
+        Ok(Self {
+            columns: vec![vec![]; properties_schema.fields().len()],
+            properties_schema,
+            batch_size: DEFAULT_BATCH_SIZE,
+            coord_type: Default::default(),
+            metadata: Default::default(),
+            geometries: vec![],
+            batches: vec![],
+            geom_arrays: vec![],
+        })
+    }
+
+    /// Set the max number of rows in each batch.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Set the [`CoordType`] of the geometry arrays.
+    pub fn with_coord_type(mut self, coord_type: CoordType) -> Self {
+        self.coord_type = coord_type;
+        self
+    }
+
+    /// Set the metadata, such as the CRS, attached to the geometry arrays.
+    pub fn with_metadata(mut self, metadata: Arc<ArrayMetadata>) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    /// The number of features pushed so far.
+    pub fn len(&self) -> usize {
+        self.batches
+            .iter()
+            .map(|batch| batch.num_rows())
+            .sum::<usize>()
+            + self.geometries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Add a feature with a geometry and one property for each field of the properties schema,
+    /// in order.
+    ///
+    /// Integers fit integer fields they are in the range of and float fields, floats fit float
+    /// fields, and [`PropertyValue::Null`] fits nullable fields. Returns an error, without adding
+    /// the feature, if there isn't one property per field or if a property doesn't fit its
+    /// field, or if the geometry is a geometry collection, which can't be stored in the mixed
+    /// arrays of the builder.
+    pub fn push_feature(
+        &mut self,
+        geometry: impl Into<geo::Geometry>,
+        properties: Vec<PropertyValue>,
+    ) -> Result<()> {
+        let row = self.len();
+        let fields = self.properties_schema.fields();
+        if properties.len() != fields.len() {
+            return Err(GeoArrowError::General(format!(
+                "Feature {} has {} properties, but the table has {} property fields",
+                row,
+                properties.len(),
+                fields.len()
+            )));
+        }
+        if let Some((field, value)) = fields
+            .iter()
+            .zip(&properties)
+            .find(|(field, value)| !value.fits(field))
+        {
+            return Err(GeoArrowError::IncorrectType(
+                format!(
+                    "Property {} of feature {} is {:?}, which doesn't fit a field of type {}",
+                    field.name(),
+                    row,
+                    value,
+                    field.data_type()
+                )
+                .into(),
+            ));
+        }
+
+        let geometry = match geometry.into() {
+            geo::Geometry::Line(line) => geo::Geometry::LineString(line.into()),
+            geo::Geometry::Rect(rect) => geo::Geometry::Polygon(rect.to_polygon()),
+            geo::Geometry::Triangle(triangle) => geo::Geometry::Polygon(triangle.to_polygon()),
+            geo::Geometry::GeometryCollection(_) => {
+                return Err(GeoArrowError::NotYetImplemented(format!(
+                    "Feature {} is a geometry collection, which a TableBuilder doesn't support",
+                    row
+                )))
+            }
+            geometry => geometry,
+        };
+
+        self.geometries.push(geometry);
+        for (column, value) in self.columns.iter_mut().zip(properties) {
+            column.push(value);
+        }
+
+        if self.geometries.len() >= self.batch_size {
+            self.flush_batch()?;
+        }
+        Ok(())
+    }
+
+    fn flush_batch(&mut self) -> Result<()> {
+        let columns = self
+            .properties_schema
+            .fields()
+            .iter()
+            .zip(self.columns.iter_mut())
+            .map(|(field, values)| column_to_array(field, &std::mem::take(values)))
+            .collect::<Result<Vec<_>>>()?;
+        let batch = RecordBatch::try_new_with_options(
+            self.properties_schema.clone(),
+            columns,
+            &RecordBatchOptions::new().with_row_count(Some(self.geometries.len())),
+        )?;
+
+        let geometries = std::mem::take(&mut self.geometries);
+        let geom_array = MixedGeometryBuilder::<i32>::from_geometries(
+            &geometries,
+            Some(self.coord_type),
+            self.metadata.clone(),
+            false,
+        )?
+        .finish();
+
+        self.batches.push(batch);
+        self.geom_arrays.push(Arc::new(geom_array));
+        Ok(())
+    }
+
+    /// Finish the table, flushing any remaining rows.
+    ///
+    /// Returns an error if no features were pushed, as the type of the geometry column is then
+    /// unknown.
+    pub fn finish(mut self) -> Result<GeoTable> {
+        if !self.geometries.is_empty() {
+            self.flush_batch()?;
+        }
+        table_from_batches(self.batches, self.geom_arrays)
+    }
+}
+
+/// Build the array of a property column from its values, which must fit `field`.
+fn column_to_array(field: &Field, values: &[PropertyValue]) -> Result<ArrayRef> {
+    let array: ArrayRef = match field.data_type() {
+        DataType::Boolean => Arc::new(
+            values
+                .iter()
+                .map(|value| match value {
+                    PropertyValue::Bool(value) => Some(*value),
+                    _ => None,
+                })
+                .collect::<BooleanArray>(),
+        ),
+        DataType::Utf8 | DataType::LargeUtf8 => Arc::new(
+            values
+                .iter()
+                .map(|value| match value {
+                    PropertyValue::String(value) => Some(value.as_str()),
+                    _ => None,
+                })
+                .collect::<StringArray>(),
+        ),
+        DataType::Float32 | DataType::Float64 => Arc::new(
+            values
+                .iter()
+                .map(|value| match value {
+                    PropertyValue::Int(value) => Some(*value as f64),
+                    PropertyValue::Float(value) => Some(*value),
+                    _ => None,
+                })
+                .collect::<Float64Array>(),
+        ),
+        _ => Arc::new(
+            values
+                .iter()
+                .map(|value| match value {
+                    PropertyValue::Int(value) => Some(*value),
+                    _ => None,
+                })
+                .collect::<Int64Array>(),
+        ),
+    };
+
+    // The values fit the field, so casting to its data type doesn't lose any of them
+    if array.data_type() == field.data_type() {
+        Ok(array)
+    } else {
+        Ok(cast(&array, field.data_type())?)
+    }
+}
+
+/// Create a table from batches of properties and the geometry arrays of each batch, adding the
+/// geometry column after the property columns and downcasting it.
+pub(crate) fn table_from_batches(
+    batches: Vec<RecordBatch>,
+    geom_arrays: Vec<Arc<dyn GeometryArrayTrait>>,
+) -> Result<GeoTable> {
+    let (Some(batch), Some(first_geom_arr)) = (batches.first(), geom_arrays.first()) else {
+        return Err(GeoArrowError::General("No rows loaded".to_string()));
+    };
+
+    // TODO: validate schema compatibility of batches and geometry arrays
+    let schema = batch.schema();
+
+    // Set geometry column after property columns
+    let geometry_column_index = schema.fields().len();
+
+    let mut new_schema = SchemaBuilder::with_capacity(schema.fields().len() + 1);
+    schema
+        .fields()
+        .iter()
+        .for_each(|field| new_schema.push(field.clone()));
+    new_schema.push(first_geom_arr.extension_field());
+    let new_schema = Arc::new(new_schema.finish());
+
+    // Need to add the geometry column onto the table
+    let batches = batches
+        .into_iter()
+        .zip(geom_arrays)
+        .map(|(batch, geom_arr)| {
+            let mut columns = batch.columns().to_vec();
+            columns.push(geom_arr.to_array_ref());
+            Ok(RecordBatch::try_new(new_schema.clone(), columns)?)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let table = GeoTable::try_new(new_schema, batches, geometry_column_index)?;
+    table.downcast(false, false)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::datatypes::GeoDataType;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::UInt8Type;
+    use arrow_schema::Schema;
+    use geo::{line_string, point};
+
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("id", DataType::UInt8, false),
+            Field::new("name", DataType::Utf8, true),
+            Field::new("value", DataType::Float64, true),
+        ]))
+    }
+
+    #[test]
+    fn build_point_table() {
+        let mut builder = TableBuilder::try_new(schema()).unwrap().with_batch_size(2);
+        for i in 0..5u8 {
+            let name = (i % 2 == 0).then(|| format!("point {}", i));
+            builder
+                .push_feature(
+                    point!(x: i as f64, y: 0.),
+                    vec![i.into(), name.into(), (i as i32).into()],
+                )
+                .unwrap();
+        }
+        assert_eq!(builder.len(), 5);
+        let table = builder.finish().unwrap();
+
+        assert_eq!(table.batches().len(), 3);
+        assert_eq!(table.len(), 5);
+        assert_eq!(table.geometry_column_index(), 3);
+        assert!(matches!(
+            table.geometry_data_type().unwrap(),
+            GeoDataType::Point(_)
+        ));
+        let field = table.schema().field(3);
+        assert_eq!(
+            field.metadata().get("ARROW:extension:name").unwrap(),
+            "geoarrow.point"
+        );
+
+        let ids = table.batches()[2].column(0).as_primitive::<UInt8Type>();
+        assert_eq!(ids.value(0), 4);
+        let names = table.batches()[0].column(1).as_string::<i32>();
+        assert!(names.is_valid(0));
+        assert!(names.is_null(1));
+    }
+
+    #[test]
+    fn build_mixed_table() {
+        let mut builder = TableBuilder::try_new(schema()).unwrap();
+        builder
+            .push_feature(point!(x: 0., y: 0.), vec![1.into(), "a".into(), 1.5.into()])
+            .unwrap();
+        builder
+            .push_feature(
+                line_string![(x: 0., y: 0.), (x: 1., y: 1.)],
+                vec![2.into(), PropertyValue::Null, PropertyValue::Null],
+            )
+            .unwrap();
+        let table = builder.finish().unwrap();
+        assert!(matches!(
+            table.geometry_data_type().unwrap(),
+            GeoDataType::Mixed(_)
+        ));
+    }
+
+    #[test]
+    fn invalid_features() {
+        let mut builder = TableBuilder::try_new(schema()).unwrap();
+        let point = point!(x: 0., y: 0.);
+        // Wrong number of properties
+        assert!(builder.push_feature(point, vec![1.into()]).is_err());
+        // Out of range for UInt8
+        let properties = vec![300.into(), "a".into(), 1.5.into()];
+        assert!(builder.push_feature(point, properties).is_err());
+        // Null in a non-nullable field
+        let properties = vec![PropertyValue::Null, "a".into(), 1.5.into()];
+        assert!(builder.push_feature(point, properties).is_err());
+        // Wrong type
+        let properties = vec![1.into(), 2.into(), 1.5.into()];
+        assert!(builder.push_feature(point, properties).is_err());
+        assert!(builder.is_empty());
+        assert!(builder.finish().is_err());
+
+        let schema = Arc::new(Schema::new(vec![Field::new("d", DataType::Date32, true)]));
+        assert!(TableBuilder::try_new(schema).is_err());
+    }
+}
@@ -0,0 +1,657 @@
+//! Conversion between a geometry column and a long "tidy" table with one row per coordinate.
+
+use std::sync::Arc;
+
+use arrow::compute::{cast, concat_batches, take};
+use arrow_array::cast::AsArray;
+use arrow_array::types::{Float64Type, UInt32Type};
+use arrow_array::{Array, ArrayRef, Float64Array, RecordBatch, UInt32Array, UInt64Array};
+use arrow_schema::{DataType, Field, Schema};
+
+use crate::array::*;
+use crate::chunked_array::from_arrow_chunks;
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::table::GeoTable;
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+
+const X: &str = "x";
+const Y: &str = "y";
+const Z: &str = "z";
+const FEATURE_INDEX: &str = "feature_index";
+const PART_INDEX: &str = "part_index";
+const RING_INDEX: &str = "ring_index";
+const COORD_INDEX: &str = "coord_index";
+
+/// The names of the columns written by [`GeoTable::to_coordinate_table`].
+const COORDINATE_COLUMNS: [&str; 6] = [X, Y, FEATURE_INDEX, PART_INDEX, RING_INDEX, COORD_INDEX];
+
+impl GeoTable {
+    /// Convert a geometry column to a long "tidy" table with one row per coordinate, as used for
+    /// plotting or by data frame libraries without geometry types.
+    ///
+    /// The coordinate table has the columns `x`, `y`, `feature_index`, `part_index`,
+    /// `ring_index` and `coord_index`. There is no `z` column, as GeoArrow-native arrays are 2D.
+    /// `feature_index` is the row of the geometry in this table. `part_index` is the index of the
+    /// point, line string or polygon within a multi geometry, and is 0 for single geometries.
+    /// `ring_index` is the index of the ring within a polygon, where 0 is the exterior, and is 0
+    /// for geometries without rings. `coord_index` is the index of the coordinate within its ring,
+    /// line string or point. Polygon rings keep their closing coordinate.
+    ///
+    /// Null and empty geometries have no coordinates, so they have no rows. With
+    /// `include_attributes`, every other column of this table is repeated for each coordinate of
+    /// the row.
+    ///
+    /// Geometry collections are not supported.
+    pub fn to_coordinate_table(
+        &self,
+        geometry_column_index: usize,
+        include_attributes: bool,
+    ) -> Result<RecordBatch> {
+        let field = self.schema().field(geometry_column_index);
+        let chunks = self
+            .batches()
+            .iter()
+            .map(|batch| batch.column(geometry_column_index).as_ref())
+            .collect::<Vec<_>>();
+        let geometry = from_arrow_chunks(&chunks, field)?;
+
+        let mut coords = CoordinateColumns::default();
+        let mut row_offset = 0;
+        for chunk in geometry.geometry_chunks() {
+            for (i, maybe_geometry) in chunk_to_geo(chunk).into_iter().enumerate() {
+                if let Some(geometry) = maybe_geometry {
+                    coords.push_geometry(row_offset + i, &geometry)?;
+                }
+            }
+            row_offset += chunk.len();
+        }
+
+        let mut fields = vec![
+            Field::new(X, DataType::Float64, false),
+            Field::new(Y, DataType::Float64, false),
+        ];
+        fields.extend(
+            [FEATURE_INDEX, PART_INDEX, RING_INDEX, COORD_INDEX]
+                .map(|name| Field::new(name, DataType::UInt32, false)),
+        );
+        let feature_index = UInt32Array::from(coords.feature_index);
+        let mut columns: Vec<ArrayRef> = vec![
+            Arc::new(Float64Array::from(coords.x)),
+            Arc::new(Float64Array::from(coords.y)),
+            Arc::new(feature_index.clone()),
+            Arc::new(UInt32Array::from(coords.part_index)),
+            Arc::new(UInt32Array::from(coords.ring_index)),
+            Arc::new(UInt32Array::from(coords.coord_index)),
+        ];
+
+        if include_attributes {
+            let attribute_indices = (0..self.num_columns())
+                .filter(|&i| i != geometry_column_index)
+                .collect::<Vec<_>>();
+            let attribute_schema = Arc::new(self.schema().project(&attribute_indices)?);
+            let attribute_batches = self
+                .batches()
+                .iter()
+                .map(|batch| batch.project(&attribute_indices))
+                .collect::<std::result::Result<Vec<_>, _>>()?;
+            let attributes = concat_batches(&attribute_schema, &attribute_batches)?;
+
+            let take_indices = cast(&feature_index, &DataType::UInt64)?;
+            for (field, column) in attribute_schema.fields().iter().zip(attributes.columns()) {
+                if COORDINATE_COLUMNS.contains(&field.name().as_str()) {
+                    return Err(GeoArrowError::General(format!(
+                        "Attribute column '{}' conflicts with a coordinate table column",
+                        field.name()
+                    )));
+                }
+                fields.push(field.as_ref().clone());
+                columns.push(take(column, &take_indices, None)?);
+            }
+        }
+
+        Ok(RecordBatch::try_new(
+            Arc::new(Schema::new(fields)),
+            columns,
+        )?)
+    }
+
+    /// Rebuild a table with a geometry column from a coordinate table, as written by
+    /// [`to_coordinate_table`][Self::to_coordinate_table].
+    ///
+    /// `coord_table` must have `x`, `y` and `feature_index` columns, which may be of any numeric
+    /// type. The `part_index` and `ring_index` columns are optional, and are 0 when missing. The
+    /// `coord_index` column is optional too, in which case coordinates are taken in row order. The
+    /// rows must be sorted by feature, part, ring and coordinate index, with part, ring and
+    /// coordinate indices counting up from 0 without gaps, and the rings of polygons must be
+    /// closed.
+    ///
+    /// `geometry_type` is the type of the rebuilt geometry column, and must be a point,
+    /// line string, polygon or multi geometry type. The rebuilt table has a row for every
+    /// feature index up to the largest one, where feature indices without coordinates get a null
+    /// geometry. Other columns of `coord_table` are kept as attributes, taking the value of the
+    /// first coordinate of each feature. The geometry column is the last column.
+    pub fn from_coordinate_table(
+        coord_table: &RecordBatch,
+        geometry_type: GeoDataType,
+    ) -> Result<Self> {
+        if coord_table.column_by_name(Z).is_some() {
+            return Err(GeoArrowError::General(
+                "Z coordinates are not supported, as GeoArrow-native arrays are 2D".to_string(),
+            ));
+        }
+        let x = float_column(coord_table, X)?;
+        let y = float_column(coord_table, Y)?;
+        let feature_index = index_column(coord_table, FEATURE_INDEX)?
+            .ok_or_else(|| missing_column(FEATURE_INDEX))?;
+        let part_index = index_column(coord_table, PART_INDEX)?;
+        let ring_index = index_column(coord_table, RING_INDEX)?;
+        let coord_index = index_column(coord_table, COORD_INDEX)?;
+        let index_at = |column: &Option<UInt32Array>, row: usize| {
+            column.as_ref().map(|column| column.value(row)).unwrap_or(0)
+        };
+
+        // The first row and the coordinates, grouped by part and ring, of each feature
+        let mut features: Vec<(u32, usize, Vec<Vec<Vec<geo::Coord>>>)> = vec![];
+        let mut prev: Option<(u32, u32, u32, u32)> = None;
+        for row in 0..coord_table.num_rows() {
+            let feature = feature_index.value(row);
+            let part = index_at(&part_index, row);
+            let ring = index_at(&ring_index, row);
+            let coord = index_at(&coord_index, row);
+
+            let is_ordered = match prev {
+                Some((prev_feature, prev_part, prev_ring, prev_coord))
+                    if feature == prev_feature =>
+                {
+                    if part != prev_part {
+                        part == prev_part + 1 && ring == 0 && coord == 0
+                    } else if ring != prev_ring {
+                        ring == prev_ring + 1 && coord == 0
+                    } else {
+                        coord_index.is_none() || coord == prev_coord + 1
+                    }
+                }
+                Some((prev_feature, ..)) if feature < prev_feature => false,
+                _ => part == 0 && ring == 0 && coord == 0,
+            };
+            if !is_ordered {
+                return Err(GeoArrowError::General(format!(
+                    "Coordinate table is not ordered by feature, part, ring and coordinate index \
+                     at row {}",
+                    row
+                )));
+            }
+
+            match prev {
+                Some((prev_feature, prev_part, prev_ring, _)) if feature == prev_feature => {
+                    let parts = &mut features.last_mut().unwrap().2;
+                    if part != prev_part {
+                        parts.push(vec![vec![]]);
+                    } else if ring != prev_ring {
+                        parts.last_mut().unwrap().push(vec![]);
+                    }
+                }
+                _ => features.push((feature, row, vec![vec![vec![]]])),
+            }
+            let rings = features.last_mut().unwrap().2.last_mut().unwrap();
+            rings.last_mut().unwrap().push(geo::coord! {
+                x: x.value(row),
+                y: y.value(row),
+            });
+            prev = Some((feature, part, ring, coord));
+        }
+
+        let num_rows = features
+            .last()
+            .map_or(0, |(feature, ..)| *feature as usize + 1);
+        let mut geoms: Vec<Option<geo::Geometry>> = vec![None; num_rows];
+        let mut take_indices: Vec<Option<u64>> = vec![None; num_rows];
+        for (feature, first_row, parts) in features {
+            let geometry = build_geometry(&geometry_type, parts).map_err(|err| {
+                GeoArrowError::General(format!(
+                    "Invalid {:?} at feature index {}: {}",
+                    geometry_type, feature, err
+                ))
+            })?;
+            geoms[feature as usize] = Some(geometry);
+            take_indices[feature as usize] = Some(first_row as u64);
+        }
+        let geometry = geometry_array(&geoms, geometry_type)?;
+
+        let take_indices = UInt64Array::from(take_indices);
+        let mut fields = vec![];
+        let mut columns = vec![];
+        for (field, column) in coord_table
+            .schema()
+            .fields()
+            .iter()
+            .zip(coord_table.columns())
+        {
+            if COORDINATE_COLUMNS.contains(&field.name().as_str()) {
+                continue;
+            }
+            fields.push(field.as_ref().clone().with_nullable(true));
+            columns.push(take(column, &take_indices, None)?);
+        }
+        fields.push(geometry.extension_field().as_ref().clone());
+        columns.push(geometry.to_array_ref());
+
+        let schema = Arc::new(Schema::new(fields));
+        let geometry_column_index = schema.fields().len() - 1;
+        let batch = RecordBatch::try_new(schema.clone(), columns)?;
+        Self::try_new(schema, vec![batch], geometry_column_index)
+    }
+}
+
+/// The columns of a coordinate table, built up geometry by geometry.
+#[derive(Debug, Default)]
+struct CoordinateColumns {
+    x: Vec<f64>,
+    y: Vec<f64>,
+    feature_index: Vec<u32>,
+    part_index: Vec<u32>,
+    ring_index: Vec<u32>,
+    coord_index: Vec<u32>,
+}
+
+impl CoordinateColumns {
+    fn push_coords(
+        &mut self,
+        feature: usize,
+        part: usize,
+        ring: usize,
+        coords: impl IntoIterator<Item = geo::Coord>,
+    ) -> Result<()> {
+        let (feature, part, ring) = (to_index(feature)?, to_index(part)?, to_index(ring)?);
+        for (i, coord) in coords.into_iter().enumerate() {
+            self.x.push(coord.x);
+            self.y.push(coord.y);
+            self.feature_index.push(feature);
+            self.part_index.push(part);
+            self.ring_index.push(ring);
+            self.coord_index.push(to_index(i)?);
+        }
+        Ok(())
+    }
+
+    fn push_polygon(&mut self, feature: usize, part: usize, polygon: &geo::Polygon) -> Result<()> {
+        self.push_coords(feature, part, 0, polygon.exterior().0.iter().copied())?;
+        for (i, interior) in polygon.interiors().iter().enumerate() {
+            self.push_coords(feature, part, i + 1, interior.0.iter().copied())?;
+        }
+        Ok(())
+    }
+
+    fn push_geometry(&mut self, feature: usize, geometry: &geo::Geometry) -> Result<()> {
+        match geometry {
+            geo::Geometry::Point(point) => {
+                // Empty points are stored with NaN coordinates
+                if !(point.x().is_nan() && point.y().is_nan()) {
+                    self.push_coords(feature, 0, 0, [point.0])?;
+                }
+            }
+            geo::Geometry::Line(line) => self.push_coords(feature, 0, 0, [line.start, line.end])?,
+            geo::Geometry::LineString(line_string) => {
+                self.push_coords(feature, 0, 0, line_string.0.iter().copied())?
+            }
+            geo::Geometry::Polygon(polygon) => self.push_polygon(feature, 0, polygon)?,
+            geo::Geometry::Rect(rect) => self.push_polygon(feature, 0, &rect.to_polygon())?,
+            geo::Geometry::Triangle(triangle) => {
+                self.push_polygon(feature, 0, &triangle.to_polygon())?
+            }
+            geo::Geometry::MultiPoint(multi_point) => {
+                for (part, point) in multi_point.iter().enumerate() {
+                    self.push_coords(feature, part, 0, [point.0])?;
+                }
+            }
+            geo::Geometry::MultiLineString(multi_line_string) => {
+                for (part, line_string) in multi_line_string.iter().enumerate() {
+                    self.push_coords(feature, part, 0, line_string.0.iter().copied())?;
+                }
+            }
+            geo::Geometry::MultiPolygon(multi_polygon) => {
+                for (part, polygon) in multi_polygon.iter().enumerate() {
+                    self.push_polygon(feature, part, polygon)?;
+                }
+            }
+            geo::Geometry::GeometryCollection(_) => {
+                return Err(GeoArrowError::NotYetImplemented(format!(
+                    "Geometry collections can't be converted to a coordinate table, found one \
+                     at row {}",
+                    feature
+                )))
+            }
+        }
+        Ok(())
+    }
+}
+
+fn to_index(index: usize) -> Result<u32> {
+    u32::try_from(index).map_err(|_| {
+        GeoArrowError::General(format!(
+            "Index {} doesn't fit in a coordinate table index column",
+            index
+        ))
+    })
+}
+
+pub(super) fn chunk_to_geo(chunk: &dyn GeometryArrayTrait) -> Vec<Option<geo::Geometry>> {
+    macro_rules! iter_geo {
+        ($array:expr) => {
+            $array
+                .iter_geo()
+                .map(|maybe_geometry| maybe_geometry.map(geo::Geometry::from))
+                .collect()
+        };
+    }
+
+    match chunk.data_type() {
+        GeoDataType::Point(_) => iter_geo!(chunk.as_point()),
+        GeoDataType::LineString(_) => iter_geo!(chunk.as_line_string()),
+        GeoDataType::LargeLineString(_) => iter_geo!(chunk.as_large_line_string()),
+        GeoDataType::Polygon(_) => iter_geo!(chunk.as_polygon()),
+        GeoDataType::LargePolygon(_) => iter_geo!(chunk.as_large_polygon()),
+        GeoDataType::MultiPoint(_) => iter_geo!(chunk.as_multi_point()),
+        GeoDataType::LargeMultiPoint(_) => iter_geo!(chunk.as_large_multi_point()),
+        GeoDataType::MultiLineString(_) => iter_geo!(chunk.as_multi_line_string()),
+        GeoDataType::LargeMultiLineString(_) => iter_geo!(chunk.as_large_multi_line_string()),
+        GeoDataType::MultiPolygon(_) => iter_geo!(chunk.as_multi_polygon()),
+        GeoDataType::LargeMultiPolygon(_) => iter_geo!(chunk.as_large_multi_polygon()),
+        GeoDataType::Mixed(_) => iter_geo!(chunk.as_mixed()),
+        GeoDataType::LargeMixed(_) => iter_geo!(chunk.as_large_mixed()),
+        GeoDataType::GeometryCollection(_) => iter_geo!(chunk.as_geometry_collection()),
+        GeoDataType::LargeGeometryCollection(_) => {
+            iter_geo!(chunk.as_large_geometry_collection())
+        }
+        GeoDataType::Rect => iter_geo!(chunk.as_rect()),
+        GeoDataType::WKB => iter_geo!(chunk.as_wkb()),
+        GeoDataType::LargeWKB => iter_geo!(chunk.as_large_wkb()),
+    }
+}
+
+fn float_column(coord_table: &RecordBatch, name: &str) -> Result<Float64Array> {
+    let column = coord_table
+        .column_by_name(name)
+        .ok_or_else(|| missing_column(name))?;
+    let column = cast(column, &DataType::Float64)?;
+    if column.null_count() > 0 {
+        return Err(GeoArrowError::General(format!(
+            "Column '{}' of the coordinate table has nulls",
+            name
+        )));
+    }
+    Ok(column.as_primitive::<Float64Type>().clone())
+}
+
+fn missing_column(name: &str) -> GeoArrowError {
+    GeoArrowError::General(format!("Coordinate table has no '{}' column", name))
+}
+
+/// An index column cast to `u32`, or `None` if the column is missing.
+fn index_column(coord_table: &RecordBatch, name: &str) -> Result<Option<UInt32Array>> {
+    let Some(column) = coord_table.column_by_name(name) else {
+        return Ok(None);
+    };
+    // Negative and fractional indices become null
+    let column = cast(column, &DataType::UInt32)?;
+    if column.null_count() > 0 {
+        return Err(GeoArrowError::General(format!(
+            "Column '{}' of the coordinate table must only have non-negative integers",
+            name
+        )));
+    }
+    Ok(Some(column.as_primitive::<UInt32Type>().clone()))
+}
+
+fn is_closed(ring: &[geo::Coord]) -> bool {
+    ring.first() == ring.last()
+}
+
+fn build_polygon(mut rings: Vec<Vec<geo::Coord>>) -> std::result::Result<geo::Polygon, String> {
+    if let Some(ring_index) = rings.iter().position(|ring| !is_closed(ring)) {
+        return Err(format!("ring {} is not closed", ring_index));
+    }
+    let exterior = rings.remove(0);
+    Ok(geo::Polygon::new(
+        exterior.into(),
+        rings.into_iter().map(geo::LineString::from).collect(),
+    ))
+}
+
+/// Build a geometry of the kind of `geometry_type` from coordinates grouped by part and ring.
+fn build_geometry(
+    geometry_type: &GeoDataType,
+    parts: Vec<Vec<Vec<geo::Coord>>>,
+) -> std::result::Result<geo::Geometry, String> {
+    use GeoDataType::*;
+
+    let single_ring = |rings: Vec<Vec<geo::Coord>>| match <[_; 1]>::try_from(rings) {
+        Ok([ring]) => Ok(ring),
+        Err(rings) => Err(format!("expected a single ring, found {}", rings.len())),
+    };
+    let single_coord = |ring: Vec<geo::Coord>| match <[_; 1]>::try_from(ring) {
+        Ok([coord]) => Ok(coord),
+        Err(ring) => Err(format!(
+            "expected a single coordinate, found {}",
+            ring.len()
+        )),
+    };
+    let single_part = |parts: Vec<Vec<Vec<geo::Coord>>>| match <[_; 1]>::try_from(parts) {
+        Ok([part]) => Ok(part),
+        Err(parts) => Err(format!("expected a single part, found {}", parts.len())),
+    };
+
+    let geometry: geo::Geometry = match geometry_type {
+        Point(_) => geo::Point(single_coord(single_ring(single_part(parts)?)?)?).into(),
+        LineString(_) | LargeLineString(_) => {
+            geo::LineString::new(single_ring(single_part(parts)?)?).into()
+        }
+        Polygon(_) | LargePolygon(_) => build_polygon(single_part(parts)?)?.into(),
+        MultiPoint(_) | LargeMultiPoint(_) => geo::MultiPoint::new(
+            parts
+                .into_iter()
+                .map(|rings| Ok(geo::Point(single_coord(single_ring(rings)?)?)))
+                .collect::<std::result::Result<_, String>>()?,
+        )
+        .into(),
+        MultiLineString(_) | LargeMultiLineString(_) => geo::MultiLineString::new(
+            parts
+                .into_iter()
+                .map(|rings| Ok(geo::LineString::new(single_ring(rings)?)))
+                .collect::<std::result::Result<_, String>>()?,
+        )
+        .into(),
+        MultiPolygon(_) | LargeMultiPolygon(_) => geo::MultiPolygon::new(
+            parts
+                .into_iter()
+                .map(build_polygon)
+                .collect::<std::result::Result<_, String>>()?,
+        )
+        .into(),
+        t => return Err(format!("can't rebuild geometries of type {:?}", t)),
+    };
+    Ok(geometry)
+}
+
+fn geometry_array(
+    geoms: &[Option<geo::Geometry>],
+    geometry_type: GeoDataType,
+) -> Result<Arc<dyn GeometryArrayTrait>> {
+    use GeoDataType::*;
+
+    macro_rules! push_geoms {
+        ($builder:expr) => {{
+            let mut builder = $builder;
+            for geom in geoms.iter() {
+                builder.push_geometry(geom.as_ref())?;
+            }
+            Ok(Arc::new(builder.finish()))
+        }};
+    }
+
+    let metadata = Default::default();
+    match geometry_type {
+        Point(coord_type) => push_geoms!(PointBuilder::new_with_options(coord_type, metadata)),
+        LineString(coord_type) => push_geoms!(LineStringBuilder::<i32>::new_with_options(
+            coord_type, metadata
+        )),
+        LargeLineString(coord_type) => push_geoms!(LineStringBuilder::<i64>::new_with_options(
+            coord_type, metadata
+        )),
+        Polygon(coord_type) => push_geoms!(PolygonBuilder::<i32>::new_with_options(
+            coord_type, metadata
+        )),
+        LargePolygon(coord_type) => push_geoms!(PolygonBuilder::<i64>::new_with_options(
+            coord_type, metadata
+        )),
+        MultiPoint(coord_type) => push_geoms!(MultiPointBuilder::<i32>::new_with_options(
+            coord_type, metadata
+        )),
+        LargeMultiPoint(coord_type) => push_geoms!(MultiPointBuilder::<i64>::new_with_options(
+            coord_type, metadata
+        )),
+        MultiLineString(coord_type) => push_geoms!(
+            MultiLineStringBuilder::<i32>::new_with_options(coord_type, metadata)
+        ),
+        LargeMultiLineString(coord_type) => push_geoms!(
+            MultiLineStringBuilder::<i64>::new_with_options(coord_type, metadata)
+        ),
+        MultiPolygon(coord_type) => push_geoms!(MultiPolygonBuilder::<i32>::new_with_options(
+            coord_type, metadata
+        )),
+        LargeMultiPolygon(coord_type) => push_geoms!(MultiPolygonBuilder::<i64>::new_with_options(
+            coord_type, metadata
+        )),
+        t => Err(GeoArrowError::General(format!(
+            "Can't rebuild geometries of type {:?} from a coordinate table",
+            t
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::{Int32Array, StringArray};
+
+    use super::*;
+    use crate::array::AsChunkedGeometryArray;
+
+    fn table(geometry: Arc<dyn GeometryArrayTrait>) -> GeoTable {
+        let ids = Int32Array::from_iter_values(0..geometry.len() as i32);
+        let schema = Arc::new(Schema::new(vec![
+            Arc::new(Field::new("id", DataType::Int32, false)),
+            geometry.extension_field(),
+        ]));
+        let batch =
+            RecordBatch::try_new(schema.clone(), vec![Arc::new(ids), geometry.to_array_ref()])
+                .unwrap();
+        GeoTable::try_new(schema, vec![batch], 1).unwrap()
+    }
+
+    #[test]
+    fn round_trip_polygon_with_holes() {
+        let polygon = geo::polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [
+                [(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.)],
+                [(x: 5., y: 5.), (x: 6., y: 5.), (x: 6., y: 6.)],
+            ],
+        );
+        let square = geo::polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        let polygons: PolygonArray<i32> = vec![Some(polygon), None, Some(square)].into();
+        let table = table(Arc::new(polygons.clone()));
+
+        let coord_table = table.to_coordinate_table(1, true).unwrap();
+        // 5 + 4 + 4 coordinates for the first polygon, and 5 for the square
+        assert_eq!(coord_table.num_rows(), 18);
+        assert_eq!(coord_table.num_columns(), 7);
+        let ring_index = coord_table.column(4).as_primitive::<UInt32Type>();
+        assert_eq!(ring_index.value(5), 1);
+        assert_eq!(ring_index.value(9), 2);
+        let ids = coord_table
+            .column(6)
+            .as_primitive::<arrow_array::types::Int32Type>();
+        assert_eq!(ids.value(17), 2);
+
+        let rebuilt =
+            GeoTable::from_coordinate_table(&coord_table, GeoDataType::Polygon(Default::default()))
+                .unwrap();
+        assert_eq!(rebuilt.len(), 3);
+        assert_eq!(rebuilt.geometry_column_index(), 1);
+        let geometry = rebuilt.geometry().unwrap();
+        let rebuilt_polygons = &geometry.as_ref().as_polygon().chunks()[0];
+        assert_eq!(
+            rebuilt_polygons.iter_geo().collect::<Vec<_>>(),
+            polygons.iter_geo().collect::<Vec<_>>()
+        );
+        let ids = rebuilt.batches()[0].column(0);
+        assert!(ids.is_null(1));
+    }
+
+    #[test]
+    fn round_trip_multi_line_string() {
+        let multi_line_strings: MultiLineStringArray<i32> = vec![
+            geo::MultiLineString::new(vec![
+                geo::line_string![(x: 0., y: 0.), (x: 1., y: 1.)],
+                geo::line_string![(x: 2., y: 2.), (x: 3., y: 3.), (x: 4., y: 2.)],
+            ]),
+            geo::MultiLineString::new(vec![geo::line_string![(x: 5., y: 5.), (x: 6., y: 6.)]]),
+        ]
+        .as_slice()
+        .into();
+        let table = table(Arc::new(multi_line_strings.clone()));
+
+        let coord_table = table.to_coordinate_table(1, false).unwrap();
+        assert_eq!(coord_table.num_rows(), 7);
+        assert_eq!(coord_table.num_columns(), 6);
+        let part_index = coord_table.column(3).as_primitive::<UInt32Type>();
+        assert_eq!(part_index.values().to_vec(), vec![0, 0, 1, 1, 1, 0, 0]);
+
+        let rebuilt = GeoTable::from_coordinate_table(
+            &coord_table,
+            GeoDataType::LargeMultiLineString(Default::default()),
+        )
+        .unwrap();
+        let geometry = rebuilt.geometry().unwrap();
+        let rebuilt_multi_line_strings =
+            &geometry.as_ref().as_large_multi_line_string().chunks()[0];
+        assert_eq!(
+            rebuilt_multi_line_strings.iter_geo().collect::<Vec<_>>(),
+            multi_line_strings.iter_geo().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn invalid_coordinate_tables() {
+        let coord_table = |x: Vec<f64>, y: Vec<f64>, coord_index: Vec<u32>| {
+            let len = x.len();
+            RecordBatch::try_from_iter(vec![
+                ("x", Arc::new(Float64Array::from(x)) as ArrayRef),
+                ("y", Arc::new(Float64Array::from(y)) as ArrayRef),
+                ("feature_index", Arc::new(UInt32Array::from(vec![0; len]))),
+                ("coord_index", Arc::new(UInt32Array::from(coord_index))),
+                ("name", Arc::new(StringArray::from(vec!["a"; len]))),
+            ])
+            .unwrap()
+        };
+
+        // An unclosed ring
+        let unclosed = coord_table(vec![0., 1., 1.], vec![0., 0., 1.], vec![0, 1, 2]);
+        let polygon_type = GeoDataType::Polygon(Default::default());
+        let err = GeoTable::from_coordinate_table(&unclosed, polygon_type).unwrap_err();
+        assert!(err.to_string().contains("ring 0 is not closed"), "{}", err);
+
+        // The same coordinates are a valid line string
+        let line_string_type = GeoDataType::LineString(Default::default());
+        let rebuilt = GeoTable::from_coordinate_table(&unclosed, line_string_type).unwrap();
+        assert_eq!(rebuilt.len(), 1);
+        assert_eq!(rebuilt.schema().field(0).name(), "name");
+
+        // Coordinates out of order
+        let unordered = coord_table(vec![0., 1., 1.], vec![0., 0., 1.], vec![0, 2, 1]);
+        let err = GeoTable::from_coordinate_table(&unordered, line_string_type).unwrap_err();
+        assert!(err.to_string().contains("at row 1"), "{}", err);
+    }
+}
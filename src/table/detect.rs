@@ -0,0 +1,202 @@
+//! Detecting a geometry column without GeoArrow extension metadata.
+
+use std::str::FromStr;
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::{OffsetSizeTrait, RecordBatch};
+use arrow_schema::{DataType, Schema, SchemaRef};
+use wkt::Wkt;
+
+use crate::datatypes::GeoDataType;
+use crate::error::Result;
+use crate::table::{tagged_geometry_column_index, GeoTable};
+
+/// The names of binary columns that are read as WKB by geometry detection.
+const WKB_COLUMN_NAMES: [&str; 3] = ["geometry", "geom", "wkb_geometry"];
+
+/// Options for [`GeoTable::from_arrow_with_options`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TableOptions {
+    /// Whether to look for a geometry column without GeoArrow extension metadata if no geometry
+    /// column index is given and no field has a GeoArrow extension name, as is common in Parquet
+    /// and IPC files written by other tools. Defaults to `false`.
+    ///
+    /// The first column that is either a `Binary` or `LargeBinary` column named `geometry`,
+    /// `geom` or `wkb_geometry`, which is read as WKB, or a `Utf8` or `LargeUtf8` column whose
+    /// first non-null value is valid WKT, which is read as WKT, is used as the geometry column.
+    pub geometry_detection: bool,
+}
+
+impl TableOptions {
+    /// Set whether to look for a geometry column without GeoArrow extension metadata.
+    pub fn with_geometry_detection(mut self, geometry_detection: bool) -> Self {
+        self.geometry_detection = geometry_detection;
+        self
+    }
+}
+
+impl GeoTable {
+    /// Create a table from record batches as with [`from_arrow`][Self::from_arrow], with
+    /// `options`.
+    ///
+    /// With [`geometry_detection`][TableOptions::geometry_detection], a detected geometry column
+    /// is tagged with the `geoarrow.wkb` or `geoarrow.wkt` extension name and then parsed like any
+    /// other WKB or WKT column. Returns an error if there is no tagged geometry column and none is
+    /// detected.
+    pub fn from_arrow_with_options(
+        batches: Vec<RecordBatch>,
+        schema: SchemaRef,
+        geometry_column_index: Option<usize>,
+        target_geo_data_type: Option<GeoDataType>,
+        options: &TableOptions,
+    ) -> Result<Self> {
+        if options.geometry_detection
+            && geometry_column_index.is_none()
+            && tagged_geometry_column_index(&schema).is_none()
+        {
+            if let Some((index, extension_name)) = detect_geometry_column(&schema, &batches) {
+                let (schema, batches) = tag_column(schema, batches, index, extension_name)?;
+                return Self::from_arrow(batches, schema, Some(index), target_geo_data_type);
+            }
+        }
+
+        Self::from_arrow(batches, schema, geometry_column_index, target_geo_data_type)
+    }
+}
+
+/// The index and extension name of the first untagged column that looks like a geometry column.
+fn detect_geometry_column(
+    schema: &Schema,
+    batches: &[RecordBatch],
+) -> Option<(usize, &'static str)> {
+    schema
+        .fields()
+        .iter()
+        .enumerate()
+        .find_map(|(index, field)| match field.data_type() {
+            DataType::Binary | DataType::LargeBinary
+                if WKB_COLUMN_NAMES.contains(&field.name().as_str()) =>
+            {
+                Some((index, "geoarrow.wkb"))
+            }
+            DataType::Utf8 if first_value_is_wkt::<i32>(batches, index) => {
+                Some((index, "geoarrow.wkt"))
+            }
+            DataType::LargeUtf8 if first_value_is_wkt::<i64>(batches, index) => {
+                Some((index, "geoarrow.wkt"))
+            }
+            _ => None,
+        })
+}
+
+/// Whether the first non-null value of the string column at `index` is valid WKT.
+fn first_value_is_wkt<O: OffsetSizeTrait>(batches: &[RecordBatch], index: usize) -> bool {
+    batches
+        .iter()
+        .flat_map(|batch| batch.column(index).as_string::<O>().iter())
+        .flatten()
+        .next()
+        .is_some_and(|value| Wkt::<f64>::from_str(value).is_ok())
+}
+
+/// Add the extension name `extension_name` to the field at `index`.
+fn tag_column(
+    schema: SchemaRef,
+    batches: Vec<RecordBatch>,
+    index: usize,
+    extension_name: &str,
+) -> Result<(SchemaRef, Vec<RecordBatch>)> {
+    let mut fields = schema.fields().to_vec();
+    let mut metadata = fields[index].metadata().clone();
+    metadata.insert(
+        "ARROW:extension:name".to_string(),
+        extension_name.to_string(),
+    );
+    fields[index] = Arc::new(fields[index].as_ref().clone().with_metadata(metadata));
+    let schema = Arc::new(Schema::new_with_metadata(fields, schema.metadata().clone()));
+
+    let batches = batches
+        .into_iter()
+        .map(|batch| {
+            Ok(RecordBatch::try_new(
+                schema.clone(),
+                batch.columns().to_vec(),
+            )?)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok((schema, batches))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::AsChunkedGeometryArray;
+    use crate::io::wkb::to_wkb;
+    use crate::test::point::{p1, point_array};
+    use crate::trait_::GeometryArrayAccessor;
+    use crate::GeometryArrayTrait;
+    use arrow_array::{ArrayRef, StringArray, UInt8Array};
+    use arrow_schema::Field;
+
+    fn untagged_table(geometry_field: Field, geometry: ArrayRef) -> (SchemaRef, RecordBatch) {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::UInt8, true),
+            geometry_field,
+        ]));
+        let ids = Arc::new(UInt8Array::from(vec![1, 2, 3]));
+        let batch = RecordBatch::try_new(schema.clone(), vec![ids, geometry]).unwrap();
+        (schema, batch)
+    }
+
+    #[test]
+    fn detect_wkb_column() {
+        let wkb = to_wkb::<i32>(&point_array()).into_array_ref();
+        let field = Field::new("geometry", DataType::Binary, true);
+        let (schema, batch) = untagged_table(field, wkb);
+
+        // Without detection this is an error rather than a panic
+        let result = GeoTable::from_arrow(vec![batch.clone()], schema.clone(), None, None);
+        assert!(result.is_err());
+
+        let options = TableOptions::default().with_geometry_detection(true);
+        let table =
+            GeoTable::from_arrow_with_options(vec![batch], schema, None, None, &options).unwrap();
+        assert!(matches!(
+            table.geometry_data_type().unwrap(),
+            GeoDataType::Point(_)
+        ));
+        let geometry = table.geometry().unwrap();
+        assert_eq!(
+            geometry.as_ref().as_point().chunks()[0].value_as_geo(1),
+            p1()
+        );
+    }
+
+    #[test]
+    fn detect_wkt_column() {
+        let wkt = StringArray::from(vec!["POINT (0 1)", "POINT (1 2)", "POINT (2 3)"]);
+        let field = Field::new("location", DataType::Utf8, true);
+        let (schema, batch) = untagged_table(field, Arc::new(wkt));
+
+        let options = TableOptions::default().with_geometry_detection(true);
+        let table =
+            GeoTable::from_arrow_with_options(vec![batch], schema, None, None, &options).unwrap();
+        let geometry = table.geometry().unwrap();
+        assert_eq!(
+            geometry.as_ref().as_point().chunks()[0].value_as_geo(1),
+            p1()
+        );
+    }
+
+    #[test]
+    fn no_detected_column() {
+        let names = StringArray::from(vec!["a", "b", "c"]);
+        let field = Field::new("name", DataType::Utf8, true);
+        let (schema, batch) = untagged_table(field, Arc::new(names));
+
+        let options = TableOptions::default().with_geometry_detection(true);
+        let result = GeoTable::from_arrow_with_options(vec![batch], schema, None, None, &options);
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,460 @@
+//! Comparing two versions of the same dataset.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow::compute::{concat, interleave};
+use arrow::row::{Row, RowConverter, Rows, SortField};
+use arrow_array::builder::{ListBuilder, StringBuilder};
+use arrow_array::{new_empty_array, Array, ArrayRef, RecordBatch, StringArray, UInt64Array};
+use arrow_cast::display::array_value_to_string;
+use arrow_schema::{DataType, Field, Schema};
+
+use crate::algorithm::native::equals_exact;
+use crate::error::{GeoArrowError, Result};
+use crate::table::coordinates::chunk_to_geo;
+use crate::table::GeoTable;
+
+/// A row whose attributes differ between two versions of a table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttributeChange {
+    /// The index of the row in the old table.
+    pub old_row: usize,
+
+    /// The index of the row in the new table.
+    pub new_row: usize,
+
+    /// The names of the attribute columns whose values differ, in the order of the columns of the
+    /// old table.
+    pub columns: Vec<String>,
+}
+
+/// The differences between two versions of a table, as returned by [`GeoTable::diff`].
+///
+/// Row indices are positions in the whole table, across its record batches. A row whose geometry
+/// and attributes both changed is in both `geometry_changed` and `attributes_changed`.
+#[derive(Debug, Clone)]
+pub struct DiffResult {
+    /// Rows with the same geometry and attributes in both tables, as pairs of the old and new
+    /// row index.
+    pub unchanged: Vec<(usize, usize)>,
+
+    /// Rows whose geometry differs by more than the tolerance, as pairs of the old and new row
+    /// index.
+    pub geometry_changed: Vec<(usize, usize)>,
+
+    /// Rows whose attributes differ.
+    pub attributes_changed: Vec<AttributeChange>,
+
+    /// Rows of the new table whose key isn't in the old table.
+    pub added: Vec<usize>,
+
+    /// Rows of the old table whose key isn't in the new table.
+    pub removed: Vec<usize>,
+
+    /// One row per key, with the key column, a `status` column of `unchanged`, `changed`,
+    /// `added` or `removed`, the `old_row` and `new_row` indices, and a `changed_columns` list
+    /// naming the changed columns, including the geometry column. The rows of the old table come
+    /// first, in order, followed by the added rows.
+    pub summary: RecordBatch,
+}
+
+impl GeoTable {
+    /// Compare this table with a newer version of the same dataset, matching rows by the value of
+    /// `key_column`.
+    ///
+    /// Matched rows are compared by their geometry, using [`equals_exact`] with
+    /// `geometry_tolerance`, and by the values of every other column. Both tables must have the
+    /// same attribute columns, with the same types, but they may be in a different order.
+    ///
+    /// Returns an error if a key is null or appears more than once in either table.
+    pub fn diff(
+        &self,
+        other: &GeoTable,
+        key_column: &str,
+        geometry_tolerance: f64,
+    ) -> Result<DiffResult> {
+        let old_key_index = self.schema().index_of(key_column)?;
+        let new_key_index = other.schema().index_of(key_column)?;
+        let old_keys = concat_column(self, old_key_index)?;
+        let new_keys = concat_column(other, new_key_index)?;
+        if old_keys.data_type() != new_keys.data_type() {
+            return Err(GeoArrowError::General(format!(
+                "Key column {} has type {} in the old table and {} in the new table",
+                key_column,
+                old_keys.data_type(),
+                new_keys.data_type()
+            )));
+        }
+
+        let attribute_columns = attribute_columns(self, other, old_key_index)?;
+
+        let key_converter = RowConverter::new(vec![SortField::new(old_keys.data_type().clone())])?;
+        let old_key_rows = key_converter.convert_columns(&[old_keys.clone()])?;
+        let new_key_rows = key_converter.convert_columns(&[new_keys.clone()])?;
+        let old_lookup = key_lookup(&old_keys, &old_key_rows, key_column, "old")?;
+        let new_lookup = key_lookup(&new_keys, &new_key_rows, key_column, "new")?;
+
+        let old_geometry = geometries(self)?;
+        let new_geometry = geometries(other)?;
+
+        let mut attribute_rows = Vec::with_capacity(attribute_columns.len());
+        for (name, old_index, new_index) in &attribute_columns {
+            let old_column = concat_column(self, *old_index)?;
+            let new_column = concat_column(other, *new_index)?;
+            let converter =
+                RowConverter::new(vec![SortField::new(old_column.data_type().clone())])?;
+            attribute_rows.push((
+                name.as_str(),
+                converter.convert_columns(&[old_column])?,
+                converter.convert_columns(&[new_column])?,
+            ));
+        }
+
+        let geometry_name = self.schema().field(self.geometry_column_index()).name();
+        let mut summary = SummaryBuilder::default();
+        let mut unchanged = vec![];
+        let mut geometry_changed = vec![];
+        let mut attributes_changed = vec![];
+        let mut removed = vec![];
+
+        for old_row in 0..old_keys.len() {
+            let Some(&new_row) = new_lookup.get(&old_key_rows.row(old_row)) else {
+                removed.push(old_row);
+                summary.push("removed", Some(old_row), None, None);
+                continue;
+            };
+
+            let moved = match (&old_geometry[old_row], &new_geometry[new_row]) {
+                (Some(old), Some(new)) => !equals_exact(old, new, geometry_tolerance),
+                (None, None) => false,
+                _ => true,
+            };
+            let columns = attribute_rows
+                .iter()
+                .filter(|(_, old, new)| old.row(old_row) != new.row(new_row))
+                .map(|(name, _, _)| name.to_string())
+                .collect::<Vec<_>>();
+
+            let mut changed_columns = columns.clone();
+            if moved {
+                geometry_changed.push((old_row, new_row));
+                changed_columns.insert(0, geometry_name.clone());
+            }
+            if !columns.is_empty() {
+                attributes_changed.push(AttributeChange {
+                    old_row,
+                    new_row,
+                    columns,
+                });
+            }
+            let status = if changed_columns.is_empty() {
+                unchanged.push((old_row, new_row));
+                "unchanged"
+            } else {
+                "changed"
+            };
+            summary.push(status, Some(old_row), Some(new_row), Some(changed_columns));
+        }
+
+        let added = (0..new_keys.len())
+            .filter(|new_row| !old_lookup.contains_key(&new_key_rows.row(*new_row)))
+            .collect::<Vec<_>>();
+        for new_row in &added {
+            summary.push("added", None, Some(*new_row), None);
+        }
+
+        Ok(DiffResult {
+            unchanged,
+            geometry_changed,
+            attributes_changed,
+            added,
+            removed,
+            summary: summary.finish(key_column, &old_keys, &new_keys)?,
+        })
+    }
+}
+
+/// The values of a column across all record batches of a table.
+pub(super) fn concat_column(table: &GeoTable, index: usize) -> Result<ArrayRef> {
+    let chunks = table
+        .batches()
+        .iter()
+        .map(|batch| batch.column(index).as_ref())
+        .collect::<Vec<_>>();
+    if chunks.is_empty() {
+        Ok(new_empty_array(table.schema().field(index).data_type()))
+    } else {
+        Ok(concat(&chunks)?)
+    }
+}
+
+/// The geometries of the geometry column of a table, across all record batches.
+pub(super) fn geometries(table: &GeoTable) -> Result<Vec<Option<geo::Geometry>>> {
+    let geometry = table.geometry()?;
+    Ok(geometry
+        .geometry_chunks()
+        .into_iter()
+        .flat_map(chunk_to_geo)
+        .collect())
+}
+
+/// Match the attribute columns of the two tables by name, returning the name and the index in
+/// each table of every column other than the key and geometry columns.
+fn attribute_columns(
+    old: &GeoTable,
+    new: &GeoTable,
+    key_index: usize,
+) -> Result<Vec<(String, usize, usize)>> {
+    let is_attribute = |table: &GeoTable, index: usize, key: &str| {
+        index != table.geometry_column_index() && table.schema().field(index).name() != key
+    };
+    let key_column = old.schema().field(key_index).name();
+
+    let mut columns = vec![];
+    for (old_index, field) in old.schema().fields().iter().enumerate() {
+        if !is_attribute(old, old_index, key_column) {
+            continue;
+        }
+        let new_index = new
+            .schema()
+            .index_of(field.name())
+            .ok()
+            .filter(|new_index| is_attribute(new, *new_index, key_column))
+            .ok_or_else(|| {
+                GeoArrowError::General(format!(
+                    "Column {} of the old table is missing from the new table",
+                    field.name()
+                ))
+            })?;
+        let new_type = new.schema().field(new_index).data_type();
+        if new_type != field.data_type() {
+            return Err(GeoArrowError::General(format!(
+                "Column {} has type {} in the old table and {} in the new table",
+                field.name(),
+                field.data_type(),
+                new_type
+            )));
+        }
+        columns.push((field.name().clone(), old_index, new_index));
+    }
+
+    let num_new_attributes = (0..new.num_columns())
+        .filter(|index| is_attribute(new, *index, key_column))
+        .count();
+    if num_new_attributes != columns.len() {
+        return Err(GeoArrowError::General(
+            "The new table has columns that are missing from the old table".to_string(),
+        ));
+    }
+    Ok(columns)
+}
+
+/// Map each key to its row, checking that keys are unique and not null.
+fn key_lookup<'a>(
+    keys: &ArrayRef,
+    rows: &'a Rows,
+    key_column: &str,
+    table: &str,
+) -> Result<HashMap<Row<'a>, usize>> {
+    let mut lookup = HashMap::with_capacity(keys.len());
+    for row in 0..keys.len() {
+        if keys.is_null(row) {
+            return Err(GeoArrowError::General(format!(
+                "Null key in column {} of the {} table at row {}",
+                key_column, table, row
+            )));
+        }
+        if let Some(previous) = lookup.insert(rows.row(row), row) {
+            return Err(GeoArrowError::General(format!(
+                "Duplicate key {} in column {} of the {} table at rows {} and {}",
+                array_value_to_string(keys, row)?,
+                key_column,
+                table,
+                previous,
+                row
+            )));
+        }
+    }
+    Ok(lookup)
+}
+
+#[derive(Default)]
+struct SummaryBuilder {
+    /// The source of the key of each row: 0 for the old table and 1 for the new table.
+    key_indices: Vec<(usize, usize)>,
+    status: Vec<&'static str>,
+    old_rows: Vec<Option<u64>>,
+    new_rows: Vec<Option<u64>>,
+    changed_columns: ListBuilder<StringBuilder>,
+}
+
+impl SummaryBuilder {
+    fn push(
+        &mut self,
+        status: &'static str,
+        old_row: Option<usize>,
+        new_row: Option<usize>,
+        changed_columns: Option<Vec<String>>,
+    ) {
+        self.key_indices.push(match (old_row, new_row) {
+            (Some(old_row), _) => (0, old_row),
+            (None, new_row) => (1, new_row.unwrap()),
+        });
+        self.status.push(status);
+        self.old_rows.push(old_row.map(|row| row as u64));
+        self.new_rows.push(new_row.map(|row| row as u64));
+        self.changed_columns.append_option(
+            changed_columns.map(|columns| columns.into_iter().map(Some).collect::<Vec<_>>()),
+        );
+    }
+
+    fn finish(
+        mut self,
+        key_column: &str,
+        old_keys: &ArrayRef,
+        new_keys: &ArrayRef,
+    ) -> Result<RecordBatch> {
+        let keys = interleave(&[old_keys.as_ref(), new_keys.as_ref()], &self.key_indices)?;
+        let changed_columns = self.changed_columns.finish();
+        let schema = Schema::new(vec![
+            Field::new(key_column, keys.data_type().clone(), false),
+            Field::new("status", DataType::Utf8, false),
+            Field::new("old_row", DataType::UInt64, true),
+            Field::new("new_row", DataType::UInt64, true),
+            Field::new("changed_columns", changed_columns.data_type().clone(), true),
+        ]);
+        let columns: Vec<ArrayRef> = vec![
+            keys,
+            Arc::new(StringArray::from(self.status)),
+            Arc::new(UInt64Array::from(self.old_rows)),
+            Arc::new(UInt64Array::from(self.new_rows)),
+            Arc::new(changed_columns),
+        ];
+        Ok(RecordBatch::try_new(Arc::new(schema), columns)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Int32Type, UInt64Type};
+    use arrow_array::{Int32Array, StringArray};
+    use geo::point;
+
+    use super::*;
+    use crate::array::PointArray;
+    use crate::GeometryArrayTrait;
+
+    fn table(rows: &[(i32, &str, geo::Point)], batch_size: usize) -> GeoTable {
+        let geometry: PointArray = rows
+            .iter()
+            .map(|(_, _, point)| *point)
+            .collect::<Vec<_>>()
+            .as_slice()
+            .into();
+        let schema = Arc::new(Schema::new(vec![
+            Arc::new(Field::new("id", DataType::Int32, false)),
+            geometry.extension_field(),
+            Arc::new(Field::new("name", DataType::Utf8, true)),
+        ]));
+        let ids = Int32Array::from_iter_values(rows.iter().map(|(id, _, _)| *id));
+        let names = StringArray::from_iter_values(rows.iter().map(|(_, name, _)| *name));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(ids), geometry.to_array_ref(), Arc::new(names)],
+        )
+        .unwrap();
+        let batches = (0..batch.num_rows())
+            .step_by(batch_size)
+            .map(|offset| batch.slice(offset, batch_size.min(batch.num_rows() - offset)))
+            .collect();
+        GeoTable::try_new(schema, batches, 1).unwrap()
+    }
+
+    fn old_rows() -> Vec<(i32, &'static str, geo::Point)> {
+        vec![
+            (0, "a", point!(x: 0., y: 0.)),
+            (1, "b", point!(x: 1., y: 1.)),
+            (2, "c", point!(x: 2., y: 2.)),
+            (3, "d", point!(x: 3., y: 3.)),
+            (4, "e", point!(x: 4., y: 4.)),
+        ]
+    }
+
+    #[test]
+    fn classify_changes() {
+        let old = table(&old_rows(), 2);
+
+        let mut new_rows = old_rows();
+        // Move one geometry, nudge another within the tolerance, change one attribute, delete
+        // one row and add one row
+        new_rows[1].2 = point!(x: 1.5, y: 1.);
+        new_rows[4].2 = point!(x: 4.0001, y: 4.);
+        new_rows[2].1 = "changed";
+        new_rows.remove(3);
+        new_rows.push((5, "f", point!(x: 5., y: 5.)));
+        new_rows.reverse();
+        let new = table(&new_rows, 3);
+
+        let diff = old.diff(&new, "id", 0.001).unwrap();
+        assert_eq!(diff.unchanged, vec![(0, 4), (4, 1)]);
+        assert_eq!(diff.geometry_changed, vec![(1, 3)]);
+        assert_eq!(
+            diff.attributes_changed,
+            vec![AttributeChange {
+                old_row: 2,
+                new_row: 2,
+                columns: vec!["name".to_string()]
+            }]
+        );
+        assert_eq!(diff.removed, vec![3]);
+        assert_eq!(diff.added, vec![0]);
+
+        let summary = &diff.summary;
+        assert_eq!(summary.num_rows(), 6);
+        let ids = summary.column(0).as_primitive::<Int32Type>();
+        assert_eq!(ids.values().to_vec(), vec![0, 1, 2, 3, 4, 5]);
+        let status = summary.column(1).as_string::<i32>();
+        assert_eq!(
+            status.iter().flatten().collect::<Vec<_>>(),
+            vec![
+                "unchanged",
+                "changed",
+                "changed",
+                "removed",
+                "unchanged",
+                "added"
+            ]
+        );
+        let new_row = summary.column(3).as_primitive::<UInt64Type>();
+        assert_eq!(
+            new_row.iter().collect::<Vec<_>>(),
+            vec![Some(4), Some(3), Some(2), None, Some(1), Some(0)]
+        );
+        let changed_columns = summary.column(4).as_list::<i32>();
+        assert_eq!(
+            changed_columns.value(1).as_string::<i32>().value(0),
+            "geometry"
+        );
+        assert_eq!(changed_columns.value(2).as_string::<i32>().value(0), "name");
+        assert!(changed_columns.value(0).is_empty());
+        assert!(changed_columns.is_null(3));
+
+        let diff = old.diff(&new, "id", 0.).unwrap();
+        assert_eq!(diff.geometry_changed, vec![(1, 3), (4, 1)]);
+    }
+
+    #[test]
+    fn duplicate_keys() {
+        let mut rows = old_rows();
+        rows[4].0 = 1;
+        let duplicated = table(&rows, 5);
+        let err = duplicated
+            .diff(&table(&old_rows(), 5), "id", 0.)
+            .unwrap_err();
+        assert!(err.to_string().contains("Duplicate key 1"), "{}", err);
+        assert!(table(&old_rows(), 5).diff(&duplicated, "id", 0.).is_err());
+        assert!(duplicated.diff(&duplicated, "missing", 0.).is_err());
+    }
+}
@@ -0,0 +1,176 @@
+//! Merging the geometries of rows that share the value of a column.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow::compute::take;
+use arrow::row::{RowConverter, SortField};
+use arrow_array::{RecordBatch, UInt64Array};
+use arrow_schema::Schema;
+
+use crate::algorithm::geo::unary_union;
+use crate::array::MultiPolygonBuilder;
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::table::coordinates::chunk_to_geo;
+use crate::table::diff::concat_column;
+use crate::table::GeoTable;
+use crate::GeometryArrayTrait;
+
+impl GeoTable {
+    /// Group the rows of the table by the values of the column `by`, and union the polygons of
+    /// each group into a single MultiPolygon, as with
+    /// [`UnaryUnion`][crate::algorithm::geo::UnaryUnion].
+    ///
+    /// The result has one row per group, in the order in which the groups first appear, with the
+    /// `by` column followed by a MultiPolygon geometry column with the metadata of the geometry
+    /// column of this table. Other columns are dropped. Rows with a null value of `by` form a
+    /// group of their own, and null geometries are skipped.
+    ///
+    /// The geometry column must contain polygons or multi polygons. Invalid polygons return an
+    /// error with their row index.
+    pub fn dissolve(&self, by: &str) -> Result<GeoTable> {
+        match self.geometry_data_type()? {
+            GeoDataType::Polygon(_)
+            | GeoDataType::LargePolygon(_)
+            | GeoDataType::MultiPolygon(_)
+            | GeoDataType::LargeMultiPolygon(_) => {}
+            data_type => {
+                return Err(GeoArrowError::IncorrectType(
+                    format!("Cannot dissolve {:?} geometries", data_type).into(),
+                ))
+            }
+        }
+
+        let by_index = self.schema().index_of(by)?;
+        let keys = concat_column(self, by_index)?;
+        let converter = RowConverter::new(vec![SortField::new(keys.data_type().clone())])?;
+        let key_rows = converter.convert_columns(&[keys.clone()])?;
+
+        let geometry = self.geometry()?;
+        let chunks = geometry.geometry_chunks();
+        let mut group_lookup = HashMap::new();
+        let mut first_rows = vec![];
+        let mut groups: Vec<Vec<(usize, Option<geo::MultiPolygon>)>> = vec![];
+        for (row, maybe_geometry) in chunks
+            .iter()
+            .flat_map(|chunk| chunk_to_geo(*chunk))
+            .enumerate()
+        {
+            let maybe_multi_polygon = match maybe_geometry {
+                Some(geo::Geometry::Polygon(polygon)) => Some(polygon.into()),
+                Some(geo::Geometry::MultiPolygon(multi_polygon)) => Some(multi_polygon),
+                _ => None,
+            };
+            let group = *group_lookup.entry(key_rows.row(row)).or_insert_with(|| {
+                first_rows.push(row as u64);
+                groups.push(vec![]);
+                groups.len() - 1
+            });
+            groups[group].push((row, maybe_multi_polygon));
+        }
+
+        let dissolved = groups
+            .into_iter()
+            .map(|group| unary_union(group).map(Some))
+            .collect::<Result<Vec<_>>>()?;
+        let (coord_type, metadata) = chunks
+            .first()
+            .map(|chunk| (Some(chunk.coord_type()), chunk.metadata()))
+            .unwrap_or_default();
+        let dissolved = MultiPolygonBuilder::<i32>::from_nullable_multi_polygons(
+            &dissolved, coord_type, metadata,
+        )
+        .finish();
+
+        let keys = take(keys.as_ref(), &UInt64Array::from(first_rows), None)?;
+        let schema = Arc::new(Schema::new(vec![
+            Arc::new(self.schema().field(by_index).clone()),
+            dissolved.extension_field(),
+        ]));
+        let batch = RecordBatch::try_new(schema.clone(), vec![keys, dissolved.into_array_ref()])?;
+        GeoTable::try_new(schema, vec![batch], 1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::cast::AsArray;
+    use arrow_array::StringArray;
+    use arrow_schema::{DataType, Field};
+    use geo::{polygon, Area};
+
+    use super::*;
+    use crate::array::{AsChunkedGeometryArray, PolygonBuilder};
+    use crate::test::point;
+    use crate::trait_::GeometryArrayAccessor;
+
+    fn square(x: f64, y: f64) -> geo::Polygon {
+        polygon![(x: x, y: y), (x: x + 1., y: y), (x: x + 1., y: y + 1.), (x: x, y: y + 1.)]
+    }
+
+    fn table(rows: Vec<(Option<&str>, Option<geo::Polygon>)>) -> GeoTable {
+        let (regions, polygons): (Vec<_>, Vec<_>) = rows.into_iter().unzip();
+        let geometry = PolygonBuilder::<i32>::from_nullable_polygons(
+            &polygons,
+            Default::default(),
+            Default::default(),
+        )
+        .finish();
+        let schema = Arc::new(Schema::new(vec![
+            Arc::new(Field::new("region", DataType::Utf8, true)),
+            geometry.extension_field(),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(StringArray::from(regions)),
+                geometry.into_array_ref(),
+            ],
+        )
+        .unwrap();
+        GeoTable::try_new(schema, vec![batch], 1).unwrap()
+    }
+
+    #[test]
+    fn dissolve_by_region() {
+        let table = table(vec![
+            (Some("a"), Some(square(0., 0.))),
+            (Some("b"), Some(square(5., 5.))),
+            (None, Some(square(10., 10.))),
+            (Some("a"), Some(square(1., 0.))),
+            (Some("b"), None),
+        ]);
+        let dissolved = table.dissolve("region").unwrap();
+        assert_eq!(dissolved.len(), 3);
+        assert_eq!(dissolved.geometry_column_index(), 1);
+
+        let regions = dissolved.batches()[0].column(0).as_string::<i32>();
+        assert_eq!(
+            regions.iter().collect::<Vec<_>>(),
+            vec![Some("a"), Some("b"), None]
+        );
+
+        let geometry = dissolved.geometry().unwrap();
+        let geometry = geometry.as_ref().as_multi_polygon();
+        let areas = geometry.chunks()[0]
+            .iter_geo_values()
+            .map(|multi_polygon| multi_polygon.unsigned_area())
+            .collect::<Vec<_>>();
+        assert_eq!(areas, vec![2., 1., 1.]);
+        assert_eq!(geometry.chunks()[0].value_as_geo(0).0.len(), 1);
+    }
+
+    #[test]
+    fn dissolve_errors() {
+        let invalid = table(vec![
+            (Some("a"), Some(square(0., 0.))),
+            (Some("a"), Some(polygon![(x: 0., y: 0.), (x: 1., y: 1.)])),
+        ]);
+        let err = invalid.dissolve("region").unwrap_err();
+        assert!(err.to_string().contains("row 1"), "{}", err);
+
+        assert!(invalid.dissolve("missing").is_err());
+        assert!(point::table().dissolve("string").is_err());
+    }
+}
@@ -0,0 +1,398 @@
+//! Extent metadata of a geometry column, for catalog entries such as STAC items and assets.
+
+use std::collections::BTreeSet;
+
+use arrow::compute::{cast, max, min};
+use arrow_array::cast::AsArray;
+use arrow_array::types::TimestampMicrosecondType;
+use arrow_schema::{DataType, TimeUnit};
+use chrono::{DateTime, SecondsFormat};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+
+use crate::algorithm::native::bounding_rect::BoundingRect;
+use crate::algorithm::native::type_id::TypeIds;
+use crate::algorithm::native::TotalBounds;
+use crate::array::metadata::ArrayMetadata;
+use crate::chunked_array::from_arrow_chunks;
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::table::{GeoTable, GEOARROW_EXTENSION_NAMES};
+use crate::GeometryArrayTrait;
+
+/// The temporal extent of a table, as RFC 3339 timestamps in UTC.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TemporalExtent {
+    pub start: String,
+    pub end: String,
+}
+
+/// The extent of a geometry column, as returned by [`GeoTable::extent_metadata`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExtentMetadata {
+    /// The name of the geometry column.
+    pub column: String,
+
+    /// The number of non-null geometries.
+    pub count: usize,
+
+    /// The bounding box of the column, as `[minx, miny, maxx, maxy]`, or `None` if the column has
+    /// no non-empty geometries.
+    ///
+    /// Geometry arrays are 2D, so this never holds the six values of a 3D bounding box.
+    pub bbox: Option<Vec<f64>>,
+
+    /// The sorted, unique geometry types of the non-null geometries, named as in GeoParquet, such
+    /// as `"Polygon"`.
+    pub geometry_types: Vec<String>,
+
+    /// The `authority:code` identifier of the CRS of the column, such as `"EPSG:4326"`, if the CRS
+    /// has one.
+    pub crs_id: Option<String>,
+
+    /// The CRS of the column, usually PROJJSON.
+    pub crs: Option<Value>,
+
+    /// The range of the timestamp column passed to [`GeoTable::extent_metadata`], or `None` if no
+    /// timestamp column was given or it only holds nulls.
+    pub temporal: Option<TemporalExtent>,
+}
+
+impl ExtentMetadata {
+    /// Format as the fields of a STAC asset, using the
+    /// [projection extension](https://github.com/stac-extensions/projection) for the CRS and
+    /// bounding box in that CRS.
+    ///
+    /// An `extent` object in the form of a STAC collection extent is included as well. STAC
+    /// extents are in WGS84, so its spatial bounding box is only included when the CRS is
+    /// missing, `OGC:CRS84` or `EPSG:4326`.
+    pub fn to_stac_asset_json(&self) -> Value {
+        let mut fields = Map::new();
+        fields.insert("proj:code".to_string(), json!(self.crs_id));
+        if let Some(epsg) = self
+            .crs_id
+            .as_ref()
+            .and_then(|crs_id| crs_id.strip_prefix("EPSG:"))
+            .and_then(|code| code.parse::<u32>().ok())
+        {
+            fields.insert("proj:epsg".to_string(), json!(epsg));
+        }
+        if let Some(crs @ Value::Object(_)) = &self.crs {
+            fields.insert("proj:projjson".to_string(), crs.clone());
+        }
+        if let Some(bbox) = &self.bbox {
+            fields.insert("proj:bbox".to_string(), json!(bbox));
+        }
+
+        let mut extent = Map::new();
+        let is_wgs84 = match (&self.crs_id, &self.crs) {
+            (Some(crs_id), _) => crs_id == "OGC:CRS84" || crs_id == "EPSG:4326",
+            (None, crs) => crs.is_none(),
+        };
+        if let Some(bbox) = self.bbox.as_ref().filter(|_| is_wgs84) {
+            extent.insert("spatial".to_string(), json!({ "bbox": [bbox] }));
+        }
+        if let Some(temporal) = &self.temporal {
+            extent.insert(
+                "temporal".to_string(),
+                json!({ "interval": [[temporal.start, temporal.end]] }),
+            );
+        }
+        if !extent.is_empty() {
+            fields.insert("extent".to_string(), Value::Object(extent));
+        }
+
+        Value::Object(fields)
+    }
+}
+
+impl GeoTable {
+    /// Compute the extent of a geometry column, for publishing catalog entries.
+    ///
+    /// `geometry_column_index` may be the index of any geometry column of the table, including
+    /// WKB columns. If `timestamp_column_index` is given, it must be a timestamp or date column,
+    /// and its minimum and maximum are reported as the temporal extent.
+    pub fn extent_metadata(
+        &self,
+        geometry_column_index: usize,
+        timestamp_column_index: Option<usize>,
+    ) -> Result<ExtentMetadata> {
+        let field = self.schema().field(geometry_column_index);
+        let chunks = self
+            .batches()
+            .iter()
+            .map(|batch| batch.column(geometry_column_index).as_ref())
+            .collect::<Vec<_>>();
+        let geometry = from_arrow_chunks(&chunks, field)?;
+        let metadata = ArrayMetadata::try_from(field)?;
+
+        let bounds = geometry.as_ref().total_bounds();
+        let bbox = (bounds.minx() <= bounds.maxx())
+            .then(|| vec![bounds.minx(), bounds.miny(), bounds.maxx(), bounds.maxy()]);
+
+        let mut count = 0;
+        let mut geometry_types = BTreeSet::new();
+        for chunk in geometry.geometry_chunks() {
+            count += chunk.len() - chunk.null_count();
+            geometry_types.extend(chunk_geometry_types(chunk));
+        }
+
+        let temporal = timestamp_column_index
+            .map(|column_index| self.temporal_extent(column_index))
+            .transpose()?
+            .flatten();
+
+        Ok(ExtentMetadata {
+            column: field.name().clone(),
+            count,
+            bbox,
+            geometry_types: geometry_types.into_iter().map(String::from).collect(),
+            crs_id: metadata.crs.as_ref().and_then(crs_id),
+            crs: metadata.crs,
+            temporal,
+        })
+    }
+
+    /// The bounding box of all geometry columns of the table, as `[minx, miny, maxx, maxy]`, or
+    /// `None` if every geometry is null or empty.
+    ///
+    /// Every column with a GeoArrow extension type is included, including WKB columns. WKT
+    /// columns must be parsed first with
+    /// [`parse_geometry_to_native`][Self::parse_geometry_to_native].
+    pub fn total_bounds(&self) -> Result<Option<[f64; 4]>> {
+        let mut bounds = BoundingRect::new();
+        let geometry_columns = self
+            .schema()
+            .fields()
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| {
+                field
+                    .metadata()
+                    .get("ARROW:extension:name")
+                    .is_some_and(|name| GEOARROW_EXTENSION_NAMES.contains(name.as_str()))
+            });
+        for (column_index, field) in geometry_columns {
+            let chunks = self
+                .batches()
+                .iter()
+                .map(|batch| batch.column(column_index).as_ref())
+                .collect::<Vec<_>>();
+            let geometry = from_arrow_chunks(&chunks, field)?;
+            bounds = bounds + geometry.as_ref().total_bounds();
+        }
+        Ok((bounds.minx() <= bounds.maxx())
+            .then(|| [bounds.minx(), bounds.miny(), bounds.maxx(), bounds.maxy()]))
+    }
+
+    fn temporal_extent(&self, column_index: usize) -> Result<Option<TemporalExtent>> {
+        let field = self.schema().field(column_index);
+        if !matches!(
+            field.data_type(),
+            DataType::Timestamp(_, _) | DataType::Date32 | DataType::Date64
+        ) {
+            return Err(GeoArrowError::General(format!(
+                "Expected column {} to be a timestamp or date column, got {}",
+                field.name(),
+                field.data_type()
+            )));
+        }
+
+        // Timestamps without a timezone are interpreted as UTC
+        let utc = DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into()));
+        let mut range: Option<(i64, i64)> = None;
+        for batch in self.batches() {
+            let timestamps = cast(batch.column(column_index).as_ref(), &utc)?;
+            let timestamps = timestamps.as_primitive::<TimestampMicrosecondType>();
+            if let (Some(batch_min), Some(batch_max)) = (min(timestamps), max(timestamps)) {
+                range = Some(match range {
+                    Some((start, end)) => (start.min(batch_min), end.max(batch_max)),
+                    None => (batch_min, batch_max),
+                });
+            }
+        }
+
+        range
+            .map(|(start, end)| {
+                Ok(TemporalExtent {
+                    start: format_timestamp(start)?,
+                    end: format_timestamp(end)?,
+                })
+            })
+            .transpose()
+    }
+}
+
+fn format_timestamp(micros: i64) -> Result<String> {
+    let secs = micros.div_euclid(1_000_000);
+    let nanos = (micros.rem_euclid(1_000_000) * 1_000) as u32;
+    let timestamp = DateTime::from_timestamp(secs, nanos)
+        .ok_or_else(|| GeoArrowError::General(format!("Timestamp {} is out of range", micros)))?;
+    Ok(timestamp.to_rfc3339_opts(SecondsFormat::AutoSi, true))
+}
+
+/// The `authority:code` identifier of a PROJJSON CRS.
+fn crs_id(crs: &Value) -> Option<String> {
+    let id = crs.get("id")?;
+    let authority = id.get("authority")?.as_str()?;
+    match id.get("code")? {
+        Value::String(code) => Some(format!("{}:{}", authority, code)),
+        Value::Number(code) => Some(format!("{}:{}", authority, code)),
+        _ => None,
+    }
+}
+
+/// The GeoParquet names of the geometry types of the non-null geometries of a chunk.
+fn chunk_geometry_types(chunk: &dyn GeometryArrayTrait) -> Vec<&'static str> {
+    use GeoDataType::*;
+
+    if chunk.null_count() == chunk.len() {
+        return vec![];
+    }
+    let type_ids = match chunk.data_type() {
+        Mixed(_) => chunk.as_mixed().get_unique_type_ids(),
+        LargeMixed(_) => chunk.as_large_mixed().get_unique_type_ids(),
+        WKB => chunk.as_wkb().get_unique_type_ids(),
+        LargeWKB => chunk.as_large_wkb().get_unique_type_ids(),
+        Point(_) => [0].into(),
+        LineString(_) | LargeLineString(_) => [1].into(),
+        Polygon(_) | LargePolygon(_) | Rect => [3].into(),
+        MultiPoint(_) | LargeMultiPoint(_) => [4].into(),
+        MultiLineString(_) | LargeMultiLineString(_) => [5].into(),
+        MultiPolygon(_) | LargeMultiPolygon(_) => [6].into(),
+        GeometryCollection(_) | LargeGeometryCollection(_) => [7].into(),
+    };
+    type_ids
+        .into_iter()
+        .filter_map(|type_id| match type_id {
+            0 => Some("Point"),
+            1 => Some("LineString"),
+            3 => Some("Polygon"),
+            4 => Some("MultiPoint"),
+            5 => Some("MultiLineString"),
+            6 => Some("MultiPolygon"),
+            7 => Some("GeometryCollection"),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arrow_array::{Array, RecordBatch, TimestampMillisecondArray};
+    use arrow_schema::{Field, Schema};
+
+    use super::*;
+    use crate::array::{MixedGeometryArray, PolygonArray};
+    use crate::test::{point, polygon};
+
+    fn table() -> GeoTable {
+        let polygon_array: PolygonArray<i32> =
+            vec![Some(polygon::p0()), None, Some(polygon::p1())].into();
+        let geoms: Vec<geo::Geometry> =
+            vec![point::p0().into(), polygon::p0().into(), point::p2().into()];
+        let mixed_array = MixedGeometryArray::<i32>::try_from(geoms.as_slice()).unwrap();
+        let timestamps = TimestampMillisecondArray::from(vec![
+            Some(1_700_000_000_000),
+            None,
+            Some(1_600_000_000_500),
+        ]);
+        let schema = Arc::new(Schema::new(vec![
+            polygon_array.extension_field().as_ref().clone(),
+            mixed_array
+                .extension_field()
+                .as_ref()
+                .clone()
+                .with_name("mixed"),
+            Field::new("time", timestamps.data_type().clone(), true),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                polygon_array.into_array_ref(),
+                mixed_array.into_array_ref(),
+                Arc::new(timestamps),
+            ],
+        )
+        .unwrap();
+        GeoTable::try_new(schema, vec![batch], 0).unwrap()
+    }
+
+    #[test]
+    fn extent_matches_kernels() {
+        let table = table();
+        let extent = table.extent_metadata(0, Some(2)).unwrap();
+        let bounds = table.geometry().unwrap().as_ref().total_bounds();
+        assert_eq!(
+            extent.bbox,
+            Some(vec![
+                bounds.minx(),
+                bounds.miny(),
+                bounds.maxx(),
+                bounds.maxy()
+            ])
+        );
+        assert_eq!(extent.count, 2);
+        assert_eq!(extent.geometry_types, vec!["Polygon"]);
+        assert_eq!(extent.crs_id, None);
+        assert_eq!(
+            extent.temporal,
+            Some(TemporalExtent {
+                start: "2020-09-13T12:26:40.500Z".to_string(),
+                end: "2023-11-14T22:13:20Z".to_string(),
+            })
+        );
+
+        let extent = table.extent_metadata(1, None).unwrap();
+        assert_eq!(extent.count, 3);
+        assert_eq!(extent.geometry_types, vec!["Point", "Polygon"]);
+        assert_eq!(extent.temporal, None);
+
+        assert!(table.extent_metadata(0, Some(1)).is_err());
+    }
+
+    #[test]
+    fn total_bounds_of_all_columns() {
+        let table = table();
+        let polygon_bbox = table.extent_metadata(0, None).unwrap().bbox.unwrap();
+        let mixed_bbox = table.extent_metadata(1, None).unwrap().bbox.unwrap();
+        let expected = [
+            polygon_bbox[0].min(mixed_bbox[0]),
+            polygon_bbox[1].min(mixed_bbox[1]),
+            polygon_bbox[2].max(mixed_bbox[2]),
+            polygon_bbox[3].max(mixed_bbox[3]),
+        ];
+        assert_eq!(table.total_bounds().unwrap(), Some(expected));
+
+        let polygon_array: PolygonArray<i32> = vec![None::<geo::Polygon>].into();
+        let schema = Arc::new(Schema::new(vec![polygon_array.extension_field()]));
+        let batch =
+            RecordBatch::try_new(schema.clone(), vec![polygon_array.into_array_ref()]).unwrap();
+        let table = GeoTable::try_new(schema, vec![batch], 0).unwrap();
+        assert_eq!(table.total_bounds().unwrap(), None);
+    }
+
+    #[test]
+    fn stac_asset_json() {
+        let mut extent = table().extent_metadata(0, Some(2)).unwrap();
+        extent.crs = Some(json!({"id": {"authority": "EPSG", "code": 4326}}));
+        extent.crs_id = extent.crs.as_ref().and_then(crs_id);
+
+        let asset = extent.to_stac_asset_json();
+        assert_eq!(asset["proj:code"], "EPSG:4326");
+        assert_eq!(asset["proj:epsg"], 4326);
+        assert_eq!(asset["proj:bbox"], json!(extent.bbox));
+        assert_eq!(asset["extent"]["spatial"]["bbox"][0], json!(extent.bbox));
+        assert_eq!(
+            asset["extent"]["temporal"]["interval"][0][1],
+            "2023-11-14T22:13:20Z"
+        );
+
+        extent.crs = Some(json!({"id": {"authority": "EPSG", "code": 32618}}));
+        extent.crs_id = extent.crs.as_ref().and_then(crs_id);
+        let asset = extent.to_stac_asset_json();
+        assert!(asset["extent"].get("spatial").is_none());
+    }
+}
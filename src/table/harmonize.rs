@@ -0,0 +1,218 @@
+//! Checking the schemas of the batches of a table, and casting them to the table schema.
+
+use arrow::compute::cast;
+use arrow_array::RecordBatch;
+use arrow_schema::{Schema, SchemaRef};
+
+use crate::error::{GeoArrowError, Result};
+use crate::table::GeoTable;
+
+impl GeoTable {
+    /// Create a table as with [`try_new`][Self::try_new], but require the fields of each batch,
+    /// including their metadata and nullability, to be identical to the fields of `schema`.
+    pub fn try_new_strict(
+        schema: SchemaRef,
+        batches: Vec<RecordBatch>,
+        geometry_column_index: usize,
+    ) -> Result<Self> {
+        check_batches(&schema, &batches, geometry_column_index, true)?;
+        Self::try_new(schema, batches, geometry_column_index)
+    }
+
+    /// Create a table as with [`try_new`][Self::try_new], after rewrapping each batch with
+    /// `schema`.
+    ///
+    /// Each column whose data type differs from that of its field, such as a `Utf8` column for a
+    /// `LargeUtf8` field or a list with other child field names, is cast to the data type of the
+    /// field. The batches then have the fields of `schema`, including the extension metadata of
+    /// the geometry column, so they are identical to the fields of the table.
+    ///
+    /// Returns an error if a batch doesn't have as many columns as `schema`, if a column can't
+    /// be cast, or if a column has nulls where its field isn't nullable.
+    pub fn try_new_with_harmonize(
+        schema: SchemaRef,
+        batches: Vec<RecordBatch>,
+        geometry_column_index: usize,
+    ) -> Result<Self> {
+        let batches = batches
+            .into_iter()
+            .enumerate()
+            .map(|(i, batch)| harmonize_batch(&schema, &batch, i))
+            .collect::<Result<Vec<_>>>()?;
+        Self::try_new(schema, batches, geometry_column_index)
+    }
+}
+
+/// Check that the fields of each batch are compatible with the fields of `schema`, or identical
+/// to them if `strict`.
+pub(super) fn check_batches(
+    schema: &Schema,
+    batches: &[RecordBatch],
+    geometry_column_index: usize,
+    strict: bool,
+) -> Result<()> {
+    let fields = schema.fields();
+    if geometry_column_index >= fields.len() {
+        return Err(GeoArrowError::General(format!(
+            "Geometry column index {} out of bounds for a table with {} columns",
+            geometry_column_index,
+            fields.len()
+        )));
+    }
+
+    for (i, batch) in batches.iter().enumerate() {
+        let batch_schema = batch.schema();
+        let batch_fields = batch_schema.fields();
+        if batch_fields.len() != fields.len() {
+            return Err(GeoArrowError::General(format!(
+                "Batch {} has {} columns, but the table has {} columns",
+                i,
+                batch_fields.len(),
+                fields.len()
+            )));
+        }
+
+        for (field, batch_field) in fields.iter().zip(batch_fields) {
+            if strict {
+                if field != batch_field {
+                    return Err(GeoArrowError::General(format!(
+                        "Field {:?} of batch {} is not identical to field {:?} of the table",
+                        batch_field, i, field
+                    )));
+                }
+                continue;
+            }
+
+            if field.name() != batch_field.name() {
+                return Err(GeoArrowError::General(format!(
+                    "Batch {} has a column named {} where the table has a column named {}",
+                    i,
+                    batch_field.name(),
+                    field.name()
+                )));
+            }
+            if !field.data_type().equals_datatype(batch_field.data_type()) {
+                return Err(GeoArrowError::General(format!(
+                    "Column {} of batch {} has data type {}, but the table field has data type {}",
+                    field.name(),
+                    i,
+                    batch_field.data_type(),
+                    field.data_type()
+                )));
+            }
+            if !field.is_nullable() && batch_field.is_nullable() {
+                return Err(GeoArrowError::General(format!(
+                    "Column {} of batch {} is nullable, but the table field is not",
+                    field.name(),
+                    i
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Rewrap the columns of the batch at index `i` with `schema`, casting them if needed.
+fn harmonize_batch(schema: &SchemaRef, batch: &RecordBatch, i: usize) -> Result<RecordBatch> {
+    if batch.num_columns() != schema.fields().len() {
+        return Err(GeoArrowError::General(format!(
+            "Batch {} has {} columns, but the table has {} columns",
+            i,
+            batch.num_columns(),
+            schema.fields().len()
+        )));
+    }
+
+    let columns = schema
+        .fields()
+        .iter()
+        .zip(batch.columns())
+        .map(|(field, column)| {
+            if column.data_type() == field.data_type() {
+                Ok(column.clone())
+            } else {
+                Ok(cast(column, field.data_type())?)
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(RecordBatch::try_new(schema.clone(), columns)?)
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::test::point;
+    use arrow_array::{ArrayRef, LargeStringArray, StringArray, UInt8Array};
+    use arrow_schema::{DataType, Field};
+
+    /// The point table, with batches whose fields have no metadata and aren't nullable.
+    fn plain_batches(table: &GeoTable) -> Vec<RecordBatch> {
+        let fields = table
+            .schema()
+            .fields()
+            .iter()
+            .map(|field| {
+                field
+                    .as_ref()
+                    .clone()
+                    .with_metadata(HashMap::new())
+                    .with_nullable(false)
+            })
+            .collect::<Vec<_>>();
+        let schema = Arc::new(Schema::new(fields));
+        table
+            .batches()
+            .iter()
+            .map(|batch| RecordBatch::try_new(schema.clone(), batch.columns().to_vec()).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn relaxed_schema_comparison() {
+        let table = point::table();
+        let batches = plain_batches(&table);
+
+        let relaxed = GeoTable::try_new(table.schema().clone(), batches.clone(), 2).unwrap();
+        assert!(relaxed.geometry().is_ok());
+        assert!(GeoTable::try_new_strict(table.schema().clone(), batches.clone(), 2).is_err());
+        assert!(
+            GeoTable::try_new_strict(table.schema().clone(), table.batches().clone(), 2).is_ok()
+        );
+
+        // Harmonizing rewraps the batches with the table schema and its extension metadata
+        let harmonized =
+            GeoTable::try_new_with_harmonize(table.schema().clone(), batches, 2).unwrap();
+        assert_eq!(harmonized, table);
+
+        assert!(GeoTable::try_new(table.schema().clone(), table.batches().clone(), 3).is_err());
+    }
+
+    #[test]
+    fn harmonize_data_types() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::UInt8, true),
+            Field::new("name", DataType::LargeUtf8, true),
+        ]));
+        let batch_schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::UInt8, true),
+            Field::new("name", DataType::Utf8, true),
+        ]));
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(UInt8Array::from(vec![1, 2])),
+            Arc::new(StringArray::from(vec!["a", "b"])),
+        ];
+        let batch = RecordBatch::try_new(batch_schema, columns).unwrap();
+
+        assert!(GeoTable::try_new(schema.clone(), vec![batch.clone()], 0).is_err());
+        let table = GeoTable::try_new_with_harmonize(schema.clone(), vec![batch], 0).unwrap();
+        assert_eq!(table.batches()[0].schema(), schema);
+        let names = table.batches()[0].column(1);
+        assert_eq!(
+            names.as_any().downcast_ref::<LargeStringArray>().unwrap(),
+            &LargeStringArray::from(vec!["a", "b"])
+        );
+    }
+}
@@ -0,0 +1,373 @@
+//! Joining the rows of two tables by the spatial relationship of their geometries.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use arrow::compute::take;
+use arrow_array::{RecordBatch, UInt32Array};
+use arrow_schema::Schema;
+use geo::{BoundingRect, Contains, Intersects};
+
+use crate::algorithm::geo_index::{NearestSearch, SpatialIndex};
+use crate::error::{GeoArrowError, Result};
+use crate::table::diff::{concat_column, geometries};
+use crate::table::GeoTable;
+
+/// The relationship between a left and a right geometry under which their rows are joined by
+/// [`GeoTable::spatial_join`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpatialPredicate {
+    /// The geometries share at least one point.
+    Intersects,
+
+    /// The left geometry contains the right geometry.
+    Contains,
+
+    /// The left geometry is within the right geometry.
+    Within,
+}
+
+impl SpatialPredicate {
+    fn evaluate(&self, left: &geo::Geometry, right: &geo::Geometry) -> bool {
+        match self {
+            SpatialPredicate::Intersects => left.intersects(right),
+            SpatialPredicate::Contains => left.contains(right),
+            SpatialPredicate::Within => right.contains(left),
+        }
+    }
+}
+
+/// The rows kept by [`GeoTable::spatial_join`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JoinType {
+    /// Keep only the pairs of rows that match.
+    #[default]
+    Inner,
+
+    /// Keep the pairs of rows that match, and every left row without a match, with nulls for the
+    /// columns of the right table.
+    Left,
+}
+
+impl GeoTable {
+    /// Join the rows of this table with the rows of `right` whose geometries satisfy `predicate`.
+    ///
+    /// The result has a row for each matching pair of rows, ordered by the index of the left row
+    /// and then of the right row. Its columns are those of this table, which keep their order
+    /// and the geometry column, followed by the columns of `right` other than its geometry
+    /// column. Columns of both tables with the same name get a `_left` or `_right` suffix, apart
+    /// from the geometry column of this table.
+    ///
+    /// The smaller table is indexed with a [`SpatialIndex`], the geometries of the other are used
+    /// to query it, and the candidates are refined with the exact predicate. Null and empty
+    /// geometries never match.
+    pub fn spatial_join(
+        &self,
+        right: &GeoTable,
+        predicate: SpatialPredicate,
+        how: JoinType,
+    ) -> Result<GeoTable> {
+        let left_geometries = geometries(self)?;
+        let right_geometries = geometries(right)?;
+
+        let mut pairs = vec![];
+        if right_geometries.len() <= left_geometries.len() {
+            let index = SpatialIndex::try_new_chunked(right.geometry()?.as_ref())?;
+            for (left_row, left_geometry) in left_geometries.iter().enumerate() {
+                let Some(left_geometry) = left_geometry else {
+                    continue;
+                };
+                for right_row in query(&index, left_geometry) {
+                    if let Some(right_geometry) = &right_geometries[right_row] {
+                        if predicate.evaluate(left_geometry, right_geometry) {
+                            pairs.push((left_row, right_row));
+                        }
+                    }
+                }
+            }
+        } else {
+            let index = SpatialIndex::try_new_chunked(self.geometry()?.as_ref())?;
+            for (right_row, right_geometry) in right_geometries.iter().enumerate() {
+                let Some(right_geometry) = right_geometry else {
+                    continue;
+                };
+                for left_row in query(&index, right_geometry) {
+                    if let Some(left_geometry) = &left_geometries[left_row] {
+                        if predicate.evaluate(left_geometry, right_geometry) {
+                            pairs.push((left_row, right_row));
+                        }
+                    }
+                }
+            }
+        }
+        pairs.sort_unstable();
+
+        join_pairs(self, right, pairs, how)
+    }
+
+    /// Join each row of this table with the row of `right` whose geometry is nearest to its
+    /// point, as with GeoPandas' `sjoin_nearest`.
+    ///
+    /// The geometry column of this table must hold points. Nearest geometries are found with
+    /// [`nearest`][crate::algorithm::geo_index::nearest], so distances are planar and a tie
+    /// between rows of `right` at the same distance resolves to the lower row. Rows whose point
+    /// is null, or further than `max_distance` from every geometry of `right`, have no match:
+    /// they are dropped by an inner join and get nulls for the columns of `right` under a left
+    /// join. The columns of the result are named as with [`spatial_join`][Self::spatial_join].
+    pub fn sjoin_nearest(
+        &self,
+        right: &GeoTable,
+        max_distance: Option<f64>,
+        how: JoinType,
+    ) -> Result<GeoTable> {
+        let right_geometry = right.geometry()?;
+        let search = NearestSearch::new(
+            SpatialIndex::try_new_chunked(right_geometry.as_ref())?,
+            geometries(right)?,
+            crate::algorithm::geo::BoundingRect::total_bounds(&right_geometry.as_ref()),
+        );
+
+        let mut pairs = vec![];
+        for (left_row, left_geometry) in geometries(self)?.into_iter().enumerate() {
+            let point = match left_geometry {
+                Some(geo::Geometry::Point(point)) => point,
+                None => continue,
+                Some(_) => {
+                    return Err(GeoArrowError::IncorrectType(
+                        format!("Row {} of the left table is not a point", left_row).into(),
+                    ))
+                }
+            };
+            if let Some((right_row, _)) = search.query(&point, 1, max_distance).first() {
+                pairs.push((left_row, *right_row));
+            }
+        }
+        join_pairs(self, right, pairs, how)
+    }
+}
+
+/// Build the table of the matching pairs of rows of `left` and `right`, which must be sorted, as
+/// described in [`GeoTable::spatial_join`].
+fn join_pairs(
+    left: &GeoTable,
+    right: &GeoTable,
+    pairs: Vec<(usize, usize)>,
+    how: JoinType,
+) -> Result<GeoTable> {
+    let mut left_indices = Vec::with_capacity(pairs.len());
+    let mut right_indices = Vec::with_capacity(pairs.len());
+    let mut pairs = pairs.into_iter().peekable();
+    for left_row in 0..left.len() {
+        let mut matched = false;
+        while let Some((_, right_row)) = pairs.next_if(|(row, _)| *row == left_row) {
+            left_indices.push(left_row as u32);
+            right_indices.push(Some(right_row as u32));
+            matched = true;
+        }
+        if !matched && how == JoinType::Left {
+            left_indices.push(left_row as u32);
+            right_indices.push(None);
+        }
+    }
+    let right_indices = UInt32Array::from(right_indices);
+
+    let left_taken = left.take(&UInt32Array::from(left_indices))?;
+    let right_geometry_index = right.geometry_column_index();
+    let right_columns = (0..right.schema().fields().len())
+        .filter(|index| *index != right_geometry_index)
+        .collect::<Vec<_>>();
+
+    let left_names = left
+        .schema()
+        .fields()
+        .iter()
+        .map(|field| field.name().as_str())
+        .collect::<HashSet<_>>();
+    let right_names = right_columns
+        .iter()
+        .map(|index| right.schema().field(*index).name().as_str())
+        .collect::<HashSet<_>>();
+
+    let mut fields = Vec::with_capacity(left.schema().fields().len() + right_columns.len());
+    for (index, field) in left.schema().fields().iter().enumerate() {
+        if index != left.geometry_column_index() && right_names.contains(field.name().as_str()) {
+            let name = format!("{}_left", field.name());
+            fields.push(Arc::new(field.as_ref().clone().with_name(name)));
+        } else {
+            fields.push(field.clone());
+        }
+    }
+    let mut columns = left_taken.batches()[0].columns().to_vec();
+    for index in right_columns {
+        let field = right.schema().field(index);
+        let mut field = field
+            .clone()
+            .with_nullable(field.is_nullable() || how == JoinType::Left);
+        if left_names.contains(field.name().as_str()) {
+            let name = format!("{}_right", field.name());
+            field = field.with_name(name);
+        }
+        fields.push(Arc::new(field));
+        columns.push(take(
+            concat_column(right, index)?.as_ref(),
+            &right_indices,
+            None,
+        )?);
+    }
+
+    let schema = Arc::new(Schema::new_with_metadata(
+        fields,
+        left.schema().metadata().clone(),
+    ));
+    let batch = RecordBatch::try_new(schema.clone(), columns)?;
+    GeoTable::try_new(schema, vec![batch], left.geometry_column_index())
+}
+
+/// The rows of the indexed geometry column whose bounding box intersects that of `geometry`.
+fn query(index: &SpatialIndex, geometry: &geo::Geometry) -> Vec<usize> {
+    geometry
+        .bounding_rect()
+        .map(|rect| index.query_bbox(&rect))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{PointArray, PolygonArray};
+    use crate::GeometryArrayTrait;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int32Type;
+    use arrow_array::{Int32Array, StringArray};
+    use arrow_schema::{DataType, Field};
+
+    fn points() -> GeoTable {
+        let points: PointArray = vec![
+            Some(geo::point!(x: 0.5, y: 0.5)),
+            Some(geo::point!(x: 1.5, y: 0.5)),
+            Some(geo::point!(x: 1.5, y: 1.5)),
+            Some(geo::point!(x: 5., y: 5.)),
+            None,
+            Some(geo::point!(x: 1., y: 0.5)),
+        ]
+        .into();
+        let ids = Int32Array::from(vec![0, 1, 2, 3, 4, 5]);
+        let schema = Arc::new(Schema::new(vec![
+            Arc::new(Field::new("id", DataType::Int32, false)),
+            points.extension_field(),
+        ]));
+        let batch =
+            RecordBatch::try_new(schema.clone(), vec![Arc::new(ids), points.into_array_ref()])
+                .unwrap();
+        GeoTable::try_new(schema, vec![batch], 1).unwrap()
+    }
+
+    fn squares() -> GeoTable {
+        let square = |x: f64| {
+            geo::polygon![
+                (x: x, y: 0.),
+                (x: x + 1., y: 0.),
+                (x: x + 1., y: 1.),
+                (x: x, y: 1.),
+                (x: x, y: 0.),
+            ]
+        };
+        let polygons: PolygonArray<i32> = vec![square(0.), square(1.)].as_slice().into();
+        let names = StringArray::from(vec!["a", "b"]);
+        let ids = Int32Array::from(vec![10, 11]);
+        let schema = Arc::new(Schema::new(vec![
+            polygons.extension_field(),
+            Arc::new(Field::new("name", DataType::Utf8, false)),
+            Arc::new(Field::new("id", DataType::Int32, false)),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![polygons.into_array_ref(), Arc::new(names), Arc::new(ids)],
+        )
+        .unwrap();
+        GeoTable::try_new(schema, vec![batch], 0).unwrap()
+    }
+
+    #[test]
+    fn points_within_polygons() {
+        let points = points();
+        let squares = squares();
+
+        let inner = points
+            .spatial_join(&squares, SpatialPredicate::Within, JoinType::Inner)
+            .unwrap();
+        // The point on the shared edge is within neither square
+        assert_eq!(inner.len(), 2);
+        let field_names = inner
+            .schema()
+            .fields()
+            .iter()
+            .map(|field| field.name().as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(field_names, vec!["id_left", "geometry", "name", "id_right"]);
+        let batch = &inner.batches()[0];
+        let left_ids = batch.column(0).as_primitive::<Int32Type>();
+        assert_eq!(left_ids.values().to_vec(), vec![0, 1]);
+        let names = batch.column(2).as_string::<i32>();
+        assert_eq!(names.value(0), "a");
+        assert_eq!(names.value(1), "b");
+
+        let intersects = points
+            .spatial_join(&squares, SpatialPredicate::Intersects, JoinType::Inner)
+            .unwrap();
+        // The point on the shared edge intersects both squares
+        assert_eq!(intersects.len(), 4);
+
+        let left = points
+            .spatial_join(&squares, SpatialPredicate::Within, JoinType::Left)
+            .unwrap();
+        assert_eq!(left.len(), 6);
+        let batch = &left.batches()[0];
+        let names = batch.column(2).as_string::<i32>();
+        assert_eq!(names.null_count(), 4);
+        assert!(left.schema().field(2).is_nullable());
+        assert_eq!(left.geometry().unwrap().null_count(), 1);
+    }
+
+    #[test]
+    fn polygons_contain_points() {
+        // The smaller table is on the left, so the squares are indexed and the points query them
+        let joined = squares()
+            .spatial_join(&points(), SpatialPredicate::Contains, JoinType::Inner)
+            .unwrap();
+        assert_eq!(joined.len(), 2);
+        let batch = &joined.batches()[0];
+        let right_ids = batch.column(3).as_primitive::<Int32Type>();
+        assert_eq!(right_ids.values().to_vec(), vec![0, 1]);
+    }
+
+    #[test]
+    fn nearest_polygons() {
+        let points = points();
+        let squares = squares();
+
+        let inner = points
+            .sjoin_nearest(&squares, None, JoinType::Inner)
+            .unwrap();
+        assert_eq!(inner.len(), 5);
+        let batch = &inner.batches()[0];
+        let names = batch.column(2).as_string::<i32>();
+        // The point on the shared edge is at distance zero from both squares
+        let names = names.iter().map(Option::unwrap).collect::<Vec<_>>();
+        assert_eq!(names, vec!["a", "b", "b", "b", "a"]);
+
+        let left = points
+            .sjoin_nearest(&squares, Some(1.), JoinType::Left)
+            .unwrap();
+        assert_eq!(left.len(), 6);
+        let batch = &left.batches()[0];
+        let names = batch.column(2).as_string::<i32>();
+        assert!(names.is_null(3));
+        assert!(names.is_null(4));
+        assert_eq!(names.null_count(), 2);
+
+        assert!(squares
+            .sjoin_nearest(&points, None, JoinType::Inner)
+            .is_err());
+    }
+}
@@ -0,0 +1,119 @@
+//! Extracting the entries of a map column to their own columns.
+
+use std::sync::Arc;
+
+use arrow_array::builder::StringBuilder;
+use arrow_array::cast::AsArray;
+use arrow_array::{Array, ArrayRef, MapArray, RecordBatch, StringArray};
+use arrow_schema::{DataType, Field, Schema};
+
+use crate::error::{GeoArrowError, Result};
+use crate::table::GeoTable;
+
+impl GeoTable {
+    /// Extract the values of `keys` from the `Map<Utf8, Utf8>` column `name` into `Utf8` columns
+    /// named after each key, such as the `properties` column read by
+    /// [`read_geojson`][crate::io::geojson::read_geojson] with `properties_as_map`.
+    ///
+    /// The new columns are inserted after the map column, in the order of `keys`, and the map
+    /// column itself is kept. A value is null where the map is null or doesn't contain the key.
+    /// If a map contains a key more than once, its first value is used.
+    ///
+    /// Returns an error if the column doesn't exist or isn't a map of strings, or if a key is
+    /// already the name of a column.
+    pub fn map_column_to_columns(&self, name: &str, keys: &[&str]) -> Result<Self> {
+        let index = self.schema().index_of(name)?;
+        if !is_string_map(self.schema().field(index).data_type()) {
+            return Err(GeoArrowError::IncorrectType(
+                format!(
+                    "Column {} must be a Map<Utf8, Utf8>, got {}",
+                    name,
+                    self.schema().field(index).data_type()
+                )
+                .into(),
+            ));
+        }
+        if let Some(key) = keys.iter().find(|key| self.schema().index_of(key).is_ok()) {
+            return Err(GeoArrowError::General(format!(
+                "Cannot extract key {} to a column; the table already has a column named {}",
+                key, key
+            )));
+        }
+
+        let mut fields = self.schema().fields().to_vec();
+        let new_fields = keys
+            .iter()
+            .map(|key| Arc::new(Field::new(*key, DataType::Utf8, true)));
+        fields.splice(index + 1..index + 1, new_fields);
+        let schema = Arc::new(Schema::new_with_metadata(
+            fields,
+            self.schema().metadata().clone(),
+        ));
+
+        let batches = self
+            .batches()
+            .iter()
+            .map(|batch| {
+                let mut columns = batch.columns().to_vec();
+                let new_columns = extract_keys(batch.column(index).as_map(), keys);
+                columns.splice(index + 1..index + 1, new_columns);
+                Ok(RecordBatch::try_new(schema.clone(), columns)?)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let geometry_column_index = if self.geometry_column_index() > index {
+            self.geometry_column_index() + keys.len()
+        } else {
+            self.geometry_column_index()
+        };
+        Self::try_new(schema, batches, geometry_column_index)
+    }
+}
+
+fn is_string_map(data_type: &DataType) -> bool {
+    match data_type {
+        DataType::Map(entries, _) => match entries.data_type() {
+            DataType::Struct(fields) => {
+                fields.len() == 2
+                    && fields[0].data_type() == &DataType::Utf8
+                    && fields[1].data_type() == &DataType::Utf8
+            }
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Build one string array per key with the value of the key in each map of `map`.
+fn extract_keys(map: &MapArray, keys: &[&str]) -> Vec<ArrayRef> {
+    let map_keys: &StringArray = map.keys().as_string();
+    let map_values: &StringArray = map.values().as_string();
+
+    let mut builders = keys
+        .iter()
+        .map(|_| StringBuilder::with_capacity(map.len(), 0))
+        .collect::<Vec<_>>();
+    for row in 0..map.len() {
+        let mut row_values = vec![None; keys.len()];
+        if map.is_valid(row) {
+            let offsets = map.value_offsets();
+            for entry in offsets[row] as usize..offsets[row + 1] as usize {
+                let key = map_keys.value(entry);
+                if let Some(key_index) = keys.iter().position(|k| *k == key) {
+                    if row_values[key_index].is_none() {
+                        let value = map_values.is_valid(entry).then(|| map_values.value(entry));
+                        row_values[key_index] = Some(value);
+                    }
+                }
+            }
+        }
+        for (builder, value) in builders.iter_mut().zip(row_values) {
+            builder.append_option(value.flatten());
+        }
+    }
+
+    builders
+        .into_iter()
+        .map(|mut builder| Arc::new(builder.finish()) as ArrayRef)
+        .collect()
+}
@@ -3,19 +3,57 @@
 
 use std::sync::Arc;
 
-use arrow_array::{ArrayRef, RecordBatch};
-use arrow_schema::{FieldRef, SchemaBuilder, SchemaRef};
+use arrow::compute::filter_record_batch;
+use arrow_array::cast::AsArray;
+use arrow_array::{ArrayRef, BooleanArray, RecordBatch, UInt32Array};
+use arrow_schema::{DataType, Field, FieldRef, Schema, SchemaBuilder, SchemaRef};
 
-use crate::algorithm::native::Downcast;
+use crate::algorithm::geo::HasDimensions;
+use crate::algorithm::native::{
+    explode_table, hilbert_sort_indices, Cast, Clip, Downcast, ExplodeOptions, Take,
+};
+use crate::array::metadata::ArrayMetadata;
 use crate::array::*;
 use crate::chunked_array::{from_arrow_chunks, from_geoarrow_chunks, ChunkedGeometryArrayTrait};
 use crate::chunked_array::{ChunkedArray, ChunkedGeometryArray};
 use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
-use crate::io::wkb::from_wkb;
+use crate::io::guards::ParseGuards;
+use crate::io::wkb::{from_wkb, CurvePolicy, DimensionPolicy};
+use crate::io::wkt::from_wkt;
+use crate::table::diff::concat_column;
+use crate::table::harmonize::check_batches;
 use phf::{phf_set, Set};
+use serde_json::Value;
+
+mod builder;
+mod coordinates;
+mod detect;
+mod diff;
+mod dissolve;
+mod extent;
+mod harmonize;
+mod join;
+mod map_column;
+mod rasterize;
+mod reader;
+mod robustness;
+mod rows;
+mod statistics;
 
-static GEOARROW_EXTENSION_NAMES: Set<&'static str> = phf_set! {
+pub(crate) use builder::table_from_batches;
+pub use builder::{PropertyValue, TableBuilder};
+pub use detect::TableOptions;
+pub use diff::{AttributeChange, DiffResult};
+pub use extent::{ExtentMetadata, TemporalExtent};
+pub use join::{JoinType, SpatialPredicate};
+#[cfg(feature = "stream")]
+pub use reader::GeoTableStream;
+pub use robustness::{DistributionSummary, RobustnessReport};
+pub use rows::{Row, Rows};
+pub use statistics::ColumnStatistics;
+
+pub(crate) static GEOARROW_EXTENSION_NAMES: Set<&'static str> = phf_set! {
     "geoarrow.point",
     "geoarrow.linestring",
     "geoarrow.polygon",
@@ -26,8 +64,22 @@ static GEOARROW_EXTENSION_NAMES: Set<&'static str> = phf_set! {
     "geoarrow.geometrycollection",
     "geoarrow.wkb",
     "ogc.wkb",
+    "geoarrow.wkt",
+    "geoarrow.box",
 };
 
+/// The index of the first field with a GeoArrow extension name.
+fn tagged_geometry_column_index(schema: &Schema) -> Option<usize> {
+    schema.fields().iter().position(|field| {
+        field
+            .metadata()
+            .get("ARROW:extension:name")
+            .is_some_and(|extension_name| {
+                GEOARROW_EXTENSION_NAMES.contains(extension_name.as_str())
+            })
+    })
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct GeoTable {
     schema: SchemaRef,
@@ -36,12 +88,21 @@ pub struct GeoTable {
 }
 
 impl GeoTable {
+    /// Create a table from batches with the columns of `schema`, with the geometry column at
+    /// `geometry_column_index`.
+    ///
+    /// The fields of each batch must have the names and data types of the fields of `schema`,
+    /// but their metadata is ignored, as readers such as the Parquet reader produce batches
+    /// without the GeoArrow extension metadata of the table schema. A batch field may also be
+    /// non-nullable where the table field is nullable. Use [`try_new_strict`][Self::try_new_strict]
+    /// to require identical fields, or [`try_new_with_harmonize`][Self::try_new_with_harmonize]
+    /// to cast the batches to `schema`.
     pub fn try_new(
         schema: SchemaRef,
         batches: Vec<RecordBatch>,
         geometry_column_index: usize,
     ) -> Result<Self> {
-        // TODO: validate
+        check_batches(&schema, &batches, geometry_column_index, false)?;
         Ok(Self {
             schema,
             batches,
@@ -73,13 +134,76 @@ impl GeoTable {
         Self::try_new(new_schema, new_batches, geometry_column_index)
     }
 
+    pub fn from_arrow(
+        batches: Vec<RecordBatch>,
+        schema: SchemaRef,
+        geometry_column_index: Option<usize>,
+        target_geo_data_type: Option<GeoDataType>,
+    ) -> Result<Self> {
+        Self::from_arrow_with_wkb_policies(
+            batches,
+            schema,
+            geometry_column_index,
+            target_geo_data_type,
+            DimensionPolicy::Error,
+            CurvePolicy::Error,
+            false,
+        )
+    }
+
+    /// Parse a WKB or WKT geometry column to a GeoArrow-native array, handling WKB geometries
+    /// with Z or M coordinates according to `dimension_policy` and curved WKB geometries according
+    /// to `curve_policy`. WKT coordinates are always parsed as 2D.
+    ///
+    /// As with [`from_arrow`][Self::from_arrow], the parsed geometry column is moved to the end of
+    /// the schema. Tables with a GeoArrow-native geometry column are returned unchanged, apart from
+    /// that move.
+    ///
+    /// With the `rayon` feature, each batch of a WKB column is parsed in parallel. A table read as
+    /// a few large batches can first be split with [`split_batches`][Self::split_batches] to make
+    /// use of more threads.
+    ///
+    /// If `coerce_multi` is `true`, a parsed column of only single and multi geometries of the same
+    /// kind, such as Polygons and MultiPolygons, is returned as the multi type instead of as a
+    /// mixed array. See [`Downcast::downcast`].
+    pub fn parse_geometry_to_native(
+        &self,
+        target_geo_data_type: Option<GeoDataType>,
+        dimension_policy: DimensionPolicy,
+        curve_policy: CurvePolicy,
+        coerce_multi: bool,
+    ) -> Result<Self> {
+        Self::from_arrow_with_wkb_policies(
+            self.batches.clone(),
+            self.schema.clone(),
+            Some(self.geometry_column_index),
+            target_geo_data_type,
+            dimension_policy,
+            curve_policy,
+            coerce_multi,
+        )
+    }
+
     // Note: This function is relatively complex because we want to parse any WKB columns to
     // geoarrow-native arrays
-    pub fn from_arrow(
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            skip_all,
+            fields(
+                num_batches = batches.len(),
+                num_rows = batches.iter().map(|batch| batch.num_rows()).sum::<usize>(),
+            )
+        )
+    )]
+    fn from_arrow_with_wkb_policies(
         batches: Vec<RecordBatch>,
         schema: SchemaRef,
         geometry_column_index: Option<usize>,
         target_geo_data_type: Option<GeoDataType>,
+        dimension_policy: DimensionPolicy,
+        curve_policy: CurvePolicy,
+        coerce_multi: bool,
     ) -> Result<Self> {
         if batches.is_empty() {
             return Err(GeoArrowError::General("empty input".to_string()));
@@ -87,20 +211,15 @@ impl GeoTable {
 
         let num_batches = batches.len();
 
-        let original_geometry_column_index = geometry_column_index.unwrap_or_else(|| {
-            schema
-                .fields
-                .iter()
-                .position(|field| {
-                    field
-                        .metadata()
-                        .get("ARROW:extension:name")
-                        .is_some_and(|extension_name| {
-                            GEOARROW_EXTENSION_NAMES.contains(extension_name.as_str())
-                        })
-                })
-                .expect("no geometry column in table")
-        });
+        let original_geometry_column_index = match geometry_column_index {
+            Some(index) => index,
+            None => tagged_geometry_column_index(&schema).ok_or_else(|| {
+                GeoArrowError::General(
+                    "No geometry column in table: no field has a GeoArrow extension name"
+                        .to_string(),
+                )
+            })?,
+        };
 
         let original_geometry_field = schema.field(original_geometry_column_index);
 
@@ -125,52 +244,108 @@ impl GeoTable {
             new_batches.push(new_batch);
         }
 
-        let orig_geom_slices = orig_geom_chunks
-            .iter()
-            .map(|c| c.as_ref())
-            .collect::<Vec<_>>();
-        let mut chunked_geometry_array =
-            from_arrow_chunks(orig_geom_slices.as_slice(), original_geometry_field)?;
-
         let target_geo_data_type =
             target_geo_data_type.unwrap_or(GeoDataType::LargeMixed(Default::default()));
+        let is_wkt = original_geometry_field
+            .metadata()
+            .get("ARROW:extension:name")
+            .is_some_and(|extension_name| extension_name == "geoarrow.wkt");
+        let mut chunked_geometry_array = if is_wkt {
+            let parsed_chunks = orig_geom_chunks
+                .iter()
+                .map(|chunk| match chunk.data_type() {
+                    DataType::Utf8 => from_wkt(
+                        chunk.as_string::<i32>(),
+                        target_geo_data_type,
+                        ParseGuards::default(),
+                    ),
+                    DataType::LargeUtf8 => from_wkt(
+                        chunk.as_string::<i64>(),
+                        target_geo_data_type,
+                        ParseGuards::default(),
+                    ),
+                    dt => Err(GeoArrowError::General(format!(
+                        "Expected a string array for a WKT column, got {}",
+                        dt
+                    ))),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let parsed_chunks_refs = parsed_chunks
+                .iter()
+                .map(|chunk| chunk.as_ref())
+                .collect::<Vec<_>>();
+            from_geoarrow_chunks(parsed_chunks_refs.as_slice())?
+                .as_ref()
+                .downcast(true, coerce_multi)
+        } else {
+            let orig_geom_slices = orig_geom_chunks
+                .iter()
+                .map(|c| c.as_ref())
+                .collect::<Vec<_>>();
+            from_arrow_chunks(orig_geom_slices.as_slice(), original_geometry_field)?
+        };
+
+        // The metadata of the parsed WKB, which has a CRS if the WKB was EWKB with an SRID
+        let mut parsed_metadata = None;
         match chunked_geometry_array.data_type() {
             GeoDataType::WKB => {
-                let parsed_chunks = chunked_geometry_array
-                    .as_ref()
-                    .as_wkb()
-                    .chunks()
-                    .iter()
-                    .map(|chunk| from_wkb(chunk, target_geo_data_type, true))
-                    .collect::<Result<Vec<_>>>()?;
+                let parsed_chunks = chunked_geometry_array.as_ref().as_wkb().try_map(|chunk| {
+                    from_wkb(
+                        chunk,
+                        target_geo_data_type,
+                        true,
+                        dimension_policy,
+                        curve_policy,
+                        ParseGuards::default(),
+                    )
+                })?;
+                parsed_metadata = parsed_chunks.first().map(|chunk| chunk.metadata());
                 let parsed_chunks_refs = parsed_chunks
                     .iter()
                     .map(|chunk| chunk.as_ref())
                     .collect::<Vec<_>>();
                 chunked_geometry_array = from_geoarrow_chunks(parsed_chunks_refs.as_slice())?
                     .as_ref()
-                    .downcast(true);
+                    .downcast(true, coerce_multi);
             }
             GeoDataType::LargeWKB => {
-                let parsed_chunks = chunked_geometry_array
-                    .as_ref()
-                    .as_large_wkb()
-                    .chunks()
-                    .iter()
-                    .map(|chunk| from_wkb(chunk, target_geo_data_type, true))
-                    .collect::<Result<Vec<_>>>()?;
+                let parsed_chunks =
+                    chunked_geometry_array
+                        .as_ref()
+                        .as_large_wkb()
+                        .try_map(|chunk| {
+                            from_wkb(
+                                chunk,
+                                target_geo_data_type,
+                                true,
+                                dimension_policy,
+                                curve_policy,
+                                ParseGuards::default(),
+                            )
+                        })?;
+                parsed_metadata = parsed_chunks.first().map(|chunk| chunk.metadata());
                 let parsed_chunks_refs = parsed_chunks
                     .iter()
                     .map(|chunk| chunk.as_ref())
                     .collect::<Vec<_>>();
                 chunked_geometry_array = from_geoarrow_chunks(parsed_chunks_refs.as_slice())?
                     .as_ref()
-                    .downcast(true);
+                    .downcast(true, coerce_multi);
             }
             _ => (),
         };
 
-        new_schema.push(chunked_geometry_array.extension_field());
+        // Parsing doesn't change the CRS or edges of the geometries, so carry over the normalized
+        // metadata of the original field. An SRID found while parsing EWKB fills in a missing CRS.
+        let mut array_metadata = ArrayMetadata::try_from(original_geometry_field)?;
+        if array_metadata.crs.is_none() {
+            array_metadata.crs = parsed_metadata.and_then(|metadata| metadata.crs.clone());
+        }
+        let new_geometry_field = with_array_metadata(
+            chunked_geometry_array.extension_field().as_ref(),
+            &array_metadata,
+        );
+        new_schema.push(new_geometry_field);
         let new_schema = Arc::new(new_schema.finish());
         let new_geometry_column_index = new_schema.fields().len() - 1;
 
@@ -219,20 +394,407 @@ impl GeoTable {
         self.schema.fields().len()
     }
 
-    pub(crate) fn remove_column(&mut self, i: usize) -> ChunkedArray<ArrayRef> {
-        // NOTE: remove_column drops schema metadata as of
-        // https://github.com/apache/arrow-rs/issues/5327
+    /// Rewrite the extension metadata of every geometry column to the canonical form of the
+    /// current GeoArrow specification, for a clean re-export of a table that was produced
+    /// elsewhere.
+    ///
+    /// The metadata is normalized with [`ArrayMetadata::from_extension_metadata`], and the legacy
+    /// `ogc.wkb` extension name is renamed to `geoarrow.wkb`. The column data is unchanged.
+    pub fn normalize_metadata(&mut self) -> Result<()> {
+        let fields = self
+            .schema
+            .fields()
+            .iter()
+            .map(|field| {
+                let extension_name = field.metadata().get("ARROW:extension:name");
+                match extension_name {
+                    Some(extension_name)
+                        if GEOARROW_EXTENSION_NAMES.contains(extension_name.as_str()) =>
+                    {
+                        let array_metadata = ArrayMetadata::try_from(field.as_ref())?;
+                        Ok(with_array_metadata(field, &array_metadata))
+                    }
+                    _ => Ok(field.clone()),
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        self.replace_fields(fields)
+    }
+
+    /// The CRS of the geometry column at `index`, as PROJJSON or another JSON value, or `None` if
+    /// the column has no CRS.
+    ///
+    /// Returns an error if the column isn't a geometry column.
+    pub fn crs(&self, index: usize) -> Result<Option<Value>> {
+        let field = self.geometry_field(index)?;
+        Ok(ArrayMetadata::try_from(field)?.crs)
+    }
+
+    /// Set the CRS of the geometry column at `index`, or remove it with `None`. The rest of the
+    /// extension metadata and the column data are unchanged; the coordinates are not reprojected.
+    ///
+    /// Returns an error if the column isn't a geometry column.
+    pub fn set_crs(&mut self, index: usize, crs: Option<Value>) -> Result<()> {
+        let field = self.geometry_field(index)?;
+        let mut array_metadata = ArrayMetadata::try_from(field)?;
+        array_metadata.crs = crs;
+        let mut fields = self.schema.fields().to_vec();
+        fields[index] = with_array_metadata(field, &array_metadata);
+        self.replace_fields(fields)
+    }
+
+    /// Cast the geometry column to `to_type`, such as another coord type or the multi geometry
+    /// type of its geometries, with [`Cast`].
+    ///
+    /// The name and extension metadata of the geometry field, including its CRS, are kept.
+    pub fn cast_geometry(&self, to_type: &GeoDataType) -> Result<Self> {
+        self.cast_geometry_column(self.geometry_column_index, to_type)
+    }
+
+    /// Cast the geometry column named `name` to `to_type`, as with
+    /// [`cast_geometry`][Self::cast_geometry]. The geometry column of the table is unchanged if
+    /// it's another column.
+    ///
+    /// Returns an error if there is no column or more than one column named `name`, or if the
+    /// column isn't tagged as a geometry column.
+    pub fn cast_geometry_by_name(&self, name: &str, to_type: &GeoDataType) -> Result<Self> {
+        self.cast_geometry_column(self.geometry_index_of(name)?, to_type)
+    }
+
+    fn cast_geometry_column(&self, index: usize, to_type: &GeoDataType) -> Result<Self> {
+        let casted = self.chunked_geometry(index)?.as_ref().cast(to_type)?;
+
+        let original_field = self.schema.field(index);
+        let casted_field = casted
+            .extension_field()
+            .as_ref()
+            .clone()
+            .with_name(original_field.name());
+        let mut fields = self.schema.fields().to_vec();
+        fields[index] =
+            with_array_metadata(&casted_field, &ArrayMetadata::try_from(original_field)?);
+        let schema = Arc::new(Schema::new_with_metadata(
+            fields,
+            self.schema.metadata().clone(),
+        ));
+
+        let batches = self
+            .batches
+            .iter()
+            .zip(casted.geometry_chunks())
+            .map(|(batch, chunk)| {
+                let mut columns = batch.columns().to_vec();
+                columns[index] = chunk.to_array_ref();
+                Ok(RecordBatch::try_new(schema.clone(), columns)?)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Self::try_new(schema, batches, self.geometry_column_index)
+    }
+
+    /// The index of the column named `name`, or `None` if there is no such column.
+    ///
+    /// Returns an error if more than one column is named `name`, rather than picking one.
+    pub fn column_index(&self, name: &str) -> Result<Option<usize>> {
+        let mut indices = self
+            .schema
+            .fields()
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| field.name() == name)
+            .map(|(index, _)| index);
+        match (indices.next(), indices.next()) {
+            (Some(_), Some(_)) => Err(GeoArrowError::General(format!(
+                "Column name {} is ambiguous; the table has more than one column with that name",
+                name
+            ))),
+            (index, _) => Ok(index),
+        }
+    }
+
+    /// The index of the column named `name`, or an error if there is no such column.
+    fn existing_column_index(&self, name: &str) -> Result<usize> {
+        self.column_index(name)?
+            .ok_or_else(|| GeoArrowError::General(format!("Table has no column named {}", name)))
+    }
+
+    /// The index of the geometry column named `name`.
+    fn geometry_index_of(&self, name: &str) -> Result<usize> {
+        let index = self.existing_column_index(name)?;
+        self.geometry_field(index)?;
+        Ok(index)
+    }
+
+    /// The field at `index`, or an error if it isn't a geometry column.
+    fn geometry_field(&self, index: usize) -> Result<&Field> {
+        if index >= self.schema.fields().len() {
+            return Err(GeoArrowError::General(format!(
+                "Column index {} out of bounds for a table with {} columns",
+                index,
+                self.schema.fields().len()
+            )));
+        }
+        let field = self.schema.field(index);
+        match field.metadata().get("ARROW:extension:name") {
+            Some(extension_name) if GEOARROW_EXTENSION_NAMES.contains(extension_name.as_str()) => {
+                Ok(field)
+            }
+            _ => Err(GeoArrowError::IncorrectType(
+                format!("Column {} is not tagged as a geometry column", field.name()).into(),
+            )),
+        }
+    }
+
+    /// Replace the fields of the schema with `fields`, which must have the same data types.
+    fn replace_fields(&mut self, fields: Vec<FieldRef>) -> Result<()> {
+        let schema = Arc::new(Schema::new_with_metadata(
+            fields,
+            self.schema.metadata().clone(),
+        ));
+
+        self.batches = self
+            .batches
+            .iter()
+            .map(|batch| {
+                Ok(RecordBatch::try_new(
+                    schema.clone(),
+                    batch.columns().to_vec(),
+                )?)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        self.schema = schema;
+        Ok(())
+    }
+
+    /// Split every batch with more than `max_rows` rows into batches of at most `max_rows` rows.
+    ///
+    /// The new batches are zero-copy slices of the original ones, so this is cheap. Kernels over
+    /// chunked arrays run in parallel over batches with the `rayon` feature, so smaller batches
+    /// let them use more threads.
+    pub fn split_batches(&self, max_rows: usize) -> Result<Self> {
+        if max_rows == 0 {
+            return Err(GeoArrowError::General(
+                "max_rows must be greater than 0".to_string(),
+            ));
+        }
+
+        let mut batches = Vec::with_capacity(self.batches.len());
+        for batch in self.batches.iter() {
+            if batch.num_rows() <= max_rows {
+                batches.push(batch.clone());
+                continue;
+            }
+            for offset in (0..batch.num_rows()).step_by(max_rows) {
+                let length = max_rows.min(batch.num_rows() - offset);
+                batches.push(batch.slice(offset, length));
+            }
+        }
+        Self::try_new(self.schema.clone(), batches, self.geometry_column_index)
+    }
+
+    /// The number of bytes used by the columns of this table.
+    ///
+    /// The geometry column is measured with
+    /// [`GeometryArrayTrait::buffer_sizes`][crate::GeometryArrayTrait::buffer_sizes], so a table of
+    /// sliced batches only counts its share of the geometry buffers. The other columns are
+    /// measured with [`Array::get_array_memory_size`][arrow_array::Array::get_array_memory_size],
+    /// which counts the full capacity of their buffers.
+    pub fn memory_size(&self) -> Result<usize> {
+        let geometry_size = self.geometry()?.buffer_sizes().total();
+        let attribute_size = self
+            .batches
+            .iter()
+            .flat_map(|batch| {
+                batch
+                    .columns()
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| *index != self.geometry_column_index)
+                    .map(|(_, column)| column.get_array_memory_size())
+            })
+            .sum::<usize>();
+        Ok(geometry_size + attribute_size)
+    }
+
+    /// Remove the rows where the geometry is null.
+    ///
+    /// Batches without null geometries are kept as is. Batches where every geometry is null are
+    /// dropped, apart from a single empty batch if no rows remain.
+    pub fn drop_null_rows(&self) -> Result<Self> {
+        let geometry = self.geometry()?;
+        let mut batches = Vec::with_capacity(self.batches.len());
+        for (batch, chunk) in self.batches.iter().zip(geometry.geometry_chunks()) {
+            match chunk.nulls() {
+                Some(nulls) if nulls.null_count() == batch.num_rows() => continue,
+                Some(nulls) if nulls.null_count() > 0 => {
+                    let predicate = BooleanArray::new(nulls.inner().clone(), None);
+                    batches.push(filter_record_batch(batch, &predicate)?);
+                }
+                _ => batches.push(batch.clone()),
+            }
+        }
+        if batches.is_empty() {
+            batches.push(RecordBatch::new_empty(self.schema.clone()));
+        }
+        Self::try_new(self.schema.clone(), batches, self.geometry_column_index)
+    }
+
+    /// Take the rows at `indices` into a new table with a single batch.
+    ///
+    /// Indices refer to the rows of the whole table rather than of a single batch, and a null
+    /// index creates a row of nulls. Native geometry columns are taken with
+    /// [`Take`][crate::algorithm::native::Take], so they keep their type and coordinate layout,
+    /// and the other columns with arrow's `take` kernel.
+    pub fn take(&self, indices: &UInt32Array) -> Result<Self> {
+        // Arrow's take kernel panics on out of bounds indices
+        let len = self.len();
+        if let Some(index) = indices
+            .iter()
+            .flatten()
+            .find(|index| *index as usize >= len)
+        {
+            return Err(GeoArrowError::General(format!(
+                "Index {} out of bounds for a table with {} rows",
+                index, len
+            )));
+        }
+
+        let mut columns = Vec::with_capacity(self.schema.fields().len());
+        for (index, field) in self.schema.fields().iter().enumerate() {
+            let is_native_geometry = self.geometry_field(index).is_ok()
+                && !matches!(
+                    GeoDataType::try_from(field.as_ref())?,
+                    GeoDataType::WKB | GeoDataType::LargeWKB
+                );
+            let column = if is_native_geometry {
+                let taken = self.chunked_geometry(index)?.as_ref().take(indices)?;
+                taken.geometry_chunks()[0].to_array_ref()
+            } else {
+                arrow::compute::take(concat_column(self, index)?.as_ref(), indices, None)?
+            };
+            columns.push(column);
+        }
+
+        let batch = RecordBatch::try_new(self.schema.clone(), columns)?;
+        Self::try_new(self.schema.clone(), vec![batch], self.geometry_column_index)
+    }
+
+    /// Sort the rows of this table along a Hilbert curve through the bounding box centers of the
+    /// geometry column at `index`, so that nearby geometries end up in nearby rows.
+    ///
+    /// If `index` is `None`, the geometry column of the table is used. Rows with null or empty
+    /// geometries are moved to the end. The sorted table has a single batch; see
+    /// [`hilbert_sort_indices`][crate::algorithm::native::hilbert_sort_indices] for the ordering.
+    pub fn sort_hilbert(&self, index: Option<usize>) -> Result<Self> {
+        let geometry = self.geometry_column(index.unwrap_or(self.geometry_column_index))?;
+        self.take(&hilbert_sort_indices(geometry.as_ref())?)
+    }
+
+    /// Explode the multi-part geometries of the geometry column at `index` into one row per part,
+    /// repeating the values of the other columns for each part.
+    ///
+    /// If `index` is `None`, the geometry column of the table is exploded. The exploded column
+    /// keeps its position, and its type becomes the single-part type, such as Polygon for a
+    /// MultiPolygon column. See [`Explode`][crate::algorithm::native::Explode] for how null and
+    /// empty geometries are handled.
+    pub fn explode_column(&self, index: Option<usize>, options: ExplodeOptions) -> Result<Self> {
+        explode_table(self, index.unwrap_or(self.geometry_column_index), options)
+    }
+
+    /// Clip the geometry column of this table to `rect`, removing the rows whose geometry is null
+    /// or clipped away.
+    ///
+    /// A LineString column becomes a MultiLineString column; see
+    /// [`Clip`][crate::algorithm::native::Clip] for how geometries are clipped.
+    pub fn clip(&self, rect: &geo::Rect) -> Result<Self> {
+        let clipped = self.geometry()?.as_ref().clip(rect)?;
+
+        let mut fields = self.schema.fields().to_vec();
+        fields[self.geometry_column_index] = clipped.extension_field();
+        let schema = Arc::new(Schema::new_with_metadata(
+            fields,
+            self.schema.metadata().clone(),
+        ));
+
+        let mut batches = Vec::with_capacity(self.batches.len());
+        for (batch, chunk) in self.batches.iter().zip(clipped.geometry_chunks()) {
+            let mut columns = batch.columns().to_vec();
+            columns[self.geometry_column_index] = chunk.to_array_ref();
+            let batch = RecordBatch::try_new(schema.clone(), columns)?;
+
+            // Null values of the predicate are not selected, so null geometries are removed too
+            let predicate = arrow::compute::not(&HasDimensions::is_empty(&chunk)?)?;
+            let batch = filter_record_batch(&batch, &predicate)?;
+            if batch.num_rows() > 0 {
+                batches.push(batch);
+            }
+        }
+        if batches.is_empty() {
+            batches.push(RecordBatch::new_empty(schema.clone()));
+        }
+        Self::try_new(schema, batches, self.geometry_column_index)
+    }
+
+    /// Remove the column at index `i`, returning its field and data.
+    ///
+    /// The schema metadata of the table and of its batches is kept, and the geometry column index
+    /// is updated if the geometry column comes after the removed column.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is out of bounds or is the index of the geometry column.
+    pub fn remove_column(&mut self, i: usize) -> (FieldRef, ChunkedArray<ArrayRef>) {
+        assert_ne!(
+            i, self.geometry_column_index,
+            "Cannot remove the geometry column of a table"
+        );
+
+        // Rebuild the schemas rather than use RecordBatch::remove_column, which drops schema
+        // metadata as of https://github.com/apache/arrow-rs/issues/5327
+        let mut fields = self.schema.fields().to_vec();
+        let removed_field = fields.remove(i);
+        self.schema = Arc::new(Schema::new_with_metadata(
+            fields,
+            self.schema.metadata().clone(),
+        ));
+
+        let kept_indices = (0..self.schema.fields().len() + 1)
+            .filter(|index| *index != i)
+            .collect::<Vec<_>>();
         let removed_chunks = self
             .batches
             .iter_mut()
-            .map(|batch| batch.remove_column(i))
+            .map(|batch| {
+                let removed_chunk = batch.column(i).clone();
+                *batch = batch
+                    .project(&kept_indices)
+                    .expect("indices are within the batch");
+                removed_chunk
+            })
             .collect::<Vec<_>>();
 
-        let mut schema_builder = SchemaBuilder::from(self.schema.as_ref().clone());
-        schema_builder.remove(i);
-        self.schema = Arc::new(schema_builder.finish());
+        if i < self.geometry_column_index {
+            self.geometry_column_index -= 1;
+        }
+
+        (removed_field, ChunkedArray::new(removed_chunks))
+    }
 
-        ChunkedArray::new(removed_chunks)
+    /// Remove the column named `name`, as with [`remove_column`][Self::remove_column].
+    ///
+    /// Returns an error if there is no column or more than one column named `name`, or if it's
+    /// the geometry column.
+    pub fn remove_column_by_name(
+        &mut self,
+        name: &str,
+    ) -> Result<(FieldRef, ChunkedArray<ArrayRef>)> {
+        let index = self.existing_column_index(name)?;
+        if index == self.geometry_column_index {
+            return Err(GeoArrowError::General(format!(
+                "Cannot remove the geometry column {} of a table",
+                name
+            )));
+        }
+        Ok(self.remove_column(index))
     }
 
     #[allow(dead_code)]
@@ -271,143 +833,550 @@ impl GeoTable {
     }
 
     /// Access the geometry column of the table
+    ///
+    /// Every chunk has the [`ArrayMetadata`] of the geometry field, such as its CRS.
     pub fn geometry(&self) -> Result<Arc<dyn ChunkedGeometryArrayTrait>> {
-        let field = self.schema.field(self.geometry_column_index);
+        self.chunked_geometry(self.geometry_column_index)
+    }
+
+    /// Access the geometry column at `index`, which doesn't need to be the geometry column of
+    /// the table, as with [`geometry`][Self::geometry].
+    ///
+    /// Returns an error if the column isn't tagged as a geometry column.
+    pub fn geometry_column(&self, index: usize) -> Result<Arc<dyn ChunkedGeometryArrayTrait>> {
+        self.geometry_field(index)?;
+        self.chunked_geometry(index)
+    }
+
+    /// Access the geometry column named `name`, as with [`geometry_column`][Self::geometry_column].
+    ///
+    /// Returns an error if there is no column or more than one column named `name`, or if the
+    /// column isn't tagged as a geometry column.
+    pub fn geometry_column_by_name(
+        &self,
+        name: &str,
+    ) -> Result<Arc<dyn ChunkedGeometryArrayTrait>> {
+        self.chunked_geometry(self.geometry_index_of(name)?)
+    }
+
+    fn chunked_geometry(&self, index: usize) -> Result<Arc<dyn ChunkedGeometryArrayTrait>> {
+        let field = self.schema.field(index);
         let array_refs = self
             .batches
             .iter()
-            .map(|batch| batch.column(self.geometry_column_index))
+            .map(|batch| batch.column(index))
             .collect::<Vec<_>>();
         let geo_data_type = GeoDataType::try_from(field)?;
+        let metadata = Arc::new(ArrayMetadata::try_from(field)?);
         match geo_data_type {
             GeoDataType::Point(_) => {
                 let chunks: Result<Vec<PointArray>> = array_refs
                     .into_iter()
                     .map(|arr| arr.as_ref().try_into())
                     .collect();
-                Ok(Arc::new(ChunkedGeometryArray::new(chunks?)))
+                Ok(Arc::new(
+                    ChunkedGeometryArray::new_unchecked(chunks?).with_metadata(metadata),
+                ))
             }
             GeoDataType::LineString(_) => {
                 let chunks: Result<Vec<LineStringArray<i32>>> = array_refs
                     .into_iter()
                     .map(|arr| arr.as_ref().try_into())
                     .collect();
-                Ok(Arc::new(ChunkedGeometryArray::new(chunks?)))
+                Ok(Arc::new(
+                    ChunkedGeometryArray::new_unchecked(chunks?).with_metadata(metadata),
+                ))
             }
             GeoDataType::LargeLineString(_) => {
                 let chunks: Result<Vec<LineStringArray<i64>>> = array_refs
                     .into_iter()
                     .map(|arr| arr.as_ref().try_into())
                     .collect();
-                Ok(Arc::new(ChunkedGeometryArray::new(chunks?)))
+                Ok(Arc::new(
+                    ChunkedGeometryArray::new_unchecked(chunks?).with_metadata(metadata),
+                ))
             }
             GeoDataType::Polygon(_) => {
                 let chunks: Result<Vec<PolygonArray<i32>>> = array_refs
                     .into_iter()
                     .map(|arr| arr.as_ref().try_into())
                     .collect();
-                Ok(Arc::new(ChunkedGeometryArray::new(chunks?)))
+                Ok(Arc::new(
+                    ChunkedGeometryArray::new_unchecked(chunks?).with_metadata(metadata),
+                ))
             }
             GeoDataType::LargePolygon(_) => {
                 let chunks: Result<Vec<PolygonArray<i64>>> = array_refs
                     .into_iter()
                     .map(|arr| arr.as_ref().try_into())
                     .collect();
-                Ok(Arc::new(ChunkedGeometryArray::new(chunks?)))
+                Ok(Arc::new(
+                    ChunkedGeometryArray::new_unchecked(chunks?).with_metadata(metadata),
+                ))
             }
             GeoDataType::MultiPoint(_) => {
                 let chunks: Result<Vec<MultiPointArray<i32>>> = array_refs
                     .into_iter()
                     .map(|arr| arr.as_ref().try_into())
                     .collect();
-                Ok(Arc::new(ChunkedGeometryArray::new(chunks?)))
+                Ok(Arc::new(
+                    ChunkedGeometryArray::new_unchecked(chunks?).with_metadata(metadata),
+                ))
             }
             GeoDataType::LargeMultiPoint(_) => {
                 let chunks: Result<Vec<MultiPointArray<i64>>> = array_refs
                     .into_iter()
                     .map(|arr| arr.as_ref().try_into())
                     .collect();
-                Ok(Arc::new(ChunkedGeometryArray::new(chunks?)))
+                Ok(Arc::new(
+                    ChunkedGeometryArray::new_unchecked(chunks?).with_metadata(metadata),
+                ))
             }
             GeoDataType::MultiLineString(_) => {
                 let chunks: Result<Vec<MultiLineStringArray<i32>>> = array_refs
                     .into_iter()
                     .map(|arr| arr.as_ref().try_into())
                     .collect();
-                Ok(Arc::new(ChunkedGeometryArray::new(chunks?)))
+                Ok(Arc::new(
+                    ChunkedGeometryArray::new_unchecked(chunks?).with_metadata(metadata),
+                ))
             }
             GeoDataType::LargeMultiLineString(_) => {
                 let chunks: Result<Vec<MultiLineStringArray<i64>>> = array_refs
                     .into_iter()
                     .map(|arr| arr.as_ref().try_into())
                     .collect();
-                Ok(Arc::new(ChunkedGeometryArray::new(chunks?)))
+                Ok(Arc::new(
+                    ChunkedGeometryArray::new_unchecked(chunks?).with_metadata(metadata),
+                ))
             }
             GeoDataType::MultiPolygon(_) => {
                 let chunks: Result<Vec<MultiPolygonArray<i32>>> = array_refs
                     .into_iter()
                     .map(|arr| arr.as_ref().try_into())
                     .collect();
-                Ok(Arc::new(ChunkedGeometryArray::new(chunks?)))
+                Ok(Arc::new(
+                    ChunkedGeometryArray::new_unchecked(chunks?).with_metadata(metadata),
+                ))
             }
             GeoDataType::LargeMultiPolygon(_) => {
                 let chunks: Result<Vec<MultiPolygonArray<i64>>> = array_refs
                     .into_iter()
                     .map(|arr| arr.as_ref().try_into())
                     .collect();
-                Ok(Arc::new(ChunkedGeometryArray::new(chunks?)))
+                Ok(Arc::new(
+                    ChunkedGeometryArray::new_unchecked(chunks?).with_metadata(metadata),
+                ))
             }
             GeoDataType::Mixed(_) => {
                 let chunks: Result<Vec<MixedGeometryArray<i32>>> = array_refs
                     .into_iter()
                     .map(|arr| arr.as_ref().try_into())
                     .collect();
-                Ok(Arc::new(ChunkedGeometryArray::new(chunks?)))
+                Ok(Arc::new(
+                    ChunkedGeometryArray::new_unchecked(chunks?).with_metadata(metadata),
+                ))
             }
             GeoDataType::LargeMixed(_) => {
                 let chunks: Result<Vec<MixedGeometryArray<i64>>> = array_refs
                     .into_iter()
                     .map(|arr| arr.as_ref().try_into())
                     .collect();
-                Ok(Arc::new(ChunkedGeometryArray::new(chunks?)))
+                Ok(Arc::new(
+                    ChunkedGeometryArray::new_unchecked(chunks?).with_metadata(metadata),
+                ))
             }
             GeoDataType::GeometryCollection(_) => {
                 let chunks: Result<Vec<GeometryCollectionArray<i32>>> = array_refs
                     .into_iter()
                     .map(|arr| arr.as_ref().try_into())
                     .collect();
-                Ok(Arc::new(ChunkedGeometryArray::new(chunks?)))
+                Ok(Arc::new(
+                    ChunkedGeometryArray::new_unchecked(chunks?).with_metadata(metadata),
+                ))
             }
             GeoDataType::LargeGeometryCollection(_) => {
                 let chunks: Result<Vec<GeometryCollectionArray<i64>>> = array_refs
                     .into_iter()
                     .map(|arr| arr.as_ref().try_into())
                     .collect();
-                Ok(Arc::new(ChunkedGeometryArray::new(chunks?)))
+                Ok(Arc::new(
+                    ChunkedGeometryArray::new_unchecked(chunks?).with_metadata(metadata),
+                ))
             }
             GeoDataType::WKB => {
                 let chunks: Result<Vec<WKBArray<i32>>> = array_refs
                     .into_iter()
                     .map(|arr| arr.as_ref().try_into())
                     .collect();
-                Ok(Arc::new(ChunkedGeometryArray::new(chunks?)))
+                Ok(Arc::new(
+                    ChunkedGeometryArray::new_unchecked(chunks?).with_metadata(metadata),
+                ))
             }
             GeoDataType::LargeWKB => {
                 let chunks: Result<Vec<WKBArray<i64>>> = array_refs
                     .into_iter()
                     .map(|arr| arr.as_ref().try_into())
                     .collect();
-                Ok(Arc::new(ChunkedGeometryArray::new(chunks?)))
+                Ok(Arc::new(
+                    ChunkedGeometryArray::new_unchecked(chunks?).with_metadata(metadata),
+                ))
             }
             GeoDataType::Rect => {
-                // tryfrom not implemented for RectArray
-                todo!()
-                // let chunks: Result<Vec<RectArray>> = array_refs
-                //     .into_iter()
-                //     .map(|arr| arr.as_ref().try_into())
-                //     .collect();
-                // Ok(Arc::new(ChunkedGeometryArray::new(chunks?)))
+                let chunks: Result<Vec<RectArray>> = array_refs
+                    .into_iter()
+                    .map(|arr| arr.as_ref().try_into())
+                    .collect();
+                Ok(Arc::new(
+                    ChunkedGeometryArray::new_unchecked(chunks?).with_metadata(metadata),
+                ))
             }
         }
     }
 }
+
+/// A copy of `field` with `metadata` as its extension metadata, and with the legacy `ogc.wkb`
+/// extension name renamed to `geoarrow.wkb`.
+fn with_array_metadata(field: &Field, metadata: &ArrayMetadata) -> FieldRef {
+    let mut field_metadata = field.metadata().clone();
+    field_metadata.insert(
+        "ARROW:extension:metadata".to_string(),
+        serde_json::to_string(metadata).unwrap(),
+    );
+    if field_metadata
+        .get("ARROW:extension:name")
+        .is_some_and(|extension_name| extension_name == "ogc.wkb")
+    {
+        field_metadata.insert(
+            "ARROW:extension:name".to_string(),
+            "geoarrow.wkb".to_string(),
+        );
+    }
+    Arc::new(field.clone().with_metadata(field_metadata))
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::trait_::GeometryArrayAccessor;
+    use crate::GeometryArrayTrait;
+    use arrow_array::{Array, BinaryArray};
+    use serde_json::json;
+
+    #[test]
+    fn parse_ewkb_with_srid() {
+        // EWKB Point with SRID 4326, as exported by PostGIS
+        let mut ewkb = vec![1];
+        ewkb.extend_from_slice(&0x2000_0001_u32.to_le_bytes());
+        ewkb.extend_from_slice(&4326_u32.to_le_bytes());
+        ewkb.extend_from_slice(&1_f64.to_le_bytes());
+        ewkb.extend_from_slice(&2_f64.to_le_bytes());
+        let wkb_array = WKBArray::new(
+            BinaryArray::from_vec(vec![ewkb.as_slice(), ewkb.as_slice()]),
+            Default::default(),
+        );
+
+        let schema = Arc::new(arrow_schema::Schema::new(vec![wkb_array.extension_field()]));
+        let batch = RecordBatch::try_new(schema.clone(), vec![wkb_array.into_array_ref()]).unwrap();
+        let table = GeoTable::try_new(schema, vec![batch], 0).unwrap();
+
+        let parsed = table
+            .parse_geometry_to_native(None, DimensionPolicy::Error, CurvePolicy::Error, false)
+            .unwrap();
+        assert_eq!(
+            parsed.geometry_data_type().unwrap(),
+            GeoDataType::Point(Default::default())
+        );
+        let field = parsed.schema().field(parsed.geometry_column_index());
+        let metadata: ArrayMetadata =
+            serde_json::from_str(&field.metadata()["ARROW:extension:metadata"]).unwrap();
+        assert_eq!(
+            metadata.crs,
+            Some(json!({"id": {"authority": "EPSG", "code": 4326}}))
+        );
+    }
+
+    #[test]
+    fn parse_wkt() {
+        let wkt_array = arrow_array::LargeStringArray::from(vec![
+            Some("POINT (1 2)"),
+            None,
+            Some("POINT Z (3 4 5)"),
+        ]);
+        let field = arrow_schema::Field::new("geometry", DataType::LargeUtf8, true).with_metadata(
+            [(
+                "ARROW:extension:name".to_string(),
+                "geoarrow.wkt".to_string(),
+            )]
+            .into(),
+        );
+        let schema = Arc::new(arrow_schema::Schema::new(vec![field]));
+        let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(wkt_array)]).unwrap();
+        let table = GeoTable::try_new(schema, vec![batch], 0).unwrap();
+
+        let parsed = table
+            .parse_geometry_to_native(None, DimensionPolicy::Error, CurvePolicy::Error, false)
+            .unwrap();
+        assert_eq!(
+            parsed.geometry_data_type().unwrap(),
+            GeoDataType::Point(Default::default())
+        );
+        assert_eq!(parsed.len(), 3);
+    }
+
+    #[test]
+    fn normalize_metadata() {
+        let wkb_array: WKBArray<i32> = (&crate::test::point::point_array()).into();
+        let mut field = wkb_array.extension_field().as_ref().clone();
+        field.set_metadata(
+            [
+                ("ARROW:extension:name".to_string(), "ogc.wkb".to_string()),
+                (
+                    "ARROW:extension:metadata".to_string(),
+                    r#"{"crs":"EPSG:4326","crs_type":"authority_code"}"#.to_string(),
+                ),
+            ]
+            .into(),
+        );
+        let schema = Arc::new(arrow_schema::Schema::new(vec![field]));
+        let batch = RecordBatch::try_new(schema.clone(), vec![wkb_array.into_array_ref()]).unwrap();
+        let mut table = GeoTable::try_new(schema, vec![batch], 0).unwrap();
+
+        table.normalize_metadata().unwrap();
+        let field = table.schema().field(0);
+        assert_eq!(field.metadata()["ARROW:extension:name"], "geoarrow.wkb");
+        assert_eq!(
+            field.metadata()["ARROW:extension:metadata"],
+            r#"{"crs":{"id":{"authority":"EPSG","code":4326}}}"#
+        );
+        assert_eq!(table.batches()[0].schema(), *table.schema());
+
+        // Parsing carries over the normalized metadata
+        let parsed = table
+            .parse_geometry_to_native(None, DimensionPolicy::Error, CurvePolicy::Error, false)
+            .unwrap();
+        let geometry = parsed.geometry().unwrap();
+        let field = parsed.schema().field(parsed.geometry_column_index());
+        assert_eq!(
+            field.metadata()["ARROW:extension:metadata"],
+            r#"{"crs":{"id":{"authority":"EPSG","code":4326}}}"#
+        );
+        assert_eq!(
+            geometry.data_type(),
+            &GeoDataType::Point(Default::default())
+        );
+    }
+
+    #[test]
+    fn drop_null_rows() {
+        let chunks: Vec<PointArray> = vec![
+            vec![Some(crate::test::point::p0()), None].into(),
+            vec![None::<geo::Point>, None].into(),
+            vec![Some(crate::test::point::p2())].into(),
+        ];
+        let schema = Arc::new(arrow_schema::Schema::new(vec![chunks[0].extension_field()]));
+        let batches = chunks
+            .into_iter()
+            .map(|chunk| RecordBatch::try_new(schema.clone(), vec![chunk.into_array_ref()]))
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .unwrap();
+        let table = GeoTable::try_new(schema, batches, 0).unwrap();
+        let geometry = table.geometry().unwrap();
+        assert_eq!(geometry.chunk_null_counts(), vec![1, 2, 0]);
+        assert_eq!(geometry.null_count(), 3);
+
+        let table = table.drop_null_rows().unwrap();
+        assert_eq!(table.len(), 2);
+        assert_eq!(table.batches().len(), 2);
+        assert_eq!(table.geometry().unwrap().null_count(), 0);
+    }
+
+    #[test]
+    fn split_batches() {
+        let points: PointArray = vec![
+            Some(crate::test::point::p0()),
+            None,
+            Some(crate::test::point::p1()),
+            Some(crate::test::point::p2()),
+            Some(crate::test::point::p0()),
+        ]
+        .into();
+        let wkb_array = crate::io::wkb::to_wkb::<i32>(&points);
+        let schema = Arc::new(arrow_schema::Schema::new(vec![wkb_array.extension_field()]));
+        let batch = RecordBatch::try_new(schema.clone(), vec![wkb_array.into_array_ref()]).unwrap();
+        let table = GeoTable::try_new(schema, vec![batch], 0).unwrap();
+
+        let split = table.split_batches(2).unwrap();
+        let batch_lengths = split
+            .batches()
+            .iter()
+            .map(|batch| batch.num_rows())
+            .collect::<Vec<_>>();
+        assert_eq!(batch_lengths, vec![2, 2, 1]);
+        assert!(table.split_batches(0).is_err());
+
+        let parsed = split
+            .parse_geometry_to_native(None, DimensionPolicy::Error, CurvePolicy::Error, false)
+            .unwrap();
+        assert_eq!(parsed.batches().len(), 3);
+        let geometry = parsed.geometry().unwrap();
+        let geometry = geometry.as_ref().as_point();
+        let parsed_points = geometry
+            .chunks()
+            .iter()
+            .flat_map(|chunk| chunk.iter_geo().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(parsed_points, points.iter_geo().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn memory_size() {
+        let table = crate::test::point::table();
+        let batch = &table.batches()[0];
+        let geometry_size = table.geometry().unwrap().buffer_sizes().total();
+        assert_eq!(geometry_size, 3 * 2 * 8);
+        let attribute_size =
+            batch.column(0).get_array_memory_size() + batch.column(1).get_array_memory_size();
+        assert_eq!(table.memory_size().unwrap(), geometry_size + attribute_size);
+
+        // The geometry buffers of sliced batches are counted once
+        let split = table.split_batches(1).unwrap();
+        assert_eq!(
+            split.geometry().unwrap().buffer_sizes().total(),
+            geometry_size
+        );
+    }
+
+    #[test]
+    fn take() {
+        let table = crate::test::point::table();
+        let batch = table.batches()[0].clone();
+        let table =
+            GeoTable::try_new(table.schema().clone(), vec![batch.clone(), batch], 2).unwrap();
+
+        let indices = UInt32Array::from(vec![Some(4), None, Some(0)]);
+        let taken = table.take(&indices).unwrap();
+        assert_eq!(taken.len(), 3);
+        assert_eq!(taken.batches().len(), 1);
+
+        let batch = &taken.batches()[0];
+        let strings = batch.column(1).as_string::<i32>();
+        assert_eq!(strings.value(0), "bar");
+        assert!(strings.is_null(1));
+        assert_eq!(strings.value(2), "foo");
+
+        let geometry = taken.geometry().unwrap();
+        let points = geometry.as_ref().as_point();
+        let chunk = &points.chunks()[0];
+        assert_eq!(chunk.value_as_geo(0), crate::test::point::p1());
+        assert!(chunk.is_null(1));
+        assert_eq!(chunk.value_as_geo(2), crate::test::point::p0());
+
+        assert!(table.take(&UInt32Array::from(vec![6])).is_err());
+    }
+
+    #[test]
+    fn set_crs() {
+        let mut table = crate::test::point::table();
+        assert_eq!(table.crs(2).unwrap(), None);
+        assert!(table.crs(0).is_err());
+        assert!(table.crs(3).is_err());
+
+        let crs = json!({"id": {"authority": "EPSG", "code": 4326}});
+        table.set_crs(2, Some(crs.clone())).unwrap();
+        assert_eq!(table.crs(2).unwrap(), Some(crs.clone()));
+        assert_eq!(table.batches()[0].schema(), *table.schema());
+        // The geometry arrays carry the CRS of the field
+        assert_eq!(table.geometry().unwrap().metadata().crs, Some(crs.clone()));
+
+        let casted = table
+            .cast_geometry(&GeoDataType::MultiPoint(Default::default()))
+            .unwrap();
+        assert_eq!(casted.crs(2).unwrap(), Some(crs));
+
+        table.set_crs(2, None).unwrap();
+        assert_eq!(table.crs(2).unwrap(), None);
+    }
+
+    #[test]
+    fn remove_column() {
+        let table = crate::test::point::table();
+        let metadata = HashMap::from([("key".to_string(), "value".to_string())]);
+        let schema = Arc::new(
+            table
+                .schema()
+                .as_ref()
+                .clone()
+                .with_metadata(metadata.clone()),
+        );
+        let batches = table
+            .batches()
+            .iter()
+            .map(|batch| batch.clone().with_schema(schema.clone()).unwrap())
+            .collect();
+        let mut table = GeoTable::try_new(schema, batches, 2).unwrap();
+
+        let (field, column) = table.remove_column(0);
+        assert_eq!(field.name(), "u8");
+        assert_eq!(column.chunks()[0].len(), 3);
+        assert_eq!(table.geometry_column_index(), 1);
+        assert_eq!(table.schema().metadata(), &metadata);
+        assert_eq!(table.batches()[0].schema().metadata(), &metadata);
+        assert_eq!(table.schema().fields().len(), 2);
+        assert!(table.geometry().is_ok());
+
+        let (field, _) = table.remove_column_by_name("string").unwrap();
+        assert_eq!(field.name(), "string");
+        assert_eq!(table.geometry_column_index(), 0);
+        let geometry_name = table.schema().field(0).name().clone();
+        assert!(table.remove_column_by_name(&geometry_name).is_err());
+        assert!(table.remove_column_by_name("missing").is_err());
+    }
+
+    #[test]
+    fn geometry_column_by_name() {
+        let mut table = crate::test::point::table();
+        let other = crate::test::point::point_array();
+        let other_field = Arc::new(other.extension_field().as_ref().clone().with_name("other"));
+        table
+            .append_column(other_field, ChunkedArray::new(vec![other.into_array_ref()]))
+            .unwrap();
+        let geometry_name = table.schema().field(2).name().clone();
+
+        assert_eq!(table.column_index("u8").unwrap(), Some(0));
+        assert_eq!(table.column_index("other").unwrap(), Some(3));
+        assert_eq!(table.column_index("missing").unwrap(), None);
+
+        let geometry = table.geometry_column_by_name(&geometry_name).unwrap();
+        assert_eq!(geometry.geometry_chunks()[0].len(), 3);
+        let other = table.geometry_column_by_name("other").unwrap();
+        assert!(matches!(other.data_type(), GeoDataType::Point(_)));
+        let err = table.geometry_column_by_name("u8").unwrap_err();
+        assert!(err.to_string().contains("not tagged as a geometry column"));
+        assert!(table.geometry_column_by_name("missing").is_err());
+
+        let casted = table
+            .cast_geometry_by_name("other", &GeoDataType::MultiPoint(Default::default()))
+            .unwrap();
+        assert_eq!(casted.geometry_column_index(), 2);
+        assert!(matches!(
+            casted.geometry_data_type().unwrap(),
+            GeoDataType::Point(_)
+        ));
+        let other = casted.geometry_column(3).unwrap();
+        assert!(matches!(other.data_type(), GeoDataType::MultiPoint(_)));
+
+        // Duplicate names are ambiguous
+        let duplicate = Arc::new(Field::new("u8", DataType::UInt8, true));
+        let column = table.batches()[0].column(0).clone();
+        table
+            .append_column(duplicate, ChunkedArray::new(vec![column]))
+            .unwrap();
+        assert!(table.column_index("u8").is_err());
+        assert!(table
+            .cast_geometry_by_name("u8", &GeoDataType::WKB)
+            .is_err());
+    }
+}
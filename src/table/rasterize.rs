@@ -0,0 +1,81 @@
+//! Rasterizing the geometry column of a table, optionally weighted by another column.
+
+use arrow::compute::cast;
+use arrow_array::cast::AsArray;
+use arrow_array::types::Float64Type;
+use arrow_schema::DataType;
+use geo::Rect;
+
+use crate::algorithm::native::{Grid, Raster, RasterizeMode};
+use crate::error::Result;
+use crate::table::GeoTable;
+
+impl GeoTable {
+    /// Rasterize the geometry column onto a grid of `width` by `height` cells covering `bounds`,
+    /// as with [`rasterize`][crate::algorithm::native::rasterize].
+    ///
+    /// With [`RasterizeMode::AttributeSum`], each cell is the sum of the values of the column
+    /// with the given name for the geometries that cover it. The column is cast to `Float64`, so
+    /// it can be any numeric column.
+    ///
+    /// Returns an error if the attribute column doesn't exist or can't be cast to `Float64`.
+    pub fn rasterize(
+        &self,
+        bounds: &Rect,
+        width: usize,
+        height: usize,
+        mode: RasterizeMode<&str>,
+    ) -> Result<Raster> {
+        let mode = match mode {
+            RasterizeMode::Binary => RasterizeMode::Binary,
+            RasterizeMode::Count => RasterizeMode::Count,
+            RasterizeMode::AttributeSum(name) => {
+                RasterizeMode::AttributeSum(self.schema().index_of(name)?)
+            }
+        };
+
+        let mut grid = Grid::try_new(bounds, width, height)?;
+        let geometry = self.geometry()?;
+        for (batch, chunk) in self.batches().iter().zip(geometry.geometry_chunks()) {
+            match mode {
+                RasterizeMode::Binary => grid.add_array(chunk, RasterizeMode::Binary)?,
+                RasterizeMode::Count => grid.add_array(chunk, RasterizeMode::Count)?,
+                RasterizeMode::AttributeSum(index) => {
+                    let attribute = cast(batch.column(index), &DataType::Float64)?;
+                    let attribute = attribute.as_primitive::<Float64Type>();
+                    grid.add_array(chunk, RasterizeMode::AttributeSum(attribute))?;
+                }
+            }
+        }
+        Ok(grid.finish())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::point;
+
+    #[test]
+    fn rasterize_attribute_sum() {
+        // Three points at (0, 1), (1, 2) and (2, 3)
+        let table = point::table();
+        let bounds = Rect::new((0., 0.), (4., 4.));
+
+        let raster = table
+            .rasterize(&bounds, 4, 4, RasterizeMode::AttributeSum("u8"))
+            .unwrap();
+        assert_eq!(raster.values().value(3 * 4), 1.);
+        assert_eq!(raster.values().value(2 * 4 + 1), 2.);
+        assert_eq!(raster.values().value(4 + 2), 3.);
+
+        let raster = table
+            .rasterize(&bounds, 4, 4, RasterizeMode::Count)
+            .unwrap();
+        assert_eq!(raster.values().values().iter().sum::<f64>(), 3.);
+
+        assert!(table
+            .rasterize(&bounds, 4, 4, RasterizeMode::AttributeSum("missing"))
+            .is_err());
+    }
+}
@@ -0,0 +1,139 @@
+#[cfg(feature = "stream")]
+use std::pin::Pin;
+#[cfg(feature = "stream")]
+use std::task::{Context, Poll};
+
+use arrow_array::{RecordBatch, RecordBatchIterator, RecordBatchReader};
+use arrow_schema::{ArrowError, SchemaRef};
+#[cfg(feature = "stream")]
+use futures::Stream;
+
+use crate::table::GeoTable;
+
+impl GeoTable {
+    /// Convert this table to a [`RecordBatchReader`] over its batches, for APIs that consume one,
+    /// such as the Arrow C stream interface.
+    ///
+    /// Every batch is emitted with the table schema, so its schema metadata and the GeoArrow
+    /// extension metadata of its geometry column are kept.
+    pub fn into_reader(self) -> impl RecordBatchReader + Send {
+        let (schema, batches, _) = self.into_inner();
+        let batch_schema = schema.clone();
+        let batches = batches
+            .into_iter()
+            .map(move |batch| with_schema(batch_schema.clone(), batch));
+        RecordBatchIterator::new(batches, schema)
+    }
+
+    /// Convert this table to an async [`Stream`] over its batches, which also exposes the table
+    /// schema.
+    ///
+    /// As with [`into_reader`][Self::into_reader], every batch is emitted with the table schema.
+    #[cfg(feature = "stream")]
+    pub fn into_stream(self) -> GeoTableStream {
+        let (schema, batches, _) = self.into_inner();
+        GeoTableStream {
+            schema,
+            batches: batches.into_iter(),
+        }
+    }
+}
+
+/// Replace the schema of a batch with the table schema, which may have metadata the batch doesn't.
+fn with_schema(schema: SchemaRef, batch: RecordBatch) -> Result<RecordBatch, ArrowError> {
+    RecordBatch::try_new(schema, batch.columns().to_vec())
+}
+
+/// An async stream of the batches of a [`GeoTable`], created with [`GeoTable::into_stream`].
+#[cfg(feature = "stream")]
+#[derive(Debug)]
+pub struct GeoTableStream {
+    schema: SchemaRef,
+    batches: std::vec::IntoIter<RecordBatch>,
+}
+
+#[cfg(feature = "stream")]
+impl GeoTableStream {
+    /// The schema of every batch in this stream.
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+}
+
+#[cfg(feature = "stream")]
+impl Stream for GeoTableStream {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let schema = self.schema.clone();
+        Poll::Ready(self.batches.next().map(|batch| with_schema(schema, batch)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.batches.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arrow_schema::Schema;
+
+    use super::*;
+
+    /// The point table with its batches stripped of schema metadata.
+    fn table() -> GeoTable {
+        let table = crate::test::point::table();
+        let batch_schema = Arc::new(Schema::new(
+            table
+                .schema()
+                .fields()
+                .iter()
+                .map(|field| field.as_ref().clone().with_metadata(Default::default()))
+                .collect::<Vec<_>>(),
+        ));
+        let batches = table
+            .batches()
+            .iter()
+            .map(|batch| RecordBatch::try_new(batch_schema.clone(), batch.columns().to_vec()))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        GeoTable::try_new(
+            table.schema().clone(),
+            batches,
+            table.geometry_column_index(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn into_reader() {
+        let table = table();
+        let schema = table.schema().clone();
+        let num_rows = table.len();
+
+        let reader = table.into_reader();
+        assert_eq!(reader.schema(), schema);
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert!(batches.iter().all(|batch| batch.schema() == schema));
+        assert_eq!(
+            batches.iter().map(|batch| batch.num_rows()).sum::<usize>(),
+            num_rows
+        );
+    }
+
+    #[cfg(feature = "stream")]
+    #[test]
+    fn into_stream() {
+        use futures::TryStreamExt;
+
+        let table = table();
+        let schema = table.schema().clone();
+
+        let stream = table.into_stream();
+        assert_eq!(stream.schema(), schema);
+        let batches = futures::executor::block_on(stream.try_collect::<Vec<_>>()).unwrap();
+        assert!(batches.iter().all(|batch| batch.schema() == schema));
+    }
+}
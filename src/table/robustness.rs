@@ -0,0 +1,148 @@
+//! Summaries of how numerically fragile the geometries of a table are.
+
+use arrow_array::Float64Array;
+
+use crate::algorithm::native::MinimumSegmentLength;
+use crate::chunked_array::from_arrow_chunks;
+use crate::error::Result;
+use crate::table::GeoTable;
+
+/// Summary statistics of a per-geometry metric over a geometry column.
+///
+/// Null geometries and geometries for which the metric is infinite, such as points, are left out.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DistributionSummary {
+    /// The number of geometries included in the summary.
+    pub count: usize,
+    pub min: f64,
+
+    /// The 5th percentile, using the nearest-rank method.
+    pub p05: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub max: f64,
+}
+
+impl DistributionSummary {
+    /// Summarize the finite values of the given arrays, or `None` if there are no finite values.
+    fn from_arrays(arrays: &[Float64Array]) -> Option<Self> {
+        let mut values = arrays
+            .iter()
+            .flat_map(|arr| arr.iter().flatten())
+            .filter(|value| value.is_finite())
+            .collect::<Vec<_>>();
+        if values.is_empty() {
+            return None;
+        }
+        values.sort_unstable_by(f64::total_cmp);
+
+        let count = values.len();
+        let percentile = |p: f64| values[((p * count as f64).ceil() as usize).clamp(1, count) - 1];
+        Some(Self {
+            count,
+            min: values[0],
+            p05: percentile(0.05),
+            median: percentile(0.5),
+            mean: values.iter().sum::<f64>() / count as f64,
+            max: values[count - 1],
+        })
+    }
+}
+
+/// A summary of how numerically fragile the geometries of a column are, as returned by
+/// [`GeoTable::robustness_report`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RobustnessReport {
+    /// The number of non-null geometries.
+    pub num_geometries: usize,
+
+    /// The distribution of the length of the shortest segment of each geometry. See
+    /// [`MinimumSegmentLength`].
+    pub minimum_segment_length: Option<DistributionSummary>,
+
+    /// The distribution of the minimum clearance of each geometry. This is only computed with the
+    /// `geos` feature.
+    pub minimum_clearance: Option<DistributionSummary>,
+}
+
+impl GeoTable {
+    /// Summarize how numerically fragile the geometries of a column are, before running overlay
+    /// operations on them.
+    ///
+    /// `geometry_column_index` may be the index of any geometry column of the table, including
+    /// WKB columns.
+    pub fn robustness_report(&self, geometry_column_index: usize) -> Result<RobustnessReport> {
+        let field = self.schema().field(geometry_column_index);
+        let chunks = self
+            .batches()
+            .iter()
+            .map(|batch| batch.column(geometry_column_index).as_ref())
+            .collect::<Vec<_>>();
+        let geometry = from_arrow_chunks(&chunks, field)?;
+        let geometry_chunks = geometry.geometry_chunks();
+
+        let num_geometries = geometry_chunks
+            .iter()
+            .map(|chunk| chunk.len() - chunk.null_count())
+            .sum();
+        let segment_lengths = geometry_chunks
+            .iter()
+            .map(|chunk| chunk.minimum_segment_length())
+            .collect::<Result<Vec<_>>>()?;
+
+        #[cfg(feature = "geos")]
+        let minimum_clearance = {
+            use crate::algorithm::geos::MinimumClearance;
+
+            let clearances = geometry_chunks
+                .iter()
+                .map(|chunk| chunk.minimum_clearance())
+                .collect::<Result<Vec<_>>>()?;
+            DistributionSummary::from_arrays(&clearances)
+        };
+        #[cfg(not(feature = "geos"))]
+        let minimum_clearance = None;
+
+        Ok(RobustnessReport {
+            num_geometries,
+            minimum_segment_length: DistributionSummary::from_arrays(&segment_lengths),
+            minimum_clearance,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arrow_array::RecordBatch;
+    use arrow_schema::Schema;
+
+    use super::*;
+    use crate::array::PolygonArray;
+    use crate::GeometryArrayTrait;
+
+    #[test]
+    fn sliver_polygon() {
+        let sliver = geo::polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 5., y: 0.01)];
+        let square = geo::polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        let polygon_array: PolygonArray<i32> = vec![Some(sliver), Some(square), None].into();
+        let schema = Arc::new(Schema::new(vec![polygon_array.extension_field()]));
+        let batch =
+            RecordBatch::try_new(schema.clone(), vec![polygon_array.into_array_ref()]).unwrap();
+        let table = GeoTable::try_new(schema, vec![batch], 0).unwrap();
+
+        let report = table.robustness_report(0).unwrap();
+        assert_eq!(report.num_geometries, 2);
+        let segment_length = report.minimum_segment_length.unwrap();
+        assert_eq!(segment_length.count, 2);
+        assert_eq!(segment_length.min, 1.);
+        assert!((segment_length.max - 0.01_f64.hypot(5.)).abs() < 1e-12);
+
+        #[cfg(feature = "geos")]
+        {
+            let clearance = report.minimum_clearance.unwrap();
+            assert!((clearance.min - 0.01).abs() < 1e-9);
+        }
+    }
+}
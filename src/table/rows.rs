@@ -0,0 +1,277 @@
+//! Iterating over the rows of a table, with typed access to their geometry and attributes.
+
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::{Array, ArrayRef, RecordBatch};
+use arrow_schema::DataType;
+
+use crate::array::{geometry_at, AsChunkedGeometryArray};
+use crate::chunked_array::ChunkedGeometryArrayTrait;
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::table::GeoTable;
+use crate::GeometryArrayTrait;
+
+impl GeoTable {
+    /// Iterate over the rows of the table, across batches.
+    ///
+    /// Each [`Row`] refers to the batches of the table, so no data is copied, and its geometry is
+    /// only converted to a [`geo::Geometry`] when [`Row::geometry`] is called.
+    pub fn rows(&self) -> Result<Rows<'_>> {
+        let geometry = self.geometry()?;
+        Ok(Rows {
+            table: self,
+            geometry_chunks: geometry_chunk_arcs(geometry.as_ref()),
+            batch_index: 0,
+            row: 0,
+            index: 0,
+        })
+    }
+}
+
+/// An iterator over the rows of a [`GeoTable`], as returned by [`GeoTable::rows`].
+#[derive(Debug)]
+pub struct Rows<'a> {
+    table: &'a GeoTable,
+    geometry_chunks: Vec<Arc<dyn GeometryArrayTrait>>,
+    batch_index: usize,
+    row: usize,
+    index: usize,
+}
+
+impl<'a> Iterator for Rows<'a> {
+    type Item = Row<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let batch = self.table.batches().get(self.batch_index)?;
+            if self.row < batch.num_rows() {
+                let row = Row {
+                    batch,
+                    geometry: self.geometry_chunks[self.batch_index].clone(),
+                    row: self.row,
+                    index: self.index,
+                };
+                self.row += 1;
+                self.index += 1;
+                return Some(row);
+            }
+            self.batch_index += 1;
+            self.row = 0;
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.table.len() - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Rows<'_> {}
+
+/// A row of a [`GeoTable`].
+///
+/// Attributes are accessed by column name. Each accessor returns `Ok(None)` if the value is null
+/// and an error if there is no column with that name or if its data type doesn't match the
+/// accessor.
+#[derive(Debug, Clone)]
+pub struct Row<'a> {
+    batch: &'a RecordBatch,
+    geometry: Arc<dyn GeometryArrayTrait>,
+    row: usize,
+    index: usize,
+}
+
+impl<'a> Row<'a> {
+    /// The index of this row in the table.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The geometry of this row, or `None` if it's null.
+    pub fn geometry(&self) -> Option<geo::Geometry> {
+        geometry_at(self.geometry.as_ref(), self.row)
+    }
+
+    /// The column named `name` of the batch of this row, and the index of this row within it.
+    pub fn column(&self, name: &str) -> Result<(&'a ArrayRef, usize)> {
+        let column = self
+            .batch
+            .column_by_name(name)
+            .ok_or_else(|| GeoArrowError::General(format!("Table has no column named {}", name)))?;
+        Ok((column, self.row))
+    }
+
+    /// Whether the value of the column named `name` is null.
+    pub fn is_null(&self, name: &str) -> Result<bool> {
+        let (column, row) = self.column(name)?;
+        Ok(column.is_null(row))
+    }
+
+    /// The value of the `Utf8` or `LargeUtf8` column named `name`.
+    pub fn get_string(&self, name: &str) -> Result<Option<&'a str>> {
+        let (column, row) = self.column(name)?;
+        if column.is_null(row) {
+            return Ok(None);
+        }
+        match column.data_type() {
+            DataType::Utf8 => Ok(Some(column.as_string::<i32>().value(row))),
+            DataType::LargeUtf8 => Ok(Some(column.as_string::<i64>().value(row))),
+            data_type => Err(mismatched_type(name, data_type, "a string")),
+        }
+    }
+
+    /// The value of the boolean column named `name`.
+    pub fn get_bool(&self, name: &str) -> Result<Option<bool>> {
+        let (column, row) = self.column(name)?;
+        if column.is_null(row) {
+            return Ok(None);
+        }
+        match column.data_type() {
+            DataType::Boolean => Ok(Some(column.as_boolean().value(row))),
+            data_type => Err(mismatched_type(name, data_type, "a bool")),
+        }
+    }
+
+    /// The value of the integer column named `name`.
+    ///
+    /// Returns an error for a `UInt64` value that doesn't fit an `i64`.
+    pub fn get_i64(&self, name: &str) -> Result<Option<i64>> {
+        let (column, row) = self.column(name)?;
+        if column.is_null(row) {
+            return Ok(None);
+        }
+        let value = match column.data_type() {
+            DataType::Int8 => column.as_primitive::<Int8Type>().value(row).into(),
+            DataType::Int16 => column.as_primitive::<Int16Type>().value(row).into(),
+            DataType::Int32 => column.as_primitive::<Int32Type>().value(row).into(),
+            DataType::Int64 => column.as_primitive::<Int64Type>().value(row),
+            DataType::UInt8 => column.as_primitive::<UInt8Type>().value(row).into(),
+            DataType::UInt16 => column.as_primitive::<UInt16Type>().value(row).into(),
+            DataType::UInt32 => column.as_primitive::<UInt32Type>().value(row).into(),
+            DataType::UInt64 => {
+                let value = column.as_primitive::<UInt64Type>().value(row);
+                i64::try_from(value).map_err(|_| {
+                    GeoArrowError::General(format!(
+                        "Value {} of column {} doesn't fit an i64",
+                        value, name
+                    ))
+                })?
+            }
+            data_type => return Err(mismatched_type(name, data_type, "an integer")),
+        };
+        Ok(Some(value))
+    }
+
+    /// The value of the float or integer column named `name`, as an `f64`.
+    ///
+    /// Integers with more than 53 significant bits are rounded.
+    pub fn get_f64(&self, name: &str) -> Result<Option<f64>> {
+        let (column, row) = self.column(name)?;
+        if column.is_null(row) {
+            return Ok(None);
+        }
+        let value = match column.data_type() {
+            DataType::Float16 => column.as_primitive::<Float16Type>().value(row).to_f64(),
+            DataType::Float32 => column.as_primitive::<Float32Type>().value(row).into(),
+            DataType::Float64 => column.as_primitive::<Float64Type>().value(row),
+            DataType::Int8 => column.as_primitive::<Int8Type>().value(row).into(),
+            DataType::Int16 => column.as_primitive::<Int16Type>().value(row).into(),
+            DataType::Int32 => column.as_primitive::<Int32Type>().value(row).into(),
+            DataType::Int64 => column.as_primitive::<Int64Type>().value(row) as f64,
+            DataType::UInt8 => column.as_primitive::<UInt8Type>().value(row).into(),
+            DataType::UInt16 => column.as_primitive::<UInt16Type>().value(row).into(),
+            DataType::UInt32 => column.as_primitive::<UInt32Type>().value(row).into(),
+            DataType::UInt64 => column.as_primitive::<UInt64Type>().value(row) as f64,
+            data_type => return Err(mismatched_type(name, data_type, "a number")),
+        };
+        Ok(Some(value))
+    }
+}
+
+fn mismatched_type(name: &str, data_type: &DataType, expected: &str) -> GeoArrowError {
+    GeoArrowError::IncorrectType(
+        format!(
+            "Column {} has data type {}, which isn't {}",
+            name, data_type, expected
+        )
+        .into(),
+    )
+}
+
+/// The chunks of a chunked geometry array as trait objects, sharing their buffers.
+fn geometry_chunk_arcs(
+    chunked: &dyn ChunkedGeometryArrayTrait,
+) -> Vec<Arc<dyn GeometryArrayTrait>> {
+    macro_rules! impl_chunks {
+        ($cast_func:ident) => {
+            chunked
+                .$cast_func()
+                .chunks()
+                .iter()
+                .map(|chunk| Arc::new(chunk.clone()) as Arc<dyn GeometryArrayTrait>)
+                .collect()
+        };
+    }
+
+    match chunked.data_type() {
+        GeoDataType::Point(_) => impl_chunks!(as_point),
+        GeoDataType::LineString(_) => impl_chunks!(as_line_string),
+        GeoDataType::LargeLineString(_) => impl_chunks!(as_large_line_string),
+        GeoDataType::Polygon(_) => impl_chunks!(as_polygon),
+        GeoDataType::LargePolygon(_) => impl_chunks!(as_large_polygon),
+        GeoDataType::MultiPoint(_) => impl_chunks!(as_multi_point),
+        GeoDataType::LargeMultiPoint(_) => impl_chunks!(as_large_multi_point),
+        GeoDataType::MultiLineString(_) => impl_chunks!(as_multi_line_string),
+        GeoDataType::LargeMultiLineString(_) => impl_chunks!(as_large_multi_line_string),
+        GeoDataType::MultiPolygon(_) => impl_chunks!(as_multi_polygon),
+        GeoDataType::LargeMultiPolygon(_) => impl_chunks!(as_large_multi_polygon),
+        GeoDataType::Mixed(_) => impl_chunks!(as_mixed),
+        GeoDataType::LargeMixed(_) => impl_chunks!(as_large_mixed),
+        GeoDataType::GeometryCollection(_) => impl_chunks!(as_geometry_collection),
+        GeoDataType::LargeGeometryCollection(_) => impl_chunks!(as_large_geometry_collection),
+        GeoDataType::Rect => impl_chunks!(as_rect),
+        GeoDataType::WKB => impl_chunks!(as_wkb),
+        GeoDataType::LargeWKB => impl_chunks!(as_large_wkb),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::point;
+
+    #[test]
+    fn iterate_rows() {
+        let table = point::table();
+        let rows = table.rows().unwrap().collect::<Vec<_>>();
+        assert_eq!(rows.len(), 3);
+
+        let row = &rows[1];
+        assert_eq!(row.index(), 1);
+        assert_eq!(row.geometry(), Some(point::p1().into()));
+        assert_eq!(row.get_i64("u8").unwrap(), Some(2));
+        assert_eq!(row.get_f64("u8").unwrap(), Some(2.));
+        assert_eq!(row.get_string("string").unwrap(), Some("bar"));
+        assert!(!row.is_null("string").unwrap());
+        assert!(row.get_string("u8").is_err());
+        assert!(row.get_bool("string").is_err());
+        assert!(row.get_f64("missing").is_err());
+    }
+
+    #[test]
+    fn iterate_rows_across_batches() {
+        let table = point::table();
+        let batches = vec![table.batches()[0].clone(), table.batches()[0].slice(1, 2)];
+        let table = GeoTable::try_new(table.schema().clone(), batches, 2).unwrap();
+
+        let rows = table.rows().unwrap();
+        assert_eq!(rows.len(), 5);
+        let values = rows
+            .map(|row| (row.index(), row.get_i64("u8").unwrap().unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(values, vec![(0, 1), (1, 2), (2, 3), (3, 2), (4, 3)]);
+    }
+}
@@ -0,0 +1,247 @@
+//! Per-column geometry statistics, as inputs to the cost estimates in
+//! [`cost`][crate::algorithm::cost].
+
+use arrow_array::OffsetSizeTrait;
+use arrow_buffer::OffsetBuffer;
+use geo::CoordsIter;
+
+use crate::array::*;
+use crate::chunked_array::from_arrow_chunks;
+use crate::datatypes::GeoDataType;
+use crate::error::Result;
+use crate::table::GeoTable;
+use crate::trait_::GeometryArrayAccessor;
+use crate::GeometryArrayTrait;
+
+/// The size of the WKB header of a geometry: the byte order, the geometry type and the number of
+/// points, rings or parts.
+const WKB_HEADER_SIZE: usize = 9;
+
+/// The number of coordinates of the geometries of a column, as returned by
+/// [`GeoTable::column_statistics`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ColumnStatistics {
+    /// The number of non-null geometries.
+    pub count: usize,
+
+    /// The mean number of coordinates of the non-null geometries, or `0.` if there are none.
+    pub avg_coords_per_geom: f64,
+
+    /// The largest number of coordinates of any geometry.
+    pub max_coords_per_geom: usize,
+}
+
+impl ColumnStatistics {
+    fn add_geometry(&mut self, num_coords: usize) {
+        // Keep the running sum in the average field until all geometries are added
+        self.count += 1;
+        self.avg_coords_per_geom += num_coords as f64;
+        self.max_coords_per_geom = self.max_coords_per_geom.max(num_coords);
+    }
+}
+
+impl GeoTable {
+    /// Count the coordinates per geometry of a geometry column, as needed by the cost estimates
+    /// in [`cost`][crate::algorithm::cost].
+    ///
+    /// For GeoArrow-native arrays other than mixed and geometry collection arrays, this only
+    /// reads the offsets and is cheap. Mixed and geometry collection arrays visit every geometry.
+    /// For WKB columns, the number of coordinates is estimated from the size of each geometry,
+    /// assuming 2D coordinates, so it is slightly too high for geometries with many parts or rings.
+    pub fn column_statistics(&self, geometry_column_index: usize) -> Result<ColumnStatistics> {
+        let field = self.schema().field(geometry_column_index);
+        let chunks = self
+            .batches()
+            .iter()
+            .map(|batch| batch.column(geometry_column_index).as_ref())
+            .collect::<Vec<_>>();
+        let geometry = from_arrow_chunks(&chunks, field)?;
+
+        let mut statistics = ColumnStatistics::default();
+        for chunk in geometry.geometry_chunks() {
+            add_chunk(&mut statistics, chunk);
+        }
+        if statistics.count > 0 {
+            statistics.avg_coords_per_geom /= statistics.count as f64;
+        }
+        Ok(statistics)
+    }
+}
+
+fn add_chunk(statistics: &mut ColumnStatistics, arr: &dyn GeometryArrayTrait) {
+    use GeoDataType::*;
+    match arr.data_type() {
+        Point(_) => add_constant(statistics, arr, 1),
+        Rect => add_constant(statistics, arr, 2),
+        LineString(_) => add_offsets(statistics, arr, arr.as_line_string().geom_offsets(), &[]),
+        LargeLineString(_) => add_offsets(
+            statistics,
+            arr,
+            arr.as_large_line_string().geom_offsets(),
+            &[],
+        ),
+        Polygon(_) => {
+            let arr = arr.as_polygon();
+            add_offsets(statistics, arr, arr.geom_offsets(), &[arr.ring_offsets()])
+        }
+        LargePolygon(_) => {
+            let arr = arr.as_large_polygon();
+            add_offsets(statistics, arr, arr.geom_offsets(), &[arr.ring_offsets()])
+        }
+        MultiPoint(_) => add_offsets(statistics, arr, arr.as_multi_point().geom_offsets(), &[]),
+        LargeMultiPoint(_) => add_offsets(
+            statistics,
+            arr,
+            arr.as_large_multi_point().geom_offsets(),
+            &[],
+        ),
+        MultiLineString(_) => {
+            let arr = arr.as_multi_line_string();
+            add_offsets(statistics, arr, arr.geom_offsets(), &[arr.ring_offsets()])
+        }
+        LargeMultiLineString(_) => {
+            let arr = arr.as_large_multi_line_string();
+            add_offsets(statistics, arr, arr.geom_offsets(), &[arr.ring_offsets()])
+        }
+        MultiPolygon(_) => {
+            let arr = arr.as_multi_polygon();
+            add_offsets(
+                statistics,
+                arr,
+                arr.geom_offsets(),
+                &[arr.polygon_offsets(), arr.ring_offsets()],
+            )
+        }
+        LargeMultiPolygon(_) => {
+            let arr = arr.as_large_multi_polygon();
+            add_offsets(
+                statistics,
+                arr,
+                arr.geom_offsets(),
+                &[arr.polygon_offsets(), arr.ring_offsets()],
+            )
+        }
+        Mixed(_) => add_geo(statistics, arr.as_mixed().iter_geo()),
+        LargeMixed(_) => add_geo(statistics, arr.as_large_mixed().iter_geo()),
+        GeometryCollection(_) => add_geo(statistics, arr.as_geometry_collection().iter_geo()),
+        LargeGeometryCollection(_) => {
+            add_geo(statistics, arr.as_large_geometry_collection().iter_geo())
+        }
+        WKB => add_wkb(statistics, arr.as_wkb()),
+        LargeWKB => add_wkb(statistics, arr.as_large_wkb()),
+    }
+}
+
+fn add_constant(
+    statistics: &mut ColumnStatistics,
+    arr: &dyn GeometryArrayTrait,
+    num_coords: usize,
+) {
+    for i in 0..arr.len() {
+        if arr.is_valid(i) {
+            statistics.add_geometry(num_coords);
+        }
+    }
+}
+
+/// Add the geometries of an array whose geometries are given by `geom_offsets` into the first of
+/// `nested_offsets`, which each index into the next, with the last indexing into the coordinates.
+fn add_offsets<O: OffsetSizeTrait>(
+    statistics: &mut ColumnStatistics,
+    arr: &dyn GeometryArrayTrait,
+    geom_offsets: &OffsetBuffer<O>,
+    nested_offsets: &[&OffsetBuffer<O>],
+) {
+    for (i, window) in geom_offsets.windows(2).enumerate() {
+        if arr.is_valid(i) {
+            let (mut start, mut end) = (window[0].as_usize(), window[1].as_usize());
+            for offsets in nested_offsets {
+                (start, end) = (offsets[start].as_usize(), offsets[end].as_usize());
+            }
+            statistics.add_geometry(end - start);
+        }
+    }
+}
+
+fn add_geo(
+    statistics: &mut ColumnStatistics,
+    geometries: impl Iterator<Item = Option<geo::Geometry>>,
+) {
+    for geometry in geometries.flatten() {
+        statistics.add_geometry(geometry.coords_count());
+    }
+}
+
+fn add_wkb<O: OffsetSizeTrait>(statistics: &mut ColumnStatistics, arr: &WKBArray<O>) {
+    for wkb in arr.iter().flatten() {
+        let num_bytes = wkb.as_ref().len();
+        statistics.add_geometry(num_bytes.saturating_sub(WKB_HEADER_SIZE) / 16);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::io::wkb::to_wkb;
+    use crate::test::{multipolygon, point, polygon};
+    use arrow_array::RecordBatch;
+    use arrow_schema::Schema;
+    use std::sync::Arc;
+
+    fn table(arr: &dyn GeometryArrayTrait) -> GeoTable {
+        let schema = Arc::new(Schema::new(vec![arr.extension_field()]));
+        let batch = RecordBatch::try_new(schema.clone(), vec![arr.to_array_ref()]).unwrap();
+        GeoTable::try_new(schema, vec![batch], 0).unwrap()
+    }
+
+    #[test]
+    fn native_statistics() {
+        let statistics = point::table().column_statistics(2).unwrap();
+        assert_eq!(statistics.count, 3);
+        assert_eq!(statistics.avg_coords_per_geom, 1.);
+        assert_eq!(statistics.max_coords_per_geom, 1);
+
+        let arr = polygon::p_array();
+        let statistics = table(&arr).column_statistics(0).unwrap();
+        let counts = arr
+            .iter_geo_values()
+            .map(|geom| geom.coords_count())
+            .collect::<Vec<_>>();
+        assert_eq!(statistics.count, counts.len());
+        assert_eq!(
+            statistics.avg_coords_per_geom,
+            counts.iter().sum::<usize>() as f64 / counts.len() as f64
+        );
+        assert_eq!(
+            statistics.max_coords_per_geom,
+            *counts.iter().max().unwrap()
+        );
+
+        let arr = multipolygon::mp_array();
+        let statistics = table(&arr).column_statistics(0).unwrap();
+        let counts = arr
+            .iter_geo_values()
+            .map(|geom| geom.coords_count())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            statistics.max_coords_per_geom,
+            *counts.iter().max().unwrap()
+        );
+        assert_eq!(
+            statistics.avg_coords_per_geom,
+            counts.iter().sum::<usize>() as f64 / counts.len() as f64
+        );
+    }
+
+    #[test]
+    fn wkb_statistics() {
+        let arr = polygon::p_array();
+        let wkb = to_wkb::<i32>(&arr);
+        let native = table(&arr).column_statistics(0).unwrap();
+        let estimated = table(&wkb).column_statistics(0).unwrap();
+        assert_eq!(estimated.count, native.count);
+        // The 4 byte coordinate count of each ring only adds a coordinate for every fourth ring
+        assert_eq!(estimated.avg_coords_per_geom, native.avg_coords_per_geom);
+        assert_eq!(estimated.max_coords_per_geom, native.max_coords_per_geom);
+    }
+}
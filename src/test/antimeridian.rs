@@ -0,0 +1,47 @@
+//! Geometries crossing the antimeridian.
+
+use geo::{line_string, polygon, LineString, Polygon};
+
+/// A polygon around the Fiji islands, which straddle the antimeridian.
+pub(crate) fn fiji() -> Polygon {
+    polygon![
+        (x: 177.0, y: -16.0),
+        (x: 177.0, y: -19.0),
+        (x: -178.0, y: -19.0),
+        (x: -178.0, y: -16.0),
+        (x: 179.0, y: -17.0),
+    ]
+}
+
+/// A flight path from Tokyo to Los Angeles via Honolulu, crossing the antimeridian once.
+pub(crate) fn transpacific_flight() -> LineString {
+    line_string![
+        (x: 139.78, y: 35.55),
+        (x: 160.0, y: 40.0),
+        (x: 175.0, y: 38.0),
+        (x: -170.0, y: 32.0),
+        (x: -157.92, y: 21.32),
+        (x: -140.0, y: 28.0),
+        (x: -118.41, y: 33.94),
+    ]
+}
+
+/// A flight path from Auckland to Nadi via Nuku'alofa, crossing the antimeridian twice.
+pub(crate) fn tonga_loop() -> LineString {
+    line_string![
+        (x: 174.79, y: -37.01),
+        (x: -175.15, y: -21.24),
+        (x: 177.44, y: -17.76),
+    ]
+}
+
+/// A polygon enclosing the north pole, crossing the antimeridian once.
+pub(crate) fn north_pole_cap() -> Polygon {
+    polygon![
+        (x: 0.0, y: 80.0),
+        (x: 90.0, y: 80.0),
+        (x: 170.0, y: 80.0),
+        (x: -170.0, y: 80.0),
+        (x: -90.0, y: 80.0),
+    ]
+}
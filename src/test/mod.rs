@@ -1,3 +1,4 @@
+pub mod antimeridian;
 pub mod binary;
 pub mod coord;
 pub mod geoarrow_data;
@@ -7,8 +7,53 @@ use arrow_array::{Array, ArrayRef};
 use arrow_buffer::{NullBuffer, NullBufferBuilder};
 use arrow_schema::{DataType, Field};
 use std::any::Any;
+use std::iter::Sum;
+use std::ops::Add;
 use std::sync::Arc;
 
+/// The number of bytes used by each kind of buffer of a geometry array, as returned by
+/// [`GeometryArrayTrait::buffer_sizes`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BufferSizes {
+    /// The coordinate buffers, or the values of a rect array.
+    pub coords: usize,
+
+    /// The offset buffers, and the type ids of mixed arrays.
+    pub offsets: usize,
+
+    /// The validity buffers.
+    pub validity: usize,
+
+    /// Any other data, such as the bytes of WKB geometries.
+    pub data: usize,
+}
+
+impl BufferSizes {
+    /// The total number of bytes.
+    pub fn total(&self) -> usize {
+        self.coords + self.offsets + self.validity + self.data
+    }
+}
+
+impl Add for BufferSizes {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            coords: self.coords + rhs.coords,
+            offsets: self.offsets + rhs.offsets,
+            validity: self.validity + rhs.validity,
+            data: self.data + rhs.data,
+        }
+    }
+}
+
+impl Sum for BufferSizes {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |sum, sizes| sum + sizes)
+    }
+}
+
 /// A trait of common methods that all geometry arrays in this crate implement.
 ///
 /// This trait is often used for downcasting. If you have a dynamically-typed `Arc<dyn
@@ -113,6 +158,14 @@ pub trait GeometryArrayTrait: std::fmt::Debug + Send + Sync {
 
     fn metadata(&self) -> Arc<ArrayMetadata>;
 
+    /// The number of bytes used by the buffers of this array, split by kind of buffer.
+    ///
+    /// A sliced array shares its buffers with the array it was sliced from, so this counts only
+    /// the part of the buffers covered by the slice: the memory the array would use once copied,
+    /// e.g. with [`owned_slice`][GeometryArraySelfMethods::owned_slice]. The one exception is the
+    /// child arrays of mixed and geometry collection arrays, which are counted in full.
+    fn buffer_sizes(&self) -> BufferSizes;
+
     /// The number of null slots in this array.
     /// # Implementation
     /// This is `O(1)` since the number of null elements is pre-computed.
@@ -241,6 +294,11 @@ pub trait GeometryArraySelfMethods {
     /// Cast the coordinate buffer of this geometry array to the given coordinate type.
     fn into_coord_type(self, coord_type: CoordType) -> Self;
 
+    /// Replace the metadata of this geometry array, such as its CRS, keeping its geometries.
+    ///
+    /// Coordinate buffers don't have metadata, so they are returned unchanged.
+    fn with_metadata(self, metadata: Arc<ArrayMetadata>) -> Self;
+
     /// Returns a zero-copy slice of this array with the indicated offset and length.
     ///
     /// # Panic
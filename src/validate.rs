@@ -0,0 +1,641 @@
+//! Check that Arrow data follows the [GeoArrow specification](https://geoarrow.org/format).
+//!
+//! This is stricter than the readers of this crate, which accept some deviations from the
+//! specification, such as misnamed child fields. It is meant for producers of GeoArrow data to
+//! check their output.
+//!
+//! ```
+//! use geoarrow::validate::validate_geoarrow_field;
+//! use geoarrow::array::PointArray;
+//! use geoarrow::GeometryArrayTrait;
+//!
+//! let array: PointArray = vec![geo::point!(x: 1., y: 2.)].as_slice().into();
+//! let violations = validate_geoarrow_field(&array.extension_field(), &array.to_array_ref());
+//! assert!(violations.is_empty());
+//! ```
+
+use std::fmt::Display;
+
+use arrow_array::cast::AsArray;
+use arrow_array::{Array, OffsetSizeTrait};
+use arrow_buffer::OffsetBuffer;
+use arrow_schema::{DataType, Field, UnionMode};
+use serde_json::Value;
+
+use crate::array::metadata::ArrayMetadata;
+use crate::table::{GeoTable, GEOARROW_EXTENSION_NAMES};
+
+/// Extension metadata keys defined by the specification.
+const KNOWN_METADATA_KEYS: [&str; 3] = ["crs", "crs_type", "edges"];
+
+/// How severe a [`Violation`] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// The data can be read, but doesn't exactly follow the specification, such as a misnamed
+    /// child field or an unknown metadata key.
+    Warning,
+
+    /// The data doesn't follow the specification, and other implementations may fail to read it
+    /// or read it incorrectly.
+    Error,
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// A deviation from the GeoArrow specification found by [`validate_geoarrow_field`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    /// How severe the violation is.
+    pub severity: Severity,
+
+    /// The field the violation was found in, as the names of the fields from the top-level field
+    /// joined by `.`, such as `geometry.rings.vertices`.
+    pub path: String,
+
+    /// A description of the violation.
+    pub message: String,
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at {}: {}", self.severity, self.path, self.message)
+    }
+}
+
+/// Check that a field and its array follow the GeoArrow specification.
+///
+/// This checks that:
+///
+/// - The field has a GeoArrow extension name, and its physical type is the layout of that
+///   extension type.
+/// - Child fields have the names given by the specification, such as `vertices` and `rings`.
+/// - The coordinate dimension named by the coordinate fields matches their number.
+/// - The extension metadata is a JSON object with only known keys and valid values.
+/// - The offsets of every list in the array start at zero or more, never decrease and stay within
+///   their child array.
+///
+/// An empty list means the field follows the specification.
+pub fn validate_geoarrow_field(field: &Field, array: &dyn Array) -> Vec<Violation> {
+    let mut validator = Validator::default();
+    let path = field.name().to_string();
+
+    if field.data_type() != array.data_type() {
+        validator.error(
+            &path,
+            format!(
+                "Field has type {} but the array has type {}",
+                field.data_type(),
+                array.data_type()
+            ),
+        );
+        return validator.violations;
+    }
+
+    let Some(extension_name) = field.metadata().get("ARROW:extension:name") else {
+        validator.error(&path, "Field has no GeoArrow extension name".to_string());
+        return validator.violations;
+    };
+
+    validator.metadata(&path, field);
+    match extension_name.as_str() {
+        "geoarrow.point" => validator.coords(&path, field.data_type()),
+        "geoarrow.linestring" => validator.nested(&path, array, &["vertices"]),
+        "geoarrow.polygon" => validator.nested(&path, array, &["rings", "vertices"]),
+        "geoarrow.multipoint" => validator.nested(&path, array, &["points"]),
+        "geoarrow.multilinestring" => validator.nested(&path, array, &["linestrings", "vertices"]),
+        "geoarrow.multipolygon" => {
+            validator.nested(&path, array, &["polygons", "rings", "vertices"])
+        }
+        "geoarrow.geometry" => validator.union(&path, array),
+        "geoarrow.geometrycollection" => validator.geometry_collection(&path, array),
+        "geoarrow.wkb" | "ogc.wkb" => {
+            if extension_name == "ogc.wkb" {
+                validator.warning(
+                    &path,
+                    "The extension name ogc.wkb is deprecated in favor of geoarrow.wkb".to_string(),
+                );
+            }
+            match array.data_type() {
+                DataType::Binary => {
+                    let array = array.as_binary::<i32>();
+                    validator.offsets(&path, array.offsets(), array.values().len())
+                }
+                DataType::LargeBinary => {
+                    let array = array.as_binary::<i64>();
+                    validator.offsets(&path, array.offsets(), array.values().len())
+                }
+                dt => validator.error(
+                    &path,
+                    format!("Expected a Binary or LargeBinary array, got {}", dt),
+                ),
+            }
+        }
+        "geoarrow.wkt" => {
+            if !matches!(array.data_type(), DataType::Utf8 | DataType::LargeUtf8) {
+                validator.error(
+                    &path,
+                    format!(
+                        "Expected a Utf8 or LargeUtf8 array, got {}",
+                        array.data_type()
+                    ),
+                );
+            }
+        }
+        name => validator.error(&path, format!("Unknown extension name {}", name)),
+    }
+
+    validator.violations
+}
+
+impl GeoTable {
+    /// Check that every GeoArrow column of the table follows the GeoArrow specification, with
+    /// [`validate_geoarrow_field`].
+    ///
+    /// Each violation is reported once, even if it is found in several record batches.
+    pub fn validate_geoarrow(&self) -> Vec<Violation> {
+        let mut violations = vec![];
+        for (column_index, field) in self.schema().fields().iter().enumerate() {
+            let is_geoarrow = field
+                .metadata()
+                .get("ARROW:extension:name")
+                .is_some_and(|name| GEOARROW_EXTENSION_NAMES.contains(name.as_str()));
+            if !is_geoarrow && column_index != self.geometry_column_index() {
+                continue;
+            }
+
+            for batch in self.batches() {
+                for violation in validate_geoarrow_field(field, batch.column(column_index)) {
+                    if !violations.contains(&violation) {
+                        violations.push(violation);
+                    }
+                }
+            }
+        }
+        violations
+    }
+}
+
+#[derive(Default)]
+struct Validator {
+    violations: Vec<Violation>,
+}
+
+impl Validator {
+    fn error(&mut self, path: &str, message: String) {
+        self.violations.push(Violation {
+            severity: Severity::Error,
+            path: path.to_string(),
+            message,
+        });
+    }
+
+    fn warning(&mut self, path: &str, message: String) {
+        self.violations.push(Violation {
+            severity: Severity::Warning,
+            path: path.to_string(),
+            message,
+        });
+    }
+
+    fn metadata(&mut self, path: &str, field: &Field) {
+        let Some(extension_metadata) = field.metadata().get("ARROW:extension:metadata") else {
+            return;
+        };
+        if let Err(err) = ArrayMetadata::from_extension_metadata(extension_metadata) {
+            self.error(path, format!("Invalid extension metadata: {}", err));
+            return;
+        }
+        if let Ok(Value::Object(object)) = serde_json::from_str(extension_metadata) {
+            for key in object.keys() {
+                if !KNOWN_METADATA_KEYS.contains(&key.as_str()) {
+                    self.warning(path, format!("Unknown extension metadata key {}", key));
+                }
+            }
+        }
+    }
+
+    /// Check the coordinates at `path`, which must be interleaved or separated.
+    fn coords(&mut self, path: &str, data_type: &DataType) {
+        match data_type {
+            DataType::FixedSizeList(child, size) => {
+                let child_path = format!("{}.{}", path, child.name());
+                let expected_names: &[&str] = match size {
+                    2 => &["xy"],
+                    3 => &["xyz", "xym"],
+                    4 => &["xyzm"],
+                    _ => {
+                        self.error(
+                            path,
+                            format!("Expected 2, 3 or 4 coordinate dimensions, got {}", size),
+                        );
+                        return;
+                    }
+                };
+                if !expected_names.contains(&child.name().as_str()) {
+                    self.error(
+                        &child_path,
+                        format!(
+                            "Interleaved coordinates with {} dimensions must be named {}",
+                            size,
+                            expected_names.join(" or ")
+                        ),
+                    );
+                }
+                if child.data_type() != &DataType::Float64 {
+                    self.error(
+                        &child_path,
+                        format!("Expected Float64 coordinates, got {}", child.data_type()),
+                    );
+                }
+            }
+            DataType::Struct(fields) => {
+                let names = fields
+                    .iter()
+                    .map(|field| field.name().as_str())
+                    .collect::<Vec<_>>();
+                if !matches!(
+                    names.as_slice(),
+                    ["x", "y"] | ["x", "y", "z"] | ["x", "y", "m"] | ["x", "y", "z", "m"]
+                ) {
+                    self.error(
+                        path,
+                        format!(
+                            "Separated coordinates must be x, y and optionally z and m, got {}",
+                            names.join(", ")
+                        ),
+                    );
+                }
+                for field in fields {
+                    if field.data_type() != &DataType::Float64 {
+                        self.error(
+                            &format!("{}.{}", path, field.name()),
+                            format!("Expected Float64 coordinates, got {}", field.data_type()),
+                        );
+                    }
+                }
+            }
+            dt => self.error(
+                path,
+                format!("Expected FixedSizeList or Struct coordinates, got {}", dt),
+            ),
+        }
+    }
+
+    /// Check an array of lists nested `child_names.len()` deep, with children named
+    /// `child_names`, around coordinates.
+    fn nested(&mut self, path: &str, array: &dyn Array, child_names: &[&str]) {
+        let Some((child_name, child_names)) = child_names.split_first() else {
+            self.coords(path, array.data_type());
+            return;
+        };
+        match array.data_type() {
+            DataType::List(child) => {
+                let list = array.as_list::<i32>();
+                self.list(
+                    path,
+                    child,
+                    list.offsets(),
+                    list.values().as_ref(),
+                    child_name,
+                    child_names,
+                )
+            }
+            DataType::LargeList(child) => {
+                let list = array.as_list::<i64>();
+                self.list(
+                    path,
+                    child,
+                    list.offsets(),
+                    list.values().as_ref(),
+                    child_name,
+                    child_names,
+                )
+            }
+            dt => self.error(path, format!("Expected a List or LargeList, got {}", dt)),
+        }
+    }
+
+    fn list<O: OffsetSizeTrait>(
+        &mut self,
+        path: &str,
+        child: &Field,
+        offsets: &OffsetBuffer<O>,
+        values: &dyn Array,
+        child_name: &str,
+        child_names: &[&str],
+    ) {
+        let child_path = format!("{}.{}", path, child.name());
+        if child.name() != child_name {
+            self.warning(
+                &child_path,
+                format!("Expected the child field to be named {}", child_name),
+            );
+        }
+        self.offsets(path, offsets, values.len());
+        self.nested(&child_path, values, child_names);
+    }
+
+    fn offsets<O: OffsetSizeTrait>(
+        &mut self,
+        path: &str,
+        offsets: &OffsetBuffer<O>,
+        num_values: usize,
+    ) {
+        if offsets[0] < O::zero() {
+            self.error(path, "The first offset is negative".to_string());
+        }
+        if let Some(i) = offsets.windows(2).position(|window| window[1] < window[0]) {
+            self.error(path, format!("Offsets decrease after index {}", i));
+        }
+        if offsets.last().as_usize() > num_values {
+            self.error(
+                path,
+                format!(
+                    "The last offset {} is beyond the {} child values",
+                    offsets.last().as_usize(),
+                    num_values
+                ),
+            );
+        }
+    }
+
+    /// Check a `geoarrow.geometry` union, whose children are identified by their type ids.
+    fn union(&mut self, path: &str, array: &dyn Array) {
+        let DataType::Union(fields, mode) = array.data_type() else {
+            self.error(path, format!("Expected a Union, got {}", array.data_type()));
+            return;
+        };
+        if *mode != UnionMode::Dense {
+            self.error(path, "Expected a dense Union".to_string());
+        }
+        let union = array.as_union();
+        for (type_id, field) in fields.iter() {
+            let child_path = format!("{}.{}", path, field.name());
+            let child = union.child(type_id);
+            match type_id % 10 {
+                1 => self.coords(&child_path, child.data_type()),
+                2 => self.nested(&child_path, child.as_ref(), &["vertices"]),
+                3 => self.nested(&child_path, child.as_ref(), &["rings", "vertices"]),
+                4 => self.nested(&child_path, child.as_ref(), &["points"]),
+                5 => self.nested(&child_path, child.as_ref(), &["linestrings", "vertices"]),
+                6 => self.nested(
+                    &child_path,
+                    child.as_ref(),
+                    &["polygons", "rings", "vertices"],
+                ),
+                7 => self.geometry_collection(&child_path, child.as_ref()),
+                _ => self.error(&child_path, format!("Unknown union type id {}", type_id)),
+            }
+        }
+    }
+
+    /// Check a list of `geoarrow.geometry` unions.
+    fn geometry_collection(&mut self, path: &str, array: &dyn Array) {
+        let (child, values) = match array.data_type() {
+            DataType::List(child) => {
+                let list = array.as_list::<i32>();
+                self.offsets(path, list.offsets(), list.values().len());
+                (child, list.values())
+            }
+            DataType::LargeList(child) => {
+                let list = array.as_list::<i64>();
+                self.offsets(path, list.offsets(), list.values().len());
+                (child, list.values())
+            }
+            dt => {
+                self.error(path, format!("Expected a List or LargeList, got {}", dt));
+                return;
+            }
+        };
+        let child_path = format!("{}.{}", path, child.name());
+        if child.name() != "geometries" {
+            self.warning(
+                &child_path,
+                "Expected the child field to be named geometries".to_string(),
+            );
+        }
+        self.union(&child_path, values.as_ref());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use arrow_array::{ArrayRef, Float64Array, ListArray, StructArray};
+    use arrow_buffer::ScalarBuffer;
+    use arrow_schema::Fields;
+
+    use super::*;
+    use crate::array::{CoordType, MixedGeometryBuilder};
+    use crate::io::wkb::to_wkb;
+    use crate::test::{linestring, multilinestring, multipoint, multipolygon, point, polygon};
+    use crate::trait_::GeometryArraySelfMethods;
+    use crate::GeometryArrayTrait;
+
+    fn validate(array: &dyn GeometryArrayTrait) -> Vec<Violation> {
+        validate_geoarrow_field(&array.extension_field(), &array.to_array_ref())
+    }
+
+    fn with_extension(field: Field, name: &str, metadata: Option<&str>) -> Field {
+        let mut field_metadata = HashMap::new();
+        field_metadata.insert("ARROW:extension:name".to_string(), name.to_string());
+        if let Some(metadata) = metadata {
+            field_metadata.insert("ARROW:extension:metadata".to_string(), metadata.to_string());
+        }
+        field.with_metadata(field_metadata)
+    }
+
+    #[test]
+    fn valid_arrays() {
+        let arrays: Vec<Arc<dyn GeometryArrayTrait>> = vec![
+            Arc::new(point::point_array()),
+            Arc::new(linestring::ls_array()),
+            Arc::new(linestring::large_ls_array()),
+            Arc::new(polygon::p_array()),
+            Arc::new(multipoint::mp_array()),
+            Arc::new(multilinestring::ml_array()),
+            Arc::new(multipolygon::mp_array()),
+            Arc::new(
+                MixedGeometryBuilder::<i32>::from_nullable_geometries(
+                    &[
+                        Some(geo::Geometry::Point(point::p0())),
+                        Some(geo::Geometry::Polygon(polygon::p0())),
+                    ],
+                    None,
+                    Default::default(),
+                    false,
+                )
+                .unwrap()
+                .finish(),
+            ),
+            Arc::new(to_wkb::<i32>(&polygon::p_array())),
+        ];
+        for array in arrays {
+            assert_eq!(validate(array.as_ref()), vec![], "{:?}", array.data_type());
+        }
+
+        let separated = polygon::p_array().into_coord_type(CoordType::Separated);
+        assert_eq!(validate(&separated), vec![]);
+    }
+
+    #[test]
+    fn missing_extension_name() {
+        let array = point::point_array();
+        let field = Field::new("geometry", array.storage_type(), true);
+        let violations = validate_geoarrow_field(&field, &array.to_array_ref());
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].severity, Severity::Error);
+        assert_eq!(violations[0].path, "geometry");
+    }
+
+    #[test]
+    fn extension_name_does_not_match_layout() {
+        let array = point::point_array();
+        let field = with_extension(
+            Field::new("geometry", array.storage_type(), true),
+            "geoarrow.polygon",
+            None,
+        );
+        let violations = validate_geoarrow_field(&field, &array.to_array_ref());
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].severity, Severity::Error);
+    }
+
+    #[test]
+    fn misnamed_children() {
+        // A linestring whose coordinates are named "xyz" but have 2 dimensions, in a list whose
+        // child is named "item"
+        let coords_field = Arc::new(Field::new("xyz", DataType::Float64, false));
+        let coords = arrow_array::FixedSizeListArray::new(
+            coords_field,
+            2,
+            Arc::new(Float64Array::from(vec![0., 1., 2., 3.])),
+            None,
+        );
+        let list = ListArray::new(
+            Arc::new(Field::new("item", coords.data_type().clone(), false)),
+            OffsetBuffer::new(ScalarBuffer::from(vec![0, 2])),
+            Arc::new(coords),
+            None,
+        );
+        let field = with_extension(
+            Field::new("geometry", list.data_type().clone(), true),
+            "geoarrow.linestring",
+            None,
+        );
+        let violations = validate_geoarrow_field(&field, &list);
+        assert_eq!(
+            violations,
+            vec![
+                Violation {
+                    severity: Severity::Warning,
+                    path: "geometry.item".to_string(),
+                    message: "Expected the child field to be named vertices".to_string(),
+                },
+                Violation {
+                    severity: Severity::Error,
+                    path: "geometry.item.xyz".to_string(),
+                    message: "Interleaved coordinates with 2 dimensions must be named xy"
+                        .to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn separated_coordinate_names() {
+        let fields = Fields::from(vec![
+            Field::new("lon", DataType::Float64, false),
+            Field::new("lat", DataType::Float64, false),
+        ]);
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(Float64Array::from(vec![0.])),
+            Arc::new(Float64Array::from(vec![1.])),
+        ];
+        let array = StructArray::new(fields, columns, None);
+        let field = with_extension(
+            Field::new("geometry", array.data_type().clone(), true),
+            "geoarrow.point",
+            None,
+        );
+        let violations = validate_geoarrow_field(&field, &array);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].severity, Severity::Error);
+    }
+
+    #[test]
+    fn metadata() {
+        let array = point::point_array();
+        let field = |metadata| {
+            with_extension(
+                Field::new("geometry", array.storage_type(), true),
+                "geoarrow.point",
+                Some(metadata),
+            )
+        };
+
+        let violations = validate_geoarrow_field(
+            &field(r#"{"crs": "EPSG:4326", "crs_type": "authority_code"}"#),
+            &array.to_array_ref(),
+        );
+        assert_eq!(violations, vec![]);
+
+        let violations = validate_geoarrow_field(
+            &field(r#"{"projection": "EPSG:4326"}"#),
+            &array.to_array_ref(),
+        );
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].severity, Severity::Warning);
+
+        for metadata in [r#"{"crs": "#, r#"{"edges": "vincenty"}"#, "[]"] {
+            let violations = validate_geoarrow_field(&field(metadata), &array.to_array_ref());
+            assert_eq!(violations.len(), 1, "{}", metadata);
+            assert_eq!(violations[0].severity, Severity::Error);
+        }
+    }
+
+    #[test]
+    fn decreasing_offsets() {
+        let array = linestring::ls_array();
+        let coords = array.to_array_ref().as_list::<i32>().values().clone();
+        // Bypass the checks of OffsetBuffer::new
+        let offsets = unsafe { OffsetBuffer::new_unchecked(ScalarBuffer::from(vec![0, 3, 1])) };
+        let list = ListArray::new(
+            Arc::new(Field::new("vertices", coords.data_type().clone(), false)),
+            offsets,
+            coords,
+            None,
+        );
+        let violations = validate_geoarrow_field(&array.extension_field(), &list);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].message, "Offsets decrease after index 1");
+    }
+
+    #[test]
+    fn table() {
+        let table = point::table();
+        assert_eq!(table.validate_geoarrow(), vec![]);
+    }
+
+    #[test]
+    fn display() {
+        let violation = Violation {
+            severity: Severity::Warning,
+            path: "geometry.item".to_string(),
+            message: "Expected the child field to be named vertices".to_string(),
+        };
+        assert_eq!(
+            violation.to_string(),
+            "warning at geometry.item: Expected the child field to be named vertices"
+        );
+    }
+}